@@ -403,6 +403,7 @@ enum Command {
     AppendChunk(Chunk),
     AppendRow(EntityPath, PendingRow),
     Flush(Sender<()>),
+    FlushThen(Box<dyn FnOnce() + Send>),
     UpdateConfig(ChunkBatcherConfig),
     Shutdown,
 }
@@ -492,6 +493,16 @@ impl ChunkBatcher {
         self.inner.flush_blocking();
     }
 
+    /// Initiates a flush of the pipeline and returns immediately.
+    ///
+    /// `on_done` is called from the batching thread once all the flushed chunks have been sent,
+    /// which lets callers wait for the flush without blocking a thread.
+    /// See [`ChunkBatcher`] docs for ordering semantics and multithreading guarantees.
+    #[inline]
+    pub fn flush_then(&self, on_done: impl FnOnce() + Send + 'static) {
+        self.inner.flush_then(Box::new(on_done));
+    }
+
     /// Updates the batcher's configuration as far as possible.
     pub fn update_config(&self, config: ChunkBatcherConfig) {
         self.inner.update_config(config);
@@ -532,6 +543,10 @@ impl ChunkBatcherInner {
         oneshot.recv().ok();
     }
 
+    fn flush_then(&self, on_done: Box<dyn FnOnce() + Send>) {
+        self.send_cmd(Command::FlushThen(on_done));
+    }
+
     fn update_config(&self, config: ChunkBatcherConfig) {
         self.send_cmd(Command::UpdateConfig(config));
     }
@@ -695,6 +710,14 @@ fn batching_thread(
                         drop(oneshot); // signals the oneshot
                     },
 
+                    Command::FlushThen(on_done) => {
+                        skip_next_tick = true;
+                        for acc in accs.values_mut() {
+                            do_flush_all(acc, &tx_chunk, "manual", config.chunk_max_rows_if_unsorted);
+                        }
+                        on_done();
+                    },
+
                     Command::UpdateConfig(new_config) => {
                         // Warn if properties changed that we can't change here.
                         if config.max_commands_in_flight != new_config.max_commands_in_flight ||
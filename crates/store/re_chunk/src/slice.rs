@@ -5,7 +5,7 @@ use arrow::array::{
 use itertools::Itertools as _;
 use nohash_hasher::IntSet;
 
-use re_log_types::TimelineName;
+use re_log_types::{AbsoluteTimeRange, TimelineName};
 use re_types_core::ComponentDescriptor;
 
 use crate::{Chunk, RowId, TimeColumn};
@@ -587,6 +587,37 @@ impl Chunk {
         Some(chunk)
     }
 
+    /// Keeps only the rows whose time on `timeline` lies within `time_range`.
+    ///
+    /// If `timeline` is not found within the [`Chunk`] (e.g. static chunks), the chunk is returned as-is.
+    ///
+    /// WARNING: the returned chunk has the same old [`crate::ChunkId`]! Change it with [`Self::with_id`].
+    #[must_use]
+    pub fn time_range_filtered(
+        &self,
+        timeline: &TimelineName,
+        time_range: AbsoluteTimeRange,
+    ) -> Self {
+        let Some(time_column) = self.timelines.get(timeline) else {
+            return self.clone();
+        };
+
+        let chunk_time_range = time_column.time_range();
+        if time_range.contains(chunk_time_range.min())
+            && time_range.contains(chunk_time_range.max())
+        {
+            return self.clone();
+        }
+
+        let filter = ArrowBooleanArray::from(
+            time_column
+                .times()
+                .map(|time| time_range.contains(time))
+                .collect_vec(),
+        );
+        self.filtered(&filter).unwrap_or_else(|| self.emptied())
+    }
+
     /// Applies a [take] kernel to the [`Chunk`] as a whole.
     ///
     /// In release builds, indices are allowed to have null entries (they will be taken as `null`s).
@@ -0,0 +1,252 @@
+use std::{sync::Arc, time::Duration};
+
+use ahash::HashSet;
+use re_byte_size::SizeBytes as _;
+use web_time::Instant;
+
+use re_chunk::{Chunk, ChunkId};
+use re_log_types::StoreId;
+
+use crate::{ChunkStore, ChunkStoreConfig, ChunkStoreDiffKind, ChunkStoreEvent, ChunkStoreResult};
+
+// ---
+
+/// Configures [`ChunkStore::compact`].
+///
+/// Insertion-time compaction only ever merges a new chunk with a single neighbor, which falls
+/// apart when data comes in as many tiny chunks, e.g. when streaming from the SDKs with small
+/// batches or when loading MCAP files message by message.
+/// Background compaction goes over the whole store instead, and merges all the small chunks it
+/// can find into larger, sorted ones.
+#[derive(Debug, Clone)]
+pub struct CompactionOptions {
+    /// Merged chunks won't grow beyond this size, in bytes.
+    ///
+    /// See [`ChunkStoreConfig::chunk_max_bytes`].
+    pub target_chunk_max_bytes: u64,
+
+    /// Merged time-sorted chunks won't grow beyond this number of rows.
+    ///
+    /// See [`ChunkStoreConfig::chunk_max_rows`].
+    pub target_chunk_max_rows: u64,
+
+    /// Merged non time-sorted chunks won't grow beyond this number of rows.
+    ///
+    /// See [`ChunkStoreConfig::chunk_max_rows_if_unsorted`].
+    pub target_chunk_max_rows_if_unsorted: u64,
+
+    /// Don't bother compacting unless there are at least this many fragmented chunks.
+    ///
+    /// A chunk is considered fragmented if it is less than half the target size, both in bytes
+    /// and in rows, see [`Self::is_chunk_fragmented`].
+    pub trigger_min_num_fragmented_chunks: u64,
+
+    /// Don't bother compacting unless at least this fraction of the temporal chunks is fragmented.
+    ///
+    /// The fraction must be a float in the range [0.0 : 1.0].
+    pub trigger_min_fragmented_fraction: f64,
+
+    /// How long the compaction is allowed to run for.
+    ///
+    /// Chunks that couldn't be looked at within the budget are left as-is, until the next run.
+    ///
+    /// The default is an unbounded time budget (i.e. throughput only).
+    pub time_budget: Duration,
+}
+
+impl Default for CompactionOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::from_store_config(&ChunkStoreConfig::DEFAULT)
+    }
+}
+
+impl CompactionOptions {
+    /// Targets the same chunk sizes as the insertion-time compaction of the given configuration.
+    pub fn from_store_config(config: &ChunkStoreConfig) -> Self {
+        Self {
+            target_chunk_max_bytes: config.chunk_max_bytes,
+            target_chunk_max_rows: config.chunk_max_rows,
+            target_chunk_max_rows_if_unsorted: config.chunk_max_rows_if_unsorted,
+            trigger_min_num_fragmented_chunks: 128,
+            trigger_min_fragmented_fraction: 0.25,
+            time_budget: Duration::MAX,
+        }
+    }
+
+    /// Is this chunk small enough that it could at least double in size by being merged?
+    ///
+    /// Static chunks are never considered fragmented.
+    pub fn is_chunk_fragmented(&self, chunk: &Chunk) -> bool {
+        if chunk.is_static() {
+            return false;
+        }
+
+        let max_rows = if chunk.is_time_sorted() {
+            self.target_chunk_max_rows
+        } else {
+            self.target_chunk_max_rows_if_unsorted
+        };
+
+        2 * chunk.total_size_bytes() <= self.target_chunk_max_bytes
+            && 2 * chunk.num_rows() as u64 <= max_rows
+    }
+
+    /// The configuration of the scratch store used to merge chunks together.
+    fn scratch_store_config(&self) -> ChunkStoreConfig {
+        ChunkStoreConfig {
+            enable_changelog: false,
+            enable_spill_to_disk: false,
+            enable_static_history: false,
+            chunk_max_bytes: self.target_chunk_max_bytes,
+            chunk_max_rows: self.target_chunk_max_rows,
+            chunk_max_rows_if_unsorted: self.target_chunk_max_rows_if_unsorted,
+        }
+    }
+}
+
+impl ChunkStore {
+    /// Merges the small temporal chunks of the store into larger ones, according to `options`.
+    ///
+    /// This is a no-op unless the trigger thresholds of `options` are met.
+    ///
+    /// Returns the resulting [`ChunkStoreEvent`]s: a deletion for every chunk that was merged
+    /// away, followed by an addition for every merged chunk.
+    /// Chunks that were spilled to disk are left untouched.
+    pub fn compact(
+        &mut self,
+        options: &CompactionOptions,
+    ) -> ChunkStoreResult<Vec<ChunkStoreEvent>> {
+        re_tracing::profile_function!();
+
+        let start_time = Instant::now();
+
+        // Going through the chunks in RowId order gives the merged chunks the best shot at being
+        // sorted already.
+        let fragmented_chunks: Vec<Arc<Chunk>> = self
+            .chunk_ids_per_min_row_id
+            .values()
+            .filter_map(|chunk_id| self.chunks_per_chunk_id.get(chunk_id))
+            .filter(|chunk| options.is_chunk_fragmented(chunk))
+            .cloned()
+            .collect();
+
+        let num_fragmented_chunks = fragmented_chunks.len() as u64;
+        let num_temporal_chunks = self.temporal_chunks_stats.num_chunks;
+        if num_fragmented_chunks < options.trigger_min_num_fragmented_chunks
+            || (num_fragmented_chunks as f64)
+                < (num_temporal_chunks as f64) * options.trigger_min_fragmented_fraction
+        {
+            return Ok(Vec::new());
+        }
+
+        // Let the regular insertion-time compaction do the heavy lifting, in isolation.
+        // The scratch store gets its own id so that dropping it doesn't affect our subscribers.
+        let mut scratch = Self::new(
+            StoreId::random(self.id.kind(), self.id.application_id().clone()),
+            options.scratch_store_config(),
+        );
+
+        let mut chunk_ids_seen: HashSet<ChunkId> = HashSet::default();
+        for chunk in &fragmented_chunks {
+            if start_time.elapsed() >= options.time_budget {
+                break;
+            }
+
+            scratch.insert_chunk(chunk)?;
+            chunk_ids_seen.insert(chunk.id());
+        }
+
+        let merged_chunks: Vec<Arc<Chunk>> = scratch
+            .chunks_per_chunk_id
+            .values()
+            .filter(|chunk| !chunk_ids_seen.contains(&chunk.id()))
+            .cloned()
+            .collect();
+
+        if merged_chunks.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let chunk_ids_merged: Vec<ChunkId> = chunk_ids_seen
+            .into_iter()
+            .filter(|chunk_id| !scratch.chunks_per_chunk_id.contains_key(chunk_id))
+            .collect();
+
+        re_log::trace!(
+            kind = "compaction",
+            num_fragmented_chunks = re_format::format_uint(num_fragmented_chunks),
+            num_chunks_merged = re_format::format_uint(chunk_ids_merged.len()),
+            num_chunks_created = re_format::format_uint(merged_chunks.len()),
+            "compacted store"
+        );
+
+        self.replace_chunks(chunk_ids_merged, &merged_chunks)
+    }
+
+    /// Removes the given chunks, then inserts the new ones as-is.
+    ///
+    /// The new chunks are inserted with insertion-time compaction disabled, as they're expected to
+    /// be as large as they're going to get already.
+    ///
+    /// Returns the resulting [`ChunkStoreEvent`]s: a deletion for every chunk that was removed,
+    /// followed by an addition for every chunk that was inserted.
+    pub(crate) fn replace_chunks(
+        &mut self,
+        chunk_ids_to_remove: impl IntoIterator<Item = ChunkId>,
+        chunks_to_insert: &[Arc<Chunk>],
+    ) -> ChunkStoreResult<Vec<ChunkStoreEvent>> {
+        re_tracing::profile_function!();
+
+        // The old chunks must be removed first, otherwise their RowIds would be indexed twice.
+        let generation = self.generation();
+        let mut events: Vec<ChunkStoreEvent> = Vec::new();
+
+        for chunk_id in chunk_ids_to_remove {
+            for diff in self.remove_chunk(chunk_id) {
+                events.push(ChunkStoreEvent {
+                    store_id: self.id.clone(),
+                    store_generation: generation.clone(),
+                    event_id: self
+                        .event_id
+                        .fetch_add(1, std::sync::atomic::Ordering::Relaxed),
+                    diff,
+                });
+            }
+        }
+
+        if self.config.enable_changelog {
+            if cfg!(debug_assertions) {
+                let any_event_other_than_deletion = events
+                    .iter()
+                    .any(|e| e.kind != ChunkStoreDiffKind::Deletion);
+                assert!(!any_event_other_than_deletion);
+            }
+
+            Self::on_events(&events);
+        } else {
+            events.clear();
+        }
+
+        // Don't let the insertion-time compaction try and merge the new chunks any further.
+        let compaction_disabled = ChunkStoreConfig {
+            chunk_max_bytes: 0,
+            chunk_max_rows: 0,
+            chunk_max_rows_if_unsorted: 0,
+            ..self.config.clone()
+        };
+        let config = std::mem::replace(&mut self.config, compaction_disabled);
+
+        let result = chunks_to_insert
+            .iter()
+            .try_for_each(|chunk| -> ChunkStoreResult<()> {
+                events.append(&mut self.insert_chunk(chunk)?);
+                Ok(())
+            });
+
+        self.config = config;
+        result?;
+
+        Ok(events)
+    }
+}
@@ -6,7 +6,7 @@ use std::{
 };
 
 use arrow::{
-    array::ListArray as ArrowListArray,
+    array::{Array as _, ArrayRef as ArrowArrayRef, ListArray as ArrowListArray},
     datatypes::{DataType as ArrowDatatype, Field as ArrowField},
 };
 use itertools::Itertools as _;
@@ -22,6 +22,255 @@ use tap::Tap as _;
 
 // --- Queries v2 ---
 
+/// Keeps only the rows where a component column contains at least one of the given values.
+///
+/// See [`QueryExpression::filtered_component_values`].
+#[derive(Debug, Clone)]
+pub struct ComponentValueFilter {
+    /// The component column to filter on.
+    pub column: ComponentColumnSelector,
+
+    /// The values to look for.
+    ///
+    /// These must be of the same datatype as the individual values of the column, e.g. `UInt16`
+    /// for class IDs.
+    pub values: ArrowArrayRef,
+}
+
+impl PartialEq for ComponentValueFilter {
+    fn eq(&self, other: &Self) -> bool {
+        let Self { column, values } = self;
+        *column == other.column && values.to_data() == other.values.to_data()
+    }
+}
+
+impl Eq for ComponentValueFilter {}
+
+impl std::hash::Hash for ComponentValueFilter {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self { column, values } = self;
+        column.hash(state);
+        values.data_type().hash(state);
+        values.len().hash(state);
+    }
+}
+
+/// How component values are compared to the reference value of a [`ComponentPredicate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ComparisonOperator {
+    /// `==`
+    Eq,
+
+    /// `!=`
+    NotEq,
+
+    /// `<`
+    Lt,
+
+    /// `<=`
+    LtEq,
+
+    /// `>`
+    Gt,
+
+    /// `>=`
+    GtEq,
+}
+
+impl std::fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Eq => "==",
+            Self::NotEq => "!=",
+            Self::Lt => "<",
+            Self::LtEq => "<=",
+            Self::Gt => ">",
+            Self::GtEq => ">=",
+        })
+    }
+}
+
+impl std::str::FromStr for ComparisonOperator {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "==" => Ok(Self::Eq),
+            "!=" => Ok(Self::NotEq),
+            "<" => Ok(Self::Lt),
+            "<=" => Ok(Self::LtEq),
+            ">" => Ok(Self::Gt),
+            ">=" => Ok(Self::GtEq),
+            _ => Err(format!(
+                "unknown comparison operator {s:?}, expected one of: ==, !=, <, <=, >, >="
+            )),
+        }
+    }
+}
+
+/// Keeps only the rows where at least one of the values of a component column satisfies a
+/// comparison, e.g. `confidence > 0.8` or `label == "car"`.
+///
+/// See [`QueryExpression::filtered_component_predicates`].
+#[derive(Debug, Clone)]
+pub struct ComponentPredicate {
+    /// The component column to filter on.
+    pub column: ComponentColumnSelector,
+
+    pub op: ComparisonOperator,
+
+    /// The value to compare against, as an array of length 1.
+    ///
+    /// It is cast to the datatype of the individual values of the column before comparing.
+    pub value: ArrowArrayRef,
+}
+
+impl PartialEq for ComponentPredicate {
+    fn eq(&self, other: &Self) -> bool {
+        let Self { column, op, value } = self;
+        *column == other.column && *op == other.op && value.to_data() == other.value.to_data()
+    }
+}
+
+impl Eq for ComponentPredicate {}
+
+impl std::hash::Hash for ComponentPredicate {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self { column, op, value } = self;
+        column.hash(state);
+        op.hash(state);
+        value.data_type().hash(state);
+    }
+}
+
+impl std::fmt::Display for ComponentPredicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { column, op, value } = self;
+        let value = re_arrow_util::format_data_type(value.data_type());
+        write!(f, "{column} {op} <{value}>")
+    }
+}
+
+impl ComponentPredicate {
+    /// For each row of a component column, returns whether at least one of its values satisfies
+    /// the predicate.
+    ///
+    /// Nulls never satisfy a predicate.
+    pub fn matching_rows(
+        &self,
+        list_array: &ArrowListArray,
+    ) -> Result<Vec<bool>, arrow::error::ArrowError> {
+        use arrow::compute::kernels::cmp;
+
+        if self.value.len() != 1 {
+            return Err(arrow::error::ArrowError::InvalidArgumentError(format!(
+                "expected a single value to compare against, got {}",
+                self.value.len()
+            )));
+        }
+
+        let values = list_array.values();
+        let value = arrow::compute::cast(&self.value, values.data_type())?;
+        let value = arrow::array::Scalar::new(value);
+
+        let mask = match self.op {
+            ComparisonOperator::Eq => cmp::eq(values, &value),
+            ComparisonOperator::NotEq => cmp::neq(values, &value),
+            ComparisonOperator::Lt => cmp::lt(values, &value),
+            ComparisonOperator::LtEq => cmp::lt_eq(values, &value),
+            ComparisonOperator::Gt => cmp::gt(values, &value),
+            ComparisonOperator::GtEq => cmp::gt_eq(values, &value),
+        }?;
+
+        Ok(list_array
+            .offsets()
+            .windows(2)
+            .enumerate()
+            .map(|(i, offsets)| {
+                list_array.is_valid(i)
+                    && (offsets[0] as usize..offsets[1] as usize)
+                        .any(|j| mask.is_valid(j) && mask.value(j))
+            })
+            .collect())
+    }
+}
+
+/// How the values of a component column get reduced within a time bucket.
+///
+/// See [`TemporalAggregation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AggregationOperator {
+    /// The smallest value.
+    Min,
+
+    /// The largest value.
+    Max,
+
+    /// The arithmetic mean of all the values.
+    Mean,
+
+    /// The most recent value.
+    Last,
+}
+
+impl std::fmt::Display for AggregationOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Min => "min",
+            Self::Max => "max",
+            Self::Mean => "mean",
+            Self::Last => "last",
+        })
+    }
+}
+
+impl std::str::FromStr for AggregationOperator {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "min" => Ok(Self::Min),
+            "max" => Ok(Self::Max),
+            "mean" => Ok(Self::Mean),
+            "last" => Ok(Self::Last),
+            _ => Err(format!(
+                "unknown aggregation operator {s:?}, expected one of: min, max, mean, last"
+            )),
+        }
+    }
+}
+
+/// Groups the rows of a query into fixed-size buckets of index values, and reduces each bucket
+/// into a single row.
+///
+/// Buckets are aligned on multiples of [`Self::bucket_size`], and only non-empty buckets yield
+/// a row. The index value of that row is the start of its bucket.
+///
+/// Numeric component columns are reduced instance-wise using [`Self::op`], i.e. the n-th
+/// instance of the result is computed from the n-th instances of all the rows in the bucket.
+/// The reduction happens in 64-bit floating point, and the result is cast back to the datatype
+/// of the column.
+/// All other columns always yield their most recent non-null value within the bucket.
+///
+/// See [`QueryExpression::aggregation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TemporalAggregation {
+    /// The size of each bucket, in index units (e.g. nanoseconds or sequence numbers).
+    pub bucket_size: std::num::NonZeroU64,
+
+    /// How the values within a bucket get reduced.
+    pub op: AggregationOperator,
+}
+
+impl TemporalAggregation {
+    /// Returns the start of the bucket that contains `index_value`.
+    pub fn bucket_start(&self, index_value: IndexValue) -> IndexValue {
+        let bucket_size = i64::try_from(self.bucket_size.get()).unwrap_or(i64::MAX);
+        let index_value = index_value.as_i64();
+        TimeInt::new_temporal(index_value - index_value.rem_euclid(bucket_size))
+    }
+}
+
 /// Specifies how null values should be filled in the returned dataframe.
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SparseFillStrategy {
@@ -232,11 +481,68 @@ pub struct QueryExpression {
     // TODO(cmc): multi-pov support
     pub filtered_is_not_null: Option<ComponentColumnSelector>,
 
+    /// The component values used to filter out _rows_ from the view contents.
+    ///
+    /// Only rows where the selected column contains at least one of the given values will be kept
+    /// in the final dataset.
+    ///
+    /// * This has no effect if `filtered_index` isn't set.
+    /// * This has no effect if [`QueryExpression::using_index_values`] is set.
+    ///
+    /// Queries with such a filter can skip most of the data if the component is indexed, see
+    /// [`ChunkStore::create_value_index`].
+    ///
+    /// Example: `ComponentValueFilter("detections:ClassId", [1, 2])`.
+    pub filtered_component_values: Option<ComponentValueFilter>,
+
+    /// The predicates used to filter out _rows_ from the view contents.
+    ///
+    /// Only rows where the selected columns satisfy _all_ of the predicates will be kept in the
+    /// final dataset. Predicates are evaluated as the chunks are iterated over, without
+    /// materializing the intermediate results.
+    ///
+    /// * This has no effect if `filtered_index` isn't set.
+    /// * This has no effect if [`QueryExpression::using_index_values`] is set.
+    ///
+    /// Equality predicates can skip most of the data if the component is indexed, see
+    /// [`ChunkStore::create_value_index`].
+    ///
+    /// Example: `[ComponentPredicate("detections:Scalars:scalars", Gt, 0.8)]`.
+    pub filtered_component_predicates: Vec<ComponentPredicate>,
+
+    /// The entity whose data drives the _rows_ of the final dataset, akin to an as-of join.
+    ///
+    /// Only rows where at least 1 column of this entity contains non-null data will be kept in the
+    /// final dataset.
+    /// The columns of all the other entities are then aligned on these rows using latest-at
+    /// semantics, i.e. they yield their most recent value at or before each index value,
+    /// regardless of [`QueryExpression::sparse_fill_strategy`].
+    ///
+    /// This makes it possible to e.g. compare odometry with ground-truth poses logged at different
+    /// rates, in a single query.
+    ///
+    /// * This has no effect if `filtered_index` isn't set.
+    /// * This has no effect if [`QueryExpression::using_index_values`] is set.
+    ///
+    /// Example: `EntityPath("/robot/odometry")`.
+    pub join_on_entity: Option<EntityPath>,
+
     /// Specifies how null values should be filled in the returned dataframe.
     ///
     /// Defaults to [`SparseFillStrategy::None`].
     pub sparse_fill_strategy: SparseFillStrategy,
 
+    /// Reduces the _rows_ of the view contents into fixed-size buckets of index values.
+    ///
+    /// This bounds the number of rows returned for large datasets (e.g. when plotting
+    /// million-point scalar series), while doing the heavy lifting store-side.
+    /// The reduction happens after all the other filters and the sparse-filling have been applied.
+    ///
+    /// * This has no effect if `filtered_index` isn't set.
+    ///
+    /// Example: `TemporalAggregation(bucket_size=1_000_000, op=Mean)`.
+    pub aggregation: Option<TemporalAggregation>,
+
     /// The specific _columns_ to sample from the final view contents.
     ///
     /// The order of the samples will be respected in the final result.
@@ -478,7 +784,11 @@ impl ChunkStore {
             filtered_index_values: _,
             using_index_values: _,
             filtered_is_not_null: _,
+            filtered_component_values: _,
+            filtered_component_predicates: _,
+            join_on_entity: _,
             sparse_fill_strategy: _,
+            aggregation: _,
             selection: _,
         } = query;
 
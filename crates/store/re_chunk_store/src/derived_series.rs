@@ -0,0 +1,214 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use arrow::{
+    array::{Array as _, ArrayRef as ArrowArrayRef, AsArray as _, ListArray as ArrowListArray},
+    datatypes::{DataType as ArrowDataType, Float64Type},
+};
+
+use re_chunk::{Chunk, ChunkComponents, ChunkId};
+use re_log_types::EntityPath;
+use re_types_core::ComponentDescriptor;
+
+use crate::{ChunkStore, ChunkStoreError, ChunkStoreEvent, ChunkStoreResult, Expression};
+
+// ---
+
+/// A scalar series whose values are computed from the components of another entity, e.g. the
+/// magnitude `sqrt(ax² + ay² + az²)` of the acceleration measured by an IMU.
+///
+/// For every temporal row of [`Self::source_entity_path`], [`Self::expression`] is evaluated
+/// and the result is written to [`Self::entity_path`] as a `Scalars` archetype, with the same
+/// times. All the variables of the expression must have been logged in the same row, rows where
+/// any of them is missing get a null value.
+///
+/// Values are computed as chunks get inserted, see [`ChunkStore::register_derived_series`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DerivedSeries {
+    /// The entity to write the derived series to.
+    pub entity_path: EntityPath,
+
+    /// The entity whose components the variables of the expression refer to.
+    pub source_entity_path: EntityPath,
+
+    /// How to compute the derived values, see [`crate::expression`].
+    pub expression: Expression,
+}
+
+impl DerivedSeries {
+    /// The descriptor of the derived values, matching `Scalars:scalars`.
+    pub fn descriptor() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Scalars".into()),
+            component: "Scalars:scalars".into(),
+            component_type: Some("rerun.components.Scalar".into()),
+        }
+    }
+
+    /// Computes the derived chunk for `chunk`, if it belongs to the source entity.
+    fn derive_chunk(&self, chunk: &Chunk) -> Option<Chunk> {
+        if chunk.is_static() || chunk.entity_path() != &self.source_entity_path {
+            return None;
+        }
+
+        // Resolve all variables to their (float) columns up front.
+        let variables = self.expression.variables();
+        let mut columns: BTreeMap<&str, (&ArrowListArray, ArrowArrayRef)> = BTreeMap::default();
+        for variable in &variables {
+            if columns.contains_key(variable.name.as_str()) {
+                continue;
+            }
+
+            let list_array = chunk.components().iter().find_map(|(descr, list_array)| {
+                variable
+                    .matches(descr.component.as_str())
+                    .then_some(list_array)
+            })?;
+            let values = match arrow::compute::cast(list_array.values(), &ArrowDataType::Float64) {
+                Ok(values) => values,
+                Err(err) => {
+                    re_log::warn_once!(
+                        "Cannot derive series {} from {}: {}: {err}",
+                        self.entity_path,
+                        self.source_entity_path,
+                        variable.name,
+                    );
+                    return None;
+                }
+            };
+            columns.insert(variable.name.as_str(), (list_array, values));
+        }
+
+        let values: Vec<Option<f64>> = (0..chunk.num_rows())
+            .map(|row_idx| {
+                self.expression.eval(&|variable| {
+                    let (list_array, values) = columns.get(variable.name.as_str())?;
+                    if !list_array.is_valid(row_idx) {
+                        return None;
+                    }
+
+                    let offsets = list_array.offsets();
+                    let value_idx = offsets[row_idx] as usize + variable.instance;
+                    let values = values.as_primitive::<Float64Type>();
+                    (value_idx < offsets[row_idx + 1] as usize && values.is_valid(value_idx))
+                        .then(|| values.value(value_idx))
+                })
+            })
+            .collect();
+
+        let list_array = ArrowListArray::from_iter_primitive::<Float64Type, _, _>(
+            values
+                .into_iter()
+                .map(|value| value.map(|value| [Some(value)])),
+        );
+
+        let components =
+            ChunkComponents(std::iter::once((Self::descriptor(), list_array)).collect());
+        match Chunk::new(
+            ChunkId::new(),
+            self.entity_path.clone(),
+            Some(chunk.is_sorted()),
+            chunk.row_ids_array().clone(),
+            chunk.timelines().clone(),
+            components,
+        ) {
+            Ok(chunk) => Some(chunk),
+            Err(err) => {
+                re_log::warn_once!("Failed to derive series {}: {err}", self.entity_path);
+                None
+            }
+        }
+    }
+}
+
+/// All the derived series of a store, see [`ChunkStore::register_derived_series`].
+#[derive(Debug, Default, Clone)]
+pub(crate) struct DerivedSeriesSet {
+    per_entity: BTreeMap<EntityPath, DerivedSeries>,
+}
+
+impl DerivedSeriesSet {
+    /// Computes the derived chunks of all the series that `chunk` is a source of.
+    pub(crate) fn on_chunk_inserted(&self, chunk: &Chunk) -> Vec<Chunk> {
+        if self.per_entity.is_empty() {
+            return Vec::new();
+        }
+
+        re_tracing::profile_function!();
+
+        self.per_entity
+            .values()
+            .filter_map(|derived| derived.derive_chunk(chunk))
+            .collect()
+    }
+}
+
+impl ChunkStore {
+    /// Registers a new [`DerivedSeries`], replacing any previous one for the same entity.
+    ///
+    /// The series is computed right away for all the chunks of the source entity already present
+    /// in the store, and will be for all the chunks inserted from now on.
+    /// The values derived by a previous series for the same entity are dropped.
+    ///
+    /// Derived series cannot be chained: the source of a series cannot be a derived series itself.
+    ///
+    /// Returns the resulting [`ChunkStoreEvent`]s.
+    pub fn register_derived_series(
+        &mut self,
+        derived: DerivedSeries,
+    ) -> ChunkStoreResult<Vec<ChunkStoreEvent>> {
+        re_tracing::profile_function!();
+
+        let per_entity = &self.derived_series.per_entity;
+        if per_entity.contains_key(&derived.source_entity_path)
+            || per_entity
+                .values()
+                .any(|other| other.source_entity_path == derived.entity_path)
+            || derived.source_entity_path == derived.entity_path
+        {
+            return Err(ChunkStoreError::DerivedSeriesChain(derived.entity_path));
+        }
+
+        let mut events = if self
+            .derived_series
+            .per_entity
+            .contains_key(&derived.entity_path)
+        {
+            self.drop_entity_path(&derived.entity_path)
+        } else {
+            Vec::new()
+        };
+
+        let derived_chunks: Vec<Chunk> = self
+            .chunks_per_chunk_id
+            .values()
+            .filter_map(|chunk| derived.derive_chunk(chunk))
+            .collect();
+
+        self.derived_series
+            .per_entity
+            .insert(derived.entity_path.clone(), derived);
+
+        for chunk in derived_chunks {
+            events.extend(self.insert_chunk(&Arc::new(chunk))?);
+        }
+
+        Ok(events)
+    }
+
+    /// Removes the [`DerivedSeries`] for the given entity, along with all of its values.
+    pub fn unregister_derived_series(&mut self, entity_path: &EntityPath) -> Vec<ChunkStoreEvent> {
+        if self.derived_series.per_entity.remove(entity_path).is_some() {
+            self.drop_entity_path(entity_path)
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// All the [`DerivedSeries`] registered so far.
+    ///
+    /// See [`Self::register_derived_series`].
+    pub fn derived_series(&self) -> impl Iterator<Item = &DerivedSeries> {
+        self.derived_series.per_entity.values()
+    }
+}
@@ -0,0 +1,232 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use arrow::{
+    array::{Array as _, AsArray as _},
+    buffer::ScalarBuffer as ArrowScalarBuffer,
+    datatypes::{DataType as ArrowDataType, Float64Type},
+};
+
+use re_chunk::{Chunk, ChunkId, ComponentIdentifier, TimeColumn, TimelineName};
+use re_log_types::{EntityPath, TimeInt, Timeline};
+
+use crate::{ChunkStore, ChunkStoreEvent, ChunkStoreResult};
+
+// ---
+
+/// How the values of the source component translate into times on a [`DerivedTimeline`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DerivedTimeMapping {
+    /// The values are the times themselves, e.g. "distance traveled" from odometry.
+    Absolute,
+
+    /// The values are offsets added to the times of the base timeline, e.g. to re-base a clock
+    /// using a time reference topic.
+    Offset,
+}
+
+/// A timeline whose times are computed from the values of a component, rather than logged
+/// explicitly.
+///
+/// Every temporal chunk with data on [`Self::base_timeline`] gets an extra time column for
+/// [`Self::timeline`], which then behaves like any other timeline for scrubbing and queries.
+///
+/// The derived time of each row is computed from the most recent source value at or before that
+/// row on the base timeline, or the earliest source value for rows that precede all of them.
+/// Derived times are computed as chunks get inserted: source data that arrives later on doesn't
+/// affect the chunks that are already in the store.
+///
+/// See [`ChunkStore::register_derived_timeline`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DerivedTimeline {
+    /// The timeline to create.
+    pub timeline: Timeline,
+
+    /// The existing timeline that the derived one is computed from.
+    pub base_timeline: TimelineName,
+
+    /// The entity that holds the source values.
+    pub source_entity_path: EntityPath,
+
+    /// The numeric component that holds the source values, e.g. `Scalars:scalars`.
+    ///
+    /// Only the first instance of each row is taken into account.
+    pub source_component: ComponentIdentifier,
+
+    /// The source values are multiplied by this factor before being rounded to integer times,
+    /// e.g. `1000.0` to go from meters to millimeters, or from seconds to milliseconds.
+    pub scale: f64,
+
+    /// How the (scaled) source values translate into times.
+    pub mapping: DerivedTimeMapping,
+}
+
+impl DerivedTimeline {
+    /// Records all the source values found in `chunk`, indexed by their time on the base timeline.
+    fn collect_samples(&self, chunk: &Chunk, samples: &mut BTreeMap<TimeInt, f64>) {
+        if chunk.is_static() || chunk.entity_path() != &self.source_entity_path {
+            return;
+        }
+
+        let Some(time_column) = chunk.timelines().get(&self.base_timeline) else {
+            return;
+        };
+
+        let Some(list_array) = chunk.components().iter().find_map(|(descr, list_array)| {
+            (descr.component == self.source_component).then_some(list_array)
+        }) else {
+            return;
+        };
+
+        let values = match arrow::compute::cast(list_array.values(), &ArrowDataType::Float64) {
+            Ok(values) => values,
+            Err(err) => {
+                re_log::warn_once!(
+                    "Cannot derive timeline {:?} from {}:{}: {err}",
+                    self.timeline.name(),
+                    self.source_entity_path,
+                    self.source_component,
+                );
+                return;
+            }
+        };
+        let values = values.as_primitive::<Float64Type>();
+
+        for (row_idx, (&time, offsets)) in time_column
+            .times_raw()
+            .iter()
+            .zip(list_array.offsets().windows(2))
+            .enumerate()
+        {
+            let value_idx = offsets[0] as usize;
+            if list_array.is_valid(row_idx) && offsets[0] < offsets[1] && values.is_valid(value_idx)
+            {
+                samples.insert(TimeInt::new_temporal(time), values.value(value_idx));
+            }
+        }
+    }
+
+    /// Computes the derived time column of `chunk`, if it needs one.
+    fn time_column(&self, samples: &BTreeMap<TimeInt, f64>, chunk: &Chunk) -> Option<TimeColumn> {
+        if chunk.is_static() || chunk.timelines().contains_key(self.timeline.name()) {
+            return None;
+        }
+
+        let base_time_column = chunk.timelines().get(&self.base_timeline)?;
+        let (_, first_value) = samples.first_key_value()?;
+
+        let times: Vec<i64> = base_time_column
+            .times()
+            .map(|time| {
+                let value = samples
+                    .range(..=time)
+                    .next_back()
+                    .map_or(*first_value, |(_, value)| *value);
+                let value = (value * self.scale).round() as i64;
+
+                let derived_time = match self.mapping {
+                    DerivedTimeMapping::Absolute => value,
+                    DerivedTimeMapping::Offset => time.as_i64().saturating_add(value),
+                };
+                TimeInt::saturated_temporal_i64(derived_time).as_i64()
+            })
+            .collect();
+
+        Some(TimeColumn::new(
+            None,
+            self.timeline,
+            ArrowScalarBuffer::from(times),
+        ))
+    }
+}
+
+/// All the derived timelines of a store, see [`ChunkStore::register_derived_timeline`].
+#[derive(Debug, Default, Clone)]
+pub(crate) struct DerivedTimelines {
+    /// Every derived timeline, along with all the source values seen so far.
+    per_timeline: BTreeMap<TimelineName, (DerivedTimeline, BTreeMap<TimeInt, f64>)>,
+}
+
+impl DerivedTimelines {
+    /// Records the source values of `chunk`, then returns a copy of it extended with all the
+    /// derived timelines that apply, if any.
+    pub(crate) fn on_chunk_inserted(&mut self, chunk: &Chunk) -> Option<Chunk> {
+        if self.per_timeline.is_empty() {
+            return None;
+        }
+
+        for (derived, samples) in self.per_timeline.values_mut() {
+            derived.collect_samples(chunk, samples);
+        }
+
+        self.extend_chunk(chunk)
+    }
+
+    fn extend_chunk(&self, chunk: &Chunk) -> Option<Chunk> {
+        let mut extended_chunk: Option<Chunk> = None;
+
+        for (derived, samples) in self.per_timeline.values() {
+            let Some(time_column) = derived.time_column(samples, chunk) else {
+                continue;
+            };
+
+            let extended_chunk = extended_chunk.get_or_insert_with(|| chunk.clone());
+            if let Err(err) = extended_chunk.add_timeline(time_column) {
+                re_log::warn_once!(
+                    "Failed to add derived timeline {:?}: {err}",
+                    derived.timeline.name()
+                );
+            }
+        }
+
+        extended_chunk
+    }
+}
+
+impl ChunkStore {
+    /// Registers a new [`DerivedTimeline`], replacing any previous one with the same name.
+    ///
+    /// All the chunks already present in the store get extended with the derived timeline right
+    /// away, and so will all the chunks inserted from now on.
+    /// Chunks that were spilled to disk are left untouched.
+    ///
+    /// Returns the resulting [`ChunkStoreEvent`]s: a deletion for every chunk that was extended,
+    /// followed by an addition for its extended counterpart.
+    pub fn register_derived_timeline(
+        &mut self,
+        derived: DerivedTimeline,
+    ) -> ChunkStoreResult<Vec<ChunkStoreEvent>> {
+        re_tracing::profile_function!();
+
+        let mut samples = BTreeMap::default();
+        for chunk in self.chunks_per_chunk_id.values() {
+            derived.collect_samples(chunk, &mut samples);
+        }
+
+        self.derived_timelines
+            .per_timeline
+            .insert(*derived.timeline.name(), (derived, samples));
+
+        let (chunk_ids_to_remove, chunks_to_insert): (Vec<ChunkId>, Vec<Arc<Chunk>>) = self
+            .chunks_per_chunk_id
+            .values()
+            .filter_map(|chunk| {
+                self.derived_timelines
+                    .extend_chunk(chunk)
+                    .map(|extended_chunk| (chunk.id(), Arc::new(extended_chunk)))
+            })
+            .unzip();
+
+        self.replace_chunks(chunk_ids_to_remove, &chunks_to_insert)
+    }
+
+    /// All the [`DerivedTimeline`]s registered so far.
+    ///
+    /// See [`Self::register_derived_timeline`].
+    pub fn derived_timelines(&self) -> impl Iterator<Item = &DerivedTimeline> {
+        self.derived_timelines
+            .per_timeline
+            .values()
+            .map(|(derived, _samples)| derived)
+    }
+}
@@ -4,13 +4,15 @@ use re_log_types::AbsoluteTimeRange;
 use crate::{ChunkStore, ChunkStoreEvent};
 
 impl ChunkStore {
-    /// Drop all events that are in the given range on the given timeline.
+    /// Drop all events that are in the given range on the given timeline, across all entities.
     ///
     /// Note that matching events will be dropped from all timelines they appear on.
+    /// Chunks that only partially overlap the range are split, keeping the rows outside of it.
     ///
     /// Static chunks are unaffected.
     ///
-    /// Used to implement undo (erase the last event from the blueprint db).
+    /// Used to implement undo (erase the last event from the blueprint db), and to trim
+    /// sections out of a recording.
     pub fn drop_time_range(
         &mut self,
         timeline: &TimelineName,
@@ -22,6 +24,25 @@ impl ChunkStore {
             return Default::default();
         }
 
+        // Spilled chunks are still in the indices, so they must be dropped too: bring back the
+        // ones in the range so that they're handled like any other.
+        let spilled_chunk_ids: Vec<_> = self
+            .spilled_chunks
+            .iter()
+            .filter(|(_, spilled)| {
+                spilled
+                    .time_ranges
+                    .iter()
+                    .any(|(spilled_timeline, time_range)| {
+                        spilled_timeline == timeline && drop_range.intersects(*time_range)
+                    })
+            })
+            .map(|(chunk_id, _)| *chunk_id)
+            .collect();
+        for chunk_id in &spilled_chunk_ids {
+            self.unspill_chunk(chunk_id);
+        }
+
         // Prepare the changes:
 
         let mut chunk_ids_to_drop = vec![];
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use re_chunk::{ChunkId, TimelineName};
+use re_log_types::{AbsoluteTimeRange, EntityPath, StoreId};
+
+use crate::{ChunkStore, ChunkStoreResult};
+
+impl ChunkStore {
+    /// Copies all data logged at or below `entity_path` into a new store with the given id.
+    ///
+    /// If a `time_range` is specified, only the data within that range on that timeline is
+    /// copied, splitting chunks as needed. Temporal data that isn't on that timeline is skipped.
+    ///
+    /// Static data is always copied. Chunks that were spilled to disk by the garbage collector are
+    /// reloaded.
+    ///
+    /// The new store uses the same configuration as this one.
+    pub fn export_subtree(
+        &self,
+        new_id: StoreId,
+        entity_path: &EntityPath,
+        time_range: Option<(TimelineName, AbsoluteTimeRange)>,
+    ) -> ChunkStoreResult<Self> {
+        re_tracing::profile_function!();
+
+        let mut new_store = Self::new(new_id, self.config.clone());
+
+        // Spilled chunks are reloaded, but only for the entities being exported.
+        let spilled_chunks = self
+            .spilled_chunks
+            .iter()
+            .filter(|(_, spilled)| spilled.entity_path.starts_with(entity_path))
+            .filter_map(|(chunk_id, _)| self.chunk_or_spilled(chunk_id));
+
+        for chunk in self
+            .chunks_per_chunk_id
+            .values()
+            .cloned()
+            .chain(spilled_chunks)
+        {
+            if !chunk.entity_path().starts_with(entity_path) {
+                continue;
+            }
+
+            let Some((timeline, time_range)) = &time_range else {
+                new_store.insert_chunk(&chunk)?;
+                continue;
+            };
+
+            if chunk.is_static() {
+                new_store.insert_chunk(&chunk)?;
+                continue;
+            }
+
+            let Some(time_column) = chunk.timelines().get(timeline) else {
+                continue; // not on this timeline
+            };
+
+            let chunk_range = time_column.time_range();
+
+            if time_range.contains_range(chunk_range) {
+                // The whole chunk is kept!
+                new_store.insert_chunk(&chunk)?;
+            } else if time_range.intersects(chunk_range) {
+                let chunk = chunk.sorted_by_timeline_if_unsorted(timeline);
+
+                #[allow(clippy::unwrap_used)] // We already know the chunk has the timeline
+                let times = chunk.timelines().get(timeline).unwrap().times_raw();
+
+                let min_idx = times.partition_point(|&time| time < time_range.min().as_i64());
+                let max_idx = times.partition_point(|&time| time <= time_range.max().as_i64());
+
+                if min_idx < max_idx {
+                    let mut chunk = chunk
+                        .row_sliced(min_idx, max_idx - min_idx)
+                        .with_id(ChunkId::new());
+                    chunk.sort_if_unsorted();
+                    new_store.insert_chunk(&Arc::new(chunk))?;
+                }
+            }
+        }
+
+        Ok(new_store)
+    }
+}
@@ -0,0 +1,559 @@
+//! A small arithmetic expression language, used to compute [`crate::DerivedSeries`].
+//!
+//! ```text
+//! sqrt(ax² + ay² + az²)
+//! atan2(scalars[1], scalars[0]) * 180 / pi
+//! ```
+//!
+//! Supported are numbers, `+ - * /`, `^` and the `²`/`³` superscripts for powers, parentheses,
+//! the constants `pi` and `e`, and the functions listed in [`Function`].
+//!
+//! Any other identifier is a variable that refers to a component of the source entity, either by
+//! its full identifier (e.g. `Scalars:scalars`) or just by its field name (e.g. `scalars`).
+//! By default it takes the first instance of the component, `name[i]` takes the `i`-th one.
+
+use std::fmt;
+
+/// A parsed expression, see [`crate::expression`].
+#[derive(Clone, Debug)]
+pub struct Expression {
+    source: String,
+    root: Node,
+}
+
+impl PartialEq for Expression {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.source.fmt(f)
+    }
+}
+
+impl std::str::FromStr for Expression {
+    type Err = ExpressionError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::parse(source)
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum ExpressionError {
+    #[error("Unexpected {found} at position {position}, expected {expected}")]
+    Unexpected {
+        position: usize,
+        found: String,
+        expected: &'static str,
+    },
+
+    #[error("Unknown function {0:?}")]
+    UnknownFunction(String),
+
+    #[error("Function {name} takes {expected} argument(s), got {found}")]
+    WrongArgumentCount {
+        name: &'static str,
+        expected: usize,
+        found: usize,
+    },
+}
+
+/// A variable of an [`Expression`], i.e. an instance of a component of the source entity.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Variable {
+    /// The component identifier, or only its field name.
+    pub name: String,
+
+    /// The index of the instance within the component batch.
+    pub instance: usize,
+}
+
+impl Variable {
+    /// Whether this variable refers to the given component.
+    pub fn matches(&self, component: &str) -> bool {
+        component == self.name
+            || component
+                .rsplit_once(':')
+                .is_some_and(|(_, field)| field == self.name)
+    }
+}
+
+/// The built-in functions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Function {
+    Abs,
+    Sqrt,
+    Exp,
+    Ln,
+    Log10,
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Atan2,
+    Hypot,
+    Pow,
+    Min,
+    Max,
+}
+
+impl Function {
+    const ALL: [(&'static str, Self); 16] = [
+        ("abs", Self::Abs),
+        ("sqrt", Self::Sqrt),
+        ("exp", Self::Exp),
+        ("ln", Self::Ln),
+        ("log10", Self::Log10),
+        ("sin", Self::Sin),
+        ("cos", Self::Cos),
+        ("tan", Self::Tan),
+        ("asin", Self::Asin),
+        ("acos", Self::Acos),
+        ("atan", Self::Atan),
+        ("atan2", Self::Atan2),
+        ("hypot", Self::Hypot),
+        ("pow", Self::Pow),
+        ("min", Self::Min),
+        ("max", Self::Max),
+    ];
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find_map(|&(candidate, function)| (candidate == name).then_some(function))
+    }
+
+    fn name(self) -> &'static str {
+        Self::ALL
+            .iter()
+            .find_map(|&(name, function)| (function == self).then_some(name))
+            .unwrap_or_default()
+    }
+
+    fn arity(self) -> usize {
+        match self {
+            Self::Atan2 | Self::Hypot | Self::Pow | Self::Min | Self::Max => 2,
+            _ => 1,
+        }
+    }
+
+    fn apply(self, args: &[f64]) -> f64 {
+        match (self, args) {
+            (Self::Abs, [x]) => x.abs(),
+            (Self::Sqrt, [x]) => x.sqrt(),
+            (Self::Exp, [x]) => x.exp(),
+            (Self::Ln, [x]) => x.ln(),
+            (Self::Log10, [x]) => x.log10(),
+            (Self::Sin, [x]) => x.sin(),
+            (Self::Cos, [x]) => x.cos(),
+            (Self::Tan, [x]) => x.tan(),
+            (Self::Asin, [x]) => x.asin(),
+            (Self::Acos, [x]) => x.acos(),
+            (Self::Atan, [x]) => x.atan(),
+            (Self::Atan2, [y, x]) => y.atan2(*x),
+            (Self::Hypot, [x, y]) => x.hypot(*y),
+            (Self::Pow, [x, y]) => x.powf(*y),
+            (Self::Min, [x, y]) => x.min(*y),
+            (Self::Max, [x, y]) => x.max(*y),
+            _ => f64::NAN, // Arity is checked when parsing.
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+}
+
+#[derive(Clone, Debug)]
+enum Node {
+    Number(f64),
+    Variable(Variable),
+    Neg(Box<Node>),
+    Binary(BinaryOp, Box<Node>, Box<Node>),
+    Call(Function, Vec<Node>),
+}
+
+impl Node {
+    fn eval(&self, lookup: &dyn Fn(&Variable) -> Option<f64>) -> Option<f64> {
+        Some(match self {
+            Self::Number(value) => *value,
+            Self::Variable(variable) => lookup(variable)?,
+            Self::Neg(node) => -node.eval(lookup)?,
+            Self::Binary(op, lhs, rhs) => {
+                let (lhs, rhs) = (lhs.eval(lookup)?, rhs.eval(lookup)?);
+                match op {
+                    BinaryOp::Add => lhs + rhs,
+                    BinaryOp::Sub => lhs - rhs,
+                    BinaryOp::Mul => lhs * rhs,
+                    BinaryOp::Div => lhs / rhs,
+                    BinaryOp::Pow => lhs.powf(rhs),
+                }
+            }
+            Self::Call(function, args) => {
+                let args = args
+                    .iter()
+                    .map(|arg| arg.eval(lookup))
+                    .collect::<Option<Vec<_>>>()?;
+                function.apply(&args)
+            }
+        })
+    }
+
+    fn collect_variables<'a>(&'a self, variables: &mut Vec<&'a Variable>) {
+        match self {
+            Self::Number(_) => {}
+            Self::Variable(variable) => variables.push(variable),
+            Self::Neg(node) => node.collect_variables(variables),
+            Self::Binary(_, lhs, rhs) => {
+                lhs.collect_variables(variables);
+                rhs.collect_variables(variables);
+            }
+            Self::Call(_, args) => {
+                for arg in args {
+                    arg.collect_variables(variables);
+                }
+            }
+        }
+    }
+}
+
+impl Expression {
+    pub fn parse(source: &str) -> Result<Self, ExpressionError> {
+        let mut parser = Parser {
+            chars: source.char_indices().peekable(),
+            len: source.len(),
+        };
+        let root = parser.expression()?;
+        parser.expect_end()?;
+
+        Ok(Self {
+            source: source.trim().to_owned(),
+            root,
+        })
+    }
+
+    /// The source text of the expression.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// All the variables the expression refers to, in order of appearance.
+    pub fn variables(&self) -> Vec<&Variable> {
+        let mut variables = Vec::new();
+        self.root.collect_variables(&mut variables);
+        variables
+    }
+
+    /// Evaluates the expression, returning `None` if any of its variables has no value.
+    pub fn eval(&self, lookup: &dyn Fn(&Variable) -> Option<f64>) -> Option<f64> {
+        self.root.eval(lookup)
+    }
+}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+    len: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.chars.peek().map(|&(_, c)| c)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.chars.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn unexpected(&mut self, expected: &'static str) -> ExpressionError {
+        self.skip_whitespace();
+        let (position, found) = match self.chars.peek() {
+            Some(&(position, c)) => (position, format!("{c:?}")),
+            None => (self.len, "end of expression".to_owned()),
+        };
+        ExpressionError::Unexpected {
+            position,
+            found,
+            expected,
+        }
+    }
+
+    fn expect(&mut self, expected: char, description: &'static str) -> Result<(), ExpressionError> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.unexpected(description))
+        }
+    }
+
+    fn expect_end(&mut self) -> Result<(), ExpressionError> {
+        if self.peek().is_none() {
+            Ok(())
+        } else {
+            Err(self.unexpected("an operator"))
+        }
+    }
+
+    /// `term (('+' | '-') term)*`
+    fn expression(&mut self) -> Result<Node, ExpressionError> {
+        let mut lhs = self.term()?;
+        loop {
+            let op = if self.eat('+') {
+                BinaryOp::Add
+            } else if self.eat('-') {
+                BinaryOp::Sub
+            } else {
+                return Ok(lhs);
+            };
+            lhs = Node::Binary(op, Box::new(lhs), Box::new(self.term()?));
+        }
+    }
+
+    /// `unary (('*' | '/') unary)*`
+    fn term(&mut self) -> Result<Node, ExpressionError> {
+        let mut lhs = self.unary()?;
+        loop {
+            let op = if self.eat('*') {
+                BinaryOp::Mul
+            } else if self.eat('/') {
+                BinaryOp::Div
+            } else {
+                return Ok(lhs);
+            };
+            lhs = Node::Binary(op, Box::new(lhs), Box::new(self.unary()?));
+        }
+    }
+
+    /// `'-' unary | power`
+    fn unary(&mut self) -> Result<Node, ExpressionError> {
+        if self.eat('-') {
+            Ok(Node::Neg(Box::new(self.unary()?)))
+        } else {
+            self.power()
+        }
+    }
+
+    /// `postfix ('^' unary)?`, so that `-x^2` is `-(x^2)` and `2^3^2` is `2^(3^2)`.
+    fn power(&mut self) -> Result<Node, ExpressionError> {
+        let base = self.postfix()?;
+        if self.eat('^') {
+            Ok(Node::Binary(
+                BinaryOp::Pow,
+                Box::new(base),
+                Box::new(self.unary()?),
+            ))
+        } else {
+            Ok(base)
+        }
+    }
+
+    /// `primary ('²' | '³')*`
+    fn postfix(&mut self) -> Result<Node, ExpressionError> {
+        let mut node = self.primary()?;
+        loop {
+            let exponent = if self.eat('²') {
+                2.0
+            } else if self.eat('³') {
+                3.0
+            } else {
+                return Ok(node);
+            };
+            node = Node::Binary(
+                BinaryOp::Pow,
+                Box::new(node),
+                Box::new(Node::Number(exponent)),
+            );
+        }
+    }
+
+    /// `number | '(' expression ')' | function '(' arguments ')' | variable ('[' index ']')?`
+    fn primary(&mut self) -> Result<Node, ExpressionError> {
+        match self.peek() {
+            Some('(') => {
+                self.chars.next();
+                let node = self.expression()?;
+                self.expect(')', "')'")?;
+                Ok(node)
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => self.number(),
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => self.identifier(),
+            _ => Err(self.unexpected("a number, variable or function")),
+        }
+    }
+
+    fn number(&mut self) -> Result<Node, ExpressionError> {
+        let position = self
+            .chars
+            .peek()
+            .map_or(self.len, |&(position, _)| position);
+        let mut text = String::new();
+        while let Some((_, c)) = self.chars.next_if(|(_, c)| c.is_ascii_digit() || *c == '.') {
+            text.push(c);
+        }
+
+        // Exponent, e.g. `1.5e-3`.
+        if let Some((_, e)) = self.chars.next_if(|(_, c)| *c == 'e' || *c == 'E') {
+            text.push(e);
+            if let Some((_, sign)) = self.chars.next_if(|(_, c)| *c == '-' || *c == '+') {
+                text.push(sign);
+            }
+            while let Some((_, c)) = self.chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                text.push(c);
+            }
+        }
+
+        text.parse()
+            .map(Node::Number)
+            .map_err(|_err| ExpressionError::Unexpected {
+                position,
+                found: format!("{text:?}"),
+                expected: "a number",
+            })
+    }
+
+    fn identifier(&mut self) -> Result<Node, ExpressionError> {
+        let mut name = String::new();
+        while let Some((_, c)) = self
+            .chars
+            .next_if(|(_, c)| c.is_ascii_alphanumeric() || *c == '_' || *c == ':')
+        {
+            name.push(c);
+        }
+
+        if self.eat('(') {
+            let function = Function::from_name(&name)
+                .ok_or_else(|| ExpressionError::UnknownFunction(name.clone()))?;
+
+            let mut args = Vec::new();
+            if !self.eat(')') {
+                loop {
+                    args.push(self.expression()?);
+                    if self.eat(')') {
+                        break;
+                    }
+                    self.expect(',', "',' or ')'")?;
+                }
+            }
+
+            if args.len() != function.arity() {
+                return Err(ExpressionError::WrongArgumentCount {
+                    name: function.name(),
+                    expected: function.arity(),
+                    found: args.len(),
+                });
+            }
+            return Ok(Node::Call(function, args));
+        }
+
+        match name.as_str() {
+            "pi" => return Ok(Node::Number(std::f64::consts::PI)),
+            "e" => return Ok(Node::Number(std::f64::consts::E)),
+            _ => {}
+        }
+
+        let mut instance = 0;
+        if self.eat('[') {
+            let mut digits = String::new();
+            self.skip_whitespace();
+            while let Some((_, c)) = self.chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                digits.push(c);
+            }
+            instance = digits
+                .parse()
+                .map_err(|_err| self.unexpected("an instance index"))?;
+            self.expect(']', "']'")?;
+        }
+
+        Ok(Node::Variable(Variable { name, instance }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(source: &str, variables: &[(&str, usize, f64)]) -> Option<f64> {
+        Expression::parse(source).unwrap().eval(&|variable| {
+            variables.iter().find_map(|&(name, instance, value)| {
+                (variable.matches(name) && variable.instance == instance).then_some(value)
+            })
+        })
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(eval("1 + 2 * 3", &[]), Some(7.0));
+        assert_eq!(eval("(1 + 2) * 3", &[]), Some(9.0));
+        assert_eq!(eval("8 / 4 / 2", &[]), Some(1.0));
+        assert_eq!(eval("-2^2", &[]), Some(-4.0));
+        assert_eq!(eval("2^3^2", &[]), Some(512.0));
+        assert_eq!(eval("3² + 2³", &[]), Some(17.0));
+        assert_eq!(eval("1.5e3 - 500", &[]), Some(1000.0));
+        assert_eq!(eval("max(1, min(5, 3))", &[]), Some(3.0));
+        assert_eq!(eval("atan2(1, 1) * 4", &[]), Some(std::f64::consts::PI));
+    }
+
+    #[test]
+    fn variables() {
+        let imu = [
+            ("Imu:ax", 0, 3.0),
+            ("Imu:ay", 0, 4.0),
+            ("Imu:az", 0, 12.0),
+            ("Scalars:scalars", 1, 2.0),
+        ];
+        assert_eq!(eval("sqrt(ax² + ay² + az²)", &imu), Some(13.0));
+        assert_eq!(eval("Imu:ax * scalars[1]", &imu), Some(6.0));
+        assert_eq!(eval("scalars", &imu), None);
+
+        let expression = Expression::parse("hypot(x, y[2]) + x").unwrap();
+        let names: Vec<_> = expression
+            .variables()
+            .into_iter()
+            .map(|variable| (variable.name.as_str(), variable.instance))
+            .collect();
+        assert_eq!(names, [("x", 0), ("y", 2), ("x", 0)]);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            Expression::parse("1 +"),
+            Err(ExpressionError::Unexpected { position: 3, .. })
+        ));
+        assert!(matches!(
+            Expression::parse("(1"),
+            Err(ExpressionError::Unexpected { .. })
+        ));
+        assert!(matches!(
+            Expression::parse("1 2"),
+            Err(ExpressionError::Unexpected { position: 2, .. })
+        ));
+        assert_eq!(
+            Expression::parse("foo(1)"),
+            Err(ExpressionError::UnknownFunction("foo".to_owned()))
+        );
+        assert!(matches!(
+            Expression::parse("atan2(1)"),
+            Err(ExpressionError::WrongArgumentCount { expected: 2, .. })
+        ));
+    }
+}
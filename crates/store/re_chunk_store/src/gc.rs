@@ -80,6 +80,67 @@ impl GarbageCollectionOptions {
     }
 }
 
+/// Data that the garbage collector must never drop, on top of whatever the
+/// [`GarbageCollectionOptions`] of a given run protect.
+///
+/// This lives in the store itself, so that long-running live sessions keep their reference data
+/// (maps, calibrations, annotation contexts…) around when the memory budget kicks in, whoever
+/// triggers the GC.
+///
+/// See [`ChunkStore::protect_entity_subtree`] and [`ChunkStore::protect_time_range`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GarbageCollectionProtection {
+    /// All the data logged to these entities or their descendants is protected.
+    pub entity_subtrees: BTreeSet<EntityPath>,
+
+    /// All the data overlapping these time ranges is protected, whatever the entity.
+    pub time_ranges: IntMap<TimelineName, Vec<AbsoluteTimeRange>>,
+}
+
+impl GarbageCollectionProtection {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entity_subtrees.is_empty() && self.time_ranges.values().all(Vec::is_empty)
+    }
+
+    /// If true, we cannot remove data of that entity covering these time ranges.
+    pub fn is_protected(
+        &self,
+        entity_path: &EntityPath,
+        time_ranges: impl IntoIterator<Item = (TimelineName, AbsoluteTimeRange)>,
+    ) -> bool {
+        if self
+            .entity_subtrees
+            .iter()
+            .any(|subtree| entity_path.starts_with(subtree))
+        {
+            return true;
+        }
+
+        time_ranges.into_iter().any(|(timeline, time_range)| {
+            self.time_ranges
+                .get(&timeline)
+                .is_some_and(|protected_time_ranges| {
+                    protected_time_ranges
+                        .iter()
+                        .any(|protected_time_range| time_range.intersects(*protected_time_range))
+                })
+        })
+    }
+
+    /// If true, we cannot remove this chunk.
+    #[inline]
+    pub fn is_chunk_protected(&self, chunk: &Chunk) -> bool {
+        self.is_protected(
+            chunk.entity_path(),
+            chunk
+                .timelines()
+                .iter()
+                .map(|(&timeline, time_column)| (timeline, time_column.time_range())),
+        )
+    }
+}
+
 impl std::fmt::Display for GarbageCollectionTarget {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -97,6 +158,38 @@ pub type RemovableChunkIdPerTimePerComponentPerTimelinePerEntity = IntMap<
 >;
 
 impl ChunkStore {
+    /// What the garbage collector must never drop, regardless of [`GarbageCollectionOptions`].
+    #[inline]
+    pub fn gc_protection(&self) -> &GarbageCollectionProtection {
+        &self.gc_protection
+    }
+
+    /// Replaces what the garbage collector must never drop, e.g. to lift previous protections.
+    #[inline]
+    pub fn set_gc_protection(&mut self, gc_protection: GarbageCollectionProtection) {
+        self.gc_protection = gc_protection;
+    }
+
+    /// Exempts all the data of the given entity and its descendants from garbage collection,
+    /// now and in the future.
+    ///
+    /// This has no effect on explicit removals, e.g. [`ChunkStore::drop_entity_path`].
+    pub fn protect_entity_subtree(&mut self, entity_path: EntityPath) {
+        self.gc_protection.entity_subtrees.insert(entity_path);
+    }
+
+    /// Exempts all the data overlapping the given time range from garbage collection, now and in
+    /// the future.
+    ///
+    /// This has no effect on explicit removals, e.g. [`ChunkStore::drop_time_range`].
+    pub fn protect_time_range(&mut self, timeline: TimelineName, time_range: AbsoluteTimeRange) {
+        self.gc_protection
+            .time_ranges
+            .entry(timeline)
+            .or_default()
+            .push(time_range);
+    }
+
     /// Triggers a garbage collection according to the desired `target`.
     ///
     /// Returns the list of `Chunk`s that were purged from the store in the form of [`ChunkStoreEvent`]s.
@@ -120,6 +213,9 @@ impl ChunkStore {
     /// component on each timeline. The only practical guarantee this gives is that a latest-at query
     /// with a value of max-int will be unchanged. However, latest-at queries from other arbitrary
     /// points in time may provide different results pre- and post- GC.
+    ///
+    /// On top of what the `options` protect, data protected by the store's
+    /// [`GarbageCollectionProtection`] is never dropped, see [`ChunkStore::gc_protection`].
     pub fn gc(
         &mut self,
         options: &GarbageCollectionOptions,
@@ -276,6 +372,11 @@ impl ChunkStore {
             RemovableChunkIdPerTimePerComponentPerTimelinePerEntity::default();
         let mut chunk_ids_dangling = HashSet::default();
 
+        // Spilled chunks are only dropped for good when explicitly collecting everything.
+        let collect_everything = matches!(options.target, GarbageCollectionTarget::Everything);
+        let spill_to_disk = self.config.enable_spill_to_disk && !collect_everything;
+        let mut chunk_ids_to_be_spilled = Vec::new();
+
         let start_time = Instant::now();
 
         {
@@ -287,7 +388,9 @@ impl ChunkStore {
                 .filter(|chunk_id| !protected_chunk_ids.contains(chunk_id))
             {
                 if let Some(chunk) = self.chunks_per_chunk_id.get(chunk_id) {
-                    if options.is_chunk_protected(chunk) {
+                    if options.is_chunk_protected(chunk)
+                        || self.gc_protection.is_chunk_protected(chunk)
+                    {
                         continue;
                     }
 
@@ -295,31 +398,23 @@ impl ChunkStore {
                     // and would count as amortized (i.e. 0 bytes).
                     num_bytes_to_drop -= <Chunk as SizeBytes>::total_size_bytes(chunk) as f64;
 
-                    // NOTE: We cannot blindly `retain` across all temporal tables, it's way too costly
-                    // and slow. Rather we need to surgically remove the superfluous chunks.
-                    let entity_path = chunk.entity_path();
-                    let per_timeline = chunk_ids_to_be_removed
-                        .entry(entity_path.clone())
-                        .or_default();
-                    for (&timeline, time_column) in chunk.timelines() {
-                        let per_component = per_timeline.entry(timeline).or_default();
-                        for component_descr in chunk.component_descriptors() {
-                            let per_time = per_component.entry(component_descr).or_default();
-
-                            // NOTE: As usual, these are vectors of `ChunkId`s, as it is legal to
-                            // have perfectly overlapping chunks.
-                            let time_range = time_column.time_range();
-                            per_time
-                                .entry(time_range.min())
-                                .or_default()
-                                .push(chunk.id());
-                            if time_range.min() != time_range.max() {
-                                per_time
-                                    .entry(time_range.max())
-                                    .or_default()
-                                    .push(chunk.id());
-                            }
+                    if spill_to_disk {
+                        // Static chunks are never garbage collected, so never spilled either.
+                        if !chunk.is_static() {
+                            chunk_ids_to_be_spilled.push(chunk.id());
                         }
+                    } else {
+                        // NOTE: We cannot blindly `retain` across all temporal tables, it's way too costly
+                        // and slow. Rather we need to surgically remove the superfluous chunks.
+                        mark_for_removal(
+                            &mut chunk_ids_to_be_removed,
+                            chunk.id(),
+                            chunk.entity_path(),
+                            chunk.timelines().iter().map(|(&timeline, time_column)| {
+                                (timeline, time_column.time_range())
+                            }),
+                            &chunk.component_descriptors().collect::<Vec<_>>(),
+                        );
                     }
                 } else {
                     chunk_ids_dangling.insert(*chunk_id);
@@ -332,6 +427,27 @@ impl ChunkStore {
                     break;
                 }
             }
+
+            if collect_everything {
+                for (&chunk_id, spilled) in self.spilled_chunks.iter() {
+                    if protected_chunk_ids.contains(&chunk_id)
+                        || spilled.is_protected(options)
+                        || self
+                            .gc_protection
+                            .is_protected(&spilled.entity_path, spilled.time_ranges.iter().copied())
+                    {
+                        continue;
+                    }
+
+                    mark_for_removal(
+                        &mut chunk_ids_to_be_removed,
+                        chunk_id,
+                        &spilled.entity_path,
+                        spilled.time_ranges.iter().copied(),
+                        &spilled.component_descriptors,
+                    );
+                }
+            }
         }
 
         {
@@ -351,6 +467,12 @@ impl ChunkStore {
                 temporal_chunks_stats: _,
                 static_chunk_ids_per_entity: _, // we don't GC static data
                 static_chunks_stats: _,         // we don't GC static data
+                spilled_chunks: _,
+                value_indexes,
+                derived_timelines: _,
+                derived_series: _,
+                static_history: _,
+                gc_protection: _,
                 insert_id: _,
                 gc_id: _,
                 event_id: _,
@@ -435,6 +557,7 @@ impl ChunkStore {
                 diffs.extend(
                     chunk_ids_dangling
                         .into_iter()
+                        .inspect(|chunk_id| value_indexes.on_chunk_removed(chunk_id))
                         .filter_map(|chunk_id| chunks_per_chunk_id.remove(&chunk_id))
                         .map(ChunkStoreDiff::deletion),
                 );
@@ -447,6 +570,18 @@ impl ChunkStore {
                 ));
             }
 
+            // NOTE: Spilled chunks are still around as far as the outside world is concerned, so
+            // they don't generate any event. Chunks that failed to be spilled are dropped as usual.
+            for chunk_id in chunk_ids_to_be_spilled {
+                if start_time.elapsed() >= options.time_budget {
+                    break;
+                }
+
+                if !self.spill_chunk(chunk_id) {
+                    diffs.extend(self.remove_chunk(chunk_id));
+                }
+            }
+
             diffs
         }
     }
@@ -693,6 +828,17 @@ impl ChunkStore {
             }
         }
 
+        if !self.spilled_chunks.is_empty() {
+            // Spilled chunks only ever live in the indices: there's nothing else to clean up.
+            for chunk_id in &chunk_ids_removed {
+                self.spilled_chunks.remove(chunk_id);
+            }
+        }
+
+        for chunk_id in &chunk_ids_removed {
+            self.value_indexes.on_chunk_removed(chunk_id);
+        }
+
         {
             re_tracing::profile_scope!("last collect");
             chunk_ids_removed
@@ -706,3 +852,29 @@ impl ChunkStore {
         }
     }
 }
+
+/// Registers a _temporal_ chunk in all the places it is indexed at, for [`ChunkStore::remove_chunks`].
+fn mark_for_removal(
+    chunk_ids_to_be_removed: &mut RemovableChunkIdPerTimePerComponentPerTimelinePerEntity,
+    chunk_id: ChunkId,
+    entity_path: &EntityPath,
+    time_ranges: impl Iterator<Item = (TimelineName, AbsoluteTimeRange)>,
+    component_descriptors: &[ComponentDescriptor],
+) {
+    let per_timeline = chunk_ids_to_be_removed
+        .entry(entity_path.clone())
+        .or_default();
+    for (timeline, time_range) in time_ranges {
+        let per_component = per_timeline.entry(timeline).or_default();
+        for component_descr in component_descriptors {
+            let per_time = per_component.entry(component_descr.clone()).or_default();
+
+            // NOTE: As usual, these are vectors of `ChunkId`s, as it is legal to
+            // have perfectly overlapping chunks.
+            per_time.entry(time_range.min()).or_default().push(chunk_id);
+            if time_range.min() != time_range.max() {
+                per_time.entry(time_range.max()).or_default().push(chunk_id);
+            }
+        }
+    }
+}
@@ -14,26 +14,43 @@
 #![doc = document_features::document_features!()]
 //!
 
+mod compaction;
 mod dataframe;
+mod derived_series;
+mod derived_timeline;
 mod drop_time_range;
 mod events;
+mod export_subtree;
+pub mod expression;
 mod gc;
+mod merge_recordings;
 mod query;
+mod spill;
+mod static_history;
 mod stats;
 mod store;
 mod subscribers;
+mod value_index;
 mod writes;
 
 pub use self::{
+    compaction::CompactionOptions,
     dataframe::{
-        Index, IndexRange, IndexValue, QueryExpression, SparseFillStrategy, StaticColumnSelection,
-        ViewContentsSelector,
+        AggregationOperator, ComparisonOperator, ComponentPredicate, ComponentValueFilter, Index,
+        IndexRange, IndexValue, QueryExpression, SparseFillStrategy, StaticColumnSelection,
+        TemporalAggregation, ViewContentsSelector,
     },
+    derived_series::DerivedSeries,
+    derived_timeline::{DerivedTimeMapping, DerivedTimeline},
     events::{ChunkCompactionReport, ChunkStoreDiff, ChunkStoreDiffKind, ChunkStoreEvent},
-    gc::{GarbageCollectionOptions, GarbageCollectionTarget},
+    expression::{Expression, ExpressionError},
+    gc::{GarbageCollectionOptions, GarbageCollectionProtection, GarbageCollectionTarget},
+    merge_recordings::MergeTimeAlignment,
+    static_history::StaticHistoryEntry,
     stats::{ChunkStoreChunkStats, ChunkStoreStats},
     store::{ChunkStore, ChunkStoreConfig, ChunkStoreGeneration, ChunkStoreHandle, ColumnMetadata},
     subscribers::{ChunkStoreSubscriber, ChunkStoreSubscriberHandle, PerStoreChunkSubscriber},
+    value_index::ComponentValueSet,
 };
 pub use re_sorbet::{ColumnDescriptor, ComponentColumnDescriptor, IndexColumnDescriptor};
 
@@ -65,6 +82,11 @@ pub enum ChunkStoreError {
     #[error(transparent)]
     Chunk(#[from] re_chunk::ChunkError),
 
+    #[error(
+        "Derived series {0} cannot be computed from another derived series, nor be the source of one"
+    )]
+    DerivedSeriesChain(re_log_types::EntityPath),
+
     /// Error when parsing configuration from environment.
     #[error("Failed to parse config: '{name}={value}': {err}")]
     ParseConfig {
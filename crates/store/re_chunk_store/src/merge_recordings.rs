@@ -0,0 +1,125 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use re_chunk::{Chunk, TimeColumn, TimelineName};
+use re_log_types::{EntityPath, StoreId, TimeInt};
+
+use crate::{ChunkStore, ChunkStoreConfig, ChunkStoreResult};
+
+// ---
+
+/// How the times of the recordings relate to each other, see [`ChunkStore::merge_recordings`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MergeTimeAlignment {
+    /// Times are kept as is: recordings that cover the same times overlap.
+    #[default]
+    Keep,
+
+    /// Every recording is shifted forward, one timeline at a time, so that it starts right after
+    /// the previous one ends on that timeline.
+    ///
+    /// Recordings that already come after the previous ones (e.g. successive sessions on a
+    /// `log_time` timeline) are left untouched.
+    Concatenate,
+}
+
+impl ChunkStore {
+    /// Merges several recordings into a single new store with the given id, e.g. to assemble
+    /// multi-robot or multi-session datasets.
+    ///
+    /// The data of every recording is nested under the entity path it comes with: use
+    /// [`EntityPath::root`] to keep the original paths as they are.
+    /// Recordings are merged in order, which matters for [`MergeTimeAlignment::Concatenate`].
+    ///
+    /// Static data is merged like any other data: if several recordings log static data for the
+    /// same component of the same entity, the one with the most recent `RowId` wins.
+    pub fn merge_recordings<'a>(
+        new_id: StoreId,
+        config: ChunkStoreConfig,
+        recordings: impl IntoIterator<Item = (EntityPath, &'a Self)>,
+        alignment: MergeTimeAlignment,
+    ) -> ChunkStoreResult<Self> {
+        re_tracing::profile_function!();
+
+        let mut new_store = Self::new(new_id, config);
+
+        // The end of the data merged so far, for every timeline.
+        let mut time_ends: BTreeMap<TimelineName, TimeInt> = BTreeMap::default();
+
+        for (prefix, store) in recordings {
+            let time_offsets: BTreeMap<TimelineName, i64> = match alignment {
+                MergeTimeAlignment::Keep => BTreeMap::default(),
+
+                MergeTimeAlignment::Concatenate => store
+                    .timelines()
+                    .into_keys()
+                    .filter_map(|timeline| {
+                        let time_range = store.time_range(&timeline)?;
+                        let offset = time_ends.get(&timeline).map_or(0, |time_end| {
+                            (time_end.as_i64().saturating_add(1))
+                                .saturating_sub(time_range.min().as_i64())
+                                .max(0)
+                        });
+                        Some((timeline, offset))
+                    })
+                    .collect(),
+            };
+
+            for chunk in store.chunks_per_chunk_id.values() {
+                if prefix.is_root() && time_offsets.values().all(|&offset| offset == 0) {
+                    new_store.insert_chunk(chunk)?;
+                    continue;
+                }
+
+                let timelines = chunk
+                    .timelines()
+                    .iter()
+                    .map(|(timeline, time_column)| {
+                        let offset = time_offsets.get(timeline).copied().unwrap_or(0);
+                        let time_column = if offset == 0 {
+                            time_column.clone()
+                        } else {
+                            TimeColumn::new(
+                                Some(time_column.is_sorted()),
+                                *time_column.timeline(),
+                                time_column
+                                    .times_raw()
+                                    .iter()
+                                    .map(|&time| {
+                                        TimeInt::saturated_temporal_i64(time.saturating_add(offset))
+                                            .as_i64()
+                                    })
+                                    .collect(),
+                            )
+                        };
+                        (*timeline, time_column)
+                    })
+                    .collect();
+
+                let chunk = Chunk::new(
+                    chunk.id(),
+                    prefix.join(chunk.entity_path()),
+                    Some(chunk.is_sorted()),
+                    chunk.row_ids_array().clone(),
+                    timelines,
+                    chunk.components().clone(),
+                )?;
+                new_store.insert_chunk(&Arc::new(chunk))?;
+            }
+
+            for (timeline, offset) in time_offsets {
+                if let Some(time_range) = store.time_range(&timeline) {
+                    let time_end = TimeInt::saturated_temporal_i64(
+                        time_range.max().as_i64().saturating_add(offset),
+                    );
+                    time_ends
+                        .entry(timeline)
+                        .and_modify(|cur_time_end| *cur_time_end = (*cur_time_end).max(time_end))
+                        .or_insert(time_end);
+                }
+            }
+        }
+
+        Ok(new_store)
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::BTreeMap;
+use std::io::{Read as _, Seek as _, SeekFrom, Write as _};
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use parking_lot::Mutex;
+
+use re_chunk::{Chunk, ChunkId, TimelineName};
+use re_log_types::{AbsoluteTimeRange, EntityPath};
+use re_types_core::ComponentDescriptor;
+
+use crate::{ChunkStore, ChunkStoreChunkStats, GarbageCollectionOptions};
+
+// ---
+
+/// Chunks that were evicted from memory by the garbage collector, but written to disk rather than
+/// dropped.
+///
+/// Spilled chunks are still referenced by all the temporal indices of the store, they just aren't
+/// resident in memory anymore.
+///
+/// See [`crate::ChunkStoreConfig::enable_spill_to_disk`].
+#[derive(Debug, Default, Clone)]
+pub(crate) struct SpilledChunks {
+    /// Lazily created on first spill.
+    ///
+    /// The file is append-only, which makes it safe to share between clones of a store.
+    /// It is deleted by the OS once the last handle to it is dropped.
+    file: Option<Arc<Mutex<std::fs::File>>>,
+
+    chunks: BTreeMap<ChunkId, SpilledChunk>,
+}
+
+/// Where a spilled chunk lives on disk, plus just enough metadata to remove it from the indices
+/// without having to load it back.
+#[derive(Debug, Clone)]
+pub(crate) struct SpilledChunk {
+    offset: u64,
+    len: u64,
+
+    pub(crate) entity_path: EntityPath,
+    pub(crate) time_ranges: Vec<(TimelineName, AbsoluteTimeRange)>,
+    pub(crate) component_descriptors: Vec<ComponentDescriptor>,
+}
+
+impl SpilledChunk {
+    /// Same as [`GarbageCollectionOptions::is_chunk_protected`], for a spilled chunk.
+    pub(crate) fn is_protected(&self, options: &GarbageCollectionOptions) -> bool {
+        self.time_ranges.iter().any(|(timeline, time_range)| {
+            options
+                .protected_time_ranges
+                .get(timeline)
+                .is_some_and(|protected_time_range| time_range.intersects(*protected_time_range))
+        })
+    }
+}
+
+impl SpilledChunks {
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    #[inline]
+    pub(crate) fn contains(&self, chunk_id: &ChunkId) -> bool {
+        self.chunks.contains_key(chunk_id)
+    }
+
+    #[inline]
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&ChunkId, &SpilledChunk)> {
+        self.chunks.iter()
+    }
+
+    /// Forgets about a spilled chunk.
+    ///
+    /// The space it occupies on disk is only reclaimed once the store is dropped.
+    #[inline]
+    pub(crate) fn remove(&mut self, chunk_id: &ChunkId) -> Option<SpilledChunk> {
+        self.chunks.remove(chunk_id)
+    }
+
+    fn write(&mut self, chunk: &Chunk) -> anyhow::Result<()> {
+        re_tracing::profile_function!();
+
+        let batch = chunk.to_record_batch()?;
+
+        let mut bytes = Vec::new();
+        {
+            let mut writer =
+                arrow::ipc::writer::StreamWriter::try_new(&mut bytes, batch.schema_ref())?;
+            writer.write(&batch)?;
+            writer.finish()?;
+        }
+
+        let file = if let Some(file) = &self.file {
+            Arc::clone(file)
+        } else {
+            let file = Arc::new(Mutex::new(create_spill_file()?));
+            self.file = Some(Arc::clone(&file));
+            file
+        };
+
+        let offset = {
+            let mut file = file.lock();
+            let offset = file.seek(SeekFrom::End(0))?;
+            file.write_all(&bytes)?;
+            offset
+        };
+
+        self.chunks.insert(
+            chunk.id(),
+            SpilledChunk {
+                offset,
+                len: bytes.len() as u64,
+                entity_path: chunk.entity_path().clone(),
+                time_ranges: chunk
+                    .timelines()
+                    .iter()
+                    .map(|(&timeline, time_column)| (timeline, time_column.time_range()))
+                    .collect(),
+                component_descriptors: chunk.component_descriptors().collect(),
+            },
+        );
+
+        Ok(())
+    }
+
+    fn read(&self, chunk_id: &ChunkId) -> anyhow::Result<Option<Chunk>> {
+        re_tracing::profile_function!();
+
+        let (Some(spilled), Some(file)) = (self.chunks.get(chunk_id), &self.file) else {
+            return Ok(None);
+        };
+
+        let mut bytes = vec![0; spilled.len as usize];
+        {
+            let mut file = file.lock();
+            file.seek(SeekFrom::Start(spilled.offset))?;
+            file.read_exact(&mut bytes)?;
+        }
+
+        let mut reader =
+            arrow::ipc::reader::StreamReader::try_new(std::io::Cursor::new(bytes), None)?;
+        let batch = reader.next().context("missing record batch")??;
+
+        Ok(Some(Chunk::from_record_batch(&batch)?))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn create_spill_file() -> std::io::Result<std::fs::File> {
+    tempfile::tempfile()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn create_spill_file() -> std::io::Result<std::fs::File> {
+    Err(std::io::ErrorKind::Unsupported.into())
+}
+
+// ---
+
+impl ChunkStore {
+    /// Get the number of chunks that were spilled to disk by the garbage collector.
+    ///
+    /// See [`crate::ChunkStoreConfig::enable_spill_to_disk`].
+    #[inline]
+    pub fn num_spilled_chunks(&self) -> usize {
+        self.spilled_chunks.len()
+    }
+
+    /// Whether the chunk is known to the store, either in memory or spilled to disk.
+    #[inline]
+    pub(crate) fn contains_chunk(&self, chunk_id: &ChunkId) -> bool {
+        self.chunks_per_chunk_id.contains_key(chunk_id) || self.spilled_chunks.contains(chunk_id)
+    }
+
+    /// Get a chunk based on its ID, reloading it from disk if it was spilled.
+    ///
+    /// Reloaded chunks are _not_ put back in memory: it's up to the caller (and the query cache)
+    /// to keep them around.
+    pub(crate) fn chunk_or_spilled(&self, chunk_id: &ChunkId) -> Option<Arc<Chunk>> {
+        if let Some(chunk) = self.chunks_per_chunk_id.get(chunk_id) {
+            return Some(Arc::clone(chunk));
+        }
+
+        if !self.spilled_chunks.contains(chunk_id) {
+            return None;
+        }
+
+        match self.spilled_chunks.read(chunk_id) {
+            Ok(chunk) => chunk.map(Arc::new),
+            Err(err) => {
+                re_log::warn_once!("Failed to reload chunk {chunk_id} from disk: {err}");
+                None
+            }
+        }
+    }
+
+    /// Reloads a spilled chunk back into memory, as if it had never been spilled.
+    ///
+    /// Returns `false` if the chunk isn't spilled or couldn't be reloaded, in which case the store
+    /// is left untouched.
+    pub(crate) fn unspill_chunk(&mut self, chunk_id: &ChunkId) -> bool {
+        re_tracing::profile_function!();
+
+        let chunk = match self.spilled_chunks.read(chunk_id) {
+            Ok(Some(chunk)) => chunk,
+            Ok(None) => return false,
+            Err(err) => {
+                re_log::warn_once!("Failed to reload chunk {chunk_id} from disk: {err}");
+                return false;
+            }
+        };
+
+        self.spilled_chunks.remove(chunk_id);
+
+        if let Some((min_row_id, _)) = chunk.row_id_range() {
+            self.chunk_ids_per_min_row_id.insert(min_row_id, *chunk_id);
+        }
+        self.temporal_chunks_stats += ChunkStoreChunkStats::from_chunk(&chunk);
+        self.chunks_per_chunk_id.insert(*chunk_id, Arc::new(chunk));
+
+        true
+    }
+
+    /// Writes a _temporal_ chunk to disk and evicts it from memory.
+    ///
+    /// The chunk is left in all the temporal indices, but it won't be considered by the garbage
+    /// collector anymore (except when collecting everything).
+    ///
+    /// Returns `false` if the chunk couldn't be spilled, in which case the store is left untouched.
+    pub(crate) fn spill_chunk(&mut self, chunk_id: ChunkId) -> bool {
+        re_tracing::profile_function!();
+
+        let Some(chunk) = self.chunks_per_chunk_id.get(&chunk_id) else {
+            return false;
+        };
+
+        if let Err(err) = self.spilled_chunks.write(chunk) {
+            re_log::warn_once!("Failed to spill chunk to disk, dropping it instead: {err}");
+            return false;
+        }
+
+        if let Some(chunk) = self.chunks_per_chunk_id.remove(&chunk_id) {
+            if let Some((min_row_id, _)) = chunk.row_id_range() {
+                self.chunk_ids_per_min_row_id.remove(&min_row_id);
+            }
+            self.temporal_chunks_stats -= ChunkStoreChunkStats::from_chunk(&chunk);
+        }
+
+        true
+    }
+}
@@ -0,0 +1,111 @@
+use arrow::array::{Array as _, ArrayRef as ArrowArrayRef};
+use nohash_hasher::IntMap;
+
+use re_chunk::{Chunk, ChunkId, ComponentIdentifier, RowId};
+use re_log_types::EntityPath;
+
+use crate::ChunkStore;
+
+// ---
+
+/// A static value that was logged at some point, along with where it came from.
+///
+/// See [`crate::ChunkStoreConfig::enable_static_history`] and [`ChunkStore::static_history`].
+#[derive(Debug, Clone)]
+pub struct StaticHistoryEntry {
+    /// The row the value was logged in.
+    ///
+    /// Its timestamp tells when the value was logged, and it decides which value wins in the end:
+    /// the one with the most recent [`RowId`].
+    pub row_id: RowId,
+
+    /// The chunk the value was ingested with.
+    pub chunk_id: ChunkId,
+
+    /// The number of chunks that had been inserted into the store at the time, including this one.
+    ///
+    /// Tells apart the ingestion steps of a single store, e.g. when the same calibration was
+    /// logged by several processes.
+    pub insert_id: u64,
+
+    /// The wall-clock time at which the value was ingested by the store.
+    pub inserted_at: web_time::SystemTime,
+
+    /// The value itself, i.e. all the instances of that row.
+    pub value: ArrowArrayRef,
+}
+
+/// All the static values ever logged, per entity and component.
+///
+/// Only populated if [`crate::ChunkStoreConfig::enable_static_history`] is set.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct StaticHistory {
+    /// Sorted by [`StaticHistoryEntry::row_id`].
+    entries_per_entity: IntMap<EntityPath, IntMap<ComponentIdentifier, Vec<StaticHistoryEntry>>>,
+}
+
+impl StaticHistory {
+    pub(crate) fn on_static_chunk_added(&mut self, insert_id: u64, chunk: &Chunk) {
+        debug_assert!(chunk.is_static());
+
+        let inserted_at = web_time::SystemTime::now();
+        let row_ids = chunk.row_ids_slice();
+
+        for (descr, list_array) in chunk.components().iter() {
+            // Within a chunk, only the most recent row of each component counts.
+            let Some((row_idx, &row_id)) = row_ids
+                .iter()
+                .enumerate()
+                .filter(|(row_idx, _)| list_array.is_valid(*row_idx))
+                .max_by_key(|(_, row_id)| **row_id)
+            else {
+                continue;
+            };
+
+            let entries = self
+                .entries_per_entity
+                .entry(chunk.entity_path().clone())
+                .or_default()
+                .entry(descr.component)
+                .or_default();
+
+            let entry_idx = entries.partition_point(|entry| entry.row_id < row_id);
+            entries.insert(
+                entry_idx,
+                StaticHistoryEntry {
+                    row_id,
+                    chunk_id: chunk.id(),
+                    insert_id,
+                    inserted_at,
+                    value: list_array.value(row_idx),
+                },
+            );
+        }
+    }
+
+    pub(crate) fn on_entity_dropped(&mut self, entity_path: &EntityPath) {
+        self.entries_per_entity.remove(entity_path);
+    }
+}
+
+// ---
+
+impl ChunkStore {
+    /// Every static value ever logged for the given component, including the ones that have
+    /// since been overwritten, from oldest to most recent (according to [`RowId`]).
+    ///
+    /// The last entry is the value that is currently in effect.
+    ///
+    /// Always empty unless [`crate::ChunkStoreConfig::enable_static_history`] is set.
+    pub fn static_history(
+        &self,
+        entity_path: &EntityPath,
+        component: &ComponentIdentifier,
+    ) -> &[StaticHistoryEntry] {
+        self.static_history
+            .entries_per_entity
+            .get(entity_path)
+            .and_then(|per_component| per_component.get(component))
+            .map_or(&[], |entries| entries.as_slice())
+    }
+}
@@ -9,7 +9,11 @@ use re_chunk::{Chunk, ChunkId, ComponentIdentifier, RowId, TimelineName};
 use re_log_types::{EntityPath, StoreId, StoreInfo, TimeInt, TimeType};
 use re_types_core::{ComponentDescriptor, ComponentType};
 
-use crate::{ChunkStoreChunkStats, ChunkStoreError, ChunkStoreResult};
+use crate::{
+    ChunkStoreChunkStats, ChunkStoreError, ChunkStoreResult, GarbageCollectionProtection,
+    derived_series::DerivedSeriesSet, derived_timeline::DerivedTimelines, spill::SpilledChunks,
+    static_history::StaticHistory, value_index::ValueIndexes,
+};
 
 // ---
 
@@ -22,6 +26,31 @@ pub struct ChunkStoreConfig {
     /// in some workloads, provided that the subscribers aren't needed (e.g. headless mode).
     pub enable_changelog: bool,
 
+    /// If `true`, chunks evicted by the garbage collector to free up memory are written to a
+    /// temporary file instead of being dropped, and transparently reloaded when queried.
+    ///
+    /// This trades disk space and query latency on cold data for not losing anything for the rest
+    /// of the session. Only applies to [`crate::GarbageCollectionTarget::DropAtLeastFraction`]:
+    /// explicitly collecting everything still drops the spilled chunks for good.
+    ///
+    /// Spilled chunks are reloaded by queries, by [`ChunkStore::iter_all_chunks`] (e.g. when saving),
+    /// and when their time range is dropped. They are not part of [`ChunkStore::iter_chunks`] nor
+    /// of the store stats.
+    ///
+    /// Disabled by default. Has no effect on the web, where there is no filesystem.
+    pub enable_spill_to_disk: bool,
+
+    /// If `true`, every static value that gets logged is retained along with its provenance,
+    /// even after it has been overwritten by a more recent one.
+    ///
+    /// This is useful to debug which ingestion step produced e.g. a given calibration or
+    /// annotation context, see [`ChunkStore::static_history`].
+    /// The history is never garbage collected: this is meant for debugging sessions, not for
+    /// long-running recordings with lots of static overwrites.
+    ///
+    /// Disabled by default.
+    pub enable_static_history: bool,
+
     /// What is the threshold, in bytes, after which a [`Chunk`] cannot be compacted any further?
     ///
     /// This is a multi-dimensional trade-off:
@@ -96,6 +125,10 @@ impl ChunkStoreConfig {
     pub const DEFAULT: Self = Self {
         enable_changelog: true,
 
+        enable_spill_to_disk: false,
+
+        enable_static_history: false,
+
         // This gives us 96 bytes per row (assuming a default limit of 4096 rows), which is enough to
         // fit a couple scalar columns, a RowId column, a handful of timeline columns, all the
         // necessary offsets, etc.
@@ -128,6 +161,8 @@ impl ChunkStoreConfig {
     /// All features disabled.
     pub const ALL_DISABLED: Self = Self {
         enable_changelog: false,
+        enable_spill_to_disk: false,
+        enable_static_history: false,
         chunk_max_bytes: 0,
         chunk_max_rows: 0,
         chunk_max_rows_if_unsorted: 0,
@@ -136,6 +171,12 @@ impl ChunkStoreConfig {
     /// Environment variable to configure [`Self::enable_changelog`].
     pub const ENV_STORE_ENABLE_CHANGELOG: &'static str = "RERUN_STORE_ENABLE_CHANGELOG";
 
+    /// Environment variable to configure [`Self::enable_spill_to_disk`].
+    pub const ENV_STORE_ENABLE_SPILL_TO_DISK: &'static str = "RERUN_STORE_ENABLE_SPILL_TO_DISK";
+
+    /// Environment variable to configure [`Self::enable_static_history`].
+    pub const ENV_STORE_ENABLE_STATIC_HISTORY: &'static str = "RERUN_STORE_ENABLE_STATIC_HISTORY";
+
     /// Environment variable to configure [`Self::chunk_max_bytes`].
     pub const ENV_CHUNK_MAX_BYTES: &'static str = "RERUN_CHUNK_MAX_BYTES";
 
@@ -159,8 +200,9 @@ impl ChunkStoreConfig {
     /// Returns a copy of `self`, overriding existing fields with values from the environment if
     /// they are present.
     ///
-    /// See [`Self::ENV_STORE_ENABLE_CHANGELOG`], [`Self::ENV_CHUNK_MAX_BYTES`], [`Self::ENV_CHUNK_MAX_ROWS`]
-    /// and [`Self::ENV_CHUNK_MAX_ROWS_IF_UNSORTED`].
+    /// See [`Self::ENV_STORE_ENABLE_CHANGELOG`], [`Self::ENV_STORE_ENABLE_SPILL_TO_DISK`],
+    /// [`Self::ENV_STORE_ENABLE_STATIC_HISTORY`], [`Self::ENV_CHUNK_MAX_BYTES`],
+    /// [`Self::ENV_CHUNK_MAX_ROWS`] and [`Self::ENV_CHUNK_MAX_ROWS_IF_UNSORTED`].
     pub fn apply_env(&self) -> ChunkStoreResult<Self> {
         let mut new = self.clone();
 
@@ -172,6 +214,22 @@ impl ChunkStoreConfig {
             })?;
         }
 
+        if let Ok(s) = std::env::var(Self::ENV_STORE_ENABLE_SPILL_TO_DISK) {
+            new.enable_spill_to_disk = s.parse().map_err(|err| ChunkStoreError::ParseConfig {
+                name: Self::ENV_STORE_ENABLE_SPILL_TO_DISK,
+                value: s.clone(),
+                err: Box::new(err),
+            })?;
+        }
+
+        if let Ok(s) = std::env::var(Self::ENV_STORE_ENABLE_STATIC_HISTORY) {
+            new.enable_static_history = s.parse().map_err(|err| ChunkStoreError::ParseConfig {
+                name: Self::ENV_STORE_ENABLE_STATIC_HISTORY,
+                value: s.clone(),
+                err: Box::new(err),
+            })?;
+        }
+
         if let Ok(s) = std::env::var(Self::ENV_CHUNK_MAX_BYTES) {
             new.chunk_max_bytes = s.parse().map_err(|err| ChunkStoreError::ParseConfig {
                 name: Self::ENV_CHUNK_MAX_BYTES,
@@ -209,6 +267,8 @@ fn chunk_store_config() {
     #[expect(unsafe_code)]
     unsafe {
         std::env::set_var("RERUN_STORE_ENABLE_CHANGELOG", "false");
+        std::env::set_var("RERUN_STORE_ENABLE_SPILL_TO_DISK", "true");
+        std::env::set_var("RERUN_STORE_ENABLE_STATIC_HISTORY", "true");
         std::env::set_var("RERUN_CHUNK_MAX_BYTES", "42");
         std::env::set_var("RERUN_CHUNK_MAX_ROWS", "666");
         std::env::set_var("RERUN_CHUNK_MAX_ROWS_IF_UNSORTED", "999");
@@ -218,6 +278,8 @@ fn chunk_store_config() {
 
     let expected = ChunkStoreConfig {
         enable_changelog: false,
+        enable_spill_to_disk: true,
+        enable_static_history: true,
         chunk_max_bytes: 42,
         chunk_max_rows: 666,
         chunk_max_rows_if_unsorted: 999,
@@ -461,6 +523,37 @@ pub struct ChunkStore {
     /// This is too costly to be computed from scratch every frame, and is required by e.g. the GC.
     pub(crate) static_chunks_stats: ChunkStoreChunkStats,
 
+    /// Temporal chunks that were evicted from memory by the GC but are still referenced by the
+    /// temporal indices above.
+    ///
+    /// See [`ChunkStoreConfig::enable_spill_to_disk`].
+    pub(crate) spilled_chunks: SpilledChunks,
+
+    /// Optional indexes on component values.
+    ///
+    /// See [`ChunkStore::create_value_index`].
+    pub(crate) value_indexes: ValueIndexes,
+
+    /// Timelines computed from existing data.
+    ///
+    /// See [`ChunkStore::register_derived_timeline`].
+    pub(crate) derived_timelines: DerivedTimelines,
+
+    /// Scalar series computed from existing data.
+    ///
+    /// See [`ChunkStore::register_derived_series`].
+    pub(crate) derived_series: DerivedSeriesSet,
+
+    /// All the static values ever logged.
+    ///
+    /// See [`ChunkStoreConfig::enable_static_history`].
+    pub(crate) static_history: StaticHistory,
+
+    /// What the garbage collector must never drop.
+    ///
+    /// See [`ChunkStore::gc_protection`].
+    pub(crate) gc_protection: GarbageCollectionProtection,
+
     /// Monotonically increasing ID for insertions.
     pub(crate) insert_id: u64,
 
@@ -505,6 +598,12 @@ impl Clone for ChunkStore {
             temporal_chunks_stats: self.temporal_chunks_stats,
             static_chunk_ids_per_entity: self.static_chunk_ids_per_entity.clone(),
             static_chunks_stats: self.static_chunks_stats,
+            spilled_chunks: self.spilled_chunks.clone(),
+            value_indexes: self.value_indexes.clone(),
+            derived_timelines: self.derived_timelines.clone(),
+            derived_series: self.derived_series.clone(),
+            static_history: self.static_history.clone(),
+            gc_protection: self.gc_protection.clone(),
             insert_id: Default::default(),
             gc_id: Default::default(),
             event_id: Default::default(),
@@ -528,6 +627,12 @@ impl std::fmt::Display for ChunkStore {
             temporal_chunks_stats,
             static_chunk_ids_per_entity: _,
             static_chunks_stats,
+            spilled_chunks: _,
+            value_indexes: _,
+            derived_timelines: _,
+            derived_series: _,
+            static_history: _,
+            gc_protection: _,
             insert_id: _,
             gc_id: _,
             event_id: _,
@@ -590,6 +695,12 @@ impl ChunkStore {
             temporal_chunks_stats: Default::default(),
             static_chunk_ids_per_entity: Default::default(),
             static_chunks_stats: Default::default(),
+            spilled_chunks: Default::default(),
+            value_indexes: Default::default(),
+            derived_timelines: Default::default(),
+            derived_series: Default::default(),
+            static_history: Default::default(),
+            gc_protection: Default::default(),
             insert_id: 0,
             gc_id: 0,
             event_id: AtomicU64::new(0),
@@ -639,11 +750,26 @@ impl ChunkStore {
     }
 
     /// Iterate over all chunks in the store, in ascending [`ChunkId`] order.
+    ///
+    /// This only covers the chunks that are in memory, see [`Self::iter_all_chunks`].
     #[inline]
     pub fn iter_chunks(&self) -> impl Iterator<Item = &Arc<Chunk>> + '_ {
         self.chunks_per_chunk_id.values()
     }
 
+    /// Iterate over all chunks in the store, including the ones that were spilled to disk by the
+    /// garbage collector, which are reloaded on the fly.
+    ///
+    /// The chunks in memory come first, in ascending [`ChunkId`] order, then the spilled ones.
+    /// See [`ChunkStoreConfig::enable_spill_to_disk`].
+    pub fn iter_all_chunks(&self) -> impl Iterator<Item = Arc<Chunk>> + '_ {
+        self.chunks_per_chunk_id.values().cloned().chain(
+            self.spilled_chunks
+                .iter()
+                .filter_map(|(chunk_id, _)| self.chunk_or_spilled(chunk_id)),
+        )
+    }
+
     /// Get a chunk based on its ID.
     #[inline]
     pub fn chunk(&self, id: &ChunkId) -> Option<&Arc<Chunk>> {
@@ -722,14 +848,12 @@ impl ChunkStore {
 
         let mut stores = BTreeMap::new();
 
-        let rrd_file = std::fs::File::open(path_to_rrd)
-            .with_context(|| format!("couldn't open {path_to_rrd:?}"))?;
-
-        let mut decoder = re_log_encoding::decoder::Decoder::new(rrd_file)
+        // Memory-mapped, so that the file gets paged in as we go rather than read upfront.
+        let decoder = re_log_encoding::decoder::mmap::MmapDecoder::open(path_to_rrd)
             .with_context(|| format!("couldn't decode {path_to_rrd:?}"))?;
 
         // TODO(cmc): offload the decoding to a background thread.
-        for res in &mut decoder {
+        for res in decoder {
             let msg = res.with_context(|| format!("couldn't decode message {path_to_rrd:?}"))?;
             match msg {
                 re_log_types::LogMsg::SetStoreInfo(info) => {
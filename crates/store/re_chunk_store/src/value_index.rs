@@ -0,0 +1,282 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use ahash::{HashMap, HashSet};
+use arrow::{
+    array::{Array as _, ArrayRef as ArrowArrayRef, ListArray as ArrowListArray},
+    datatypes::DataType as ArrowDatatype,
+    row::{RowConverter, Rows, SortField},
+};
+use nohash_hasher::IntMap;
+
+use re_chunk::{Chunk, ChunkId, ComponentIdentifier};
+use re_log_types::EntityPath;
+
+use crate::ChunkStore;
+
+// ---
+
+/// Encodes arbitrary arrow values into hashable, byte-comparable keys.
+///
+/// Returns `None` if the datatype isn't supported by the row format.
+fn encode_values(values: &ArrowArrayRef) -> Option<Rows> {
+    let converter = RowConverter::new(vec![SortField::new(values.data_type().clone())]).ok()?;
+    converter.convert_columns(&[Arc::clone(values)]).ok()
+}
+
+/// A set of component values to filter on.
+///
+/// See [`crate::ComponentValueFilter`] and [`ChunkStore::chunk_ids_with_component_values`].
+#[derive(Debug, Clone)]
+pub struct ComponentValueSet {
+    datatype: ArrowDatatype,
+    values: HashSet<Box<[u8]>>,
+}
+
+impl ComponentValueSet {
+    /// Returns `None` if the datatype of the values isn't supported.
+    ///
+    /// Nulls are ignored.
+    pub fn new(values: &ArrowArrayRef) -> Option<Self> {
+        let rows = encode_values(values)?;
+
+        Some(Self {
+            datatype: values.data_type().clone(),
+            values: (0..values.len())
+                .filter(|&i| values.is_valid(i))
+                .map(|i| rows.row(i).as_ref().into())
+                .collect(),
+        })
+    }
+
+    /// For each row of a component column, returns whether it contains at least one of the values.
+    pub fn matching_rows(&self, list_array: &ArrowListArray) -> Vec<bool> {
+        let values = list_array.values();
+
+        let rows = if values.data_type() == &self.datatype {
+            encode_values(values)
+        } else {
+            None // values of different types cannot possibly be equal
+        };
+        let Some(rows) = rows else {
+            return vec![false; list_array.len()];
+        };
+
+        list_array
+            .offsets()
+            .windows(2)
+            .enumerate()
+            .map(|(i, offsets)| {
+                list_array.is_valid(i)
+                    && (offsets[0] as usize..offsets[1] as usize)
+                        .any(|j| values.is_valid(j) && self.values.contains(rows.row(j).as_ref()))
+            })
+            .collect()
+    }
+}
+
+// ---
+
+/// All the optional value indexes of a store, see [`ChunkStore::create_value_index`].
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ValueIndexes {
+    per_component: BTreeMap<ComponentIdentifier, ValueIndex>,
+}
+
+/// Maps the values of a single component to the chunks that contain them, for every entity.
+#[derive(Debug, Default, Clone)]
+struct ValueIndex {
+    chunk_ids_per_value_per_entity: IntMap<EntityPath, HashMap<Box<[u8]>, BTreeSet<ChunkId>>>,
+
+    /// Chunks whose values couldn't be encoded: they always have to be considered as potential
+    /// matches.
+    unindexed_chunk_ids_per_entity: IntMap<EntityPath, BTreeSet<ChunkId>>,
+
+    /// Reverse mapping, so that removals don't need the chunk itself.
+    ///
+    /// The values are `None` for unindexed chunks.
+    values_per_chunk_id: HashMap<ChunkId, (EntityPath, Option<Vec<Box<[u8]>>>)>,
+}
+
+impl ValueIndex {
+    fn on_chunk_added(&mut self, component: ComponentIdentifier, chunk: &Chunk) {
+        let Some(list_array) = chunk
+            .components()
+            .iter()
+            .find_map(|(descr, list_array)| (descr.component == component).then_some(list_array))
+        else {
+            return;
+        };
+
+        let entity_path = chunk.entity_path();
+        let list_values = list_array.values();
+
+        let Some(rows) = encode_values(list_values) else {
+            self.unindexed_chunk_ids_per_entity
+                .entry(entity_path.clone())
+                .or_default()
+                .insert(chunk.id());
+            self.values_per_chunk_id
+                .insert(chunk.id(), (entity_path.clone(), None));
+            return;
+        };
+
+        let values: HashSet<Box<[u8]>> = (0..list_values.len())
+            .filter(|&i| list_values.is_valid(i))
+            .map(|i| rows.row(i).as_ref().into())
+            .collect();
+
+        let chunk_ids_per_value = self
+            .chunk_ids_per_value_per_entity
+            .entry(entity_path.clone())
+            .or_default();
+        for value in &values {
+            chunk_ids_per_value
+                .entry(value.clone())
+                .or_default()
+                .insert(chunk.id());
+        }
+
+        self.values_per_chunk_id.insert(
+            chunk.id(),
+            (entity_path.clone(), Some(values.into_iter().collect())),
+        );
+    }
+
+    fn on_chunk_removed(&mut self, chunk_id: &ChunkId) {
+        let Some((entity_path, values)) = self.values_per_chunk_id.remove(chunk_id) else {
+            return;
+        };
+
+        let Some(values) = values else {
+            if let Some(chunk_ids) = self.unindexed_chunk_ids_per_entity.get_mut(&entity_path) {
+                chunk_ids.remove(chunk_id);
+                if chunk_ids.is_empty() {
+                    self.unindexed_chunk_ids_per_entity.remove(&entity_path);
+                }
+            }
+            return;
+        };
+
+        let Some(chunk_ids_per_value) = self.chunk_ids_per_value_per_entity.get_mut(&entity_path)
+        else {
+            return;
+        };
+
+        for value in values {
+            if let Some(chunk_ids) = chunk_ids_per_value.get_mut(&value) {
+                chunk_ids.remove(chunk_id);
+                if chunk_ids.is_empty() {
+                    chunk_ids_per_value.remove(&value);
+                }
+            }
+        }
+
+        if chunk_ids_per_value.is_empty() {
+            self.chunk_ids_per_value_per_entity.remove(&entity_path);
+        }
+    }
+}
+
+impl ValueIndexes {
+    pub(crate) fn on_chunk_added(&mut self, chunk: &Chunk) {
+        for (component, index) in &mut self.per_component {
+            index.on_chunk_added(*component, chunk);
+        }
+    }
+
+    pub(crate) fn on_chunk_removed(&mut self, chunk_id: &ChunkId) {
+        for index in self.per_component.values_mut() {
+            index.on_chunk_removed(chunk_id);
+        }
+    }
+}
+
+// ---
+
+impl ChunkStore {
+    /// Starts indexing the values of the given component, across all entities.
+    ///
+    /// Value indexes make it possible for queries that filter on component values (e.g. class IDs,
+    /// labels or any other key-like component) to skip all the chunks that cannot possibly
+    /// match, see [`crate::QueryExpression::filtered_component_values`].
+    ///
+    /// Existing data is indexed right away. This is a no-op if the index already exists.
+    pub fn create_value_index(&mut self, component: ComponentIdentifier) {
+        re_tracing::profile_function!();
+
+        if self.value_indexes.per_component.contains_key(&component) {
+            return;
+        }
+
+        let mut index = ValueIndex::default();
+        for chunk in self.chunks_per_chunk_id.values() {
+            index.on_chunk_added(component, chunk);
+        }
+
+        // Spilled chunks are out of reach, but still part of the store.
+        for (chunk_id, spilled) in self.spilled_chunks.iter() {
+            if spilled
+                .component_descriptors
+                .iter()
+                .any(|descr| descr.component == component)
+            {
+                index
+                    .unindexed_chunk_ids_per_entity
+                    .entry(spilled.entity_path.clone())
+                    .or_default()
+                    .insert(*chunk_id);
+                index
+                    .values_per_chunk_id
+                    .insert(*chunk_id, (spilled.entity_path.clone(), None));
+            }
+        }
+
+        self.value_indexes.per_component.insert(component, index);
+    }
+
+    /// Stops indexing the values of the given component.
+    pub fn drop_value_index(&mut self, component: &ComponentIdentifier) {
+        self.value_indexes.per_component.remove(component);
+    }
+
+    /// Whether the values of the given component are indexed.
+    ///
+    /// See [`Self::create_value_index`].
+    #[inline]
+    pub fn has_value_index(&self, component: &ComponentIdentifier) -> bool {
+        self.value_indexes.per_component.contains_key(component)
+    }
+
+    /// Returns the IDs of all the chunks of `entity_path` that might contain at least one of
+    /// the given values for `component`.
+    ///
+    /// Returns `None` if the component isn't indexed, in which case all chunks have to be
+    /// considered, see [`Self::create_value_index`].
+    pub fn chunk_ids_with_component_values(
+        &self,
+        entity_path: &EntityPath,
+        component: &ComponentIdentifier,
+        values: &ComponentValueSet,
+    ) -> Option<BTreeSet<ChunkId>> {
+        re_tracing::profile_function!();
+
+        let index = self.value_indexes.per_component.get(component)?;
+
+        let mut chunk_ids = index
+            .unindexed_chunk_ids_per_entity
+            .get(entity_path)
+            .cloned()
+            .unwrap_or_default();
+
+        if let Some(chunk_ids_per_value) = index.chunk_ids_per_value_per_entity.get(entity_path) {
+            for value in &values.values {
+                if let Some(matching_chunk_ids) = chunk_ids_per_value.get(value) {
+                    chunk_ids.extend(matching_chunk_ids);
+                }
+            }
+        }
+
+        Some(chunk_ids)
+    }
+}
@@ -42,7 +42,7 @@ impl ChunkStore {
             return Ok(vec![]);
         }
 
-        if self.chunks_per_chunk_id.contains_key(&chunk.id()) {
+        if self.contains_chunk(&chunk.id()) {
             // We assume that chunk IDs are unique, and that reinserting a chunk has no effect.
             re_log::debug_once!(
                 "Chunk #{} was inserted more than once (this has no effect)",
@@ -61,6 +61,14 @@ impl ChunkStore {
 
         re_tracing::profile_function!();
 
+        let extended_chunk = self
+            .derived_timelines
+            .on_chunk_inserted(chunk)
+            .map(Arc::new);
+        let chunk = extended_chunk.as_ref().unwrap_or(chunk);
+
+        let derived_chunks = self.derived_series.on_chunk_inserted(chunk);
+
         self.insert_id += 1;
 
         let non_compacted_chunk = Arc::clone(chunk); // we'll need it to create the store event
@@ -138,6 +146,11 @@ impl ChunkStore {
 
             self.static_chunks_stats += ChunkStoreChunkStats::from_chunk(chunk);
 
+            if self.config.enable_static_history {
+                self.static_history
+                    .on_static_chunk_added(self.insert_id, chunk);
+            }
+
             let mut diffs = vec![ChunkStoreDiff::addition(
                 non_compacted_chunk, /* added */
                 None,                /* compacted */
@@ -181,6 +194,8 @@ impl ChunkStore {
                         let chunk_removed = self.chunks_per_chunk_id.remove(&chunk_id);
                         debug_assert!(chunk_removed.is_some());
 
+                        self.value_indexes.on_chunk_removed(&chunk_id);
+
                         if let Some(chunk_removed) = chunk_removed {
                             self.static_chunks_stats -=
                                 ChunkStoreChunkStats::from_chunk(&chunk_removed);
@@ -347,6 +362,7 @@ impl ChunkStore {
         };
 
         self.chunks_per_chunk_id.insert(chunk.id(), chunk.clone());
+        self.value_indexes.on_chunk_added(&chunk);
         // NOTE: ⚠️Make sure to recompute the Row ID range! The chunk might have been compacted
         // with another one, which might or might not have modified the range.
         if let Some(min_row_id) = chunk.row_id_range().map(|(min, _)| min)
@@ -419,7 +435,7 @@ impl ChunkStore {
             }
         }
 
-        let events = if self.config.enable_changelog {
+        let mut events = if self.config.enable_changelog {
             let events: Vec<_> = diffs
                 .into_iter()
                 .map(|diff| ChunkStoreEvent {
@@ -439,6 +455,10 @@ impl ChunkStore {
             Vec::new()
         };
 
+        for derived_chunk in derived_chunks {
+            events.extend(self.insert_chunk(&Arc::new(derived_chunk))?);
+        }
+
         Ok(events)
     }
 
@@ -461,6 +481,8 @@ impl ChunkStore {
 
             let ChunkStoreConfig {
                 enable_changelog: _,
+                enable_spill_to_disk: _,
+                enable_static_history: _,
                 chunk_max_bytes,
                 chunk_max_rows,
                 chunk_max_rows_if_unsorted,
@@ -486,6 +508,8 @@ impl ChunkStore {
             |store: &Self, candidate_chunk_id: ChunkId| -> bool {
                 let ChunkStoreConfig {
                     enable_changelog: _,
+                    enable_spill_to_disk: _,
+                    enable_static_history: _,
                     chunk_max_bytes,
                     chunk_max_rows,
                     chunk_max_rows_if_unsorted,
@@ -620,12 +644,19 @@ impl ChunkStore {
             temporal_chunks_stats,
             static_chunk_ids_per_entity,
             static_chunks_stats,
+            spilled_chunks,
+            value_indexes,
+            derived_timelines: _,
+            derived_series: _,
+            static_history,
+            gc_protection: _, // protections outlive the data
             insert_id: _,
             gc_id: _,
             event_id,
         } = self;
 
         per_column_metadata.remove(entity_path);
+        static_history.on_entity_dropped(entity_path);
 
         let dropped_static_chunks = {
             let dropped_static_chunk_ids: BTreeSet<_> = static_chunk_ids_per_entity
@@ -641,6 +672,8 @@ impl ChunkStore {
                 {
                     chunk_ids_per_min_row_id.remove(&min_row_id);
                 }
+
+                value_indexes.on_chunk_removed(chunk_id);
             }
 
             dropped_static_chunk_ids.into_iter()
@@ -673,6 +706,10 @@ impl ChunkStore {
                 {
                     chunk_ids_per_min_row_id.remove(&min_row_id);
                 }
+
+                // Spilled chunks are gone for good, there's no event to send for them.
+                spilled_chunks.remove(chunk_id);
+                value_indexes.on_chunk_removed(chunk_id);
             }
 
             dropped_temporal_chunk_ids.into_iter()
@@ -1174,6 +1211,8 @@ mod tests {
                 re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
                 ChunkStoreConfig {
                     enable_changelog: false,
+                    enable_spill_to_disk: false,
+                    enable_static_history: false,
                     chunk_max_bytes: u64::MAX,
                     chunk_max_rows: u64::MAX,
                     chunk_max_rows_if_unsorted: u64::MAX,
@@ -1203,6 +1242,8 @@ mod tests {
                 re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
                 ChunkStoreConfig {
                     enable_changelog: false,
+                    enable_spill_to_disk: false,
+                    enable_static_history: false,
                     chunk_max_bytes: u64::MAX,
                     chunk_max_rows: u64::MAX,
                     chunk_max_rows_if_unsorted: u64::MAX,
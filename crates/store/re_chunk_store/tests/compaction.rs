@@ -0,0 +1,86 @@
+// https://github.com/rust-lang/rust-clippy/issues/10011
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use re_chunk::{Chunk, RowId};
+use re_chunk_store::{ChunkStore, ChunkStoreConfig, ChunkStoreDiffKind, CompactionOptions};
+use re_log_types::example_components::{MyColor, MyPoints};
+use re_log_types::{EntityPath, StoreId, StoreKind, TimePoint, Timeline};
+
+#[test]
+fn compact_fragmented_store() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let timeline = Timeline::new_sequence("timeline");
+    let data = MyColor::from_rgb(255, 0, 0);
+    let entity_path = EntityPath::from("this/that");
+
+    // Emulate a stream of tiny chunks that never got compacted on insertion.
+    let mut store = ChunkStore::new(
+        StoreId::random(StoreKind::Recording, "test_app"),
+        ChunkStoreConfig::COMPACTION_DISABLED,
+    );
+
+    let num_rows = 100;
+    for time in 0..num_rows {
+        let chunk = Chunk::builder(entity_path.clone())
+            .with_component_batch(
+                RowId::new(),
+                TimePoint::from([(timeline, time)]),
+                (MyPoints::descriptor_colors(), &data),
+            )
+            .build()?;
+        store.insert_chunk(&Arc::new(chunk))?;
+    }
+    assert_eq!(store.num_chunks(), num_rows as usize);
+
+    let options = CompactionOptions {
+        target_chunk_max_rows: 32,
+        trigger_min_num_fragmented_chunks: 10,
+        ..CompactionOptions::default()
+    };
+
+    // Not enough fragmented chunks to trigger a compaction.
+    {
+        let options = CompactionOptions {
+            trigger_min_num_fragmented_chunks: num_rows as u64 + 1,
+            ..options.clone()
+        };
+        assert!(store.compact(&options)?.is_empty());
+        assert_eq!(store.num_chunks(), num_rows as usize);
+    }
+
+    let events = store.compact(&options)?;
+    let num_deletions = events
+        .iter()
+        .filter(|event| event.kind == ChunkStoreDiffKind::Deletion)
+        .count();
+    let num_additions = events
+        .iter()
+        .filter(|event| event.kind == ChunkStoreDiffKind::Addition)
+        .count();
+
+    assert!(store.num_chunks() <= 8, "{}", store.num_chunks());
+    assert_eq!(num_additions, store.num_chunks());
+    assert_eq!(num_deletions, num_rows as usize);
+
+    for chunk in store.iter_chunks() {
+        assert!(chunk.num_rows() <= 32);
+        assert!(chunk.is_sorted());
+    }
+
+    assert_eq!(
+        store.num_temporal_events_for_component_on_timeline(
+            timeline.name(),
+            &entity_path,
+            &MyPoints::descriptor_colors(),
+        ),
+        num_rows as u64
+    );
+
+    // Running it again doesn't find anything left to do.
+    assert!(store.compact(&options)?.is_empty());
+
+    Ok(())
+}
@@ -121,7 +121,11 @@ fn schema_for_query() -> anyhow::Result<()> {
         filtered_index_values: None,
         using_index_values: None,
         filtered_is_not_null: None,
+        filtered_component_values: None,
+        filtered_component_predicates: Vec::new(),
+        join_on_entity: None,
         sparse_fill_strategy: re_chunk_store::SparseFillStrategy::None,
+        aggregation: None,
         selection: None,
     };
 
@@ -186,7 +190,11 @@ fn schema_static_columns() -> anyhow::Result<()> {
         filtered_index_values: None,
         using_index_values: None,
         filtered_is_not_null: None,
+        filtered_component_values: None,
+        filtered_component_predicates: Vec::new(),
+        join_on_entity: None,
         sparse_fill_strategy: re_chunk_store::SparseFillStrategy::None,
+        aggregation: None,
         selection: None,
     };
 
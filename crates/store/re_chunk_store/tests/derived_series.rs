@@ -0,0 +1,121 @@
+// https://github.com/rust-lang/rust-clippy/issues/10011
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use arrow::array::{Array as _, AsArray as _};
+use arrow::datatypes::Float64Type;
+use re_chunk::{Chunk, RowId};
+use re_chunk_store::{ChunkStore, ChunkStoreConfig, ChunkStoreError, DerivedSeries, Expression};
+use re_log_types::example_components::{MyColor, MyPoints};
+use re_log_types::{EntityPath, StoreId, StoreKind, TimePoint, Timeline};
+
+#[test]
+fn derived_series() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let timeline = Timeline::new_sequence("frame");
+    let imu = EntityPath::from("imu");
+    let magnitude = EntityPath::from("imu/magnitude");
+
+    let mut store = ChunkStore::new(
+        StoreId::random(StoreKind::Recording, "test_app"),
+        ChunkStoreConfig::COMPACTION_DISABLED,
+    );
+
+    let insert = |store: &mut ChunkStore, rows: &[(i64, &[u32])]| -> anyhow::Result<()> {
+        let mut builder = Chunk::builder(imu.clone());
+        for &(time, values) in rows {
+            builder = builder.with_component_batch(
+                RowId::new(),
+                TimePoint::from([(timeline, time)]),
+                (
+                    MyPoints::descriptor_colors(),
+                    &MyColor::from_iter(values.iter().copied()),
+                ),
+            );
+        }
+        store.insert_chunk(&Arc::new(builder.build()?))?;
+        Ok(())
+    };
+
+    let derived_values = |store: &ChunkStore| {
+        let mut values = store
+            .iter_chunks()
+            .filter(|chunk| chunk.entity_path() == &magnitude)
+            .flat_map(|chunk| {
+                let times = chunk.timelines()[timeline.name()].times_raw().to_vec();
+                let list_array = chunk.components()[&DerivedSeries::descriptor()].clone();
+                times
+                    .into_iter()
+                    .enumerate()
+                    .map(|(row_idx, time)| {
+                        let value = list_array.is_valid(row_idx).then(|| {
+                            list_array
+                                .value(row_idx)
+                                .as_primitive::<Float64Type>()
+                                .value(0)
+                        });
+                        (time, value)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        values.sort_by_key(|(time, _)| *time);
+        values
+    };
+
+    insert(&mut store, &[(1, &[3, 4]), (2, &[6, 8])])?;
+
+    let series = DerivedSeries {
+        entity_path: magnitude.clone(),
+        source_entity_path: imu.clone(),
+        expression: Expression::parse("sqrt(colors[0]² + colors[1]²)")?,
+    };
+
+    // Existing chunks get derived right away…
+    store.register_derived_series(series.clone())?;
+    assert_eq!(vec![&series], store.derived_series().collect::<Vec<_>>());
+    similar_asserts::assert_eq!(
+        vec![(1, Some(5.0)), (2, Some(10.0))],
+        derived_values(&store)
+    );
+
+    // …and so do the new ones, with nulls where a variable is missing.
+    insert(&mut store, &[(3, &[5, 12]), (4, &[1])])?;
+    similar_asserts::assert_eq!(
+        vec![(1, Some(5.0)), (2, Some(10.0)), (3, Some(13.0)), (4, None)],
+        derived_values(&store)
+    );
+
+    // Replacing the series recomputes all of its values.
+    store.register_derived_series(DerivedSeries {
+        expression: Expression::parse("colors[0] * 2")?,
+        ..series.clone()
+    })?;
+    similar_asserts::assert_eq!(
+        vec![
+            (1, Some(6.0)),
+            (2, Some(12.0)),
+            (3, Some(10.0)),
+            (4, Some(2.0))
+        ],
+        derived_values(&store)
+    );
+
+    // Series cannot be derived from other derived series.
+    assert!(matches!(
+        store.register_derived_series(DerivedSeries {
+            entity_path: EntityPath::from("imu/magnitude/doubled"),
+            source_entity_path: magnitude.clone(),
+            expression: Expression::parse("scalars * 2")?,
+        }),
+        Err(ChunkStoreError::DerivedSeriesChain(_))
+    ));
+
+    store.unregister_derived_series(&magnitude);
+    assert_eq!(0, store.derived_series().count());
+    assert!(derived_values(&store).is_empty());
+
+    Ok(())
+}
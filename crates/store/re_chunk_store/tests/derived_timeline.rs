@@ -0,0 +1,109 @@
+// https://github.com/rust-lang/rust-clippy/issues/10011
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use re_chunk::{Chunk, RowId, TimelineName};
+use re_chunk_store::{
+    ChunkStore, ChunkStoreConfig, ChunkStoreDiffKind, DerivedTimeMapping, DerivedTimeline,
+};
+use re_log_types::example_components::{MyColor, MyPoints};
+use re_log_types::{EntityPath, StoreId, StoreKind, TimePoint, Timeline};
+
+#[test]
+fn derived_timeline() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let timeline = Timeline::new_sequence("frame");
+    let odometry = EntityPath::from("odometry");
+    let camera = EntityPath::from("camera");
+
+    let mut store = ChunkStore::new(
+        StoreId::random(StoreKind::Recording, "test_app"),
+        ChunkStoreConfig::COMPACTION_DISABLED,
+    );
+
+    let insert = |store: &mut ChunkStore,
+                  entity_path: &EntityPath,
+                  rows: &[(i64, u32)]|
+     -> anyhow::Result<()> {
+        let mut builder = Chunk::builder(entity_path.clone());
+        for &(time, value) in rows {
+            builder = builder.with_component_batch(
+                RowId::new(),
+                TimePoint::from([(timeline, time)]),
+                (MyPoints::descriptor_colors(), &MyColor::from_iter([value])),
+            );
+        }
+        store.insert_chunk(&Arc::new(builder.build()?))?;
+        Ok(())
+    };
+
+    let derived_times = |store: &ChunkStore, entity_path: &EntityPath, name: &str| {
+        store
+            .iter_chunks()
+            .filter(|chunk| chunk.entity_path() == entity_path)
+            .filter_map(|chunk| chunk.timelines().get(&TimelineName::new(name)))
+            .flat_map(|time_column| time_column.times_raw().to_vec())
+            .collect::<Vec<_>>()
+    };
+
+    // The distance traveled so far, in meters.
+    insert(&mut store, &odometry, &[(1, 10), (2, 20), (3, 30)])?;
+    insert(&mut store, &camera, &[(0, 0), (1, 0), (2, 0), (4, 0)])?;
+
+    let distance = DerivedTimeline {
+        timeline: Timeline::new_sequence("distance"),
+        base_timeline: *timeline.name(),
+        source_entity_path: odometry.clone(),
+        source_component: MyPoints::descriptor_colors().component,
+        scale: 1.0,
+        mapping: DerivedTimeMapping::Absolute,
+    };
+
+    // Existing chunks get extended right away…
+    let events = store.register_derived_timeline(distance.clone())?;
+    assert_eq!(
+        2,
+        events
+            .iter()
+            .filter(|event| event.kind == ChunkStoreDiffKind::Deletion)
+            .count()
+    );
+    assert_eq!(2, store.num_chunks());
+    assert_eq!(
+        vec![&distance],
+        store.derived_timelines().collect::<Vec<_>>()
+    );
+
+    similar_asserts::assert_eq!(
+        vec![10, 20, 30],
+        derived_times(&store, &odometry, "distance")
+    );
+    similar_asserts::assert_eq!(
+        vec![10, 10, 20, 30],
+        derived_times(&store, &camera, "distance")
+    );
+
+    // …and so do the new ones.
+    insert(&mut store, &odometry, &[(5, 50)])?;
+    insert(&mut store, &camera, &[(5, 0), (6, 0)])?;
+    similar_asserts::assert_eq!(
+        vec![10, 10, 20, 30, 50, 50],
+        derived_times(&store, &camera, "distance")
+    );
+
+    // Offsets get added to the base timeline.
+    store.register_derived_timeline(DerivedTimeline {
+        timeline: Timeline::new_sequence("offset"),
+        scale: 0.1,
+        mapping: DerivedTimeMapping::Offset,
+        ..distance
+    })?;
+    similar_asserts::assert_eq!(
+        vec![1, 2, 4, 7, 10, 11],
+        derived_times(&store, &camera, "offset")
+    );
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+// https://github.com/rust-lang/rust-clippy/issues/10011
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use re_chunk::{Chunk, RowId};
+use re_chunk_store::{ChunkStore, ChunkStoreConfig};
+use re_log_types::example_components::{MyColor, MyPoints};
+use re_log_types::{AbsoluteTimeRange, EntityPath, StoreId, StoreKind, TimePoint, Timeline};
+
+#[test]
+fn export_subtree() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let timeline = Timeline::new_sequence("timeline");
+    let data = MyColor::from_rgb(255, 0, 0);
+    let time_point_at = |time: i64| TimePoint::from([(timeline, time)]);
+
+    let sensor_path = EntityPath::from("robot/sensor");
+    let lidar_path = EntityPath::from("robot/sensor/lidar");
+    let camera_path = EntityPath::from("robot/camera");
+
+    for config in [
+        ChunkStoreConfig::DEFAULT,
+        ChunkStoreConfig::COMPACTION_DISABLED,
+    ] {
+        let mut store = ChunkStore::new(StoreId::random(StoreKind::Recording, "test_app"), config);
+
+        for entity_path in [&sensor_path, &lidar_path, &camera_path] {
+            let mut builder = Chunk::builder(entity_path.clone()).with_component_batch(
+                RowId::new(),
+                TimePoint::default(),
+                (MyPoints::descriptor_colors(), &data),
+            );
+            store.insert_chunk(&Arc::new(builder.build()?))?;
+
+            builder = Chunk::builder(entity_path.clone());
+            for time in 0..8 {
+                builder = builder.with_component_batch(
+                    RowId::new(),
+                    time_point_at(time),
+                    (MyPoints::descriptor_colors(), &data),
+                );
+            }
+            store.insert_chunk(&Arc::new(builder.build()?))?;
+        }
+
+        let num_events = |store: &ChunkStore, entity_path: &EntityPath| {
+            store.num_temporal_events_for_component_on_timeline(
+                timeline.name(),
+                entity_path,
+                &MyPoints::descriptor_colors(),
+            )
+        };
+        let num_static = |store: &ChunkStore, entity_path: &EntityPath| {
+            store
+                .iter_chunks()
+                .filter(|chunk| chunk.is_static() && chunk.entity_path() == entity_path)
+                .count()
+        };
+
+        // The whole subtree:
+        let subtree = store.export_subtree(
+            StoreId::random(StoreKind::Recording, "test_app"),
+            &sensor_path,
+            None,
+        )?;
+        assert_eq!(num_events(&subtree, &sensor_path), 8);
+        assert_eq!(num_events(&subtree, &lidar_path), 8);
+        assert_eq!(num_events(&subtree, &camera_path), 0);
+        assert_eq!(num_static(&subtree, &lidar_path), 1);
+        assert_eq!(num_static(&subtree, &camera_path), 0);
+
+        // Only a time range of a single entity:
+        let subtree = store.export_subtree(
+            StoreId::random(StoreKind::Recording, "test_app"),
+            &lidar_path,
+            Some((*timeline.name(), AbsoluteTimeRange::new(2, 4))),
+        )?;
+        assert_eq!(num_events(&subtree, &sensor_path), 0);
+        assert_eq!(num_events(&subtree, &lidar_path), 3);
+        assert_eq!(num_static(&subtree, &lidar_path), 1);
+
+        // The source store is left untouched:
+        assert_eq!(num_events(&store, &lidar_path), 8);
+    }
+
+    Ok(())
+}
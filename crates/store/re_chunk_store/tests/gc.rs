@@ -445,6 +445,56 @@ fn protected_time_ranges() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn gc_protection() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let mut store = ChunkStore::new(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+        ChunkStoreConfig::COMPACTION_DISABLED,
+    );
+
+    let chunk = |entity_path: &str, frame_nr: i64| -> anyhow::Result<Arc<Chunk>> {
+        Ok(Arc::new(
+            Chunk::builder(entity_path)
+                .with_component_batches(
+                    RowId::new(),
+                    [build_frame_nr(frame_nr)],
+                    [(
+                        MyPoints::descriptor_points(),
+                        &MyPoint::from_iter(0..3) as _,
+                    )],
+                )
+                .build()?,
+        ))
+    };
+
+    let chunk_map = chunk("world/map", 1)?;
+    let chunk_map_tiles = chunk("world/map/tiles", 2)?;
+    let chunk_robot1 = chunk("world/robot", 3)?;
+    let chunk_robot2 = chunk("world/robot", 10)?;
+
+    for chunk in [&chunk_map, &chunk_map_tiles, &chunk_robot1, &chunk_robot2] {
+        store.insert_chunk(chunk)?;
+    }
+
+    store.protect_entity_subtree(EntityPath::from("world/map"));
+    store.protect_time_range(TimelineName::new("frame_nr"), AbsoluteTimeRange::new(8, 12));
+
+    // The protection applies whatever the options of a given run.
+    let (events, _) = store.gc(&GarbageCollectionOptions::gc_everything());
+    assert_eq!(events.len(), 1);
+    assert!(Arc::ptr_eq(&events[0].diff.chunk, &chunk_robot1));
+
+    // Lifting the protection makes the data collectable again.
+    store.set_gc_protection(Default::default());
+    let (events, _) = store.gc(&GarbageCollectionOptions::gc_everything());
+    assert_eq!(events.len(), 3);
+    assert_eq!(0, store.num_chunks());
+
+    Ok(())
+}
+
 // ---
 
 #[test]
@@ -609,3 +659,154 @@ fn manual_drop_entity_path() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn spill_to_disk() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let mut store = ChunkStore::new(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+        ChunkStoreConfig {
+            enable_spill_to_disk: true,
+            ..ChunkStoreConfig::COMPACTION_DISABLED
+        },
+    );
+
+    let entity_path = EntityPath::from("this/that");
+    let timeline_frame_nr = TimelineName::new("frame_nr");
+
+    let mut row_ids = Vec::new();
+    for frame_nr in 0..4_i64 {
+        let row_id = RowId::new();
+        let chunk = Chunk::builder(entity_path.clone())
+            .with_component_batch(
+                row_id,
+                [build_frame_nr(frame_nr)],
+                (MyPoints::descriptor_colors(), &MyColor::from_rgb(255, 0, 0)),
+            )
+            .build()?;
+        store.insert_chunk(&Arc::new(chunk))?;
+        row_ids.push(row_id);
+    }
+
+    let (store_events, stats_diff) = store.gc(&GarbageCollectionOptions {
+        target: GarbageCollectionTarget::DropAtLeastFraction(1.0),
+        ..GarbageCollectionOptions::gc_everything()
+    });
+
+    // Everything got evicted from memory…
+    assert!(store_events.is_empty());
+    assert_eq!(4, stats_diff.total().num_chunks);
+    assert_eq!(0, store.num_chunks());
+    assert_eq!(4, store.num_spilled_chunks());
+    assert!(store.entity_has_temporal_data(&entity_path));
+
+    // …but is transparently reloaded when queried.
+    for (frame_nr, row_id) in row_ids.iter().enumerate() {
+        let (_, data_row_id, _) = query_latest_array(
+            &store,
+            &entity_path,
+            &MyPoints::descriptor_colors(),
+            &LatestAtQuery::new(timeline_frame_nr, TimeInt::new_temporal(frame_nr as i64)),
+        )
+        .unwrap();
+        assert_eq!(*row_id, data_row_id);
+    }
+
+    let chunks = store.range_relevant_chunks(
+        &re_chunk::RangeQuery::new(timeline_frame_nr, AbsoluteTimeRange::new(1, 2)),
+        &entity_path,
+        &MyPoints::descriptor_colors(),
+    );
+    assert_eq!(2, chunks.len());
+
+    // Spilled chunks are never spilled twice…
+    let (store_events, _) = store.gc(&GarbageCollectionOptions {
+        target: GarbageCollectionTarget::DropAtLeastFraction(1.0),
+        ..GarbageCollectionOptions::gc_everything()
+    });
+    assert!(store_events.is_empty());
+    assert_eq!(4, store.num_spilled_chunks());
+
+    // …and only dropped for good when collecting everything.
+    store.gc(&GarbageCollectionOptions::gc_everything());
+    assert_eq!(0, store.num_spilled_chunks());
+    assert!(!store.entity_has_temporal_data(&entity_path));
+    assert!(
+        query_latest_array(
+            &store,
+            &entity_path,
+            &MyPoints::descriptor_colors(),
+            &LatestAtQuery::new(timeline_frame_nr, TimeInt::MAX),
+        )
+        .is_none()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn spill_to_disk_drop_and_export() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let mut store = ChunkStore::new(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+        ChunkStoreConfig {
+            enable_spill_to_disk: true,
+            ..ChunkStoreConfig::COMPACTION_DISABLED
+        },
+    );
+
+    let entity_path = EntityPath::from("this/that");
+    let timeline_frame_nr = TimelineName::new("frame_nr");
+
+    for frame_nr in 0..4_i64 {
+        let chunk = Chunk::builder(entity_path.clone())
+            .with_component_batch(
+                RowId::new(),
+                [build_frame_nr(frame_nr)],
+                (MyPoints::descriptor_colors(), &MyColor::from_rgb(255, 0, 0)),
+            )
+            .build()?;
+        store.insert_chunk(&Arc::new(chunk))?;
+    }
+
+    store.gc(&GarbageCollectionOptions {
+        target: GarbageCollectionTarget::DropAtLeastFraction(1.0),
+        ..GarbageCollectionOptions::gc_everything()
+    });
+    assert_eq!(0, store.num_chunks());
+    assert_eq!(4, store.num_spilled_chunks());
+
+    // Spilled chunks are part of everything that gets saved…
+    assert_eq!(0, store.iter_chunks().count());
+    assert_eq!(4, store.iter_all_chunks().count());
+
+    let exported = store.export_subtree(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+        &EntityPath::from("this"),
+        Some((timeline_frame_nr, AbsoluteTimeRange::new(1, 2))),
+    )?;
+    assert_eq!(2, exported.num_chunks());
+
+    // …and dropping their time range drops them for good.
+    store.drop_time_range(&timeline_frame_nr, AbsoluteTimeRange::new(1, 2));
+    assert_eq!(2, store.num_spilled_chunks());
+    assert_eq!(2, store.iter_all_chunks().count());
+    for frame_nr in [1, 2] {
+        let query = LatestAtQuery::new(timeline_frame_nr, TimeInt::new_temporal(frame_nr));
+        let (data_time, _, _) =
+            query_latest_array(&store, &entity_path, &MyPoints::descriptor_colors(), &query)
+                .unwrap();
+        assert_eq!(TimeInt::new_temporal(0), data_time);
+    }
+
+    let chunks = store.range_relevant_chunks(
+        &re_chunk::RangeQuery::new(timeline_frame_nr, AbsoluteTimeRange::new(1, 2)),
+        &entity_path,
+        &MyPoints::descriptor_colors(),
+    );
+    assert!(chunks.is_empty());
+
+    Ok(())
+}
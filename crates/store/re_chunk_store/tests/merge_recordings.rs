@@ -0,0 +1,100 @@
+// https://github.com/rust-lang/rust-clippy/issues/10011
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use re_chunk::{Chunk, RowId, TimelineName};
+use re_chunk_store::{ChunkStore, ChunkStoreConfig, MergeTimeAlignment};
+use re_log_types::example_components::{MyColor, MyPoints};
+use re_log_types::{AbsoluteTimeRange, EntityPath, StoreId, StoreKind, TimePoint, Timeline};
+
+fn create_store(entity_path: &EntityPath, frames: &[i64]) -> anyhow::Result<ChunkStore> {
+    let timeline = Timeline::new_sequence("frame");
+
+    let mut store = ChunkStore::new(
+        StoreId::random(StoreKind::Recording, "test_app"),
+        ChunkStoreConfig::COMPACTION_DISABLED,
+    );
+
+    let mut builder = Chunk::builder(entity_path.clone());
+    for &frame in frames {
+        builder = builder.with_component_batch(
+            RowId::new(),
+            TimePoint::from([(timeline, frame)]),
+            (MyPoints::descriptor_colors(), &MyColor::from_rgb(255, 0, 0)),
+        );
+    }
+    store.insert_chunk(&Arc::new(builder.build()?))?;
+
+    Ok(store)
+}
+
+#[test]
+fn merge_recordings() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let entity_path = EntityPath::from("robot");
+    let frame = TimelineName::new("frame");
+
+    let store1 = create_store(&entity_path, &[0, 1, 2])?;
+    let store2 = create_store(&entity_path, &[1, 2, 3])?;
+    let store3 = create_store(&entity_path, &[10, 11])?;
+
+    let new_id = || StoreId::random(StoreKind::Recording, "test_app");
+
+    // Nested recordings keep their times.
+    {
+        let merged = ChunkStore::merge_recordings(
+            new_id(),
+            ChunkStoreConfig::COMPACTION_DISABLED,
+            [
+                (EntityPath::from("robot1"), &store1),
+                (EntityPath::from("robot2"), &store2),
+            ],
+            MergeTimeAlignment::Keep,
+        )?;
+
+        assert_eq!(2, merged.num_chunks());
+        assert_eq!(
+            Some(AbsoluteTimeRange::new(0, 2)),
+            merged.entity_time_range(&frame, &EntityPath::from("robot1/robot"))
+        );
+        assert_eq!(
+            Some(AbsoluteTimeRange::new(1, 3)),
+            merged.entity_time_range(&frame, &EntityPath::from("robot2/robot"))
+        );
+        assert_eq!(None, merged.entity_time_range(&frame, &entity_path));
+    }
+
+    // Concatenated recordings follow each other, unless they already do.
+    {
+        let merged = ChunkStore::merge_recordings(
+            new_id(),
+            ChunkStoreConfig::COMPACTION_DISABLED,
+            [
+                (EntityPath::root(), &store1),
+                (EntityPath::root(), &store2),
+                (EntityPath::root(), &store3),
+            ],
+            MergeTimeAlignment::Concatenate,
+        )?;
+
+        let mut time_ranges = merged
+            .iter_chunks()
+            .filter_map(|chunk| chunk.timelines().get(&frame))
+            .map(|time_column| time_column.time_range())
+            .collect::<Vec<_>>();
+        time_ranges.sort_by_key(|time_range| time_range.min());
+
+        similar_asserts::assert_eq!(
+            vec![
+                AbsoluteTimeRange::new(0, 2),
+                AbsoluteTimeRange::new(3, 5),
+                AbsoluteTimeRange::new(10, 11),
+            ],
+            time_ranges
+        );
+    }
+
+    Ok(())
+}
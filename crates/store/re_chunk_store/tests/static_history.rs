@@ -0,0 +1,97 @@
+// https://github.com/rust-lang/rust-clippy/issues/10011
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use arrow::array::{Array as _, UInt32Array};
+
+use re_chunk::{Chunk, RowId};
+use re_chunk_store::{ChunkStore, ChunkStoreConfig};
+use re_log_types::example_components::{MyColor, MyPoints};
+use re_log_types::{EntityPath, StoreId, StoreKind, TimePoint};
+
+#[test]
+fn static_history() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let entity_path = EntityPath::from("calibration");
+    let component = MyPoints::descriptor_colors().component;
+
+    let new_store = |enable_static_history| {
+        ChunkStore::new(
+            StoreId::random(StoreKind::Recording, "test_app"),
+            ChunkStoreConfig {
+                enable_static_history,
+                ..ChunkStoreConfig::COMPACTION_DISABLED
+            },
+        )
+    };
+
+    let row_id1 = RowId::new();
+    let row_id2 = RowId::new();
+    let row_id3 = RowId::new();
+
+    let chunk = |row_id: RowId, colors: &[u32]| -> anyhow::Result<Arc<Chunk>> {
+        Ok(Arc::new(
+            Chunk::builder(entity_path.clone())
+                .with_component_batch(
+                    row_id,
+                    TimePoint::STATIC,
+                    (
+                        MyPoints::descriptor_colors(),
+                        &MyColor::from_iter(colors.iter().copied()),
+                    ),
+                )
+                .build()?,
+        ))
+    };
+
+    let chunk1 = chunk(row_id1, &[1])?;
+    let chunk2 = chunk(row_id2, &[2, 2])?;
+    let chunk3 = chunk(row_id3, &[3])?;
+
+    // Disabled by default.
+    {
+        let mut store = new_store(false);
+        store.insert_chunk(&chunk1)?;
+        store.insert_chunk(&chunk2)?;
+        assert!(store.static_history(&entity_path, &component).is_empty());
+    }
+
+    let mut store = new_store(true);
+    store.insert_chunk(&chunk1)?;
+    store.insert_chunk(&chunk3)?;
+    store.insert_chunk(&chunk2)?; // out of order: loses against `chunk3`
+
+    let history = store.static_history(&entity_path, &component);
+
+    similar_asserts::assert_eq!(
+        vec![
+            (row_id1, chunk1.id(), 1),
+            (row_id2, chunk2.id(), 3),
+            (row_id3, chunk3.id(), 2),
+        ],
+        history
+            .iter()
+            .map(|entry| (entry.row_id, entry.chunk_id, entry.insert_id))
+            .collect::<Vec<_>>()
+    );
+
+    let values = history
+        .iter()
+        .map(|entry| {
+            entry
+                .value
+                .as_any()
+                .downcast_ref::<UInt32Array>()
+                .map(|values| values.values().to_vec())
+        })
+        .collect::<Vec<_>>();
+    similar_asserts::assert_eq!(vec![Some(vec![1]), Some(vec![2, 2]), Some(vec![3])], values);
+
+    // Dropping the entity drops its history too.
+    store.drop_entity_path(&entity_path);
+    assert!(store.static_history(&entity_path, &component).is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+// https://github.com/rust-lang/rust-clippy/issues/10011
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, UInt32Array};
+
+use re_chunk::{Chunk, RowId};
+use re_chunk_store::{ChunkStore, ChunkStoreConfig, ComponentValueSet, GarbageCollectionOptions};
+use re_log_types::example_components::{MyColor, MyPoints};
+use re_log_types::{EntityPath, StoreId, StoreKind, TimePoint, Timeline};
+
+#[test]
+fn value_index() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let timeline = Timeline::new_sequence("timeline");
+    let entity_path = EntityPath::from("this/that");
+    let component = MyPoints::descriptor_colors().component;
+
+    let mut store = ChunkStore::new(
+        StoreId::random(StoreKind::Recording, "test_app"),
+        ChunkStoreConfig::COMPACTION_DISABLED,
+    );
+
+    let mut chunk_ids = Vec::new();
+    let mut insert =
+        |store: &mut ChunkStore, time: i64, colors: &[MyColor]| -> anyhow::Result<()> {
+            let chunk = Chunk::builder(entity_path.clone())
+                .with_component_batch(
+                    RowId::new(),
+                    TimePoint::from([(timeline, time)]),
+                    (MyPoints::descriptor_colors(), &colors.to_vec()),
+                )
+                .build()?;
+            chunk_ids.push(chunk.id());
+            store.insert_chunk(&Arc::new(chunk))?;
+            Ok(())
+        };
+
+    // Existing data gets indexed on creation…
+    insert(&mut store, 0, &MyColor::from_iter(0..2))?;
+    insert(&mut store, 1, &MyColor::from_iter(2..4))?;
+    store.create_value_index(component);
+    assert!(store.has_value_index(&component));
+
+    // …and new data as it comes in.
+    insert(&mut store, 2, &MyColor::from_iter(1..3))?;
+
+    let matching = |store: &ChunkStore, values: Vec<u32>| {
+        let values: ArrayRef = Arc::new(UInt32Array::from(values));
+        let values = ComponentValueSet::new(&values).expect("u32s are supported");
+        store.chunk_ids_with_component_values(&entity_path, &component, &values)
+    };
+
+    similar_asserts::assert_eq!(Some([chunk_ids[0]].into()), matching(&store, vec![0]));
+    similar_asserts::assert_eq!(
+        Some([chunk_ids[0], chunk_ids[2]].into()),
+        matching(&store, vec![1])
+    );
+    similar_asserts::assert_eq!(
+        Some([chunk_ids[1], chunk_ids[2]].into()),
+        matching(&store, vec![2, 42])
+    );
+    similar_asserts::assert_eq!(Some(Default::default()), matching(&store, vec![42]));
+
+    // Removed chunks are removed from the index too.
+    store.gc(&GarbageCollectionOptions::gc_everything());
+    similar_asserts::assert_eq!(Some(Default::default()), matching(&store, vec![1, 2]));
+
+    store.drop_value_index(&component);
+    assert_eq!(None, matching(&store, vec![1, 2]));
+
+    Ok(())
+}
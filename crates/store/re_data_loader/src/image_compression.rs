@@ -0,0 +1,300 @@
+//! Re-encoding of raw images to JPEG or PNG at ingest, see [`ImageCompression`].
+
+use std::borrow::Cow;
+
+use image::ImageEncoder as _;
+
+use re_chunk::{Chunk, ChunkComponents, ChunkId, ChunkResult};
+use re_types::{
+    archetypes::{EncodedImage, Image},
+    components::{ImageBuffer, ImageFormat, MediaType},
+    datatypes::{ChannelDatatype, ColorModel},
+};
+
+use crate::LoadedData;
+
+/// How raw images are re-encoded when loaded, to save memory and disk space.
+///
+/// Only the buffers of [`Image`]s are affected: they are replaced by [`EncodedImage`]s on the
+/// same entity, while the original [`ImageFormat`] and all other components are kept.
+/// Depth and segmentation images, as well as images in chroma-subsampled pixel formats, are left as is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageCompression {
+    /// Lossy JPEG of the given quality, from 1 to 100.
+    ///
+    /// Images with an alpha channel or 16 bit channels are losslessly encoded as PNG instead.
+    Jpeg { quality: u8 },
+
+    /// Lossless PNG.
+    Png,
+}
+
+impl Default for ImageCompression {
+    fn default() -> Self {
+        Self::Jpeg { quality: 90 }
+    }
+}
+
+impl std::str::FromStr for ImageCompression {
+    type Err = String;
+
+    /// Parses `png`, `jpeg` or `jpeg:<quality>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (format, quality) = s
+            .split_once(':')
+            .map_or((s, None), |(format, quality)| (format, Some(quality)));
+
+        match (format.trim().to_lowercase().as_str(), quality) {
+            ("png", None) => Ok(Self::Png),
+            ("jpeg" | "jpg", None) => Ok(Self::default()),
+            ("jpeg" | "jpg", Some(quality)) => match quality.trim().parse::<u8>() {
+                Ok(quality @ 1..=100) => Ok(Self::Jpeg { quality }),
+                _ => Err(format!(
+                    "expected a JPEG quality from 1 to 100, got {quality:?}"
+                )),
+            },
+            _ => Err(format!(
+                "expected `png`, `jpeg` or `jpeg:<quality>`, got {s:?}"
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for ImageCompression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Jpeg { quality } => write!(f, "jpeg:{quality}"),
+            Self::Png => f.write_str("png"),
+        }
+    }
+}
+
+/// Applies [`compress_images`] to the chunks of recordings.
+pub(crate) fn compress_loaded_images(
+    data: LoadedData,
+    compression: ImageCompression,
+) -> LoadedData {
+    match data {
+        LoadedData::Chunk(loader_name, store_id, chunk) if store_id.is_recording() => {
+            match compress_images(&chunk, compression) {
+                Ok(Some(compressed)) => LoadedData::Chunk(loader_name, store_id, compressed),
+                Ok(None) => LoadedData::Chunk(loader_name, store_id, chunk),
+                Err(err) => {
+                    re_log::warn_once!(
+                        "Failed to compress the images of {}: {err}",
+                        chunk.entity_path()
+                    );
+                    LoadedData::Chunk(loader_name, store_id, chunk)
+                }
+            }
+        }
+
+        data => data,
+    }
+}
+
+/// Re-encodes the raw [`Image`] buffers of the chunk.
+///
+/// Returns `None` if the chunk has no raw images, or if any of them can't be encoded.
+pub fn compress_images(chunk: &Chunk, compression: ImageCompression) -> ChunkResult<Option<Chunk>> {
+    re_tracing::profile_function!();
+
+    let buffer_descr = Image::descriptor_buffer();
+    let format_descr = Image::descriptor_format();
+    if !chunk.contains_component(&buffer_descr) {
+        return Ok(None);
+    }
+
+    let mut blobs = Vec::with_capacity(chunk.num_rows());
+    let mut media_types = Vec::with_capacity(chunk.num_rows());
+    for row in 0..chunk.num_rows() {
+        let (Some(buffer), Some(format)) = (
+            chunk.component_mono::<ImageBuffer>(&buffer_descr, row),
+            chunk.component_mono::<ImageFormat>(&format_descr, row),
+        ) else {
+            // Rows without a buffer would have to stay raw, so the whole chunk does.
+            return Ok(None);
+        };
+
+        let Some((blob, media_type)) = encode_image(&buffer?, &format?, compression) else {
+            return Ok(None);
+        };
+        blobs.push(blob);
+        media_types.push(media_type);
+    }
+
+    let mut components = ChunkComponents(
+        chunk
+            .components()
+            .iter()
+            .filter(|(descr, _)| **descr != buffer_descr)
+            .map(|(descr, list_array)| (descr.clone(), list_array.clone()))
+            .collect(),
+    );
+    for column in EncodedImage::update_fields()
+        .with_many_blob(blobs)
+        .with_many_media_type(media_types)
+        .columns_of_unit_batches()?
+    {
+        components.insert(column.descriptor, column.list_array);
+    }
+
+    Chunk::new(
+        ChunkId::new(),
+        chunk.entity_path().clone(),
+        None,
+        chunk.row_ids_array().clone(),
+        chunk.timelines().clone(),
+        components,
+    )
+    .map(Some)
+}
+
+/// Encodes a single raw image, if its format is supported.
+fn encode_image(
+    buffer: &ImageBuffer,
+    format: &ImageFormat,
+    compression: ImageCompression,
+) -> Option<(Vec<u8>, MediaType)> {
+    if format.pixel_format.is_some() {
+        return None;
+    }
+
+    let (width, height) = (format.width, format.height);
+    let color_model = format.color_model?;
+    let bytes: &[u8] = buffer;
+
+    let (color_type, bytes) = match (format.channel_datatype?, color_model) {
+        (ChannelDatatype::U8, ColorModel::L) => {
+            (image::ExtendedColorType::L8, Cow::Borrowed(bytes))
+        }
+        (ChannelDatatype::U8, ColorModel::RGB) => {
+            (image::ExtendedColorType::Rgb8, Cow::Borrowed(bytes))
+        }
+        (ChannelDatatype::U8, ColorModel::RGBA) => {
+            (image::ExtendedColorType::Rgba8, Cow::Borrowed(bytes))
+        }
+        (ChannelDatatype::U8, ColorModel::BGR) => (
+            image::ExtendedColorType::Rgb8,
+            Cow::Owned(swap_rb(bytes, 3)),
+        ),
+        (ChannelDatatype::U8, ColorModel::BGRA) => (
+            image::ExtendedColorType::Rgba8,
+            Cow::Owned(swap_rb(bytes, 4)),
+        ),
+        (ChannelDatatype::U16, ColorModel::L) => {
+            (image::ExtendedColorType::L16, Cow::Borrowed(bytes))
+        }
+        (ChannelDatatype::U16, ColorModel::RGB) => {
+            (image::ExtendedColorType::Rgb16, Cow::Borrowed(bytes))
+        }
+        (ChannelDatatype::U16, ColorModel::RGBA) => {
+            (image::ExtendedColorType::Rgba16, Cow::Borrowed(bytes))
+        }
+        _ => return None,
+    };
+
+    let expected_len = width as usize * height as usize * color_type.bits_per_pixel() as usize / 8;
+    if bytes.len() != expected_len {
+        return None;
+    }
+
+    let mut encoded = Vec::new();
+    match compression {
+        ImageCompression::Jpeg { quality }
+            if matches!(
+                color_type,
+                image::ExtendedColorType::L8 | image::ExtendedColorType::Rgb8
+            ) =>
+        {
+            image::codecs::jpeg::JpegEncoder::new_with_quality(&mut encoded, quality)
+                .write_image(&bytes, width, height, color_type)
+                .ok()?;
+            Some((encoded, MediaType::jpeg()))
+        }
+
+        ImageCompression::Jpeg { .. } | ImageCompression::Png => {
+            image::codecs::png::PngEncoder::new(&mut encoded)
+                .write_image(&bytes, width, height, color_type)
+                .ok()?;
+            Some((encoded, MediaType::png()))
+        }
+    }
+}
+
+/// Swaps the red and blue channels of BGR(A) pixels.
+fn swap_rb(bytes: &[u8], num_channels: usize) -> Vec<u8> {
+    let mut bytes = bytes.to_vec();
+    for pixel in bytes.chunks_exact_mut(num_channels) {
+        pixel.swap(0, 2);
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::{RowId, TimePoint, Timeline};
+    use re_types::datatypes::ImageFormat as ImageFormatDatatype;
+
+    use super::*;
+
+    #[test]
+    fn parse_image_compression() {
+        assert_eq!("png".parse(), Ok(ImageCompression::Png));
+        assert_eq!("jpeg".parse(), Ok(ImageCompression::default()));
+        assert_eq!("jpg:50".parse(), Ok(ImageCompression::Jpeg { quality: 50 }));
+        assert!("jpeg:0".parse::<ImageCompression>().is_err());
+        assert!("webp".parse::<ImageCompression>().is_err());
+    }
+
+    #[test]
+    fn compresses_raw_images() {
+        let [width, height] = [16, 8];
+        let pixels = (0..width * height * 3)
+            .map(|i| (i % 256) as u8)
+            .collect::<Vec<_>>();
+        let image = Image::from_rgb24(pixels, [width, height]).with_draw_order(1.0);
+
+        let timeline = Timeline::new_sequence("frame");
+        let chunk = Chunk::builder("camera")
+            .with_archetype(RowId::new(), TimePoint::default().with(timeline, 1), &image)
+            .with_archetype(RowId::new(), TimePoint::default().with(timeline, 2), &image)
+            .build()
+            .unwrap();
+
+        for (compression, media_type) in [
+            (ImageCompression::default(), MediaType::jpeg()),
+            (ImageCompression::Png, MediaType::png()),
+        ] {
+            let compressed = compress_images(&chunk, compression).unwrap().unwrap();
+
+            assert_eq!(compressed.num_rows(), 2);
+            assert_eq!(compressed.row_ids_slice(), chunk.row_ids_slice());
+            assert!(!compressed.contains_component(&Image::descriptor_buffer()));
+            assert!(compressed.contains_component(&Image::descriptor_format()));
+            assert!(compressed.contains_component(&Image::descriptor_draw_order()));
+            assert_eq!(
+                compressed
+                    .component_mono::<MediaType>(&EncodedImage::descriptor_media_type(), 0)
+                    .unwrap()
+                    .unwrap(),
+                media_type
+            );
+        }
+
+        // Depth images are left as is.
+        let depth = re_types::archetypes::DepthImage::new(
+            vec![0_u8; (width * height * 2) as usize],
+            ImageFormatDatatype::depth([width, height], ChannelDatatype::U16),
+        );
+        let chunk = Chunk::builder("depth")
+            .with_archetype(RowId::new(), TimePoint::default().with(timeline, 1), &depth)
+            .build()
+            .unwrap();
+        assert!(
+            compress_images(&chunk, ImageCompression::Png)
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -7,15 +7,21 @@ use re_log_types::{ArrowMsg, EntityPath, LogMsg, RecordingId, StoreId, TimePoint
 
 // ----------------------------------------------------------------------------
 
+mod image_compression;
 mod load_file;
 mod loader_archetype;
 mod loader_directory;
+mod loader_label_map;
 mod loader_rrd;
 mod loader_urdf;
+mod video_encoding;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub mod lerobot;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod rosbag2;
+
 // This loader currently only works when loading the entire dataset directory, and we cannot do that on web yet.
 #[cfg(not(target_arch = "wasm32"))]
 pub mod loader_lerobot;
@@ -29,9 +35,15 @@ mod loader_external;
 pub use self::loader_mcap::McapLoader;
 
 pub use self::{
-    load_file::load_from_file_contents, loader_archetype::ArchetypeLoader,
-    loader_directory::DirectoryLoader, loader_rrd::RrdLoader, loader_urdf::UrdfDataLoader,
+    image_compression::{ImageCompression, compress_images},
+    load_file::load_from_file_contents,
+    loader_archetype::ArchetypeLoader,
+    loader_directory::DirectoryLoader,
+    loader_label_map::LabelMapLoader,
+    loader_rrd::{RrdLoadOptions, RrdLoader},
+    loader_urdf::UrdfDataLoader,
     loader_urdf::UrdfTree,
+    video_encoding::VideoEncoding,
 };
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -90,6 +102,20 @@ pub struct DataLoaderSettings {
 
     /// At what time(s) should the data be logged to?
     pub timepoint: Option<TimePoint>,
+
+    /// If set, raw images are re-encoded this way once loaded.
+    ///
+    /// This is applied to the output of all loaders, so they don't have to handle it themselves.
+    pub image_compression: Option<ImageCompression>,
+
+    /// If set, sequences of raw images are encoded to video streams once loaded.
+    ///
+    /// Like [`Self::image_compression`], this is applied to the output of all loaders. Images that
+    /// end up in a video stream are not compressed on their own.
+    pub video_encoding: Option<VideoEncoding>,
+
+    /// How `rrd` files are decoded.
+    pub rrd: RrdLoadOptions,
 }
 
 impl DataLoaderSettings {
@@ -102,6 +128,9 @@ impl DataLoaderSettings {
             force_store_info: false,
             entity_path_prefix: Default::default(),
             timepoint: Default::default(),
+            image_compression: None,
+            video_encoding: None,
+            rrd: RrdLoadOptions::default(),
         }
     }
 
@@ -132,6 +161,9 @@ impl DataLoaderSettings {
             force_store_info: _,
             entity_path_prefix,
             timepoint,
+            image_compression: _,
+            video_encoding: _,
+            rrd: _,
         } = self;
 
         let mut args = Vec::new();
@@ -234,6 +266,7 @@ pub type DataLoaderName = String;
 ///     - [Point clouds]
 ///     - [Text files]
 /// - [`DirectoryLoader`] for recursively loading folders.
+/// - [`LabelMapLoader`] for COCO categories and YAML label maps.
 /// - [`ExternalLoader`], which looks for user-defined data loaders in $PATH.
 ///
 /// ## Registering custom loaders
@@ -426,6 +459,7 @@ static BUILTIN_LOADERS: LazyLock<Vec<Arc<dyn DataLoader>>> = LazyLock::new(|| {
         #[cfg(not(target_arch = "wasm32"))]
         Arc::new(ExternalLoader),
         Arc::new(UrdfDataLoader),
+        Arc::new(LabelMapLoader),
     ]
 });
 
@@ -477,7 +511,7 @@ pub const SUPPORTED_VIDEO_EXTENSIONS: &[&str] = &["mp4"];
 
 pub const SUPPORTED_MESH_EXTENSIONS: &[&str] = &["glb", "gltf", "obj", "stl"];
 
-// TODO(#4532): `.ply` data loader should support 2D point cloud & meshes
+// TODO(#4532): `.ply` data loader should support 2D point cloud
 pub const SUPPORTED_POINT_CLOUD_EXTENSIONS: &[&str] = &["ply"];
 
 pub const SUPPORTED_RERUN_EXTENSIONS: &[&str] = &["rbl", "rrd"];
@@ -289,12 +289,24 @@ pub(crate) fn send(
 
         let tx = tx.clone();
         move || {
-            // ## Ignoring channel errors
-            //
-            // Not our problem whether or not the other end has hung up, but we still want to
-            // poll the channel in any case so as to make sure that the data producer
-            // doesn't get stuck.
-            for data in rx_loader {
+            #[cfg(not(target_arch = "wasm32"))]
+            let mut video_encoder = settings
+                .video_encoding
+                .clone()
+                .map(crate::video_encoding::VideoEncoderStage::new);
+            #[cfg(target_arch = "wasm32")]
+            if settings.video_encoding.is_some() {
+                re_log::warn_once!("Video encoding at ingest is not supported on the web");
+            }
+
+            let mut forward = |data: LoadedData| {
+                let data = match settings.image_compression {
+                    Some(compression) => {
+                        crate::image_compression::compress_loaded_images(data, compression)
+                    }
+                    None => data,
+                };
+
                 let data_loader_name = data.data_loader_name().clone();
                 let msg = match data.into_log_msg() {
                     Ok(msg) => {
@@ -319,10 +331,34 @@ pub(crate) fn send(
                     }
                     Err(err) => {
                         re_log::error!(%err, "Couldn't serialize component data");
-                        continue;
+                        return;
                     }
                 };
                 tx.send(msg).ok();
+            };
+
+            // ## Ignoring channel errors
+            //
+            // Not our problem whether or not the other end has hung up, but we still want to
+            // poll the channel in any case so as to make sure that the data producer
+            // doesn't get stuck.
+            for data in rx_loader {
+                // Raw images are encoded to videos first, so that they aren't compressed on their own.
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(video_encoder) = &mut video_encoder {
+                    video_encoder
+                        .process(data)
+                        .into_iter()
+                        .for_each(&mut forward);
+                    continue;
+                }
+
+                forward(data);
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(video_encoder) = video_encoder {
+                video_encoder.finish().into_iter().for_each(&mut forward);
             }
 
             for (store_id, tracked) in store_info_tracker {
@@ -286,11 +286,16 @@ fn load_point_cloud(
 
     let rows = [
         {
-            // TODO(#4532): `.ply` data loader should support 2D point cloud & meshes
-            let points3d = re_types::archetypes::Points3D::from_file_contents(contents)?;
-            Chunk::builder(entity_path)
-                .with_archetype(RowId::new(), timepoint, &points3d)
-                .build()?
+            // TODO(#4532): `.ply` data loader should support 2D point cloud
+            let chunk = Chunk::builder(entity_path);
+            let chunk = if ply_has_faces(contents) {
+                let mesh3d = re_types::archetypes::Mesh3D::from_file_contents(contents)?;
+                chunk.with_archetype(RowId::new(), timepoint, &mesh3d)
+            } else {
+                let points3d = re_types::archetypes::Points3D::from_file_contents(contents)?;
+                chunk.with_archetype(RowId::new(), timepoint, &points3d)
+            };
+            chunk.build()?
         },
         //
     ];
@@ -298,6 +303,24 @@ fn load_point_cloud(
     Ok(rows.into_iter())
 }
 
+/// Checks the header of a `.ply` file for a non-empty `face` element, i.e. whether it's a mesh
+/// rather than a point cloud.
+fn ply_has_faces(contents: &[u8]) -> bool {
+    contents
+        .split(|&b| b == b'\n')
+        .map(String::from_utf8_lossy)
+        .take_while(|line| line.trim() != "end_header")
+        .any(|line| {
+            let mut words = line.split_whitespace();
+            words.next() == Some("element")
+                && words.next() == Some("face")
+                && words
+                    .next()
+                    .and_then(|count| count.parse::<u64>().ok())
+                    .is_some_and(|count| count > 0)
+        })
+}
+
 fn load_text_document(
     filepath: std::path::PathBuf,
     timepoint: TimePoint,
@@ -29,6 +29,11 @@ impl crate::DataLoader for DirectoryLoader {
             return Err(crate::DataLoaderError::Incompatible(dirpath.clone()));
         }
 
+        if crate::rosbag2::is_rosbag2_directory(&dirpath) {
+            // Split rosbag2 recordings are loaded by McapLoader
+            return Err(crate::DataLoaderError::Incompatible(dirpath.clone()));
+        }
+
         re_tracing::profile_function!(dirpath.display().to_string());
 
         re_log::debug!(?dirpath, loader = self.name(), "Loading directory…",);
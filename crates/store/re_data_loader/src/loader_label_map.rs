@@ -0,0 +1,141 @@
+use std::path::Path;
+
+use re_chunk::{Chunk, EntityPath, RowId, TimePoint};
+use re_types::archetypes::AnnotationContext;
+
+use crate::{DataLoader, DataLoaderError, LoadedData};
+
+/// Extensions of the label map formats understood by the [`LabelMapLoader`].
+///
+/// These are also used for plenty of unrelated files, so the [`LabelMapLoader`] only claims
+/// those whose contents actually look like a label map, see [`is_label_map`].
+const LABEL_MAP_EXTENSIONS: &[&str] = &["json", "yaml", "yml"];
+
+/// A [`DataLoader`] for label maps, loaded as a static [`AnnotationContext`].
+///
+/// Supports the `categories` of [COCO](https://cocodataset.org/#format-data) annotation files,
+/// as well as YAML label maps with a top-level `names` key, such as the ones used by YOLO datasets.
+/// See [`AnnotationContext::from_coco_categories`] and [`AnnotationContext::from_yaml_label_map`].
+pub struct LabelMapLoader;
+
+impl DataLoader for LabelMapLoader {
+    fn name(&self) -> crate::DataLoaderName {
+        "rerun.data_loaders.LabelMap".into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_label_map_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath));
+        }
+
+        let contents = std::fs::read(&filepath)?;
+        self.load_from_file_contents(settings, filepath, contents.into(), tx)
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_label_map_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let is_json = crate::extension(&filepath) == "json";
+        if !is_label_map(is_json, &contents) {
+            return Err(DataLoaderError::Incompatible(filepath));
+        }
+
+        let annotation_context = if is_json {
+            AnnotationContext::from_coco_categories(&contents)
+        } else {
+            AnnotationContext::from_yaml_label_map(&contents)
+        };
+        let annotation_context = match annotation_context {
+            Ok(annotation_context) => annotation_context,
+            Err(err) => {
+                re_log::debug!(?filepath, loader = self.name(), "Not a label map: {err}");
+                return Err(DataLoaderError::Incompatible(filepath));
+            }
+        };
+
+        // Annotation contexts apply to the whole subtree they're logged at.
+        let entity_path = settings
+            .entity_path_prefix
+            .clone()
+            .unwrap_or_else(EntityPath::root);
+
+        let chunk = Chunk::builder(entity_path)
+            .with_archetype(RowId::new(), TimePoint::default(), &annotation_context)
+            .build()?;
+
+        // If the other end has hung up, that's not our problem.
+        tx.send(LoadedData::Chunk(
+            self.name(),
+            settings.recommended_store_id(),
+            chunk,
+        ))
+        .ok();
+
+        Ok(())
+    }
+}
+
+fn is_label_map_file(path: &Path) -> bool {
+    LABEL_MAP_EXTENSIONS.contains(&crate::extension(path).as_str())
+}
+
+/// Whether `contents` has the non-empty COCO `categories` or YAML `names` of a label map.
+///
+/// Bare lists and maps are valid label maps too, but far too common in unrelated files.
+fn is_label_map(is_json: bool, contents: &[u8]) -> bool {
+    if is_json {
+        serde_json::from_slice::<serde_json::Value>(contents).is_ok_and(|json| {
+            json.get("categories")
+                .and_then(serde_json::Value::as_array)
+                .is_some_and(|categories| !categories.is_empty())
+        })
+    } else {
+        serde_yaml::from_slice::<serde_yaml::Value>(contents).is_ok_and(|yaml| {
+            yaml.get("names").is_some_and(|names| match names {
+                serde_yaml::Value::Sequence(names) => !names.is_empty(),
+                serde_yaml::Value::Mapping(names) => !names.is_empty(),
+                _ => false,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_map_detection() {
+        assert!(is_label_map(
+            true,
+            br#"{"categories": [{"id": 1, "name": "person"}]}"#
+        ));
+        assert!(is_label_map(false, b"names:\n  0: person\n  1: bicycle\n"));
+        assert!(is_label_map(false, b"names: [person, bicycle]\n"));
+
+        assert!(!is_label_map(true, b""));
+        assert!(!is_label_map(true, b"[]"));
+        assert!(!is_label_map(true, br#"[{"id": 1, "name": "person"}]"#));
+        assert!(!is_label_map(true, br#"{"categories": []}"#));
+        assert!(!is_label_map(false, b""));
+        assert!(!is_label_map(false, b"- person\n- bicycle\n"));
+        assert!(!is_label_map(false, b"names: []\n"));
+        assert!(!is_label_map(false, b"version: 2\nservices: {}\n"));
+    }
+}
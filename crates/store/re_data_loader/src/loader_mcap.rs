@@ -1,11 +1,21 @@
 //! Rerun dataloader for MCAP files.
 
-use std::{io::Cursor, sync::mpsc::Sender};
+use std::{
+    io::Cursor,
+    sync::{Arc, mpsc::Sender},
+};
 
 use anyhow::Context as _;
-use re_chunk::RowId;
-use re_log_types::{SetStoreInfo, StoreId, StoreInfo};
-use re_mcap::{LayerRegistry, SelectedLayers};
+use arrow::array::StringArray;
+use re_chunk::{Chunk, EntityPath, RowId, TimePoint};
+use re_log_types::{
+    BlueprintActivationCommand, DefaultTimeline, LogMsg, SetStoreInfo, StoreId, StoreInfo,
+    StoreKind, TimelineName,
+};
+use re_mcap::{
+    LayerRegistry, MessageDeduplicator, MessageLayer as _, PointCloudFieldMapping, SelectedLayers,
+    layers::McapRos2Layer,
+};
 
 use crate::{DataLoader, DataLoaderError, DataLoaderSettings, LoadedData};
 
@@ -23,58 +33,116 @@ const MCAP_LOADER_NAME: &str = "McapLoader";
 /// to an .rrd. Here are a few examples:
 /// - [`re_mcap::layers::McapProtobufLayer`]
 /// - [`re_mcap::layers::McapRawLayer`]
+///
+/// Directories of rosbag2 recordings that are split into multiple MCAP files are loaded as a
+/// single recording, see [`crate::rosbag2`].
+#[derive(Clone)]
 pub struct McapLoader {
     selected_layers: SelectedLayers,
+    ros2_layer: McapRos2Layer,
+    deduplicator: Option<MessageDeduplicator>,
+    default_timeline: Option<TimelineName>,
 }
 
 impl Default for McapLoader {
     fn default() -> Self {
-        Self {
-            selected_layers: SelectedLayers::All,
-        }
+        Self::new(SelectedLayers::All)
     }
 }
 
 impl McapLoader {
     /// Creates a new [`McapLoader`] that only extracts the specified `layers`.
     pub fn new(selected_layers: SelectedLayers) -> Self {
-        Self { selected_layers }
+        Self {
+            selected_layers,
+            ros2_layer: McapRos2Layer::default(),
+            deduplicator: None,
+            default_timeline: None,
+        }
     }
-}
 
-impl DataLoader for McapLoader {
-    fn name(&self) -> crate::DataLoaderName {
-        MCAP_LOADER_NAME.into()
+    /// Uses `point_cloud_fields` to find the colors, intensities, etc. of point clouds.
+    pub fn with_point_cloud_fields(mut self, point_cloud_fields: PointCloudFieldMapping) -> Self {
+        self.ros2_layer = self.ros2_layer.with_point_cloud_fields(point_cloud_fields);
+        self
+    }
+
+    /// De-skews point clouds with per-point timestamps using the poses published on `topic`.
+    ///
+    /// See [`McapRos2Layer::with_deskew_topic`].
+    pub fn with_deskew_topic(mut self, topic: impl Into<String>) -> Self {
+        self.ros2_layer = self.ros2_layer.with_deskew_topic(topic);
+        self
     }
 
+    /// Rectifies raw images with the calibration of their camera.
+    ///
+    /// See [`McapRos2Layer::with_image_rectification`].
+    pub fn with_image_rectification(mut self, rectify_images: bool) -> Self {
+        self.ros2_layer = self.ros2_layer.with_image_rectification(rectify_images);
+        self
+    }
+
+    /// Drops messages that were already loaded from another MCAP file by this loader, e.g. when
+    /// merging the overlapping recordings of redundant recorders.
+    ///
+    /// See [`MessageDeduplicator`].
+    pub fn with_deduplication(mut self, deduplicator: MessageDeduplicator) -> Self {
+        self.deduplicator = Some(deduplicator);
+        self
+    }
+
+    /// Asks the viewer to play back the recording on `timeline`, see [`DefaultTimeline`].
+    ///
+    /// Every message is on the `log_time` and `publish_time` timelines, and most of them also
+    /// on `timestamp`, the time from their header. Simulations often have diverging clocks, in
+    /// which case the default choice of the viewer might not be the most useful one.
+    ///
+    /// Without one, recordings of simulations with a `/clock` topic are played back on `sim_time`.
+    pub fn with_default_timeline(mut self, timeline: impl Into<TimelineName>) -> Self {
+        self.default_timeline = Some(timeline.into());
+        self
+    }
+
+    /// Loads the MCAP files and rosbag2 directories at `paths` into a single recording, in order.
+    ///
+    /// Unlike loading each of them with [`DataLoader::load_from_path`], this only sends the store
+    /// info, the default blueprint and the default timeline once, and uses a single thread.
     #[cfg(not(target_arch = "wasm32"))]
-    fn load_from_path(
+    pub fn load_from_paths(
         &self,
-        settings: &crate::DataLoaderSettings,
-        path: std::path::PathBuf,
-        tx: Sender<crate::LoadedData>,
-    ) -> std::result::Result<(), DataLoaderError> {
-        if path.is_dir()
-            || path
-                .extension()
-                .is_none_or(|ext| !ext.eq_ignore_ascii_case("mcap"))
-        {
-            return Err(DataLoaderError::Incompatible(path)); // simply not interested
+        settings: &DataLoaderSettings,
+        paths: impl IntoIterator<Item = std::path::PathBuf>,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        let mut filepaths = Vec::new();
+        for path in paths {
+            filepaths.extend(mcap_files(path)?);
         }
 
         re_tracing::profile_function!();
 
+        self.spawn_load_mcap(settings, filepaths, tx)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn spawn_load_mcap(
+        &self,
+        settings: &DataLoaderSettings,
+        filepaths: Vec<std::path::PathBuf>,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
         // NOTE(1): `spawn` is fine, this whole function is native-only.
         // NOTE(2): this must spawned on a dedicated thread to avoid a deadlock!
         // `load` will spawn a bunch of loaders on the common rayon thread pool and wait for
         // their response via channels: we cannot be waiting for these responses on the
         // common rayon thread pool.
         let settings = settings.clone();
-        let selected_layers = self.selected_layers.clone();
+        let loader = self.clone();
         std::thread::Builder::new()
-            .name(format!("load_mcap({path:?}"))
+            .name(format!("load_mcap({:?})", filepaths.first()))
             .spawn(
-                move || match load_mcap_mmap(&path, &settings, &tx, selected_layers) {
+                move || match load_mcap_mmap(&filepaths, &settings, &tx, &loader) {
                     Ok(_) => {}
                     Err(err) => {
                         re_log::error!("Failed to load MCAP file: {err}");
@@ -85,6 +153,26 @@ impl DataLoader for McapLoader {
 
         Ok(())
     }
+}
+
+impl DataLoader for McapLoader {
+    fn name(&self) -> crate::DataLoaderName {
+        MCAP_LOADER_NAME.into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        path: std::path::PathBuf,
+        tx: Sender<crate::LoadedData>,
+    ) -> std::result::Result<(), DataLoaderError> {
+        let filepaths = mcap_files(path)?;
+
+        re_tracing::profile_function!();
+
+        self.spawn_load_mcap(settings, filepaths, tx)
+    }
 
     #[cfg(not(target_arch = "wasm32"))]
     fn load_from_file_contents(
@@ -100,27 +188,7 @@ impl DataLoader for McapLoader {
 
         re_tracing::profile_function!();
 
-        let settings = settings.clone();
-        let selected_layers = self.selected_layers.clone();
-
-        // NOTE(1): `spawn` is fine, this whole function is native-only.
-        // NOTE(2): this must spawned on a dedicated thread to avoid a deadlock!
-        // `load` will spawn a bunch of loaders on the common rayon thread pool and wait for
-        // their response via channels: we cannot be waiting for these responses on the
-        // common rayon thread pool.
-        std::thread::Builder::new()
-            .name(format!("load_mcap({filepath:?}"))
-            .spawn(
-                move || match load_mcap_mmap(&filepath, &settings, &tx, selected_layers) {
-                    Ok(_) => {}
-                    Err(err) => {
-                        re_log::error!("Failed to load MCAP file: {err}");
-                    }
-                },
-            )
-            .map_err(|err| DataLoaderError::Other(err.into()))?;
-
-        Ok(())
+        self.spawn_load_mcap(settings, vec![filepath], tx)
     }
 
     #[cfg(target_arch = "wasm32")]
@@ -133,32 +201,66 @@ impl DataLoader for McapLoader {
     ) -> std::result::Result<(), DataLoaderError> {
         let contents = contents.into_owned();
 
-        load_mcap(&contents, settings, &tx, self.selected_layers.clone())
+        load_mcap(&[&contents], settings, &tx, self)
     }
 }
 
+/// The MCAP files at `path`, which is either an MCAP file or the directory of a rosbag2 recording.
+#[cfg(not(target_arch = "wasm32"))]
+fn mcap_files(path: std::path::PathBuf) -> Result<Vec<std::path::PathBuf>, DataLoaderError> {
+    if path.is_dir() {
+        match crate::rosbag2::rosbag2_mcap_files(&path) {
+            Some(filepaths) => Ok(filepaths),
+            None => Err(DataLoaderError::Incompatible(path)), // simply not interested
+        }
+    } else if path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("mcap"))
+    {
+        Ok(vec![path])
+    } else {
+        Err(DataLoaderError::Incompatible(path)) // simply not interested
+    }
+}
+
+/// Loads the sequential MCAP files of a single recording at `filepaths`.
 #[cfg(not(target_arch = "wasm32"))]
 fn load_mcap_mmap(
-    filepath: &std::path::PathBuf,
+    filepaths: &[std::path::PathBuf],
     settings: &DataLoaderSettings,
     tx: &Sender<LoadedData>,
-    selected_layers: SelectedLayers,
+    loader: &McapLoader,
 ) -> std::result::Result<(), DataLoaderError> {
     use std::fs::File;
-    let file = File::open(filepath)?;
-
-    // SAFETY: file-backed memory maps are marked unsafe because of potential UB when using the map and the underlying file is modified.
-    #[allow(unsafe_code)]
-    let mmap = unsafe { memmap2::Mmap::map(&file)? };
 
-    load_mcap(&mmap, settings, tx, selected_layers)
+    let mmaps = filepaths
+        .iter()
+        .map(|filepath| {
+            let file = File::open(filepath)?;
+
+            // SAFETY: file-backed memory maps are marked unsafe because of potential UB when using the map and the underlying file is modified.
+            #[allow(unsafe_code)]
+            unsafe {
+                memmap2::Mmap::map(&file)
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let mcaps = mmaps.iter().map(|mmap| &mmap[..]).collect::<Vec<_>>();
+
+    load_mcap(&mcaps, settings, tx, loader)
 }
 
+/// Loads `mcaps` into a single recording.
+///
+/// Recordings that are split into multiple MCAP files share their topics, so the files are
+/// processed one after the other, resulting in the same entities and continuous timelines.
+///
+/// With a `deduplicator`, each of the files is a separate source of messages.
 fn load_mcap(
-    mcap: &[u8],
+    mcaps: &[&[u8]],
     settings: &DataLoaderSettings,
     tx: &Sender<LoadedData>,
-    selected_layers: SelectedLayers,
+    loader: &McapLoader,
 ) -> Result<(), DataLoaderError> {
     re_tracing::profile_function!();
 
@@ -194,25 +296,122 @@ fn load_mcap(
         }
     };
 
-    let reader = Cursor::new(&mcap);
+    let McapLoader {
+        selected_layers,
+        ros2_layer,
+        deduplicator,
+        default_timeline,
+    } = loader;
+    let registry = LayerRegistry::all().register_layer(ros2_layer.clone());
 
-    let summary = re_mcap::read_summary(reader)?
-        .ok_or_else(|| anyhow::anyhow!("MCAP file does not contain a summary"))?;
+    for (index, mcap) in mcaps.iter().enumerate() {
+        re_tracing::profile_scope!("process-file");
 
-    let registry = LayerRegistry::all();
+        let reader = Cursor::new(mcap);
 
-    // TODO(#10862): Add warning for channel that miss semantic information.
+        let summary = re_mcap::read_summary(reader)?
+            .ok_or_else(|| anyhow::anyhow!("MCAP file does not contain a summary"))?;
 
-    let mut empty = true;
-    for mut layer in registry.layers(selected_layers) {
-        re_tracing::profile_scope!("process-layer");
-        empty = false;
-        layer
-            .process(mcap, &summary, &mut send_chunk)
-            .with_context(|| "processing layers")?;
+        // The blueprint is sent before the data, so that the viewer doesn't create views using its heuristics in the meantime.
+        if index == 0 && selected_layers.contains(&McapRos2Layer::identifier()) {
+            send_default_blueprint(&summary, &store_id, tx)?;
+        }
+
+        // Simulations are played back on their simulated time, unless asked otherwise.
+        if index == 0 {
+            let default_timeline = default_timeline.or_else(|| {
+                (selected_layers.contains(&McapRos2Layer::identifier())
+                    && re_mcap::sim_clock::has_sim_clock(&summary))
+                .then(|| TimelineName::new(re_mcap::SIM_TIME_TIMELINE))
+            });
+            if let Some(default_timeline) = default_timeline {
+                send_chunk(default_timeline_chunk(default_timeline)?);
+            }
+        }
+
+        // TODO(#10862): Add warning for channel that miss semantic information.
+
+        let deduplicator = deduplicator.as_ref().map(MessageDeduplicator::next_source);
+
+        let mut empty = true;
+        for mut layer in registry.layers(selected_layers.clone()) {
+            re_tracing::profile_scope!("process-layer");
+            empty = false;
+            match &deduplicator {
+                Some(deduplicator) => {
+                    layer.process_deduplicated(mcap, &summary, deduplicator, &mut send_chunk)
+                }
+                None => layer.process(mcap, &summary, &mut send_chunk),
+            }
+            .with_context(|| format!("processing layers of file {}", index + 1))?;
+        }
+        if empty {
+            re_log::warn_once!("No layers were selected");
+        }
     }
-    if empty {
-        re_log::warn_once!("No layers were selected");
+
+    if let Some(deduplicator) = deduplicator {
+        re_log::debug!(
+            "Dropped {} duplicate messages so far",
+            deduplicator.num_duplicates()
+        );
+    }
+
+    Ok(())
+}
+
+/// The recording property that asks the viewer to play back the recording on `timeline`.
+fn default_timeline_chunk(timeline: TimelineName) -> Result<Chunk, DataLoaderError> {
+    let values = re_types::AnyValues::new(DefaultTimeline::ARCHETYPE_NAME).with_field(
+        DefaultTimeline::COMPONENT_TIMELINE,
+        Arc::new(StringArray::from(vec![timeline.as_str()])),
+    );
+
+    Ok(
+        Chunk::builder(EntityPath::properties().join(&DefaultTimeline::entity_path()))
+            .with_archetype(RowId::new(), TimePoint::STATIC, &values)
+            .build()?,
+    )
+}
+
+/// Sends the default blueprint for the topics of the MCAP file, if any of them is of a known message type.
+///
+/// It only becomes the active blueprint if there isn't already one for the application.
+fn send_default_blueprint(
+    summary: &::mcap::Summary,
+    store_id: &StoreId,
+    tx: &Sender<LoadedData>,
+) -> Result<(), DataLoaderError> {
+    re_tracing::profile_function!();
+
+    let chunks = re_mcap::blueprint::default_blueprint(summary)?;
+    if chunks.is_empty() {
+        return Ok(());
+    }
+
+    let blueprint_id = StoreId::random(StoreKind::Blueprint, store_id.application_id().clone());
+
+    let msgs =
+        std::iter::once(LoadedData::LogMsg(
+            MCAP_LOADER_NAME.to_owned(),
+            LogMsg::SetStoreInfo(store_info(blueprint_id.clone())),
+        ))
+        .chain(chunks.into_iter().map(|chunk| {
+            LoadedData::Chunk(MCAP_LOADER_NAME.to_owned(), blueprint_id.clone(), chunk)
+        }))
+        .chain(std::iter::once(LoadedData::LogMsg(
+            MCAP_LOADER_NAME.to_owned(),
+            BlueprintActivationCommand::make_default(blueprint_id.clone()).into(),
+        )));
+
+    for msg in msgs {
+        if tx.send(msg).is_err() {
+            // If the other side decided to hang up this is not our problem.
+            re_log::debug_once!(
+                "Failed to send blueprint because the smart channel has been closed unexpectedly."
+            );
+            break;
+        }
     }
 
     Ok(())
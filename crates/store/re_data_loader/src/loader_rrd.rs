@@ -1,4 +1,4 @@
-use re_log_encoding::decoder::Decoder;
+use re_log_encoding::decoder::{DecodeError, Decoder};
 
 #[cfg(not(target_arch = "wasm32"))]
 use crossbeam::channel::Receiver;
@@ -8,6 +8,21 @@ use crate::{DataLoader as _, LoadedData};
 
 // ---
 
+/// Options for decoding `rrd` files, see [`crate::DataLoaderSettings::rrd`].
+#[derive(Debug, Clone, Default)]
+pub struct RrdLoadOptions {
+    /// Key used to decrypt encrypted payloads.
+    ///
+    /// Encrypted payloads cannot be decoded without it.
+    pub decryption_key: Option<re_log_encoding::codec::encryption::EncryptionKey>,
+
+    /// Skip the messages that cannot be decoded, rather than giving up on the file.
+    ///
+    /// Useful for recordings cut short by a crash, which might end with garbage.
+    /// See [`re_log_encoding::decoder::Decoder::with_recovery`].
+    pub recovery: bool,
+}
+
 /// Loads data from any `rrd` file or in-memory contents.
 pub struct RrdLoader;
 
@@ -50,7 +65,9 @@ impl crate::DataLoader for RrdLoader {
                     .with_context(|| format!("Failed to open file {filepath:?}"))?;
                 let file = std::io::BufReader::new(file);
 
-                let decoder = Decoder::new(file)?;
+                let decoder = Decoder::new(file)?
+                    .with_recovery(settings.rrd.recovery)
+                    .with_decryption_key(settings.rrd.decryption_key.clone());
 
                 // NOTE: This is IO bound, it must run on a dedicated thread, not the shared rayon thread pool.
                 std::thread::Builder::new()
@@ -76,12 +93,49 @@ impl crate::DataLoader for RrdLoader {
             }
 
             "rrd" => {
+                // Recordings that are done being written to are memory-mapped, so that messages
+                // are only read from disk as they get decoded, rather than all upfront.
+                match re_log_encoding::decoder::mmap::MmapDecoder::open(&filepath) {
+                    Ok(decoder) if decoder.is_complete() => {
+                        let decoder =
+                            decoder.with_decryption_key(settings.rrd.decryption_key.clone());
+                        // NOTE: This is IO bound, it must run on a dedicated thread, not the shared rayon thread pool.
+                        std::thread::Builder::new()
+                            .name(format!("decode_and_stream({filepath:?})"))
+                            .spawn({
+                                let filepath = filepath.clone();
+                                move || {
+                                    decode_and_stream(
+                                        &filepath, &tx, decoder,
+                                        // Never use import semantics for .rrd files
+                                        None, None,
+                                    );
+                                }
+                            })
+                            .with_context(|| {
+                                format!("Failed to spawn IO thread for {filepath:?}")
+                            })?;
+
+                        return Ok(());
+                    }
+
+                    Ok(_) => {} // still being written to
+                    Err(err) => {
+                        re_log::debug!(
+                            ?filepath,
+                            "Failed to memory-map rrd file, falling back to regular reads: {err}"
+                        );
+                    }
+                }
+
                 // For .rrd files we retry reading despite reaching EOF to support live (writer) streaming.
                 // Decoder will give up when it sees end of file marker (i.e. end-of-stream message header)
                 let retryable_reader = RetryableFileReader::new(&filepath).with_context(|| {
                     format!("failed to create retryable file reader for {filepath:?}")
                 })?;
-                let decoder = Decoder::new(retryable_reader)?;
+                let decoder = Decoder::new(retryable_reader)?
+                    .with_recovery(settings.rrd.recovery)
+                    .with_decryption_key(settings.rrd.decryption_key.clone());
 
                 // NOTE: This is IO bound, it must run on a dedicated thread, not the shared rayon thread pool.
                 std::thread::Builder::new()
@@ -121,7 +175,9 @@ impl crate::DataLoader for RrdLoader {
 
         let contents = std::io::Cursor::new(contents);
         let decoder = match re_log_encoding::decoder::Decoder::new(contents) {
-            Ok(decoder) => decoder,
+            Ok(decoder) => decoder
+                .with_recovery(settings.rrd.recovery)
+                .with_decryption_key(settings.rrd.decryption_key.clone()),
             Err(err) => match err {
                 // simply not interested
                 re_log_encoding::decoder::DecodeError::NotAnRrd
@@ -154,10 +210,10 @@ impl crate::DataLoader for RrdLoader {
     }
 }
 
-fn decode_and_stream<R: std::io::Read>(
+fn decode_and_stream(
     filepath: &std::path::Path,
     tx: &std::sync::mpsc::Sender<crate::LoadedData>,
-    decoder: Decoder<R>,
+    decoder: impl IntoIterator<Item = Result<re_log_types::LogMsg, DecodeError>>,
     forced_application_id: Option<&ApplicationId>,
     forced_recording_id: Option<&String>,
 ) {
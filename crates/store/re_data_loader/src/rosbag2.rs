@@ -0,0 +1,160 @@
+//! Support for rosbag2 recordings that are split into multiple MCAP files.
+//!
+//! rosbag2 writes each recording to a directory, splitting it into sequential
+//! `<name>_<index>.mcap` files once they reach a configured size or duration, and lists them in
+//! a `metadata.yaml` file next to them.
+
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+/// The name of the metadata file of rosbag2 recordings.
+const METADATA_FILE_NAME: &str = "metadata.yaml";
+
+#[derive(Debug, Deserialize)]
+struct Metadata {
+    rosbag2_bagfile_information: BagfileInformation,
+}
+
+#[derive(Debug, Deserialize)]
+struct BagfileInformation {
+    storage_identifier: String,
+
+    #[serde(default)]
+    relative_file_paths: Vec<String>,
+}
+
+/// Check whether the provided path is a directory containing a rosbag2 recording in MCAP files.
+pub fn is_rosbag2_directory(path: impl AsRef<Path>) -> bool {
+    rosbag2_mcap_files(path).is_some()
+}
+
+/// Returns the MCAP files of the rosbag2 recording in the directory at `path`, in order.
+///
+/// The files are listed by the `metadata.yaml` file of the recording. Without one, the MCAP files
+/// of the directory are used if they all follow the `<name>_<index>.mcap` naming convention.
+///
+/// Returns `None` if `path` isn't a directory containing a rosbag2 recording in MCAP files.
+pub fn rosbag2_mcap_files(path: impl AsRef<Path>) -> Option<Vec<PathBuf>> {
+    let path = path.as_ref();
+    if !path.is_dir() {
+        return None;
+    }
+
+    let metadata_path = path.join(METADATA_FILE_NAME);
+    if metadata_path.is_file() {
+        match std::fs::read_to_string(&metadata_path)
+            .map_err(|err| err.to_string())
+            .and_then(|metadata| files_from_metadata(&metadata).map_err(|err| err.to_string()))
+        {
+            Ok(files) => {
+                return files.map(|files| files.into_iter().map(|file| path.join(file)).collect());
+            }
+            Err(err) => {
+                re_log::warn!(?metadata_path, "Failed to read rosbag2 metadata: {err}");
+            }
+        }
+    }
+
+    let file_names = path
+        .read_dir()
+        .ok()?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|file_name| {
+            Path::new(file_name)
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("mcap"))
+        });
+
+    files_from_names(file_names)
+        .map(|files| files.into_iter().map(|file| path.join(file)).collect())
+}
+
+/// The MCAP files listed by the contents of a `metadata.yaml` file, or `None` if the recording
+/// isn't stored in MCAP files.
+fn files_from_metadata(metadata: &str) -> Result<Option<Vec<String>>, serde_yaml::Error> {
+    let BagfileInformation {
+        storage_identifier,
+        relative_file_paths,
+    } = serde_yaml::from_str::<Metadata>(metadata)?.rosbag2_bagfile_information;
+
+    Ok(
+        (storage_identifier == "mcap" && !relative_file_paths.is_empty())
+            .then_some(relative_file_paths),
+    )
+}
+
+/// Sorts MCAP file names by their split index, or returns `None` if they don't all follow the
+/// `<name>_<index>.mcap` naming convention of the same recording.
+fn files_from_names(file_names: impl Iterator<Item = String>) -> Option<Vec<String>> {
+    let mut name = None;
+    let mut files = Vec::new();
+    for file_name in file_names {
+        let stem = Path::new(&file_name).file_stem()?.to_str()?;
+        let (prefix, index) = stem.rsplit_once('_')?;
+        let index = index.parse::<u64>().ok()?;
+        if *name.get_or_insert_with(|| prefix.to_owned()) != prefix {
+            return None;
+        }
+        files.push((index, file_name));
+    }
+
+    if files.is_empty() {
+        return None;
+    }
+
+    files.sort();
+    Some(files.into_iter().map(|(_, file_name)| file_name).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_files_from_metadata() {
+        let metadata = r#"
+rosbag2_bagfile_information:
+  version: 5
+  storage_identifier: mcap
+  duration:
+    nanoseconds: 120000000000
+  relative_file_paths:
+    - drive_0.mcap
+    - drive_1.mcap
+  files:
+    - path: drive_0.mcap
+      message_count: 42
+"#;
+        assert_eq!(
+            files_from_metadata(metadata).unwrap(),
+            Some(vec!["drive_0.mcap".to_owned(), "drive_1.mcap".to_owned()])
+        );
+
+        let sqlite = metadata.replace("storage_identifier: mcap", "storage_identifier: sqlite3");
+        assert_eq!(files_from_metadata(&sqlite).unwrap(), None);
+
+        assert!(files_from_metadata("not: [a, bag").is_err());
+    }
+
+    #[test]
+    fn test_files_from_names() {
+        let names = ["drive_10.mcap", "drive_2.mcap", "drive_0.mcap"].map(str::to_owned);
+        assert_eq!(
+            files_from_names(names.into_iter()),
+            Some(vec![
+                "drive_0.mcap".to_owned(),
+                "drive_2.mcap".to_owned(),
+                "drive_10.mcap".to_owned()
+            ])
+        );
+
+        let mixed = ["drive_0.mcap", "other_1.mcap"].map(str::to_owned);
+        assert_eq!(files_from_names(mixed.into_iter()), None);
+
+        let unnumbered = ["recording.mcap"].map(str::to_owned);
+        assert_eq!(files_from_names(unnumbered.into_iter()), None);
+
+        assert_eq!(files_from_names(std::iter::empty()), None);
+    }
+}
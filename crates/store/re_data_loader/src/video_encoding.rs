@@ -0,0 +1,573 @@
+//! Encoding of raw image sequences to H.264 video streams at ingest, see [`VideoEncoding`].
+
+use re_log_types::ResolvedEntityPathFilter;
+
+/// How sequences of raw images are encoded to [`re_types::archetypes::VideoStream`]s when loaded.
+///
+/// The images of every matching entity are fed to an FFmpeg H.264 encoder, whose output replaces
+/// their buffers: each frame becomes a video sample at the exact same time as the original image,
+/// so scrubbing stays frame-accurate. The original [`re_types::components::ImageFormat`] and all
+/// other components are kept.
+///
+/// Requires FFmpeg to be installed, and is not available on the web.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VideoEncoding {
+    /// The FFmpeg H.264 encoder to use, e.g. `libx264` for software encoding, or a hardware
+    /// encoder such as `h264_nvenc`, `h264_qsv` or `h264_videotoolbox`.
+    pub encoder: String,
+
+    /// Maximum number of frames between two key frames.
+    ///
+    /// Lower values make seeking faster, at the cost of bigger streams.
+    pub keyframe_interval: u32,
+
+    /// Only images of entities matching this filter are encoded. All are if unset.
+    pub entity_filter: Option<ResolvedEntityPathFilter>,
+
+    /// Custom path for the FFmpeg binary, looked up in `PATH` if unset.
+    pub ffmpeg_path: Option<std::path::PathBuf>,
+}
+
+impl VideoEncoding {
+    /// Software encoding with `libx264`, with a key frame every second at 30 FPS.
+    pub fn software() -> Self {
+        Self {
+            encoder: "libx264".to_owned(),
+            keyframe_interval: 30,
+            entity_filter: None,
+            ffmpeg_path: None,
+        }
+    }
+
+    /// Encoding with the given FFmpeg encoder, with a key frame every second at 30 FPS.
+    pub fn with_encoder(encoder: impl Into<String>) -> Self {
+        Self {
+            encoder: encoder.into(),
+            ..Self::software()
+        }
+    }
+}
+
+impl Default for VideoEncoding {
+    fn default() -> Self {
+        Self::software()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use self::native::VideoEncoderStage;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use std::{
+        collections::{HashMap, HashSet, VecDeque},
+        io::{Read as _, Write as _},
+        process::{Child, ChildStdin, Command, Stdio},
+    };
+
+    use re_chunk::{Chunk, ChunkComponents, ChunkId, RowId, TimePoint};
+    use re_log_types::{EntityPath, StoreId};
+    use re_types::{
+        archetypes::{Image, VideoStream},
+        components::{ImageBuffer, ImageFormat, VideoCodec},
+        datatypes::{self, ChannelDatatype, ColorModel, PixelFormat},
+    };
+
+    use super::VideoEncoding;
+    use crate::{DataLoaderName, LoadedData};
+
+    /// Replaces the raw images of the loaded data by video streams, one encoder per entity.
+    pub(crate) struct VideoEncoderStage {
+        encoding: VideoEncoding,
+        encoders: HashMap<(StoreId, EntityPath), StreamEncoder>,
+
+        /// Entities for which the static codec was already sent.
+        with_codec: HashSet<(StoreId, EntityPath)>,
+
+        /// Set once FFmpeg couldn't be started, after which images are left as is.
+        is_unavailable: bool,
+    }
+
+    impl VideoEncoderStage {
+        pub fn new(encoding: VideoEncoding) -> Self {
+            Self {
+                encoding,
+                encoders: Default::default(),
+                with_codec: Default::default(),
+                is_unavailable: false,
+            }
+        }
+
+        /// Returns what should be forwarded in place of `data`.
+        ///
+        /// Encoded samples come out of the encoders with some delay, so they may be part of the
+        /// output of later calls, or of [`Self::finish`].
+        pub fn process(&mut self, data: LoadedData) -> Vec<LoadedData> {
+            let LoadedData::Chunk(loader_name, store_id, chunk) = data else {
+                return vec![data];
+            };
+
+            let Some(frames) = self.frames_to_encode(&store_id, &chunk) else {
+                return vec![LoadedData::Chunk(loader_name, store_id, chunk)];
+            };
+
+            re_tracing::profile_function!();
+
+            let entity_path = chunk.entity_path().clone();
+            let key = (store_id.clone(), entity_path.clone());
+            let mut output = Vec::new();
+
+            for (row, (buffer, format)) in frames.into_iter().enumerate() {
+                let needs_new_encoder = self
+                    .encoders
+                    .get(&key)
+                    .is_none_or(|encoder| encoder.format != format);
+                if needs_new_encoder {
+                    // Resolution or pixel format changed: start a new stream, beginning with a key frame.
+                    if let Some(encoder) = self.encoders.remove(&key) {
+                        output.extend(encoder.finish(&store_id, &entity_path));
+                    }
+
+                    match StreamEncoder::spawn(&self.encoding, loader_name.clone(), format) {
+                        Ok(encoder) => {
+                            self.encoders.insert(key.clone(), encoder);
+                        }
+                        Err(err) => {
+                            re_log::warn_once!(
+                                "Failed to start FFmpeg encoder {:?}, images are kept raw: {err}",
+                                self.encoding.encoder
+                            );
+                            self.is_unavailable = true;
+                            output.push(LoadedData::Chunk(loader_name, store_id, chunk));
+                            return output;
+                        }
+                    }
+
+                    if self.with_codec.insert(key.clone()) {
+                        output.extend(codec_chunk(&loader_name, &store_id, &entity_path));
+                    }
+                }
+
+                #[allow(clippy::unwrap_used)] // inserted above
+                let encoder = self.encoders.get_mut(&key).unwrap();
+                if let Err(err) = encoder.push(&buffer, timepoint_at(&chunk, row)) {
+                    re_log::warn!("Failed to encode the images of {entity_path}: {err}");
+                    if let Some(encoder) = self.encoders.remove(&key) {
+                        encoder.kill();
+                    }
+                    output.push(LoadedData::Chunk(loader_name, store_id, chunk));
+                    return output;
+                }
+            }
+
+            match without_image_buffers(&chunk) {
+                Ok(chunk) => output.push(LoadedData::Chunk(
+                    loader_name.clone(),
+                    store_id.clone(),
+                    chunk,
+                )),
+                Err(err) => {
+                    re_log::warn_once!("Failed to strip the images of {entity_path}: {err}");
+                }
+            }
+
+            if let Some(encoder) = self.encoders.get_mut(&key) {
+                output.extend(encoder.ready_samples(&store_id, &entity_path));
+            }
+
+            output
+        }
+
+        /// Flushes all encoders, returning the remaining samples.
+        pub fn finish(self) -> Vec<LoadedData> {
+            re_tracing::profile_function!();
+
+            self.encoders
+                .into_iter()
+                .filter_map(|((store_id, entity_path), encoder)| {
+                    encoder.finish(&store_id, &entity_path)
+                })
+                .collect()
+        }
+
+        /// The buffers and formats of all rows, if this chunk is made of images that should be encoded.
+        fn frames_to_encode(
+            &self,
+            store_id: &StoreId,
+            chunk: &Chunk,
+        ) -> Option<Vec<(ImageBuffer, datatypes::ImageFormat)>> {
+            let buffer_descr = Image::descriptor_buffer();
+            if self.is_unavailable
+                || !store_id.is_recording()
+                || chunk.is_static()
+                || !chunk.contains_component(&buffer_descr)
+            {
+                return None;
+            }
+            if let Some(entity_filter) = &self.encoding.entity_filter {
+                if !entity_filter.matches(chunk.entity_path()) {
+                    return None;
+                }
+            }
+
+            let format_descr = Image::descriptor_format();
+            (0..chunk.num_rows())
+                .map(|row| {
+                    let buffer = chunk
+                        .component_mono::<ImageBuffer>(&buffer_descr, row)?
+                        .ok()?;
+                    let format = chunk
+                        .component_mono::<ImageFormat>(&format_descr, row)?
+                        .ok()?
+                        .0;
+                    (ffmpeg_pixel_format(&format).is_some() && buffer.len() == format.num_bytes())
+                        .then_some((buffer, format))
+                })
+                .collect()
+        }
+    }
+
+    /// An FFmpeg process encoding the frames of a single entity.
+    struct StreamEncoder {
+        loader_name: DataLoaderName,
+        format: datatypes::ImageFormat,
+        child: Child,
+        stdin: ChildStdin,
+        rx_samples: crossbeam::channel::Receiver<Vec<u8>>,
+        reader: std::thread::JoinHandle<String>,
+
+        /// The times of the frames that were sent, but not received yet.
+        pending: VecDeque<TimePoint>,
+    }
+
+    impl StreamEncoder {
+        fn spawn(
+            encoding: &VideoEncoding,
+            loader_name: DataLoaderName,
+            format: datatypes::ImageFormat,
+        ) -> std::io::Result<Self> {
+            let pixel_format = ffmpeg_pixel_format(&format).ok_or_else(|| {
+                std::io::Error::other(format!("unsupported image format {format:?}"))
+            })?;
+
+            let ffmpeg_path = encoding.ffmpeg_path.as_deref().unwrap_or("ffmpeg".as_ref());
+            let mut child = Command::new(ffmpeg_path)
+                .args(["-hide_banner", "-loglevel", "error"])
+                .args(["-f", "rawvideo", "-pix_fmt", pixel_format])
+                .args(["-s", &format!("{}x{}", format.width, format.height)])
+                .args(["-i", "pipe:0"])
+                // H.264 with 4:2:0 chroma subsampling requires even dimensions.
+                .args(["-vf", "pad=ceil(iw/2)*2:ceil(ih/2)*2"])
+                .args(["-c:v", &encoding.encoder, "-pix_fmt", "yuv420p"])
+                // Samples don't have decoding timestamps, so B-frames aren't supported.
+                .args(["-bf", "0", "-g", &encoding.keyframe_interval.to_string()])
+                // Access unit delimiters tell us where each frame starts.
+                .args(["-bsf:v", "h264_metadata=aud=insert", "-f", "h264", "pipe:1"])
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .spawn()?;
+
+            let (Some(stdin), Some(stdout), Some(stderr)) =
+                (child.stdin.take(), child.stdout.take(), child.stderr.take())
+            else {
+                child.kill().ok();
+                return Err(std::io::Error::other("FFmpeg pipes unreachable"));
+            };
+
+            let (tx_samples, rx_samples) = crossbeam::channel::unbounded();
+
+            // NOTE: Reading must happen concurrently with writing, otherwise both pipes fill up.
+            let reader = std::thread::Builder::new()
+                .name("video_encoder_output".to_owned())
+                .spawn(move || {
+                    read_access_units(stdout, &tx_samples);
+
+                    let mut stderr_str = String::new();
+                    std::io::BufReader::new(stderr)
+                        .read_to_string(&mut stderr_str)
+                        .ok();
+                    stderr_str
+                })?;
+
+            Ok(Self {
+                loader_name,
+                format,
+                child,
+                stdin,
+                rx_samples,
+                reader,
+                pending: VecDeque::new(),
+            })
+        }
+
+        fn push(&mut self, buffer: &[u8], timepoint: TimePoint) -> std::io::Result<()> {
+            self.stdin.write_all(buffer)?;
+            self.pending.push_back(timepoint);
+            Ok(())
+        }
+
+        /// The samples that were encoded so far, if any.
+        fn ready_samples(
+            &mut self,
+            store_id: &StoreId,
+            entity_path: &EntityPath,
+        ) -> Option<LoadedData> {
+            let mut samples = Vec::new();
+            while let Ok(sample) = self.rx_samples.try_recv() {
+                let Some(timepoint) = self.pending.pop_front() else {
+                    re_log::warn_once!("FFmpeg output more video frames than it was given");
+                    break;
+                };
+                samples.push((timepoint, sample));
+            }
+            samples_chunk(&self.loader_name, store_id, entity_path, samples)
+        }
+
+        /// Closes the input, and waits for all remaining samples.
+        fn finish(self, store_id: &StoreId, entity_path: &EntityPath) -> Option<LoadedData> {
+            let Self {
+                loader_name,
+                format: _,
+                mut child,
+                stdin,
+                rx_samples,
+                reader,
+                mut pending,
+            } = self;
+
+            // Closing the input makes FFmpeg flush its last frames and exit.
+            drop(stdin);
+            let stderr_str = reader.join().unwrap_or_default();
+
+            let samples: Vec<_> = rx_samples
+                .into_iter()
+                .map_while(|sample| pending.pop_front().map(|timepoint| (timepoint, sample)))
+                .collect();
+
+            match child.wait() {
+                Ok(status) if status.success() => {
+                    if !pending.is_empty() {
+                        re_log::warn!("FFmpeg dropped {} frames of {entity_path}", pending.len());
+                    }
+                }
+                Ok(status) => {
+                    re_log::warn!(%status, %stderr_str, "Failed to encode the images of {entity_path}");
+                }
+                Err(err) => {
+                    re_log::warn!(%err, "Failed to encode the images of {entity_path}");
+                }
+            }
+
+            samples_chunk(&loader_name, store_id, entity_path, samples)
+        }
+
+        fn kill(mut self) {
+            self.child.kill().ok();
+            self.child.wait().ok();
+        }
+    }
+
+    /// Reads FFmpeg's Annex B output, sending one sample per access unit.
+    fn read_access_units(
+        mut stdout: std::process::ChildStdout,
+        tx_samples: &crossbeam::channel::Sender<Vec<u8>>,
+    ) {
+        let mut buffer = Vec::new();
+        let mut read_buffer = vec![0; 64 * 1024];
+        loop {
+            match stdout.read(&mut read_buffer) {
+                Ok(0) | Err(_) => break,
+                Ok(num_bytes) => {
+                    buffer.extend_from_slice(&read_buffer[..num_bytes]);
+                    for sample in split_access_units(&mut buffer) {
+                        tx_samples.send(sample).ok();
+                    }
+                }
+            }
+        }
+
+        if !buffer.is_empty() {
+            tx_samples.send(buffer).ok();
+        }
+    }
+
+    /// Removes all complete access units from the start of `buffer`.
+    ///
+    /// An access unit is complete once the delimiter of the next one was received.
+    pub(super) fn split_access_units(buffer: &mut Vec<u8>) -> Vec<Vec<u8>> {
+        const NAL_TYPE_ACCESS_UNIT_DELIMITER: u8 = 9;
+
+        let starts = (0..buffer.len().saturating_sub(3))
+            .filter(|&i| {
+                buffer[i..i + 3] == [0, 0, 1]
+                    && buffer[i + 3] & 0x1F == NAL_TYPE_ACCESS_UNIT_DELIMITER
+            })
+            // Include the leading zero of 4 byte start codes.
+            .map(|i| {
+                if i > 0 && buffer[i - 1] == 0 {
+                    i - 1
+                } else {
+                    i
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let access_units = starts
+            .windows(2)
+            .map(|range| buffer[range[0]..range[1]].to_vec())
+            .collect();
+        if let Some(&last_start) = starts.last() {
+            buffer.drain(..last_start);
+        }
+
+        access_units
+    }
+
+    /// The FFmpeg name of the pixel format of these images, if supported.
+    fn ffmpeg_pixel_format(format: &datatypes::ImageFormat) -> Option<&'static str> {
+        if let Some(pixel_format) = format.pixel_format {
+            return match pixel_format {
+                PixelFormat::NV12 => Some("nv12"),
+                PixelFormat::YUY2 => Some("yuyv422"),
+                PixelFormat::Y_U_V12_LimitedRange => Some("yuv420p"),
+                PixelFormat::Y_U_V16_LimitedRange => Some("yuv422p"),
+                PixelFormat::Y_U_V24_LimitedRange => Some("yuv444p"),
+                PixelFormat::Y_U_V12_FullRange
+                | PixelFormat::Y_U_V16_FullRange
+                | PixelFormat::Y_U_V24_FullRange
+                | PixelFormat::Y8_FullRange
+                | PixelFormat::Y8_LimitedRange => None,
+            };
+        }
+
+        match (format.channel_datatype?, format.color_model?) {
+            (ChannelDatatype::U8, ColorModel::L) => Some("gray"),
+            (ChannelDatatype::U8, ColorModel::RGB) => Some("rgb24"),
+            (ChannelDatatype::U8, ColorModel::RGBA) => Some("rgba"),
+            (ChannelDatatype::U8, ColorModel::BGR) => Some("bgr24"),
+            (ChannelDatatype::U8, ColorModel::BGRA) => Some("bgra"),
+            _ => None,
+        }
+    }
+
+    fn timepoint_at(chunk: &Chunk, row: usize) -> TimePoint {
+        chunk
+            .timelines()
+            .values()
+            .fold(TimePoint::default(), |timepoint, time_column| {
+                timepoint.with(*time_column.timeline(), time_column.times_raw()[row])
+            })
+    }
+
+    /// The chunk without its image buffers, keeping the image formats and all other components.
+    fn without_image_buffers(chunk: &Chunk) -> re_chunk::ChunkResult<Chunk> {
+        let buffer_descr = Image::descriptor_buffer();
+        let components = ChunkComponents(
+            chunk
+                .components()
+                .iter()
+                .filter(|(descr, _)| **descr != buffer_descr)
+                .map(|(descr, list_array)| (descr.clone(), list_array.clone()))
+                .collect(),
+        );
+
+        Chunk::new(
+            ChunkId::new(),
+            chunk.entity_path().clone(),
+            None,
+            chunk.row_ids_array().clone(),
+            chunk.timelines().clone(),
+            components,
+        )
+    }
+
+    fn codec_chunk(
+        loader_name: &DataLoaderName,
+        store_id: &StoreId,
+        entity_path: &EntityPath,
+    ) -> Option<LoadedData> {
+        let chunk = Chunk::builder(entity_path.clone())
+            .with_archetype(
+                RowId::new(),
+                TimePoint::default(),
+                &VideoStream::update_fields().with_codec(VideoCodec::H264),
+            )
+            .build();
+
+        match chunk {
+            Ok(chunk) => Some(LoadedData::Chunk(
+                loader_name.clone(),
+                store_id.clone(),
+                chunk,
+            )),
+            Err(err) => {
+                re_log::warn_once!("Failed to log the video codec of {entity_path}: {err}");
+                None
+            }
+        }
+    }
+
+    fn samples_chunk(
+        loader_name: &DataLoaderName,
+        store_id: &StoreId,
+        entity_path: &EntityPath,
+        samples: Vec<(TimePoint, Vec<u8>)>,
+    ) -> Option<LoadedData> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let chunk = samples
+            .into_iter()
+            .fold(
+                Chunk::builder(entity_path.clone()),
+                |builder, (timepoint, sample)| {
+                    builder.with_archetype(
+                        RowId::new(),
+                        timepoint,
+                        &VideoStream::update_fields().with_sample(sample),
+                    )
+                },
+            )
+            .build();
+
+        match chunk {
+            Ok(chunk) => Some(LoadedData::Chunk(
+                loader_name.clone(),
+                store_id.clone(),
+                chunk,
+            )),
+            Err(err) => {
+                re_log::warn_once!("Failed to log the video samples of {entity_path}: {err}");
+                None
+            }
+        }
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::native::split_access_units;
+
+    #[test]
+    fn splits_access_units() {
+        let aud = [0, 0, 0, 1, 9, 0xF0];
+        let idr = [0, 0, 1, 0x65, 1, 2, 3];
+        let slice = [0, 0, 1, 0x41, 4, 5];
+
+        let mut buffer = [&aud[..], &idr, &aud, &slice, &aud].concat();
+        let access_units = split_access_units(&mut buffer);
+
+        assert_eq!(
+            access_units,
+            vec![[&aud[..], &idr].concat(), [&aud[..], &slice].concat()]
+        );
+        // The last one might not be complete yet.
+        assert_eq!(buffer, aud);
+
+        let mut buffer = [&aud[..], &slice].concat();
+        assert!(split_access_units(&mut buffer).is_empty());
+        assert_eq!(buffer, [&aud[..], &slice].concat());
+    }
+}
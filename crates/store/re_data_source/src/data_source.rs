@@ -158,12 +158,15 @@ impl DataSource {
     /// Will do minimal checks (e.g. that the file exists), for synchronous errors,
     /// but the loading is done in a background task.
     ///
+    /// `rrd_options` are used for decoding `rrd` files and streams read from disk or stdin.
+    ///
     /// `on_cmd` is used to respond to UI commands.
     ///
     /// `on_msg` can be used to wake up the UI thread on Wasm.
     pub fn stream(
         self,
         connection_registry: &ConnectionRegistryHandle,
+        rrd_options: &re_data_loader::RrdLoadOptions,
         on_cmd: Box<dyn Fn(DataSourceCommand) + Send + Sync>,
         on_msg: Option<Box<dyn Fn() + Send + Sync>>,
     ) -> anyhow::Result<StreamSource> {
@@ -190,6 +193,7 @@ impl DataSource {
                 let settings = re_data_loader::DataLoaderSettings {
                     opened_store_id: file_source.recommended_store_id().cloned(),
                     force_store_info: file_source.force_store_info(),
+                    rrd: rrd_options.clone(),
                     ..re_data_loader::DataLoaderSettings::recommended(shared_recording_id)
                 };
                 re_data_loader::load_from_path(&settings, file_source, &path, &tx)
@@ -217,6 +221,7 @@ impl DataSource {
                 let settings = re_data_loader::DataLoaderSettings {
                     opened_store_id: file_source.recommended_store_id().cloned(),
                     force_store_info: file_source.force_store_info(),
+                    rrd: rrd_options.clone(),
                     ..re_data_loader::DataLoaderSettings::recommended(shared_recording_id)
                 };
                 re_data_loader::load_from_file_contents(
@@ -241,7 +246,8 @@ impl DataSource {
                     SmartChannelSource::Stdin,
                 );
 
-                crate::load_stdin::load_stdin(tx).with_context(|| "stdin".to_owned())?;
+                crate::load_stdin::load_stdin(tx, rrd_options)
+                    .with_context(|| "stdin".to_owned())?;
 
                 if let Some(on_msg) = on_msg {
                     on_msg();
@@ -312,7 +318,9 @@ impl DataSource {
                 uri: re_uri::RedapUri::Proxy(uri),
                 ..
             } => Ok(StreamSource::LogMessages(message_proxy::stream(
-                uri, on_msg,
+                uri,
+                connection_registry.proxy_token(),
+                on_msg,
             ))),
         }
     }
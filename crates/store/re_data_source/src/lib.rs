@@ -12,6 +12,7 @@ mod data_source;
 mod load_stdin;
 
 pub use self::data_source::{DataSource, DataSourceCommand, StreamSource};
+pub use re_data_loader::RrdLoadOptions;
 
 // ----------------------------------------------------------------------------
 
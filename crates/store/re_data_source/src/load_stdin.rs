@@ -5,9 +5,14 @@ use re_smart_channel::Sender;
 ///
 /// This fails synchronously iff the standard input stream could not be opened, otherwise errors
 /// are handled asynchronously (as in: they're logged).
-pub fn load_stdin(tx: Sender<LogMsg>) -> anyhow::Result<()> {
+pub fn load_stdin(
+    tx: Sender<LogMsg>,
+    rrd_options: &re_data_loader::RrdLoadOptions,
+) -> anyhow::Result<()> {
     let stdin = std::io::BufReader::new(std::io::stdin());
-    let decoder = re_log_encoding::decoder::Decoder::new_concatenated(stdin)?;
+    let decoder = re_log_encoding::decoder::Decoder::new_concatenated(stdin)?
+        .with_recovery(rrd_options.recovery)
+        .with_decryption_key(rrd_options.decryption_key.clone());
 
     rayon::spawn(move || {
         re_tracing::profile_scope!("stdin");
@@ -0,0 +1,140 @@
+//! Reduction of consecutive rows into time buckets, see [`QueryExpression::aggregation`].
+//!
+//! [`QueryExpression::aggregation`]: re_chunk_store::QueryExpression::aggregation
+
+use std::sync::Arc;
+
+use arrow::{
+    array::{
+        Array as _, ArrayRef as ArrowArrayRef, AsArray as _, Float64Array as ArrowFloat64Array,
+        ListArray as ArrowListArray,
+    },
+    buffer::{OffsetBuffer as ArrowOffsetBuffer, ScalarBuffer as ArrowScalarBuffer},
+    datatypes::DataType as ArrowDataType,
+};
+
+use re_chunk::TimelineName;
+use re_chunk_store::{AggregationOperator, IndexValue};
+use re_sorbet::ColumnDescriptor;
+
+// ---
+
+/// Reduces all the `rows` of a bucket into a single one.
+///
+/// Every row, as well as the returned one, follows the layout of `selected_contents`.
+pub(crate) fn aggregate_rows(
+    selected_contents: &[(usize, ColumnDescriptor)],
+    filtered_index: TimelineName,
+    op: AggregationOperator,
+    bucket_start: IndexValue,
+    rows: &[Vec<ArrowArrayRef>],
+) -> Vec<ArrowArrayRef> {
+    re_tracing::profile_function!();
+
+    selected_contents
+        .iter()
+        .enumerate()
+        .map(|(column_idx, (_view_idx, column))| {
+            let cells = rows.iter().filter_map(|row| row.get(column_idx));
+
+            match column {
+                ColumnDescriptor::Time(descr) if descr.timeline().name() == &filtered_index => {
+                    descr
+                        .timeline()
+                        .typ()
+                        .make_arrow_array(ArrowScalarBuffer::from(vec![bucket_start.as_i64()]))
+                }
+
+                ColumnDescriptor::Component(_) if op != AggregationOperator::Last => {
+                    let cells = cells
+                        .filter_map(|cell| cell.as_list_opt::<i32>())
+                        .collect::<Vec<_>>();
+
+                    reduce_list_cells(op, &cells).unwrap_or_else(|| {
+                        last_non_null_cell(rows, column_idx, &column.arrow_datatype())
+                    })
+                }
+
+                ColumnDescriptor::RowId(_)
+                | ColumnDescriptor::Time(_)
+                | ColumnDescriptor::Component(_) => {
+                    last_non_null_cell(rows, column_idx, &column.arrow_datatype())
+                }
+            }
+        })
+        .collect()
+}
+
+/// Returns the last cell of the given column that isn't null, or a null cell if there is none.
+fn last_non_null_cell(
+    rows: &[Vec<ArrowArrayRef>],
+    column_idx: usize,
+    datatype: &ArrowDataType,
+) -> ArrowArrayRef {
+    rows.iter()
+        .rev()
+        .filter_map(|row| row.get(column_idx))
+        .find(|cell| cell.null_count() < cell.len())
+        .cloned()
+        .unwrap_or_else(|| arrow::array::new_null_array(datatype, 1))
+}
+
+/// Reduces single-row list cells instance-wise.
+///
+/// Returns `None` if the values aren't numeric, in which case the caller should fall back to
+/// [`AggregationOperator::Last`].
+fn reduce_list_cells(op: AggregationOperator, cells: &[&ArrowListArray]) -> Option<ArrowArrayRef> {
+    let ArrowDataType::List(field) = cells.first()?.data_type() else {
+        return None;
+    };
+    if !field.data_type().is_numeric() {
+        return None;
+    }
+
+    let instances = cells
+        .iter()
+        .filter(|cell| cell.is_valid(0))
+        .map(|cell| arrow::compute::cast(&cell.value(0), &ArrowDataType::Float64).ok())
+        .collect::<Option<Vec<_>>>()?;
+    if instances.is_empty() {
+        return None;
+    }
+
+    let num_instances = instances
+        .iter()
+        .map(|values| values.len())
+        .max()
+        .unwrap_or(0);
+
+    let reduced: ArrowFloat64Array = (0..num_instances)
+        .map(|instance_idx| {
+            let values = instances
+                .iter()
+                .map(|values| values.as_primitive::<arrow::datatypes::Float64Type>())
+                .filter(|values| instance_idx < values.len() && values.is_valid(instance_idx))
+                .map(|values| values.value(instance_idx));
+
+            match op {
+                AggregationOperator::Min => values.reduce(f64::min),
+                AggregationOperator::Max => values.reduce(f64::max),
+                AggregationOperator::Mean => {
+                    let (sum, count) =
+                        values.fold((0.0, 0_u64), |(sum, count), value| (sum + value, count + 1));
+                    (count > 0).then(|| sum / count as f64)
+                }
+                AggregationOperator::Last => values.last(),
+            }
+        })
+        .collect();
+
+    let values = arrow::compute::cast(&reduced, field.data_type()).ok()?;
+
+    ArrowListArray::try_new(
+        Arc::clone(field),
+        ArrowOffsetBuffer::from_lengths([values.len()]),
+        values,
+        None,
+    )
+    .ok()
+    .map(|list_array| Arc::new(list_array) as ArrowArrayRef)
+}
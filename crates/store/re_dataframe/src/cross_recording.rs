@@ -0,0 +1,301 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow::{
+    array::{
+        Array as _, ArrayRef as ArrowArrayRef, RecordBatch as ArrowRecordBatch,
+        StringArray as ArrowStringArray,
+    },
+    datatypes::{
+        DataType as ArrowDataType, Field as ArrowField, Fields as ArrowFields,
+        Schema as ArrowSchema, SchemaRef as ArrowSchemaRef,
+    },
+};
+use itertools::Itertools as _;
+
+use re_chunk_store::QueryExpression;
+use re_log_types::StoreId;
+use re_query::StorageEngineLike;
+use re_sorbet::ColumnSelector;
+
+use crate::{QueryEngine, QueryHandle};
+
+// ---
+
+/// The name of the extra column that identifies the recording each row comes from.
+///
+/// See [`CrossRecordingQueryHandle`].
+pub const RECORDING_ID_COLUMN_NAME: &str = "rerun_recording_id";
+
+/// A handle to a dataframe query spanning several recordings at once, ready to be executed.
+///
+/// The same [`QueryExpression`] runs against every recording, and the results are merged into a
+/// single stream of rows, sorted on [`QueryExpression::filtered_index`].
+/// Recordings are aligned on raw index values, so this is mostly useful with absolute timestamp
+/// timelines (e.g. `log_time`), e.g. to compare several runs of the same robot mission.
+///
+/// Every row starts with a [`RECORDING_ID_COLUMN_NAME`] column, followed by the selected columns.
+/// If no selection was specified, the union of the view contents of all recordings is returned.
+///
+/// Cheaply created via [`QueryEngine::query_across`].
+pub struct CrossRecordingQueryHandle<E: StorageEngineLike> {
+    /// The per-recording queries, all sharing the exact same selection.
+    handles: Vec<(StoreId, QueryHandle<E>)>,
+
+    /// The Arrow schema of the merged results. Lazily computed.
+    schema: OnceLock<ArrowSchemaRef>,
+}
+
+impl<E: StorageEngineLike + Clone> QueryEngine<E> {
+    /// Starts a new query spanning all the given recordings by instantiating a
+    /// [`CrossRecordingQueryHandle`].
+    pub fn query_across(
+        engines: impl IntoIterator<Item = (StoreId, Self)>,
+        query: QueryExpression,
+    ) -> CrossRecordingQueryHandle<E> {
+        re_tracing::profile_function!();
+
+        let engines = engines.into_iter().collect_vec();
+
+        // All recordings must yield the same columns, in the same order.
+        let selection = query.selection.clone().unwrap_or_else(|| {
+            engines
+                .iter()
+                .flat_map(|(_store_id, engine)| {
+                    engine.schema_for_query(&query).indices_and_components()
+                })
+                .map(ColumnSelector::from)
+                .unique()
+                .sorted_by_key(|selector| match selector {
+                    ColumnSelector::RowId => 0,
+                    ColumnSelector::Time(_) => 1,
+                    ColumnSelector::Component(_) => 2,
+                })
+                .collect()
+        });
+
+        let handles = engines
+            .into_iter()
+            .map(|(store_id, engine)| {
+                let query = QueryExpression {
+                    selection: Some(selection.clone()),
+                    ..query.clone()
+                };
+                (store_id, engine.query(query))
+            })
+            .collect();
+
+        CrossRecordingQueryHandle {
+            handles,
+            schema: OnceLock::new(),
+        }
+    }
+}
+
+impl<E: StorageEngineLike> CrossRecordingQueryHandle<E> {
+    /// All results returned by this handle will strictly follow this Arrow schema.
+    ///
+    /// Columns that do not yield any data will still be present in the results, filled with null values.
+    pub fn schema(&self) -> &ArrowSchemaRef {
+        self.schema.get_or_init(|| {
+            let recording_id_field =
+                ArrowField::new(RECORDING_ID_COLUMN_NAME, ArrowDataType::Utf8, false);
+
+            let schemas = self
+                .handles
+                .iter()
+                .map(|(_store_id, handle)| handle.schema())
+                .collect_vec();
+
+            // Columns missing from some of the recordings are null-typed there: pick the actual
+            // datatype from whichever recording has it, if any.
+            let fields = schemas.first().into_iter().flat_map(|first_schema| {
+                first_schema
+                    .fields()
+                    .iter()
+                    .enumerate()
+                    .map(|(column_idx, first_field)| {
+                        schemas
+                            .iter()
+                            .map(|schema| schema.field(column_idx))
+                            .find(|field| field.data_type() != &ArrowDataType::Null)
+                            .unwrap_or(first_field)
+                            .clone()
+                            // Any recording might be missing any column.
+                            .with_nullable(true)
+                    })
+            });
+
+            Arc::new(ArrowSchema::new(
+                std::iter::once(recording_id_field)
+                    .chain(fields)
+                    .collect::<ArrowFields>(),
+            ))
+        })
+    }
+
+    /// How many rows of data will be returned, across all recordings?
+    pub fn num_rows(&self) -> u64 {
+        self.handles
+            .iter()
+            .map(|(_store_id, handle)| handle.num_rows())
+            .sum()
+    }
+
+    /// Returns the next row's worth of data, from whichever recording comes next on the index.
+    ///
+    /// Ties are broken using the order in which the recordings were specified.
+    ///
+    /// The returned vector of Arrow arrays strictly follows the schema specified by [`Self::schema`].
+    /// Cells whose datatype doesn't match that schema (i.e. the column is missing from the
+    /// recording, or has a different datatype there) are returned as nulls.
+    pub fn next_row(&self) -> Option<Vec<ArrowArrayRef>> {
+        let schema = self.schema().clone();
+
+        let (store_id, handle) = self
+            .handles
+            .iter()
+            .filter_map(|(store_id, handle)| {
+                handle
+                    .next_index_value()
+                    .map(|index_value| (index_value, store_id, handle))
+            })
+            .min_by_key(|(index_value, _store_id, _handle)| *index_value)
+            .map(|(_index_value, store_id, handle)| (store_id, handle))?;
+
+        let row = handle.next_row()?;
+
+        let recording_id: ArrowArrayRef = Arc::new(ArrowStringArray::from(vec![
+            store_id.recording_id().as_str(),
+        ]));
+
+        Some(
+            std::iter::once(recording_id)
+                .chain(
+                    row.into_iter()
+                        .zip(schema.fields().iter().skip(1))
+                        .map(|(array, field)| {
+                            if array.data_type() == field.data_type() {
+                                array
+                            } else {
+                                arrow::array::new_null_array(field.data_type(), array.len())
+                            }
+                        }),
+                )
+                .collect(),
+        )
+    }
+
+    /// Calls [`Self::next_row`] and wraps the result in a [`ArrowRecordBatch`].
+    pub fn next_row_batch(&self) -> Option<ArrowRecordBatch> {
+        let row = self.next_row()?;
+        match ArrowRecordBatch::try_new(self.schema().clone(), row) {
+            Ok(batch) => Some(batch),
+            Err(err) => {
+                if cfg!(debug_assertions) {
+                    panic!("Failed to create record batch: {err}");
+                } else {
+                    re_log::error_once!("Failed to create record batch: {err}");
+                    None
+                }
+            }
+        }
+    }
+
+    /// Returns an iterator backed by [`Self::next_row_batch`].
+    pub fn batch_iter(&self) -> impl Iterator<Item = ArrowRecordBatch> + '_ {
+        std::iter::from_fn(move || self.next_row_batch())
+    }
+
+    /// Returns an iterator backed by [`Self::next_row_batch`].
+    pub fn into_batch_iter(self) -> impl Iterator<Item = ArrowRecordBatch> {
+        std::iter::from_fn(move || self.next_row_batch())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::{
+        array::{AsArray as _, Int64Array},
+        compute::concat_batches,
+    };
+
+    use re_chunk::{Chunk, RowId};
+    use re_chunk_store::{ChunkStore, ChunkStoreConfig, ChunkStoreHandle};
+    use re_log_types::{
+        EntityPath, StoreKind, TimeInt, TimelineName, build_frame_nr,
+        example_components::{MyColor, MyPoints},
+    };
+
+    use super::*;
+
+    fn create_store(
+        frames: &[u32],
+    ) -> anyhow::Result<(StoreId, QueryEngine<re_query::StorageEngine>)> {
+        let store_id = StoreId::random(StoreKind::Recording, "test_app");
+        let mut store = ChunkStore::new(store_id.clone(), ChunkStoreConfig::COMPACTION_DISABLED);
+
+        let mut builder = Chunk::builder(EntityPath::from("/robot"));
+        for &frame in frames {
+            builder = builder.with_component_batch(
+                RowId::new(),
+                [build_frame_nr(TimeInt::new_temporal(frame.into()))],
+                (MyPoints::descriptor_colors(), &MyColor::from_iter([frame])),
+            );
+        }
+        store.insert_chunk(&Arc::new(builder.build()?))?;
+
+        Ok((
+            store_id,
+            QueryEngine::from_store(ChunkStoreHandle::new(store)),
+        ))
+    }
+
+    #[test]
+    fn merges_recordings_on_index() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        let (store_id1, engine1) = create_store(&[10, 30, 50])?;
+        let (store_id2, engine2) = create_store(&[20, 30, 40])?;
+
+        let query = QueryExpression {
+            filtered_index: Some(TimelineName::new("frame_nr")),
+            ..Default::default()
+        };
+
+        let query_handle = QueryEngine::query_across(
+            [(store_id1.clone(), engine1), (store_id2.clone(), engine2)],
+            query,
+        );
+        assert_eq!(6, query_handle.num_rows());
+
+        let dataframe = concat_batches(
+            query_handle.schema(),
+            &query_handle.batch_iter().collect_vec(),
+        )?;
+        eprintln!("{}", re_format_arrow::format_record_batch(&dataframe));
+
+        assert_eq!(RECORDING_ID_COLUMN_NAME, dataframe.schema().field(0).name());
+
+        let recording_ids = dataframe
+            .column(0)
+            .as_string::<i32>()
+            .iter()
+            .flatten()
+            .collect_vec();
+        let (id1, id2) = (
+            store_id1.recording_id().as_str(),
+            store_id2.recording_id().as_str(),
+        );
+        similar_asserts::assert_eq!(vec![id1, id2, id1, id2, id2, id1], recording_ids);
+
+        let frames = dataframe
+            .column(1)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .map(|frames| frames.values().to_vec())
+            .unwrap_or_default();
+        similar_asserts::assert_eq!(vec![10, 20, 30, 30, 40, 50], frames);
+
+        Ok(())
+    }
+}
@@ -1,15 +1,18 @@
 //! The Rerun public data APIs. Get dataframes back from your Rerun datastore.
 
+mod aggregation;
+mod cross_recording;
 mod engine;
 mod query;
 
+pub use self::cross_recording::{CrossRecordingQueryHandle, RECORDING_ID_COLUMN_NAME};
 pub use self::engine::QueryEngine;
 pub use self::query::QueryHandle;
 
 #[doc(no_inline)]
 pub use self::external::re_chunk_store::{
-    ChunkStoreConfig, ChunkStoreHandle, Index, IndexRange, IndexValue, QueryExpression,
-    SparseFillStrategy, ViewContentsSelector,
+    AggregationOperator, ChunkStoreConfig, ChunkStoreHandle, Index, IndexRange, IndexValue,
+    QueryExpression, SparseFillStrategy, TemporalAggregation, ViewContentsSelector,
 };
 #[doc(no_inline)]
 pub use self::external::re_log_types::{
@@ -1,5 +1,6 @@
 use std::{
     collections::BTreeSet,
+    ops::Range,
     sync::{
         OnceLock,
         atomic::{AtomicU64, Ordering},
@@ -8,8 +9,9 @@ use std::{
 
 use arrow::{
     array::{
-        ArrayRef as ArrowArrayRef, BooleanArray as ArrowBooleanArray,
-        PrimitiveArray as ArrowPrimitiveArray, RecordBatch as ArrowRecordBatch,
+        Array as _, ArrayRef as ArrowArrayRef, BooleanArray as ArrowBooleanArray,
+        ListArray as ArrowListArray, PrimitiveArray as ArrowPrimitiveArray,
+        RecordBatch as ArrowRecordBatch,
     },
     buffer::ScalarBuffer as ArrowScalarBuffer,
     datatypes::{
@@ -26,13 +28,15 @@ use re_chunk::{
     external::arrow::array::ArrayRef,
 };
 use re_chunk_store::{
-    ChunkStore, ColumnDescriptor, ComponentColumnDescriptor, Index, IndexColumnDescriptor,
+    ChunkStore, ColumnDescriptor, ComparisonOperator, ComponentColumnDescriptor,
+    ComponentPredicate, ComponentValueFilter, ComponentValueSet, Index, IndexColumnDescriptor,
     IndexValue, QueryExpression, SparseFillStrategy,
 };
 use re_log_types::AbsoluteTimeRange;
 use re_query::{QueryCache, StorageEngineLike};
 use re_sorbet::{
-    ChunkColumnDescriptors, ColumnSelector, RowIdColumnDescriptor, TimeColumnSelector,
+    ChunkColumnDescriptors, ColumnSelector, ComponentColumnSelector, RowIdColumnDescriptor,
+    TimeColumnSelector,
 };
 use re_types_core::{ComponentDescriptor, Loggable as _, archetypes, arrow_helpers::as_array_ref};
 
@@ -144,6 +148,18 @@ struct QueryHandleState {
     ///
     /// See also [`QueryHandleState::cur_row`].
     unique_index_values: Vec<IndexValue>,
+
+    /// The start of every bucket, along with the range of `unique_index_values` that it covers.
+    ///
+    /// Only set when aggregating, see [`QueryExpression::aggregation`].
+    ///
+    /// `unique_index_values`: [`QueryHandleState::unique_index_values`]
+    buckets: Option<Vec<(IndexValue, Range<usize>)>>,
+
+    /// Tracks the current bucket index, when aggregating. For [`QueryHandle::next_row`].
+    ///
+    /// See also [`QueryHandleState::buckets`].
+    cur_bucket: AtomicU64,
 }
 
 impl<E: StorageEngineLike> QueryHandle<E> {
@@ -302,6 +318,39 @@ impl<E: StorageEngineLike> QueryHandle<E> {
         } else {
             re_tracing::profile_scope!("index_values");
 
+            let matching_index_values = {
+                let mut matching_index_values =
+                    self.query.filtered_component_values.as_ref().map(|filter| {
+                        self.fetch_index_values_matching_component_values(
+                            store,
+                            filtered_index,
+                            &view_contents,
+                            &view_chunks,
+                            filter,
+                        )
+                    });
+
+                for predicate in &self.query.filtered_component_predicates {
+                    let index_values = self.fetch_index_values_matching_predicate(
+                        store,
+                        filtered_index,
+                        &view_contents,
+                        &view_chunks,
+                        predicate,
+                    );
+
+                    matching_index_values = Some(match matching_index_values {
+                        Some(matching_index_values) => matching_index_values
+                            .intersection(&index_values)
+                            .copied()
+                            .collect(),
+                        None => index_values,
+                    });
+                }
+
+                matching_index_values
+            };
+
             let mut view_chunks = view_chunks.iter();
             let view_chunks = if let Some(view_pov_chunks_idx) = view_pov_chunks_idx {
                 Either::Left(view_chunks.nth(view_pov_chunks_idx).into_iter())
@@ -325,6 +374,34 @@ impl<E: StorageEngineLike> QueryHandle<E> {
                 all_unique_index_values.retain(|time| filtered_index_values.contains(time));
             }
 
+            if let Some(matching_index_values) = matching_index_values.as_ref() {
+                all_unique_index_values.retain(|time| matching_index_values.contains(time));
+            }
+
+            if let Some(join_on_entity) = self.query.join_on_entity.as_ref() {
+                let join_index_values: BTreeSet<TimeInt> = view_contents
+                    .iter()
+                    .zip(&view_chunks)
+                    .filter(|(column, _chunks)| match column {
+                        ColumnDescriptor::Component(column) => {
+                            &column.entity_path == join_on_entity
+                        }
+                        ColumnDescriptor::RowId(_) | ColumnDescriptor::Time(_) => false,
+                    })
+                    .flat_map(|(_column, chunks)| {
+                        chunks.iter().filter_map(|(_cursor, chunk)| {
+                            chunk
+                                .timelines()
+                                .get(&filtered_index)
+                                .map(|time_column| time_column.times())
+                        })
+                    })
+                    .flatten()
+                    .collect();
+
+                all_unique_index_values.retain(|time| join_index_values.contains(time));
+            }
+
             all_unique_index_values
                 .into_iter()
                 .filter(|index_value| !index_value.is_static())
@@ -362,6 +439,25 @@ impl<E: StorageEngineLike> QueryHandle<E> {
                 .collect_vec()
         };
 
+        // 7. Group the index values into buckets, if aggregating.
+        let buckets = self
+            .query
+            .aggregation
+            .filter(|_| self.query.filtered_index.is_some())
+            .map(|aggregation| {
+                let mut buckets: Vec<(IndexValue, Range<usize>)> = Vec::new();
+                for (row_idx, index_value) in unique_index_values.iter().enumerate() {
+                    let bucket_start = aggregation.bucket_start(*index_value);
+                    match buckets.last_mut() {
+                        Some((last_bucket_start, rows)) if *last_bucket_start == bucket_start => {
+                            rows.end = row_idx + 1;
+                        }
+                        _ => buckets.push((bucket_start, row_idx..row_idx + 1)),
+                    }
+                }
+                buckets
+            });
+
         for (_, descr) in &selected_contents {
             descr.sanity_check();
         }
@@ -375,6 +471,8 @@ impl<E: StorageEngineLike> QueryHandle<E> {
             view_chunks,
             cur_row: AtomicU64::new(0),
             unique_index_values,
+            buckets,
+            cur_bucket: AtomicU64::new(0),
         }
     }
 
@@ -501,6 +599,146 @@ impl<E: StorageEngineLike> QueryHandle<E> {
         (view_pov_chunks_idx, view_chunks)
     }
 
+    /// Returns all the index values at which the column selected by `filter` contains at least
+    /// one of the filtered values.
+    ///
+    /// If the component is indexed, chunks that cannot possibly match are skipped entirely,
+    /// see [`ChunkStore::create_value_index`].
+    fn fetch_index_values_matching_component_values(
+        &self,
+        store: &ChunkStore,
+        filtered_index: TimelineName,
+        view_contents: &[ColumnDescriptor],
+        view_chunks: &[Vec<(AtomicU64, Chunk)>],
+        filter: &ComponentValueFilter,
+    ) -> BTreeSet<TimeInt> {
+        re_tracing::profile_function!();
+
+        let Some(values) = ComponentValueSet::new(&filter.values) else {
+            re_log::warn_once!(
+                "Cannot filter on values of type {}",
+                filter.values.data_type()
+            );
+            return BTreeSet::new();
+        };
+
+        self.fetch_index_values_matching_rows(
+            store,
+            filtered_index,
+            view_contents,
+            view_chunks,
+            &filter.column,
+            |_column| Some(values.clone()),
+            |list_array| values.matching_rows(list_array),
+        )
+    }
+
+    /// Returns all the index values at which the column selected by `predicate` contains at least
+    /// one value that satisfies it.
+    ///
+    /// For equality predicates on indexed components, chunks that cannot possibly match are
+    /// skipped entirely, see [`ChunkStore::create_value_index`].
+    fn fetch_index_values_matching_predicate(
+        &self,
+        store: &ChunkStore,
+        filtered_index: TimelineName,
+        view_contents: &[ColumnDescriptor],
+        view_chunks: &[Vec<(AtomicU64, Chunk)>],
+        predicate: &ComponentPredicate,
+    ) -> BTreeSet<TimeInt> {
+        re_tracing::profile_function!();
+
+        self.fetch_index_values_matching_rows(
+            store,
+            filtered_index,
+            view_contents,
+            view_chunks,
+            &predicate.column,
+            |column| {
+                if predicate.op != ComparisonOperator::Eq {
+                    return None;
+                }
+
+                // The index is keyed on the values as stored, so look for them as such.
+                let datatype = match &column.store_datatype {
+                    ArrowDataType::List(field) | ArrowDataType::LargeList(field) => {
+                        field.data_type()
+                    }
+                    datatype => datatype,
+                };
+                let value = arrow::compute::cast(&predicate.value, datatype).ok()?;
+                ComponentValueSet::new(&value)
+            },
+            |list_array| {
+                predicate.matching_rows(list_array).unwrap_or_else(|err| {
+                    re_log::warn_once!("Cannot evaluate predicate {predicate}: {err}");
+                    vec![false; list_array.len()]
+                })
+            },
+        )
+    }
+
+    /// Returns all the index values at which `matching_rows` holds for the column selected by
+    /// `selector`.
+    ///
+    /// If `values_to_look_up` returns some values and the component is indexed, only the chunks
+    /// that contain at least one of these values are looked at.
+    #[allow(clippy::unused_self, clippy::too_many_arguments)]
+    fn fetch_index_values_matching_rows(
+        &self,
+        store: &ChunkStore,
+        filtered_index: TimelineName,
+        view_contents: &[ColumnDescriptor],
+        view_chunks: &[Vec<(AtomicU64, Chunk)>],
+        selector: &ComponentColumnSelector,
+        values_to_look_up: impl FnOnce(&ComponentColumnDescriptor) -> Option<ComponentValueSet>,
+        matching_rows: impl Fn(&ArrowListArray) -> Vec<bool>,
+    ) -> BTreeSet<TimeInt> {
+        let Some((view_idx, column)) =
+            view_contents
+                .iter()
+                .enumerate()
+                .find_map(|(view_idx, column)| match column {
+                    ColumnDescriptor::Component(column) if column.matches(selector) => {
+                        Some((view_idx, column))
+                    }
+                    _ => None,
+                })
+        else {
+            return BTreeSet::new(); // not part of the view: nothing can possibly match
+        };
+
+        let component_descr: ComponentDescriptor = column.into();
+        let candidate_chunk_ids = values_to_look_up(column).and_then(|values| {
+            store.chunk_ids_with_component_values(&column.entity_path, &column.component, &values)
+        });
+
+        view_chunks
+            .get(view_idx)
+            .into_iter()
+            .flatten()
+            .filter(|(_cursor, chunk)| {
+                candidate_chunk_ids
+                    .as_ref()
+                    .is_none_or(|chunk_ids| chunk_ids.contains(&chunk.id()))
+            })
+            .flat_map(|(_cursor, chunk)| {
+                let list_array = chunk.components().get(&component_descr);
+                let time_column = chunk.timelines().get(&filtered_index);
+
+                list_array
+                    .zip(time_column)
+                    .into_iter()
+                    .flat_map(|(list_array, time_column)| {
+                        matching_rows(list_array)
+                            .into_iter()
+                            .zip(time_column.times())
+                            .filter_map(|(is_match, time)| is_match.then_some(time))
+                    })
+            })
+            .collect()
+    }
+
     /// Returns all potentially relevant clear [`Chunk`]s for each unique entity path in the view contents.
     ///
     /// These chunks take recursive clear semantics into account and are guaranteed to be properly densified.
@@ -711,6 +949,17 @@ impl<E: StorageEngineLike> QueryHandle<E> {
     pub fn seek_to_row(&self, row_idx: usize) {
         let state = self.init();
 
+        // When aggregating, rows are buckets.
+        let row_idx = if let Some(buckets) = state.buckets.as_ref() {
+            let Some((_bucket_start, rows)) = buckets.get(row_idx) else {
+                return;
+            };
+            state.cur_bucket.store(row_idx as _, Ordering::Relaxed);
+            rows.start
+        } else {
+            row_idx
+        };
+
         let Some(index_value) = state.unique_index_values.get(row_idx) else {
             return;
         };
@@ -781,8 +1030,32 @@ impl<E: StorageEngineLike> QueryHandle<E> {
     ///
     /// The number of rows depends and only depends on the _view contents_.
     /// The _selected contents_ has no influence on this value.
+    ///
+    /// When aggregating, this is the number of non-empty buckets, see [`QueryExpression::aggregation`].
     pub fn num_rows(&self) -> u64 {
-        self.init().unique_index_values.len() as _
+        let state = self.init();
+        if let Some(buckets) = state.buckets.as_ref() {
+            buckets.len() as _
+        } else {
+            state.unique_index_values.len() as _
+        }
+    }
+
+    /// The index value of the row that the next call to [`Self::next_row`] will yield, if any.
+    ///
+    /// When aggregating, this is the start of the next bucket.
+    pub(crate) fn next_index_value(&self) -> Option<IndexValue> {
+        let state = self.init();
+        if let Some(buckets) = state.buckets.as_ref() {
+            buckets
+                .get(state.cur_bucket.load(Ordering::Relaxed) as usize)
+                .map(|(bucket_start, _rows)| *bucket_start)
+        } else {
+            state
+                .unique_index_values
+                .get(state.cur_row.load(Ordering::Relaxed) as usize)
+                .copied()
+        }
     }
 
     /// Returns the next row's worth of data.
@@ -874,6 +1147,39 @@ impl<E: StorageEngineLike> QueryHandle<E> {
     pub fn _next_row(&self, store: &ChunkStore, cache: &QueryCache) -> Option<Vec<ArrowArrayRef>> {
         re_tracing::profile_function!();
 
+        let state = self.state.get_or_init(move || self.init_(store, cache));
+
+        let (Some(aggregation), Some(buckets)) =
+            (self.query.aggregation.as_ref(), state.buckets.as_ref())
+        else {
+            return self.next_unaggregated_row(store, cache);
+        };
+
+        let bucket_idx = state.cur_bucket.fetch_add(1, Ordering::Relaxed);
+        let (bucket_start, rows) = buckets.get(bucket_idx as usize)?;
+
+        // Buckets are contiguous ranges of rows, which we are already positioned at.
+        let rows = rows
+            .clone()
+            .map(|_| self.next_unaggregated_row(store, cache))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(crate::aggregation::aggregate_rows(
+            &state.selected_contents,
+            state.filtered_index,
+            aggregation.op,
+            *bucket_start,
+            &rows,
+        ))
+    }
+
+    fn next_unaggregated_row(
+        &self,
+        store: &ChunkStore,
+        cache: &QueryCache,
+    ) -> Option<Vec<ArrowArrayRef>> {
+        re_tracing::profile_function!();
+
         /// Temporary state used to resolve the streaming join for the current iteration.
         #[derive(Debug)]
         struct StreamingJoinStateEntry<'a> {
@@ -1033,60 +1339,63 @@ impl<E: StorageEngineLike> QueryHandle<E> {
             }
         }
 
-        match self.query.sparse_fill_strategy {
-            SparseFillStrategy::None => {}
+        // Columns that are joined onto another entity always get latest-at aligned, see
+        // [`QueryExpression::join_on_entity`].
+        let is_latest_at_filled =
+            |descr: &ComponentColumnDescriptor| match self.query.sparse_fill_strategy {
+                SparseFillStrategy::None => self
+                    .query
+                    .join_on_entity
+                    .as_ref()
+                    .is_some_and(|join_on_entity| join_on_entity != &descr.entity_path),
+
+                SparseFillStrategy::LatestAtGlobal => true,
+            };
 
-            SparseFillStrategy::LatestAtGlobal => {
-                // Everything that yielded `null` for the current iteration.
-                let null_streaming_states = view_streaming_state
-                    .iter_mut()
-                    .enumerate()
-                    .filter(|(_view_idx, streaming_state)| streaming_state.is_none());
+        // Everything that yielded `null` for the current iteration.
+        let null_streaming_states = view_streaming_state
+            .iter_mut()
+            .enumerate()
+            .filter(|(_view_idx, streaming_state)| streaming_state.is_none());
 
-                for (view_idx, streaming_state) in null_streaming_states {
-                    let Some(ColumnDescriptor::Component(descr)) =
-                        state.view_contents.get_index_or_component(view_idx)
-                    else {
-                        continue;
-                    };
+        for (view_idx, streaming_state) in null_streaming_states {
+            let Some(ColumnDescriptor::Component(descr)) =
+                state.view_contents.get_index_or_component(view_idx)
+            else {
+                continue;
+            };
 
-                    // NOTE: While it would be very tempting to resolve the latest-at state
-                    // of the entire view contents at `filtered_index_range.start - 1` once
-                    // during `QueryHandle` initialization, and then bootstrap off of that, that
-                    // would effectively close the door to efficient pagination forever, since
-                    // we'd have to iterate over all the pages to compute the right latest-at
-                    // value at t+n (i.e. no more random access).
-                    // Therefore, it is better to simply do this the "dumb" way.
-                    //
-                    // TODO(cmc): Still, as always, this can be made faster and smarter at
-                    // the cost of some extra complexity (e.g. caching the result across
-                    // consecutive nulls etc). Later.
-
-                    let query =
-                        re_chunk::LatestAtQuery::new(state.filtered_index, *cur_index_value);
-
-                    let component_descriptor = store
-                        .entity_component_descriptor(
-                            &descr.entity_path,
-                            descr.archetype,
-                            descr.component,
-                        )
-                        .into_iter()
-                        .next()?;
+            if !is_latest_at_filled(descr) {
+                continue;
+            }
 
-                    let results = cache.latest_at(
-                        &query,
-                        &descr.entity_path.clone(),
-                        [&component_descriptor],
-                    );
+            // NOTE: While it would be very tempting to resolve the latest-at state
+            // of the entire view contents at `filtered_index_range.start - 1` once
+            // during `QueryHandle` initialization, and then bootstrap off of that, that
+            // would effectively close the door to efficient pagination forever, since
+            // we'd have to iterate over all the pages to compute the right latest-at
+            // value at t+n (i.e. no more random access).
+            // Therefore, it is better to simply do this the "dumb" way.
+            //
+            // TODO(cmc): Still, as always, this can be made faster and smarter at
+            // the cost of some extra complexity (e.g. caching the result across
+            // consecutive nulls etc). Later.
 
-                    *streaming_state = results
-                        .components
-                        .into_values()
-                        .next()
-                        .map(|unit| StreamingJoinState::Retrofilled(unit.clone()));
-                }
-            }
+            let query = re_chunk::LatestAtQuery::new(state.filtered_index, *cur_index_value);
+
+            let component_descriptor = store
+                .entity_component_descriptor(&descr.entity_path, descr.archetype, descr.component)
+                .into_iter()
+                .next()?;
+
+            let results =
+                cache.latest_at(&query, &descr.entity_path.clone(), [&component_descriptor]);
+
+            *streaming_state = results
+                .components
+                .into_values()
+                .next()
+                .map(|unit| StreamingJoinState::Retrofilled(unit.clone()));
         }
 
         // We are stitching a bunch of unrelated cells together in order to create the final row
@@ -1338,7 +1647,8 @@ mod tests {
 
     use re_chunk::{Chunk, ChunkId, ComponentIdentifier, RowId, TimePoint};
     use re_chunk_store::{
-        AbsoluteTimeRange, ChunkStore, ChunkStoreConfig, ChunkStoreHandle, QueryExpression, TimeInt,
+        AbsoluteTimeRange, AggregationOperator, ChunkStore, ChunkStoreConfig, ChunkStoreHandle,
+        QueryExpression, TemporalAggregation, TimeInt,
     };
     use re_format_arrow::format_record_batch;
     use re_log_types::{
@@ -1379,7 +1689,11 @@ mod tests {
     // * [x] view_contents
     // * [x] selection
     // * [x] filtered_is_not_null
+    // * [x] filtered_component_values
+    // * [x] filtered_component_predicates
+    // * [x] join_on_entity
     // * [x] sparse_fill_strategy
+    // * [x] aggregation
     // * [x] using_index_values
     //
     // In addition to those, some much needed extras:
@@ -1751,6 +2065,323 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn filtered_component_values() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        for with_value_index in [false, true] {
+            let mut store = create_nasty_store()?;
+            if with_value_index {
+                store.create_value_index(MyPoints::descriptor_colors().component);
+            }
+
+            let store = ChunkStoreHandle::new(store);
+            eprintln!("{store}");
+            let query_cache = QueryCache::new_handle(store.clone());
+            let query_engine = QueryEngine::new(store.clone(), query_cache.clone());
+
+            let filtered_index = Some(TimelineName::new("frame_nr"));
+            let column = ComponentColumnSelector {
+                entity_path: "this/that".into(),
+                component: MyPoints::descriptor_colors().component.to_string(),
+            };
+
+            for (values, expected_frames) in [
+                (vec![2, 4], vec![30, 50]),
+                (vec![6], vec![70]),
+                (vec![42], vec![]),
+            ] {
+                let query = QueryExpression {
+                    filtered_index,
+                    filtered_component_values: Some(ComponentValueFilter {
+                        column: column.clone(),
+                        values: Arc::new(UInt32Array::from(values)),
+                    }),
+                    selection: Some(vec![ColumnSelector::Time(TimeColumnSelector::from(
+                        "frame_nr",
+                    ))]),
+                    ..Default::default()
+                };
+                eprintln!("{query:#?}:");
+
+                let query_handle = query_engine.query(query.clone());
+                let dataframe = concat_batches(
+                    query_handle.schema(),
+                    &query_handle.batch_iter().collect_vec(),
+                )?;
+                eprintln!("{}", format_record_batch(&dataframe.clone()));
+
+                let frames = dataframe
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<arrow::array::Int64Array>()
+                    .map(|frames| frames.values().to_vec())
+                    .unwrap_or_default();
+                similar_asserts::assert_eq!(expected_frames, frames);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn filtered_component_predicates() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        for with_value_index in [false, true] {
+            let mut store = create_nasty_store()?;
+            if with_value_index {
+                store.create_value_index(MyPoints::descriptor_colors().component);
+            }
+
+            let store = ChunkStoreHandle::new(store);
+            eprintln!("{store}");
+            let query_cache = QueryCache::new_handle(store.clone());
+            let query_engine = QueryEngine::new(store.clone(), query_cache.clone());
+
+            let filtered_index = Some(TimelineName::new("frame_nr"));
+            let column = ComponentColumnSelector {
+                entity_path: "this/that".into(),
+                component: MyPoints::descriptor_colors().component.to_string(),
+            };
+            let predicate = |op, value: i64| ComponentPredicate {
+                column: column.clone(),
+                op,
+                // Deliberately not the stored datatype: values get cast as needed.
+                value: Arc::new(arrow::array::Int64Array::from(vec![value])),
+            };
+
+            for (predicates, expected_frames) in [
+                (vec![predicate(ComparisonOperator::Gt, 3)], vec![50, 70]),
+                (vec![predicate(ComparisonOperator::Eq, 3)], vec![40]),
+                (
+                    vec![
+                        predicate(ComparisonOperator::GtEq, 3),
+                        predicate(ComparisonOperator::NotEq, 6),
+                    ],
+                    vec![40, 50],
+                ),
+                (vec![predicate(ComparisonOperator::Lt, 0)], vec![]),
+            ] {
+                let query = QueryExpression {
+                    filtered_index,
+                    filtered_component_predicates: predicates,
+                    selection: Some(vec![ColumnSelector::Time(TimeColumnSelector::from(
+                        "frame_nr",
+                    ))]),
+                    ..Default::default()
+                };
+                eprintln!("{query:#?}:");
+
+                let query_handle = query_engine.query(query.clone());
+                let dataframe = concat_batches(
+                    query_handle.schema(),
+                    &query_handle.batch_iter().collect_vec(),
+                )?;
+                eprintln!("{}", format_record_batch(&dataframe.clone()));
+
+                let frames = dataframe
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<arrow::array::Int64Array>()
+                    .map(|frames| frames.values().to_vec())
+                    .unwrap_or_default();
+                similar_asserts::assert_eq!(expected_frames, frames);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn join_on_entity() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+            ChunkStoreConfig::COMPACTION_DISABLED,
+        );
+
+        // Odometry and ground truth, logged at different rates.
+        let odometry = EntityPath::from("/robot/odometry");
+        let ground_truth = EntityPath::from("/robot/ground_truth");
+        for (entity_path, frames) in [
+            (&odometry, [10, 20, 30, 40]),
+            (&ground_truth, [15, 35, 45, 55]),
+        ] {
+            let mut builder = Chunk::builder(entity_path.clone());
+            for frame in frames {
+                builder = builder.with_component_batch(
+                    RowId::new(),
+                    [build_frame_nr(TimeInt::new_temporal(frame.into()))],
+                    (MyPoints::descriptor_colors(), &MyColor::from_iter([frame])),
+                );
+            }
+            store.insert_chunk(&Arc::new(builder.build()?))?;
+        }
+
+        let store = ChunkStoreHandle::new(store);
+        eprintln!("{store}");
+        let query_cache = QueryCache::new_handle(store.clone());
+        let query_engine = QueryEngine::new(store.clone(), query_cache.clone());
+
+        let color_column = |entity_path: &EntityPath| {
+            ColumnSelector::Component(ComponentColumnSelector {
+                entity_path: entity_path.clone(),
+                component: MyPoints::descriptor_colors().component.to_string(),
+            })
+        };
+
+        let query = QueryExpression {
+            filtered_index: Some(TimelineName::new("frame_nr")),
+            join_on_entity: Some(odometry.clone()),
+            selection: Some(vec![
+                ColumnSelector::Time(TimeColumnSelector::from("frame_nr")),
+                color_column(&odometry),
+                color_column(&ground_truth),
+            ]),
+            ..Default::default()
+        };
+        eprintln!("{query:#?}:");
+
+        let query_handle = query_engine.query(query.clone());
+        let dataframe = concat_batches(
+            query_handle.schema(),
+            &query_handle.batch_iter().collect_vec(),
+        )?;
+        eprintln!("{}", format_record_batch(&dataframe.clone()));
+
+        let frames = dataframe
+            .column(0)
+            .as_any()
+            .downcast_ref::<arrow::array::Int64Array>()
+            .map(|frames| frames.values().to_vec())
+            .unwrap_or_default();
+        similar_asserts::assert_eq!(vec![10, 20, 30, 40], frames);
+
+        let colors = |column_idx: usize| -> Vec<Option<u32>> {
+            let Some(list_array) = dataframe
+                .column(column_idx)
+                .as_any()
+                .downcast_ref::<arrow::array::ListArray>()
+            else {
+                return Vec::new();
+            };
+
+            (0..list_array.len())
+                .map(|row_idx| {
+                    list_array
+                        .is_valid(row_idx)
+                        .then(|| list_array.value(row_idx))
+                        .and_then(|values| {
+                            values
+                                .as_any()
+                                .downcast_ref::<UInt32Array>()
+                                .and_then(|values| values.values().first().copied())
+                        })
+                })
+                .collect()
+        };
+
+        // Rows are driven by the odometry…
+        similar_asserts::assert_eq!(vec![Some(10), Some(20), Some(30), Some(40)], colors(1));
+        // …and the ground truth is aligned on them using latest-at semantics.
+        similar_asserts::assert_eq!(vec![None, Some(15), Some(15), Some(35)], colors(2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn aggregation() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+            ChunkStoreConfig::COMPACTION_DISABLED,
+        );
+
+        let entity_path = EntityPath::from("/series");
+        let mut builder = Chunk::builder(entity_path.clone());
+        for frame in 0..10 {
+            builder = builder.with_component_batch(
+                RowId::new(),
+                [build_frame_nr(TimeInt::new_temporal(frame.into()))],
+                (MyPoints::descriptor_colors(), &MyColor::from_iter([frame])),
+            );
+        }
+        store.insert_chunk(&Arc::new(builder.build()?))?;
+
+        let store = ChunkStoreHandle::new(store);
+        eprintln!("{store}");
+        let query_cache = QueryCache::new_handle(store.clone());
+        let query_engine = QueryEngine::new(store.clone(), query_cache.clone());
+
+        for (op, expected_values) in [
+            (AggregationOperator::Min, vec![0, 4, 8]),
+            (AggregationOperator::Max, vec![3, 7, 9]),
+            (AggregationOperator::Mean, vec![1, 5, 8]), // truncated back to `u32`
+            (AggregationOperator::Last, vec![3, 7, 9]),
+        ] {
+            let query = QueryExpression {
+                filtered_index: Some(TimelineName::new("frame_nr")),
+                aggregation: Some(TemporalAggregation {
+                    bucket_size: std::num::NonZeroU64::new(4).expect("non-zero"),
+                    op,
+                }),
+                selection: Some(vec![
+                    ColumnSelector::Time(TimeColumnSelector::from("frame_nr")),
+                    ColumnSelector::Component(ComponentColumnSelector {
+                        entity_path: entity_path.clone(),
+                        component: MyPoints::descriptor_colors().component.to_string(),
+                    }),
+                ]),
+                ..Default::default()
+            };
+            eprintln!("{query:#?}:");
+
+            let query_handle = query_engine.query(query.clone());
+            assert_eq!(3, query_handle.num_rows());
+
+            let dataframe = concat_batches(
+                query_handle.schema(),
+                &query_handle.batch_iter().collect_vec(),
+            )?;
+            eprintln!("{}", format_record_batch(&dataframe.clone()));
+
+            let frames = dataframe
+                .column(0)
+                .as_any()
+                .downcast_ref::<arrow::array::Int64Array>()
+                .map(|frames| frames.values().to_vec())
+                .unwrap_or_default();
+            similar_asserts::assert_eq!(vec![0, 4, 8], frames);
+
+            let values = dataframe
+                .column(1)
+                .as_any()
+                .downcast_ref::<arrow::array::ListArray>()
+                .and_then(|list_array| {
+                    list_array
+                        .values()
+                        .as_any()
+                        .downcast_ref::<UInt32Array>()
+                        .map(|values| values.values().to_vec())
+                })
+                .unwrap_or_default();
+            similar_asserts::assert_eq!(expected_values, values, "{op}");
+
+            // Pagination works on buckets.
+            query_handle.seek_to_row(2);
+            let dataframe = concat_batches(
+                query_handle.schema(),
+                &query_handle.batch_iter().collect_vec(),
+            )?;
+            assert_eq!(1, dataframe.num_rows());
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn view_contents() -> anyhow::Result<()> {
         re_log::setup_logging();
@@ -2343,7 +2974,11 @@ mod tests {
             filtered_index_values: None,
             using_index_values: None,
             filtered_is_not_null: None,
+            filtered_component_values: None,
+            filtered_component_predicates: Vec::new(),
+            join_on_entity: None,
             sparse_fill_strategy: re_chunk_store::SparseFillStrategy::None,
+            aggregation: None,
             selection: None,
         };
 
@@ -8,11 +8,13 @@ use re_chunk::{
 };
 use re_chunk_store::{
     ChunkStore, ChunkStoreChunkStats, ChunkStoreConfig, ChunkStoreDiffKind, ChunkStoreEvent,
-    ChunkStoreHandle, ChunkStoreSubscriber as _, GarbageCollectionOptions, GarbageCollectionTarget,
+    ChunkStoreHandle, ChunkStoreSubscriber as _, CompactionOptions, DerivedSeries, DerivedTimeline,
+    GarbageCollectionOptions, GarbageCollectionProtection, GarbageCollectionTarget,
 };
 use re_log_types::{
-    AbsoluteTimeRange, AbsoluteTimeRangeF, ApplicationId, EntityPath, EntityPathHash, LogMsg,
-    RecordingId, SetStoreInfo, StoreId, StoreInfo, StoreKind, TimeType,
+    AbsoluteTimeRange, AbsoluteTimeRangeF, ApplicationId, DefaultTimeline, EntityPath,
+    EntityPathHash, LogMsg, RecordingId, SetStoreInfo, StoreId, StoreInfo, StoreKind, TimeType,
+    TimelineMapping,
 };
 use re_query::{
     QueryCache, QueryCacheHandle, StorageEngine, StorageEngineArcReadGuard, StorageEngineReadGuard,
@@ -20,7 +22,7 @@ use re_query::{
 };
 use re_smart_channel::SmartChannelSource;
 
-use crate::{Error, TimesPerTimeline, ingestion_statistics::IngestionStatistics};
+use crate::{Error, IngestTransform, TimesPerTimeline, ingestion_statistics::IngestionStatistics};
 
 // ----------------------------------------------------------------------------
 
@@ -106,6 +108,9 @@ pub struct EntityDb {
     storage_engine: StorageEngine,
 
     stats: IngestionStatistics,
+
+    /// See [`Self::add_ingest_transform`].
+    pub(crate) ingest_transforms: Vec<Arc<dyn IngestTransform>>,
 }
 
 impl EntityDb {
@@ -133,6 +138,7 @@ impl EntityDb {
             time_histogram_per_timeline: Default::default(),
             storage_engine,
             stats: IngestionStatistics::default(),
+            ingest_transforms: Vec::new(),
         }
     }
 
@@ -274,6 +280,67 @@ impl EntityDb {
         Ok(())
     }
 
+    /// All the [`TimelineMapping`]s declared in the recording properties.
+    pub fn timeline_mappings(&self) -> Vec<TimelineMapping> {
+        use re_log_types::external::arrow::array::{Array as _, Float64Array, Int64Array};
+
+        re_tracing::profile_function!();
+
+        let descr_offset = TimelineMapping::descriptor_offset();
+        let descr_drift = TimelineMapping::descriptor_drift();
+        let query = LatestAtQuery::latest(TimelineName::log_tick());
+
+        self.entity_path_from_hash
+            .values()
+            .filter_map(|entity_path| {
+                let (source, target) = TimelineMapping::timelines_from_entity_path(entity_path)?;
+
+                let results = self.storage_engine.read().cache().latest_at(
+                    &query,
+                    entity_path,
+                    [&descr_offset, &descr_drift],
+                );
+
+                let offset = results
+                    .component_batch_raw(&descr_offset)
+                    .and_then(|array| {
+                        let array = array.as_any().downcast_ref::<Int64Array>()?;
+                        (!array.is_empty()).then(|| array.value(0))
+                    })
+                    .unwrap_or_default();
+                let drift = results
+                    .component_batch_raw(&descr_drift)
+                    .and_then(|array| {
+                        let array = array.as_any().downcast_ref::<Float64Array>()?;
+                        (!array.is_empty()).then(|| array.value(0))
+                    })
+                    .unwrap_or_default();
+
+                Some(
+                    TimelineMapping::new(source, target)
+                        .with_offset(offset)
+                        .with_drift(drift),
+                )
+            })
+            .collect()
+    }
+
+    /// The timeline that the recording properties ask to select by default, if any.
+    pub fn default_timeline(&self) -> Option<TimelineName> {
+        use re_log_types::external::arrow::array::{Array as _, StringArray};
+
+        let descr = DefaultTimeline::descriptor_timeline();
+        let results = self.latest_at(
+            &LatestAtQuery::latest(TimelineName::log_tick()),
+            &EntityPath::properties().join(&DefaultTimeline::entity_path()),
+            [&descr],
+        );
+
+        let array = results.component_batch_raw(&descr)?;
+        let array = array.as_any().downcast_ref::<StringArray>()?;
+        (!array.is_empty() && array.is_valid(0)).then(|| TimelineName::new(array.value(0)))
+    }
+
     pub fn timeline_type(&self, timeline_name: &TimelineName) -> TimeType {
         self.storage_engine()
             .store()
@@ -526,6 +593,14 @@ impl EntityDb {
         chunk: &Arc<Chunk>,
         timestamps: &re_sorbet::TimestampMetadata,
     ) -> Result<Vec<ChunkStoreEvent>, Error> {
+        let transformed;
+        let chunk = if self.ingest_transforms.is_empty() {
+            chunk
+        } else {
+            transformed = self.apply_ingest_transforms(chunk);
+            &transformed
+        };
+
         let mut engine = self.storage_engine.write();
         let store_events = engine.store().insert_chunk(chunk)?;
         engine.cache().on_events(&store_events);
@@ -627,9 +702,44 @@ impl EntityDb {
         store_events
     }
 
-    /// Drop all events in the given time range from the given timeline.
+    /// Exempts all the data of the given entity and its descendants from garbage collection.
+    ///
+    /// See [`ChunkStore::protect_entity_subtree`].
+    pub fn protect_entity_subtree_from_gc(&mut self, entity_path: EntityPath) {
+        self.storage_engine
+            .write()
+            .store()
+            .protect_entity_subtree(entity_path);
+    }
+
+    /// Exempts all the data overlapping the given time range from garbage collection.
     ///
-    /// Used to implement undo (erase the last event from the blueprint db).
+    /// See [`ChunkStore::protect_time_range`].
+    pub fn protect_time_range_from_gc(
+        &mut self,
+        timeline: TimelineName,
+        time_range: AbsoluteTimeRange,
+    ) {
+        self.storage_engine
+            .write()
+            .store()
+            .protect_time_range(timeline, time_range);
+    }
+
+    /// Replaces what the garbage collector must never drop, e.g. to lift previous protections.
+    ///
+    /// See [`ChunkStore::set_gc_protection`].
+    pub fn set_gc_protection(&mut self, gc_protection: GarbageCollectionProtection) {
+        self.storage_engine
+            .write()
+            .store()
+            .set_gc_protection(gc_protection);
+    }
+
+    /// Drop all events in the given time range from the given timeline, across all entities.
+    ///
+    /// Used to implement undo (erase the last event from the blueprint db), and to trim
+    /// sections out of a recording.
     pub fn drop_time_range(
         &mut self,
         timeline: &TimelineName,
@@ -651,6 +761,94 @@ impl EntityDb {
         store_events
     }
 
+    /// Merges the small chunks of this database into larger ones, see [`ChunkStore::compact`].
+    ///
+    /// Queries over heavily fragmented recordings (e.g. streamed in tiny batches) get a lot
+    /// faster once compacted.
+    pub fn compact(&mut self, options: &CompactionOptions) -> Result<Vec<ChunkStoreEvent>, Error> {
+        re_tracing::profile_function!();
+
+        let mut engine = self.storage_engine.write();
+
+        let store_events = engine.store().compact(options)?;
+        Self::on_store_deletions(
+            &mut self.times_per_timeline,
+            &mut self.time_histogram_per_timeline,
+            &mut self.tree,
+            engine,
+            &store_events,
+        );
+
+        Ok(store_events)
+    }
+
+    /// Registers a timeline computed from component values.
+    ///
+    /// See [`ChunkStore::register_derived_timeline`].
+    pub fn register_derived_timeline(
+        &mut self,
+        derived: DerivedTimeline,
+    ) -> Result<Vec<ChunkStoreEvent>, Error> {
+        re_tracing::profile_function!();
+
+        let mut engine = self.storage_engine.write();
+
+        let store_events = engine.store().register_derived_timeline(derived)?;
+        Self::on_store_deletions(
+            &mut self.times_per_timeline,
+            &mut self.time_histogram_per_timeline,
+            &mut self.tree,
+            engine,
+            &store_events,
+        );
+
+        Ok(store_events)
+    }
+
+    /// Registers a scalar series computed from the components of another entity.
+    ///
+    /// See [`ChunkStore::register_derived_series`].
+    pub fn register_derived_series(
+        &mut self,
+        derived: DerivedSeries,
+    ) -> Result<Vec<ChunkStoreEvent>, Error> {
+        re_tracing::profile_function!();
+
+        let mut engine = self.storage_engine.write();
+
+        self.entity_path_from_hash
+            .entry(derived.entity_path.hash())
+            .or_insert_with(|| derived.entity_path.clone());
+
+        let store_events = engine.store().register_derived_series(derived)?;
+        self.tree.on_store_additions(&store_events);
+        Self::on_store_deletions(
+            &mut self.times_per_timeline,
+            &mut self.time_histogram_per_timeline,
+            &mut self.tree,
+            engine,
+            &store_events,
+        );
+
+        Ok(store_events)
+    }
+
+    /// Removes a series registered with [`Self::register_derived_series`], along with its values.
+    pub fn unregister_derived_series(&mut self, entity_path: &EntityPath) {
+        re_tracing::profile_function!();
+
+        let mut engine = self.storage_engine.write();
+
+        let store_events = engine.store().unregister_derived_series(entity_path);
+        Self::on_store_deletions(
+            &mut self.times_per_timeline,
+            &mut self.time_histogram_per_timeline,
+            &mut self.tree,
+            engine,
+            &store_events,
+        );
+    }
+
     /// Unconditionally drops all the data for a given [`EntityPath`] .
     ///
     /// This is _not_ recursive. Children of this entity will not be affected.
@@ -734,9 +932,10 @@ impl EntityDb {
                 )
             });
 
+            // NOTE: This includes the chunks that were spilled to disk, which aren't lost data.
             let mut chunks: Vec<Arc<Chunk>> = engine
                 .store()
-                .iter_chunks()
+                .iter_all_chunks()
                 .filter(move |chunk| {
                     let Some((timeline, time_range)) = time_filter else {
                         return true;
@@ -748,7 +947,6 @@ impl EntityDb {
                             || time_range.contains(time_column.time_range().max())
                     })
                 })
-                .cloned() // refcount
                 .collect();
 
             // Try to roughly preserve the order of the chunks
@@ -784,6 +982,67 @@ impl EntityDb {
             .chain(blueprint_ready)
     }
 
+    /// The chunks of the entities matching `entity_filter`, cut down to `time_range` on `timeline`.
+    ///
+    /// Static chunks are kept as-is, so that the clip still has e.g. its annotation contexts.
+    pub fn clip_chunks(
+        &self,
+        timeline: &TimelineName,
+        time_range: AbsoluteTimeRange,
+        entity_filter: impl Fn(&EntityPath) -> bool,
+    ) -> Vec<Arc<Chunk>> {
+        re_tracing::profile_function!();
+
+        let engine = self.storage_engine.read();
+
+        let mut chunks: Vec<Arc<Chunk>> = engine
+            .store()
+            .iter_all_chunks()
+            .filter(|chunk| entity_filter(chunk.entity_path()))
+            .filter_map(|chunk| {
+                if chunk.is_static() {
+                    return Some(chunk);
+                }
+
+                let time_column = chunk.timelines().get(timeline)?;
+                if !time_column.time_range().intersects(time_range) {
+                    return None;
+                }
+
+                let clipped = chunk.time_range_filtered(timeline, time_range);
+                (!clipped.is_empty()).then(|| Arc::new(clipped.with_id(ChunkId::new())))
+            })
+            .collect();
+
+        // Same order as in `to_messages`.
+        chunks.sort_by_key(|chunk| chunk.row_id_range().map(|(min, _)| min));
+
+        chunks
+    }
+
+    /// Like [`Self::to_messages`], but only for the data of [`Self::clip_chunks`].
+    pub fn clip_to_messages(
+        &self,
+        timeline: &TimelineName,
+        time_range: AbsoluteTimeRange,
+        entity_filter: impl Fn(&EntityPath) -> bool,
+    ) -> impl Iterator<Item = ChunkResult<LogMsg>> + '_ {
+        let set_store_info_msg = self
+            .store_info_msg()
+            .map(|msg| Ok(LogMsg::SetStoreInfo(msg.clone())));
+
+        let data_messages = self
+            .clip_chunks(timeline, time_range, entity_filter)
+            .into_iter()
+            .map(|chunk| {
+                chunk
+                    .to_arrow_msg()
+                    .map(|msg| LogMsg::ArrowMsg(self.store_id().clone(), msg))
+            });
+
+        set_store_info_msg.into_iter().chain(data_messages)
+    }
+
     /// Make a clone of this [`EntityDb`], assigning it a new [`StoreId`].
     pub fn clone_with_new_id(&self, new_id: StoreId) -> Result<Self, Error> {
         re_tracing::profile_function!();
@@ -813,7 +1072,46 @@ impl EntityDb {
         }
 
         let engine = self.storage_engine.read();
-        for chunk in engine.store().iter_chunks() {
+        for chunk in engine.store().iter_all_chunks() {
+            new_db.add_chunk(&chunk)?;
+        }
+
+        Ok(new_db)
+    }
+
+    /// Copies all data logged at or below `entity_path` into a new recording with the given id.
+    ///
+    /// If a `time_range` is specified, only the data within that range on that timeline is
+    /// copied. Static data is always copied.
+    ///
+    /// Use [`Self::to_messages`] on the result to write it to an `.rrd` file.
+    pub fn export_subtree(
+        &self,
+        new_id: StoreId,
+        entity_path: &EntityPath,
+        time_range: Option<(TimelineName, AbsoluteTimeRange)>,
+    ) -> Result<Self, Error> {
+        re_tracing::profile_function!();
+
+        let mut new_db = Self::new(new_id.clone());
+
+        if let Some(store_info) = self.store_info() {
+            let mut new_info = store_info.clone();
+            new_info.store_id = new_id.clone();
+            new_info.cloned_from = Some(self.store_id().clone());
+
+            new_db.set_store_info(SetStoreInfo {
+                row_id: *RowId::new(),
+                info: new_info,
+            });
+        }
+
+        let subtree_store =
+            self.storage_engine
+                .read()
+                .store()
+                .export_subtree(new_id, entity_path, time_range)?;
+        for chunk in subtree_store.iter_chunks() {
             new_db.add_chunk(&Arc::clone(chunk))?;
         }
 
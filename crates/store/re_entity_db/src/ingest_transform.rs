@@ -0,0 +1,54 @@
+//! User-provided stages that modify data before it gets inserted into a recording, see
+//! [`IngestTransform`].
+
+use std::sync::Arc;
+
+use re_chunk::Chunk;
+
+use crate::EntityDb;
+
+/// A stage of the ingestion of a recording, that can modify chunks before they hit the store.
+///
+/// This is e.g. how images get redacted before they can be seen or saved, for privacy compliance
+/// of shared recordings.
+///
+/// Transforms are added with [`EntityDb::add_ingest_transform`], and run in order.
+pub trait IngestTransform: Send + Sync {
+    /// Name of the transform, used in logs.
+    fn name(&self) -> &str;
+
+    /// Returns the chunk to insert in place of `chunk`, or `None` to insert it unchanged.
+    ///
+    /// `recording` contains everything that was ingested so far, so that e.g. detections that
+    /// were logged before an image can be used to redact it.
+    fn transform(&self, recording: &EntityDb, chunk: &Chunk) -> Option<Chunk>;
+}
+
+impl EntityDb {
+    /// Adds a stage that all the chunks added from now on go through.
+    pub fn add_ingest_transform(&mut self, transform: Arc<dyn IngestTransform>) {
+        re_log::debug!(
+            "Added ingest transform {:?} to {:?}",
+            transform.name(),
+            self.store_id()
+        );
+        self.ingest_transforms.push(transform);
+    }
+
+    /// The transforms added with [`Self::add_ingest_transform`].
+    pub fn ingest_transforms(&self) -> &[Arc<dyn IngestTransform>] {
+        &self.ingest_transforms
+    }
+
+    pub(crate) fn apply_ingest_transforms(&self, chunk: &Arc<Chunk>) -> Arc<Chunk> {
+        re_tracing::profile_function!();
+
+        let mut chunk = chunk.clone();
+        for transform in &self.ingest_transforms {
+            if let Some(transformed) = transform.transform(self, &chunk) {
+                chunk = Arc::new(transformed);
+            }
+        }
+        chunk
+    }
+}
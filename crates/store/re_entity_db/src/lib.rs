@@ -6,8 +6,10 @@
 
 pub mod entity_db;
 pub mod entity_tree;
+mod ingest_transform;
 mod ingestion_statistics;
 mod instance_path;
+mod schema_validation;
 mod store_bundle;
 mod time_histogram_per_timeline;
 mod times_per_timeline;
@@ -16,8 +18,13 @@ mod versioned_instance_path;
 pub use self::{
     entity_db::{DEFAULT_GC_TIME_BUDGET, EntityDb},
     entity_tree::EntityTree,
+    ingest_transform::IngestTransform,
     ingestion_statistics::{IngestionStatistics, LatencySnapshot, LatencyStats},
     instance_path::{InstancePath, InstancePathHash},
+    schema_validation::{
+        EntitySchema, RateBounds, RecordingSchema, ValidationIssue, ValidationIssueKind,
+        ValidationReport,
+    },
     store_bundle::{
         DatasetRecordings, LocalRecordings, RemoteRecordings, SortDatasetsResults, StoreBundle,
         StoreLoadError,
@@ -0,0 +1,341 @@
+//! Checking that a recording contains everything it is expected to, see [`RecordingSchema`].
+
+use std::collections::BTreeSet;
+
+use re_chunk_store::ChunkStore;
+use re_log_types::{EntityPath, TimeType, TimelineName};
+
+use crate::EntityDb;
+
+/// The data that a recording is expected to contain, e.g. all the sensors of a robot.
+///
+/// Validate a recording against it with [`EntityDb::validate`], which returns a
+/// [`ValidationReport`] listing everything that is missing or logged at the wrong rate.
+///
+/// ```
+/// # use re_entity_db::{EntitySchema, RecordingSchema, RateBounds};
+/// let schema = RecordingSchema::default()
+///     .with_entity(
+///         EntitySchema::new("robot/lidar")
+///             .with_archetype("Points3D")
+///             .with_rate(RateBounds::new("log_time").with_min_hz(9.0).with_max_hz(11.0)),
+///     )
+///     .with_entity(EntitySchema::new("robot/battery").with_component("Scalars:scalars"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct RecordingSchema {
+    /// The entities that must be present.
+    pub entities: Vec<EntitySchema>,
+}
+
+/// The data that a single entity is expected to contain, see [`RecordingSchema`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct EntitySchema {
+    pub entity_path: EntityPath,
+
+    /// Archetypes that must have been logged to the entity, by full (`rerun.archetypes.Points3D`)
+    /// or short (`Points3D`) name.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub archetypes: Vec<String>,
+
+    /// Components that must have been logged to the entity, by identifier (`Points3D:positions`).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub components: Vec<String>,
+
+    /// Bounds on the rate the entity is logged at.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rate: Option<RateBounds>,
+}
+
+/// Bounds on the rate an entity is logged at, on a given timeline.
+///
+/// The rate is the number of distinct times the entity was logged at, divided by the time span
+/// between the first and the last one. On sequence timelines, it is per sequence step instead of
+/// per second.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct RateBounds {
+    pub timeline: String,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub min_hz: Option<f64>,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_hz: Option<f64>,
+}
+
+impl RecordingSchema {
+    #[inline]
+    pub fn with_entity(mut self, entity: EntitySchema) -> Self {
+        self.entities.push(entity);
+        self
+    }
+
+    /// Validates the contents of `store` against this schema.
+    pub fn validate(&self, store: &ChunkStore) -> ValidationReport {
+        re_tracing::profile_function!();
+
+        let issues = self
+            .entities
+            .iter()
+            .flat_map(|entity| entity.validate(store))
+            .collect();
+
+        ValidationReport { issues }
+    }
+}
+
+impl EntitySchema {
+    #[inline]
+    pub fn new(entity_path: impl Into<EntityPath>) -> Self {
+        Self {
+            entity_path: entity_path.into(),
+            archetypes: Vec::new(),
+            components: Vec::new(),
+            rate: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_archetype(mut self, archetype: impl Into<String>) -> Self {
+        self.archetypes.push(archetype.into());
+        self
+    }
+
+    #[inline]
+    pub fn with_component(mut self, component: impl Into<String>) -> Self {
+        self.components.push(component.into());
+        self
+    }
+
+    #[inline]
+    pub fn with_rate(mut self, rate: RateBounds) -> Self {
+        self.rate = Some(rate);
+        self
+    }
+
+    fn validate(&self, store: &ChunkStore) -> Vec<ValidationIssue> {
+        let issue = |kind| ValidationIssue {
+            entity_path: self.entity_path.clone(),
+            kind,
+        };
+
+        let Some(descriptors) = store.all_components_for_entity(&self.entity_path) else {
+            return vec![issue(ValidationIssueKind::MissingEntity)];
+        };
+
+        let mut issues = Vec::new();
+
+        for archetype in &self.archetypes {
+            let is_present = descriptors.iter().any(|descr| {
+                descr.archetype.is_some_and(|name| {
+                    name.as_str() == archetype || name.short_name() == archetype
+                })
+            });
+            if !is_present {
+                issues.push(issue(ValidationIssueKind::MissingArchetype {
+                    archetype: archetype.clone(),
+                }));
+            }
+        }
+
+        for component in &self.components {
+            if !descriptors
+                .iter()
+                .any(|descr| descr.component.as_str() == component)
+            {
+                issues.push(issue(ValidationIssueKind::MissingComponent {
+                    component: component.clone(),
+                }));
+            }
+        }
+
+        if let Some(rate) = &self.rate {
+            issues.extend(rate.validate(store, &self.entity_path).map(issue));
+        }
+
+        issues
+    }
+}
+
+impl RateBounds {
+    #[inline]
+    pub fn new(timeline: impl Into<String>) -> Self {
+        Self {
+            timeline: timeline.into(),
+            min_hz: None,
+            max_hz: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_min_hz(mut self, min_hz: f64) -> Self {
+        self.min_hz = Some(min_hz);
+        self
+    }
+
+    #[inline]
+    pub fn with_max_hz(mut self, max_hz: f64) -> Self {
+        self.max_hz = Some(max_hz);
+        self
+    }
+
+    fn validate(
+        &self,
+        store: &ChunkStore,
+        entity_path: &EntityPath,
+    ) -> Option<ValidationIssueKind> {
+        let timeline = TimelineName::new(&self.timeline);
+        let Some(time_type) = store.time_column_type(&timeline) else {
+            return Some(ValidationIssueKind::MissingTimeline {
+                timeline: self.timeline.clone(),
+            });
+        };
+
+        let times: BTreeSet<i64> = store
+            .iter_chunks()
+            .filter(|chunk| chunk.entity_path() == entity_path && !chunk.components().is_empty())
+            .filter_map(|chunk| chunk.timelines().get(&timeline))
+            .flat_map(|time_column| time_column.times_raw().iter().copied())
+            .collect();
+
+        let actual_hz = match (times.first(), times.last()) {
+            (Some(first), Some(last)) if first < last => {
+                let span = (last - first) as f64;
+                let span = match time_type {
+                    TimeType::Sequence => span,
+                    TimeType::DurationNs | TimeType::TimestampNs => span / 1e9,
+                };
+                (times.len() - 1) as f64 / span
+            }
+            _ => 0.0,
+        };
+
+        if let Some(min_hz) = self.min_hz
+            && actual_hz < min_hz
+        {
+            Some(ValidationIssueKind::RateTooLow {
+                timeline: self.timeline.clone(),
+                min_hz,
+                actual_hz,
+            })
+        } else if let Some(max_hz) = self.max_hz
+            && actual_hz > max_hz
+        {
+            Some(ValidationIssueKind::RateTooHigh {
+                timeline: self.timeline.clone(),
+                max_hz,
+                actual_hz,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+// ---
+
+/// The result of validating a recording against a [`RecordingSchema`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ValidationReport {
+    /// Everything that doesn't match the schema, empty if the recording is valid.
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Whether the recording matches the schema.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// A mismatch between a recording and its [`RecordingSchema`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ValidationIssue {
+    pub entity_path: EntityPath,
+
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub kind: ValidationIssueKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(tag = "kind", rename_all = "snake_case")
+)]
+pub enum ValidationIssueKind {
+    /// Nothing was logged to the entity.
+    MissingEntity,
+
+    MissingArchetype {
+        archetype: String,
+    },
+
+    MissingComponent {
+        component: String,
+    },
+
+    /// The timeline of the rate bounds doesn't exist in the recording.
+    MissingTimeline {
+        timeline: String,
+    },
+
+    RateTooLow {
+        timeline: String,
+        min_hz: f64,
+        actual_hz: f64,
+    },
+
+    RateTooHigh {
+        timeline: String,
+        max_hz: f64,
+        actual_hz: f64,
+    },
+}
+
+impl std::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { entity_path, kind } = self;
+        match kind {
+            ValidationIssueKind::MissingEntity => write!(f, "{entity_path}: missing entity"),
+            ValidationIssueKind::MissingArchetype { archetype } => {
+                write!(f, "{entity_path}: missing archetype {archetype}")
+            }
+            ValidationIssueKind::MissingComponent { component } => {
+                write!(f, "{entity_path}: missing component {component}")
+            }
+            ValidationIssueKind::MissingTimeline { timeline } => {
+                write!(f, "{entity_path}: missing timeline {timeline:?}")
+            }
+            ValidationIssueKind::RateTooLow {
+                timeline,
+                min_hz,
+                actual_hz,
+            } => write!(
+                f,
+                "{entity_path}: logged at {actual_hz:.2} Hz on {timeline:?}, expected at least {min_hz} Hz"
+            ),
+            ValidationIssueKind::RateTooHigh {
+                timeline,
+                max_hz,
+                actual_hz,
+            } => write!(
+                f,
+                "{entity_path}: logged at {actual_hz:.2} Hz on {timeline:?}, expected at most {max_hz} Hz"
+            ),
+        }
+    }
+}
+
+impl EntityDb {
+    /// Validates the contents of the recording against `schema`.
+    pub fn validate(&self, schema: &RecordingSchema) -> ValidationReport {
+        schema.validate(self.storage_engine().store())
+    }
+}
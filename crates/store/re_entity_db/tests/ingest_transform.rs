@@ -0,0 +1,77 @@
+// https://github.com/rust-lang/rust-clippy/issues/10011
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use re_chunk::{Chunk, RowId};
+use re_entity_db::{EntityDb, IngestTransform};
+use re_log_types::{
+    StoreId, StoreKind, TimePoint, Timeline,
+    example_components::{MyColor, MyPoints},
+};
+
+/// Blacks out all the colors logged to `secret`.
+struct Redact;
+
+impl IngestTransform for Redact {
+    fn name(&self) -> &str {
+        "redact"
+    }
+
+    fn transform(&self, _recording: &EntityDb, chunk: &Chunk) -> Option<Chunk> {
+        if chunk.entity_path() != &"secret".into() {
+            return None;
+        }
+
+        let mut builder = Chunk::builder(chunk.entity_path().clone());
+        for row_id in chunk.row_ids() {
+            builder = builder.with_component_batch(
+                row_id,
+                TimePoint::default(),
+                (MyPoints::descriptor_colors(), &MyColor::from_iter([0])),
+            );
+        }
+        builder.build().ok()
+    }
+}
+
+#[test]
+fn ingest_transform() -> anyhow::Result<()> {
+    let mut db = EntityDb::new(StoreId::random(StoreKind::Recording, "test_app"));
+    db.add_ingest_transform(Arc::new(Redact));
+
+    let timeline = Timeline::new_sequence("frame");
+    for entity_path in ["public", "secret"] {
+        let chunk = Chunk::builder(entity_path)
+            .with_component_batch(
+                RowId::new(),
+                [(timeline, 1)],
+                (
+                    MyPoints::descriptor_colors(),
+                    &MyColor::from_iter([0xFF00_00FF]),
+                ),
+            )
+            .build()?;
+        db.add_chunk(&Arc::new(chunk))?;
+    }
+
+    let colors = |entity_path: &str| {
+        let engine = db.storage_engine();
+        engine
+            .store()
+            .iter_chunks()
+            .filter(|chunk| chunk.entity_path() == &entity_path.into())
+            .flat_map(|chunk| {
+                chunk
+                    .iter_component::<MyColor>(&MyPoints::descriptor_colors())
+                    .flat_map(|colors| colors.to_vec())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(colors("public"), vec![MyColor(0xFF00_00FF)]);
+    assert_eq!(colors("secret"), vec![MyColor(0)]);
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+// https://github.com/rust-lang/rust-clippy/issues/10011
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use re_chunk::{Chunk, RowId};
+use re_entity_db::{
+    EntityDb, EntitySchema, RateBounds, RecordingSchema, ValidationIssue, ValidationIssueKind,
+};
+use re_log_types::{
+    EntityPath, StoreId, StoreKind, TimePoint, Timeline,
+    example_components::{MyPoint, MyPoints},
+};
+
+#[test]
+fn schema_validation() -> anyhow::Result<()> {
+    let mut db = EntityDb::new(StoreId::random(StoreKind::Recording, "test_app"));
+
+    // 10 Hz for one second.
+    let timeline = Timeline::new_duration("sensor_time");
+    let mut builder = Chunk::builder("robot/lidar");
+    for i in 0..=10 {
+        builder = builder.with_archetype(
+            RowId::new(),
+            TimePoint::from([(timeline, i * 100_000_000)]),
+            &MyPoints::new([MyPoint::new(1.0, 2.0)]),
+        );
+    }
+    db.add_chunk(&Arc::new(builder.build()?))?;
+
+    let lidar = EntitySchema::new("robot/lidar")
+        .with_archetype("example.MyPoints")
+        .with_archetype("MyPoints")
+        .with_component("example.MyPoints:points");
+
+    assert!(
+        db.validate(
+            &RecordingSchema::default().with_entity(
+                lidar.clone().with_rate(
+                    RateBounds::new("sensor_time")
+                        .with_min_hz(9.0)
+                        .with_max_hz(11.0)
+                )
+            )
+        )
+        .is_valid()
+    );
+
+    let report = db.validate(
+        &RecordingSchema::default()
+            .with_entity(
+                lidar
+                    .with_archetype("Points3D")
+                    .with_component(MyPoints::descriptor_colors().component.to_string())
+                    .with_rate(RateBounds::new("sensor_time").with_min_hz(20.0)),
+            )
+            .with_entity(
+                EntitySchema::new("robot/camera")
+                    .with_rate(RateBounds::new("frame").with_min_hz(1.0)),
+            ),
+    );
+
+    let lidar = EntityPath::from("robot/lidar");
+    assert_eq!(
+        report.issues,
+        vec![
+            ValidationIssue {
+                entity_path: lidar.clone(),
+                kind: ValidationIssueKind::MissingArchetype {
+                    archetype: "Points3D".to_owned()
+                },
+            },
+            ValidationIssue {
+                entity_path: lidar.clone(),
+                kind: ValidationIssueKind::MissingComponent {
+                    component: "example.MyPoints:colors".to_owned()
+                },
+            },
+            ValidationIssue {
+                entity_path: lidar,
+                kind: ValidationIssueKind::RateTooLow {
+                    timeline: "sensor_time".to_owned(),
+                    min_hz: 20.0,
+                    actual_hz: 10.0,
+                },
+            },
+            ValidationIssue {
+                entity_path: EntityPath::from("robot/camera"),
+                kind: ValidationIssueKind::MissingEntity,
+            },
+        ]
+    );
+
+    Ok(())
+}
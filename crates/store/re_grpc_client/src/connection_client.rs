@@ -22,7 +22,10 @@ use re_protos::{
         ext::{IfDuplicateBehavior, IfMissingBehavior, PartitionId, ScanParameters},
     },
     frontend::v1alpha1::{
-        ext::{RegisterWithDatasetRequest, ScanPartitionTableRequest},
+        ext::{
+            FindPartitionsRequest, RegisterWithDatasetRequest, ScanPartitionTableRequest,
+            UpdatePartitionTagsRequest,
+        },
         frontend_service_client::FrontendServiceClient,
     },
     manifest_registry::v1alpha1::{
@@ -379,4 +382,64 @@ where
 
         Ok(())
     }
+
+    /// Adds tags to, and removes tags from, a partition, and returns all of its tags.
+    ///
+    /// Tags are names with an optional value, e.g. `("mission", Some("survey-42"))`.
+    pub async fn update_partition_tags(
+        &mut self,
+        dataset_id: EntryId,
+        partition_id: PartitionId,
+        add_tags: Vec<(String, Option<String>)>,
+        remove_tags: Vec<String>,
+    ) -> Result<Vec<(String, Option<String>)>, StreamError> {
+        let response = self
+            .inner()
+            .update_partition_tags(tonic::Request::new(
+                UpdatePartitionTagsRequest {
+                    dataset_id,
+                    partition_id,
+                    add_tags,
+                    remove_tags,
+                }
+                .into(),
+            ))
+            .await?
+            .into_inner();
+
+        Ok(response
+            .tags
+            .into_iter()
+            .map(|tag| (tag.name, tag.value))
+            .collect())
+    }
+
+    /// Returns the partitions that have all of `tags`, and data within `time_range` if any.
+    ///
+    /// A tag without a value matches any value.
+    pub async fn find_partitions(
+        &mut self,
+        dataset_id: EntryId,
+        tags: Vec<(String, Option<String>)>,
+        time_range: Option<(re_log_types::TimelineName, re_log_types::AbsoluteTimeRange)>,
+    ) -> Result<Vec<PartitionId>, StreamError> {
+        let response = self
+            .inner()
+            .find_partitions(tonic::Request::new(
+                FindPartitionsRequest {
+                    dataset_id,
+                    tags,
+                    time_range,
+                }
+                .into(),
+            ))
+            .await?
+            .into_inner();
+
+        Ok(response
+            .partition_ids
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
 }
@@ -27,6 +27,11 @@ pub struct ConnectionRegistry {
     /// If set, the fallback token is used when no specific token is registered for a given origin.
     fallback_token: Option<Jwt>,
 
+    /// The token presented to message proxies, see [`crate::message_proxy::TokenInterceptor`].
+    ///
+    /// Unlike the redap tokens, this is an opaque string, and it is never persisted.
+    proxy_token: Option<String>,
+
     /// The cached clients.
     ///
     /// Clients are much cheaper to clone than create (since the latter involves establishing an
@@ -42,6 +47,7 @@ impl ConnectionRegistry {
             inner: Arc::new(RwLock::new(Self {
                 saved_tokens: HashMap::new(),
                 fallback_token: None,
+                proxy_token: None,
                 clients: HashMap::new(),
             })),
         }
@@ -87,6 +93,21 @@ impl ConnectionRegistryHandle {
         });
     }
 
+    pub fn set_proxy_token(&self, token: Option<String>) {
+        wrap_blocking_lock(|| {
+            let mut inner = self.inner.blocking_write();
+            inner.proxy_token = token;
+        });
+    }
+
+    /// The token to present to message proxies, if any.
+    pub fn proxy_token(&self) -> Option<String> {
+        wrap_blocking_lock(|| {
+            let inner = self.inner.blocking_read();
+            inner.proxy_token.clone()
+        })
+    }
+
     /// Get a client for the given origin, creating one if it doesn't exist yet.
     ///
     /// Note: although `RedapClient` is cheap to clone, callsites should generally *not* hold on to
@@ -1,6 +1,9 @@
 pub mod read;
 pub use read::stream;
 
+mod token;
+pub use token::{TOKEN_ENV_VAR, TokenInterceptor, token_from_env};
+
 #[cfg(not(target_arch = "wasm32"))]
 pub mod write;
 
@@ -9,3 +12,15 @@ pub use write::Client;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub mod write_table;
+
+/// The endpoint of a message proxy, reached over TLS for `rerun+https` origins.
+#[cfg(not(target_arch = "wasm32"))]
+fn endpoint(
+    origin: &re_uri::Origin,
+) -> Result<tonic::transport::Endpoint, tonic::transport::Error> {
+    tonic::transport::Endpoint::new(origin.as_url())?.tls_config(
+        tonic::transport::ClientTlsConfig::new()
+            .with_enabled_roots()
+            .assume_http2(true),
+    )
+}
@@ -5,6 +5,7 @@ use re_protos::sdk_comms::v1alpha1::ReadMessagesResponse;
 use re_protos::sdk_comms::v1alpha1::message_proxy_service_client::MessageProxyServiceClient;
 use tokio_stream::StreamExt as _;
 
+use super::TokenInterceptor;
 use crate::MAX_DECODING_MESSAGE_SIZE;
 use crate::StreamError;
 use crate::TonicStatusError;
@@ -12,8 +13,11 @@ use crate::TonicStatusError;
 /// Read log messages from a proxy server.
 ///
 /// This is used by the viewer to _receive_ log messages.
+///
+/// `token` is only needed by proxies that let in clients with a token, see [`super::TOKEN_ENV_VAR`].
 pub fn stream(
     uri: re_uri::ProxyUri,
+    token: Option<String>,
     on_msg: Option<Box<dyn Fn() + Send + Sync>>,
 ) -> re_smart_channel::Receiver<LogMsg> {
     re_log::debug!("Loading {uri} via gRPC…");
@@ -24,7 +28,7 @@ pub fn stream(
     );
 
     crate::spawn_future(async move {
-        if let Err(err) = stream_async(uri, &tx, on_msg).await {
+        if let Err(err) = stream_async(uri, token, &tx, on_msg).await {
             tx.quit(Some(Box::new(err))).ok();
         }
     });
@@ -34,14 +38,14 @@ pub fn stream(
 
 async fn stream_async(
     uri: re_uri::ProxyUri,
+    token: Option<String>,
     tx: &re_smart_channel::Sender<LogMsg>,
     on_msg: Option<Box<dyn Fn() + Send + Sync>>,
 ) -> Result<(), StreamError> {
     let mut client = {
-        let url = uri.origin.as_url();
-
         #[cfg(target_arch = "wasm32")]
         let tonic_client = {
+            let url = uri.origin.as_url();
             tonic_web_wasm_client::Client::new_with_options(
                 url,
                 tonic_web_wasm_client::options::FetchOptions::new(),
@@ -49,9 +53,9 @@ async fn stream_async(
         };
 
         #[cfg(not(target_arch = "wasm32"))]
-        let tonic_client = { tonic::transport::Endpoint::new(url)?.connect().await? };
+        let tonic_client = { super::endpoint(&uri.origin)?.connect().await? };
 
-        MessageProxyServiceClient::new(tonic_client)
+        MessageProxyServiceClient::with_interceptor(tonic_client, TokenInterceptor::new(token))
             .max_decoding_message_size(MAX_DECODING_MESSAGE_SIZE)
     };
 
@@ -0,0 +1,56 @@
+//! Tokens that clients present to a message proxy that only lets some of them in.
+
+use tonic::{Request, Status, metadata::errors::InvalidMetadataValue, service::Interceptor};
+
+/// The environment variable entry points read the token of a client from, see [`token_from_env`].
+pub const TOKEN_ENV_VAR: &str = "RERUN_PROXY_TOKEN";
+
+/// The metadata key holding the token of a client.
+const AUTHORIZATION_KEY: &str = "authorization";
+
+/// The prefix of the token in the metadata.
+const TOKEN_PREFIX: &str = "Bearer ";
+
+/// Reads the token of a client from [`TOKEN_ENV_VAR`], if set.
+pub fn token_from_env() -> Option<String> {
+    std::env::var(TOKEN_ENV_VAR)
+        .ok()
+        .map(|token| token.trim().to_owned())
+        .filter(|token| !token.is_empty())
+}
+
+/// Adds the token of a client, if any, to each of its requests to a message proxy.
+#[derive(Clone, Default)]
+pub struct TokenInterceptor {
+    token: Option<String>,
+}
+
+impl TokenInterceptor {
+    pub fn new(token: Option<String>) -> Self {
+        Self { token }
+    }
+}
+
+impl std::fmt::Debug for TokenInterceptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokenInterceptor")
+            .field("has_token", &self.token.is_some())
+            .finish()
+    }
+}
+
+impl Interceptor for TokenInterceptor {
+    fn call(&mut self, mut req: Request<()>) -> Result<Request<()>, Status> {
+        if let Some(token) = &self.token {
+            let token = format!("{TOKEN_PREFIX}{}", token.trim()).parse().map_err(
+                |err: InvalidMetadataValue| {
+                    re_log::error!("malformed message proxy token: {err}");
+                    Status::invalid_argument("malformed token")
+                },
+            )?;
+            req.metadata_mut().insert(AUTHORIZATION_KEY, token);
+        }
+
+        Ok(req)
+    }
+}
@@ -3,6 +3,8 @@ use std::thread;
 use std::thread::JoinHandle;
 use std::time::Duration;
 
+use super::TokenInterceptor;
+use crate::TonicStatusError;
 use re_chunk::external::crossbeam::atomic::AtomicCell;
 use re_log_encoding::Compression;
 use re_log_types::LogMsg;
@@ -16,9 +18,6 @@ use tokio::sync::mpsc::Sender;
 use tokio::sync::mpsc::UnboundedReceiver;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::oneshot;
-use tonic::transport::Endpoint;
-
-use crate::TonicStatusError;
 
 enum Cmd {
     LogMsg(LogMsg),
@@ -29,6 +28,9 @@ enum Cmd {
 pub struct Options {
     pub compression: Compression,
     pub flush_timeout: Option<Duration>,
+
+    /// Only needed by proxies that let in clients with a token, see [`super::TOKEN_ENV_VAR`].
+    pub token: Option<String>,
 }
 
 impl Default for Options {
@@ -36,6 +38,7 @@ impl Default for Options {
         Self {
             compression: Compression::LZ4,
             flush_timeout: Default::default(),
+            token: None,
         }
     }
 }
@@ -78,7 +81,6 @@ pub struct Client {
 }
 
 impl Client {
-    #[expect(clippy::needless_pass_by_value)]
     pub fn new(uri: ProxyUri, options: Options) -> Self {
         let (cmd_tx, cmd_rx) = mpsc::unbounded_channel();
         let (shutdown_tx, shutdown_rx) = mpsc::channel(1);
@@ -99,6 +101,7 @@ impl Client {
                             cmd_rx,
                             shutdown_rx,
                             options.compression,
+                            options.token,
                             status,
                         ));
                 })
@@ -187,9 +190,10 @@ async fn message_proxy_client(
     mut cmd_rx: UnboundedReceiver<Cmd>,
     mut shutdown_rx: Receiver<()>,
     compression: Compression,
+    token: Option<String>,
     status: Arc<AtomicCell<ClientConnectionState>>,
 ) {
-    let endpoint = match Endpoint::from_shared(uri.origin.as_url()) {
+    let endpoint = match super::endpoint(&uri.origin) {
         Ok(endpoint) => endpoint,
         Err(err) => {
             status.store(ClientConnectionState::Disconnected(Err(
@@ -220,8 +224,9 @@ async fn message_proxy_client(
 
     status.store(ClientConnectionState::Connected);
 
-    let mut client = MessageProxyServiceClient::new(channel)
-        .max_decoding_message_size(crate::MAX_DECODING_MESSAGE_SIZE);
+    let mut client =
+        MessageProxyServiceClient::with_interceptor(channel, TokenInterceptor::new(token))
+            .max_decoding_message_size(crate::MAX_DECODING_MESSAGE_SIZE);
 
     let stream_status = status.clone();
     let stream = async_stream::stream! {
@@ -1,13 +1,22 @@
 use re_protos::sdk_comms::v1alpha1::message_proxy_service_client::MessageProxyServiceClient;
 
 #[cfg(not(target_arch = "wasm32"))]
-pub type ViewerClient = MessageProxyServiceClient<tonic::transport::Channel>;
+pub type ViewerClient = MessageProxyServiceClient<
+    tonic::service::interceptor::InterceptedService<
+        tonic::transport::Channel,
+        super::TokenInterceptor,
+    >,
+>;
 
+/// `token` is only needed by proxies that let in clients with a token, see [`super::TOKEN_ENV_VAR`].
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn viewer_client(
     origin: re_uri::Origin,
+    token: Option<String>,
 ) -> Result<ViewerClient, crate::redap::ConnectionError> {
     let channel = crate::redap::channel(origin).await?;
-    Ok(MessageProxyServiceClient::new(channel)
-        .max_decoding_message_size(crate::MAX_DECODING_MESSAGE_SIZE))
+    Ok(
+        MessageProxyServiceClient::with_interceptor(channel, super::TokenInterceptor::new(token))
+            .max_decoding_message_size(crate::MAX_DECODING_MESSAGE_SIZE),
+    )
 }
@@ -0,0 +1,153 @@
+//! Access control for the message proxy.
+//!
+//! Clients pass their token in the `authorization` metadata of every request, as `Bearer <token>`.
+
+use std::collections::HashMap;
+
+/// The metadata key holding the token of a client.
+const AUTHORIZATION_KEY: &str = "authorization";
+
+/// The prefix of the token in the metadata.
+const TOKEN_PREFIX: &str = "Bearer ";
+
+/// What a client is allowed to do.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Permission {
+    /// Can only receive data, e.g. a viewer.
+    Read,
+
+    /// Can only send data, e.g. a logging SDK.
+    Write,
+
+    /// Can both send and receive data.
+    ReadWrite,
+}
+
+impl Permission {
+    #[inline]
+    fn allows(self, required: Self) -> bool {
+        self == Self::ReadWrite || self == required
+    }
+}
+
+/// The tokens accepted by a server, and what each of them allows.
+///
+/// A server with a [`ServerAuth`] rejects all the requests that don't carry one of its tokens.
+#[derive(Clone, Default)]
+pub struct ServerAuth {
+    tokens: HashMap<String, Permission>,
+}
+
+impl ServerAuth {
+    /// Accepts `token`, granting `permission` to the clients that use it.
+    ///
+    /// Granting several permissions to the same token combines them.
+    pub fn with_token(mut self, token: impl Into<String>, permission: Permission) -> Self {
+        self.tokens
+            .entry(token.into())
+            .and_modify(|granted| {
+                if *granted != permission {
+                    *granted = Permission::ReadWrite;
+                }
+            })
+            .or_insert(permission);
+        self
+    }
+
+    /// Checks that the client that sent `request` is allowed to do what `required` stands for.
+    pub(crate) fn check<T>(
+        &self,
+        request: &tonic::Request<T>,
+        required: Permission,
+    ) -> tonic::Result<()> {
+        let Some(value) = request.metadata().get(AUTHORIZATION_KEY) else {
+            return Err(tonic::Status::unauthenticated("missing credentials"));
+        };
+        let token = value
+            .to_str()
+            .ok()
+            .and_then(|value| value.strip_prefix(TOKEN_PREFIX))
+            .ok_or_else(|| tonic::Status::unauthenticated("malformed credentials"))?;
+
+        // Every token is compared so that timing doesn't leak which one almost matched.
+        let permission = self
+            .tokens
+            .iter()
+            .filter(|(known, _)| constant_time_eq(known.as_bytes(), token.as_bytes()))
+            .map(|(_, permission)| *permission)
+            .last()
+            .ok_or_else(|| tonic::Status::unauthenticated("invalid credentials"))?;
+
+        if permission.allows(required) {
+            Ok(())
+        } else {
+            Err(tonic::Status::permission_denied(format!(
+                "{required:?} access is not allowed with these credentials"
+            )))
+        }
+    }
+}
+
+impl std::fmt::Debug for ServerAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServerAuth")
+            .field("num_tokens", &self.tokens.len())
+            .finish()
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn request(token: Option<&str>) -> tonic::Request<()> {
+        let mut request = tonic::Request::new(());
+        if let Some(token) = token {
+            request
+                .metadata_mut()
+                .insert(AUTHORIZATION_KEY, token.parse().unwrap());
+        }
+        request
+    }
+
+    #[test]
+    fn checks_tokens_and_permissions() {
+        let auth = ServerAuth::default()
+            .with_token("viewer", Permission::Read)
+            .with_token("logger", Permission::Write)
+            .with_token("admin", Permission::Read)
+            .with_token("admin", Permission::Write);
+
+        let code = |token, required| {
+            auth.check(&request(token), required)
+                .err()
+                .map(|status| status.code())
+        };
+
+        assert_eq!(None, code(Some("Bearer viewer"), Permission::Read));
+        assert_eq!(
+            Some(tonic::Code::PermissionDenied),
+            code(Some("Bearer viewer"), Permission::Write)
+        );
+        assert_eq!(None, code(Some("Bearer logger"), Permission::Write));
+        assert_eq!(
+            Some(tonic::Code::PermissionDenied),
+            code(Some("Bearer logger"), Permission::Read)
+        );
+        assert_eq!(None, code(Some("Bearer admin"), Permission::Read));
+        assert_eq!(None, code(Some("Bearer admin"), Permission::Write));
+
+        for token in [None, Some("viewer"), Some("Bearer nobody")] {
+            assert_eq!(
+                Some(tonic::Code::Unauthenticated),
+                code(token, Permission::Read)
+            );
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Notifications about what happens on a server, see `ReadEvents`.
+
+use std::pin::Pin;
+
+use re_protos::{
+    common::v1alpha1::StoreId as StoreIdProto,
+    sdk_comms::v1alpha1::{ReadEventsResponse, ServerError, read_events_response::Event},
+};
+use tokio::sync::broadcast;
+use tokio_stream::Stream;
+use tokio_stream::StreamExt as _;
+use tokio_stream::wrappers::{BroadcastStream, errors::BroadcastStreamRecvError};
+
+/// Subscribers lagging further behind than this miss events.
+const EVENT_QUEUE_CAPACITY: usize = 1024;
+
+pub(crate) type ReadEventsStream =
+    Pin<Box<dyn Stream<Item = tonic::Result<ReadEventsResponse>> + Send>>;
+
+/// Sends server events to all the clients that called `ReadEvents`.
+///
+/// Events aren't kept around: subscribers only see what happens after they subscribed.
+#[derive(Clone)]
+pub(crate) struct Events {
+    tx: broadcast::Sender<ReadEventsResponse>,
+}
+
+impl Events {
+    pub fn new() -> Self {
+        let (tx, _) = broadcast::channel(EVENT_QUEUE_CAPACITY);
+        Self { tx }
+    }
+
+    /// Sends the event made by `make_event` to all subscribers.
+    ///
+    /// `make_event` is only called if there is anyone to send it to.
+    pub fn publish(&self, make_event: impl FnOnce() -> Event) {
+        if self.tx.receiver_count() > 0 {
+            self.tx
+                .send(ReadEventsResponse {
+                    event: Some(make_event()),
+                })
+                .ok();
+        }
+    }
+
+    pub fn publish_error(&self, message: String, store_id: Option<StoreIdProto>) {
+        self.publish(|| Event::Error(ServerError { message, store_id }));
+    }
+
+    /// The events from now on.
+    ///
+    /// A subscriber that can't keep up skips the events it missed, rather than being disconnected.
+    pub fn subscribe(&self) -> ReadEventsStream {
+        Box::pin(
+            BroadcastStream::new(self.tx.subscribe()).filter_map(|result| match result {
+                Ok(event) => Some(Ok(event)),
+                Err(BroadcastStreamRecvError::Lagged(n)) => {
+                    re_log::warn!("Events subscriber fell behind, skipping {n} event(s)");
+                    None
+                }
+            }),
+        )
+    }
+}
@@ -1,16 +1,25 @@
 //! Server implementation of an in-memory Storage Node ("proxy").
 
+pub mod auth;
+mod events;
+mod mcap;
+pub mod retention;
 pub mod shutdown;
+mod spool;
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::pin::Pin;
 
 use re_byte_size::SizeBytes;
 use re_log_encoding::codec::wire::decoder::Decode as _;
 use re_log_types::TableMsg;
+use re_protos::sdk_comms::v1alpha1::ReadEventsRequest;
 use re_protos::sdk_comms::v1alpha1::ReadTablesRequest;
 use re_protos::sdk_comms::v1alpha1::ReadTablesResponse;
+use re_protos::sdk_comms::v1alpha1::WriteMcapRequest;
+use re_protos::sdk_comms::v1alpha1::WriteMcapResponse;
 use re_protos::sdk_comms::v1alpha1::WriteMessagesRequest;
 use re_protos::sdk_comms::v1alpha1::WriteTableRequest;
 use re_protos::sdk_comms::v1alpha1::WriteTableResponse;
@@ -28,12 +37,14 @@ use tower_http::cors::CorsLayer;
 use re_memory::MemoryLimit;
 use re_protos::{
     common::v1alpha1::{
-        DataframePart as DataframePartProto, StoreKind as StoreKindProto, TableId as TableIdProto,
+        DataframePart as DataframePartProto, StoreId as StoreIdProto, StoreKind as StoreKindProto,
+        TableId as TableIdProto,
     },
     log_msg::v1alpha1::LogMsg as LogMsgProto,
     sdk_comms::v1alpha1::{
-        ReadMessagesRequest, ReadMessagesResponse, WriteMessagesResponse,
-        message_proxy_service_server,
+        ChunkIngested, ReadMessagesRequest, ReadMessagesResponse, RecordingAdded, RecordingEvicted,
+        WriteMessagesResponse, message_proxy_service_server,
+        read_events_response::Event as ServerEvent,
     },
 };
 
@@ -83,6 +94,63 @@ impl From<tonic::Status> for TonicStatusError {
     }
 }
 
+/// Settings of a Rerun server, beyond its address and memory limit.
+///
+/// The default lets any client connect over plain HTTP, which is only safe on localhost,
+/// and keeps all recordings until the memory limit is reached.
+#[derive(Clone, Debug, Default)]
+pub struct ServerOptions {
+    /// Tokens that clients must present, see [`auth::ServerAuth`].
+    ///
+    /// `None` lets every client read and write.
+    pub auth: Option<auth::ServerAuth>,
+
+    /// Serve over TLS instead of plain HTTP.
+    ///
+    /// Requiring clients to present a certificate (mTLS) is done with
+    /// [`tonic::transport::ServerTlsConfig::client_ca_root`].
+    #[cfg(feature = "tls")]
+    pub tls: Option<tonic::transport::ServerTlsConfig>,
+
+    /// Which recordings are kept for the clients that connect later.
+    pub retention: retention::RetentionPolicy,
+
+    /// Also write every incoming stream to an `.rrd` file in this directory, as it arrives,
+    /// so that it outlives the memory limit, the retention policy, and the server itself.
+    ///
+    /// Each recording gets its own `<recording_id>.rrd` file, each blueprint its own
+    /// `<recording_id>.rbl` file. Existing files are appended to.
+    pub spool_dir: Option<PathBuf>,
+
+    /// The directory clients can have MCAP files converted from with `WriteMcap`, by giving a path
+    /// relative to it.
+    ///
+    /// `None` only lets clients upload the contents of the files.
+    pub load_dir: Option<PathBuf>,
+}
+
+#[cfg(feature = "tls")]
+impl ServerOptions {
+    /// Serve over TLS, presenting the PEM encoded certificate chain `cert` with its private `key`.
+    ///
+    /// If `client_ca` is given, only the clients presenting a certificate signed by it are let in.
+    pub fn with_tls_pem(
+        mut self,
+        cert: impl AsRef<[u8]>,
+        key: impl AsRef<[u8]>,
+        client_ca: Option<impl AsRef<[u8]>>,
+    ) -> Self {
+        use tonic::transport::{Certificate, Identity, ServerTlsConfig};
+
+        let mut tls = ServerTlsConfig::new().identity(Identity::from_pem(cert, key));
+        if let Some(client_ca) = client_ca {
+            tls = tls.client_ca_root(Certificate::from_pem(client_ca));
+        }
+        self.tls = Some(tls);
+        self
+    }
+}
+
 // TODO(jan): Refactor `serve`/`spawn` variants into a builder?
 
 /// Start a Rerun server, listening on `addr`.
@@ -106,21 +174,44 @@ pub async fn serve(
     memory_limit: MemoryLimit,
     shutdown: shutdown::Shutdown,
 ) -> anyhow::Result<()> {
-    serve_impl(addr, MessageProxy::new(memory_limit), shutdown).await
+    serve_with_options(addr, memory_limit, ServerOptions::default(), shutdown).await
+}
+
+/// Same as [`serve`], with authentication and/or TLS, see [`ServerOptions`].
+pub async fn serve_with_options(
+    addr: SocketAddr,
+    memory_limit: MemoryLimit,
+    options: ServerOptions,
+    shutdown: shutdown::Shutdown,
+) -> anyhow::Result<()> {
+    let message_proxy = MessageProxy::new_with_options(memory_limit, &options);
+    serve_impl(addr, message_proxy, options, shutdown).await
 }
 
 async fn serve_impl(
     addr: SocketAddr,
-    message_proxy: MessageProxy,
+    mut message_proxy: MessageProxy,
+    options: ServerOptions,
     shutdown: shutdown::Shutdown,
 ) -> anyhow::Result<()> {
     let tcp_listener = TcpListener::bind(addr).await?;
     let incoming = TcpIncoming::from(tcp_listener).with_nodelay(Some(true));
 
+    #[cfg(feature = "tls")]
+    let (server, scheme) = match options.tls {
+        Some(tls) => (Server::builder().tls_config(tls)?, "rerun+https"),
+        None => (Server::builder(), "rerun+http"),
+    };
+    #[cfg(not(feature = "tls"))]
+    let (server, scheme) = (Server::builder(), "rerun+http");
+
+    message_proxy.auth = options.auth;
+    message_proxy.load_dir = options.load_dir;
+
     let connect_addr = if addr.ip().is_loopback() || addr.ip().is_unspecified() {
-        format!("rerun+http://127.0.0.1:{}/proxy", addr.port())
+        format!("{scheme}://127.0.0.1:{}/proxy", addr.port())
     } else {
-        format!("rerun+http://{addr}/proxy")
+        format!("{scheme}://{addr}/proxy")
     };
     re_log::info!(
         "Listening for gRPC connections on {addr}. Connect by running `rerun --connect {connect_addr}`"
@@ -141,7 +232,7 @@ async fn serve_impl(
         routes_builder.routes()
     };
 
-    Server::builder()
+    server
         .accept_http1(true) // Support `grpc-web` clients
         .layer(cors) // Allow CORS requests from web clients
         .layer(grpc_web) // Support `grpc-web` clients
@@ -220,7 +311,7 @@ pub async fn serve_from_channel(
         }
     });
 
-    if let Err(err) = serve_impl(addr, message_proxy, shutdown).await {
+    if let Err(err) = serve_impl(addr, message_proxy, ServerOptions::default(), shutdown).await {
         re_log::error!("message proxy server crashed: {err}");
     }
 }
@@ -236,14 +327,15 @@ pub async fn serve_from_channel(
 pub fn spawn_from_rx_set(
     addr: SocketAddr,
     memory_limit: MemoryLimit,
+    options: ServerOptions,
     shutdown: shutdown::Shutdown,
     rxs: re_smart_channel::ReceiveSet<re_log_types::LogMsg>,
 ) {
-    let message_proxy = MessageProxy::new(memory_limit);
+    let message_proxy = MessageProxy::new_with_options(memory_limit, &options);
     let event_tx = message_proxy.event_tx.clone();
 
     tokio::spawn(async move {
-        if let Err(err) = serve_impl(addr, message_proxy, shutdown).await {
+        if let Err(err) = serve_impl(addr, message_proxy, options, shutdown).await {
             re_log::error!("message proxy server crashed: {err}");
         }
     });
@@ -309,9 +401,10 @@ pub fn spawn_with_recv(
     );
     let (channel_table_tx, channel_table_rx) = crossbeam::channel::unbounded();
     let (message_proxy, mut broadcast_log_rx, mut broadcast_table_rx) =
-        MessageProxy::new_with_recv(memory_limit);
+        MessageProxy::new_with_recv(memory_limit, Default::default(), None);
     tokio::spawn(async move {
-        if let Err(err) = serve_impl(addr, message_proxy, shutdown).await {
+        if let Err(err) = serve_impl(addr, message_proxy, ServerOptions::default(), shutdown).await
+        {
             re_log::error!("message proxy server crashed: {err}");
         }
     });
@@ -469,11 +562,26 @@ struct EventLoop {
 
     /// Messages potentially out of order with the rest of the message stream. These are never garbage collected.
     persistent_message_queue: VecDeque<LogMsgProto>,
+
+    /// Recordings in the history, evicted according to their retention policy.
+    recordings: retention::Recordings,
+
+    /// Writes all incoming messages to disk, if enabled.
+    spooler: Option<spool::Spooler>,
+
+    /// Tells `ReadEvents` subscribers what happens.
+    events: events::Events,
+
+    /// The recordings subscribers were told about with `RecordingAdded`, by recording id.
+    announced_recordings: HashMap<String, StoreIdProto>,
 }
 
 impl EventLoop {
     fn new(
         server_memory_limit: MemoryLimit,
+        retention: retention::RetentionPolicy,
+        spooler: Option<spool::Spooler>,
+        events: events::Events,
         event_rx: mpsc::Receiver<Event>,
         broadcast_log_tx: broadcast::Sender<LogMsgProto>,
         broadcast_table_tx: broadcast::Sender<TableMsgProto>,
@@ -486,6 +594,10 @@ impl EventLoop {
             ordered_message_queue: Default::default(),
             ordered_message_bytes: 0,
             persistent_message_queue: Default::default(),
+            recordings: retention::Recordings::new(retention),
+            spooler,
+            events,
+            announced_recordings: HashMap::default(),
         }
     }
 
@@ -504,13 +616,16 @@ impl EventLoop {
     }
 
     fn handle_new_client(
-        &self,
+        &mut self,
         channel: oneshot::Sender<(
             Vec<Msg>,
             broadcast::Receiver<LogMsgProto>,
             broadcast::Receiver<TableMsgProto>,
         )>,
     ) {
+        // Don't send expired recordings.
+        self.evict_recordings();
+
         channel
             .send((
                 // static messages come first
@@ -529,6 +644,12 @@ impl EventLoop {
     fn handle_msg(&mut self, msg: LogMsgProto) {
         self.broadcast_log_tx.send(msg.clone()).ok();
 
+        if let Some(spooler) = &self.spooler {
+            spooler.send(&msg);
+        }
+
+        self.publish_ingest_events(&msg);
+
         if self.is_history_disabled() {
             // no need to gc or maintain history
             return;
@@ -550,6 +671,11 @@ impl EventLoop {
         match inner {
             // Store info, blueprint activation commands
             Msg::SetStoreInfo(..) | Msg::BlueprintActivationCommand(..) => {
+                if let Some(recording_id) = retention::recording_id(&msg) {
+                    // Store infos don't count towards the size of the recording: they're tiny.
+                    self.recordings
+                        .on_write(recording_id, 0, std::time::Instant::now());
+                }
                 self.persistent_message_queue.push_back(msg);
             }
 
@@ -566,10 +692,48 @@ impl EventLoop {
             // Recording data
             Msg::ArrowMsg(..) => {
                 let approx_size_bytes = msg.total_size_bytes();
+                if let Some(recording_id) = retention::recording_id(&msg) {
+                    self.recordings.on_write(
+                        recording_id,
+                        approx_size_bytes,
+                        std::time::Instant::now(),
+                    );
+                }
                 self.ordered_message_bytes += approx_size_bytes;
                 self.ordered_message_queue.push_back(msg.into());
             }
         }
+
+        self.evict_recordings();
+    }
+
+    /// Tells the `ReadEvents` subscribers about a new recording, and about the chunk in `msg`.
+    fn publish_ingest_events(&mut self, msg: &LogMsgProto) {
+        let Some(store_id) = retention::recording_store_id(msg) else {
+            return;
+        };
+
+        if let std::collections::hash_map::Entry::Vacant(entry) = self
+            .announced_recordings
+            .entry(store_id.recording_id.clone())
+        {
+            entry.insert(store_id.clone());
+            self.events.publish(|| {
+                ServerEvent::RecordingAdded(RecordingAdded {
+                    store_id: Some(store_id.clone()),
+                })
+            });
+        }
+
+        if let Some(re_protos::log_msg::v1alpha1::log_msg::Msg::ArrowMsg(arrow_msg)) = &msg.msg {
+            self.events.publish(|| {
+                ServerEvent::ChunkIngested(ChunkIngested {
+                    store_id: Some(store_id.clone()),
+                    chunk_id: arrow_msg.chunk_id,
+                    num_bytes: u64::try_from(arrow_msg.uncompressed_size).unwrap_or_default(),
+                })
+            });
+        }
     }
 
     fn handle_table(&mut self, table: TableMsgProto) {
@@ -620,7 +784,13 @@ impl EventLoop {
             while bytes_dropped < bytes_to_free {
                 // only drop messages from temporal queue
                 if let Some(msg) = self.ordered_message_queue.pop_front() {
-                    bytes_dropped += msg.total_size_bytes();
+                    let approx_size_bytes = msg.total_size_bytes();
+                    if let Msg::LogMsg(msg) = &msg
+                        && let Some(recording_id) = retention::recording_id(msg)
+                    {
+                        self.recordings.on_drop(recording_id, approx_size_bytes);
+                    }
+                    bytes_dropped += approx_size_bytes;
                     messages_dropped += 1;
                 } else {
                     break;
@@ -633,6 +803,48 @@ impl EventLoop {
             );
         }
     }
+
+    /// Removes the recordings that have to go according to the retention policy from the history.
+    fn evict_recordings(&mut self) {
+        let evicted = self.recordings.evict(std::time::Instant::now());
+        if evicted.is_empty() {
+            return;
+        }
+
+        re_tracing::profile_function!();
+
+        for recording_id in &evicted {
+            if let Some(store_id) = self.announced_recordings.remove(recording_id) {
+                self.events.publish(|| {
+                    ServerEvent::RecordingEvicted(RecordingEvicted {
+                        store_id: Some(store_id),
+                    })
+                });
+            }
+        }
+
+        let is_evicted = |msg: &LogMsgProto| {
+            retention::recording_id(msg)
+                .is_some_and(|id| evicted.iter().any(|evicted| evicted == id))
+        };
+
+        let mut bytes_dropped = 0;
+        self.ordered_message_queue.retain(|msg| match msg {
+            Msg::LogMsg(log_msg) if is_evicted(log_msg) => {
+                bytes_dropped += msg.total_size_bytes();
+                false
+            }
+            _ => true,
+        });
+        self.ordered_message_bytes -= bytes_dropped;
+        self.persistent_message_queue.retain(|msg| !is_evicted(msg));
+
+        re_log::debug!(
+            "Evicted {} recording(s) from the server history, freeing {}",
+            evicted.len(),
+            re_format::format_bytes(bytes_dropped as _)
+        );
+    }
 }
 
 impl SizeBytes for TableMsgProto {
@@ -645,15 +857,35 @@ impl SizeBytes for TableMsgProto {
 pub struct MessageProxy {
     _queue_task_handle: tokio::task::JoinHandle<()>,
     event_tx: mpsc::Sender<Event>,
+
+    /// `None` lets every client in.
+    auth: Option<auth::ServerAuth>,
+
+    /// See [`ServerOptions::load_dir`].
+    load_dir: Option<PathBuf>,
+
+    events: events::Events,
 }
 
 impl MessageProxy {
     pub fn new(server_memory_limit: MemoryLimit) -> Self {
-        Self::new_with_recv(server_memory_limit).0
+        Self::new_with_recv(server_memory_limit, Default::default(), None).0
+    }
+
+    /// Applies the [`ServerOptions::retention`] and [`ServerOptions::spool_dir`] of `options`.
+    pub fn new_with_options(server_memory_limit: MemoryLimit, options: &ServerOptions) -> Self {
+        Self::new_with_recv(
+            server_memory_limit,
+            options.retention.clone(),
+            options.spool_dir.clone(),
+        )
+        .0
     }
 
     fn new_with_recv(
         server_memory_limit: MemoryLimit,
+        retention: retention::RetentionPolicy,
+        spool_dir: Option<PathBuf>,
     ) -> (
         Self,
         broadcast::Receiver<LogMsgProto>,
@@ -662,28 +894,62 @@ impl MessageProxy {
         let (event_tx, event_rx) = mpsc::channel(MESSAGE_QUEUE_CAPACITY);
         let (broadcast_log_tx, broadcast_log_rx) = broadcast::channel(MESSAGE_QUEUE_CAPACITY);
         let (broadcast_table_tx, broadcast_table_rx) = broadcast::channel(MESSAGE_QUEUE_CAPACITY);
+        let events = events::Events::new();
 
-        let task_handle = tokio::spawn(async move {
-            EventLoop::new(
-                server_memory_limit,
-                event_rx,
-                broadcast_log_tx,
-                broadcast_table_tx,
-            )
-            .run_in_place()
-            .await;
+        let spooler = spool_dir.and_then(|spool_dir| {
+            spool::Spooler::new(spool_dir)
+                .map_err(|err| re_log::error!("Failed to start spooling to disk: {err}"))
+                .ok()
+        });
+
+        let task_handle = tokio::spawn({
+            let events = events.clone();
+            async move {
+                EventLoop::new(
+                    server_memory_limit,
+                    retention,
+                    spooler,
+                    events,
+                    event_rx,
+                    broadcast_log_tx,
+                    broadcast_table_tx,
+                )
+                .run_in_place()
+                .await;
+            }
         });
 
         (
             Self {
                 _queue_task_handle: task_handle,
                 event_tx,
+                auth: None,
+                load_dir: None,
+                events,
             },
             broadcast_log_rx,
             broadcast_table_rx,
         )
     }
 
+    /// Only lets in the clients that present one of the tokens of `auth`.
+    #[inline]
+    pub fn with_auth(mut self, auth: auth::ServerAuth) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    fn check_permission<T>(
+        &self,
+        request: &tonic::Request<T>,
+        required: auth::Permission,
+    ) -> tonic::Result<()> {
+        match &self.auth {
+            Some(auth) => auth.check(request, required),
+            None => Ok(()),
+        }
+    }
+
     async fn push_msg(&self, msg: LogMsgProto) {
         self.event_tx.send(Event::Message(msg)).await.ok();
     }
@@ -788,6 +1054,8 @@ impl message_proxy_service_server::MessageProxyService for MessageProxy {
         &self,
         request: tonic::Request<tonic::Streaming<WriteMessagesRequest>>,
     ) -> tonic::Result<tonic::Response<WriteMessagesResponse>> {
+        self.check_permission(&request, auth::Permission::Write)?;
+
         let mut stream = request.into_inner();
         loop {
             match stream.message().await {
@@ -807,7 +1075,10 @@ impl message_proxy_service_server::MessageProxyService for MessageProxy {
                 }
 
                 Err(err) => {
-                    re_log::error!("Error while receiving messages: {}", TonicStatusError(err));
+                    let message =
+                        format!("Error while receiving messages: {}", TonicStatusError(err));
+                    re_log::error!("{message}");
+                    self.events.publish_error(message, None);
                     break;
                 }
             }
@@ -820,8 +1091,9 @@ impl message_proxy_service_server::MessageProxyService for MessageProxy {
 
     async fn read_messages(
         &self,
-        _: tonic::Request<ReadMessagesRequest>,
+        request: tonic::Request<ReadMessagesRequest>,
     ) -> tonic::Result<tonic::Response<Self::ReadMessagesStream>> {
+        self.check_permission(&request, auth::Permission::Read)?;
         Ok(tonic::Response::new(self.new_client_message_stream().await))
     }
 
@@ -831,6 +1103,8 @@ impl message_proxy_service_server::MessageProxyService for MessageProxy {
         &self,
         request: tonic::Request<WriteTableRequest>,
     ) -> tonic::Result<tonic::Response<WriteTableResponse>> {
+        self.check_permission(&request, auth::Permission::Write)?;
+
         if let WriteTableRequest {
             id: Some(id),
             data: Some(data),
@@ -846,10 +1120,37 @@ impl message_proxy_service_server::MessageProxyService for MessageProxy {
 
     async fn read_tables(
         &self,
-        _: tonic::Request<ReadTablesRequest>,
+        request: tonic::Request<ReadTablesRequest>,
     ) -> tonic::Result<tonic::Response<Self::ReadTablesStream>> {
+        self.check_permission(&request, auth::Permission::Read)?;
         Ok(tonic::Response::new(self.new_client_table_stream().await))
     }
+
+    async fn write_mcap(
+        &self,
+        request: tonic::Request<WriteMcapRequest>,
+    ) -> tonic::Result<tonic::Response<WriteMcapResponse>> {
+        self.check_permission(&request, auth::Permission::Write)?;
+
+        mcap::write_mcap(
+            self.event_tx.clone(),
+            &self.events,
+            self.load_dir.as_deref(),
+            request.into_inner(),
+        )
+        .await
+        .map(tonic::Response::new)
+    }
+
+    type ReadEventsStream = events::ReadEventsStream;
+
+    async fn read_events(
+        &self,
+        request: tonic::Request<ReadEventsRequest>,
+    ) -> tonic::Result<tonic::Response<Self::ReadEventsStream>> {
+        self.check_permission(&request, auth::Permission::Read)?;
+        Ok(tonic::Response::new(self.events.subscribe()))
+    }
 }
 
 #[cfg(test)]
@@ -991,6 +1292,10 @@ mod tests {
     }
 
     async fn setup_with_memory_limit(memory_limit: MemoryLimit) -> (Completion, SocketAddr) {
+        setup_with_proxy(super::MessageProxy::new(memory_limit)).await
+    }
+
+    async fn setup_with_proxy(message_proxy: super::MessageProxy) -> (Completion, SocketAddr) {
         let completion = Completion::new();
 
         let tcp_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
@@ -1001,7 +1306,7 @@ mod tests {
             async move {
                 tonic::transport::Server::builder()
                     .add_service(
-                        MessageProxyServiceServer::new(super::MessageProxy::new(memory_limit))
+                        MessageProxyServiceServer::new(message_proxy)
                             .max_decoding_message_size(MAX_DECODING_MESSAGE_SIZE)
                             .max_encoding_message_size(MAX_ENCODING_MESSAGE_SIZE),
                     )
@@ -1317,4 +1622,202 @@ mod tests {
 
         completion.finish();
     }
+
+    #[tokio::test]
+    async fn auth_checks_permissions() {
+        use crate::auth::{Permission, ServerAuth};
+
+        let (completion, addr) = setup_with_proxy(
+            super::MessageProxy::new(MemoryLimit::UNLIMITED).with_auth(
+                ServerAuth::default()
+                    .with_token("viewer", Permission::Read)
+                    .with_token("logger", Permission::Write),
+            ),
+        )
+        .await;
+
+        let client = |token: Option<&'static str>| async move {
+            let channel = Endpoint::from_shared(format!("http://{addr}"))
+                .unwrap()
+                .connect()
+                .await
+                .unwrap();
+            MessageProxyServiceClient::with_interceptor(
+                channel,
+                move |mut request: tonic::Request<()>| -> tonic::Result<tonic::Request<()>> {
+                    if let Some(token) = token {
+                        request
+                            .metadata_mut()
+                            .insert("authorization", format!("Bearer {token}").parse().unwrap());
+                    }
+                    Ok(request)
+                },
+            )
+        };
+
+        let read_code = |token| async move {
+            client(token)
+                .await
+                .read_messages(ReadMessagesRequest {})
+                .await
+                .err()
+                .map(|status| status.code())
+        };
+        let write_code = |token| async move {
+            client(token)
+                .await
+                .write_messages(tokio_stream::iter(
+                    fake_log_stream_recording(1)
+                        .into_iter()
+                        .map(|msg| WriteMessagesRequest {
+                            log_msg: Some(log_msg_to_proto(msg, Compression::Off).unwrap()),
+                        }),
+                ))
+                .await
+                .err()
+                .map(|status| status.code())
+        };
+
+        assert_eq!(None, read_code(Some("viewer")).await);
+        assert_eq!(None, write_code(Some("logger")).await);
+        assert_eq!(
+            Some(tonic::Code::PermissionDenied),
+            read_code(Some("logger")).await
+        );
+        assert_eq!(
+            Some(tonic::Code::PermissionDenied),
+            write_code(Some("viewer")).await
+        );
+        assert_eq!(Some(tonic::Code::Unauthenticated), read_code(None).await);
+        assert_eq!(
+            Some(tonic::Code::Unauthenticated),
+            write_code(Some("nobody")).await
+        );
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn read_events_follows_ingest() {
+        let (completion, addr) = setup().await;
+        let mut client = make_client(addr).await;
+        let messages = fake_log_stream_recording(2);
+
+        let mut event_stream = client.read_events(ReadEventsRequest {}).await.unwrap();
+
+        client
+            .write_messages(tokio_stream::iter(
+                messages
+                    .clone()
+                    .into_iter()
+                    .map(|msg| log_msg_to_proto(msg, Compression::Off).unwrap())
+                    .map(|msg| WriteMessagesRequest { log_msg: Some(msg) }),
+            ))
+            .await
+            .unwrap();
+
+        let mut events = Vec::new();
+        for _ in 0..messages.len() {
+            events.push(event_stream.get_mut().next().await.unwrap().unwrap().event);
+        }
+
+        let store_id: StoreIdProto = messages[0].store_id().clone().into();
+        assert!(matches!(
+            &events[0],
+            Some(ServerEvent::RecordingAdded(added)) if added.store_id.as_ref() == Some(&store_id)
+        ));
+        for event in &events[1..] {
+            assert!(matches!(
+                event,
+                Some(ServerEvent::ChunkIngested(chunk))
+                    if chunk.store_id.as_ref() == Some(&store_id) && chunk.chunk_id.is_some()
+            ));
+        }
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn retention_publishes_evictions() {
+        let (_event_tx, event_rx) = mpsc::channel(1);
+        let (broadcast_log_tx, _) = broadcast::channel(1);
+        let (broadcast_table_tx, _) = broadcast::channel(1);
+        let events = events::Events::new();
+        let event_stream = events.subscribe();
+        let mut event_loop = EventLoop::new(
+            MemoryLimit::UNLIMITED,
+            retention::RetentionPolicy::default().with_max_recordings(1),
+            None,
+            events,
+            event_rx,
+            broadcast_log_tx,
+            broadcast_table_tx,
+        );
+
+        let first = fake_log_stream_recording(0);
+        let second = fake_log_stream_recording(0);
+        for msg in first.iter().chain(&second) {
+            event_loop.handle_msg(log_msg_to_proto(msg.clone(), Compression::Off).unwrap());
+        }
+        drop(event_loop);
+
+        let store_id =
+            |messages: &[LogMsg]| Some(StoreIdProto::from(messages[0].store_id().clone()));
+        let events = event_stream
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .map(|response| response.unwrap().event.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ServerEvent::RecordingAdded(RecordingAdded {
+                    store_id: store_id(&first)
+                }),
+                ServerEvent::RecordingAdded(RecordingAdded {
+                    store_id: store_id(&second)
+                }),
+                ServerEvent::RecordingEvicted(RecordingEvicted {
+                    store_id: store_id(&first)
+                }),
+            ],
+            events
+        );
+    }
+
+    #[test]
+    fn retention_evicts_whole_recordings() {
+        let (_event_tx, event_rx) = mpsc::channel(1);
+        let (broadcast_log_tx, _) = broadcast::channel(1);
+        let (broadcast_table_tx, _) = broadcast::channel(1);
+        let mut event_loop = EventLoop::new(
+            MemoryLimit::UNLIMITED,
+            retention::RetentionPolicy::default().with_max_recordings(1),
+            None,
+            events::Events::new(),
+            event_rx,
+            broadcast_log_tx,
+            broadcast_table_tx,
+        );
+
+        let first = fake_log_stream_recording(3);
+        let second = fake_log_stream_recording(3);
+        for msg in first.iter().chain(&second) {
+            event_loop.handle_msg(log_msg_to_proto(msg.clone(), Compression::Off).unwrap());
+        }
+
+        let (history_tx, mut history_rx) = oneshot::channel();
+        event_loop.handle_new_client(history_tx);
+        let (history, _, _) = history_rx.try_recv().unwrap();
+
+        let mut app_id_cache = re_log_encoding::CachingApplicationIdInjector::default();
+        let history = history
+            .into_iter()
+            .filter_map(|msg| match msg {
+                Msg::LogMsg(msg) => Some(log_msg_from_proto(&mut app_id_cache, msg).unwrap()),
+                Msg::Table(_) => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(second, history);
+    }
 }
@@ -0,0 +1,221 @@
+//! Conversion of MCAP files on the server, see `WriteMcap`.
+
+use std::path::Path;
+
+use re_protos::sdk_comms::v1alpha1::{WriteMcapRequest, WriteMcapResponse};
+use tokio::sync::mpsc;
+
+use crate::Event;
+use crate::events::Events;
+
+/// How many messages are loaded between two `LoadProgress` events.
+#[cfg(feature = "data_loaders")]
+const PROGRESS_INTERVAL: u64 = 1024;
+
+/// Loads the MCAP file of `request` into a new recording, and writes all of it to `event_tx`.
+///
+/// The progress of the loading, and its failure if any, are published to `events`.
+///
+/// `load_dir` is where files requested by path are loaded from, `None` if they aren't allowed.
+#[cfg(feature = "data_loaders")]
+pub(crate) async fn write_mcap(
+    event_tx: mpsc::Sender<Event>,
+    events: &Events,
+    load_dir: Option<&Path>,
+    request: WriteMcapRequest,
+) -> tonic::Result<WriteMcapResponse> {
+    let WriteMcapRequest {
+        contents,
+        path,
+        application_id,
+    } = request;
+
+    let (file_name, path) = match (contents.is_empty(), path.is_empty()) {
+        (false, true) => ("upload.mcap".to_owned(), None),
+
+        (true, false) => {
+            let Some(load_dir) = load_dir else {
+                return Err(tonic::Status::failed_precondition(
+                    "this server doesn't load files by path",
+                ));
+            };
+            let path = resolve(load_dir, &path)?;
+            let file_name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            (file_name, Some(path))
+        }
+
+        _ => {
+            return Err(tonic::Status::invalid_argument(
+                "exactly one of `contents` and `path` must be set",
+            ));
+        }
+    };
+
+    let mut settings =
+        re_data_loader::DataLoaderSettings::recommended(re_log_types::RecordingId::random());
+    settings.application_id = Some(application_id.unwrap_or(file_name).into());
+    settings.force_store_info = true;
+    let store_id: re_protos::common::v1alpha1::StoreId = settings.recommended_store_id().into();
+
+    let result = tokio::task::spawn_blocking({
+        let events = events.clone();
+        let store_id = store_id.clone();
+        move || {
+            let contents = match path {
+                Some(path) => std::fs::read(&path).map_err(|err| {
+                    tonic::Status::not_found(format!("failed to read {}: {err}", path.display()))
+                })?,
+                None => contents.to_vec(),
+            };
+            load(&settings, contents, &event_tx, &events, &store_id)
+        }
+    })
+    .await
+    .map_err(|err| tonic::Status::internal(format!("loading failed: {err}")))
+    .and_then(|result| result);
+
+    if let Err(status) = &result {
+        events.publish_error(status.message().to_owned(), Some(store_id.clone()));
+    }
+    result?;
+
+    Ok(WriteMcapResponse {
+        store_id: Some(store_id),
+    })
+}
+
+#[cfg(not(feature = "data_loaders"))]
+pub(crate) async fn write_mcap(
+    _event_tx: mpsc::Sender<Event>,
+    _events: &Events,
+    _load_dir: Option<&Path>,
+    _request: WriteMcapRequest,
+) -> tonic::Result<WriteMcapResponse> {
+    Err(tonic::Status::unimplemented(
+        "this server was built without the `data_loaders` feature",
+    ))
+}
+
+/// Runs the data loaders on `contents`, and writes the resulting messages to `event_tx`.
+#[cfg(feature = "data_loaders")]
+fn load(
+    settings: &re_data_loader::DataLoaderSettings,
+    contents: Vec<u8>,
+    event_tx: &mpsc::Sender<Event>,
+    events: &Events,
+    store_id: &re_protos::common::v1alpha1::StoreId,
+) -> tonic::Result<()> {
+    use re_protos::sdk_comms::v1alpha1::{LoadProgress, read_events_response};
+    use re_smart_channel::SmartMessagePayload;
+
+    let publish_progress = |num_messages, done| {
+        events.publish(|| {
+            read_events_response::Event::LoadProgress(LoadProgress {
+                store_id: Some(store_id.clone()),
+                num_messages,
+                done,
+            })
+        });
+    };
+
+    re_tracing::profile_function!();
+
+    // The data loaders pick what to do based on the extension.
+    let filepath = Path::new("upload.mcap");
+
+    let (tx, rx) = re_smart_channel::smart_channel(
+        re_smart_channel::SmartMessageSource::File(filepath.to_owned()),
+        re_smart_channel::SmartChannelSource::File(filepath.to_owned()),
+    );
+    re_data_loader::load_from_file_contents(
+        settings,
+        re_log_types::FileSource::Sdk,
+        filepath,
+        std::borrow::Cow::Owned(contents),
+        &tx,
+    )
+    .map_err(|err| tonic::Status::invalid_argument(format!("failed to load MCAP: {err}")))?;
+    drop(tx);
+
+    let mut num_messages = 0;
+    while let Ok(msg) = rx.recv() {
+        let msg = match msg.payload {
+            SmartMessagePayload::Msg(msg) => msg,
+            SmartMessagePayload::Flush { on_flush_done } => {
+                on_flush_done();
+                continue;
+            }
+            SmartMessagePayload::Quit(_) => break,
+        };
+
+        let msg = re_log_encoding::protobuf_conversions::log_msg_to_proto(
+            msg,
+            re_log_encoding::Compression::LZ4,
+        )
+        .map_err(|err| tonic::Status::internal(format!("failed to encode message: {err}")))?;
+
+        if event_tx.blocking_send(Event::Message(msg)).is_err() {
+            return Err(tonic::Status::unavailable("the server is shutting down"));
+        }
+
+        num_messages += 1;
+        if num_messages % PROGRESS_INTERVAL == 0 {
+            publish_progress(num_messages, false);
+        }
+    }
+
+    publish_progress(num_messages, true);
+
+    Ok(())
+}
+
+/// Resolves the relative `path` within `load_dir`, making sure it doesn't escape it.
+#[cfg(feature = "data_loaders")]
+fn resolve(load_dir: &Path, path: &str) -> tonic::Result<std::path::PathBuf> {
+    use std::path::Component;
+
+    let path = Path::new(path);
+    if !path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return Err(tonic::Status::invalid_argument(
+            "`path` must be relative to the directory the server loads files from",
+        ));
+    }
+    if path
+        .extension()
+        .is_none_or(|ext| !ext.eq_ignore_ascii_case("mcap"))
+    {
+        return Err(tonic::Status::invalid_argument(
+            "`path` must be an MCAP file",
+        ));
+    }
+
+    Ok(load_dir.join(path))
+}
+
+#[cfg(all(test, feature = "data_loaders"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_stays_in_load_dir() {
+        let load_dir = Path::new("/data");
+
+        assert_eq!(
+            Ok(load_dir.join("robot/run.mcap")),
+            resolve(load_dir, "robot/run.mcap").map_err(|status| status.code())
+        );
+        for path in ["../secret.mcap", "/etc/run.mcap", "./run.mcap", "run.txt"] {
+            assert_eq!(
+                Err(tonic::Code::InvalidArgument),
+                resolve(load_dir, path).map_err(|status| status.code()),
+                "{path}"
+            );
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! Limits on the recordings a server keeps around for late-joining clients.
+
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use re_protos::{
+    common::v1alpha1::{StoreId as StoreIdProto, StoreKind as StoreKindProto},
+    log_msg::v1alpha1::LogMsg as LogMsgProto,
+};
+
+/// Which recordings a server keeps in its history.
+///
+/// Recordings are evicted as a whole, least recently written to first. Clients that are
+/// already connected keep what they received; clients connecting later won't see them.
+///
+/// This comes on top of the memory limit of the server, which drops the oldest messages
+/// regardless of the recording they belong to.
+///
+/// By default, everything is kept.
+#[derive(Clone, Debug, Default)]
+pub struct RetentionPolicy {
+    /// Maximum number of recordings in the history, pinned ones included.
+    pub max_recordings: Option<usize>,
+
+    /// Maximum total size of the recordings in the history, pinned ones included.
+    pub max_bytes: Option<u64>,
+
+    /// Recordings that weren't written to for this long are evicted.
+    pub ttl: Option<Duration>,
+
+    /// Ids of the recordings that are never evicted.
+    pub pinned: HashSet<String>,
+}
+
+impl RetentionPolicy {
+    #[inline]
+    pub fn with_max_recordings(mut self, max_recordings: usize) -> Self {
+        self.max_recordings = Some(max_recordings);
+        self
+    }
+
+    #[inline]
+    pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    #[inline]
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Never evicts the recording with the given id.
+    #[inline]
+    pub fn with_pinned(mut self, recording_id: impl Into<String>) -> Self {
+        self.pinned.insert(recording_id.into());
+        self
+    }
+
+    fn is_unlimited(&self) -> bool {
+        let Self {
+            max_recordings,
+            max_bytes,
+            ttl,
+            pinned: _,
+        } = self;
+        max_recordings.is_none() && max_bytes.is_none() && ttl.is_none()
+    }
+}
+
+/// The id of the recording a message belongs to, if any.
+///
+/// Blueprints aren't recordings: they are never evicted.
+pub(crate) fn recording_id(msg: &LogMsgProto) -> Option<&str> {
+    recording_store_id(msg).map(|store_id| store_id.recording_id.as_str())
+}
+
+/// The store id of the recording a message belongs to, if any.
+pub(crate) fn recording_store_id(msg: &LogMsgProto) -> Option<&StoreIdProto> {
+    use re_protos::log_msg::v1alpha1::log_msg::Msg;
+
+    let store_id = match msg.msg.as_ref()? {
+        Msg::SetStoreInfo(inner) => inner.info.as_ref()?.store_id.as_ref()?,
+        Msg::ArrowMsg(inner) => inner.store_id.as_ref()?,
+        Msg::BlueprintActivationCommand(_) => return None,
+    };
+
+    (store_id.kind() == StoreKindProto::Recording).then_some(store_id)
+}
+
+struct RecordingStats {
+    last_write: Instant,
+
+    /// Orders the recordings by last write, even when [`Instant`]s are equal.
+    last_write_index: u64,
+
+    /// Size of the messages of the recording that are still in the history.
+    num_bytes: u64,
+}
+
+/// Keeps track of the recordings in the history of a server, and decides which ones to evict.
+pub(crate) struct Recordings {
+    policy: RetentionPolicy,
+    stats: HashMap<String, RecordingStats>,
+    total_bytes: u64,
+    num_writes: u64,
+}
+
+impl Recordings {
+    pub fn new(policy: RetentionPolicy) -> Self {
+        Self {
+            policy,
+            stats: HashMap::default(),
+            total_bytes: 0,
+            num_writes: 0,
+        }
+    }
+
+    /// A message of `num_bytes` was added to the history of the given recording.
+    pub fn on_write(&mut self, recording_id: &str, num_bytes: u64, now: Instant) {
+        if self.policy.is_unlimited() {
+            return;
+        }
+
+        let stats = self
+            .stats
+            .entry(recording_id.to_owned())
+            .or_insert(RecordingStats {
+                last_write: now,
+                last_write_index: 0,
+                num_bytes: 0,
+            });
+        self.num_writes += 1;
+        stats.last_write = now;
+        stats.last_write_index = self.num_writes;
+        stats.num_bytes += num_bytes;
+        self.total_bytes += num_bytes;
+    }
+
+    /// A message of `num_bytes` was dropped from the history of the given recording.
+    pub fn on_drop(&mut self, recording_id: &str, num_bytes: u64) {
+        if let Some(stats) = self.stats.get_mut(recording_id) {
+            let num_bytes = num_bytes.min(stats.num_bytes);
+            stats.num_bytes -= num_bytes;
+            self.total_bytes -= num_bytes;
+        }
+    }
+
+    /// Returns the recordings to evict as of `now`, and forgets about them.
+    pub fn evict(&mut self, now: Instant) -> Vec<String> {
+        let mut evicted = Vec::new();
+        if self.policy.is_unlimited() {
+            return evicted;
+        }
+
+        if let Some(ttl) = self.policy.ttl {
+            evicted.extend(
+                self.stats
+                    .iter()
+                    .filter(|(id, stats)| {
+                        !self.policy.pinned.contains(*id)
+                            && now.saturating_duration_since(stats.last_write) > ttl
+                    })
+                    .map(|(id, _)| id.clone()),
+            );
+            for id in &evicted {
+                self.remove(id);
+            }
+        }
+
+        while self
+            .policy
+            .max_recordings
+            .is_some_and(|max| self.stats.len() > max)
+            || self
+                .policy
+                .max_bytes
+                .is_some_and(|max| self.total_bytes > max)
+        {
+            let Some(id) = self
+                .stats
+                .iter()
+                .filter(|(id, _)| !self.policy.pinned.contains(*id))
+                .min_by_key(|(_, stats)| stats.last_write_index)
+                .map(|(id, _)| id.clone())
+            else {
+                // Only pinned recordings are left.
+                break;
+            };
+            self.remove(&id);
+            evicted.push(id);
+        }
+
+        evicted
+    }
+
+    fn remove(&mut self, recording_id: &str) {
+        if let Some(stats) = self.stats.remove(recording_id) {
+            self.total_bytes -= stats.num_bytes;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_least_recently_written() {
+        let start = Instant::now();
+        let at = |secs| start + Duration::from_secs(secs);
+
+        let mut recordings = Recordings::new(
+            RetentionPolicy::default()
+                .with_max_recordings(2)
+                .with_max_bytes(100)
+                .with_ttl(Duration::from_secs(60))
+                .with_pinned("pinned"),
+        );
+
+        recordings.on_write("pinned", 10, at(0));
+        recordings.on_write("a", 10, at(1));
+        recordings.on_write("b", 10, at(2));
+        assert_eq!(vec!["a".to_owned()], recordings.evict(at(2)));
+
+        // Writing to a recording makes it the most recent one.
+        recordings.on_write("b", 10, at(3));
+        recordings.on_write("c", 10, at(4));
+        recordings.on_write("b", 10, at(5));
+        assert_eq!(vec!["c".to_owned()], recordings.evict(at(5)));
+
+        recordings.on_write("b", 100, at(6));
+        assert_eq!(vec!["b".to_owned()], recordings.evict(at(6)));
+
+        recordings.on_write("d", 10, at(7));
+        assert!(recordings.evict(at(67)).is_empty());
+        assert_eq!(vec!["d".to_owned()], recordings.evict(at(68)));
+
+        // Pinned recordings are never evicted.
+        recordings.on_write("pinned", 1_000, at(69));
+        assert!(recordings.evict(at(1_000)).is_empty());
+    }
+}
@@ -0,0 +1,224 @@
+//! Writing the streams received by a server to disk, as they arrive.
+
+use std::collections::HashMap;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+use re_log_encoding::encoder::DroppableEncoder;
+use re_protos::{
+    common::v1alpha1::{StoreId as StoreIdProto, StoreKind as StoreKindProto},
+    log_msg::v1alpha1::LogMsg as LogMsgProto,
+};
+
+type FileEncoder = DroppableEncoder<BufWriter<std::fs::File>>;
+
+/// Appends every store received by a server to its own file in a directory:
+/// `<recording_id>.rrd` for recordings, `<recording_id>.rbl` for blueprints.
+///
+/// Files are flushed whenever the server runs out of messages to write, so that a crashing
+/// client loses as little as possible. Files that already exist are appended to, as a new
+/// stream: a server that restarts doesn't overwrite what it spooled before.
+///
+/// Tables aren't spooled.
+pub(crate) struct Spooler {
+    tx: Option<crossbeam::channel::Sender<LogMsgProto>>,
+    join_handle: Option<std::thread::JoinHandle<()>>,
+}
+
+impl Spooler {
+    pub fn new(dir: PathBuf) -> std::io::Result<Self> {
+        let (tx, rx) = crossbeam::channel::unbounded();
+
+        let join_handle = std::thread::Builder::new()
+            .name("spool_writer".to_owned())
+            .spawn(move || write_all(&dir, &rx))?;
+
+        Ok(Self {
+            tx: Some(tx),
+            join_handle: Some(join_handle),
+        })
+    }
+
+    pub fn send(&self, msg: &LogMsgProto) {
+        if let Some(tx) = &self.tx {
+            tx.send(msg.clone()).ok();
+        }
+    }
+}
+
+impl Drop for Spooler {
+    fn drop(&mut self) {
+        // Disconnecting lets the writer finish all files.
+        self.tx = None;
+        if let Some(join_handle) = self.join_handle.take() {
+            join_handle.join().ok();
+        }
+    }
+}
+
+fn write_all(dir: &Path, rx: &crossbeam::channel::Receiver<LogMsgProto>) {
+    // `None` for the files that couldn't be created, so that we don't retry on every message.
+    let mut encoders: HashMap<String, Option<FileEncoder>> = HashMap::default();
+
+    loop {
+        let msg = match rx.try_recv() {
+            Ok(msg) => msg,
+            Err(crossbeam::channel::TryRecvError::Empty) => {
+                for (file_name, encoder) in &mut encoders {
+                    if let Some(encoder) = encoder
+                        && let Err(err) = encoder.flush_blocking()
+                    {
+                        re_log::error!("Failed to flush {file_name:?}: {err}");
+                    }
+                }
+
+                match rx.recv() {
+                    Ok(msg) => msg,
+                    Err(crossbeam::channel::RecvError) => break,
+                }
+            }
+            Err(crossbeam::channel::TryRecvError::Disconnected) => break,
+        };
+
+        let Some(file_name) = file_name(&msg) else {
+            continue;
+        };
+
+        let encoder =
+            encoders.entry(file_name).or_insert_with_key(|file_name| {
+                match create_encoder(&dir.join(file_name)) {
+                    Ok(encoder) => {
+                        re_log::debug!("Spooling to {:?}", dir.join(file_name));
+                        Some(encoder)
+                    }
+                    Err(err) => {
+                        re_log::error!("Failed to spool to {:?}: {err}", dir.join(file_name));
+                        None
+                    }
+                }
+            });
+
+        if let Some(inner) = encoder
+            && let Err(err) = inner.append_proto(msg)
+        {
+            re_log::error!("Failed to spool message, stopping: {err}");
+            *encoder = None;
+        }
+    }
+
+    for (file_name, encoder) in encoders {
+        if let Some(mut encoder) = encoder
+            && let Err(err) = encoder.finish()
+        {
+            re_log::error!("Failed to finish {file_name:?}: {err}");
+        }
+    }
+}
+
+fn create_encoder(path: &Path) -> anyhow::Result<FileEncoder> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+
+    Ok(DroppableEncoder::new(
+        re_build_info::CrateVersion::LOCAL,
+        re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED,
+        BufWriter::new(file),
+    )?)
+}
+
+/// The name of the file the message is spooled to.
+fn file_name(msg: &LogMsgProto) -> Option<String> {
+    use re_protos::log_msg::v1alpha1::log_msg::Msg;
+
+    let store_id: &StoreIdProto = match msg.msg.as_ref()? {
+        Msg::SetStoreInfo(inner) => inner.info.as_ref()?.store_id.as_ref()?,
+        Msg::ArrowMsg(inner) => inner.store_id.as_ref()?,
+        Msg::BlueprintActivationCommand(inner) => inner.blueprint_id.as_ref()?,
+    };
+
+    let extension = match store_id.kind() {
+        StoreKindProto::Recording => "rrd",
+        StoreKindProto::Blueprint => "rbl",
+        StoreKindProto::Unspecified => return None,
+    };
+
+    // Recording ids are arbitrary strings: keep them from escaping the directory.
+    let stem = store_id
+        .recording_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+
+    Some(format!("{stem}.{extension}"))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::io::BufReader;
+
+    use re_log_encoding::Compression;
+    use re_log_encoding::protobuf_conversions::log_msg_to_proto;
+    use re_log_types::{LogMsg, StoreKind};
+
+    use super::*;
+
+    #[test]
+    fn spools_each_store_to_its_own_file() {
+        let dir = std::env::temp_dir().join(format!("rerun_spool_{}", re_chunk::ChunkId::new()));
+
+        let store_id = re_log_types::StoreId::random(StoreKind::Recording, "rerun_example_spool");
+        let messages = (0..3)
+            .map(|_| {
+                LogMsg::ArrowMsg(
+                    store_id.clone(),
+                    re_chunk::Chunk::builder("points")
+                        .with_archetype(
+                            re_chunk::RowId::new(),
+                            re_log_types::TimePoint::default(),
+                            &re_types::archetypes::Points2D::new([(0.0, 0.0)]),
+                        )
+                        .build()
+                        .unwrap()
+                        .to_arrow_msg()
+                        .unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Two runs of the same server append to the same file.
+        for _ in 0..2 {
+            let spooler = Spooler::new(dir.clone()).unwrap();
+            for msg in &messages {
+                spooler.send(&log_msg_to_proto(msg.clone(), Compression::LZ4).unwrap());
+            }
+        }
+
+        let file_names = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![format!("{}.rrd", store_id.recording_id())], file_names);
+
+        let file = std::fs::File::open(dir.join(&file_names[0])).unwrap();
+        let decoded = re_log_encoding::decoder::Decoder::new_concatenated(BufReader::new(file))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!([messages.clone(), messages].concat(), decoded);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
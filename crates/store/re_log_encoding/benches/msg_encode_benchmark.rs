@@ -30,6 +30,7 @@ criterion_group!(
     mono_points_arrow,
     mono_points_arrow_batched,
     batch_points_arrow,
+    compression,
 );
 criterion_main!(benches);
 
@@ -289,6 +290,68 @@ fn batch_points_arrow(c: &mut Criterion) {
     }
 }
 
+/// Compares the compression options on many small chunks, which is where they differ the most.
+fn compression(c: &mut Criterion) {
+    use re_log_encoding::Compression;
+
+    let store_id = StoreId::random(StoreKind::Recording, "test_app");
+    let chunks = (0..NUM_POINTS)
+        .map(|i| {
+            Chunk::builder(entity_path!("points", i.to_string()))
+                .with_component_batches(
+                    RowId::new(),
+                    [build_frame_nr(TimeInt::new_temporal(i as _))],
+                    [
+                        (
+                            MyPoints::descriptor_points(),
+                            &MyPoint::from_iter(0..1) as _,
+                        ),
+                        (
+                            MyPoints::descriptor_colors(),
+                            &MyColor::from_iter(0..1) as _,
+                        ),
+                    ],
+                )
+                .build()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let messages = generate_messages(&store_id, &chunks);
+
+    let all_options = [
+        ("off", Compression::Off, 0),
+        ("lz4", Compression::LZ4, 0),
+        #[cfg(feature = "zstd")]
+        ("zstd_1", Compression::Zstd, 1),
+        #[cfg(feature = "zstd")]
+        ("zstd_3", Compression::Zstd, 3),
+        #[cfg(feature = "zstd")]
+        ("zstd_9", Compression::Zstd, 9),
+        #[cfg(feature = "zstd")]
+        ("zstd_19", Compression::Zstd, 19),
+    ];
+
+    let mut group = c.benchmark_group("compression");
+    group.throughput(criterion::Throughput::Elements(NUM_POINTS as _));
+    for (name, compression, compression_level) in all_options {
+        let encoding_options = EncodingOptions {
+            compression,
+            compression_level,
+            ..PROTOBUF_COMPRESSED
+        };
+
+        let encoded = encode_log_msgs(&messages, encoding_options);
+        println!("compression/{name}: {} bytes", encoded.len());
+
+        group.bench_function(format!("encode({name})"), |b| {
+            b.iter(|| encode_log_msgs(&messages, encoding_options));
+        });
+        group.bench_function(format!("decode({name})"), |b| {
+            b.iter(|| decode_log_msgs(&encoded));
+        });
+    }
+}
+
 /// Build a ([`Timeline`], [`TimeInt`]) tuple from `frame_nr` suitable for inserting in a [`re_log_types::TimePoint`].
 fn build_frame_nr(frame_nr: TimeInt) -> (Timeline, TimeInt) {
     (Timeline::new("frame_nr", TimeType::Sequence), frame_nr)
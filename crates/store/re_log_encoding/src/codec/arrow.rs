@@ -56,6 +56,9 @@ pub(crate) fn read_arrow_from_bytes<R: std::io::Read>(
 pub(crate) struct Payload {
     pub uncompressed_size: usize,
     pub data: Vec<u8>,
+
+    /// CRC-32 of [`Self::data`], see [`super::checksum::crc32`].
+    pub checksum: u32,
 }
 
 #[cfg(feature = "encoder")]
@@ -63,6 +66,7 @@ pub(crate) struct Payload {
 pub(crate) fn encode_arrow(
     batch: &ArrowRecordBatch,
     compression: crate::Compression,
+    compression_level: i32,
 ) -> Result<Payload, crate::encoder::EncodeError> {
     re_tracing::profile_function!();
 
@@ -70,18 +74,14 @@ pub(crate) fn encode_arrow(
     write_arrow_to_bytes(&mut uncompressed, batch)?;
     let uncompressed_size = uncompressed.len();
 
-    let data = match compression {
-        crate::Compression::Off => uncompressed,
-        crate::Compression::LZ4 => {
-            re_tracing::profile_scope!("lz4::compress");
-            let _span = tracing::trace_span!("lz4::compress").entered();
-            lz4_flex::block::compress(&uncompressed)
-        }
-    };
+    let data = super::compression::compress(uncompressed, compression, compression_level)?;
+
+    let checksum = super::checksum::crc32(&data);
 
     Ok(Payload {
         uncompressed_size,
         data,
+        checksum,
     })
 }
 
@@ -95,18 +95,11 @@ pub(crate) fn decode_arrow(
     uncompressed_size: usize,
     compression: crate::Compression,
 ) -> Result<ArrowRecordBatch, crate::decoder::DecodeError> {
+    use super::compression::decompress;
+
     if true {
         let mut uncompressed = Vec::new();
-        let data = match compression {
-            crate::Compression::Off => data,
-            crate::Compression::LZ4 => {
-                re_tracing::profile_scope!("LZ4-decompress");
-                let _span = tracing::trace_span!("lz4::decompress").entered();
-                uncompressed.resize(uncompressed_size, 0);
-                lz4_flex::block::decompress_into(data, &mut uncompressed)?;
-                uncompressed.as_slice()
-            }
-        };
+        let data = decompress(data, uncompressed_size, compression, &mut uncompressed)?;
 
         Ok(read_arrow_from_bytes(&mut &data[..])?)
     } else {
@@ -119,15 +112,7 @@ pub(crate) fn decode_arrow(
         }
 
         BUFFER.with_borrow_mut(|uncompressed| {
-            let data = match compression {
-                crate::Compression::Off => data,
-                crate::Compression::LZ4 => {
-                    let _span = tracing::trace_span!("lz4::decompress").entered();
-                    uncompressed.resize(uncompressed_size, 0);
-                    lz4_flex::block::decompress_into(data, uncompressed)?;
-                    uncompressed.as_slice()
-                }
-            };
+            let data = decompress(data, uncompressed_size, compression, uncompressed)?;
 
             Ok(read_arrow_from_bytes(&mut &data[..])?)
         })
@@ -0,0 +1,18 @@
+//! Checksums of message payloads, used to detect corrupted data, e.g. in files cut short by
+//! a crash.
+
+/// Computes the CRC-32 (IEEE 802.3) checksum of `bytes`, as used by e.g. zlib and PNG.
+pub fn crc32(bytes: &[u8]) -> u32 {
+    re_tracing::profile_function!();
+    crc32fast::hash(bytes)
+}
+
+#[test]
+fn test_crc32() {
+    assert_eq!(crc32(b""), 0);
+    assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    assert_eq!(
+        crc32(b"The quick brown fox jumps over the lazy dog"),
+        0x414F_A339
+    );
+}
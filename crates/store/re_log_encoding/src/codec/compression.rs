@@ -0,0 +1,161 @@
+//! Compression of message payloads, see [`crate::Compression`].
+
+#[cfg(feature = "encoder")]
+#[tracing::instrument(level = "debug", skip_all)]
+pub(crate) fn compress(
+    data: Vec<u8>,
+    compression: crate::Compression,
+    level: i32,
+) -> Result<Vec<u8>, crate::encoder::EncodeError> {
+    match compression {
+        crate::Compression::Off => Ok(data),
+
+        crate::Compression::LZ4 => {
+            re_tracing::profile_scope!("lz4::compress");
+            let _span = tracing::trace_span!("lz4::compress").entered();
+            Ok(lz4_flex::block::compress(&data))
+        }
+
+        #[cfg(not(feature = "zstd"))]
+        crate::Compression::Zstd => {
+            _ = level;
+            Err(crate::encoder::EncodeError::Zstd(zstd_unsupported()))
+        }
+
+        #[cfg(feature = "zstd")]
+        crate::Compression::Zstd => {
+            re_tracing::profile_scope!("zstd::compress");
+            let _span = tracing::trace_span!("zstd::compress").entered();
+
+            use std::cell::RefCell;
+
+            type CachedCompressor = (i32, zstd::bulk::Compressor<'static>);
+
+            thread_local! {
+                // Setting up a compression context is expensive.
+                static COMPRESSOR: RefCell<Option<CachedCompressor>> = const { RefCell::new(None) };
+            }
+
+            COMPRESSOR.with_borrow_mut(|compressor| {
+                let compressor = match compressor {
+                    Some((cur_level, compressor)) if *cur_level == level => compressor,
+
+                    _ => {
+                        let new_compressor = zstd::bulk::Compressor::new(level)
+                            .map_err(crate::encoder::EncodeError::Zstd)?;
+                        &mut compressor.insert((level, new_compressor)).1
+                    }
+                };
+
+                compressor
+                    .compress(&data)
+                    .map_err(crate::encoder::EncodeError::Zstd)
+            })
+        }
+    }
+}
+
+/// Decompresses `data`, using `uncompressed` as backing storage if needed.
+///
+/// `uncompressed_size` must be the exact size of the data once decompressed.
+#[cfg(feature = "decoder")]
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn decompress<'a>(
+    data: &'a [u8],
+    uncompressed_size: usize,
+    compression: crate::Compression,
+    uncompressed: &'a mut Vec<u8>,
+) -> Result<&'a [u8], crate::decoder::DecodeError> {
+    match compression {
+        crate::Compression::Off => Ok(data),
+
+        crate::Compression::LZ4 => {
+            re_tracing::profile_scope!("LZ4-decompress");
+            let _span = tracing::trace_span!("lz4::decompress").entered();
+            uncompressed.resize(uncompressed_size, 0);
+            lz4_flex::block::decompress_into(data, uncompressed)?;
+            Ok(uncompressed.as_slice())
+        }
+
+        #[cfg(not(feature = "zstd"))]
+        crate::Compression::Zstd => Err(crate::decoder::DecodeError::Zstd(zstd_unsupported())),
+
+        #[cfg(feature = "zstd")]
+        crate::Compression::Zstd => {
+            re_tracing::profile_scope!("zstd-decompress");
+            let _span = tracing::trace_span!("zstd::decompress").entered();
+
+            use std::cell::RefCell;
+
+            thread_local! {
+                static DECOMPRESSOR: RefCell<Option<zstd::bulk::Decompressor<'static>>> =
+                    const { RefCell::new(None) };
+            }
+
+            uncompressed.resize(uncompressed_size, 0);
+
+            let num_bytes = DECOMPRESSOR.with_borrow_mut(|decompressor| {
+                let decompressor = match decompressor {
+                    Some(decompressor) => decompressor,
+                    None => decompressor.insert(
+                        zstd::bulk::Decompressor::new()
+                            .map_err(crate::decoder::DecodeError::Zstd)?,
+                    ),
+                };
+
+                decompressor
+                    .decompress_to_buffer(data, uncompressed.as_mut_slice())
+                    .map_err(crate::decoder::DecodeError::Zstd)
+            })?;
+
+            if num_bytes != uncompressed_size {
+                return Err(crate::decoder::DecodeError::Zstd(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!(
+                        "expected {uncompressed_size} bytes once decompressed, got {num_bytes}"
+                    ),
+                )));
+            }
+
+            Ok(uncompressed.as_slice())
+        }
+    }
+}
+
+#[cfg(not(feature = "zstd"))]
+#[cfg(any(feature = "encoder", feature = "decoder"))]
+fn zstd_unsupported() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "built without zstd support, see the `zstd` feature of `re_log_encoding`",
+    )
+}
+
+#[cfg(all(test, feature = "encoder", feature = "decoder"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let data = b"rerun.components.Position3D rerun.components.Color ".repeat(64);
+
+        for compression in [
+            crate::Compression::Off,
+            crate::Compression::LZ4,
+            #[cfg(feature = "zstd")]
+            crate::Compression::Zstd,
+        ] {
+            for level in [0, 1, 19] {
+                let compressed = compress(data.clone(), compression, level).unwrap();
+                if compression != crate::Compression::Off {
+                    assert!(compressed.len() < data.len(), "{compression:?}");
+                }
+
+                let mut uncompressed = Vec::new();
+                let decompressed =
+                    decompress(&compressed, data.len(), compression, &mut uncompressed).unwrap();
+                assert_eq!(data, decompressed, "{compression:?} at level {level}");
+            }
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! Authenticated encryption of message payloads, e.g. for recordings containing personal data
+//! that must be stored encrypted at rest.
+//!
+//! Only the payloads of chunks are encrypted: store ids, chunk ids and payload sizes remain
+//! readable, so that the structure of the stream can be inspected without the key. They are
+//! authenticated along with the payload though, so that encrypted payloads cannot be moved to
+//! other messages, nor have their metadata changed.
+//!
+//! Nothing is encrypted unless a key is passed explicitly, e.g. to
+//! [`crate::encoder::Encoder::with_encryption_key`]. Reading it from [`ENCRYPTION_KEY_ENV_VAR`] is
+//! up to the entry points, like the CLI.
+//!
+//! Requires the `encryption` feature.
+
+use re_protos::log_msg::v1alpha1 as proto;
+
+/// The environment variable the CLI reads the key from, see [`EncryptionKey::from_env`].
+///
+/// It must contain the 256-bit key as 64 hexadecimal characters.
+pub const ENCRYPTION_KEY_ENV_VAR: &str = "RERUN_RRD_ENCRYPTION_KEY";
+
+/// On failure to encrypt or decrypt a payload.
+#[derive(Debug, thiserror::Error)]
+pub enum EncryptionError {
+    #[error("Invalid encryption key, expected 64 hexadecimal characters")]
+    InvalidKey,
+
+    #[error("Encryption is not supported, enable the `encryption` feature of `re_log_encoding`")]
+    Unsupported,
+
+    #[error("The payload is encrypted but no key was provided, e.g. with {ENCRYPTION_KEY_ENV_VAR}")]
+    MissingKey,
+
+    #[error("Unknown encryption algorithm: {0}")]
+    UnknownAlgorithm(i32),
+
+    #[error("Failed to decrypt the payload, either the key is wrong or the data was tampered with")]
+    Decryption,
+
+    #[error("Failed to encrypt the payload")]
+    Encryption,
+}
+
+/// A 256-bit key used to encrypt and decrypt payloads with AES-256-GCM.
+#[derive(Clone, PartialEq, Eq)]
+pub struct EncryptionKey([u8; 32]);
+
+impl std::fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("EncryptionKey(<redacted>)")
+    }
+}
+
+impl EncryptionKey {
+    #[inline]
+    pub fn new(key: [u8; 32]) -> Self {
+        Self(key)
+    }
+
+    /// Parses a key made of 64 hexadecimal characters.
+    pub fn from_hex(hex: &str) -> Result<Self, EncryptionError> {
+        let hex = hex.trim();
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(EncryptionError::InvalidKey);
+        }
+
+        let mut key = [0_u8; 32];
+        for (byte, digits) in key.iter_mut().zip(hex.as_bytes().chunks_exact(2)) {
+            *byte = std::str::from_utf8(digits)
+                .ok()
+                .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+                .ok_or(EncryptionError::InvalidKey)?;
+        }
+
+        Ok(Self(key))
+    }
+
+    /// Reads the key from [`ENCRYPTION_KEY_ENV_VAR`], if set.
+    ///
+    /// Meant for entry points like the CLI: the encoders and decoders never read it themselves.
+    pub fn from_env() -> Result<Option<Self>, EncryptionError> {
+        match std::env::var(ENCRYPTION_KEY_ENV_VAR) {
+            Ok(hex) if !hex.trim().is_empty() => Self::from_hex(&hex).map(Some),
+            Ok(_) | Err(std::env::VarError::NotPresent) => Ok(None),
+            Err(std::env::VarError::NotUnicode(_)) => Err(EncryptionError::InvalidKey),
+        }
+    }
+}
+
+/// The data that is authenticated along with the payload of `arrow_msg`, i.e. everything that
+/// describes the payload: its store id, chunk id, compression, encoding and size.
+#[cfg(feature = "encryption")]
+fn associated_data(arrow_msg: &proto::ArrowMsg, algorithm: i32) -> Vec<u8> {
+    use re_protos::external::prost::Message as _;
+
+    let store_id = arrow_msg
+        .store_id
+        .as_ref()
+        .map(|store_id| store_id.encode_to_vec())
+        .unwrap_or_default();
+    let chunk_id = arrow_msg
+        .chunk_id
+        .as_ref()
+        .map(|chunk_id| chunk_id.encode_to_vec())
+        .unwrap_or_default();
+
+    let mut aad = b"rerun.ArrowMsg".to_vec();
+    for field in [store_id, chunk_id] {
+        aad.extend_from_slice(&(field.len() as u64).to_le_bytes());
+        aad.extend_from_slice(&field);
+    }
+    for field in [
+        arrow_msg.compression,
+        arrow_msg.uncompressed_size,
+        arrow_msg.encoding,
+        algorithm,
+    ] {
+        aad.extend_from_slice(&field.to_le_bytes());
+    }
+
+    aad
+}
+
+/// Encrypts the payload of `arrow_msg` in place, with a fresh random nonce.
+///
+/// The header of the message (store id, chunk id, compression, size) is authenticated as well.
+/// The checksum of the message is updated to cover the encrypted payload.
+#[cfg(feature = "encryption")]
+pub fn encrypt_arrow_msg(
+    arrow_msg: &mut proto::ArrowMsg,
+    key: &EncryptionKey,
+) -> Result<(), EncryptionError> {
+    re_tracing::profile_function!();
+
+    use ring::aead;
+    use ring::rand::SecureRandom as _;
+
+    let mut nonce = [0_u8; aead::NONCE_LEN];
+    ring::rand::SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|_err| EncryptionError::Encryption)?;
+
+    let algorithm = proto::EncryptionAlgorithm::Aes256Gcm as i32;
+    let aad = associated_data(arrow_msg, algorithm);
+
+    let mut payload = arrow_msg.payload.to_vec();
+    aes_256_gcm(key)?
+        .seal_in_place_append_tag(
+            aead::Nonce::assume_unique_for_key(nonce),
+            aead::Aad::from(aad),
+            &mut payload,
+        )
+        .map_err(|_err| EncryptionError::Encryption)?;
+
+    arrow_msg.payload_crc32 = Some(super::checksum::crc32(&payload));
+    arrow_msg.payload = payload.into();
+    arrow_msg.encryption = Some(proto::PayloadEncryption {
+        algorithm,
+        nonce: nonce.to_vec().into(),
+    });
+
+    Ok(())
+}
+
+/// Decrypts the payload of `arrow_msg` in place, if it is encrypted.
+///
+/// The checksum covers the encrypted payload, so it must be verified beforehand: it is cleared
+/// once the payload is decrypted. The authentication tag is checked either way, and fails if
+/// either the payload or the header of the message was changed.
+#[cfg(feature = "encryption")]
+pub fn decrypt_arrow_msg(
+    arrow_msg: &mut proto::ArrowMsg,
+    key: &EncryptionKey,
+) -> Result<(), EncryptionError> {
+    re_tracing::profile_function!();
+
+    use ring::aead;
+
+    let Some(encryption) = arrow_msg.encryption.take() else {
+        return Ok(());
+    };
+
+    match proto::EncryptionAlgorithm::try_from(encryption.algorithm) {
+        Ok(proto::EncryptionAlgorithm::Aes256Gcm) => {}
+        Ok(proto::EncryptionAlgorithm::Unspecified) | Err(_) => {
+            return Err(EncryptionError::UnknownAlgorithm(encryption.algorithm));
+        }
+    }
+
+    let nonce = aead::Nonce::try_assume_unique_for_key(&encryption.nonce)
+        .map_err(|_err| EncryptionError::Decryption)?;
+
+    let aad = associated_data(arrow_msg, encryption.algorithm);
+
+    let mut payload = arrow_msg.payload.to_vec();
+    let len = aes_256_gcm(key)?
+        .open_in_place(nonce, aead::Aad::from(aad), &mut payload)
+        .map_err(|_err| EncryptionError::Decryption)?
+        .len();
+    payload.truncate(len);
+
+    arrow_msg.payload_crc32 = None;
+    arrow_msg.payload = payload.into();
+
+    Ok(())
+}
+
+#[cfg(feature = "encryption")]
+fn aes_256_gcm(key: &EncryptionKey) -> Result<ring::aead::LessSafeKey, EncryptionError> {
+    let key = ring::aead::UnboundKey::new(&ring::aead::AES_256_GCM, &key.0)
+        .map_err(|_err| EncryptionError::InvalidKey)?;
+    Ok(ring::aead::LessSafeKey::new(key))
+}
+
+/// Encrypts the payload of `arrow_msg` in place.
+///
+/// Always fails: the `encryption` feature is disabled.
+#[cfg(not(feature = "encryption"))]
+pub fn encrypt_arrow_msg(
+    _arrow_msg: &mut proto::ArrowMsg,
+    _key: &EncryptionKey,
+) -> Result<(), EncryptionError> {
+    Err(EncryptionError::Unsupported)
+}
+
+/// Decrypts the payload of `arrow_msg` in place, if it is encrypted.
+///
+/// Always fails on encrypted payloads: the `encryption` feature is disabled.
+#[cfg(not(feature = "encryption"))]
+pub fn decrypt_arrow_msg(
+    arrow_msg: &mut proto::ArrowMsg,
+    _key: &EncryptionKey,
+) -> Result<(), EncryptionError> {
+    if arrow_msg.encryption.is_some() {
+        Err(EncryptionError::Unsupported)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "encryption"))]
+mod tests {
+    #![allow(clippy::unwrap_used)] // acceptable for tests
+
+    use super::*;
+
+    #[test]
+    fn test_key_from_hex() {
+        let hex = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1F";
+        let key = EncryptionKey::from_hex(hex).unwrap();
+        assert_eq!(key.0[1], 1);
+        assert_eq!(key.0[31], 31);
+
+        assert!(EncryptionKey::from_hex(&hex[2..]).is_err());
+        assert!(EncryptionKey::from_hex(&hex.replace('0', "g")).is_err());
+        assert_eq!("EncryptionKey(<redacted>)", format!("{key:?}"));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let key = EncryptionKey::new([42; 32]);
+        let payload = b"some sensitive sensor data".to_vec();
+
+        let mut arrow_msg = proto::ArrowMsg {
+            payload: payload.clone().into(),
+            ..Default::default()
+        };
+
+        encrypt_arrow_msg(&mut arrow_msg, &key).unwrap();
+        assert!(arrow_msg.encryption.is_some());
+        assert_ne!(payload, arrow_msg.payload);
+        assert_eq!(
+            Some(crate::codec::checksum::crc32(&arrow_msg.payload)),
+            arrow_msg.payload_crc32
+        );
+
+        // Wrong key.
+        assert!(matches!(
+            decrypt_arrow_msg(&mut arrow_msg.clone(), &EncryptionKey::new([0; 32])),
+            Err(EncryptionError::Decryption)
+        ));
+
+        // Payload moved to another message.
+        {
+            let mut moved = arrow_msg.clone();
+            moved.chunk_id = Some(re_protos::common::v1alpha1::Tuid {
+                time_ns: Some(1),
+                inc: Some(2),
+            });
+            assert!(matches!(
+                decrypt_arrow_msg(&mut moved, &key),
+                Err(EncryptionError::Decryption)
+            ));
+
+            let mut resized = arrow_msg.clone();
+            resized.uncompressed_size += 1;
+            assert!(matches!(
+                decrypt_arrow_msg(&mut resized, &key),
+                Err(EncryptionError::Decryption)
+            ));
+        }
+
+        // Tampered payload.
+        {
+            let mut tampered = arrow_msg.clone();
+            let mut data = tampered.payload.to_vec();
+            data[0] ^= 1;
+            tampered.payload = data.into();
+            assert!(matches!(
+                decrypt_arrow_msg(&mut tampered, &key),
+                Err(EncryptionError::Decryption)
+            ));
+        }
+
+        decrypt_arrow_msg(&mut arrow_msg, &key).unwrap();
+        assert_eq!(payload, arrow_msg.payload);
+        assert!(arrow_msg.encryption.is_none());
+    }
+}
@@ -3,7 +3,12 @@ use re_protos::missing_field;
 
 use crate::{
     ApplicationIdInjector,
-    codec::{CodecError, arrow::decode_arrow},
+    codec::{
+        CodecError,
+        arrow::decode_arrow,
+        checksum,
+        encryption::{self, EncryptionError, EncryptionKey},
+    },
     decoder::DecodeError,
 };
 
@@ -11,44 +16,20 @@ use super::{MessageHeader, MessageKind};
 
 // ---
 
-/// This decodes all the way from raw bytes to application-level types (i.e. even Arrow layers are decoded).
+/// Reads the next message from `data`, without decoding it.
 ///
-/// See also:
-/// * [`decode_to_transport`]
-pub(crate) fn decode_to_app(
-    app_id_injector: &mut impl ApplicationIdInjector,
-    data: &mut impl std::io::Read,
-) -> Result<(u64, Option<LogMsg>), DecodeError> {
-    let mut read_bytes = 0u64;
-    let header = MessageHeader::decode(data)?;
-    read_bytes += std::mem::size_of::<MessageHeader>() as u64 + header.len;
-
-    let mut buf = vec![0; header.len as usize];
-    data.read_exact(&mut buf[..])?;
-
-    let msg = decode_bytes_to_app(app_id_injector, header.kind, &buf)?;
-
-    Ok((read_bytes, msg))
-}
-
-/// This only decodes from raw bytes up to transport-level types (i.e. Protobuf payloads are
-/// decoded, but Arrow data is never touched).
-///
-/// See also:
-/// * [`decode_to_app`]
-pub(crate) fn decode_to_transport(
+/// Returns the header of the message along with its raw bytes.
+/// The message can then be decoded using e.g. [`decode_bytes_to_app`] or
+/// [`decode_bytes_to_transport`].
+pub(crate) fn read_message(
     data: &mut impl std::io::Read,
-) -> Result<(u64, Option<re_protos::log_msg::v1alpha1::log_msg::Msg>), DecodeError> {
-    let mut read_bytes = 0u64;
+) -> Result<(MessageHeader, Vec<u8>), DecodeError> {
     let header = MessageHeader::decode(data)?;
-    read_bytes += std::mem::size_of::<MessageHeader>() as u64 + header.len;
 
     let mut buf = vec![0; header.len as usize];
     data.read_exact(&mut buf[..])?;
 
-    let msg = decode_bytes_to_transport(header.kind, &buf)?;
-
-    Ok((read_bytes, msg))
+    Ok((header, buf))
 }
 
 /// Decode a message of kind `message_kind` from `buf`.
@@ -70,6 +51,24 @@ pub fn decode_bytes_to_app(
     decoded.transpose()
 }
 
+/// Decode a message of kind `message_kind` from `buf`, decrypting its payload if needed.
+///
+/// Same as [`decode_bytes_to_app`], see also [`decrypt_transport`].
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn decode_encrypted_bytes_to_app(
+    app_id_injector: &mut impl ApplicationIdInjector,
+    message_kind: MessageKind,
+    buf: &[u8],
+    key: &EncryptionKey,
+) -> Result<Option<LogMsg>, DecodeError> {
+    let decoded = decode_bytes_to_transport(message_kind, buf)?;
+    let decoded = decoded.map(|msg| {
+        let msg = decrypt_transport(msg, key)?;
+        decode_transport_to_app(app_id_injector, msg)
+    });
+    decoded.transpose()
+}
+
 /// Decode a message of kind `message_kind` from `buf`.
 ///
 /// This only decodes from raw bytes up to transport-level types (i.e. Protobuf payloads are
@@ -108,10 +107,48 @@ pub fn decode_bytes_to_transport(
     Ok(msg)
 }
 
+/// Decrypts the payload of a transport-level message, if it is encrypted.
+///
+/// The checksum of the payload is verified beforehand, since it covers the encrypted payload.
+pub fn decrypt_transport(
+    msg: re_protos::log_msg::v1alpha1::log_msg::Msg,
+    key: &EncryptionKey,
+) -> Result<re_protos::log_msg::v1alpha1::log_msg::Msg, DecodeError> {
+    match msg {
+        re_protos::log_msg::v1alpha1::log_msg::Msg::ArrowMsg(mut arrow_msg)
+            if arrow_msg.encryption.is_some() =>
+        {
+            verify_checksum(&arrow_msg)?;
+            encryption::decrypt_arrow_msg(&mut arrow_msg, key).map_err(CodecError::from)?;
+            Ok(re_protos::log_msg::v1alpha1::log_msg::Msg::ArrowMsg(
+                arrow_msg,
+            ))
+        }
+
+        msg => Ok(msg),
+    }
+}
+
+fn verify_checksum(arrow_msg: &re_protos::log_msg::v1alpha1::ArrowMsg) -> Result<(), DecodeError> {
+    if let Some(expected) = arrow_msg.payload_crc32 {
+        let actual = checksum::crc32(&arrow_msg.payload);
+        if actual != expected {
+            return Err(DecodeError::Codec(CodecError::ChecksumMismatch {
+                expected,
+                actual,
+            }));
+        }
+    }
+
+    Ok(())
+}
+
 /// Decode a transport-level message.
 ///
 /// This decodes a message from the transport layer (Protobuf) all the way to app layer, i.e. this
 /// is where all Arrow data will be decoded.
+///
+/// Encrypted payloads must be decrypted beforehand, see [`decrypt_transport`].
 #[tracing::instrument(level = "debug", skip_all)]
 pub fn decode_transport_to_app(
     app_id_injector: &mut impl ApplicationIdInjector,
@@ -131,6 +168,12 @@ pub fn decode_transport_to_app(
                 return Err(DecodeError::Codec(CodecError::UnsupportedEncoding));
             }
 
+            if arrow_msg.encryption.is_some() {
+                return Err(DecodeError::Codec(EncryptionError::MissingKey.into()));
+            }
+
+            verify_checksum(&arrow_msg)?;
+
             let batch = decode_arrow(
                 &arrow_msg.payload,
                 arrow_msg.uncompressed_size as usize,
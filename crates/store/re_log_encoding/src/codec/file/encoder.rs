@@ -1,6 +1,8 @@
 use super::{MessageHeader, MessageKind};
 use crate::Compression;
+use crate::codec::CodecError;
 use crate::codec::arrow::encode_arrow;
+use crate::codec::encryption::{EncryptionKey, encrypt_arrow_msg};
 use crate::encoder::EncodeError;
 use re_log_types::LogMsg;
 use re_protos::log_msg::v1alpha1 as proto;
@@ -9,6 +11,8 @@ pub(crate) fn encode(
     buf: &mut Vec<u8>,
     message: &LogMsg,
     compression: Compression,
+    compression_level: i32,
+    encryption_key: Option<&EncryptionKey>,
 ) -> Result<(), EncodeError> {
     use re_protos::external::prost::Message as _;
     use re_protos::log_msg::v1alpha1::{
@@ -26,18 +30,20 @@ pub(crate) fn encode(
             set_store_info.encode(buf)?;
         }
         LogMsg::ArrowMsg(store_id, arrow_msg) => {
-            let payload = encode_arrow(&arrow_msg.batch, compression)?;
-            let arrow_msg = ArrowMsg {
+            let payload = encode_arrow(&arrow_msg.batch, compression, compression_level)?;
+            let mut arrow_msg = ArrowMsg {
                 store_id: Some(store_id.clone().into()),
                 chunk_id: Some(arrow_msg.chunk_id.into()),
-                compression: match compression {
-                    Compression::Off => proto::Compression::None as i32,
-                    Compression::LZ4 => proto::Compression::Lz4 as i32,
-                },
+                compression: proto::Compression::from(compression) as i32,
                 uncompressed_size: payload.uncompressed_size as i32,
                 encoding: Encoding::ArrowIpc as i32,
                 payload: payload.data.into(),
+                payload_crc32: Some(payload.checksum),
+                encryption: None,
             };
+            if let Some(encryption_key) = encryption_key {
+                encrypt_arrow_msg(&mut arrow_msg, encryption_key).map_err(CodecError::from)?;
+            }
             let header = MessageHeader {
                 kind: MessageKind::ArrowMsg,
                 len: arrow_msg.encoded_len() as u64,
@@ -60,7 +66,12 @@ pub(crate) fn encode(
     Ok(())
 }
 
-pub(crate) fn encode_proto(buf: &mut Vec<u8>, message: proto::LogMsg) -> Result<(), EncodeError> {
+/// Payloads that are already encrypted are written as is.
+pub(crate) fn encode_proto(
+    buf: &mut Vec<u8>,
+    message: proto::LogMsg,
+    encryption_key: Option<&EncryptionKey>,
+) -> Result<(), EncodeError> {
     use re_protos::external::prost::Message as _;
     use re_protos::log_msg::v1alpha1 as proto;
 
@@ -73,7 +84,12 @@ pub(crate) fn encode_proto(buf: &mut Vec<u8>, message: proto::LogMsg) -> Result<
             header.encode(buf)?;
             set_store_info.encode(buf)?;
         }
-        proto::log_msg::Msg::ArrowMsg(arrow_msg) => {
+        proto::log_msg::Msg::ArrowMsg(mut arrow_msg) => {
+            if let Some(encryption_key) = encryption_key
+                && arrow_msg.encryption.is_none()
+            {
+                encrypt_arrow_msg(&mut arrow_msg, encryption_key).map_err(CodecError::from)?;
+            }
             let header = MessageHeader {
                 kind: MessageKind::ArrowMsg,
                 len: arrow_msg.encoded_len() as u64,
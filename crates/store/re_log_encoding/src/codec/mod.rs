@@ -1,4 +1,7 @@
 pub(crate) mod arrow;
+pub mod checksum;
+pub mod compression;
+pub mod encryption;
 pub mod file;
 pub mod wire;
 
@@ -30,4 +33,10 @@ pub enum CodecError {
 
     #[error("Unknown message header")]
     UnknownMessageHeader,
+
+    #[error("Checksum mismatch: expected {expected:#010x}, got {actual:#010x}")]
+    ChecksumMismatch { expected: u32, actual: u32 },
+
+    #[error("{0}")]
+    Encryption(#[from] encryption::EncryptionError),
 }
@@ -0,0 +1,286 @@
+//! Lazy decoding of memory-mapped `.rrd` files.
+
+use re_build_info::CrateVersion;
+use re_log_types::LogMsg;
+
+use crate::{
+    CachingApplicationIdInjector, FileHeader,
+    codec::{
+        encryption::EncryptionKey,
+        file::{MessageHeader, MessageKind, decoder},
+    },
+};
+
+use super::{DecodeError, options_from_bytes};
+
+// ---
+
+/// Where a single message lives in the underlying bytes.
+#[derive(Debug, Clone, Copy)]
+struct MessageSpan {
+    kind: MessageKind,
+    start: usize,
+    end: usize,
+}
+
+/// Decodes [`LogMsg`]es from a memory-mapped `.rrd` file.
+///
+/// Instantiating the decoder only walks over the message headers in order to find out where
+/// each message lives: payloads are left untouched until the message is actually decoded, at
+/// which point the OS pages in the relevant parts of the file.
+/// This makes it possible to open very large recordings without having to read them upfront.
+///
+/// Handles concatenated RRD streams, see [`super::Decoder::new_concatenated`].
+///
+/// The underlying file must not be modified while it is mapped, see [`Self::is_complete`].
+pub struct MmapDecoder<B = memmap2::Mmap> {
+    bytes: B,
+
+    /// The most recent version among all the streams found in the file.
+    version: CrateVersion,
+
+    messages: Vec<MessageSpan>,
+
+    /// Whether the last stream in the file was properly terminated.
+    is_complete: bool,
+
+    /// See [`Self::with_decryption_key`].
+    decryption_key: Option<EncryptionKey>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl MmapDecoder<memmap2::Mmap> {
+    /// Memory-maps the `.rrd` file at the given path and indexes its messages.
+    pub fn open(path: impl AsRef<std::path::Path>) -> Result<Self, DecodeError> {
+        re_tracing::profile_function!();
+
+        let file = std::fs::File::open(path)?;
+
+        // SAFETY: file-backed memory maps are marked unsafe because of potential UB when using the map and the underlying file is modified.
+        #[allow(unsafe_code)]
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+
+        Self::new(mmap)
+    }
+}
+
+impl<B: AsRef<[u8]>> MmapDecoder<B> {
+    /// Indexes the messages of the `.rrd` stream held in `bytes`.
+    ///
+    /// A trailing message that is cut short (e.g. because the file is still being written to) is
+    /// ignored, see [`Self::is_complete`].
+    pub fn new(bytes: B) -> Result<Self, DecodeError> {
+        re_tracing::profile_function!();
+
+        let data = bytes.as_ref();
+
+        let (mut version, _options) =
+            options_from_bytes(data.get(..FileHeader::SIZE).ok_or(DecodeError::NotAnRrd)?)?;
+
+        let mut messages = Vec::new();
+        let mut is_complete = false;
+        let mut offset = FileHeader::SIZE;
+
+        while let Some(header) = data.get(offset..offset + MessageHeader::SIZE_BYTES) {
+            let header = MessageHeader::from_bytes(header)?;
+
+            let start = offset + MessageHeader::SIZE_BYTES;
+            let end = start.saturating_add(header.len as usize);
+            if end > data.len() {
+                break;
+            }
+            offset = end;
+
+            if header.kind != MessageKind::End {
+                messages.push(MessageSpan {
+                    kind: header.kind,
+                    start,
+                    end,
+                });
+                continue;
+            }
+
+            // We might have a concatenated stream, so we peek beyond the end of stream marker.
+            if let Some(next_header) = data.get(offset..offset + FileHeader::SIZE)
+                && let Ok((next_version, _options)) = options_from_bytes(next_header)
+            {
+                version = CrateVersion::max(version, next_version);
+                offset += FileHeader::SIZE;
+                continue;
+            }
+
+            is_complete = true;
+            break;
+        }
+
+        Ok(Self {
+            bytes,
+            version,
+            messages,
+            is_complete,
+            decryption_key: None,
+        })
+    }
+
+    /// See [`super::Decoder::with_decryption_key`].
+    #[inline]
+    pub fn with_decryption_key(mut self, decryption_key: Option<EncryptionKey>) -> Self {
+        self.decryption_key = decryption_key;
+        self
+    }
+
+    /// Returns the Rerun version that was used to encode the data in the first place.
+    #[inline]
+    pub fn version(&self) -> CrateVersion {
+        self.version
+    }
+
+    /// Returns the number of messages in the stream, excluding end of stream markers.
+    #[inline]
+    pub fn num_messages(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Returns `true` if the stream was properly terminated.
+    ///
+    /// An incomplete stream is most likely still being written to, in which case a regular
+    /// [`super::Decoder`] should be used to follow along.
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.is_complete
+    }
+}
+
+impl<B: AsRef<[u8]>> IntoIterator for MmapDecoder<B> {
+    type Item = Result<LogMsg, DecodeError>;
+    type IntoIter = MmapIterator<B>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        MmapIterator {
+            decoder: self,
+            next_message: 0,
+            app_id_cache: CachingApplicationIdInjector::default(),
+        }
+    }
+}
+
+/// Iterator over the messages of a [`MmapDecoder`], decoding each message only as it is reached.
+pub struct MmapIterator<B = memmap2::Mmap> {
+    decoder: MmapDecoder<B>,
+    next_message: usize,
+
+    /// The application id cache used for migrating old data.
+    app_id_cache: CachingApplicationIdInjector,
+}
+
+impl<B: AsRef<[u8]>> Iterator for MmapIterator<B> {
+    type Item = Result<LogMsg, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let MessageSpan { kind, start, end } = *self.decoder.messages.get(self.next_message)?;
+            self.next_message += 1;
+
+            let data = &self.decoder.bytes.as_ref()[start..end];
+            let decoded = if let Some(decryption_key) = &self.decoder.decryption_key {
+                decoder::decode_encrypted_bytes_to_app(
+                    &mut self.app_id_cache,
+                    kind,
+                    data,
+                    decryption_key,
+                )
+            } else {
+                decoder::decode_bytes_to_app(&mut self.app_id_cache, kind, data)
+            };
+            match decoded {
+                Ok(Some(msg)) => return Some(Ok(msg)),
+                Ok(None) => {} // end of stream markers are never indexed
+
+                //TODO(#10730): remove this if/when we remove the legacy `StoreId` migration.
+                Err(DecodeError::StoreIdMissingApplicationId {
+                    store_kind,
+                    recording_id,
+                }) => {
+                    re_log::warn_once!(
+                        "Dropping message without application id which arrived before `SetStoreInfo` \
+                        (kind: {store_kind}, recording id: {recording_id}."
+                    );
+                }
+
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.decoder.messages.len() - self.next_message;
+        (0, Some(remaining))
+    }
+}
+
+// ---
+
+#[cfg(all(test, feature = "encoder"))]
+mod tests {
+    #![allow(clippy::unwrap_used)] // acceptable for tests
+
+    use super::*;
+    use crate::decoder::tests::fake_log_messages;
+    use crate::{Compression, EncodingOptions, Serializer};
+
+    fn encode(messages: &[LogMsg]) -> Vec<u8> {
+        let options = EncodingOptions {
+            compression: Compression::LZ4,
+            serializer: Serializer::Protobuf,
+            compression_level: 0,
+        };
+
+        let mut data = vec![];
+        crate::encoder::encode_ref(
+            CrateVersion::LOCAL,
+            options,
+            messages.iter().map(Ok),
+            &mut data,
+        )
+        .unwrap();
+        data
+    }
+
+    #[test]
+    fn test_mmap_decode() {
+        let messages = fake_log_messages();
+        let data = encode(&messages);
+
+        let decoder = MmapDecoder::new(data.as_slice()).unwrap();
+        assert!(decoder.is_complete());
+        assert_eq!(decoder.num_messages(), messages.len());
+
+        let decoded_messages = decoder.into_iter().collect::<Result<Vec<_>, _>>().unwrap();
+        similar_asserts::assert_eq!(decoded_messages, messages);
+    }
+
+    #[test]
+    fn test_mmap_decode_concatenated() {
+        let messages = fake_log_messages();
+        let data = [encode(&messages), encode(&messages)].concat();
+
+        let decoder = MmapDecoder::new(data).unwrap();
+        assert!(decoder.is_complete());
+
+        let decoded_messages = decoder.into_iter().collect::<Result<Vec<_>, _>>().unwrap();
+        similar_asserts::assert_eq!(decoded_messages, [messages.clone(), messages].concat());
+    }
+
+    #[test]
+    fn test_mmap_decode_truncated() {
+        let messages = fake_log_messages();
+        let data = encode(&messages);
+
+        // Cut the stream in the middle of the last message (and end of stream marker).
+        let data = &data[..data.len() - MessageHeader::SIZE_BYTES - 1];
+
+        let decoder = MmapDecoder::new(data).unwrap();
+        assert!(!decoder.is_complete());
+        assert_eq!(decoder.num_messages(), messages.len() - 1);
+    }
+}
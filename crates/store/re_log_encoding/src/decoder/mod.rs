@@ -1,5 +1,6 @@
 //! Decoding [`LogMsg`]:es from `.rrd` files/streams.
 
+pub mod mmap;
 pub mod stream;
 
 #[cfg(feature = "decoder")]
@@ -13,7 +14,11 @@ use re_log_types::LogMsg;
 use crate::{
     EncodingOptions, FileHeader, OLD_RRD_HEADERS, Serializer,
     app_id_injector::CachingApplicationIdInjector,
-    codec::{self, file::decoder},
+    codec::{
+        self,
+        encryption::EncryptionKey,
+        file::{MessageHeader, MessageKind, decoder},
+    },
 };
 
 // ----------------------------------------------------------------------------
@@ -81,6 +86,9 @@ pub enum DecodeError {
     #[error("lz4 error: {0}")]
     Lz4(#[from] lz4_flex::block::DecompressError),
 
+    #[error("zstd error: {0}")]
+    Zstd(std::io::Error),
+
     #[error("Protobuf error: {0}")]
     Protobuf(#[from] re_protos::external::prost::DecodeError),
 
@@ -109,6 +117,27 @@ fn test_error_size() {
     );
 }
 
+impl DecodeError {
+    /// Whether this error only affects a single message, in which case decoding can carry on
+    /// with the next one.
+    ///
+    /// Errors that affect the framing of the stream itself (e.g. a corrupted message header) make
+    /// it impossible to find out where the next message starts.
+    pub fn is_confined_to_message(&self) -> bool {
+        !matches!(
+            self,
+            Self::NotAnRrd
+                | Self::OldRrdVersion
+                | Self::IncompatibleRerunVersion { .. }
+                | Self::Options(_)
+                | Self::Read(_)
+                | Self::Codec(
+                    codec::CodecError::HeaderDecoding(_) | codec::CodecError::UnknownMessageHeader
+                )
+        )
+    }
+}
+
 impl From<re_protos::TypeConversionError> for DecodeError {
     fn from(value: re_protos::TypeConversionError) -> Self {
         Self::TypeConversion(Box::new(value))
@@ -208,6 +237,16 @@ impl<R: std::io::Read> std::io::Read for Reader<R> {
     }
 }
 
+/// A message that was skipped by a [`Decoder`] in recovery mode, see [`Decoder::with_recovery`].
+#[derive(Debug)]
+pub struct CorruptedMessage {
+    /// Where the message starts in the stream, in bytes.
+    pub offset: u64,
+
+    /// Why the message couldn't be decoded.
+    pub err: DecodeError,
+}
+
 pub struct Decoder<R: std::io::Read> {
     version: CrateVersion,
     options: EncodingOptions,
@@ -218,6 +257,18 @@ pub struct Decoder<R: std::io::Read> {
 
     /// The application id cache used for migrating old data.
     app_id_cache: CachingApplicationIdInjector,
+
+    /// See [`Self::with_recovery`].
+    recovery: bool,
+
+    /// Where the message currently being decoded starts, in bytes.
+    message_offset: u64,
+
+    /// All the messages that were skipped so far, in recovery mode.
+    corrupted_messages: Vec<CorruptedMessage>,
+
+    /// See [`Self::with_decryption_key`].
+    decryption_key: Option<EncryptionKey>,
 }
 
 impl<R: std::io::Read> Decoder<R> {
@@ -245,6 +296,10 @@ impl<R: std::io::Read> Decoder<R> {
             read: Reader::Raw(read),
             size_bytes: FileHeader::SIZE as _,
             app_id_cache: CachingApplicationIdInjector::default(),
+            recovery: false,
+            message_offset: FileHeader::SIZE as _,
+            corrupted_messages: Vec::new(),
+            decryption_key: None,
         })
     }
 
@@ -255,6 +310,10 @@ impl<R: std::io::Read> Decoder<R> {
             read: Reader::Raw(read),
             size_bytes: FileHeader::SIZE as _,
             app_id_cache: CachingApplicationIdInjector::default(),
+            recovery: false,
+            message_offset: FileHeader::SIZE as _,
+            corrupted_messages: Vec::new(),
+            decryption_key: None,
         }
     }
 
@@ -288,9 +347,45 @@ impl<R: std::io::Read> Decoder<R> {
             read: Reader::Buffered(read),
             size_bytes: FileHeader::SIZE as _,
             app_id_cache: CachingApplicationIdInjector::default(),
+            recovery: false,
+            message_offset: FileHeader::SIZE as _,
+            corrupted_messages: Vec::new(),
+            decryption_key: None,
         })
     }
 
+    /// Enables or disables recovery mode.
+    ///
+    /// In recovery mode, messages that cannot be decoded (e.g. because their checksum doesn't
+    /// match) are skipped rather than returned as errors, so that the rest of the stream remains
+    /// loadable. Corrupted message headers make it impossible to find the next message, so they
+    /// end the stream instead.
+    ///
+    /// Every skipped message is logged and reported, see [`Self::corrupted_messages`].
+    #[inline]
+    pub fn with_recovery(mut self, recovery: bool) -> Self {
+        self.recovery = recovery;
+        self
+    }
+
+    /// Sets the key used to decrypt encrypted payloads.
+    ///
+    /// None by default, in which case encrypted payloads fail to decode.
+    /// Payloads that are not encrypted are decoded as usual either way.
+    #[inline]
+    pub fn with_decryption_key(mut self, decryption_key: Option<EncryptionKey>) -> Self {
+        self.decryption_key = decryption_key;
+        self
+    }
+
+    /// All the messages that were skipped so far, in recovery mode.
+    ///
+    /// See [`Self::with_recovery`].
+    #[inline]
+    pub fn corrupted_messages(&self) -> &[CorruptedMessage] {
+        &self.corrupted_messages
+    }
+
     /// Returns the Rerun version that was used to encode the data in the first place.
     #[inline]
     pub fn version(&self) -> CrateVersion {
@@ -306,27 +401,50 @@ impl<R: std::io::Read> Decoder<R> {
 
     /// Returns the next message in the stream, dropping messages missing application id that cannot
     /// be migrated (because they arrived before `SetStoreInfo`).
+    ///
+    /// In recovery mode, corrupted messages are dropped too, see [`Self::with_recovery`].
     fn next<F, T>(&mut self, mut decoder: F) -> Option<Result<T, DecodeError>>
     where
         F: FnMut(
             &mut CachingApplicationIdInjector,
-            &mut Reader<R>,
-        ) -> Result<(u64, Option<T>), DecodeError>,
+            MessageKind,
+            &[u8],
+        ) -> Result<Option<T>, DecodeError>,
     {
-        //TODO(#10730): remove this if/when we remove the legacy `StoreId` migration.
         loop {
             let result = self.next_impl(&mut decoder);
-            if let Some(Err(DecodeError::StoreIdMissingApplicationId {
-                store_kind,
-                recording_id,
-            })) = result
-            {
-                re_log::warn_once!(
-                    "Dropping message without application id which arrived before `SetStoreInfo` \
-                    (kind: {store_kind}, recording id: {recording_id}."
-                );
-            } else {
-                return result;
+            match result {
+                //TODO(#10730): remove this if/when we remove the legacy `StoreId` migration.
+                Some(Err(DecodeError::StoreIdMissingApplicationId {
+                    store_kind,
+                    recording_id,
+                })) => {
+                    re_log::warn_once!(
+                        "Dropping message without application id which arrived before `SetStoreInfo` \
+                        (kind: {store_kind}, recording id: {recording_id}."
+                    );
+                }
+
+                Some(Err(err)) if self.recovery => {
+                    let offset = self.message_offset;
+                    let is_confined_to_message = err.is_confined_to_message();
+
+                    if is_confined_to_message {
+                        re_log::warn!("Skipping corrupted message at offset {offset}: {err}");
+                    } else {
+                        re_log::warn!(
+                            "Stopping at corrupted data at offset {offset}, the rest of the stream is lost: {err}"
+                        );
+                    }
+                    self.corrupted_messages
+                        .push(CorruptedMessage { offset, err });
+
+                    if !is_confined_to_message {
+                        return None;
+                    }
+                }
+
+                result => return result,
             }
         }
     }
@@ -336,8 +454,9 @@ impl<R: std::io::Read> Decoder<R> {
     where
         F: FnMut(
             &mut CachingApplicationIdInjector,
-            &mut Reader<R>,
-        ) -> Result<(u64, Option<T>), DecodeError>,
+            MessageKind,
+            &[u8],
+        ) -> Result<Option<T>, DecodeError>,
     {
         re_tracing::profile_function!();
 
@@ -360,20 +479,30 @@ impl<R: std::io::Read> Decoder<R> {
             self.size_bytes += FileHeader::SIZE as u64;
         }
 
+        self.message_offset = self.size_bytes;
+
         let msg = match self.options.serializer {
-            Serializer::Protobuf => match decoder(&mut self.app_id_cache, &mut self.read) {
-                Ok((read_bytes, msg)) => {
-                    self.size_bytes += read_bytes;
-                    msg
-                }
+            Serializer::Protobuf => {
+                let (header, buf) = match decoder::read_message(&mut self.read) {
+                    Ok(message) => message,
+
+                    Err(err) => match err {
+                        DecodeError::Read(err)
+                            if err.kind() == std::io::ErrorKind::UnexpectedEof =>
+                        {
+                            return None;
+                        }
+                        _ => return Some(Err(err)),
+                    },
+                };
 
-                Err(err) => match err {
-                    DecodeError::Read(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => {
-                        return None;
-                    }
-                    _ => return Some(Err(err)),
-                },
-            },
+                self.size_bytes += (MessageHeader::SIZE_BYTES + buf.len()) as u64;
+
+                match decoder(&mut self.app_id_cache, header.kind, &buf) {
+                    Ok(msg) => msg,
+                    Err(err) => return Some(Err(err)),
+                }
+            }
         };
 
         let Some(msg) = msg else {
@@ -423,7 +552,8 @@ impl<R: std::io::Read> Decoder<R> {
 
 /// Iterator over the transport-level data (Protobuf).
 ///
-/// Application-level data (Arrow) is not decoded.
+/// Application-level data (Arrow) is not decoded. Payloads are decrypted only if the decoder has
+/// a key, see [`Decoder::with_decryption_key`].
 pub struct RawIterator<R: std::io::Read> {
     decoder: Decoder<R>,
 }
@@ -442,7 +572,13 @@ impl<R: std::io::Read> Iterator for Decoder<R> {
     type Item = Result<LogMsg, DecodeError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.next(decoder::decode_to_app)
+        let Some(decryption_key) = self.decryption_key.clone() else {
+            return self.next(decoder::decode_bytes_to_app);
+        };
+
+        self.next(|app_id_cache, kind, buf| {
+            decoder::decode_encrypted_bytes_to_app(app_id_cache, kind, buf, &decryption_key)
+        })
     }
 }
 
@@ -450,8 +586,17 @@ impl<R: std::io::Read> Iterator for RawIterator<R> {
     type Item = Result<re_protos::log_msg::v1alpha1::log_msg::Msg, DecodeError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.decoder
-            .next(|_app_id_cache, reader| decoder::decode_to_transport(reader))
+        let Some(decryption_key) = self.decoder.decryption_key.clone() else {
+            return self
+                .decoder
+                .next(|_app_id_cache, kind, buf| decoder::decode_bytes_to_transport(kind, buf));
+        };
+
+        self.decoder.next(|_app_id_cache, kind, buf| {
+            decoder::decode_bytes_to_transport(kind, buf)?
+                .map(|msg| decoder::decrypt_transport(msg, &decryption_key))
+                .transpose()
+        })
     }
 }
 
@@ -525,7 +670,7 @@ mod tests {
                 proto::log_msg::Msg::SetStoreInfo(set_store_info)
             }
             LogMsg::ArrowMsg(store_id, arrow_msg) => {
-                let payload = encode_arrow(&arrow_msg.batch, Compression::Off)
+                let payload = encode_arrow(&arrow_msg.batch, Compression::Off, 0)
                     .expect("compression should succeed");
                 let arrow_msg = ArrowMsg {
                     store_id: Some(store_id.clone().into()),
@@ -534,6 +679,8 @@ mod tests {
                     uncompressed_size: payload.uncompressed_size as i32,
                     encoding: Encoding::ArrowIpc as i32,
                     payload: payload.data.into(),
+                    payload_crc32: Some(payload.checksum),
+                    encryption: None,
                 };
                 proto::log_msg::Msg::ArrowMsg(arrow_msg)
             }
@@ -612,10 +759,24 @@ mod tests {
             EncodingOptions {
                 compression: Compression::Off,
                 serializer: Serializer::Protobuf,
+                compression_level: 0,
             },
             EncodingOptions {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
+                compression_level: 0,
+            },
+            #[cfg(feature = "zstd")]
+            EncodingOptions {
+                compression: Compression::Zstd,
+                serializer: Serializer::Protobuf,
+                compression_level: 0,
+            },
+            #[cfg(feature = "zstd")]
+            EncodingOptions {
+                compression: Compression::Zstd,
+                serializer: Serializer::Protobuf,
+                compression_level: 19,
             },
         ];
 
@@ -633,6 +794,51 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "encryption")]
+    #[test]
+    fn test_encode_decode_encrypted() {
+        use crate::codec::encryption::EncryptionError;
+
+        let key = EncryptionKey::new([7; 32]);
+        let messages = fake_log_messages();
+
+        let mut file = vec![];
+        {
+            let mut encoder = DroppableEncoder::new(
+                CrateVersion::LOCAL,
+                EncodingOptions::PROTOBUF_COMPRESSED,
+                &mut file,
+            )
+            .unwrap()
+            .with_encryption_key(Some(key.clone()));
+            for msg in &messages {
+                encoder.append(msg).unwrap();
+            }
+        }
+
+        let decode = |key: Option<EncryptionKey>| {
+            Decoder::new(&mut file.as_slice())
+                .unwrap()
+                .with_decryption_key(key)
+                .collect::<Result<Vec<LogMsg>, DecodeError>>()
+        };
+
+        similar_asserts::assert_eq!(decode(Some(key)).unwrap(), messages);
+
+        assert!(matches!(
+            decode(None),
+            Err(DecodeError::Codec(codec::CodecError::Encryption(
+                EncryptionError::MissingKey
+            )))
+        ));
+        assert!(matches!(
+            decode(Some(EncryptionKey::new([8; 32]))),
+            Err(DecodeError::Codec(codec::CodecError::Encryption(
+                EncryptionError::Decryption
+            )))
+        ));
+    }
+
     /// Test that legacy messages (aka `StoreId` without an application id) are properly decoded.
     #[test]
     fn test_decode_legacy() {
@@ -644,10 +850,12 @@ mod tests {
             EncodingOptions {
                 compression: Compression::Off,
                 serializer: Serializer::Protobuf,
+                compression_level: 0,
             },
             EncodingOptions {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
+                compression_level: 0,
             },
         ];
 
@@ -699,10 +907,12 @@ mod tests {
             EncodingOptions {
                 compression: Compression::Off,
                 serializer: Serializer::Protobuf,
+                compression_level: 0,
             },
             EncodingOptions {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
+                compression_level: 0,
             },
         ];
 
@@ -752,10 +962,12 @@ mod tests {
             EncodingOptions {
                 compression: Compression::Off,
                 serializer: Serializer::Protobuf,
+                compression_level: 0,
             },
             EncodingOptions {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
+                compression_level: 0,
             },
         ];
 
@@ -788,10 +1000,12 @@ mod tests {
             EncodingOptions {
                 compression: Compression::Off,
                 serializer: Serializer::Protobuf,
+                compression_level: 0,
             },
             EncodingOptions {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
+                compression_level: 0,
             },
         ];
 
@@ -828,4 +1042,68 @@ mod tests {
             similar_asserts::assert_eq!(decoded_messages, [messages.clone(), messages].concat());
         }
     }
+
+    #[test]
+    fn test_recovery() {
+        let rrd_version = CrateVersion::LOCAL;
+
+        let messages = fake_log_messages();
+
+        let mut file = vec![];
+        crate::encoder::encode_ref(
+            rrd_version,
+            EncodingOptions::PROTOBUF_UNCOMPRESSED,
+            messages.iter().map(Ok),
+            &mut file,
+        )
+        .unwrap();
+
+        // Flip a bit right in the middle of the Arrow payload.
+        let LogMsg::ArrowMsg(_, arrow_msg) = &messages[1] else {
+            panic!("expected an Arrow message");
+        };
+        let payload = encode_arrow(&arrow_msg.batch, Compression::Off, 0).unwrap();
+        let payload_offset = file
+            .windows(payload.data.len())
+            .position(|window| window == payload.data.as_slice())
+            .unwrap();
+        file[payload_offset + payload.data.len() / 2] ^= 1;
+
+        // By default, the corrupted message is reported as an error…
+        let decoded_messages = Decoder::new(&mut file.as_slice())
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(decoded_messages.len(), messages.len());
+        assert!(matches!(
+            decoded_messages[1],
+            Err(DecodeError::Codec(
+                codec::CodecError::ChecksumMismatch { .. }
+            ))
+        ));
+
+        // …whereas recovery mode skips it.
+        let mut decoder = Decoder::new(&mut file.as_slice())
+            .unwrap()
+            .with_recovery(true);
+        let decoded_messages = decoder
+            .by_ref()
+            .collect::<Result<Vec<LogMsg>, DecodeError>>()
+            .unwrap();
+        similar_asserts::assert_eq!(
+            decoded_messages,
+            vec![messages[0].clone(), messages[2].clone()]
+        );
+        assert_eq!(decoder.corrupted_messages().len(), 1);
+        assert!(decoder.corrupted_messages()[0].offset < payload_offset as u64);
+
+        // Corrupted headers end the stream.
+        let mut file = file[..file.len() - MessageHeader::SIZE_BYTES].to_vec(); // end of stream marker
+        file.extend_from_slice(&[0xFF; MessageHeader::SIZE_BYTES]);
+        let mut decoder = Decoder::new(&mut file.as_slice())
+            .unwrap()
+            .with_recovery(true);
+        assert_eq!(decoder.by_ref().count(), 2);
+        assert_eq!(decoder.corrupted_messages().len(), 2);
+        assert!(!decoder.corrupted_messages()[1].err.is_confined_to_message());
+    }
 }
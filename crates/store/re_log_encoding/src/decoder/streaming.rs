@@ -69,7 +69,7 @@ impl StreamingLogMsg {
             crate::protobuf_conversions::log_msg_to_proto(log_msg.clone(), compression)?;
 
         let mut log_msg_encoded = Vec::new();
-        crate::codec::file::encoder::encode(&mut log_msg_encoded, &log_msg, compression)?;
+        crate::codec::file::encoder::encode(&mut log_msg_encoded, &log_msg, compression, 0, None)?;
 
         let byte_len = log_msg_encoded.len() as _;
 
@@ -464,10 +464,12 @@ mod tests {
             EncodingOptions {
                 compression: Compression::Off,
                 serializer: Serializer::Protobuf,
+                compression_level: 0,
             },
             EncodingOptions {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
+                compression_level: 0,
             },
         ];
 
@@ -508,10 +510,12 @@ mod tests {
             EncodingOptions {
                 compression: Compression::Off,
                 serializer: Serializer::Protobuf,
+                compression_level: 0,
             },
             EncodingOptions {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
+                compression_level: 0,
             },
         ];
 
@@ -548,10 +552,12 @@ mod tests {
             EncodingOptions {
                 compression: Compression::Off,
                 serializer: Serializer::Protobuf,
+                compression_level: 0,
             },
             EncodingOptions {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
+                compression_level: 0,
             },
         ];
 
@@ -3,6 +3,7 @@
 use crate::FileHeader;
 use crate::Serializer;
 use crate::codec;
+use crate::codec::encryption::EncryptionKey;
 use crate::codec::file::{self, encoder};
 use crate::{Compression, EncodingOptions};
 use re_build_info::CrateVersion;
@@ -21,6 +22,9 @@ pub enum EncodeError {
     #[error("lz4 error: {0}")]
     Lz4(#[from] lz4_flex::block::CompressError),
 
+    #[error("zstd error: {0}")]
+    Zstd(std::io::Error),
+
     #[error("Protobuf error: {0}")]
     Protobuf(#[from] re_protos::external::prost::EncodeError),
 
@@ -97,12 +101,25 @@ impl<W: std::io::Write> DroppableEncoder<W> {
         })
     }
 
+    /// See [`Encoder::with_encryption_key`].
+    #[inline]
+    pub fn with_encryption_key(mut self, encryption_key: Option<EncryptionKey>) -> Self {
+        self.encoder.set_encryption_key(encryption_key);
+        self
+    }
+
     /// Returns the size in bytes of the encoded data.
     #[inline]
     pub fn append(&mut self, message: &LogMsg) -> Result<u64, EncodeError> {
         self.encoder.append(message)
     }
 
+    /// See [`Encoder::append_batch`].
+    #[inline]
+    pub fn append_batch(&mut self, messages: &[LogMsg]) -> Result<u64, EncodeError> {
+        self.encoder.append_batch(messages)
+    }
+
     /// Returns the size in bytes of the encoded data.
     #[inline]
     pub fn append_proto(&mut self, message: LogMsgProto) -> Result<u64, EncodeError> {
@@ -143,11 +160,17 @@ impl<W: std::io::Write> std::ops::Drop for DroppableEncoder<W> {
 pub struct Encoder<W: std::io::Write> {
     serializer: Serializer,
     compression: Compression,
+    compression_level: i32,
+
+    /// See [`Self::with_encryption_key`].
+    encryption_key: Option<EncryptionKey>,
+
     write: W,
     scratch: Vec<u8>,
 }
 
 impl<W: std::io::Write> Encoder<W> {
+    /// Payloads are not encrypted, see [`Self::with_encryption_key`].
     pub fn new(
         version: CrateVersion,
         options: EncodingOptions,
@@ -163,11 +186,29 @@ impl<W: std::io::Write> Encoder<W> {
         Ok(Self {
             serializer: options.serializer,
             compression: options.compression,
+            compression_level: options.compression_level,
+            encryption_key: None,
             write,
             scratch: Vec::new(),
         })
     }
 
+    /// Sets the key used to encrypt the payloads of chunks, or disables encryption.
+    ///
+    /// Disabled by default. Encryption requires the `encryption` feature: without it, appending
+    /// chunks fails.
+    #[inline]
+    pub fn with_encryption_key(mut self, encryption_key: Option<EncryptionKey>) -> Self {
+        self.set_encryption_key(encryption_key);
+        self
+    }
+
+    /// See [`Self::with_encryption_key`].
+    #[inline]
+    pub fn set_encryption_key(&mut self, encryption_key: Option<EncryptionKey>) {
+        self.encryption_key = encryption_key;
+    }
+
     /// Returns the size in bytes of the encoded data.
     pub fn append(&mut self, message: &LogMsg) -> Result<u64, EncodeError> {
         re_tracing::profile_function!();
@@ -175,7 +216,13 @@ impl<W: std::io::Write> Encoder<W> {
         self.scratch.clear();
         match self.serializer {
             Serializer::Protobuf => {
-                encoder::encode(&mut self.scratch, message, self.compression)?;
+                encoder::encode(
+                    &mut self.scratch,
+                    message,
+                    self.compression,
+                    self.compression_level,
+                    self.encryption_key.as_ref(),
+                )?;
 
                 self.write
                     .write_all(&self.scratch)
@@ -185,6 +232,50 @@ impl<W: std::io::Write> Encoder<W> {
         }
     }
 
+    /// Like calling [`Self::append`] for every message, but serializes and compresses them in parallel.
+    ///
+    /// Messages are still written in order, so the output is exactly the same.
+    ///
+    /// Returns the size in bytes of the encoded data.
+    pub fn append_batch(&mut self, messages: &[LogMsg]) -> Result<u64, EncodeError> {
+        use rayon::prelude::*;
+
+        re_tracing::profile_function!();
+
+        match self.serializer {
+            Serializer::Protobuf => {
+                let Self {
+                    compression,
+                    compression_level,
+                    encryption_key,
+                    ..
+                } = self;
+
+                let encoded = messages
+                    .par_iter()
+                    .map(|message| {
+                        let mut buf = Vec::new();
+                        encoder::encode(
+                            &mut buf,
+                            message,
+                            *compression,
+                            *compression_level,
+                            encryption_key.as_ref(),
+                        )?;
+                        Ok(buf)
+                    })
+                    .collect::<Result<Vec<_>, EncodeError>>()?;
+
+                let mut size_bytes = 0;
+                for buf in encoded {
+                    self.write.write_all(&buf)?;
+                    size_bytes += buf.len() as u64;
+                }
+                Ok(size_bytes)
+            }
+        }
+    }
+
     /// Returns the size in bytes of the encoded data.
     pub fn append_proto(&mut self, message: LogMsgProto) -> Result<u64, EncodeError> {
         re_tracing::profile_function!();
@@ -192,7 +283,7 @@ impl<W: std::io::Write> Encoder<W> {
         self.scratch.clear();
         match self.serializer {
             Serializer::Protobuf => {
-                encoder::encode_proto(&mut self.scratch, message)?;
+                encoder::encode_proto(&mut self.scratch, message, self.encryption_key.as_ref())?;
 
                 self.write
                     .write_all(&self.scratch)
@@ -229,19 +320,46 @@ impl<W: std::io::Write> Encoder<W> {
     }
 }
 
+/// Encodes the messages on all cores, see [`Encoder::append_batch`].
+///
 /// Returns the size in bytes of the encoded data.
 pub fn encode(
     version: CrateVersion,
     options: EncodingOptions,
     messages: impl Iterator<Item = ChunkResult<LogMsg>>,
     write: &mut impl std::io::Write,
+) -> Result<u64, EncodeError> {
+    encode_encrypted(version, options, None, messages, write)
+}
+
+/// Like [`encode`], but encrypts the payloads of chunks if there is an `encryption_key`, see
+/// [`Encoder::with_encryption_key`].
+///
+/// Returns the size in bytes of the encoded data.
+pub fn encode_encrypted(
+    version: CrateVersion,
+    options: EncodingOptions,
+    encryption_key: Option<EncryptionKey>,
+    messages: impl Iterator<Item = ChunkResult<LogMsg>>,
+    write: &mut impl std::io::Write,
 ) -> Result<u64, EncodeError> {
     re_tracing::profile_function!();
-    let mut encoder = DroppableEncoder::new(version, options, write)?;
+
+    // Large enough to keep all cores busy, small enough to not hold on to too much encoded data at once.
+    let batch_size = 4 * rayon::current_num_threads();
+
+    let mut encoder =
+        DroppableEncoder::new(version, options, write)?.with_encryption_key(encryption_key);
     let mut size_bytes = 0;
+    let mut batch = Vec::with_capacity(batch_size);
     for message in messages {
-        size_bytes += encoder.append(&message?)?;
+        batch.push(message?);
+        if batch.len() == batch_size {
+            size_bytes += encoder.append_batch(&batch)?;
+            batch.clear();
+        }
     }
+    size_bytes += encoder.append_batch(&batch)?;
     Ok(size_bytes)
 }
 
@@ -15,6 +15,10 @@ pub enum FileSinkError {
     #[error("Failed to create file {0}: {1}")]
     CreateFile(PathBuf, std::io::Error),
 
+    /// Error reading a file, e.g. a segment of a [`crate::RingBufferSink`].
+    #[error("Failed to read file {0}: {1}")]
+    ReadFile(PathBuf, std::io::Error),
+
     /// Error spawning the file writer thread.
     #[error("Failed to spawn thread: {0}")]
     SpawnThread(std::io::Error),
@@ -22,6 +26,10 @@ pub enum FileSinkError {
     /// Error encoding a log message.
     #[error("Failed to encode LogMsg: {0}")]
     LogMsgEncode(#[from] crate::encoder::EncodeError),
+
+    /// The writer thread has stopped.
+    #[error("The writer thread has stopped")]
+    Disconnected,
 }
 
 enum Command {
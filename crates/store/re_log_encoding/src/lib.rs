@@ -14,6 +14,10 @@ pub mod protobuf_conversions;
 #[cfg(not(target_arch = "wasm32"))]
 mod file_sink;
 
+#[cfg(feature = "encoder")]
+#[cfg(not(target_arch = "wasm32"))]
+mod ring_buffer_sink;
+
 #[cfg(feature = "stream_from_http")]
 pub mod stream_rrd_from_http;
 
@@ -32,6 +36,10 @@ pub use app_id_injector::{
 #[cfg(not(target_arch = "wasm32"))]
 pub use file_sink::{FileSink, FileSinkError};
 
+#[cfg(feature = "encoder")]
+#[cfg(not(target_arch = "wasm32"))]
+pub use ring_buffer_sink::RingBufferSink;
+
 // ----------------------------------------------------------------------------
 
 #[cfg(any(feature = "encoder", feature = "decoder"))]
@@ -50,6 +58,12 @@ pub enum Compression {
 
     /// Very fast compression and decompression, but not very good compression ratio.
     LZ4 = 1,
+
+    /// Much better compression ratio, at the cost of slower compression.
+    ///
+    /// Decompression remains fast. The tradeoff is configured with
+    /// [`EncodingOptions::compression_level`].
+    Zstd = 2,
 }
 
 /// How we serialize the data
@@ -63,16 +77,38 @@ pub enum Serializer {
 pub struct EncodingOptions {
     pub compression: Compression,
     pub serializer: Serializer,
+
+    /// The compression level, for the algorithms that have one (i.e. [`Compression::Zstd`]).
+    ///
+    /// Zstd levels go from 1 (fastest) to 22 (smallest), `0` picks the default level (3).
+    /// Negative levels trade even more compression ratio for speed.
+    ///
+    /// Only used when encoding: this is not stored in the encoded data.
+    pub compression_level: i32,
 }
 
 impl EncodingOptions {
+    /// Fast enough to keep up with live data, this is what is used for streaming and saving.
     pub const PROTOBUF_COMPRESSED: Self = Self {
         compression: Compression::LZ4,
         serializer: Serializer::Protobuf,
+        compression_level: 0,
     };
     pub const PROTOBUF_UNCOMPRESSED: Self = Self {
         compression: Compression::Off,
         serializer: Serializer::Protobuf,
+        compression_level: 0,
+    };
+
+    /// Produces much smaller files than [`Self::PROTOBUF_COMPRESSED`], for a small encoding
+    /// overhead.
+    ///
+    /// Uses zstd's default level: higher levels are a lot slower to encode for little gain,
+    /// see `msg_encode_benchmark`.
+    pub const PROTOBUF_ZSTD: Self = Self {
+        compression: Compression::Zstd,
+        serializer: Serializer::Protobuf,
+        compression_level: 0,
     };
 
     pub fn from_bytes(bytes: [u8; 4]) -> Result<Self, OptionsError> {
@@ -81,6 +117,7 @@ impl EncodingOptions {
                 let compression = match compression {
                     0 => Compression::Off,
                     1 => Compression::LZ4,
+                    2 => Compression::Zstd,
                     _ => return Err(OptionsError::UnknownCompression(compression)),
                 };
                 let serializer = match serializer {
@@ -91,6 +128,7 @@ impl EncodingOptions {
                 Ok(Self {
                     compression,
                     serializer,
+                    compression_level: 0,
                 })
             }
             _ => Err(OptionsError::UnknownReservedBytes),
@@ -137,7 +175,6 @@ pub(crate) struct FileHeader {
 
 #[cfg(any(feature = "encoder", feature = "decoder"))]
 impl FileHeader {
-    #[cfg(feature = "decoder")]
     pub const SIZE: usize = 12;
 
     #[cfg(feature = "encoder")]
@@ -12,6 +12,7 @@ impl From<re_protos::log_msg::v1alpha1::Compression> for crate::Compression {
             re_protos::log_msg::v1alpha1::Compression::Unspecified
             | re_protos::log_msg::v1alpha1::Compression::None => Self::Off,
             re_protos::log_msg::v1alpha1::Compression::Lz4 => Self::LZ4,
+            re_protos::log_msg::v1alpha1::Compression::Zstd => Self::Zstd,
         }
     }
 }
@@ -21,6 +22,7 @@ impl From<crate::Compression> for re_protos::log_msg::v1alpha1::Compression {
         match value {
             crate::Compression::Off => Self::None,
             crate::Compression::LZ4 => Self::Lz4,
+            crate::Compression::Zstd => Self::Zstd,
         }
     }
 }
@@ -111,7 +113,7 @@ pub fn log_msg_from_proto(
 pub fn arrow_msg_from_proto(
     arrow_msg: &re_protos::log_msg::v1alpha1::ArrowMsg,
 ) -> Result<re_log_types::ArrowMsg, crate::decoder::DecodeError> {
-    use crate::codec::{CodecError, arrow::decode_arrow};
+    use crate::codec::{CodecError, arrow::decode_arrow, encryption::EncryptionError};
     use crate::decoder::DecodeError;
     use re_protos::log_msg::v1alpha1::Encoding;
 
@@ -119,6 +121,10 @@ pub fn arrow_msg_from_proto(
         return Err(DecodeError::Codec(CodecError::UnsupportedEncoding));
     }
 
+    if arrow_msg.encryption.is_some() {
+        return Err(DecodeError::Codec(EncryptionError::MissingKey.into()));
+    }
+
     let batch = decode_arrow(
         &arrow_msg.payload,
         arrow_msg.uncompressed_size as usize,
@@ -193,17 +199,16 @@ pub fn arrow_msg_to_proto(
     use crate::codec::arrow::encode_arrow;
     use re_protos::log_msg::v1alpha1::ArrowMsg as ProtoArrowMsg;
 
-    let payload = encode_arrow(&arrow_msg.batch, compression)?;
+    let payload = encode_arrow(&arrow_msg.batch, compression, 0 /* default level */)?;
 
     Ok(ProtoArrowMsg {
         store_id: Some(store_id.into()),
         chunk_id: Some(arrow_msg.chunk_id.into()),
-        compression: match compression {
-            crate::Compression::Off => re_protos::log_msg::v1alpha1::Compression::None as i32,
-            crate::Compression::LZ4 => re_protos::log_msg::v1alpha1::Compression::Lz4 as i32,
-        },
+        compression: re_protos::log_msg::v1alpha1::Compression::from(compression) as i32,
         uncompressed_size: payload.uncompressed_size as i32,
         encoding: re_protos::log_msg::v1alpha1::Encoding::ArrowIpc as i32,
         payload: payload.data.into(),
+        payload_crc32: Some(payload.checksum),
+        encryption: None,
     })
 }
@@ -0,0 +1,498 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{BufWriter, Seek as _, Write as _};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::mpsc::{Receiver, Sender, SyncSender};
+
+use parking_lot::Mutex;
+
+use re_build_info::CrateVersion;
+use re_log_types::{LogMsg, StoreKind};
+
+use crate::codec::file::{self, encoder};
+use crate::encoder::EncodeError;
+use crate::{EncodingOptions, FileHeader, FileSinkError};
+
+/// The size budget of a [`RingBufferSink`] is split among this many segments.
+///
+/// When the budget is exceeded, the oldest segment is deleted as a whole.
+const NUM_SEGMENTS: u64 = 8;
+
+const SEGMENT_EXTENSION: &str = "rrd";
+
+enum Command {
+    Send(LogMsg),
+    Flush(SyncSender<()>),
+    Snapshot(PathBuf, SyncSender<Result<(), FileSinkError>>),
+}
+
+/// Continuously records log messages to a size-bounded ring buffer on disk, a.k.a. a black-box
+/// recorder.
+///
+/// Only the most recent data is kept: once the buffer is full, the oldest data is deleted to make
+/// room for new data. Call [`Self::snapshot`] to write the current contents of the buffer to a
+/// regular `.rrd` file, e.g. when something goes wrong.
+///
+/// The buffer is a directory of segments, which are themselves valid (if unterminated) `.rrd`
+/// files. Segments left over by a previous process, e.g. one that crashed, are kept around and
+/// count against the budget; use [`Self::snapshot_dir`] to recover them.
+///
+/// Store infos, blueprints and static data are written again at the start of every segment, so
+/// that each segment is self-contained. This means that they must fit comfortably in a segment,
+/// i.e. an eighth of the budget.
+///
+/// The sink is a cheap handle: keep a clone around to take snapshots after handing it over to a
+/// recording stream.
+#[derive(Clone)]
+pub struct RingBufferSink {
+    inner: Arc<RingBufferSinkInner>,
+}
+
+struct RingBufferSinkInner {
+    // None = quit
+    tx: Mutex<Sender<Option<Command>>>,
+    join_handle: Option<std::thread::JoinHandle<()>>,
+
+    /// Only used for diagnostics, not for access after `new()`.
+    dir: PathBuf,
+}
+
+impl Drop for RingBufferSinkInner {
+    fn drop(&mut self) {
+        self.tx.lock().send(None).ok();
+        if let Some(join_handle) = self.join_handle.take() {
+            join_handle.join().ok();
+        }
+    }
+}
+
+impl RingBufferSink {
+    /// Start recording log messages to the given directory, using at most `max_bytes` of disk.
+    ///
+    /// The directory is created if needed.
+    pub fn new(dir: impl Into<PathBuf>, max_bytes: u64) -> Result<Self, FileSinkError> {
+        let dir = dir.into();
+
+        re_log::debug!("Recording to ring buffer at {dir:?}…");
+
+        std::fs::create_dir_all(&dir).map_err(|err| FileSinkError::CreateFile(dir.clone(), err))?;
+
+        let ring_buffer = RingBuffer::new(dir.clone(), max_bytes)?;
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let join_handle = spawn_and_record(ring_buffer, rx)?;
+
+        Ok(Self {
+            inner: Arc::new(RingBufferSinkInner {
+                tx: tx.into(),
+                join_handle: Some(join_handle),
+                dir,
+            }),
+        })
+    }
+
+    #[inline]
+    pub fn flush_blocking(&self) {
+        let (tx, rx) = std::sync::mpsc::sync_channel(0); // oneshot
+        self.inner.tx.lock().send(Some(Command::Flush(tx))).ok();
+        rx.recv().ok();
+    }
+
+    #[inline]
+    pub fn send(&self, log_msg: LogMsg) {
+        self.inner.tx.lock().send(Some(Command::Send(log_msg))).ok();
+    }
+
+    /// Writes everything currently in the ring buffer to a regular `.rrd` file at `path`.
+    ///
+    /// All messages sent before this call are included. Recording carries on in the meantime.
+    pub fn snapshot(&self, path: impl Into<PathBuf>) -> Result<(), FileSinkError> {
+        let (tx, rx) = std::sync::mpsc::sync_channel(0); // oneshot
+        self.inner
+            .tx
+            .lock()
+            .send(Some(Command::Snapshot(path.into(), tx)))
+            .ok();
+        rx.recv().unwrap_or(Err(FileSinkError::Disconnected))
+    }
+
+    /// Writes everything in the ring buffer directory `dir` to a regular `.rrd` file at `path`.
+    ///
+    /// Use this to recover the data of a process that didn't get the chance to take a snapshot.
+    /// Don't use this on a directory that is still being recorded to, see [`Self::snapshot`]
+    /// instead.
+    pub fn snapshot_dir(
+        dir: impl AsRef<Path>,
+        path: impl AsRef<Path>,
+    ) -> Result<(), FileSinkError> {
+        let dir = dir.as_ref();
+        let segments = list_segments(dir)?
+            .into_iter()
+            .map(|(index, _)| segment_path(dir, index))
+            .collect::<Vec<_>>();
+        write_snapshot(&segments, path.as_ref())
+    }
+}
+
+fn spawn_and_record(
+    mut ring_buffer: RingBuffer,
+    rx: Receiver<Option<Command>>,
+) -> Result<std::thread::JoinHandle<()>, FileSinkError> {
+    std::thread::Builder::new()
+        .name("ring_buffer_writer".into())
+        .spawn(move || {
+            while let Ok(Some(cmd)) = rx.recv() {
+                match cmd {
+                    Command::Send(log_msg) => {
+                        if let Err(err) = ring_buffer.append(&log_msg) {
+                            re_log::error_once!(
+                                "Failed to write to ring buffer at {:?}: {err}",
+                                ring_buffer.dir
+                            );
+                        }
+                    }
+                    Command::Flush(oneshot) => {
+                        re_log::trace!("Flushing…");
+                        if let Err(err) = ring_buffer.flush() {
+                            re_log::error_once!(
+                                "Failed to flush ring buffer at {:?}: {err}",
+                                ring_buffer.dir
+                            );
+                        }
+                        drop(oneshot); // signals the oneshot
+                    }
+                    Command::Snapshot(path, oneshot) => {
+                        oneshot.send(ring_buffer.snapshot(&path)).ok();
+                    }
+                }
+            }
+            if let Err(err) = ring_buffer.flush() {
+                re_log::error!(
+                    "Failed to flush ring buffer at {:?}: {err}",
+                    ring_buffer.dir
+                );
+            }
+            re_log::debug!("Ring buffer written to {:?}", ring_buffer.dir);
+        })
+        .map_err(FileSinkError::SpawnThread)
+}
+
+impl fmt::Debug for RingBufferSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RingBufferSink")
+            .field("dir", &self.inner.dir)
+            .finish_non_exhaustive()
+    }
+}
+
+// ---
+
+/// The state of the writer thread.
+struct RingBuffer {
+    dir: PathBuf,
+    options: EncodingOptions,
+
+    max_bytes: u64,
+    max_segment_bytes: u64,
+
+    /// Index and size of all segments, oldest first. The last one is being written to.
+    segments: VecDeque<(u64, u64)>,
+    writer: BufWriter<std::fs::File>,
+
+    /// Encoded messages that are written again at the start of every segment.
+    sticky: Vec<u8>,
+    scratch: Vec<u8>,
+}
+
+impl RingBuffer {
+    fn new(dir: PathBuf, max_bytes: u64) -> Result<Self, FileSinkError> {
+        let segments = list_segments(&dir)?.into();
+        let next_index = last_index(&segments).map_or(0, |index| index + 1);
+        let path = segment_path(&dir, next_index);
+        let file = std::fs::File::create(&path)
+            .map_err(|err| FileSinkError::CreateFile(path.clone(), err))?;
+
+        let mut ring_buffer = Self {
+            dir,
+            // We always compress on disk
+            options: EncodingOptions::PROTOBUF_COMPRESSED,
+            max_bytes,
+            max_segment_bytes: (max_bytes / NUM_SEGMENTS).max(1),
+            segments,
+            writer: BufWriter::new(file),
+            sticky: Vec::new(),
+            scratch: Vec::new(),
+        };
+        ring_buffer.start_segment(next_index)?;
+
+        Ok(ring_buffer)
+    }
+
+    fn append(&mut self, msg: &LogMsg) -> Result<(), FileSinkError> {
+        re_tracing::profile_function!();
+
+        self.scratch.clear();
+        encoder::encode(
+            &mut self.scratch,
+            msg,
+            self.options.compression,
+            self.options.compression_level,
+            None,
+        )?;
+
+        let current_size = self.segments.back().map_or(0, |(_, size)| *size);
+        let header_size = (FileHeader::SIZE + self.sticky.len()) as u64;
+        if current_size > header_size
+            && current_size + self.scratch.len() as u64 > self.max_segment_bytes
+        {
+            self.rotate()?;
+        }
+
+        self.writer
+            .write_all(&self.scratch)
+            .map_err(EncodeError::Write)?;
+        if let Some((_, size)) = self.segments.back_mut() {
+            *size += self.scratch.len() as u64;
+        }
+
+        if is_sticky(msg) {
+            self.sticky.extend_from_slice(&self.scratch);
+        }
+
+        Ok(())
+    }
+
+    /// Writes the header of a new segment, which `writer` points to.
+    fn start_segment(&mut self, index: u64) -> Result<(), FileSinkError> {
+        FileHeader {
+            magic: *crate::RRD_HEADER,
+            version: CrateVersion::LOCAL.to_bytes(),
+            options: self.options,
+        }
+        .encode(&mut self.writer)?;
+        self.writer
+            .write_all(&self.sticky)
+            .map_err(EncodeError::Write)?;
+
+        self.segments
+            .push_back((index, (FileHeader::SIZE + self.sticky.len()) as u64));
+
+        Ok(())
+    }
+
+    /// Starts a new segment, and deletes the oldest ones if over budget.
+    fn rotate(&mut self) -> Result<(), FileSinkError> {
+        self.flush()?;
+
+        let index = last_index(&self.segments).map_or(0, |index| index + 1);
+        let path = segment_path(&self.dir, index);
+        let file = std::fs::File::create(&path)
+            .map_err(|err| FileSinkError::CreateFile(path.clone(), err))?;
+        self.writer = BufWriter::new(file);
+        self.start_segment(index)?;
+
+        while self.segments.len() > 1
+            && self.segments.iter().map(|(_, size)| size).sum::<u64>() > self.max_bytes
+        {
+            if let Some((index, _)) = self.segments.pop_front() {
+                let path = segment_path(&self.dir, index);
+                if let Err(err) = std::fs::remove_file(&path) {
+                    re_log::warn!("Failed to remove ring buffer segment {path:?}: {err}");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), FileSinkError> {
+        self.writer.flush().map_err(EncodeError::Write)?;
+        Ok(())
+    }
+
+    fn snapshot(&mut self, path: &Path) -> Result<(), FileSinkError> {
+        re_tracing::profile_function!();
+
+        self.flush()?;
+
+        let segments = self
+            .segments
+            .iter()
+            .map(|(index, _)| segment_path(&self.dir, *index))
+            .collect::<Vec<_>>();
+        write_snapshot(&segments, path)
+    }
+}
+
+/// Whether the message must be part of every segment for the segment to be usable on its own.
+fn is_sticky(msg: &LogMsg) -> bool {
+    match msg {
+        LogMsg::SetStoreInfo(_) | LogMsg::BlueprintActivationCommand(_) => true,
+        LogMsg::ArrowMsg(store_id, arrow_msg) => {
+            store_id.kind() == StoreKind::Blueprint
+                || re_chunk::Chunk::from_arrow_msg(arrow_msg).is_ok_and(|chunk| chunk.is_static())
+        }
+    }
+}
+
+fn segment_path(dir: &Path, index: u64) -> PathBuf {
+    dir.join(format!("{index:010}.{SEGMENT_EXTENSION}"))
+}
+
+fn last_index(segments: &VecDeque<(u64, u64)>) -> Option<u64> {
+    segments.back().map(|(index, _)| *index)
+}
+
+/// Index and size of all the segments in `dir`, oldest first.
+fn list_segments(dir: &Path) -> Result<Vec<(u64, u64)>, FileSinkError> {
+    let entries =
+        std::fs::read_dir(dir).map_err(|err| FileSinkError::ReadFile(dir.to_owned(), err))?;
+
+    let mut segments = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension()? != SEGMENT_EXTENSION {
+                return None;
+            }
+            let index = path.file_stem()?.to_str()?.parse::<u64>().ok()?;
+            let size = entry.metadata().ok()?.len();
+            Some((index, size))
+        })
+        .collect::<Vec<_>>();
+    segments.sort_unstable();
+
+    Ok(segments)
+}
+
+/// Concatenates the messages of all `segments` into a single `.rrd` file.
+fn write_snapshot(segments: &[PathBuf], path: &Path) -> Result<(), FileSinkError> {
+    let file = std::fs::File::create(path)
+        .map_err(|err| FileSinkError::CreateFile(path.to_owned(), err))?;
+    let mut writer = BufWriter::new(file);
+
+    FileHeader {
+        magic: *crate::RRD_HEADER,
+        version: CrateVersion::LOCAL.to_bytes(),
+        options: EncodingOptions::PROTOBUF_COMPRESSED,
+    }
+    .encode(&mut writer)?;
+
+    for segment in segments {
+        let mut reader = std::fs::File::open(segment)
+            .map_err(|err| FileSinkError::ReadFile(segment.clone(), err))?;
+        reader
+            .seek(std::io::SeekFrom::Start(FileHeader::SIZE as u64))
+            .map_err(|err| FileSinkError::ReadFile(segment.clone(), err))?;
+        std::io::copy(&mut reader, &mut writer)
+            .map_err(|err| FileSinkError::ReadFile(segment.clone(), err))?;
+    }
+
+    file::MessageHeader {
+        kind: file::MessageKind::End,
+        len: 0,
+    }
+    .encode(&mut writer)?;
+    writer.flush().map_err(EncodeError::Write)?;
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "decoder"))]
+mod tests {
+    #![allow(clippy::unwrap_used)] // acceptable for tests
+
+    use re_chunk::{Chunk, RowId};
+    use re_log_types::{
+        SetStoreInfo, StoreId, StoreInfo, StoreSource, TimePoint, Timeline,
+        example_components::{MyPoint, MyPoints},
+    };
+
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rerun_{name}_{}", re_chunk::ChunkId::new()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn decode(path: &Path) -> Vec<LogMsg> {
+        let file = std::fs::File::open(path).unwrap();
+        crate::decoder::Decoder::new(std::io::BufReader::new(file))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn keeps_most_recent_data() {
+        const MAX_BYTES: u64 = 16 * 1024;
+
+        let dir = temp_dir("ring_buffer_sink");
+        let store_id = StoreId::random(StoreKind::Recording, "rerun_example_ring_buffer");
+
+        let frame_msg = |frame: i64| {
+            let chunk = Chunk::builder("points")
+                .with_archetype(
+                    RowId::new(),
+                    TimePoint::default().with(Timeline::new_sequence("frame"), frame),
+                    &MyPoints::new((0..100).map(|i| MyPoint::new(i as f32, frame as f32))),
+                )
+                .build()
+                .unwrap();
+            LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg().unwrap())
+        };
+
+        let sink = RingBufferSink::new(&dir, MAX_BYTES).unwrap();
+        sink.send(LogMsg::SetStoreInfo(SetStoreInfo {
+            row_id: *RowId::new(),
+            info: StoreInfo {
+                store_id: store_id.clone(),
+                cloned_from: None,
+                store_source: StoreSource::Unknown,
+                store_version: Some(CrateVersion::LOCAL),
+            },
+        }));
+        for frame in 0..100 {
+            sink.send(frame_msg(frame));
+        }
+
+        let snapshot = dir.join("snapshot.rrd");
+        sink.snapshot(&snapshot).unwrap();
+
+        let total_size = list_segments(&dir)
+            .unwrap()
+            .iter()
+            .map(|(_, size)| size)
+            .sum::<u64>();
+        assert!(total_size <= MAX_BYTES);
+
+        let msgs = decode(&snapshot);
+        assert!(matches!(msgs[0], LogMsg::SetStoreInfo(_)));
+
+        let num_chunks = msgs
+            .iter()
+            .filter(|msg| matches!(msg, LogMsg::ArrowMsg(..)))
+            .count();
+        assert!(0 < num_chunks && num_chunks < 100);
+
+        // The most recent frame is always there.
+        let LogMsg::ArrowMsg(_, last) = msgs.last().unwrap() else {
+            panic!("expected a chunk");
+        };
+        let last = Chunk::from_arrow_msg(last).unwrap();
+        assert_eq!(
+            Some(99),
+            last.timelines()
+                .values()
+                .next()
+                .map(|column| column.times_raw()[0])
+        );
+
+        drop(sink);
+        RingBufferSink::snapshot_dir(&dir, dir.join("recovered.rrd")).unwrap();
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
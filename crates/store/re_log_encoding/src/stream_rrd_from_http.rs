@@ -1,4 +1,5 @@
 use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::ops::ControlFlow;
 use std::sync::Arc;
 
@@ -23,9 +24,9 @@ pub fn stream_rrd_from_http_to_channel(
             follow,
         },
     );
-    stream_rrd_from_http(
-        url.clone(),
-        Arc::new(move |msg| {
+    let on_msg: Arc<HttpMessageCallback> = Arc::new({
+        let url = url.clone();
+        move |msg| {
             if let Some(on_msg) = &on_msg {
                 on_msg();
             }
@@ -48,8 +49,15 @@ pub fn stream_rrd_from_http_to_channel(
                     ControlFlow::Break(())
                 }
             }
-        }),
-    );
+        }
+    });
+
+    if cfg!(target_arch = "wasm32") {
+        // A dropped connection then only costs a block of a multi-GB recording, not the whole download.
+        stream_rrd_from_http_ranges(url, RANGE_BLOCK_SIZE, on_msg);
+    } else {
+        stream_rrd_from_http(url, on_msg);
+    }
     rx
 }
 
@@ -96,26 +104,9 @@ pub fn stream_rrd_from_http(url: String, on_msg: Arc<HttpMessageCallback>) {
                         return on_msg(HttpMessage::Success);
                     }
 
-                    re_tracing::profile_scope!("decoding_rrd_stream");
-                    decoder.borrow_mut().push_chunk(chunk);
-                    loop {
-                        match decoder.borrow_mut().try_read() {
-                            Ok(message) => match message {
-                                Some(message) => {
-                                    // only return if the callback asks us to
-                                    if on_msg(HttpMessage::LogMsg(message)).is_break() {
-                                        return ControlFlow::Break(());
-                                    }
-                                }
-                                None => return ControlFlow::Continue(()),
-                            },
-                            Err(err) => {
-                                return on_msg(HttpMessage::Failure(
-                                    format!("Failed to fetch .rrd file from {url}: {err}").into(),
-                                ));
-                            }
-                        }
-                    }
+                    let mut decoder = decoder.borrow_mut();
+                    decoder.push_chunk(chunk);
+                    decode_available(&mut decoder, &url, on_msg.as_ref())
                 }
             },
             Err(err) => on_msg(HttpMessage::Failure(
@@ -125,6 +116,287 @@ pub fn stream_rrd_from_http(url: String, on_msg: Arc<HttpMessageCallback>) {
     });
 }
 
+/// How many bytes [`stream_rrd_from_http_ranges`] requests at a time when loading in the browser.
+pub const RANGE_BLOCK_SIZE: u64 = 8 * 1024 * 1024;
+
+/// How many blocks [`stream_rrd_from_http_ranges`] keeps in flight at once, so that downloading
+/// isn't bound by the round trip time.
+pub const MAX_BLOCKS_IN_FLIGHT: u64 = 4;
+
+/// How often a block is requested again before giving up, e.g. on a flaky connection.
+const MAX_RANGE_RETRIES: u32 = 3;
+
+/// Stream an rrd file from a HTTP server in blocks of `block_size` bytes, using range requests.
+///
+/// The .rrd format has no index to seek with, so the blocks are decoded front to back. The first
+/// block tells the size of the file, after which up to [`MAX_BLOCKS_IN_FLIGHT`] blocks are
+/// downloaded concurrently, and handed over to the decoder in order as they arrive.
+/// Downloading stops as soon as `on_msg` asks to, and failed blocks are retried, which matters
+/// for multi-GB recordings hosted on object storage.
+///
+/// Servers that don't support range requests reply to the first one with the whole file,
+/// which is then decoded as is. Servers that don't tell the size of the file get one block
+/// requested at a time.
+pub fn stream_rrd_from_http_ranges(url: String, block_size: u64, on_msg: Arc<HttpMessageCallback>) {
+    re_log::debug!("Downloading .rrd file from {url:?} in blocks of {block_size} bytes…");
+
+    Arc::new(RangeStream::new(url, block_size, on_msg)).fetch_block(0, 0);
+}
+
+/// The state of [`stream_rrd_from_http_ranges`], shared by the requests of all blocks.
+struct RangeStream {
+    url: String,
+    block_size: u64,
+    state: parking_lot::Mutex<RangeStreamState>,
+    on_msg: Arc<HttpMessageCallback>,
+}
+
+struct RangeStreamState {
+    decoder: StreamDecoder,
+
+    /// The offset of the next block to decode.
+    decoded_up_to: u64,
+
+    /// The offset of the next block to request.
+    requested_up_to: u64,
+
+    /// The size of the file, once a server response told it.
+    total_size: Option<u64>,
+
+    /// Blocks that arrived before the ones in front of them, by offset.
+    pending_blocks: BTreeMap<u64, Vec<u8>>,
+
+    /// Set once the stream succeeded, failed or was cancelled, so that the responses that are
+    /// still in flight are ignored.
+    is_done: bool,
+}
+
+impl RangeStream {
+    fn new(url: String, block_size: u64, on_msg: Arc<HttpMessageCallback>) -> Self {
+        let block_size = block_size.max(1);
+        Self {
+            url,
+            block_size,
+            state: parking_lot::Mutex::new(RangeStreamState {
+                decoder: StreamDecoder::new(),
+                decoded_up_to: 0,
+                requested_up_to: block_size, // The first block is requested right away.
+                total_size: None,
+                pending_blocks: BTreeMap::new(),
+                is_done: false,
+            }),
+            on_msg,
+        }
+    }
+
+    fn fetch_block(self: Arc<Self>, offset: u64, attempt: u32) {
+        let mut request = ehttp::Request::get(&self.url);
+        request.headers.insert(
+            "Range",
+            format!("bytes={offset}-{}", offset + self.block_size - 1),
+        );
+        ehttp::fetch(request, move |response| {
+            self.on_response(response, offset, attempt);
+        });
+    }
+
+    fn on_response(
+        self: Arc<Self>,
+        response: ehttp::Result<ehttp::Response>,
+        offset: u64,
+        attempt: u32,
+    ) {
+        let url = &self.url;
+
+        if self.state.lock().is_done {
+            return;
+        }
+
+        let response = match response {
+            Ok(response) if response.status < 500 => response,
+
+            // Network and server errors may well be transient.
+            response => {
+                let err = match response {
+                    Ok(response) => format!("{} {}", response.status, response.status_text),
+                    Err(err) => err,
+                };
+                self.retry_or_fail(offset, attempt, &err);
+                return;
+            }
+        };
+
+        match response.status {
+            // The server ignored the range, so this is the whole file.
+            200 if offset == 0 => {
+                let mut state = self.state.lock();
+                state.is_done = true;
+                if self.decode(&mut state, response.bytes).is_continue() {
+                    (self.on_msg)(HttpMessage::Success);
+                }
+            }
+
+            206 => {
+                let total_size = response
+                    .headers
+                    .get("content-range")
+                    .and_then(parse_content_range_size);
+
+                let num_bytes = response.bytes.len() as u64;
+                let expected_num_bytes = total_size.map_or(self.block_size, |total_size| {
+                    self.block_size.min(total_size.saturating_sub(offset))
+                });
+                if num_bytes == 0 || num_bytes > self.block_size {
+                    self.retry_or_fail(offset, attempt, "unexpected range");
+                    return;
+                }
+                if total_size.is_some() && num_bytes != expected_num_bytes {
+                    // Blocks are decoded in order, so a partial one would leave a gap.
+                    self.retry_or_fail(offset, attempt, "partial range");
+                    return;
+                }
+
+                let next_blocks = {
+                    let mut state = self.state.lock();
+                    if state.total_size.is_none() {
+                        state.total_size = total_size;
+                    }
+                    state.pending_blocks.insert(offset, response.bytes);
+
+                    self.decode_pending(&mut state);
+                    if state.is_done {
+                        return;
+                    }
+
+                    // Without the file size, a short block can only be the last one.
+                    if state.total_size.is_none() && num_bytes < self.block_size {
+                        state.is_done = true;
+                        re_log::debug!("Finished decoding .rrd file from {url:?}…");
+                        (self.on_msg)(HttpMessage::Success);
+                        return;
+                    }
+
+                    self.next_blocks(&mut state)
+                };
+
+                for offset in next_blocks {
+                    self.clone().fetch_block(offset, 0);
+                }
+            }
+
+            // The file size is a multiple of the block size, and the server didn't tell us.
+            416 if offset > 0 => {
+                let mut state = self.state.lock();
+                if state.decoded_up_to == offset {
+                    state.is_done = true;
+                    (self.on_msg)(HttpMessage::Success);
+                }
+            }
+
+            status => {
+                self.fail(&format!("{status} {}", response.status_text));
+            }
+        }
+    }
+
+    /// Decodes the blocks that are next in line, and checks whether the whole file is done.
+    fn decode_pending(&self, state: &mut RangeStreamState) {
+        while let Some(bytes) = state.pending_blocks.remove(&state.decoded_up_to) {
+            state.decoded_up_to += bytes.len() as u64;
+            if self.decode(state, bytes).is_break() {
+                state.is_done = true;
+                return;
+            }
+        }
+
+        if state
+            .total_size
+            .is_some_and(|total_size| state.decoded_up_to >= total_size)
+        {
+            state.is_done = true;
+            re_log::debug!("Finished decoding .rrd file from {:?}…", self.url);
+            (self.on_msg)(HttpMessage::Success);
+        }
+    }
+
+    /// The offsets of the blocks to request next, to keep up to [`MAX_BLOCKS_IN_FLIGHT`] blocks
+    /// ahead of the decoder.
+    fn next_blocks(&self, state: &mut RangeStreamState) -> Vec<u64> {
+        let (end, max_in_flight) = match state.total_size {
+            Some(total_size) => (total_size, MAX_BLOCKS_IN_FLIGHT),
+            None => (u64::MAX, 1),
+        };
+        let max_requested = state
+            .decoded_up_to
+            .saturating_add(max_in_flight * self.block_size)
+            .min(end);
+
+        let mut offsets = Vec::new();
+        while state.requested_up_to < max_requested {
+            offsets.push(state.requested_up_to);
+            state.requested_up_to += self.block_size;
+        }
+        offsets
+    }
+
+    fn decode(&self, state: &mut RangeStreamState, bytes: Vec<u8>) -> ControlFlow<()> {
+        state.decoder.push_chunk(bytes);
+        decode_available(&mut state.decoder, &self.url, self.on_msg.as_ref())
+    }
+
+    fn retry_or_fail(self: Arc<Self>, offset: u64, attempt: u32, err: &str) {
+        if attempt < MAX_RANGE_RETRIES {
+            re_log::debug!("Retrying to fetch {} from byte {offset}: {err}", self.url);
+            self.fetch_block(offset, attempt + 1);
+        } else {
+            self.fail(err);
+        }
+    }
+
+    fn fail(&self, err: &str) {
+        let mut state = self.state.lock();
+        if !state.is_done {
+            state.is_done = true;
+            (self.on_msg)(HttpMessage::Failure(
+                format!("Failed to fetch .rrd file from {}: {err}", self.url).into(),
+            ));
+        }
+    }
+}
+
+/// The total size in a `Content-Range` header like `bytes 0-1023/4096`, if known.
+fn parse_content_range_size(content_range: &str) -> Option<u64> {
+    content_range.rsplit_once('/')?.1.trim().parse().ok()
+}
+
+/// Hands all messages that are complete so far over to `on_msg`.
+fn decode_available(
+    decoder: &mut StreamDecoder,
+    url: &str,
+    on_msg: &HttpMessageCallback,
+) -> ControlFlow<()> {
+    re_tracing::profile_function!();
+
+    loop {
+        match decoder.try_read() {
+            Ok(Some(message)) => {
+                // only return if the callback asks us to
+                if on_msg(HttpMessage::LogMsg(message)).is_break() {
+                    return ControlFlow::Break(());
+                }
+            }
+            Ok(None) => return ControlFlow::Continue(()),
+            Err(err) => {
+                // Regardless of what the message handler returns, we are done here.
+                let _ignored_control_flow = on_msg(HttpMessage::Failure(
+                    format!("Failed to fetch .rrd file from {url}: {err}").into(),
+                ));
+                return ControlFlow::Break(());
+            }
+        }
+    }
+}
+
 #[cfg(target_arch = "wasm32")]
 // TODO(#6330): remove unwrap()
 #[allow(clippy::unwrap_used)]
@@ -240,3 +512,37 @@ pub mod web_decode {
 use web_decode::decode_rrd;
 
 use crate::decoder::stream::StreamDecoder;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_content_range_size() {
+        assert_eq!(parse_content_range_size("bytes 0-1023/4096"), Some(4096));
+        assert_eq!(parse_content_range_size("bytes 0-1023/*"), None);
+        assert_eq!(parse_content_range_size("bytes */4096"), Some(4096));
+    }
+
+    #[test]
+    fn test_range_stream_next_blocks() {
+        let stream = RangeStream::new(
+            "http://localhost/recording.rrd".to_owned(),
+            10,
+            Arc::new(|_| ControlFlow::Continue(())),
+        );
+        let mut state = stream.state.lock();
+
+        // Without the file size, blocks are requested one at a time.
+        state.decoded_up_to = 10;
+        assert_eq!(stream.next_blocks(&mut state), [10]);
+        assert!(stream.next_blocks(&mut state).is_empty());
+
+        // Once it is known, several blocks are kept in flight, but none past the end of the file.
+        state.total_size = Some(45);
+        assert_eq!(stream.next_blocks(&mut state), [20, 30, 40]);
+
+        state.decoded_up_to = 20;
+        assert!(stream.next_blocks(&mut state).is_empty());
+    }
+}
@@ -2,7 +2,11 @@ use similar_asserts::assert_eq;
 
 use re_build_info::CrateVersion;
 use re_chunk::{Chunk, RowId, TimePoint, Timeline};
-use re_log_encoding::{EncodingOptions, decoder::decode_bytes, encoder::encode_as_bytes};
+use re_log_encoding::{
+    EncodingOptions,
+    decoder::decode_bytes,
+    encoder::{encode, encode_as_bytes},
+};
 use re_log_types::{LogMsg, StoreId};
 use re_types::archetypes::Points3D;
 
@@ -39,13 +43,62 @@ fn encode_roundtrip() {
     let store_id = StoreId::empty_recording();
     let messages = [LogMsg::ArrowMsg(store_id, arrow_msg)];
 
-    let option = EncodingOptions::PROTOBUF_COMPRESSED;
+    for option in [
+        EncodingOptions::PROTOBUF_COMPRESSED,
+        EncodingOptions::PROTOBUF_UNCOMPRESSED,
+        #[cfg(feature = "zstd")]
+        EncodingOptions::PROTOBUF_ZSTD,
+    ] {
+        let crate_version = CrateVersion::LOCAL;
+        let encoded =
+            encode_as_bytes(crate_version, option, messages.iter().cloned().map(Ok)).unwrap();
+        let decoded = decode_bytes(&encoded).unwrap();
+        similar_asserts::assert_eq!(
+            decoded,
+            messages,
+            "Failed to roundtrip chunk with option {option:?}"
+        );
+    }
+}
+
+#[test]
+fn parallel_encode_matches_sequential() {
+    let timeline = Timeline::new_sequence("my_index");
+    let store_id = StoreId::empty_recording();
+
+    // Enough messages for several batches.
+    let messages = (0..100)
+        .map(|i| {
+            let chunk = Chunk::builder("points")
+                .with_archetype(
+                    RowId::new(),
+                    TimePoint::default().with(timeline, i),
+                    &Points3D::new([[i as f32, 2., 3.]]),
+                )
+                .build()
+                .unwrap();
+            LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg().unwrap())
+        })
+        .collect::<Vec<_>>();
+
     let crate_version = CrateVersion::LOCAL;
-    let encoded = encode_as_bytes(crate_version, option, messages.iter().cloned().map(Ok)).unwrap();
-    let decoded = decode_bytes(&encoded).unwrap();
-    similar_asserts::assert_eq!(
-        decoded,
-        messages,
-        "Failed to roundtrip chunk with option {option:?}"
+    let option = EncodingOptions::PROTOBUF_COMPRESSED;
+
+    let sequential =
+        encode_as_bytes(crate_version, option, messages.iter().cloned().map(Ok)).unwrap();
+
+    let mut parallel = Vec::new();
+    encode(
+        crate_version,
+        option,
+        messages.iter().cloned().map(Ok),
+        &mut parallel,
+    )
+    .unwrap();
+
+    assert!(
+        sequential == parallel,
+        "Parallel encoding changed the output"
     );
+    assert_eq!(decode_bytes(&parallel).unwrap(), messages);
 }
@@ -0,0 +1,54 @@
+//! Grouping of [`ArrowMsg`]s into atomic batches, which the viewer applies all at once.
+//!
+//! The grouping is stored in the schema metadata of the record batches, so that it survives
+//! being written to and read from files and streams.
+
+use crate::{ArrowMsg, LogMsg};
+
+/// The id of the atomic batch the message belongs to.
+pub const KEY_ATOMIC_BATCH_ID: &str = "rerun:atomic_batch_id";
+
+/// The total number of messages in the atomic batch.
+pub const KEY_ATOMIC_BATCH_LEN: &str = "rerun:atomic_batch_len";
+
+/// Identifies a group of [`ArrowMsg`]s that must be applied all at once.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AtomicBatchMetadata {
+    /// Unique id of the batch.
+    pub id: re_tuid::Tuid,
+
+    /// Total number of messages in the batch.
+    pub len: usize,
+}
+
+impl AtomicBatchMetadata {
+    /// A new batch made of `len` messages.
+    #[inline]
+    pub fn new(len: usize) -> Self {
+        Self {
+            id: re_tuid::Tuid::new(),
+            len,
+        }
+    }
+
+    /// Returns the batch the message belongs to, if any.
+    pub fn from_arrow_msg(msg: &ArrowMsg) -> Option<Self> {
+        let metadata = msg.batch.schema_ref().metadata();
+
+        let id = metadata.get(KEY_ATOMIC_BATCH_ID)?.parse().ok()?;
+        let len = metadata.get(KEY_ATOMIC_BATCH_LEN)?.parse().ok()?;
+
+        Some(Self { id, len })
+    }
+
+    /// Marks the message as belonging to this batch.
+    ///
+    /// Does nothing if the message isn't an [`ArrowMsg`].
+    pub fn insert_into(&self, msg: &mut LogMsg) {
+        msg.insert_arrow_record_batch_metadata(KEY_ATOMIC_BATCH_ID.to_owned(), self.id.to_string());
+        msg.insert_arrow_record_batch_metadata(
+            KEY_ATOMIC_BATCH_LEN.to_owned(),
+            self.len.to_string(),
+        );
+    }
+}
@@ -0,0 +1,38 @@
+//! The timeline that the viewer selects for a recording, until the user picks another one.
+//!
+//! Without it, the viewer prefers the first user-defined timeline. Recordings with several
+//! clocks, e.g. the simulated and the wall time of a simulation, can name the one that makes the
+//! most sense to play back instead.
+//!
+//! The timeline is stored as a recording property, under [`DefaultTimeline::entity_path`].
+
+use re_types_core::{ArchetypeName, ComponentDescriptor};
+
+use crate::{EntityPath, EntityPathPart};
+
+/// Names of the recording property holding the default timeline of a recording.
+pub struct DefaultTimeline;
+
+impl DefaultTimeline {
+    /// Archetype name of the component holding the default timeline.
+    pub const ARCHETYPE_NAME: &'static str = "rerun.DefaultTimeline";
+
+    /// Component holding the name of the timeline, as a single `Utf8`.
+    pub const COMPONENT_TIMELINE: &'static str = "timeline";
+
+    /// Name of the property holding the default timeline.
+    pub const PROPERTY_NAME: &'static str = "default_timeline";
+
+    /// The property path under which the default timeline is stored, relative to
+    /// [`EntityPath::properties`].
+    pub fn entity_path() -> EntityPath {
+        EntityPath::new(vec![EntityPathPart::from(Self::PROPERTY_NAME)])
+    }
+
+    /// Descriptor of the [`Self::COMPONENT_TIMELINE`] component.
+    #[inline]
+    pub fn descriptor_timeline() -> ComponentDescriptor {
+        ComponentDescriptor::partial(Self::COMPONENT_TIMELINE)
+            .with_archetype(ArchetypeName::from(Self::ARCHETYPE_NAME))
+    }
+}
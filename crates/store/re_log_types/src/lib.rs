@@ -18,11 +18,14 @@
 //! `foo.transform * foo/bar.transform * foo/bar/baz.transform`.
 
 pub mod arrow_msg;
+pub mod atomic_batch;
+pub mod default_timeline;
 mod entry_id;
 pub mod example_components;
 pub mod hash;
 mod index;
 pub mod path;
+pub mod timeline_mapping;
 
 // mod data_cell;
 // mod data_row;
@@ -39,6 +42,8 @@ use re_byte_size::SizeBytes;
 
 pub use self::{
     arrow_msg::{ArrowMsg, ArrowRecordBatchReleaseCallback},
+    atomic_batch::AtomicBatchMetadata,
+    default_timeline::DefaultTimeline,
     entry_id::{EntryId, EntryIdOrName},
     index::{
         AbsoluteTimeRange, AbsoluteTimeRangeF, Duration, NonMinI64, TimeCell, TimeInt, TimePoint,
@@ -46,6 +51,7 @@ pub use self::{
     },
     instance::Instance,
     path::*,
+    timeline_mapping::TimelineMapping,
     vec_deque_ext::{VecDequeInsertionExt, VecDequeRemovalExt, VecDequeSortingExt},
 };
 
@@ -0,0 +1,172 @@
+//! Mappings between the clock domains of different timelines.
+//!
+//! Robots typically log the same events on several timelines, e.g. the `ros_time` of the
+//! middleware and the `wall_clock` of the host. A [`TimelineMapping`] tells the viewer how to
+//! go from one to the other, so that it can keep the time cursor in place when switching
+//! timelines.
+//!
+//! Mappings are stored as recording properties, under [`TimelineMapping::entity_path`].
+
+use re_types_core::{ArchetypeName, ComponentDescriptor};
+
+use crate::{EntityPath, EntityPathPart, TimeInt, TimelineName};
+
+/// Linear mapping from the times of a `source` timeline to those of a `target` timeline:
+/// `target = source + offset + drift * source`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimelineMapping {
+    pub source: TimelineName,
+    pub target: TimelineName,
+
+    /// Added to the source time, in the units of the target timeline.
+    pub offset: i64,
+
+    /// Rate at which the target clock drifts away from the source clock, e.g. `1e-6` if the
+    /// target gains a microsecond per second.
+    pub drift: f64,
+}
+
+impl TimelineMapping {
+    /// Archetype name of the components holding a mapping.
+    pub const ARCHETYPE_NAME: &'static str = "rerun.TimelineMapping";
+
+    /// Component holding [`Self::offset`], as a single `Int64`.
+    pub const COMPONENT_OFFSET: &'static str = "offset";
+
+    /// Component holding [`Self::drift`], as a single `Float64`.
+    pub const COMPONENT_DRIFT: &'static str = "drift";
+
+    /// Name of the property subtree holding all mappings.
+    pub const PROPERTY_NAME: &'static str = "timeline_mappings";
+
+    /// The identity mapping, to be adjusted with [`Self::with_offset`] and [`Self::with_drift`].
+    #[inline]
+    pub fn new(source: impl Into<TimelineName>, target: impl Into<TimelineName>) -> Self {
+        Self {
+            source: source.into(),
+            target: target.into(),
+            offset: 0,
+            drift: 0.0,
+        }
+    }
+
+    #[inline]
+    pub fn with_offset(mut self, offset: i64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    #[inline]
+    pub fn with_drift(mut self, drift: f64) -> Self {
+        self.drift = drift;
+        self
+    }
+
+    /// The mapping from `target` back to `source`.
+    pub fn inverse(&self) -> Self {
+        // source = (target - offset) / (1 + drift)
+        //        = target - offset / (1 + drift) - target * drift / (1 + drift)
+        let drift = -self.drift / (1.0 + self.drift);
+        Self {
+            source: self.target,
+            target: self.source,
+            offset: (-(self.offset as f64) * (1.0 + drift)).round() as i64,
+            drift,
+        }
+    }
+
+    /// Maps a time of the `source` timeline onto the `target` timeline.
+    ///
+    /// Static and out-of-range times saturate.
+    pub fn map(&self, time: TimeInt) -> TimeInt {
+        if time.is_static() {
+            return time;
+        }
+
+        let time = time.as_i64();
+        // The drift is applied separately, so that the bulk of the value keeps its full integer
+        // precision: nanoseconds since the epoch don't fit in the mantissa of an `f64`.
+        let drift = (time as f64 * self.drift).round() as i64;
+        TimeInt::saturated_temporal_i64(time.saturating_add(self.offset).saturating_add(drift))
+    }
+
+    /// The property path under which the mapping is stored, relative to
+    /// [`EntityPath::properties`].
+    pub fn entity_path(&self) -> EntityPath {
+        EntityPath::new(vec![
+            EntityPathPart::from(Self::PROPERTY_NAME),
+            EntityPathPart::from(self.source.as_str()),
+            EntityPathPart::from(self.target.as_str()),
+        ])
+    }
+
+    /// The source and target timelines of the mapping stored at the given (full) entity path.
+    pub fn timelines_from_entity_path(
+        entity_path: &EntityPath,
+    ) -> Option<(TimelineName, TimelineName)> {
+        match entity_path.as_slice() {
+            [properties, name, source, target]
+                if properties == &EntityPathPart::properties()
+                    && name.unescaped_str() == Self::PROPERTY_NAME =>
+            {
+                Some((
+                    TimelineName::new(source.unescaped_str()),
+                    TimelineName::new(target.unescaped_str()),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Descriptor of the [`Self::COMPONENT_OFFSET`] component.
+    #[inline]
+    pub fn descriptor_offset() -> ComponentDescriptor {
+        ComponentDescriptor::partial(Self::COMPONENT_OFFSET)
+            .with_archetype(ArchetypeName::from(Self::ARCHETYPE_NAME))
+    }
+
+    /// Descriptor of the [`Self::COMPONENT_DRIFT`] component.
+    #[inline]
+    pub fn descriptor_drift() -> ComponentDescriptor {
+        ComponentDescriptor::partial(Self::COMPONENT_DRIFT)
+            .with_archetype(ArchetypeName::from(Self::ARCHETYPE_NAME))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_and_inverse() {
+        let mapping = TimelineMapping::new("ros_time", "wall_clock")
+            .with_offset(1_000)
+            .with_drift(1e-3);
+
+        let source = TimeInt::new_temporal(1_000_000);
+        let target = mapping.map(source);
+        assert_eq!(TimeInt::new_temporal(1_000_000 + 1_000 + 1_000), target);
+
+        let inverse = mapping.inverse();
+        assert_eq!(mapping.target, inverse.source);
+        assert_eq!(mapping.source, inverse.target);
+        assert!((inverse.map(target).as_i64() - source.as_i64()).abs() <= 1);
+
+        assert_eq!(TimeInt::STATIC, mapping.map(TimeInt::STATIC));
+    }
+
+    #[test]
+    fn entity_path_roundtrip() {
+        let mapping = TimelineMapping::new("ros time", "wall_clock");
+        let entity_path = EntityPath::properties().join(&mapping.entity_path());
+
+        assert_eq!(
+            Some((mapping.source, mapping.target)),
+            TimelineMapping::timelines_from_entity_path(&entity_path)
+        );
+        assert_eq!(
+            None,
+            TimelineMapping::timelines_from_entity_path(&mapping.entity_path())
+        );
+    }
+}
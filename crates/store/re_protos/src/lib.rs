@@ -359,6 +359,8 @@ mod sizes {
                 uncompressed_size,
                 encoding,
                 payload,
+                payload_crc32,
+                encryption,
             } = self;
 
             store_id.heap_size_bytes()
@@ -367,6 +369,10 @@ mod sizes {
                 + uncompressed_size.heap_size_bytes()
                 + encoding.heap_size_bytes()
                 + payload.len() as u64
+                + payload_crc32.heap_size_bytes()
+                + encryption
+                    .as_ref()
+                    .map_or(0, |encryption| encryption.nonce.len() as u64)
         }
     }
 
@@ -76,6 +76,135 @@ impl TryFrom<crate::frontend::v1alpha1::GetDatasetSchemaRequest> for re_log_type
     }
 }
 
+// --- UpdatePartitionTagsRequest ---
+
+pub struct UpdatePartitionTagsRequest {
+    pub dataset_id: EntryId,
+    pub partition_id: crate::common::v1alpha1::ext::PartitionId,
+
+    /// Tag name and optional value.
+    pub add_tags: Vec<(String, Option<String>)>,
+    pub remove_tags: Vec<String>,
+}
+
+impl TryFrom<crate::frontend::v1alpha1::UpdatePartitionTagsRequest> for UpdatePartitionTagsRequest {
+    type Error = TypeConversionError;
+
+    fn try_from(
+        value: crate::frontend::v1alpha1::UpdatePartitionTagsRequest,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            dataset_id: value
+                .dataset_id
+                .ok_or(missing_field!(
+                    crate::frontend::v1alpha1::UpdatePartitionTagsRequest,
+                    "dataset_id"
+                ))?
+                .try_into()?,
+            partition_id: value
+                .partition_id
+                .ok_or(missing_field!(
+                    crate::frontend::v1alpha1::UpdatePartitionTagsRequest,
+                    "partition_id"
+                ))?
+                .try_into()?,
+            add_tags: value
+                .add_tags
+                .into_iter()
+                .map(|tag| (tag.name, tag.value))
+                .collect(),
+            remove_tags: value.remove_tags,
+        })
+    }
+}
+
+impl From<UpdatePartitionTagsRequest> for crate::frontend::v1alpha1::UpdatePartitionTagsRequest {
+    fn from(value: UpdatePartitionTagsRequest) -> Self {
+        Self {
+            dataset_id: Some(value.dataset_id.into()),
+            partition_id: Some(value.partition_id.into()),
+            add_tags: value
+                .add_tags
+                .into_iter()
+                .map(|(name, value)| crate::frontend::v1alpha1::PartitionTag { name, value })
+                .collect(),
+            remove_tags: value.remove_tags,
+        }
+    }
+}
+
+// --- FindPartitionsRequest ---
+
+pub struct FindPartitionsRequest {
+    pub dataset_id: EntryId,
+
+    /// Tag name and optional value, `None` matching any value.
+    pub tags: Vec<(String, Option<String>)>,
+
+    pub time_range: Option<(re_log_types::TimelineName, re_log_types::AbsoluteTimeRange)>,
+}
+
+impl TryFrom<crate::frontend::v1alpha1::FindPartitionsRequest> for FindPartitionsRequest {
+    type Error = TypeConversionError;
+
+    fn try_from(
+        value: crate::frontend::v1alpha1::FindPartitionsRequest,
+    ) -> Result<Self, Self::Error> {
+        let time_range = match (value.timeline, value.time_range) {
+            (Some(timeline), Some(time_range)) => Some((timeline.into(), time_range.into())),
+            (None, None) => None,
+            (None, Some(_)) => {
+                return Err(missing_field!(
+                    crate::frontend::v1alpha1::FindPartitionsRequest,
+                    "timeline"
+                ));
+            }
+            (Some(_), None) => {
+                return Err(missing_field!(
+                    crate::frontend::v1alpha1::FindPartitionsRequest,
+                    "time_range"
+                ));
+            }
+        };
+
+        Ok(Self {
+            dataset_id: value
+                .dataset_id
+                .ok_or(missing_field!(
+                    crate::frontend::v1alpha1::FindPartitionsRequest,
+                    "dataset_id"
+                ))?
+                .try_into()?,
+            tags: value
+                .tags
+                .into_iter()
+                .map(|tag| (tag.name, tag.value))
+                .collect(),
+            time_range,
+        })
+    }
+}
+
+impl From<FindPartitionsRequest> for crate::frontend::v1alpha1::FindPartitionsRequest {
+    fn from(value: FindPartitionsRequest) -> Self {
+        let (timeline, time_range) = value
+            .time_range
+            .map(|(timeline, time_range)| (timeline.into(), time_range.into()))
+            .unzip();
+
+        Self {
+            dataset_id: Some(value.dataset_id.into()),
+            tags: value
+                .tags
+                .into_iter()
+                .map(|(name, value)| crate::frontend::v1alpha1::PartitionTag { name, value })
+                .collect(),
+            timeline,
+            time_range,
+        }
+    }
+}
+
 // --- RegisterWithDatasetRequest ---
 
 #[derive(Debug)]
@@ -97,6 +97,106 @@ impl ::prost::Name for GetDatasetSchemaRequest {
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartitionTag {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Set for properties, e.g. the ID of the robot for a `robot_id` tag.
+    #[prost(string, optional, tag = "2")]
+    pub value: ::core::option::Option<::prost::alloc::string::String>,
+}
+impl ::prost::Name for PartitionTag {
+    const NAME: &'static str = "PartitionTag";
+    const PACKAGE: &'static str = "rerun.frontend.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.frontend.v1alpha1.PartitionTag".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.frontend.v1alpha1.PartitionTag".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdatePartitionTagsRequest {
+    #[prost(message, optional, tag = "1")]
+    pub dataset_id: ::core::option::Option<super::super::common::v1alpha1::EntryId>,
+    #[prost(message, optional, tag = "2")]
+    pub partition_id: ::core::option::Option<super::super::common::v1alpha1::PartitionId>,
+    /// Tags to add. A tag the partition already has gets its value replaced.
+    #[prost(message, repeated, tag = "3")]
+    pub add_tags: ::prost::alloc::vec::Vec<PartitionTag>,
+    /// Names of the tags to remove. Removing a tag the partition doesn't have is a no-op.
+    #[prost(string, repeated, tag = "4")]
+    pub remove_tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+impl ::prost::Name for UpdatePartitionTagsRequest {
+    const NAME: &'static str = "UpdatePartitionTagsRequest";
+    const PACKAGE: &'static str = "rerun.frontend.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.frontend.v1alpha1.UpdatePartitionTagsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.frontend.v1alpha1.UpdatePartitionTagsRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdatePartitionTagsResponse {
+    /// All the tags of the partition, once updated, sorted by name.
+    #[prost(message, repeated, tag = "1")]
+    pub tags: ::prost::alloc::vec::Vec<PartitionTag>,
+}
+impl ::prost::Name for UpdatePartitionTagsResponse {
+    const NAME: &'static str = "UpdatePartitionTagsResponse";
+    const PACKAGE: &'static str = "rerun.frontend.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.frontend.v1alpha1.UpdatePartitionTagsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.frontend.v1alpha1.UpdatePartitionTagsResponse".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FindPartitionsRequest {
+    #[prost(message, optional, tag = "1")]
+    pub dataset_id: ::core::option::Option<super::super::common::v1alpha1::EntryId>,
+    /// Only the partitions that have all of these tags.
+    ///
+    /// A tag without a value matches any value.
+    #[prost(message, repeated, tag = "2")]
+    pub tags: ::prost::alloc::vec::Vec<PartitionTag>,
+    /// Only the partitions with data on this timeline within `time_range`.
+    ///
+    /// E.g. the partitions recorded within a date range, with the `log_time` timeline.
+    #[prost(message, optional, tag = "3")]
+    pub timeline: ::core::option::Option<super::super::common::v1alpha1::Timeline>,
+    /// Must be set if, and only if, `timeline` is.
+    #[prost(message, optional, tag = "4")]
+    pub time_range: ::core::option::Option<super::super::common::v1alpha1::TimeRange>,
+}
+impl ::prost::Name for FindPartitionsRequest {
+    const NAME: &'static str = "FindPartitionsRequest";
+    const PACKAGE: &'static str = "rerun.frontend.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.frontend.v1alpha1.FindPartitionsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.frontend.v1alpha1.FindPartitionsRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FindPartitionsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub partition_ids: ::prost::alloc::vec::Vec<super::super::common::v1alpha1::PartitionId>,
+}
+impl ::prost::Name for FindPartitionsResponse {
+    const NAME: &'static str = "FindPartitionsResponse";
+    const PACKAGE: &'static str = "rerun.frontend.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.frontend.v1alpha1.FindPartitionsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.frontend.v1alpha1.FindPartitionsResponse".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateIndexRequest {
     #[prost(message, optional, tag = "1")]
     pub dataset_id: ::core::option::Option<super::super::common::v1alpha1::EntryId>,
@@ -777,6 +877,50 @@ pub mod frontend_service_client {
             ));
             self.inner.unary(req, path, codec).await
         }
+        /// Adds tags to, and removes tags from, a partition.
+        ///
+        /// Tags are user-defined: either plain labels (e.g. `reviewed`), or properties with a value
+        /// (e.g. `robot_id` = `r2d2`, `mission` = `survey-42`). They show up in the partition table.
+        pub async fn update_partition_tags(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdatePartitionTagsRequest>,
+        ) -> std::result::Result<tonic::Response<super::UpdatePartitionTagsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rerun.frontend.v1alpha1.FrontendService/UpdatePartitionTags",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "rerun.frontend.v1alpha1.FrontendService",
+                "UpdatePartitionTags",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Returns the partitions of a dataset matching all the given criteria, e.g. a robot, a mission,
+        /// and a date range.
+        pub async fn find_partitions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindPartitionsRequest>,
+        ) -> std::result::Result<tonic::Response<super::FindPartitionsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rerun.frontend.v1alpha1.FrontendService/FindPartitions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "rerun.frontend.v1alpha1.FrontendService",
+                "FindPartitions",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
         /// Creates a custom index for a specific column (vector search, full-text search, etc).
         pub async fn create_index(
             &mut self,
@@ -1221,6 +1365,20 @@ pub mod frontend_service_server {
             >,
             tonic::Status,
         >;
+        /// Adds tags to, and removes tags from, a partition.
+        ///
+        /// Tags are user-defined: either plain labels (e.g. `reviewed`), or properties with a value
+        /// (e.g. `robot_id` = `r2d2`, `mission` = `survey-42`). They show up in the partition table.
+        async fn update_partition_tags(
+            &self,
+            request: tonic::Request<super::UpdatePartitionTagsRequest>,
+        ) -> std::result::Result<tonic::Response<super::UpdatePartitionTagsResponse>, tonic::Status>;
+        /// Returns the partitions of a dataset matching all the given criteria, e.g. a robot, a mission,
+        /// and a date range.
+        async fn find_partitions(
+            &self,
+            request: tonic::Request<super::FindPartitionsRequest>,
+        ) -> std::result::Result<tonic::Response<super::FindPartitionsResponse>, tonic::Status>;
         /// Creates a custom index for a specific column (vector search, full-text search, etc).
         async fn create_index(
             &self,
@@ -2009,6 +2167,90 @@ pub mod frontend_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/rerun.frontend.v1alpha1.FrontendService/UpdatePartitionTags" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdatePartitionTagsSvc<T: FrontendService>(pub Arc<T>);
+                    impl<T: FrontendService>
+                        tonic::server::UnaryService<super::UpdatePartitionTagsRequest>
+                        for UpdatePartitionTagsSvc<T>
+                    {
+                        type Response = super::UpdatePartitionTagsResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdatePartitionTagsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as FrontendService>::update_partition_tags(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UpdatePartitionTagsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rerun.frontend.v1alpha1.FrontendService/FindPartitions" => {
+                    #[allow(non_camel_case_types)]
+                    struct FindPartitionsSvc<T: FrontendService>(pub Arc<T>);
+                    impl<T: FrontendService>
+                        tonic::server::UnaryService<super::FindPartitionsRequest>
+                        for FindPartitionsSvc<T>
+                    {
+                        type Response = super::FindPartitionsResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindPartitionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as FrontendService>::find_partitions(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FindPartitionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/rerun.frontend.v1alpha1.FrontendService/CreateIndex" => {
                     #[allow(non_camel_case_types)]
                     struct CreateIndexSvc<T: FrontendService>(pub Arc<T>);
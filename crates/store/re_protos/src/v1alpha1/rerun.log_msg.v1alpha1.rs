@@ -70,6 +70,17 @@ pub struct ArrowMsg {
     /// Arrow-IPC encoded schema and chunk, compressed according to the `compression` field.
     #[prost(bytes = "bytes", tag = "5")]
     pub payload: ::prost::bytes::Bytes,
+    /// CRC-32 (IEEE) checksum of `payload`, as it was encoded.
+    ///
+    /// Used to detect corrupted data. Missing from data encoded by older versions of Rerun.
+    #[prost(fixed32, optional, tag = "7")]
+    pub payload_crc32: ::core::option::Option<u32>,
+    /// Set if the payload is encrypted.
+    ///
+    /// Encryption is applied last, i.e. to the compressed payload. `payload_crc32` covers the
+    /// encrypted payload.
+    #[prost(message, optional, tag = "8")]
+    pub encryption: ::core::option::Option<PayloadEncryption>,
 }
 impl ::prost::Name for ArrowMsg {
     const NAME: &'static str = "ArrowMsg";
@@ -81,6 +92,26 @@ impl ::prost::Name for ArrowMsg {
         "/rerun.log_msg.v1alpha1.ArrowMsg".into()
     }
 }
+/// How a payload is encrypted.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadEncryption {
+    /// The authenticated encryption algorithm used.
+    #[prost(enumeration = "EncryptionAlgorithm", tag = "1")]
+    pub algorithm: i32,
+    /// The nonce the payload was encrypted with, unique for every payload.
+    #[prost(bytes = "bytes", tag = "2")]
+    pub nonce: ::prost::bytes::Bytes,
+}
+impl ::prost::Name for PayloadEncryption {
+    const NAME: &'static str = "PayloadEncryption";
+    const PACKAGE: &'static str = "rerun.log_msg.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.log_msg.v1alpha1.PayloadEncryption".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.log_msg.v1alpha1.PayloadEncryption".into()
+    }
+}
 /// Corresponds to `LogMsg::BlueprintActivationCommand`.
 ///
 /// Used for activating a blueprint once it has been fully transmitted,
@@ -261,6 +292,8 @@ pub enum Compression {
     None = 1,
     /// LZ4 block compression.
     Lz4 = 2,
+    /// Zstandard compression.
+    Zstd = 3,
 }
 impl Compression {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -272,6 +305,7 @@ impl Compression {
             Self::Unspecified => "COMPRESSION_UNSPECIFIED",
             Self::None => "COMPRESSION_NONE",
             Self::Lz4 => "COMPRESSION_LZ4",
+            Self::Zstd => "COMPRESSION_ZSTD",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -280,6 +314,7 @@ impl Compression {
             "COMPRESSION_UNSPECIFIED" => Some(Self::Unspecified),
             "COMPRESSION_NONE" => Some(Self::None),
             "COMPRESSION_LZ4" => Some(Self::Lz4),
+            "COMPRESSION_ZSTD" => Some(Self::Zstd),
             _ => None,
         }
     }
@@ -313,6 +348,37 @@ impl Encoding {
         }
     }
 }
+/// The authenticated encryption algorithm used on a payload.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EncryptionAlgorithm {
+    /// We don't know what algorithm the payload is encrypted with.
+    Unspecified = 0,
+    /// AES-256 in Galois/Counter Mode, with a 96-bit nonce.
+    ///
+    /// The 128-bit authentication tag is appended to the encrypted payload.
+    Aes256Gcm = 1,
+}
+impl EncryptionAlgorithm {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "ENCRYPTION_ALGORITHM_UNSPECIFIED",
+            Self::Aes256Gcm => "ENCRYPTION_ALGORITHM_AES_256_GCM",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ENCRYPTION_ALGORITHM_UNSPECIFIED" => Some(Self::Unspecified),
+            "ENCRYPTION_ALGORITHM_AES_256_GCM" => Some(Self::Aes256Gcm),
+            _ => None,
+        }
+    }
+}
 /// What kind of source a recording comes from.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -1,7 +1,10 @@
 use std::sync::Arc;
 
 use arrow::{
-    array::{Array, ArrayRef, RecordBatch, StringArray, TimestampNanosecondArray},
+    array::{
+        Array, ArrayRef, ListBuilder, RecordBatch, StringArray, StringBuilder,
+        TimestampNanosecondArray,
+    },
     datatypes::{DataType, Field, Schema, TimeUnit},
     error::ArrowError,
 };
@@ -371,6 +374,9 @@ impl ScanPartitionTableResponse {
     pub const PARTITION_MANIFEST_UPDATED_AT: &str = "rerun_partition_manifest_updated_at";
     pub const PARTITION_MANIFEST_URL: &str = "rerun_partition_manifest_url";
 
+    /// The user-defined tags of the partition, as `name` or `name=value`.
+    pub const PARTITION_TAGS: &str = "rerun_partition_tags";
+
     pub fn schema() -> Schema {
         Schema::new(vec![
             Field::new(Self::PARTITION_ID, DataType::Utf8, false),
@@ -387,6 +393,11 @@ impl ScanPartitionTableResponse {
                 true,
             ),
             Field::new(Self::PARTITION_MANIFEST_URL, DataType::Utf8, true),
+            Field::new(
+                Self::PARTITION_TAGS,
+                DataType::List(Self::partition_tag_field()),
+                false,
+            ),
         ])
     }
 
@@ -398,7 +409,17 @@ impl ScanPartitionTableResponse {
         registration_times: Vec<i64>,
         partition_manifest_updated_ats: Vec<Option<i64>>,
         partition_manifest_urls: Vec<Option<String>>,
+        partition_tags: Vec<Vec<String>>,
     ) -> arrow::error::Result<RecordBatch> {
+        let mut partition_tags_builder =
+            ListBuilder::new(StringBuilder::new()).with_field(Self::partition_tag_field());
+        for tags in partition_tags {
+            for tag in tags {
+                partition_tags_builder.values().append_value(tag);
+            }
+            partition_tags_builder.append(true);
+        }
+
         let schema = Arc::new(Self::schema());
         let columns: Vec<ArrayRef> = vec![
             Arc::new(StringArray::from(partition_ids)),
@@ -409,11 +430,16 @@ impl ScanPartitionTableResponse {
                 partition_manifest_updated_ats,
             )),
             Arc::new(StringArray::from(partition_manifest_urls)),
+            Arc::new(partition_tags_builder.finish()),
         ];
 
         RecordBatch::try_new(schema, columns)
     }
 
+    fn partition_tag_field() -> Arc<Field> {
+        Arc::new(Field::new_list_field(DataType::Utf8, false))
+    }
+
     pub fn data(&self) -> Result<&DataframePart, TypeConversionError> {
         Ok(self.data.as_ref().ok_or_else(|| {
             missing_field!(
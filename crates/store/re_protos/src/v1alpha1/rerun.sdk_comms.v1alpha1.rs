@@ -82,6 +82,189 @@ impl ::prost::Name for WriteTableResponse {
         "/rerun.sdk_comms.v1alpha1.WriteTableResponse".into()
     }
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WriteMcapRequest {
+    /// The contents of the MCAP file.
+    ///
+    /// Exactly one of `contents` and `path` must be set.
+    #[prost(bytes = "bytes", tag = "1")]
+    pub contents: ::prost::bytes::Bytes,
+    /// The path of the MCAP file, relative to the directory the server loads files from.
+    ///
+    /// Servers don't load files by path unless they were configured to.
+    #[prost(string, tag = "2")]
+    pub path: ::prost::alloc::string::String,
+    /// The application id of the new recording.
+    ///
+    /// Defaults to the file name.
+    #[prost(string, optional, tag = "3")]
+    pub application_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+impl ::prost::Name for WriteMcapRequest {
+    const NAME: &'static str = "WriteMcapRequest";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.WriteMcapRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.WriteMcapRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WriteMcapResponse {
+    /// The id of the new recording.
+    #[prost(message, optional, tag = "1")]
+    pub store_id: ::core::option::Option<super::super::common::v1alpha1::StoreId>,
+}
+impl ::prost::Name for WriteMcapResponse {
+    const NAME: &'static str = "WriteMcapResponse";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.WriteMcapResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.WriteMcapResponse".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ReadEventsRequest {}
+impl ::prost::Name for ReadEventsRequest {
+    const NAME: &'static str = "ReadEventsRequest";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.ReadEventsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.ReadEventsRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReadEventsResponse {
+    #[prost(oneof = "read_events_response::Event", tags = "1, 2, 3, 4, 5")]
+    pub event: ::core::option::Option<read_events_response::Event>,
+}
+/// Nested message and enum types in `ReadEventsResponse`.
+pub mod read_events_response {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag = "1")]
+        RecordingAdded(super::RecordingAdded),
+        #[prost(message, tag = "2")]
+        ChunkIngested(super::ChunkIngested),
+        #[prost(message, tag = "3")]
+        RecordingEvicted(super::RecordingEvicted),
+        #[prost(message, tag = "4")]
+        LoadProgress(super::LoadProgress),
+        #[prost(message, tag = "5")]
+        Error(super::ServerError),
+    }
+}
+impl ::prost::Name for ReadEventsResponse {
+    const NAME: &'static str = "ReadEventsResponse";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.ReadEventsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.ReadEventsResponse".into()
+    }
+}
+/// The server received the first message of a recording.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RecordingAdded {
+    #[prost(message, optional, tag = "1")]
+    pub store_id: ::core::option::Option<super::super::common::v1alpha1::StoreId>,
+}
+impl ::prost::Name for RecordingAdded {
+    const NAME: &'static str = "RecordingAdded";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.RecordingAdded".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.RecordingAdded".into()
+    }
+}
+/// The server received a chunk of a recording.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChunkIngested {
+    #[prost(message, optional, tag = "1")]
+    pub store_id: ::core::option::Option<super::super::common::v1alpha1::StoreId>,
+    /// The ID of the chunk, if the message was a chunk.
+    #[prost(message, optional, tag = "2")]
+    pub chunk_id: ::core::option::Option<super::super::common::v1alpha1::Tuid>,
+    /// The size of the chunk, uncompressed.
+    #[prost(uint64, tag = "3")]
+    pub num_bytes: u64,
+}
+impl ::prost::Name for ChunkIngested {
+    const NAME: &'static str = "ChunkIngested";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.ChunkIngested".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.ChunkIngested".into()
+    }
+}
+/// The server dropped a recording from its history, according to its retention policy.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RecordingEvicted {
+    #[prost(message, optional, tag = "1")]
+    pub store_id: ::core::option::Option<super::super::common::v1alpha1::StoreId>,
+}
+impl ::prost::Name for RecordingEvicted {
+    const NAME: &'static str = "RecordingEvicted";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.RecordingEvicted".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.RecordingEvicted".into()
+    }
+}
+/// Progress of a `WriteMcap` call.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LoadProgress {
+    /// The recording the file is loaded into.
+    #[prost(message, optional, tag = "1")]
+    pub store_id: ::core::option::Option<super::super::common::v1alpha1::StoreId>,
+    /// Number of messages loaded so far.
+    #[prost(uint64, tag = "2")]
+    pub num_messages: u64,
+    /// Whether the whole file was loaded.
+    #[prost(bool, tag = "3")]
+    pub done: bool,
+}
+impl ::prost::Name for LoadProgress {
+    const NAME: &'static str = "LoadProgress";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.LoadProgress".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.LoadProgress".into()
+    }
+}
+/// Something went wrong on the server, e.g. a client stream broke or a file failed to load.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ServerError {
+    #[prost(string, tag = "1")]
+    pub message: ::prost::alloc::string::String,
+    /// The recording the error is about, if any.
+    #[prost(message, optional, tag = "2")]
+    pub store_id: ::core::option::Option<super::super::common::v1alpha1::StoreId>,
+}
+impl ::prost::Name for ServerError {
+    const NAME: &'static str = "ServerError";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.ServerError".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.ServerError".into()
+    }
+}
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct ReadTablesRequest {}
 impl ::prost::Name for ReadTablesRequest {
@@ -281,6 +464,54 @@ pub mod message_proxy_service_client {
             ));
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Converts an MCAP file into a new recording on the server, which is then buffered like
+        /// any other written message.
+        ///
+        /// This lets clients that don't have the data loaders compiled in send MCAP files.
+        pub async fn write_mcap(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WriteMcapRequest>,
+        ) -> std::result::Result<tonic::Response<super::WriteMcapResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rerun.sdk_comms.v1alpha1.MessageProxyService/WriteMcap",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "rerun.sdk_comms.v1alpha1.MessageProxyService",
+                "WriteMcap",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Subscribes to what happens on the server: recordings coming and going, data being ingested,
+        /// files being loaded, and errors.
+        ///
+        /// This lets dashboards and orchestration tools follow the server without polling it.
+        /// Unlike `ReadMessages`, there is no history: only the events that happen after the call are sent.
+        pub async fn read_events(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReadEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ReadEventsResponse>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rerun.sdk_comms.v1alpha1.MessageProxyService/ReadEvents",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "rerun.sdk_comms.v1alpha1.MessageProxyService",
+                "ReadEvents",
+            ));
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -324,6 +555,28 @@ pub mod message_proxy_service_server {
             &self,
             request: tonic::Request<super::ReadTablesRequest>,
         ) -> std::result::Result<tonic::Response<Self::ReadTablesStream>, tonic::Status>;
+        /// Converts an MCAP file into a new recording on the server, which is then buffered like
+        /// any other written message.
+        ///
+        /// This lets clients that don't have the data loaders compiled in send MCAP files.
+        async fn write_mcap(
+            &self,
+            request: tonic::Request<super::WriteMcapRequest>,
+        ) -> std::result::Result<tonic::Response<super::WriteMcapResponse>, tonic::Status>;
+        /// Server streaming response type for the ReadEvents method.
+        type ReadEventsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ReadEventsResponse, tonic::Status>,
+            > + std::marker::Send
+            + 'static;
+        /// Subscribes to what happens on the server: recordings coming and going, data being ingested,
+        /// files being loaded, and errors.
+        ///
+        /// This lets dashboards and orchestration tools follow the server without polling it.
+        /// Unlike `ReadMessages`, there is no history: only the events that happen after the call are sent.
+        async fn read_events(
+            &self,
+            request: tonic::Request<super::ReadEventsRequest>,
+        ) -> std::result::Result<tonic::Response<Self::ReadEventsStream>, tonic::Status>;
     }
     /// Simple buffer for messages between SDKs and viewers.
     ///
@@ -578,6 +831,91 @@ pub mod message_proxy_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/rerun.sdk_comms.v1alpha1.MessageProxyService/WriteMcap" => {
+                    #[allow(non_camel_case_types)]
+                    struct WriteMcapSvc<T: MessageProxyService>(pub Arc<T>);
+                    impl<T: MessageProxyService>
+                        tonic::server::UnaryService<super::WriteMcapRequest> for WriteMcapSvc<T>
+                    {
+                        type Response = super::WriteMcapResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WriteMcapRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MessageProxyService>::write_mcap(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = WriteMcapSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rerun.sdk_comms.v1alpha1.MessageProxyService/ReadEvents" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReadEventsSvc<T: MessageProxyService>(pub Arc<T>);
+                    impl<T: MessageProxyService>
+                        tonic::server::ServerStreamingService<super::ReadEventsRequest>
+                        for ReadEventsSvc<T>
+                    {
+                        type Response = super::ReadEventsResponse;
+                        type ResponseStream = T::ReadEventsStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReadEventsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MessageProxyService>::read_events(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReadEventsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => Box::pin(async move {
                     let mut response = http::Response::new(tonic::body::Body::default());
                     let headers = response.headers_mut();
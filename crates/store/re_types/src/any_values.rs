@@ -5,7 +5,10 @@ use nohash_hasher::IntMap;
 use crate::{
     ArchetypeName, Component, ComponentDescriptor, ComponentIdentifier, SerializedComponentBatch,
 };
-use re_types_core::{try_serialize_field, AsComponents, ComponentType, Loggable};
+use re_types_core::{
+    try_serialize_field, AsComponents, ComponentType, Loggable, SerializationResult,
+    SerializedComponentColumn,
+};
 
 /// A helper for logging arbitrary data to Rerun.
 #[derive(Default)]
@@ -79,6 +82,46 @@ impl AnyValues {
         .and_then(|serialized| self.batches.insert(component, serialized));
         self
     }
+
+    /// Partitions the data into multiple sub-batches.
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send arbitrary data alongside
+    /// the columns of an archetype, e.g. per-frame metadata next to the samples of a
+    /// [`crate::archetypes::VideoStream`].
+    ///
+    /// The specified `lengths` must sum to the total length of each field.
+    #[inline]
+    pub fn columns<I>(
+        self,
+        lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = self
+            .batches
+            .into_values()
+            .map(|batch| batch.partitioned(lengths.clone()))
+            .collect::<SerializationResult<Vec<_>>>()?;
+        Ok(columns.into_iter())
+    }
+
+    /// Helper to partition the data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is the length of the longest field.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = SerializedComponentColumn>> {
+        let len = self
+            .batches
+            .values()
+            .map(|batch| batch.array.len())
+            .max()
+            .unwrap_or(0);
+        self.columns(std::iter::repeat_n(1, len))
+    }
 }
 
 impl AsComponents for AnyValues {
@@ -125,6 +168,24 @@ mod test {
         );
     }
 
+    #[test]
+    fn columns() {
+        use arrow::array::Array as _;
+
+        let columns = AnyValues::new("MyExample")
+            .with_component::<components::Scalar>("exposure", [1.0f64, 2.0, 3.0])
+            .with_component::<components::Text>("frame_id", ["left", "left", "left"])
+            .columns_of_unit_batches()
+            .unwrap()
+            .collect::<Vec<_>>();
+
+        assert_eq!(columns.len(), 2);
+        for column in columns {
+            assert_eq!(column.descriptor.archetype, Some("MyExample".into()));
+            assert_eq!(column.list_array.len(), 3);
+        }
+    }
+
     #[test]
     fn with_archetype() {
         let values = AnyValues::new("MyExample")
@@ -0,0 +1,239 @@
+use crate::datatypes::{ClassDescription, Rgba32};
+
+use super::AnnotationContext;
+
+/// The classes of the [Cityscapes](https://www.cityscapes-dataset.com/) dataset, with their official colors.
+///
+/// `license plate` is left out since its id is `-1`.
+const CITYSCAPES_CLASSES: [(&str, [u8; 3]); 34] = [
+    ("unlabeled", [0, 0, 0]),
+    ("ego vehicle", [0, 0, 0]),
+    ("rectification border", [0, 0, 0]),
+    ("out of roi", [0, 0, 0]),
+    ("static", [0, 0, 0]),
+    ("dynamic", [111, 74, 0]),
+    ("ground", [81, 0, 81]),
+    ("road", [128, 64, 128]),
+    ("sidewalk", [244, 35, 232]),
+    ("parking", [250, 170, 160]),
+    ("rail track", [230, 150, 140]),
+    ("building", [70, 70, 70]),
+    ("wall", [102, 102, 156]),
+    ("fence", [190, 153, 153]),
+    ("guard rail", [180, 165, 180]),
+    ("bridge", [150, 100, 100]),
+    ("tunnel", [150, 120, 90]),
+    ("pole", [153, 153, 153]),
+    ("polegroup", [153, 153, 153]),
+    ("traffic light", [250, 170, 30]),
+    ("traffic sign", [220, 220, 0]),
+    ("vegetation", [107, 142, 35]),
+    ("terrain", [152, 251, 152]),
+    ("sky", [70, 130, 180]),
+    ("person", [220, 20, 60]),
+    ("rider", [255, 0, 0]),
+    ("car", [0, 0, 142]),
+    ("truck", [0, 0, 70]),
+    ("bus", [0, 60, 100]),
+    ("caravan", [0, 0, 90]),
+    ("trailer", [0, 0, 110]),
+    ("train", [0, 80, 100]),
+    ("motorcycle", [0, 0, 230]),
+    ("bicycle", [119, 11, 32]),
+];
+
+impl AnnotationContext {
+    /// The label ids, names and colors used by the [Cityscapes](https://www.cityscapes-dataset.com/) dataset.
+    ///
+    /// Use this with segmentation images that contain Cityscapes label ids (not train ids).
+    pub fn cityscapes() -> Self {
+        Self::new(
+            CITYSCAPES_CLASSES
+                .iter()
+                .enumerate()
+                .map(|(id, &(label, [r, g, b]))| {
+                    ClassDescription::from((id as u16, label, Rgba32::from_rgb(r, g, b)))
+                }),
+        )
+    }
+
+    /// Creates an [`AnnotationContext`] from the `categories` of a [COCO](https://cocodataset.org/#format-data) dataset.
+    ///
+    /// Accepts either a whole COCO annotation file or just the list of categories.
+    /// The `keypoints` and `skeleton` of each category are used as keypoint annotations and connections.
+    #[cfg(feature = "label_maps")]
+    pub fn from_coco_categories(contents: &[u8]) -> anyhow::Result<Self> {
+        re_tracing::profile_function!();
+
+        use anyhow::Context as _;
+        use serde_json::Value;
+
+        use crate::datatypes::{AnnotationInfo, KeypointPair};
+
+        let json: Value = serde_json::from_slice(contents)?;
+        let categories = match &json {
+            Value::Array(categories) => categories,
+            Value::Object(object) => object
+                .get("categories")
+                .and_then(Value::as_array)
+                .context("COCO file has no `categories`")?,
+            _ => anyhow::bail!("Expected a COCO annotation file or a list of categories"),
+        };
+
+        let classes = categories
+            .iter()
+            .map(|category| {
+                let id = category
+                    .get("id")
+                    .and_then(Value::as_u64)
+                    .and_then(|id| u16::try_from(id).ok())
+                    .with_context(|| format!("Invalid COCO category id in {category}"))?;
+                let name = category.get("name").and_then(Value::as_str);
+
+                let keypoint_annotations = category
+                    .get("keypoints")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .enumerate()
+                    .map(|(keypoint_id, keypoint)| AnnotationInfo {
+                        id: keypoint_id as u16,
+                        label: keypoint.as_str().map(Into::into),
+                        color: None,
+                    })
+                    .collect();
+
+                // COCO skeletons refer to keypoints with 1-based indices.
+                let keypoint_connections = category
+                    .get("skeleton")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .map(|pair| {
+                        let pair = pair.as_array().map(|pair| {
+                            pair.iter()
+                                .filter_map(|index| index.as_u64()?.checked_sub(1))
+                                .filter_map(|index| u16::try_from(index).ok())
+                                .collect::<Vec<_>>()
+                        });
+                        match pair.as_deref() {
+                            Some(&[keypoint0, keypoint1]) => {
+                                Ok(KeypointPair::from((keypoint0, keypoint1)))
+                            }
+                            _ => anyhow::bail!("Invalid COCO skeleton connection in category {id}"),
+                        }
+                    })
+                    .collect::<anyhow::Result<_>>()?;
+
+                Ok(ClassDescription {
+                    info: AnnotationInfo {
+                        id,
+                        label: name.map(Into::into),
+                        color: None,
+                    },
+                    keypoint_annotations,
+                    keypoint_connections,
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(Self::new(classes))
+    }
+
+    /// Creates an [`AnnotationContext`] from a YAML label map.
+    ///
+    /// The following layouts are supported, optionally nested under a top-level `names` key
+    /// (as used by e.g. YOLO dataset files):
+    /// - A list of names, where the index is the class id: `[person, bicycle]`.
+    /// - A map from class id to name: `{0: person, 1: bicycle}`.
+    /// - A map from class id to a name and color: `{0: {name: person, color: [255, 0, 0]}}`.
+    ///
+    /// Colors are either lists of 3 or 4 components in `0..=255`, or hex strings like `"#ff0000"`.
+    #[cfg(feature = "label_maps")]
+    pub fn from_yaml_label_map(contents: &[u8]) -> anyhow::Result<Self> {
+        re_tracing::profile_function!();
+
+        use serde_yaml::Value;
+
+        use crate::datatypes::AnnotationInfo;
+
+        fn class_id(value: &Value) -> Option<u16> {
+            match value {
+                Value::Number(number) => number.as_u64().and_then(|id| u16::try_from(id).ok()),
+                Value::String(string) => string.trim().parse().ok(),
+                _ => None,
+            }
+        }
+
+        fn color(value: &Value) -> Option<Rgba32> {
+            match value {
+                Value::Sequence(components) => {
+                    let components = components
+                        .iter()
+                        .map(|c| c.as_u64().and_then(|c| u8::try_from(c).ok()))
+                        .collect::<Option<Vec<_>>>()?;
+                    match components[..] {
+                        [r, g, b] => Some(Rgba32::from_rgb(r, g, b)),
+                        [r, g, b, a] => Some(Rgba32::from_unmultiplied_rgba(r, g, b, a)),
+                        _ => None,
+                    }
+                }
+                Value::String(hex) => {
+                    let hex = hex.strip_prefix('#').unwrap_or(hex);
+                    let rgba = u32::from_str_radix(hex, 16).ok()?;
+                    match hex.len() {
+                        6 => Some(Rgba32::from((rgba << 8) | 0xFF)),
+                        8 => Some(Rgba32::from(rgba)),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+
+        fn class(id: u16, value: &Value) -> anyhow::Result<ClassDescription> {
+            let info = match value {
+                Value::String(name) => AnnotationInfo {
+                    id,
+                    label: Some(name.as_str().into()),
+                    color: None,
+                },
+                Value::Mapping(mapping) => AnnotationInfo {
+                    id,
+                    label: mapping
+                        .get("name")
+                        .or_else(|| mapping.get("label"))
+                        .and_then(Value::as_str)
+                        .map(Into::into),
+                    color: mapping.get("color").and_then(color),
+                },
+                _ => anyhow::bail!("Invalid label for class {id}"),
+            };
+            Ok(info.into())
+        }
+
+        let mut yaml: Value = serde_yaml::from_slice(contents)?;
+        if let Some(names) = yaml.get("names") {
+            yaml = names.clone();
+        }
+
+        let classes = match &yaml {
+            Value::Sequence(names) => names
+                .iter()
+                .enumerate()
+                .map(|(id, value)| class(id as u16, value))
+                .collect::<anyhow::Result<Vec<_>>>()?,
+            Value::Mapping(mapping) => mapping
+                .iter()
+                .map(|(key, value)| {
+                    let id =
+                        class_id(key).ok_or_else(|| anyhow::anyhow!("Invalid class id {key:?}"))?;
+                    class(id, value)
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?,
+            _ => anyhow::bail!("Expected a list or a map of labels"),
+        };
+
+        Ok(Self::new(classes))
+    }
+}
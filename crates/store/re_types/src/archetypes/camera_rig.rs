@@ -0,0 +1,325 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/camera_rig.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Describes a rig of several cameras with fixed extrinsics relative to the rig entity.
+///
+/// This makes it possible to express the calibration of a stereo pair or a multi-camera robot once,
+/// at the rig entity, instead of logging a [`archetypes::Transform3D`][crate::archetypes::Transform3D] for each camera.
+/// Each camera is a direct child of the rig entity and is placed relative to it by its pose in the rig.
+///
+/// If a camera entity also has a [`archetypes::Transform3D`][crate::archetypes::Transform3D], it is applied on top of its pose in the rig.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct CameraRig {
+    /// The camera entities of the rig, relative to the rig entity.
+    ///
+    /// Each camera must be a direct child of the rig entity, e.g. `left` and `right` for a stereo pair.
+    pub cameras: Option<SerializedComponentBatch>,
+
+    /// Translation of each camera relative to the rig entity.
+    ///
+    /// If there are fewer translations than cameras, the missing cameras aren't translated.
+    pub translations: Option<SerializedComponentBatch>,
+
+    /// Rotation of each camera relative to the rig entity, as a quaternion.
+    ///
+    /// If there are fewer quaternions than cameras, the missing cameras aren't rotated.
+    pub quaternions: Option<SerializedComponentBatch>,
+}
+
+impl CameraRig {
+    /// Returns the [`ComponentDescriptor`] for [`Self::cameras`].
+    ///
+    /// The corresponding component is [`crate::components::EntityPath`].
+    #[inline]
+    pub fn descriptor_cameras() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.CameraRig".into()),
+            component: "CameraRig:cameras".into(),
+            component_type: Some("rerun.components.EntityPath".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::translations`].
+    ///
+    /// The corresponding component is [`crate::components::PoseTranslation3D`].
+    #[inline]
+    pub fn descriptor_translations() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.CameraRig".into()),
+            component: "CameraRig:translations".into(),
+            component_type: Some("rerun.components.PoseTranslation3D".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::quaternions`].
+    ///
+    /// The corresponding component is [`crate::components::PoseRotationQuat`].
+    #[inline]
+    pub fn descriptor_quaternions() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.CameraRig".into()),
+            component: "CameraRig:quaternions".into(),
+            component_type: Some("rerun.components.PoseRotationQuat".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [CameraRig::descriptor_cameras()]);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            CameraRig::descriptor_translations(),
+            CameraRig::descriptor_quaternions(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 3usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            CameraRig::descriptor_cameras(),
+            CameraRig::descriptor_translations(),
+            CameraRig::descriptor_quaternions(),
+        ]
+    });
+
+impl CameraRig {
+    /// The total number of components in the archetype: 1 required, 0 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
+}
+
+impl ::re_types_core::Archetype for CameraRig {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.CameraRig".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Camera rig"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let cameras = arrays_by_descr
+            .get(&Self::descriptor_cameras())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_cameras()));
+        let translations = arrays_by_descr
+            .get(&Self::descriptor_translations())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_translations())
+            });
+        let quaternions = arrays_by_descr
+            .get(&Self::descriptor_quaternions())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_quaternions())
+            });
+        Ok(Self {
+            cameras,
+            translations,
+            quaternions,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for CameraRig {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.cameras.clone(),
+            self.translations.clone(),
+            self.quaternions.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for CameraRig {}
+
+impl CameraRig {
+    /// Create a new `CameraRig`.
+    #[inline]
+    pub fn new(
+        cameras: impl IntoIterator<Item = impl Into<crate::components::EntityPath>>,
+    ) -> Self {
+        Self {
+            cameras: try_serialize_field(Self::descriptor_cameras(), cameras),
+            translations: None,
+            quaternions: None,
+        }
+    }
+
+    /// Update only some specific fields of a `CameraRig`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `CameraRig`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            cameras: Some(SerializedComponentBatch::new(
+                crate::components::EntityPath::arrow_empty(),
+                Self::descriptor_cameras(),
+            )),
+            translations: Some(SerializedComponentBatch::new(
+                crate::components::PoseTranslation3D::arrow_empty(),
+                Self::descriptor_translations(),
+            )),
+            quaternions: Some(SerializedComponentBatch::new(
+                crate::components::PoseRotationQuat::arrow_empty(),
+                Self::descriptor_quaternions(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.cameras
+                .map(|cameras| cameras.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.translations
+                .map(|translations| translations.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.quaternions
+                .map(|quaternions| quaternions.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns.into_iter().flatten())
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_cameras = self.cameras.as_ref().map(|b| b.array.len());
+        let len_translations = self.translations.as_ref().map(|b| b.array.len());
+        let len_quaternions = self.quaternions.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_cameras)
+            .or(len_translations)
+            .or(len_quaternions)
+            .unwrap_or(0);
+        self.columns(std::iter::repeat_n(1, len))
+    }
+
+    /// The camera entities of the rig, relative to the rig entity.
+    ///
+    /// Each camera must be a direct child of the rig entity, e.g. `left` and `right` for a stereo pair.
+    #[inline]
+    pub fn with_cameras(
+        mut self,
+        cameras: impl IntoIterator<Item = impl Into<crate::components::EntityPath>>,
+    ) -> Self {
+        self.cameras = try_serialize_field(Self::descriptor_cameras(), cameras);
+        self
+    }
+
+    /// Translation of each camera relative to the rig entity.
+    ///
+    /// If there are fewer translations than cameras, the missing cameras aren't translated.
+    #[inline]
+    pub fn with_translations(
+        mut self,
+        translations: impl IntoIterator<Item = impl Into<crate::components::PoseTranslation3D>>,
+    ) -> Self {
+        self.translations = try_serialize_field(Self::descriptor_translations(), translations);
+        self
+    }
+
+    /// Rotation of each camera relative to the rig entity, as a quaternion.
+    ///
+    /// If there are fewer quaternions than cameras, the missing cameras aren't rotated.
+    #[inline]
+    pub fn with_quaternions(
+        mut self,
+        quaternions: impl IntoIterator<Item = impl Into<crate::components::PoseRotationQuat>>,
+    ) -> Self {
+        self.quaternions = try_serialize_field(Self::descriptor_quaternions(), quaternions);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for CameraRig {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.cameras.heap_size_bytes()
+            + self.translations.heap_size_bytes()
+            + self.quaternions.heap_size_bytes()
+    }
+}
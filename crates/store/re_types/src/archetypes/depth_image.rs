@@ -100,6 +100,14 @@ pub struct DepthImage {
     /// the Viewer will guess that the data likely came from an 8bit image, thus assuming a range of 0-255.
     pub depth_range: Option<SerializedComponentBatch>,
 
+    /// Depth values outside of this range, in meters, are left out of the point cloud generated from this image.
+    ///
+    /// Use this to hide invalid measurements close to the sensor or far away from it, e.g. `[0.3, 10.0]`.
+    /// If not specified, all points with a positive depth are shown.
+    ///
+    /// This has no effect on 2D views.
+    pub clip_range: Option<SerializedComponentBatch>,
+
     /// Scale the radii of the points in the point cloud generated from this image.
     ///
     /// A fill ratio of 1.0 (the default) means that each point is as big as to touch the center of its neighbor
@@ -177,6 +185,18 @@ impl DepthImage {
         }
     }
 
+    /// Returns the [`ComponentDescriptor`] for [`Self::clip_range`].
+    ///
+    /// The corresponding component is [`crate::components::Range1D`].
+    #[inline]
+    pub fn descriptor_clip_range() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.DepthImage".into()),
+            component: "DepthImage:clip_range".into(),
+            component_type: Some("rerun.components.Range1D".into()),
+        }
+    }
+
     /// Returns the [`ComponentDescriptor`] for [`Self::point_fill_ratio`].
     ///
     /// The corresponding component is [`crate::components::FillRatio`].
@@ -213,18 +233,19 @@ static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
 static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
     std::sync::LazyLock::new(|| []);
 
-static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 6usize]> =
     std::sync::LazyLock::new(|| {
         [
             DepthImage::descriptor_meter(),
             DepthImage::descriptor_colormap(),
             DepthImage::descriptor_depth_range(),
+            DepthImage::descriptor_clip_range(),
             DepthImage::descriptor_point_fill_ratio(),
             DepthImage::descriptor_draw_order(),
         ]
     });
 
-static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 8usize]> =
     std::sync::LazyLock::new(|| {
         [
             DepthImage::descriptor_buffer(),
@@ -232,14 +253,15 @@ static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
             DepthImage::descriptor_meter(),
             DepthImage::descriptor_colormap(),
             DepthImage::descriptor_depth_range(),
+            DepthImage::descriptor_clip_range(),
             DepthImage::descriptor_point_fill_ratio(),
             DepthImage::descriptor_draw_order(),
         ]
     });
 
 impl DepthImage {
-    /// The total number of components in the archetype: 2 required, 0 recommended, 5 optional
-    pub const NUM_COMPONENTS: usize = 7usize;
+    /// The total number of components in the archetype: 2 required, 0 recommended, 6 optional
+    pub const NUM_COMPONENTS: usize = 8usize;
 }
 
 impl ::re_types_core::Archetype for DepthImage {
@@ -297,6 +319,11 @@ impl ::re_types_core::Archetype for DepthImage {
             .map(|array| {
                 SerializedComponentBatch::new(array.clone(), Self::descriptor_depth_range())
             });
+        let clip_range = arrays_by_descr
+            .get(&Self::descriptor_clip_range())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_clip_range())
+            });
         let point_fill_ratio = arrays_by_descr
             .get(&Self::descriptor_point_fill_ratio())
             .map(|array| {
@@ -313,6 +340,7 @@ impl ::re_types_core::Archetype for DepthImage {
             meter,
             colormap,
             depth_range,
+            clip_range,
             point_fill_ratio,
             draw_order,
         })
@@ -329,6 +357,7 @@ impl ::re_types_core::AsComponents for DepthImage {
             self.meter.clone(),
             self.colormap.clone(),
             self.depth_range.clone(),
+            self.clip_range.clone(),
             self.point_fill_ratio.clone(),
             self.draw_order.clone(),
         ]
@@ -353,6 +382,7 @@ impl DepthImage {
             meter: None,
             colormap: None,
             depth_range: None,
+            clip_range: None,
             point_fill_ratio: None,
             draw_order: None,
         }
@@ -389,6 +419,10 @@ impl DepthImage {
                 crate::components::ValueRange::arrow_empty(),
                 Self::descriptor_depth_range(),
             )),
+            clip_range: Some(SerializedComponentBatch::new(
+                crate::components::Range1D::arrow_empty(),
+                Self::descriptor_clip_range(),
+            )),
             point_fill_ratio: Some(SerializedComponentBatch::new(
                 crate::components::FillRatio::arrow_empty(),
                 Self::descriptor_point_fill_ratio(),
@@ -434,6 +468,9 @@ impl DepthImage {
             self.depth_range
                 .map(|depth_range| depth_range.partitioned(_lengths.clone()))
                 .transpose()?,
+            self.clip_range
+                .map(|clip_range| clip_range.partitioned(_lengths.clone()))
+                .transpose()?,
             self.point_fill_ratio
                 .map(|point_fill_ratio| point_fill_ratio.partitioned(_lengths.clone()))
                 .transpose()?,
@@ -457,6 +494,7 @@ impl DepthImage {
         let len_meter = self.meter.as_ref().map(|b| b.array.len());
         let len_colormap = self.colormap.as_ref().map(|b| b.array.len());
         let len_depth_range = self.depth_range.as_ref().map(|b| b.array.len());
+        let len_clip_range = self.clip_range.as_ref().map(|b| b.array.len());
         let len_point_fill_ratio = self.point_fill_ratio.as_ref().map(|b| b.array.len());
         let len_draw_order = self.draw_order.as_ref().map(|b| b.array.len());
         let len = None
@@ -465,6 +503,7 @@ impl DepthImage {
             .or(len_meter)
             .or(len_colormap)
             .or(len_depth_range)
+            .or(len_clip_range)
             .or(len_point_fill_ratio)
             .or(len_draw_order)
             .unwrap_or(0);
@@ -592,6 +631,31 @@ impl DepthImage {
         self
     }
 
+    /// Depth values outside of this range, in meters, are left out of the point cloud generated from this image.
+    ///
+    /// Use this to hide invalid measurements close to the sensor or far away from it, e.g. `[0.3, 10.0]`.
+    /// If not specified, all points with a positive depth are shown.
+    ///
+    /// This has no effect on 2D views.
+    #[inline]
+    pub fn with_clip_range(mut self, clip_range: impl Into<crate::components::Range1D>) -> Self {
+        self.clip_range = try_serialize_field(Self::descriptor_clip_range(), [clip_range]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Range1D`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_clip_range`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_clip_range(
+        mut self,
+        clip_range: impl IntoIterator<Item = impl Into<crate::components::Range1D>>,
+    ) -> Self {
+        self.clip_range = try_serialize_field(Self::descriptor_clip_range(), clip_range);
+        self
+    }
+
     /// Scale the radii of the points in the point cloud generated from this image.
     ///
     /// A fill ratio of 1.0 (the default) means that each point is as big as to touch the center of its neighbor
@@ -655,6 +719,7 @@ impl ::re_byte_size::SizeBytes for DepthImage {
             + self.meter.heap_size_bytes()
             + self.colormap.heap_size_bytes()
             + self.depth_range.heap_size_bytes()
+            + self.clip_range.heap_size_bytes()
             + self.point_fill_ratio.heap_size_bytes()
             + self.draw_order.heap_size_bytes()
     }
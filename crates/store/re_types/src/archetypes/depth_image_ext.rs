@@ -1,7 +1,10 @@
 use crate::{
     components::{ImageBuffer, ImageFormat},
     datatypes::{ChannelDatatype, ColorModel, TensorData},
-    image::{blob_and_datatype_from_tensor, find_non_empty_dim_indices, ImageConstructionError},
+    image::{
+        blob_and_datatype_from_tensor, find_non_empty_dim_indices, repack_strided_rows,
+        ImageConstructionError, ImageStrideError,
+    },
 };
 
 use super::DepthImage;
@@ -59,6 +62,27 @@ impl DepthImage {
         Self::new(buffer, image_format)
     }
 
+    /// Construct a depth image from a byte buffer with padded rows, given its resolution, row
+    /// stride, and data type.
+    ///
+    /// `stride` is the distance in bytes between the start of two consecutive rows, like the
+    /// `step` field of a ROS `sensor_msgs/Image`. The padding is stripped before logging, and the
+    /// buffer is only copied if there is any.
+    pub fn from_data_type_and_strided_bytes(
+        bytes: &[u8],
+        [width, height]: [u32; 2],
+        stride: usize,
+        datatype: ChannelDatatype,
+    ) -> Result<Self, ImageStrideError> {
+        let row_bytes = ImageFormat::depth([width, 1], datatype).num_bytes();
+        let bytes = repack_strided_rows(bytes, row_bytes, height as usize, stride)?;
+        Ok(Self::from_data_type_and_bytes(
+            bytes.as_ref(),
+            [width, height],
+            datatype,
+        ))
+    }
+
     /// From an 16-bit grayscale image.
     pub fn from_gray16(bytes: impl Into<ImageBuffer>, resolution: [u32; 2]) -> Self {
         Self::from_data_type_and_bytes(bytes, resolution, ChannelDatatype::U16)
@@ -0,0 +1,258 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/entity_relations.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Relations between an entity and other entities, beyond the entity hierarchy.
+///
+/// This makes it possible to express that a detection came from a given camera, or that a track
+/// is the same object as another one across frames.
+/// Relations are logged on the source entity, and each target is the full path of a related entity.
+/// Like any other data, relations can change over time.
+///
+/// The viewer lists the related entities of the selected entity, in both directions, so that one can
+/// navigate between them.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct EntityRelations {
+    /// The related entities.
+    pub targets: Option<SerializedComponentBatch>,
+
+    /// The kind of each relation, e.g. `source` or `same_track_as`.
+    ///
+    /// If there are fewer kinds than targets, the remaining relations have no kind.
+    pub kinds: Option<SerializedComponentBatch>,
+}
+
+impl EntityRelations {
+    /// Returns the [`ComponentDescriptor`] for [`Self::targets`].
+    ///
+    /// The corresponding component is [`crate::components::EntityPath`].
+    #[inline]
+    pub fn descriptor_targets() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.EntityRelations".into()),
+            component: "EntityRelations:targets".into(),
+            component_type: Some("rerun.components.EntityPath".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::kinds`].
+    ///
+    /// The corresponding component is [`crate::components::Text`].
+    #[inline]
+    pub fn descriptor_kinds() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.EntityRelations".into()),
+            component: "EntityRelations:kinds".into(),
+            component_type: Some("rerun.components.Text".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [EntityRelations::descriptor_targets()]);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [EntityRelations::descriptor_kinds()]);
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            EntityRelations::descriptor_targets(),
+            EntityRelations::descriptor_kinds(),
+        ]
+    });
+
+impl EntityRelations {
+    /// The total number of components in the archetype: 1 required, 0 recommended, 1 optional
+    pub const NUM_COMPONENTS: usize = 2usize;
+}
+
+impl ::re_types_core::Archetype for EntityRelations {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.EntityRelations".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Entity relations"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let targets = arrays_by_descr
+            .get(&Self::descriptor_targets())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_targets()));
+        let kinds = arrays_by_descr
+            .get(&Self::descriptor_kinds())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_kinds()));
+        Ok(Self { targets, kinds })
+    }
+}
+
+impl ::re_types_core::AsComponents for EntityRelations {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [self.targets.clone(), self.kinds.clone()]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for EntityRelations {}
+
+impl EntityRelations {
+    /// Create a new `EntityRelations`.
+    #[inline]
+    pub fn new(
+        targets: impl IntoIterator<Item = impl Into<crate::components::EntityPath>>,
+    ) -> Self {
+        Self {
+            targets: try_serialize_field(Self::descriptor_targets(), targets),
+            kinds: None,
+        }
+    }
+
+    /// Update only some specific fields of a `EntityRelations`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `EntityRelations`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            targets: Some(SerializedComponentBatch::new(
+                crate::components::EntityPath::arrow_empty(),
+                Self::descriptor_targets(),
+            )),
+            kinds: Some(SerializedComponentBatch::new(
+                crate::components::Text::arrow_empty(),
+                Self::descriptor_kinds(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.targets
+                .map(|targets| targets.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.kinds
+                .map(|kinds| kinds.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns.into_iter().flatten())
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_targets = self.targets.as_ref().map(|b| b.array.len());
+        let len_kinds = self.kinds.as_ref().map(|b| b.array.len());
+        let len = None.or(len_targets).or(len_kinds).unwrap_or(0);
+        self.columns(std::iter::repeat_n(1, len))
+    }
+
+    /// The related entities.
+    #[inline]
+    pub fn with_targets(
+        mut self,
+        targets: impl IntoIterator<Item = impl Into<crate::components::EntityPath>>,
+    ) -> Self {
+        self.targets = try_serialize_field(Self::descriptor_targets(), targets);
+        self
+    }
+
+    /// The kind of each relation, e.g. `source` or `same_track_as`.
+    ///
+    /// If there are fewer kinds than targets, the remaining relations have no kind.
+    #[inline]
+    pub fn with_kinds(
+        mut self,
+        kinds: impl IntoIterator<Item = impl Into<crate::components::Text>>,
+    ) -> Self {
+        self.kinds = try_serialize_field(Self::descriptor_kinds(), kinds);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for EntityRelations {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.targets.heap_size_bytes() + self.kinds.heap_size_bytes()
+    }
+}
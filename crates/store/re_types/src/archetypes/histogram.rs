@@ -0,0 +1,324 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/histogram.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: A histogram, i.e. counts over a set of contiguous bins.
+///
+/// Each logged histogram replaces the previous one, making it possible to step through
+/// per-frame distributions (e.g. latencies, pixel intensities) on the timeline.
+///
+/// If no bin edges are provided, bin `i` spans the range `[i, i + 1)`.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Histogram {
+    /// The count (or weight) of each bin.
+    pub counts: Option<SerializedComponentBatch>,
+
+    /// The edges of the bins, in ascending order.
+    ///
+    /// Must contain exactly one more value than `counts`: bin `i` spans `[bin_edges[i], bin_edges[i + 1])`.
+    /// Defaults to unit-width bins starting at zero.
+    pub bin_edges: Option<SerializedComponentBatch>,
+
+    /// The color of the bars.
+    pub color: Option<SerializedComponentBatch>,
+}
+
+impl Histogram {
+    /// Returns the [`ComponentDescriptor`] for [`Self::counts`].
+    ///
+    /// The corresponding component is [`crate::components::Scalar`].
+    #[inline]
+    pub fn descriptor_counts() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Histogram".into()),
+            component: "Histogram:counts".into(),
+            component_type: Some("rerun.components.Scalar".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::bin_edges`].
+    ///
+    /// The corresponding component is [`crate::components::Scalar`].
+    #[inline]
+    pub fn descriptor_bin_edges() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Histogram".into()),
+            component: "Histogram:bin_edges".into(),
+            component_type: Some("rerun.components.Scalar".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::color`].
+    ///
+    /// The corresponding component is [`crate::components::Color`].
+    #[inline]
+    pub fn descriptor_color() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Histogram".into()),
+            component: "Histogram:color".into(),
+            component_type: Some("rerun.components.Color".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [Histogram::descriptor_counts()]);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Histogram::descriptor_bin_edges(),
+            Histogram::descriptor_color(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 3usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Histogram::descriptor_counts(),
+            Histogram::descriptor_bin_edges(),
+            Histogram::descriptor_color(),
+        ]
+    });
+
+impl Histogram {
+    /// The total number of components in the archetype: 1 required, 0 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
+}
+
+impl ::re_types_core::Archetype for Histogram {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.Histogram".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Histogram"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let counts = arrays_by_descr
+            .get(&Self::descriptor_counts())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_counts()));
+        let bin_edges = arrays_by_descr
+            .get(&Self::descriptor_bin_edges())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_bin_edges())
+            });
+        let color = arrays_by_descr
+            .get(&Self::descriptor_color())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_color()));
+        Ok(Self {
+            counts,
+            bin_edges,
+            color,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for Histogram {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.counts.clone(),
+            self.bin_edges.clone(),
+            self.color.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for Histogram {}
+
+impl Histogram {
+    /// Create a new `Histogram`.
+    #[inline]
+    pub fn new(counts: impl IntoIterator<Item = impl Into<crate::components::Scalar>>) -> Self {
+        Self {
+            counts: try_serialize_field(Self::descriptor_counts(), counts),
+            bin_edges: None,
+            color: None,
+        }
+    }
+
+    /// Update only some specific fields of a `Histogram`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `Histogram`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            counts: Some(SerializedComponentBatch::new(
+                crate::components::Scalar::arrow_empty(),
+                Self::descriptor_counts(),
+            )),
+            bin_edges: Some(SerializedComponentBatch::new(
+                crate::components::Scalar::arrow_empty(),
+                Self::descriptor_bin_edges(),
+            )),
+            color: Some(SerializedComponentBatch::new(
+                crate::components::Color::arrow_empty(),
+                Self::descriptor_color(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.counts
+                .map(|counts| counts.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.bin_edges
+                .map(|bin_edges| bin_edges.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.color
+                .map(|color| color.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns.into_iter().flatten())
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_counts = self.counts.as_ref().map(|b| b.array.len());
+        let len_bin_edges = self.bin_edges.as_ref().map(|b| b.array.len());
+        let len_color = self.color.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_counts)
+            .or(len_bin_edges)
+            .or(len_color)
+            .unwrap_or(0);
+        self.columns(std::iter::repeat_n(1, len))
+    }
+
+    /// The count (or weight) of each bin.
+    #[inline]
+    pub fn with_counts(
+        mut self,
+        counts: impl IntoIterator<Item = impl Into<crate::components::Scalar>>,
+    ) -> Self {
+        self.counts = try_serialize_field(Self::descriptor_counts(), counts);
+        self
+    }
+
+    /// The edges of the bins, in ascending order.
+    ///
+    /// Must contain exactly one more value than `counts`: bin `i` spans `[bin_edges[i], bin_edges[i + 1])`.
+    /// Defaults to unit-width bins starting at zero.
+    #[inline]
+    pub fn with_bin_edges(
+        mut self,
+        bin_edges: impl IntoIterator<Item = impl Into<crate::components::Scalar>>,
+    ) -> Self {
+        self.bin_edges = try_serialize_field(Self::descriptor_bin_edges(), bin_edges);
+        self
+    }
+
+    /// The color of the bars.
+    #[inline]
+    pub fn with_color(mut self, color: impl Into<crate::components::Color>) -> Self {
+        self.color = try_serialize_field(Self::descriptor_color(), [color]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Color`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_color`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_color(
+        mut self,
+        color: impl IntoIterator<Item = impl Into<crate::components::Color>>,
+    ) -> Self {
+        self.color = try_serialize_field(Self::descriptor_color(), color);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for Histogram {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.counts.heap_size_bytes()
+            + self.bin_edges.heap_size_bytes()
+            + self.color.heap_size_bytes()
+    }
+}
@@ -2,8 +2,8 @@ use crate::{
     components::ImageBuffer,
     datatypes::{ChannelDatatype, ColorModel, ImageFormat, PixelFormat, TensorData},
     image::{
-        blob_and_datatype_from_tensor, find_non_empty_dim_indices, ImageChannelType,
-        ImageConstructionError,
+        blob_and_datatype_from_tensor, find_non_empty_dim_indices, repack_strided_rows,
+        ImageChannelType, ImageConstructionError, ImageStrideError,
     },
 };
 
@@ -107,6 +107,32 @@ impl Image {
         Self::new(buffer, image_format)
     }
 
+    /// Construct an image from a byte buffer with padded rows, given its resolution, row stride,
+    /// color model, and data type.
+    ///
+    /// `stride` is the distance in bytes between the start of two consecutive rows, like the
+    /// `step` field of a ROS `sensor_msgs/Image`. The padding is stripped before logging, and the
+    /// buffer is only copied if there is any.
+    ///
+    /// See also [`Self::from_color_model_and_bytes`].
+    pub fn from_color_model_and_strided_bytes(
+        bytes: &[u8],
+        [width, height]: [u32; 2],
+        stride: usize,
+        color_model: ColorModel,
+        datatype: ChannelDatatype,
+    ) -> Result<Self, ImageStrideError> {
+        let row_bytes =
+            ImageFormat::from_color_model([width, 1], color_model, datatype).num_bytes();
+        let bytes = repack_strided_rows(bytes, row_bytes, height as usize, stride)?;
+        Ok(Self::from_color_model_and_bytes(
+            bytes.as_ref(),
+            [width, height],
+            color_model,
+            datatype,
+        ))
+    }
+
     /// Construct an image from a byte buffer given its resolution, color model,
     /// and using the data type of the given vector.
     pub fn from_elements<T: ImageChannelType>(
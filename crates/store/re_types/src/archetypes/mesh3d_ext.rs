@@ -107,3 +107,166 @@ impl Mesh3D {
         }
     }
 }
+
+impl Mesh3D {
+    /// Creates a new [`Mesh3D`] from a `.ply` file.
+    ///
+    /// ## Supported properties
+    ///
+    /// This expects the following property names:
+    /// - (Required) Positions of the vertices: `"x"`, `"y"` & `"z"`.
+    /// - (Optional) Normals of the vertices: `"nx"`, `"ny"` & `"nz"`.
+    /// - (Optional) Colors of the vertices: `"red"`, `"green"`, `"blue"` & `"alpha"`.
+    /// - (Optional) Texture coordinates of the vertices: `"s"` & `"t"`, `"u"` & `"v"`,
+    ///   or `"texture_u"` & `"texture_v"`.
+    /// - (Required) Faces as lists of vertex indices: `"vertex_indices"` or `"vertex_index"`.
+    ///
+    /// Polygonal faces are triangulated as fans.
+    /// Texture coordinates are flipped vertically, since `.ply` files place the origin at the bottom-left.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_file_path(filepath: &std::path::Path) -> anyhow::Result<Self> {
+        re_tracing::profile_function!(filepath.to_string_lossy());
+        use anyhow::Context as _;
+
+        let file = std::fs::File::open(filepath)
+            .with_context(|| format!("Failed to open file {filepath:?}"))?;
+        let ply = crate::ply::read_ply(std::io::BufReader::new(file))?;
+
+        from_ply(ply)
+    }
+
+    /// Creates a new [`Mesh3D`] from the contents of a `.ply` file.
+    ///
+    /// See [`Self::from_file_path`] for the supported properties.
+    pub fn from_file_contents(contents: &[u8]) -> anyhow::Result<Self> {
+        re_tracing::profile_function!();
+        let ply = crate::ply::read_ply(std::io::Cursor::new(contents))?;
+        from_ply(ply)
+    }
+}
+
+fn from_ply(ply: ply_rs::ply::Ply<ply_rs::ply::DefaultElement>) -> anyhow::Result<Mesh3D> {
+    re_tracing::profile_function!();
+
+    use std::collections::BTreeSet;
+
+    use crate::components::{Color, Position3D, Texcoord2D, Vector3D};
+    use crate::datatypes::UVec3D;
+    use crate::ply::{f32, indices, u8};
+
+    // NOTE: Empirical evidence points to these being de-facto standard…
+    const PROPS_POSITION: [&str; 3] = ["x", "y", "z"];
+    const PROPS_NORMAL: [&str; 3] = ["nx", "ny", "nz"];
+    const PROPS_COLOR: [&str; 3] = ["red", "green", "blue"];
+    const PROP_ALPHA: &str = "alpha";
+    const PROPS_TEXCOORD: [[&str; 2]; 3] = [["s", "t"], ["u", "v"], ["texture_u", "texture_v"]];
+    const PROPS_FACE: [&str; 2] = ["vertex_indices", "vertex_index"];
+
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut colors = Vec::new();
+    let mut texcoords = Vec::new();
+    let mut triangles = Vec::new();
+
+    let mut ignored_props = BTreeSet::new();
+
+    for (key, all_props) in ply.payload {
+        match key.as_str() {
+            "vertex" => {
+                for mut props in all_props {
+                    // Removes properties as they are read so we can warn about the ones we don't recognize.
+                    let mut take_vec3 = |names: [&str; 3]| {
+                        let values = names.map(|name| props.get(name).and_then(f32));
+                        let [Some(a), Some(b), Some(c)] = values else {
+                            return None;
+                        };
+                        for name in names {
+                            props.remove(name);
+                        }
+                        Some([a, b, c])
+                    };
+
+                    let Some(position) = take_vec3(PROPS_POSITION) else {
+                        anyhow::bail!("All vertices of a .ply mesh must have positions");
+                    };
+                    positions.push(Position3D::from(position));
+                    normals.push(take_vec3(PROPS_NORMAL).map(Vector3D::from));
+
+                    let color = PROPS_COLOR.map(|name| props.get(name).and_then(u8));
+                    colors.push(if let [Some(r), Some(g), Some(b)] = color {
+                        let a = props.get(PROP_ALPHA).and_then(u8).unwrap_or(255);
+                        for name in PROPS_COLOR.into_iter().chain([PROP_ALPHA]) {
+                            props.remove(name);
+                        }
+                        Some(Color::from_unmultiplied_rgba(r, g, b, a))
+                    } else {
+                        None
+                    });
+
+                    texcoords.push(PROPS_TEXCOORD.into_iter().find_map(|[u_name, v_name]| {
+                        let u = props.get(u_name).and_then(f32)?;
+                        let v = props.get(v_name).and_then(f32)?;
+                        props.remove(u_name);
+                        props.remove(v_name);
+                        Some(Texcoord2D::from([u, 1.0 - v]))
+                    }));
+
+                    ignored_props.extend(props.into_iter().map(|(key, _value)| key));
+                }
+            }
+
+            "face" => {
+                for props in all_props {
+                    let Some(face) = PROPS_FACE
+                        .into_iter()
+                        .find_map(|name| props.get(name).and_then(indices))
+                    else {
+                        ignored_props.extend(props.into_iter().map(|(key, _value)| key));
+                        continue;
+                    };
+
+                    // Triangulate as a fan around the first corner.
+                    if let Some((&first, rest)) = face.split_first() {
+                        triangles.extend(
+                            rest.windows(2)
+                                .map(|edge| UVec3D::new(first, edge[0], edge[1])),
+                        );
+                    }
+                }
+            }
+
+            _ => re_log::warn!("Ignoring {key:?} in .ply file"),
+        }
+    }
+
+    if !ignored_props.is_empty() {
+        re_log::warn!("Ignored properties of .ply file: {ignored_props:?}");
+    }
+
+    if triangles.is_empty() {
+        anyhow::bail!(".ply file doesn't contain any faces");
+    }
+
+    re_tracing::profile_scope!("fill-in");
+
+    let mut arch = Mesh3D::new(positions).with_triangle_indices(triangles);
+    if normals.iter().any(|opt| opt.is_some()) {
+        // If some normals have been specified but not others, the renderer will have to compute them.
+        arch = arch.with_vertex_normals(normals.into_iter().map(Option::unwrap_or_default));
+    }
+    if colors.iter().any(|opt| opt.is_some()) {
+        // If some colors have been specified but not others, default the unspecified ones to white.
+        arch = arch.with_vertex_colors(
+            colors
+                .into_iter()
+                .map(|opt| opt.unwrap_or(Color::from_rgb(255, 255, 255))),
+        );
+    }
+    if texcoords.iter().any(|opt| opt.is_some()) {
+        arch = arch.with_vertex_texcoords(texcoords.into_iter().map(Option::unwrap_or_default));
+    }
+
+    arch.sanity_check()?;
+
+    Ok(arch)
+}
@@ -1,6 +1,7 @@
 // DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
 
 mod annotation_context;
+mod annotation_context_ext;
 mod arrows2d;
 mod arrows2d_ext;
 mod arrows3d;
@@ -14,6 +15,7 @@ mod boxes2d;
 mod boxes2d_ext;
 mod boxes3d;
 mod boxes3d_ext;
+mod camera_rig;
 mod capsules3d;
 mod capsules3d_ext;
 mod cylinders3d;
@@ -24,6 +26,7 @@ mod ellipsoids3d;
 mod ellipsoids3d_ext;
 mod encoded_image;
 mod encoded_image_ext;
+mod entity_relations;
 mod geo_line_strings;
 mod geo_line_strings_ext;
 mod geo_points;
@@ -31,6 +34,7 @@ mod geo_points_ext;
 mod graph_edges;
 mod graph_edges_ext;
 mod graph_nodes;
+mod histogram;
 mod image;
 mod image_ext;
 mod instance_poses3d;
@@ -61,6 +65,7 @@ mod video_frame_reference;
 mod video_stream;
 mod view_coordinates;
 mod view_coordinates_ext;
+mod waveform;
 
 pub use self::annotation_context::AnnotationContext;
 pub use self::arrows2d::Arrows2D;
@@ -70,15 +75,18 @@ pub use self::asset_video::AssetVideo;
 pub use self::bar_chart::BarChart;
 pub use self::boxes2d::Boxes2D;
 pub use self::boxes3d::Boxes3D;
+pub use self::camera_rig::CameraRig;
 pub use self::capsules3d::Capsules3D;
 pub use self::cylinders3d::Cylinders3D;
 pub use self::depth_image::DepthImage;
 pub use self::ellipsoids3d::Ellipsoids3D;
 pub use self::encoded_image::EncodedImage;
+pub use self::entity_relations::EntityRelations;
 pub use self::geo_line_strings::GeoLineStrings;
 pub use self::geo_points::GeoPoints;
 pub use self::graph_edges::GraphEdges;
 pub use self::graph_nodes::GraphNodes;
+pub use self::histogram::Histogram;
 pub use self::image::Image;
 pub use self::instance_poses3d::InstancePoses3D;
 pub use self::line_strips2d::LineStrips2D;
@@ -99,3 +107,4 @@ pub use self::transform3d::Transform3D;
 pub use self::video_frame_reference::VideoFrameReference;
 pub use self::video_stream::VideoStream;
 pub use self::view_coordinates::ViewCoordinates;
+pub use self::waveform::Waveform;
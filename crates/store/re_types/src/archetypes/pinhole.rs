@@ -139,6 +139,17 @@ pub struct Pinhole {
     ///
     /// This is only used for visualization purposes, and does not affect the projection itself.
     pub image_plane_distance: Option<SerializedComponentBatch>,
+
+    /// The lens distortion model that the `distortion_coefficients` are given for.
+    ///
+    /// Defaults to the Brown-Conrady (`plumb_bob`) model used by OpenCV.
+    pub distortion_model: Option<SerializedComponentBatch>,
+
+    /// Lens distortion coefficients, in the order defined by the `distortion_model`.
+    ///
+    /// Points are distorted on the normalized image plane, i.e. after dividing by depth and before applying
+    /// `image_from_camera`. If no coefficients are logged, the camera is an ideal pinhole camera.
+    pub distortion_coefficients: Option<SerializedComponentBatch>,
 }
 
 impl Pinhole {
@@ -189,6 +200,30 @@ impl Pinhole {
             component_type: Some("rerun.components.ImagePlaneDistance".into()),
         }
     }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::distortion_model`].
+    ///
+    /// The corresponding component is [`crate::components::DistortionModel`].
+    #[inline]
+    pub fn descriptor_distortion_model() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Pinhole".into()),
+            component: "Pinhole:distortion_model".into(),
+            component_type: Some("rerun.components.DistortionModel".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::distortion_coefficients`].
+    ///
+    /// The corresponding component is [`crate::components::DistortionCoefficient`].
+    #[inline]
+    pub fn descriptor_distortion_coefficients() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Pinhole".into()),
+            component: "Pinhole:distortion_coefficients".into(),
+            component_type: Some("rerun.components.DistortionCoefficient".into()),
+        }
+    }
 }
 
 static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
@@ -197,27 +232,31 @@ static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
 static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
     std::sync::LazyLock::new(|| [Pinhole::descriptor_resolution()]);
 
-static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 4usize]> =
     std::sync::LazyLock::new(|| {
         [
             Pinhole::descriptor_camera_xyz(),
             Pinhole::descriptor_image_plane_distance(),
+            Pinhole::descriptor_distortion_model(),
+            Pinhole::descriptor_distortion_coefficients(),
         ]
     });
 
-static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 4usize]> =
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 6usize]> =
     std::sync::LazyLock::new(|| {
         [
             Pinhole::descriptor_image_from_camera(),
             Pinhole::descriptor_resolution(),
             Pinhole::descriptor_camera_xyz(),
             Pinhole::descriptor_image_plane_distance(),
+            Pinhole::descriptor_distortion_model(),
+            Pinhole::descriptor_distortion_coefficients(),
         ]
     });
 
 impl Pinhole {
-    /// The total number of components in the archetype: 1 required, 1 recommended, 2 optional
-    pub const NUM_COMPONENTS: usize = 4usize;
+    /// The total number of components in the archetype: 1 required, 1 recommended, 4 optional
+    pub const NUM_COMPONENTS: usize = 6usize;
 }
 
 impl ::re_types_core::Archetype for Pinhole {
@@ -281,11 +320,26 @@ impl ::re_types_core::Archetype for Pinhole {
                     Self::descriptor_image_plane_distance(),
                 )
             });
+        let distortion_model = arrays_by_descr
+            .get(&Self::descriptor_distortion_model())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_distortion_model())
+            });
+        let distortion_coefficients = arrays_by_descr
+            .get(&Self::descriptor_distortion_coefficients())
+            .map(|array| {
+                SerializedComponentBatch::new(
+                    array.clone(),
+                    Self::descriptor_distortion_coefficients(),
+                )
+            });
         Ok(Self {
             image_from_camera,
             resolution,
             camera_xyz,
             image_plane_distance,
+            distortion_model,
+            distortion_coefficients,
         })
     }
 }
@@ -299,6 +353,8 @@ impl ::re_types_core::AsComponents for Pinhole {
             self.resolution.clone(),
             self.camera_xyz.clone(),
             self.image_plane_distance.clone(),
+            self.distortion_model.clone(),
+            self.distortion_coefficients.clone(),
         ]
         .into_iter()
         .flatten()
@@ -320,6 +376,8 @@ impl Pinhole {
             resolution: None,
             camera_xyz: None,
             image_plane_distance: None,
+            distortion_model: None,
+            distortion_coefficients: None,
         }
     }
 
@@ -350,6 +408,14 @@ impl Pinhole {
                 crate::components::ImagePlaneDistance::arrow_empty(),
                 Self::descriptor_image_plane_distance(),
             )),
+            distortion_model: Some(SerializedComponentBatch::new(
+                crate::components::DistortionModel::arrow_empty(),
+                Self::descriptor_distortion_model(),
+            )),
+            distortion_coefficients: Some(SerializedComponentBatch::new(
+                crate::components::DistortionCoefficient::arrow_empty(),
+                Self::descriptor_distortion_coefficients(),
+            )),
         }
     }
 
@@ -384,6 +450,14 @@ impl Pinhole {
             self.image_plane_distance
                 .map(|image_plane_distance| image_plane_distance.partitioned(_lengths.clone()))
                 .transpose()?,
+            self.distortion_model
+                .map(|distortion_model| distortion_model.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.distortion_coefficients
+                .map(|distortion_coefficients| {
+                    distortion_coefficients.partitioned(_lengths.clone())
+                })
+                .transpose()?,
         ];
         Ok(columns.into_iter().flatten())
     }
@@ -400,11 +474,16 @@ impl Pinhole {
         let len_resolution = self.resolution.as_ref().map(|b| b.array.len());
         let len_camera_xyz = self.camera_xyz.as_ref().map(|b| b.array.len());
         let len_image_plane_distance = self.image_plane_distance.as_ref().map(|b| b.array.len());
+        let len_distortion_model = self.distortion_model.as_ref().map(|b| b.array.len());
+        let len_distortion_coefficients =
+            self.distortion_coefficients.as_ref().map(|b| b.array.len());
         let len = None
             .or(len_image_from_camera)
             .or(len_resolution)
             .or(len_camera_xyz)
             .or(len_image_plane_distance)
+            .or(len_distortion_model)
+            .or(len_distortion_coefficients)
             .unwrap_or(0);
         self.columns(std::iter::repeat_n(1, len))
     }
@@ -540,6 +619,51 @@ impl Pinhole {
         );
         self
     }
+
+    /// The lens distortion model that the `distortion_coefficients` are given for.
+    ///
+    /// Defaults to the Brown-Conrady (`plumb_bob`) model used by OpenCV.
+    #[inline]
+    pub fn with_distortion_model(
+        mut self,
+        distortion_model: impl Into<crate::components::DistortionModel>,
+    ) -> Self {
+        self.distortion_model =
+            try_serialize_field(Self::descriptor_distortion_model(), [distortion_model]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::DistortionModel`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_distortion_model`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_distortion_model(
+        mut self,
+        distortion_model: impl IntoIterator<Item = impl Into<crate::components::DistortionModel>>,
+    ) -> Self {
+        self.distortion_model =
+            try_serialize_field(Self::descriptor_distortion_model(), distortion_model);
+        self
+    }
+
+    /// Lens distortion coefficients, in the order defined by the `distortion_model`.
+    ///
+    /// Points are distorted on the normalized image plane, i.e. after dividing by depth and before applying
+    /// `image_from_camera`. If no coefficients are logged, the camera is an ideal pinhole camera.
+    #[inline]
+    pub fn with_distortion_coefficients(
+        mut self,
+        distortion_coefficients: impl IntoIterator<
+            Item = impl Into<crate::components::DistortionCoefficient>,
+        >,
+    ) -> Self {
+        self.distortion_coefficients = try_serialize_field(
+            Self::descriptor_distortion_coefficients(),
+            distortion_coefficients,
+        );
+        self
+    }
 }
 
 impl ::re_byte_size::SizeBytes for Pinhole {
@@ -549,5 +673,7 @@ impl ::re_byte_size::SizeBytes for Pinhole {
             + self.resolution.heap_size_bytes()
             + self.camera_xyz.heap_size_bytes()
             + self.image_plane_distance.heap_size_bytes()
+            + self.distortion_model.heap_size_bytes()
+            + self.distortion_coefficients.heap_size_bytes()
     }
 }
@@ -21,13 +21,7 @@ impl Points3D {
 
         let file = std::fs::File::open(filepath)
             .with_context(|| format!("Failed to open file {filepath:?}"))?;
-        let mut file = std::io::BufReader::new(file);
-
-        let parser = ply_rs::parser::Parser::<ply_rs::ply::DefaultElement>::new();
-        let ply = {
-            re_tracing::profile_scope!("read_ply");
-            parser.read_ply(&mut file)?
-        };
+        let ply = crate::ply::read_ply(std::io::BufReader::new(file))?;
 
         Ok(from_ply(ply))
     }
@@ -37,12 +31,7 @@ impl Points3D {
     /// If unspecified, he media type will be inferred from the contents.
     pub fn from_file_contents(contents: &[u8]) -> anyhow::Result<Self> {
         re_tracing::profile_function!();
-        let parser = ply_rs::parser::Parser::<ply_rs::ply::DefaultElement>::new();
-        let mut contents = std::io::Cursor::new(contents);
-        let ply = {
-            re_tracing::profile_scope!("read_ply");
-            parser.read_ply(&mut contents)?
-        };
+        let ply = crate::ply::read_ply(std::io::Cursor::new(contents))?;
         Ok(from_ply(ply))
     }
 }
@@ -50,75 +39,11 @@ impl Points3D {
 fn from_ply(ply: ply_rs::ply::Ply<ply_rs::ply::DefaultElement>) -> Points3D {
     re_tracing::profile_function!();
 
-    use std::borrow::Cow;
-
     use linked_hash_map::LinkedHashMap;
     use ply_rs::ply::Property;
 
     use crate::components::{Color, Position3D, Radius, Text};
-
-    fn f32(prop: &Property) -> Option<f32> {
-        match *prop {
-            Property::Short(v) => Some(v as f32),
-            Property::UShort(v) => Some(v as f32),
-            Property::Int(v) => Some(v as f32),
-            Property::UInt(v) => Some(v as f32),
-            Property::Float(v) => Some(v),
-            Property::Double(v) => Some(v as f32),
-            Property::Char(_)
-            | Property::UChar(_)
-            | Property::ListChar(_)
-            | Property::ListUChar(_)
-            | Property::ListShort(_)
-            | Property::ListUShort(_)
-            | Property::ListInt(_)
-            | Property::ListUInt(_)
-            | Property::ListFloat(_)
-            | Property::ListDouble(_) => None,
-        }
-    }
-
-    fn u8(prop: &Property) -> Option<u8> {
-        match *prop {
-            Property::Short(v) => Some(v as u8),
-            Property::UShort(v) => Some(v as u8),
-            Property::Int(v) => Some(v as u8),
-            Property::UInt(v) => Some(v as u8),
-            Property::Float(v) => Some((v * 255.0) as u8),
-            Property::Double(v) => Some((v * 255.0) as u8),
-            Property::Char(v) => Some(v as u8),
-            Property::UChar(v) => Some(v),
-            Property::ListChar(_)
-            | Property::ListUChar(_)
-            | Property::ListShort(_)
-            | Property::ListUShort(_)
-            | Property::ListInt(_)
-            | Property::ListUInt(_)
-            | Property::ListFloat(_)
-            | Property::ListDouble(_) => None,
-        }
-    }
-
-    fn string(prop: &Property) -> Option<Cow<'_, str>> {
-        match prop {
-            Property::ListUChar(chars) => Some(String::from_utf8_lossy(chars)),
-            Property::ListChar(_)
-            | Property::ListShort(_)
-            | Property::ListUShort(_)
-            | Property::ListInt(_)
-            | Property::ListUInt(_)
-            | Property::ListFloat(_)
-            | Property::ListDouble(_)
-            | Property::Char(_)
-            | Property::UChar(_)
-            | Property::Short(_)
-            | Property::UShort(_)
-            | Property::Int(_)
-            | Property::UInt(_)
-            | Property::Float(_)
-            | Property::Double(_) => None,
-        }
-    }
+    use crate::ply::{f32, string, u8};
 
     struct Vertex {
         position: Position3D,
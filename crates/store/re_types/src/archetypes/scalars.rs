@@ -90,6 +90,13 @@ use ::re_types_core::{DeserializationError, DeserializationResult};
 pub struct Scalars {
     /// The scalar values to log.
     pub scalars: Option<SerializedComponentBatch>,
+
+    /// The unit of each scalar value, e.g. `m/s` or `°C`.
+    ///
+    /// Shown on the plot axes and in tooltips.
+    /// If a single unit is logged, it applies to all scalars.
+    /// Expected to be unchanging over time.
+    pub units: Option<SerializedComponentBatch>,
 }
 
 impl Scalars {
@@ -104,6 +111,18 @@ impl Scalars {
             component_type: Some("rerun.components.Scalar".into()),
         }
     }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::units`].
+    ///
+    /// The corresponding component is [`crate::components::Unit`].
+    #[inline]
+    pub fn descriptor_units() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Scalars".into()),
+            component: "Scalars:units".into(),
+            component_type: Some("rerun.components.Unit".into()),
+        }
+    }
 }
 
 static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
@@ -112,15 +131,15 @@ static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
 static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
     std::sync::LazyLock::new(|| []);
 
-static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
-    std::sync::LazyLock::new(|| []);
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [Scalars::descriptor_units()]);
 
-static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
-    std::sync::LazyLock::new(|| [Scalars::descriptor_scalars()]);
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| [Scalars::descriptor_scalars(), Scalars::descriptor_units()]);
 
 impl Scalars {
-    /// The total number of components in the archetype: 1 required, 0 recommended, 0 optional
-    pub const NUM_COMPONENTS: usize = 1usize;
+    /// The total number of components in the archetype: 1 required, 0 recommended, 1 optional
+    pub const NUM_COMPONENTS: usize = 2usize;
 }
 
 impl ::re_types_core::Archetype for Scalars {
@@ -164,7 +183,10 @@ impl ::re_types_core::Archetype for Scalars {
         let scalars = arrays_by_descr
             .get(&Self::descriptor_scalars())
             .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_scalars()));
-        Ok(Self { scalars })
+        let units = arrays_by_descr
+            .get(&Self::descriptor_units())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_units()));
+        Ok(Self { scalars, units })
     }
 }
 
@@ -172,7 +194,10 @@ impl ::re_types_core::AsComponents for Scalars {
     #[inline]
     fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
         use ::re_types_core::Archetype as _;
-        std::iter::once(self.scalars.clone()).flatten().collect()
+        [self.scalars.clone(), self.units.clone()]
+            .into_iter()
+            .flatten()
+            .collect()
     }
 }
 
@@ -184,6 +209,7 @@ impl Scalars {
     pub fn new(scalars: impl IntoIterator<Item = impl Into<crate::components::Scalar>>) -> Self {
         Self {
             scalars: try_serialize_field(Self::descriptor_scalars(), scalars),
+            units: None,
         }
     }
 
@@ -202,6 +228,10 @@ impl Scalars {
                 crate::components::Scalar::arrow_empty(),
                 Self::descriptor_scalars(),
             )),
+            units: Some(SerializedComponentBatch::new(
+                crate::components::Unit::arrow_empty(),
+                Self::descriptor_units(),
+            )),
         }
     }
 
@@ -223,10 +253,14 @@ impl Scalars {
     where
         I: IntoIterator<Item = usize> + Clone,
     {
-        let columns = [self
-            .scalars
-            .map(|scalars| scalars.partitioned(_lengths.clone()))
-            .transpose()?];
+        let columns = [
+            self.scalars
+                .map(|scalars| scalars.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.units
+                .map(|units| units.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
         Ok(columns.into_iter().flatten())
     }
 
@@ -239,7 +273,8 @@ impl Scalars {
         self,
     ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
         let len_scalars = self.scalars.as_ref().map(|b| b.array.len());
-        let len = None.or(len_scalars).unwrap_or(0);
+        let len_units = self.units.as_ref().map(|b| b.array.len());
+        let len = None.or(len_scalars).or(len_units).unwrap_or(0);
         self.columns(std::iter::repeat_n(1, len))
     }
 
@@ -252,11 +287,25 @@ impl Scalars {
         self.scalars = try_serialize_field(Self::descriptor_scalars(), scalars);
         self
     }
+
+    /// The unit of each scalar value, e.g. `m/s` or `°C`.
+    ///
+    /// Shown on the plot axes and in tooltips.
+    /// If a single unit is logged, it applies to all scalars.
+    /// Expected to be unchanging over time.
+    #[inline]
+    pub fn with_units(
+        mut self,
+        units: impl IntoIterator<Item = impl Into<crate::components::Unit>>,
+    ) -> Self {
+        self.units = try_serialize_field(Self::descriptor_units(), units);
+        self
+    }
 }
 
 impl ::re_byte_size::SizeBytes for Scalars {
     #[inline]
     fn heap_size_bytes(&self) -> u64 {
-        self.scalars.heap_size_bytes()
+        self.scalars.heap_size_bytes() + self.units.heap_size_bytes()
     }
 }
@@ -343,6 +343,16 @@ pub struct Transform3D {
     /// Rotation via quaternion.
     pub quaternion: Option<SerializedComponentBatch>,
 
+    /// Rotation via Euler angles, in radians.
+    ///
+    /// The axes the angles are applied about are given by [`components::EulerConvention`][crate::components::EulerConvention].
+    pub rotation_euler: Option<SerializedComponentBatch>,
+
+    /// The axis order used to interpret [`components::RotationEuler`][crate::components::RotationEuler].
+    ///
+    /// Defaults to `Zyx`, i.e. yaw, pitch, roll.
+    pub euler_convention: Option<SerializedComponentBatch>,
+
     /// Scaling factor.
     pub scale: Option<SerializedComponentBatch>,
 
@@ -396,6 +406,30 @@ impl Transform3D {
         }
     }
 
+    /// Returns the [`ComponentDescriptor`] for [`Self::rotation_euler`].
+    ///
+    /// The corresponding component is [`crate::components::RotationEuler`].
+    #[inline]
+    pub fn descriptor_rotation_euler() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Transform3D".into()),
+            component: "Transform3D:rotation_euler".into(),
+            component_type: Some("rerun.components.RotationEuler".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::euler_convention`].
+    ///
+    /// The corresponding component is [`crate::components::EulerConvention`].
+    #[inline]
+    pub fn descriptor_euler_convention() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Transform3D".into()),
+            component: "Transform3D:euler_convention".into(),
+            component_type: Some("rerun.components.EulerConvention".into()),
+        }
+    }
+
     /// Returns the [`ComponentDescriptor`] for [`Self::scale`].
     ///
     /// The corresponding component is [`crate::components::Scale3D`].
@@ -451,12 +485,14 @@ static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
 static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
     std::sync::LazyLock::new(|| []);
 
-static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 9usize]> =
     std::sync::LazyLock::new(|| {
         [
             Transform3D::descriptor_translation(),
             Transform3D::descriptor_rotation_axis_angle(),
             Transform3D::descriptor_quaternion(),
+            Transform3D::descriptor_rotation_euler(),
+            Transform3D::descriptor_euler_convention(),
             Transform3D::descriptor_scale(),
             Transform3D::descriptor_mat3x3(),
             Transform3D::descriptor_relation(),
@@ -464,12 +500,14 @@ static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
         ]
     });
 
-static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 9usize]> =
     std::sync::LazyLock::new(|| {
         [
             Transform3D::descriptor_translation(),
             Transform3D::descriptor_rotation_axis_angle(),
             Transform3D::descriptor_quaternion(),
+            Transform3D::descriptor_rotation_euler(),
+            Transform3D::descriptor_euler_convention(),
             Transform3D::descriptor_scale(),
             Transform3D::descriptor_mat3x3(),
             Transform3D::descriptor_relation(),
@@ -478,8 +516,8 @@ static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
     });
 
 impl Transform3D {
-    /// The total number of components in the archetype: 0 required, 0 recommended, 7 optional
-    pub const NUM_COMPONENTS: usize = 7usize;
+    /// The total number of components in the archetype: 0 required, 0 recommended, 9 optional
+    pub const NUM_COMPONENTS: usize = 9usize;
 }
 
 impl ::re_types_core::Archetype for Transform3D {
@@ -535,6 +573,16 @@ impl ::re_types_core::Archetype for Transform3D {
             .map(|array| {
                 SerializedComponentBatch::new(array.clone(), Self::descriptor_quaternion())
             });
+        let rotation_euler = arrays_by_descr
+            .get(&Self::descriptor_rotation_euler())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_rotation_euler())
+            });
+        let euler_convention = arrays_by_descr
+            .get(&Self::descriptor_euler_convention())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_euler_convention())
+            });
         let scale = arrays_by_descr
             .get(&Self::descriptor_scale())
             .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_scale()));
@@ -553,6 +601,8 @@ impl ::re_types_core::Archetype for Transform3D {
             translation,
             rotation_axis_angle,
             quaternion,
+            rotation_euler,
+            euler_convention,
             scale,
             mat3x3,
             relation,
@@ -569,6 +619,8 @@ impl ::re_types_core::AsComponents for Transform3D {
             self.translation.clone(),
             self.rotation_axis_angle.clone(),
             self.quaternion.clone(),
+            self.rotation_euler.clone(),
+            self.euler_convention.clone(),
             self.scale.clone(),
             self.mat3x3.clone(),
             self.relation.clone(),
@@ -606,6 +658,14 @@ impl Transform3D {
                 crate::components::RotationQuat::arrow_empty(),
                 Self::descriptor_quaternion(),
             )),
+            rotation_euler: Some(SerializedComponentBatch::new(
+                crate::components::RotationEuler::arrow_empty(),
+                Self::descriptor_rotation_euler(),
+            )),
+            euler_convention: Some(SerializedComponentBatch::new(
+                crate::components::EulerConvention::arrow_empty(),
+                Self::descriptor_euler_convention(),
+            )),
             scale: Some(SerializedComponentBatch::new(
                 crate::components::Scale3D::arrow_empty(),
                 Self::descriptor_scale(),
@@ -653,6 +713,12 @@ impl Transform3D {
             self.quaternion
                 .map(|quaternion| quaternion.partitioned(_lengths.clone()))
                 .transpose()?,
+            self.rotation_euler
+                .map(|rotation_euler| rotation_euler.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.euler_convention
+                .map(|euler_convention| euler_convention.partitioned(_lengths.clone()))
+                .transpose()?,
             self.scale
                 .map(|scale| scale.partitioned(_lengths.clone()))
                 .transpose()?,
@@ -680,6 +746,8 @@ impl Transform3D {
         let len_translation = self.translation.as_ref().map(|b| b.array.len());
         let len_rotation_axis_angle = self.rotation_axis_angle.as_ref().map(|b| b.array.len());
         let len_quaternion = self.quaternion.as_ref().map(|b| b.array.len());
+        let len_rotation_euler = self.rotation_euler.as_ref().map(|b| b.array.len());
+        let len_euler_convention = self.euler_convention.as_ref().map(|b| b.array.len());
         let len_scale = self.scale.as_ref().map(|b| b.array.len());
         let len_mat3x3 = self.mat3x3.as_ref().map(|b| b.array.len());
         let len_relation = self.relation.as_ref().map(|b| b.array.len());
@@ -688,6 +756,8 @@ impl Transform3D {
             .or(len_translation)
             .or(len_rotation_axis_angle)
             .or(len_quaternion)
+            .or(len_rotation_euler)
+            .or(len_euler_convention)
             .or(len_scale)
             .or(len_mat3x3)
             .or(len_relation)
@@ -769,6 +839,60 @@ impl Transform3D {
         self
     }
 
+    /// Rotation via Euler angles, in radians.
+    ///
+    /// The axes the angles are applied about are given by [`components::EulerConvention`][crate::components::EulerConvention].
+    #[inline]
+    pub fn with_rotation_euler(
+        mut self,
+        rotation_euler: impl Into<crate::components::RotationEuler>,
+    ) -> Self {
+        self.rotation_euler =
+            try_serialize_field(Self::descriptor_rotation_euler(), [rotation_euler]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::RotationEuler`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_rotation_euler`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_rotation_euler(
+        mut self,
+        rotation_euler: impl IntoIterator<Item = impl Into<crate::components::RotationEuler>>,
+    ) -> Self {
+        self.rotation_euler =
+            try_serialize_field(Self::descriptor_rotation_euler(), rotation_euler);
+        self
+    }
+
+    /// The axis order used to interpret [`components::RotationEuler`][crate::components::RotationEuler].
+    ///
+    /// Defaults to `Zyx`, i.e. yaw, pitch, roll.
+    #[inline]
+    pub fn with_euler_convention(
+        mut self,
+        euler_convention: impl Into<crate::components::EulerConvention>,
+    ) -> Self {
+        self.euler_convention =
+            try_serialize_field(Self::descriptor_euler_convention(), [euler_convention]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::EulerConvention`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_euler_convention`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_euler_convention(
+        mut self,
+        euler_convention: impl IntoIterator<Item = impl Into<crate::components::EulerConvention>>,
+    ) -> Self {
+        self.euler_convention =
+            try_serialize_field(Self::descriptor_euler_convention(), euler_convention);
+        self
+    }
+
     /// Scaling factor.
     #[inline]
     pub fn with_scale(mut self, scale: impl Into<crate::components::Scale3D>) -> Self {
@@ -865,6 +989,8 @@ impl ::re_byte_size::SizeBytes for Transform3D {
         self.translation.heap_size_bytes()
             + self.rotation_axis_angle.heap_size_bytes()
             + self.quaternion.heap_size_bytes()
+            + self.rotation_euler.heap_size_bytes()
+            + self.euler_convention.heap_size_bytes()
             + self.scale.heap_size_bytes()
             + self.mat3x3.heap_size_bytes()
             + self.relation.heap_size_bytes()
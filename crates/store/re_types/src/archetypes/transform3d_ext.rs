@@ -14,6 +14,8 @@ impl Transform3D {
         translation: None,
         rotation_axis_angle: None,
         quaternion: None,
+        rotation_euler: None,
+        euler_convention: None,
         scale: None,
         mat3x3: None,
         relation: None,
@@ -27,6 +27,9 @@ use ::re_types_core::{DeserializationError, DeserializationResult};
 /// All components except `sample` are typically logged statically once per entity.
 /// `sample` is then logged repeatedly for each frame on the timeline.
 ///
+/// Arbitrary per-frame metadata (e.g. exposure, gain or sensor header fields) can be logged alongside `sample`,
+/// at the same entity and in the same rows, which keeps it associated with the individual frames.
+///
 /// TODO(#10422): [`archetypes::VideoFrameReference`][crate::archetypes::VideoFrameReference] does not yet work with [`archetypes::VideoStream`][crate::archetypes::VideoStream].
 ///
 /// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
@@ -0,0 +1,358 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/waveform.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: A block of evenly spaced samples for one or more channels, e.g. for high-rate IMU, audio or EMG data.
+///
+/// Instead of logging one [`archetypes::Scalars`][crate::archetypes::Scalars] row per sample, a whole block of samples is logged at once:
+/// the current timeline value is the time of the first sample in the block, and all following samples
+/// are spaced according to the sample rate.
+/// This drastically reduces the number of rows for kHz-rate signals.
+///
+/// For multi-channel data, samples are interleaved, i.e. the first sample of every channel comes first,
+/// then the second sample of every channel, etc.
+/// The number of channels is expected to be the same over time.
+///
+/// Like for [`archetypes::Scalars`][crate::archetypes::Scalars], the style of each channel can be configured by logging [`archetypes::SeriesLines`][crate::archetypes::SeriesLines]
+/// to the same entity path.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Waveform {
+    /// The interleaved samples of all channels in this block.
+    pub samples: Option<SerializedComponentBatch>,
+
+    /// The rate at which the samples were acquired.
+    ///
+    /// In Hz for temporal timelines, and per tick for sequence timelines.
+    /// Expected to be unchanging over time and can therefore be logged statically.
+    pub sample_rate: Option<SerializedComponentBatch>,
+
+    /// The number of channels the samples are interleaved across.
+    ///
+    /// Defaults to a single channel.
+    /// Expected to be unchanging over time and can therefore be logged statically.
+    pub channel_count: Option<SerializedComponentBatch>,
+}
+
+impl Waveform {
+    /// Returns the [`ComponentDescriptor`] for [`Self::samples`].
+    ///
+    /// The corresponding component is [`crate::components::Scalar`].
+    #[inline]
+    pub fn descriptor_samples() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Waveform".into()),
+            component: "Waveform:samples".into(),
+            component_type: Some("rerun.components.Scalar".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::sample_rate`].
+    ///
+    /// The corresponding component is [`crate::components::SampleRate`].
+    #[inline]
+    pub fn descriptor_sample_rate() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Waveform".into()),
+            component: "Waveform:sample_rate".into(),
+            component_type: Some("rerun.components.SampleRate".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::channel_count`].
+    ///
+    /// The corresponding component is [`crate::components::ChannelCount`].
+    #[inline]
+    pub fn descriptor_channel_count() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Waveform".into()),
+            component: "Waveform:channel_count".into(),
+            component_type: Some("rerun.components.ChannelCount".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Waveform::descriptor_samples(),
+            Waveform::descriptor_sample_rate(),
+        ]
+    });
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [Waveform::descriptor_channel_count()]);
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 3usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Waveform::descriptor_samples(),
+            Waveform::descriptor_sample_rate(),
+            Waveform::descriptor_channel_count(),
+        ]
+    });
+
+impl Waveform {
+    /// The total number of components in the archetype: 2 required, 0 recommended, 1 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
+}
+
+impl ::re_types_core::Archetype for Waveform {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.Waveform".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Waveform"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let samples = arrays_by_descr
+            .get(&Self::descriptor_samples())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_samples()));
+        let sample_rate = arrays_by_descr
+            .get(&Self::descriptor_sample_rate())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_sample_rate())
+            });
+        let channel_count = arrays_by_descr
+            .get(&Self::descriptor_channel_count())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_channel_count())
+            });
+        Ok(Self {
+            samples,
+            sample_rate,
+            channel_count,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for Waveform {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.samples.clone(),
+            self.sample_rate.clone(),
+            self.channel_count.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for Waveform {}
+
+impl Waveform {
+    /// Create a new `Waveform`.
+    #[inline]
+    pub fn new(
+        samples: impl IntoIterator<Item = impl Into<crate::components::Scalar>>,
+        sample_rate: impl Into<crate::components::SampleRate>,
+    ) -> Self {
+        Self {
+            samples: try_serialize_field(Self::descriptor_samples(), samples),
+            sample_rate: try_serialize_field(Self::descriptor_sample_rate(), [sample_rate]),
+            channel_count: None,
+        }
+    }
+
+    /// Update only some specific fields of a `Waveform`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `Waveform`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            samples: Some(SerializedComponentBatch::new(
+                crate::components::Scalar::arrow_empty(),
+                Self::descriptor_samples(),
+            )),
+            sample_rate: Some(SerializedComponentBatch::new(
+                crate::components::SampleRate::arrow_empty(),
+                Self::descriptor_sample_rate(),
+            )),
+            channel_count: Some(SerializedComponentBatch::new(
+                crate::components::ChannelCount::arrow_empty(),
+                Self::descriptor_channel_count(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.samples
+                .map(|samples| samples.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.sample_rate
+                .map(|sample_rate| sample_rate.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.channel_count
+                .map(|channel_count| channel_count.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns.into_iter().flatten())
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_samples = self.samples.as_ref().map(|b| b.array.len());
+        let len_sample_rate = self.sample_rate.as_ref().map(|b| b.array.len());
+        let len_channel_count = self.channel_count.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_samples)
+            .or(len_sample_rate)
+            .or(len_channel_count)
+            .unwrap_or(0);
+        self.columns(std::iter::repeat_n(1, len))
+    }
+
+    /// The interleaved samples of all channels in this block.
+    #[inline]
+    pub fn with_samples(
+        mut self,
+        samples: impl IntoIterator<Item = impl Into<crate::components::Scalar>>,
+    ) -> Self {
+        self.samples = try_serialize_field(Self::descriptor_samples(), samples);
+        self
+    }
+
+    /// The rate at which the samples were acquired.
+    ///
+    /// In Hz for temporal timelines, and per tick for sequence timelines.
+    /// Expected to be unchanging over time and can therefore be logged statically.
+    #[inline]
+    pub fn with_sample_rate(
+        mut self,
+        sample_rate: impl Into<crate::components::SampleRate>,
+    ) -> Self {
+        self.sample_rate = try_serialize_field(Self::descriptor_sample_rate(), [sample_rate]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::SampleRate`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_sample_rate`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_sample_rate(
+        mut self,
+        sample_rate: impl IntoIterator<Item = impl Into<crate::components::SampleRate>>,
+    ) -> Self {
+        self.sample_rate = try_serialize_field(Self::descriptor_sample_rate(), sample_rate);
+        self
+    }
+
+    /// The number of channels the samples are interleaved across.
+    ///
+    /// Defaults to a single channel.
+    /// Expected to be unchanging over time and can therefore be logged statically.
+    #[inline]
+    pub fn with_channel_count(
+        mut self,
+        channel_count: impl Into<crate::components::ChannelCount>,
+    ) -> Self {
+        self.channel_count = try_serialize_field(Self::descriptor_channel_count(), [channel_count]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::ChannelCount`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_channel_count`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_channel_count(
+        mut self,
+        channel_count: impl IntoIterator<Item = impl Into<crate::components::ChannelCount>>,
+    ) -> Self {
+        self.channel_count = try_serialize_field(Self::descriptor_channel_count(), channel_count);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for Waveform {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.samples.heap_size_bytes()
+            + self.sample_rate.heap_size_bytes()
+            + self.channel_count.heap_size_bytes()
+    }
+}
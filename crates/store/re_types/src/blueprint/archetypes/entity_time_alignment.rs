@@ -0,0 +1,237 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/entity_time_alignment.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Aligns the data of an entity with the timeline, e.g. for sensors that were recorded with a skewed clock.
+///
+/// An entity's data logged at time `t` is shown at time `t * playback_rate + time_offset` on the timeline.
+///
+/// TODO(#6541): Fields of this archetype currently only have an effect when logged in the blueprint store.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct EntityTimeAlignment {
+    /// A constant offset added to the times of the entity's data.
+    ///
+    /// This property is propagated down the entity hierarchy until another child entity
+    /// sets `time_offset` to a different value at which point propagation continues with that value instead.
+    ///
+    /// Defaults to parent's `time_offset` value or 0 if there is no parent.
+    pub time_offset: Option<SerializedComponentBatch>,
+
+    /// How fast the clock of the entity's data runs relative to the timeline.
+    ///
+    /// This property is propagated down the entity hierarchy until another child entity
+    /// sets `playback_rate` to a different value at which point propagation continues with that value instead.
+    ///
+    /// Defaults to parent's `playback_rate` value or 1 if there is no parent.
+    pub playback_rate: Option<SerializedComponentBatch>,
+}
+
+impl EntityTimeAlignment {
+    /// Returns the [`ComponentDescriptor`] for [`Self::time_offset`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::TimeOffset`].
+    #[inline]
+    pub fn descriptor_time_offset() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.EntityTimeAlignment".into()),
+            component: "EntityTimeAlignment:time_offset".into(),
+            component_type: Some("rerun.blueprint.components.TimeOffset".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::playback_rate`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::PlaybackRate`].
+    #[inline]
+    pub fn descriptor_playback_rate() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.EntityTimeAlignment".into()),
+            component: "EntityTimeAlignment:playback_rate".into(),
+            component_type: Some("rerun.blueprint.components.PlaybackRate".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            EntityTimeAlignment::descriptor_time_offset(),
+            EntityTimeAlignment::descriptor_playback_rate(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            EntityTimeAlignment::descriptor_time_offset(),
+            EntityTimeAlignment::descriptor_playback_rate(),
+        ]
+    });
+
+impl EntityTimeAlignment {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 2usize;
+}
+
+impl ::re_types_core::Archetype for EntityTimeAlignment {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.EntityTimeAlignment".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Entity time alignment"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let time_offset = arrays_by_descr
+            .get(&Self::descriptor_time_offset())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_time_offset())
+            });
+        let playback_rate = arrays_by_descr
+            .get(&Self::descriptor_playback_rate())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_playback_rate())
+            });
+        Ok(Self {
+            time_offset,
+            playback_rate,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for EntityTimeAlignment {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [self.time_offset.clone(), self.playback_rate.clone()]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for EntityTimeAlignment {}
+
+impl EntityTimeAlignment {
+    /// Create a new `EntityTimeAlignment`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            time_offset: None,
+            playback_rate: None,
+        }
+    }
+
+    /// Update only some specific fields of a `EntityTimeAlignment`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `EntityTimeAlignment`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            time_offset: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TimeOffset::arrow_empty(),
+                Self::descriptor_time_offset(),
+            )),
+            playback_rate: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::PlaybackRate::arrow_empty(),
+                Self::descriptor_playback_rate(),
+            )),
+        }
+    }
+
+    /// A constant offset added to the times of the entity's data.
+    ///
+    /// This property is propagated down the entity hierarchy until another child entity
+    /// sets `time_offset` to a different value at which point propagation continues with that value instead.
+    ///
+    /// Defaults to parent's `time_offset` value or 0 if there is no parent.
+    #[inline]
+    pub fn with_time_offset(
+        mut self,
+        time_offset: impl Into<crate::blueprint::components::TimeOffset>,
+    ) -> Self {
+        self.time_offset = try_serialize_field(Self::descriptor_time_offset(), [time_offset]);
+        self
+    }
+
+    /// How fast the clock of the entity's data runs relative to the timeline.
+    ///
+    /// This property is propagated down the entity hierarchy until another child entity
+    /// sets `playback_rate` to a different value at which point propagation continues with that value instead.
+    ///
+    /// Defaults to parent's `playback_rate` value or 1 if there is no parent.
+    #[inline]
+    pub fn with_playback_rate(
+        mut self,
+        playback_rate: impl Into<crate::blueprint::components::PlaybackRate>,
+    ) -> Self {
+        self.playback_rate = try_serialize_field(Self::descriptor_playback_rate(), [playback_rate]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for EntityTimeAlignment {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.time_offset.heap_size_bytes() + self.playback_rate.heap_size_bytes()
+    }
+}
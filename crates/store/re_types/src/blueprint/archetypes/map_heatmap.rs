@@ -0,0 +1,257 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/map_heatmap.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configuration of the density heatmap layer of the map view.
+///
+/// The heatmap shows where geo points are concentrated,
+/// which is easier to read than the individual points when there are many of them.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct MapHeatmap {
+    /// Whether the heatmap is visible.
+    ///
+    /// Defaults to false.
+    pub visible: Option<SerializedComponentBatch>,
+
+    /// Colormap used to map the density of points to colors.
+    ///
+    /// Defaults to inferno.
+    pub colormap: Option<SerializedComponentBatch>,
+
+    /// Opacity of the heatmap on top of the background map.
+    ///
+    /// Defaults to 0.6.
+    pub opacity: Option<SerializedComponentBatch>,
+}
+
+impl MapHeatmap {
+    /// Returns the [`ComponentDescriptor`] for [`Self::visible`].
+    ///
+    /// The corresponding component is [`crate::components::Visible`].
+    #[inline]
+    pub fn descriptor_visible() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.MapHeatmap".into()),
+            component: "MapHeatmap:visible".into(),
+            component_type: Some("rerun.components.Visible".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::colormap`].
+    ///
+    /// The corresponding component is [`crate::components::Colormap`].
+    #[inline]
+    pub fn descriptor_colormap() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.MapHeatmap".into()),
+            component: "MapHeatmap:colormap".into(),
+            component_type: Some("rerun.components.Colormap".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::opacity`].
+    ///
+    /// The corresponding component is [`crate::components::Opacity`].
+    #[inline]
+    pub fn descriptor_opacity() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.MapHeatmap".into()),
+            component: "MapHeatmap:opacity".into(),
+            component_type: Some("rerun.components.Opacity".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 3usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            MapHeatmap::descriptor_visible(),
+            MapHeatmap::descriptor_colormap(),
+            MapHeatmap::descriptor_opacity(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 3usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            MapHeatmap::descriptor_visible(),
+            MapHeatmap::descriptor_colormap(),
+            MapHeatmap::descriptor_opacity(),
+        ]
+    });
+
+impl MapHeatmap {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 3 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
+}
+
+impl ::re_types_core::Archetype for MapHeatmap {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.MapHeatmap".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Map heatmap"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let visible = arrays_by_descr
+            .get(&Self::descriptor_visible())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_visible()));
+        let colormap = arrays_by_descr
+            .get(&Self::descriptor_colormap())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_colormap()));
+        let opacity = arrays_by_descr
+            .get(&Self::descriptor_opacity())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_opacity()));
+        Ok(Self {
+            visible,
+            colormap,
+            opacity,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for MapHeatmap {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.visible.clone(),
+            self.colormap.clone(),
+            self.opacity.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for MapHeatmap {}
+
+impl MapHeatmap {
+    /// Create a new `MapHeatmap`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            visible: None,
+            colormap: None,
+            opacity: None,
+        }
+    }
+
+    /// Update only some specific fields of a `MapHeatmap`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `MapHeatmap`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            visible: Some(SerializedComponentBatch::new(
+                crate::components::Visible::arrow_empty(),
+                Self::descriptor_visible(),
+            )),
+            colormap: Some(SerializedComponentBatch::new(
+                crate::components::Colormap::arrow_empty(),
+                Self::descriptor_colormap(),
+            )),
+            opacity: Some(SerializedComponentBatch::new(
+                crate::components::Opacity::arrow_empty(),
+                Self::descriptor_opacity(),
+            )),
+        }
+    }
+
+    /// Whether the heatmap is visible.
+    ///
+    /// Defaults to false.
+    #[inline]
+    pub fn with_visible(mut self, visible: impl Into<crate::components::Visible>) -> Self {
+        self.visible = try_serialize_field(Self::descriptor_visible(), [visible]);
+        self
+    }
+
+    /// Colormap used to map the density of points to colors.
+    ///
+    /// Defaults to inferno.
+    #[inline]
+    pub fn with_colormap(mut self, colormap: impl Into<crate::components::Colormap>) -> Self {
+        self.colormap = try_serialize_field(Self::descriptor_colormap(), [colormap]);
+        self
+    }
+
+    /// Opacity of the heatmap on top of the background map.
+    ///
+    /// Defaults to 0.6.
+    #[inline]
+    pub fn with_opacity(mut self, opacity: impl Into<crate::components::Opacity>) -> Self {
+        self.opacity = try_serialize_field(Self::descriptor_opacity(), [opacity]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for MapHeatmap {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.visible.heap_size_bytes()
+            + self.colormap.heap_size_bytes()
+            + self.opacity.heap_size_bytes()
+    }
+}
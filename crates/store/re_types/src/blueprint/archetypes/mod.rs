@@ -4,6 +4,7 @@ mod background;
 mod container_blueprint;
 mod dataframe_query;
 mod entity_behavior;
+mod entity_time_alignment;
 mod eye_controls3d;
 mod force_center;
 mod force_collision_radius;
@@ -12,6 +13,7 @@ mod force_many_body;
 mod force_position;
 mod line_grid3d;
 mod map_background;
+mod map_heatmap;
 mod map_zoom;
 mod near_clip_plane;
 mod panel_blueprint;
@@ -32,6 +34,7 @@ pub use self::background::Background;
 pub use self::container_blueprint::ContainerBlueprint;
 pub use self::dataframe_query::DataframeQuery;
 pub use self::entity_behavior::EntityBehavior;
+pub use self::entity_time_alignment::EntityTimeAlignment;
 pub use self::eye_controls3d::EyeControls3D;
 pub use self::force_center::ForceCenter;
 pub use self::force_collision_radius::ForceCollisionRadius;
@@ -40,6 +43,7 @@ pub use self::force_many_body::ForceManyBody;
 pub use self::force_position::ForcePosition;
 pub use self::line_grid3d::LineGrid3D;
 pub use self::map_background::MapBackground;
+pub use self::map_heatmap::MapHeatmap;
 pub use self::map_zoom::MapZoom;
 pub use self::near_clip_plane::NearClipPlane;
 pub use self::panel_blueprint::PanelBlueprint;
@@ -36,6 +36,11 @@ pub enum MapProvider {
 
     /// Mapbox Satellite is a satellite map designed by Mapbox.
     MapboxSatellite = 4,
+
+    /// Tiles from the URL template configured in the viewer settings.
+    ///
+    /// Use this for self-hosted tile servers, e.g. one serving offline MBTiles.
+    Custom = 5,
 }
 
 impl ::re_types_core::Component for MapProvider {
@@ -114,6 +119,7 @@ impl ::re_types_core::Loggable for MapProvider {
                 Some(2) => Ok(Some(Self::MapboxStreets)),
                 Some(3) => Ok(Some(Self::MapboxDark)),
                 Some(4) => Ok(Some(Self::MapboxSatellite)),
+                Some(5) => Ok(Some(Self::Custom)),
                 None => Ok(None),
                 Some(invalid) => Err(DeserializationError::missing_union_arm(
                     Self::arrow_datatype(),
@@ -133,6 +139,7 @@ impl std::fmt::Display for MapProvider {
             Self::MapboxStreets => write!(f, "MapboxStreets"),
             Self::MapboxDark => write!(f, "MapboxDark"),
             Self::MapboxSatellite => write!(f, "MapboxSatellite"),
+            Self::Custom => write!(f, "Custom"),
         }
     }
 }
@@ -145,6 +152,7 @@ impl ::re_types_core::reflection::Enum for MapProvider {
             Self::MapboxStreets,
             Self::MapboxDark,
             Self::MapboxSatellite,
+            Self::Custom,
         ]
     }
 
@@ -155,6 +163,9 @@ impl ::re_types_core::reflection::Enum for MapProvider {
             Self::MapboxStreets => "Mapbox Streets is a minimalistic map designed by Mapbox.",
             Self::MapboxDark => "Mapbox Dark is a dark-themed map designed by Mapbox.",
             Self::MapboxSatellite => "Mapbox Satellite is a satellite map designed by Mapbox.",
+            Self::Custom => {
+                "Tiles from the URL template configured in the viewer settings.\n\nUse this for self-hosted tile servers, e.g. one serving offline MBTiles."
+            }
         }
     }
 }
@@ -33,12 +33,16 @@ mod near_clip_plane;
 mod near_clip_plane_ext;
 mod panel_state;
 mod panel_state_ext;
+mod playback_rate;
+mod playback_rate_ext;
 mod query_expression;
 mod root_container;
 mod row_share;
 mod selected_columns;
 mod tensor_dimension_index_slider;
 mod tensor_dimension_index_slider_ext;
+mod time_offset;
+mod time_offset_ext;
 mod timeline_name;
 mod timeline_name_ext;
 mod view_class;
@@ -79,11 +83,13 @@ pub use self::lock_range_during_zoom::LockRangeDuringZoom;
 pub use self::map_provider::MapProvider;
 pub use self::near_clip_plane::NearClipPlane;
 pub use self::panel_state::PanelState;
+pub use self::playback_rate::PlaybackRate;
 pub use self::query_expression::QueryExpression;
 pub use self::root_container::RootContainer;
 pub use self::row_share::RowShare;
 pub use self::selected_columns::SelectedColumns;
 pub use self::tensor_dimension_index_slider::TensorDimensionIndexSlider;
+pub use self::time_offset::TimeOffset;
 pub use self::timeline_name::TimelineName;
 pub use self::view_class::ViewClass;
 pub use self::view_fit::ViewFit;
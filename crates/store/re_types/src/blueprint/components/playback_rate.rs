@@ -0,0 +1,118 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/playback_rate.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Component**: How fast the clock of an entity's data runs relative to the timeline.
+///
+/// A rate of 1 means both clocks run at the same speed.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Copy, PartialEq, PartialOrd, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(transparent)]
+pub struct PlaybackRate(pub crate::datatypes::Float64);
+
+impl ::re_types_core::Component for PlaybackRate {
+    #[inline]
+    fn name() -> ComponentType {
+        "rerun.blueprint.components.PlaybackRate".into()
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(PlaybackRate);
+
+impl ::re_types_core::Loggable for PlaybackRate {
+    #[inline]
+    fn arrow_datatype() -> arrow::datatypes::DataType {
+        crate::datatypes::Float64::arrow_datatype()
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<arrow::array::ArrayRef>
+    where
+        Self: Clone + 'a,
+    {
+        crate::datatypes::Float64::to_arrow_opt(data.into_iter().map(|datum| {
+            datum.map(|datum| match datum.into() {
+                ::std::borrow::Cow::Borrowed(datum) => ::std::borrow::Cow::Borrowed(&datum.0),
+                ::std::borrow::Cow::Owned(datum) => ::std::borrow::Cow::Owned(datum.0),
+            })
+        }))
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        crate::datatypes::Float64::from_arrow_opt(arrow_data)
+            .map(|v| v.into_iter().map(|v| v.map(Self)).collect())
+    }
+
+    #[inline]
+    fn from_arrow(arrow_data: &dyn arrow::array::Array) -> DeserializationResult<Vec<Self>>
+    where
+        Self: Sized,
+    {
+        crate::datatypes::Float64::from_arrow(arrow_data).map(bytemuck::cast_vec)
+    }
+}
+
+impl<T: Into<crate::datatypes::Float64>> From<T> for PlaybackRate {
+    fn from(v: T) -> Self {
+        Self(v.into())
+    }
+}
+
+impl std::borrow::Borrow<crate::datatypes::Float64> for PlaybackRate {
+    #[inline]
+    fn borrow(&self) -> &crate::datatypes::Float64 {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for PlaybackRate {
+    type Target = crate::datatypes::Float64;
+
+    #[inline]
+    fn deref(&self) -> &crate::datatypes::Float64 {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for PlaybackRate {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut crate::datatypes::Float64 {
+        &mut self.0
+    }
+}
+
+impl ::re_byte_size::SizeBytes for PlaybackRate {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.0.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <crate::datatypes::Float64>::is_pod()
+    }
+}
@@ -0,0 +1,11 @@
+use re_types_core::datatypes::Float64;
+
+use super::PlaybackRate;
+
+impl Default for PlaybackRate {
+    #[inline]
+    fn default() -> Self {
+        // The data runs at the same speed as the timeline.
+        Self(Float64(1.0))
+    }
+}
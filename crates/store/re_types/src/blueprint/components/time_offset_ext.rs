@@ -0,0 +1,10 @@
+use re_types_core::datatypes::TimeInt;
+
+use super::TimeOffset;
+
+impl Default for TimeOffset {
+    #[inline]
+    fn default() -> Self {
+        Self(TimeInt(0))
+    }
+}
@@ -0,0 +1,215 @@
+//! Reflection about user-defined components, registered at runtime.
+//!
+//! The built-in components come with reflection generated from their definitions, see
+//! [`crate::reflection`]. Applications that log their own components can describe them with a
+//! [`CustomComponentReflection`] instead, which is logged to the recording and picked up by the
+//! Viewer, so that it can show their documentation in the selection panel.
+
+use re_log_types::{EntityPath, EntityPathPart};
+use re_types_core::{
+    try_serialize_field, AsComponents, Component as _, ComponentDescriptor, ComponentType,
+    SerializedComponentBatch,
+};
+
+use crate::components::Text;
+
+/// The reserved namespace that custom component reflection is logged to.
+const ENTITY_PATH_ROOT: &str = "__component_reflection";
+
+const ARCHETYPE_NAME: &str = "rerun.CustomComponentReflection";
+
+/// Reflection about a user-defined component, see [`crate::component_reflection`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CustomComponentReflection {
+    /// The name of the component in human case, e.g. `Battery level`.
+    ///
+    /// Defaults to the short name of the component type.
+    pub display_name: Option<String>,
+
+    /// Markdown docstring for the component.
+    pub docstring_md: String,
+
+    /// Docstrings of the fields of struct components, in order.
+    pub fields: Vec<CustomFieldReflection>,
+}
+
+/// Reflection about a field of a user-defined struct component.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CustomFieldReflection {
+    /// The name of the field in the Arrow struct.
+    pub name: String,
+
+    /// Markdown docstring for the field, e.g. what it means and in what unit it is.
+    pub docstring_md: String,
+}
+
+impl CustomComponentReflection {
+    #[inline]
+    pub fn new(docstring_md: impl Into<String>) -> Self {
+        Self {
+            display_name: None,
+            docstring_md: docstring_md.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn with_display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    /// Documents a field of the Arrow struct of the component.
+    #[inline]
+    pub fn with_field(mut self, name: impl Into<String>, docstring_md: impl Into<String>) -> Self {
+        self.fields.push(CustomFieldReflection {
+            name: name.into(),
+            docstring_md: docstring_md.into(),
+        });
+        self
+    }
+
+    /// The static entity that the reflection of the given component type is logged to.
+    pub fn entity_path(component_type: ComponentType) -> EntityPath {
+        EntityPath::new(vec![
+            EntityPathPart::new(ENTITY_PATH_ROOT),
+            EntityPathPart::new(component_type.as_str()),
+        ])
+    }
+
+    /// The namespace that the reflection of all component types is logged to.
+    pub fn entity_path_root() -> EntityPath {
+        EntityPath::new(vec![EntityPathPart::new(ENTITY_PATH_ROOT)])
+    }
+
+    #[inline]
+    pub fn descriptor_display_name() -> ComponentDescriptor {
+        Self::descriptor("display_name")
+    }
+
+    #[inline]
+    pub fn descriptor_docstring_md() -> ComponentDescriptor {
+        Self::descriptor("docstring_md")
+    }
+
+    #[inline]
+    pub fn descriptor_field_names() -> ComponentDescriptor {
+        Self::descriptor("field_names")
+    }
+
+    #[inline]
+    pub fn descriptor_field_docstrings_md() -> ComponentDescriptor {
+        Self::descriptor("field_docstrings_md")
+    }
+
+    /// All the descriptors that [`Self`] is logged with.
+    pub fn all_descriptors() -> [ComponentDescriptor; 4] {
+        [
+            Self::descriptor_display_name(),
+            Self::descriptor_docstring_md(),
+            Self::descriptor_field_names(),
+            Self::descriptor_field_docstrings_md(),
+        ]
+    }
+
+    /// Reassembles the reflection from the logged fields, the inverse of [`AsComponents`].
+    ///
+    /// Returns `None` if the docstring is missing.
+    pub fn from_fields(
+        display_name: Option<Vec<Text>>,
+        docstring_md: Option<Vec<Text>>,
+        field_names: Option<Vec<Text>>,
+        field_docstrings_md: Option<Vec<Text>>,
+    ) -> Option<Self> {
+        let docstring_md = docstring_md?.into_iter().next()?;
+        let fields = itertools::izip!(
+            field_names.unwrap_or_default(),
+            field_docstrings_md.unwrap_or_default()
+        )
+        .map(|(name, docstring_md)| CustomFieldReflection {
+            name: name.into(),
+            docstring_md: docstring_md.into(),
+        })
+        .collect();
+
+        Some(Self {
+            display_name: display_name
+                .and_then(|names| names.into_iter().next())
+                .map(Into::into),
+            docstring_md: docstring_md.into(),
+            fields,
+        })
+    }
+
+    fn descriptor(field: &str) -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some(ARCHETYPE_NAME.into()),
+            component: format!("CustomComponentReflection:{field}").into(),
+            component_type: Some(Text::name()),
+        }
+    }
+}
+
+impl AsComponents for CustomComponentReflection {
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        let (field_names, field_docstrings_md): (Vec<_>, Vec<_>) = self
+            .fields
+            .iter()
+            .map(|field| {
+                (
+                    Text::from(field.name.as_str()),
+                    Text::from(field.docstring_md.as_str()),
+                )
+            })
+            .unzip();
+
+        [
+            try_serialize_field::<Text>(
+                Self::descriptor_display_name(),
+                self.display_name.as_deref().map(Text::from),
+            ),
+            try_serialize_field::<Text>(
+                Self::descriptor_docstring_md(),
+                [Text::from(self.docstring_md.as_str())],
+            ),
+            try_serialize_field::<Text>(Self::descriptor_field_names(), field_names),
+            try_serialize_field::<Text>(
+                Self::descriptor_field_docstrings_md(),
+                field_docstrings_md,
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_types_core::Loggable as _;
+
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let reflection = CustomComponentReflection::new("The charge of the battery.")
+            .with_display_name("Battery level")
+            .with_field("percent", "In percent, from 0 to 100.")
+            .with_field("charging", "Whether the battery is plugged in.");
+
+        let mut batches = reflection.as_serialized_batches();
+        let mut take = |descr: ComponentDescriptor| {
+            let index = batches.iter().position(|batch| batch.descriptor == descr)?;
+            Some(Text::from_arrow(&batches.remove(index).array).unwrap())
+        };
+
+        let roundtripped = CustomComponentReflection::from_fields(
+            take(CustomComponentReflection::descriptor_display_name()),
+            take(CustomComponentReflection::descriptor_docstring_md()),
+            take(CustomComponentReflection::descriptor_field_names()),
+            take(CustomComponentReflection::descriptor_field_docstrings_md()),
+        );
+        assert_eq!(roundtripped, Some(reflection));
+        assert!(batches.is_empty());
+    }
+}
@@ -0,0 +1,8 @@
+use super::ChannelCount;
+
+impl Default for ChannelCount {
+    #[inline]
+    fn default() -> Self {
+        Self(1.into()) // A single, non-interleaved channel.
+    }
+}
@@ -0,0 +1,168 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/components/distortion_model.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(non_camel_case_types)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Component**: The lens distortion model of a camera, which defines how [`components::DistortionCoefficient`][crate::components::DistortionCoefficient]s are interpreted.
+///
+/// All models operate on normalized image coordinates, i.e. camera space coordinates divided by their depth.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum DistortionModel {
+    /// The Brown-Conrady model used by OpenCV, also known as `plumb_bob` in ROS.
+    ///
+    /// The coefficients are `[k1, k2, p1, p2, k3, k4, k5, k6]`, where `k` are radial and `p` tangential coefficients.
+    /// Any trailing coefficients may be omitted, in which case they are zero.
+    /// Specifying `k4`, `k5` and `k6` yields the `rational_polynomial` model of OpenCV.
+    #[default]
+    PlumbBob = 1,
+
+    /// The equidistant fisheye model used by OpenCV's `fisheye` module, also known as `equidistant` in ROS.
+    ///
+    /// The coefficients are `[k1, k2, k3, k4]`.
+    /// Any trailing coefficients may be omitted, in which case they are zero.
+    Fisheye = 2,
+}
+
+impl ::re_types_core::Component for DistortionModel {
+    #[inline]
+    fn name() -> ComponentType {
+        "rerun.components.DistortionModel".into()
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(DistortionModel);
+
+impl ::re_types_core::Loggable for DistortionModel {
+    #[inline]
+    fn arrow_datatype() -> arrow::datatypes::DataType {
+        #![allow(clippy::wildcard_imports)]
+        use arrow::datatypes::*;
+        DataType::UInt8
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<arrow::array::ArrayRef>
+    where
+        Self: Clone + 'a,
+    {
+        #![allow(clippy::wildcard_imports)]
+        #![allow(clippy::manual_is_variant_and)]
+        use ::re_types_core::{arrow_helpers::as_array_ref, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok({
+            let (somes, data0): (Vec<_>, Vec<_>) = data
+                .into_iter()
+                .map(|datum| {
+                    let datum: Option<::std::borrow::Cow<'a, Self>> = datum.map(Into::into);
+                    let datum = datum.map(|datum| *datum as u8);
+                    (datum.is_some(), datum)
+                })
+                .unzip();
+            let data0_validity: Option<arrow::buffer::NullBuffer> = {
+                let any_nones = somes.iter().any(|some| !*some);
+                any_nones.then(|| somes.into())
+            };
+            as_array_ref(PrimitiveArray::<UInt8Type>::new(
+                ScalarBuffer::from(
+                    data0
+                        .into_iter()
+                        .map(|v| v.unwrap_or_default())
+                        .collect::<Vec<_>>(),
+                ),
+                data0_validity,
+            ))
+        })
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        #![allow(clippy::wildcard_imports)]
+        use ::re_types_core::{arrow_zip_validity::ZipValidity, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok(arrow_data
+            .as_any()
+            .downcast_ref::<UInt8Array>()
+            .ok_or_else(|| {
+                let expected = Self::arrow_datatype();
+                let actual = arrow_data.data_type().clone();
+                DeserializationError::datatype_mismatch(expected, actual)
+            })
+            .with_context("rerun.components.DistortionModel#enum")?
+            .into_iter()
+            .map(|typ| match typ {
+                Some(1) => Ok(Some(Self::PlumbBob)),
+                Some(2) => Ok(Some(Self::Fisheye)),
+                None => Ok(None),
+                Some(invalid) => Err(DeserializationError::missing_union_arm(
+                    Self::arrow_datatype(),
+                    "<invalid>",
+                    invalid as _,
+                )),
+            })
+            .collect::<DeserializationResult<Vec<Option<_>>>>()
+            .with_context("rerun.components.DistortionModel")?)
+    }
+}
+
+impl std::fmt::Display for DistortionModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PlumbBob => write!(f, "PlumbBob"),
+            Self::Fisheye => write!(f, "Fisheye"),
+        }
+    }
+}
+
+impl ::re_types_core::reflection::Enum for DistortionModel {
+    #[inline]
+    fn variants() -> &'static [Self] {
+        &[Self::PlumbBob, Self::Fisheye]
+    }
+
+    #[inline]
+    fn docstring_md(self) -> &'static str {
+        match self {
+            Self::PlumbBob => {
+                "The Brown-Conrady model used by OpenCV, also known as `plumb_bob` in ROS.\n\nThe coefficients are `[k1, k2, p1, p2, k3, k4, k5, k6]`, where `k` are radial and `p` tangential coefficients.\nAny trailing coefficients may be omitted, in which case they are zero.\nSpecifying `k4`, `k5` and `k6` yields the `rational_polynomial` model of OpenCV."
+            }
+            Self::Fisheye => {
+                "The equidistant fisheye model used by OpenCV's `fisheye` module, also known as `equidistant` in ROS.\n\nThe coefficients are `[k1, k2, k3, k4]`.\nAny trailing coefficients may be omitted, in which case they are zero."
+            }
+        }
+    }
+}
+
+impl ::re_byte_size::SizeBytes for DistortionModel {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        0
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        true
+    }
+}
@@ -0,0 +1,98 @@
+use super::DistortionModel;
+
+impl DistortionModel {
+    /// The maximum number of coefficients used by any distortion model.
+    pub const MAX_COEFFICIENTS: usize = 8;
+
+    /// The number of coefficients used by this model.
+    ///
+    /// Any further coefficients are ignored.
+    #[inline]
+    pub fn num_coefficients(self) -> usize {
+        match self {
+            Self::PlumbBob => 8,
+            Self::Fisheye => 4,
+        }
+    }
+
+    /// Applies the lens distortion to a point on the normalized image plane,
+    /// i.e. a camera space point divided by its depth.
+    ///
+    /// Missing coefficients are treated as zero.
+    #[cfg(feature = "glam")]
+    pub fn distort(self, coefficients: &[f32], point: glam::Vec2) -> glam::Vec2 {
+        let k = |index: usize| coefficients.get(index).copied().unwrap_or(0.0);
+
+        match self {
+            Self::PlumbBob => {
+                let glam::Vec2 { x, y } = point;
+                let r2 = point.length_squared();
+                let r4 = r2 * r2;
+                let r6 = r4 * r2;
+
+                let radial = (1.0 + k(0) * r2 + k(1) * r4 + k(4) * r6)
+                    / (1.0 + k(5) * r2 + k(6) * r4 + k(7) * r6);
+                let (p1, p2) = (k(2), k(3));
+
+                glam::vec2(
+                    x * radial + 2.0 * p1 * x * y + p2 * (r2 + 2.0 * x * x),
+                    y * radial + p1 * (r2 + 2.0 * y * y) + 2.0 * p2 * x * y,
+                )
+            }
+
+            Self::Fisheye => {
+                let r = point.length();
+                if r < f32::EPSILON {
+                    return point;
+                }
+
+                let theta = r.atan();
+                let theta2 = theta * theta;
+                let theta_distorted = theta
+                    * (1.0 + theta2 * (k(0) + theta2 * (k(1) + theta2 * (k(2) + theta2 * k(3)))));
+
+                point * (theta_distorted / r)
+            }
+        }
+    }
+
+    /// Removes the lens distortion from a point on the normalized image plane,
+    /// i.e. the inverse of [`Self::distort`].
+    ///
+    /// There's no closed form for this, so it is solved iteratively.
+    /// Returns `None` if that doesn't converge, which happens for points far outside of the
+    /// area the distortion model is valid for.
+    #[cfg(feature = "glam")]
+    pub fn undistort(self, coefficients: &[f32], point: glam::Vec2) -> Option<glam::Vec2> {
+        const MAX_ITERATIONS: usize = 20;
+        const MAX_ERROR_SQ: f32 = 1e-12;
+        const STEP: f32 = 1e-4;
+
+        let mut undistorted = point;
+        for _ in 0..MAX_ITERATIONS {
+            let distorted = self.distort(coefficients, undistorted);
+            let error = distorted - point;
+            if error.length_squared() < MAX_ERROR_SQ {
+                break;
+            }
+
+            // Newton's method, with a numerically estimated Jacobian.
+            let jacobian = glam::Mat2::from_cols(
+                (self.distort(coefficients, undistorted + glam::Vec2::X * STEP) - distorted) / STEP,
+                (self.distort(coefficients, undistorted + glam::Vec2::Y * STEP) - distorted) / STEP,
+            );
+            if jacobian.determinant().abs() < f32::EPSILON {
+                return None;
+            }
+
+            undistorted -= jacobian.inverse() * error;
+            if !undistorted.is_finite() {
+                return None;
+            }
+        }
+
+        // Accept a somewhat larger error than we iterate towards, since f32 may not get there.
+        let error = self.distort(coefficients, undistorted) - point;
+        (error.length_squared() < MAX_ERROR_SQ * 1e4).then_some(undistorted)
+    }
+}
@@ -0,0 +1,203 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/components/euler_convention.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(non_camel_case_types)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Component**: The order of the axes that the angles of a [`components::RotationEuler`][crate::components::RotationEuler] are applied about.
+///
+/// All conventions describe intrinsic rotations, i.e. each rotation is about an axis of the frame
+/// resulting from the previous rotations.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum EulerConvention {
+    /// Rotate about X, then about the new Y, then about the new Z.
+    Xyz = 1,
+
+    /// Rotate about X, then about the new Z, then about the new Y.
+    Xzy = 2,
+
+    /// Rotate about Y, then about the new X, then about the new Z.
+    Yxz = 3,
+
+    /// Rotate about Y, then about the new Z, then about the new X.
+    Yzx = 4,
+
+    /// Rotate about Z, then about the new X, then about the new Y.
+    Zxy = 5,
+
+    /// Rotate about Z, then about the new Y, then about the new X.
+    ///
+    /// This is the common yaw, pitch, roll convention used in aerospace and robotics.
+    #[default]
+    Zyx = 6,
+}
+
+impl ::re_types_core::Component for EulerConvention {
+    #[inline]
+    fn name() -> ComponentType {
+        "rerun.components.EulerConvention".into()
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(EulerConvention);
+
+impl ::re_types_core::Loggable for EulerConvention {
+    #[inline]
+    fn arrow_datatype() -> arrow::datatypes::DataType {
+        #![allow(clippy::wildcard_imports)]
+        use arrow::datatypes::*;
+        DataType::UInt8
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<arrow::array::ArrayRef>
+    where
+        Self: Clone + 'a,
+    {
+        #![allow(clippy::wildcard_imports)]
+        #![allow(clippy::manual_is_variant_and)]
+        use ::re_types_core::{arrow_helpers::as_array_ref, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok({
+            let (somes, data0): (Vec<_>, Vec<_>) = data
+                .into_iter()
+                .map(|datum| {
+                    let datum: Option<::std::borrow::Cow<'a, Self>> = datum.map(Into::into);
+                    let datum = datum.map(|datum| *datum as u8);
+                    (datum.is_some(), datum)
+                })
+                .unzip();
+            let data0_validity: Option<arrow::buffer::NullBuffer> = {
+                let any_nones = somes.iter().any(|some| !*some);
+                any_nones.then(|| somes.into())
+            };
+            as_array_ref(PrimitiveArray::<UInt8Type>::new(
+                ScalarBuffer::from(
+                    data0
+                        .into_iter()
+                        .map(|v| v.unwrap_or_default())
+                        .collect::<Vec<_>>(),
+                ),
+                data0_validity,
+            ))
+        })
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        #![allow(clippy::wildcard_imports)]
+        use ::re_types_core::{arrow_zip_validity::ZipValidity, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok(arrow_data
+            .as_any()
+            .downcast_ref::<UInt8Array>()
+            .ok_or_else(|| {
+                let expected = Self::arrow_datatype();
+                let actual = arrow_data.data_type().clone();
+                DeserializationError::datatype_mismatch(expected, actual)
+            })
+            .with_context("rerun.components.EulerConvention#enum")?
+            .into_iter()
+            .map(|typ| match typ {
+                Some(1) => Ok(Some(Self::Xyz)),
+                Some(2) => Ok(Some(Self::Xzy)),
+                Some(3) => Ok(Some(Self::Yxz)),
+                Some(4) => Ok(Some(Self::Yzx)),
+                Some(5) => Ok(Some(Self::Zxy)),
+                Some(6) => Ok(Some(Self::Zyx)),
+                None => Ok(None),
+                Some(invalid) => Err(DeserializationError::missing_union_arm(
+                    Self::arrow_datatype(),
+                    "<invalid>",
+                    invalid as _,
+                )),
+            })
+            .collect::<DeserializationResult<Vec<Option<_>>>>()
+            .with_context("rerun.components.EulerConvention")?)
+    }
+}
+
+impl std::fmt::Display for EulerConvention {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Xyz => write!(f, "Xyz"),
+            Self::Xzy => write!(f, "Xzy"),
+            Self::Yxz => write!(f, "Yxz"),
+            Self::Yzx => write!(f, "Yzx"),
+            Self::Zxy => write!(f, "Zxy"),
+            Self::Zyx => write!(f, "Zyx"),
+        }
+    }
+}
+
+impl ::re_types_core::reflection::Enum for EulerConvention {
+    #[inline]
+    fn variants() -> &'static [Self] {
+        &[
+            Self::Xyz,
+            Self::Xzy,
+            Self::Yxz,
+            Self::Yzx,
+            Self::Zxy,
+            Self::Zyx,
+        ]
+    }
+
+    #[inline]
+    fn docstring_md(self) -> &'static str {
+        match self {
+            Self::Xyz => {
+                "Rotate about X, then about the new Y, then about the new Z."
+            }
+            Self::Xzy => {
+                "Rotate about X, then about the new Z, then about the new Y."
+            }
+            Self::Yxz => {
+                "Rotate about Y, then about the new X, then about the new Z."
+            }
+            Self::Yzx => {
+                "Rotate about Y, then about the new Z, then about the new X."
+            }
+            Self::Zxy => {
+                "Rotate about Z, then about the new X, then about the new Y."
+            }
+            Self::Zyx => {
+                "Rotate about Z, then about the new Y, then about the new X.\n\nThis is the common yaw, pitch, roll convention used in aerospace and robotics."
+            }
+        }
+    }
+}
+
+impl ::re_byte_size::SizeBytes for EulerConvention {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        0
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        true
+    }
+}
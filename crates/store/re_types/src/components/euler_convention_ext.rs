@@ -0,0 +1,16 @@
+use super::EulerConvention;
+
+#[cfg(feature = "glam")]
+impl From<EulerConvention> for glam::EulerRot {
+    #[inline]
+    fn from(convention: EulerConvention) -> Self {
+        match convention {
+            EulerConvention::Xyz => Self::XYZ,
+            EulerConvention::Xzy => Self::XZY,
+            EulerConvention::Yxz => Self::YXZ,
+            EulerConvention::Yzx => Self::YZX,
+            EulerConvention::Zxy => Self::ZXY,
+            EulerConvention::Zyx => Self::ZYX,
+        }
+    }
+}
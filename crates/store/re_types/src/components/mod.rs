@@ -7,6 +7,8 @@ mod annotation_context;
 mod axis_length;
 mod axis_length_ext;
 mod blob;
+mod channel_count;
+mod channel_count_ext;
 mod class_id;
 mod class_id_ext;
 mod color;
@@ -15,9 +17,14 @@ mod colormap;
 mod colormap_ext;
 mod depth_meter;
 mod depth_meter_ext;
+mod distortion_coefficient;
+mod distortion_model;
+mod distortion_model_ext;
 mod draw_order;
 mod draw_order_ext;
 mod entity_path;
+mod euler_convention;
+mod euler_convention_ext;
 mod fill_mode;
 mod fill_mode_ext;
 mod fill_ratio;
@@ -90,8 +97,11 @@ mod resolution;
 mod resolution_ext;
 mod rotation_axis_angle;
 mod rotation_axis_angle_ext;
+mod rotation_euler;
+mod rotation_euler_ext;
 mod rotation_quat;
 mod rotation_quat_ext;
+mod sample_rate;
 mod scalar;
 mod scalar_ext;
 mod scale3d;
@@ -121,6 +131,8 @@ mod translation3d;
 mod translation3d_ext;
 mod triangle_indices;
 mod triangle_indices_ext;
+mod unit;
+mod unit_ext;
 mod value_range;
 mod value_range_ext;
 mod vector2d;
@@ -141,12 +153,16 @@ pub use self::albedo_factor::AlbedoFactor;
 pub use self::annotation_context::AnnotationContext;
 pub use self::axis_length::AxisLength;
 pub use self::blob::Blob;
+pub use self::channel_count::ChannelCount;
 pub use self::class_id::ClassId;
 pub use self::color::Color;
 pub use self::colormap::Colormap;
 pub use self::depth_meter::DepthMeter;
+pub use self::distortion_coefficient::DistortionCoefficient;
+pub use self::distortion_model::DistortionModel;
 pub use self::draw_order::DrawOrder;
 pub use self::entity_path::EntityPath;
+pub use self::euler_convention::EulerConvention;
 pub use self::fill_mode::FillMode;
 pub use self::fill_ratio::FillRatio;
 pub use self::gamma_correction::GammaCorrection;
@@ -185,7 +201,9 @@ pub use self::radius::Radius;
 pub use self::range1d::Range1D;
 pub use self::resolution::Resolution;
 pub use self::rotation_axis_angle::RotationAxisAngle;
+pub use self::rotation_euler::RotationEuler;
 pub use self::rotation_quat::RotationQuat;
+pub use self::sample_rate::SampleRate;
 pub use self::scalar::Scalar;
 pub use self::scale3d::Scale3D;
 pub use self::series_visible::SeriesVisible;
@@ -203,6 +221,7 @@ pub use self::transform_mat3x3::TransformMat3x3;
 pub use self::transform_relation::TransformRelation;
 pub use self::translation3d::Translation3D;
 pub use self::triangle_indices::TriangleIndices;
+pub use self::unit::Unit;
 pub use self::value_range::ValueRange;
 pub use self::vector2d::Vector2D;
 pub use self::vector3d::Vector3D;
@@ -72,6 +72,129 @@ impl PinholeProjection {
         .extend(pixel.z)
     }
 
+    /// Backprojects every pixel of a depth image into camera space, the same way the viewer
+    /// draws depth clouds.
+    ///
+    /// `depth_meter` is how many depth values make up one meter, see [`super::DepthMeter`].
+    /// Pixels without a valid depth (zero, negative, or not finite) are skipped, so the points
+    /// are in row-major order but not necessarily one per pixel.
+    #[cfg(feature = "glam")]
+    pub fn backproject_depth_image(
+        &self,
+        buffer: &[u8],
+        format: &crate::datatypes::ImageFormat,
+        depth_meter: f32,
+    ) -> Vec<glam::Vec3> {
+        use crate::datatypes::ChannelDatatype;
+
+        re_tracing::profile_function!();
+
+        let width = format.width as usize;
+        match format.datatype() {
+            ChannelDatatype::U8 => {
+                self.backproject_depths(buffer, width, depth_meter, |d: u8| d as f32)
+            }
+            ChannelDatatype::U16 => {
+                self.backproject_depths(&*cast::<u16>(buffer), width, depth_meter, |d| d as f32)
+            }
+            ChannelDatatype::U32 => {
+                self.backproject_depths(&*cast::<u32>(buffer), width, depth_meter, |d| d as f32)
+            }
+            ChannelDatatype::U64 => {
+                self.backproject_depths(&*cast::<u64>(buffer), width, depth_meter, |d| d as f32)
+            }
+            ChannelDatatype::I8 => {
+                self.backproject_depths(&*cast::<i8>(buffer), width, depth_meter, |d| d as f32)
+            }
+            ChannelDatatype::I16 => {
+                self.backproject_depths(&*cast::<i16>(buffer), width, depth_meter, |d| d as f32)
+            }
+            ChannelDatatype::I32 => {
+                self.backproject_depths(&*cast::<i32>(buffer), width, depth_meter, |d| d as f32)
+            }
+            ChannelDatatype::I64 => {
+                self.backproject_depths(&*cast::<i64>(buffer), width, depth_meter, |d| d as f32)
+            }
+            ChannelDatatype::F16 => self.backproject_depths(
+                &*cast::<half::f16>(buffer),
+                width,
+                depth_meter,
+                half::f16::to_f32,
+            ),
+            ChannelDatatype::F32 => {
+                self.backproject_depths(&*cast::<f32>(buffer), width, depth_meter, |d| d)
+            }
+            ChannelDatatype::F64 => {
+                self.backproject_depths(&*cast::<f64>(buffer), width, depth_meter, |d| d as f32)
+            }
+        }
+    }
+
+    /// Backprojects row-major depth values of an image `width` pixels wide into camera space.
+    ///
+    /// See [`Self::backproject_depth_image`].
+    #[cfg(feature = "glam")]
+    pub fn backproject_depths<T: Copy>(
+        &self,
+        depths: &[T],
+        width: usize,
+        depth_meter: f32,
+        to_f32: impl Fn(T) -> f32,
+    ) -> Vec<glam::Vec3> {
+        // Pixels are processed in fixed-size lanes without branches, so that the compiler
+        // turns the arithmetic into SIMD instructions. Only the filtering is done per pixel.
+        const LANES: usize = 8;
+
+        if width == 0 {
+            return Vec::new();
+        }
+
+        let focal_length = glam::Vec2::from(self.focal_length_in_pixels());
+        let principal_point = self.principal_point();
+        let world_from_depth = 1.0 / depth_meter;
+
+        // `x / f * z` only depends on the column, and `y / f * z` only on the row.
+        let x_factors: Vec<f32> = (0..width)
+            .map(|x| (x as f32 - principal_point.x) / focal_length.x * world_from_depth)
+            .collect();
+
+        let mut points = Vec::with_capacity(depths.len());
+        let mut row_depths = vec![0.0; width];
+        for (y, row) in depths.chunks_exact(width).enumerate() {
+            let y_factor = (y as f32 - principal_point.y) / focal_length.y * world_from_depth;
+
+            for (depth, &value) in row_depths.iter_mut().zip(row) {
+                *depth = to_f32(value);
+            }
+
+            let mut depth_lanes = row_depths.chunks_exact(LANES);
+            let mut x_factor_lanes = x_factors.chunks_exact(LANES);
+            for (lane_depths, lane_x_factors) in (&mut depth_lanes).zip(&mut x_factor_lanes) {
+                let mut lane = [glam::Vec3::ZERO; LANES];
+                for ((point, &depth), &x_factor) in
+                    lane.iter_mut().zip(lane_depths).zip(lane_x_factors)
+                {
+                    *point =
+                        glam::vec3(x_factor * depth, y_factor * depth, world_from_depth * depth);
+                }
+                points.extend(lane.into_iter().filter(|point| is_valid_depth(point.z)));
+            }
+
+            for (&depth, &x_factor) in depth_lanes
+                .remainder()
+                .iter()
+                .zip(x_factor_lanes.remainder())
+            {
+                let z = world_from_depth * depth;
+                if is_valid_depth(z) {
+                    points.push(glam::vec3(x_factor * depth, y_factor * depth, z));
+                }
+            }
+        }
+
+        points
+    }
+
     /// Field of View on the Y axis, i.e. the angle between top and bottom (in radians).
     pub fn fov_y(&self, resolution: impl Into<super::Resolution>) -> f32 {
         let resolution = resolution.into();
@@ -79,6 +202,21 @@ impl PinholeProjection {
     }
 }
 
+#[cfg(feature = "glam")]
+#[inline]
+fn is_valid_depth(z: f32) -> bool {
+    z > 0.0 && z.is_finite()
+}
+
+/// Reinterprets the bytes of an image, copying them only if they aren't aligned.
+#[cfg(feature = "glam")]
+fn cast<T: bytemuck::Pod>(bytes: &[u8]) -> std::borrow::Cow<'_, [T]> {
+    bytemuck::try_cast_slice(bytes).map_or_else(
+        |_err| std::borrow::Cow::Owned(bytemuck::pod_collect_to_vec(bytes)),
+        std::borrow::Cow::Borrowed,
+    )
+}
+
 impl Default for PinholeProjection {
     #[inline]
     fn default() -> Self {
@@ -97,3 +235,33 @@ fn test_pinhole() {
     assert_eq!(pinhole.focal_length_in_pixels(), fl);
     assert_eq!(pinhole.principal_point(), pp);
 }
+
+#[test]
+#[cfg(feature = "glam")]
+fn test_backproject_depth_image() {
+    use crate::datatypes::{ChannelDatatype, ImageFormat};
+
+    let pinhole = PinholeProjection::from_focal_length_and_principal_point([2.0, 4.0], [5.0, 1.0]);
+
+    // Wide enough to have both full lanes and a remainder.
+    let [width, height] = [11_u32, 3];
+    let depths: Vec<u16> = (0..width * height).map(|i| (i % 7) as u16 * 1000).collect();
+    let format = ImageFormat::depth([width, height], ChannelDatatype::U16);
+
+    let points = pinhole.backproject_depth_image(bytemuck::cast_slice(&depths), &format, 1000.0);
+
+    let expected: Vec<glam::Vec3> = depths
+        .iter()
+        .enumerate()
+        .filter(|(_, depth)| **depth != 0)
+        .map(|(i, depth)| {
+            let pixel = glam::vec2((i % width as usize) as f32, (i / width as usize) as f32);
+            pinhole.unproject(pixel.extend(*depth as f32 / 1000.0))
+        })
+        .collect();
+
+    assert_eq!(points.len(), expected.len());
+    for (point, expected) in points.iter().zip(&expected) {
+        assert!(point.abs_diff_eq(*expected, 1e-5), "{point} != {expected}");
+    }
+}
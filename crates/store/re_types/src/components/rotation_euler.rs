@@ -0,0 +1,117 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/components/rotation_euler.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Component**: A 3D rotation expressed as three Euler angles, in radians.
+///
+/// The angles are intrinsic rotations about the axes given by [`components::EulerConvention`][crate::components::EulerConvention], in that order.
+/// E.g. for the default `Zyx` convention, the angles are yaw (about Z), pitch (about the new Y) and roll (about the new X).
+#[derive(Clone, Debug, Default, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(transparent)]
+pub struct RotationEuler(pub crate::datatypes::Vec3D);
+
+impl ::re_types_core::Component for RotationEuler {
+    #[inline]
+    fn name() -> ComponentType {
+        "rerun.components.RotationEuler".into()
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(RotationEuler);
+
+impl ::re_types_core::Loggable for RotationEuler {
+    #[inline]
+    fn arrow_datatype() -> arrow::datatypes::DataType {
+        crate::datatypes::Vec3D::arrow_datatype()
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<arrow::array::ArrayRef>
+    where
+        Self: Clone + 'a,
+    {
+        crate::datatypes::Vec3D::to_arrow_opt(data.into_iter().map(|datum| {
+            datum.map(|datum| match datum.into() {
+                ::std::borrow::Cow::Borrowed(datum) => ::std::borrow::Cow::Borrowed(&datum.0),
+                ::std::borrow::Cow::Owned(datum) => ::std::borrow::Cow::Owned(datum.0),
+            })
+        }))
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        crate::datatypes::Vec3D::from_arrow_opt(arrow_data)
+            .map(|v| v.into_iter().map(|v| v.map(Self)).collect())
+    }
+
+    #[inline]
+    fn from_arrow(arrow_data: &dyn arrow::array::Array) -> DeserializationResult<Vec<Self>>
+    where
+        Self: Sized,
+    {
+        crate::datatypes::Vec3D::from_arrow(arrow_data).map(bytemuck::cast_vec)
+    }
+}
+
+impl<T: Into<crate::datatypes::Vec3D>> From<T> for RotationEuler {
+    fn from(v: T) -> Self {
+        Self(v.into())
+    }
+}
+
+impl std::borrow::Borrow<crate::datatypes::Vec3D> for RotationEuler {
+    #[inline]
+    fn borrow(&self) -> &crate::datatypes::Vec3D {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for RotationEuler {
+    type Target = crate::datatypes::Vec3D;
+
+    #[inline]
+    fn deref(&self) -> &crate::datatypes::Vec3D {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for RotationEuler {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut crate::datatypes::Vec3D {
+        &mut self.0
+    }
+}
+
+impl ::re_byte_size::SizeBytes for RotationEuler {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.0.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <crate::datatypes::Vec3D>::is_pod()
+    }
+}
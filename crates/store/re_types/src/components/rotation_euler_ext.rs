@@ -0,0 +1,42 @@
+use crate::datatypes;
+
+use super::RotationEuler;
+
+impl RotationEuler {
+    /// The identity rotation, representing no rotation.
+    pub const IDENTITY: Self = Self(datatypes::Vec3D::ZERO);
+
+    /// Create a new rotation from three angles in radians.
+    ///
+    /// The angles are applied in the order given by the [`super::EulerConvention`] they are
+    /// interpreted with, e.g. yaw, pitch, roll for the default [`super::EulerConvention::Zyx`].
+    #[inline]
+    pub const fn new(a: f32, b: f32, c: f32) -> Self {
+        Self(datatypes::Vec3D::new(a, b, c))
+    }
+
+    /// Converts the Euler angles to a quaternion, using the given convention.
+    #[cfg(feature = "glam")]
+    #[inline]
+    pub fn to_quat(self, convention: super::EulerConvention) -> glam::Quat {
+        let [a, b, c] = self.0 .0;
+        glam::Quat::from_euler(convention.into(), a, b, c)
+    }
+
+    /// Converts a quaternion to Euler angles, using the given convention.
+    #[cfg(feature = "glam")]
+    #[inline]
+    pub fn from_quat(quat: glam::Quat, convention: super::EulerConvention) -> Self {
+        let (a, b, c) = quat.to_euler(convention.into());
+        Self::new(a, b, c)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<RotationEuler> for glam::Affine3A {
+    /// Converts using the default [`super::EulerConvention`].
+    #[inline]
+    fn from(val: RotationEuler) -> Self {
+        Self::from_quat(val.to_quat(super::EulerConvention::default()))
+    }
+}
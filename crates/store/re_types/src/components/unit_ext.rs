@@ -0,0 +1,29 @@
+use super::Unit;
+
+impl Unit {
+    /// Returns the unit as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl From<Unit> for String {
+    #[inline]
+    fn from(value: Unit) -> Self {
+        value.as_str().to_owned()
+    }
+}
+
+impl AsRef<str> for Unit {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::image::{rgb_from_yuv, YuvMatrixCoefficients};
+use crate::image::{rgb_from_yuv, RgbFromYuv, YuvMatrixCoefficients};
 
 use super::{ChannelDatatype, ColorModel, PixelFormat};
 
@@ -135,7 +135,7 @@ impl PixelFormat {
                 let y_plane_size = (w * h) as usize;
                 let uv_plane_size = y_plane_size / 4; // Half horizontal & vertical resolution.
                 let y_plane_coord = (y * w + x) as usize;
-                let uv_plane_coord = (y * w / 4 + x / 2) as usize; // == ((y / 2) * (w / 2) + x / 2)
+                let uv_plane_coord = ((y / 2) * (w / 2) + x / 2) as usize;
 
                 let luma = *buf.get(y_plane_coord)?;
                 let u = *buf.get(uv_plane_coord + y_plane_size)?;
@@ -151,8 +151,9 @@ impl PixelFormat {
             Self::NV12 => {
                 let uv_offset = w * h;
                 let luma = *buf.get((y * w + x) as usize)?;
-                let u = *buf.get((uv_offset + (y / 2) * w + x) as usize)?;
-                let v = *buf.get((uv_offset + (y / 2) * w + x) as usize + 1)?;
+                let uv_coord = (uv_offset + (y / 2) * w + (x / 2) * 2) as usize;
+                let u = *buf.get(uv_coord)?;
+                let v = *buf.get(uv_coord + 1)?;
                 Some([luma, u, v])
             }
 
@@ -220,4 +221,195 @@ impl PixelFormat {
             self.yuv_matrix_coefficients(),
         ))
     }
+
+    /// Decodes whole rows of an image to tightly packed RGB.
+    ///
+    /// This is much faster than calling [`Self::decode_rgb_at`] for every pixel.
+    /// `rgb` receives the rows starting at `first_row`, with 3 bytes per pixel, and its length
+    /// determines how many rows are decoded. Rows don't depend on each other, so an image can be
+    /// decoded in parallel by splitting its output into bands of rows.
+    ///
+    /// Returns `None` if `buf` is too small for an image of this size, or if `rgb` isn't made of whole rows of it.
+    pub fn decode_rgb_rows(
+        &self,
+        buf: &[u8],
+        [w, h]: [u32; 2],
+        first_row: u32,
+        rgb: &mut [u8],
+    ) -> Option<()> {
+        let row_len = 3 * w as usize;
+        if row_len == 0
+            || rgb.len() % row_len != 0
+            || first_row as usize + rgb.len() / row_len > h as usize
+            || buf.len() < self.num_bytes([w, h])
+        {
+            return None;
+        }
+
+        let to_rgb = RgbFromYuv::new(self.is_limited_yuv_range(), self.yuv_matrix_coefficients());
+        let (w, h) = (w as usize, h as usize);
+        let y_plane_size = w * h;
+
+        for (row_offset, rgb_row) in rgb.chunks_exact_mut(row_len).enumerate() {
+            let y = first_row as usize + row_offset;
+
+            if w % 2 != 0
+                && !matches!(
+                    self,
+                    Self::Y_U_V24_FullRange
+                        | Self::Y_U_V24_LimitedRange
+                        | Self::Y8_FullRange
+                        | Self::Y8_LimitedRange
+                )
+            {
+                // Odd widths of chroma-subsampled formats are rare, use the slow path.
+                for (x, pixel) in rgb_row.chunks_exact_mut(3).enumerate() {
+                    pixel.copy_from_slice(&self.decode_rgb_at(
+                        buf,
+                        [w as u32, h as u32],
+                        [x as u32, y as u32],
+                    )?);
+                }
+                continue;
+            }
+
+            match self {
+                Self::Y_U_V24_FullRange | Self::Y_U_V24_LimitedRange => {
+                    let luma_row = buf.get(y * w..(y + 1) * w)?;
+                    let u_row = buf.get(y_plane_size + y * w..y_plane_size + (y + 1) * w)?;
+                    let v_row =
+                        buf.get(2 * y_plane_size + y * w..2 * y_plane_size + (y + 1) * w)?;
+                    for (((pixel, &luma), &u), &v) in rgb_row
+                        .chunks_exact_mut(3)
+                        .zip(luma_row)
+                        .zip(u_row)
+                        .zip(v_row)
+                    {
+                        pixel.copy_from_slice(&to_rgb.convert(luma, u, v));
+                    }
+                }
+
+                Self::Y_U_V16_FullRange | Self::Y_U_V16_LimitedRange => {
+                    let (uv_plane_size, uv_row_len) = (y_plane_size / 2, w / 2);
+                    let luma_row = buf.get(y * w..(y + 1) * w)?;
+                    let u_start = y_plane_size + y * uv_row_len;
+                    let u_row = buf.get(u_start..u_start + uv_row_len)?;
+                    let v_row =
+                        buf.get(u_start + uv_plane_size..u_start + uv_plane_size + uv_row_len)?;
+                    decode_subsampled_row(&to_rgb, rgb_row, luma_row, u_row, v_row);
+                }
+
+                Self::Y_U_V12_FullRange | Self::Y_U_V12_LimitedRange => {
+                    let (uv_plane_size, uv_row_len) = (y_plane_size / 4, w / 2);
+                    let luma_row = buf.get(y * w..(y + 1) * w)?;
+                    let u_start = y_plane_size + (y / 2) * uv_row_len;
+                    let u_row = buf.get(u_start..u_start + uv_row_len)?;
+                    let v_row =
+                        buf.get(u_start + uv_plane_size..u_start + uv_plane_size + uv_row_len)?;
+                    decode_subsampled_row(&to_rgb, rgb_row, luma_row, u_row, v_row);
+                }
+
+                Self::Y8_FullRange | Self::Y8_LimitedRange => {
+                    let luma_row = buf.get(y * w..(y + 1) * w)?;
+                    for (pixel, &luma) in rgb_row.chunks_exact_mut(3).zip(luma_row) {
+                        pixel.copy_from_slice(&to_rgb.convert(luma, 128, 128));
+                    }
+                }
+
+                Self::NV12 => {
+                    let luma_row = buf.get(y * w..(y + 1) * w)?;
+                    let uv_start = y_plane_size + (y / 2) * w;
+                    let uv_row = buf.get(uv_start..uv_start + w)?;
+                    for ((pixels, lumas), uv) in rgb_row
+                        .chunks_exact_mut(6)
+                        .zip(luma_row.chunks_exact(2))
+                        .zip(uv_row.chunks_exact(2))
+                    {
+                        pixels[..3].copy_from_slice(&to_rgb.convert(lumas[0], uv[0], uv[1]));
+                        pixels[3..].copy_from_slice(&to_rgb.convert(lumas[1], uv[0], uv[1]));
+                    }
+                }
+
+                Self::YUY2 => {
+                    let yuyv_row = buf.get(y * w * 2..(y + 1) * w * 2)?;
+                    for (pixels, yuyv) in rgb_row.chunks_exact_mut(6).zip(yuyv_row.chunks_exact(4))
+                    {
+                        pixels[..3].copy_from_slice(&to_rgb.convert(yuyv[0], yuyv[1], yuyv[3]));
+                        pixels[3..].copy_from_slice(&to_rgb.convert(yuyv[2], yuyv[1], yuyv[3]));
+                    }
+                }
+            }
+        }
+
+        Some(())
+    }
+}
+
+/// Decodes a row of a planar format with half horizontal chroma resolution.
+#[inline]
+fn decode_subsampled_row(
+    to_rgb: &RgbFromYuv,
+    rgb_row: &mut [u8],
+    luma_row: &[u8],
+    u_row: &[u8],
+    v_row: &[u8],
+) {
+    for (((pixels, lumas), &u), &v) in rgb_row
+        .chunks_exact_mut(6)
+        .zip(luma_row.chunks_exact(2))
+        .zip(u_row)
+        .zip(v_row)
+    {
+        pixels[..3].copy_from_slice(&to_rgb.convert(lumas[0], u, v));
+        pixels[3..].copy_from_slice(&to_rgb.convert(lumas[1], u, v));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_rgb_rows_matches_decode_rgb_at() {
+        let [w, h] = [6_u32, 4];
+        for pixel_format in [
+            PixelFormat::NV12,
+            PixelFormat::YUY2,
+            PixelFormat::Y_U_V12_LimitedRange,
+            PixelFormat::Y_U_V16_FullRange,
+            PixelFormat::Y_U_V24_LimitedRange,
+            PixelFormat::Y8_FullRange,
+        ] {
+            let buf = (0..pixel_format.num_bytes([w, h]))
+                .map(|i| (i * 37 % 256) as u8)
+                .collect::<Vec<_>>();
+
+            let mut rgb = vec![0; (w * h * 3) as usize];
+            pixel_format
+                .decode_rgb_rows(&buf, [w, h], 0, &mut rgb)
+                .unwrap();
+
+            // Decoding a band of rows gives the same result.
+            let mut band = vec![0; (w * 2 * 3) as usize];
+            pixel_format
+                .decode_rgb_rows(&buf, [w, h], 1, &mut band)
+                .unwrap();
+            assert_eq!(band, rgb[(w * 3) as usize..(w * 3 * 3) as usize]);
+
+            for y in 0..h {
+                for x in 0..w {
+                    let offset = ((y * w + x) * 3) as usize;
+                    assert_eq!(
+                        rgb[offset..offset + 3],
+                        pixel_format.decode_rgb_at(&buf, [w, h], [x, y]).unwrap(),
+                        "{pixel_format:?} at {x}, {y}"
+                    );
+                }
+            }
+
+            assert!(pixel_format
+                .decode_rgb_rows(&buf[1..], [w, h], 0, &mut rgb)
+                .is_none());
+        }
+    }
 }
@@ -0,0 +1,162 @@
+//! Definitions of derived scalar series, stored in the blueprint.
+//!
+//! A [`DerivedSeriesDefinition`] logged to the blueprint asks the Viewer to compute a scalar
+//! series from the components of an entity of the recording, e.g. the magnitude of an
+//! acceleration with `sqrt(ax² + ay² + az²)`. The values are computed by the store of the
+//! recording as data comes in, and can be plotted like any other `Scalars`.
+
+use re_log_types::{EntityPath, EntityPathPart};
+use re_types_core::{
+    try_serialize_field, AsComponents, Component as _, ComponentDescriptor,
+    SerializedComponentBatch,
+};
+
+use crate::components::Text;
+
+/// The blueprint entity that all derived series definitions are logged under.
+const ENTITY_PATH_ROOT: &str = "derived_series";
+
+const ARCHETYPE_NAME: &str = "rerun.blueprint.DerivedSeries";
+
+/// A scalar series computed from the components of another entity, see [`crate::derived_series`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DerivedSeriesDefinition {
+    /// The entity of the recording to write the derived series to.
+    pub entity_path: EntityPath,
+
+    /// The entity of the recording whose components the expression refers to.
+    pub source_entity_path: EntityPath,
+
+    /// The expression computing the value of each row, e.g. `sqrt(ax² + ay² + az²)`.
+    ///
+    /// Variables name components of the source entity, either by their full identifier
+    /// (`Imu:acceleration`) or by their field name (`acceleration`), and can pick an instance
+    /// with `acceleration[2]`. Supports `+ - * / ^ ² ³`, `pi`, `e`, and the usual functions
+    /// (`abs`, `sqrt`, `exp`, `ln`, `log10`, trigonometry, `atan2`, `hypot`, `pow`, `min`, `max`).
+    pub expression: String,
+}
+
+impl DerivedSeriesDefinition {
+    #[inline]
+    pub fn new(
+        entity_path: impl Into<EntityPath>,
+        source_entity_path: impl Into<EntityPath>,
+        expression: impl Into<String>,
+    ) -> Self {
+        Self {
+            entity_path: entity_path.into(),
+            source_entity_path: source_entity_path.into(),
+            expression: expression.into(),
+        }
+    }
+
+    /// The blueprint entity that the definition with the given name is logged to.
+    pub fn entity_path(name: &str) -> EntityPath {
+        EntityPath::new(vec![
+            EntityPathPart::new(ENTITY_PATH_ROOT),
+            EntityPathPart::new(name),
+        ])
+    }
+
+    /// The blueprint entity that all definitions are logged under.
+    pub fn entity_path_root() -> EntityPath {
+        EntityPath::new(vec![EntityPathPart::new(ENTITY_PATH_ROOT)])
+    }
+
+    #[inline]
+    pub fn descriptor_entity_path() -> ComponentDescriptor {
+        Self::descriptor("entity_path")
+    }
+
+    #[inline]
+    pub fn descriptor_source_entity_path() -> ComponentDescriptor {
+        Self::descriptor("source_entity_path")
+    }
+
+    #[inline]
+    pub fn descriptor_expression() -> ComponentDescriptor {
+        Self::descriptor("expression")
+    }
+
+    /// All the descriptors that [`Self`] is logged with.
+    pub fn all_descriptors() -> [ComponentDescriptor; 3] {
+        [
+            Self::descriptor_entity_path(),
+            Self::descriptor_source_entity_path(),
+            Self::descriptor_expression(),
+        ]
+    }
+
+    /// Reassembles the definition from the logged fields, the inverse of [`AsComponents`].
+    ///
+    /// Returns `None` if any of them is missing.
+    pub fn from_fields(
+        entity_path: Option<Vec<Text>>,
+        source_entity_path: Option<Vec<Text>>,
+        expression: Option<Vec<Text>>,
+    ) -> Option<Self> {
+        let first = |texts: Option<Vec<Text>>| texts?.into_iter().next();
+        Some(Self {
+            entity_path: EntityPath::parse_forgiving(first(entity_path)?.as_str()),
+            source_entity_path: EntityPath::parse_forgiving(first(source_entity_path)?.as_str()),
+            expression: first(expression)?.into(),
+        })
+    }
+
+    fn descriptor(field: &str) -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some(ARCHETYPE_NAME.into()),
+            component: format!("DerivedSeries:{field}").into(),
+            component_type: Some(Text::name()),
+        }
+    }
+}
+
+impl AsComponents for DerivedSeriesDefinition {
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        [
+            try_serialize_field::<Text>(
+                Self::descriptor_entity_path(),
+                [Text::from(self.entity_path.to_string())],
+            ),
+            try_serialize_field::<Text>(
+                Self::descriptor_source_entity_path(),
+                [Text::from(self.source_entity_path.to_string())],
+            ),
+            try_serialize_field::<Text>(
+                Self::descriptor_expression(),
+                [Text::from(self.expression.as_str())],
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_types_core::Loggable as _;
+
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let definition =
+            DerivedSeriesDefinition::new("imu/magnitude", "imu", "sqrt(ax² + ay² + az²)");
+
+        let mut batches = definition.as_serialized_batches();
+        let mut take = |descr: ComponentDescriptor| {
+            let index = batches.iter().position(|batch| batch.descriptor == descr)?;
+            Some(Text::from_arrow(&batches.remove(index).array).unwrap())
+        };
+
+        let roundtripped = DerivedSeriesDefinition::from_fields(
+            take(DerivedSeriesDefinition::descriptor_entity_path()),
+            take(DerivedSeriesDefinition::descriptor_source_entity_path()),
+            take(DerivedSeriesDefinition::descriptor_expression()),
+        );
+        assert_eq!(roundtripped, Some(definition));
+        assert!(batches.is_empty());
+    }
+}
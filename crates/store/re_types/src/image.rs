@@ -147,6 +147,81 @@ where
     ChromaDownsamplingNotSupported,
 }
 
+/// Error returned when trying to repack an image buffer with padded rows.
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum ImageStrideError {
+    /// The row stride is smaller than the size of a row.
+    #[error("Row stride of {stride} B is smaller than the {row_bytes} B of a single row")]
+    StrideTooSmall {
+        /// The given row stride, in bytes.
+        stride: usize,
+
+        /// The size of a row without padding, in bytes.
+        row_bytes: usize,
+    },
+
+    /// The buffer does not hold all the rows.
+    #[error("Expected at least {expected} B for {height} rows with a stride of {stride} B, but got {actual} B")]
+    BufferTooSmall {
+        /// The number of rows of the image.
+        height: usize,
+
+        /// The given row stride, in bytes.
+        stride: usize,
+
+        /// The minimum size of the buffer, in bytes.
+        expected: usize,
+
+        /// The actual size of the buffer, in bytes.
+        actual: usize,
+    },
+}
+
+/// Removes the padding at the end of the rows of an image buffer.
+///
+/// `stride` is the distance in bytes between the start of two consecutive rows (e.g. the `step`
+/// field of a ROS `sensor_msgs/Image`), and `row_bytes` is the size of the actual pixel data of
+/// a row. The last row does not need to be padded.
+///
+/// The buffer is borrowed as is if the rows are tightly packed, otherwise they are copied into a
+/// new buffer.
+pub fn repack_strided_rows(
+    bytes: &[u8],
+    row_bytes: usize,
+    height: usize,
+    stride: usize,
+) -> Result<std::borrow::Cow<'_, [u8]>, ImageStrideError> {
+    if stride < row_bytes {
+        return Err(ImageStrideError::StrideTooSmall { stride, row_bytes });
+    }
+
+    let expected = match height {
+        0 => 0,
+        height => stride * (height - 1) + row_bytes,
+    };
+    if bytes.len() < expected {
+        return Err(ImageStrideError::BufferTooSmall {
+            height,
+            stride,
+            expected,
+            actual: bytes.len(),
+        });
+    }
+
+    if stride == row_bytes {
+        return Ok(std::borrow::Cow::Borrowed(&bytes[..expected]));
+    }
+
+    re_tracing::profile_function!();
+
+    let mut packed = Vec::with_capacity(row_bytes * height);
+    for row in bytes[..expected].chunks(stride) {
+        packed.extend_from_slice(&row[..row_bytes]);
+    }
+
+    Ok(std::borrow::Cow::Owned(packed))
+}
+
 /// Converts it to what is useful for the image API.
 pub fn blob_and_datatype_from_tensor(tensor_buffer: TensorBuffer) -> (Blob, ChannelDatatype) {
     match tensor_buffer {
@@ -310,6 +385,36 @@ fn test_find_non_empty_dim_indices() {
     expect(&[1, 1, 3, 1], &[2, 3]);
 }
 
+#[test]
+fn test_repack_strided_rows() {
+    // 3 bytes per row, padded to 4, without padding after the last row.
+    let bytes = [1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9];
+    let packed = repack_strided_rows(&bytes, 3, 3, 4).unwrap();
+    assert_eq!(&[1, 2, 3, 4, 5, 6, 7, 8, 9], packed.as_ref());
+
+    // Tightly packed rows are borrowed, trailing bytes are ignored.
+    let packed = repack_strided_rows(&bytes, 4, 2, 4).unwrap();
+    assert!(matches!(packed, std::borrow::Cow::Borrowed(_)));
+    assert_eq!(&bytes[..8], packed.as_ref());
+
+    assert_eq!(
+        Err(ImageStrideError::StrideTooSmall {
+            stride: 2,
+            row_bytes: 3
+        }),
+        repack_strided_rows(&bytes, 3, 3, 2)
+    );
+    assert_eq!(
+        Err(ImageStrideError::BufferTooSmall {
+            height: 4,
+            stride: 4,
+            expected: 15,
+            actual: 11
+        }),
+        repack_strided_rows(&bytes, 3, 4, 4)
+    );
+}
+
 // ----------------------------------------------------------------------------
 
 // TODO(andreas): Expose this in the API?
@@ -360,6 +465,7 @@ pub enum YuvMatrixCoefficients {
 /// This conversion mirrors the function of the same name in `yuv_converter.wgsl`
 ///
 /// Specifying the color standard should be exposed in the future [#3541](https://github.com/rerun-io/rerun/pull/3541)
+#[inline]
 pub fn rgb_from_yuv(
     y: u8,
     u: u8,
@@ -367,43 +473,66 @@ pub fn rgb_from_yuv(
     limited_range: bool,
     coefficients: YuvMatrixCoefficients,
 ) -> [u8; 3] {
-    let (mut y, mut u, mut v) = (y as f32, u as f32, v as f32);
-
-    // rescale YUV values
-    if limited_range {
-        // Via https://en.wikipedia.org/wiki/YCbCr#ITU-R_BT.601_conversion:
-        // "The resultant signals range from 16 to 235 for Y′ (Cb and Cr range from 16 to 240);
-        // the values from 0 to 15 are called footroom, while the values from 236 to 255 are called headroom."
-        y = (y - 16.0) / 219.0;
-        u = (u - 128.0) / 224.0;
-        v = (v - 128.0) / 224.0;
-    } else {
-        y /= 255.0;
-        u = (u - 128.0) / 255.0;
-        v = (v - 128.0) / 255.0;
-    }
+    RgbFromYuv::new(limited_range, coefficients).convert(y, u, v)
+}
 
-    let r;
-    let g;
-    let b;
+/// [`rgb_from_yuv`] with the range and coefficients resolved once, for converting many pixels.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RgbFromYuv {
+    y_offset: f32,
+    y_range: f32,
+    uv_range: f32,
+
+    r_from_v: f32,
+    g_from_u: f32,
+    g_from_v: f32,
+    b_from_u: f32,
+}
+
+impl RgbFromYuv {
+    pub(crate) fn new(limited_range: bool, coefficients: YuvMatrixCoefficients) -> Self {
+        // rescale YUV values
+        let (y_offset, y_range, uv_range) = if limited_range {
+            // Via https://en.wikipedia.org/wiki/YCbCr#ITU-R_BT.601_conversion:
+            // "The resultant signals range from 16 to 235 for Y′ (Cb and Cr range from 16 to 240);
+            // the values from 0 to 15 are called footroom, while the values from 236 to 255 are called headroom."
+            (16.0, 219.0, 224.0)
+        } else {
+            (0.0, 255.0, 255.0)
+        };
 
-    match coefficients {
-        YuvMatrixCoefficients::Bt601 => {
+        let (r_from_v, g_from_u, g_from_v, b_from_u) = match coefficients {
             // BT.601 (aka. SDTV, aka. Rec.601). wiki: https://en.wikipedia.org/wiki/YCbCr#ITU-R_BT.601_conversion
-            r = y + 1.402 * v;
-            g = y - 0.344 * u - 0.714 * v;
-            b = y + 1.772 * u;
-        }
+            YuvMatrixCoefficients::Bt601 => (1.402, 0.344, 0.714, 1.772),
 
-        YuvMatrixCoefficients::Bt709 => {
             // BT.709 (aka. HDTV, aka. Rec.709). wiki: https://en.wikipedia.org/wiki/YCbCr#ITU-R_BT.709_conversion
-            r = y + 1.575 * v;
-            g = y - 0.187 * u - 0.468 * v;
-            b = y + 1.856 * u;
+            YuvMatrixCoefficients::Bt709 => (1.575, 0.187, 0.468, 1.856),
+        };
+
+        Self {
+            y_offset,
+            y_range,
+            uv_range,
+            r_from_v,
+            g_from_u,
+            g_from_v,
+            b_from_u,
         }
     }
 
-    [(255.0 * r) as u8, (255.0 * g) as u8, (255.0 * b) as u8]
+    /// Branch-free, so that loops over pixels can be vectorized.
+    #[inline(always)]
+    pub(crate) fn convert(&self, y: u8, u: u8, v: u8) -> [u8; 3] {
+        let y = (y as f32 - self.y_offset) / self.y_range;
+        let u = (u as f32 - 128.0) / self.uv_range;
+        let v = (v as f32 - 128.0) / self.uv_range;
+
+        let r = y + self.r_from_v * v;
+        let g = y - self.g_from_u * u - self.g_from_v * v;
+        let b = y + self.b_from_u * u;
+
+        [(255.0 * r) as u8, (255.0 * g) as u8, (255.0 * b) as u8]
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -300,8 +300,16 @@ pub mod view_coordinates;
 pub mod any_values;
 pub use any_values::AnyValues;
 
+pub mod component_reflection;
+pub use component_reflection::{CustomComponentReflection, CustomFieldReflection};
+
+pub mod derived_series;
+pub use derived_series::DerivedSeriesDefinition;
+
 mod rotation3d;
 pub use rotation3d::Rotation3D;
 
+mod ply;
+
 #[cfg(feature = "testing")]
 pub mod testing;
@@ -0,0 +1,100 @@
+//! Helpers shared by the `.ply` importers of [`crate::archetypes::Points3D`] and
+//! [`crate::archetypes::Mesh3D`].
+
+use std::borrow::Cow;
+
+use ply_rs::ply::{DefaultElement, Ply, Property};
+
+/// Parses a `.ply` file, header and payload.
+pub(crate) fn read_ply(mut reader: impl std::io::BufRead) -> std::io::Result<Ply<DefaultElement>> {
+    re_tracing::profile_function!();
+    let parser = ply_rs::parser::Parser::<DefaultElement>::new();
+    parser.read_ply(&mut reader)
+}
+
+pub(crate) fn f32(prop: &Property) -> Option<f32> {
+    match *prop {
+        Property::Short(v) => Some(v as f32),
+        Property::UShort(v) => Some(v as f32),
+        Property::Int(v) => Some(v as f32),
+        Property::UInt(v) => Some(v as f32),
+        Property::Float(v) => Some(v),
+        Property::Double(v) => Some(v as f32),
+        Property::Char(_)
+        | Property::UChar(_)
+        | Property::ListChar(_)
+        | Property::ListUChar(_)
+        | Property::ListShort(_)
+        | Property::ListUShort(_)
+        | Property::ListInt(_)
+        | Property::ListUInt(_)
+        | Property::ListFloat(_)
+        | Property::ListDouble(_) => None,
+    }
+}
+
+pub(crate) fn u8(prop: &Property) -> Option<u8> {
+    match *prop {
+        Property::Short(v) => Some(v as u8),
+        Property::UShort(v) => Some(v as u8),
+        Property::Int(v) => Some(v as u8),
+        Property::UInt(v) => Some(v as u8),
+        Property::Float(v) => Some((v * 255.0) as u8),
+        Property::Double(v) => Some((v * 255.0) as u8),
+        Property::Char(v) => Some(v as u8),
+        Property::UChar(v) => Some(v),
+        Property::ListChar(_)
+        | Property::ListUChar(_)
+        | Property::ListShort(_)
+        | Property::ListUShort(_)
+        | Property::ListInt(_)
+        | Property::ListUInt(_)
+        | Property::ListFloat(_)
+        | Property::ListDouble(_) => None,
+    }
+}
+
+pub(crate) fn string(prop: &Property) -> Option<Cow<'_, str>> {
+    match prop {
+        Property::ListUChar(chars) => Some(String::from_utf8_lossy(chars)),
+        Property::ListChar(_)
+        | Property::ListShort(_)
+        | Property::ListUShort(_)
+        | Property::ListInt(_)
+        | Property::ListUInt(_)
+        | Property::ListFloat(_)
+        | Property::ListDouble(_)
+        | Property::Char(_)
+        | Property::UChar(_)
+        | Property::Short(_)
+        | Property::UShort(_)
+        | Property::Int(_)
+        | Property::UInt(_)
+        | Property::Float(_)
+        | Property::Double(_) => None,
+    }
+}
+
+/// Reads a list of vertex indices, e.g. the corners of a face.
+///
+/// Returns `None` if the property isn't an integer list or contains negative indices.
+pub(crate) fn indices(prop: &Property) -> Option<Vec<u32>> {
+    match prop {
+        Property::ListChar(v) => v.iter().map(|&i| u32::try_from(i).ok()).collect(),
+        Property::ListUChar(v) => Some(v.iter().map(|&i| i.into()).collect()),
+        Property::ListShort(v) => v.iter().map(|&i| u32::try_from(i).ok()).collect(),
+        Property::ListUShort(v) => Some(v.iter().map(|&i| i.into()).collect()),
+        Property::ListInt(v) => v.iter().map(|&i| u32::try_from(i).ok()).collect(),
+        Property::ListUInt(v) => Some(v.clone()),
+        Property::ListFloat(_)
+        | Property::ListDouble(_)
+        | Property::Char(_)
+        | Property::UChar(_)
+        | Property::Short(_)
+        | Property::UShort(_)
+        | Property::Int(_)
+        | Property::UInt(_)
+        | Property::Float(_)
+        | Property::Double(_) => None,
+    }
+}
@@ -136,6 +136,36 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: Enabled::verify_arrow_array,
             },
         ),
+        (
+            <DistortionCoefficient as Component>::name(),
+            ComponentReflection {
+                docstring_md: "A single lens distortion coefficient of a camera.\n\nThe meaning of each coefficient depends on its index and the [`components.DistortionModel`](https://rerun.io/docs/reference/types/components/distortion_model).",
+                deprecation_summary: None,
+                custom_placeholder: Some(DistortionCoefficient::default().to_arrow()?),
+                datatype: DistortionCoefficient::arrow_datatype(),
+                verify_arrow_array: DistortionCoefficient::verify_arrow_array,
+            },
+        ),
+        (
+            <DistortionModel as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The lens distortion model of a camera, which defines how [`components.DistortionCoefficient`](https://rerun.io/docs/reference/types/components/distortion_coefficient)s are interpreted.\n\nAll models operate on normalized image coordinates, i.e. camera space coordinates divided by their depth.",
+                deprecation_summary: None,
+                custom_placeholder: Some(DistortionModel::default().to_arrow()?),
+                datatype: DistortionModel::arrow_datatype(),
+                verify_arrow_array: DistortionModel::verify_arrow_array,
+            },
+        ),
+        (
+            <EulerConvention as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The order of the axes that the angles of a [`components.RotationEuler`](https://rerun.io/docs/reference/types/components/rotation_euler) are applied about.\n\nAll conventions describe intrinsic rotations, i.e. each rotation is about an axis of the frame\nresulting from the previous rotations.",
+                deprecation_summary: None,
+                custom_placeholder: Some(EulerConvention::default().to_arrow()?),
+                datatype: EulerConvention::arrow_datatype(),
+                verify_arrow_array: EulerConvention::verify_arrow_array,
+            },
+        ),
         (
             <Eye3DKind as Component>::name(),
             ComponentReflection {
@@ -276,6 +306,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: PanelState::verify_arrow_array,
             },
         ),
+        (
+            <PlaybackRate as Component>::name(),
+            ComponentReflection {
+                docstring_md: "How fast the clock of an entity's data runs relative to the timeline.\n\nA rate of 1 means both clocks run at the same speed.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(PlaybackRate::default().to_arrow()?),
+                datatype: PlaybackRate::arrow_datatype(),
+                verify_arrow_array: PlaybackRate::verify_arrow_array,
+            },
+        ),
         (
             <QueryExpression as Component>::name(),
             ComponentReflection {
@@ -328,6 +368,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: TensorDimensionIndexSlider::verify_arrow_array,
             },
         ),
+        (
+            <TimeOffset as Component>::name(),
+            ComponentReflection {
+                docstring_md: "A constant offset added to the times of an entity's data, to align it with the timeline.\n\nIn nanoseconds for temporal timelines, and in ticks for sequence timelines.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(TimeOffset::default().to_arrow()?),
+                datatype: TimeOffset::arrow_datatype(),
+                verify_arrow_array: TimeOffset::verify_arrow_array,
+            },
+        ),
         (
             <TimelineName as Component>::name(),
             ComponentReflection {
@@ -480,6 +530,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: Blob::verify_arrow_array,
             },
         ),
+        (
+            <ChannelCount as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The number of channels that samples are interleaved across.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: None,
+                datatype: ChannelCount::arrow_datatype(),
+                verify_arrow_array: ChannelCount::verify_arrow_array,
+            },
+        ),
         (
             <ClassId as Component>::name(),
             ComponentReflection {
@@ -930,6 +990,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: RotationAxisAngle::verify_arrow_array,
             },
         ),
+        (
+            <RotationEuler as Component>::name(),
+            ComponentReflection {
+                docstring_md: "A 3D rotation expressed as three Euler angles, in radians.\n\nThe angles are intrinsic rotations about the axes given by [`components.EulerConvention`](https://rerun.io/docs/reference/types/components/euler_convention), in that order.\nE.g. for the default `Zyx` convention, the angles are yaw (about Z), pitch (about the new Y) and roll (about the new X).",
+                deprecation_summary: None,
+                custom_placeholder: Some(RotationEuler::default().to_arrow()?),
+                datatype: RotationEuler::arrow_datatype(),
+                verify_arrow_array: RotationEuler::verify_arrow_array,
+            },
+        ),
         (
             <RotationQuat as Component>::name(),
             ComponentReflection {
@@ -940,6 +1010,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: RotationQuat::verify_arrow_array,
             },
         ),
+        (
+            <SampleRate as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The rate at which evenly spaced samples were acquired, in samples per second (Hz).\n\nOn sequence timelines, this is interpreted as samples per timeline tick instead.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: None,
+                datatype: SampleRate::arrow_datatype(),
+                verify_arrow_array: SampleRate::verify_arrow_array,
+            },
+        ),
         (
             <Scalar as Component>::name(),
             ComponentReflection {
@@ -1112,6 +1192,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: TriangleIndices::verify_arrow_array,
             },
         ),
+        (
+            <Unit as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The unit of a quantity, e.g. `m/s` or `°C`.\n\nThis is used purely for display purposes, no conversions between units are performed.",
+                deprecation_summary: None,
+                custom_placeholder: Some(Unit::default().to_arrow()?),
+                datatype: Unit::arrow_datatype(),
+                verify_arrow_array: Unit::verify_arrow_array,
+            },
+        ),
         (
             <ValueRange as Component>::name(),
             ComponentReflection {
@@ -1453,6 +1543,30 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.CameraRig"),
+            ArchetypeReflection {
+                display_name: "Camera rig",
+                deprecation_summary: None,
+                scope: None,
+                view_types: &["Spatial3DView", "Spatial2DView"],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "cameras", display_name :
+                    "Cameras", component_type : "rerun.components.EntityPath".into(),
+                    docstring_md :
+                    "The camera entities of the rig, relative to the rig entity.\n\nEach camera must be a direct child of the rig entity, e.g. `left` and `right` for a stereo pair.",
+                    is_required : true, }, ArchetypeFieldReflection { name :
+                    "translations", display_name : "Translations", component_type :
+                    "rerun.components.PoseTranslation3D".into(), docstring_md :
+                    "Translation of each camera relative to the rig entity.\n\nIf there are fewer translations than cameras, the missing cameras aren't translated.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "quaternions", display_name : "Quaternions", component_type :
+                    "rerun.components.PoseRotationQuat".into(), docstring_md :
+                    "Rotation of each camera relative to the rig entity, as a quaternion.\n\nIf there are fewer quaternions than cameras, the missing cameras aren't rotated.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.archetypes.Capsules3D"),
             ArchetypeReflection {
@@ -1603,6 +1717,10 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                     "rerun.components.ValueRange".into(), docstring_md :
                     "The expected range of depth values.\n\nThis is typically the expected range of valid values.\nEverything outside of the range is clamped to the range for the purpose of colormpaping.\nNote that point clouds generated from this image will still display all points, regardless of this range.\n\nIf not specified, the range will be automatically estimated from the data.\nNote that the Viewer may try to guess a wider range than the minimum/maximum of values\nin the contents of the depth image.\nE.g. if all values are positive, some bigger than 1.0 and all smaller than 255.0,\nthe Viewer will guess that the data likely came from an 8bit image, thus assuming a range of 0-255.",
                     is_required : false, }, ArchetypeFieldReflection { name :
+                    "clip_range", display_name : "Clip range", component_type :
+                    "rerun.components.Range1D".into(), docstring_md :
+                    "Depth values outside of this range, in meters, are left out of the point cloud generated from this image.\n\nUse this to hide invalid measurements close to the sensor or far away from it, e.g. `[0.3, 10.0]`.\nIf not specified, all points with a positive depth are shown.\n\nThis has no effect on 2D views.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
                     "point_fill_ratio", display_name : "Point fill ratio", component_type
                     : "rerun.components.FillRatio".into(), docstring_md :
                     "Scale the radii of the points in the point cloud generated from this image.\n\nA fill ratio of 1.0 (the default) means that each point is as big as to touch the center of its neighbor\nif it is at the same depth, leaving no gaps.\nA fill ratio of 0.5 means that each point touches the edge of its neighbor if it has the same depth.\n\nTODO(#6744): This applies only to 3D views!",
@@ -1692,6 +1810,24 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.EntityRelations"),
+            ArchetypeReflection {
+                display_name: "Entity relations",
+                deprecation_summary: None,
+                scope: None,
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "targets", display_name :
+                    "Targets", component_type : "rerun.components.EntityPath".into(),
+                    docstring_md : "The related entities.", is_required : true, },
+                    ArchetypeFieldReflection { name : "kinds", display_name : "Kinds",
+                    component_type : "rerun.components.Text".into(), docstring_md :
+                    "The kind of each relation, e.g. `source` or `same_track_as`.\n\nIf there are fewer kinds than targets, the remaining relations have no kind.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.archetypes.GeoLineStrings"),
             ArchetypeReflection {
@@ -1792,6 +1928,28 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.Histogram"),
+            ArchetypeReflection {
+                display_name: "Histogram",
+                deprecation_summary: None,
+                scope: None,
+                view_types: &["BarChartView"],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "counts", display_name : "Counts",
+                    component_type : "rerun.components.Scalar".into(), docstring_md :
+                    "The count (or weight) of each bin.", is_required : true, },
+                    ArchetypeFieldReflection { name : "bin_edges", display_name :
+                    "Bin edges", component_type : "rerun.components.Scalar".into(),
+                    docstring_md :
+                    "The edges of the bins, in ascending order.\n\nMust contain exactly one more value than `counts`: bin `i` spans `[bin_edges[i], bin_edges[i + 1])`.\nDefaults to unit-width bins starting at zero.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "color",
+                    display_name : "Color", component_type :
+                    "rerun.components.Color".into(), docstring_md :
+                    "The color of the bars.", is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.archetypes.Image"),
             ArchetypeReflection {
@@ -1988,6 +2146,16 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                     component_type : "rerun.components.ImagePlaneDistance".into(),
                     docstring_md :
                     "The distance from the camera origin to the image plane when the projection is shown in a 3D viewer.\n\nThis is only used for visualization purposes, and does not affect the projection itself.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "distortion_model", display_name : "Distortion model",
+                    component_type : "rerun.components.DistortionModel".into(),
+                    docstring_md :
+                    "The lens distortion model that the `distortion_coefficients` are given for.\n\nDefaults to the Brown-Conrady (`plumb_bob`) model used by OpenCV.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "distortion_coefficients", display_name : "Distortion coefficients",
+                    component_type : "rerun.components.DistortionCoefficient".into(),
+                    docstring_md :
+                    "Lens distortion coefficients, in the order defined by the `distortion_model`.\n\nPoints are distorted on the normalized image plane, i.e. after dividing by depth and before applying\n`image_from_camera`. If no coefficients are logged, the camera is an ideal pinhole camera.",
                     is_required : false, },
                 ],
             },
@@ -2104,6 +2272,10 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                     ArchetypeFieldReflection { name : "scalars", display_name :
                     "Scalars", component_type : "rerun.components.Scalar".into(),
                     docstring_md : "The scalar values to log.", is_required : true, },
+                    ArchetypeFieldReflection { name : "units", display_name : "Units",
+                    component_type : "rerun.components.Unit".into(), docstring_md :
+                    "The unit of each scalar value, e.g. `m/s` or `°C`.\n\nShown on the plot axes and in tooltips.\nIf a single unit is logged, it applies to all scalars.\nExpected to be unchanging over time.",
+                    is_required : false, },
                 ],
             },
         ),
@@ -2274,9 +2446,18 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                     ArchetypeFieldReflection { name : "quaternion", display_name :
                     "Quaternion", component_type : "rerun.components.RotationQuat"
                     .into(), docstring_md : "Rotation via quaternion.", is_required :
-                    false, }, ArchetypeFieldReflection { name : "scale", display_name :
-                    "Scale", component_type : "rerun.components.Scale3D".into(),
-                    docstring_md : "Scaling factor.", is_required : false, },
+                    false, }, ArchetypeFieldReflection { name : "rotation_euler",
+                    display_name : "Rotation euler", component_type :
+                    "rerun.components.RotationEuler".into(), docstring_md :
+                    "Rotation via Euler angles, in radians.\n\nThe axes the angles are applied about are given by [`components.EulerConvention`](https://rerun.io/docs/reference/types/components/euler_convention).",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "euler_convention", display_name : "Euler convention",
+                    component_type : "rerun.components.EulerConvention".into(),
+                    docstring_md :
+                    "The axis order used to interpret [`components.RotationEuler`](https://rerun.io/docs/reference/types/components/rotation_euler).\n\nDefaults to `Zyx`, i.e. yaw, pitch, roll.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "scale",
+                    display_name : "Scale", component_type : "rerun.components.Scale3D"
+                    .into(), docstring_md : "Scaling factor.", is_required : false, },
                     ArchetypeFieldReflection { name : "mat3x3", display_name :
                     "Mat 3x 3", component_type : "rerun.components.TransformMat3x3"
                     .into(), docstring_md : "3x3 transformation matrix.", is_required :
@@ -2354,6 +2535,30 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.Waveform"),
+            ArchetypeReflection {
+                display_name: "Waveform",
+                deprecation_summary: None,
+                scope: None,
+                view_types: &["TimeSeriesView"],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "samples", display_name :
+                    "Samples", component_type : "rerun.components.Scalar".into(),
+                    docstring_md :
+                    "The interleaved samples of all channels in this block.", is_required
+                    : true, }, ArchetypeFieldReflection { name : "sample_rate",
+                    display_name : "Sample rate", component_type :
+                    "rerun.components.SampleRate".into(), docstring_md :
+                    "The rate at which the samples were acquired.\n\nIn Hz for temporal timelines, and per tick for sequence timelines.\nExpected to be unchanging over time and can therefore be logged statically.",
+                    is_required : true, }, ArchetypeFieldReflection { name :
+                    "channel_count", display_name : "Channel count", component_type :
+                    "rerun.components.ChannelCount".into(), docstring_md :
+                    "The number of channels the samples are interleaved across.\n\nDefaults to a single channel.\nExpected to be unchanging over time and can therefore be logged statically.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.Background"),
             ArchetypeReflection {
@@ -2467,6 +2672,26 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.EntityTimeAlignment"),
+            ArchetypeReflection {
+                display_name: "Entity time alignment",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "time_offset", display_name :
+                    "Time offset", component_type :
+                    "rerun.blueprint.components.TimeOffset".into(), docstring_md :
+                    "A constant offset added to the times of the entity's data.\n\nThis property is propagated down the entity hierarchy until another child entity\nsets `time_offset` to a different value at which point propagation continues with that value instead.\n\nDefaults to parent's `time_offset` value or 0 if there is no parent.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "playback_rate", display_name : "Playback rate", component_type :
+                    "rerun.blueprint.components.PlaybackRate".into(), docstring_md :
+                    "How fast the clock of the entity's data runs relative to the timeline.\n\nThis property is propagated down the entity hierarchy until another child entity\nsets `playback_rate` to a different value at which point propagation continues with that value instead.\n\nDefaults to parent's `playback_rate` value or 1 if there is no parent.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.EyeControls3D"),
             ArchetypeReflection {
@@ -2645,6 +2870,29 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.MapHeatmap"),
+            ArchetypeReflection {
+                display_name: "Map heatmap",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "visible", display_name :
+                    "Visible", component_type : "rerun.components.Visible".into(),
+                    docstring_md : "Whether the heatmap is visible.\n\nDefaults to false.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "colormap",
+                    display_name : "Colormap", component_type :
+                    "rerun.components.Colormap".into(), docstring_md :
+                    "Colormap used to map the density of points to colors.\n\nDefaults to inferno.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "opacity",
+                    display_name : "Opacity", component_type : "rerun.components.Opacity"
+                    .into(), docstring_md :
+                    "Opacity of the heatmap on top of the background map.\n\nDefaults to 0.6.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.MapZoom"),
             ArchetypeReflection {
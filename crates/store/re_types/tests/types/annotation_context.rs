@@ -2,7 +2,7 @@ use re_types::{
     archetypes::AnnotationContext,
     components,
     datatypes::{ClassDescription, KeypointPair, Rgba32},
-    Archetype as _, AsComponents as _,
+    Archetype as _, AsComponents as _, Loggable as _,
 };
 
 #[test]
@@ -30,3 +30,75 @@ fn roundtrip() {
     let deserialized = AnnotationContext::from_arrow(serialized).unwrap();
     similar_asserts::assert_eq!(arch, deserialized);
 }
+
+#[test]
+fn cityscapes() {
+    let arch = AnnotationContext::cityscapes();
+
+    let context = arch.context.as_ref().unwrap();
+    let context = components::AnnotationContext::from_arrow(&context.array).unwrap();
+    let classes = &context[0].0;
+    assert_eq!(classes.len(), 34);
+
+    let road = &classes[7].class_description;
+    assert_eq!(road.info.id, 7);
+    assert_eq!(road.info.label.as_ref().unwrap().as_str(), "road");
+    assert_eq!(road.info.color, Some(Rgba32::from_rgb(128, 64, 128)));
+}
+
+#[cfg(feature = "label_maps")]
+#[test]
+fn from_coco_categories() {
+    let contents = br#"{
+        "images": [],
+        "categories": [
+            {"id": 1, "name": "person", "keypoints": ["nose", "left_eye"], "skeleton": [[1, 2]]},
+            {"id": 2, "name": "bicycle"}
+        ]
+    }"#;
+
+    let expected = AnnotationContext::new([
+        ClassDescription {
+            info: (1, "person").into(),
+            keypoint_annotations: vec![(0, "nose").into(), (1, "left_eye").into()],
+            keypoint_connections: KeypointPair::vec_from([(0, 1)]),
+        },
+        (2, "bicycle").into(),
+    ]);
+
+    let arch = AnnotationContext::from_coco_categories(contents).unwrap();
+    similar_asserts::assert_eq!(expected, arch);
+
+    assert!(AnnotationContext::from_coco_categories(br#"{"images": []}"#).is_err());
+}
+
+#[cfg(feature = "label_maps")]
+#[test]
+fn from_yaml_label_map() {
+    let expected = AnnotationContext::new([
+        ClassDescription::from((0, "person")),
+        ClassDescription::from((1, "bicycle")),
+    ]);
+
+    let list = b"names: [person, bicycle]";
+    similar_asserts::assert_eq!(
+        expected,
+        AnnotationContext::from_yaml_label_map(list).unwrap()
+    );
+
+    let map = b"0: person\n1: bicycle\n";
+    similar_asserts::assert_eq!(
+        expected,
+        AnnotationContext::from_yaml_label_map(map).unwrap()
+    );
+
+    let with_colors =
+        b"0: {name: person, color: [255, 0, 0]}\n1: {name: bicycle, color: '#00ff00'}\n";
+    similar_asserts::assert_eq!(
+        AnnotationContext::new([
+            ClassDescription::from((0, "person", Rgba32::from_rgb(255, 0, 0))),
+            ClassDescription::from((1, "bicycle", Rgba32::from_rgb(0, 255, 0))),
+        ]),
+        AnnotationContext::from_yaml_label_map(with_colors).unwrap()
+    );
+}
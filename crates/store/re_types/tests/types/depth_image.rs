@@ -1,6 +1,6 @@
 use re_types::{
     archetypes::DepthImage,
-    components::{DepthMeter, ImageBuffer, ImageFormat},
+    components::{DepthMeter, ImageBuffer, ImageFormat, Range1D},
     datatypes::{self, ChannelDatatype},
     Archetype as _, AsComponents as _, ComponentBatch as _,
 };
@@ -24,12 +24,14 @@ fn depth_image_roundtrip() {
         colormap: None,
         point_fill_ratio: None,
         depth_range: None,
+        clip_range: Range1D::new(0.3, 10.0).serialized(DepthImage::descriptor_clip_range()),
     }];
 
     let all_arch_serialized = [
         DepthImage::try_from(ndarray::array![[1u8, 2, 3], [4, 5, 6]])
             .unwrap()
             .with_meter(1000.0)
+            .with_clip_range([0.3, 10.0])
             .to_arrow()
             .unwrap(),
     ];
@@ -1,6 +1,10 @@
 
 
-use re_types::{archetypes::Image, datatypes::ColorModel, Archetype as _, AsComponents as _};
+use re_types::{
+    archetypes::Image,
+    datatypes::{ChannelDatatype, ColorModel},
+    Archetype as _, AsComponents as _,
+};
 
 
 
@@ -29,6 +33,38 @@ fn image_roundtrip() {
     }
 }
 
+#[test]
+fn strided_image() {
+    // 2x2 RGB image, with rows padded to 8 bytes.
+    let bytes = [
+        1, 2, 3, 4, 5, 6, 0, 0, //
+        7, 8, 9, 10, 11, 12, 0, 0, //
+    ];
+
+    let image = Image::from_color_model_and_strided_bytes(
+        &bytes,
+        [2, 2],
+        8,
+        ColorModel::RGB,
+        ChannelDatatype::U8,
+    )
+    .unwrap();
+
+    similar_asserts::assert_eq!(
+        Image::from_rgb24(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12], [2, 2]),
+        image
+    );
+
+    assert!(Image::from_color_model_and_strided_bytes(
+        &bytes,
+        [2, 2],
+        4,
+        ColorModel::RGB,
+        ChannelDatatype::U8,
+    )
+    .is_err());
+}
+
 #[test]
 #[cfg(feature = "image")]
 fn dynamic_image_roundtrip() {
@@ -20,6 +20,7 @@ mod tensor;
 mod text_document;
 mod transform3d;
 mod view_coordinates;
+mod waveform;
 
 // Tests of other things
 
@@ -71,3 +71,62 @@ fn roundtrip() {
     let deserialized = Mesh3D::from_arrow(serialized).unwrap();
     similar_asserts::assert_eq!(expected, deserialized);
 }
+
+#[test]
+fn from_ply() {
+    let ply = b"ply
+format ascii 1.0
+element vertex 4
+property float x
+property float y
+property float z
+property float nx
+property float ny
+property float nz
+property uchar red
+property uchar green
+property uchar blue
+property float s
+property float t
+element face 1
+property list uchar int vertex_indices
+end_header
+0 0 0 0 0 1 255 0 0 0 0
+1 0 0 0 0 1 0 255 0 1 0
+1 1 0 0 0 1 0 0 255 1 1
+0 1 0 0 0 1 255 255 255 0 1
+4 0 1 2 3
+";
+
+    let expected = Mesh3D::new([
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [0.0, 1.0, 0.0],
+    ])
+    .with_triangle_indices([UVec3D::new(0, 1, 2), UVec3D::new(0, 2, 3)])
+    .with_vertex_normals([[0.0, 0.0, 1.0]; 4])
+    .with_vertex_colors([
+        Color::from_rgb(255, 0, 0),
+        Color::from_rgb(0, 255, 0),
+        Color::from_rgb(0, 0, 255),
+        Color::from_rgb(255, 255, 255),
+    ])
+    // `.ply` texture coordinates have their origin at the bottom-left.
+    .with_vertex_texcoords([[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]]);
+
+    let mesh = Mesh3D::from_file_contents(ply).unwrap();
+    similar_asserts::assert_eq!(expected, mesh);
+
+    // Point clouds aren't meshes.
+    let point_cloud = b"ply
+format ascii 1.0
+element vertex 1
+property float x
+property float y
+property float z
+end_header
+0 0 0
+";
+    assert!(Mesh3D::from_file_contents(point_cloud).is_err());
+}
@@ -13,11 +13,20 @@ fn roundtrip() {
             .serialized(Pinhole::descriptor_resolution()),
         camera_xyz: components::ViewCoordinates::RDF.serialized(Pinhole::descriptor_camera_xyz()),
         image_plane_distance: None,
+        distortion_model: components::DistortionModel::Fisheye
+            .serialized(Pinhole::descriptor_distortion_model()),
+        distortion_coefficients: vec![
+            components::DistortionCoefficient::from(0.1),
+            components::DistortionCoefficient::from(-0.05),
+        ]
+        .serialized(Pinhole::descriptor_distortion_coefficients()),
     };
 
     let arch = Pinhole::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
         .with_resolution([1.0, 2.0])
-        .with_camera_xyz(components::ViewCoordinates::RDF);
+        .with_camera_xyz(components::ViewCoordinates::RDF)
+        .with_distortion_model(components::DistortionModel::Fisheye)
+        .with_distortion_coefficients([0.1, -0.05]);
     similar_asserts::assert_eq!(expected, arch);
 
     eprintln!("arch = {arch:#?}");
@@ -41,3 +50,38 @@ fn from_focal_length_and_resolution() {
             .with_resolution([3.0, 4.0])
     );
 }
+
+#[cfg(feature = "glam")]
+#[test]
+fn distortion_roundtrip() {
+    use components::DistortionModel;
+
+    let cases = [
+        (DistortionModel::PlumbBob, vec![]),
+        (DistortionModel::PlumbBob, vec![-0.28, 0.07, 2e-4, 2e-5]),
+        (
+            DistortionModel::PlumbBob,
+            vec![0.5, -0.1, 1e-3, -1e-3, 0.01, 0.4, -0.05, 0.01],
+        ),
+        (DistortionModel::Fisheye, vec![0.1, -0.05, 0.01, 0.0]),
+    ];
+
+    for (model, coefficients) in cases {
+        for point in [
+            glam::vec2(0.0, 0.0),
+            glam::vec2(0.3, -0.2),
+            glam::vec2(-0.5, 0.4),
+        ] {
+            let distorted = model.distort(&coefficients, point);
+            if coefficients.is_empty() {
+                assert_eq!(distorted, point);
+            }
+
+            let undistorted = model.undistort(&coefficients, distorted).unwrap();
+            assert!(
+                undistorted.abs_diff_eq(point, 1e-4),
+                "{model}: {point} -> {distorted} -> {undistorted}"
+            );
+        }
+    }
+}
@@ -2,7 +2,10 @@ use std::f32::consts::TAU;
 
 use re_types::{
     archetypes::Transform3D,
-    components::{RotationAxisAngle, Scale3D, TransformMat3x3, TransformRelation, Translation3D},
+    components::{
+        EulerConvention, RotationAxisAngle, RotationEuler, Scale3D, TransformMat3x3,
+        TransformRelation, Translation3D,
+    },
     datatypes::Angle,
     Archetype as _, AsComponents as _, ComponentBatch as _,
 };
@@ -18,6 +21,10 @@ fn roundtrip() {
     let rotation_axis_angle_serialized =
         RotationAxisAngle::new([0.2, 0.2, 0.8], Angle::from_radians(0.5 * TAU))
             .serialized(Transform3D::descriptor_rotation_axis_angle());
+    let rotation_euler_serialized =
+        RotationEuler::new(0.1, 0.2, 0.3).serialized(Transform3D::descriptor_rotation_euler());
+    let euler_convention_serialized =
+        EulerConvention::Xyz.serialized(Transform3D::descriptor_euler_convention());
     let relation_child_from_parent_serialized =
         TransformRelation::ChildFromParent.serialized(Transform3D::descriptor_relation());
     let relation_parent_from_child_serialized =
@@ -53,6 +60,12 @@ fn roundtrip() {
             relation: relation_parent_from_child_serialized.clone(),
             ..Transform3D::clear_fields()
         }, //
+        Transform3D {
+            translation: translation_serialized.clone(),
+            rotation_euler: rotation_euler_serialized.clone(),
+            euler_convention: euler_convention_serialized.clone(),
+            ..Transform3D::clear_fields()
+        }, //
     ];
 
     let all_arch = [
@@ -73,6 +86,9 @@ fn roundtrip() {
             .with_relation(TransformRelation::ChildFromParent),
         Transform3D::from_mat3x3([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
             .with_relation(TransformRelation::ParentFromChild),
+        Transform3D::from_translation([1.0, 2.0, 3.0])
+            .with_rotation_euler(RotationEuler::new(0.1, 0.2, 0.3))
+            .with_euler_convention(EulerConvention::Xyz),
     ];
 
     for (expected, arch) in all_expected.into_iter().zip(all_arch) {
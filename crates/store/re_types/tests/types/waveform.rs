@@ -0,0 +1,35 @@
+use re_types::{
+    archetypes::Waveform, components, Archetype as _, AsComponents as _, ComponentBatch as _,
+};
+
+#[test]
+fn roundtrip() {
+    let expected = Waveform {
+        samples: vec![
+            components::Scalar::from(1.0_f64), //
+            components::Scalar::from(-1.0_f64),
+            components::Scalar::from(2.0_f64),
+            components::Scalar::from(-2.0_f64),
+        ]
+        .serialized(Waveform::descriptor_samples()),
+        sample_rate: components::SampleRate::from(1000.0_f64)
+            .serialized(Waveform::descriptor_sample_rate()),
+        channel_count: components::ChannelCount::from(2)
+            .serialized(Waveform::descriptor_channel_count()),
+    };
+
+    let arch = Waveform::new([1.0_f64, -1.0, 2.0, -2.0], 1000.0_f64).with_channel_count(2);
+    similar_asserts::assert_eq!(expected, arch);
+
+    eprintln!("arch = {arch:#?}");
+    let serialized = arch.to_arrow().unwrap();
+    for (field, array) in &serialized {
+        // NOTE: Keep those around please, very useful when debugging.
+        // eprintln!("field = {field:#?}");
+        // eprintln!("array = {array:#?}");
+        eprintln!("{} = {array:#?}", field.name());
+    }
+
+    let deserialized = Waveform::from_arrow(serialized).unwrap();
+    similar_asserts::assert_eq!(expected, deserialized);
+}
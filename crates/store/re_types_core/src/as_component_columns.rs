@@ -0,0 +1,49 @@
+use crate::{SerializationResult, SerializedComponentColumn};
+
+/// Describes the interface for interpreting an object as a set of component columns, ready to be
+/// logged all at once with `RecordingStream::send_columns`.
+///
+/// This is the columnar counterpart of [`AsComponents`]: where [`AsComponents`] describes a single
+/// row's worth of data, [`AsComponentColumns`] describes many rows at once.
+///
+/// ## Deriving
+///
+/// Rather than implementing this trait manually, derive it on a plain struct made of `Vec<C>`
+/// fields, where every `C` is a [`Component`]:
+/// ```ignore
+/// #[derive(rerun::AsComponentColumns)]
+/// #[rerun(archetype = "my_robot.State")]
+/// struct RobotStates {
+///     positions: Vec<rerun::components::Position3D>,
+///     colors: Vec<rerun::components::Color>,
+/// }
+///
+/// let times = rerun::TimeColumn::new_sequence("step", 0..states.positions.len() as i64);
+/// rec.send_columns("robot", [times], states.as_component_columns()?)?;
+/// ```
+///
+/// Every field becomes one column, where each row holds a single instance of the component.
+/// Every field also gets a `descriptor_<field>` method, just like generated archetypes.
+///
+/// The derive macro accepts the following attributes:
+/// * `#[rerun(archetype = "…")]` on the struct: the archetype name used in the descriptors of all
+///   the columns. Defaults to the name of the struct.
+/// * `#[rerun(component = "…")]` on a field: the component identifier of that column.
+///   Defaults to `<short archetype name>:<field name>`, e.g. `State:positions`.
+/// * `#[rerun(crate = "…")]` on the struct: the path of the `rerun` crate, for when it is not
+///   available as `::rerun`, e.g. `re_sdk`.
+///
+/// [`AsComponents`]: [crate::AsComponents]
+/// [`Component`]: [crate::Component]
+pub trait AsComponentColumns {
+    /// Serializes every column of the object, in declaration order.
+    ///
+    /// All columns must have the same number of rows, and as many rows as the index columns they
+    /// are logged with.
+    fn as_component_columns(&self) -> SerializationResult<Vec<SerializedComponentColumn>>;
+}
+
+#[allow(dead_code)]
+fn assert_object_safe() {
+    let _: &dyn AsComponentColumns;
+}
@@ -24,6 +24,7 @@ mod archetype;
 pub mod arrow_helpers;
 mod arrow_string;
 pub mod arrow_zip_validity;
+mod as_component_columns;
 mod as_components;
 mod component_batch;
 mod component_descriptor;
@@ -37,6 +38,7 @@ mod view;
 pub use self::{
     archetype::{Archetype, ArchetypeName, ArchetypeReflectionMarker, ComponentIdentifier},
     arrow_string::ArrowString,
+    as_component_columns::AsComponentColumns,
     as_components::AsComponents,
     component_batch::{ComponentBatch, SerializedComponentBatch, SerializedComponentColumn},
     component_descriptor::ComponentDescriptor,
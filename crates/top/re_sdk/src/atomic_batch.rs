@@ -0,0 +1,199 @@
+use re_chunk::{Chunk, RowId};
+use re_log_types::{AtomicBatchMetadata, EntityPath, LogMsg, TimePoint};
+use re_types::AsComponents;
+
+use crate::{RecordingStream, RecordingStreamResult};
+
+/// A group of log calls that the viewer applies all at once.
+///
+/// Without it, a live viewer may render a frame in between two related log calls, e.g. showing
+/// new detection boxes on top of the previous camera image.
+///
+/// Created with [`RecordingStream::begin_atomic_batch`]. Nothing is sent until
+/// [`AtomicBatch::commit`] is called: dropping the batch discards all of its data.
+///
+/// ```
+/// # use re_sdk::external::re_log_types::example_components::{MyPoint, MyPoints};
+/// let rec = re_sdk::RecordingStreamBuilder::new("rerun_example_atomic_batch").buffered()?;
+///
+/// let mut batch = rec.begin_atomic_batch();
+/// batch.log("left", &MyPoints::new([MyPoint::new(1.0, 2.0)]))?;
+/// batch.log("right", &MyPoints::new([MyPoint::new(3.0, 4.0)]))?;
+/// batch.commit()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// All the data of a batch is logged at the same time: the time of the thread that began the
+/// batch, as it was when the batch began.
+#[must_use = "The data of an atomic batch is discarded unless it is committed"]
+pub struct AtomicBatch {
+    rec: RecordingStream,
+    timepoint: TimePoint,
+    chunks: Vec<Chunk>,
+}
+
+impl AtomicBatch {
+    pub(crate) fn new(rec: RecordingStream) -> Self {
+        let timepoint = rec.now();
+        Self {
+            rec,
+            timepoint,
+            chunks: Vec::new(),
+        }
+    }
+
+    /// Adds data to the batch.
+    ///
+    /// This behaves like [`RecordingStream::log`], except that nothing is sent before
+    /// [`Self::commit`].
+    pub fn log<AS: ?Sized + AsComponents>(
+        &mut self,
+        ent_path: impl Into<EntityPath>,
+        as_components: &AS,
+    ) -> RecordingStreamResult<()> {
+        if !crate::is_logging_enabled() {
+            return Ok(());
+        }
+        let ent_path = ent_path.into();
+        if !self.rec.is_entity_logged(&ent_path) {
+            return Ok(());
+        }
+
+        let chunk = Chunk::builder(ent_path)
+            .with_serialized_batches(
+                RowId::new(),
+                self.timepoint.clone(),
+                as_components.as_serialized_batches(),
+            )
+            .build()?;
+
+        self.chunks.push(chunk);
+
+        Ok(())
+    }
+
+    /// Adds a whole [`Chunk`] to the batch, as is.
+    pub fn send_chunk(&mut self, chunk: Chunk) {
+        self.chunks.push(chunk);
+    }
+
+    /// Number of chunks in the batch so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Whether nothing was added to the batch yet.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    /// Sends all the data of the batch.
+    pub fn commit(self) -> RecordingStreamResult<()> {
+        let Self {
+            rec,
+            timepoint: _,
+            chunks,
+        } = self;
+
+        let Some(store_info) = rec.store_info() else {
+            re_log::warn_once!("Recording disabled - call to AtomicBatch::commit() ignored");
+            return Ok(());
+        };
+
+        let msgs = chunks
+            .iter()
+            .map(|chunk| chunk.to_arrow_msg())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let metadata = AtomicBatchMetadata::new(msgs.len());
+        for msg in msgs {
+            let mut msg = LogMsg::ArrowMsg(store_info.store_id.clone(), msg);
+            metadata.insert_into(&mut msg);
+            rec.record_msg(msg);
+        }
+
+        Ok(())
+    }
+}
+
+impl RecordingStream {
+    /// Begins a group of log calls that the viewer will apply all at once.
+    ///
+    /// See [`AtomicBatch`].
+    #[inline]
+    pub fn begin_atomic_batch(&self) -> AtomicBatch {
+        AtomicBatch::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use re_chunk::ChunkBatcherConfig;
+    use re_log_types::example_components::{MyPoint, MyPoints};
+
+    use super::*;
+    use crate::RecordingStreamBuilder;
+
+    #[test]
+    fn commit_tags_all_messages() {
+        let (rec, storage) = RecordingStreamBuilder::new("rerun_example_atomic_batch")
+            .enabled(true)
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .memory()
+            .unwrap();
+
+        let mut batch = rec.begin_atomic_batch();
+        batch
+            .log("left", &MyPoints::new([MyPoint::new(1.0, 2.0)]))
+            .unwrap();
+        batch
+            .log("right", &MyPoints::new([MyPoint::new(3.0, 4.0)]))
+            .unwrap();
+        assert_eq!(2, batch.len());
+        batch.commit().unwrap();
+
+        rec.log("outside", &MyPoints::new([MyPoint::new(5.0, 6.0)]))
+            .unwrap();
+
+        let metadata = storage
+            .take()
+            .into_iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, msg) => Some(AtomicBatchMetadata::from_arrow_msg(&msg)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(3, metadata.len());
+        assert_eq!(None, metadata[2]);
+        let first = metadata[0].unwrap();
+        assert_eq!(2, first.len);
+        assert_eq!(Some(first), metadata[1]);
+    }
+
+    #[test]
+    fn dropped_batch_sends_nothing() {
+        let (rec, storage) = RecordingStreamBuilder::new("rerun_example_atomic_batch")
+            .enabled(true)
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .memory()
+            .unwrap();
+
+        let mut batch = rec.begin_atomic_batch();
+        batch
+            .log("left", &MyPoints::new([MyPoint::new(1.0, 2.0)]))
+            .unwrap();
+        drop(batch);
+
+        let num_arrow_msgs = storage
+            .take()
+            .iter()
+            .filter(|msg| matches!(msg, LogMsg::ArrowMsg(..)))
+            .count();
+        assert_eq!(0, num_arrow_msgs);
+    }
+}
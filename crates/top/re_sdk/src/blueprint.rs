@@ -0,0 +1,741 @@
+//! Building blueprints, i.e. the layout and configuration of the viewer, from code.
+//!
+//! A [`Blueprint`] is a tree of [`Container`]s with [`View`]s as leaves, plus the state of the
+//! panels around the viewport:
+//!
+//! ```
+//! use re_sdk::blueprint::{Blueprint, Container, PanelState, View};
+//!
+//! let blueprint = Blueprint::new(Container::horizontal([
+//!     View::of::<re_sdk::blueprint::views::Spatial3DView>()
+//!         .with_name("Scene")
+//!         .into(),
+//!     Container::vertical([
+//!         View::of::<re_sdk::blueprint::views::Spatial2DView>()
+//!             .with_origin("camera/image")
+//!             .into(),
+//!         View::of::<re_sdk::blueprint::views::TimeSeriesView>()
+//!             .with_origin("metrics")
+//!             .into(),
+//!     ])
+//!     .into(),
+//! ]))
+//! .with_time_panel(PanelState::Collapsed);
+//!
+//! let rec = re_sdk::RecordingStreamBuilder::new("rerun_example_blueprint").buffered()?;
+//! blueprint.send(&rec, true, true)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! This mirrors the blueprint API of the Python SDK, and logs the same entities to the blueprint store.
+//!
+//! Blueprints are currently an experimental part of the Rust SDK.
+
+use re_log_types::{ApplicationId, BlueprintActivationCommand, EntityPath, LogMsg};
+use re_types::{
+    Archetype, AsComponents, SerializedComponentBatch,
+    blueprint::{
+        archetypes::{
+            ContainerBlueprint, PanelBlueprint, ViewBlueprint, ViewContents, ViewportBlueprint,
+            VisualizerOverrides,
+        },
+        components::ContainerKind,
+    },
+    external::uuid,
+};
+
+use crate::{RecordingStream, RecordingStreamBuilder, RecordingStreamResult};
+
+pub use re_types::blueprint::{archetypes, components, components::PanelState, datatypes, views};
+
+const TOP_PANEL_PATH: &str = "top_panel";
+const BLUEPRINT_PANEL_PATH: &str = "blueprint_panel";
+const SELECTION_PANEL_PATH: &str = "selection_panel";
+const TIME_PANEL_PATH: &str = "time_panel";
+const VIEWPORT_PATH: &str = "viewport";
+
+/// The blueprint timeline, all blueprint data is logged at its start.
+const BLUEPRINT_TIMELINE: &str = "blueprint";
+
+// ---
+
+/// A view of the viewport, showing the entities matched by its contents.
+///
+/// This is an ergonomic helper on top of [`ViewBlueprint`] and [`ViewContents`].
+#[derive(Clone, Debug)]
+pub struct View {
+    id: uuid::Uuid,
+    class_identifier: String,
+    name: Option<String>,
+    origin: EntityPath,
+    contents: Vec<String>,
+    visible: Option<bool>,
+    properties: Vec<(&'static str, Vec<SerializedComponentBatch>)>,
+    defaults: Vec<SerializedComponentBatch>,
+    overrides: Vec<(EntityPath, Vec<SerializedComponentBatch>)>,
+}
+
+impl View {
+    /// A view of the given class, e.g. `"3D"` or `"TimeSeries"`.
+    ///
+    /// Prefer [`Self::of`] for the builtin view classes.
+    /// By default, the view is rooted at `/` and shows everything below its origin.
+    pub fn new(class_identifier: impl Into<String>) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4(),
+            class_identifier: class_identifier.into(),
+            name: None,
+            origin: EntityPath::root(),
+            contents: vec!["$origin/**".to_owned()],
+            visible: None,
+            properties: Vec::new(),
+            defaults: Vec::new(),
+            overrides: Vec::new(),
+        }
+    }
+
+    /// A view of the class of `V`, e.g. [`views::Spatial3DView`].
+    pub fn of<V: re_types::View>() -> Self {
+        Self::new(V::identifier().as_str())
+    }
+
+    /// The name shown in the viewer.
+    ///
+    /// If not set, the viewer picks a name based on the origin.
+    #[inline]
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The origin of the view, which is the root of its coordinate system.
+    ///
+    /// Query expressions may refer to it as `$origin`.
+    #[inline]
+    pub fn with_origin(mut self, origin: impl Into<EntityPath>) -> Self {
+        self.origin = origin.into();
+        self
+    }
+
+    /// The query expressions selecting the entities shown in the view, e.g. `["+ /world/**", "- /world/debug"]`.
+    ///
+    /// Replaces the default, which is `$origin/**`.
+    #[inline]
+    pub fn with_contents(mut self, contents: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.contents = contents.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Whether the view is visible.
+    ///
+    /// Defaults to true.
+    #[inline]
+    pub fn with_visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// Sets a view property, e.g. [`archetypes::Background`] for a 3D view.
+    ///
+    /// Setting the same property archetype again replaces it.
+    pub fn with_property<A: Archetype + AsComponents>(mut self, property: &A) -> Self {
+        let name = A::name().short_name();
+        let batches = property.as_serialized_batches();
+        if let Some((_, existing)) = self.properties.iter_mut().find(|(n, _)| *n == name) {
+            *existing = batches;
+        } else {
+            self.properties.push((name, batches));
+        }
+        self
+    }
+
+    /// Default values for components of all visualizers in this view.
+    ///
+    /// These are used instead of the fallbacks of the visualizers when a component isn't logged.
+    pub fn with_defaults(mut self, defaults: &dyn AsComponents) -> Self {
+        self.defaults.extend(defaults.as_serialized_batches());
+        self
+    }
+
+    /// Overrides components for an entity in this view, taking precedence over the logged data.
+    ///
+    /// Components with the same descriptor replace the ones set by earlier calls.
+    pub fn with_overrides(
+        mut self,
+        entity_path: impl Into<EntityPath>,
+        overrides: &dyn AsComponents,
+    ) -> Self {
+        let entity_path = entity_path.into();
+        let batches = overrides.as_serialized_batches();
+
+        if let Some((_, existing)) = self
+            .overrides
+            .iter_mut()
+            .find(|(path, _)| *path == entity_path)
+        {
+            existing.retain(|batch| batches.iter().all(|new| new.descriptor != batch.descriptor));
+            existing.extend(batches);
+        } else {
+            self.overrides.push((entity_path, batches));
+        }
+        self
+    }
+
+    /// Picks the visualizers used for an entity in this view, instead of the ones chosen by the heuristics.
+    ///
+    /// See `re_types::blueprint::archetypes::VisualizerOverrides` for the available visualizer names.
+    pub fn with_visualizers(
+        self,
+        entity_path: impl Into<EntityPath>,
+        visualizers: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.with_overrides(
+            entity_path,
+            &VisualizerOverrides::new(visualizers.into_iter().map(Into::<String>::into)),
+        )
+    }
+
+    /// Where the view is logged in the blueprint store.
+    pub fn blueprint_path(&self) -> EntityPath {
+        EntityPath::from(format!("view/{}", self.id))
+    }
+
+    fn log_to_stream(&self, stream: &RecordingStream) -> RecordingStreamResult<()> {
+        let path = self.blueprint_path();
+
+        stream.log(
+            path.join(&EntityPath::from_single_string(
+                ViewContents::name().short_name(),
+            )),
+            &ViewContents::new(self.contents.iter().map(String::as_str)),
+        )?;
+
+        let mut view = ViewBlueprint::new(self.class_identifier.as_str())
+            .with_space_origin(self.origin.to_string());
+        if let Some(name) = &self.name {
+            view = view.with_display_name(name.as_str());
+        }
+        if let Some(visible) = self.visible {
+            view = view.with_visible(visible);
+        }
+        stream.log(path.clone(), &view)?;
+
+        for (name, property) in &self.properties {
+            stream.log(path.join(&EntityPath::from_single_string(*name)), property)?;
+        }
+
+        if !self.defaults.is_empty() {
+            stream.log(
+                path.join(&EntityPath::from_single_string("defaults")),
+                &self.defaults,
+            )?;
+        }
+
+        let overrides_path = path
+            .join(&EntityPath::from_single_string(
+                ViewContents::name().short_name(),
+            ))
+            .join(&EntityPath::from_single_string("overrides"));
+        for (entity_path, overrides) in &self.overrides {
+            stream.log(overrides_path.join(entity_path), overrides)?;
+        }
+
+        Ok(())
+    }
+}
+
+// ---
+
+/// Either a [`Container`] or a [`View`], i.e. anything that can be put in a container.
+#[derive(Clone, Debug)]
+pub enum ContainerLike {
+    /// A nested container.
+    Container(Container),
+
+    /// A view.
+    View(View),
+}
+
+impl From<Container> for ContainerLike {
+    #[inline]
+    fn from(container: Container) -> Self {
+        Self::Container(container)
+    }
+}
+
+impl From<View> for ContainerLike {
+    #[inline]
+    fn from(view: View) -> Self {
+        Self::View(view)
+    }
+}
+
+impl ContainerLike {
+    /// Where the container or view is logged in the blueprint store.
+    pub fn blueprint_path(&self) -> EntityPath {
+        match self {
+            Self::Container(container) => container.blueprint_path(),
+            Self::View(view) => view.blueprint_path(),
+        }
+    }
+
+    /// Wraps a view in a tab container, since the viewport root has to be a container.
+    fn into_container(self) -> Container {
+        match self {
+            Self::Container(container) => container,
+            Self::View(view) => Container::tabs([view.into()]),
+        }
+    }
+
+    fn name(&self) -> Option<&str> {
+        match self {
+            Self::Container(container) => container.name.as_deref(),
+            Self::View(view) => view.name.as_deref(),
+        }
+    }
+
+    fn log_to_stream(&self, stream: &RecordingStream) -> RecordingStreamResult<()> {
+        match self {
+            Self::Container(container) => container.log_to_stream(stream),
+            Self::View(view) => view.log_to_stream(stream),
+        }
+    }
+}
+
+/// Lays out views and other containers.
+///
+/// This is an ergonomic helper on top of [`ContainerBlueprint`].
+#[derive(Clone, Debug)]
+pub struct Container {
+    id: uuid::Uuid,
+    kind: ContainerKind,
+    contents: Vec<ContainerLike>,
+    name: Option<String>,
+    column_shares: Option<Vec<f32>>,
+    row_shares: Option<Vec<f32>>,
+    grid_columns: Option<u32>,
+    active_tab: Option<usize>,
+    visible: Option<bool>,
+}
+
+impl Container {
+    /// A container of the given kind.
+    ///
+    /// Prefer [`Self::horizontal`], [`Self::vertical`], [`Self::grid`] or [`Self::tabs`].
+    pub fn new(kind: ContainerKind, contents: impl IntoIterator<Item = ContainerLike>) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4(),
+            kind,
+            contents: contents.into_iter().collect(),
+            name: None,
+            column_shares: None,
+            row_shares: None,
+            grid_columns: None,
+            active_tab: None,
+            visible: None,
+        }
+    }
+
+    /// Lays out its contents side by side.
+    #[inline]
+    pub fn horizontal(contents: impl IntoIterator<Item = ContainerLike>) -> Self {
+        Self::new(ContainerKind::Horizontal, contents)
+    }
+
+    /// Lays out its contents on top of each other.
+    #[inline]
+    pub fn vertical(contents: impl IntoIterator<Item = ContainerLike>) -> Self {
+        Self::new(ContainerKind::Vertical, contents)
+    }
+
+    /// Lays out its contents in a grid.
+    #[inline]
+    pub fn grid(contents: impl IntoIterator<Item = ContainerLike>) -> Self {
+        Self::new(ContainerKind::Grid, contents)
+    }
+
+    /// Shows one of its contents at a time, in tabs.
+    #[inline]
+    pub fn tabs(contents: impl IntoIterator<Item = ContainerLike>) -> Self {
+        Self::new(ContainerKind::Tabs, contents)
+    }
+
+    /// The name shown in the viewer.
+    #[inline]
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The fraction of the width taken by each column, relative to their sum.
+    ///
+    /// Only applies to horizontal and grid containers.
+    #[inline]
+    pub fn with_column_shares(mut self, shares: impl IntoIterator<Item = f32>) -> Self {
+        self.column_shares = Some(shares.into_iter().collect());
+        self
+    }
+
+    /// The fraction of the height taken by each row, relative to their sum.
+    ///
+    /// Only applies to vertical and grid containers.
+    #[inline]
+    pub fn with_row_shares(mut self, shares: impl IntoIterator<Item = f32>) -> Self {
+        self.row_shares = Some(shares.into_iter().collect());
+        self
+    }
+
+    /// The number of columns of a grid container.
+    ///
+    /// If not set, the viewer picks a number of columns that keeps the cells close to square.
+    #[inline]
+    pub fn with_grid_columns(mut self, grid_columns: u32) -> Self {
+        self.grid_columns = Some(grid_columns);
+        self
+    }
+
+    /// The index of the tab shown by a tab container.
+    ///
+    /// See also [`Self::with_active_tab_named`].
+    #[inline]
+    pub fn with_active_tab(mut self, index: usize) -> Self {
+        self.active_tab = Some(index);
+        self
+    }
+
+    /// Shows the first tab with the given name in a tab container.
+    ///
+    /// Does nothing if there's no such tab.
+    pub fn with_active_tab_named(mut self, name: &str) -> Self {
+        if let Some(index) = self
+            .contents
+            .iter()
+            .position(|content| content.name() == Some(name))
+        {
+            self.active_tab = Some(index);
+        } else {
+            re_log::warn!("Active tab {name:?} not found in the container contents");
+        }
+        self
+    }
+
+    /// Whether the container is visible.
+    ///
+    /// Defaults to true.
+    #[inline]
+    pub fn with_visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// Where the container is logged in the blueprint store.
+    pub fn blueprint_path(&self) -> EntityPath {
+        EntityPath::from(format!("container/{}", self.id))
+    }
+
+    fn log_to_stream(&self, stream: &RecordingStream) -> RecordingStreamResult<()> {
+        for content in &self.contents {
+            content.log_to_stream(stream)?;
+        }
+
+        let mut container = ContainerBlueprint::new(self.kind)
+            .with_contents(
+                self.contents
+                    .iter()
+                    .map(|content| content.blueprint_path().to_string()),
+            )
+            .with_visible(self.visible.unwrap_or(true));
+        if let Some(name) = &self.name {
+            container = container.with_display_name(name.as_str());
+        }
+        if let Some(column_shares) = &self.column_shares {
+            container = container.with_col_shares(column_shares.iter().copied());
+        }
+        if let Some(row_shares) = &self.row_shares {
+            container = container.with_row_shares(row_shares.iter().copied());
+        }
+        if let Some(grid_columns) = self.grid_columns {
+            container = container.with_grid_columns(grid_columns);
+        }
+        if let Some(active_tab) = self.active_tab.and_then(|index| self.contents.get(index)) {
+            container = container.with_active_tab(active_tab.blueprint_path().to_string());
+        }
+
+        stream.log(self.blueprint_path(), &container)
+    }
+}
+
+// ---
+
+/// The layout of the viewport, and the state of the panels around it.
+///
+/// This is an ergonomic helper on top of [`ViewportBlueprint`] and [`PanelBlueprint`].
+#[derive(Clone, Debug, Default)]
+pub struct Blueprint {
+    root_container: Option<Container>,
+    auto_layout: Option<bool>,
+    auto_views: Option<bool>,
+    top_panel: Option<PanelState>,
+    blueprint_panel: Option<PanelState>,
+    selection_panel: Option<PanelState>,
+    time_panel: Option<PanelState>,
+}
+
+impl Blueprint {
+    /// A blueprint with the given container or view at the root of the viewport.
+    ///
+    /// Views are put in a tab container, since the root of the viewport has to be a container.
+    pub fn new(root: impl Into<ContainerLike>) -> Self {
+        Self {
+            root_container: Some(root.into().into_container()),
+            ..Default::default()
+        }
+    }
+
+    /// A blueprint without any views, where the viewer creates and lays out views using its heuristics.
+    pub fn auto() -> Self {
+        Self {
+            auto_layout: Some(true),
+            auto_views: Some(true),
+            ..Default::default()
+        }
+    }
+
+    /// Whether the viewer may change the layout of the containers, e.g. when views are added.
+    ///
+    /// Defaults to false if there's a root container.
+    #[inline]
+    pub fn with_auto_layout(mut self, auto_layout: bool) -> Self {
+        self.auto_layout = Some(auto_layout);
+        self
+    }
+
+    /// Whether the viewer may add views for data that isn't shown by any view yet.
+    ///
+    /// Defaults to false if there's a root container.
+    #[inline]
+    pub fn with_auto_views(mut self, auto_views: bool) -> Self {
+        self.auto_views = Some(auto_views);
+        self
+    }
+
+    /// State of the top panel, with the menu and the recording controls.
+    #[inline]
+    pub fn with_top_panel(mut self, state: PanelState) -> Self {
+        self.top_panel = Some(state);
+        self
+    }
+
+    /// State of the blueprint panel on the left.
+    #[inline]
+    pub fn with_blueprint_panel(mut self, state: PanelState) -> Self {
+        self.blueprint_panel = Some(state);
+        self
+    }
+
+    /// State of the selection panel on the right.
+    #[inline]
+    pub fn with_selection_panel(mut self, state: PanelState) -> Self {
+        self.selection_panel = Some(state);
+        self
+    }
+
+    /// State of the time panel at the bottom.
+    #[inline]
+    pub fn with_time_panel(mut self, state: PanelState) -> Self {
+        self.time_panel = Some(state);
+        self
+    }
+
+    /// Collapses the blueprint, selection and time panels that weren't configured otherwise.
+    ///
+    /// Useful to give all the space to the views.
+    pub fn with_collapsed_panels(mut self) -> Self {
+        for panel in [
+            &mut self.blueprint_panel,
+            &mut self.selection_panel,
+            &mut self.time_panel,
+        ] {
+            panel.get_or_insert(PanelState::Collapsed);
+        }
+        self
+    }
+
+    /// Logs the blueprint to a blueprint stream, see [`RecordingStreamBuilder::blueprint`].
+    pub fn log_to_stream(&self, stream: &RecordingStream) -> RecordingStreamResult<()> {
+        stream.set_time_sequence(BLUEPRINT_TIMELINE, 0);
+
+        let mut viewport = ViewportBlueprint::new();
+        if let Some(root_container) = &self.root_container {
+            root_container.log_to_stream(stream)?;
+            viewport =
+                viewport.with_root_container(re_types::datatypes::Uuid::from(root_container.id));
+        }
+        if let Some(auto_layout) = self.auto_layout {
+            viewport = viewport.with_auto_layout(auto_layout);
+        }
+        if let Some(auto_views) = self.auto_views {
+            viewport = viewport.with_auto_views(auto_views);
+        }
+        stream.log(VIEWPORT_PATH, &viewport)?;
+
+        for (path, state) in [
+            (TOP_PANEL_PATH, self.top_panel),
+            (BLUEPRINT_PANEL_PATH, self.blueprint_panel),
+            (SELECTION_PANEL_PATH, self.selection_panel),
+            (TIME_PANEL_PATH, self.time_panel),
+        ] {
+            if let Some(state) = state {
+                stream.log(path, &PanelBlueprint::new().with_state(state))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Log messages of a new blueprint store for `application_id`, ending with the activation command.
+    ///
+    /// Data loaders can send these along with the data they load, to show it with a sensible layout.
+    /// Returns no messages if logging is disabled, e.g. with the `RERUN` environment variable.
+    pub fn to_log_msgs(
+        &self,
+        application_id: impl Into<ApplicationId>,
+        make_active: bool,
+        make_default: bool,
+    ) -> RecordingStreamResult<Vec<LogMsg>> {
+        let Some((blueprint_id, mut msgs)) = self.to_blueprint_msgs(application_id.into())? else {
+            return Ok(Vec::new());
+        };
+        msgs.push(
+            BlueprintActivationCommand {
+                blueprint_id,
+                make_active,
+                make_default,
+            }
+            .into(),
+        );
+        Ok(msgs)
+    }
+
+    /// Sends the blueprint through `rec`, for the application of `rec`.
+    ///
+    /// With `make_active`, the viewer immediately switches to this blueprint.
+    /// With `make_default`, the viewer goes back to this blueprint when the user resets the blueprint.
+    pub fn send(
+        &self,
+        rec: &RecordingStream,
+        make_active: bool,
+        make_default: bool,
+    ) -> RecordingStreamResult<()> {
+        let Some(store_info) = rec.store_info() else {
+            re_log::debug!("Recording disabled - call to Blueprint::send() ignored");
+            return Ok(());
+        };
+
+        if let Some((blueprint_id, msgs)) =
+            self.to_blueprint_msgs(store_info.application_id().clone())?
+        {
+            rec.send_blueprint(
+                msgs,
+                BlueprintActivationCommand {
+                    blueprint_id,
+                    make_active,
+                    make_default,
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    fn to_blueprint_msgs(
+        &self,
+        application_id: ApplicationId,
+    ) -> RecordingStreamResult<Option<(re_log_types::StoreId, Vec<LogMsg>)>> {
+        let (stream, storage) = RecordingStreamBuilder::new(application_id)
+            .blueprint()
+            .memory()?;
+        self.log_to_stream(&stream)?;
+
+        let msgs = storage.take();
+        Ok(storage.store_id().map(|blueprint_id| (blueprint_id, msgs)))
+    }
+}
+
+impl From<Container> for Blueprint {
+    #[inline]
+    fn from(container: Container) -> Self {
+        Self::new(container)
+    }
+}
+
+impl From<View> for Blueprint {
+    #[inline]
+    fn from(view: View) -> Self {
+        Self::new(view)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::Chunk;
+    use re_sdk::blueprint::views::{Spatial3DView, TimeSeriesView};
+
+    use super::*;
+
+    #[test]
+    fn logs_layout() {
+        let scene = View::of::<Spatial3DView>()
+            .with_name("Scene")
+            .with_origin("world")
+            .with_property(&archetypes::Background::new(
+                components::BackgroundKind::SolidColor,
+            ))
+            .with_visualizers("world/points", ["Points3D"]);
+        let plot = View::of::<TimeSeriesView>().with_origin("metrics");
+
+        let scene_path = scene.blueprint_path();
+        let plot_path = plot.blueprint_path();
+
+        let blueprint = Blueprint::new(
+            Container::tabs([scene.into(), plot.into()]).with_active_tab_named("Scene"),
+        )
+        .with_collapsed_panels();
+
+        let msgs = blueprint
+            .to_log_msgs("rerun_example_blueprint", true, true)
+            .unwrap();
+
+        assert!(matches!(msgs.first(), Some(LogMsg::SetStoreInfo(_))));
+        assert!(matches!(
+            msgs.last(),
+            Some(LogMsg::BlueprintActivationCommand(cmd)) if cmd.make_active && cmd.make_default
+        ));
+
+        let entity_paths = msgs
+            .iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, msg) => {
+                    Some(Chunk::from_arrow_msg(msg).unwrap().entity_path().clone())
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        for expected in [
+            scene_path.clone(),
+            scene_path.join(&"ViewContents".into()),
+            scene_path.join(&"Background".into()),
+            EntityPath::from(format!("{scene_path}/ViewContents/overrides/world/points")),
+            plot_path,
+            EntityPath::from(VIEWPORT_PATH),
+            EntityPath::from(TIME_PANEL_PATH),
+        ] {
+            assert!(
+                entity_paths.contains(&expected),
+                "{expected} not in {entity_paths:?}"
+            );
+        }
+        assert!(!entity_paths.contains(&EntityPath::from(TOP_PANEL_PATH)));
+    }
+}
@@ -0,0 +1,297 @@
+use std::fmt;
+
+use nohash_hasher::IntMap;
+use parking_lot::Mutex;
+
+use re_chunk::external::arrow::array::BooleanArray;
+use re_chunk::{Chunk, ChunkBatcherConfig, ChunkId};
+use re_log_types::{
+    EntityPathFilter, EntityPathHash, LogMsg, ResolvedEntityPathFilter, StoreKind, TimelineName,
+};
+
+use crate::sink::LogSink;
+
+/// Decides which data a [`FilteredSink`] lets through.
+///
+/// Everything goes through by default.
+#[derive(Clone, Debug, Default)]
+pub struct SinkFilter {
+    entities: Option<ResolvedEntityPathFilter>,
+    min_interval: Option<(TimelineName, i64)>,
+}
+
+impl SinkFilter {
+    /// Only lets through the entities that match the given filter, e.g.
+    /// `EntityPathFilter::parse_forgiving("+ /telemetry/**\n- /telemetry/debug/**")`.
+    #[inline]
+    pub fn with_entities(mut self, filter: EntityPathFilter) -> Self {
+        self.entities = Some(filter.resolve_without_substitutions());
+        self
+    }
+
+    /// Downsamples the data of each entity: rows logged less than `min_interval` after the
+    /// previous row that was let through for the same entity are dropped.
+    ///
+    /// The interval is measured on the given timeline, in its own units: nanoseconds for
+    /// temporal timelines, ticks for sequence timelines.
+    /// Data that isn't logged on this timeline, and static data, is never downsampled.
+    #[inline]
+    pub fn with_min_interval(
+        mut self,
+        timeline: impl Into<TimelineName>,
+        min_interval: i64,
+    ) -> Self {
+        self.min_interval = Some((timeline.into(), min_interval));
+        self
+    }
+
+    /// Same as [`Self::with_min_interval`], for temporal timelines.
+    #[inline]
+    pub fn with_min_interval_secs(
+        self,
+        timeline: impl Into<TimelineName>,
+        min_interval: impl Into<f64>,
+    ) -> Self {
+        let min_interval = (min_interval.into() * 1e9).round() as i64;
+        self.with_min_interval(timeline, min_interval)
+    }
+}
+
+/// Wraps another sink and only forwards the data that passes a [`SinkFilter`].
+///
+/// Combined with [`crate::sink::MultiSink`], this allows sending different data to each sink, e.g.
+/// everything to disk, but only downsampled telemetry over the network:
+/// ```no_run
+/// # use re_sdk::external::re_log_types::EntityPathFilter;
+/// # use re_sdk::sink::{FileSink, FilteredSink, GrpcSink, SinkFilter};
+/// let rec = re_sdk::RecordingStreamBuilder::new("rerun_example_filtered_sink").set_sinks((
+///     FileSink::new("recording.rrd")?,
+///     FilteredSink::new(
+///         GrpcSink::default(),
+///         SinkFilter::default()
+///             .with_entities(EntityPathFilter::parse_forgiving("+ /telemetry/**"))
+///             .with_min_interval_secs("log_time", 0.1),
+///     ),
+/// ))?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// Store infos, blueprints and blueprint activation commands are always forwarded.
+pub struct FilteredSink {
+    sink: Box<dyn LogSink>,
+    filter: SinkFilter,
+
+    /// Time of the last row that was let through, per entity, see [`SinkFilter::with_min_interval`].
+    last_times: Mutex<IntMap<EntityPathHash, i64>>,
+}
+
+impl FilteredSink {
+    /// Filters the data sent to `sink`.
+    #[inline]
+    pub fn new(sink: impl LogSink, filter: SinkFilter) -> Self {
+        Self {
+            sink: Box::new(sink),
+            filter,
+            last_times: Default::default(),
+        }
+    }
+
+    /// Applies the filter, returning `None` if nothing is left.
+    fn filter(&self, msg: LogMsg) -> Option<LogMsg> {
+        re_tracing::profile_function!();
+
+        let LogMsg::ArrowMsg(store_id, arrow_msg) = &msg else {
+            return Some(msg);
+        };
+        if store_id.kind() == StoreKind::Blueprint {
+            return Some(msg);
+        }
+
+        let chunk = match Chunk::from_arrow_msg(arrow_msg) {
+            Ok(chunk) => chunk,
+            Err(err) => {
+                re_log::warn_once!("Failed to filter chunk, forwarding it as is: {err}");
+                return Some(msg);
+            }
+        };
+
+        if let Some(entities) = &self.filter.entities
+            && !entities.matches(chunk.entity_path())
+        {
+            return None;
+        }
+
+        let Some((timeline, min_interval)) = &self.filter.min_interval else {
+            return Some(msg);
+        };
+        let Some(time_column) = chunk.timelines().get(timeline) else {
+            return Some(msg);
+        };
+
+        let keep = {
+            let mut last_times = self.last_times.lock();
+            let last_time = last_times
+                .entry(chunk.entity_path().hash())
+                .or_insert(i64::MIN);
+
+            time_column
+                .times_raw()
+                .iter()
+                .map(|&time| {
+                    // Time going backwards is most likely a new run: start over.
+                    let keep =
+                        time < *last_time || *last_time <= time.saturating_sub(*min_interval);
+                    if keep {
+                        *last_time = time;
+                    }
+                    keep
+                })
+                .collect::<Vec<_>>()
+        };
+
+        if keep.iter().all(|&keep| keep) {
+            return Some(msg);
+        }
+        if !keep.iter().any(|&keep| keep) {
+            return None;
+        }
+
+        let chunk = chunk
+            .filtered(&BooleanArray::from(keep))?
+            .with_id(ChunkId::new());
+        match chunk.to_arrow_msg() {
+            Ok(arrow_msg) => Some(LogMsg::ArrowMsg(store_id.clone(), arrow_msg)),
+            Err(err) => {
+                re_log::warn_once!("Failed to filter chunk, dropping it: {err}");
+                None
+            }
+        }
+    }
+}
+
+impl LogSink for FilteredSink {
+    #[inline]
+    fn send(&self, msg: LogMsg) {
+        if let Some(msg) = self.filter(msg) {
+            self.sink.send(msg);
+        }
+    }
+
+    #[inline]
+    fn send_all(&self, messages: Vec<LogMsg>) {
+        self.sink.send_all(
+            messages
+                .into_iter()
+                .filter_map(|msg| self.filter(msg))
+                .collect(),
+        );
+    }
+
+    #[inline]
+    fn flush_blocking(&self) {
+        self.sink.flush_blocking();
+    }
+
+    #[inline]
+    fn drop_if_disconnected(&self) {
+        self.sink.drop_if_disconnected();
+    }
+
+    #[inline]
+    fn default_batcher_config(&self) -> ChunkBatcherConfig {
+        self.sink.default_batcher_config()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl fmt::Debug for FilteredSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FilteredSink")
+            .field("filter", &self.filter)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::sync::Arc;
+
+    use re_chunk::RowId;
+    use re_log_types::example_components::{MyPoint, MyPoints};
+    use re_log_types::{StoreId, TimePoint, Timeline};
+
+    use super::*;
+    use crate::sink::CallbackSink;
+
+    fn received_rows(filter: SinkFilter, entity_paths: &[&str], frames: i64) -> Vec<(String, i64)> {
+        let received = Arc::new(Mutex::new(Vec::new()));
+
+        let sink = FilteredSink::new(
+            CallbackSink::new({
+                let received = received.clone();
+                move |msgs: &[LogMsg]| {
+                    for msg in msgs {
+                        if let LogMsg::ArrowMsg(_, msg) = msg {
+                            let chunk = Chunk::from_arrow_msg(msg).unwrap();
+                            for &time in chunk.timelines()[&TimelineName::new("frame")].times_raw()
+                            {
+                                received
+                                    .lock()
+                                    .push((chunk.entity_path().to_string(), time));
+                            }
+                        }
+                    }
+                }
+            }),
+            filter,
+        );
+
+        let store_id = StoreId::random(StoreKind::Recording, "rerun_example_filtered_sink");
+        for entity_path in entity_paths {
+            let mut builder = Chunk::builder(*entity_path);
+            for frame in 0..frames {
+                builder = builder.with_archetype(
+                    RowId::new(),
+                    TimePoint::default().with(Timeline::new_sequence("frame"), frame),
+                    &MyPoints::new([MyPoint::new(1.0, 2.0)]),
+                );
+            }
+            let chunk = builder.build().unwrap();
+            sink.send(LogMsg::ArrowMsg(
+                store_id.clone(),
+                chunk.to_arrow_msg().unwrap(),
+            ));
+        }
+
+        received.lock().clone()
+    }
+
+    #[test]
+    fn filters_entities() {
+        let rows = received_rows(
+            SinkFilter::default().with_entities(EntityPathFilter::parse_forgiving("+ /a/**")),
+            &["a/b", "c"],
+            2,
+        );
+        assert_eq!(rows, vec![("/a/b".to_owned(), 0), ("/a/b".to_owned(), 1)]);
+    }
+
+    #[test]
+    fn downsamples_rows() {
+        let rows = received_rows(
+            SinkFilter::default().with_min_interval("frame", 3),
+            &["a", "b"],
+            7,
+        );
+        let expected = ["/a", "/b"]
+            .into_iter()
+            .flat_map(|path| [0, 3, 6].map(|frame| (path.to_owned(), frame)))
+            .collect::<Vec<_>>();
+        assert_eq!(rows, expected);
+    }
+}
@@ -15,17 +15,24 @@
 // ----------------
 // Private modules:
 
+mod atomic_batch;
 mod binary_stream_sink;
+mod filtered_sink;
 mod global;
 mod log_sink;
+mod recording_queue;
 mod recording_stream;
 mod spawn;
 
 // -------------
 // Public items:
 
+#[cfg(feature = "async")]
+pub use spawn::spawn_async;
 pub use spawn::{SpawnError, SpawnOptions, spawn};
 
+pub use self::atomic_batch::AtomicBatch;
+pub use self::recording_queue::{BackpressurePolicy, QueueLimits, QueueStats};
 pub use self::recording_stream::{
     RecordingStream, RecordingStreamBuilder, RecordingStreamError, RecordingStreamResult,
     forced_sink_path,
@@ -54,7 +61,8 @@ pub fn default_flush_timeout() -> Option<std::time::Duration> {
 }
 
 pub use re_log_types::{
-    ApplicationId, EntityPath, EntityPathPart, Instance, StoreId, StoreKind, entity_path,
+    ApplicationId, EntityPath, EntityPathPart, Instance, StoreId, StoreKind, TimelineMapping,
+    entity_path,
 };
 pub use re_memory::MemoryLimit;
 pub use re_types::archetypes::RecordingInfo;
@@ -77,15 +85,34 @@ impl crate::sink::LogSink for re_log_encoding::FileSink {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+impl crate::sink::LogSink for re_log_encoding::RingBufferSink {
+    fn send(&self, msg: re_log_types::LogMsg) {
+        Self::send(self, msg);
+    }
+
+    #[inline]
+    fn flush_blocking(&self) {
+        Self::flush_blocking(self);
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
 // ---------------
 // Public modules:
 
+pub mod blueprint;
+
 /// Different destinations for log messages.
 ///
 /// This is how you select whether the log stream ends up
 /// sent over gRPC, written to file, etc.
 pub mod sink {
     pub use crate::binary_stream_sink::{BinaryStreamSink, BinaryStreamStorage};
+    pub use crate::filtered_sink::{FilteredSink, SinkFilter};
     pub use crate::log_sink::{
         BufferedSink, CallbackSink, IntoMultiSink, LogSink, MemorySink, MemorySinkStorage,
         MultiSink,
@@ -94,7 +121,7 @@ pub mod sink {
     pub use crate::log_sink::{GrpcSink, GrpcSinkConnectionFailure, GrpcSinkConnectionState};
 
     #[cfg(not(target_arch = "wasm32"))]
-    pub use re_log_encoding::{FileSink, FileSinkError};
+    pub use re_log_encoding::{FileSink, FileSinkError, RingBufferSink};
 }
 
 /// Things directly related to logging.
@@ -113,14 +140,19 @@ pub mod time {
 pub use time::{TimeCell, TimePoint, Timeline};
 
 pub use re_types::{
-    Archetype, ArchetypeName, AsComponents, Component, ComponentBatch, ComponentDescriptor,
-    ComponentIdentifier, ComponentType, DatatypeName, DeserializationError, DeserializationResult,
-    Loggable, SerializationError, SerializationResult, SerializedComponentBatch,
-    SerializedComponentColumn,
+    Archetype, ArchetypeName, AsComponentColumns, AsComponents, Component, ComponentBatch,
+    ComponentDescriptor, ComponentIdentifier, ComponentType, CustomComponentReflection,
+    CustomFieldReflection, DatatypeName, DerivedSeriesDefinition, DeserializationError,
+    DeserializationResult, Loggable, SerializationError, SerializationResult,
+    SerializedComponentBatch, SerializedComponentColumn,
 };
 
 pub use re_byte_size::SizeBytes;
 
+/// Derives [`AsComponentColumns`](trait@AsComponentColumns) for a struct made of `Vec<C>` fields, where every `C` is a
+/// [`Component`].
+pub use re_sdk_derive::AsComponentColumns;
+
 #[cfg(feature = "data_loaders")]
 pub use re_data_loader::{DataLoader, DataLoaderError, DataLoaderSettings, LoadedData};
 
@@ -132,6 +164,12 @@ pub mod web_viewer;
 #[cfg(feature = "server")]
 pub mod grpc_server;
 
+#[cfg(feature = "system_metrics")]
+pub mod system_metrics;
+
+#[cfg(feature = "system_metrics")]
+pub use system_metrics::{SystemMetricsConfig, SystemMetricsHandle};
+
 /// Re-exports of other crates.
 pub mod external {
     pub use re_grpc_client;
@@ -160,6 +198,27 @@ pub fn build_info() -> re_build_info::BuildInfo {
     re_build_info::build_info!()
 }
 
+static LOGGING_ENABLED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(true);
+
+/// Turns logging on or off for all [`RecordingStream`]s at once, at runtime.
+///
+/// While turned off, log calls return right away, before any of their data gets serialized: the
+/// cost of leaving the instrumentation in place is that of loading an atomic flag.
+/// Unlike a disabled [`RecordingStream`], recording streams keep running, so that logging can be
+/// turned back on at any point.
+///
+/// See also [`RecordingStream::set_entity_filter`] to only log some entities.
+#[inline]
+pub fn set_logging_enabled(enabled: bool) {
+    LOGGING_ENABLED.store(enabled, std::sync::atomic::Ordering::Relaxed);
+}
+
+/// Whether logging is turned on, see [`set_logging_enabled`].
+#[inline]
+pub fn is_logging_enabled() -> bool {
+    LOGGING_ENABLED.load(std::sync::atomic::Ordering::Relaxed)
+}
+
 const RERUN_ENV_VAR: &str = "RERUN";
 
 /// Helper to get the value of the `RERUN` environment variable.
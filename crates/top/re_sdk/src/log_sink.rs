@@ -211,6 +211,14 @@ impl private::Sealed for crate::sink::FileSink {}
 
 impl MultiSinkCompatible for crate::sink::FileSink {}
 
+impl private::Sealed for crate::sink::RingBufferSink {}
+
+impl MultiSinkCompatible for crate::sink::RingBufferSink {}
+
+impl private::Sealed for crate::sink::FilteredSink {}
+
+impl MultiSinkCompatible for crate::sink::FilteredSink {}
+
 impl private::Sealed for crate::sink::GrpcSink {}
 
 impl MultiSinkCompatible for crate::sink::GrpcSink {}
@@ -511,10 +519,30 @@ impl GrpcSink {
     /// ```ignore
     /// GrpcSink::new("rerun+http://127.0.0.1:9434/proxy");
     /// ```
+    ///
+    /// Proxies that only let in clients with a token are given the one from the
+    /// `RERUN_PROXY_TOKEN` environment variable, see [`Self::new_with_token`].
     #[inline]
     pub fn new(uri: re_uri::ProxyUri, flush_timeout: Option<Duration>) -> Self {
+        Self::new_with_token(
+            uri,
+            flush_timeout,
+            re_grpc_client::message_proxy::token_from_env(),
+        )
+    }
+
+    /// Same as [`Self::new`], presenting `token` to the server.
+    ///
+    /// `None` never reads the token from the environment.
+    #[inline]
+    pub fn new_with_token(
+        uri: re_uri::ProxyUri,
+        flush_timeout: Option<Duration>,
+        token: Option<String>,
+    ) -> Self {
         let options = Options {
             flush_timeout,
+            token,
             ..Default::default()
         };
         Self {
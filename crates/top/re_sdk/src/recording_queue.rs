@@ -0,0 +1,275 @@
+//! Bookkeeping for the data that has been logged to a [`crate::RecordingStream`] but hasn't
+//! reached its sink yet.
+
+use parking_lot::{Condvar, Mutex};
+
+/// What happens when logging more data would exceed the [`QueueLimits`] of a
+/// [`crate::RecordingStream`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BackpressurePolicy {
+    /// The logging call blocks until enough data has been forwarded to the sink.
+    ///
+    /// This never loses data, but a slow sink will slow down the logging thread.
+    #[default]
+    Block,
+
+    /// The oldest queued data is discarded, at chunk granularity, to make room for the new data.
+    ///
+    /// Queued data is discarded as it reaches the sink, so the queue may temporarily hold up to
+    /// twice its limits. Past that point, new data is discarded instead.
+    DropOldest,
+
+    /// The new data is discarded.
+    DropNewest,
+}
+
+/// Limits on the amount of data that a [`crate::RecordingStream`] may hold on to before it is
+/// forwarded to the sink.
+///
+/// This covers data waiting to be batched as well as chunks waiting for the sink.
+/// Sizes are estimated from the in-memory size of the logged data.
+///
+/// See [`crate::RecordingStreamBuilder::queue_limits`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QueueLimits {
+    /// Maximum estimated size of the queued data, in bytes.
+    ///
+    /// `None` means unlimited.
+    pub max_bytes: Option<u64>,
+
+    /// Maximum number of queued rows.
+    ///
+    /// `None` means unlimited.
+    pub max_rows: Option<u64>,
+
+    /// What to do once either of the limits is reached.
+    pub policy: BackpressurePolicy,
+}
+
+impl Default for QueueLimits {
+    #[inline]
+    fn default() -> Self {
+        Self::UNLIMITED
+    }
+}
+
+impl QueueLimits {
+    /// No limits: the queue grows as much as needed.
+    pub const UNLIMITED: Self = Self {
+        max_bytes: None,
+        max_rows: None,
+        policy: BackpressurePolicy::Block,
+    };
+
+    #[inline]
+    pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    #[inline]
+    pub fn with_max_rows(mut self, max_rows: u64) -> Self {
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    #[inline]
+    pub fn with_policy(mut self, policy: BackpressurePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    #[inline]
+    fn is_unlimited(&self) -> bool {
+        self.max_bytes.is_none() && self.max_rows.is_none()
+    }
+}
+
+/// A snapshot of the state of the queue of a [`crate::RecordingStream`].
+///
+/// See [`crate::RecordingStream::queue_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QueueStats {
+    /// Estimated size of the queued data, in bytes.
+    pub queued_bytes: u64,
+
+    /// Number of queued rows.
+    pub queued_rows: u64,
+
+    /// Total number of rows discarded because of the [`QueueLimits`].
+    pub dropped_rows: u64,
+
+    /// Total estimated size of the rows discarded because of the [`QueueLimits`], in bytes.
+    pub dropped_bytes: u64,
+}
+
+#[derive(Default)]
+struct QueueState {
+    limits: QueueLimits,
+    stats: QueueStats,
+
+    /// Queued data that must be discarded as soon as it reaches the sink.
+    ///
+    /// Only used with [`BackpressurePolicy::DropOldest`].
+    rows_to_drop: u64,
+    bytes_to_drop: u64,
+}
+
+impl QueueState {
+    fn fits(&self, max_factor: u64, rows: u64, bytes: u64) -> bool {
+        let QueueLimits {
+            max_bytes,
+            max_rows,
+            policy: _,
+        } = self.limits;
+
+        let fits = |max: Option<u64>, queued: u64, new: u64| {
+            max.is_none_or(|max| queued.saturating_add(new) <= max.saturating_mul(max_factor))
+        };
+
+        self.stats.queued_rows == 0
+            || (fits(max_rows, self.stats.queued_rows, rows)
+                && fits(max_bytes, self.stats.queued_bytes, bytes))
+    }
+}
+
+/// Keeps track of the data in flight between the logging threads and the sink, and enforces the
+/// [`QueueLimits`].
+#[derive(Default)]
+pub(crate) struct RecordingQueue {
+    state: Mutex<QueueState>,
+
+    /// Signaled whenever data leaves the queue or the limits change.
+    released: Condvar,
+}
+
+impl RecordingQueue {
+    pub fn new(limits: QueueLimits) -> Self {
+        Self {
+            state: Mutex::new(QueueState {
+                limits,
+                ..Default::default()
+            }),
+            released: Condvar::new(),
+        }
+    }
+
+    pub fn set_limits(&self, limits: QueueLimits) {
+        self.state.lock().limits = limits;
+        self.released.notify_all();
+    }
+
+    pub fn stats(&self) -> QueueStats {
+        self.state.lock().stats
+    }
+
+    /// Reserves room for new data, applying the [`BackpressurePolicy`] if needed.
+    ///
+    /// `flush` is called, without holding any lock, before blocking: data that is still waiting to
+    /// be batched can only leave the queue once it's been flushed.
+    ///
+    /// Returns `false` if the new data must be discarded.
+    pub fn reserve(&self, rows: u64, bytes: u64, flush: impl Fn()) -> bool {
+        let mut state = self.state.lock();
+
+        if !state.limits.is_unlimited() {
+            match state.limits.policy {
+                BackpressurePolicy::Block => {
+                    while !state.fits(1, rows, bytes) {
+                        parking_lot::MutexGuard::unlocked(&mut state, &flush);
+                        if !state.fits(1, rows, bytes) {
+                            self.released.wait(&mut state);
+                        }
+                    }
+                }
+
+                BackpressurePolicy::DropOldest => {
+                    if !state.fits(2, rows, bytes) {
+                        drop_new_data(&mut state, rows, bytes);
+                        return false;
+                    }
+
+                    // Whatever isn't already scheduled to be discarded must fit within the limits.
+                    let QueueLimits {
+                        max_bytes,
+                        max_rows,
+                        policy: _,
+                    } = state.limits;
+                    let excess = |max: Option<u64>, queued: u64, to_drop: u64, new: u64| {
+                        max.map_or(0, |max| {
+                            (queued.saturating_sub(to_drop) + new).saturating_sub(max)
+                        })
+                    };
+                    let excess_rows =
+                        excess(max_rows, state.stats.queued_rows, state.rows_to_drop, rows);
+                    let excess_bytes = excess(
+                        max_bytes,
+                        state.stats.queued_bytes,
+                        state.bytes_to_drop,
+                        bytes,
+                    );
+                    state.rows_to_drop += excess_rows;
+                    state.bytes_to_drop += excess_bytes;
+                }
+
+                BackpressurePolicy::DropNewest => {
+                    if !state.fits(1, rows, bytes) {
+                        drop_new_data(&mut state, rows, bytes);
+                        return false;
+                    }
+                }
+            }
+        }
+
+        state.stats.queued_rows += rows;
+        state.stats.queued_bytes += bytes;
+
+        true
+    }
+
+    /// Marks `rows` rows as having left the queue.
+    ///
+    /// Batching changes the layout of the data, so the size of the released data is estimated
+    /// from the average size of the queued rows.
+    ///
+    /// Returns `false` if the data must be discarded rather than sent to the sink.
+    pub fn release(&self, rows: u64) -> bool {
+        let mut state = self.state.lock();
+
+        let rows = rows.min(state.stats.queued_rows);
+        let bytes = if rows == state.stats.queued_rows {
+            state.stats.queued_bytes
+        } else {
+            // NOTE: `rows < queued_rows`, which cannot be zero.
+            (state.stats.queued_bytes as u128 * rows as u128 / state.stats.queued_rows as u128)
+                as u64
+        };
+
+        state.stats.queued_rows -= rows;
+        state.stats.queued_bytes -= bytes;
+
+        let keep = if state.rows_to_drop > 0 || state.bytes_to_drop > 0 {
+            state.rows_to_drop = state.rows_to_drop.saturating_sub(rows);
+            state.bytes_to_drop = state.bytes_to_drop.saturating_sub(bytes);
+            state.stats.dropped_rows += rows;
+            state.stats.dropped_bytes += bytes;
+            re_log::warn_once!(
+                "RecordingStream queue is full: dropping the oldest data (see QueueLimits)"
+            );
+            false
+        } else {
+            true
+        };
+
+        drop(state);
+        self.released.notify_all();
+
+        keep
+    }
+}
+
+fn drop_new_data(state: &mut QueueState, rows: u64, bytes: u64) {
+    state.stats.dropped_rows += rows;
+    state.stats.dropped_bytes += bytes;
+    re_log::warn_once!("RecordingStream queue is full: dropping new data (see QueueLimits)");
+}
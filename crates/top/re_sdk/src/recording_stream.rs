@@ -1,7 +1,10 @@
 use std::fmt;
 use std::io::IsTerminal as _;
 use std::sync::Weak;
-use std::sync::{Arc, atomic::AtomicI64};
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, AtomicI64},
+};
 use std::time::Duration;
 
 use ahash::HashMap;
@@ -10,23 +13,28 @@ use itertools::Either;
 use nohash_hasher::IntMap;
 use parking_lot::Mutex;
 
+use re_byte_size::SizeBytes as _;
 use re_chunk::{
     BatcherHooks, Chunk, ChunkBatcher, ChunkBatcherConfig, ChunkBatcherError, ChunkComponents,
     ChunkError, ChunkId, PendingRow, RowId, TimeColumn,
 };
 use re_log_types::{
-    ApplicationId, ArrowRecordBatchReleaseCallback, BlueprintActivationCommand, EntityPath, LogMsg,
-    RecordingId, StoreId, StoreInfo, StoreKind, StoreSource, TimeCell, TimeInt, TimePoint,
-    Timeline, TimelineName,
+    ApplicationId, ArrowRecordBatchReleaseCallback, BlueprintActivationCommand, EntityPath,
+    EntityPathFilter, LogMsg, RecordingId, ResolvedEntityPathFilter, StoreId, StoreInfo, StoreKind,
+    StoreSource, TimeCell, TimeInt, TimePoint, Timeline, TimelineMapping, TimelineName,
 };
 use re_types::archetypes::RecordingInfo;
 use re_types::components::Timestamp;
-use re_types::{AsComponents, SerializationError, SerializedComponentColumn};
+use re_types::{
+    AsComponents, ComponentType, CustomComponentReflection, SerializationError,
+    SerializedComponentColumn,
+};
 
 #[cfg(feature = "web_viewer")]
 use re_web_viewer_server::WebViewerServerPort;
 
 use crate::binary_stream_sink::BinaryStreamStorage;
+use crate::recording_queue::{QueueLimits, QueueStats, RecordingQueue};
 use crate::sink::{LogSink, MemorySinkStorage};
 
 // ---
@@ -130,6 +138,7 @@ pub struct RecordingStreamBuilder {
 
     batcher_hooks: BatcherHooks,
     batcher_config: Option<ChunkBatcherConfig>,
+    queue_limits: QueueLimits,
 
     // Optional user-defined recording properties.
     should_send_properties: bool,
@@ -163,6 +172,7 @@ impl RecordingStreamBuilder {
 
             batcher_config: None,
             batcher_hooks: BatcherHooks::NONE,
+            queue_limits: QueueLimits::UNLIMITED,
 
             should_send_properties: true,
             recording_info: RecordingInfo::new()
@@ -186,6 +196,7 @@ impl RecordingStreamBuilder {
 
             batcher_config: None,
             batcher_hooks: BatcherHooks::NONE,
+            queue_limits: QueueLimits::UNLIMITED,
 
             should_send_properties: true,
             recording_info: RecordingInfo::new()
@@ -283,6 +294,18 @@ impl RecordingStreamBuilder {
         self
     }
 
+    /// Bounds the amount of data that the [`RecordingStream`] may hold on to before it is
+    /// forwarded to the sink, and decides what happens once that bound is reached.
+    ///
+    /// Unlimited by default. See [`QueueLimits`] & [`BackpressurePolicy`] for more information.
+    ///
+    /// [`BackpressurePolicy`]: crate::BackpressurePolicy
+    #[inline]
+    pub fn queue_limits(mut self, queue_limits: QueueLimits) -> Self {
+        self.queue_limits = queue_limits;
+        self
+    }
+
     #[doc(hidden)]
     #[inline]
     pub fn store_source(mut self, store_source: StoreSource) -> Self {
@@ -307,13 +330,15 @@ impl RecordingStreamBuilder {
     /// ```
     pub fn buffered(self) -> RecordingStreamResult<RecordingStream> {
         let sink = crate::log_sink::BufferedSink::new();
+        let queue_limits = self.queue_limits;
         let (enabled, store_info, properties, batcher_config, batcher_hooks) = self.into_args();
         if enabled {
-            RecordingStream::new(
+            RecordingStream::new_with_queue_limits(
                 store_info,
                 properties,
                 batcher_config,
                 batcher_hooks,
+                queue_limits,
                 Box::new(sink),
             )
         } else {
@@ -341,13 +366,15 @@ impl RecordingStreamBuilder {
     pub fn memory(
         self,
     ) -> RecordingStreamResult<(RecordingStream, crate::log_sink::MemorySinkStorage)> {
+        let queue_limits = self.queue_limits;
         let (enabled, store_info, properties, batcher_config, batcher_hooks) = self.into_args();
         let rec = if enabled {
-            RecordingStream::new(
+            RecordingStream::new_with_queue_limits(
                 store_info,
                 properties,
                 batcher_config,
                 batcher_hooks,
+                queue_limits,
                 Box::new(crate::log_sink::BufferedSink::new()),
             )
         } else {
@@ -380,13 +407,15 @@ impl RecordingStreamBuilder {
         self,
         sinks: impl crate::sink::IntoMultiSink,
     ) -> RecordingStreamResult<RecordingStream> {
+        let queue_limits = self.queue_limits;
         let (enabled, store_info, properties, batcher_config, batcher_hooks) = self.into_args();
         if enabled {
-            RecordingStream::new(
+            RecordingStream::new_with_queue_limits(
                 store_info,
                 properties,
                 batcher_config,
                 batcher_hooks,
+                queue_limits,
                 Box::new(sinks.into_multi_sink()),
             )
         } else {
@@ -435,6 +464,7 @@ impl RecordingStreamBuilder {
         url: impl Into<String>,
         flush_timeout: Option<Duration>,
     ) -> RecordingStreamResult<RecordingStream> {
+        let queue_limits = self.queue_limits;
         let (enabled, store_info, properties, batcher_config, batcher_hooks) = self.into_args();
         if enabled {
             let url: String = url.into();
@@ -442,11 +472,12 @@ impl RecordingStreamBuilder {
                 return Err(RecordingStreamError::NotAProxyEndpoint);
             };
 
-            RecordingStream::new(
+            RecordingStream::new_with_queue_limits(
                 store_info,
                 properties,
                 batcher_config,
                 batcher_hooks,
+                queue_limits,
                 Box::new(crate::log_sink::GrpcSink::new(uri, flush_timeout)),
             )
         } else {
@@ -501,13 +532,15 @@ impl RecordingStreamBuilder {
         port: u16,
         server_memory_limit: re_memory::MemoryLimit,
     ) -> RecordingStreamResult<RecordingStream> {
+        let queue_limits = self.queue_limits;
         let (enabled, store_info, properties, batcher_config, batcher_hooks) = self.into_args();
         if enabled {
-            RecordingStream::new(
+            RecordingStream::new_with_queue_limits(
                 store_info,
                 properties,
                 batcher_config,
                 batcher_hooks,
+                queue_limits,
                 Box::new(crate::grpc_server::GrpcServerSink::new(
                     bind_ip.as_ref(),
                     port,
@@ -538,14 +571,16 @@ impl RecordingStreamBuilder {
         self,
         path: impl Into<std::path::PathBuf>,
     ) -> RecordingStreamResult<RecordingStream> {
+        let queue_limits = self.queue_limits;
         let (enabled, store_info, properties, batcher_config, batcher_hooks) = self.into_args();
 
         if enabled {
-            RecordingStream::new(
+            RecordingStream::new_with_queue_limits(
                 store_info,
                 properties,
                 batcher_config,
                 batcher_hooks,
+                queue_limits,
                 Box::new(crate::sink::FileSink::new(path)?),
             )
         } else {
@@ -572,14 +607,16 @@ impl RecordingStreamBuilder {
             return self.buffered();
         }
 
+        let queue_limits = self.queue_limits;
         let (enabled, store_info, properties, batcher_config, batcher_hooks) = self.into_args();
 
         if enabled {
-            RecordingStream::new(
+            RecordingStream::new_with_queue_limits(
                 store_info,
                 properties,
                 batcher_config,
                 batcher_hooks,
+                queue_limits,
                 Box::new(crate::sink::FileSink::stdout()?),
             )
         } else {
@@ -650,6 +687,48 @@ impl RecordingStreamBuilder {
         self.connect_grpc_opts(url, flush_timeout)
     }
 
+    /// Same as [`Self::spawn`], but without blocking the calling thread while waiting for the
+    /// Viewer, see [`crate::spawn_async`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let rec = re_sdk::RecordingStreamBuilder::new("rerun_example_app")
+    ///     .spawn_async()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn spawn_async(self) -> RecordingStreamResult<RecordingStream> {
+        self.spawn_opts_async(&Default::default(), crate::default_flush_timeout())
+            .await
+    }
+
+    /// Same as [`Self::spawn_opts`], but without blocking the calling thread while waiting for
+    /// the Viewer, see [`crate::spawn_async`].
+    #[cfg(feature = "async")]
+    pub async fn spawn_opts_async(
+        self,
+        opts: &crate::SpawnOptions,
+        flush_timeout: Option<Duration>,
+    ) -> RecordingStreamResult<RecordingStream> {
+        if !self.is_enabled() {
+            re_log::debug!("Rerun disabled - call to spawn_async() ignored");
+            return Ok(RecordingStream::disabled());
+        }
+
+        let url = format!("rerun+http://{}/proxy", opts.connect_addr());
+
+        // NOTE: See `spawn_opts` regarding `_RERUN_TEST_FORCE_SAVE`.
+        if forced_sink_path().is_none() {
+            crate::spawn_async(opts).await?;
+        }
+
+        self.connect_grpc_opts(url, flush_timeout)
+    }
+
     /// Creates a new [`RecordingStream`] that is pre-configured to stream the data through to a
     /// web-based Rerun viewer via gRPC.
     ///
@@ -695,6 +774,7 @@ impl RecordingStreamBuilder {
         server_memory_limit: re_memory::MemoryLimit,
         open_browser: bool,
     ) -> RecordingStreamResult<RecordingStream> {
+        let queue_limits = self.queue_limits;
         let (enabled, store_info, recording_info, batcher_config, batcher_hooks) = self.into_args();
         if enabled {
             let sink = crate::web_viewer::new_sink(
@@ -704,11 +784,12 @@ impl RecordingStreamBuilder {
                 grpc_port,
                 server_memory_limit,
             )?;
-            RecordingStream::new(
+            RecordingStream::new_with_queue_limits(
                 store_info,
                 recording_info,
                 batcher_config,
                 batcher_hooks,
+                queue_limits,
                 sink,
             )
         } else {
@@ -742,6 +823,7 @@ impl RecordingStreamBuilder {
             enabled: _,
             batcher_config,
             batcher_hooks,
+            queue_limits: _,
             should_send_properties,
             recording_info,
         } = self;
@@ -893,9 +975,20 @@ struct RecordingStreamInner {
     batcher: ChunkBatcher,
     batcher_to_sink_handle: Option<std::thread::JoinHandle<()>>,
 
+    /// Keeps track of the data in flight between the batcher and the sink.
+    ///
+    /// See [`RecordingStreamBuilder::queue_limits`].
+    queue: Arc<RecordingQueue>,
+
     /// It true, any new sink will update the batcher's configuration (as far as possible).
     sink_dependent_batcher_config: bool,
 
+    /// Only the entities matching this filter are logged, see [`RecordingStream::set_entity_filter`].
+    entity_filter: parking_lot::RwLock<Option<ResolvedEntityPathFilter>>,
+
+    /// Whether [`Self::entity_filter`] is set, so that log calls don't have to lock it otherwise.
+    has_entity_filter: AtomicBool,
+
     /// Keeps track of the top-level threads that were spawned in order to execute the `DataLoader`
     /// machinery in the context of this `RecordingStream`.
     ///
@@ -957,6 +1050,7 @@ impl RecordingStreamInner {
         recording_info: Option<RecordingInfo>,
         batcher_config: Option<ChunkBatcherConfig>,
         batcher_hooks: BatcherHooks,
+        queue_limits: QueueLimits,
         sink: Box<dyn LogSink>,
     ) -> RecordingStreamResult<Self> {
         let sink_dependent_batcher_config = batcher_config.is_none();
@@ -981,6 +1075,8 @@ impl RecordingStreamInner {
 
         let (cmds_tx, cmds_rx) = crossbeam::channel::unbounded();
 
+        let queue = Arc::new(RecordingQueue::new(queue_limits));
+
         let batcher_to_sink_handle = {
             const NAME: &str = "RecordingStream::batcher_to_sink";
             std::thread::Builder::new()
@@ -988,7 +1084,10 @@ impl RecordingStreamInner {
                 .spawn({
                     let info = store_info.clone();
                     let batcher = batcher.clone();
-                    move || forwarding_thread(info, sink, cmds_rx, batcher.chunks(), on_release)
+                    let queue = queue.clone();
+                    move || {
+                        forwarding_thread(info, sink, cmds_rx, batcher.chunks(), queue, on_release)
+                    }
                 })
                 .map_err(|err| RecordingStreamError::SpawnThread {
                     name: NAME.into(),
@@ -1006,6 +1105,8 @@ impl RecordingStreamInner {
                 .with_archetype(RowId::new(), TimePoint::default(), recording_info)
                 .build()?;
 
+            // The queue is still empty, so this always fits.
+            queue.reserve(chunk.num_rows() as u64, chunk.total_size_bytes(), || {});
             batcher.push_chunk(chunk);
         }
 
@@ -1016,7 +1117,10 @@ impl RecordingStreamInner {
             cmds_tx,
             batcher,
             batcher_to_sink_handle: Some(batcher_to_sink_handle),
+            queue,
             sink_dependent_batcher_config,
+            entity_filter: Default::default(),
+            has_entity_filter: AtomicBool::new(false),
             dataloader_handles: Mutex::new(Vec::new()),
             pid_at_creation: std::process::id(),
         })
@@ -1027,6 +1131,23 @@ impl RecordingStreamInner {
         self.pid_at_creation != std::process::id()
     }
 
+    #[inline]
+    fn is_entity_logged(&self, entity_path: &EntityPath) -> bool {
+        // Reserved entities, e.g. the recording properties, are logged by the SDK itself.
+        if !self
+            .has_entity_filter
+            .load(std::sync::atomic::Ordering::Relaxed)
+            || entity_path.is_reserved()
+        {
+            return true;
+        }
+
+        self.entity_filter
+            .read()
+            .as_ref()
+            .is_none_or(|filter| filter.matches(entity_path))
+    }
+
     /// Make sure all pending top-level `DataLoader` threads that were started from the SDK run to completion.
     //
     // TODO(cmc): At some point we might want to make it configurable, though I cannot really
@@ -1047,15 +1168,29 @@ enum Command {
     SwapSink(Box<dyn LogSink>),
     // TODO(#10444): This should go away with more explicit sinks.
     InspectSink(InspectSinkFn),
-    Flush(Sender<()>),
+    Flush(FlushDone),
     PopPendingChunks,
     Shutdown,
 }
 
+/// Signals the completion of a [`Command::Flush`] when dropped.
+enum FlushDone {
+    Blocking(Sender<()>),
+
+    #[cfg(feature = "async")]
+    Async(tokio::sync::oneshot::Sender<()>),
+}
+
 impl Command {
     fn flush() -> (Self, Receiver<()>) {
         let (tx, rx) = crossbeam::channel::bounded(0); // oneshot
-        (Self::Flush(tx), rx)
+        (Self::Flush(FlushDone::Blocking(tx)), rx)
+    }
+
+    #[cfg(feature = "async")]
+    fn flush_awaitable() -> (Self, tokio::sync::oneshot::Receiver<()>) {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        (Self::Flush(FlushDone::Async(tx)), rx)
     }
 }
 
@@ -1080,6 +1215,30 @@ impl RecordingStream {
         batcher_config: Option<ChunkBatcherConfig>,
         batcher_hooks: BatcherHooks,
         sink: Box<dyn LogSink>,
+    ) -> RecordingStreamResult<Self> {
+        Self::new_with_queue_limits(
+            store_info,
+            recording_info,
+            batcher_config,
+            batcher_hooks,
+            QueueLimits::UNLIMITED,
+            sink,
+        )
+    }
+
+    /// Like [`Self::new`], but with the given [`QueueLimits`].
+    ///
+    /// The limits apply from the start, including to the [`RecordingInfo`].
+    ///
+    /// See also: [`RecordingStreamBuilder::queue_limits`].
+    #[must_use = "Recording will get closed automatically once all instances of this object have been dropped"]
+    pub fn new_with_queue_limits(
+        store_info: StoreInfo,
+        recording_info: Option<RecordingInfo>,
+        batcher_config: Option<ChunkBatcherConfig>,
+        batcher_hooks: BatcherHooks,
+        queue_limits: QueueLimits,
+        sink: Box<dyn LogSink>,
     ) -> RecordingStreamResult<Self> {
         let sink = store_info
             .store_id
@@ -1099,6 +1258,7 @@ impl RecordingStream {
             recording_info,
             batcher_config,
             batcher_hooks,
+            queue_limits,
             sink,
         )
         .map(|inner| Self {
@@ -1256,6 +1416,15 @@ impl RecordingStream {
         static_: bool,
         as_components: &AS,
     ) -> RecordingStreamResult<()> {
+        // Bail before doing anything else, most importantly before serializing the data.
+        if !crate::is_logging_enabled() {
+            return Ok(());
+        }
+        let ent_path = ent_path.into();
+        if !self.is_entity_logged(&ent_path) {
+            return Ok(());
+        }
+
         let row_id = RowId::new(); // Create row-id as early as possible. It has a timestamp and is used to estimate e2e latency.
         self.log_serialized_batches_impl(
             row_id,
@@ -1328,6 +1497,95 @@ impl RecordingStream {
         self.log_static(EntityPath::properties(), &update)
     }
 
+    /// Declares how the times of one timeline map onto those of another, e.g. `ros_time` onto
+    /// `wall_clock`.
+    ///
+    /// The mapping is stored in the recording, and lets the viewer keep the time cursor on the
+    /// same moment when switching between the two timelines.
+    /// Sending a mapping for the same pair of timelines again replaces the previous one.
+    ///
+    /// ```
+    /// # use re_sdk::TimelineMapping;
+    /// # let rec = re_sdk::RecordingStreamBuilder::new("rerun_example_timeline_mapping").buffered()?;
+    /// // `wall_clock` is 1.5s ahead of `ros_time`, and gains 20µs per second.
+    /// rec.send_timeline_mapping(
+    ///     &TimelineMapping::new("ros_time", "wall_clock")
+    ///         .with_offset(1_500_000_000)
+    ///         .with_drift(20e-6),
+    /// )?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn send_timeline_mapping(&self, mapping: &TimelineMapping) -> RecordingStreamResult<()> {
+        use re_log_types::external::arrow::array::{Float64Array, Int64Array};
+
+        let values = re_types::AnyValues::new(TimelineMapping::ARCHETYPE_NAME)
+            .with_field(
+                TimelineMapping::COMPONENT_OFFSET,
+                Arc::new(Int64Array::from(vec![mapping.offset])),
+            )
+            .with_field(
+                TimelineMapping::COMPONENT_DRIFT,
+                Arc::new(Float64Array::from(vec![mapping.drift])),
+            );
+
+        self.log_static(
+            EntityPath::properties().join(&mapping.entity_path()),
+            &values,
+        )
+    }
+
+    /// Asks the viewer to select the given timeline for this recording, until the user picks
+    /// another one.
+    ///
+    /// By default, the viewer selects the first user-defined timeline, which isn't always the
+    /// most useful one when the recording has several clocks.
+    pub fn send_default_timeline(
+        &self,
+        timeline: impl Into<re_log_types::TimelineName>,
+    ) -> RecordingStreamResult<()> {
+        use re_log_types::{DefaultTimeline, external::arrow::array::StringArray};
+
+        let timeline = timeline.into();
+        let values = re_types::AnyValues::new(DefaultTimeline::ARCHETYPE_NAME).with_field(
+            DefaultTimeline::COMPONENT_TIMELINE,
+            Arc::new(StringArray::from(vec![timeline.as_str()])),
+        );
+
+        self.log_static(
+            EntityPath::properties().join(&DefaultTimeline::entity_path()),
+            &values,
+        )
+    }
+
+    /// Describes a user-defined component to the viewer, so that the selection panel can show
+    /// its display name and documentation, including that of the fields of struct components.
+    ///
+    /// The reflection is stored in the recording. Registering the same component type again
+    /// replaces the previous reflection.
+    ///
+    /// ```
+    /// # use re_sdk::CustomComponentReflection;
+    /// # let rec = re_sdk::RecordingStreamBuilder::new("rerun_example_component_reflection").buffered()?;
+    /// rec.register_component_reflection(
+    ///     "my_robot.components.Battery",
+    ///     &CustomComponentReflection::new("The state of the main battery.")
+    ///         .with_display_name("Battery")
+    ///         .with_field("level", "Charge level, from 0 to 1.")
+    ///         .with_field("charging", "Whether the robot is docked."),
+    /// )?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn register_component_reflection(
+        &self,
+        component_type: impl Into<ComponentType>,
+        reflection: &CustomComponentReflection,
+    ) -> RecordingStreamResult<()> {
+        self.log_static(
+            CustomComponentReflection::entity_path(component_type.into()),
+            reflection,
+        )
+    }
+
     // NOTE: For bw and fw compatibility reasons, we need our logging APIs to be fallible, even
     // though they really aren't at the moment.
     #[allow(clippy::unnecessary_wraps)]
@@ -1338,11 +1596,14 @@ impl RecordingStream {
         static_: bool,
         comp_batches: impl IntoIterator<Item = re_types::SerializedComponentBatch>,
     ) -> RecordingStreamResult<()> {
-        if !self.is_enabled() {
+        if !self.is_enabled() || !crate::is_logging_enabled() {
             return Ok(()); // silently drop the message
         }
 
         let entity_path = entity_path.into();
+        if !self.is_entity_logged(&entity_path) {
+            return Ok(());
+        }
 
         let comp_batches: Vec<_> = comp_batches
             .into_iter()
@@ -1452,6 +1713,9 @@ impl RecordingStream {
                 })
                 .unwrap_or_default()
             }),
+            image_compression: None,
+            video_encoding: None,
+            rrd: Default::default(),
         };
 
         if prefer_current_recording {
@@ -1510,6 +1774,7 @@ fn forwarding_thread(
     mut sink: Box<dyn LogSink>,
     cmds_rx: Receiver<Command>,
     chunks: Receiver<Chunk>,
+    queue: Arc<RecordingQueue>,
     on_release: Option<ArrowRecordBatchReleaseCallback>,
 ) {
     /// Returns `true` to indicate that processing can continue; i.e. `false` means immediate
@@ -1554,12 +1819,12 @@ fn forwarding_thread(
             Command::InspectSink(f) => {
                 f(sink.as_ref());
             }
-            Command::Flush(oneshot) => {
+            Command::Flush(done) => {
                 re_log::trace!("Flushing…");
                 // Flush the underlying sink if possible.
                 sink.drop_if_disconnected();
                 sink.flush_blocking();
-                drop(oneshot); // signals the oneshot
+                drop(done); // signals the oneshot
             }
             Command::PopPendingChunks => {
                 // Wake up and skip the current iteration so that we can drain all pending chunks
@@ -1576,6 +1841,10 @@ fn forwarding_thread(
         // NOTE: Always pop chunks first, this is what makes `Command::PopPendingChunks` possible,
         // which in turns makes `RecordingStream::flush_blocking` well defined.
         while let Ok(chunk) = chunks.try_recv() {
+            if !queue.release(chunk.num_rows() as u64) {
+                continue;
+            }
+
             let mut msg = match chunk.to_arrow_msg() {
                 Ok(chunk) => chunk,
                 Err(err) => {
@@ -1596,6 +1865,10 @@ fn forwarding_thread(
                     break;
                 };
 
+                if !queue.release(chunk.num_rows() as u64) {
+                    continue;
+                }
+
                 let msg = match chunk.to_arrow_msg() {
                     Ok(chunk) => chunk,
                     Err(err) => {
@@ -1634,12 +1907,66 @@ impl RecordingStream {
         self.with(|_| true).unwrap_or(false)
     }
 
+    /// Only logs the entities that match the given filter from now on, or all of them if `None`.
+    ///
+    /// Log calls targeting the other entities return right away, before any of their data gets
+    /// serialized. Reserved entities such as [`EntityPath::properties`] are always logged.
+    ///
+    /// ```
+    /// # use re_sdk::external::re_log_types::EntityPathFilter;
+    /// # let rec = re_sdk::RecordingStreamBuilder::new("rerun_example_entity_filter").buffered()?;
+    /// rec.set_entity_filter(Some(EntityPathFilter::parse_forgiving(
+    ///     "+ /**\n- /debug/**",
+    /// )));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// See also [`crate::set_logging_enabled`] to turn all logging on or off.
+    pub fn set_entity_filter(&self, filter: Option<EntityPathFilter>) {
+        let filter = filter.map(|filter| filter.resolve_without_substitutions());
+        self.with(|inner| {
+            let mut entity_filter = inner.entity_filter.write();
+            inner
+                .has_entity_filter
+                .store(filter.is_some(), std::sync::atomic::Ordering::Relaxed);
+            *entity_filter = filter;
+        });
+    }
+
+    /// Whether data logged to the given entity currently makes it into the recording, see
+    /// [`Self::set_entity_filter`].
+    #[inline]
+    pub fn is_entity_logged(&self, entity_path: &EntityPath) -> bool {
+        self.with(|inner| inner.is_entity_logged(entity_path))
+            .unwrap_or(false)
+    }
+
     /// The [`StoreInfo`] associated with this `RecordingStream`.
     #[inline]
     pub fn store_info(&self) -> Option<StoreInfo> {
         self.with(|inner| inner.store_info.clone())
     }
 
+    /// Changes the [`QueueLimits`] of this `RecordingStream`.
+    ///
+    /// The new limits apply to the data logged from now on, data that is already queued is left
+    /// untouched.
+    ///
+    /// See also [`RecordingStreamBuilder::queue_limits`].
+    #[inline]
+    pub fn set_queue_limits(&self, queue_limits: QueueLimits) {
+        self.with(|inner| inner.queue.set_limits(queue_limits));
+    }
+
+    /// Returns the current state of the internal queue, including how much data was discarded
+    /// because of the [`QueueLimits`].
+    ///
+    /// Returns the default, empty, statistics if the stream is disabled.
+    #[inline]
+    pub fn queue_stats(&self) -> QueueStats {
+        self.with(|inner| inner.queue.stats()).unwrap_or_default()
+    }
+
     /// Determine whether a fork has happened since creating this `RecordingStream`. In general, this means our
     /// batcher/sink threads are gone and all data logged since the fork has been dropped.
     ///
@@ -1696,7 +2023,12 @@ impl RecordingStream {
                 }
             }
 
-            inner.batcher.push_row(entity_path, row);
+            if inner
+                .queue
+                .reserve(1, row.total_size_bytes(), || inner.batcher.flush_async())
+            {
+                inner.batcher.push_row(entity_path, row);
+            }
         };
 
         if self.with(f).is_none() {
@@ -1754,7 +2086,14 @@ impl RecordingStream {
                 }
             }
 
-            inner.batcher.push_chunk(chunk);
+            if inner
+                .queue
+                .reserve(chunk.num_rows() as u64, chunk.total_size_bytes(), || {
+                    inner.batcher.flush_async()
+                })
+            {
+                inner.batcher.push_chunk(chunk);
+            }
         };
 
         if self.with(f).is_none() {
@@ -1778,8 +2117,23 @@ impl RecordingStream {
     /// If you don't want to inject these, use [`Self::send_chunks`] instead.
     #[inline]
     pub fn send_chunk(&self, chunk: Chunk) {
+        if !crate::is_logging_enabled() {
+            return;
+        }
+
         let f = move |inner: &RecordingStreamInner| {
-            inner.batcher.push_chunk(chunk);
+            if !inner.is_entity_logged(chunk.entity_path()) {
+                return;
+            }
+
+            if inner
+                .queue
+                .reserve(chunk.num_rows() as u64, chunk.total_size_bytes(), || {
+                    inner.batcher.flush_async()
+                })
+            {
+                inner.batcher.push_chunk(chunk);
+            }
         };
 
         if self.with(f).is_none() {
@@ -1924,6 +2278,51 @@ impl RecordingStream {
             re_log::warn_once!("Recording disabled - call to flush_blocking() ignored");
         }
     }
+
+    /// Initiates a flush the batching pipeline and waits for it to propagate, without blocking
+    /// the calling thread.
+    ///
+    /// This is the async counterpart of [`Self::flush_blocking`]: the batching and the I/O happen
+    /// on the recording stream's own threads while the returned future waits for them, so it can
+    /// be safely awaited from within an async runtime.
+    ///
+    /// See [`RecordingStream`] docs for ordering semantics and multithreading guarantees.
+    #[cfg(feature = "async")]
+    pub async fn flush_awaitable(&self) {
+        if self.is_forked_child() {
+            re_log::error_once!(
+                "Fork detected during flush_awaitable. cleanup_if_forked() should always be called after forking. This is likely a bug in the SDK."
+            );
+            return;
+        }
+
+        let f = move |inner: &RecordingStreamInner| {
+            let (cmd, oneshot) = Command::flush_awaitable();
+
+            // 1. Flush the batcher down the chunk channel, without waiting for it
+            let cmds_tx = inner.cmds_tx.clone();
+            inner.batcher.flush_then(move || {
+                // NOTE: This is called once the flushed chunks are in the chunk channel, after
+                // which the commands are handled as in `flush_blocking`. If the stream is dropped
+                // in the meantime, the oneshot is dropped as well, which ends the wait.
+
+                // 2. Drain all pending chunks from the batcher's channel _before_ any other future command
+                cmds_tx.send(Command::PopPendingChunks).ok();
+
+                // 3. Let the caller wait for all chunks to have been forwarded down the sink
+                cmds_tx.send(cmd).ok();
+            });
+
+            oneshot
+        };
+
+        let Some(oneshot) = self.with(f) else {
+            re_log::warn_once!("Recording disabled - call to flush_awaitable() ignored");
+            return;
+        };
+
+        oneshot.await.ok();
+    }
 }
 
 impl RecordingStream {
@@ -2841,6 +3240,92 @@ mod tests {
         assert!(msgs.pop().is_none());
     }
 
+    #[test]
+    fn entity_filter_skips_serialization() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct CountingPoints<'a>(&'a AtomicUsize);
+
+        impl AsComponents for CountingPoints<'_> {
+            fn as_serialized_batches(&self) -> Vec<re_types::SerializedComponentBatch> {
+                self.0.fetch_add(1, Ordering::Relaxed);
+                MyPoints::new([re_log_types::example_components::MyPoint::new(1.0, 2.0)])
+                    .as_serialized_batches()
+            }
+        }
+
+        let (rec, storage) = RecordingStreamBuilder::new("rerun_example_entity_filter")
+            .enabled(true)
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .memory()
+            .unwrap();
+
+        rec.set_entity_filter(Some(EntityPathFilter::parse_forgiving(
+            "+ /**\n- /debug/**",
+        )));
+        assert!(rec.is_entity_logged(&"points".into()));
+        assert!(!rec.is_entity_logged(&"debug/points".into()));
+        assert!(rec.is_entity_logged(&EntityPath::properties()));
+
+        let num_serialized = AtomicUsize::new(0);
+        rec.log("points", &CountingPoints(&num_serialized)).unwrap();
+        rec.log("debug/points", &CountingPoints(&num_serialized))
+            .unwrap();
+        assert_eq!(1, num_serialized.load(Ordering::Relaxed));
+
+        rec.set_entity_filter(None);
+        rec.log("debug/points", &CountingPoints(&num_serialized))
+            .unwrap();
+        assert_eq!(2, num_serialized.load(Ordering::Relaxed));
+
+        let entity_paths = storage
+            .take()
+            .into_iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, msg) => {
+                    Some(Chunk::from_arrow_msg(&msg).unwrap().entity_path().clone())
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![EntityPath::from("points"), EntityPath::from("debug/points")],
+            entity_paths
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn flush_awaitable() {
+        let rec = RecordingStreamBuilder::new("rerun_example_flush_awaitable")
+            .enabled(true)
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .buffered()
+            .unwrap();
+
+        let num_arrow_msgs = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        rec.set_sink(Box::new(crate::sink::CallbackSink::new({
+            let num_arrow_msgs = num_arrow_msgs.clone();
+            move |msgs: &[LogMsg]| {
+                let num = msgs
+                    .iter()
+                    .filter(|msg| matches!(msg, LogMsg::ArrowMsg(..)))
+                    .count();
+                num_arrow_msgs.fetch_add(num, std::sync::atomic::Ordering::Relaxed);
+            }
+        })));
+
+        for row in example_rows(false) {
+            rec.record_row("a".into(), row, false);
+        }
+
+        // The batcher never flushes on its own: nothing can have reached the sink yet.
+        assert_eq!(0, num_arrow_msgs.load(std::sync::atomic::Ordering::Relaxed));
+
+        rec.flush_awaitable().await;
+        assert!(num_arrow_msgs.load(std::sync::atomic::Ordering::Relaxed) > 0);
+    }
+
     #[test]
     fn flush_hierarchy() {
         let (rec, storage) = RecordingStreamBuilder::new("rerun_example_flush_hierarchy")
@@ -3234,4 +3719,123 @@ mod tests {
             Err(std::sync::mpsc::RecvTimeoutError::Timeout)
         );
     }
+
+    #[test]
+    fn derived_component_columns() {
+        use re_log_types::example_components::{MyColor, MyPoint};
+        use re_types::{AsComponentColumns as _, Component as _, ComponentDescriptor};
+
+        #[derive(crate::AsComponentColumns)]
+        #[rerun(crate = "crate", archetype = "rerun_example.Robot")]
+        struct Robot {
+            points: Vec<MyPoint>,
+
+            #[rerun(component = "rerun_example.Robot:led")]
+            colors: Vec<MyColor>,
+        }
+
+        assert_eq!(
+            ComponentDescriptor {
+                archetype: Some("rerun_example.Robot".into()),
+                component: "Robot:points".into(),
+                component_type: Some(MyPoint::name()),
+            },
+            Robot::descriptor_points()
+        );
+        assert_eq!(
+            "rerun_example.Robot:led",
+            Robot::descriptor_colors().component.as_str()
+        );
+
+        let robot = Robot {
+            points: vec![MyPoint::new(1.0, 2.0), MyPoint::new(3.0, 4.0)],
+            colors: vec![MyColor::from_rgb(255, 0, 0), MyColor::from_rgb(0, 255, 0)],
+        };
+
+        let columns = robot.as_component_columns().unwrap();
+        assert_eq!(2, columns.len());
+        for column in &columns {
+            assert_eq!(2, column.list_array.len());
+        }
+
+        let (rec, storage) = RecordingStreamBuilder::new("rerun_example_derived_component_columns")
+            .enabled(true)
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .memory()
+            .unwrap();
+
+        rec.send_columns(
+            "robot",
+            [TimeColumn::new_sequence("frame", [0, 1])],
+            columns,
+        )
+        .unwrap();
+
+        let chunks = storage
+            .take()
+            .into_iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, msg) => Some(Chunk::from_arrow_msg(&msg).unwrap()),
+                _ => None,
+            })
+            .filter(|chunk| chunk.entity_path() == &EntityPath::from("robot"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(1, chunks.len());
+        assert_eq!(2, chunks[0].num_rows());
+        assert!(
+            chunks[0]
+                .components()
+                .contains_component(&Robot::descriptor_colors())
+        );
+    }
+
+    #[test]
+    fn queue_limits_drop_newest() {
+        use re_log_types::example_components::MyPoint;
+
+        let (rec, storage) = RecordingStreamBuilder::new("rerun_example_queue_limits")
+            .enabled(true)
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .queue_limits(
+                QueueLimits::UNLIMITED
+                    .with_max_rows(3)
+                    .with_policy(crate::BackpressurePolicy::DropNewest),
+            )
+            .memory()
+            .unwrap();
+
+        for i in 0..5 {
+            rec.log("points", &MyPoints::new([MyPoint::new(i as f32, 0.0)]))
+                .unwrap();
+        }
+
+        // The batcher never flushes on its own, so the first three rows are still queued.
+        let stats = rec.queue_stats();
+        assert_eq!(3, stats.queued_rows);
+        assert_eq!(2, stats.dropped_rows);
+        assert!(stats.dropped_bytes > 0);
+
+        let num_rows: usize = storage
+            .take()
+            .into_iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, msg) => Some(Chunk::from_arrow_msg(&msg).unwrap()),
+                _ => None,
+            })
+            .filter(|chunk| chunk.entity_path() == &EntityPath::from("points"))
+            .map(|chunk| chunk.num_rows())
+            .sum();
+        assert_eq!(3, num_rows);
+
+        let stats = rec.queue_stats();
+        assert_eq!(0, stats.queued_rows);
+        assert_eq!(0, stats.queued_bytes);
+
+        // Now that the queue is empty, there is room for new rows again.
+        rec.log("points", &MyPoints::new([MyPoint::new(5.0, 0.0)]))
+            .unwrap();
+        assert_eq!(1, rec.queue_stats().queued_rows);
+        assert_eq!(2, rec.queue_stats().dropped_rows);
+    }
 }
@@ -159,25 +159,9 @@ impl std::fmt::Debug for SpawnError {
     }
 }
 
-/// Spawns a new Rerun Viewer process ready to listen for connections.
-///
-/// If there is already a process listening on this port (Rerun or not), this function returns `Ok`
-/// WITHOUT spawning a `rerun` process (!).
-///
-/// Refer to [`SpawnOptions`]'s documentation for configuration options.
-///
-/// This only starts a Viewer process: if you'd like to connect to it and start sending data, refer
-/// to [`crate::RecordingStream::connect_grpc`] or use [`crate::RecordingStream::spawn`] directly.
-#[allow(unsafe_code)]
-pub fn spawn(opts: &SpawnOptions) -> Result<(), SpawnError> {
-    #[cfg(target_family = "unix")]
-    use std::os::unix::process::CommandExt as _;
-
-    use std::{net::TcpStream, process::Command, time::Duration};
-
-    // NOTE: These are indented on purpose, it just looks better and reads easier.
+// NOTE: These are indented on purpose, it just looks better and reads easier.
 
-    const MSG_INSTALL_HOW_TO: &str = //
+const MSG_INSTALL_HOW_TO: &str = //
     "
     You can install binary releases of the Rerun Viewer:
     * Using `cargo`: `cargo binstall rerun-cli` (see https://github.com/cargo-bins/cargo-binstall)
@@ -188,7 +172,7 @@ pub fn spawn(opts: &SpawnOptions) -> Result<(), SpawnError> {
     https://rerun.io/docs/getting-started/installing-viewer
     ";
 
-    const MSG_INSTALL_HOW_TO_VERSIONED: &str = //
+const MSG_INSTALL_HOW_TO_VERSIONED: &str = //
     "
     You can install an appropriate version of the Rerun Viewer via binary releases:
     * Using `cargo`: `cargo binstall --force rerun-cli@__VIEWER_VERSION__` (see https://github.com/cargo-bins/cargo-binstall)
@@ -199,8 +183,8 @@ pub fn spawn(opts: &SpawnOptions) -> Result<(), SpawnError> {
     https://rerun.io/docs/getting-started/installing-viewer
     ";
 
-    const MSG_VERSION_MISMATCH: &str = //
-        "
+const MSG_VERSION_MISMATCH: &str = //
+    "
     ⚠ The version of the Rerun Viewer available on your PATH does not match the version of your Rerun SDK ⚠
 
     Rerun does not make any kind of backwards/forwards compatibility guarantee yet: this can lead to (subtle) bugs.
@@ -208,11 +192,22 @@ pub fn spawn(opts: &SpawnOptions) -> Result<(), SpawnError> {
     > Rerun Viewer: v__VIEWER_VERSION__ (executable: \"__VIEWER_PATH__\")
     > Rerun SDK: v__SDK_VERSION__";
 
-    let port = opts.port;
+/// Spawns a new Rerun Viewer process ready to listen for connections.
+///
+/// If there is already a process listening on this port (Rerun or not), this function returns `Ok`
+/// WITHOUT spawning a `rerun` process (!).
+///
+/// Refer to [`SpawnOptions`]'s documentation for configuration options.
+///
+/// This only starts a Viewer process: if you'd like to connect to it and start sending data, refer
+/// to [`crate::RecordingStream::connect_grpc`] or use [`crate::RecordingStream::spawn`] directly.
+///
+/// This blocks the calling thread while waiting for the Viewer, see `spawn_async` (behind the
+/// `async` feature) for use from within an async runtime.
+pub fn spawn(opts: &SpawnOptions) -> Result<(), SpawnError> {
+    use std::{net::TcpStream, process::Command, time::Duration};
+
     let connect_addr = opts.connect_addr();
-    let memory_limit = &opts.memory_limit;
-    let server_memory_limit = &opts.server_memory_limit;
-    let executable_path = opts.executable_path();
 
     // TODO(#4019): application-level handshake
     if TcpStream::connect_timeout(&connect_addr, Duration::from_secs(1)).is_ok() {
@@ -223,69 +218,143 @@ pub fn spawn(opts: &SpawnOptions) -> Result<(), SpawnError> {
         return Ok(());
     }
 
-    let map_err = |err: std::io::Error| -> SpawnError {
-        if err.kind() == std::io::ErrorKind::NotFound {
-            if let Some(executable_path) = opts.executable_path.as_ref() {
-                SpawnError::ExecutableNotFound {
-                    executable_path: executable_path.clone(),
-                }
-            } else {
-                let sdk_version = re_build_info::build_info!().version;
-                SpawnError::ExecutableNotFoundInPath {
-                    // Only recommend a specific Viewer version for non-alpha/rc/dev SDKs.
-                    message: if sdk_version.is_release() {
-                        MSG_INSTALL_HOW_TO_VERSIONED
-                            .replace("__VIEWER_VERSION__", &sdk_version.to_string())
-                    } else {
-                        MSG_INSTALL_HOW_TO.to_owned()
-                    },
-                    executable_name: opts.executable_name.clone(),
-                    search_path: std::env::var("PATH").unwrap_or_else(|_| String::new()),
-                }
+    // Try to check the version of the Viewer.
+    // Do not fail if we can't retrieve the version, it's not a critical error.
+    let version_output = Command::new(opts.executable_path())
+        .arg("--version")
+        .output()
+        .ok();
+    check_viewer_version(opts, version_output);
+
+    let rerun_bin = viewer_command(opts)
+        .spawn()
+        .map_err(|err| map_spawn_err(opts, err))?;
+
+    if opts.wait_for_bind {
+        // Give the newly spawned Rerun Viewer some time to bind.
+        //
+        // NOTE: The timeout only covers the TCP handshake: if no process is bound to that address
+        // at all, the connection will fail immediately, irrelevant of the timeout configuration.
+        // For that reason we use an extra loop.
+        for i in 0..5 {
+            re_log::debug!("connection attempt {}", i + 1);
+            if TcpStream::connect_timeout(&connect_addr, Duration::from_secs(1)).is_ok() {
+                break;
             }
-        } else {
-            err.into()
+            std::thread::sleep(Duration::from_millis(100));
         }
+    }
+
+    // Simply forget about the child process, we want it to outlive the parent process if needed.
+    _ = rerun_bin;
+
+    Ok(())
+}
+
+/// Same as [`spawn`], but never blocks the calling thread: all the waiting (connection attempts,
+/// version check, waiting for the Viewer to bind) is done asynchronously.
+///
+/// Must be called from within a `tokio` runtime with both I/O and time enabled.
+#[cfg(feature = "async")]
+pub async fn spawn_async(opts: &SpawnOptions) -> Result<(), SpawnError> {
+    use std::time::Duration;
+    use tokio::{net::TcpStream, process::Command};
+
+    let connect_addr = opts.connect_addr();
+    let is_listening = || async move {
+        matches!(
+            tokio::time::timeout(Duration::from_secs(1), TcpStream::connect(connect_addr)).await,
+            Ok(Ok(_))
+        )
     };
 
+    // TODO(#4019): application-level handshake
+    if is_listening().await {
+        re_log::info!(
+            addr = %opts.listen_addr(),
+            "A process is already listening at this address. Assuming it's a Rerun Viewer."
+        );
+        return Ok(());
+    }
+
     // Try to check the version of the Viewer.
     // Do not fail if we can't retrieve the version, it's not a critical error.
-    let viewer_version = Command::new(&executable_path)
+    let version_output = Command::new(opts.executable_path())
         .arg("--version")
         .output()
-        .ok()
-        .and_then(|output| {
-            let output = String::from_utf8_lossy(&output.stdout);
-            re_build_info::CrateVersion::try_parse_from_build_info_string(output).ok()
-        });
+        .await
+        .ok();
+    check_viewer_version(opts, version_output);
 
-    if let Some(viewer_version) = viewer_version {
-        let sdk_version = re_build_info::build_info!().version;
+    // NOTE: Starting the process doesn't wait for it, there's no need for `tokio::process` here.
+    let rerun_bin = viewer_command(opts)
+        .spawn()
+        .map_err(|err| map_spawn_err(opts, err))?;
 
-        if !viewer_version.is_compatible_with(sdk_version) {
-            eprintln!(
-                "{}",
-                MSG_VERSION_MISMATCH
-                    .replace("__VIEWER_VERSION__", &viewer_version.to_string())
-                    .replace("__VIEWER_PATH__", &executable_path)
-                    .replace("__SDK_VERSION__", &sdk_version.to_string())
-            );
-
-            // Don't recommend installing stuff through registries if the user is running some
-            // weird version.
-            if sdk_version.is_release() {
-                eprintln!(
-                    "{}",
-                    MSG_INSTALL_HOW_TO_VERSIONED
-                        .replace("__VIEWER_VERSION__", &sdk_version.to_string())
-                );
-            } else {
-                eprintln!();
+    if opts.wait_for_bind {
+        // See `spawn` as to why we need a loop.
+        for i in 0..5 {
+            re_log::debug!("connection attempt {}", i + 1);
+            if is_listening().await {
+                break;
             }
+            tokio::time::sleep(Duration::from_millis(100)).await;
         }
     }
 
-    let mut rerun_bin = Command::new(&executable_path);
+    // Simply forget about the child process, we want it to outlive the parent process if needed.
+    _ = rerun_bin;
+
+    Ok(())
+}
+
+/// Warns the user if the output of `rerun --version` doesn't match the version of the SDK.
+fn check_viewer_version(opts: &SpawnOptions, version_output: Option<std::process::Output>) {
+    let viewer_version = version_output.and_then(|output| {
+        let output = String::from_utf8_lossy(&output.stdout);
+        re_build_info::CrateVersion::try_parse_from_build_info_string(output).ok()
+    });
+
+    let Some(viewer_version) = viewer_version else {
+        return;
+    };
+
+    let sdk_version = re_build_info::build_info!().version;
+    if viewer_version.is_compatible_with(sdk_version) {
+        return;
+    }
+
+    eprintln!(
+        "{}",
+        MSG_VERSION_MISMATCH
+            .replace("__VIEWER_VERSION__", &viewer_version.to_string())
+            .replace("__VIEWER_PATH__", &opts.executable_path())
+            .replace("__SDK_VERSION__", &sdk_version.to_string())
+    );
+
+    // Don't recommend installing stuff through registries if the user is running some
+    // weird version.
+    if sdk_version.is_release() {
+        eprintln!(
+            "{}",
+            MSG_INSTALL_HOW_TO_VERSIONED.replace("__VIEWER_VERSION__", &sdk_version.to_string())
+        );
+    } else {
+        eprintln!();
+    }
+}
+
+/// The command that starts the Viewer process.
+#[allow(unsafe_code)]
+fn viewer_command(opts: &SpawnOptions) -> std::process::Command {
+    #[cfg(target_family = "unix")]
+    use std::os::unix::process::CommandExt as _;
+
+    let port = opts.port;
+    let memory_limit = &opts.memory_limit;
+    let server_memory_limit = &opts.server_memory_limit;
+
+    let mut rerun_bin = std::process::Command::new(opts.executable_path());
 
     // By default stdin is inherited which may cause issues in some debugger setups.
     // Also, there's really no reason to forward stdin to the child process in this case.
@@ -320,25 +389,29 @@ pub fn spawn(opts: &SpawnOptions) -> Result<(), SpawnError> {
         };
     }
 
-    rerun_bin.spawn().map_err(map_err)?;
+    rerun_bin
+}
 
-    if opts.wait_for_bind {
-        // Give the newly spawned Rerun Viewer some time to bind.
-        //
-        // NOTE: The timeout only covers the TCP handshake: if no process is bound to that address
-        // at all, the connection will fail immediately, irrelevant of the timeout configuration.
-        // For that reason we use an extra loop.
-        for i in 0..5 {
-            re_log::debug!("connection attempt {}", i + 1);
-            if TcpStream::connect_timeout(&connect_addr, Duration::from_secs(1)).is_ok() {
-                break;
-            }
-            std::thread::sleep(Duration::from_millis(100));
-        }
+fn map_spawn_err(opts: &SpawnOptions, err: std::io::Error) -> SpawnError {
+    if err.kind() != std::io::ErrorKind::NotFound {
+        return err.into();
     }
 
-    // Simply forget about the child process, we want it to outlive the parent process if needed.
-    _ = rerun_bin;
-
-    Ok(())
+    if let Some(executable_path) = opts.executable_path.as_ref() {
+        SpawnError::ExecutableNotFound {
+            executable_path: executable_path.clone(),
+        }
+    } else {
+        let sdk_version = re_build_info::build_info!().version;
+        SpawnError::ExecutableNotFoundInPath {
+            // Only recommend a specific Viewer version for non-alpha/rc/dev SDKs.
+            message: if sdk_version.is_release() {
+                MSG_INSTALL_HOW_TO_VERSIONED.replace("__VIEWER_VERSION__", &sdk_version.to_string())
+            } else {
+                MSG_INSTALL_HOW_TO.to_owned()
+            },
+            executable_name: opts.executable_name.clone(),
+            search_path: std::env::var("PATH").unwrap_or_else(|_| String::new()),
+        }
+    }
 }
@@ -0,0 +1,282 @@
+//! Periodic logging of the resource usage of the current process.
+//!
+//! See [`RecordingStream::log_system_metrics`].
+
+use std::time::Duration;
+
+use crossbeam::channel::{RecvTimeoutError, Sender};
+use re_log_types::EntityPath;
+use re_types::archetypes::Scalars;
+
+use crate::{RecordingStream, RecordingStreamError, RecordingStreamResult};
+
+/// Configures [`RecordingStream::log_system_metrics`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SystemMetricsConfig {
+    /// All metrics are logged under this entity path.
+    ///
+    /// Defaults to `system_metrics`.
+    pub prefix: EntityPath,
+
+    /// How often the metrics are sampled.
+    ///
+    /// Defaults to one second.
+    pub interval: Duration,
+}
+
+impl Default for SystemMetricsConfig {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            prefix: EntityPath::from("system_metrics"),
+            interval: Duration::from_secs(1),
+        }
+    }
+}
+
+impl SystemMetricsConfig {
+    #[inline]
+    pub fn with_prefix(mut self, prefix: impl Into<EntityPath>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    #[inline]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+}
+
+/// Keeps the system metrics thread alive.
+///
+/// The thread stops as soon as this handle is dropped, or once the recording itself is gone.
+#[must_use = "System metrics stop being logged as soon as the handle is dropped"]
+pub struct SystemMetricsHandle {
+    /// Dropping this disconnects the channel, which wakes up and stops the thread.
+    stop_tx: Option<Sender<()>>,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl Drop for SystemMetricsHandle {
+    fn drop(&mut self) {
+        self.stop_tx.take();
+        if let Some(thread) = self.thread.take() {
+            thread.join().ok();
+        }
+    }
+}
+
+impl RecordingStream {
+    /// Starts logging the resource usage of the current process, every
+    /// [`SystemMetricsConfig::interval`], as scalar series under [`SystemMetricsConfig::prefix`]:
+    /// * `cpu_usage`: CPU usage of the process, in percent of a single core. This can exceed 100
+    ///   on multi-core machines.
+    /// * `memory/resident`: resident memory of the process, in bytes.
+    /// * `memory/gpu`: GPU memory in use, in bytes, summed over all devices. This is only
+    ///   available on Linux, with drivers that report it through sysfs (e.g. `amdgpu`), and
+    ///   covers every process using the devices.
+    /// * `threads`: number of threads of the process. Only available on Linux.
+    ///
+    /// Metrics are logged on the `log_time` timeline from a dedicated thread, which holds a weak
+    /// reference to the recording: it won't keep it alive.
+    /// The thread stops when the returned handle is dropped.
+    pub fn log_system_metrics(
+        &self,
+        config: SystemMetricsConfig,
+    ) -> RecordingStreamResult<SystemMetricsHandle> {
+        const NAME: &str = "RecordingStream::system_metrics";
+
+        let (stop_tx, stop_rx) = crossbeam::channel::bounded::<()>(0);
+        let rec = self.clone_weak();
+
+        let thread = std::thread::Builder::new()
+            .name(NAME.into())
+            .spawn(move || {
+                let mut sampler = Sampler::new();
+                loop {
+                    match stop_rx.recv_timeout(config.interval) {
+                        Err(RecvTimeoutError::Timeout) => {}
+                        Ok(()) | Err(RecvTimeoutError::Disconnected) => break,
+                    }
+
+                    if rec.ref_count() == 0 {
+                        break; // The recording is gone.
+                    }
+
+                    if let Err(err) = sampler.sample().log(&rec, &config.prefix) {
+                        re_log::warn_once!("Failed to log system metrics: {err}");
+                    }
+                }
+            })
+            .map_err(|err| RecordingStreamError::SpawnThread {
+                name: NAME.into(),
+                err,
+            })?;
+
+        Ok(SystemMetricsHandle {
+            stop_tx: Some(stop_tx),
+            thread: Some(thread),
+        })
+    }
+}
+
+// ---
+
+/// A single measurement of all the metrics.
+///
+/// Metrics that aren't available on the current platform are `None`.
+#[derive(Clone, Copy, Debug, Default)]
+struct Sample {
+    cpu_usage: Option<f64>,
+    resident_memory: Option<u64>,
+    gpu_memory: Option<u64>,
+    threads: Option<u64>,
+}
+
+impl Sample {
+    fn log(&self, rec: &RecordingStream, prefix: &EntityPath) -> RecordingStreamResult<()> {
+        let Self {
+            cpu_usage,
+            resident_memory,
+            gpu_memory,
+            threads,
+        } = *self;
+
+        let metrics = [
+            ("cpu_usage", cpu_usage),
+            ("memory/resident", resident_memory.map(|v| v as f64)),
+            ("memory/gpu", gpu_memory.map(|v| v as f64)),
+            ("threads", threads.map(|v| v as f64)),
+        ];
+
+        for (name, value) in metrics {
+            if let Some(value) = value {
+                let path = prefix.join(&EntityPath::from(name));
+                rec.log(path, &Scalars::single(value))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+struct Sampler {
+    system: sysinfo::System,
+    pid: Option<sysinfo::Pid>,
+}
+
+impl Sampler {
+    fn new() -> Self {
+        let mut sampler = Self {
+            system: sysinfo::System::new(),
+            pid: sysinfo::get_current_pid().ok(),
+        };
+
+        // CPU usage is computed relative to the previous refresh.
+        sampler.refresh_process();
+
+        sampler
+    }
+
+    fn refresh_process(&mut self) -> Option<&sysinfo::Process> {
+        let pid = self.pid?;
+        self.system.refresh_process_specifics(
+            pid,
+            sysinfo::ProcessRefreshKind::new().with_cpu().with_memory(),
+        );
+        self.system.process(pid)
+    }
+
+    fn sample(&mut self) -> Sample {
+        re_tracing::profile_function!();
+
+        let process = self.refresh_process();
+
+        Sample {
+            cpu_usage: process.map(|process| process.cpu_usage() as f64),
+            resident_memory: process.map(|process| process.memory()),
+            gpu_memory: gpu_memory(),
+            threads: thread_count(),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn thread_count() -> Option<u64> {
+    std::fs::read_dir("/proc/self/task")
+        .ok()
+        .map(|tasks| tasks.count() as u64)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn thread_count() -> Option<u64> {
+    None
+}
+
+/// Sums the VRAM usage reported by the DRM drivers that expose it.
+#[cfg(target_os = "linux")]
+fn gpu_memory() -> Option<u64> {
+    let cards = std::fs::read_dir("/sys/class/drm").ok()?;
+
+    let used = cards
+        .filter_map(Result::ok)
+        // Skip the connectors, e.g. `card0-DP-1`.
+        .filter(|card| {
+            card.file_name()
+                .to_str()
+                .and_then(|name| name.strip_prefix("card"))
+                .is_some_and(|index| index.chars().all(|c| c.is_ascii_digit()))
+        })
+        .filter_map(|card| {
+            std::fs::read_to_string(card.path().join("device/mem_info_vram_used")).ok()
+        })
+        .filter_map(|used| used.trim().parse::<u64>().ok())
+        .collect::<Vec<_>>();
+
+    (!used.is_empty()).then(|| used.iter().sum())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn gpu_memory() -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use re_chunk::{Chunk, ChunkBatcherConfig};
+    use re_log_types::LogMsg;
+
+    use super::*;
+    use crate::RecordingStreamBuilder;
+
+    #[test]
+    fn logs_resident_memory() {
+        let (rec, storage) = RecordingStreamBuilder::new("rerun_example_system_metrics")
+            .enabled(true)
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .memory()
+            .unwrap();
+
+        let sample = Sampler::new().sample();
+        assert!(sample.resident_memory.is_some_and(|bytes| bytes > 0));
+
+        let prefix = EntityPath::from("metrics");
+        sample.log(&rec, &prefix).unwrap();
+
+        let entity_paths = storage
+            .take()
+            .into_iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, msg) => Some(Chunk::from_arrow_msg(&msg).unwrap()),
+                _ => None,
+            })
+            .map(|chunk| chunk.entity_path().clone())
+            .collect::<Vec<_>>();
+
+        assert!(entity_paths.contains(&EntityPath::from("metrics/memory/resident")));
+        assert!(entity_paths.contains(&EntityPath::from("metrics/cpu_usage")));
+    }
+}
@@ -0,0 +1,188 @@
+//! Derive macros for the Rerun logging SDK.
+//!
+//! Use them through the `rerun` crate rather than directly, see e.g. `rerun::AsComponentColumns`.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::spanned::Spanned as _;
+
+/// Derives `AsComponentColumns` for a struct made of `Vec<C>` fields, where every `C` is a
+/// component.
+///
+/// See the documentation of the `AsComponentColumns` trait for the supported attributes.
+#[proc_macro_derive(AsComponentColumns, attributes(rerun))]
+pub fn derive_as_component_columns(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    as_component_columns(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn as_component_columns(input: &syn::DeriveInput) -> syn::Result<TokenStream> {
+    let syn::Data::Struct(syn::DataStruct {
+        fields: syn::Fields::Named(fields),
+        ..
+    }) = &input.data
+    else {
+        return Err(syn::Error::new(
+            input.span(),
+            "`AsComponentColumns` can only be derived for structs with named fields",
+        ));
+    };
+
+    let StructAttributes {
+        archetype,
+        crate_path,
+    } = StructAttributes::parse(input)?;
+    let archetype = archetype.unwrap_or_else(|| input.ident.to_string());
+    let short_archetype = archetype
+        .rsplit('.')
+        .next()
+        .unwrap_or(&archetype)
+        .to_owned();
+
+    let mut descriptor_fns = Vec::new();
+    let mut columns = Vec::new();
+
+    for field in &fields.named {
+        let Some(field_name) = &field.ident else {
+            continue;
+        };
+
+        let Some(component_type) = vec_item_type(&field.ty) else {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                "expected a `Vec<C>` field, where `C` is a component",
+            ));
+        };
+
+        let component = FieldAttributes::parse(field)?
+            .component
+            .unwrap_or_else(|| format!("{short_archetype}:{field_name}"));
+
+        let descriptor_fn = format_ident!("descriptor_{field_name}");
+        let doc = format!("Returns the `ComponentDescriptor` for `Self::{field_name}`.");
+        let vis = &input.vis;
+
+        descriptor_fns.push(quote! {
+            #[doc = #doc]
+            #[inline]
+            #vis fn #descriptor_fn() -> #crate_path::ComponentDescriptor {
+                #crate_path::ComponentDescriptor {
+                    archetype: ::std::option::Option::Some(#archetype.into()),
+                    component: #component.into(),
+                    component_type: ::std::option::Option::Some(<#component_type as #crate_path::Component>::name()),
+                }
+            }
+        });
+
+        columns.push(quote! {
+            #crate_path::ComponentBatch::try_serialized(&self.#field_name, Self::#descriptor_fn())?
+                .partitioned(::std::iter::repeat_n(1, self.#field_name.len()))?
+        });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#descriptor_fns)*
+        }
+
+        impl #impl_generics #crate_path::AsComponentColumns for #name #ty_generics #where_clause {
+            fn as_component_columns(
+                &self,
+            ) -> #crate_path::SerializationResult<::std::vec::Vec<#crate_path::SerializedComponentColumn>> {
+                ::std::result::Result::Ok(::std::vec![#(#columns),*])
+            }
+        }
+    })
+}
+
+/// Returns `T` if `ty` is `Vec<T>`.
+fn vec_item_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+
+    let segment = path.segments.last()?;
+    if segment.ident != "Vec" {
+        return None;
+    }
+
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+
+    match args.args.first()? {
+        syn::GenericArgument::Type(item_type) if args.args.len() == 1 => Some(item_type),
+        _ => None,
+    }
+}
+
+// ---
+
+/// The `#[rerun(…)]` attributes of the struct.
+struct StructAttributes {
+    archetype: Option<String>,
+    crate_path: syn::Path,
+}
+
+impl StructAttributes {
+    fn parse(input: &syn::DeriveInput) -> syn::Result<Self> {
+        let mut archetype = None;
+        let mut crate_path = None;
+
+        for attr in input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("rerun"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("archetype") {
+                    archetype = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("crate") {
+                    crate_path = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown attribute, expected `archetype` or `crate`"))
+                }
+            })?;
+        }
+
+        Ok(Self {
+            archetype,
+            crate_path: crate_path.unwrap_or_else(|| syn::parse_quote!(::rerun)),
+        })
+    }
+}
+
+/// The `#[rerun(…)]` attributes of a field.
+struct FieldAttributes {
+    component: Option<String>,
+}
+
+impl FieldAttributes {
+    fn parse(field: &syn::Field) -> syn::Result<Self> {
+        let mut component = None;
+
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("rerun"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("component") {
+                    component = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown attribute, expected `component`"))
+                }
+            })?;
+        }
+
+        Ok(Self { component })
+    }
+}
@@ -0,0 +1,333 @@
+use std::{fs::File, io::BufWriter, path::PathBuf};
+
+use anyhow::Context as _;
+
+use re_build_info::CrateVersion;
+use re_chunk::TimelineName;
+use re_log_types::{
+    AbsoluteTimeRange, ApplicationId, EntityPath, EntityPathFilter, FileSource, LogMsg,
+    RecordingId, ResolvedEntityPathFilter,
+};
+use re_sdk::{
+    DataLoaderSettings,
+    external::re_data_loader::{self, ImageCompression, VideoEncoding},
+};
+use re_smart_channel::{SmartChannelSource, SmartMessagePayload, SmartMessageSource};
+
+use crate::commands::rrd::{keep_time_ranges, parse_time_range};
+
+// ---
+
+/// Converts any file supported by the data-loaders to an .rrd, without starting a viewer.
+///
+/// This runs the same data-loaders as the viewer does when opening a file, including external ones.
+///
+/// Example: `rerun convert --topic /camera/** --time-range log_time=0..1000000000 input.mcap output.rrd`
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ConvertCommand {
+    /// Path to read from.
+    path_to_input: PathBuf,
+
+    /// Path to write to.
+    #[arg(value_name = "dst.rrd")]
+    path_to_output_rrd: PathBuf,
+
+    /// If set, specifies the application id of the output.
+    ///
+    /// Defaults to the name of the input file.
+    #[clap(long = "application-id")]
+    application_id: Option<String>,
+
+    /// If set, specifies the recording id of the output.
+    #[clap(long = "recording-id")]
+    recording_id: Option<String>,
+
+    /// If set, prefixes the path of all entities with this.
+    #[clap(long = "entity-path-prefix")]
+    entity_path_prefix: Option<String>,
+
+    /// Topics (i.e. entity paths) to keep, as entity path filter rules such as `/camera/**`.
+    ///
+    /// Rules starting with `-` exclude topics instead, e.g. `--topic /** --topic "-/tf"`.
+    /// Conflicting rules are resolved by the last one. Keeps everything if unspecified.
+    ///
+    /// Rules are matched against the entity paths of the output, i.e. after `--entity-path-prefix` was applied.
+    #[clap(long = "topic")]
+    topics: Vec<String>,
+
+    /// Time ranges to keep, in the form `timeline=min..max`.
+    ///
+    /// Both bounds are inclusive and in the native unit of the timeline (e.g. nanoseconds for
+    /// timestamps). Either bound can be left out, e.g. `frame=100..`.
+    ///
+    /// Only rows within all of the ranges are kept, splitting chunks as needed.
+    /// Static data, and data not on the timeline of a range, is kept.
+    #[clap(long = "time-range", value_parser = parse_time_range)]
+    time_ranges: Vec<(TimelineName, AbsoluteTimeRange)>,
+
+    /// If set, re-encodes raw images as `png`, `jpeg` or `jpeg:<quality>` (from 1 to 100).
+    ///
+    /// JPEG is lossy, but shrinks camera-heavy recordings considerably. Images with an alpha channel
+    /// or 16 bit channels are encoded as PNG instead.
+    #[clap(long = "image-compression")]
+    image_compression: Option<ImageCompression>,
+
+    /// If set, encodes sequences of raw images to H.264 video streams with this FFmpeg encoder.
+    ///
+    /// Use `libx264` for software encoding, or a hardware encoder such as `h264_nvenc`, `h264_qsv`
+    /// or `h264_videotoolbox`. Every frame is kept at its original time. Requires FFmpeg.
+    #[clap(long = "video-encoder")]
+    video_encoder: Option<String>,
+
+    /// Maximum number of frames between two key frames of the encoded videos.
+    #[clap(long = "video-keyframe-interval", default_value_t = 30)]
+    video_keyframe_interval: u32,
+
+    /// Entity path filter rules restricting which images are encoded to videos, e.g. `/camera/**`.
+    ///
+    /// Encodes all raw images if unspecified.
+    #[clap(long = "video-topic")]
+    video_topics: Vec<String>,
+}
+
+impl ConvertCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            path_to_input,
+            path_to_output_rrd,
+            application_id,
+            recording_id,
+            entity_path_prefix,
+            topics,
+            time_ranges,
+            image_compression,
+            video_encoder,
+            video_keyframe_interval,
+            video_topics,
+        } = self;
+
+        let now = std::time::Instant::now();
+        re_log::info!(
+            src = ?path_to_input,
+            dst = ?path_to_output_rrd,
+            ?topics,
+            ?time_ranges,
+            "conversion started"
+        );
+
+        let settings = DataLoaderSettings {
+            entity_path_prefix: entity_path_prefix
+                .as_deref()
+                .map(EntityPath::parse_forgiving),
+            image_compression: *image_compression,
+            video_encoding: video_encoder.as_ref().map(|encoder| VideoEncoding {
+                keyframe_interval: *video_keyframe_interval,
+                entity_filter: (!video_topics.is_empty()).then(|| {
+                    EntityPathFilter::from_query_expressions(
+                        video_topics.iter().map(String::as_str),
+                    )
+                    .resolve_without_substitutions()
+                }),
+                ..VideoEncoding::with_encoder(encoder)
+            }),
+            ..DataLoaderSettings::recommended(
+                recording_id
+                    .clone()
+                    .map_or_else(RecordingId::random, RecordingId::from),
+            )
+        };
+
+        // `load_from_path` always names the application after the file, so we have to
+        // override it afterwards.
+        let application_id = application_id.clone().map(ApplicationId::from);
+
+        let topic_filter = (!topics.is_empty()).then(|| {
+            EntityPathFilter::from_query_expressions(topics.iter().map(String::as_str))
+                .resolve_without_substitutions()
+        });
+
+        let (tx, rx) = re_smart_channel::smart_channel(
+            SmartMessageSource::File(path_to_input.clone()),
+            SmartChannelSource::File(path_to_input.clone()),
+        );
+        re_data_loader::load_from_path(&settings, FileSource::Cli, path_to_input, &tx)
+            .with_context(|| format!("{path_to_input:?}"))?;
+        drop(tx);
+
+        let mut rrd_out = BufWriter::new(
+            File::create(path_to_output_rrd).with_context(|| format!("{path_to_output_rrd:?}"))?,
+        );
+        let mut encoder = {
+            let version = CrateVersion::LOCAL;
+            let options = re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED;
+            re_log_encoding::encoder::DroppableEncoder::new(version, options, &mut rrd_out)
+                .context("couldn't init encoder")?
+                .with_encryption_key(super::rrd_encryption_key()?)
+        };
+
+        let mut num_msgs = 0;
+        let mut size_bytes = 0;
+        while let Ok(msg) = rx.recv() {
+            match msg.payload {
+                SmartMessagePayload::Msg(msg) => {
+                    let msg = with_application_id(msg, application_id.as_ref());
+                    let Some(msg) = filter_msg(msg, topic_filter.as_ref(), time_ranges)? else {
+                        continue;
+                    };
+
+                    num_msgs += 1;
+                    size_bytes += encoder.append(&msg).context("encoding failure")?;
+                }
+
+                SmartMessagePayload::Flush { on_flush_done } => on_flush_done(),
+
+                SmartMessagePayload::Quit(None) => break,
+
+                SmartMessagePayload::Quit(Some(err)) => {
+                    anyhow::bail!("failed to load {path_to_input:?}: {err}");
+                }
+            }
+        }
+
+        drop(encoder);
+        {
+            use std::io::Write as _;
+            rrd_out.flush().context("couldn't flush output")?;
+        }
+
+        re_log::info!(
+            num_msgs,
+            dst_size_bytes = %re_format::format_bytes(size_bytes as _),
+            time = ?now.elapsed(),
+            "conversion finished"
+        );
+
+        Ok(())
+    }
+}
+
+/// Moves the message to the given application, if any.
+fn with_application_id(msg: LogMsg, application_id: Option<&ApplicationId>) -> LogMsg {
+    let Some(application_id) = application_id else {
+        return msg;
+    };
+
+    match msg {
+        LogMsg::SetStoreInfo(mut set_store_info) => {
+            set_store_info.info.store_id = set_store_info
+                .info
+                .store_id
+                .with_application_id(application_id.clone());
+            LogMsg::SetStoreInfo(set_store_info)
+        }
+
+        LogMsg::ArrowMsg(store_id, arrow_msg) => LogMsg::ArrowMsg(
+            store_id.with_application_id(application_id.clone()),
+            arrow_msg,
+        ),
+
+        LogMsg::BlueprintActivationCommand(mut blueprint_activation_command) => {
+            blueprint_activation_command.blueprint_id = blueprint_activation_command
+                .blueprint_id
+                .with_application_id(application_id.clone());
+            LogMsg::BlueprintActivationCommand(blueprint_activation_command)
+        }
+    }
+}
+
+/// Applies the topic filter and the time ranges to the data of recordings.
+///
+/// Blueprints are kept as is. Returns `None` if nothing is left of the message.
+fn filter_msg(
+    msg: LogMsg,
+    topic_filter: Option<&ResolvedEntityPathFilter>,
+    time_ranges: &[(TimelineName, AbsoluteTimeRange)],
+) -> anyhow::Result<Option<LogMsg>> {
+    let LogMsg::ArrowMsg(store_id, arrow_msg) = msg else {
+        return Ok(Some(msg));
+    };
+    if !store_id.is_recording() {
+        return Ok(Some(LogMsg::ArrowMsg(store_id, arrow_msg)));
+    }
+
+    if let Some(topic_filter) = topic_filter {
+        let batch = re_sorbet::ChunkBatch::try_from(&arrow_msg.batch)
+            .context("failed to parse chunk schema")?;
+        if !topic_filter.matches(batch.entity_path()) {
+            return Ok(None);
+        }
+    }
+
+    let arrow_msg = if time_ranges.is_empty() {
+        Some(arrow_msg)
+    } else {
+        keep_time_ranges(arrow_msg, time_ranges)?
+    };
+
+    Ok(arrow_msg.map(|arrow_msg| LogMsg::ArrowMsg(store_id, arrow_msg)))
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::{Chunk, RowId, TimePoint, Timeline};
+    use re_log_types::StoreId;
+    use re_types::archetypes::Clear;
+
+    use super::*;
+
+    fn chunk_msg(store_id: &StoreId, entity_path: &str, frames: &[i64]) -> LogMsg {
+        let timeline = Timeline::new_sequence("frame");
+        let mut builder = Chunk::builder(entity_path);
+        for &frame in frames {
+            builder = builder.with_archetype(
+                RowId::new(),
+                TimePoint::default().with(timeline, frame),
+                &Clear::flat(),
+            );
+        }
+        let chunk = builder.build().unwrap();
+        LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg().unwrap())
+    }
+
+    fn num_rows(msg: Option<&LogMsg>) -> usize {
+        match msg {
+            Some(LogMsg::ArrowMsg(_, arrow_msg)) => {
+                Chunk::from_arrow_msg(arrow_msg).unwrap().num_rows()
+            }
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn filters_topics_and_time_ranges() {
+        let store_id = StoreId::random(re_log_types::StoreKind::Recording, "test");
+        let topic_filter =
+            EntityPathFilter::from_query_expressions(["/camera/**", "-/camera/depth"])
+                .resolve_without_substitutions();
+        let time_ranges = [parse_time_range("frame=2..3").unwrap()];
+
+        let filter = |entity_path, frames: &[i64]| {
+            filter_msg(
+                chunk_msg(&store_id, entity_path, frames),
+                Some(&topic_filter),
+                &time_ranges,
+            )
+            .unwrap()
+        };
+
+        assert_eq!(num_rows(filter("/camera/rgb", &[1, 2, 3, 4]).as_ref()), 2);
+        assert!(filter("/camera/rgb", &[5, 6]).is_none());
+        assert!(filter("/camera/depth", &[1, 2, 3, 4]).is_none());
+        assert!(filter("/lidar", &[1, 2, 3, 4]).is_none());
+
+        // Blueprints are never filtered.
+        let blueprint_id = StoreId::random(re_log_types::StoreKind::Blueprint, "test");
+        let msg = filter_msg(
+            chunk_msg(&blueprint_id, "/lidar", &[1]),
+            Some(&topic_filter),
+            &time_ranges,
+        )
+        .unwrap();
+        assert_eq!(num_rows(msg.as_ref()), 1);
+    }
+}
@@ -0,0 +1,84 @@
+//! Pinning all of Rerun to a subset of the CPU cores, see `rerun --cpu-affinity`.
+
+/// Parses a list of CPU cores in the format of `taskset --cpu-list`, e.g. `0,2,4-7`.
+pub fn parse_cpu_list(s: &str) -> Result<Vec<usize>, String> {
+    let parse_core = |core: &str| {
+        core.trim()
+            .parse::<usize>()
+            .map_err(|err| format!("invalid core {core:?}: {err}"))
+    };
+
+    let mut cores = Vec::new();
+    for part in s.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+        if let Some((first, last)) = part.split_once('-') {
+            let (first, last) = (parse_core(first)?, parse_core(last)?);
+            if last < first {
+                return Err(format!("invalid core range {part:?}"));
+            }
+            cores.extend(first..=last);
+        } else {
+            cores.push(parse_core(part)?);
+        }
+    }
+
+    if cores.is_empty() {
+        return Err(format!("expected a list of cores like `2-5`, got {s:?}"));
+    }
+
+    cores.sort_unstable();
+    cores.dedup();
+    Ok(cores)
+}
+
+/// Restricts the calling thread, and all threads and processes it spawns afterwards, to the given cores.
+///
+/// Call this on the main thread before spawning any threads, so that it applies to the whole process.
+#[cfg(target_os = "linux")]
+#[allow(unsafe_code)]
+pub fn set_cpu_affinity(cores: &[usize]) -> std::io::Result<()> {
+    // SAFETY: `cpu_set_t` is a plain bit set, for which all zeros is the empty set.
+    let mut cpu_set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    for &core in cores {
+        if core >= libc::CPU_SETSIZE as usize {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("core {core} is out of range"),
+            ));
+        }
+
+        // SAFETY: the core was checked to be within the bounds of the set.
+        unsafe { libc::CPU_SET(core, &mut cpu_set) };
+    }
+
+    // SAFETY: the set is initialized, and pid 0 refers to the calling thread.
+    let result =
+        unsafe { libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &cpu_set) };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn set_cpu_affinity(_cores: &[usize]) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "CPU affinity is only supported on Linux",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(parse_cpu_list("3"), Ok(vec![3]));
+        assert_eq!(parse_cpu_list("2-5"), Ok(vec![2, 3, 4, 5]));
+        assert_eq!(parse_cpu_list("6, 0,2-3,2"), Ok(vec![0, 2, 3, 6]));
+        assert!(parse_cpu_list("").is_err());
+        assert!(parse_cpu_list("5-2").is_err());
+        assert!(parse_cpu_list("a-b").is_err());
+    }
+}
@@ -0,0 +1,218 @@
+use std::{collections::BTreeSet, path::PathBuf};
+
+use crate::commands::inspect::{RecordingSummary, load_recordings};
+
+// ---
+
+/// Compares the schema and row counts of two recordings.
+///
+/// Checks that both have the same timelines, entities and components (including their Arrow
+/// datatypes), and the same number of rows per entity. The data itself and the way it is split
+/// into chunks are not compared, see `rerun rrd compare` for that.
+///
+/// Exits with a non-zero code if they differ, so that e.g. conversions can be validated in scripts.
+/// Supports any file supported by the data-loaders, e.g. .rrd or .mcap.
+///
+/// Example: `rerun diff original.mcap converted.rrd`
+#[derive(Debug, Clone, clap::Parser)]
+pub struct DiffCommand {
+    path_to_input1: PathBuf,
+    path_to_input2: PathBuf,
+}
+
+impl DiffCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            path_to_input1,
+            path_to_input2,
+        } = self;
+
+        let load_summary = |path: &PathBuf| -> anyhow::Result<RecordingSummary> {
+            let mut recordings = load_recordings(path)?;
+            anyhow::ensure!(
+                recordings.len() == 1,
+                "expected a single data recording in {path:?}, found {}",
+                recordings.len()
+            );
+            #[allow(clippy::unwrap_used)] // safe, ensured above
+            let recording = recordings.pop().unwrap();
+            Ok(RecordingSummary::from_entity_db(&recording))
+        };
+
+        let summary1 = load_summary(path_to_input1)?;
+        let summary2 = load_summary(path_to_input2)?;
+
+        let differences = diff_summaries(&summary1, &summary2);
+        for difference in &differences {
+            println!("{difference}");
+        }
+
+        anyhow::ensure!(
+            differences.is_empty(),
+            "{path_to_input1:?} and {path_to_input2:?} differ in {} places",
+            differences.len()
+        );
+
+        re_log::info!(
+            "{path_to_input1:?} and {path_to_input2:?} have the same schema and row counts"
+        );
+
+        Ok(())
+    }
+}
+
+/// Describes every difference in schema or row counts, one per line.
+///
+/// Lines starting with `-` are about what's only in the first recording, `+` what's only in the second,
+/// and `~` what's in both but differs.
+fn diff_summaries(summary1: &RecordingSummary, summary2: &RecordingSummary) -> Vec<String> {
+    let mut differences = Vec::new();
+
+    let timelines: BTreeSet<_> = summary1
+        .timelines
+        .keys()
+        .chain(summary2.timelines.keys())
+        .collect();
+    for name in timelines {
+        match (summary1.timelines.get(name), summary2.timelines.get(name)) {
+            (Some(_), None) => differences.push(format!("- timeline {name}")),
+            (None, Some(_)) => differences.push(format!("+ timeline {name}")),
+            (Some(timeline1), Some(timeline2)) => {
+                if timeline1.timeline.typ() != timeline2.timeline.typ() {
+                    differences.push(format!(
+                        "~ timeline {name}: {} vs. {}",
+                        timeline1.timeline.typ(),
+                        timeline2.timeline.typ()
+                    ));
+                }
+            }
+            (None, None) => {}
+        }
+    }
+
+    let entities: BTreeSet<_> = summary1
+        .entities
+        .keys()
+        .chain(summary2.entities.keys())
+        .collect();
+    for entity_path in entities {
+        let (entity1, entity2) = match (
+            summary1.entities.get(entity_path),
+            summary2.entities.get(entity_path),
+        ) {
+            (Some(entity1), Some(entity2)) => (entity1, entity2),
+            (Some(_), None) => {
+                differences.push(format!("- entity {entity_path}"));
+                continue;
+            }
+            (None, Some(_)) => {
+                differences.push(format!("+ entity {entity_path}"));
+                continue;
+            }
+            (None, None) => continue,
+        };
+
+        if entity1.num_rows != entity2.num_rows {
+            differences.push(format!(
+                "~ entity {entity_path}: {} vs. {} rows",
+                entity1.num_rows, entity2.num_rows
+            ));
+        }
+        if entity1.num_static_rows != entity2.num_static_rows {
+            differences.push(format!(
+                "~ entity {entity_path}: {} vs. {} static rows",
+                entity1.num_static_rows, entity2.num_static_rows
+            ));
+        }
+
+        let components: BTreeSet<_> = entity1
+            .components
+            .keys()
+            .chain(entity2.components.keys())
+            .collect();
+        for component in components {
+            match (
+                entity1.components.get(component),
+                entity2.components.get(component),
+            ) {
+                (Some(_), None) => {
+                    differences.push(format!("- component {entity_path}:{component}"));
+                }
+                (None, Some(_)) => {
+                    differences.push(format!("+ component {entity_path}:{component}"));
+                }
+                (Some(datatype1), Some(datatype2)) => {
+                    if datatype1 != datatype2 {
+                        differences.push(format!(
+                            "~ component {entity_path}:{component}: {datatype1} vs. {datatype2}"
+                        ));
+                    }
+                }
+                (None, None) => {}
+            }
+        }
+    }
+
+    differences
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::{Chunk, RowId, TimePoint, Timeline};
+    use re_types::archetypes::Points2D;
+
+    use super::*;
+
+    fn points_chunk(entity_path: &str, frames: &[i64], with_radii: bool) -> Chunk {
+        let timeline = Timeline::new_sequence("frame");
+        let mut builder = Chunk::builder(entity_path);
+        for &frame in frames {
+            let mut points = Points2D::new([(0.0, 0.0)]);
+            if with_radii {
+                points = points.with_radii([1.0]);
+            }
+            builder = builder.with_archetype(
+                RowId::new(),
+                TimePoint::default().with(timeline, frame),
+                &points,
+            );
+        }
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn same_schema_and_rows() {
+        // Chunking doesn't matter.
+        let summary1 = RecordingSummary::from_chunks(&[points_chunk("/points", &[1, 2], false)]);
+        let summary2 = RecordingSummary::from_chunks(&[
+            points_chunk("/points", &[1], false),
+            points_chunk("/points", &[2], false),
+        ]);
+
+        assert!(diff_summaries(&summary1, &summary2).is_empty());
+        assert_eq!(summary2.num_chunks(), 2);
+        assert_eq!(summary2.num_rows(), 2);
+    }
+
+    #[test]
+    fn different_schema_and_rows() {
+        let summary1 = RecordingSummary::from_chunks(&[
+            points_chunk("/points", &[1, 2], false),
+            points_chunk("/removed", &[1], false),
+        ]);
+        let summary2 = RecordingSummary::from_chunks(&[
+            points_chunk("/points", &[1], true),
+            points_chunk("/added", &[1], false),
+        ]);
+
+        assert_eq!(
+            diff_summaries(&summary1, &summary2),
+            [
+                "+ entity /added".to_owned(),
+                "~ entity /points: 2 vs. 1 rows".to_owned(),
+                "+ component /points:Points2D:radii".to_owned(),
+                "- entity /removed".to_owned(),
+            ]
+        );
+    }
+}
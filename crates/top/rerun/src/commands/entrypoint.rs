@@ -1,19 +1,22 @@
 use std::net::IpAddr;
 
+use anyhow::Context as _;
 use clap::{CommandFactory as _, Subcommand};
 use crossbeam::channel::Receiver as CrossbeamReceiver;
 use itertools::Itertools as _;
-use tokio::runtime::Runtime;
 
 use re_data_source::DataSource;
 use re_log_types::{LogMsg, TableMsg};
 use re_smart_channel::{ReceiveSet, Receiver, SmartMessagePayload};
 use re_uri::RedapUri;
 
-use crate::{CallSource, commands::RrdCommands};
+use crate::{
+    CallSource,
+    commands::{FilterCommand, RrdCommands},
+};
 
 #[cfg(feature = "data_loaders")]
-use crate::commands::McapCommands;
+use crate::commands::{ConvertCommand, DiffCommand, InspectCommand, McapCommands};
 
 #[cfg(feature = "web_viewer")]
 use re_sdk::web_viewer::WebViewerConfig;
@@ -132,6 +135,94 @@ Default is `0B`, or `25%` if any of the `--serve-*` flags are set."
     )]
     server_memory_limit: Option<String>,
 
+    /// A token that lets a client of the gRPC server (`--serve-grpc`, `--serve-web`) receive data, e.g. a viewer.
+    ///
+    /// Once any `--server-*-token` is given, clients without a valid token are rejected.
+    /// Clients pass their token as `Bearer <token>` in the `authorization` metadata of each request,
+    /// see `--proxy-token`.
+    /// Can be given several times, or through the `RERUN_SERVER_READ_TOKEN` environment variable.
+    #[cfg(feature = "server")]
+    #[clap(long, env = "RERUN_SERVER_READ_TOKEN", hide_env_values = true)]
+    server_read_token: Vec<String>,
+
+    /// A file with one `--server-read-token` per line.
+    ///
+    /// Empty lines and lines starting with `#` are ignored.
+    #[cfg(feature = "server")]
+    #[clap(long)]
+    server_read_token_file: Option<std::path::PathBuf>,
+
+    /// A token that lets a client of the gRPC server (`--serve-grpc`, `--serve-web`) send data, e.g. a logging SDK.
+    ///
+    /// Can be given several times, or through the `RERUN_SERVER_WRITE_TOKEN` environment variable.
+    /// See `--server-read-token`.
+    #[cfg(feature = "server")]
+    #[clap(long, env = "RERUN_SERVER_WRITE_TOKEN", hide_env_values = true)]
+    server_write_token: Vec<String>,
+
+    /// A file with one `--server-write-token` per line.
+    ///
+    /// Empty lines and lines starting with `#` are ignored.
+    #[cfg(feature = "server")]
+    #[clap(long)]
+    server_write_token_file: Option<std::path::PathBuf>,
+
+    /// Have the gRPC server (`--serve-grpc`, `--serve-web`) serve over TLS, with this PEM encoded certificate chain.
+    ///
+    /// Requires `--server-tls-key`. Clients then connect with `rerun+https://`.
+    #[cfg(feature = "server")]
+    #[clap(long, requires = "server_tls_key")]
+    server_tls_cert: Option<std::path::PathBuf>,
+
+    /// The PEM encoded private key of `--server-tls-cert`.
+    #[cfg(feature = "server")]
+    #[clap(long, requires = "server_tls_cert")]
+    server_tls_key: Option<std::path::PathBuf>,
+
+    /// Only let in the clients presenting a certificate signed by this PEM encoded certificate authority (mTLS).
+    ///
+    /// Requires `--server-tls-cert`.
+    #[cfg(feature = "server")]
+    #[clap(long, requires = "server_tls_cert")]
+    server_tls_client_ca: Option<std::path::PathBuf>,
+
+    /// The maximum number of recordings the gRPC server keeps for late-arriving viewers.
+    ///
+    /// When this limit is reached, the recording that was least recently written to is dropped as a whole.
+    /// Recordings pinned with `--server-pin-recording` count towards the limit, but are never dropped.
+    #[cfg(feature = "server")]
+    #[clap(long)]
+    server_max_recordings: Option<usize>,
+
+    /// How long the gRPC server keeps recordings that aren't written to anymore, e.g. "30m" or "24h".
+    ///
+    /// Recordings pinned with `--server-pin-recording` are never dropped.
+    #[cfg(feature = "server")]
+    #[clap(long)]
+    server_recording_ttl: Option<String>,
+
+    /// The id of a recording the gRPC server should never drop.
+    ///
+    /// The recording is still subject to `--server-memory-limit`. Can be given several times.
+    #[cfg(feature = "server")]
+    #[clap(long)]
+    server_pin_recording: Vec<String>,
+
+    /// Have the gRPC server also write all incoming data to disk, as it arrives.
+    ///
+    /// Each recording is appended to its own `<recording_id>.rrd` file in this directory,
+    /// so that nothing is lost if a viewer crashes or data has to be dropped from memory.
+    #[cfg(feature = "server")]
+    #[clap(long)]
+    server_spool_dir: Option<std::path::PathBuf>,
+
+    /// A directory the gRPC server may load MCAP files from, when a client asks for it by path.
+    ///
+    /// Clients can always upload MCAP files for the server to convert.
+    #[cfg(feature = "server")]
+    #[clap(long)]
+    server_load_dir: Option<std::path::PathBuf>,
+
     #[clap(
         long,
         default_value_t = true,
@@ -152,6 +243,12 @@ When persisted, the state will be stored at the following locations:
     #[clap(long)]
     profile: bool,
 
+    /// Skip the messages of `.rrd` files that cannot be decoded, rather than giving up on the file.
+    ///
+    /// Useful for recordings cut short by a crash, which might end with garbage.
+    #[clap(long)]
+    rrd_recovery: bool,
+
     /// Stream incoming log events to an .rrd file at the given path.
     #[clap(long)]
     save: Option<String>,
@@ -162,6 +259,26 @@ When persisted, the state will be stored at the following locations:
     #[clap(long)]
     screenshot_to: Option<std::path::PathBuf>,
 
+    /// Render a view at every time with data to PNG frames or an MP4 video, and quit.
+    ///
+    /// The view is given by its name or id. Useful together with `--export-to` and `--window-size`.
+    #[clap(long, requires = "export_to")]
+    export_view: Option<String>,
+
+    /// Where to save the export of `--export-view`: an `.mp4` video, or numbered frames for a `.png` path.
+    ///
+    /// Videos are encoded with FFmpeg, which has to be installed.
+    #[clap(long, requires = "export_view")]
+    export_to: Option<std::path::PathBuf>,
+
+    /// The timeline to step through with `--export-view`, the default one if not set.
+    #[clap(long, requires = "export_view")]
+    export_timeline: Option<String>,
+
+    /// The frame rate of videos exported with `--export-view`.
+    #[clap(long, default_value_t = 30.0)]
+    export_fps: f32,
+
     /// Deprecated: use `--serve-web` instead.
     #[clap(long)]
     serve: bool,
@@ -196,6 +313,17 @@ When persisted, the state will be stored at the following locations:
     #[allow(clippy::option_option)] // Tri-state: none, --connect, --connect <url>.
     connect: Option<Option<String>>,
 
+    /// The token to present to the gRPC servers we connect to, if they require one.
+    ///
+    /// Used by `--connect` and by `/proxy` URLs.
+    /// Can also be given through the `RERUN_PROXY_TOKEN` environment variable.
+    #[clap(long, env = "RERUN_PROXY_TOKEN", hide_env_values = true)]
+    proxy_token: Option<String>,
+
+    /// A file containing the `--proxy-token`.
+    #[clap(long, conflicts_with = "proxy_token")]
+    proxy_token_file: Option<std::path::PathBuf>,
+
     /// This is a hint that we expect a recording to stream in very soon.
     ///
     /// This is set by the `spawn()` method in our logging SDK.
@@ -206,19 +334,45 @@ When persisted, the state will be stored at the following locations:
     #[clap(long)]
     expect_data_soon: bool,
 
-    /// The number of compute threads to use.
+    /// The number of compute threads to use, e.g. for ingesting and querying data.
     ///
     /// If zero, the same number of threads as the number of cores will be used.
     /// If negative, will use that much fewer threads than cores.
     ///
-    /// Rerun will still use some additional threads for I/O.
+    /// Rerun will still use some additional threads for I/O, see `--io-threads`.
+    /// Can also be given through the `RERUN_THREADS` environment variable, e.g. for viewers spawned by an SDK.
     #[clap(
         long,
         short = 'j',
+        env = "RERUN_THREADS",
         default_value = "-2", // save some CPU for the main thread and the rest of the users system
     )]
     threads: i32,
 
+    /// The number of threads to use for networking and other I/O.
+    ///
+    /// If zero, the same number of threads as the number of cores will be used.
+    /// Can also be given through the `RERUN_IO_THREADS` environment variable.
+    #[clap(long, env = "RERUN_IO_THREADS", default_value = "0")]
+    io_threads: usize,
+
+    /// The number of threads each software video decoder may use.
+    ///
+    /// If zero, the decoders pick a number based on the number of cores.
+    /// Can also be given through the `RERUN_VIDEO_DECODER_THREADS` environment variable.
+    #[clap(long, env = "RERUN_VIDEO_DECODER_THREADS", default_value = "0")]
+    video_decoder_threads: usize,
+
+    /// Only run on these CPU cores, e.g. `2-5` or `0,2,4-7`.
+    ///
+    /// All threads of Rerun, as well as the processes it spawns (e.g. FFmpeg), are pinned to
+    /// these cores, leaving the others free for e.g. the control loops of a robot.
+    /// The number of cores that `--threads` and `--io-threads` refer to is then the number of these cores.
+    ///
+    /// Only supported on Linux. Can also be given through the `RERUN_CPU_AFFINITY` environment variable.
+    #[clap(long, env = "RERUN_CPU_AFFINITY", value_parser = super::cpu_affinity::parse_cpu_list)]
+    cpu_affinity: Option<Vec<usize>>,
+
     #[clap(long_help = r"Any combination of:
 - A gRPC url to a Rerun server
 - A path to a Rerun .rrd recording
@@ -299,6 +453,13 @@ If no arguments are given, a server will be hosted which a Rerun SDK can connect
     #[clap(long, verbatim_doc_comment)]
     video_decoder: Option<String>,
 
+    /// Load a Viewer plugin with custom views and visualizers from this dynamic library.
+    ///
+    /// The plugin must have been built against the same version of Rerun, with the same Rust compiler.
+    /// Can be passed multiple times.
+    #[clap(long)]
+    plugin: Vec<std::path::PathBuf>,
+
     // ----------------------------------------------------------------------------
     // Debug-options:
     /// Ingest data and then quit once the goodbye message has been received.
@@ -537,6 +698,17 @@ enum Command {
     #[command(subcommand)]
     Analytics(AnalyticsCommands),
 
+    #[cfg(feature = "data_loaders")]
+    Convert(ConvertCommand),
+
+    #[cfg(feature = "data_loaders")]
+    Diff(DiffCommand),
+
+    Filter(FilterCommand),
+
+    #[cfg(feature = "data_loaders")]
+    Inspect(InspectCommand),
+
     #[cfg(feature = "data_loaders")]
     #[command(subcommand)]
     Mcap(McapCommands),
@@ -587,6 +759,62 @@ pub fn run<I, T>(
     call_source: CallSource,
     args: I,
 ) -> anyhow::Result<u8>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<std::ffi::OsString> + Clone,
+{
+    run_with_optional_plugins(
+        main_thread_token,
+        build_info,
+        call_source,
+        args,
+        #[cfg(feature = "native_viewer")]
+        Vec::new(),
+    )
+}
+
+/// Like [`run`], but with custom view classes and visualizers compiled into the Viewer.
+///
+/// This lets downstream crates ship their own panels inside the standard Rerun Viewer,
+/// including its full command line interface:
+///
+/// ```ignore
+/// fn main() -> anyhow::Result<std::process::ExitCode> {
+///     let main_thread_token = rerun::MainThreadToken::i_promise_i_am_on_the_main_thread();
+///     rerun::run_with_plugins(
+///         main_thread_token,
+///         re_build_info::build_info!(),
+///         rerun::CallSource::Cli,
+///         std::env::args(),
+///         vec![Box::new(BatteryDashboardPlugin)],
+///     )
+///     .map(std::process::ExitCode::from)
+/// }
+/// ```
+///
+/// See [`re_viewer::ViewerPlugin`] for how to write a plugin.
+#[cfg(feature = "native_viewer")]
+pub fn run_with_plugins<I, T>(
+    main_thread_token: crate::MainThreadToken,
+    build_info: re_build_info::BuildInfo,
+    call_source: CallSource,
+    args: I,
+    plugins: Vec<Box<dyn re_viewer::ViewerPlugin>>,
+) -> anyhow::Result<u8>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<std::ffi::OsString> + Clone,
+{
+    run_with_optional_plugins(main_thread_token, build_info, call_source, args, plugins)
+}
+
+fn run_with_optional_plugins<I, T>(
+    main_thread_token: crate::MainThreadToken,
+    build_info: re_build_info::BuildInfo,
+    call_source: CallSource,
+    args: I,
+    #[cfg(feature = "native_viewer")] plugins: Vec<Box<dyn re_viewer::ViewerPlugin>>,
+) -> anyhow::Result<u8>
 where
     I: IntoIterator<Item = T>,
     T: Into<std::ffi::OsString> + Clone,
@@ -602,6 +830,14 @@ where
     use clap::Parser as _;
     let mut args = Args::parse_from(args);
 
+    // Before any threads are spawned, so that they all inherit it:
+    if let Some(cores) = &args.cpu_affinity {
+        match super::cpu_affinity::set_cpu_affinity(cores) {
+            Ok(()) => re_log::debug!("Pinned to cores {cores:?}"),
+            Err(err) => re_log::warn!("Failed to set the CPU affinity: {err}"),
+        }
+    }
+
     initialize_thread_pool(args.threads);
 
     if args.web_viewer {
@@ -621,7 +857,13 @@ where
     // We don't want the runtime to run on the main thread, as we need that one for our UI.
     // So we can't call `block_on` anywhere in the entrypoint - we must call `tokio::spawn`
     // and synchronize the result using some other means instead.
-    let tokio_runtime = Runtime::new()?;
+    let tokio_runtime = {
+        let mut builder = tokio::runtime::Builder::new_multi_thread();
+        if args.io_threads > 0 {
+            builder.worker_threads(args.io_threads);
+        }
+        builder.enable_all().build()?
+    };
     let _tokio_guard = tokio_runtime.enter();
 
     let res = if let Some(command) = args.command {
@@ -629,6 +871,17 @@ where
             #[cfg(feature = "analytics")]
             Command::Analytics(analytics) => analytics.run().map_err(Into::into),
 
+            #[cfg(feature = "data_loaders")]
+            Command::Convert(convert) => convert.run(),
+
+            #[cfg(feature = "data_loaders")]
+            Command::Diff(diff) => diff.run(),
+
+            Command::Filter(filter) => filter.run(),
+
+            #[cfg(feature = "data_loaders")]
+            Command::Inspect(inspect) => inspect.run(),
+
             #[cfg(feature = "data_loaders")]
             Command::Mcap(mcap) => mcap.run(),
 
@@ -705,6 +958,8 @@ where
             tokio_runtime.handle(),
             #[cfg(feature = "native_viewer")]
             profiler,
+            #[cfg(feature = "native_viewer")]
+            plugins,
         )
     };
 
@@ -734,9 +989,16 @@ fn run_impl(
     args: Args,
     tokio_runtime_handle: &tokio::runtime::Handle,
     #[cfg(feature = "native_viewer")] profiler: re_tracing::Profiler,
+    #[cfg(feature = "native_viewer")] plugins: Vec<Box<dyn re_viewer::ViewerPlugin>>,
 ) -> anyhow::Result<()> {
     //TODO(#10068): populate token passed with `--token`
     let connection_registry = re_grpc_client::ConnectionRegistry::new();
+    connection_registry.set_proxy_token(proxy_token(&args)?);
+
+    let rrd_load_options = re_data_source::RrdLoadOptions {
+        decryption_key: super::rrd_decryption_key(),
+        recovery: args.rrd_recovery,
+    };
 
     #[cfg(feature = "server")]
     let mut is_another_server_running = false;
@@ -765,6 +1027,14 @@ fn run_impl(
             persist_state: args.persist_state,
             is_in_notebook: false,
             screenshot_to_path_then_quit: args.screenshot_to.clone(),
+            export_view_then_quit: args.export_view.clone().zip(args.export_to.clone()).map(
+                |(view, path)| re_viewer::ViewExportOptions {
+                    view,
+                    path,
+                    timeline: args.export_timeline.clone(),
+                    fps: args.export_fps,
+                },
+            ),
 
             expect_data_soon: if args.expect_data_soon {
                 Some(true)
@@ -780,6 +1050,9 @@ fn run_impl(
             },
             force_wgpu_backend: args.renderer.clone(),
             video_decoder_hw_acceleration,
+            video_decoder_threads: std::num::NonZeroUsize::new(args.video_decoder_threads),
+            plugins: args.plugin.clone(),
+            rrd_load_options: rrd_load_options.clone(),
 
             on_event: None,
 
@@ -807,6 +1080,8 @@ fn run_impl(
         re_memory::MemoryLimit::parse(value)
             .map_err(|err| anyhow::format_err!("Bad --server-memory-limit: {err}"))?
     };
+    #[cfg(feature = "server")]
+    let server_options = parse_server_options(&args)?;
 
     #[allow(unused_variables)]
     let (command_sender, command_receiver) = re_global_context::command_channel();
@@ -879,7 +1154,12 @@ fn run_impl(
                 // TODO(#10093): this is problematic because the connection registry's token have
                 // not yet been deserialized from persistence (this is done later by `App`. So if
                 // this requires such a token, it will fail even though it'd succeed later.
-                match data_source.stream(&connection_registry, on_cmd.clone(), None) {
+                match data_source.stream(
+                    &connection_registry,
+                    &rrd_load_options,
+                    on_cmd.clone(),
+                    None,
+                ) {
                     Ok(re_data_source::StreamSource::LogMessages(rx)) => Some(Ok(rx)),
 
                     Ok(re_data_source::StreamSource::CatalogUri(uri)) => {
@@ -903,7 +1183,11 @@ fn run_impl(
             let re_uri::RedapUri::Proxy(uri) = url.as_str().parse()? else {
                 anyhow::bail!("expected `/proxy` endpoint");
             };
-            let rx = re_sdk::external::re_grpc_client::message_proxy::stream(uri, None);
+            let rx = re_sdk::external::re_grpc_client::message_proxy::stream(
+                uri,
+                connection_registry.proxy_token(),
+                None,
+            );
             rxs_logs.push(rx);
         } else {
             // Check if there is already a viewer running and if so, send the data to it.
@@ -951,7 +1235,12 @@ fn run_impl(
         }
 
         let rx = ReceiveSet::new(rxs_log);
-        Ok(stream_to_rrd_on_disk(&rx, &rrd_path.into())?)
+        let encryption_key = super::rrd_encryption_key()?;
+        Ok(stream_to_rrd_on_disk(
+            &rx,
+            &rrd_path.into(),
+            encryption_key,
+        )?)
     } else if args.serve_grpc {
         if !redap_uris.is_empty() {
             anyhow::bail!("`--serve` does not support catalogs");
@@ -970,6 +1259,7 @@ fn run_impl(
             re_grpc_server::spawn_from_rx_set(
                 server_addr,
                 server_memory_limit,
+                server_options,
                 shutdown,
                 ReceiveSet::new(rxs_log),
             );
@@ -1013,6 +1303,7 @@ fn run_impl(
             re_grpc_server::spawn_from_rx_set(
                 server_addr,
                 server_memory_limit,
+                server_options,
                 re_grpc_server::shutdown::never(),
                 ReceiveSet::new(rxs_log),
             );
@@ -1119,6 +1410,11 @@ fn run_impl(
                     for rx in rxs_table {
                         app.add_table_receiver(rx);
                     }
+                    for plugin in plugins {
+                        if let Err(err) = app.add_plugin(plugin.as_ref()) {
+                            re_log::error!("{err}");
+                        }
+                    }
                     app.set_profiler(profiler);
                     if let Ok(url) = std::env::var("EXAMPLES_MANIFEST_URL") {
                         app.set_examples_manifest_url(url);
@@ -1269,6 +1565,84 @@ fn parse_size(size: &str) -> anyhow::Result<[f32; 2]> {
         .ok_or_else(|| anyhow::anyhow!("Invalid size {:?}, expected e.g. 800x600", size))
 }
 
+/// The `--server-*` arguments, as [`re_grpc_server::ServerOptions`].
+#[cfg(feature = "server")]
+fn parse_server_options(args: &Args) -> anyhow::Result<re_grpc_server::ServerOptions> {
+    use re_grpc_server::auth::{Permission, ServerAuth};
+
+    let mut options = re_grpc_server::ServerOptions::default();
+
+    let mut read_tokens = args.server_read_token.clone();
+    if let Some(path) = &args.server_read_token_file {
+        read_tokens.extend(read_token_file(path)?);
+    }
+    let mut write_tokens = args.server_write_token.clone();
+    if let Some(path) = &args.server_write_token_file {
+        write_tokens.extend(read_token_file(path)?);
+    }
+
+    if !read_tokens.is_empty() || !write_tokens.is_empty() {
+        let tokens = read_tokens
+            .into_iter()
+            .map(|token| (token, Permission::Read))
+            .chain(
+                write_tokens
+                    .into_iter()
+                    .map(|token| (token, Permission::Write)),
+            );
+        options.auth = Some(
+            tokens.fold(ServerAuth::default(), |auth, (token, permission)| {
+                auth.with_token(token, permission)
+            }),
+        );
+    }
+
+    if let (Some(cert), Some(key)) = (&args.server_tls_cert, &args.server_tls_key) {
+        let read = |path: &std::path::PathBuf| {
+            std::fs::read(path).with_context(|| format!("couldn't read {path:?}"))
+        };
+        let client_ca = args.server_tls_client_ca.as_ref().map(read).transpose()?;
+        options = options.with_tls_pem(read(cert)?, read(key)?, client_ca);
+    }
+
+    options.retention.max_recordings = args.server_max_recordings;
+    if let Some(ttl) = &args.server_recording_ttl {
+        let secs = re_format::parse_duration(ttl)
+            .map_err(|err| anyhow::format_err!("Bad --server-recording-ttl: {err}"))?;
+        options.retention.ttl = Some(std::time::Duration::from_secs_f32(secs));
+    }
+    options.retention.pinned = args.server_pin_recording.iter().cloned().collect();
+
+    options.spool_dir.clone_from(&args.server_spool_dir);
+    options.load_dir.clone_from(&args.server_load_dir);
+
+    Ok(options)
+}
+
+/// The tokens listed in a `--server-*-token-file`, one per line.
+#[cfg(feature = "server")]
+fn read_token_file(path: &std::path::Path) -> anyhow::Result<Vec<String>> {
+    let contents =
+        std::fs::read_to_string(path).with_context(|| format!("couldn't read {path:?}"))?;
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(ToOwned::to_owned)
+        .collect())
+}
+
+/// The `--proxy-token` argument, read from `--proxy-token-file` if need be.
+fn proxy_token(args: &Args) -> anyhow::Result<Option<String>> {
+    if let Some(path) = &args.proxy_token_file {
+        let token =
+            std::fs::read_to_string(path).with_context(|| format!("couldn't read {path:?}"))?;
+        return Ok(Some(token.trim().to_owned()).filter(|token| !token.is_empty()));
+    }
+
+    Ok(args.proxy_token.clone())
+}
+
 // --- io ---
 
 // TODO(cmc): dedicated module for io utils, especially stdio streaming in and out.
@@ -1276,6 +1650,7 @@ fn parse_size(size: &str) -> anyhow::Result<[f32; 2]> {
 fn stream_to_rrd_on_disk(
     rx: &re_smart_channel::ReceiveSet<LogMsg>,
     path: &std::path::PathBuf,
+    encryption_key: Option<re_log_encoding::codec::encryption::EncryptionKey>,
 ) -> Result<(), re_log_encoding::FileSinkError> {
     use re_log_encoding::FileSinkError;
 
@@ -1292,7 +1667,8 @@ fn stream_to_rrd_on_disk(
         re_build_info::CrateVersion::LOCAL,
         encoding_options,
         file,
-    )?;
+    )?
+    .with_encryption_key(encryption_key);
 
     loop {
         if let Ok(msg) = rx.recv() {
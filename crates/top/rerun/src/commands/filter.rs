@@ -0,0 +1,208 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use anyhow::Context as _;
+use itertools::Itertools as _;
+
+use re_chunk::TimelineName;
+use re_entity_db::EntityDb;
+use re_log_types::{AbsoluteTimeRange, EntityPath, LogMsg, StoreId, StoreKind};
+
+use crate::commands::rrd::parse_time_range;
+
+// ---
+
+/// Carves down the recordings of an .rrd file to an entity subtree and/or a time range.
+///
+/// Every recording gets a new recording id, while blueprints are kept as is.
+///
+/// Example: `rerun filter --entity-path-glob /world/camera/** --time-range frame=100..200 in.rrd out.rrd`
+#[derive(Debug, Clone, clap::Parser)]
+pub struct FilterCommand {
+    /// Path to read from.
+    path_to_input_rrd: PathBuf,
+
+    /// Path to write to.
+    path_to_output_rrd: PathBuf,
+
+    /// The entities to keep, either a whole subtree (e.g. `/world/**`) or a single entity (e.g. `/world`).
+    ///
+    /// Keeps all entities if unspecified.
+    #[clap(long = "entity-path-glob", value_parser = parse_entity_path_glob)]
+    entity_path_glob: Option<EntityPathGlob>,
+
+    /// Time range to keep, in the form `timeline=min..max`.
+    ///
+    /// Both bounds are inclusive and in the native unit of the timeline (e.g. nanoseconds for
+    /// timestamps). Either bound can be left out, e.g. `frame=100..`.
+    ///
+    /// Chunks are split as needed. Static data is kept, temporal data not on that timeline is dropped.
+    #[clap(long = "time-range", value_parser = parse_time_range)]
+    time_range: Option<(TimelineName, AbsoluteTimeRange)>,
+}
+
+impl FilterCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            path_to_input_rrd,
+            path_to_output_rrd,
+            entity_path_glob,
+            time_range,
+        } = self;
+
+        let now = std::time::Instant::now();
+        re_log::info!(
+            src = ?path_to_input_rrd,
+            ?entity_path_glob,
+            ?time_range,
+            "filter started"
+        );
+
+        let rrd_in = std::fs::File::open(path_to_input_rrd)
+            .with_context(|| format!("{path_to_input_rrd:?}"))?;
+        let decoder = re_log_encoding::decoder::Decoder::new(std::io::BufReader::new(rrd_in))?
+            .with_decryption_key(super::rrd_decryption_key());
+
+        let mut entity_dbs: BTreeMap<StoreId, EntityDb> = BTreeMap::new();
+        for msg in decoder {
+            let msg = msg.context("decode rrd message")?;
+            entity_dbs
+                .entry(msg.store_id().clone())
+                .or_insert_with(|| EntityDb::new(msg.store_id().clone()))
+                .add(&msg)
+                .context("decode rrd file contents")?;
+        }
+
+        let EntityPathGlob {
+            entity_path,
+            subtree,
+        } = entity_path_glob.clone().unwrap_or_default();
+
+        let mut filtered_dbs = Vec::new();
+        for entity_db in entity_dbs.values() {
+            if entity_db.store_kind() != StoreKind::Recording {
+                continue;
+            }
+
+            let new_id = StoreId::random(StoreKind::Recording, entity_db.application_id().clone());
+            filtered_dbs.push(
+                entity_db
+                    .export_subtree(new_id, &entity_path, *time_range)
+                    .with_context(|| format!("couldn't filter {:?}", entity_db.store_id()))?,
+            );
+        }
+
+        let messages_rbl = entity_dbs
+            .values()
+            .filter(|entity_db| entity_db.store_kind() == StoreKind::Blueprint)
+            .flat_map(|entity_db| entity_db.to_messages(None /* time selection */));
+
+        let messages_rrd = filtered_dbs
+            .iter()
+            .flat_map(|entity_db| entity_db.to_messages(None /* time selection */))
+            .filter(|msg| subtree || is_msg_for_entity(msg, &entity_path));
+
+        let mut rrd_out = std::io::BufWriter::new(
+            std::fs::File::create(path_to_output_rrd)
+                .with_context(|| format!("{path_to_output_rrd:?}"))?,
+        );
+        let rrd_out_size = re_log_encoding::encoder::encode_encrypted(
+            re_build_info::CrateVersion::LOCAL,
+            re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED,
+            super::rrd_encryption_key()?,
+            // NOTE: We want to make sure all blueprints come first, so that the viewer can immediately
+            // set up the viewport correctly.
+            messages_rbl.chain(messages_rrd),
+            &mut rrd_out,
+        )
+        .context("couldn't encode messages")?;
+
+        {
+            use std::io::Write as _;
+            rrd_out.flush().context("couldn't flush output")?;
+        }
+
+        re_log::info!(
+            dst = ?path_to_output_rrd,
+            dst_size_bytes = %re_format::format_bytes(rrd_out_size as _),
+            recordings = ?filtered_dbs.iter().map(|db| db.store_id().recording_id().to_string()).collect_vec(),
+            time = ?now.elapsed(),
+            "filter finished"
+        );
+
+        Ok(())
+    }
+}
+
+/// Either a single entity, or a whole subtree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct EntityPathGlob {
+    entity_path: EntityPath,
+    subtree: bool,
+}
+
+impl Default for EntityPathGlob {
+    fn default() -> Self {
+        Self {
+            entity_path: EntityPath::root(),
+            subtree: true,
+        }
+    }
+}
+
+/// Parses `/path/**` as a subtree, and `/path` as a single entity.
+fn parse_entity_path_glob(s: &str) -> Result<EntityPathGlob, String> {
+    let s = s.trim();
+    let (path, subtree) = if s == "/**" || s == "**" {
+        ("/", true)
+    } else if let Some(path) = s.strip_suffix("/**") {
+        (path, true)
+    } else {
+        (s, false)
+    };
+
+    if path.contains('*') {
+        return Err(format!(
+            "only a trailing `/**` is supported, e.g. `/world/**`, got {s:?}"
+        ));
+    }
+
+    Ok(EntityPathGlob {
+        entity_path: EntityPath::parse_forgiving(path),
+        subtree,
+    })
+}
+
+/// Whether the message is data of exactly this entity, or isn't data at all.
+fn is_msg_for_entity(msg: &re_chunk::ChunkResult<LogMsg>, entity_path: &EntityPath) -> bool {
+    let Ok(LogMsg::ArrowMsg(_, arrow_msg)) = msg else {
+        return true;
+    };
+
+    re_sorbet::ChunkBatch::try_from(&arrow_msg.batch)
+        .map_or(true, |batch| batch.entity_path() == entity_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_entity_path_glob() {
+        assert_eq!(
+            parse_entity_path_glob("/world/**"),
+            Ok(EntityPathGlob {
+                entity_path: EntityPath::from("world"),
+                subtree: true,
+            })
+        );
+        assert_eq!(
+            parse_entity_path_glob("/world/camera"),
+            Ok(EntityPathGlob {
+                entity_path: EntityPath::from("world/camera"),
+                subtree: false,
+            })
+        );
+        assert_eq!(parse_entity_path_glob("/**"), Ok(EntityPathGlob::default()));
+        assert!(parse_entity_path_glob("/world/*/image").is_err());
+    }
+}
@@ -0,0 +1,233 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context as _;
+use itertools::Itertools as _;
+
+use re_chunk::{Chunk, TimelineName};
+use re_entity_db::EntityDb;
+use re_log_types::{AbsoluteTimeRange, EntityPath, FileSource, RecordingId, StoreId, Timeline};
+use re_sdk::{DataLoaderSettings, external::re_data_loader};
+use re_smart_channel::{SmartChannelSource, SmartMessagePayload, SmartMessageSource};
+
+// ---
+
+/// Prints the entities, components, time ranges and chunk statistics of a recording.
+///
+/// Supports any file supported by the data-loaders, e.g. .rrd or .mcap.
+///
+/// Example: `rerun inspect my_recording.rrd`
+#[derive(Debug, Clone, clap::Parser)]
+pub struct InspectCommand {
+    /// Path to read from.
+    path_to_input: PathBuf,
+
+    /// If set, also lists the components of every entity.
+    #[clap(long = "components", default_value_t = false)]
+    components: bool,
+}
+
+impl InspectCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            path_to_input,
+            components,
+        } = self;
+
+        let recordings = load_recordings(path_to_input)?;
+        anyhow::ensure!(
+            !recordings.is_empty(),
+            "no data recording found in {path_to_input:?}"
+        );
+
+        for recording in &recordings {
+            let summary = RecordingSummary::from_entity_db(recording);
+            print!("{}", summary.to_text(*components));
+        }
+
+        Ok(())
+    }
+}
+
+/// What a recording contains, without the data itself.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RecordingSummary {
+    pub store_id: Option<StoreId>,
+    pub timelines: BTreeMap<TimelineName, TimelineSummary>,
+    pub entities: BTreeMap<EntityPath, EntitySummary>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineSummary {
+    pub timeline: Timeline,
+    pub time_range: AbsoluteTimeRange,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EntitySummary {
+    pub num_chunks: u64,
+
+    /// Including static rows.
+    pub num_rows: u64,
+    pub num_static_rows: u64,
+
+    /// The Arrow datatype of each component, by descriptor.
+    pub components: BTreeMap<String, String>,
+}
+
+impl RecordingSummary {
+    pub fn from_entity_db(entity_db: &EntityDb) -> Self {
+        let engine = entity_db.storage_engine();
+        let mut summary = Self::from_chunks(engine.store().iter_chunks().map(|chunk| &**chunk));
+        summary.store_id = Some(entity_db.store_id().clone());
+        summary
+    }
+
+    pub fn from_chunks<'a>(chunks: impl IntoIterator<Item = &'a Chunk>) -> Self {
+        let mut summary = Self::default();
+
+        for chunk in chunks {
+            let entity = summary
+                .entities
+                .entry(chunk.entity_path().clone())
+                .or_default();
+            entity.num_chunks += 1;
+            entity.num_rows += chunk.num_rows() as u64;
+            if chunk.is_static() {
+                entity.num_static_rows += chunk.num_rows() as u64;
+            }
+            for (descriptor, list_array) in chunk.components().iter() {
+                entity
+                    .components
+                    .entry(descriptor.to_string())
+                    .or_insert_with(|| list_array.value_type().to_string());
+            }
+
+            for (name, time_column) in chunk.timelines() {
+                let time_range = time_column.time_range();
+                summary
+                    .timelines
+                    .entry(*name)
+                    .and_modify(|timeline| {
+                        timeline.time_range = timeline.time_range.union(time_range);
+                    })
+                    .or_insert_with(|| TimelineSummary {
+                        timeline: *time_column.timeline(),
+                        time_range,
+                    });
+            }
+        }
+
+        summary
+    }
+
+    pub fn num_chunks(&self) -> u64 {
+        self.entities.values().map(|entity| entity.num_chunks).sum()
+    }
+
+    pub fn num_rows(&self) -> u64 {
+        self.entities.values().map(|entity| entity.num_rows).sum()
+    }
+
+    pub fn to_text(&self, with_components: bool) -> String {
+        use std::fmt::Write as _;
+
+        let mut text = String::new();
+
+        if let Some(store_id) = &self.store_id {
+            writeln!(text, "Recording {}", store_id.recording_id()).ok();
+            writeln!(text, "  application: {}", store_id.application_id()).ok();
+        }
+        writeln!(
+            text,
+            "  chunks: {}, rows: {}",
+            re_format::format_uint(self.num_chunks()),
+            re_format::format_uint(self.num_rows()),
+        )
+        .ok();
+
+        writeln!(text, "Timelines").ok();
+        for (name, timeline) in &self.timelines {
+            writeln!(
+                text,
+                "  {name} ({}): {}",
+                timeline.timeline.typ(),
+                timeline
+                    .timeline
+                    .format_time_range_utc(&timeline.time_range),
+            )
+            .ok();
+        }
+
+        writeln!(text, "Entities").ok();
+        for (entity_path, entity) in &self.entities {
+            writeln!(
+                text,
+                "  {entity_path}: {} chunks, {} rows ({} static), {} components",
+                re_format::format_uint(entity.num_chunks),
+                re_format::format_uint(entity.num_rows),
+                re_format::format_uint(entity.num_static_rows),
+                entity.components.len(),
+            )
+            .ok();
+
+            if with_components {
+                for (descriptor, datatype) in &entity.components {
+                    writeln!(text, "    {descriptor}: {datatype}").ok();
+                }
+            }
+        }
+
+        text
+    }
+}
+
+/// Loads all the recordings in the file at `path` using the data-loaders.
+///
+/// Blueprints are ignored. Recordings are returned ordered by their id.
+pub(crate) fn load_recordings(path: &Path) -> anyhow::Result<Vec<EntityDb>> {
+    let settings = DataLoaderSettings::recommended(RecordingId::random());
+
+    let (tx, rx) = re_smart_channel::smart_channel(
+        SmartMessageSource::File(path.to_owned()),
+        SmartChannelSource::File(path.to_owned()),
+    );
+    re_data_loader::load_from_path(&settings, FileSource::Cli, path, &tx)
+        .with_context(|| format!("{path:?}"))?;
+    drop(tx);
+
+    let mut stores: BTreeMap<StoreId, EntityDb> = BTreeMap::new();
+    while let Ok(msg) = rx.recv() {
+        match msg.payload {
+            SmartMessagePayload::Msg(msg) => {
+                if !msg.store_id().is_recording() {
+                    continue;
+                }
+
+                stores
+                    .entry(msg.store_id().clone())
+                    .or_insert_with(|| {
+                        // No compaction, so that the chunks are the ones of the file.
+                        EntityDb::with_store_config(
+                            msg.store_id().clone(),
+                            re_chunk_store::ChunkStoreConfig::ALL_DISABLED,
+                        )
+                    })
+                    .add(&msg)
+                    .with_context(|| format!("{path:?}"))?;
+            }
+
+            SmartMessagePayload::Flush { on_flush_done } => on_flush_done(),
+
+            SmartMessagePayload::Quit(None) => break,
+
+            SmartMessagePayload::Quit(Some(err)) => {
+                anyhow::bail!("failed to load {path:?}: {err}");
+            }
+        }
+    }
+
+    Ok(stores.into_values().collect_vec())
+}
@@ -3,15 +3,20 @@ use std::{collections::BTreeSet, fs::File, io::BufWriter, sync::mpsc::Receiver};
 use clap::Subcommand;
 use re_log_encoding::encoder::DroppableEncoder;
 use re_log_types::{LogMsg, RecordingId};
-use re_mcap::{LayerIdentifier, SelectedLayers};
+use re_mcap::{LayerIdentifier, MessageDeduplicator, PointCloudFieldMapping, SelectedLayers};
 use re_sdk::{
-    ApplicationId, DataLoader, DataLoaderSettings, LoadedData, external::re_data_loader::McapLoader,
+    ApplicationId, DataLoaderSettings, LoadedData,
+    external::re_data_loader::{self, ImageCompression, McapLoader},
 };
 
 #[derive(Debug, Clone, clap::Parser)]
 pub struct ConvertCommand {
     /// Paths to read from. Reads from standard input if none are specified.
-    path_to_input_mcap: String,
+    ///
+    /// Can also be the directory of a rosbag2 recording that is split into multiple MCAP files.
+    /// Multiple inputs are merged into a single recording.
+    #[arg(required = true)]
+    path_to_input_mcaps: Vec<String>,
 
     /// Path to write to. Writes to standard output if unspecified.
     #[arg(short = 'o', long = "output", value_name = "dst.rrd")]
@@ -32,16 +37,67 @@ pub struct ConvertCommand {
     /// output.
     #[clap(long = "recording-id")]
     recording_id: Option<String>,
+
+    /// If set, re-encodes raw images as `png`, `jpeg` or `jpeg:<quality>` (from 1 to 100).
+    ///
+    /// JPEG is lossy, but shrinks camera-heavy recordings considerably. Images with an alpha channel
+    /// or 16 bit channels are encoded as PNG instead.
+    #[clap(long = "image-compression")]
+    image_compression: Option<ImageCompression>,
+
+    /// Overrides the fields of point clouds that provide a given semantic, as
+    /// `<semantic>=<field>[,<field>…]` in order of preference, e.g. `intensity=reflectivity`.
+    ///
+    /// The semantics are `color`, `intensity`, `timestamp` and `ring`. Can be repeated.
+    #[clap(long = "point-cloud-field", value_name = "SEMANTIC=FIELDS")]
+    point_cloud_fields: Vec<String>,
+
+    /// If set, de-skews point clouds with per-point timestamps using the poses on this topic.
+    ///
+    /// The topic has to be of type `nav_msgs/msg/Odometry` or `geometry_msgs/msg/PoseStamped`, with
+    /// the poses of the lidars themselves. This undoes the smearing of spinning lidars during fast
+    /// motion.
+    #[clap(long = "deskew-topic", value_name = "TOPIC")]
+    deskew_topic: Option<String>,
+
+    /// If set, rectifies raw images with the `camera_info` topic next to them.
+    ///
+    /// The logged pinhole cameras then have the intrinsics of the rectified images and no
+    /// distortion, so that 3D data projects correctly onto the images.
+    #[clap(long = "rectify-images", default_value = "false")]
+    rectify_images: bool,
+
+    /// If set, drops messages that were already loaded from another input, e.g. when merging the
+    /// overlapping recordings of redundant recorders.
+    ///
+    /// Messages are compared by topic and payload, and their log times have to be within a second
+    /// of each other.
+    #[clap(long = "dedup", default_value = "false")]
+    dedup: bool,
+
+    /// The timeline that the viewer plays back by default, e.g. `log_time`, `publish_time`,
+    /// `timestamp` (the header stamps of the messages) or `sim_time` (from the `/clock` topic).
+    ///
+    /// Useful when the clocks of a recording diverge, like in simulations. Recordings with a
+    /// `/clock` topic default to `sim_time`.
+    #[clap(long = "default-timeline", value_name = "TIMELINE")]
+    default_timeline: Option<String>,
 }
 
 impl ConvertCommand {
     fn run(&self) -> anyhow::Result<()> {
         let Self {
-            path_to_input_mcap,
+            path_to_input_mcaps,
             path_to_output_rrd,
             application_id,
             recording_id,
             selected_layers,
+            image_compression,
+            point_cloud_fields,
+            deskew_topic,
+            rectify_images,
+            dedup,
+            default_timeline,
         } = self;
 
         let start_time = std::time::Instant::now();
@@ -49,7 +105,7 @@ impl ConvertCommand {
         let application_id = application_id
             .to_owned()
             .map(ApplicationId::from)
-            .unwrap_or(ApplicationId::from(path_to_input_mcap.clone()));
+            .unwrap_or(ApplicationId::from(path_to_input_mcaps[0].clone()));
 
         let recording_id = recording_id
             .to_owned()
@@ -68,31 +124,50 @@ impl ConvertCommand {
             )
         };
 
-        let loader: &dyn DataLoader = &McapLoader::new(selected_layers);
+        let point_cloud_fields = point_cloud_fields
+            .iter()
+            .try_fold(PointCloudFieldMapping::default(), |mapping, field| {
+                mapping.with_override(field)
+            })
+            .map_err(|err| anyhow::anyhow!("invalid --point-cloud-field: {err}"))?;
+
+        let mut loader = McapLoader::new(selected_layers)
+            .with_point_cloud_fields(point_cloud_fields)
+            .with_image_rectification(*rectify_images);
+        if let Some(deskew_topic) = deskew_topic {
+            loader = loader.with_deskew_topic(deskew_topic);
+        }
+        if *dedup {
+            loader = loader.with_deduplication(MessageDeduplicator::default());
+        }
+        if let Some(default_timeline) = default_timeline {
+            loader = loader.with_default_timeline(default_timeline.as_str());
+        }
 
         // TODO(#10862): This currently loads the entire file into memory.
         let (tx, rx) = std::sync::mpsc::channel::<LoadedData>();
-        loader.load_from_path(
-            &DataLoaderSettings {
-                application_id: Some(application_id),
-                recording_id,
-                opened_store_id: None,
-                force_store_info: false,
-                entity_path_prefix: None,
-                timepoint: None,
-            },
-            path_to_input_mcap.into(),
-            tx,
-        )?;
+        let settings = DataLoaderSettings {
+            application_id: Some(application_id),
+            recording_id,
+            opened_store_id: None,
+            force_store_info: false,
+            entity_path_prefix: None,
+            timepoint: None,
+            image_compression: None,
+            video_encoding: None,
+            rrd: Default::default(),
+        };
+        // All inputs are loaded at once, in order, so that they end up in a single recording.
+        loader.load_from_paths(&settings, path_to_input_mcaps.iter().map(Into::into), tx)?;
 
         if let Some(path) = path_to_output_rrd {
             let writer = BufWriter::new(File::create(path)?);
-            process_mcap(writer, &rx)?;
+            process_mcap(writer, &rx, *image_compression)?;
         } else {
             let stdout = std::io::stdout();
             let lock = stdout.lock();
             let writer = BufWriter::new(lock);
-            process_mcap(writer, &rx)?;
+            process_mcap(writer, &rx, *image_compression)?;
         }
 
         re_log::info!("Processing took {}s", start_time.elapsed().as_secs());
@@ -119,12 +194,14 @@ impl McapCommands {
 fn process_mcap<W: std::io::Write>(
     writer: W,
     receiver: &Receiver<LoadedData>,
+    image_compression: Option<ImageCompression>,
 ) -> anyhow::Result<()> {
     let mut num_total_msgs = 0;
     let mut topics = BTreeSet::new();
     let options = re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED;
     let version = re_build_info::CrateVersion::LOCAL;
-    let mut encoder = DroppableEncoder::new(version, options, writer)?;
+    let mut encoder = DroppableEncoder::new(version, options, writer)?
+        .with_encryption_key(crate::commands::rrd_encryption_key()?);
 
     while let Ok(res) = receiver.recv() {
         num_total_msgs += 1;
@@ -132,7 +209,15 @@ fn process_mcap<W: std::io::Write>(
         let log_msg = match res {
             LoadedData::LogMsg(_, log_msg) => log_msg,
             LoadedData::Chunk(_, store_id, chunk) => {
-                topics.insert(chunk.entity_path().clone());
+                if store_id.is_recording() {
+                    topics.insert(chunk.entity_path().clone());
+                }
+                let chunk = match image_compression {
+                    Some(compression) if store_id.is_recording() => {
+                        re_data_loader::compress_images(&chunk, compression)?.unwrap_or(chunk)
+                    }
+                    _ => chunk,
+                };
                 let arrow_msg = chunk.to_arrow_msg()?;
                 LogMsg::ArrowMsg(store_id, arrow_msg)
             }
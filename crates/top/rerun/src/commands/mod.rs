@@ -24,7 +24,15 @@ impl CallSource {
 #[cfg(feature = "auth")]
 mod auth;
 
+#[cfg(feature = "data_loaders")]
+mod convert;
+mod cpu_affinity;
+#[cfg(feature = "data_loaders")]
+mod diff;
 mod entrypoint;
+mod filter;
+#[cfg(feature = "data_loaders")]
+mod inspect;
 #[cfg(feature = "data_loaders")]
 mod mcap;
 mod rrd;
@@ -34,12 +42,43 @@ mod stdio;
 mod analytics;
 
 pub use self::entrypoint::run;
+#[cfg(feature = "native_viewer")]
+pub use self::entrypoint::run_with_plugins;
+pub use self::filter::FilterCommand;
 #[cfg(feature = "data_loaders")]
 pub use self::mcap::McapCommands;
 pub use self::rrd::RrdCommands;
 pub use self::stdio::{
     read_raw_rrd_streams_from_file_or_stdin, read_rrd_streams_from_file_or_stdin,
 };
+#[cfg(feature = "data_loaders")]
+pub use self::{convert::ConvertCommand, diff::DiffCommand, inspect::InspectCommand};
 
 #[cfg(feature = "analytics")]
 pub(crate) use self::analytics::AnalyticsCommands;
+
+// ---
+
+/// The key to encrypt the payloads of the `.rrd` files we write with, if
+/// [`re_log_encoding::codec::encryption::ENCRYPTION_KEY_ENV_VAR`] is set.
+pub(crate) fn rrd_encryption_key()
+-> anyhow::Result<Option<re_log_encoding::codec::encryption::EncryptionKey>> {
+    use anyhow::Context as _;
+
+    re_log_encoding::codec::encryption::EncryptionKey::from_env().with_context(|| {
+        format!(
+            "invalid {}",
+            re_log_encoding::codec::encryption::ENCRYPTION_KEY_ENV_VAR
+        )
+    })
+}
+
+/// Like [`rrd_encryption_key`], but for the `.rrd` files we read.
+///
+/// A malformed key is only warned about: encrypted payloads then fail to decode on their own.
+pub(crate) fn rrd_decryption_key() -> Option<re_log_encoding::codec::encryption::EncryptionKey> {
+    rrd_encryption_key().unwrap_or_else(|err| {
+        re_log::warn!("{err:#}");
+        None
+    })
+}
@@ -157,7 +157,8 @@ fn load_chunks(
     // in `Decoder` requires `SetStoreInfo` to arrive before the corresponding `ArrowMsg`. Ideally
     // this tool would cache orphan `ArrowMsg` until a matching `SetStoreInfo` arrives.
     let mut stores: std::collections::HashMap<StoreId, EntityDb> = Default::default();
-    let decoder = re_log_encoding::decoder::Decoder::new(rrd_file)?;
+    let decoder = re_log_encoding::decoder::Decoder::new(rrd_file)?
+        .with_decryption_key(crate::commands::rrd_decryption_key());
     for msg in decoder {
         let msg = msg.context("decode rrd message")?;
         stores
@@ -2,13 +2,14 @@ use std::{collections::HashSet, io::IsTerminal as _};
 
 use anyhow::Context as _;
 use arrow::{
-    array::RecordBatch as ArrowRecordBatch,
+    array::{BooleanArray as ArrowBooleanArray, RecordBatch as ArrowRecordBatch},
     datatypes::{Field as ArrowField, Schema as ArrowSchema},
 };
 use itertools::Either;
 
 use re_build_info::CrateVersion;
-use re_chunk::external::crossbeam;
+use re_chunk::{Chunk, ChunkId, TimelineName, external::crossbeam};
+use re_log_types::{AbsoluteTimeRange, ArrowMsg, TimeInt};
 use re_sdk::{EntityPath, external::arrow};
 
 use crate::commands::read_rrd_streams_from_file_or_stdin;
@@ -32,6 +33,15 @@ pub struct FilterCommand {
     #[clap(long = "drop-entity")]
     dropped_entity_paths: Vec<String>,
 
+    /// Time ranges to be filtered out, in the form `timeline=min..max`.
+    ///
+    /// Both bounds are inclusive and in the native unit of the timeline (e.g. nanoseconds for
+    /// timestamps). Either bound can be left out, e.g. `frame=100..`.
+    ///
+    /// Applies to all entities, splitting chunks as needed. Static data is kept.
+    #[clap(long = "drop-time-range", value_parser = parse_time_range)]
+    dropped_time_ranges: Vec<(TimelineName, AbsoluteTimeRange)>,
+
     /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
     #[clap(long = "continue-on-error", default_value_t = false)]
     continue_on_error: bool,
@@ -44,6 +54,7 @@ impl FilterCommand {
             path_to_output_rrd,
             dropped_timelines,
             dropped_entity_paths,
+            dropped_time_ranges,
             continue_on_error,
         } = self;
 
@@ -56,7 +67,12 @@ impl FilterCommand {
         }
 
         let now = std::time::Instant::now();
-        re_log::info!(srcs = ?path_to_input_rrds, ?dropped_timelines, "filter started");
+        re_log::info!(
+            srcs = ?path_to_input_rrds,
+            ?dropped_timelines,
+            ?dropped_time_ranges,
+            "filter started"
+        );
 
         let dropped_timelines: HashSet<_> = dropped_timelines.iter().cloned().collect();
         let dropped_entity_paths: HashSet<EntityPath> = dropped_entity_paths
@@ -88,6 +104,7 @@ impl FilterCommand {
                     let options = re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED;
                     re_log_encoding::encoder::DroppableEncoder::new(version, options, &mut rrd_out)
                         .context("couldn't init encoder")?
+                        .with_encryption_key(crate::commands::rrd_encryption_key()?)
                 };
 
                 let mut size_bytes = 0;
@@ -110,7 +127,7 @@ impl FilterCommand {
                         re_log_types::LogMsg::ArrowMsg(store_id, mut msg) => {
                             match re_sorbet::ChunkBatch::try_from(&msg.batch) {
                                 Ok(batch) => {
-                                    if !dropped_entity_paths.contains(batch.entity_path()) {
+                                    if dropped_entity_paths.contains(batch.entity_path()) {
                                         None
                                     } else {
                                         let (fields, columns): (Vec<_>, Vec<_>) = itertools::izip!(
@@ -148,6 +165,24 @@ impl FilterCommand {
                         msg => Some(msg),
                     };
 
+                    let msg = match msg {
+                        Some(re_log_types::LogMsg::ArrowMsg(store_id, msg))
+                            if !dropped_time_ranges.is_empty() =>
+                        {
+                            match drop_time_ranges(msg, dropped_time_ranges) {
+                                Ok(msg) => {
+                                    msg.map(|msg| re_log_types::LogMsg::ArrowMsg(store_id, msg))
+                                }
+                                Err(err) => {
+                                    re_log::error!(%err, "couldn't drop time ranges from chunk");
+                                    is_success = false;
+                                    None
+                                }
+                            }
+                        }
+                        msg => msg,
+                    };
+
                     if let Some(msg) = msg {
                         tx_encoder.send(msg).ok();
                     }
@@ -211,3 +246,119 @@ fn is_field_timeline_of(field: &ArrowField, dropped_timelines: &HashSet<String>)
         .ok()
         .is_some_and(|schema| dropped_timelines.contains(schema.column_name()))
 }
+
+/// Parses a time range of the form `timeline=min..max`, where either bound may be omitted.
+pub(crate) fn parse_time_range(s: &str) -> Result<(TimelineName, AbsoluteTimeRange), String> {
+    let (timeline, range) = s
+        .rsplit_once('=')
+        .ok_or_else(|| format!("expected `timeline=min..max`, got {s:?}"))?;
+    let (min, max) = range
+        .split_once("..")
+        .ok_or_else(|| format!("expected `min..max`, got {range:?}"))?;
+
+    let parse_bound = |bound: &str, unbounded: TimeInt| {
+        let bound = bound.trim();
+        if bound.is_empty() {
+            Ok(unbounded)
+        } else {
+            bound
+                .parse::<i64>()
+                .map(TimeInt::new_temporal)
+                .map_err(|err| format!("invalid time {bound:?}: {err}"))
+        }
+    };
+
+    Ok((
+        TimelineName::new(timeline.trim()),
+        AbsoluteTimeRange::new(
+            parse_bound(min, TimeInt::MIN)?,
+            parse_bound(max, TimeInt::MAX)?,
+        ),
+    ))
+}
+
+/// Drops all rows of the chunk that fall within any of the given time ranges.
+///
+/// Returns `None` if no rows are left.
+fn drop_time_ranges(
+    msg: ArrowMsg,
+    dropped_time_ranges: &[(TimelineName, AbsoluteTimeRange)],
+) -> anyhow::Result<Option<ArrowMsg>> {
+    let chunk = Chunk::from_arrow_msg(&msg)?;
+
+    let mut kept_rows = vec![true; chunk.num_rows()];
+    for (timeline, time_range) in dropped_time_ranges {
+        // Static chunks and chunks without this timeline are unaffected.
+        if let Some(time_column) = chunk.timelines().get(timeline) {
+            for (kept, &time) in kept_rows.iter_mut().zip(time_column.times_raw()) {
+                *kept &= !time_range.contains(TimeInt::new_temporal(time));
+            }
+        }
+    }
+
+    retain_rows(msg, &chunk, kept_rows)
+}
+
+/// Keeps only the rows of the chunk that fall within all of the given time ranges.
+///
+/// Static chunks and chunks without the timeline of a range are kept as is.
+/// Returns `None` if no rows are left.
+#[cfg(feature = "data_loaders")]
+pub(crate) fn keep_time_ranges(
+    msg: ArrowMsg,
+    kept_time_ranges: &[(TimelineName, AbsoluteTimeRange)],
+) -> anyhow::Result<Option<ArrowMsg>> {
+    let chunk = Chunk::from_arrow_msg(&msg)?;
+
+    let mut kept_rows = vec![true; chunk.num_rows()];
+    for (timeline, time_range) in kept_time_ranges {
+        if let Some(time_column) = chunk.timelines().get(timeline) {
+            for (kept, &time) in kept_rows.iter_mut().zip(time_column.times_raw()) {
+                *kept &= time_range.contains(TimeInt::new_temporal(time));
+            }
+        }
+    }
+
+    retain_rows(msg, &chunk, kept_rows)
+}
+
+fn retain_rows(
+    msg: ArrowMsg,
+    chunk: &Chunk,
+    kept_rows: Vec<bool>,
+) -> anyhow::Result<Option<ArrowMsg>> {
+    if kept_rows.iter().all(|&kept| kept) {
+        Ok(Some(msg))
+    } else if !kept_rows.iter().any(|&kept| kept) {
+        Ok(None)
+    } else {
+        let chunk = chunk
+            .filtered(&ArrowBooleanArray::from(kept_rows))
+            .context("filter doesn't match the chunk")?
+            .with_id(ChunkId::new());
+        Ok(Some(chunk.to_arrow_msg()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_time_range() {
+        assert_eq!(
+            parse_time_range("frame=10..20"),
+            Ok((TimelineName::new("frame"), AbsoluteTimeRange::new(10, 20)))
+        );
+        assert_eq!(
+            parse_time_range("my=timeline=-5.."),
+            Ok((
+                TimelineName::new("my=timeline"),
+                AbsoluteTimeRange::new(TimeInt::new_temporal(-5), TimeInt::MAX)
+            ))
+        );
+        assert!(parse_time_range("frame").is_err());
+        assert!(parse_time_range("frame=10").is_err());
+        assert!(parse_time_range("frame=a..b").is_err());
+    }
+}
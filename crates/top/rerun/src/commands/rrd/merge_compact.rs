@@ -1,11 +1,11 @@
 use std::io::{IsTerminal as _, Write as _};
 
 use anyhow::Context as _;
-use itertools::Either;
+use itertools::{Either, Itertools as _};
 
-use re_chunk_store::{ChunkStore, ChunkStoreConfig, ChunkStoreError};
+use re_chunk_store::{ChunkStore, ChunkStoreConfig, ChunkStoreError, MergeTimeAlignment};
 use re_entity_db::EntityDb;
-use re_log_types::StoreId;
+use re_log_types::{EntityPath, StoreId};
 use re_sdk::StoreKind;
 
 use crate::commands::read_rrd_streams_from_file_or_stdin;
@@ -21,6 +21,32 @@ pub struct MergeCommand {
     #[arg(short = 'o', long = "output", value_name = "dst.(rrd|rbl)")]
     path_to_output_rrd: Option<String>,
 
+    /// If set, all recordings are merged into a single one, each of them shifted forward on every
+    /// timeline so that it starts right after the previous one ends.
+    ///
+    /// Recordings are concatenated in the order they appear in the input.
+    #[clap(long = "concatenate", default_value_t = false)]
+    concatenate: bool,
+
+    /// If set, all recordings are merged into a single one, each of them nested under
+    /// `/<recording_id>`.
+    #[clap(long = "nest", default_value_t = false)]
+    nest: bool,
+
+    /// How to compress the output.
+    #[arg(long = "compression", value_enum, default_value_t = OutputCompression::Lz4)]
+    compression: OutputCompression,
+
+    /// The zstd compression level, from 1 (fastest) to 22 (smallest).
+    ///
+    /// `0` picks the default level (3).
+    #[arg(
+        long = "compression-level",
+        default_value_t = 0,
+        allow_negative_numbers = true
+    )]
+    compression_level: i32,
+
     /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
     #[clap(long = "continue-on-error", default_value_t = false)]
     continue_on_error: bool,
@@ -31,6 +57,10 @@ impl MergeCommand {
         let Self {
             path_to_input_rrds,
             path_to_output_rrd,
+            concatenate,
+            nest,
+            compression,
+            compression_level,
             continue_on_error,
         } = self;
 
@@ -47,11 +77,22 @@ impl MergeCommand {
         // (e.g. by recompacting it differently), so make sure to disable all these features.
         let store_config = ChunkStoreConfig::ALL_DISABLED;
 
+        let single_recording = (*concatenate || *nest).then(|| SingleRecordingMerge {
+            alignment: if *concatenate {
+                MergeTimeAlignment::Concatenate
+            } else {
+                MergeTimeAlignment::Keep
+            },
+            nest: *nest,
+        });
+
         let num_passes = 0;
         merge_and_compact(
             num_passes,
             *continue_on_error,
             &store_config,
+            compression.encoding_options(*compression_level),
+            single_recording,
             path_to_input_rrds,
             path_to_output_rrd.as_ref(),
         )
@@ -107,6 +148,20 @@ pub struct CompactCommand {
     #[arg(long = "num-pass", default_value_t = 50)]
     num_extra_passes: u32,
 
+    /// How to compress the output.
+    #[arg(long = "compression", value_enum, default_value_t = OutputCompression::Lz4)]
+    compression: OutputCompression,
+
+    /// The zstd compression level, from 1 (fastest) to 22 (smallest).
+    ///
+    /// `0` picks the default level (3).
+    #[arg(
+        long = "compression-level",
+        default_value_t = 0,
+        allow_negative_numbers = true
+    )]
+    compression_level: i32,
+
     /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
     #[clap(long = "continue-on-error", default_value_t = false)]
     continue_on_error: bool,
@@ -121,6 +176,8 @@ impl CompactCommand {
             max_rows,
             max_rows_if_unsorted,
             num_extra_passes,
+            compression,
+            compression_level,
             continue_on_error,
         } = self;
 
@@ -150,16 +207,58 @@ impl CompactCommand {
             *num_extra_passes,
             *continue_on_error,
             &store_config,
+            compression.encoding_options(*compression_level),
+            None, /* single_recording */
             path_to_input_rrds,
             path_to_output_rrd.as_ref(),
         )
     }
 }
 
+/// How to compress the output of [`MergeCommand`] and [`CompactCommand`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum OutputCompression {
+    /// No compression.
+    Off,
+
+    /// Very fast, but produces the largest files.
+    Lz4,
+
+    /// Slower, but produces much smaller files.
+    Zstd,
+}
+
+impl OutputCompression {
+    fn encoding_options(self, compression_level: i32) -> re_log_encoding::EncodingOptions {
+        let compression = match self {
+            Self::Off => re_log_encoding::Compression::Off,
+            Self::Lz4 => re_log_encoding::Compression::LZ4,
+            Self::Zstd => re_log_encoding::Compression::Zstd,
+        };
+
+        re_log_encoding::EncodingOptions {
+            compression,
+            compression_level,
+            ..re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED
+        }
+    }
+}
+
+/// How to merge all recordings into a single one, see [`MergeCommand`].
+#[derive(Debug, Clone, Copy)]
+struct SingleRecordingMerge {
+    alignment: MergeTimeAlignment,
+
+    /// Whether each recording gets nested under `/<recording_id>`.
+    nest: bool,
+}
+
 fn merge_and_compact(
     num_passes: u32,
     continue_on_error: bool,
     store_config: &ChunkStoreConfig,
+    encoding_options: re_log_encoding::EncodingOptions,
+    single_recording: Option<SingleRecordingMerge>,
     path_to_input_rrds: &[String],
     path_to_output_rrd: Option<&String>,
 ) -> anyhow::Result<()> {
@@ -182,6 +281,7 @@ fn merge_and_compact(
     let (rx, rx_size_bytes) = read_rrd_streams_from_file_or_stdin(path_to_input_rrds);
 
     let mut entity_dbs: std::collections::HashMap<StoreId, EntityDb> = Default::default();
+    let mut store_ids_in_order: Vec<StoreId> = Vec::new();
 
     re_log::info!("processing input…");
     let mut num_chunks_before = 0u64;
@@ -195,6 +295,7 @@ fn merge_and_compact(
                 if let Err(err) = entity_dbs
                     .entry(msg.store_id().clone())
                     .or_insert_with(|| {
+                        store_ids_in_order.push(msg.store_id().clone());
                         re_entity_db::EntityDb::with_store_config(
                             msg.store_id().clone(),
                             store_config.clone(),
@@ -231,6 +332,10 @@ fn merge_and_compact(
         }
     }
 
+    if let Some(single_recording) = single_recording {
+        merge_into_single_recording(&mut entity_dbs, &store_ids_in_order, single_recording)?;
+    }
+
     for pass in 0..num_passes {
         re_log::info!(pass, "running extra compaction pass…");
 
@@ -299,8 +404,6 @@ fn merge_and_compact(
             num_chunks_after += matches!(msg, Ok(re_log_types::LogMsg::ArrowMsg(_, _))) as u64;
         });
 
-    // TODO(cmc): encoding options should match the original.
-    let encoding_options = re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED;
     let version = entity_dbs
         .values()
         .next()
@@ -309,9 +412,10 @@ fn merge_and_compact(
         .unwrap_or(re_build_info::CrateVersion::LOCAL);
 
     re_log::info!("encoding…");
-    let rrd_out_size = re_log_encoding::encoder::encode(
+    let rrd_out_size = re_log_encoding::encoder::encode_encrypted(
         version,
         encoding_options,
+        crate::commands::rrd_encryption_key()?,
         // NOTE: We want to make sure all blueprints come first, so that the viewer can immediately
         // set up the viewport correctly.
         messages_rbl.chain(messages_rrd),
@@ -349,3 +453,70 @@ fn merge_and_compact(
 
     Ok(())
 }
+
+/// Replaces all the recordings in `entity_dbs` with a single one, which keeps the id of the first
+/// recording in `store_ids_in_order`.
+///
+/// Blueprints are left untouched.
+fn merge_into_single_recording(
+    entity_dbs: &mut std::collections::HashMap<StoreId, EntityDb>,
+    store_ids_in_order: &[StoreId],
+    single_recording: SingleRecordingMerge,
+) -> anyhow::Result<()> {
+    let SingleRecordingMerge { alignment, nest } = single_recording;
+
+    let recording_ids = store_ids_in_order
+        .iter()
+        .filter(|store_id| store_id.is_recording() && entity_dbs.contains_key(store_id))
+        .cloned()
+        .collect_vec();
+    let Some(merged_id) = recording_ids.first().cloned() else {
+        return Ok(());
+    };
+
+    let now = std::time::Instant::now();
+    re_log::info!(
+        num_recordings = recording_ids.len(),
+        ?alignment,
+        nest,
+        "merging recordings into {merged_id:?}…"
+    );
+
+    let merged_store = {
+        let engines = recording_ids
+            .iter()
+            .filter_map(|store_id| Some((store_id, entity_dbs.get(store_id)?.storage_engine())))
+            .collect_vec();
+
+        let recordings = engines.iter().map(|(store_id, engine)| {
+            let prefix = if nest {
+                EntityPath::from_single_string(store_id.recording_id().as_str())
+            } else {
+                EntityPath::root()
+            };
+            (prefix, engine.store())
+        });
+
+        let store_config = engines
+            .first()
+            .map(|(_store_id, engine)| engine.store().config().clone())
+            .unwrap_or_default();
+
+        ChunkStore::merge_recordings(merged_id.clone(), store_config, recordings, alignment)?
+    };
+
+    for store_id in recording_ids.iter().skip(1) {
+        entity_dbs.remove(store_id);
+    }
+
+    if let Some(db) = entity_dbs.get(&merged_id) {
+        // Safety: we are the only owners of that data, it's fine.
+        #[expect(unsafe_code)]
+        let engine = unsafe { db.storage_engine_raw() };
+        *engine.write().store() = merged_store;
+    }
+
+    re_log::info!(time = ?now.elapsed(), "recordings merged");
+
+    Ok(())
+}
@@ -103,7 +103,8 @@ fn migrate_from_to(from_path: &Utf8PathBuf, to_path: &Utf8PathBuf) -> anyhow::Re
     let from_file =
         std::fs::File::open(from_path).with_context(|| format!("Failed to open {from_path:?}"))?;
 
-    let decoder = re_log_encoding::decoder::Decoder::new(std::io::BufReader::new(from_file))?;
+    let decoder = re_log_encoding::decoder::Decoder::new(std::io::BufReader::new(from_file))?
+        .with_decryption_key(crate::commands::rrd_decryption_key());
 
     let mut errors = indexmap::IndexSet::new();
 
@@ -151,9 +152,10 @@ fn migrate_from_to(from_path: &Utf8PathBuf, to_path: &Utf8PathBuf) -> anyhow::Re
 
     let mut buffered_writer = std::io::BufWriter::new(new_file);
 
-    re_log_encoding::encoder::encode(
+    re_log_encoding::encoder::encode_encrypted(
         CrateVersion::LOCAL,
         EncodingOptions::PROTOBUF_COMPRESSED,
+        crate::commands::rrd_encryption_key()?,
         messages,
         &mut buffered_writer,
     )
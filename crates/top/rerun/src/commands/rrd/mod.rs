@@ -5,6 +5,7 @@ mod migrate;
 mod print;
 mod route;
 mod stats;
+mod validate;
 mod verify;
 
 use self::{
@@ -15,9 +16,14 @@ use self::{
     print::PrintCommand,
     route::RouteCommand,
     stats::StatsCommand,
+    validate::ValidateCommand,
     verify::VerifyCommand,
 };
 
+#[cfg(feature = "data_loaders")]
+pub(crate) use self::filter::keep_time_ranges;
+pub(crate) use self::filter::parse_time_range;
+
 // ---
 
 use anyhow::Context as _;
@@ -56,7 +62,8 @@ pub enum RrdCommands {
     ///
     /// Reads from standard input if no paths are specified.
     ///
-    /// This will not affect the chunking of the data in any way.
+    /// This will not affect the chunking of the data in any way, except for chunks that get split
+    /// by `--drop-time-range`.
     ///
     /// Example: `rerun rrd filter --drop-timeline log_tick /my/recordings/*.rrd > output.rrd`
     Filter(FilterCommand),
@@ -67,7 +74,13 @@ pub enum RrdCommands {
     ///
     /// ⚠️ This will automatically migrate the data to the latest version of the RRD protocol, if needed. ⚠️
     ///
-    /// Example: `rerun rrd merge /my/recordings/*.rrd > output.rrd`
+    /// Examples:
+    ///
+    /// * `rerun rrd merge /my/recordings/*.rrd > output.rrd`
+    ///
+    /// * `rerun rrd merge --concatenate session1.rrd session2.rrd > output.rrd`
+    ///
+    /// * `rerun rrd merge --nest robot1.rrd robot2.rrd > output.rrd`
     Merge(MergeCommand),
 
     /// Migrate one or more .rrd files to the newest Rerun version.
@@ -98,6 +111,16 @@ pub enum RrdCommands {
     /// Example: `rerun rrd stats /my/recordings/*.rrd`
     Stats(StatsCommand),
 
+    /// Validates recordings against an expected schema: entities, archetypes, components, and rates.
+    ///
+    /// Reads from standard input if no paths are specified.
+    ///
+    /// Prints a JSON report for each recording to standard output, and exits with a non-zero code
+    /// if any of them doesn't match the schema, so that it can be used in CI.
+    ///
+    /// Example: `rerun rrd validate --schema robot.json /my/recordings/*.rrd`
+    Validate(ValidateCommand),
+
     /// Verify the that the .rrd file can be loaded and correctly interpreted.
     ///
     /// Can be used to ensure that the current Rerun version can load the data.
@@ -119,6 +142,7 @@ impl RrdCommands {
             Self::Print(cmd) => cmd.run(),
             Self::Route(cmd) => cmd.run(),
             Self::Stats(cmd) => cmd.run(),
+            Self::Validate(cmd) => cmd.run(),
             Self::Verify(cmd) => cmd.run(),
         }
     }
@@ -108,7 +108,8 @@ fn process_messages<W: std::io::Write>(
     // TODO(grtlr): encoding should match the original (just like in `rrd stats`).
     let options = re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED;
     let version = re_build_info::CrateVersion::LOCAL;
-    let mut encoder = DroppableEncoder::new(version, options, writer)?;
+    let mut encoder = DroppableEncoder::new(version, options, writer)?
+        .with_encryption_key(crate::commands::rrd_encryption_key()?);
 
     while let Ok((_input, res)) = receiver.recv() {
         let mut is_success = true;
@@ -117,6 +118,18 @@ fn process_messages<W: std::io::Write>(
             Ok(mut msg) => {
                 num_total_msgs += 1;
 
+                // The store id is authenticated along with encrypted payloads: it cannot be
+                // rewritten unless we could decrypt them, and thus re-encrypt them.
+                if let Msg::ArrowMsg(arrow_msg) = &msg {
+                    anyhow::ensure!(
+                        arrow_msg.encryption.is_none()
+                            || (rewrites.recording_id.is_none()
+                                && rewrites.application_id.is_none()),
+                        "cannot rewrite the store id of encrypted chunks, set {} to decrypt them",
+                        re_log_encoding::codec::encryption::ENCRYPTION_KEY_ENV_VAR,
+                    );
+                }
+
                 #[allow(deprecated)]
                 match &mut msg {
                     // This needs to come first, as an
@@ -147,6 +160,8 @@ fn process_messages<W: std::io::Write>(
                         uncompressed_size: _,
                         encoding: _,
                         payload: _,
+                        payload_crc32: _,
+                        encryption: _,
                     }) => {
                         if let Some(target_store_id) = store_id {
                             if let Some(recording_id) = &rewrites.recording_id {
@@ -57,27 +57,23 @@ impl StatsCommand {
                         continue;
                     };
 
-                    let mut uncompressed = Vec::new();
-
-                    const COMPRESSION_NONE: i32 =
-                        re_protos::log_msg::v1alpha1::Compression::None as _;
-                    const COMPRESSION_LZ4: i32 =
-                        re_protos::log_msg::v1alpha1::Compression::Lz4 as _;
-
-                    match msg.compression {
-                        COMPRESSION_NONE => {}
-
-                        COMPRESSION_LZ4 => {
-                            uncompressed.resize(msg.uncompressed_size as _, 0);
-                            re_log_encoding::external::lz4_flex::block::decompress_into(
-                                &msg.payload,
-                                &mut uncompressed,
-                            )?;
-                            msg.payload = uncompressed.into();
-                            msg.compression = COMPRESSION_NONE;
-                        }
+                    let Ok(compression) =
+                        re_protos::log_msg::v1alpha1::Compression::try_from(msg.compression)
+                    else {
+                        anyhow::bail!("unknown Compression: {}", msg.compression);
+                    };
 
-                        huh => anyhow::bail!("unknown Compression: {huh}"),
+                    let compression = re_log_encoding::Compression::from(compression);
+                    if compression != re_log_encoding::Compression::Off {
+                        let mut uncompressed = Vec::new();
+                        re_log_encoding::codec::compression::decompress(
+                            &msg.payload,
+                            msg.uncompressed_size as _,
+                            compression,
+                            &mut uncompressed,
+                        )?;
+                        msg.payload = uncompressed.into();
+                        msg.compression = re_protos::log_msg::v1alpha1::Compression::None as _;
                     }
 
                     tx_uncompressed.send(Ok(
@@ -356,8 +352,15 @@ fn compute_stats(app: bool, msg: &Msg) -> anyhow::Result<Option<ChunkStats>> {
             uncompressed_size,
             encoding: _,
             payload,
+            payload_crc32: _,
+            encryption,
         } = arrow_msg;
 
+        anyhow::ensure!(
+            encryption.is_none(),
+            "Cannot compute stats of encrypted payloads"
+        );
+
         let ipc_schema_size_bytes = {
             // NOTE: This is based on the implementation of `arrow::ipc::convert::try_schema_from_ipc_buffer`.
 
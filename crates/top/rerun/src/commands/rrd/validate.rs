@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use anyhow::Context as _;
+
+use re_entity_db::{EntityDb, RecordingSchema, ValidationReport};
+use re_log_types::{StoreId, StoreKind};
+
+use crate::commands::read_rrd_streams_from_file_or_stdin;
+
+// ---
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ValidateCommand {
+    /// Path to the expected schema, as JSON.
+    ///
+    /// Example: `{"entities": [{"entity_path": "/robot/lidar", "archetypes": ["Points3D"], "rate": {"timeline": "log_time", "min_hz": 9.0}}]}`
+    #[clap(long)]
+    schema: PathBuf,
+
+    /// Paths to read from. Reads from standard input if none are specified.
+    path_to_input_rrds: Vec<String>,
+}
+
+/// The report of a single recording, as printed to standard output.
+#[derive(serde::Serialize)]
+struct RecordingReport {
+    application_id: String,
+    recording_id: String,
+    is_valid: bool,
+
+    #[serde(flatten)]
+    report: ValidationReport,
+}
+
+impl ValidateCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            schema,
+            path_to_input_rrds,
+        } = self;
+
+        let schema: RecordingSchema = serde_json::from_reader(std::io::BufReader::new(
+            std::fs::File::open(schema).with_context(|| format!("{schema:?}"))?,
+        ))
+        .with_context(|| format!("invalid schema {schema:?}"))?;
+
+        let (rx, _) = read_rrd_streams_from_file_or_stdin(path_to_input_rrds);
+
+        let mut recordings: HashMap<StoreId, EntityDb> = HashMap::default();
+        for (_source, msg) in rx {
+            let msg = msg?;
+            if msg.store_id().kind() != StoreKind::Recording {
+                continue;
+            }
+
+            recordings
+                .entry(msg.store_id().clone())
+                .or_insert_with(|| EntityDb::new(msg.store_id().clone()))
+                .add(&msg)?;
+        }
+
+        anyhow::ensure!(!recordings.is_empty(), "no recording found");
+
+        let mut reports: Vec<RecordingReport> = recordings
+            .values()
+            .map(|recording| {
+                let report = recording.validate(&schema);
+                RecordingReport {
+                    application_id: recording.application_id().to_string(),
+                    recording_id: recording.store_id().recording_id().to_string(),
+                    is_valid: report.is_valid(),
+                    report,
+                }
+            })
+            .collect();
+        reports.sort_by(|a, b| a.recording_id.cmp(&b.recording_id));
+
+        println!("{}", serde_json::to_string_pretty(&reports)?);
+
+        let num_invalid = reports.iter().filter(|report| !report.is_valid).count();
+        for issue in reports.iter().flat_map(|report| &report.report.issues) {
+            eprintln!("{issue}");
+        }
+        anyhow::ensure!(
+            num_invalid == 0,
+            "{num_invalid} of {} recordings don't match the schema",
+            reports.len()
+        );
+
+        Ok(())
+    }
+}
@@ -57,6 +57,7 @@ pub fn read_rrd_streams_from_file_or_stdin(
         .name("rerun-rrd-in".to_owned())
         .spawn(move || {
             let mut size_bytes = 0;
+            let decryption_key = super::rrd_decryption_key();
 
             if path_to_input_rrds.is_empty() {
                 // stdin
@@ -66,7 +67,7 @@ pub fn read_rrd_streams_from_file_or_stdin(
                 let mut decoder = match re_log_encoding::decoder::Decoder::new_concatenated(stdin)
                     .context("couldn't decode stdin stream -- skipping")
                 {
-                    Ok(decoder) => decoder,
+                    Ok(decoder) => decoder.with_decryption_key(decryption_key),
                     Err(err) => {
                         tx.send((InputSource::Stdin, Err(err))).ok();
                         return;
@@ -97,7 +98,7 @@ pub fn read_rrd_streams_from_file_or_stdin(
                     let mut decoder = match re_log_encoding::decoder::Decoder::new(rrd_file)
                         .with_context(|| format!("couldn't decode {rrd_path:?} -- skipping"))
                     {
-                        Ok(decoder) => decoder,
+                        Ok(decoder) => decoder.with_decryption_key(decryption_key.clone()),
                         Err(err) => {
                             tx.send((InputSource::File(rrd_path.clone()), Err(err)))
                                 .ok();
@@ -167,6 +168,7 @@ pub fn read_raw_rrd_streams_from_file_or_stdin(
         .name("rerun-rrd-in".to_owned())
         .spawn(move || {
             let mut size_bytes = 0;
+            let decryption_key = super::rrd_decryption_key();
 
             if path_to_input_rrds.is_empty() {
                 // stdin
@@ -176,7 +178,7 @@ pub fn read_raw_rrd_streams_from_file_or_stdin(
                 let mut decoder = match re_log_encoding::decoder::Decoder::new_concatenated(stdin)
                     .context("couldn't decode stdin stream -- skipping")
                 {
-                    Ok(decoder) => decoder.into_raw_iter(),
+                    Ok(decoder) => decoder.with_decryption_key(decryption_key).into_raw_iter(),
                     Err(err) => {
                         tx.send((InputSource::Stdin, Err(err))).ok();
                         return;
@@ -207,7 +209,9 @@ pub fn read_raw_rrd_streams_from_file_or_stdin(
                     let mut decoder = match re_log_encoding::decoder::Decoder::new(rrd_file)
                         .with_context(|| format!("couldn't decode {rrd_path:?} -- skipping"))
                     {
-                        Ok(decoder) => decoder.into_raw_iter(),
+                        Ok(decoder) => decoder
+                            .with_decryption_key(decryption_key.clone())
+                            .into_raw_iter(),
                         Err(err) => {
                             tx.send((InputSource::File(rrd_path.clone()), Err(err)))
                                 .ok();
@@ -99,7 +99,8 @@
 //!
 //!
 //! ## Forwarding text log events to Rerun
-//! See [`Logger`].
+//! See [`Logger`] for the `log` crate, and `TracingLayer` for the `tracing` crate (requires the
+//! `tracing` feature).
 //!
 
 #![warn(missing_docs)] // Let's keep the this crate well-documented!
@@ -129,9 +130,21 @@ pub use re_log::default_log_filter;
 #[cfg(feature = "log")]
 pub use log_integration::Logger;
 
+#[cfg(feature = "tracing")]
+pub mod tracing_integration;
+
+#[cfg(feature = "tracing")]
+pub use tracing_integration::TracingLayer;
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod text_log_fields;
+
 #[cfg(feature = "run")]
 pub use commands::{CallSource, run};
 
+#[cfg(all(feature = "run", feature = "native_viewer"))]
+pub use commands::run_with_plugins;
+
 #[cfg(feature = "sdk")]
 pub use sdk::*;
 
@@ -1,14 +1,18 @@
 //! Integrates the Rerun SDK with the [`log`] crate.
 
 use log::Log as _;
-use re_types::{archetypes::TextLog, components::TextLogLevel};
+use re_types::{AsComponents, archetypes::TextLog, components::TextLogLevel};
 
 use crate::RecordingStream;
+use crate::text_log_fields::{FieldValue, TextLogFields};
 
 // ---
 
 /// Implements a [`log::Log`] that forwards all events to the Rerun SDK.
 ///
+/// The structured key-values of the records, if any, are logged as extra components alongside
+/// the [`TextLog`].
+///
 /// ```
 /// let rec = rerun::RecordingStreamBuilder::new("rerun_example_app").buffered()?;
 ///
@@ -109,10 +113,18 @@ impl log::Log for Logger {
         let level = log_level_to_rerun_level(record.metadata().level());
 
         let body = format!("{}", record.args());
+        let text_log = TextLog::new(body).with_level(level);
+
+        let mut fields = FieldVisitor::default();
+        record.key_values().visit(&mut fields).ok(); // the visitor never fails
 
-        self.rec
-            .log(ent_path, &TextLog::new(body).with_level(level))
-            .ok(); // ignore error
+        if let Some(fields) = fields.0.to_any_values() {
+            self.rec
+                .log(ent_path, &[&text_log as &dyn AsComponents, &fields])
+                .ok(); // ignore error
+        } else {
+            self.rec.log(ent_path, &text_log).ok(); // ignore error
+        }
     }
 
     #[inline]
@@ -123,6 +135,32 @@ impl log::Log for Logger {
 
 // ---
 
+#[derive(Default)]
+struct FieldVisitor(TextLogFields);
+
+impl<'kvs> log::kv::VisitSource<'kvs> for FieldVisitor {
+    fn visit_pair(
+        &mut self,
+        key: log::kv::Key<'kvs>,
+        value: log::kv::Value<'kvs>,
+    ) -> Result<(), log::kv::Error> {
+        let value = if let Some(value) = value.to_bool() {
+            FieldValue::Bool(value)
+        } else if let Some(value) = value.to_i64() {
+            FieldValue::I64(value)
+        } else if let Some(value) = value.to_u64() {
+            FieldValue::U64(value)
+        } else if let Some(value) = value.to_f64() {
+            FieldValue::F64(value)
+        } else {
+            FieldValue::String(value.to_string())
+        };
+
+        self.0.insert(key.as_str(), value);
+        Ok(())
+    }
+}
+
 fn log_level_to_rerun_level(lvl: log::Level) -> TextLogLevel {
     match lvl {
         log::Level::Error => TextLogLevel::ERROR,
@@ -0,0 +1,61 @@
+//! Structured fields of text log events, shared by the [`log`] and [`tracing`] integrations.
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, BooleanArray, Float64Array, Int64Array, StringArray, UInt64Array};
+use re_types::AnyValues;
+
+/// The value of a single structured field.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum FieldValue {
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    String(String),
+}
+
+impl FieldValue {
+    fn to_arrow(&self) -> ArrayRef {
+        match self {
+            Self::Bool(value) => Arc::new(BooleanArray::from(vec![*value])),
+            Self::I64(value) => Arc::new(Int64Array::from(vec![*value])),
+            Self::U64(value) => Arc::new(UInt64Array::from(vec![*value])),
+            Self::F64(value) => Arc::new(Float64Array::from(vec![*value])),
+            Self::String(value) => Arc::new(StringArray::from(vec![value.as_str()])),
+        }
+    }
+}
+
+/// The structured fields of an event, in the order they were recorded.
+///
+/// Recording a field twice overwrites its previous value.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TextLogFields(Vec<(String, FieldValue)>);
+
+impl TextLogFields {
+    pub fn insert(&mut self, name: impl Into<String>, value: FieldValue) {
+        let name = name.into();
+        if let Some((_, existing)) = self.0.iter_mut().find(|(field, _)| *field == name) {
+            *existing = value;
+        } else {
+            self.0.push((name, value));
+        }
+    }
+
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &FieldValue)> {
+        self.0.iter().map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Returns the fields as components to be logged alongside the `TextLog`, if there are any.
+    pub fn to_any_values(&self) -> Option<AnyValues> {
+        (!self.0.is_empty()).then(|| {
+            self.0
+                .iter()
+                .fold(AnyValues::default(), |values, (name, value)| {
+                    values.with_field(name, value.to_arrow())
+                })
+        })
+    }
+}
@@ -0,0 +1,257 @@
+//! Integrates the Rerun SDK with the [`tracing`] crate.
+
+use std::fmt;
+
+use re_types::{AsComponents, archetypes::TextLog, components::TextLogLevel};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::RecordingStream;
+use crate::text_log_fields::{FieldValue, TextLogFields};
+
+// ---
+
+/// Implements a [`tracing_subscriber::Layer`] that forwards all events to the Rerun SDK.
+///
+/// Every event is logged as a [`TextLog`] under an entity path derived from its target, just
+/// like [`crate::Logger`] does for the [`log`](https://docs.rs/log) crate.
+/// The fields of the event, and those of all the spans it happened in, are logged as extra
+/// components alongside it. Span fields are named `<span>.<field>`, and the `span` component
+/// holds the names of the spans, from the root, e.g. `request:db_query`.
+///
+/// Filtering is left to the usual `tracing_subscriber` machinery:
+/// ```
+/// use tracing_subscriber::layer::SubscriberExt as _;
+///
+/// let rec = rerun::RecordingStreamBuilder::new("rerun_example_app").buffered()?;
+///
+/// let subscriber = tracing_subscriber::registry().with(
+///     rerun::TracingLayer::new(rec.clone()) // recording streams are ref-counted
+///         .with_path_prefix("logs"),
+/// );
+///
+/// tracing::subscriber::with_default(subscriber, || {
+///     tracing::info!(user_id = 42, "This INFO event got added through tracing");
+/// });
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct TracingLayer {
+    rec: RecordingStream,
+    path_prefix: Option<String>,
+}
+
+impl TracingLayer {
+    /// Returns a new [`TracingLayer`] that forwards all events to the specified [`RecordingStream`].
+    pub fn new(rec: RecordingStream) -> Self {
+        Self {
+            rec,
+            path_prefix: None,
+        }
+    }
+
+    /// Configures the [`TracingLayer`] to prefix the specified `path_prefix` to all events.
+    #[inline]
+    pub fn with_path_prefix(mut self, path_prefix: impl Into<String>) -> Self {
+        self.path_prefix = Some(path_prefix.into());
+        self
+    }
+}
+
+/// The fields of a span, stored in its extensions.
+struct SpanFields(TextLogFields);
+
+impl<S> tracing_subscriber::Layer<S> for TracingLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let mut fields = FieldVisitor::default();
+        attrs.record(&mut fields);
+        span.extensions_mut().insert(SpanFields(fields.fields));
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let mut extensions = span.extensions_mut();
+        if let Some(SpanFields(fields)) = extensions.get_mut::<SpanFields>() {
+            let mut visitor = FieldVisitor {
+                message: None,
+                fields: std::mem::take(fields),
+            };
+            values.record(&mut visitor);
+            *fields = visitor.fields;
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+
+        let target = metadata.target().replace("::", "/");
+        let ent_path = if let Some(path_prefix) = self.path_prefix.as_ref() {
+            format!("{path_prefix}/{target}")
+        } else {
+            target
+        };
+
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+
+        let FieldVisitor {
+            message,
+            fields: event_fields,
+        } = visitor;
+
+        let mut fields = TextLogFields::default();
+
+        if let Some(scope) = ctx.event_scope(event) {
+            let mut span_names = Vec::new();
+            for span in scope.from_root() {
+                span_names.push(span.name());
+                if let Some(SpanFields(span_fields)) = span.extensions().get::<SpanFields>() {
+                    for (name, value) in span_fields.iter() {
+                        fields.insert(format!("{}.{name}", span.name()), value.clone());
+                    }
+                }
+            }
+
+            if !span_names.is_empty() {
+                fields.insert("span", FieldValue::String(span_names.join(":")));
+            }
+        }
+
+        for (name, value) in event_fields.iter() {
+            fields.insert(name, value.clone());
+        }
+
+        let level = tracing_level_to_rerun_level(*metadata.level());
+        let text_log = TextLog::new(message.unwrap_or_default()).with_level(level);
+
+        if let Some(fields) = fields.to_any_values() {
+            self.rec
+                .log(ent_path, &[&text_log as &dyn AsComponents, &fields])
+                .ok(); // ignore error
+        } else {
+            self.rec.log(ent_path, &text_log).ok(); // ignore error
+        }
+    }
+}
+
+// ---
+
+#[derive(Default)]
+struct FieldVisitor {
+    /// The special `message` field, which holds the formatted text of an event.
+    message: Option<String>,
+    fields: TextLogFields,
+}
+
+impl Visit for FieldVisitor {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.fields.insert(field.name(), FieldValue::F64(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.fields.insert(field.name(), FieldValue::I64(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.fields.insert(field.name(), FieldValue::U64(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.fields.insert(field.name(), FieldValue::Bool(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = Some(value.to_owned());
+        } else {
+            self.fields
+                .insert(field.name(), FieldValue::String(value.to_owned()));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = Some(format!("{value:?}"));
+        } else {
+            self.fields
+                .insert(field.name(), FieldValue::String(format!("{value:?}")));
+        }
+    }
+}
+
+fn tracing_level_to_rerun_level(lvl: tracing::Level) -> TextLogLevel {
+    // NOTE: `tracing::Level` is not an enum, hence the catch-all for `TRACE`.
+    match lvl {
+        tracing::Level::ERROR => TextLogLevel::ERROR,
+        tracing::Level::WARN => TextLogLevel::WARN,
+        tracing::Level::INFO => TextLogLevel::INFO,
+        tracing::Level::DEBUG => TextLogLevel::DEBUG,
+        _ => TextLogLevel::TRACE,
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use re_chunk::{Chunk, ChunkBatcherConfig};
+    use re_log_types::{EntityPath, LogMsg};
+    use tracing_subscriber::layer::SubscriberExt as _;
+
+    use super::*;
+
+    #[test]
+    fn forwards_event_and_span_fields() {
+        let (rec, storage) = crate::RecordingStreamBuilder::new("rerun_example_tracing_layer")
+            .enabled(true)
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .memory()
+            .unwrap();
+
+        let subscriber = tracing_subscriber::registry()
+            .with(TracingLayer::new(rec.clone()).with_path_prefix("logs"));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!(target: "my_app::db", "query", table = "users");
+            let _guard = span.enter();
+            tracing::warn!(target: "my_app::db", rows = 3_u64, "slow query");
+        });
+
+        let chunks = storage
+            .take()
+            .into_iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, msg) => Some(Chunk::from_arrow_msg(&msg).unwrap()),
+                _ => None,
+            })
+            .filter(|chunk| chunk.entity_path() == &EntityPath::from("logs/my_app/db"))
+            .collect::<Vec<_>>();
+        assert_eq!(1, chunks.len());
+
+        let components = chunks[0]
+            .components()
+            .keys()
+            .map(|descr| descr.component.as_str().to_owned())
+            .collect::<Vec<_>>();
+        for component in ["rows", "query.table", "span"] {
+            assert!(
+                components.iter().any(|c| c == component),
+                "missing {component} in {components:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use std::ffi::{c_char, c_void};
+
+use arrow::{
+    array::{Array as _, StructArray},
+    ffi::{FFI_ArrowArray, FFI_ArrowSchema},
+};
+
+use re_sdk::{
+    DataLoaderSettings, EntityPath,
+    external::re_log_types::{FileSource, LogMsg, RecordingId},
+    log::Chunk,
+};
+
+use crate::{CBytesView, CError, CErrorCode, CStringView};
+
+/// This is called `rr_data_loader_chunk_callback` in the C API.
+pub type CDataLoaderChunkCallback = extern "C" fn(
+    user_data: *mut c_void,
+    entity_path: CStringView,
+    chunk: *mut FFI_ArrowArray,
+    schema: *mut FFI_ArrowSchema,
+);
+
+#[allow(clippy::result_large_err)]
+fn rr_data_loader_load_file_impl(
+    filepath: CStringView,
+    contents: Option<CBytesView>,
+    entity_path_prefix: CStringView,
+    on_chunk: Option<CDataLoaderChunkCallback>,
+    user_data: *mut c_void,
+) -> Result<(), CError> {
+    let filepath = filepath.as_str("filepath")?;
+    let contents = contents
+        .map(|contents| contents.as_bytes("contents"))
+        .transpose()?;
+    let entity_path_prefix = entity_path_prefix.as_str("entity_path_prefix").ok();
+    let Some(on_chunk) = on_chunk else {
+        return Err(CError::unexpected_null("on_chunk"));
+    };
+
+    let settings = DataLoaderSettings {
+        entity_path_prefix: entity_path_prefix.map(EntityPath::from),
+        ..DataLoaderSettings::recommended(RecordingId::random())
+    };
+
+    let (tx, rx) = re_smart_channel::smart_channel(
+        re_smart_channel::SmartMessageSource::Sdk,
+        re_smart_channel::SmartChannelSource::File(filepath.into()),
+    );
+
+    let loaded = if let Some(contents) = contents {
+        re_sdk::external::re_data_loader::load_from_file_contents(
+            &settings,
+            FileSource::Sdk,
+            filepath.as_ref(),
+            std::borrow::Cow::Borrowed(contents),
+            &tx,
+        )
+    } else {
+        re_sdk::external::re_data_loader::load_from_path(
+            &settings,
+            FileSource::Sdk,
+            filepath.as_ref(),
+            &tx,
+        )
+    };
+    loaded.map_err(|err| {
+        CError::new(
+            CErrorCode::DataLoaderError,
+            &format!("Couldn't load file {filepath:?}: {err}"),
+        )
+    })?;
+    drop(tx);
+
+    // The loaders run in the background, until they are all done and have dropped their senders.
+    while let Some(msg) = rx.recv().ok().and_then(|msg| msg.into_data()) {
+        let LogMsg::ArrowMsg(_store_id, arrow_msg) = msg else {
+            continue; // Store infos and blueprint activation don't matter to the caller.
+        };
+
+        let entity_path = Chunk::from_arrow_msg(&arrow_msg)
+            .map_err(|err| {
+                CError::new(
+                    CErrorCode::DataLoaderError,
+                    &format!("Loaded an invalid chunk from {filepath:?}: {err}"),
+                )
+            })?
+            .entity_path()
+            .to_string();
+
+        let mut schema =
+            FFI_ArrowSchema::try_from(arrow_msg.batch.schema().as_ref()).map_err(|err| {
+                CError::new(
+                    CErrorCode::ArrowFfiSchemaImportError,
+                    &format!("Couldn't export the schema of a chunk: {err}"),
+                )
+            })?;
+        let mut chunk = FFI_ArrowArray::new(&StructArray::from(arrow_msg.batch).into_data());
+
+        // Released on drop, unless the callback moved them out.
+        on_chunk(
+            user_data,
+            CStringView {
+                string: entity_path.as_ptr().cast::<c_char>(),
+                length: entity_path.len() as u32,
+            },
+            &mut chunk,
+            &mut schema,
+        );
+    }
+
+    Ok(())
+}
+
+#[allow(unsafe_code)]
+#[unsafe(no_mangle)]
+pub extern "C" fn rr_data_loader_load_file(
+    filepath: CStringView,
+    entity_path_prefix: CStringView,
+    on_chunk: Option<CDataLoaderChunkCallback>,
+    user_data: *mut c_void,
+    error: *mut CError,
+) {
+    if let Err(err) =
+        rr_data_loader_load_file_impl(filepath, None, entity_path_prefix, on_chunk, user_data)
+    {
+        err.write_error(error);
+    }
+}
+
+#[allow(unsafe_code)]
+#[unsafe(no_mangle)]
+pub extern "C" fn rr_data_loader_load_file_from_contents(
+    filepath: CStringView,
+    contents: CBytesView,
+    entity_path_prefix: CStringView,
+    on_chunk: Option<CDataLoaderChunkCallback>,
+    user_data: *mut c_void,
+    error: *mut CError,
+) {
+    if let Err(err) = rr_data_loader_load_file_impl(
+        filepath,
+        Some(contents),
+        entity_path_prefix,
+        on_chunk,
+        user_data,
+    ) {
+        err.write_error(error);
+    }
+}
@@ -7,6 +7,7 @@
 
 mod arrow_utils;
 mod component_type_registry;
+mod data_loader;
 mod error;
 mod ptr;
 mod recording_streams;
@@ -368,6 +369,7 @@ pub enum CErrorCode {
 
     _CategoryUtilities = 0x0001_0000,
     VideoLoadError,
+    DataLoaderError,
 
     Unknown = 0xFFFF_FFFF,
 }
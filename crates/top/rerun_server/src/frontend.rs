@@ -22,7 +22,12 @@ use re_protos::catalog::v1alpha1::{
     DeleteEntryResponse, EntryKind, RegisterTableRequest, RegisterTableResponse,
 };
 use re_protos::common::v1alpha1::ext::PartitionId;
-use re_protos::frontend::v1alpha1::ext::{GetChunksRequest, ScanPartitionTableRequest};
+use re_protos::frontend::v1alpha1::ext::{
+    FindPartitionsRequest, GetChunksRequest, ScanPartitionTableRequest, UpdatePartitionTagsRequest,
+};
+use re_protos::frontend::v1alpha1::{
+    FindPartitionsResponse, PartitionTag, UpdatePartitionTagsResponse,
+};
 use re_protos::manifest_registry::v1alpha1::{
     GetChunksResponse, GetDatasetSchemaResponse, GetPartitionTableSchemaResponse,
     QueryDatasetResponse, ScanPartitionTableResponse,
@@ -544,6 +549,61 @@ impl FrontendService for FrontendHandler {
         }))
     }
 
+    /* Tags */
+
+    async fn update_partition_tags(
+        &self,
+        request: tonic::Request<re_protos::frontend::v1alpha1::UpdatePartitionTagsRequest>,
+    ) -> std::result::Result<tonic::Response<UpdatePartitionTagsResponse>, tonic::Status> {
+        let UpdatePartitionTagsRequest {
+            dataset_id,
+            partition_id,
+            add_tags,
+            remove_tags,
+        } = request.into_inner().try_into()?;
+
+        let mut store = self.store.write().await;
+        let dataset = store.dataset_mut(dataset_id).ok_or_else(|| {
+            tonic::Status::not_found(format!("Entry with ID {dataset_id} not found"))
+        })?;
+
+        let tags = dataset.update_partition_tags(&partition_id, add_tags, &remove_tags)?;
+
+        Ok(tonic::Response::new(UpdatePartitionTagsResponse {
+            tags: tags
+                .iter()
+                .map(|(name, value)| PartitionTag {
+                    name: name.clone(),
+                    value: value.clone(),
+                })
+                .collect(),
+        }))
+    }
+
+    async fn find_partitions(
+        &self,
+        request: tonic::Request<re_protos::frontend::v1alpha1::FindPartitionsRequest>,
+    ) -> std::result::Result<tonic::Response<FindPartitionsResponse>, tonic::Status> {
+        let FindPartitionsRequest {
+            dataset_id,
+            tags,
+            time_range,
+        } = request.into_inner().try_into()?;
+
+        let store = self.store.read().await;
+        let dataset = store.dataset(dataset_id).ok_or_else(|| {
+            tonic::Status::not_found(format!("Entry with ID {dataset_id} not found"))
+        })?;
+
+        Ok(tonic::Response::new(FindPartitionsResponse {
+            partition_ids: dataset
+                .find_partitions(&tags, time_range.as_ref())
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }))
+    }
+
     /* Indexing */
 
     async fn create_index(
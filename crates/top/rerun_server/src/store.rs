@@ -1,11 +1,11 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
 
 use arrow::array::RecordBatch;
 use arrow::datatypes::Schema;
 
 use re_entity_db::{EntityDb, StoreBundle};
-use re_log_types::{EntryId, StoreKind};
+use re_log_types::{AbsoluteTimeRange, EntryId, StoreKind, TimelineName};
 use re_protos::catalog::v1alpha1::EntryKind;
 use re_protos::catalog::v1alpha1::ext::{DatasetEntry, EntryDetails};
 use re_protos::common::v1alpha1::ext::{DatasetHandle, PartitionId};
@@ -25,6 +25,12 @@ pub enum Error {
 
     #[error("Entry id '{0}' not found")]
     EntryIdNotFound(EntryId),
+
+    #[error("Partition id '{0}' not found")]
+    PartitionIdNotFound(PartitionId),
+
+    #[error("Invalid tag name '{0}'")]
+    InvalidTagName(String),
 }
 
 impl From<Error> for tonic::Status {
@@ -36,6 +42,12 @@ impl From<Error> for tonic::Status {
                 Self::already_exists(format!("Entry name already exists: {name}"))
             }
             Error::EntryIdNotFound(id) => Self::not_found(format!("Entry ID not found: {id}")),
+            Error::PartitionIdNotFound(id) => {
+                Self::not_found(format!("Partition ID not found: {id}"))
+            }
+            Error::InvalidTagName(name) => Self::invalid_argument(format!(
+                "Tag names must be non-empty and can't contain '=': {name:?}"
+            )),
         }
     }
 }
@@ -43,6 +55,38 @@ impl From<Error> for tonic::Status {
 pub struct Partition {
     entity_db: EntityDb,
     registration_time: jiff::Timestamp,
+
+    /// User-defined tags: either plain labels, or properties with a value.
+    tags: BTreeMap<String, Option<String>>,
+}
+
+impl Partition {
+    fn new(entity_db: EntityDb) -> Self {
+        Self {
+            entity_db,
+            registration_time: jiff::Timestamp::now(),
+            tags: BTreeMap::new(),
+        }
+    }
+
+    /// Whether the partition has all of `tags`, a `None` value matching any value.
+    fn has_tags(&self, tags: &[(String, Option<String>)]) -> bool {
+        tags.iter().all(|(name, value)| match self.tags.get(name) {
+            Some(actual) => value.is_none() || value == actual,
+            None => false,
+        })
+    }
+
+    /// The tags of the partition, as `name` or `name=value`.
+    fn formatted_tags(&self) -> Vec<String> {
+        self.tags
+            .iter()
+            .map(|(name, value)| match value {
+                Some(value) => format!("{name}={value}"),
+                None => name.clone(),
+            })
+            .collect()
+    }
 }
 
 pub struct Dataset {
@@ -104,16 +148,19 @@ impl Dataset {
     }
 
     pub fn partition_table(&self) -> arrow::error::Result<RecordBatch> {
-        let (partition_ids, registration_times): (Vec<_>, Vec<_>) = self
-            .partitions
-            .iter()
-            .map(|(store_id, partition)| {
-                (
-                    store_id.to_string(),
-                    partition.registration_time.as_nanosecond() as i64,
-                )
-            })
-            .unzip();
+        let ((partition_ids, registration_times), partition_tags): ((Vec<_>, Vec<_>), Vec<_>) =
+            self.partitions
+                .iter()
+                .map(|(store_id, partition)| {
+                    (
+                        (
+                            store_id.to_string(),
+                            partition.registration_time.as_nanosecond() as i64,
+                        ),
+                        partition.formatted_tags(),
+                    )
+                })
+                .unzip();
 
         let partition_types = vec!["rrd".to_owned(); partition_ids.len()];
 
@@ -132,6 +179,7 @@ impl Dataset {
             registration_times,
             partition_manifest_updated_ats,
             partition_manifest_urls,
+            partition_tags,
         )
     }
 
@@ -140,15 +188,66 @@ impl Dataset {
     }
 
     pub fn add_partition(&mut self, partition_id: PartitionId, entity_db: EntityDb) {
-        self.partitions.insert(
-            partition_id,
-            Partition {
-                entity_db,
-                registration_time: jiff::Timestamp::now(),
-            },
-        );
+        self.partitions
+            .insert(partition_id, Partition::new(entity_db));
         self.updated_at = jiff::Timestamp::now();
     }
+
+    /// Adds and removes tags of a partition, and returns all its tags.
+    ///
+    /// Adding a tag the partition already has replaces its value.
+    pub fn update_partition_tags(
+        &mut self,
+        partition_id: &PartitionId,
+        add_tags: Vec<(String, Option<String>)>,
+        remove_tags: &[String],
+    ) -> Result<&BTreeMap<String, Option<String>>, Error> {
+        if let Some((name, _)) = add_tags
+            .iter()
+            .find(|(name, _)| name.is_empty() || name.contains('='))
+        {
+            return Err(Error::InvalidTagName(name.clone()));
+        }
+
+        let partition = self
+            .partitions
+            .get_mut(partition_id)
+            .ok_or_else(|| Error::PartitionIdNotFound(partition_id.clone()))?;
+
+        for name in remove_tags {
+            partition.tags.remove(name);
+        }
+        partition.tags.extend(add_tags);
+        self.updated_at = jiff::Timestamp::now();
+
+        Ok(&partition.tags)
+    }
+
+    /// The ids of the partitions that have all of `tags`, and data within `time_range` if any.
+    ///
+    /// A tag with a `None` value matches any value.
+    pub fn find_partitions(
+        &self,
+        tags: &[(String, Option<String>)],
+        time_range: Option<&(TimelineName, AbsoluteTimeRange)>,
+    ) -> Vec<PartitionId> {
+        let mut partition_ids = self
+            .partitions
+            .iter()
+            .filter(|(_, partition)| partition.has_tags(tags))
+            .filter(|(_, partition)| {
+                time_range.is_none_or(|(timeline, time_range)| {
+                    partition
+                        .entity_db
+                        .time_range_for(timeline)
+                        .is_some_and(|range| range.intersects(*time_range))
+                })
+            })
+            .map(|(partition_id, _)| partition_id.clone())
+            .collect::<Vec<_>>();
+        partition_ids.sort();
+        partition_ids
+    }
 }
 
 #[derive(Default)]
@@ -208,10 +307,7 @@ impl InMemoryStore {
                                 .partitions
                                 .insert(
                                     PartitionId::new(store_id.recording_id().to_string()),
-                                    Partition {
-                                        entity_db,
-                                        registration_time: jiff::Timestamp::now(),
-                                    },
+                                    Partition::new(entity_db),
                                 );
                         }
                     }
@@ -0,0 +1,313 @@
+//! Default blueprint for MCAP files, based on the message types of the topics.
+//!
+//! Without a blueprint, the viewer puts every topic in a view of its own. Instead, this lays out
+//! camera topics in a grid of 2D views, point clouds and transforms in a shared 3D view, sensor
+//! readings in plots, and logs (e.g. `/rosout`) in a text log view.
+
+use re_chunk::{Chunk, ChunkResult, EntityPath, RowId, TimePoint};
+use re_log_types::TimeCell;
+use re_types::{
+    blueprint::{
+        archetypes::{ContainerBlueprint, ViewBlueprint, ViewContents, ViewportBlueprint},
+        components::ContainerKind,
+        views::{Spatial2DView, Spatial3DView, TextDocumentView, TextLogView, TimeSeriesView},
+    },
+    external::uuid,
+};
+
+/// How a topic is shown in the default blueprint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TopicKind {
+    /// Images, shown in a grid of 2D views.
+    Camera,
+
+    /// Point clouds and transforms, shown together in a 3D view.
+    Spatial3D,
+
+    /// Sensor readings, shown in a plot per topic.
+    Plot,
+
+    /// Log messages, shown together in a text log view.
+    Log,
+
+    /// Plain text, shown in a text document view per topic.
+    Text,
+}
+
+impl TopicKind {
+    /// The kind of topics of the given ROS2 message type, if it's shown by the default blueprint.
+    pub fn from_schema_name(schema_name: &str) -> Option<Self> {
+        Some(match schema_name {
+            "sensor_msgs/msg/Image" | "sensor_msgs/msg/CompressedImage" => Self::Camera,
+            "sensor_msgs/msg/PointCloud2"
+            | "velodyne_msgs/msg/VelodyneScan"
+            | "ouster_sensor_msgs/msg/PacketMsg"
+            | "ouster_msgs/msg/PacketMsg"
+            | "tf2_msgs/msg/TFMessage" => Self::Spatial3D,
+            "sensor_msgs/msg/Imu" | "sensor_msgs/msg/JointState" => Self::Plot,
+            "rcl_interfaces/msg/Log" => Self::Log,
+            "std_msgs/msg/String" => Self::Text,
+            _ => return None,
+        })
+    }
+}
+
+/// Creates the chunks of the default blueprint for the topics of an MCAP file.
+///
+/// Returns no chunks if none of the topics is of a known message type,
+/// in which case the viewer heuristics are a better fit.
+pub fn default_blueprint(summary: &::mcap::Summary) -> ChunkResult<Vec<Chunk>> {
+    let mut topics = summary
+        .channels
+        .values()
+        .filter_map(|channel| {
+            let schema = channel.schema.as_ref()?;
+            Some((
+                channel.topic.clone(),
+                TopicKind::from_schema_name(&schema.name)?,
+            ))
+        })
+        .collect::<Vec<_>>();
+    topics.sort();
+    topics.dedup();
+
+    blueprint_for_topics(&topics)
+}
+
+fn blueprint_for_topics(topics: &[(String, TopicKind)]) -> ChunkResult<Vec<Chunk>> {
+    let of_kind = |kind: TopicKind| {
+        topics
+            .iter()
+            .filter(move |(_, topic_kind)| *topic_kind == kind)
+            .map(|(topic, _)| topic.as_str())
+    };
+
+    let cameras = of_kind(TopicKind::Camera)
+        .map(|topic| Content::view::<Spatial2DView>(topic, topic, ["$origin/**".to_owned()]))
+        .collect::<Vec<_>>();
+
+    let spatial_3d = of_kind(TopicKind::Spatial3D)
+        .map(|topic| format!("+ {topic}/**"))
+        .collect::<Vec<_>>();
+
+    let plots = of_kind(TopicKind::Plot)
+        .map(|topic| Content::view::<TimeSeriesView>(topic, topic, ["$origin/**".to_owned()]))
+        .collect::<Vec<_>>();
+
+    let logs = of_kind(TopicKind::Log)
+        .map(|topic| format!("+ {topic}/**"))
+        .collect::<Vec<_>>();
+
+    let mut texts = Vec::new();
+    if !logs.is_empty() {
+        texts.push(Content::view::<TextLogView>("Logs", "/", logs));
+    }
+    texts.extend(
+        of_kind(TopicKind::Text)
+            .map(|topic| Content::view::<TextDocumentView>(topic, topic, ["$origin".to_owned()])),
+    );
+
+    let mut left = Vec::new();
+    if !spatial_3d.is_empty() {
+        left.push(Content::view::<Spatial3DView>("3D", "/", spatial_3d));
+    }
+    left.extend(Content::container(ContainerKind::Grid, cameras));
+
+    let mut right = Vec::new();
+    right.extend(Content::container(ContainerKind::Vertical, plots));
+    right.extend(Content::container(ContainerKind::Tabs, texts));
+
+    let columns = [
+        Content::container(ContainerKind::Vertical, left),
+        Content::container(ContainerKind::Vertical, right),
+    ];
+    let Some(root) = Content::container(ContainerKind::Horizontal, columns.into_iter().flatten())
+    else {
+        return Ok(Vec::new());
+    };
+
+    // The root of the viewport has to be a container.
+    let root = match root {
+        Content::View { .. } => Content::Container {
+            kind: ContainerKind::Tabs,
+            contents: vec![root],
+        },
+        Content::Container { .. } => root,
+    };
+
+    let mut chunks = Vec::new();
+    let root_id = root.log(&mut chunks)?;
+    chunks.push(blueprint_chunk(
+        "viewport",
+        &ViewportBlueprint::new()
+            .with_root_container(re_types::datatypes::Uuid::from(root_id))
+            .with_auto_layout(false)
+            .with_auto_views(false),
+    )?);
+
+    Ok(chunks)
+}
+
+enum Content {
+    View {
+        class_identifier: &'static str,
+        name: String,
+        origin: String,
+        contents: Vec<String>,
+    },
+    Container {
+        kind: ContainerKind,
+        contents: Vec<Content>,
+    },
+}
+
+impl Content {
+    fn view<V: re_types::View>(
+        name: &str,
+        origin: &str,
+        contents: impl IntoIterator<Item = String>,
+    ) -> Self {
+        Self::View {
+            class_identifier: V::identifier().as_str(),
+            name: name.to_owned(),
+            origin: origin.to_owned(),
+            contents: contents.into_iter().collect(),
+        }
+    }
+
+    /// A container of the given contents, or the content itself if there's only one.
+    fn container(kind: ContainerKind, contents: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut contents = contents.into_iter().collect::<Vec<_>>();
+        match contents.len() {
+            0 => None,
+            1 => contents.pop(),
+            _ => Some(Self::Container { kind, contents }),
+        }
+    }
+
+    fn blueprint_path(&self, id: uuid::Uuid) -> EntityPath {
+        match self {
+            Self::View { .. } => format!("view/{id}").into(),
+            Self::Container { .. } => format!("container/{id}").into(),
+        }
+    }
+
+    /// Adds the chunks of this content, and returns its id.
+    fn log(&self, chunks: &mut Vec<Chunk>) -> ChunkResult<uuid::Uuid> {
+        let id = uuid::Uuid::new_v4();
+        let path = self.blueprint_path(id);
+
+        match self {
+            Self::View {
+                class_identifier,
+                name,
+                origin,
+                contents,
+            } => {
+                chunks.push(blueprint_chunk(
+                    path.join(&EntityPath::from_single_string("ViewContents")),
+                    &ViewContents::new(contents.iter().map(String::as_str)),
+                )?);
+                chunks.push(blueprint_chunk(
+                    path,
+                    &ViewBlueprint::new(*class_identifier)
+                        .with_display_name(name.as_str())
+                        .with_space_origin(origin.as_str()),
+                )?);
+            }
+
+            Self::Container { kind, contents } => {
+                let mut content_paths = Vec::with_capacity(contents.len());
+                for content in contents {
+                    let content_id = content.log(chunks)?;
+                    content_paths.push(content.blueprint_path(content_id).to_string());
+                }
+                chunks.push(blueprint_chunk(
+                    path,
+                    &ContainerBlueprint::new(*kind).with_contents(content_paths),
+                )?);
+            }
+        }
+
+        Ok(id)
+    }
+}
+
+fn blueprint_chunk(
+    entity_path: impl Into<EntityPath>,
+    archetype: &dyn re_types::AsComponents,
+) -> ChunkResult<Chunk> {
+    Chunk::builder(entity_path.into())
+        .with_archetype(
+            RowId::new(),
+            TimePoint::from([("blueprint", TimeCell::from_sequence(0_i64))]),
+            archetype,
+        )
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view_classes(chunks: &[Chunk]) -> Vec<String> {
+        chunks
+            .iter()
+            .filter(|chunk| chunk.entity_path().to_string().starts_with("/view/"))
+            .filter_map(|chunk| {
+                chunk
+                    .component_batch::<re_types::blueprint::components::ViewClass>(
+                        &ViewBlueprint::descriptor_class_identifier(),
+                        0,
+                    )?
+                    .ok()?
+                    .into_iter()
+                    .next()
+                    .map(|class| class.as_str().to_owned())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn topic_kinds() {
+        assert_eq!(
+            TopicKind::from_schema_name("sensor_msgs/msg/CompressedImage"),
+            Some(TopicKind::Camera)
+        );
+        assert_eq!(
+            TopicKind::from_schema_name("rcl_interfaces/msg/Log"),
+            Some(TopicKind::Log)
+        );
+        assert_eq!(TopicKind::from_schema_name("foo_msgs/msg/Bar"), None);
+    }
+
+    #[test]
+    fn layout_of_a_bag() {
+        let topics = [
+            ("/camera/left/image_raw", TopicKind::Camera),
+            ("/camera/right/image_raw", TopicKind::Camera),
+            ("/lidar/points", TopicKind::Spatial3D),
+            ("/tf", TopicKind::Spatial3D),
+            ("/imu", TopicKind::Plot),
+            ("/rosout", TopicKind::Log),
+        ]
+        .map(|(topic, kind)| (topic.to_owned(), kind));
+
+        let chunks = blueprint_for_topics(&topics).unwrap();
+
+        let mut classes = view_classes(&chunks);
+        classes.sort();
+        assert_eq!(classes, ["2D", "2D", "3D", "TextLog", "TimeSeries"]);
+
+        assert!(
+            chunks
+                .iter()
+                .any(|chunk| chunk.entity_path() == &EntityPath::from("viewport"))
+        );
+    }
+
+    #[test]
+    fn no_known_topics() {
+        assert!(blueprint_for_topics(&[]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,163 @@
+//! Deduplication of messages across MCAP files, see [`MessageDeduplicator`].
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use ahash::HashMap;
+use parking_lot::Mutex;
+
+use crate::LayerIdentifier;
+
+/// The default for how far apart in log time copies of a message are still considered the same.
+pub const DEFAULT_DEDUPLICATION_WINDOW_NS: u64 = 1_000_000_000;
+
+#[derive(Default)]
+struct SeenMessages {
+    /// The source and log time of the latest copy of each message, by its hash.
+    messages: HashMap<u64, (usize, u64)>,
+    num_duplicates: u64,
+}
+
+/// Drops messages that were already loaded from another MCAP file, e.g. when merging the
+/// recordings of redundant recorders that overlap in time and topics.
+///
+/// Messages are identified by a hash of their topic, schema and payload. Copies of a message
+/// are only dropped if they come from a different source (i.e. file) and their log times are
+/// within [`Self::with_window_ns`] of each other, so that messages that legitimately repeat,
+/// like heartbeats, are left alone.
+///
+/// Clones share what has been seen so far, and each file is loaded with its own
+/// [`Self::next_source`].
+#[derive(Clone)]
+pub struct MessageDeduplicator {
+    seen: Arc<Mutex<SeenMessages>>,
+    hasher: ahash::RandomState,
+    next_source: Arc<AtomicUsize>,
+    source: usize,
+    window_ns: u64,
+}
+
+impl Default for MessageDeduplicator {
+    fn default() -> Self {
+        Self {
+            seen: Default::default(),
+            hasher: Default::default(),
+            next_source: Default::default(),
+            source: 0,
+            window_ns: DEFAULT_DEDUPLICATION_WINDOW_NS,
+        }
+    }
+}
+
+impl std::fmt::Debug for MessageDeduplicator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MessageDeduplicator")
+            .field("source", &self.source)
+            .field("window_ns", &self.window_ns)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MessageDeduplicator {
+    /// Sets how far apart in log time copies of a message are still considered the same.
+    pub fn with_window_ns(mut self, window_ns: u64) -> Self {
+        self.window_ns = window_ns;
+        self
+    }
+
+    /// A deduplicator for the next source, sharing the messages seen so far.
+    pub fn next_source(&self) -> Self {
+        Self {
+            source: self.next_source.fetch_add(1, Ordering::Relaxed) + 1,
+            ..self.clone()
+        }
+    }
+
+    /// The number of messages that were dropped so far.
+    pub fn num_duplicates(&self) -> u64 {
+        self.seen.lock().num_duplicates
+    }
+
+    /// Whether `msg` was already seen by `layer` from another source, otherwise remembers it.
+    pub fn is_duplicate(&self, layer: &LayerIdentifier, msg: &mcap::Message<'_>) -> bool {
+        let hash = self.hasher.hash_one((
+            layer,
+            msg.channel.topic.as_str(),
+            msg.channel
+                .schema
+                .as_ref()
+                .map(|schema| schema.name.as_str()),
+            msg.data.as_ref(),
+        ));
+
+        let mut seen = self.seen.lock();
+        match seen.messages.get(&hash) {
+            Some(&(source, log_time))
+                if source != self.source && log_time.abs_diff(msg.log_time) <= self.window_ns =>
+            {
+                seen.num_duplicates += 1;
+                true
+            }
+            _ => {
+                seen.messages.insert(hash, (self.source, msg.log_time));
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn message(
+        channel: &Arc<mcap::Channel<'static>>,
+        log_time: u64,
+        data: &[u8],
+    ) -> mcap::Message<'static> {
+        mcap::Message {
+            channel: channel.clone(),
+            sequence: 0,
+            log_time,
+            publish_time: log_time,
+            data: Cow::Owned(data.to_vec()),
+        }
+    }
+
+    #[test]
+    fn deduplicate_across_sources() {
+        let channel = Arc::new(mcap::Channel {
+            id: 0,
+            topic: "/camera/image".to_owned(),
+            schema: None,
+            message_encoding: "cdr".to_owned(),
+            metadata: Default::default(),
+        });
+        let layer = LayerIdentifier::from("ros2msg");
+
+        let deduplicator = MessageDeduplicator::default();
+        let first = deduplicator.next_source();
+        let second = deduplicator.next_source();
+
+        assert!(!first.is_duplicate(&layer, &message(&channel, 100, b"a")));
+        // Repeated messages of the same source are kept.
+        assert!(!first.is_duplicate(&layer, &message(&channel, 200, b"a")));
+
+        // The other recorder received it a bit later.
+        assert!(second.is_duplicate(&layer, &message(&channel, 150, b"a")));
+        assert!(!second.is_duplicate(&layer, &message(&channel, 150, b"b")));
+        assert!(!second.is_duplicate(
+            &layer,
+            &message(&channel, 100 + 2 * DEFAULT_DEDUPLICATION_WINDOW_NS, b"a")
+        ));
+
+        // Other layers see their own copy.
+        assert!(!second.is_duplicate(&LayerIdentifier::from("raw"), &message(&channel, 150, b"a")));
+
+        assert_eq!(deduplicator.num_duplicates(), 1);
+    }
+}
@@ -0,0 +1,164 @@
+//! Motion compensation of point clouds, see [`Trajectory`].
+
+use glam::{DAffine3, DQuat, DVec3};
+
+/// Point times, in nanoseconds, above which they are assumed to be absolute rather than
+/// relative to the time of their point cloud (about 11 days after the epoch).
+const MIN_ABSOLUTE_POINT_TIME_NS: f64 = 1e15;
+
+/// The poses of a moving sensor over time, e.g. from an odometry topic.
+///
+/// Spinning lidars measure their points over the course of a whole sweep, so point clouds get
+/// smeared when the sensor moves fast. Given the time each point was measured at, the trajectory
+/// is used to express all the points relative to the pose of the sensor at the time of the point
+/// cloud instead, see [`Self::deskew`].
+#[derive(Clone, Debug, Default)]
+pub struct Trajectory {
+    /// Sorted by time, in nanoseconds since the epoch.
+    poses: Vec<(i64, DVec3, DQuat)>,
+}
+
+impl Trajectory {
+    /// Adds the pose of the sensor at `time_ns`, in nanoseconds since the epoch.
+    pub fn insert(&mut self, time_ns: i64, translation: DVec3, rotation: DQuat) {
+        let idx = self.poses.partition_point(|(time, _, _)| *time < time_ns);
+        let pose = (time_ns, translation, rotation.normalize());
+        match self.poses.get_mut(idx) {
+            Some(existing) if existing.0 == time_ns => *existing = pose,
+            _ => self.poses.insert(idx, pose),
+        }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.poses.is_empty()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.poses.len()
+    }
+
+    /// The pose at `time_ns`, interpolated between the closest poses.
+    ///
+    /// Returns `None` outside of the time range of the trajectory, since extrapolating would
+    /// only make things worse.
+    pub fn pose_at(&self, time_ns: i64) -> Option<DAffine3> {
+        let idx = self.poses.partition_point(|(time, _, _)| *time < time_ns);
+        let (time_after, translation_after, rotation_after) = *self.poses.get(idx)?;
+        if time_after == time_ns {
+            return Some(DAffine3::from_rotation_translation(
+                rotation_after,
+                translation_after,
+            ));
+        }
+
+        let (time_before, translation_before, rotation_before) =
+            *self.poses.get(idx.checked_sub(1)?)?;
+        let t = (time_ns - time_before) as f64 / (time_after - time_before) as f64;
+        Some(DAffine3::from_rotation_translation(
+            rotation_before.slerp(rotation_after, t),
+            translation_before.lerp(translation_after, t),
+        ))
+    }
+
+    /// Moves each of `positions`, measured at the corresponding `times_ns`, to where it would have
+    /// been measured from the pose of the sensor at `reference_time_ns`.
+    ///
+    /// Points whose time is out of the range of the trajectory are left unchanged.
+    /// Returns whether the point cloud could be de-skewed at all.
+    pub fn deskew(
+        &self,
+        reference_time_ns: i64,
+        positions: &mut [[f32; 3]],
+        times_ns: impl IntoIterator<Item = i64>,
+    ) -> bool {
+        let Some(reference_pose) = self.pose_at(reference_time_ns) else {
+            return false;
+        };
+        let reference_inv = reference_pose.inverse();
+
+        for (position, time_ns) in positions.iter_mut().zip(times_ns) {
+            let Some(pose) = self.pose_at(time_ns) else {
+                continue;
+            };
+            let [x, y, z] = *position;
+            let deskewed =
+                (reference_inv * pose).transform_point3(DVec3::new(x as f64, y as f64, z as f64));
+            *position = deskewed.as_vec3().to_array();
+        }
+
+        true
+    }
+}
+
+/// Converts the raw per-point times of a point cloud stamped at `stamp_ns` to nanoseconds since
+/// the epoch.
+///
+/// Drivers disagree on the units: floating point times are assumed to be in seconds, integer ones
+/// in nanoseconds. Times that are large enough are assumed to be absolute, and relative to
+/// `stamp_ns` otherwise.
+pub fn point_times_ns(
+    stamp_ns: i64,
+    is_floating_point: bool,
+    values: &[f64],
+) -> impl Iterator<Item = i64> + '_ {
+    let scale = if is_floating_point { 1e9 } else { 1.0 };
+    values.iter().map(move |value| {
+        let time_ns = value * scale;
+        if time_ns.abs() >= MIN_ABSOLUTE_POINT_TIME_NS {
+            time_ns as i64
+        } else {
+            stamp_ns + time_ns as i64
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolation() {
+        let mut trajectory = Trajectory::default();
+        trajectory.insert(100, DVec3::ZERO, DQuat::IDENTITY);
+        trajectory.insert(300, DVec3::new(2.0, 0.0, 0.0), DQuat::from_rotation_z(1.0));
+
+        let pose = trajectory.pose_at(200).unwrap();
+        assert!(
+            pose.translation
+                .abs_diff_eq(DVec3::new(1.0, 0.0, 0.0), 1e-9)
+        );
+        let (_, rotation, _) = pose.to_scale_rotation_translation();
+        assert!(rotation.abs_diff_eq(DQuat::from_rotation_z(0.5), 1e-9));
+
+        assert!(trajectory.pose_at(99).is_none());
+        assert!(trajectory.pose_at(301).is_none());
+    }
+
+    #[test]
+    fn deskew_linear_motion() {
+        // The sensor moves by 1 m/s along x, while looking at a wall 10 m in front of it.
+        let mut trajectory = Trajectory::default();
+        trajectory.insert(0, DVec3::ZERO, DQuat::IDENTITY);
+        trajectory.insert(1_000_000_000, DVec3::X, DQuat::IDENTITY);
+
+        // Measured at 0.5 s, from 0.5 m further back than at the reference time of 1 s.
+        let mut positions = [[10.0, 0.0, 0.0], [9.5, 0.0, 0.0]];
+        let times = point_times_ns(1_000_000_000, true, &[0.0, -0.5]);
+        assert!(trajectory.deskew(1_000_000_000, &mut positions, times));
+        assert_eq!(positions, [[10.0, 0.0, 0.0], [9.0, 0.0, 0.0]]);
+
+        assert!(!trajectory.deskew(2_000_000_000, &mut positions, [0, 0]));
+    }
+
+    #[test]
+    fn absolute_and_relative_times() {
+        let stamp_ns = 1_700_000_000_000_000_000;
+        let times: Vec<_> = point_times_ns(stamp_ns, false, &[0.0, 1000.0]).collect();
+        assert_eq!(times, [stamp_ns, stamp_ns + 1000]);
+
+        let times: Vec<_> = point_times_ns(stamp_ns, true, &[1_700_000_000.5]).collect();
+        assert_eq!(times, [stamp_ns + 500_000_000]);
+    }
+}
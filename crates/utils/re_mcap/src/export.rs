@@ -0,0 +1,174 @@
+//! Writing Rerun data into MCAP files, e.g. to share a clip of a recording with other tools.
+//!
+//! Every entity becomes a schemaless channel named after its path, and every row of its chunks
+//! a JSON message, with the row's components keyed by their descriptors.
+
+use std::{
+    collections::{BTreeMap, btree_map::Entry},
+    io::{Seek, Write},
+    sync::Arc,
+};
+
+use arrow::{
+    array::{Array as _, ArrayRef, RecordBatch},
+    datatypes::{DataType, Field, Schema},
+};
+use re_chunk::{Chunk, TimelineName};
+use re_log_types::EntityPath;
+
+use crate::Error;
+
+/// The message encoding of the channels written by [`write_chunks_as_json`].
+pub const JSON_MESSAGE_ENCODING: &str = "json";
+
+/// Writes `chunks` as JSON messages into an MCAP file.
+///
+/// The log time of each message is the time of its row on `timeline`, or 0 for static rows.
+/// Components that can't be represented as JSON, like unions, are left out.
+pub fn write_chunks_as_json<'a, W: Write + Seek>(
+    writer: W,
+    chunks: impl IntoIterator<Item = &'a Arc<Chunk>>,
+    timeline: &TimelineName,
+) -> Result<(), Error> {
+    re_tracing::profile_function!();
+
+    let mut mcap = mcap::Writer::new(writer)?;
+    let mut channels: BTreeMap<EntityPath, (u16, u32)> = BTreeMap::new();
+
+    for chunk in chunks {
+        let (channel_id, sequence) = match channels.entry(chunk.entity_path().clone()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let channel_id = mcap.add_channel(
+                    0, // Schemaless
+                    &entry.key().to_string(),
+                    JSON_MESSAGE_ENCODING,
+                    &BTreeMap::new(),
+                )?;
+                entry.insert((channel_id, 0))
+            }
+        };
+
+        let times = chunk
+            .timelines()
+            .get(timeline)
+            .map(|time_column| time_column.times_raw());
+
+        for (row, message) in json_rows(chunk)?.iter().enumerate() {
+            let log_time = times.map_or(0, |times| times[row].max(0) as u64);
+            mcap.write_to_known_channel(
+                &mcap::records::MessageHeader {
+                    channel_id: *channel_id,
+                    sequence: *sequence,
+                    log_time,
+                    publish_time: log_time,
+                },
+                message,
+            )?;
+            *sequence += 1;
+        }
+    }
+
+    mcap.finish()?;
+    Ok(())
+}
+
+/// Encodes every row of `chunk` as a JSON object.
+fn json_rows(chunk: &Chunk) -> Result<Vec<Vec<u8>>, Error> {
+    let mut components = chunk
+        .components()
+        .iter()
+        .filter(|(_, list_array)| is_json_encodable(list_array.data_type()))
+        .collect::<Vec<_>>();
+    components.sort_by_key(|(component_descr, _)| *component_descr);
+
+    if components.is_empty() {
+        return Ok(vec![b"{}".to_vec(); chunk.num_rows()]);
+    }
+
+    let (fields, columns): (Vec<_>, Vec<_>) = components
+        .into_iter()
+        .map(|(component_descr, list_array)| {
+            (
+                Field::new(
+                    component_descr.to_string(),
+                    list_array.data_type().clone(),
+                    true,
+                ),
+                Arc::new(list_array.clone()) as ArrayRef,
+            )
+        })
+        .unzip();
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+
+    let mut writer = arrow::json::LineDelimitedWriter::new(Vec::new());
+    writer.write(&batch)?;
+    writer.finish()?;
+
+    Ok(writer
+        .into_inner()
+        .split(|&byte| byte == b'\n')
+        .filter(|line| !line.is_empty())
+        .map(<[u8]>::to_vec)
+        .collect())
+}
+
+fn is_json_encodable(datatype: &DataType) -> bool {
+    match datatype {
+        DataType::Union(..) | DataType::RunEndEncoded(..) => false,
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => is_json_encodable(field.data_type()),
+        DataType::Struct(fields) => fields
+            .iter()
+            .all(|field| is_json_encodable(field.data_type())),
+        DataType::Dictionary(_, values) => is_json_encodable(values),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::{RowId, TimePoint, Timeline};
+    use re_types::archetypes::Points3D;
+
+    use super::*;
+
+    #[test]
+    fn rows_become_messages() {
+        let timeline = Timeline::new_duration("time");
+        let chunk = Chunk::builder("points")
+            .with_archetype(
+                RowId::new(),
+                TimePoint::default().with(timeline, 10_i64),
+                &Points3D::new([[1.0, 2.0, 3.0]]),
+            )
+            .with_archetype(
+                RowId::new(),
+                TimePoint::default().with(timeline, 20_i64),
+                &Points3D::new([[4.0, 5.0, 6.0]]),
+            )
+            .build()
+            .unwrap();
+
+        let mut buffer = std::io::Cursor::new(Vec::new());
+        write_chunks_as_json(&mut buffer, [&Arc::new(chunk)], timeline.name()).unwrap();
+        let buffer = buffer.into_inner();
+
+        let messages = mcap::MessageStream::new(&buffer)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(2, messages.len());
+        assert_eq!("/points", messages[0].channel.topic);
+        assert_eq!(JSON_MESSAGE_ENCODING, messages[0].channel.message_encoding);
+        assert_eq!([10, 20], [messages[0].log_time, messages[1].log_time]);
+
+        let json: serde_json::Value = serde_json::from_slice(&messages[1].data).unwrap();
+        assert_eq!(
+            serde_json::json!([[4.0, 5.0, 6.0]]),
+            json["Points3D:positions"]
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! Throughput statistics of the message parsers, to find out which of them is the bottleneck.
+//!
+//! Statistics are accumulated for the lifetime of the process, across all loaded MCAP files,
+//! and are grouped by layer and schema.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+use crate::LayerIdentifier;
+
+/// Statistics of a single message parser, i.e. a schema handled by a layer.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct IngestStatistics {
+    /// The number of messages that were passed to the parser.
+    pub num_messages: u64,
+
+    /// The total size of the payloads of those messages.
+    pub num_bytes: u64,
+
+    /// Time spent in the parser, both appending messages and finalizing them into chunks.
+    pub decode_time: Duration,
+}
+
+impl IngestStatistics {
+    /// Messages decoded per second of decoding time.
+    pub fn messages_per_second(&self) -> f64 {
+        rate(self.num_messages, self.decode_time)
+    }
+
+    /// Payload bytes decoded per second of decoding time.
+    pub fn bytes_per_second(&self) -> f64 {
+        rate(self.num_bytes, self.decode_time)
+    }
+
+    pub(crate) fn add(&mut self, other: &Self) {
+        self.num_messages += other.num_messages;
+        self.num_bytes += other.num_bytes;
+        self.decode_time += other.decode_time;
+    }
+}
+
+fn rate(count: u64, time: Duration) -> f64 {
+    let secs = time.as_secs_f64();
+    if secs > 0.0 { count as f64 / secs } else { 0.0 }
+}
+
+/// Identifies a message parser in the [`ingest_statistics`].
+#[derive(Clone, Debug, PartialOrd, Ord, PartialEq, Eq)]
+pub struct ParserKey {
+    pub layer: LayerIdentifier,
+
+    /// The name of the schema of the parsed messages, empty for schemaless channels.
+    pub schema: String,
+}
+
+static STATISTICS: Mutex<BTreeMap<ParserKey, IngestStatistics>> = Mutex::new(BTreeMap::new());
+
+/// Adds the statistics of a parser that finished decoding a batch of messages.
+pub(crate) fn record(key: ParserKey, statistics: &IngestStatistics) {
+    STATISTICS.lock().entry(key).or_default().add(statistics);
+}
+
+/// The statistics of all parsers that decoded messages so far, sorted by layer and schema.
+pub fn ingest_statistics() -> Vec<(ParserKey, IngestStatistics)> {
+    STATISTICS
+        .lock()
+        .iter()
+        .map(|(key, statistics)| (key.clone(), *statistics))
+        .collect()
+}
+
+/// The statistics of all parsers combined.
+pub fn total_ingest_statistics() -> IngestStatistics {
+    let mut total = IngestStatistics::default();
+    for statistics in STATISTICS.lock().values() {
+        total.add(statistics);
+    }
+    total
+}
+
+/// Clears all statistics, e.g. before loading a file to measure it in isolation.
+pub fn reset_ingest_statistics() {
+    STATISTICS.lock().clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rates() {
+        let statistics = IngestStatistics {
+            num_messages: 10,
+            num_bytes: 1000,
+            decode_time: Duration::from_millis(500),
+        };
+        assert_eq!(statistics.messages_per_second(), 20.0);
+        assert_eq!(statistics.bytes_per_second(), 2000.0);
+        assert_eq!(IngestStatistics::default().messages_per_second(), 0.0);
+    }
+}
@@ -5,8 +5,13 @@ mod ros2;
 mod schema;
 mod stats;
 
+use arrow::buffer::Buffer;
 use re_chunk::{Chunk, EntityPath, external::nohash_hasher::IntMap};
-use std::collections::{BTreeMap, BTreeSet};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
 
 pub use self::{
     protobuf::McapProtobufLayer, raw::McapRawLayer, recording_info::McapRecordingInfoLayer,
@@ -14,12 +19,14 @@ pub use self::{
 };
 
 use crate::{
-    Error,
+    Error, MessageDeduplicator, SimClock,
+    ingest_stats::{IngestStatistics, ParserKey},
     parsers::{ChannelId, MessageParser, ParserContext},
+    static_topics::StaticChannel,
 };
 
 /// Globally unique identifier for a layer.
-#[derive(Clone, Debug, PartialOrd, Ord, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialOrd, Ord, PartialEq, Eq, Hash)]
 #[repr(transparent)]
 pub struct LayerIdentifier(String);
 
@@ -65,6 +72,20 @@ pub trait Layer {
         summary: &::mcap::Summary,
         emit: &mut dyn FnMut(Chunk),
     ) -> Result<(), Error>;
+
+    /// Like [`Self::process`], but skips the messages that were already loaded from other MCAP
+    /// files, see [`MessageDeduplicator`].
+    ///
+    /// Layers that don't look at individual messages can ignore the `deduplicator`.
+    fn process_deduplicated(
+        &mut self,
+        mcap_bytes: &[u8],
+        summary: &::mcap::Summary,
+        _deduplicator: &MessageDeduplicator,
+        emit: &mut dyn FnMut(Chunk),
+    ) -> Result<(), Error> {
+        self.process(mcap_bytes, summary, emit)
+    }
 }
 
 /// Can be used to extract per-message information from an MCAP file.
@@ -76,10 +97,18 @@ pub trait MessageLayer {
     where
         Self: Sized;
 
-    fn init(&mut self, _summary: &::mcap::Summary) -> Result<(), Error> {
+    /// Prepares the layer before the messages are parsed, with access to the entire MCAP file.
+    fn init(&mut self, _mcap_bytes: &[u8], _summary: &::mcap::Summary) -> Result<(), Error> {
         Ok(())
     }
 
+    /// The simulated time of the MCAP file, if it was recorded in a simulation.
+    ///
+    /// Available after [`Self::init`], and used to add the `sim_time` of every message.
+    fn sim_clock(&self) -> Option<Arc<SimClock>> {
+        None
+    }
+
     /// Instantites a new [`MessageParser`] that expects `num_rows` if it is interested in the current channel.
     ///
     /// Otherwise returns `None`.
@@ -93,7 +122,18 @@ pub trait MessageLayer {
     ) -> Option<Box<dyn MessageParser>>;
 }
 
-type Parser = (ParserContext, Box<dyn MessageParser>);
+struct Parser {
+    ctx: ParserContext,
+    parser: Box<dyn MessageParser>,
+    key: ParserKey,
+    statistics: IngestStatistics,
+
+    /// Whether and how the chunks are logged as static data, see [`crate::static_topics`].
+    static_channel: Option<StaticChannel>,
+
+    /// The index of the first message of this MCAP chunk within the channel.
+    first_message_index: u64,
+}
 
 /// Decodes batches of messages from an MCAP into Rerun chunks using previously registered parsers.
 struct McapChunkDecoder {
@@ -106,42 +146,96 @@ impl McapChunkDecoder {
     }
 
     /// Decode the next message in the chunk
-    pub fn decode_next(&mut self, msg: &::mcap::Message<'_>) -> Result<(), Error> {
+    pub fn decode_next(&mut self, mut msg: ::mcap::Message<'_>) -> Result<(), Error> {
         re_tracing::profile_function!();
 
-        let channel = msg.channel.as_ref();
-        let channel_id = ChannelId(channel.id);
-        let timepoint = re_chunk::TimePoint::from([
-            (
-                "log_time",
-                re_log_types::TimeCell::from_timestamp_nanos_since_epoch(msg.log_time as i64),
-            ),
-            (
-                "publish_time",
-                re_log_types::TimeCell::from_timestamp_nanos_since_epoch(msg.publish_time as i64),
-            ),
-        ]);
-
-        if let Some((ctx, parser)) = self.parsers.get_mut(&channel_id) {
-            ctx.add_timepoint(timepoint.clone());
-            parser.append(ctx, msg)?;
+        // Owned payloads (i.e. of messages in compressed chunks) are moved into a shared buffer,
+        // so that parsers can slice blob-like fields from it without copying them.
+        let message_data = match &mut msg.data {
+            Cow::Owned(data) => Some(Buffer::from_vec(std::mem::take(data))),
+            Cow::Borrowed(_) => None,
+        };
+        let msg = ::mcap::Message {
+            data: match &message_data {
+                Some(message_data) => Cow::Borrowed(message_data.as_slice()),
+                None => msg.data,
+            },
+            ..msg
+        };
+
+        let channel_id = ChannelId(msg.channel.id);
+
+        let result = if let Some(Parser {
+            ctx,
+            parser,
+            statistics,
+            ..
+        }) = self.parsers.get_mut(&channel_id)
+        {
+            let start = web_time::Instant::now();
+            ctx.add_message_times(&msg);
+            ctx.set_message_data(message_data.clone());
+            let result = parser.append(ctx, &msg);
+            ctx.set_message_data(None);
+            statistics.num_messages += 1;
+            statistics.num_bytes += msg.data.len() as u64;
+            statistics.decode_time += start.elapsed();
+            result
         } else {
             // TODO(#10867): If we encounter a message that we can't parse at all we should emit a warning.
             // Note that this quite easy to achieve when using layers and only selecting a subset.
             // However, to not overwhelm the user this should be reported in a _single_ static chunk,
             // so this is not the right place for this. Maybe we need to introduce something like a "report".
+            Ok(())
+        };
+
+        // Unless the parser kept slices of it, the payload can be reused for the next messages.
+        drop(msg);
+        if let Some(message_data) = message_data {
+            crate::parsers::pool::recycle(message_data);
         }
+
+        result?;
         Ok(())
     }
 
     /// Finish the decoding process and return the chunks.
+    ///
+    /// This also records the [`crate::ingest_stats`] of the parsers.
     pub fn finish(self) -> impl Iterator<Item = Result<Chunk, Error>> {
-        self.parsers
-            .into_values()
-            .flat_map(|(ctx, parser)| match parser.finalize(ctx) {
-                Ok(chunks) => chunks.into_iter().map(Ok).collect::<Vec<_>>(),
-                Err(err) => vec![Err(Error::Other(err))],
-            })
+        self.parsers.into_values().flat_map(
+            |Parser {
+                 ctx,
+                 parser,
+                 key,
+                 mut statistics,
+                 static_channel,
+                 first_message_index,
+             }| {
+                let start = web_time::Instant::now();
+                let result = parser.finalize(ctx);
+                statistics.decode_time += start.elapsed();
+                crate::ingest_stats::record(key, &statistics);
+
+                match result {
+                    Ok(chunks) => chunks
+                        .into_iter()
+                        .flat_map(|chunk| match static_channel {
+                            Some(static_channel) => match crate::static_topics::into_static_chunks(
+                                static_channel,
+                                chunk,
+                                first_message_index,
+                            ) {
+                                Ok(chunks) => chunks.into_iter().map(Ok).collect(),
+                                Err(err) => vec![Err(Error::Chunk(err))],
+                            },
+                            None => vec![Ok(chunk)],
+                        })
+                        .collect::<Vec<_>>(),
+                    Err(err) => vec![Err(Error::Other(err))],
+                }
+            },
+        )
     }
 }
 
@@ -156,59 +250,121 @@ impl<T: MessageLayer> Layer for T {
         summary: &mcap::Summary,
         emit: &mut dyn FnMut(Chunk),
     ) -> Result<(), Error> {
-        re_tracing::profile_scope!("process-message-layer");
-        self.init(summary)?;
-
-        for chunk in &summary.chunk_indexes {
-            re_tracing::profile_scope!("mcap-chunk");
-            let channel_counts = super::util::get_chunk_message_count(chunk, summary, mcap_bytes)?;
-
-            let parsers = summary
-                .read_message_indexes(mcap_bytes, chunk)?
-                .iter()
-                .filter_map(|(channel, msg_offsets)| {
-                    let parser = self.message_parser(channel, msg_offsets.len())?;
-                    let entity_path = EntityPath::from(channel.topic.as_str());
-                    let ctx = ParserContext::new(entity_path);
-                    Some((ChannelId::from(channel.id), (ctx, parser)))
-                })
-                .collect::<IntMap<_, _>>();
-
-            re_log::trace!(
-                "MCAP file contains {} channels with the following message counts: {:?}",
-                channel_counts.len(),
-                channel_counts
-            );
-
-            let mut decoder = McapChunkDecoder::new(parsers);
-
-            for msg in summary.stream_chunk(mcap_bytes, chunk)? {
-                match msg {
-                    Ok(message) => {
-                        if let Err(err) = decoder.decode_next(&message) {
-                            re_log::error!(
-                                "Failed to decode message from MCAP file: {err} on channel: {}",
-                                message.channel.topic
-                            );
-                        }
+        process_messages(self, mcap_bytes, summary, None, emit)
+    }
+
+    fn process_deduplicated(
+        &mut self,
+        mcap_bytes: &[u8],
+        summary: &mcap::Summary,
+        deduplicator: &MessageDeduplicator,
+        emit: &mut dyn FnMut(Chunk),
+    ) -> Result<(), Error> {
+        process_messages(self, mcap_bytes, summary, Some(deduplicator), emit)
+    }
+}
+
+/// Runs the [`MessageParser`]s of `layer` over all messages of the MCAP file.
+fn process_messages<T: MessageLayer>(
+    layer: &mut T,
+    mcap_bytes: &[u8],
+    summary: &mcap::Summary,
+    deduplicator: Option<&MessageDeduplicator>,
+    emit: &mut dyn FnMut(Chunk),
+) -> Result<(), Error> {
+    re_tracing::profile_scope!("process-message-layer");
+    layer.init(mcap_bytes, summary)?;
+    let identifier = T::identifier();
+    let sim_clock = layer.sim_clock();
+
+    // Topics usually span many MCAP chunks, so their entity paths are only parsed once.
+    // This also counts the messages of the previous MCAP chunks, see `Parser::first_message_index`.
+    let mut channels: IntMap<ChannelId, (EntityPath, ParserKey, Option<StaticChannel>, u64)> =
+        IntMap::default();
+
+    for chunk in &summary.chunk_indexes {
+        re_tracing::profile_scope!("mcap-chunk");
+        let channel_counts = super::util::get_chunk_message_count(chunk, summary, mcap_bytes)?;
+
+        let parsers = summary
+            .read_message_indexes(mcap_bytes, chunk)?
+            .iter()
+            .filter_map(|(channel, msg_offsets)| {
+                let parser = layer.message_parser(channel, msg_offsets.len())?;
+                let (entity_path, key, static_channel, num_messages) = channels
+                    .entry(ChannelId::from(channel.id))
+                    .or_insert_with(|| {
+                        let key = ParserKey {
+                            layer: T::identifier(),
+                            schema: channel
+                                .schema
+                                .as_ref()
+                                .map(|schema| schema.name.clone())
+                                .unwrap_or_default(),
+                        };
+                        let static_channel = crate::static_topics::static_channel(channel, summary);
+                        (
+                            EntityPath::from(channel.topic.as_str()),
+                            key,
+                            static_channel,
+                            0,
+                        )
+                    });
+                let first_message_index = *num_messages;
+                *num_messages += msg_offsets.len() as u64;
+                let parser = Parser {
+                    ctx: ParserContext::new(entity_path.clone()).with_sim_clock(sim_clock.clone()),
+                    parser,
+                    key: key.clone(),
+                    statistics: IngestStatistics::default(),
+                    static_channel: *static_channel,
+                    first_message_index,
+                };
+                Some((ChannelId::from(channel.id), parser))
+            })
+            .collect::<IntMap<_, _>>();
+
+        re_log::trace!(
+            "MCAP file contains {} channels with the following message counts: {:?}",
+            channel_counts.len(),
+            channel_counts
+        );
+
+        let mut decoder = McapChunkDecoder::new(parsers);
+
+        for msg in summary.stream_chunk(mcap_bytes, chunk)? {
+            match msg {
+                Ok(message) => {
+                    if deduplicator.is_some_and(|deduplicator| {
+                        deduplicator.is_duplicate(&identifier, &message)
+                    }) {
+                        continue;
                     }
-                    Err(err) => {
-                        re_log::error!("Failed to read message from MCAP file: {err}");
+
+                    let channel = message.channel.clone();
+                    if let Err(err) = decoder.decode_next(message) {
+                        re_log::error!(
+                            "Failed to decode message from MCAP file: {err} on channel: {}",
+                            channel.topic
+                        );
                     }
                 }
-            }
-
-            for chunk in decoder.finish() {
-                if let Ok(chunk) = chunk {
-                    emit(chunk);
-                } else {
-                    re_log::error!("Failed to decode chunk from MCAP file: {:?}", chunk);
+                Err(err) => {
+                    re_log::error!("Failed to read message from MCAP file: {err}");
                 }
             }
         }
 
-        Ok(())
+        for chunk in decoder.finish() {
+            if let Ok(chunk) = chunk {
+                emit(chunk);
+            } else {
+                re_log::error!("Failed to decode chunk from MCAP file: {:?}", chunk);
+            }
+        }
     }
+
+    Ok(())
 }
 
 /// Used to select certain layers.
@@ -233,7 +389,7 @@ impl SelectedLayers {
 /// Custom layers can be added by implementing the [`Layer`] or [`MessageLayer`]
 /// traits and calling [`Self::register`].
 pub struct LayerRegistry {
-    factories: BTreeMap<LayerIdentifier, fn() -> Box<dyn Layer>>,
+    factories: BTreeMap<LayerIdentifier, Box<dyn Fn() -> Box<dyn Layer>>>,
 }
 
 impl LayerRegistry {
@@ -259,7 +415,7 @@ impl LayerRegistry {
     pub fn register<L: Layer + Default + 'static>(mut self) -> Self {
         if self
             .factories
-            .insert(L::identifier(), || Box::new(L::default()))
+            .insert(L::identifier(), Box::new(|| Box::new(L::default())))
             .is_some()
         {
             re_log::warn_once!("Inserted layer {} twice.", L::identifier());
@@ -267,6 +423,16 @@ impl LayerRegistry {
         self
     }
 
+    /// Adds an already configured layer, replacing any layer with the same identifier.
+    ///
+    /// This is how the builtin layers get configured, e.g. with
+    /// [`McapRos2Layer::with_point_cloud_fields`].
+    pub fn register_layer<L: Layer + Clone + 'static>(mut self, layer: L) -> Self {
+        self.factories
+            .insert(L::identifier(), Box::new(move || Box::new(layer.clone())));
+        self
+    }
+
     /// Returns a list of all layers.
     pub fn layers(&self, selected: SelectedLayers) -> impl Iterator<Item = Box<dyn Layer>> {
         re_log::debug!(
@@ -12,6 +12,7 @@ use prost_reflect::{
     DescriptorPool, DynamicMessage, FieldDescriptor, Kind, MessageDescriptor, Value,
 };
 use re_chunk::{Chunk, ChunkId};
+use re_log_types::TimeCell;
 use re_types::ComponentDescriptor;
 
 use crate::parsers::{MessageParser, ParserContext};
@@ -20,6 +21,10 @@ use crate::{Error, LayerIdentifier, MessageLayer};
 struct ProtobufMessageParser {
     message_descriptor: MessageDescriptor,
     fields: BTreeMap<String, FixedSizeListBuilder<Box<dyn ArrayBuilder>>>,
+
+    /// The `timestamp` field of the message if it is a `google.protobuf.Timestamp`, as in the
+    /// Foxglove schemas.
+    timestamp_field: Option<FieldDescriptor>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -71,15 +76,41 @@ impl ProtobufMessageParser {
             );
         }
 
+        let timestamp_field = message_descriptor
+            .get_field_by_name("timestamp")
+            .filter(|field| {
+                let is_timestamp = match field.kind() {
+                    Kind::Message(message) => message.full_name() == "google.protobuf.Timestamp",
+                    _ => false,
+                };
+                is_timestamp && !field.is_list()
+            });
+
         Self {
             message_descriptor,
             fields,
+            timestamp_field,
         }
     }
 }
 
+/// Converts a `google.protobuf.Timestamp` to nanoseconds since the epoch.
+fn timestamp_nanos(timestamp: &DynamicMessage) -> i64 {
+    let seconds = timestamp
+        .get_field_by_name("seconds")
+        .and_then(|value| value.as_i64())
+        .unwrap_or_default();
+    let nanos = timestamp
+        .get_field_by_name("nanos")
+        .and_then(|value| value.as_i32())
+        .unwrap_or_default();
+    seconds
+        .saturating_mul(1_000_000_000)
+        .saturating_add(nanos as i64)
+}
+
 impl MessageParser for ProtobufMessageParser {
-    fn append(&mut self, _ctx: &mut ParserContext, msg: &mcap::Message<'_>) -> anyhow::Result<()> {
+    fn append(&mut self, ctx: &mut ParserContext, msg: &mcap::Message<'_>) -> anyhow::Result<()> {
         re_tracing::profile_function!();
         let dynamic_message =
             DynamicMessage::decode(self.message_descriptor.clone(), msg.data.as_ref()).map_err(
@@ -90,6 +121,20 @@ impl MessageParser for ProtobufMessageParser {
                 },
             )?;
 
+        // Like the header stamps of ROS2 messages, the timestamp ends up on the `timestamp` timeline.
+        // Messages without one fall back to their log time, so that every row has a timestamp.
+        if let Some(timestamp_field) = &self.timestamp_field {
+            let nanos = if dynamic_message.has_field(timestamp_field) {
+                dynamic_message
+                    .get_field(timestamp_field)
+                    .as_message()
+                    .map_or(0, timestamp_nanos)
+            } else {
+                msg.log_time as i64
+            };
+            ctx.add_timestamp(TimeCell::from_timestamp_nanos_since_epoch(nanos));
+        }
+
         // We always need to make sure to iterate over all our builders, adding null values whenever
         // a field is missing from the message that we received.
         for (field, builder) in &mut self.fields {
@@ -107,12 +152,12 @@ impl MessageParser for ProtobufMessageParser {
 
     fn finalize(self: Box<Self>, ctx: ParserContext) -> anyhow::Result<Vec<re_chunk::Chunk>> {
         re_tracing::profile_function!();
-        let entity_path = ctx.entity_path().clone();
-        let timelines = ctx.build_timelines();
+        let (entity_path, timelines) = ctx.into_parts();
 
         let Self {
             message_descriptor,
             fields,
+            timestamp_field: _,
         } = *self;
 
         let message_chunk = Chunk::from_auto_row_ids(
@@ -314,7 +359,7 @@ impl MessageLayer for McapProtobufLayer {
         "protobuf".into()
     }
 
-    fn init(&mut self, summary: &mcap::Summary) -> Result<(), Error> {
+    fn init(&mut self, _mcap_bytes: &[u8], summary: &mcap::Summary) -> Result<(), Error> {
         for channel in summary.channels.values() {
             let schema = channel
                 .schema
@@ -38,12 +38,11 @@ impl MessageParser for RawMcapMessageParser {
         re_tracing::profile_function!();
         let Self { mut data } = *self;
 
-        let entity_path = ctx.entity_path().clone();
-        let timelines = ctx.build_timelines();
+        let (entity_path, timelines) = ctx.into_parts();
 
         let chunk = re_chunk::Chunk::from_auto_row_ids(
             ChunkId::new(),
-            entity_path.clone(),
+            entity_path,
             timelines,
             std::iter::once((
                 ComponentDescriptor {
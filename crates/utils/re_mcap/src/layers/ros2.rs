@@ -1,12 +1,26 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
 use crate::{
+    Error, PointCloudFieldMapping, SimClock,
+    deskew::Trajectory,
     parsers::MessageParser,
     parsers::ros2msg::{
+        ouster_sensor_msgs::{OusterMetadata, OusterPacketMessageParser, read_ouster_metadata},
+        rcl_interfaces::LogMessageParser,
+        rosgraph_msgs::{ClockMessageParser, read_sim_clock},
         sensor_msgs::{
             CameraInfoMessageParser, CompressedImageMessageParser, ImageMessageParser,
             ImuMessageParser, JointStateMessageParser, PointCloud2MessageParser,
+            read_camera_calibration,
         },
         std_msgs::StringMessageParser,
+        trajectory::read_trajectory,
+        velodyne_msgs::VelodyneScanMessageParser,
     },
+    rectification::Rectification,
 };
 
 use super::MessageLayer;
@@ -15,14 +29,156 @@ use super::MessageLayer;
 ///
 /// Additionally, this layer will output Rerun archetypes for visualization in the viewer
 /// for supported ROS2 message types.
-#[derive(Debug, Default)]
-pub struct McapRos2Layer;
+#[derive(Clone, Debug, Default)]
+pub struct McapRos2Layer {
+    point_cloud_fields: PointCloudFieldMapping,
+
+    /// The topic with the poses to de-skew point clouds with, see [`Self::with_deskew_topic`].
+    deskew_topic: Option<String>,
+    trajectory: Option<Arc<Trajectory>>,
+
+    /// The metadata of the Ouster sensors, by the topic of their lidar packets.
+    ouster_metadata: BTreeMap<String, Arc<OusterMetadata>>,
+
+    /// Whether to rectify raw images with their camera info, see [`Self::with_image_rectification`].
+    rectify_images: bool,
+
+    /// The rectification of the images, by their topic.
+    rectifications: BTreeMap<String, Arc<Rectification>>,
+
+    /// The camera info topics of the rectified images.
+    rectified_camera_infos: BTreeSet<String>,
+
+    /// The simulated time, for recordings of simulations with a `/clock` topic.
+    sim_clock: Option<Arc<SimClock>>,
+}
+
+impl McapRos2Layer {
+    /// Sets how the fields of `sensor_msgs/msg/PointCloud2` messages are interpreted.
+    pub fn with_point_cloud_fields(mut self, point_cloud_fields: PointCloudFieldMapping) -> Self {
+        self.point_cloud_fields = point_cloud_fields;
+        self
+    }
+
+    /// De-skews `sensor_msgs/msg/PointCloud2` messages with per-point timestamps, using the poses
+    /// published on `topic`, see [`Trajectory`].
+    ///
+    /// The topic has to be of type `nav_msgs/msg/Odometry` or `geometry_msgs/msg/PoseStamped`,
+    /// and is assumed to hold the poses of the lidars themselves.
+    pub fn with_deskew_topic(mut self, topic: impl Into<String>) -> Self {
+        self.deskew_topic = Some(topic.into());
+        self
+    }
+
+    /// Rectifies `sensor_msgs/msg/Image` messages with the `camera_info` topic next to them,
+    /// so that they match the ideal pinhole cameras logged for them, see [`Rectification`].
+    ///
+    /// Without rectification, the distortion of the lenses is part of the logged pinholes.
+    pub fn with_image_rectification(mut self, rectify_images: bool) -> Self {
+        self.rectify_images = rectify_images;
+        self
+    }
+
+    fn init_rectifications(&mut self, mcap_bytes: &[u8], summary: &mcap::Summary) {
+        for channel in summary.channels.values() {
+            if channel.schema.as_ref().map(|schema| schema.name.as_str())
+                != Some("sensor_msgs/msg/Image")
+            {
+                continue;
+            }
+
+            let namespace = channel
+                .topic
+                .rsplit_once('/')
+                .map_or("", |(namespace, _)| namespace);
+            let camera_info_topic = format!("{namespace}/camera_info");
+            if !summary
+                .channels
+                .values()
+                .any(|channel| channel.topic == camera_info_topic)
+            {
+                continue;
+            }
+
+            match read_camera_calibration(mcap_bytes, summary, &camera_info_topic)
+                .map_err(|err| err.to_string())
+                .and_then(|calibration| Rectification::new(&calibration))
+            {
+                Ok(rectification) => {
+                    self.rectifications
+                        .insert(channel.topic.clone(), Arc::new(rectification));
+                    self.rectified_camera_infos.insert(camera_info_topic);
+                }
+                Err(err) => {
+                    re_log::warn!("Cannot rectify the images on {:?}: {err}", channel.topic);
+                }
+            }
+        }
+    }
+}
 
 impl MessageLayer for McapRos2Layer {
     fn identifier() -> super::LayerIdentifier {
         "ros2msg".into()
     }
 
+    fn init(&mut self, mcap_bytes: &[u8], summary: &mcap::Summary) -> Result<(), Error> {
+        // Raw lidar packets can only be decoded with the calibration of their sensor.
+        for channel in summary.channels.values() {
+            if !is_ouster_lidar_packets(channel) {
+                continue;
+            }
+            match read_ouster_metadata(mcap_bytes, summary, &channel.topic) {
+                Ok(metadata) => {
+                    self.ouster_metadata
+                        .insert(channel.topic.clone(), Arc::new(metadata));
+                }
+                Err(err) => {
+                    re_log::warn!("Cannot decode Ouster packets on {:?}: {err}", channel.topic)
+                }
+            }
+        }
+
+        if self.rectify_images {
+            self.init_rectifications(mcap_bytes, summary);
+        }
+
+        // The messages of simulations are put on the simulated time as well.
+        self.sim_clock = match read_sim_clock(mcap_bytes, summary) {
+            Ok(Some(sim_clock)) if !sim_clock.is_empty() => {
+                re_log::debug!("Found a simulation clock with {} ticks", sim_clock.len());
+                Some(Arc::new(sim_clock))
+            }
+            Ok(_) => None,
+            Err(err) => {
+                re_log::warn!("Cannot read the simulation clock: {err}");
+                None
+            }
+        };
+
+        let Some(topic) = &self.deskew_topic else {
+            return Ok(());
+        };
+
+        // Point clouds are still worth loading without motion compensation.
+        match read_trajectory(mcap_bytes, summary, topic) {
+            Ok(trajectory) if trajectory.is_empty() => {
+                re_log::warn!("No poses on {topic:?}, point clouds will not be de-skewed");
+            }
+            Ok(trajectory) => {
+                re_log::debug!("De-skewing point clouds with {} poses", trajectory.len());
+                self.trajectory = Some(Arc::new(trajectory));
+            }
+            Err(err) => re_log::warn!("Point clouds will not be de-skewed: {err}"),
+        }
+
+        Ok(())
+    }
+
+    fn sim_clock(&self) -> Option<Arc<SimClock>> {
+        self.sim_clock.clone()
+    }
+
     fn message_parser(
         &self,
         channel: &mcap::Channel<'_>,
@@ -38,14 +194,32 @@ impl MessageLayer for McapRos2Layer {
 
         Some(match name {
             "std_msgs/msg/String" => Box::new(StringMessageParser::new(num_rows)),
+            "rcl_interfaces/msg/Log" => Box::new(LogMessageParser::new(num_rows)),
+            "rosgraph_msgs/msg/Clock" => Box::new(ClockMessageParser::new(num_rows)),
             "sensor_msgs/msg/JointState" => Box::new(JointStateMessageParser::new(num_rows)),
             "sensor_msgs/msg/Imu" => Box::new(ImuMessageParser::new(num_rows)),
-            "sensor_msgs/msg/Image" => Box::new(ImageMessageParser::new(num_rows)),
-            "sensor_msgs/msg/CameraInfo" => Box::new(CameraInfoMessageParser::new(num_rows)),
+            "sensor_msgs/msg/Image" => Box::new(ImageMessageParser::new(
+                num_rows,
+                self.rectifications.get(&channel.topic).cloned(),
+            )),
+            "sensor_msgs/msg/CameraInfo" => Box::new(CameraInfoMessageParser::new(
+                num_rows,
+                self.rectified_camera_infos.contains(&channel.topic),
+            )),
             "sensor_msgs/msg/CompressedImage" => {
                 Box::new(CompressedImageMessageParser::new(num_rows))
             }
-            "sensor_msgs/msg/PointCloud2" => Box::new(PointCloud2MessageParser::new(num_rows)),
+            "sensor_msgs/msg/PointCloud2" => Box::new(PointCloud2MessageParser::new(
+                num_rows,
+                self.point_cloud_fields.clone(),
+                self.trajectory.clone(),
+            )),
+            "velodyne_msgs/msg/VelodyneScan" => Box::new(VelodyneScanMessageParser::new(num_rows)),
+            _ if is_ouster_lidar_packets(channel) => {
+                // Without metadata, there is already a warning from `init`.
+                let metadata = self.ouster_metadata.get(&channel.topic)?;
+                Box::new(OusterPacketMessageParser::new(num_rows, metadata.clone()))
+            }
             _ => {
                 re_log::warn_once!("Message schema {name:?} is currently not supported");
                 return None;
@@ -53,3 +227,15 @@ impl MessageLayer for McapRos2Layer {
         })
     }
 }
+
+/// Whether the channel has the lidar packets of an Ouster sensor.
+///
+/// The driver publishes its IMU packets with the same message type, on `imu_packets`.
+fn is_ouster_lidar_packets(channel: &mcap::Channel<'_>) -> bool {
+    channel.schema.as_ref().is_some_and(|schema| {
+        matches!(
+            schema.name.as_str(),
+            "ouster_sensor_msgs/msg/PacketMsg" | "ouster_msgs/msg/PacketMsg"
+        )
+    }) && channel.topic.ends_with("lidar_packets")
+}
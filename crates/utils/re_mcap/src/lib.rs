@@ -1,14 +1,26 @@
 //! Library providing utilities to load MCAP files with Rerun.
 
+pub mod blueprint;
+pub mod dedup;
+pub mod deskew;
 mod error;
+pub mod export;
+pub mod ingest_stats;
 pub mod layers;
+pub mod point_cloud;
+pub mod rectification;
+pub mod sim_clock;
+pub mod static_topics;
 
 pub(crate) mod parsers;
 pub(crate) mod util;
 
+pub use dedup::MessageDeduplicator;
 pub use error::Error;
 pub use layers::{Layer, LayerIdentifier, LayerRegistry, MessageLayer, SelectedLayers};
 pub use parsers::{MessageParser, ParserContext, cdr};
+pub use point_cloud::{PointCloudFieldMapping, PointFieldSemantic};
+pub use sim_clock::{SIM_TIME_TIMELINE, SimClock};
 
 // TODO(grtlr): We should expose an `Mcap` object that internally holds the summary + a reference to the bytes.
 pub use util::read_summary;
@@ -1,11 +1,22 @@
 //! Utilities for decoding MCAP messages into Rerun chunks.
 
+use std::sync::{Arc, LazyLock};
+
+use arrow::buffer::Buffer;
 use re_chunk::{
     Chunk, EntityPath, TimeColumn, TimeColumnBuilder, TimePoint, Timeline, TimelineName,
     external::nohash_hasher::{IntMap, IsEnabled},
 };
 use re_log_types::TimeCell;
 
+use crate::{SimClock, sim_clock::SIM_TIME};
+
+// Interning takes a global lock, so the names of the timelines that are added for every message
+// are only looked up once.
+static LOG_TIME: LazyLock<TimelineName> = LazyLock::new(TimelineName::log_time);
+static PUBLISH_TIME: LazyLock<TimelineName> = LazyLock::new(|| TimelineName::new("publish_time"));
+static TIMESTAMP: LazyLock<TimelineName> = LazyLock::new(|| TimelineName::new("timestamp"));
+
 /// Trait for parsing MCAP messages of a specific schema into Rerun chunks.
 ///
 /// This trait defines the interface for converting MCAP messages into Rerun's internal
@@ -30,7 +41,7 @@ pub trait MessageParser {
     ///
     /// 1. Decode the message data according to the schema
     /// 2. Extract any _additional_ timestamp information and add it to the [`ParserContext`].
-    ///    Note: `log_time` and `publish_time` are added automatically.
+    ///    Note: `log_time`, `publish_time` and, for simulations, `sim_time` are added automatically.
     /// 3. Accumulate the decoded data for later conversion to Rerun [`Chunk`]s in [`finalize()`](`Self::finalize`).
     fn append(&mut self, ctx: &mut ParserContext, msg: &mcap::Message<'_>) -> anyhow::Result<()>;
 
@@ -56,6 +67,12 @@ impl IsEnabled for ChannelId {}
 pub struct ParserContext {
     entity_path: EntityPath,
     pub timelines: IntMap<TimelineName, TimeColumnBuilder>,
+
+    /// The payload of the message that is currently being parsed, if it could be shared without copying it.
+    message_data: Option<Buffer>,
+
+    /// The simulated time of the recording, see [`Self::with_sim_clock`].
+    sim_clock: Option<Arc<SimClock>>,
 }
 
 impl ParserContext {
@@ -64,7 +81,40 @@ impl ParserContext {
         Self {
             entity_path,
             timelines: IntMap::default(),
+            message_data: None,
+            sim_clock: None,
+        }
+    }
+
+    /// Adds the simulated time of each message to the `sim_time` timeline, see [`SimClock`].
+    pub fn with_sim_clock(mut self, sim_clock: Option<Arc<SimClock>>) -> Self {
+        self.sim_clock = sim_clock;
+        self
+    }
+
+    /// Sets the payload of the message that is parsed next, see [`Self::slice_message_data`].
+    pub fn set_message_data(&mut self, message_data: Option<Buffer>) -> &mut Self {
+        self.message_data = message_data;
+        self
+    }
+
+    /// Turns a field that was decoded from the payload of the current message into a [`Buffer`].
+    ///
+    /// Blob-like fields (e.g. image data) borrowed from the payload are sliced from it without copying.
+    /// Anything else, e.g. fields from messages in uncompressed MCAP chunks, is copied into a
+    /// buffer from the [`pool`](super::pool).
+    pub fn slice_message_data(&self, field: &[u8]) -> Buffer {
+        if let Some(message_data) = &self.message_data
+            && let Some(offset) =
+                (field.as_ptr() as usize).checked_sub(message_data.as_ptr() as usize)
+            && offset + field.len() <= message_data.len()
+        {
+            return message_data.slice_with_length(offset, field.len());
         }
+
+        let mut buffer = super::pool::take(field.len());
+        buffer.extend_from_slice(field);
+        buffer.into()
     }
 
     /// Add an additional [`TimePoint`] to the timelines in this context.
@@ -104,12 +154,52 @@ impl ParserContext {
         self
     }
 
+    /// Add the `log_time` and `publish_time` of a message, and its `sim_time` if there is a
+    /// [`SimClock`].
+    ///
+    /// This is done automatically for every message that is passed to [`MessageParser::append`].
+    pub fn add_message_times(&mut self, msg: &mcap::Message<'_>) -> &mut Self {
+        self.add_time_cell(
+            *LOG_TIME,
+            TimeCell::from_timestamp_nanos_since_epoch(msg.log_time as i64),
+        );
+        self.add_time_cell(
+            *PUBLISH_TIME,
+            TimeCell::from_timestamp_nanos_since_epoch(msg.publish_time as i64),
+        );
+
+        if let Some(sim_time_ns) = self
+            .sim_clock
+            .as_ref()
+            .and_then(|sim_clock| sim_clock.sim_time_at(msg.log_time))
+        {
+            self.add_time_cell(*SIM_TIME, TimeCell::from_duration_nanos(sim_time_ns));
+        }
+
+        self
+    }
+
+    /// Add the sensor timestamp of a message, usually taken from its header, to the `timestamp` timeline.
+    pub fn add_timestamp(&mut self, cell: TimeCell) -> &mut Self {
+        self.add_time_cell(*TIMESTAMP, cell)
+    }
+
     /// Consume this context and build all timelines into [`TimeColumn`]s.
     pub fn build_timelines(self) -> IntMap<TimelineName, TimeColumn> {
-        self.timelines
+        self.into_parts().1
+    }
+
+    /// Consume this context into its entity path and all timelines, built into [`TimeColumn`]s.
+    ///
+    /// The time columns share their buffers when cloned, so chunks of the same messages can be
+    /// built from them without copying any data.
+    pub fn into_parts(self) -> (EntityPath, IntMap<TimelineName, TimeColumn>) {
+        let timelines = self
+            .timelines
             .into_iter()
             .map(|(name, builder)| (name, builder.build()))
-            .collect()
+            .collect();
+        (self.entity_path, timelines)
     }
 
     /// Get the entity path associated with this context.
@@ -117,3 +207,26 @@ impl ParserContext {
         &self.entity_path
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slice_message_data_without_copying() {
+        let mut ctx = ParserContext::new(EntityPath::from("image"));
+        let message_data = Buffer::from_vec((0..16_u8).collect::<Vec<_>>());
+        ctx.set_message_data(Some(message_data.clone()));
+
+        let field = &message_data.as_slice()[4..12];
+        let sliced = ctx.slice_message_data(field);
+        assert_eq!(sliced.as_slice(), field);
+        assert_eq!(sliced.as_ptr(), field.as_ptr());
+
+        // Fields that don't point into the message data are copied.
+        let other = [1, 2, 3];
+        let copied = ctx.slice_message_data(&other);
+        assert_eq!(copied.as_slice(), &other);
+        assert_ne!(copied.as_ptr(), other.as_ptr());
+    }
+}
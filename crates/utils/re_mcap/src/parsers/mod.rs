@@ -1,6 +1,7 @@
 pub mod cdr;
 pub(crate) mod dds;
 mod decode;
+pub(crate) mod pool;
 pub(crate) mod ros2msg;
 
 pub use decode::{ChannelId, MessageParser, ParserContext};
@@ -8,18 +9,48 @@ pub use decode::{ChannelId, MessageParser, ParserContext};
 /// Defines utility functions shared across parsers.
 pub(crate) mod util {
     use arrow::{
-        array::{FixedSizeListBuilder, ListBuilder, UInt8Builder},
-        datatypes::{DataType, Field},
+        array::{
+            ArrayBuilder, BooleanBuilder, FixedSizeListBuilder, ListBuilder, PrimitiveBuilder,
+            StringBuilder, UInt8Builder,
+        },
+        datatypes::{ArrowPrimitiveType, DataType, Field},
     };
     use re_types::{Loggable as _, components};
     use std::sync::Arc;
 
-    pub(crate) fn fixed_size_list_builder<T: arrow::array::ArrayBuilder + Default>(
+    use super::pool;
+
+    /// Builders that can be created with their values taken from the [`pool`].
+    pub(crate) trait PooledBuilder: ArrayBuilder {
+        fn with_pooled_capacity(capacity: usize) -> Self;
+    }
+
+    impl<T: ArrowPrimitiveType> PooledBuilder for PrimitiveBuilder<T> {
+        fn with_pooled_capacity(capacity: usize) -> Self {
+            let values = pool::take(capacity * std::mem::size_of::<T::Native>());
+            Self::new_from_buffer(values, None)
+        }
+    }
+
+    impl PooledBuilder for BooleanBuilder {
+        fn with_pooled_capacity(capacity: usize) -> Self {
+            // Bit-packed, so hardly worth pooling.
+            Self::with_capacity(capacity)
+        }
+    }
+
+    impl PooledBuilder for StringBuilder {
+        fn with_pooled_capacity(capacity: usize) -> Self {
+            Self::with_capacity(capacity, 0)
+        }
+    }
+
+    pub(crate) fn fixed_size_list_builder<T: PooledBuilder>(
         value_length: i32,
         capacity: usize,
-    ) -> arrow::array::FixedSizeListBuilder<T> {
-        arrow::array::FixedSizeListBuilder::with_capacity(
-            Default::default(),
+    ) -> FixedSizeListBuilder<T> {
+        FixedSizeListBuilder::with_capacity(
+            T::with_pooled_capacity(value_length as usize * capacity),
             value_length,
             capacity,
         )
@@ -0,0 +1,99 @@
+//! A process-wide pool of Arrow buffers shared by all parsers.
+//!
+//! Loading bags with millions of small messages otherwise allocates and frees buffers
+//! of the same few sizes over and over again. Statistics are exposed via [`re_memory::pools`].
+
+use std::{collections::BTreeMap, sync::LazyLock};
+
+use arrow::buffer::{Buffer, MutableBuffer};
+use parking_lot::Mutex;
+use re_memory::PoolStatistics;
+
+/// Pooled memory beyond this is freed instead.
+const MAX_POOLED_BYTES: usize = 64 * 1024 * 1024;
+
+/// Larger buffers are never pooled, so that a few huge messages don't pin their memory.
+const MAX_BUFFER_BYTES: usize = 8 * 1024 * 1024;
+
+#[derive(Default)]
+struct Pool {
+    /// Cleared buffers by their capacity.
+    free: BTreeMap<usize, Vec<MutableBuffer>>,
+    statistics: PoolStatistics,
+}
+
+static POOL: LazyLock<Mutex<Pool>> = LazyLock::new(|| {
+    re_memory::pools::register_pool("MCAP parser buffers", || POOL.lock().statistics);
+    Mutex::default()
+});
+
+/// Takes an empty buffer with room for at least `capacity` bytes from the pool, or allocates one.
+pub fn take(capacity: usize) -> MutableBuffer {
+    let mut pool = POOL.lock();
+
+    // The smallest one that fits, so that large buffers remain for large requests.
+    let key = pool.free.range(capacity..).next().map(|(&key, _)| key);
+    let buffer = key.and_then(|key| {
+        let buffers = pool.free.get_mut(&key)?;
+        let buffer = buffers.pop();
+        if buffers.is_empty() {
+            pool.free.remove(&key);
+        }
+        buffer
+    });
+
+    if let Some(buffer) = buffer {
+        pool.statistics.pooled.sub(buffer.capacity());
+        pool.statistics.num_reused += 1;
+        buffer
+    } else {
+        pool.statistics.num_allocated += 1;
+        drop(pool);
+        MutableBuffer::with_capacity(capacity)
+    }
+}
+
+/// Returns the memory of a buffer to the pool, unless anything else still references it.
+pub fn recycle(buffer: Buffer) {
+    // Slices of larger buffers can't be turned back into a `MutableBuffer`.
+    if buffer.ptr_offset() != 0 || buffer.capacity() > MAX_BUFFER_BYTES {
+        return;
+    }
+    let Ok(mut buffer) = buffer.into_mutable() else {
+        return;
+    };
+    buffer.clear();
+
+    let mut pool = POOL.lock();
+    if pool.statistics.pooled.size + buffer.capacity() <= MAX_POOLED_BYTES {
+        pool.statistics.pooled.add(buffer.capacity());
+        pool.free.entry(buffer.capacity()).or_default().push(buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take_and_recycle() {
+        let mut buffer = take(100);
+        assert!(buffer.is_empty());
+        assert!(buffer.capacity() >= 100);
+        buffer.extend_from_slice(&[1; 100]);
+
+        let buffer = Buffer::from(buffer);
+        let shared = buffer.clone();
+        let sliced = buffer.slice(10);
+
+        // Buffers that are still referenced elsewhere are left alone.
+        recycle(sliced);
+        recycle(buffer);
+        assert_eq!(shared.as_slice(), &[1; 100]);
+        recycle(shared);
+
+        let buffer = take(100);
+        assert!(buffer.is_empty());
+        assert!(buffer.capacity() >= 100);
+    }
+}
@@ -4,6 +4,8 @@
 //!
 use serde::{Deserialize, Serialize};
 
+use super::std_msgs::Header;
+
 /// This represents a vector in free space.
 ///
 /// This is semantically different than a point.
@@ -39,3 +41,38 @@ pub struct Pose {
     pub position: Point,
     pub orientation: Quaternion,
 }
+
+/// A [`Pose`] with reference coordinate frame and timestamp.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PoseStamped {
+    pub header: Header,
+    pub pose: Pose,
+}
+
+/// This represents a pose in free space with uncertainty.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PoseWithCovariance {
+    pub pose: Pose,
+
+    /// Row-major representation of the 6x6 covariance matrix.
+    ///
+    /// The orientation parameters use a fixed-axis representation.
+    /// In order, the parameters are: `(x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)`
+    pub covariance: [[f64; 6]; 6],
+}
+
+/// This expresses velocity in free space broken into its linear and angular parts.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Twist {
+    pub linear: Vector3,
+    pub angular: Vector3,
+}
+
+/// This expresses velocity in free space with uncertainty.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TwistWithCovariance {
+    pub twist: Twist,
+
+    /// Row-major representation of the 6x6 covariance matrix, see [`PoseWithCovariance::covariance`].
+    pub covariance: [[f64; 6]; 6],
+}
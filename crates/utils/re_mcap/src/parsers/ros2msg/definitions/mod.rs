@@ -7,9 +7,18 @@
 //! The supported message packages include:
 //!
 //! - [`builtin_interfaces`]: Time and duration representations.
+//! - [`nav_msgs`]: Odometry, e.g. for de-skewing point clouds.
+//! - [`ouster_sensor_msgs`] and [`velodyne_msgs`]: Raw packets of lidars.
+//! - [`rcl_interfaces`]: Log messages of the nodes, e.g. on `/rosout`.
+//! - [`rosgraph_msgs`]: The simulated time of simulators, on `/clock`.
 //! - [`std_msgs`]: Common standard messages like [`std_msgs::Header`] and [`std_msgs::ColorRGBA`].
 
 pub mod builtin_interfaces;
 pub mod geometry_msgs;
+pub mod nav_msgs;
+pub mod ouster_sensor_msgs;
+pub mod rcl_interfaces;
+pub mod rosgraph_msgs;
 pub mod sensor_msgs;
 pub mod std_msgs;
+pub mod velodyne_msgs;
@@ -0,0 +1,26 @@
+//! Definitions for the ROS2 `nav_msgs` package.
+//!
+//! Based on definitions taken from <https://github.com/ros2/common_interfaces/tree/rolling/nav_msgs>
+
+use serde::{Deserialize, Serialize};
+
+use super::{geometry_msgs, std_msgs::Header};
+
+/// This represents an estimate of a position and velocity in free space.
+///
+/// The pose in this message should be specified in the coordinate frame given by `header.frame_id`.
+/// The twist in this message should be specified in the coordinate frame given by `child_frame_id`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Odometry {
+    /// Includes the frame id of the pose parent.
+    pub header: Header,
+
+    /// Frame id the pose points to. The twist is in this coordinate frame.
+    pub child_frame_id: String,
+
+    /// Estimated pose that is typically relative to a fixed world frame.
+    pub pose: geometry_msgs::PoseWithCovariance,
+
+    /// Estimated linear and angular velocity relative to `child_frame_id`.
+    pub twist: geometry_msgs::TwistWithCovariance,
+}
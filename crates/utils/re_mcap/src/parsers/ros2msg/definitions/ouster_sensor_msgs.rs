@@ -0,0 +1,18 @@
+//! Definitions for the ROS2 `ouster_sensor_msgs` package.
+//!
+//! Based on definitions taken from <https://github.com/ouster-lidar/ouster-ros/tree/ros2/ouster-sensor-msgs>
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+/// A raw lidar or IMU packet, as received from the sensor over UDP.
+///
+/// The packets can only be interpreted with the metadata of the sensor, which the driver
+/// publishes as JSON on the `metadata` topic next to the packets.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PacketMsg<'a> {
+    #[serde(with = "serde_bytes")]
+    #[serde(borrow)]
+    pub buf: Cow<'a, [u8]>,
+}
@@ -0,0 +1,40 @@
+//! Definitions for the ROS2 `rcl_interfaces` package.
+//!
+//! Based on definitions taken from <https://github.com/ros2/rcl_interfaces/tree/rolling/rcl_interfaces/msg>
+
+use serde::{Deserialize, Serialize};
+
+use super::builtin_interfaces::Time;
+
+/// A log message, as published by the nodes on `/rosout`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Log {
+    /// Timestamp when this message was generated by the node.
+    pub stamp: Time,
+
+    /// Corresponding log level, see the associated constants.
+    pub level: u8,
+
+    /// Name representing the logger this message came from.
+    pub name: String,
+
+    /// The full log message.
+    pub msg: String,
+
+    /// The file the message came from.
+    pub file: String,
+
+    /// The function the message came from.
+    pub function: String,
+
+    /// The line in the file the message came from.
+    pub line: u32,
+}
+
+impl Log {
+    pub const DEBUG: u8 = 10;
+    pub const INFO: u8 = 20;
+    pub const WARN: u8 = 30;
+    pub const ERROR: u8 = 40;
+    pub const FATAL: u8 = 50;
+}
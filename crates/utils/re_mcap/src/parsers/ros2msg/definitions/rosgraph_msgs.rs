@@ -0,0 +1,15 @@
+//! Definitions for the ROS2 `rosgraph_msgs` package.
+//!
+//! Based on definitions taken from <https://github.com/ros2/rcl_interfaces/tree/rolling/rosgraph_msgs>
+
+use serde::{Deserialize, Serialize};
+
+use super::builtin_interfaces::Time;
+
+/// The current time of the system, published on `/clock` by simulators.
+///
+/// Nodes with `use_sim_time` use the latest of these instead of the wall clock.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Clock {
+    pub clock: Time,
+}
@@ -145,7 +145,7 @@ pub struct PointField {
 /// Point clouds organized as 2D images may be produced by camera depth sensors
 /// such as stereo or time-of-flight.
 #[derive(Debug, Serialize, Deserialize)]
-pub struct PointCloud2 {
+pub struct PointCloud2<'a> {
     /// Time of sensor data acquisition, and the coordinate frame ID (for 3D points).
     pub header: Header,
 
@@ -166,8 +166,10 @@ pub struct PointCloud2 {
     /// Length of a row in bytes
     pub row_step: u32,
 
+    #[serde(with = "serde_bytes")]
+    #[serde(borrow)]
     /// Actual point data, size is (`row_step`*`height`)
-    pub data: Vec<u8>,
+    pub data: Cow<'a, [u8]>,
 
     /// True if there are no invalid points
     pub is_dense: bool,
@@ -0,0 +1,83 @@
+//! Definitions for the ROS2 `velodyne_msgs` package.
+//!
+//! Based on definitions taken from <https://github.com/ros-drivers/velodyne/tree/ros2/velodyne_msgs>
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{builtin_interfaces::Time, std_msgs::Header};
+
+/// The size of the raw data of a [`VelodynePacket`].
+pub const PACKET_SIZE: usize = 1206;
+
+/// Velodyne LIDAR scan packets.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VelodyneScan {
+    /// Standard ROS message header.
+    pub header: Header,
+
+    /// Vector of raw packets.
+    pub packets: Vec<VelodynePacket>,
+}
+
+/// Raw Velodyne LIDAR packet.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VelodynePacket {
+    /// Packet timestamp.
+    pub stamp: Time,
+
+    /// Packet contents, always [`PACKET_SIZE`] bytes long.
+    #[serde(
+        serialize_with = "serialize_packet_data",
+        deserialize_with = "deserialize_packet_data"
+    )]
+    pub data: Vec<u8>,
+}
+
+// `uint8[1206]` is a fixed-size array, which unlike `uint8[]` has no length prefix.
+
+fn serialize_packet_data<S: Serializer>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    use serde::ser::{Error as _, SerializeTuple as _};
+
+    if data.len() != PACKET_SIZE {
+        return Err(S::Error::custom(format!(
+            "expected {PACKET_SIZE} bytes of packet data, got {}",
+            data.len()
+        )));
+    }
+
+    let mut tuple = serializer.serialize_tuple(PACKET_SIZE)?;
+    for byte in data {
+        tuple.serialize_element(byte)?;
+    }
+    tuple.end()
+}
+
+fn deserialize_packet_data<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<u8>, D::Error> {
+    struct PacketDataVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for PacketDataVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(formatter, "{PACKET_SIZE} bytes of packet data")
+        }
+
+        fn visit_seq<A: serde::de::SeqAccess<'de>>(
+            self,
+            mut seq: A,
+        ) -> Result<Self::Value, A::Error> {
+            let mut data = Vec::with_capacity(PACKET_SIZE);
+            while let Some(byte) = seq.next_element()? {
+                data.push(byte);
+            }
+            if data.len() != PACKET_SIZE {
+                return Err(serde::de::Error::invalid_length(data.len(), &self));
+            }
+            Ok(data)
+        }
+    }
+
+    deserializer.deserialize_tuple(PACKET_SIZE, PacketDataVisitor)
+}
@@ -1,4 +1,9 @@
 mod definitions;
 
+pub mod ouster_sensor_msgs;
+pub mod rcl_interfaces;
+pub mod rosgraph_msgs;
 pub mod sensor_msgs;
 pub mod std_msgs;
+pub mod trajectory;
+pub mod velodyne_msgs;
@@ -0,0 +1,185 @@
+use serde_json::Value;
+
+use super::super::definitions::std_msgs;
+use crate::{Error, parsers::cdr, util::for_each_channel_message};
+
+/// The layout of the lidar packets, as configured on the sensor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OusterUdpProfile {
+    /// The format of firmwares before 2.2, with a 20-bit range and 16-bit reflectivity.
+    Legacy,
+
+    /// `RNG19_RFL8_SIG16_NIR16`, the default single-return profile of newer firmwares.
+    Rng19Rfl8Sig16Nir16,
+}
+
+/// What is needed to decode the lidar packets of an Ouster sensor into points, i.e. its
+/// calibrated beam angles and the layout of its packets.
+///
+/// The driver publishes the metadata that it reads from the sensor as JSON, on a `metadata`
+/// topic next to the packets.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OusterMetadata {
+    /// The elevation of each beam, in degrees.
+    pub beam_altitude_angles: Vec<f64>,
+
+    /// The azimuth offset of each beam, in degrees.
+    pub beam_azimuth_angles: Vec<f64>,
+
+    /// The distance between the center of the lidar and the origin of the beams.
+    pub lidar_origin_to_beam_origin_mm: f64,
+
+    pub pixels_per_column: usize,
+    pub columns_per_packet: usize,
+    pub columns_per_frame: usize,
+    pub udp_profile: OusterUdpProfile,
+}
+
+impl OusterMetadata {
+    /// Parses the metadata JSON, in both the flat layout of older firmwares and the nested one of
+    /// newer ones.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let json: Value = serde_json::from_str(json).map_err(anyhow::Error::from)?;
+
+        // Newer firmwares group the fields in sections, older ones don't.
+        let field = |section: &str, key: &str| {
+            json.get(key)
+                .or_else(|| json.get(section)?.get(key))
+                .or_else(|| json.get("data_format")?.get(key))
+                .ok_or_else(|| anyhow::anyhow!("Ouster metadata has no {key:?}"))
+        };
+        let angles = |key: &str| -> Result<Vec<f64>, Error> {
+            field("beam_intrinsics", key)?
+                .as_array()
+                .and_then(|angles| angles.iter().map(Value::as_f64).collect::<Option<Vec<_>>>())
+                .ok_or_else(|| anyhow::anyhow!("Ouster metadata has invalid {key:?}").into())
+        };
+        let number = |key: &str| -> Result<usize, Error> {
+            field("lidar_data_format", key)?
+                .as_u64()
+                .map(|value| value as usize)
+                .ok_or_else(|| anyhow::anyhow!("Ouster metadata has invalid {key:?}").into())
+        };
+
+        let udp_profile = match field("config_params", "udp_profile_lidar")
+            .or_else(|_| field("lidar_data_format", "udp_profile_lidar"))
+        {
+            // Before it could be configured, there was only the legacy profile.
+            Err(_) => OusterUdpProfile::Legacy,
+            Ok(profile) => match profile.as_str() {
+                Some("LEGACY") => OusterUdpProfile::Legacy,
+                Some("RNG19_RFL8_SIG16_NIR16") => OusterUdpProfile::Rng19Rfl8Sig16Nir16,
+                _ => {
+                    return Err(anyhow::anyhow!("Unsupported Ouster UDP profile {profile}").into());
+                }
+            },
+        };
+
+        let metadata = Self {
+            beam_altitude_angles: angles("beam_altitude_angles")?,
+            beam_azimuth_angles: angles("beam_azimuth_angles")?,
+            lidar_origin_to_beam_origin_mm: field(
+                "beam_intrinsics",
+                "lidar_origin_to_beam_origin_mm",
+            )?
+            .as_f64()
+            .unwrap_or_default(),
+            pixels_per_column: number("pixels_per_column")?,
+            columns_per_packet: number("columns_per_packet")?,
+            columns_per_frame: number("columns_per_frame")?,
+            udp_profile,
+        };
+
+        if metadata.beam_altitude_angles.len() != metadata.pixels_per_column
+            || metadata.beam_azimuth_angles.len() != metadata.pixels_per_column
+        {
+            return Err(anyhow::anyhow!(
+                "Ouster metadata has beam angles for {} beams, but {} pixels per column",
+                metadata.beam_altitude_angles.len(),
+                metadata.pixels_per_column
+            )
+            .into());
+        }
+
+        Ok(metadata)
+    }
+}
+
+/// Reads the metadata of the Ouster sensor whose lidar packets are published on `packets_topic`,
+/// from the `metadata` topic in the same namespace.
+pub fn read_ouster_metadata(
+    mcap_bytes: &[u8],
+    summary: &mcap::Summary,
+    packets_topic: &str,
+) -> Result<OusterMetadata, Error> {
+    re_tracing::profile_function!();
+
+    let namespace = packets_topic
+        .rsplit_once('/')
+        .map_or("", |(namespace, _)| namespace);
+    let metadata_topic = format!("{namespace}/metadata");
+
+    let Some(channel) = summary
+        .channels
+        .values()
+        .find(|channel| channel.topic == metadata_topic)
+    else {
+        return Err(
+            anyhow::anyhow!("MCAP file has no Ouster metadata topic {metadata_topic:?}").into(),
+        );
+    };
+
+    // The metadata is published once, but it doesn't hurt to use the latest one.
+    let mut metadata = None;
+    for_each_channel_message(mcap_bytes, summary, channel.id, |msg| {
+        let std_msgs::StringMessage { data } =
+            cdr::try_decode_message::<std_msgs::StringMessage>(&msg.data)
+                .map_err(|err| Error::Other(anyhow::anyhow!(err)))?;
+        metadata = Some(OusterMetadata::from_json(&data)?);
+        Ok(())
+    })?;
+
+    metadata
+        .ok_or_else(|| anyhow::anyhow!("Ouster metadata topic {metadata_topic:?} is empty").into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_and_nested_layouts() {
+        let legacy = r#"{
+            "beam_altitude_angles": [1.0, -1.0],
+            "beam_azimuth_angles": [3.0, -3.0],
+            "lidar_origin_to_beam_origin_mm": 15.8,
+            "data_format": {"pixels_per_column": 2, "columns_per_packet": 16, "columns_per_frame": 1024}
+        }"#;
+        let nested = r#"{
+            "beam_intrinsics": {
+                "beam_altitude_angles": [1.0, -1.0],
+                "beam_azimuth_angles": [3.0, -3.0],
+                "lidar_origin_to_beam_origin_mm": 15.8
+            },
+            "config_params": {"udp_profile_lidar": "RNG19_RFL8_SIG16_NIR16"},
+            "lidar_data_format": {"pixels_per_column": 2, "columns_per_packet": 16, "columns_per_frame": 1024}
+        }"#;
+
+        let legacy = OusterMetadata::from_json(legacy).unwrap();
+        assert_eq!(legacy.udp_profile, OusterUdpProfile::Legacy);
+        assert_eq!(legacy.beam_azimuth_angles, [3.0, -3.0]);
+        assert_eq!(legacy.columns_per_frame, 1024);
+
+        let nested = OusterMetadata::from_json(nested).unwrap();
+        assert_eq!(nested.udp_profile, OusterUdpProfile::Rng19Rfl8Sig16Nir16);
+        assert_eq!(
+            OusterMetadata {
+                udp_profile: OusterUdpProfile::Legacy,
+                ..nested
+            },
+            legacy
+        );
+
+        assert!(OusterMetadata::from_json(r#"{"beam_altitude_angles": [1.0]}"#).is_err());
+    }
+}
@@ -0,0 +1,5 @@
+mod metadata;
+mod packet;
+
+pub use metadata::*;
+pub use packet::*;
@@ -0,0 +1,320 @@
+use std::sync::Arc;
+
+use re_chunk::Chunk;
+use re_types::SerializedComponentBatch;
+
+use super::super::definitions::ouster_sensor_msgs;
+use super::{OusterMetadata, OusterUdpProfile};
+use crate::{
+    Error,
+    parsers::{MessageParser, ParserContext, cdr},
+    point_cloud::{LidarPoints, point_cloud_chunks},
+};
+
+/// Plugin that parses `ouster_sensor_msgs/msg/PacketMsg` messages of lidar packets.
+#[derive(Default)]
+pub struct OusterPacketSchemaPlugin;
+
+/// The sizes of the parts of the lidar packets that depend on the [`OusterUdpProfile`].
+struct PacketLayout {
+    packet_header_size: usize,
+    column_header_size: usize,
+    column_footer_size: usize,
+    pixel_size: usize,
+    range_mask: u32,
+}
+
+impl PacketLayout {
+    fn new(profile: OusterUdpProfile) -> Self {
+        match profile {
+            OusterUdpProfile::Legacy => Self {
+                packet_header_size: 0,
+                column_header_size: 16,
+                column_footer_size: 4,
+                pixel_size: 12,
+                range_mask: 0x000F_FFFF,
+            },
+            OusterUdpProfile::Rng19Rfl8Sig16Nir16 => Self {
+                packet_header_size: 32,
+                column_header_size: 12,
+                column_footer_size: 0,
+                pixel_size: 12,
+                range_mask: 0x0007_FFFF,
+            },
+        }
+    }
+}
+
+/// A column of a lidar packet, i.e. the measurements of all beams at one azimuth.
+struct Column<'a> {
+    measurement_id: u16,
+    frame_id: u16,
+    is_valid: bool,
+    pixels: &'a [u8],
+}
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([data[offset], data[offset + 1]])
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        data[offset],
+        data[offset + 1],
+        data[offset + 2],
+        data[offset + 3],
+    ])
+}
+
+/// Splits a lidar packet into its columns.
+fn columns<'a>(
+    metadata: &OusterMetadata,
+    layout: &PacketLayout,
+    packet: &'a [u8],
+) -> Result<Vec<Column<'a>>, Error> {
+    let column_size = layout.column_header_size
+        + layout.pixel_size * metadata.pixels_per_column
+        + layout.column_footer_size;
+    let expected_size = layout.packet_header_size + column_size * metadata.columns_per_packet;
+    if packet.len() < expected_size {
+        return Err(anyhow::anyhow!(
+            "Ouster lidar packet has {} bytes, expected at least {expected_size}",
+            packet.len()
+        )
+        .into());
+    }
+
+    let packet_frame_id = read_u16(packet, 2);
+    Ok(packet[layout.packet_header_size..expected_size]
+        .chunks_exact(column_size)
+        .map(|column| {
+            let pixels = &column[layout.column_header_size..]
+                [..layout.pixel_size * metadata.pixels_per_column];
+            match metadata.udp_profile {
+                OusterUdpProfile::Legacy => Column {
+                    measurement_id: read_u16(column, 8),
+                    frame_id: read_u16(column, 10),
+                    is_valid: read_u32(column, column_size - 4) == u32::MAX,
+                    pixels,
+                },
+                OusterUdpProfile::Rng19Rfl8Sig16Nir16 => Column {
+                    measurement_id: read_u16(column, 8),
+                    frame_id: packet_frame_id,
+                    is_valid: read_u16(column, 10) & 1 == 1,
+                    pixels,
+                },
+            }
+        })
+        .collect())
+}
+
+/// Decodes the valid pixels of `column` into `points`, in the frame of the lidar.
+fn decode_column(
+    metadata: &OusterMetadata,
+    layout: &PacketLayout,
+    column: &Column<'_>,
+    points: &mut LidarPoints,
+) {
+    use std::f64::consts::TAU;
+
+    let encoder_angle =
+        TAU * (1.0 - column.measurement_id as f64 / metadata.columns_per_frame as f64);
+    let beam_origin_mm = metadata.lidar_origin_to_beam_origin_mm;
+
+    for (beam, pixel) in column.pixels.chunks_exact(layout.pixel_size).enumerate() {
+        let range_mm = (read_u32(pixel, 0) & layout.range_mask) as f64;
+        if range_mm == 0.0 {
+            // No return.
+            continue;
+        }
+
+        let signal = read_u16(pixel, 6);
+
+        let azimuth = encoder_angle - metadata.beam_azimuth_angles[beam].to_radians();
+        let altitude = metadata.beam_altitude_angles[beam].to_radians();
+        let beam_range_mm = range_mm - beam_origin_mm;
+        let position_mm = [
+            beam_range_mm * azimuth.cos() * altitude.cos() + beam_origin_mm * encoder_angle.cos(),
+            beam_range_mm * azimuth.sin() * altitude.cos() + beam_origin_mm * encoder_angle.sin(),
+            beam_range_mm * altitude.sin(),
+        ];
+
+        points.push(
+            position_mm.map(|coordinate| (coordinate / 1000.0) as f32),
+            signal as f32,
+            beam as u32,
+        );
+    }
+}
+
+/// Decodes the lidar packets of `ouster_sensor_msgs/msg/PacketMsg` messages into point clouds,
+/// one for each rotation of the sensor.
+///
+/// The packets are decoded using the calibration of the sensor from its [`OusterMetadata`].
+/// Points are in the frame of the lidar, the intensity is the signal of their return.
+pub struct OusterPacketMessageParser {
+    metadata: Arc<OusterMetadata>,
+    layout: PacketLayout,
+
+    /// The frame being assembled from the packets, along with its id and the row of its first packet.
+    frame: Option<(u16, usize, LidarPoints)>,
+    point_clouds: Vec<(usize, Vec<SerializedComponentBatch>)>,
+    next_row: usize,
+}
+
+impl OusterPacketMessageParser {
+    const ARCHETYPE_NAME: &str = "ouster_sensor_msgs.msg.PacketMsg";
+
+    pub fn new(num_rows: usize, metadata: Arc<OusterMetadata>) -> Self {
+        let layout = PacketLayout::new(metadata.udp_profile);
+        let packets_per_frame = metadata.columns_per_frame / metadata.columns_per_packet.max(1);
+        Self {
+            metadata,
+            layout,
+            frame: None,
+            point_clouds: Vec::with_capacity(num_rows / packets_per_frame.max(1) + 1),
+            next_row: 0,
+        }
+    }
+
+    fn finish_frame(&mut self) {
+        if let Some((_, row, points)) = self.frame.take()
+            && !points.is_empty()
+        {
+            self.point_clouds
+                .push((row, points.into_batches(Self::ARCHETYPE_NAME)));
+        }
+    }
+}
+
+impl MessageParser for OusterPacketMessageParser {
+    fn append(&mut self, _ctx: &mut ParserContext, msg: &mcap::Message<'_>) -> anyhow::Result<()> {
+        let packet = cdr::try_decode_message::<ouster_sensor_msgs::PacketMsg<'_>>(&msg.data)
+            .map_err(|err| Error::Other(anyhow::anyhow!(err)))?;
+
+        let row = self.next_row;
+        self.next_row += 1;
+
+        for column in columns(&self.metadata, &self.layout, &packet.buf)? {
+            if !column.is_valid {
+                continue;
+            }
+
+            if self
+                .frame
+                .as_ref()
+                .is_none_or(|(frame_id, _, _)| *frame_id != column.frame_id)
+            {
+                self.finish_frame();
+                self.frame = Some((column.frame_id, row, LidarPoints::default()));
+            }
+
+            if let Some((_, _, points)) = &mut self.frame {
+                decode_column(&self.metadata, &self.layout, &column, points);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finalize(mut self: Box<Self>, ctx: ParserContext) -> anyhow::Result<Vec<Chunk>> {
+        self.finish_frame();
+
+        let (entity_path, timelines) = ctx.into_parts();
+
+        Ok(point_cloud_chunks(
+            &entity_path,
+            &timelines,
+            self.point_clouds,
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::{array::AsArray as _, datatypes::Float32Type};
+    use re_types::archetypes::Points3D;
+
+    use super::*;
+
+    fn metadata() -> OusterMetadata {
+        OusterMetadata {
+            beam_altitude_angles: vec![0.0, 45.0],
+            beam_azimuth_angles: vec![0.0, 0.0],
+            lidar_origin_to_beam_origin_mm: 0.0,
+            pixels_per_column: 2,
+            columns_per_packet: 2,
+            columns_per_frame: 4,
+            udp_profile: OusterUdpProfile::Legacy,
+        }
+    }
+
+    /// A legacy packet with the given measurement ids, frame id, and range of all pixels.
+    fn legacy_packet(measurement_ids: [u16; 2], frame_id: u16, range_mm: u32) -> Vec<u8> {
+        let mut packet = Vec::new();
+        for measurement_id in measurement_ids {
+            packet.extend_from_slice(&0u64.to_le_bytes());
+            packet.extend_from_slice(&measurement_id.to_le_bytes());
+            packet.extend_from_slice(&frame_id.to_le_bytes());
+            packet.extend_from_slice(&0u32.to_le_bytes());
+            for _ in 0..2 {
+                packet.extend_from_slice(&range_mm.to_le_bytes());
+                packet.extend_from_slice(&7u16.to_le_bytes()); // reflectivity
+                packet.extend_from_slice(&100u16.to_le_bytes()); // signal
+                packet.extend_from_slice(&[0; 4]);
+            }
+            packet.extend_from_slice(&u32::MAX.to_le_bytes());
+        }
+        packet
+    }
+
+    #[test]
+    fn decode_legacy_columns() {
+        let metadata = metadata();
+        let layout = PacketLayout::new(metadata.udp_profile);
+        let packet = legacy_packet([0, 1], 3, 2000);
+
+        let columns = columns(&metadata, &layout, &packet).unwrap();
+        assert_eq!(columns.len(), 2);
+        assert!(
+            columns
+                .iter()
+                .all(|column| column.is_valid && column.frame_id == 3)
+        );
+
+        let mut points = LidarPoints::default();
+        for column in &columns {
+            decode_column(&metadata, &layout, column, &mut points);
+        }
+        assert!(!points.is_empty());
+
+        assert!(super::columns(&metadata, &layout, &packet[..10]).is_err());
+    }
+
+    #[test]
+    fn decode_column_geometry() {
+        let metadata = metadata();
+        let layout = PacketLayout::new(metadata.udp_profile);
+        let packet = legacy_packet([1, 2], 0, 2000);
+        let columns = columns(&metadata, &layout, &packet).unwrap();
+
+        // A quarter of a rotation in, the encoder points to -y (the sensor spins clockwise).
+        let mut points = LidarPoints::default();
+        decode_column(&metadata, &layout, &columns[0], &mut points);
+        let batches = points.into_batches("test");
+        let positions = batches
+            .iter()
+            .find(|batch| batch.descriptor == Points3D::descriptor_positions())
+            .unwrap()
+            .array
+            .as_fixed_size_list();
+        let values = positions.values().as_primitive::<Float32Type>();
+        let first = [values.value(0), values.value(1), values.value(2)];
+        let second = [values.value(3), values.value(4), values.value(5)];
+
+        let close = |a: [f32; 3], b: [f32; 3]| a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-5);
+        assert!(close(first, [0.0, -2.0, 0.0]), "{first:?}");
+        let half = 2.0 * std::f32::consts::FRAC_1_SQRT_2;
+        assert!(close(second, [0.0, -half, half]), "{second:?}");
+    }
+}
@@ -0,0 +1,90 @@
+use super::super::definitions::rcl_interfaces;
+use re_chunk::{Chunk, ChunkId};
+use re_log_types::TimeCell;
+use re_types::{archetypes::TextLog, components::TextLogLevel};
+
+use crate::parsers::{
+    cdr, {MessageParser, ParserContext},
+};
+
+/// Plugin that parses `rcl_interfaces/msg/Log` messages, e.g. from `/rosout`.
+pub struct LogMessageParser {
+    /// The messages, prefixed with the name of their logger.
+    texts: Vec<String>,
+    levels: Vec<TextLogLevel>,
+}
+
+impl LogMessageParser {
+    pub fn new(num_rows: usize) -> Self {
+        Self {
+            texts: Vec::with_capacity(num_rows),
+            levels: Vec::with_capacity(num_rows),
+        }
+    }
+}
+
+fn text_log_level(level: u8) -> TextLogLevel {
+    TextLogLevel::from(match level {
+        ..rcl_interfaces::Log::INFO => TextLogLevel::DEBUG,
+        rcl_interfaces::Log::INFO..rcl_interfaces::Log::WARN => TextLogLevel::INFO,
+        rcl_interfaces::Log::WARN..rcl_interfaces::Log::ERROR => TextLogLevel::WARN,
+        rcl_interfaces::Log::ERROR..rcl_interfaces::Log::FATAL => TextLogLevel::ERROR,
+        rcl_interfaces::Log::FATAL.. => TextLogLevel::CRITICAL,
+    })
+}
+
+impl MessageParser for LogMessageParser {
+    fn append(&mut self, ctx: &mut ParserContext, msg: &mcap::Message<'_>) -> anyhow::Result<()> {
+        let rcl_interfaces::Log {
+            stamp,
+            level,
+            name,
+            msg,
+            ..
+        } = cdr::try_decode_message::<rcl_interfaces::Log>(&msg.data)?;
+
+        // add the node timestamp to the context, `log_time` and `publish_time` are added automatically
+        ctx.add_timestamp(TimeCell::from_timestamp_nanos_since_epoch(stamp.as_nanos()));
+
+        self.texts.push(if name.is_empty() {
+            msg
+        } else {
+            format!("[{name}] {msg}")
+        });
+        self.levels.push(text_log_level(level));
+        Ok(())
+    }
+
+    fn finalize(self: Box<Self>, ctx: ParserContext) -> anyhow::Result<Vec<re_chunk::Chunk>> {
+        let Self { texts, levels } = *self;
+
+        let (entity_path, timelines) = ctx.into_parts();
+
+        let text_logs = TextLog::update_fields()
+            .with_many_text(texts)
+            .with_many_level(levels)
+            .columns_of_unit_batches()?
+            .collect();
+
+        let chunk = Chunk::from_auto_row_ids(ChunkId::new(), entity_path, timelines, text_logs)?;
+
+        Ok(vec![chunk])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_levels() {
+        let levels =
+            [0, 10, 20, 25, 30, 40, 50, 255].map(|level| String::from(text_log_level(level)));
+        assert_eq!(
+            levels,
+            [
+                "DEBUG", "DEBUG", "INFO", "INFO", "WARN", "ERROR", "CRITICAL", "CRITICAL"
+            ]
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use re_chunk::{Chunk, ChunkId};
+use re_types::archetypes::Scalars;
+
+use super::super::definitions::rosgraph_msgs;
+use crate::{
+    Error, SimClock,
+    parsers::{MessageParser, ParserContext, cdr},
+    util::for_each_channel_message,
+};
+
+/// The topic that simulators publish their clock on.
+const CLOCK_TOPIC: &str = "/clock";
+
+/// Whether the channel has the `rosgraph_msgs/msg/Clock` messages of a simulator.
+pub fn is_clock_channel(channel: &mcap::Channel<'_>) -> bool {
+    channel
+        .schema
+        .as_ref()
+        .is_some_and(|schema| schema.name == "rosgraph_msgs/msg/Clock")
+}
+
+/// Reads the simulated time of the recording from its clock topic, preferably `/clock`.
+///
+/// Returns `None` if the recording has no clock topic, i.e. it wasn't recorded in a simulation.
+pub fn read_sim_clock(
+    mcap_bytes: &[u8],
+    summary: &mcap::Summary,
+) -> Result<Option<SimClock>, Error> {
+    re_tracing::profile_function!();
+
+    let channels = summary
+        .channels
+        .values()
+        .filter(|channel| is_clock_channel(channel))
+        .collect::<Vec<_>>();
+    let Some(channel) = channels
+        .iter()
+        .find(|channel| channel.topic == CLOCK_TOPIC)
+        .or(channels.first())
+    else {
+        return Ok(None);
+    };
+
+    let mut clock = SimClock::default();
+    for_each_channel_message(mcap_bytes, summary, channel.id, |msg| {
+        let rosgraph_msgs::Clock { clock: time } =
+            cdr::try_decode_message::<rosgraph_msgs::Clock>(&msg.data)
+                .map_err(|err| Error::Other(anyhow::anyhow!(err)))?;
+        clock.insert(msg.log_time, time.as_nanos());
+        Ok(())
+    })?;
+
+    Ok(Some(clock))
+}
+
+/// Plugin that parses `rosgraph_msgs/msg/Clock` messages.
+///
+/// The simulated time is logged as a scalar in seconds, so that it can be plotted against the
+/// log time to see how fast the simulation ran.
+pub struct ClockMessageParser {
+    /// The simulated time of each message, in seconds.
+    seconds: Vec<f64>,
+}
+
+impl ClockMessageParser {
+    pub fn new(num_rows: usize) -> Self {
+        Self {
+            seconds: Vec::with_capacity(num_rows),
+        }
+    }
+}
+
+impl MessageParser for ClockMessageParser {
+    fn append(&mut self, _ctx: &mut ParserContext, msg: &mcap::Message<'_>) -> anyhow::Result<()> {
+        let rosgraph_msgs::Clock { clock } =
+            cdr::try_decode_message::<rosgraph_msgs::Clock>(&msg.data)?;
+        self.seconds.push(clock.as_nanos() as f64 * 1e-9);
+        Ok(())
+    }
+
+    fn finalize(self: Box<Self>, ctx: ParserContext) -> anyhow::Result<Vec<Chunk>> {
+        let Self { seconds } = *self;
+
+        let (entity_path, timelines) = ctx.into_parts();
+
+        let scalars = Scalars::update_fields()
+            .with_scalars(seconds)
+            .columns_of_unit_batches()?
+            .collect();
+
+        let chunk = Chunk::from_auto_row_ids(ChunkId::new(), entity_path, timelines, scalars)?;
+
+        Ok(vec![chunk])
+    }
+}
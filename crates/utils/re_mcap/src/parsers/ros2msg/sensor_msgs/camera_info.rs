@@ -4,11 +4,11 @@ use arrow::{
     datatypes::Field,
 };
 use re_chunk::{
-    Chunk, ChunkId,
+    Chunk, ChunkComponents, ChunkId,
     external::arrow::array::{FixedSizeListBuilder, Float64Builder, StringBuilder, UInt32Builder},
 };
 use re_log_types::TimeCell;
-use re_types::{ComponentDescriptor, archetypes::Pinhole};
+use re_types::{ComponentDescriptor, archetypes::Pinhole, components::DistortionModel};
 
 use crate::{
     Error,
@@ -17,6 +17,8 @@ use crate::{
         decode::{MessageParser, ParserContext},
         util::fixed_size_list_builder,
     },
+    rectification::CameraCalibration,
+    util::for_each_channel_message,
 };
 
 /// Plugin that parses `sensor_msgs/msg/CameraInfo` messages.
@@ -37,12 +39,19 @@ pub struct CameraInfoMessageParser {
     frame_ids: FixedSizeListBuilder<StringBuilder>,
     image_from_cameras: Vec<[f32; 9]>,
     resolutions: Vec<(f32, f32)>,
+    pinhole_distortion_models: Vec<DistortionModel>,
+    pinhole_distortion_coefficients: Vec<Vec<f32>>,
+
+    /// Whether the images of the camera are rectified at ingest, see [`crate::rectification`].
+    is_rectified: bool,
 }
 
 impl CameraInfoMessageParser {
     const ARCHETYPE_NAME: &str = "sensor_msgs.msg.CameraInfo";
 
-    pub fn new(num_rows: usize) -> Self {
+    /// If the images of the camera are rectified at ingest (`is_rectified`), the pinhole is
+    /// logged with the intrinsics of the rectified images, and without distortion.
+    pub fn new(num_rows: usize, is_rectified: bool) -> Self {
         Self {
             distortion_models: fixed_size_list_builder(1, num_rows),
             k_matrices: fixed_size_list_builder(9, num_rows),
@@ -76,6 +85,9 @@ impl CameraInfoMessageParser {
             frame_ids: fixed_size_list_builder(1, num_rows),
             image_from_cameras: Vec::with_capacity(num_rows),
             resolutions: Vec::with_capacity(num_rows),
+            pinhole_distortion_models: Vec::with_capacity(num_rows),
+            pinhole_distortion_coefficients: Vec::with_capacity(num_rows),
+            is_rectified,
         }
     }
 }
@@ -97,10 +109,9 @@ impl MessageParser for CameraInfoMessageParser {
         } = cdr::try_decode_message::<sensor_msgs::CameraInfo>(&msg.data)?;
 
         // add the sensor timestamp to the context, `log_time` and `publish_time` are added automatically
-        ctx.add_time_cell(
-            "timestamp",
-            TimeCell::from_timestamp_nanos_since_epoch(header.stamp.as_nanos()),
-        );
+        ctx.add_timestamp(TimeCell::from_timestamp_nanos_since_epoch(
+            header.stamp.as_nanos(),
+        ));
 
         self.distortion_models
             .values()
@@ -115,6 +126,14 @@ impl MessageParser for CameraInfoMessageParser {
         self.p_matrices.values().append_slice(&p);
         self.p_matrices.append(true);
 
+        // Unknown distortion models are logged without any coefficients, i.e. as ideal pinholes.
+        let (model, coefficients) = match pinhole_distortion_model(&distortion_model) {
+            Some(model) if !self.is_rectified => (model, d.iter().map(|&x| x as f32).collect()),
+            _ => (DistortionModel::default(), Vec::new()),
+        };
+        self.pinhole_distortion_models.push(model);
+        self.pinhole_distortion_coefficients.push(coefficients);
+
         self.d_coefficients.push(d);
 
         self.widths.values().append_value(width);
@@ -162,9 +181,15 @@ impl MessageParser for CameraInfoMessageParser {
         struct_builder.append(true);
         self.rois.append(true);
 
-        // TODO(#2315): Rerun currently only supports the pinhole model (`plumb_bob` in ROS2)
-        // so this does NOT take into account the camera model.
-        self.image_from_cameras.push(k.map(|x| x as f32));
+        // `K` and `P` are row-major, `Pinhole` expects column-major matrices.
+        let intrinsics = if self.is_rectified {
+            [p[0], p[1], p[2], p[4], p[5], p[6], p[8], p[9], p[10]]
+        } else {
+            k
+        };
+        let [m00, m01, m02, m10, m11, m12, m20, m21, m22] = intrinsics.map(|x| x as f32);
+        self.image_from_cameras
+            .push([m00, m10, m20, m01, m11, m21, m02, m12, m22]);
         self.resolutions.push((width as f32, height as f32));
 
         Ok(())
@@ -185,10 +210,12 @@ impl MessageParser for CameraInfoMessageParser {
             mut rois,
             image_from_cameras,
             resolutions,
+            pinhole_distortion_models,
+            pinhole_distortion_coefficients,
+            is_rectified: _,
         } = *self;
 
-        let entity_path = ctx.entity_path().clone();
-        let timelines = ctx.build_timelines();
+        let (entity_path, timelines) = ctx.into_parts();
 
         let d_array = {
             let mut list_builder = arrow::array::ListBuilder::new(Float64Builder::new());
@@ -199,78 +226,131 @@ impl MessageParser for CameraInfoMessageParser {
             list_builder.finish()
         };
 
-        let chunk = Chunk::from_auto_row_ids(
-            ChunkId::new(),
-            entity_path.clone(),
-            timelines.clone(),
-            [
-                (
-                    ComponentDescriptor::partial("distortion_model")
-                        .with_archetype(Self::ARCHETYPE_NAME.into()),
-                    distortion_models.finish().into(),
-                ),
-                (
-                    ComponentDescriptor::partial("k").with_archetype(Self::ARCHETYPE_NAME.into()),
-                    k_matrices.finish().into(),
-                ),
-                (
-                    ComponentDescriptor::partial("width")
-                        .with_archetype(Self::ARCHETYPE_NAME.into()),
-                    widths.finish().into(),
-                ),
-                (
-                    ComponentDescriptor::partial("height")
-                        .with_archetype(Self::ARCHETYPE_NAME.into()),
-                    heights.finish().into(),
-                ),
-                (
-                    ComponentDescriptor::partial("d").with_archetype(Self::ARCHETYPE_NAME.into()),
-                    d_array,
-                ),
-                (
-                    ComponentDescriptor::partial("r").with_archetype(Self::ARCHETYPE_NAME.into()),
-                    r_matrices.finish().into(),
-                ),
-                (
-                    ComponentDescriptor::partial("p").with_archetype(Self::ARCHETYPE_NAME.into()),
-                    p_matrices.finish().into(),
-                ),
-                (
-                    ComponentDescriptor::partial("binning_x")
-                        .with_archetype(Self::ARCHETYPE_NAME.into()),
-                    binning_x.finish().into(),
-                ),
-                (
-                    ComponentDescriptor::partial("binning_y")
-                        .with_archetype(Self::ARCHETYPE_NAME.into()),
-                    binning_y.finish().into(),
-                ),
-                (
-                    ComponentDescriptor::partial("roi").with_archetype(Self::ARCHETYPE_NAME.into()),
-                    rois.finish().into(),
-                ),
-                (
-                    ComponentDescriptor::partial("frame_id")
-                        .with_archetype(Self::ARCHETYPE_NAME.into()),
-                    frame_ids.finish().into(),
-                ),
-            ]
-            .into_iter()
-            .collect(),
-        )?;
-
-        let pinhole_chunk = Chunk::from_auto_row_ids(
-            ChunkId::new(),
-            entity_path.clone(),
-            timelines.clone(),
+        let mut components: ChunkComponents = [
+            (
+                ComponentDescriptor::partial("distortion_model")
+                    .with_archetype(Self::ARCHETYPE_NAME.into()),
+                distortion_models.finish().into(),
+            ),
+            (
+                ComponentDescriptor::partial("k").with_archetype(Self::ARCHETYPE_NAME.into()),
+                k_matrices.finish().into(),
+            ),
+            (
+                ComponentDescriptor::partial("width").with_archetype(Self::ARCHETYPE_NAME.into()),
+                widths.finish().into(),
+            ),
+            (
+                ComponentDescriptor::partial("height").with_archetype(Self::ARCHETYPE_NAME.into()),
+                heights.finish().into(),
+            ),
+            (
+                ComponentDescriptor::partial("d").with_archetype(Self::ARCHETYPE_NAME.into()),
+                d_array,
+            ),
+            (
+                ComponentDescriptor::partial("r").with_archetype(Self::ARCHETYPE_NAME.into()),
+                r_matrices.finish().into(),
+            ),
+            (
+                ComponentDescriptor::partial("p").with_archetype(Self::ARCHETYPE_NAME.into()),
+                p_matrices.finish().into(),
+            ),
+            (
+                ComponentDescriptor::partial("binning_x")
+                    .with_archetype(Self::ARCHETYPE_NAME.into()),
+                binning_x.finish().into(),
+            ),
+            (
+                ComponentDescriptor::partial("binning_y")
+                    .with_archetype(Self::ARCHETYPE_NAME.into()),
+                binning_y.finish().into(),
+            ),
+            (
+                ComponentDescriptor::partial("roi").with_archetype(Self::ARCHETYPE_NAME.into()),
+                rois.finish().into(),
+            ),
+            (
+                ComponentDescriptor::partial("frame_id")
+                    .with_archetype(Self::ARCHETYPE_NAME.into()),
+                frame_ids.finish().into(),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        // The number of distortion coefficients differs per distortion model,
+        // so they can't be partitioned into unit batches like the other components.
+        let distortion_coefficient_lengths = pinhole_distortion_coefficients
+            .iter()
+            .map(Vec::len)
+            .collect::<Vec<_>>();
+        let distortion_coefficient_columns = Pinhole::update_fields()
+            .with_distortion_coefficients(pinhole_distortion_coefficients.into_iter().flatten())
+            .columns(distortion_coefficient_lengths)
+            .map_err(|err| Error::Other(anyhow::anyhow!(err)))?;
+
+        // The pinhole is logged in the same rows as the raw message, as they share entity and times.
+        components.extend(
             Pinhole::update_fields()
                 .with_many_image_from_camera(image_from_cameras)
                 .with_many_resolution(resolutions)
+                .with_many_distortion_model(pinhole_distortion_models)
                 .columns_of_unit_batches()
                 .map_err(|err| Error::Other(anyhow::anyhow!(err)))?
-                .collect(),
-        )?;
+                .chain(distortion_coefficient_columns)
+                .map(|column| (column.descriptor, column.list_array)),
+        );
+
+        let chunk = Chunk::from_auto_row_ids(ChunkId::new(), entity_path, timelines, components)?;
 
-        Ok(vec![chunk, pinhole_chunk])
+        Ok(vec![chunk])
     }
 }
+
+/// Maps a ROS `distortion_model` to the matching [`DistortionModel`], if Rerun supports it.
+fn pinhole_distortion_model(distortion_model: &str) -> Option<DistortionModel> {
+    match distortion_model {
+        // `rational_polynomial` is `plumb_bob` with three additional radial coefficients.
+        "plumb_bob" | "rational_polynomial" => Some(DistortionModel::PlumbBob),
+        "equidistant" => Some(DistortionModel::Fisheye),
+        _ => None,
+    }
+}
+
+/// Reads the calibration of a camera from the first message on its `topic` of `CameraInfo`.
+pub fn read_camera_calibration(
+    mcap_bytes: &[u8],
+    summary: &mcap::Summary,
+    topic: &str,
+) -> Result<CameraCalibration, Error> {
+    re_tracing::profile_function!();
+
+    let Some(channel) = summary
+        .channels
+        .values()
+        .find(|channel| channel.topic == topic)
+    else {
+        return Err(anyhow::anyhow!("MCAP file has no camera info topic {topic:?}").into());
+    };
+
+    let mut calibration = None;
+    for_each_channel_message(mcap_bytes, summary, channel.id, |msg| {
+        if calibration.is_none() {
+            let camera_info = cdr::try_decode_message::<sensor_msgs::CameraInfo>(&msg.data)
+                .map_err(|err| Error::Other(anyhow::anyhow!(err)))?;
+            calibration = Some(CameraCalibration {
+                width: camera_info.width,
+                height: camera_info.height,
+                distortion_model: camera_info.distortion_model,
+                d: camera_info.d,
+                k: camera_info.k,
+                r: camera_info.r,
+                p: camera_info.p,
+            });
+        }
+        Ok(())
+    })?;
+
+    calibration.ok_or_else(|| anyhow::anyhow!("Camera info topic {topic:?} is empty").into())
+}
@@ -1,6 +1,7 @@
 use super::super::definitions::sensor_msgs;
+use arrow::buffer::Buffer;
 use re_chunk::{
-    Chunk, ChunkId, RowId, TimePoint,
+    Chunk, ChunkComponents, ChunkId, RowId, TimePoint,
     external::arrow::array::{FixedSizeListBuilder, StringBuilder},
 };
 use re_log_types::TimeCell;
@@ -13,15 +14,17 @@ use re_types::{
 use crate::parsers::{
     cdr,
     decode::{MessageParser, ParserContext},
+    pool,
 };
 
 /// Plugin that parses `sensor_msgs/msg/CompressedImage` messages.
 pub struct CompressedImageMessageParser {
     /// The raw image data blobs.
     ///
-    /// Note: These blobs are directly moved into a `Blob`, without copying.
-    blobs: Vec<Vec<u8>>,
+    /// Note: These are slices of the message payloads, see [`ParserContext::slice_message_data`].
+    blobs: Vec<Buffer>,
     formats: FixedSizeListBuilder<StringBuilder>,
+    frame_ids: FixedSizeListBuilder<StringBuilder>,
     is_h264: bool,
 }
 
@@ -32,6 +35,7 @@ impl CompressedImageMessageParser {
         Self {
             blobs: Vec::with_capacity(num_rows),
             formats: FixedSizeListBuilder::with_capacity(StringBuilder::new(), 1, num_rows),
+            frame_ids: FixedSizeListBuilder::with_capacity(StringBuilder::new(), 1, num_rows),
             is_h264: false,
         }
     }
@@ -47,12 +51,11 @@ impl MessageParser for CompressedImageMessageParser {
         } = cdr::try_decode_message::<sensor_msgs::CompressedImage<'_>>(&msg.data)?;
 
         // add the sensor timestamp to the context, `log_time` and `publish_time` are added automatically
-        ctx.add_time_cell(
-            "timestamp",
-            TimeCell::from_timestamp_nanos_since_epoch(header.stamp.as_nanos()),
-        );
+        ctx.add_timestamp(TimeCell::from_timestamp_nanos_since_epoch(
+            header.stamp.as_nanos(),
+        ));
 
-        self.blobs.push(data.into_owned());
+        self.blobs.push(ctx.slice_message_data(&data));
 
         if format.eq_ignore_ascii_case("h264") {
             // If the format for this topic is h264 once, we assume it is h264 for all messages.
@@ -62,6 +65,9 @@ impl MessageParser for CompressedImageMessageParser {
         self.formats.values().append_value(format.as_str());
         self.formats.append(true);
 
+        self.frame_ids.values().append_value(&header.frame_id);
+        self.frame_ids.append(true);
+
         Ok(())
     }
 
@@ -70,54 +76,56 @@ impl MessageParser for CompressedImageMessageParser {
         let Self {
             blobs,
             mut formats,
+            mut frame_ids,
             is_h264,
         } = *self;
 
-        let entity_path = ctx.entity_path().clone();
-        let timelines = ctx.build_timelines();
+        let (entity_path, timelines) = ctx.into_parts();
 
-        let components = if is_h264 {
+        let mut components: ChunkComponents = if is_h264 {
             VideoStream::update_fields()
-                .with_many_sample(blobs)
+                .with_many_sample(blobs.iter().cloned())
                 .columns_of_unit_batches()?
                 .collect()
         } else {
             EncodedImage::update_fields()
-                .with_many_blob(blobs)
+                .with_many_blob(blobs.iter().cloned())
                 .columns_of_unit_batches()?
                 .collect()
         };
 
-        let chunk = Chunk::from_auto_row_ids(
-            ChunkId::new(),
-            entity_path.clone(),
-            timelines.clone(),
-            components,
-        )?;
-
-        let meta_chunk = Chunk::from_auto_row_ids(
-            ChunkId::new(),
-            entity_path.clone(),
-            timelines,
-            std::iter::once((
+        // The blobs were copied when serializing them, so the buffers can be reused.
+        blobs.into_iter().for_each(pool::recycle);
+
+        // The message metadata is logged in the same rows as the images,
+        // so that it stays associated with the individual frames.
+        components.extend([
+            (
                 ComponentDescriptor::partial("format").with_archetype(Self::ARCHETYPE_NAME.into()),
                 formats.finish().into(),
-            ))
-            .collect(),
-        )?;
-
-        if is_h264 {
-            // codec should be logged once per entity, as static data.
-            let codec_chunk = Chunk::builder(entity_path.clone())
-                .with_archetype(
-                    RowId::new(),
-                    TimePoint::default(),
-                    &VideoStream::update_fields().with_codec(VideoCodec::H264),
-                )
-                .build()?;
-            Ok(vec![chunk, meta_chunk, codec_chunk])
-        } else {
-            Ok(vec![chunk, meta_chunk])
-        }
+            ),
+            (
+                ComponentDescriptor::partial("frame_id")
+                    .with_archetype(Self::ARCHETYPE_NAME.into()),
+                frame_ids.finish().into(),
+            ),
+        ]);
+
+        // codec should be logged once per entity, as static data.
+        let codec_chunk = is_h264
+            .then(|| {
+                Chunk::builder(entity_path.clone())
+                    .with_archetype(
+                        RowId::new(),
+                        TimePoint::default(),
+                        &VideoStream::update_fields().with_codec(VideoCodec::H264),
+                    )
+                    .build()
+            })
+            .transpose()?;
+
+        let chunk = Chunk::from_auto_row_ids(ChunkId::new(), entity_path, timelines, components)?;
+
+        Ok(std::iter::once(chunk).chain(codec_chunk).collect())
     }
 }
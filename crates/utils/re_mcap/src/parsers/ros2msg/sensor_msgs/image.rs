@@ -1,4 +1,7 @@
+use std::sync::Arc;
+
 use super::super::definitions::sensor_msgs;
+use arrow::buffer::Buffer;
 use re_chunk::{Chunk, ChunkId};
 use re_log_types::TimeCell;
 use re_types::{
@@ -6,9 +9,13 @@ use re_types::{
     datatypes::{ChannelDatatype, ColorModel, ImageFormat, PixelFormat},
 };
 
-use crate::parsers::{
-    cdr,
-    decode::{MessageParser, ParserContext},
+use crate::{
+    parsers::{
+        cdr,
+        decode::{MessageParser, ParserContext},
+        pool,
+    },
+    rectification::Rectification,
 };
 
 /// Plugin that parses `sensor_msgs/msg/CompressedImage` messages.
@@ -18,20 +25,53 @@ pub struct ImageSchemaPlugin;
 pub struct ImageMessageParser {
     /// The raw image data blobs.
     ///
-    /// Note: These blobs are directly moved into a `Blob`, without copying.
-    blobs: Vec<Vec<u8>>,
+    /// Note: These are slices of the message payloads, see [`ParserContext::slice_message_data`].
+    blobs: Vec<Buffer>,
     image_formats: Vec<ImageFormat>,
     is_depth_image: bool,
+
+    /// Rectifies the images with the calibration of their camera, if set.
+    rectification: Option<Arc<Rectification>>,
 }
 
 impl ImageMessageParser {
-    pub fn new(num_rows: usize) -> Self {
+    pub fn new(num_rows: usize, rectification: Option<Arc<Rectification>>) -> Self {
         Self {
             blobs: Vec::with_capacity(num_rows),
             image_formats: Vec::with_capacity(num_rows),
             is_depth_image: false,
+            rectification,
         }
     }
+
+    /// Rectifies `data`, or returns `None` if the image cannot be rectified.
+    fn rectify(
+        rectification: &Rectification,
+        data: &[u8],
+        step: u32,
+        img_format: &ImageFormat,
+    ) -> Option<Vec<u8>> {
+        if img_format.pixel_format.is_some() {
+            // Chroma subsampled pixels cannot be resampled individually.
+            re_log::warn_once!("Images with a pixel format are not rectified");
+            return None;
+        }
+
+        let rectified = rectification.rectify(
+            data,
+            step as usize,
+            img_format.color_model().num_channels(),
+            img_format.datatype().bits() / 8,
+            // Interpolating depths would make up points between objects.
+            img_format.color_model.is_some(),
+        );
+        if rectified.is_none() {
+            re_log::warn_once!(
+                "Images whose size differs from their camera info are not rectified"
+            );
+        }
+        rectified
+    }
 }
 
 impl MessageParser for ImageMessageParser {
@@ -50,10 +90,9 @@ impl MessageParser for ImageMessageParser {
         } = cdr::try_decode_message::<sensor_msgs::Image<'_>>(&msg.data)?;
 
         // add the sensor timestamp to the context, `log_time` and `publish_time` are added automatically
-        ctx.add_time_cell(
-            "timestamp",
-            TimeCell::from_timestamp_nanos_since_epoch(header.stamp.as_nanos()),
-        );
+        ctx.add_timestamp(TimeCell::from_timestamp_nanos_since_epoch(
+            header.stamp.as_nanos(),
+        ));
 
         let dimensions = [width, height];
         let img_format = decode_image_format(&encoding, dimensions)?;
@@ -62,7 +101,14 @@ impl MessageParser for ImageMessageParser {
         // `color_model` is `None` for formats created with `ImageFormat::depth`
         self.is_depth_image = img_format.color_model.is_none();
 
-        self.blobs.push(data.into_owned());
+        let rectified = self
+            .rectification
+            .as_ref()
+            .and_then(|rectification| Self::rectify(rectification, &data, step, &img_format));
+        self.blobs.push(match rectified {
+            Some(rectified) => Buffer::from_vec(rectified),
+            None => ctx.slice_message_data(&data),
+        });
         self.image_formats.push(img_format);
 
         Ok(())
@@ -74,25 +120,28 @@ impl MessageParser for ImageMessageParser {
             blobs,
             image_formats,
             is_depth_image,
+            rectification: _,
         } = *self;
 
-        let entity_path = ctx.entity_path().clone();
-        let timelines = ctx.build_timelines();
+        let (entity_path, timelines) = ctx.into_parts();
 
         let images = if is_depth_image {
             DepthImage::update_fields()
-                .with_many_buffer(blobs)
+                .with_many_buffer(blobs.iter().cloned())
                 .with_many_format(image_formats)
                 .columns_of_unit_batches()?
                 .collect()
         } else {
             Image::update_fields()
-                .with_many_buffer(blobs)
+                .with_many_buffer(blobs.iter().cloned())
                 .with_many_format(image_formats)
                 .columns_of_unit_batches()?
                 .collect()
         };
 
+        // The blobs were copied when serializing them, so the buffers can be reused.
+        blobs.into_iter().for_each(pool::recycle);
+
         let chunk = Chunk::from_auto_row_ids(ChunkId::new(), entity_path, timelines, images)?;
 
         Ok(vec![chunk])
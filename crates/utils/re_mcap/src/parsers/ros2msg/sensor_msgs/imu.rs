@@ -11,20 +11,13 @@ use re_types::{
 
 use crate::{
     Error,
-    parsers::{MessageParser, ParserContext, cdr},
+    parsers::{MessageParser, ParserContext, cdr, util::fixed_size_list_builder},
 };
 
 /// Plugin that parses `sensor_msgs/msg/Imu` messages.
 #[derive(Default)]
 pub struct ImuSchemaPlugin;
 
-fn fixed_size_list_builder(
-    value_length: i32,
-    capacity: usize,
-) -> FixedSizeListBuilder<Float64Builder> {
-    FixedSizeListBuilder::with_capacity(Float64Builder::new(), value_length, capacity)
-}
-
 pub struct ImuMessageParser {
     orientation: FixedSizeListBuilder<Float64Builder>,
     sensor_readings: FixedSizeListBuilder<Float64Builder>,
@@ -62,6 +55,12 @@ impl ImuMessageParser {
                     "accelerometer/z",
                 ]),
             )
+            .with_archetype(
+                RowId::new(),
+                TimePoint::default(),
+                &Scalars::update_fields()
+                    .with_units(["rad/s", "rad/s", "rad/s", "m/s²", "m/s²", "m/s²"]),
+            )
             .build()
     }
 }
@@ -72,10 +71,9 @@ impl MessageParser for ImuMessageParser {
             .map_err(|err| Error::Other(anyhow::anyhow!(err)))?;
 
         // add the sensor timestamp to the context, `log_time` and `publish_time` are added automatically
-        ctx.add_time_cell(
-            "timestamp",
-            TimeCell::from_timestamp_nanos_since_epoch(imu.header.stamp.as_nanos()),
-        );
+        ctx.add_timestamp(TimeCell::from_timestamp_nanos_since_epoch(
+            imu.header.stamp.as_nanos(),
+        ));
 
         self.orientation.values().append_slice(&[
             imu.orientation.x,
@@ -113,8 +111,7 @@ impl MessageParser for ImuMessageParser {
     }
 
     fn finalize(self: Box<Self>, ctx: ParserContext) -> anyhow::Result<Vec<Chunk>> {
-        let entity_path = ctx.entity_path().clone();
-        let timelines = ctx.build_timelines();
+        let (entity_path, timelines) = ctx.into_parts();
         let meta_chunk = Self::metadata_chunk(entity_path.clone())?;
 
         let Self {
@@ -127,7 +124,7 @@ impl MessageParser for ImuMessageParser {
 
         let data_chunk = Chunk::from_auto_row_ids(
             ChunkId::new(),
-            entity_path.clone(),
+            entity_path,
             timelines,
             [
                 (
@@ -46,10 +46,9 @@ impl MessageParser for JointStateMessageParser {
             .map_err(|err| Error::Other(anyhow::anyhow!(err)))?;
 
         // add the sensor timestamp to the context, `log_time` and `publish_time` are added automatically
-        ctx.add_time_cell(
-            "timestamp",
-            TimeCell::from_timestamp_nanos_since_epoch(header.stamp.as_nanos()),
-        );
+        ctx.add_timestamp(TimeCell::from_timestamp_nanos_since_epoch(
+            header.stamp.as_nanos(),
+        ));
 
         for name in &name {
             self.joint_names.values().append_value(name);
@@ -69,8 +68,7 @@ impl MessageParser for JointStateMessageParser {
     }
 
     fn finalize(self: Box<Self>, ctx: ParserContext) -> anyhow::Result<Vec<Chunk>> {
-        let entity_path = ctx.entity_path().clone();
-        let timelines = ctx.build_timelines();
+        let (entity_path, timelines) = ctx.into_parts();
 
         let Self {
             mut joint_names,
@@ -83,7 +81,7 @@ impl MessageParser for JointStateMessageParser {
 
         let positions_chunk = Chunk::from_auto_row_ids(
             ChunkId::new(),
-            entity_path.clone() / "position",
+            &entity_path / "position",
             timelines.clone(),
             [
                 (Scalars::descriptor_scalars(), positions.finish()),
@@ -95,7 +93,7 @@ impl MessageParser for JointStateMessageParser {
 
         let velocities_chunk = Chunk::from_auto_row_ids(
             ChunkId::new(),
-            entity_path.clone() / "velocity",
+            &entity_path / "velocity",
             timelines.clone(),
             [
                 (Scalars::descriptor_scalars(), velocities.finish()),
@@ -107,7 +105,7 @@ impl MessageParser for JointStateMessageParser {
 
         let efforts_chunk = Chunk::from_auto_row_ids(
             ChunkId::new(),
-            entity_path.clone() / "effort",
+            &entity_path / "effort",
             timelines,
             [
                 (Scalars::descriptor_scalars(), efforts.finish()),
@@ -1,29 +1,31 @@
 use std::io::Cursor;
+use std::sync::Arc;
 
 use super::super::definitions::sensor_msgs::{self, PointField, PointFieldDatatype};
 use arrow::{
     array::{
-        BooleanBuilder, FixedSizeListBuilder, ListBuilder, StringBuilder, StructBuilder,
-        UInt8Builder, UInt32Builder,
+        ArrayRef, BooleanBuilder, FixedSizeListBuilder, Float32Array, Float64Array, ListBuilder,
+        StringBuilder, StructBuilder, UInt8Builder, UInt32Array, UInt32Builder,
     },
     datatypes::{DataType, Field, Fields},
 };
 use byteorder::{BigEndian, LittleEndian, ReadBytesExt as _};
-use re_chunk::{Chunk, ChunkComponents, ChunkId, TimePoint};
+use re_chunk::{Chunk, ChunkId, TimePoint};
 use re_log_types::TimeCell;
 use re_types::{
-    AsComponents as _, Component as _, ComponentDescriptor, SerializedComponentColumn, archetypes,
+    AsComponents as _, Component as _, ComponentDescriptor, SerializedComponentBatch, archetypes,
     components,
 };
-use std::collections::HashMap;
 
 use crate::{
     Error,
+    deskew::{Trajectory, point_times_ns},
     parsers::{
         cdr,
         decode::{MessageParser, ParserContext},
         util::{blob_list_builder, fixed_size_list_builder},
     },
+    point_cloud::{PointCloudFieldMapping, PointFieldSemantic, point_cloud_chunks},
 };
 
 pub struct PointCloud2MessageParser {
@@ -38,15 +40,25 @@ pub struct PointCloud2MessageParser {
     data: FixedSizeListBuilder<ListBuilder<UInt8Builder>>,
     is_dense: FixedSizeListBuilder<BooleanBuilder>,
 
-    // We lazily create this, only if we can interpret the point cloud semantically.
-    // For now, this is the case if there are fields with names `x`,`y`, and `z` present.
-    points_3ds: Option<Vec<archetypes::Points3D>>,
+    field_mapping: PointCloudFieldMapping,
+    trajectory: Option<Arc<Trajectory>>,
+
+    /// The semantic interpretation of each point cloud, along with its row.
+    ///
+    /// This is only possible if there are fields with names `x`,`y`, and `z` present.
+    /// Other fields are interpreted according to the [`PointCloudFieldMapping`].
+    points: Vec<(usize, Vec<SerializedComponentBatch>)>,
+    next_row: usize,
 }
 
 impl PointCloud2MessageParser {
     const ARCHETYPE_NAME: &str = "sensor_msgs.msg.PointCloud2";
 
-    pub fn new(num_rows: usize) -> Self {
+    pub fn new(
+        num_rows: usize,
+        field_mapping: PointCloudFieldMapping,
+        trajectory: Option<Arc<Trajectory>>,
+    ) -> Self {
         let fields = FixedSizeListBuilder::with_capacity(
             ListBuilder::new(StructBuilder::new(
                 Fields::from(vec![
@@ -78,32 +90,79 @@ impl PointCloud2MessageParser {
             data: blob_list_builder(num_rows),
             is_dense: fixed_size_list_builder(1, num_rows),
 
-            points_3ds: None,
+            field_mapping,
+            trajectory,
+
+            points: Vec::new(),
+            next_row: 0,
         }
     }
 }
 
-fn access(data: &[u8], datatype: PointFieldDatatype, is_big_endian: bool) -> std::io::Result<f32> {
+fn access(data: &[u8], datatype: PointFieldDatatype, is_big_endian: bool) -> std::io::Result<f64> {
     let mut rdr = Cursor::new(data);
     match (is_big_endian, datatype) {
-        (_, PointFieldDatatype::Unknown) => Ok(0f32), // Not in the original spec.
-        (_, PointFieldDatatype::UInt8) => rdr.read_u8().map(|x| x as f32),
-        (_, PointFieldDatatype::Int8) => rdr.read_i8().map(|x| x as f32),
-        (true, PointFieldDatatype::Int16) => rdr.read_i16::<BigEndian>().map(|x| x as f32),
-        (true, PointFieldDatatype::UInt16) => rdr.read_u16::<BigEndian>().map(|x| x as f32),
-        (true, PointFieldDatatype::Int32) => rdr.read_i32::<BigEndian>().map(|x| x as f32),
-        (true, PointFieldDatatype::UInt32) => rdr.read_u32::<BigEndian>().map(|x| x as f32),
-        (true, PointFieldDatatype::Float32) => rdr.read_f32::<BigEndian>(),
-        (true, PointFieldDatatype::Float64) => rdr.read_f64::<BigEndian>().map(|x| x as f32),
-        (false, PointFieldDatatype::Int16) => rdr.read_i16::<LittleEndian>().map(|x| x as f32),
-        (false, PointFieldDatatype::UInt16) => rdr.read_u16::<LittleEndian>().map(|x| x as f32),
-        (false, PointFieldDatatype::Int32) => rdr.read_i32::<LittleEndian>().map(|x| x as f32),
-        (false, PointFieldDatatype::UInt32) => rdr.read_u32::<LittleEndian>().map(|x| x as f32),
-        (false, PointFieldDatatype::Float32) => rdr.read_f32::<LittleEndian>(),
-        (false, PointFieldDatatype::Float64) => rdr.read_f64::<LittleEndian>().map(|x| x as f32),
+        (_, PointFieldDatatype::Unknown) => Ok(0f64), // Not in the original spec.
+        (_, PointFieldDatatype::UInt8) => rdr.read_u8().map(|x| x as f64),
+        (_, PointFieldDatatype::Int8) => rdr.read_i8().map(|x| x as f64),
+        (true, PointFieldDatatype::Int16) => rdr.read_i16::<BigEndian>().map(|x| x as f64),
+        (true, PointFieldDatatype::UInt16) => rdr.read_u16::<BigEndian>().map(|x| x as f64),
+        (true, PointFieldDatatype::Int32) => rdr.read_i32::<BigEndian>().map(|x| x as f64),
+        (true, PointFieldDatatype::UInt32) => rdr.read_u32::<BigEndian>().map(|x| x as f64),
+        (true, PointFieldDatatype::Float32) => rdr.read_f32::<BigEndian>().map(|x| x as f64),
+        (true, PointFieldDatatype::Float64) => rdr.read_f64::<BigEndian>(),
+        (false, PointFieldDatatype::Int16) => rdr.read_i16::<LittleEndian>().map(|x| x as f64),
+        (false, PointFieldDatatype::UInt16) => rdr.read_u16::<LittleEndian>().map(|x| x as f64),
+        (false, PointFieldDatatype::Int32) => rdr.read_i32::<LittleEndian>().map(|x| x as f64),
+        (false, PointFieldDatatype::UInt32) => rdr.read_u32::<LittleEndian>().map(|x| x as f64),
+        (false, PointFieldDatatype::Float32) => rdr.read_f32::<LittleEndian>().map(|x| x as f64),
+        (false, PointFieldDatatype::Float64) => rdr.read_f64::<LittleEndian>(),
     }
 }
 
+/// Reads the raw bits of a 4-byte field, which is how colors are packed.
+fn access_bits(
+    data: &[u8],
+    datatype: PointFieldDatatype,
+    is_big_endian: bool,
+) -> std::io::Result<u32> {
+    let mut rdr = Cursor::new(data);
+    match (is_big_endian, datatype) {
+        (
+            true,
+            PointFieldDatatype::Float32 | PointFieldDatatype::UInt32 | PointFieldDatatype::Int32,
+        ) => rdr.read_u32::<BigEndian>(),
+        (
+            false,
+            PointFieldDatatype::Float32 | PointFieldDatatype::UInt32 | PointFieldDatatype::Int32,
+        ) => rdr.read_u32::<LittleEndian>(),
+        _ => Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("{datatype:?} cannot hold a packed color"),
+        )),
+    }
+}
+
+/// Unpacks a color packed as `0x00RRGGBB` or `0xAARRGGBB`, see [`PointFieldSemantic::Color`].
+fn unpack_color(bits: u32) -> components::Color {
+    let [a, r, g, b] = bits.to_be_bytes();
+    // `rgb` fields leave the alpha unset.
+    let a = if a == 0 { u8::MAX } else { a };
+    components::Color::from_unmultiplied_rgba(r, g, b, a)
+}
+
+/// Reads a field of all the points, or `None` if any of them cannot be read.
+fn read_field<T>(
+    data: &[u8],
+    step: usize,
+    field: &PointField,
+    read: impl Fn(&[u8]) -> std::io::Result<T>,
+) -> Option<Vec<T>> {
+    data.chunks_exact(step)
+        .map(|point| read(point.get(field.offset as usize..)?).ok())
+        .collect()
+}
+
 pub struct Position3DIter<'a> {
     point_iter: std::slice::ChunksExact<'a, u8>,
     is_big_endian: bool,
@@ -142,9 +201,9 @@ impl<'a> Position3DIter<'a> {
     }
 }
 
-fn unwrap(res: std::io::Result<f32>, component: &str) -> f32 {
+fn unwrap(res: std::io::Result<f64>, component: &str) -> f32 {
     match res {
-        Ok(x) => x,
+        Ok(x) => x as f32,
         Err(err) => {
             debug_assert!(false, "failed to read `{component}`: {err}");
             f32::NAN
@@ -170,13 +229,99 @@ impl Iterator for Position3DIter<'_> {
     }
 }
 
+/// The [`archetypes::Points3D`] of a point cloud, along with its other semantic fields.
+///
+/// If there is a `trajectory` and the points have timestamps, the positions are de-skewed.
+fn point_batches(
+    field_mapping: &PointCloudFieldMapping,
+    trajectory: Option<&Trajectory>,
+    point_cloud: &sensor_msgs::PointCloud2<'_>,
+    positions: Position3DIter<'_>,
+) -> Vec<SerializedComponentBatch> {
+    let data = &point_cloud.data;
+    let step = point_cloud.point_step as usize;
+    let is_big_endian = point_cloud.is_bigendian;
+    let find =
+        |semantic| field_mapping.find(semantic, &point_cloud.fields, |field| field.name.as_str());
+    let values = |semantic| {
+        let field = find(semantic)?;
+        let values = read_field(data, step, field, |point| {
+            access(point, field.datatype, is_big_endian)
+        });
+        if values.is_none() {
+            re_log::warn_once!("Cannot read {semantic} from point field {:?}", field.name);
+        }
+        Some((field, values?))
+    };
+
+    let mut positions: Vec<[f32; 3]> = positions.collect();
+    let timestamps = values(PointFieldSemantic::Timestamp);
+    if let Some(trajectory) = trajectory
+        && let Some((field, timestamps)) = &timestamps
+    {
+        let stamp_ns = point_cloud.header.stamp.as_nanos();
+        let is_floating_point = matches!(
+            field.datatype,
+            PointFieldDatatype::Float32 | PointFieldDatatype::Float64
+        );
+        let times_ns = point_times_ns(stamp_ns, is_floating_point, timestamps);
+        if !trajectory.deskew(stamp_ns, &mut positions, times_ns) {
+            re_log::warn_once!(
+                "Cannot de-skew point clouds outside of the time range of the trajectory"
+            );
+        }
+    }
+
+    let mut points = archetypes::Points3D::new(positions);
+    if let Some(field) = find(PointFieldSemantic::Color) {
+        match read_field(data, step, field, |point| {
+            access_bits(point, field.datatype, is_big_endian)
+        }) {
+            Some(colors) => points = points.with_colors(colors.into_iter().map(unpack_color)),
+            None => re_log::warn_once!("Cannot read colors from point field {:?}", field.name),
+        }
+    }
+
+    let mut batches = points.as_serialized_batches();
+    for (semantic, values) in [
+        (
+            PointFieldSemantic::Intensity,
+            values(PointFieldSemantic::Intensity),
+        ),
+        (PointFieldSemantic::Timestamp, timestamps),
+        (PointFieldSemantic::Ring, values(PointFieldSemantic::Ring)),
+    ] {
+        let Some((_, values)) = values else {
+            continue;
+        };
+
+        let array: ArrayRef = match semantic {
+            PointFieldSemantic::Intensity => Arc::new(Float32Array::from_iter_values(
+                values.into_iter().map(|value| value as f32),
+            )),
+            PointFieldSemantic::Timestamp => Arc::new(Float64Array::from(values)),
+            PointFieldSemantic::Ring | PointFieldSemantic::Color => Arc::new(
+                UInt32Array::from_iter_values(values.into_iter().map(|value| value as u32)),
+            ),
+        };
+        batches.push(SerializedComponentBatch::new(
+            array,
+            ComponentDescriptor::partial(semantic.name())
+                .with_archetype(PointCloud2MessageParser::ARCHETYPE_NAME.into()),
+        ));
+    }
+
+    batches
+}
+
 impl MessageParser for PointCloud2MessageParser {
     fn append(&mut self, ctx: &mut ParserContext, msg: &mcap::Message<'_>) -> anyhow::Result<()> {
-        let point_cloud = cdr::try_decode_message::<sensor_msgs::PointCloud2>(msg.data.as_ref())
-            .map_err(|err| Error::Other(anyhow::anyhow!(err)))?;
+        let point_cloud =
+            cdr::try_decode_message::<sensor_msgs::PointCloud2<'_>>(msg.data.as_ref())
+                .map_err(|err| Error::Other(anyhow::anyhow!(err)))?;
 
         let cell = TimeCell::from_timestamp_nanos_since_epoch(point_cloud.header.stamp.as_nanos());
-        ctx.add_time_cell("timestamp", cell);
+        ctx.add_timestamp(cell);
 
         let Self {
             num_rows,
@@ -190,9 +335,16 @@ impl MessageParser for PointCloud2MessageParser {
             data,
             is_dense,
 
-            points_3ds,
+            field_mapping,
+            trajectory,
+
+            points,
+            next_row,
         } = self;
 
+        let row = *next_row;
+        *next_row += 1;
+
         let mut timepoint = TimePoint::default();
         timepoint.insert_cell("timestamp", cell);
 
@@ -207,9 +359,16 @@ impl MessageParser for PointCloud2MessageParser {
         );
 
         if let Some(position_iter) = position_iter {
-            points_3ds
-                .get_or_insert_with(|| Vec::with_capacity(*num_rows))
-                .push(archetypes::Points3D::new(position_iter));
+            points.reserve(*num_rows);
+            points.push((
+                row,
+                point_batches(
+                    field_mapping,
+                    trajectory.as_deref(),
+                    &point_cloud,
+                    position_iter,
+                ),
+            ));
         }
 
         {
@@ -274,8 +433,7 @@ impl MessageParser for PointCloud2MessageParser {
     }
 
     fn finalize(self: Box<Self>, ctx: ParserContext) -> anyhow::Result<Vec<re_chunk::Chunk>> {
-        let entity_path = ctx.entity_path().clone();
-        let timelines = ctx.build_timelines();
+        let (entity_path, timelines) = ctx.into_parts();
 
         let Self {
             num_rows: _,
@@ -289,36 +447,18 @@ impl MessageParser for PointCloud2MessageParser {
             mut data,
             mut is_dense,
 
-            points_3ds,
-        } = *self;
-
-        let mut chunks = Vec::new();
+            field_mapping: _,
+            trajectory: _,
 
-        for (i, points_3d) in points_3ds.into_iter().enumerate() {
-            let timelines = timelines
-                .iter()
-                .map(|(timeline, time_col)| (*timeline, time_col.row_sliced(i, 1).clone()))
-                .collect::<HashMap<_, _, _>>();
-
-            let components = points_3d
-                .as_serialized_batches()
-                .into_iter()
-                .map(SerializedComponentColumn::from)
-                .collect::<ChunkComponents>();
-
-            let c = Chunk::from_auto_row_ids(
-                ChunkId::new(),
-                entity_path.clone(),
-                timelines,
-                components,
-            )?;
+            points,
+            next_row: _,
+        } = *self;
 
-            chunks.push(c);
-        }
+        let mut chunks = point_cloud_chunks(&entity_path, &timelines, points)?;
 
         let data_chunk = Chunk::from_auto_row_ids(
             ChunkId::new(),
-            entity_path.clone(),
+            entity_path,
             timelines,
             [
                 (
@@ -31,8 +31,7 @@ impl MessageParser for StringMessageParser {
     fn finalize(self: Box<Self>, ctx: ParserContext) -> anyhow::Result<Vec<re_chunk::Chunk>> {
         let Self { texts } = *self;
 
-        let entity_path = ctx.entity_path().clone();
-        let timelines = ctx.build_timelines();
+        let (entity_path, timelines) = ctx.into_parts();
 
         let text_documents = TextDocument::update_fields()
             .with_many_text(texts)
@@ -0,0 +1,63 @@
+use glam::{DQuat, DVec3};
+
+use super::definitions::{geometry_msgs, nav_msgs};
+use crate::{Error, deskew::Trajectory, parsers::cdr, util::for_each_channel_message};
+
+/// Reads the poses published on `topic`, which has to be of type `nav_msgs/msg/Odometry` or
+/// `geometry_msgs/msg/PoseStamped`.
+///
+/// The poses are assumed to be those of the sensors whose point clouds get de-skewed, i.e. the
+/// mounting of the sensors relative to the frame of the poses is ignored.
+pub fn read_trajectory(
+    mcap_bytes: &[u8],
+    summary: &mcap::Summary,
+    topic: &str,
+) -> Result<Trajectory, Error> {
+    re_tracing::profile_function!();
+
+    let Some(channel) = summary
+        .channels
+        .values()
+        .find(|channel| channel.topic == topic)
+    else {
+        return Err(Error::Other(anyhow::anyhow!(
+            "MCAP file has no topic {topic:?} to de-skew point clouds with"
+        )));
+    };
+    let schema = channel
+        .schema
+        .as_ref()
+        .map(|schema| schema.name.as_str())
+        .unwrap_or_default();
+    if schema != "nav_msgs/msg/Odometry" && schema != "geometry_msgs/msg/PoseStamped" {
+        return Err(Error::Other(anyhow::anyhow!(
+            "Cannot de-skew point clouds with topic {topic:?} of type {schema:?}, expected nav_msgs/msg/Odometry or geometry_msgs/msg/PoseStamped"
+        )));
+    }
+
+    let mut trajectory = Trajectory::default();
+    for_each_channel_message(mcap_bytes, summary, channel.id, |msg| {
+        let (stamp, pose) = if schema == "nav_msgs/msg/Odometry" {
+            let odometry = cdr::try_decode_message::<nav_msgs::Odometry>(&msg.data)
+                .map_err(|err| Error::Other(anyhow::anyhow!(err)))?;
+            (odometry.header.stamp, odometry.pose.pose)
+        } else {
+            let pose = cdr::try_decode_message::<geometry_msgs::PoseStamped>(&msg.data)
+                .map_err(|err| Error::Other(anyhow::anyhow!(err)))?;
+            (pose.header.stamp, pose.pose)
+        };
+
+        let geometry_msgs::Pose {
+            position,
+            orientation,
+        } = pose;
+        trajectory.insert(
+            stamp.as_nanos(),
+            DVec3::new(position.x, position.y, position.z),
+            DQuat::from_xyzw(orientation.x, orientation.y, orientation.z, orientation.w),
+        );
+        Ok(())
+    })?;
+
+    Ok(trajectory)
+}
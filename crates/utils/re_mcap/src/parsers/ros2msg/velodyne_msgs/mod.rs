@@ -0,0 +1,3 @@
+mod velodyne_scan;
+
+pub use velodyne_scan::*;
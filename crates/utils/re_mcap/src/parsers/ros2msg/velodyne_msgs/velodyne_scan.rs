@@ -0,0 +1,251 @@
+use re_chunk::Chunk;
+use re_log_types::TimeCell;
+use re_types::SerializedComponentBatch;
+
+use super::super::definitions::velodyne_msgs::{self, PACKET_SIZE};
+use crate::{
+    Error,
+    parsers::{MessageParser, ParserContext, cdr},
+    point_cloud::{LidarPoints, point_cloud_chunks},
+};
+
+/// Plugin that parses `velodyne_msgs/msg/VelodyneScan` messages.
+#[derive(Default)]
+pub struct VelodyneScanSchemaPlugin;
+
+const NUM_BLOCKS: usize = 12;
+const BLOCK_SIZE: usize = 100;
+const NUM_CHANNELS_PER_BLOCK: usize = 32;
+const BLOCK_FLAG: [u8; 2] = [0xFF, 0xEE];
+
+/// The unit of the distances in the packets.
+const DISTANCE_RESOLUTION_M: f32 = 0.002;
+
+/// The Velodyne sensors whose packets can be decoded.
+///
+/// Velodyne packets don't carry a calibration, but the models can be told apart by the product
+/// id of the packets, and all sensors of a model share the same nominal laser angles.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum VelodyneModel {
+    Hdl32e,
+    Vlp16,
+    PuckHiRes,
+}
+
+impl VelodyneModel {
+    fn from_product_id(product_id: u8) -> Option<Self> {
+        match product_id {
+            0x21 => Some(Self::Hdl32e),
+            // The Puck LITE has the same lasers as the VLP-16.
+            0x22 | 0x23 => Some(Self::Vlp16),
+            0x24 => Some(Self::PuckHiRes),
+            _ => None,
+        }
+    }
+
+    /// The elevation of each laser, in degrees.
+    fn vertical_angles(self) -> &'static [f32] {
+        match self {
+            Self::Hdl32e => &[
+                -30.67, -9.33, -29.33, -8.0, -28.0, -6.67, -26.67, -5.33, -25.33, -4.0, -24.0,
+                -2.67, -22.67, -1.33, -21.33, 0.0, -20.0, 1.33, -18.67, 2.67, -17.33, 4.0, -16.0,
+                5.33, -14.67, 6.67, -13.33, 8.0, -12.0, 9.33, -10.67, 10.67,
+            ],
+            Self::Vlp16 => &[
+                -15.0, 1.0, -13.0, 3.0, -11.0, 5.0, -9.0, 7.0, -7.0, 9.0, -5.0, 11.0, -3.0, 13.0,
+                -1.0, 15.0,
+            ],
+            Self::PuckHiRes => &[
+                -10.0, 0.67, -8.67, 2.0, -7.33, 3.33, -6.0, 4.67, -4.67, 6.0, -3.33, 7.33, -2.0,
+                8.67, -0.67, 10.0,
+            ],
+        }
+    }
+}
+
+/// Decodes a raw Velodyne packet into `points`.
+///
+/// Points are in the frame of the sensor, following the ROS convention of x pointing forward.
+fn decode_packet(data: &[u8], points: &mut LidarPoints) -> Result<(), Error> {
+    let Some(&product_id) = data.get(PACKET_SIZE - 1) else {
+        return Err(anyhow::anyhow!("Velodyne packet is too short: {} bytes", data.len()).into());
+    };
+    let Some(model) = VelodyneModel::from_product_id(product_id) else {
+        return Err(anyhow::anyhow!("Unsupported Velodyne product id {product_id:#04x}").into());
+    };
+
+    let vertical_angles = model.vertical_angles();
+    let num_lasers = vertical_angles.len();
+
+    let azimuth_of_block = |block: usize| {
+        let block = &data[block * BLOCK_SIZE..];
+        u16::from_le_bytes([block[2], block[3]])
+    };
+
+    for block_idx in 0..NUM_BLOCKS {
+        let block = &data[block_idx * BLOCK_SIZE..(block_idx + 1) * BLOCK_SIZE];
+        if block[..2] != BLOCK_FLAG {
+            continue;
+        }
+
+        // 16-laser sensors fire twice per block, the second time halfway to the next block.
+        let azimuth = azimuth_of_block(block_idx);
+        let azimuth_gap = if block_idx + 1 < NUM_BLOCKS {
+            azimuth_of_block(block_idx + 1).wrapping_sub(azimuth)
+        } else {
+            azimuth.wrapping_sub(azimuth_of_block(block_idx - 1))
+        };
+        let azimuth_gap = if azimuth_gap > 18000 {
+            azimuth_gap.wrapping_add(36000)
+        } else {
+            azimuth_gap
+        };
+
+        for channel in 0..NUM_CHANNELS_PER_BLOCK {
+            let measurement = &block[4 + 3 * channel..][..3];
+            let distance = u16::from_le_bytes([measurement[0], measurement[1]]);
+            if distance == 0 {
+                // No return.
+                continue;
+            }
+
+            let laser = channel % num_lasers;
+            let firing = channel / num_lasers;
+            let azimuth = azimuth as f32 + (firing as f32) * (azimuth_gap as f32) / 2.0;
+
+            let distance = distance as f32 * DISTANCE_RESOLUTION_M;
+            let azimuth = (azimuth / 100.0).to_radians();
+            let elevation = vertical_angles[laser].to_radians();
+            let xy_distance = distance * elevation.cos();
+            points.push(
+                [
+                    xy_distance * azimuth.cos(),
+                    -xy_distance * azimuth.sin(),
+                    distance * elevation.sin(),
+                ],
+                measurement[2] as f32,
+                laser as u32,
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Decodes the raw packets of `velodyne_msgs/msg/VelodyneScan` messages into point clouds.
+///
+/// Each scan is usually a full revolution of the sensor. The nominal laser angles of the sensor
+/// are used, see [`VelodyneModel`].
+pub struct VelodyneScanMessageParser {
+    point_clouds: Vec<(usize, Vec<SerializedComponentBatch>)>,
+    next_row: usize,
+}
+
+impl VelodyneScanMessageParser {
+    const ARCHETYPE_NAME: &str = "velodyne_msgs.msg.VelodyneScan";
+
+    pub fn new(num_rows: usize) -> Self {
+        Self {
+            point_clouds: Vec::with_capacity(num_rows),
+            next_row: 0,
+        }
+    }
+}
+
+impl MessageParser for VelodyneScanMessageParser {
+    fn append(&mut self, ctx: &mut ParserContext, msg: &mcap::Message<'_>) -> anyhow::Result<()> {
+        let scan = cdr::try_decode_message::<velodyne_msgs::VelodyneScan>(msg.data.as_ref())
+            .map_err(|err| Error::Other(anyhow::anyhow!(err)))?;
+
+        ctx.add_timestamp(TimeCell::from_timestamp_nanos_since_epoch(
+            scan.header.stamp.as_nanos(),
+        ));
+
+        let row = self.next_row;
+        self.next_row += 1;
+
+        let mut points = LidarPoints::default();
+        for packet in &scan.packets {
+            if let Err(err) = decode_packet(&packet.data, &mut points) {
+                re_log::warn_once!("Cannot decode Velodyne packets: {err}");
+            }
+        }
+
+        if !points.is_empty() {
+            self.point_clouds
+                .push((row, points.into_batches(Self::ARCHETYPE_NAME)));
+        }
+
+        Ok(())
+    }
+
+    fn finalize(self: Box<Self>, ctx: ParserContext) -> anyhow::Result<Vec<Chunk>> {
+        let (entity_path, timelines) = ctx.into_parts();
+
+        Ok(point_cloud_chunks(
+            &entity_path,
+            &timelines,
+            self.point_clouds,
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::{
+        array::{Array as _, AsArray as _},
+        datatypes::Float32Type,
+    };
+    use re_types::archetypes::Points3D;
+
+    use super::*;
+
+    fn packet(product_id: u8, azimuths: [u16; NUM_BLOCKS], distance: u16) -> Vec<u8> {
+        let mut data = vec![0; PACKET_SIZE];
+        for (block, azimuth) in data.chunks_exact_mut(BLOCK_SIZE).zip(azimuths) {
+            block[..2].copy_from_slice(&BLOCK_FLAG);
+            block[2..4].copy_from_slice(&azimuth.to_le_bytes());
+            for measurement in block[4..].chunks_exact_mut(3) {
+                measurement[..2].copy_from_slice(&distance.to_le_bytes());
+                measurement[2] = 42;
+            }
+        }
+        data[PACKET_SIZE - 1] = product_id;
+        data
+    }
+
+    #[test]
+    fn decode_vlp16() {
+        let azimuths = std::array::from_fn(|block| (block as u16 * 40 + 35990) % 36000);
+        let mut points = LidarPoints::default();
+        decode_packet(&packet(0x22, azimuths, 5000), &mut points).unwrap();
+
+        let batches = points.into_batches("test");
+        let positions = batches
+            .iter()
+            .find(|batch| batch.descriptor == Points3D::descriptor_positions())
+            .unwrap()
+            .array
+            .as_fixed_size_list();
+        assert_eq!(positions.len(), NUM_BLOCKS * NUM_CHANNELS_PER_BLOCK);
+
+        // The first laser of the first block points 15° down, at an azimuth of 359.9°.
+        let values = positions.values().as_primitive::<Float32Type>();
+        let [x, y, z] = [values.value(0), values.value(1), values.value(2)];
+        let distance = 5000.0 * DISTANCE_RESOLUTION_M;
+        assert!((z - distance * (-15f32).to_radians().sin()).abs() < 1e-4);
+        assert!(x > 9.0 && y > 0.0 && y < 0.1, "{x} {y}");
+
+        // The second firing wraps around to an azimuth of 0.1°.
+        let [x, y] = [values.value(16 * 3), values.value(16 * 3 + 1)];
+        assert!(x > 9.0 && y < 0.0 && y > -0.1, "{x} {y}");
+    }
+
+    #[test]
+    fn unsupported_model() {
+        let mut points = LidarPoints::default();
+        assert!(decode_packet(&packet(0xA1, [0; NUM_BLOCKS], 1), &mut points).is_err());
+        assert!(decode_packet(&[0; 10], &mut points).is_err());
+        assert!(points.is_empty());
+    }
+}
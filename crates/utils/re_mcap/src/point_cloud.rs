@@ -0,0 +1,248 @@
+//! Interpreting the fields of point clouds, see [`PointCloudFieldMapping`].
+
+use std::sync::Arc;
+
+use arrow::array::{Float32Array, UInt32Array};
+use re_chunk::{
+    Chunk, ChunkComponents, ChunkId, EntityPath, TimeColumn, TimelineName,
+    external::nohash_hasher::IntMap,
+};
+use re_types::{
+    AsComponents as _, ComponentDescriptor, SerializedComponentBatch, SerializedComponentColumn,
+    archetypes,
+};
+
+/// The meaning of a field of a point cloud, besides its position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PointFieldSemantic {
+    /// The color of the point, packed as `0x00RRGGBB` or `0xAARRGGBB` like PCL's `rgb` and `rgba`.
+    Color,
+
+    /// The intensity of the return, e.g. for lidars.
+    Intensity,
+
+    /// The time at which the point was measured, in the unit of the sensor.
+    Timestamp,
+
+    /// The laser ring or channel the point was measured by.
+    Ring,
+}
+
+impl PointFieldSemantic {
+    pub const ALL: [Self; 4] = [Self::Color, Self::Intensity, Self::Timestamp, Self::Ring];
+
+    /// The name of the semantic, and of the component it is logged as.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Color => "color",
+            Self::Intensity => "intensity",
+            Self::Timestamp => "timestamp",
+            Self::Ring => "ring",
+        }
+    }
+}
+
+impl std::fmt::Display for PointFieldSemantic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PointFieldSemantic {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|semantic| semantic.name() == s)
+            .ok_or_else(|| {
+                format!(
+                    "unknown point field semantic {s:?}, expected one of: {}",
+                    Self::ALL.map(Self::name).join(", ")
+                )
+            })
+    }
+}
+
+/// Maps the fields of point clouds to semantic components, since vendors name them
+/// inconsistently (`intensity` vs `reflectivity` vs `i`).
+///
+/// For each [`PointFieldSemantic`], this holds the names of the fields that can provide it, in
+/// order of preference. The defaults cover the drivers of the most common sensors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PointCloudFieldMapping {
+    pub color: Vec<String>,
+    pub intensity: Vec<String>,
+    pub timestamp: Vec<String>,
+    pub ring: Vec<String>,
+}
+
+impl Default for PointCloudFieldMapping {
+    fn default() -> Self {
+        let names = |names: &[&str]| names.iter().map(|name| (*name).to_owned()).collect();
+        Self {
+            color: names(&["rgb", "rgba"]),
+            intensity: names(&["intensity", "reflectivity", "i", "signal"]),
+            timestamp: names(&["timestamp", "time", "t", "offset_time"]),
+            ring: names(&["ring", "channel", "line", "laser_id"]),
+        }
+    }
+}
+
+impl PointCloudFieldMapping {
+    /// The names of the fields that can provide `semantic`, in order of preference.
+    pub fn field_names(&self, semantic: PointFieldSemantic) -> &[String] {
+        match semantic {
+            PointFieldSemantic::Color => &self.color,
+            PointFieldSemantic::Intensity => &self.intensity,
+            PointFieldSemantic::Timestamp => &self.timestamp,
+            PointFieldSemantic::Ring => &self.ring,
+        }
+    }
+
+    /// Replaces the names of the fields that can provide `semantic`.
+    ///
+    /// An empty list disables the semantic.
+    pub fn with_field_names(
+        mut self,
+        semantic: PointFieldSemantic,
+        names: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        let names = names.into_iter().map(Into::into).collect();
+        match semantic {
+            PointFieldSemantic::Color => self.color = names,
+            PointFieldSemantic::Intensity => self.intensity = names,
+            PointFieldSemantic::Timestamp => self.timestamp = names,
+            PointFieldSemantic::Ring => self.ring = names,
+        }
+        self
+    }
+
+    /// Applies an override of the form `<semantic>=<name>[,<name>…]`, e.g. `intensity=reflectivity`.
+    ///
+    /// This is the format of the `--point-cloud-field` CLI flag.
+    pub fn with_override(self, s: &str) -> Result<Self, String> {
+        let (semantic, names) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected <semantic>=<field>[,<field>…], got {s:?}"))?;
+        let names = names
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty());
+        Ok(self.with_field_names(semantic.trim().parse()?, names))
+    }
+
+    /// Finds the first field of `fields` that provides `semantic`.
+    pub(crate) fn find<'a, F>(
+        &self,
+        semantic: PointFieldSemantic,
+        fields: &'a [F],
+        name: impl Fn(&F) -> &str,
+    ) -> Option<&'a F> {
+        self.field_names(semantic).iter().find_map(|candidate| {
+            fields
+                .iter()
+                .find(|field| name(field) == candidate.as_str())
+        })
+    }
+}
+
+/// The points decoded from the raw packets of a lidar, see [`Self::into_batches`].
+#[derive(Default)]
+pub(crate) struct LidarPoints {
+    positions: Vec<[f32; 3]>,
+    intensities: Vec<f32>,
+    rings: Vec<u32>,
+}
+
+impl LidarPoints {
+    pub fn push(&mut self, position: [f32; 3], intensity: f32, ring: u32) {
+        self.positions.push(position);
+        self.intensities.push(intensity);
+        self.rings.push(ring);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// The [`archetypes::Points3D`] of the points, along with the same `intensity` and `ring`
+    /// components as point clouds interpreted through a [`PointCloudFieldMapping`].
+    pub fn into_batches(self, archetype_name: &str) -> Vec<SerializedComponentBatch> {
+        let Self {
+            positions,
+            intensities,
+            rings,
+        } = self;
+
+        let mut batches = archetypes::Points3D::new(positions).as_serialized_batches();
+        batches.push(SerializedComponentBatch::new(
+            Arc::new(Float32Array::from(intensities)),
+            ComponentDescriptor::partial(PointFieldSemantic::Intensity.name())
+                .with_archetype(archetype_name.into()),
+        ));
+        batches.push(SerializedComponentBatch::new(
+            Arc::new(UInt32Array::from(rings)),
+            ComponentDescriptor::partial(PointFieldSemantic::Ring.name())
+                .with_archetype(archetype_name.into()),
+        ));
+        batches
+    }
+}
+
+/// Builds a chunk for each point cloud, given along with the row of the message it came from.
+///
+/// Point clouds are too large to be batched together, so each gets its own chunk.
+pub(crate) fn point_cloud_chunks(
+    entity_path: &EntityPath,
+    timelines: &IntMap<TimelineName, TimeColumn>,
+    point_clouds: Vec<(usize, Vec<SerializedComponentBatch>)>,
+) -> Result<Vec<Chunk>, re_chunk::ChunkError> {
+    point_clouds
+        .into_iter()
+        .map(|(row, batches)| {
+            let timelines = timelines
+                .iter()
+                .map(|(timeline, time_col)| (*timeline, time_col.row_sliced(row, 1)))
+                .collect();
+
+            let components = batches
+                .into_iter()
+                .map(SerializedComponentColumn::from)
+                .collect::<ChunkComponents>();
+
+            Chunk::from_auto_row_ids(ChunkId::new(), entity_path.clone(), timelines, components)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides() {
+        let mapping = PointCloudFieldMapping::default()
+            .with_override("intensity=refl, i")
+            .unwrap()
+            .with_override("ring=")
+            .unwrap();
+        assert_eq!(mapping.intensity, vec!["refl", "i"]);
+        assert!(mapping.ring.is_empty());
+        assert_eq!(mapping.color, PointCloudFieldMapping::default().color);
+
+        assert!(mapping.clone().with_override("intensity").is_err());
+        assert!(mapping.with_override("normal=nx").is_err());
+    }
+
+    #[test]
+    fn preference_order() {
+        let mapping = PointCloudFieldMapping::default();
+        let fields = ["x", "y", "z", "reflectivity", "intensity", "t"];
+        let find = |semantic| mapping.find(semantic, &fields, |name| *name).copied();
+
+        assert_eq!(find(PointFieldSemantic::Intensity), Some("intensity"));
+        assert_eq!(find(PointFieldSemantic::Timestamp), Some("t"));
+        assert_eq!(find(PointFieldSemantic::Color), None);
+    }
+}
@@ -0,0 +1,294 @@
+//! Rectifying camera images with their calibration, see [`Rectification`].
+
+/// The calibration of a camera, as published in `sensor_msgs/msg/CameraInfo` messages.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CameraCalibration {
+    pub width: u32,
+    pub height: u32,
+
+    /// `plumb_bob`, `rational_polynomial` or `equidistant`.
+    pub distortion_model: String,
+
+    /// The distortion coefficients, depending on the distortion model.
+    pub d: Vec<f64>,
+
+    /// The row-major intrinsic matrix of the raw (distorted) images.
+    pub k: [f64; 9],
+
+    /// The row-major rectification rotation, the identity for monocular cameras.
+    pub r: [f64; 9],
+
+    /// The row-major projection matrix of the rectified images.
+    pub p: [f64; 12],
+}
+
+/// Undistorts (and for stereo cameras, rectifies) the images of a camera, so that they match an
+/// ideal pinhole camera, see [`Self::image_from_camera`].
+///
+/// Like OpenCV's `initUndistortRectifyMap`, this computes once where each pixel of the rectified
+/// images comes from in the raw images, and then samples the raw images there.
+#[derive(Clone, Debug)]
+pub struct Rectification {
+    width: u32,
+    height: u32,
+
+    /// For each rectified pixel, row by row, its position in the raw image.
+    map: Vec<[f32; 2]>,
+
+    image_from_camera: [f32; 9],
+}
+
+impl Rectification {
+    pub fn new(calibration: &CameraCalibration) -> Result<Self, String> {
+        let CameraCalibration {
+            width,
+            height,
+            distortion_model,
+            d,
+            k,
+            r,
+            p,
+        } = calibration;
+
+        if *width == 0 || *height == 0 {
+            return Err("the image is empty".to_owned());
+        }
+        if k.iter().all(|&x| x == 0.0) {
+            return Err("the camera is not calibrated".to_owned());
+        }
+        let distortion = Distortion::new(distortion_model, d)?;
+
+        // Uncalibrated cameras leave the rectification and projection unset.
+        let r = if r.iter().all(|&x| x == 0.0) {
+            [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]
+        } else {
+            *r
+        };
+        let [fx_rect, _, cx_rect, _, _, fy_rect, cy_rect, ..] = if p[0] == 0.0 || p[5] == 0.0 {
+            [
+                k[0], 0.0, k[2], 0.0, 0.0, k[4], k[5], 0.0, 0.0, 0.0, 0.0, 0.0,
+            ]
+        } else {
+            *p
+        };
+        let [fx, _, cx, _, fy, cy, ..] = *k;
+
+        let mut map = Vec::with_capacity(*width as usize * *height as usize);
+        for v in 0..*height {
+            for u in 0..*width {
+                // Back to the camera frame of the rectified image, then of the raw one.
+                let x = (u as f64 - cx_rect) / fx_rect;
+                let y = (v as f64 - cy_rect) / fy_rect;
+
+                // `r` rotates from the raw camera frame, so its inverse is its transpose.
+                let ray = [
+                    r[0] * x + r[3] * y + r[6],
+                    r[1] * x + r[4] * y + r[7],
+                    r[2] * x + r[5] * y + r[8],
+                ];
+                let [x, y] = distortion.distort(ray[0] / ray[2], ray[1] / ray[2]);
+
+                map.push([(fx * x + cx) as f32, (fy * y + cy) as f32]);
+            }
+        }
+
+        Ok(Self {
+            width: *width,
+            height: *height,
+            map,
+            image_from_camera: [
+                fx_rect as f32,
+                0.0,
+                0.0,
+                0.0,
+                fy_rect as f32,
+                0.0,
+                cx_rect as f32,
+                cy_rect as f32,
+                1.0,
+            ],
+        })
+    }
+
+    /// The column-major intrinsics of the rectified images, as expected by `Pinhole`.
+    pub fn image_from_camera(&self) -> [f32; 9] {
+        self.image_from_camera
+    }
+
+    /// Rectifies an image of `num_channels` channels of `bytes_per_channel` bytes each, whose rows
+    /// are `step` bytes apart.
+    ///
+    /// With `interpolate`, 8 and 16 bit channels are sampled bilinearly, which is what is wanted
+    /// for color images but not for e.g. depth images, which are sampled from the nearest pixel.
+    /// Pixels that map outside of the raw image are zeroed.
+    ///
+    /// Returns `None` if the image doesn't have the size of the calibration.
+    pub fn rectify(
+        &self,
+        data: &[u8],
+        step: usize,
+        num_channels: usize,
+        bytes_per_channel: usize,
+        interpolate: bool,
+    ) -> Option<Vec<u8>> {
+        re_tracing::profile_function!();
+
+        let [width, height] = [self.width as usize, self.height as usize];
+        let bytes_per_pixel = num_channels * bytes_per_channel;
+        if step < width * bytes_per_pixel
+            || data.len() < step * (height - 1) + width * bytes_per_pixel
+        {
+            return None;
+        }
+
+        let pixel = |x: usize, y: usize| &data[y * step + x * bytes_per_pixel..][..bytes_per_pixel];
+        let channel = |pixel: &[u8], c: usize| -> f32 {
+            match bytes_per_channel {
+                1 => pixel[c] as f32,
+                _ => u16::from_le_bytes([pixel[2 * c], pixel[2 * c + 1]]) as f32,
+            }
+        };
+
+        let mut rectified = vec![0; width * height * bytes_per_pixel];
+        for (target, &[x, y]) in rectified.chunks_exact_mut(bytes_per_pixel).zip(&self.map) {
+            if !(0.0..=(width - 1) as f32).contains(&x) || !(0.0..=(height - 1) as f32).contains(&y)
+            {
+                continue;
+            }
+
+            if !interpolate || bytes_per_channel > 2 {
+                target.copy_from_slice(pixel(x.round() as usize, y.round() as usize));
+                continue;
+            }
+
+            let [x0, y0] = [x.floor() as usize, y.floor() as usize];
+            let [x1, y1] = [(x0 + 1).min(width - 1), (y0 + 1).min(height - 1)];
+            let [tx, ty] = [x - x0 as f32, y - y0 as f32];
+            let [p00, p10, p01, p11] = [pixel(x0, y0), pixel(x1, y0), pixel(x0, y1), pixel(x1, y1)];
+            for c in 0..num_channels {
+                let top = channel(p00, c) * (1.0 - tx) + channel(p10, c) * tx;
+                let bottom = channel(p01, c) * (1.0 - tx) + channel(p11, c) * tx;
+                let value = (top * (1.0 - ty) + bottom * ty).round();
+                match bytes_per_channel {
+                    1 => target[c] = value as u8,
+                    _ => target[2 * c..2 * c + 2].copy_from_slice(&(value as u16).to_le_bytes()),
+                }
+            }
+        }
+
+        Some(rectified)
+    }
+}
+
+/// The lens distortion models of ROS.
+enum Distortion {
+    /// `plumb_bob` and `rational_polynomial`: `k1, k2, p1, p2[, k3[, k4, k5, k6]]`.
+    Radial { k: [f64; 6], p: [f64; 2] },
+
+    /// `equidistant`, i.e. the Kannala-Brandt fisheye model: `k1, k2, k3, k4`.
+    Equidistant { k: [f64; 4] },
+}
+
+impl Distortion {
+    fn new(model: &str, d: &[f64]) -> Result<Self, String> {
+        let coefficient = |i: usize| d.get(i).copied().unwrap_or_default();
+        match model {
+            "plumb_bob" | "rational_polynomial" => Ok(Self::Radial {
+                k: [0, 1, 4, 5, 6, 7].map(coefficient),
+                p: [2, 3].map(coefficient),
+            }),
+            "equidistant" => Ok(Self::Equidistant {
+                k: [0, 1, 2, 3].map(coefficient),
+            }),
+            _ => Err(format!("unsupported distortion model {model:?}")),
+        }
+    }
+
+    /// Maps ideal normalized image coordinates to distorted ones.
+    fn distort(&self, x: f64, y: f64) -> [f64; 2] {
+        let r2 = x * x + y * y;
+        match self {
+            Self::Radial {
+                k: [k1, k2, k3, k4, k5, k6],
+                p: [p1, p2],
+            } => {
+                let r4 = r2 * r2;
+                let r6 = r4 * r2;
+                let radial =
+                    (1.0 + k1 * r2 + k2 * r4 + k3 * r6) / (1.0 + k4 * r2 + k5 * r4 + k6 * r6);
+                [
+                    x * radial + 2.0 * p1 * x * y + p2 * (r2 + 2.0 * x * x),
+                    y * radial + p1 * (r2 + 2.0 * y * y) + 2.0 * p2 * x * y,
+                ]
+            }
+            Self::Equidistant {
+                k: [k1, k2, k3, k4],
+            } => {
+                let r = r2.sqrt();
+                if r < 1e-12 {
+                    return [x, y];
+                }
+                let theta = r.atan();
+                let theta2 = theta * theta;
+                let theta_d =
+                    theta * (1.0 + theta2 * (k1 + theta2 * (k2 + theta2 * (k3 + theta2 * k4))));
+                let scale = theta_d / r;
+                [x * scale, y * scale]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calibration(distortion_model: &str, d: Vec<f64>) -> CameraCalibration {
+        CameraCalibration {
+            width: 4,
+            height: 3,
+            distortion_model: distortion_model.to_owned(),
+            d,
+            k: [2.0, 0.0, 1.5, 0.0, 2.0, 1.0, 0.0, 0.0, 1.0],
+            r: [0.0; 9],
+            p: [0.0; 12],
+        }
+    }
+
+    #[test]
+    fn no_distortion_is_identity() {
+        let rectification = Rectification::new(&calibration("plumb_bob", vec![0.0; 5])).unwrap();
+        let image: Vec<u8> = (0..12).collect();
+        assert_eq!(rectification.rectify(&image, 4, 1, 1, true).unwrap(), image);
+
+        // Rows may be padded.
+        let padded: Vec<u8> = image
+            .chunks(4)
+            .flat_map(|row| [row, &[0; 2][..]].concat())
+            .collect();
+        assert_eq!(
+            rectification.rectify(&padded, 6, 1, 1, true).unwrap(),
+            image
+        );
+
+        assert!(rectification.rectify(&image[..8], 4, 1, 1, true).is_none());
+        assert_eq!(
+            rectification.image_from_camera(),
+            [2.0, 0.0, 0.0, 0.0, 2.0, 0.0, 1.5, 1.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn barrel_distortion() {
+        let rectification =
+            Rectification::new(&calibration("plumb_bob", vec![-0.2, 0.0, 0.0, 0.0, 0.0])).unwrap();
+
+        // The center stays put, the corners are pulled in.
+        let [cx, cy] = rectification.map[4 + 1];
+        assert!((cx - 1.0).abs() < 0.3 && (cy - 1.0).abs() < 1e-6);
+        let [x, y] = rectification.map[0];
+        assert!(x > 0.0 && y > 0.0);
+
+        assert!(Rectification::new(&calibration("unknown", vec![])).is_err());
+    }
+}
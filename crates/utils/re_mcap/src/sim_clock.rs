@@ -0,0 +1,83 @@
+//! Simulated time of simulation recordings, see [`SimClock`].
+
+use std::sync::LazyLock;
+
+use re_chunk::TimelineName;
+
+/// The name of the timeline with the simulated time of the messages, see [`SimClock`].
+pub const SIM_TIME_TIMELINE: &str = "sim_time";
+
+pub(crate) static SIM_TIME: LazyLock<TimelineName> =
+    LazyLock::new(|| TimelineName::new(SIM_TIME_TIMELINE));
+
+/// Whether the MCAP file has a clock topic, i.e. it was recorded in a simulation.
+pub fn has_sim_clock(summary: &mcap::Summary) -> bool {
+    summary
+        .channels
+        .values()
+        .any(|channel| crate::parsers::ros2msg::rosgraph_msgs::is_clock_channel(channel))
+}
+
+/// The simulated time over the course of a recording, e.g. from the `/clock` topic that
+/// simulators like Gazebo and Isaac Sim publish.
+///
+/// Simulations rarely run in real time, so the log times of their messages are of little use to
+/// line them up with each other. Like ROS nodes with `use_sim_time`, messages get the latest
+/// simulated time at their log time instead, see [`Self::sim_time_at`].
+#[derive(Clone, Debug, Default)]
+pub struct SimClock {
+    /// The simulated times in nanoseconds, by the log time of their clock message, sorted.
+    ticks: Vec<(u64, i64)>,
+}
+
+impl SimClock {
+    /// Adds a clock message, published at `log_time` with the simulated time `sim_time_ns`.
+    pub fn insert(&mut self, log_time: u64, sim_time_ns: i64) {
+        let idx = self.ticks.partition_point(|(time, _)| *time <= log_time);
+        self.ticks.insert(idx, (log_time, sim_time_ns));
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ticks.is_empty()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ticks.len()
+    }
+
+    /// The simulated time in nanoseconds at `log_time`, i.e. that of the latest clock message
+    /// logged at or before it.
+    ///
+    /// Messages logged before the first clock message get its time, so that they still end up
+    /// on the timeline.
+    pub fn sim_time_at(&self, log_time: u64) -> Option<i64> {
+        let idx = self.ticks.partition_point(|(time, _)| *time <= log_time);
+        let (_, sim_time_ns) = self.ticks.get(idx.saturating_sub(1))?;
+        Some(*sim_time_ns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latest_tick() {
+        let mut clock = SimClock::default();
+        assert_eq!(clock.sim_time_at(100), None);
+
+        // Simulations may run slower than real time.
+        clock.insert(2_000, 1_000);
+        clock.insert(1_000, 500);
+        clock.insert(3_000, 1_500);
+        assert_eq!(clock.len(), 3);
+
+        assert_eq!(clock.sim_time_at(0), Some(500));
+        assert_eq!(clock.sim_time_at(1_000), Some(500));
+        assert_eq!(clock.sim_time_at(1_999), Some(500));
+        assert_eq!(clock.sim_time_at(2_000), Some(1_000));
+        assert_eq!(clock.sim_time_at(10_000), Some(1_500));
+    }
+}
@@ -0,0 +1,214 @@
+//! Heuristics for topics whose messages are effectively static, see [`static_channel`].
+
+use re_chunk::{Chunk, ChunkError, ChunkId};
+use re_log_types::EntityPathPart;
+
+/// The topics that are static by convention, regardless of their namespace.
+const STATIC_TOPICS: &[&str] = &["tf_static", "robot_description"];
+
+/// The topics of maps, which are static if they are latched, see [`is_latched`].
+const MAP_TOPICS: &[&str] = &["map", "map_metadata"];
+
+/// How the messages of a static channel are logged, see [`static_channel`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StaticChannel {
+    /// The only message of the channel is logged as static data as is.
+    Single,
+
+    /// Each message is logged as static data under its own child entity, `<topic>/<index>`.
+    ///
+    /// Only the last message of a static entity is kept, so they would overwrite each other
+    /// otherwise, e.g. the transforms of the different publishers of `/tf_static`.
+    PerMessage,
+}
+
+/// Whether the messages of `channel` describe the recording as a whole rather than a point in
+/// time, so that they should be logged as static data.
+///
+/// That is the case for:
+/// * topics with a single message,
+/// * `/tf_static` and `/robot_description`, which are static by convention,
+/// * latched `/map` topics, e.g. of a map server.
+///
+/// Otherwise, scrubbing to before the messages were published would hide them.
+pub fn static_channel(
+    channel: &mcap::Channel<'_>,
+    summary: &mcap::Summary,
+) -> Option<StaticChannel> {
+    let message_count = summary
+        .stats
+        .as_ref()
+        .and_then(|stats| stats.channel_message_counts.get(&channel.id).copied());
+    static_topic(channel, message_count)
+}
+
+/// See [`static_channel`], with the number of messages of the channel if it is known.
+fn static_topic(channel: &mcap::Channel<'_>, message_count: Option<u64>) -> Option<StaticChannel> {
+    if message_count == Some(1) {
+        return Some(StaticChannel::Single);
+    }
+
+    let name = channel
+        .topic
+        .rsplit_once('/')
+        .map_or(channel.topic.as_str(), |(_, name)| name);
+
+    let is_static =
+        STATIC_TOPICS.contains(&name) || (MAP_TOPICS.contains(&name) && is_latched(channel));
+    is_static.then_some(StaticChannel::PerMessage)
+}
+
+/// Whether the channel was recorded from a publisher with `transient_local` durability, i.e. one
+/// that latches its last message for late subscribers.
+///
+/// rosbag2 stores the QoS profiles of the publishers as YAML in the channel metadata, with the
+/// durability as a name in newer versions and as a number in older ones.
+fn is_latched(channel: &mcap::Channel<'_>) -> bool {
+    let Some(profiles) = channel.metadata.get("offered_qos_profiles") else {
+        return false;
+    };
+
+    profiles.lines().any(|line| {
+        line.trim_start_matches([' ', '-'])
+            .strip_prefix("durability:")
+            .is_some_and(|durability| matches!(durability.trim(), "transient_local" | "1"))
+    })
+}
+
+/// Turns a chunk decoded from a static channel into static chunks.
+///
+/// Parsers emit a row per message, so with [`StaticChannel::PerMessage`] the row at `index` is
+/// logged under the child entity `first_message_index + index`, where `first_message_index` is
+/// the index of the first message of the chunk within the channel.
+pub(crate) fn into_static_chunks(
+    static_channel: StaticChannel,
+    chunk: Chunk,
+    first_message_index: u64,
+) -> Result<Vec<Chunk>, ChunkError> {
+    match static_channel {
+        StaticChannel::Single => Ok(vec![chunk.into_static()]),
+        StaticChannel::PerMessage => (0..chunk.num_rows())
+            .map(|index| {
+                let message = chunk.row_sliced(index, 1);
+                let message_index = first_message_index + index as u64;
+                Chunk::new(
+                    ChunkId::new(),
+                    chunk.entity_path() / EntityPathPart::from(message_index.to_string()),
+                    Some(true),
+                    message.row_ids_array().clone(),
+                    Default::default(),
+                    message.components().clone(),
+                )
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::{EntityPath, RowId, TimePoint, Timeline};
+    use re_types::archetypes::TextDocument;
+
+    use super::*;
+
+    fn channel(topic: &str, qos: Option<&str>) -> mcap::Channel<'static> {
+        mcap::Channel {
+            id: 1,
+            topic: topic.to_owned(),
+            schema: None,
+            message_encoding: "cdr".to_owned(),
+            metadata: qos
+                .map(|qos| ("offered_qos_profiles".to_owned(), qos.to_owned()))
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn static_topics() {
+        let per_message = Some(StaticChannel::PerMessage);
+
+        assert_eq!(
+            static_topic(&channel("/tf_static", None), None),
+            per_message
+        );
+        assert_eq!(
+            static_topic(&channel("/tf_static", None), Some(3)),
+            per_message
+        );
+        assert_eq!(
+            static_topic(&channel("/robot/robot_description", None), None),
+            per_message
+        );
+        assert_eq!(static_topic(&channel("/tf", None), Some(100)), None);
+
+        // Maps of e.g. SLAM nodes keep changing.
+        assert_eq!(static_topic(&channel("/map", None), Some(10)), None);
+        let jazzy = "- history: keep_last\n  depth: 1\n  durability: transient_local\n";
+        let humble = "- history: 3\n  depth: 0\n  reliability: 1\n  durability: 1\n";
+        let volatile = "- history: keep_last\n  depth: 1\n  durability: volatile\n";
+        assert_eq!(
+            static_topic(&channel("/map", Some(jazzy)), Some(10)),
+            per_message
+        );
+        assert_eq!(
+            static_topic(&channel("/map", Some(humble)), Some(10)),
+            per_message
+        );
+        assert_eq!(
+            static_topic(&channel("/map", Some(volatile)), Some(10)),
+            None
+        );
+
+        assert_eq!(
+            static_topic(&channel("/tf_static", None), Some(1)),
+            Some(StaticChannel::Single)
+        );
+        assert_eq!(
+            static_topic(&channel("/camera/info", None), Some(1)),
+            Some(StaticChannel::Single)
+        );
+        assert_eq!(static_topic(&channel("/camera/info", None), None), None);
+    }
+
+    #[test]
+    fn per_message_static_chunks() {
+        let timeline = Timeline::new_timestamp("timestamp");
+        let chunk = Chunk::builder(EntityPath::from("/tf_static"))
+            .with_archetype(
+                RowId::new(),
+                TimePoint::from([(timeline, 1)]),
+                &TextDocument::new("first"),
+            )
+            .with_archetype(
+                RowId::new(),
+                TimePoint::from([(timeline, 2)]),
+                &TextDocument::new("second"),
+            )
+            .build()
+            .unwrap();
+
+        let chunks = into_static_chunks(StaticChannel::PerMessage, chunk.clone(), 3).unwrap();
+        let entity_paths: Vec<_> = chunks
+            .iter()
+            .map(|chunk| chunk.entity_path().clone())
+            .collect();
+        assert_eq!(
+            entity_paths,
+            [
+                EntityPath::from("/tf_static/3"),
+                EntityPath::from("/tf_static/4")
+            ]
+        );
+        assert!(
+            chunks
+                .iter()
+                .all(|chunk| chunk.is_static() && chunk.num_rows() == 1)
+        );
+
+        let chunks = into_static_chunks(StaticChannel::Single, chunk, 0).unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert!(chunks[0].is_static());
+        assert_eq!(chunks[0].entity_path(), &EntityPath::from("/tf_static"));
+    }
+}
@@ -43,3 +43,31 @@ pub fn get_chunk_message_count(
         .map(|(channel, msg_offsets)| (channel.id.into(), msg_offsets.len()))
         .collect())
 }
+
+/// Calls `on_message` for each message of the channel with the given id, e.g. to read auxiliary
+/// data (calibrations, poses) before the messages of other channels are parsed.
+pub fn for_each_channel_message(
+    mcap: &[u8],
+    summary: &Summary,
+    channel_id: u16,
+    mut on_message: impl FnMut(&::mcap::Message<'_>) -> Result<(), crate::Error>,
+) -> Result<(), crate::Error> {
+    for chunk_index in &summary.chunk_indexes {
+        if !summary
+            .read_message_indexes(mcap, chunk_index)?
+            .keys()
+            .any(|channel| channel.id == channel_id)
+        {
+            continue;
+        }
+
+        for msg in summary.stream_chunk(mcap, chunk_index)? {
+            let msg = msg?;
+            if msg.channel.id == channel_id {
+                on_message(&msg)?;
+            }
+        }
+    }
+
+    Ok(())
+}
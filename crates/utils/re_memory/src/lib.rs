@@ -38,6 +38,7 @@ pub mod accounting_allocator;
 mod allocation_tracker;
 mod memory_limit;
 mod memory_use;
+pub mod pools;
 mod ram_warner;
 pub mod util;
 
@@ -58,6 +59,7 @@ pub use {
     allocation_tracker::{CallstackStatistics, ReadableBacktrace},
     memory_limit::MemoryLimit,
     memory_use::MemoryUse,
+    pools::PoolStatistics,
     ram_warner::*,
 };
 
@@ -0,0 +1,39 @@
+//! Statistics of memory pools.
+//!
+//! Memory held by a pool for reuse is still counted as live by the allocator,
+//! so pools register here to make it possible to tell where that memory is.
+
+use parking_lot::Mutex;
+
+use crate::CountAndSize;
+
+/// Statistics of a single memory pool.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PoolStatistics {
+    /// The buffers currently held by the pool, waiting to be reused.
+    pub pooled: CountAndSize,
+
+    /// How many buffers were handed out by reusing pooled ones.
+    pub num_reused: usize,
+
+    /// How many buffers were handed out by allocating new ones.
+    pub num_allocated: usize,
+}
+
+static POOLS: Mutex<Vec<(&'static str, fn() -> PoolStatistics)>> = Mutex::new(Vec::new());
+
+/// Registers a pool, so that its statistics are included in [`pool_statistics`].
+///
+/// The callback is called whenever statistics are requested, so it should be cheap.
+pub fn register_pool(name: &'static str, statistics: fn() -> PoolStatistics) {
+    POOLS.lock().push((name, statistics));
+}
+
+/// The current statistics of all registered pools, by name.
+pub fn pool_statistics() -> Vec<(&'static str, PoolStatistics)> {
+    POOLS
+        .lock()
+        .iter()
+        .map(|(name, statistics)| (*name, statistics()))
+        .collect()
+}
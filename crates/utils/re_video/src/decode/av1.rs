@@ -1,6 +1,9 @@
 //! AV1 support.
 
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 use crate::{Time, VideoDataDescription};
 use dav1d::{PixelLayout, PlanarImageComponent};
@@ -36,7 +39,7 @@ impl SyncDecoder for SyncDav1dDecoder {
 }
 
 impl SyncDav1dDecoder {
-    pub fn new(debug_name: String) -> Result<Self> {
+    pub fn new(debug_name: String, num_threads: Option<NonZeroUsize>) -> Result<Self> {
         re_tracing::profile_function!();
 
         if !cfg!(feature = "nasm") {
@@ -66,6 +69,11 @@ impl SyncDav1dDecoder {
         // Set to 1 for low-latency decoding.
         settings.set_max_frame_delay(1);
 
+        // Otherwise, dav1d picks a number of threads based on the number of cores.
+        if let Some(num_threads) = num_threads {
+            settings.set_n_threads(num_threads.get().try_into().unwrap_or(u32::MAX));
+        }
+
         let decoder = dav1d::Decoder::with_settings(&settings)?;
 
         Ok(Self {
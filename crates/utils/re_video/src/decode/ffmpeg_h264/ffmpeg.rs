@@ -2,6 +2,7 @@
 
 use std::{
     collections::BTreeMap,
+    num::NonZeroUsize,
     process::ChildStdin,
     sync::{
         Arc,
@@ -19,9 +20,10 @@ use h264_reader::nal::UnitType;
 use parking_lot::Mutex;
 
 use crate::{
-    PixelFormat, Time, VideoDataDescription, VideoEncodingDetails,
+    PixelFormat, Time, VideoCodec, VideoDataDescription, VideoEncodingDetails,
     decode::{
-        AsyncDecoder, Chunk, DecodeError, Frame, FrameContent, FrameInfo, OutputCallback,
+        AsyncDecoder, Chunk, DecodeError, DecodeHardwareAcceleration, Frame, FrameContent,
+        FrameInfo, OutputCallback,
         ffmpeg_h264::{FFMPEG_MINIMUM_VERSION_MAJOR, FFMPEG_MINIMUM_VERSION_MINOR, FFmpegVersion},
     },
     demux::ChromaSubsamplingModes,
@@ -51,6 +53,9 @@ pub enum Error {
     #[error(transparent)]
     FailedToDetermineFFmpegVersion(FFmpegVersionParseError),
 
+    #[error("Decoding {0:?} with FFmpeg is not supported")]
+    UnsupportedCodec(VideoCodec),
+
     #[error("Failed to get stdin handle")]
     NoStdin,
 
@@ -147,6 +152,55 @@ enum FFmpegFrameData {
     Quit,
 }
 
+/// How samples are framed on the stdin of ffmpeg.
+enum InputStream {
+    /// H.264 Annex B byte stream.
+    ///
+    /// Samples are converted from AVCC if there's an `avcC` box, otherwise they're expected to be Annex B already.
+    H264 { avcc: Option<re_mp4::Avc1Box> },
+
+    /// An IVF container, since unlike H.264, raw VP8, VP9 and AV1 bitstreams don't delimit frames.
+    Ivf {
+        fourcc: [u8; 4],
+        coded_dimensions: [u16; 2],
+    },
+}
+
+impl InputStream {
+    fn new(
+        codec: VideoCodec,
+        encoding_details: &Option<VideoEncodingDetails>,
+    ) -> Result<Self, Error> {
+        let fourcc = match codec {
+            VideoCodec::H264 => {
+                return Ok(Self::H264 {
+                    avcc: encoding_details.as_ref().and_then(|e| e.avcc()).cloned(),
+                });
+            }
+            VideoCodec::AV1 => *b"AV01",
+            VideoCodec::VP8 => *b"VP80",
+            VideoCodec::VP9 => *b"VP90",
+            VideoCodec::H265 => return Err(Error::UnsupportedCodec(codec)),
+        };
+
+        Ok(Self::Ivf {
+            fourcc,
+            // Only used as a hint, the decoder takes the actual size from the bitstream.
+            coded_dimensions: encoding_details
+                .as_ref()
+                .map_or([0, 0], |e| e.coded_dimensions),
+        })
+    }
+
+    /// Name of the ffmpeg demuxer for this stream.
+    fn ffmpeg_format(&self) -> &'static str {
+        match self {
+            Self::H264 { .. } => "h264",
+            Self::Ivf { .. } => "ivf",
+        }
+    }
+}
+
 /// Wraps an stdin with a shared shutdown boolean.
 struct StdinWithShutdown {
     shutdown: Arc<AtomicBool>,
@@ -207,14 +261,19 @@ struct FFmpegProcessAndListener {
 impl FFmpegProcessAndListener {
     fn new(
         debug_name: &str,
+        codec: VideoCodec,
         on_output: Arc<OutputCallback>,
         encoding_details: &Option<VideoEncodingDetails>,
         ffmpeg_path: Option<&std::path::Path>,
+        hw_acceleration: DecodeHardwareAcceleration,
+        num_threads: Option<NonZeroUsize>,
     ) -> Result<Self, Error> {
         re_tracing::profile_function!();
 
         // TODO(andreas): should get SPS also without AVCC from ongoing stream.
 
+        let input_stream = InputStream::new(codec, encoding_details)?;
+
         let (pixel_format, ffmpeg_pix_fmt) = if let Some(chroma_subsampling) =
             encoding_details.as_ref().and_then(|e| e.chroma_subsampling)
         {
@@ -256,6 +315,18 @@ impl FFmpegProcessAndListener {
             FfmpegCommand::new()
         };
 
+        if let Some(hwaccel) = ffmpeg_hwaccel(hw_acceleration) {
+            // FFmpeg picks the first hardware decoder that works (VideoToolbox, NVDEC/CUDA, VAAPI,
+            // D3D11VA, …) and falls back to software decoding on its own if there is none.
+            // Decoded frames are copied back to main memory, so the output format doesn't change.
+            ffmpeg_command.args(["-hwaccel", hwaccel]);
+        }
+
+        if let Some(num_threads) = num_threads {
+            // As an input option, this limits the threads of the decoder.
+            ffmpeg_command.args(["-threads", &num_threads.to_string()]);
+        }
+
         let mut ffmpeg = ffmpeg_command
             // Keep banner enabled so we can check on the version more easily.
             //.hide_banner()
@@ -271,10 +342,10 @@ impl FFmpegProcessAndListener {
                 "0",
             ])
             // Keep in mind that all arguments that are about the input, need to go before!
-            .format("h264") // TODO(andreas): should we check ahead of time whether this is available?
+            .format(input_stream.ffmpeg_format()) // TODO(andreas): should we check ahead of time whether this is available?
             //.fps_mode("0")
             .input("-") // stdin is our input!
-            // h264 bitstreams doesn't have timestamp information. Whatever ffmpeg tries to make up about timing & framerates is wrong!
+            // Bitstreams don't have (trustworthy) timestamp information. Whatever ffmpeg tries to make up about timing & framerates is wrong!
             // If we don't tell it to just pass the frames through, variable framerate (VFR) video will just not play at all.
             .fps_mode("passthrough")
             .pix_fmt(ffmpeg_pix_fmt)
@@ -326,8 +397,6 @@ impl FFmpegProcessAndListener {
             })
             .expect("Failed to spawn ffmpeg listener thread");
 
-        let avcc = encoding_details.as_ref().and_then(|e| e.avcc()).cloned();
-
         // Writes video data to the ffmpeg process:
         let write_thread = std::thread::Builder::new()
             .name(format!("ffmpeg-writer for {debug_name}"))
@@ -343,7 +412,7 @@ impl FFmpegProcessAndListener {
                         &mut ffmpeg_stdin,
                         &frame_data_rx,
                         on_output.as_ref(),
-                        &avcc,
+                        &input_stream,
                     );
                 }
             })
@@ -464,13 +533,30 @@ fn write_ffmpeg_input(
     ffmpeg_stdin: &mut dyn std::io::Write,
     frame_data_rx: &Receiver<FFmpegFrameData>,
     on_output: &Mutex<Option<Arc<OutputCallback>>>,
-    avcc: &Option<re_mp4::Avc1Box>,
+    input_stream: &InputStream,
 ) {
     let mut state = NaluStreamState::default();
 
+    if let InputStream::Ivf {
+        fourcc,
+        coded_dimensions,
+    } = input_stream
+    {
+        if let Err(err) = write_bytes(ffmpeg_stdin, &ivf_file_header(*fourcc, *coded_dimensions)) {
+            if let Some(on_output) = on_output.lock().as_ref() {
+                on_output(Err(err.into()));
+            }
+            return;
+        }
+    }
+
     while let Ok(data) = frame_data_rx.recv() {
         let chunk = match data {
             FFmpegFrameData::Chunk(chunk) => chunk,
+            FFmpegFrameData::Quit if matches!(input_stream, InputStream::Ivf { .. }) => {
+                ffmpeg_stdin.flush().ok();
+                break;
+            }
             FFmpegFrameData::Quit => {
                 // Try to flush out the last frames from ffmpeg with an EndSequence/EndStream NAL units.
                 // Unfortunatelt this doesn't help, at least not for https://github.com/rerun-io/rerun/issues/8073
@@ -491,12 +577,14 @@ fn write_ffmpeg_input(
             }
         };
 
-        let write_result = if let Some(avcc) = avcc {
-            write_avc_chunk_to_nalu_stream(avcc, ffmpeg_stdin, &chunk, &mut state)
-        } else {
+        let write_result = match input_stream {
+            InputStream::H264 { avcc: Some(avcc) } => {
+                write_avc_chunk_to_nalu_stream(avcc, ffmpeg_stdin, &chunk, &mut state)
+            }
             // If there was no AVCC box, we assume the data is already in Annex B format.
             // TODO(andreas): feels a bit implicit, would be nice to make this more clear.
-            write_bytes(ffmpeg_stdin, &chunk.data)
+            InputStream::H264 { avcc: None } => write_bytes(ffmpeg_stdin, &chunk.data),
+            InputStream::Ivf { .. } => write_ivf_frame(ffmpeg_stdin, &chunk),
         };
 
         if let Err(err) = write_result {
@@ -828,21 +916,27 @@ fn read_ffmpeg_output(
     Some(())
 }
 
-/// Decode H.264 video via ffmpeg over CLI
-pub struct FFmpegCliH264Decoder {
+/// Decode H.264, VP8, VP9 or AV1 video via ffmpeg over CLI
+pub struct FFmpegCliDecoder {
     debug_name: String,
+    codec: VideoCodec,
     // Restarted on reset
     ffmpeg: FFmpegProcessAndListener,
     on_output: Arc<OutputCallback>,
     ffmpeg_path: Option<std::path::PathBuf>,
+    hw_acceleration: DecodeHardwareAcceleration,
+    num_threads: Option<NonZeroUsize>,
 }
 
-impl FFmpegCliH264Decoder {
+impl FFmpegCliDecoder {
     pub fn new(
         debug_name: String,
+        codec: VideoCodec,
         encoding_details: &Option<VideoEncodingDetails>,
         on_output: impl Fn(crate::decode::Result<Frame>) + Send + Sync + 'static,
         ffmpeg_path: Option<std::path::PathBuf>,
+        hw_acceleration: DecodeHardwareAcceleration,
+        num_threads: Option<NonZeroUsize>,
     ) -> Result<Self, Error> {
         re_tracing::profile_function!();
 
@@ -858,20 +952,38 @@ impl FFmpegCliH264Decoder {
         let on_output = Arc::new(on_output);
         let ffmpeg = FFmpegProcessAndListener::new(
             &debug_name,
+            codec,
             on_output.clone(),
             encoding_details,
             ffmpeg_path.as_deref(),
+            hw_acceleration,
+            num_threads,
         )?;
 
         Ok(Self {
             debug_name,
+            codec,
             ffmpeg,
             on_output,
             ffmpeg_path,
+            hw_acceleration,
+            num_threads,
         })
     }
 }
 
+/// The value of FFmpeg's `-hwaccel` option for the given setting, if any.
+fn ffmpeg_hwaccel(hw_acceleration: DecodeHardwareAcceleration) -> Option<&'static str> {
+    match hw_acceleration {
+        // Multiple high resolution streams easily exceed what software decoding can do in real time,
+        // and FFmpeg falls back to it anyways if needed.
+        DecodeHardwareAcceleration::Auto | DecodeHardwareAcceleration::PreferHardware => {
+            Some("auto")
+        }
+        DecodeHardwareAcceleration::PreferSoftware => None,
+    }
+}
+
 fn check_ffmpeg_version(
     ffmpeg_version_result: Result<FFmpegVersion, FFmpegVersionParseError>,
 ) -> Result<(), Error> {
@@ -900,7 +1012,7 @@ fn check_ffmpeg_version(
     }
 }
 
-impl AsyncDecoder for FFmpegCliH264Decoder {
+impl AsyncDecoder for FFmpegCliDecoder {
     fn submit_chunk(&mut self, chunk: Chunk) -> crate::decode::Result<()> {
         re_tracing::profile_function!();
 
@@ -927,9 +1039,12 @@ impl AsyncDecoder for FFmpegCliH264Decoder {
         re_log::trace!("Resetting ffmpeg decoder {}", self.debug_name);
         self.ffmpeg = FFmpegProcessAndListener::new(
             &self.debug_name,
+            self.codec,
             self.on_output.clone(),
             &video_descr.encoding_details,
             self.ffmpeg_path.as_deref(),
+            self.hw_acceleration,
+            self.num_threads,
         )?;
         Ok(())
     }
@@ -952,6 +1067,35 @@ fn write_bytes(stream: &mut dyn std::io::Write, data: &[u8]) -> Result<(), Error
     stream.write_all(data).map_err(Error::FailedToWriteToFfmpeg)
 }
 
+/// The 32 byte header of an IVF file, see <https://wiki.multimedia.cx/index.php/IVF>.
+fn ivf_file_header(fourcc: [u8; 4], [width, height]: [u16; 2]) -> [u8; 32] {
+    let mut header = [0; 32];
+    header[0..4].copy_from_slice(b"DKIF");
+    header[4..6].copy_from_slice(&0_u16.to_le_bytes()); // Version.
+    header[6..8].copy_from_slice(&32_u16.to_le_bytes()); // Header size.
+    header[8..12].copy_from_slice(&fourcc);
+    header[12..14].copy_from_slice(&width.to_le_bytes());
+    header[14..16].copy_from_slice(&height.to_le_bytes());
+    // Made up time base, since we pass frames through and keep track of timestamps ourselves.
+    header[16..20].copy_from_slice(&30_u32.to_le_bytes()); // Time base denominator.
+    header[20..24].copy_from_slice(&1_u32.to_le_bytes()); // Time base numerator.
+    // Number of frames (unknown for a stream) and 4 unused bytes remain zero.
+    header
+}
+
+/// Writes a sample as an IVF frame, i.e. prefixed by its size and a timestamp.
+fn write_ivf_frame(stream: &mut dyn std::io::Write, chunk: &Chunk) -> Result<(), Error> {
+    let size = u32::try_from(chunk.data.len())
+        .map_err(|_err| Error::BadVideoData("Sample is larger than 4 GiB".to_owned()))?;
+
+    let mut frame_header = [0; 12];
+    frame_header[0..4].copy_from_slice(&size.to_le_bytes());
+    frame_header[4..12].copy_from_slice(&(chunk.sample_idx as u64).to_le_bytes());
+
+    write_bytes(stream, &frame_header)?;
+    write_bytes(stream, &chunk.data)
+}
+
 fn write_avc_chunk_to_nalu_stream(
     avcc: &re_mp4::Avc1Box,
     nalu_stream: &mut dyn std::io::Write,
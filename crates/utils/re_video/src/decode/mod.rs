@@ -230,33 +230,29 @@ pub fn new_decoder(
 
     #[cfg(not(target_arch = "wasm32"))]
     match video.codec {
-        #[cfg(feature = "av1")]
+        #[cfg(with_dav1d)]
         crate::VideoCodec::AV1 => {
-            #[cfg(linux_arm64)]
-            {
-                return Err(DecodeError::NoDav1dOnLinuxArm64);
-            }
-
-            #[cfg(with_dav1d)]
-            {
-                re_log::trace!("Decoding AV1…");
-                return Ok(Box::new(async_decoder_wrapper::AsyncDecoderWrapper::new(
+            re_log::trace!("Decoding AV1…");
+            Ok(Box::new(async_decoder_wrapper::AsyncDecoderWrapper::new(
+                debug_name.to_owned(),
+                Box::new(av1::SyncDav1dDecoder::new(
                     debug_name.to_owned(),
-                    Box::new(av1::SyncDav1dDecoder::new(debug_name.to_owned())?),
-                    on_output,
-                )));
-            }
+                    decode_settings.num_threads,
+                )?),
+                on_output,
+            )))
         }
 
+        // Without dav1d (e.g. on Linux ARM64), AV1 can still be decoded by FFmpeg.
+        #[cfg(all(with_ffmpeg, not(with_dav1d)))]
+        crate::VideoCodec::AV1 => new_ffmpeg_decoder(debug_name, video, decode_settings, on_output),
+
+        #[cfg(all(feature = "av1", linux_arm64, not(with_ffmpeg)))]
+        crate::VideoCodec::AV1 => Err(DecodeError::NoDav1dOnLinuxArm64),
+
         #[cfg(with_ffmpeg)]
-        crate::VideoCodec::H264 => {
-            re_log::trace!("Decoding H.264…");
-            Ok(Box::new(ffmpeg_h264::FFmpegCliH264Decoder::new(
-                debug_name.to_owned(),
-                &video.encoding_details,
-                on_output,
-                decode_settings.ffmpeg_path.clone(),
-            )?))
+        crate::VideoCodec::H264 | crate::VideoCodec::VP8 | crate::VideoCodec::VP9 => {
+            new_ffmpeg_decoder(debug_name, video, decode_settings, on_output)
         }
 
         _ => Err(DecodeError::UnsupportedCodec(
@@ -265,6 +261,28 @@ pub fn new_decoder(
     }
 }
 
+#[cfg(with_ffmpeg)]
+fn new_ffmpeg_decoder(
+    debug_name: &str,
+    video: &crate::VideoDataDescription,
+    decode_settings: &DecodeSettings,
+    on_output: impl Fn(Result<Frame>) + Send + Sync + 'static,
+) -> Result<Box<dyn AsyncDecoder>> {
+    re_log::trace!(
+        "Decoding {} with FFmpeg…",
+        video.human_readable_codec_string()
+    );
+    Ok(Box::new(ffmpeg_h264::FFmpegCliDecoder::new(
+        debug_name.to_owned(),
+        video.codec,
+        &video.encoding_details,
+        on_output,
+        decode_settings.ffmpeg_path.clone(),
+        decode_settings.hw_acceleration,
+        decode_settings.num_threads,
+    )?))
+}
+
 /// One chunk of encoded video data, representing a single [`crate::SampleMetadata`].
 ///
 /// For details on how to interpret the data, see [`crate::SampleMetadata`].
@@ -518,6 +536,10 @@ pub enum YuvMatrixCoefficients {
 ///
 /// On the web this directly corresponds to
 /// <https://www.w3.org/TR/webcodecs/#hardware-acceleration>
+///
+/// On native, H.264 is decoded by `FFmpeg`, which uses whatever hardware decoder is available
+/// (`VideoToolbox`, NVDEC, VAAPI, D3D11VA, …) unless software decoding is preferred,
+/// and falls back to software decoding otherwise.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum DecodeHardwareAcceleration {
@@ -548,6 +570,12 @@ pub struct DecodeSettings {
     /// If not provided, we use the path automatically determined by `ffmpeg_sidecar`.
     #[cfg(not(target_arch = "wasm32"))]
     pub ffmpeg_path: Option<std::path::PathBuf>,
+
+    /// How many threads a software decoder may use per video.
+    ///
+    /// If not provided, the decoder picks a number based on the number of cores.
+    /// Ignored on the web.
+    pub num_threads: Option<std::num::NonZeroUsize>,
 }
 
 impl std::fmt::Display for DecodeHardwareAcceleration {
@@ -41,11 +41,11 @@ use re_types::{
         RootContainer, ViewFit, ViewMaximized,
     },
     components::{
-        AggregationPolicy, AlbedoFactor, AxisLength, Color, DepthMeter, DrawOrder, FillMode,
-        FillRatio, GammaCorrection, GraphType, ImagePlaneDistance, LinearSpeed,
-        MagnificationFilter, MarkerSize, Name, Opacity, Position2D, Range1D, Scale3D,
-        SeriesVisible, ShowLabels, StrokeWidth, Text, Timestamp, TransformRelation, Translation3D,
-        ValueRange, VideoCodec, Visible,
+        AggregationPolicy, AlbedoFactor, AxisLength, Color, DepthMeter, DistortionCoefficient,
+        DistortionModel, DrawOrder, EulerConvention, FillMode, FillRatio, GammaCorrection,
+        GraphType, ImagePlaneDistance, LinearSpeed, MagnificationFilter, MarkerSize, Name, Opacity,
+        Position2D, Range1D, RotationEuler, Scale3D, SeriesVisible, ShowLabels, StrokeWidth, Text,
+        Timestamp, TransformRelation, Translation3D, ValueRange, VideoCodec, Visible,
     },
 };
 use re_viewer_context::gpu_bridge::colormap_edit_or_view_ui;
@@ -90,6 +90,7 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     registry.add_singleline_edit_or_view::<StrokeWidth>(edit_ui_points);
 
     // float min-max components:
+    registry.add_singleline_edit_or_view::<DistortionCoefficient>(edit_f32_min_to_max_float);
     registry.add_singleline_edit_or_view::<DrawOrder>(edit_f32_min_to_max_float);
     registry.add_singleline_edit_or_view::<ForceStrength>(edit_f64_min_to_max_float);
 
@@ -122,6 +123,8 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     registry.add_singleline_edit_or_view::<AggregationPolicy>(edit_view_enum);
     registry.add_singleline_edit_or_view::<BackgroundKind>(edit_view_enum);
     registry.add_singleline_edit_or_view::<Corner2D>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<DistortionModel>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<EulerConvention>(edit_view_enum);
     registry.add_singleline_edit_or_view::<Eye3DKind>(edit_view_enum);
     registry.add_singleline_edit_or_view::<FillMode>(edit_view_enum);
     registry.add_singleline_edit_or_view::<GraphType>(edit_view_enum);
@@ -153,6 +156,7 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     // Vec3 components:
     registry.add_singleline_edit_or_view::<Translation3D>(edit_or_view_vec3d);
     registry.add_singleline_edit_or_view::<Scale3D>(edit_or_view_vec3d);
+    registry.add_singleline_edit_or_view::<RotationEuler>(edit_or_view_vec3d);
 
     // Components that refer to views:
     registry.add_singleline_edit_or_view::<ViewMaximized>(view_view_id);
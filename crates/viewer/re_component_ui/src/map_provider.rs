@@ -27,6 +27,18 @@ impl VariantAvailableProvider<MapProvider> for MapProviderVariantAvailable {
             MapProvider::MapboxStreets | MapProvider::MapboxDark | MapProvider::MapboxSatellite => {
                 map_box_available
             }
+
+            MapProvider::Custom => {
+                if ctx.app_options().custom_map_tiles_url.is_empty() {
+                    VariantAvailable::No {
+                        reason_markdown: "A custom tiles URL is not set. You can set it in the \
+                        settings."
+                            .to_owned(),
+                    }
+                } else {
+                    VariantAvailable::Yes
+                }
+            }
         }
     }
 }
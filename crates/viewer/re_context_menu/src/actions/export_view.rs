@@ -0,0 +1,68 @@
+use re_viewer_context::{
+    Item, PublishedViewInfo, SystemCommand, SystemCommandSender as _, ViewId, ViewRectPublisher,
+};
+
+use crate::{ContextMenuAction, ContextMenuContext};
+
+/// Render the view at every time with data, and save the frames as PNG images or an MP4 video.
+///
+/// Uses the loop selection of the active timeline if there's one, and the whole timeline otherwise.
+pub struct ExportViewAction;
+
+impl ContextMenuAction for ExportViewAction {
+    fn supports_item(&self, ctx: &ContextMenuContext<'_>, item: &Item) -> bool {
+        let Item::View(view_id) = item else {
+            return false;
+        };
+
+        ctx.egui_context().memory_mut(|mem| {
+            mem.caches
+                .cache::<ViewRectPublisher>()
+                .get(view_id)
+                .is_some()
+        })
+    }
+
+    fn label(&self, _ctx: &ContextMenuContext<'_>) -> String {
+        "Export video…".to_owned()
+    }
+
+    fn process_view(&self, ctx: &ContextMenuContext<'_>, view_id: &ViewId) {
+        let Some(PublishedViewInfo { name, .. }) = ctx.egui_context().memory_mut(|mem| {
+            mem.caches
+                .cache::<ViewRectPublisher>()
+                .get(view_id)
+                .cloned()
+        }) else {
+            return;
+        };
+
+        let viewer_ctx = ctx.viewer_context;
+        let (timeline, time_range) = {
+            let time_ctrl = viewer_ctx.rec_cfg.time_ctrl.read();
+            let time_range = time_ctrl.active_loop_selection().or_else(|| {
+                time_ctrl
+                    .full_range(viewer_ctx.recording().times_per_timeline())
+                    .map(Into::into)
+            });
+            (*time_ctrl.timeline(), time_range)
+        };
+        let Some(time_range) = time_range else {
+            re_log::info!(
+                "Nothing to export: no data on the timeline {}",
+                timeline.name()
+            );
+            return;
+        };
+
+        viewer_ctx
+            .command_sender()
+            .send_system(SystemCommand::ExportView {
+                store_id: viewer_ctx.recording().store_id().clone(),
+                view_id: *view_id,
+                name,
+                timeline,
+                time_range,
+            });
+    }
+}
@@ -6,9 +6,14 @@ pub mod collapse_expand_all;
 pub mod move_contents_to_new_container;
 pub mod remove;
 pub mod show_hide;
+pub mod step_through_samples;
 
 mod copy_entity_path;
+#[cfg(not(target_arch = "wasm32"))]
+mod export_view;
 mod screenshot_action;
 
 pub use copy_entity_path::CopyEntityPathToClipboard;
+#[cfg(not(target_arch = "wasm32"))]
+pub use export_view::ExportViewAction;
 pub use screenshot_action::ScreenshotAction;
@@ -0,0 +1,57 @@
+use re_entity_db::InstancePath;
+use re_log_types::EntityPath;
+use re_viewer_context::{Item, ViewId};
+
+use crate::{ContextMenuAction, ContextMenuContext};
+
+/// Pin stepping back and forward in time to the samples of an entity, or unpin it again.
+pub(crate) struct StepThroughSamplesAction;
+
+impl StepThroughSamplesAction {
+    fn is_pinned(ctx: &ContextMenuContext<'_>, entity_path: &EntityPath) -> bool {
+        ctx.viewer_context.rec_cfg.time_ctrl.read().step_entity() == Some(entity_path)
+    }
+
+    fn toggle(ctx: &ContextMenuContext<'_>, entity_path: &EntityPath) {
+        let step_entity = (!Self::is_pinned(ctx, entity_path)).then(|| entity_path.clone());
+        ctx.viewer_context
+            .rec_cfg
+            .time_ctrl
+            .write()
+            .set_step_entity(step_entity);
+    }
+}
+
+impl ContextMenuAction for StepThroughSamplesAction {
+    fn supports_item(&self, _ctx: &ContextMenuContext<'_>, item: &Item) -> bool {
+        matches!(item, Item::InstancePath(_) | Item::DataResult(..))
+    }
+
+    fn label(&self, ctx: &ContextMenuContext<'_>) -> String {
+        let entity_path = match ctx.selection.single_item() {
+            Some(Item::InstancePath(instance_path) | Item::DataResult(_, instance_path)) => {
+                Some(&instance_path.entity_path)
+            }
+            _ => None,
+        };
+
+        if entity_path.is_some_and(|entity_path| Self::is_pinned(ctx, entity_path)) {
+            "Stop stepping through samples".to_owned()
+        } else {
+            "Step through samples".to_owned()
+        }
+    }
+
+    fn process_data_result(
+        &self,
+        ctx: &ContextMenuContext<'_>,
+        _view_id: &ViewId,
+        instance_path: &InstancePath,
+    ) {
+        Self::toggle(ctx, &instance_path.entity_path);
+    }
+
+    fn process_instance_path(&self, ctx: &ContextMenuContext<'_>, instance_path: &InstancePath) {
+        Self::toggle(ctx, &instance_path.entity_path);
+    }
+}
@@ -28,6 +28,7 @@ use actions::{
     move_contents_to_new_container::MoveContentsToNewContainerAction,
     remove::RemoveAction,
     show_hide::{HideAction, ShowAction},
+    step_through_samples::StepThroughSamplesAction,
 };
 use re_ui::menu::menu_style;
 use sub_menu::SubMenu;
@@ -167,9 +168,12 @@ fn action_list(
                 Box::new(RemoveAction),
                 Box::new(CopyEntityPathToClipboard),
             ],
+            vec![Box::new(StepThroughSamplesAction)],
             vec![
                 Box::new(actions::ScreenshotAction::CopyScreenshot),
                 Box::new(actions::ScreenshotAction::SaveScreenshot),
+                #[cfg(not(target_arch = "wasm32"))]
+                Box::new(actions::ExportViewAction),
             ],
             vec![
                 Box::new(CollapseExpandAllAction::ExpandAll),
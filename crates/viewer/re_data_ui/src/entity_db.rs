@@ -132,6 +132,8 @@ impl crate::DataUi for EntityDb {
             {
                 let &ChunkStoreConfig {
                     enable_changelog: _,
+                    enable_spill_to_disk: _,
+                    enable_static_history: _,
                     chunk_max_bytes,
                     chunk_max_rows,
                     chunk_max_rows_if_unsorted,
@@ -26,6 +26,13 @@ pub struct AppOptions {
     /// Disable garbage collection of the blueprint.
     pub blueprint_gc: bool,
 
+    /// Merge the many small chunks of fragmented recordings into larger ones in the background.
+    pub background_compaction: bool,
+
+    /// Hovering a sample in a plot highlights what was logged at that time in other views,
+    /// and hovering something in a spatial view shows a cursor at its time in plots.
+    pub linked_time_cursor: bool,
+
     /// What time zone to display timestamps in.
     #[serde(rename = "timestamp_format")]
     pub timestamp_format: TimestampFormat,
@@ -49,11 +56,23 @@ pub struct AppOptions {
     #[allow(clippy::doc_markdown)]
     pub video_decoder_ffmpeg_path: String,
 
+    /// How many threads a software video decoder may use per video, see [`DecodeSettings::num_threads`].
+    ///
+    /// Only set from the command line, so it isn't persisted.
+    #[serde(skip)]
+    pub video_decoder_threads: Option<std::num::NonZeroUsize>,
+
     /// Mapbox API key (used to enable Mapbox-based map view backgrounds).
     ///
     /// Can also be set using the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
     pub mapbox_access_token: String,
 
+    /// URL template of the tiles used by the custom map view background.
+    ///
+    /// `{z}`, `{x}` and `{y}` are replaced by the zoom level and the tile coordinates,
+    /// e.g. `http://localhost:8080/tiles/{z}/{x}/{y}.png` for a local tile server serving MBTiles.
+    pub custom_map_tiles_url: String,
+
     /// Path to the directory suitable for storing cache data.
     ///
     /// By cache data, we mean data that is safe to be garbage collected by the OS. Defaults to
@@ -80,13 +99,19 @@ impl Default for AppOptions {
 
             blueprint_gc: true,
 
+            background_compaction: true,
+
+            linked_time_cursor: false,
+
             timestamp_format: TimestampFormat::Utc,
 
             video_decoder_hw_acceleration: DecodeHardwareAcceleration::default(),
             video_decoder_override_ffmpeg_path: false,
             video_decoder_ffmpeg_path: String::new(),
+            video_decoder_threads: None,
 
             mapbox_access_token: String::new(),
+            custom_map_tiles_url: String::new(),
 
             #[cfg(not(target_arch = "wasm32"))]
             cache_directory: Self::default_cache_directory(),
@@ -128,6 +153,8 @@ impl AppOptions {
             ffmpeg_path: self
                 .video_decoder_override_ffmpeg_path
                 .then(|| std::path::PathBuf::from(&self.video_decoder_ffmpeg_path)),
+
+            num_threads: self.video_decoder_threads,
         }
     }
 }
@@ -1,7 +1,7 @@
-use re_chunk::{EntityPath, Timeline};
+use re_chunk::{EntityPath, Timeline, TimelineName};
 use re_chunk_store::external::re_chunk::Chunk;
 use re_data_source::DataSource;
-use re_log_types::{AbsoluteTimeRangeF, StoreId};
+use re_log_types::{AbsoluteTimeRange, AbsoluteTimeRangeF, StoreId};
 use re_ui::{UICommand, UICommandSender};
 
 use crate::RecordingOrTable;
@@ -86,6 +86,15 @@ pub enum SystemCommand {
     /// is both modified and changed in the same frame.
     DropEntity(StoreId, EntityPath),
 
+    /// Drop all data within the given time range on the given timeline from a store.
+    ///
+    /// Affects all entities. Static data is kept.
+    DropTimeRange {
+        store_id: StoreId,
+        timeline: TimelineName,
+        time_range: AbsoluteTimeRange,
+    },
+
     /// Show a timeline of the blueprint data.
     #[cfg(debug_assertions)]
     EnableInspectBlueprintTimeline(bool),
@@ -126,6 +135,21 @@ pub enum SystemCommand {
     /// Add a task, run on a background thread, that saves something to disk.
     #[cfg(not(target_arch = "wasm32"))]
     FileSaver(Box<dyn FnOnce() -> anyhow::Result<std::path::PathBuf> + Send + 'static>),
+
+    /// Render a view at every time with data in the given range, and save the frames as PNG images or an MP4 video.
+    ///
+    /// Asks the user where to save the export.
+    #[cfg(not(target_arch = "wasm32"))]
+    ExportView {
+        store_id: StoreId,
+        view_id: crate::ViewId,
+
+        /// Name of the view, used as the default file name.
+        name: String,
+
+        timeline: Timeline,
+        time_range: AbsoluteTimeRangeF,
+    },
 }
 
 impl std::fmt::Debug for SystemCommand {
@@ -10,7 +10,9 @@ use re_dataframe_ui::{ColumnBlueprint, default_display_name_for_column};
 use re_grpc_client::ConnectionRegistryHandle;
 use re_log_types::{EntityPathPart, EntryId};
 use re_protos::catalog::v1alpha1::EntryKind;
-use re_protos::manifest_registry::v1alpha1::DATASET_MANIFEST_ID_FIELD_NAME;
+use re_protos::manifest_registry::v1alpha1::{
+    DATASET_MANIFEST_ID_FIELD_NAME, ScanPartitionTableResponse,
+};
 use re_sorbet::{BatchType, ColumnDescriptorRef};
 use re_ui::alert::Alert;
 use re_ui::list_item::{ItemButton as _, ItemMenuButton};
@@ -225,14 +227,17 @@ impl Server {
             } else {
                 matches!(
                     desc.display_name().as_str(),
-                    RECORDING_LINK_COLUMN_NAME | DATASET_MANIFEST_ID_FIELD_NAME
+                    RECORDING_LINK_COLUMN_NAME
+                        | DATASET_MANIFEST_ID_FIELD_NAME
+                        | ScanPartitionTableResponse::PARTITION_TAGS
                 )
             };
 
             let column_sort_key = match desc.display_name().as_str() {
                 DATASET_MANIFEST_ID_FIELD_NAME => 0,
                 RECORDING_LINK_COLUMN_NAME => 1,
-                _ => 2,
+                ScanPartitionTableResponse::PARTITION_TAGS => 2,
+                _ => 3,
             };
 
             let mut blueprint = ColumnBlueprint::default()
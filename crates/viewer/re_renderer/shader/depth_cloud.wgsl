@@ -53,6 +53,9 @@ struct DepthCloudInfo {
 
     /// Changes between the opaque and outline draw-phases.
     radius_boost_in_ui_points: f32,
+
+    /// Depth values outside of this world-space range are not shown.
+    clip_depth_in_world: vec2f,
 };
 
 @group(1) @binding(0)
@@ -118,7 +121,9 @@ fn compute_point_data(quad_idx: u32) -> PointData {
 
     var data: PointData;
 
-    if 0.0 < world_space_depth && world_space_depth < f32max {
+    let clip_depth = depth_cloud_info.clip_depth_in_world;
+    if 0.0 < world_space_depth && world_space_depth < f32max &&
+       clip_depth.x <= world_space_depth && world_space_depth <= clip_depth.y {
         // TODO(cmc): albedo textures
         let normalized_depth =
             (world_space_depth - depth_cloud_info.min_max_depth_in_world.x) /
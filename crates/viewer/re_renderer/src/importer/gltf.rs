@@ -20,11 +20,6 @@ pub enum GltfImportError {
     #[error("Unsupported texture format {0:?}.")]
     UnsupportedTextureFormat(gltf::image::Format),
 
-    #[error(
-        "Mesh {mesh_name:?} has multiple sets of texture coordinates. Only a single one is supported."
-    )]
-    MultipleTextureCoordinateSets { mesh_name: String },
-
     #[error("Mesh {mesh_name:?} has no triangles.")]
     NoIndices { mesh_name: String },
 
@@ -172,7 +167,14 @@ fn import_mesh(
     // Primitives map to vertex/index ranges for us as we store all vertices/indices into the same vertex/index buffer.
     // (this means we loose the rarely used ability to re-use vertex/indices between meshes, but shouldn't loose any abilities otherwise)
     for primitive in mesh.primitives() {
-        let set = 0;
+        let primitive_material = primitive.material();
+        let pbr_material = primitive_material.pbr_metallic_roughness();
+
+        // We only support a single set of texture coordinates per vertex,
+        // so pick whichever set the albedo texture is sampled with.
+        let texcoord_set = pbr_material
+            .base_color_texture()
+            .map_or(0, |texture| texture.tex_coord());
 
         let reader = primitive.reader(|buffer| Some(&*buffers[buffer.index()]));
 
@@ -198,7 +200,7 @@ fn import_mesh(
             return Err(GltfImportError::NoPositions { mesh_name });
         }
 
-        if let Some(colors) = reader.read_colors(set) {
+        if let Some(colors) = reader.read_colors(0) {
             vertex_colors.extend(
                 colors
                     .into_rgba_u8()
@@ -214,19 +216,13 @@ fn import_mesh(
             vertex_normals.resize(vertex_positions.len(), glam::Vec3::ZERO);
         }
 
-        if let Some(primitive_texcoords) = reader.read_tex_coords(set) {
+        if let Some(primitive_texcoords) = reader.read_tex_coords(texcoord_set) {
             vertex_texcoords.extend(primitive_texcoords.into_f32().map(glam::Vec2::from));
         } else {
             vertex_texcoords.resize(vertex_positions.len(), glam::Vec2::ZERO);
         }
 
-        let primitive_material = primitive.material();
-        let pbr_material = primitive_material.pbr_metallic_roughness();
-
         let albedo = if let Some(texture) = pbr_material.base_color_texture() {
-            if texture.tex_coord() != 0 {
-                return Err(GltfImportError::MultipleTextureCoordinateSets { mesh_name });
-            }
             let texture = &texture.texture();
 
             let sampler = &texture.sampler();
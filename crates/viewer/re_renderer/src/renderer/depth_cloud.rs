@@ -78,7 +78,13 @@ mod gpu_data {
         pub _row_padding: [f32; 1],
 
         // ---
-        pub _end_padding: [wgpu_buffer_types::PaddingRow; 16 - 4 - 3 - 1 - 1 - 1],
+        /// Depth values outside of this world-space range are not shown.
+        pub clip_depth_in_world: [f32; 2],
+
+        pub _clip_row_padding: [f32; 2],
+
+        // ---
+        pub _end_padding: [wgpu_buffer_types::PaddingRow; 16 - 4 - 3 - 1 - 1 - 1 - 1],
     }
 
     impl DepthCloudInfoUBO {
@@ -92,6 +98,7 @@ mod gpu_data {
                 world_depth_from_texture_depth,
                 point_radius_from_world_depth,
                 min_max_depth_in_world,
+                clip_depth_in_world,
                 depth_dimensions: _,
                 depth_texture,
                 colormap,
@@ -123,6 +130,8 @@ mod gpu_data {
                 radius_boost_in_ui_points,
                 picking_layer_object_id: *picking_object_id,
                 _row_padding: Default::default(),
+                clip_depth_in_world: *clip_depth_in_world,
+                _clip_row_padding: Default::default(),
                 _end_padding: Default::default(),
             })
         }
@@ -148,6 +157,9 @@ pub struct DepthCloud {
     /// The minimum and maximum depth value in world-space, for use with the colormap.
     pub min_max_depth_in_world: [f32; 2],
 
+    /// Depth values outside of this world-space range are not shown.
+    pub clip_depth_in_world: [f32; 2],
+
     /// The dimensions of the depth texture in pixels.
     pub depth_dimensions: glam::UVec2,
 
@@ -247,6 +247,20 @@ impl Video {
         &mut self.video_description
     }
 
+    /// The settings used for all new decoders.
+    #[inline]
+    pub fn decode_settings(&self) -> &DecodeSettings {
+        &self.decode_settings
+    }
+
+    /// Changes how the video is decoded, dropping all decoders that were created with other settings.
+    pub fn set_decode_settings(&mut self, decode_settings: DecodeSettings) {
+        if self.decode_settings != decode_settings {
+            self.decode_settings = decode_settings;
+            self.players.get_mut().clear();
+        }
+    }
+
     /// Resets all decoders and purges any cached frames.
     ///
     /// This is useful when the video description has changed since the decoders were created.
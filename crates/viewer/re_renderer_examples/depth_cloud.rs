@@ -176,6 +176,7 @@ impl RenderDepthClouds {
                     world_depth_from_texture_depth: 1.0,
                     point_radius_from_world_depth: *point_radius_from_world_depth,
                     min_max_depth_in_world: [0.0, 5.0],
+                    clip_depth_in_world: [0.0, f32::MAX],
                     depth_dimensions: depth.dimensions,
                     depth_texture: depth.texture.clone(),
                     colormap: re_renderer::Colormap::Turbo,
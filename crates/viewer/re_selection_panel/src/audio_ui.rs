@@ -0,0 +1,51 @@
+use re_entity_db::EntityDb;
+use re_log_types::EntityPath;
+use re_types::archetypes::Waveform;
+use re_ui::{
+    UiExt as _,
+    list_item::{self, PropertyContent},
+};
+use re_viewer_context::ViewerContext;
+
+/// Mute and solo toggles for an entity with audio, i.e. [`Waveform`] samples.
+///
+/// Shows nothing for other entities.
+pub fn audio_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    db: &EntityDb,
+    entity_path: &EntityPath,
+) {
+    if db.store_id() != ctx.recording().store_id()
+        || !db
+            .storage_engine()
+            .store()
+            .entity_has_component(entity_path, &Waveform::descriptor_samples())
+    {
+        return;
+    }
+
+    ui.section_collapsing_header("Audio").show(ui, |ui| {
+        list_item::list_item_scope(ui, "audio", |ui| {
+            let mut audio_mixer = ctx.rec_cfg.audio_mixer.write();
+
+            let mut muted = audio_mixer.muted.contains(entity_path);
+            ui.list_item_flat_noninteractive(
+                PropertyContent::new("Mute").value_bool_mut(&mut muted),
+            )
+            .on_hover_text("Don't play this entity when playing the timeline");
+            audio_mixer.set_muted(entity_path, muted);
+
+            let mut soloed = audio_mixer.soloed.contains(entity_path);
+            ui.list_item_flat_noninteractive(
+                PropertyContent::new("Solo").value_bool_mut(&mut soloed),
+            )
+            .on_hover_text("Only play soloed entities when playing the timeline");
+            audio_mixer.set_soloed(entity_path, soloed);
+        });
+
+        if cfg!(not(target_arch = "wasm32")) {
+            ui.weak("Audio is only played in the web viewer");
+        }
+    });
+}
@@ -0,0 +1,181 @@
+use re_types::{
+    Component, ComponentDescriptor,
+    archetypes::DepthImage,
+    components::{Colormap, DepthMeter, FillRatio, Range1D},
+};
+use re_ui::{
+    UiExt as _,
+    list_item::{self, PropertyContent},
+};
+use re_view::{HybridLatestAtResults, latest_at_with_blueprint_resolved_data};
+use re_viewer_context::{
+    ComponentFallbackProvider, DataResult, MaybeMutRef, QueryContext, ViewContext,
+    gpu_bridge::colormap_edit_or_view_ui,
+};
+
+/// Identifier of the visualizer for depth images, see `re_view_spatial`.
+const DEPTH_IMAGE_VISUALIZER: &str = "DepthImage";
+
+/// How a depth image is turned into a point cloud in 3D views.
+///
+/// Changes are stored as overrides of the entity's depth image components,
+/// so they show up in the blueprint just like overrides made in the visualizer list.
+pub fn depth_backprojection_ui(ctx: &ViewContext<'_>, ui: &mut egui::Ui, data_result: &DataResult) {
+    if !data_result
+        .visualizers
+        .iter()
+        .any(|visualizer| visualizer.as_str() == DEPTH_IMAGE_VISUALIZER)
+    {
+        return;
+    }
+
+    let visualizers = ctx.new_visualizer_collection();
+    let Ok(visualizer) = visualizers.get_by_identifier(DEPTH_IMAGE_VISUALIZER.into()) else {
+        return;
+    };
+
+    let descriptors = [
+        DepthImage::descriptor_meter(),
+        DepthImage::descriptor_clip_range(),
+        DepthImage::descriptor_point_fill_ratio(),
+        DepthImage::descriptor_colormap(),
+    ];
+
+    let query = ctx.current_query();
+    let query_ctx = ctx.query_context(data_result, &query);
+    let results = latest_at_with_blueprint_resolved_data(
+        ctx,
+        None,
+        &query,
+        data_result,
+        descriptors.iter(),
+        false,
+    );
+    let fallback_provider = visualizer.fallback_provider();
+
+    let (
+        Some(meter_before),
+        Some(clip_range_before),
+        Some(fill_ratio_before),
+        Some(colormap_before),
+    ) = (
+        resolved::<DepthMeter>(&results, fallback_provider, &query_ctx, &descriptors[0]),
+        resolved::<Range1D>(&results, fallback_provider, &query_ctx, &descriptors[1]),
+        resolved::<FillRatio>(&results, fallback_provider, &query_ctx, &descriptors[2]),
+        resolved::<Colormap>(&results, fallback_provider, &query_ctx, &descriptors[3]),
+    )
+    else {
+        return;
+    };
+
+    let mut meter = meter_before;
+    let mut clip_range = clip_range_before;
+    let mut fill_ratio = fill_ratio_before;
+    let mut colormap = colormap_before;
+
+    let has_override = descriptors.iter().any(|descr| {
+        data_result
+            .property_overrides
+            .component_overrides
+            .contains_key(descr)
+    });
+
+    ui.section_collapsing_header("Depth backprojection")
+        .default_open(has_override)
+        .show(ui, |ui| {
+            list_item::list_item_scope(ui, "depth_backprojection", |ui| {
+                ui.list_item_flat_noninteractive(PropertyContent::new("Meter").value_fn(
+                    |ui, _| {
+                        let speed = meter.0.0 * 0.01;
+                        ui.add(
+                            egui::DragValue::new(&mut meter.0.0)
+                                .range(f32::MIN_POSITIVE..=f32::MAX)
+                                .speed(speed),
+                        );
+                    },
+                ))
+                .on_hover_text(
+                    "How many depth units make a meter, e.g. 1000 for depths in millimeters",
+                );
+
+                ui.list_item_flat_noninteractive(PropertyContent::new("Clip range").value_fn(
+                    |ui, _| {
+                        let (start, end) = (clip_range.start(), clip_range.end());
+                        ui.add(
+                            egui::DragValue::new(clip_range.start_mut())
+                                .range(0.0..=end)
+                                .speed(0.01)
+                                .suffix(" m"),
+                        );
+                        ui.add(
+                            egui::DragValue::new(clip_range.end_mut())
+                                .range(start..=f64::INFINITY)
+                                .speed(0.01)
+                                .suffix(" m"),
+                        );
+                    },
+                ))
+                .on_hover_text("Points closer or further away than this are not shown");
+
+                ui.list_item_flat_noninteractive(PropertyContent::new("Point size").value_fn(
+                    |ui, _| {
+                        ui.add(
+                            egui::DragValue::new(&mut fill_ratio.0.0)
+                                .range(0.0..=10.0)
+                                .speed(0.01),
+                        );
+                    },
+                ))
+                .on_hover_text(
+                    "Point radius relative to the distance between neighboring pixels \
+                    at the same depth",
+                );
+
+                ui.list_item_flat_noninteractive(PropertyContent::new("Colormap").value_fn(
+                    |ui, _| {
+                        colormap_edit_or_view_ui(
+                            ctx.viewer_ctx,
+                            ui,
+                            &mut MaybeMutRef::MutRef(&mut colormap),
+                        );
+                    },
+                ));
+            });
+
+            if has_override && ui.button("Reset").clicked() {
+                for descr in &descriptors {
+                    ctx.clear_blueprint_component(
+                        data_result.override_path().clone(),
+                        descr.clone(),
+                    );
+                }
+            }
+        });
+
+    let override_path = data_result.override_path();
+    if meter != meter_before {
+        ctx.save_blueprint_component(override_path.clone(), &descriptors[0], &meter);
+    }
+    if clip_range != clip_range_before {
+        ctx.save_blueprint_component(override_path.clone(), &descriptors[1], &clip_range);
+    }
+    if fill_ratio != fill_ratio_before {
+        ctx.save_blueprint_component(override_path.clone(), &descriptors[2], &fill_ratio);
+    }
+    if colormap != colormap_before {
+        ctx.save_blueprint_component(override_path.clone(), &descriptors[3], &colormap);
+    }
+}
+
+/// The value of a component after overrides, store, defaults and finally the visualizer's fallback.
+fn resolved<C: Component>(
+    results: &HybridLatestAtResults<'_>,
+    fallback_provider: &dyn ComponentFallbackProvider,
+    query_ctx: &QueryContext<'_>,
+    component_descr: &ComponentDescriptor,
+) -> Option<C> {
+    results.get_mono(component_descr).or_else(|| {
+        let fallback = fallback_provider.fallback_for(query_ctx, component_descr);
+        C::from_arrow(&fallback).ok()?.into_iter().next()
+    })
+}
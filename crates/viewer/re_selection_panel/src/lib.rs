@@ -2,11 +2,15 @@
 
 #![warn(clippy::iter_over_hash_type)] //  TODO(#6198): enable everywhere
 
+mod audio_ui;
 mod defaults_ui;
+mod depth_backprojection_ui;
 mod item_heading_no_breadcrumbs;
 mod item_heading_with_breadcrumbs;
 mod item_title;
+mod related_entities_ui;
 mod selection_panel;
+mod time_alignment_ui;
 mod view_entity_picker;
 mod view_space_origin_ui;
 mod visible_time_range_ui;
@@ -0,0 +1,156 @@
+use re_chunk::LatestAtQuery;
+use re_data_ui::item_ui;
+use re_entity_db::EntityDb;
+use re_log_types::EntityPath;
+use re_types::{Archetype as _, archetypes::EntityRelations, components};
+use re_ui::{
+    UiExt as _,
+    list_item::{self, PropertyContent},
+};
+use re_viewer_context::ViewerContext;
+
+/// One side of a relation logged with [`EntityRelations`].
+struct Relation {
+    kind: Option<String>,
+    entity_path: EntityPath,
+}
+
+/// Lists the entities related to `entity_path`, in both directions, see [`EntityRelations`].
+///
+/// Shows nothing if there are none.
+pub fn related_entities_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    query: &LatestAtQuery,
+    db: &EntityDb,
+    entity_path: &EntityPath,
+) {
+    let outgoing = outgoing_relations(db, query, entity_path);
+    let incoming = incoming_relations(db, query, entity_path);
+    if outgoing.is_empty() && incoming.is_empty() {
+        return;
+    }
+
+    ui.section_collapsing_header("Related entities")
+        .show(ui, |ui| {
+            list_item::list_item_scope(ui, "related_entities", |ui| {
+                for (relations, arrow) in [(outgoing, "→"), (incoming, "←")] {
+                    for Relation { kind, entity_path } in relations {
+                        let label = format!("{} {arrow}", kind.as_deref().unwrap_or("related"));
+                        ui.list_item_flat_noninteractive(PropertyContent::new(label).value_fn(
+                            |ui, _| {
+                                item_ui::entity_path_button(ctx, query, db, ui, None, &entity_path);
+                            },
+                        ));
+                    }
+                }
+            });
+        });
+}
+
+/// The entities that `entity_path` relates to.
+fn outgoing_relations(
+    db: &EntityDb,
+    query: &LatestAtQuery,
+    entity_path: &EntityPath,
+) -> Vec<Relation> {
+    let results = db.latest_at(query, entity_path, EntityRelations::all_components().iter());
+    let Some(targets) =
+        results.component_batch::<components::EntityPath>(&EntityRelations::descriptor_targets())
+    else {
+        return Vec::new();
+    };
+    let kinds = results
+        .component_batch::<components::Text>(&EntityRelations::descriptor_kinds())
+        .unwrap_or_default();
+
+    targets
+        .iter()
+        .enumerate()
+        .map(|(i, target)| Relation {
+            kind: kinds.get(i).map(|kind| kind.as_str().to_owned()),
+            entity_path: EntityPath::from(target.as_str()),
+        })
+        .collect()
+}
+
+/// The entities that relate to `entity_path`.
+fn incoming_relations(
+    db: &EntityDb,
+    query: &LatestAtQuery,
+    entity_path: &EntityPath,
+) -> Vec<Relation> {
+    re_tracing::profile_function!();
+
+    let descr_targets = EntityRelations::descriptor_targets();
+    let mut sources = db
+        .entity_paths()
+        .into_iter()
+        .filter(|source| {
+            source != &entity_path
+                && db
+                    .storage_engine()
+                    .store()
+                    .entity_has_component(source, &descr_targets)
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    sources.sort();
+
+    sources
+        .into_iter()
+        .flat_map(|source| {
+            outgoing_relations(db, query, &source)
+                .into_iter()
+                .filter(|relation| &relation.entity_path == entity_path)
+                .map(move |relation| Relation {
+                    kind: relation.kind,
+                    entity_path: source.clone(),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::sync::Arc;
+
+    use re_chunk::{Chunk, RowId};
+    use re_log_types::{StoreId, StoreKind, TimePoint};
+
+    use super::*;
+
+    #[test]
+    fn relations_in_both_directions() {
+        let mut db = EntityDb::new(StoreId::random(StoreKind::Recording, "test_app"));
+
+        let chunk = Chunk::builder("detections/0")
+            .with_archetype(
+                RowId::new(),
+                TimePoint::STATIC,
+                &EntityRelations::new(["/camera/front", "/tracks/7"]).with_kinds(["source"]),
+            )
+            .build()
+            .unwrap();
+        db.add_chunk(&Arc::new(chunk)).unwrap();
+
+        let query = LatestAtQuery::latest(re_log_types::TimelineName::log_tick());
+
+        let outgoing = outgoing_relations(&db, &query, &"detections/0".into());
+        assert_eq!(2, outgoing.len());
+        assert_eq!(Some("source"), outgoing[0].kind.as_deref());
+        assert_eq!(EntityPath::from("camera/front"), outgoing[0].entity_path);
+        assert_eq!(None, outgoing[1].kind);
+        assert_eq!(EntityPath::from("tracks/7"), outgoing[1].entity_path);
+
+        let incoming = incoming_relations(&db, &query, &"camera/front".into());
+        assert_eq!(1, incoming.len());
+        assert_eq!(Some("source"), incoming[0].kind.as_deref());
+        assert_eq!(EntityPath::from("detections/0"), incoming[0].entity_path);
+
+        assert!(incoming_relations(&db, &query, &"detections/0".into()).is_empty());
+    }
+}
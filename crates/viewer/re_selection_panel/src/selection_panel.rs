@@ -20,9 +20,13 @@ use re_viewer_context::{
 use re_viewport_blueprint::{ViewportBlueprint, ui::show_add_view_or_container_modal};
 
 use crate::{
+    audio_ui::audio_ui,
     defaults_ui::view_components_defaults_section_ui,
+    depth_backprojection_ui::depth_backprojection_ui,
     item_heading_no_breadcrumbs::item_title_list_item,
     item_heading_with_breadcrumbs::item_heading_with_breadcrumbs,
+    related_entities_ui::related_entities_ui,
+    time_alignment_ui::time_alignment_ui_for_data_result,
     view_entity_picker::ViewEntityPicker,
     visible_time_range_ui::{
         visible_time_range_ui_for_data_result, visible_time_range_ui_for_view,
@@ -349,6 +353,11 @@ impl SelectionPanel {
             });
         }
 
+        if let Item::InstancePath(instance_path) | Item::DataResult(_, instance_path) = item {
+            related_entities_ui(ctx, ui, &query, db, &instance_path.entity_path);
+            audio_ui(ctx, ui, db, &instance_path.entity_path);
+        }
+
         match item {
             Item::StoreId(_) => {
                 ui.section_collapsing_header("Properties").show(ui, |ui| {
@@ -554,10 +563,14 @@ fn entity_selection_ui(
     if let Some(view) = viewport.view(view_id) {
         let view_ctx = view.bundle_context_with_states(ctx, view_states);
         visualizer_ui(&view_ctx, view, entity_path, ui);
+        if let Some(data_result) = &data_result {
+            depth_backprojection_ui(&view_ctx, ui, data_result);
+        }
     }
 
     if let Some(data_result) = &data_result {
         visible_time_range_ui_for_data_result(ctx, ui, data_result);
+        time_alignment_ui_for_data_result(ctx, ui, data_result);
     }
 }
 
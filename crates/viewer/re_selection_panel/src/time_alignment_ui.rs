@@ -0,0 +1,91 @@
+use re_log_types::TimeType;
+use re_types::blueprint::archetypes::EntityTimeAlignment;
+use re_ui::{
+    UiExt as _,
+    list_item::{self, PropertyContent},
+};
+use re_viewer_context::{DataResult, ViewerContext};
+
+/// Time offset and playback rate of an entity in a view, to line up data recorded with skewed clocks.
+pub fn time_alignment_ui_for_data_result(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    data_result: &DataResult,
+) {
+    let time_alignment_before = *data_result.time_alignment();
+    let mut time_alignment = time_alignment_before;
+
+    let time_type = ctx.rec_cfg.time_ctrl.read().time_type();
+    let has_override = [
+        EntityTimeAlignment::descriptor_time_offset(),
+        EntityTimeAlignment::descriptor_playback_rate(),
+    ]
+    .iter()
+    .any(|descr| {
+        data_result
+            .property_overrides
+            .component_overrides
+            .contains_key(descr)
+    });
+
+    ui.section_collapsing_header("Time alignment")
+        .default_open(has_override)
+        .show(ui, |ui| {
+            list_item::list_item_scope(ui, "time_alignment", |ui| {
+                ui.list_item_flat_noninteractive(PropertyContent::new("Time offset").value_fn(
+                    |ui, _| match time_type {
+                        TimeType::Sequence => {
+                            ui.add(egui::DragValue::new(&mut time_alignment.offset));
+                        }
+                        TimeType::DurationNs | TimeType::TimestampNs => {
+                            let mut offset_secs = time_alignment.offset as f64 * 1e-9;
+                            if ui
+                                .add(
+                                    egui::DragValue::new(&mut offset_secs)
+                                        .speed(0.01)
+                                        .suffix("s"),
+                                )
+                                .changed()
+                            {
+                                time_alignment.offset = (offset_secs * 1e9).round() as i64;
+                            }
+                        }
+                    },
+                ))
+                .on_hover_text("Shift the data of the entity and its children along the timeline");
+
+                ui.list_item_flat_noninteractive(PropertyContent::new("Playback rate").value_fn(
+                    |ui, _| {
+                        ui.add(
+                            egui::DragValue::new(&mut time_alignment.rate)
+                                .range(0.001..=1000.0)
+                                .speed(0.01)
+                                .suffix("×"),
+                        );
+                    },
+                ))
+                .on_hover_text(
+                    "How fast the clock of the data runs relative to the timeline, \
+                    e.g. 1.001 for a sensor whose clock drifts ahead",
+                );
+            });
+
+            if has_override && ui.button("Reset").clicked() {
+                for descr in [
+                    EntityTimeAlignment::descriptor_time_offset(),
+                    EntityTimeAlignment::descriptor_playback_rate(),
+                ] {
+                    ctx.clear_blueprint_component(data_result.override_path().clone(), descr);
+                }
+            }
+        });
+
+    if time_alignment != time_alignment_before {
+        ctx.save_blueprint_archetype(
+            data_result.override_path().clone(),
+            &EntityTimeAlignment::update_fields()
+                .with_time_offset(time_alignment.offset)
+                .with_playback_rate(time_alignment.rate),
+        );
+    }
+}
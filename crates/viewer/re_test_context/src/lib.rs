@@ -565,6 +565,17 @@ impl TestContext {
                         .drop_entity_path_recursive(&entity_path);
                 }
 
+                SystemCommand::DropTimeRange {
+                    store_id,
+                    timeline,
+                    time_range,
+                } => {
+                    self.store_hub
+                        .get_mut()
+                        .entity_db_mut(&store_id)
+                        .drop_time_range(&timeline, time_range);
+                }
+
                 SystemCommand::SetSelection(item) => {
                     self.selection_state.lock().set_selection(item);
                 }
@@ -610,7 +621,7 @@ impl TestContext {
                 SystemCommand::EnableInspectBlueprintTimeline(_) => handled = false,
 
                 #[cfg(not(target_arch = "wasm32"))]
-                SystemCommand::FileSaver(_) => handled = false,
+                SystemCommand::FileSaver(_) | SystemCommand::ExportView { .. } => handled = false,
             }
 
             if !handled {
@@ -0,0 +1,108 @@
+use egui::{CursorIcon, Rect};
+
+use re_log_types::AbsoluteTimeRangeF;
+use re_ui::UiExt as _;
+use re_viewer_context::{Bookmarks, Looping, TimeControl};
+
+use super::time_ranges_ui::TimeRangesUi;
+
+/// Width of the flags marking bookmarks on the timeline.
+const FLAG_WIDTH: f32 = 10.0;
+
+/// Shows the bookmarks of the active timeline as flags on top of the timeline.
+///
+/// Clicking a flag moves the time there, its context menu renames or removes it.
+pub fn bookmarks_ui(
+    bookmarks: &mut Bookmarks,
+    time_ctrl: &mut TimeControl,
+    time_ranges_ui: &TimeRangesUi,
+    ui: &egui::Ui,
+    time_area_painter: &egui::Painter,
+    timeline_rect: &Rect,
+) {
+    let timeline = *time_ctrl.timeline().name();
+    let color = ui.tokens().loop_selection_color.to_opaque();
+
+    let mut renamed = None;
+    let mut removed = None;
+
+    for (index, bookmark) in bookmarks.on_timeline(&timeline).iter().enumerate() {
+        let Some(x) = time_ranges_ui.x_from_time_f32(bookmark.time.into()) else {
+            continue;
+        };
+        if !timeline_rect.x_range().contains(x) {
+            continue;
+        }
+
+        time_area_painter.vline(
+            x,
+            timeline_rect.top()..=time_area_painter.clip_rect().bottom(),
+            egui::Stroke::new(1.0, color.gamma_multiply(0.5)),
+        );
+
+        let flag_rect = Rect::from_min_size(
+            egui::pos2(x, timeline_rect.top()),
+            egui::vec2(FLAG_WIDTH, 0.5 * timeline_rect.height()),
+        );
+        time_area_painter.add(egui::Shape::convex_polygon(
+            vec![
+                flag_rect.left_top(),
+                flag_rect.right_top(),
+                egui::pos2(flag_rect.left() + 0.6 * FLAG_WIDTH, flag_rect.center().y),
+                flag_rect.right_bottom(),
+                flag_rect.left_bottom(),
+            ],
+            color,
+            egui::Stroke::NONE,
+        ));
+
+        let response = ui
+            .interact(
+                flag_rect.expand(2.0),
+                ui.id().with(("bookmark", index)),
+                egui::Sense::click(),
+            )
+            .on_hover_text(&bookmark.name);
+
+        if response.hovered() {
+            // Also stops the time marker from jumping here when pressing the button.
+            ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+        }
+
+        if response.clicked() {
+            time_ctrl.set_time(bookmark.time);
+            time_ctrl.pause();
+        }
+
+        let next = bookmarks.next(&timeline, bookmark.time);
+        response.context_menu(|ui| {
+            let mut name = bookmark.name.clone();
+            if ui.text_edit_singleline(&mut name).changed() {
+                renamed = Some((index, name));
+            }
+
+            if let Some(next) = next
+                && ui
+                    .button(format!("Select until {}", next.name))
+                    .on_hover_text("Set the loop selection to the time between the two bookmarks, e.g. to export it as a clip")
+                    .clicked()
+            {
+                time_ctrl.set_loop_selection(AbsoluteTimeRangeF::new(bookmark.time, next.time));
+                time_ctrl.set_looping(Looping::Selection);
+                ui.close();
+            }
+
+            if ui.button("Remove bookmark").clicked() {
+                removed = Some(index);
+                ui.close();
+            }
+        });
+    }
+
+    if let Some((index, name)) = renamed {
+        bookmarks.rename(&timeline, index, name);
+    }
+    if let Some(index) = removed {
+        bookmarks.remove(&timeline, index);
+    }
+}
@@ -5,8 +5,10 @@
 
 #![warn(clippy::iter_over_hash_type)] //  TODO(#6198): enable everywhere
 
+mod bookmarks_ui;
 mod data_density_graph;
 mod paint_ticks;
+mod playback_link_ui;
 mod recursive_chunks_per_timeline_subscriber;
 mod streams_tree_data;
 mod time_axis;
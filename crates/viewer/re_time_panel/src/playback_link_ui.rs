@@ -0,0 +1,75 @@
+use re_log_types::TimeType;
+use re_ui::UiExt as _;
+use re_viewer_context::{PlaybackLink, StoreHub, TimeControl, ViewerContext};
+
+/// Links the playback of the active recording to the other linked recordings, and aligns them.
+///
+/// Only shown if there is more than one recording to link.
+pub fn playback_link_ui(ctx: &ViewerContext<'_>, ui: &mut egui::Ui, time_ctrl: &TimeControl) {
+    let welcome_screen_app_id = StoreHub::welcome_screen_app_id();
+    let num_recordings = ctx
+        .storage_context
+        .bundle
+        .recordings()
+        .filter(|db| db.application_id() != &welcome_screen_app_id)
+        .count();
+    if num_recordings < 2 {
+        return;
+    }
+
+    let mut playback_link = ctx.rec_cfg.playback_link.write();
+
+    let is_linked = playback_link.is_some();
+    let hover_text = if is_linked {
+        "Playback is linked: playing, pausing and scrubbing moves all linked recordings"
+    } else {
+        "Link playback to other recordings, e.g. to compare runs of the same scenario"
+    };
+    if ui
+        .large_button_selected(&re_ui::icons::INTERNAL_LINK, is_linked)
+        .on_hover_text(hover_text)
+        .clicked()
+    {
+        *playback_link = if is_linked {
+            None
+        } else {
+            Some(PlaybackLink::default())
+        };
+    }
+
+    let Some(playback_link) = playback_link.as_mut() else {
+        return;
+    };
+
+    match time_ctrl.time_type() {
+        TimeType::Sequence => ui.add(egui::DragValue::new(&mut playback_link.offset)),
+        TimeType::DurationNs | TimeType::TimestampNs => {
+            let mut offset_secs = playback_link.offset as f64 * 1e-9;
+            let response = ui.add(
+                egui::DragValue::new(&mut offset_secs)
+                    .speed(0.01)
+                    .suffix("s"),
+            );
+            if response.changed() {
+                playback_link.offset = (offset_secs * 1e9).round() as i64;
+            }
+            response
+        }
+    }
+    .on_hover_text(
+        "Time offset of this recording, added to the shared time of all linked recordings.\n\
+        With all offsets at zero, recordings are aligned on absolute time.",
+    );
+
+    if let Some(time) = time_ctrl.time_i64()
+        && ui
+            .button("Align here")
+            .on_hover_text(
+                "Use the current time as the offset, \
+                so linked recordings line up with this moment",
+            )
+            .clicked()
+    {
+        playback_link.offset = time;
+    }
+}
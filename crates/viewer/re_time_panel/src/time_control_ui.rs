@@ -1,6 +1,6 @@
 use egui::NumExt as _;
 
-use re_entity_db::TimesPerTimeline;
+use re_entity_db::{EntityDb, TimesPerTimeline};
 use re_log_types::TimeType;
 use re_ui::{UICommand, UiExt as _, list_item};
 
@@ -14,9 +14,10 @@ impl TimeControlUi {
     pub fn timeline_selector_ui(
         &self,
         time_control: &mut TimeControl,
-        times_per_timeline: &TimesPerTimeline,
+        entity_db: &EntityDb,
         ui: &mut egui::Ui,
     ) {
+        let times_per_timeline = entity_db.times_per_timeline();
         time_control.select_a_valid_timeline(times_per_timeline);
 
         ui.scope(|ui| {
@@ -36,7 +37,8 @@ impl TimeControlUi {
                             )
                             .clicked()
                         {
-                            time_control.set_timeline(*timeline);
+                            time_control
+                                .set_timeline_aligned(*timeline, &entity_db.timeline_mappings());
                         }
                     }
                 })
@@ -92,16 +94,18 @@ You can also define your own timelines, e.g. for sensor time or camera frame num
     pub fn play_pause_ui(
         &self,
         time_control: &mut TimeControl,
-        times_per_timeline: &TimesPerTimeline,
+        entity_db: &EntityDb,
         ui: &mut egui::Ui,
     ) {
+        let times_per_timeline = entity_db.times_per_timeline();
         ui.horizontal(|ui| {
             ui.spacing_mut().item_spacing.x = 5.0; // from figma
             self.play_button_ui(time_control, ui, times_per_timeline);
             self.follow_button_ui(time_control, ui, times_per_timeline);
             self.pause_button_ui(time_control, ui);
-            self.step_time_button_ui(time_control, ui, times_per_timeline);
+            self.step_time_button_ui(time_control, ui, entity_db);
             self.loop_button_ui(time_control, ui);
+            self.step_entity_ui(time_control, ui);
         });
     }
 
@@ -156,22 +160,53 @@ You can also define your own timelines, e.g. for sensor time or camera frame num
         &self,
         time_control: &mut TimeControl,
         ui: &mut egui::Ui,
-        times_per_timeline: &TimesPerTimeline,
+        entity_db: &EntityDb,
     ) {
         if ui
             .large_button(&re_ui::icons::ARROW_LEFT)
-            .on_hover_ui(|ui| UICommand::PlaybackStepBack.tooltip_ui(ui))
+            .on_hover_ui(|ui| {
+                UICommand::PlaybackStepBack.tooltip_ui(ui);
+                if let Some(entity_path) = time_control.step_entity() {
+                    ui.label(format!("Steps to the previous sample of {entity_path}"));
+                }
+            })
             .clicked()
         {
-            time_control.step_time_back(times_per_timeline);
+            time_control.step_time_back(entity_db);
         }
 
         if ui
             .large_button(&re_ui::icons::ARROW_RIGHT)
-            .on_hover_ui(|ui| UICommand::PlaybackStepForward.tooltip_ui(ui))
+            .on_hover_ui(|ui| {
+                UICommand::PlaybackStepForward.tooltip_ui(ui);
+                if let Some(entity_path) = time_control.step_entity() {
+                    ui.label(format!("Steps to the next sample of {entity_path}"));
+                }
+            })
+            .clicked()
+        {
+            time_control.step_time_fwd(entity_db);
+        }
+    }
+
+    /// Shows the entity that stepping is pinned to, if any, with a button to unpin it.
+    #[allow(clippy::unused_self)]
+    fn step_entity_ui(&self, time_control: &mut TimeControl, ui: &mut egui::Ui) {
+        let Some(entity_path) = time_control.step_entity() else {
+            return;
+        };
+
+        ui.label(format!("Stepping: {entity_path}"))
+            .on_hover_text("Stepping back and forward goes to the samples of this entity");
+        if ui
+            .small_icon_button(
+                &re_ui::icons::CLOSE_SMALL,
+                "Stop stepping through this entity",
+            )
+            .on_hover_text("Step through all data again")
             .clicked()
         {
-            time_control.step_time_fwd(times_per_timeline);
+            time_control.set_step_entity(None);
         }
     }
 
@@ -17,7 +17,7 @@ use re_types::blueprint::components::PanelState;
 use re_types::reflection::ComponentDescriptorExt as _;
 use re_types_core::ComponentDescriptor;
 use re_ui::{ContextExt as _, DesignTokens, Help, UiExt as _, filter_widget, icons, list_item};
-use re_ui::{IconText, filter_widget::format_matching_text};
+use re_ui::{IconText, UICommand, filter_widget::format_matching_text};
 use re_viewer_context::{
     CollapseScope, HoverHighlight, Item, ItemCollection, ItemContext, RecordingConfig, TimeControl,
     TimeView, UiLayout, ViewerContext, VisitorControlFlow,
@@ -25,12 +25,13 @@ use re_viewer_context::{
 use re_viewport_blueprint::ViewportBlueprint;
 
 use crate::{
+    playback_link_ui::playback_link_ui,
     recursive_chunks_per_timeline_subscriber::PathRecursiveChunksPerTimelineStoreSubscriber,
     streams_tree_data::{EntityData, StreamsTreeData, components_for_entity},
     time_axis::TimelineAxis,
     time_control_ui::TimeControlUi,
     time_ranges_ui::TimeRangesUi,
-    {data_density_graph, paint_ticks, time_ranges_ui, time_selection_ui},
+    {bookmarks_ui, data_density_graph, paint_ticks, time_ranges_ui, time_selection_ui},
 };
 
 #[derive(Debug, Clone)]
@@ -348,34 +349,26 @@ impl TimePanel {
             ui.vertical(|ui| {
                 if has_more_than_one_time_point {
                     ui.horizontal(|ui| {
-                        let times_per_timeline = entity_db.times_per_timeline();
-                        self.time_control_ui
-                            .play_pause_ui(time_ctrl, times_per_timeline, ui);
+                        self.time_control_ui.play_pause_ui(time_ctrl, entity_db, ui);
 
                         self.time_control_ui.playback_speed_ui(time_ctrl, ui);
                         self.time_control_ui.fps_ui(time_ctrl, ui);
                     });
                 }
                 ui.horizontal(|ui| {
-                    self.time_control_ui.timeline_selector_ui(
-                        time_ctrl,
-                        entity_db.times_per_timeline(),
-                        ui,
-                    );
+                    self.time_control_ui
+                        .timeline_selector_ui(time_ctrl, entity_db, ui);
                     self.collapsed_time_marker_and_time(ui, ctx, entity_db, time_ctrl);
                 });
             });
         } else {
             // One row:
-            let times_per_timeline = entity_db.times_per_timeline();
-
             if has_more_than_one_time_point {
-                self.time_control_ui
-                    .play_pause_ui(time_ctrl, times_per_timeline, ui);
+                self.time_control_ui.play_pause_ui(time_ctrl, entity_db, ui);
             }
 
             self.time_control_ui
-                .timeline_selector_ui(time_ctrl, times_per_timeline, ui);
+                .timeline_selector_ui(time_ctrl, entity_db, ui);
 
             if has_more_than_one_time_point {
                 self.time_control_ui.playback_speed_ui(time_ctrl, ui);
@@ -507,13 +500,34 @@ impl TimePanel {
             &time_bg_area_painter,
             full_y_range,
         );
-        time_selection_ui::loop_selection_ui(
+        let loop_selection_response = time_selection_ui::loop_selection_ui(
             time_ctrl,
             &self.time_ranges_ui,
             ui,
             &time_bg_area_painter,
             &timeline_rect,
         );
+        if let Some(response) = loop_selection_response
+            && self.source == TimePanelSource::Recording
+        {
+            response.context_menu(|ui| {
+                UICommand::CopyTimeRangeLink.menu_button_ui(ui, ctx.command_sender());
+                UICommand::DeleteTimeSelection.menu_button_ui(ui, ctx.command_sender());
+                ui.separator();
+                UICommand::ExportClipRrd.menu_button_ui(ui, ctx.command_sender());
+                UICommand::ExportClipMcap.menu_button_ui(ui, ctx.command_sender());
+            });
+        }
+        if self.source == TimePanelSource::Recording {
+            bookmarks_ui::bookmarks_ui(
+                &mut ctx.rec_cfg.bookmarks.write(),
+                time_ctrl,
+                &self.time_ranges_ui,
+                ui,
+                &time_bg_area_painter,
+                &timeline_rect,
+            );
+        }
         let time_area_response = interact_with_streams_rect(
             &self.time_ranges_ui,
             time_ctrl,
@@ -1253,18 +1267,16 @@ impl TimePanel {
             // Responsive ui for narrow screens, e.g. mobile. Split the controls into two rows.
             ui.vertical(|ui| {
                 ui.horizontal(|ui| {
-                    let times_per_timeline = entity_db.times_per_timeline();
-                    self.time_control_ui
-                        .play_pause_ui(time_ctrl, times_per_timeline, ui);
+                    self.time_control_ui.play_pause_ui(time_ctrl, entity_db, ui);
                     self.time_control_ui.playback_speed_ui(time_ctrl, ui);
                     self.time_control_ui.fps_ui(time_ctrl, ui);
+                    if self.source == TimePanelSource::Recording {
+                        playback_link_ui(ctx, ui, time_ctrl);
+                    }
                 });
                 ui.horizontal(|ui| {
-                    self.time_control_ui.timeline_selector_ui(
-                        time_ctrl,
-                        entity_db.times_per_timeline(),
-                        ui,
-                    );
+                    self.time_control_ui
+                        .timeline_selector_ui(time_ctrl, entity_db, ui);
 
                     self.current_time_ui(ctx, ui, time_ctrl);
 
@@ -1275,14 +1287,14 @@ impl TimePanel {
             });
         } else {
             // One row:
-            let times_per_timeline = entity_db.times_per_timeline();
-
+            self.time_control_ui.play_pause_ui(time_ctrl, entity_db, ui);
             self.time_control_ui
-                .play_pause_ui(time_ctrl, times_per_timeline, ui);
-            self.time_control_ui
-                .timeline_selector_ui(time_ctrl, times_per_timeline, ui);
+                .timeline_selector_ui(time_ctrl, entity_db, ui);
             self.time_control_ui.playback_speed_ui(time_ctrl, ui);
             self.time_control_ui.fps_ui(time_ctrl, ui);
+            if self.source == TimePanelSource::Recording {
+                playback_link_ui(ctx, ui, time_ctrl);
+            }
             self.current_time_ui(ctx, ui, time_ctrl);
 
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
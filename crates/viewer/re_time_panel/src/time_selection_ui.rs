@@ -6,13 +6,16 @@ use re_viewer_context::{Looping, TimeControl};
 
 use super::time_ranges_ui::TimeRangesUi;
 
+/// Shows and interacts with the loop selection.
+///
+/// Returns the response of the selection, if it is active.
 pub fn loop_selection_ui(
     time_ctrl: &mut TimeControl,
     time_ranges_ui: &TimeRangesUi,
     ui: &egui::Ui,
     time_area_painter: &egui::Painter,
     timeline_rect: &Rect,
-) {
+) -> Option<egui::Response> {
     let tokens = ui.tokens();
 
     if time_ctrl.loop_selection().is_none() && time_ctrl.looping() == Looping::Selection {
@@ -44,6 +47,8 @@ pub fn loop_selection_ui(
 
     let interact_radius = ui.style().interaction.resize_grab_radius_side;
 
+    let mut selection_response = None;
+
     // Paint existing selection and detect drag starting and hovering:
     if let Some(mut selected_range) = time_ctrl.loop_selection() {
         let min_x = time_ranges_ui.x_from_time(selected_range.min);
@@ -122,6 +127,8 @@ pub fn loop_selection_ui(
                 if middle_response.dragged() {
                     on_drag_loop_selection(ui, time_ranges_ui, &mut selected_range);
                 }
+
+                selection_response = Some(middle_response);
             } else {
                 // inactive - show a tooltip at least:
                 ui.interact(rect, middle_id, egui::Sense::hover())
@@ -152,6 +159,8 @@ pub fn loop_selection_ui(
             ui.ctx().set_dragged_id(right_edge_id);
         }
     }
+
+    selection_response
 }
 
 fn initial_time_selection(
@@ -50,6 +50,7 @@ pub enum UICommand {
     ToggleSelectionPanel,
     ToggleTimePanel,
     ToggleChunkStoreBrowser,
+    ToggleTransformTreePanel,
     Settings,
 
     #[cfg(debug_assertions)]
@@ -93,6 +94,12 @@ pub enum UICommand {
 
     CopyTimeRangeLink,
 
+    DeleteTimeSelection,
+
+    AddBookmark,
+    ExportClipRrd,
+    ExportClipMcap,
+
     // Graphics options:
     #[cfg(target_arch = "wasm32")]
     RestartWithWebGl,
@@ -205,6 +212,10 @@ impl UICommand {
                 "Toggle chunk store browser",
                 "Toggle the chunk store browser",
             ),
+            Self::ToggleTransformTreePanel => (
+                "Toggle transform tree panel",
+                "Toggle the panel showing the transform hierarchy of the recording, with the latest transform of each frame",
+            ),
             Self::Settings => ("Settings…", "Show the settings screen"),
 
             #[cfg(debug_assertions)]
@@ -247,11 +258,11 @@ impl UICommand {
             Self::PlaybackFollow => ("Follow", "Follow on from end of timeline"),
             Self::PlaybackStepBack => (
                 "Step backwards",
-                "Move the time marker back to the previous point in time with any data",
+                "Move the time marker back to the previous point in time with any data, or to the previous sample of the entity stepping is pinned to",
             ),
             Self::PlaybackStepForward => (
                 "Step forwards",
-                "Move the time marker to the next point in time with any data",
+                "Move the time marker to the next point in time with any data, or to the next sample of the entity stepping is pinned to",
             ),
             Self::PlaybackRestart => ("Restart", "Restart from beginning of timeline"),
 
@@ -293,6 +304,26 @@ impl UICommand {
                 "Copy a link to the part of the active recording within the loop selection bounds.",
             ),
 
+            Self::DeleteTimeSelection => (
+                "Delete data in selected time range",
+                "Delete all data of the active recording within the loop selection bounds, across all entities. Static data is kept.",
+            ),
+
+            Self::AddBookmark => (
+                "Add bookmark",
+                "Add a bookmark at the current time of the active timeline",
+            ),
+
+            Self::ExportClipRrd => (
+                "Export clip as .rrd…",
+                "Save the data within the loop selection bounds to a new .rrd file. Only the selected entities are exported, or all of them if none are selected.",
+            ),
+
+            Self::ExportClipMcap => (
+                "Export clip as MCAP…",
+                "Save the data within the loop selection bounds to a new MCAP file, with one JSON channel per entity. Only the selected entities are exported, or all of them if none are selected.",
+            ),
+
             #[cfg(target_arch = "wasm32")]
             Self::RestartWithWebGl => (
                 "Restart with WebGL",
@@ -382,6 +413,7 @@ impl UICommand {
             Self::ToggleSelectionPanel => smallvec![ctrl_shift(Key::S)],
             Self::ToggleTimePanel => smallvec![ctrl_shift(Key::T)],
             Self::ToggleChunkStoreBrowser => smallvec![ctrl_shift(Key::D)],
+            Self::ToggleTransformTreePanel => smallvec![],
             Self::Settings => smallvec![cmd(Key::Comma)],
 
             #[cfg(debug_assertions)]
@@ -427,6 +459,12 @@ impl UICommand {
 
             Self::CopyTimeRangeLink => smallvec![],
 
+            Self::DeleteTimeSelection => smallvec![],
+
+            Self::AddBookmark => smallvec![cmd(Key::B)],
+            Self::ExportClipRrd => smallvec![],
+            Self::ExportClipMcap => smallvec![],
+
             #[cfg(target_arch = "wasm32")]
             Self::RestartWithWebGl => smallvec![],
             #[cfg(target_arch = "wasm32")]
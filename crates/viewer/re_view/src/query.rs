@@ -24,6 +24,9 @@ use re_viewer_context::{DataResult, QueryRange, ViewContext, ViewQuery, ViewerCo
 ///
 /// Data should be accessed via the [`crate::RangeResultsExt`] trait which is implemented for
 /// [`crate::HybridResults`].
+///
+/// The range is shifted by the time alignment of the `data_result`, so the times of the results
+/// are the times the data was logged at, not the times it is shown at.
 pub fn range_with_blueprint_resolved_data<'a, 'b>(
     ctx: &ViewContext<'a>,
     _annotations: Option<&re_viewer_context::Annotations>,
@@ -41,7 +44,7 @@ pub fn range_with_blueprint_resolved_data<'a, 'b>(
     component_descrs.retain(|component_descr| overrides.get(component_descr).is_none());
 
     let results = ctx.recording_engine().cache().range(
-        range_query,
+        &data_result.time_alignment().range_query(range_query),
         &data_result.entity_path,
         component_descrs.iter(),
     );
@@ -66,6 +69,8 @@ pub fn range_with_blueprint_resolved_data<'a, 'b>(
 /// [`crate::HybridResults`].
 ///
 /// If `query_shadowed_components` is true, store components will be queried, even if they are not used.
+///
+/// The query is shifted by the time alignment of the `data_result`.
 pub fn latest_at_with_blueprint_resolved_data<'a, 'b>(
     ctx: &'a ViewContext<'a>,
     _annotations: Option<&'a re_viewer_context::Annotations>,
@@ -86,7 +91,9 @@ pub fn latest_at_with_blueprint_resolved_data<'a, 'b>(
     }
 
     let results = ctx.viewer_ctx.recording_engine().cache().latest_at(
-        latest_at_query,
+        &data_result
+            .time_alignment()
+            .latest_at_query(latest_at_query),
         &data_result.entity_path,
         component_descrs.iter(),
     );
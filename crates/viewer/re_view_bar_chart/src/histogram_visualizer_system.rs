@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+
+use re_chunk_store::LatestAtQuery;
+use re_entity_db::EntityPath;
+use re_types::{archetypes::Histogram, components};
+use re_view::{DataResultQuery as _, RangeResultsExt as _};
+use re_viewer_context::{
+    IdentifiedViewSystem, QueryContext, TypedComponentFallbackProvider, ViewContext,
+    ViewContextCollection, ViewQuery, ViewSystemExecutionError, VisualizerQueryInfo,
+    VisualizerSystem, auto_color_for_entity_path,
+};
+
+/// A single bar of a histogram.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HistogramBin {
+    /// Lower edge of the bin.
+    pub min: f64,
+
+    /// Upper edge of the bin.
+    pub max: f64,
+
+    pub count: f64,
+}
+
+#[derive(Default)]
+pub struct HistogramData {
+    pub bins: Vec<HistogramBin>,
+    pub color: components::Color,
+}
+
+/// A histogram system, with everything needed to render it.
+#[derive(Default)]
+pub struct HistogramVisualizerSystem {
+    pub histograms: BTreeMap<EntityPath, HistogramData>,
+}
+
+impl IdentifiedViewSystem for HistogramVisualizerSystem {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "Histogram".into()
+    }
+}
+
+impl VisualizerSystem for HistogramVisualizerSystem {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<Histogram>()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        _context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        let timeline_query = LatestAtQuery::new(view_query.timeline, view_query.latest_at);
+
+        for data_result in view_query.iter_visible_data_results(Self::identifier()) {
+            let results = data_result
+                .latest_at_with_blueprint_resolved_data::<Histogram>(ctx, &timeline_query);
+
+            let Some(count_chunks) = results.get_required_chunks(Histogram::descriptor_counts())
+            else {
+                continue;
+            };
+            let counts: Vec<f64> = count_chunks
+                .iter()
+                .flat_map(|chunk| chunk.iter_slices::<f64>())
+                .flatten()
+                .copied()
+                .collect();
+
+            let bin_edges_descr = Histogram::descriptor_bin_edges();
+            let bin_edges: Vec<f64> = results
+                .get_optional_chunks(bin_edges_descr.clone())
+                .iter()
+                .flat_map(|chunk| chunk.iter_slices::<f64>(bin_edges_descr.clone()))
+                .flatten()
+                .copied()
+                .collect();
+
+            let Some(bins) = bins_from_edges(&data_result.entity_path, &counts, &bin_edges) else {
+                continue;
+            };
+
+            let color = results.get_mono_with_fallback(&Histogram::descriptor_color(), self);
+            self.histograms.insert(
+                data_result.entity_path.clone(),
+                HistogramData { bins, color },
+            );
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+/// Pairs up counts with their bin edges, falling back to unit-width bins if no edges were logged.
+///
+/// Returns `None` (and warns) if the edges don't match the counts.
+fn bins_from_edges(
+    entity_path: &EntityPath,
+    counts: &[f64],
+    bin_edges: &[f64],
+) -> Option<Vec<HistogramBin>> {
+    if bin_edges.is_empty() {
+        return Some(
+            counts
+                .iter()
+                .enumerate()
+                .map(|(i, &count)| HistogramBin {
+                    min: i as f64,
+                    max: i as f64 + 1.0,
+                    count,
+                })
+                .collect(),
+        );
+    }
+
+    if bin_edges.len() != counts.len() + 1 {
+        re_log::warn_once!(
+            "Histogram at {entity_path:?} has {} counts but {} bin edges, expected {}",
+            counts.len(),
+            bin_edges.len(),
+            counts.len() + 1
+        );
+        return None;
+    }
+
+    if !bin_edges.is_sorted() {
+        re_log::warn_once!("Histogram at {entity_path:?} has bin edges that are not ascending");
+        return None;
+    }
+
+    Some(
+        bin_edges
+            .windows(2)
+            .zip(counts)
+            .map(|(edges, &count)| HistogramBin {
+                min: edges[0],
+                max: edges[1],
+                count,
+            })
+            .collect(),
+    )
+}
+
+impl TypedComponentFallbackProvider<components::Color> for HistogramVisualizerSystem {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> components::Color {
+        auto_color_for_entity_path(ctx.target_entity_path)
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(HistogramVisualizerSystem => [components::Color]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bins_from_edges() {
+        let entity_path = EntityPath::from("histogram");
+
+        assert_eq!(
+            bins_from_edges(&entity_path, &[3.0, 5.0], &[]),
+            Some(vec![
+                HistogramBin {
+                    min: 0.0,
+                    max: 1.0,
+                    count: 3.0
+                },
+                HistogramBin {
+                    min: 1.0,
+                    max: 2.0,
+                    count: 5.0
+                },
+            ])
+        );
+
+        assert_eq!(
+            bins_from_edges(&entity_path, &[3.0, 5.0], &[-1.0, 0.5, 4.0]),
+            Some(vec![
+                HistogramBin {
+                    min: -1.0,
+                    max: 0.5,
+                    count: 3.0
+                },
+                HistogramBin {
+                    min: 0.5,
+                    max: 4.0,
+                    count: 5.0
+                },
+            ])
+        );
+
+        assert_eq!(
+            bins_from_edges(&entity_path, &[3.0, 5.0], &[0.0, 1.0]),
+            None
+        );
+        assert_eq!(
+            bins_from_edges(&entity_path, &[3.0, 5.0], &[0.0, 2.0, 1.0]),
+            None
+        );
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! A View that shows a single bar chart.
 
+mod histogram_visualizer_system;
 mod view_class;
 mod visualizer_system;
 
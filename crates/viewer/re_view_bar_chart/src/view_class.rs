@@ -17,6 +17,7 @@ use re_viewer_context::{
 };
 use re_viewport_blueprint::ViewProperty;
 
+use super::histogram_visualizer_system::{HistogramData, HistogramVisualizerSystem};
 use super::visualizer_system::{BarChartData, BarChartVisualizerSystem};
 
 #[derive(Default)]
@@ -87,7 +88,8 @@ impl ViewClass for BarChartView {
         &self,
         system_registry: &mut re_viewer_context::ViewSystemRegistrator<'_>,
     ) -> Result<(), ViewClassRegistryError> {
-        system_registry.register_visualizer::<BarChartVisualizerSystem>()
+        system_registry.register_visualizer::<BarChartVisualizerSystem>()?;
+        system_registry.register_visualizer::<HistogramVisualizerSystem>()
     }
 
     fn preferred_tile_aspect_ratio(&self, _state: &dyn ViewState) -> Option<f32> {
@@ -105,15 +107,17 @@ impl ViewClass for BarChartView {
         // since they're not indicated with a BarChart indicator.
         // (and as of writing, something needs to be both visualizable and indicated to be shown in a visualizer)
 
-        // Keeping this implementation simple: We know there's only a single visualizer here.
-        if visualizable_entities_per_visualizer
-            .get(&BarChartVisualizerSystem::identifier())
-            .is_some_and(|entities| entities.contains(entity_path))
-        {
-            std::iter::once(BarChartVisualizerSystem::identifier()).collect()
-        } else {
-            Default::default()
-        }
+        [
+            BarChartVisualizerSystem::identifier(),
+            HistogramVisualizerSystem::identifier(),
+        ]
+        .into_iter()
+        .filter(|visualizer| {
+            visualizable_entities_per_visualizer
+                .get(visualizer)
+                .is_some_and(|entities| entities.contains(entity_path))
+        })
+        .collect()
     }
 
     fn spawn_heuristics(
@@ -122,7 +126,19 @@ impl ViewClass for BarChartView {
         include_entity: &dyn Fn(&EntityPath) -> bool,
     ) -> re_viewer_context::ViewSpawnHeuristics {
         re_tracing::profile_function!();
-        suggest_view_for_each_entity::<BarChartVisualizerSystem>(ctx, self, include_entity)
+        re_viewer_context::ViewSpawnHeuristics::new(
+            suggest_view_for_each_entity::<BarChartVisualizerSystem>(ctx, self, include_entity)
+                .into_vec()
+                .into_iter()
+                .chain(
+                    suggest_view_for_each_entity::<HistogramVisualizerSystem>(
+                        ctx,
+                        self,
+                        include_entity,
+                    )
+                    .into_vec(),
+                ),
+        )
     }
 
     fn layout_priority(&self) -> re_viewer_context::ViewClassLayoutPriority {
@@ -165,6 +181,10 @@ impl ViewClass for BarChartView {
             .view_systems
             .get::<BarChartVisualizerSystem>()?
             .charts;
+        let histograms = &system_output
+            .view_systems
+            .get::<HistogramVisualizerSystem>()?
+            .histograms;
 
         let ctx = self.view_context(ctx, view_id, state);
         let plot_legend = ViewProperty::from_archetype::<PlotLegend>(
@@ -203,13 +223,7 @@ impl ViewClass for BarChartView {
                     color: &re_types::components::Color,
                     theme: egui::Theme,
                 ) -> BarChart {
-                    let color: egui::Color32 = color.0.into();
-                    let fill = if theme == egui::Theme::Dark {
-                        color.gamma_multiply(0.75).additive() // make sure overlapping bars are obvious for dark mode
-                    } else {
-                        color.gamma_multiply(0.75)
-                    };
-                    let stroke_color = fill.linear_multiply(0.5);
+                    let (fill, stroke_color) = bar_colors(color, theme);
                     BarChart::new(
                         "bar_chart",
                         values
@@ -225,7 +239,7 @@ impl ViewClass for BarChartView {
                             .collect(),
                     )
                     .name(ent_path.to_string())
-                    .color(color)
+                    .color(color.0)
                 }
 
                 for (
@@ -337,6 +351,29 @@ impl ViewClass for BarChartView {
 
                     plot_ui.bar_chart(chart);
                 }
+
+                for (ent_path, HistogramData { bins, color }) in histograms {
+                    let (fill, stroke_color) = bar_colors(color, theme);
+                    let chart = BarChart::new(
+                        "histogram",
+                        bins.iter()
+                            .enumerate()
+                            .map(|(i, bin)| {
+                                Bar::new(0.5 * (bin.min + bin.max), bin.count)
+                                    .width(bin.max - bin.min)
+                                    .name(format!("{ent_path} #{i} [{}, {})", bin.min, bin.max))
+                                    .fill(fill)
+                                    .stroke((1.0, stroke_color))
+                            })
+                            .collect(),
+                    )
+                    .name(ent_path.to_string())
+                    .color(color.0);
+
+                    let id = egui::Id::new(ent_path.hash());
+                    plot_item_id_to_entity_path.insert(id, ent_path.clone());
+                    plot_ui.bar_chart(chart.id(id));
+                }
             });
 
             // Interact with the plot items.
@@ -362,6 +399,21 @@ impl ViewClass for BarChartView {
     }
 }
 
+/// Fill & stroke colors for the bars of a chart with the given base color.
+fn bar_colors(
+    color: &re_types::components::Color,
+    theme: egui::Theme,
+) -> (egui::Color32, egui::Color32) {
+    let color: egui::Color32 = color.0.into();
+    let fill = if theme == egui::Theme::Dark {
+        color.gamma_multiply(0.75).additive() // make sure overlapping bars are obvious for dark mode
+    } else {
+        color.gamma_multiply(0.75)
+    };
+    let stroke_color = fill.linear_multiply(0.5);
+    (fill, stroke_color)
+}
+
 impl TypedComponentFallbackProvider<Corner2D> for BarChartView {
     fn fallback_for(&self, _ctx: &re_viewer_context::QueryContext<'_>) -> Corner2D {
         // Explicitly pick RightCorner2D::RightTop, we don't want to make this dependent on the (arbitrary)
@@ -146,11 +146,15 @@ Configure in the selection panel:
             filtered_index_range: Some(view_query.filter_by_range()?),
             filtered_is_not_null: view_query.filter_is_not_null()?,
             sparse_fill_strategy,
+            aggregation: None,
             selection: None,
 
             // not yet unsupported by the dataframe view
             filtered_index_values: None,
             using_index_values: None,
+            filtered_component_values: None,
+            filtered_component_predicates: Vec::new(),
+            join_on_entity: None,
             include_semantically_empty_columns: false,
             include_tombstone_columns: false,
             include_static_columns: re_chunk_store::StaticColumnSelection::Both,
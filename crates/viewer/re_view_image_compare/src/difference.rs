@@ -0,0 +1,92 @@
+use image::RgbaImage;
+
+/// Summary of how much two images differ.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DifferenceStats {
+    /// The largest difference of any channel of any pixel, from 0 to 255.
+    pub max_difference: u8,
+
+    /// How many pixels differ in at least one channel.
+    pub num_differing_pixels: u64,
+
+    pub num_pixels: u64,
+}
+
+/// The per-pixel difference of two images of the same size.
+pub struct ImageDifference {
+    /// The difference as a grayscale image: black where the images are equal.
+    pub image: RgbaImage,
+
+    pub stats: DifferenceStats,
+}
+
+/// Computes the difference of `a` and `b`, or `None` if their sizes differ.
+///
+/// The difference of a pixel is the largest absolute difference of any of its channels,
+/// multiplied by `gain` so that small differences stand out.
+pub fn image_difference(a: &RgbaImage, b: &RgbaImage, gain: f32) -> Option<ImageDifference> {
+    re_tracing::profile_function!();
+
+    if a.dimensions() != b.dimensions() {
+        return None;
+    }
+
+    let mut stats = DifferenceStats {
+        num_pixels: u64::from(a.width()) * u64::from(a.height()),
+        ..Default::default()
+    };
+
+    let mut image = RgbaImage::new(a.width(), a.height());
+    for ((a, b), out) in a.pixels().zip(b.pixels()).zip(image.pixels_mut()) {
+        let difference =
+            a.0.iter()
+                .zip(b.0)
+                .map(|(a, b)| a.abs_diff(b))
+                .max()
+                .unwrap_or_default();
+
+        if difference > 0 {
+            stats.num_differing_pixels += 1;
+            stats.max_difference = stats.max_difference.max(difference);
+        }
+
+        let value = (f32::from(difference) * gain).round().clamp(0.0, 255.0) as u8;
+        *out = image::Rgba([value, value, value, 255]);
+    }
+
+    Some(ImageDifference { image, stats })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn difference_of_images() {
+        let a = RgbaImage::from_raw(2, 1, vec![10, 20, 30, 255, 0, 0, 0, 255]).unwrap();
+        let b = RgbaImage::from_raw(2, 1, vec![10, 20, 30, 255, 0, 40, 5, 255]).unwrap();
+
+        let difference = image_difference(&a, &b, 2.0).unwrap();
+        assert_eq!(
+            DifferenceStats {
+                max_difference: 40,
+                num_differing_pixels: 1,
+                num_pixels: 2,
+            },
+            difference.stats
+        );
+        assert_eq!(
+            vec![0, 0, 0, 255, 80, 80, 80, 255],
+            difference.image.into_raw()
+        );
+
+        // Differences saturate.
+        let difference = image_difference(&a, &b, 64.0).unwrap();
+        assert_eq!(255, difference.image.get_pixel(1, 0).0[0]);
+
+        let c = RgbaImage::new(1, 2);
+        assert!(image_difference(&a, &c, 1.0).is_none());
+    }
+}
@@ -0,0 +1,10 @@
+//! Rerun image comparison view.
+//!
+//! A view that compares two images, or one image at two points in time, e.g. to check a
+//! perception pipeline for regressions.
+
+mod difference;
+mod view_class;
+mod visualizer_system;
+
+pub use view_class::ImageCompareView;
@@ -0,0 +1,604 @@
+use egui::{Align2, Vec2};
+
+use re_chunk_store::LatestAtQuery;
+use re_log_types::{EntityPath, TimeInt, TimelineName, hash::Hash64};
+use re_renderer::{
+    external::wgpu,
+    renderer::ColormappedTexture,
+    resource_managers::{ImageDataDesc, SourceImageDataFormat},
+};
+use re_types::ViewClassIdentifier;
+use re_ui::{Help, UiExt as _, icons, list_item};
+use re_viewer_context::{
+    Annotations, ImageInfo, ImageStatsCache, Item, ViewClass, ViewClassRegistryError, ViewId,
+    ViewQuery, ViewSpawnHeuristics, ViewState, ViewStateExt as _, ViewSystemExecutionError,
+    ViewerContext, gpu_bridge,
+};
+
+use crate::{
+    difference::{DifferenceStats, image_difference},
+    visualizer_system::{ImageCompareSystem, query_image},
+};
+
+/// Images are compared pixel by pixel, so they are never smoothed when magnified.
+const TEXTURE_OPTIONS: egui::TextureOptions = egui::TextureOptions {
+    magnification: egui::TextureFilter::Nearest,
+    minification: egui::TextureFilter::Linear,
+    wrap_mode: egui::TextureWrapMode::ClampToEdge,
+    mipmap_mode: None,
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum CompareMode {
+    /// The absolute difference of the two images.
+    #[default]
+    Difference,
+
+    /// The first image left of a divider, the second one right of it.
+    Swipe,
+}
+
+impl CompareMode {
+    const ALL: [Self; 2] = [Self::Difference, Self::Swipe];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Difference => "Difference",
+            Self::Swipe => "Swipe",
+        }
+    }
+}
+
+pub struct ImageCompareViewState {
+    mode: CompareMode,
+
+    /// Where the divider is in [`CompareMode::Swipe`], from 0 (left) to 1 (right).
+    swipe_position: f32,
+
+    /// How much differences are amplified in [`CompareMode::Difference`].
+    difference_gain: f32,
+
+    /// What a single entity is compared against: the time it had on the given timeline.
+    reference_time: Option<(TimelineName, TimeInt)>,
+
+    /// The last difference shown, with the key of its texture.
+    difference: Option<(u64, DifferenceStats)>,
+}
+
+impl Default for ImageCompareViewState {
+    fn default() -> Self {
+        Self {
+            mode: CompareMode::default(),
+            swipe_position: 0.5,
+            difference_gain: 1.0,
+            reference_time: None,
+            difference: None,
+        }
+    }
+}
+
+impl ImageCompareViewState {
+    fn reference_time(&self, timeline: &TimelineName) -> Option<TimeInt> {
+        self.reference_time
+            .filter(|(reference_timeline, _)| reference_timeline == timeline)
+            .map(|(_, time)| time)
+    }
+}
+
+impl ViewState for ImageCompareViewState {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// An image and what it is, e.g. the entity it was logged to.
+struct ComparedImage {
+    image: ImageInfo,
+    label: String,
+}
+
+#[derive(Default)]
+pub struct ImageCompareView;
+
+impl ViewClass for ImageCompareView {
+    fn identifier() -> ViewClassIdentifier {
+        "ImageCompare".into()
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Image comparison"
+    }
+
+    fn icon(&self) -> &'static re_ui::Icon {
+        &icons::VIEW_2D
+    }
+
+    fn help(&self, _os: egui::os::OperatingSystem) -> Help {
+        Help::new("Image comparison view")
+            .markdown(
+                "Compares two image entities, or a single image entity at the current time and at a reference time.
+
+Show the difference of the images, or both of them side by side with a swipe divider.",
+            )
+            .control("Move divider", (icons::LEFT_MOUSE_CLICK, "+", "drag"))
+    }
+
+    fn on_register(
+        &self,
+        system_registry: &mut re_viewer_context::ViewSystemRegistrator<'_>,
+    ) -> Result<(), ViewClassRegistryError> {
+        system_registry.register_visualizer::<ImageCompareSystem>()
+    }
+
+    fn preferred_tile_aspect_ratio(&self, _state: &dyn ViewState) -> Option<f32> {
+        None
+    }
+
+    fn layout_priority(&self) -> re_viewer_context::ViewClassLayoutPriority {
+        re_viewer_context::ViewClassLayoutPriority::Medium
+    }
+
+    fn new_state(&self) -> Box<dyn ViewState> {
+        Box::<ImageCompareViewState>::default()
+    }
+
+    fn spawn_heuristics(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        _include_entity: &dyn Fn(&EntityPath) -> bool,
+    ) -> ViewSpawnHeuristics {
+        // Comparisons are set up by the user, they are never spawned automatically.
+        ViewSpawnHeuristics::empty()
+    }
+
+    fn selection_ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        _space_origin: &EntityPath,
+        _view_id: ViewId,
+    ) -> Result<(), ViewSystemExecutionError> {
+        let state = state.downcast_mut::<ImageCompareViewState>()?;
+
+        let (timeline, time) = {
+            let time_ctrl = ctx.rec_cfg.time_ctrl.read();
+            (*time_ctrl.timeline(), time_ctrl.time_int())
+        };
+        let reference_time = state.reference_time(timeline.name());
+
+        list_item::list_item_scope(ui, "image_compare_selection_ui", |ui| {
+            ui.list_item_flat_noninteractive(list_item::PropertyContent::new("Mode").value_fn(
+                |ui, _| {
+                    egui::ComboBox::new("image_compare_mode", "")
+                        .selected_text(state.mode.label())
+                        .show_ui(ui, |ui| {
+                            for mode in CompareMode::ALL {
+                                ui.selectable_value(&mut state.mode, mode, mode.label());
+                            }
+                        });
+                },
+            ));
+
+            if state.mode == CompareMode::Difference {
+                ui.list_item_flat_noninteractive(list_item::PropertyContent::new("Gain").value_fn(
+                    |ui, _| {
+                        ui.add(
+                            egui::Slider::new(&mut state.difference_gain, 1.0..=64.0)
+                                .logarithmic(true),
+                        )
+                        .on_hover_text("Amplifies the differences, to make small ones visible");
+                    },
+                ));
+            }
+
+            let mut clear_reference_time = false;
+            let mut new_reference_time = None;
+            ui.list_item_flat_noninteractive(
+                list_item::PropertyContent::new("Reference time")
+                    .action_button_with_enabled(
+                        &icons::RESET,
+                        "Clear",
+                        reference_time.is_some(),
+                        || clear_reference_time = true,
+                    )
+                    .value_fn(|ui, _| {
+                        ui.label(reference_time.map_or_else(
+                            || "–".to_owned(),
+                            |time| {
+                                timeline
+                                    .typ()
+                                    .format(time, ctx.app_options().timestamp_format)
+                            },
+                        ))
+                        .on_hover_text(
+                            "A single entity is compared with itself at this time on the current timeline",
+                        );
+
+                        if ui
+                            .add_enabled(time.is_some(), egui::Button::new("Use current time"))
+                            .clicked()
+                        {
+                            new_reference_time = time;
+                        }
+                    }),
+            );
+
+            if clear_reference_time {
+                state.reference_time = None;
+            }
+            if let Some(time) = new_reference_time {
+                state.reference_time = Some((*timeline.name(), time));
+            }
+
+            if state.mode == CompareMode::Difference
+                && let Some((_, stats)) = state.difference
+            {
+                ui.list_item_flat_noninteractive(
+                    list_item::PropertyContent::new("Max difference")
+                        .value_text(stats.max_difference.to_string()),
+                );
+                ui.list_item_flat_noninteractive(
+                    list_item::PropertyContent::new("Differing pixels")
+                        .value_text(format_differing_pixels(&stats)),
+                );
+            }
+        });
+
+        Ok(())
+    }
+
+    fn ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        query: &ViewQuery<'_>,
+        system_output: re_viewer_context::SystemExecutionOutput,
+    ) -> Result<(), ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        let tokens = ui.tokens();
+
+        let state = state.downcast_mut::<ImageCompareViewState>()?;
+        let entities = &system_output
+            .view_systems
+            .get::<ImageCompareSystem>()?
+            .entities;
+
+        let response = {
+            let mut ui = ui.new_child(egui::UiBuilder::new().sense(egui::Sense::click()));
+
+            match compared_images(ctx, state, query, entities) {
+                Ok(Some([a, b])) => {
+                    if let Err(err) = compare_ui(ctx, &mut ui, state, &a, &b) {
+                        egui::Frame {
+                            inner_margin: tokens.view_padding().into(),
+                            ..egui::Frame::default()
+                        }
+                        .show(&mut ui, |ui| ui.error_label(err.to_string()));
+                    }
+                }
+                Ok(None) => {
+                    ui.centered_and_justified(|ui| ui.label("(empty)"));
+                }
+                Err(message) => {
+                    egui::Frame {
+                        inner_margin: tokens.view_padding().into(),
+                        ..egui::Frame::default()
+                    }
+                    .show(&mut ui, |ui| ui.warning_label(message));
+                }
+            }
+
+            ui.response()
+        };
+
+        if response.hovered() {
+            ctx.selection_state().set_hovered(Item::View(query.view_id));
+        }
+
+        if response.clicked() {
+            ctx.selection_state()
+                .set_selection(Item::View(query.view_id));
+        }
+
+        Ok(())
+    }
+}
+
+/// The two images to compare, `None` if they weren't logged, or why there is nothing to compare.
+fn compared_images(
+    ctx: &ViewerContext<'_>,
+    state: &ImageCompareViewState,
+    query: &ViewQuery<'_>,
+    entities: &[EntityPath],
+) -> Result<Option<[ComparedImage; 2]>, String> {
+    let current_query = LatestAtQuery::new(query.timeline, query.latest_at);
+
+    let compared_image = |entity_path: &EntityPath, query: &LatestAtQuery, label: String| {
+        query_image(ctx, entity_path, query).map(|image| ComparedImage { image, label })
+    };
+
+    let images = match entities {
+        [entity_path] => {
+            let Some(reference_time) = state.reference_time(&query.timeline) else {
+                return Err(format!(
+                    "Pick a reference time on the {:?} timeline in the selection panel, to compare {entity_path} with.",
+                    query.timeline.as_str()
+                ));
+            };
+
+            let time_type = ctx.rec_cfg.time_ctrl.read().time_type();
+            let timestamp_format = ctx.app_options().timestamp_format;
+            [
+                compared_image(
+                    entity_path,
+                    &LatestAtQuery::new(query.timeline, reference_time),
+                    format!(
+                        "{entity_path} at {}",
+                        time_type.format(reference_time, timestamp_format)
+                    ),
+                ),
+                compared_image(
+                    entity_path,
+                    &current_query,
+                    format!(
+                        "{entity_path} at {}",
+                        time_type.format(query.latest_at, timestamp_format)
+                    ),
+                ),
+            ]
+        }
+
+        [first, second] => [
+            compared_image(first, &current_query, first.to_string()),
+            compared_image(second, &current_query, second.to_string()),
+        ],
+
+        _ => {
+            return Err(format!(
+                "Can only compare two image entities, or one image entity at two times; was given {}. \
+                Update the query so that it returns one or two image entities.",
+                entities.len()
+            ));
+        }
+    };
+
+    Ok(match images {
+        [Some(a), Some(b)] => Some([a, b]),
+        _ => None,
+    })
+}
+
+fn compare_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    state: &mut ImageCompareViewState,
+    a: &ComparedImage,
+    b: &ComparedImage,
+) -> anyhow::Result<()> {
+    re_tracing::profile_function!();
+
+    let [width, height] = a.image.width_height();
+    let image_size = Vec2::new(width as f32, height as f32).max(Vec2::splat(1.0));
+    let scale = (ui.available_size() / image_size).min_elem();
+
+    let (response, painter) =
+        ui.allocate_painter(image_size * scale, egui::Sense::click_and_drag());
+    let rect = response.rect;
+
+    match state.mode {
+        CompareMode::Difference => {
+            let texture = difference_texture(ctx, state, &a.image, &b.image)?;
+            gpu_bridge::render_image(
+                ctx.render_ctx(),
+                &painter,
+                rect,
+                texture,
+                TEXTURE_OPTIONS,
+                re_renderer::DebugLabel::from("image_difference"),
+            )?;
+
+            paint_label(
+                ui,
+                &painter,
+                rect.left_top(),
+                Align2::LEFT_TOP,
+                format!("{} − {}", a.label, b.label),
+            );
+            if let Some((_, stats)) = state.difference {
+                paint_label(
+                    ui,
+                    &painter,
+                    rect.left_bottom(),
+                    Align2::LEFT_BOTTOM,
+                    format!(
+                        "Max difference: {}, differing pixels: {}",
+                        stats.max_difference,
+                        format_differing_pixels(&stats)
+                    ),
+                );
+            }
+        }
+
+        CompareMode::Swipe => {
+            if (response.dragged() || response.clicked())
+                && let Some(pointer_pos) = response.interact_pointer_pos()
+            {
+                state.swipe_position =
+                    ((pointer_pos.x - rect.left()) / rect.width()).clamp(0.0, 1.0);
+            }
+            if response.hovered() || response.dragged() {
+                ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeHorizontal);
+            }
+
+            let divider_x = egui::lerp(rect.x_range(), state.swipe_position);
+
+            for (image, clip_rect, debug_name) in [
+                (&a.image, rect.with_max_x(divider_x), "image_compare_left"),
+                (&b.image, rect.with_min_x(divider_x), "image_compare_right"),
+            ] {
+                let texture = image_texture(ctx, debug_name, image)?;
+                gpu_bridge::render_image(
+                    ctx.render_ctx(),
+                    &painter.with_clip_rect(clip_rect),
+                    rect,
+                    texture,
+                    TEXTURE_OPTIONS,
+                    re_renderer::DebugLabel::from(debug_name),
+                )?;
+            }
+
+            painter.vline(
+                divider_x,
+                rect.y_range(),
+                egui::Stroke::new(2.0, ui.visuals().strong_text_color()),
+            );
+
+            paint_label(
+                ui,
+                &painter,
+                rect.left_top(),
+                Align2::LEFT_TOP,
+                a.label.clone(),
+            );
+            paint_label(
+                ui,
+                &painter,
+                rect.right_top(),
+                Align2::RIGHT_TOP,
+                b.label.clone(),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn image_texture(
+    ctx: &ViewerContext<'_>,
+    debug_name: &str,
+    image: &ImageInfo,
+) -> anyhow::Result<ColormappedTexture> {
+    let image_stats = ctx
+        .store_context
+        .caches
+        .entry(|c: &mut ImageStatsCache| c.entry(image));
+
+    gpu_bridge::image_to_gpu(
+        ctx.render_ctx(),
+        debug_name,
+        image,
+        &image_stats,
+        &Annotations::missing(),
+        None,
+    )
+}
+
+/// Uploads the difference of `a` and `b`, only computing it when it isn't on the GPU already.
+fn difference_texture(
+    ctx: &ViewerContext<'_>,
+    state: &mut ImageCompareViewState,
+    a: &ImageInfo,
+    b: &ImageInfo,
+) -> anyhow::Result<ColormappedTexture> {
+    let gain = state.difference_gain;
+    let texture_key =
+        Hash64::hash((a.buffer_content_hash, b.buffer_content_hash, gain.to_bits())).hash64();
+
+    let compute = || {
+        let (Some(a), Some(b)) = (to_rgba8(ctx, a), to_rgba8(ctx, b)) else {
+            anyhow::bail!("Failed to convert the images to RGBA");
+        };
+        image_difference(&a, &b, gain).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Can't compute the difference of images of different sizes: {}x{} and {}x{}",
+                a.width(),
+                a.height(),
+                b.width(),
+                b.height()
+            )
+        })
+    };
+
+    // The stats are kept around for as long as the same difference is shown.
+    let mut difference = None;
+    if state
+        .difference
+        .is_none_or(|(shown_key, _)| shown_key != texture_key)
+    {
+        state.difference = None;
+        let computed = compute()?;
+        state.difference = Some((texture_key, computed.stats));
+        difference = Some(computed);
+    }
+
+    let texture = gpu_bridge::try_get_or_create_texture(ctx.render_ctx(), texture_key, || {
+        let difference = match difference {
+            Some(difference) => difference,
+            None => compute()?,
+        };
+        let width_height = [difference.image.width(), difference.image.height()];
+
+        Ok::<_, anyhow::Error>(ImageDataDesc {
+            label: "image_difference".into(),
+            data: difference.image.into_raw().into(),
+            format: SourceImageDataFormat::WgpuCompatible(wgpu::TextureFormat::Rgba8Unorm),
+            width_height,
+        })
+    })
+    .map_err(|err| anyhow::anyhow!("{err}"))?;
+
+    Ok(ColormappedTexture::from_unorm_rgba(texture))
+}
+
+fn to_rgba8(ctx: &ViewerContext<'_>, image: &ImageInfo) -> Option<image::RgbaImage> {
+    let image_stats = ctx
+        .store_context
+        .caches
+        .entry(|c: &mut ImageStatsCache| c.entry(image));
+    let data_range = gpu_bridge::image_data_range_heuristic(&image_stats, &image.format);
+
+    image.to_rgba8_image(data_range.into())
+}
+
+fn format_differing_pixels(stats: &DifferenceStats) -> String {
+    let percentage = if stats.num_pixels == 0 {
+        0.0
+    } else {
+        100.0 * stats.num_differing_pixels as f64 / stats.num_pixels as f64
+    };
+    format!(
+        "{} ({percentage:.2}%)",
+        re_format::format_uint(stats.num_differing_pixels)
+    )
+}
+
+/// Paints `text` on top of the images, with a background so that it remains readable.
+fn paint_label(
+    ui: &egui::Ui,
+    painter: &egui::Painter,
+    pos: egui::Pos2,
+    align: Align2,
+    text: String,
+) {
+    let margin = Vec2::splat(4.0);
+    let galley = painter.layout_no_wrap(
+        text,
+        egui::TextStyle::Body.resolve(ui.style()),
+        ui.visuals().strong_text_color(),
+    );
+
+    let text_rect = align.anchor_size(pos + align.to_sign() * -2.0 * margin, galley.size());
+    painter.rect_filled(
+        text_rect.expand2(margin),
+        4.0,
+        ui.visuals().extreme_bg_color.gamma_multiply(0.8),
+    );
+    painter.galley(text_rect.min, galley, ui.visuals().strong_text_color());
+}
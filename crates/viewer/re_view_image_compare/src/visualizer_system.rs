@@ -0,0 +1,88 @@
+use re_chunk_store::LatestAtQuery;
+use re_log_types::EntityPath;
+use re_types::{
+    Archetype as _,
+    archetypes::Image,
+    components::{ImageBuffer, ImageFormat},
+    image::ImageKind,
+};
+use re_viewer_context::{
+    IdentifiedViewSystem, ImageInfo, ViewContext, ViewContextCollection, ViewQuery,
+    ViewSystemExecutionError, ViewerContext, VisualizerQueryInfo, VisualizerSystem,
+};
+
+/// Collects the image entities to compare.
+///
+/// The images themselves are queried by the view, since it may need them at another time than
+/// the current one.
+#[derive(Default)]
+pub struct ImageCompareSystem {
+    pub entities: Vec<EntityPath>,
+}
+
+impl IdentifiedViewSystem for ImageCompareSystem {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "ImageCompare".into()
+    }
+}
+
+impl VisualizerSystem for ImageCompareSystem {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<Image>()
+    }
+
+    fn execute(
+        &mut self,
+        _ctx: &ViewContext<'_>,
+        query: &ViewQuery<'_>,
+        _context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        self.entities = query
+            .iter_visible_data_results(Self::identifier())
+            .map(|data_result| data_result.entity_path.clone())
+            .collect();
+        self.entities.sort();
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(ImageCompareSystem => []);
+
+/// The image logged to `entity_path` as of `query`, if any.
+pub fn query_image(
+    ctx: &ViewerContext<'_>,
+    entity_path: &EntityPath,
+    query: &LatestAtQuery,
+) -> Option<ImageInfo> {
+    let recording = ctx.recording();
+
+    let ((_time, row_id), buffer) = recording.latest_at_component::<ImageBuffer>(
+        entity_path,
+        query,
+        &Image::descriptor_buffer(),
+    )?;
+    let (_, format) = recording.latest_at_component::<ImageFormat>(
+        entity_path,
+        query,
+        &Image::descriptor_format(),
+    )?;
+
+    Some(ImageInfo::from_stored_blob(
+        row_id,
+        &Image::descriptor_buffer(),
+        buffer.0,
+        format.0,
+        ImageKind::Color,
+    ))
+}
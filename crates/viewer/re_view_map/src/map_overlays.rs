@@ -1,5 +1,8 @@
 use walkers::sources::Attribution;
 
+/// Size of the heatmap cells, in ui points.
+const HEATMAP_CELL_SIZE: f32 = 12.0;
+
 pub fn acknowledgement_overlay(
     ui: &mut egui::Ui,
     map_rect: &egui::Rect,
@@ -23,3 +26,56 @@ pub fn acknowledgement_overlay(
             ui.hyperlink_to(text, attribution.url);
         });
 }
+
+/// Paints the density of `positions` as a grid of colored cells.
+///
+/// The density is mapped logarithmically, so that sparse areas remain visible next to dense ones.
+pub fn heatmap_overlay(
+    painter: &egui::Painter,
+    map_rect: egui::Rect,
+    positions: impl Iterator<Item = egui::Pos2>,
+    colormap: re_renderer::Colormap,
+    opacity: f32,
+) {
+    re_tracing::profile_function!();
+
+    let num_columns = (map_rect.width() / HEATMAP_CELL_SIZE).ceil().max(1.0) as usize;
+    let num_rows = (map_rect.height() / HEATMAP_CELL_SIZE).ceil().max(1.0) as usize;
+
+    let mut counts = vec![0_u32; num_columns * num_rows];
+    for position in positions {
+        if !map_rect.contains(position) {
+            continue;
+        }
+        let offset = (position - map_rect.min) / HEATMAP_CELL_SIZE;
+        let column = (offset.x as usize).min(num_columns - 1);
+        let row = (offset.y as usize).min(num_rows - 1);
+        counts[row * num_columns + column] += 1;
+    }
+
+    let max_count = counts.iter().copied().max().unwrap_or_default();
+    if max_count == 0 {
+        return;
+    }
+    let max_density = (max_count as f32).ln_1p();
+    let alpha = (opacity.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    for (index, count) in counts.into_iter().enumerate() {
+        if count == 0 {
+            continue;
+        }
+
+        let [r, g, b, _] =
+            re_renderer::colormap_srgb(colormap, (count as f32).ln_1p() / max_density);
+        let min = map_rect.min
+            + egui::vec2(
+                (index % num_columns) as f32 * HEATMAP_CELL_SIZE,
+                (index / num_columns) as f32 * HEATMAP_CELL_SIZE,
+            );
+        painter.rect_filled(
+            egui::Rect::from_min_size(min, egui::Vec2::splat(HEATMAP_CELL_SIZE)),
+            0.0,
+            egui::Color32::from_rgba_unmultiplied(r, g, b, alpha),
+        );
+    }
+}
@@ -9,16 +9,17 @@ use re_renderer::{RenderContext, ViewBuilder};
 use re_types::{
     View as _, ViewClassIdentifier,
     blueprint::{
-        archetypes::{MapBackground, MapZoom},
+        archetypes::{MapBackground, MapHeatmap, MapZoom},
         components::{MapProvider, ZoomLevel},
     },
+    components::{Colormap, Opacity, Visible},
 };
 use re_ui::{Help, IconText, icons, list_item};
 use re_viewer_context::{
-    IdentifiedViewSystem as _, Item, SystemExecutionOutput, UiLayout, ViewClass, ViewClassExt as _,
-    ViewClassLayoutPriority, ViewClassRegistryError, ViewHighlights, ViewId, ViewQuery,
-    ViewSpawnHeuristics, ViewState, ViewStateExt as _, ViewSystemExecutionError,
-    ViewSystemRegistrator, ViewerContext, gpu_bridge,
+    IdentifiedViewSystem as _, Item, SystemExecutionOutput, TypedComponentFallbackProvider,
+    UiLayout, ViewClass, ViewClassExt as _, ViewClassLayoutPriority, ViewClassRegistryError,
+    ViewHighlights, ViewId, ViewQuery, ViewSpawnHeuristics, ViewState, ViewStateExt as _,
+    ViewSystemExecutionError, ViewSystemRegistrator, ViewerContext, gpu_bridge,
 };
 use re_viewport_blueprint::ViewProperty;
 
@@ -30,6 +31,9 @@ pub struct MapViewState {
     map_memory: MapMemory,
     selected_provider: MapProvider,
 
+    /// Tiles URL template the tiles were created with, if the provider is [`MapProvider::Custom`].
+    selected_custom_tiles_url: String,
+
     last_center_position: walkers::Position,
 
     /// Because `re_renderer` can have varying, multiple frames of delay, we must keep track of the
@@ -43,6 +47,7 @@ impl Default for MapViewState {
             tiles: None,
             map_memory: Default::default(),
             selected_provider: Default::default(),
+            selected_custom_tiles_url: String::new(),
 
             // default to Rerun HQ whenever we have no data (either now or historically) to provide
             // a better location
@@ -180,6 +185,7 @@ impl ViewClass for MapView {
             let ctx = self.view_context(ctx, view_id, state);
             re_view::view_property_ui::<MapZoom>(&ctx, ui, self);
             re_view::view_property_ui::<MapBackground>(&ctx, ui, self);
+            re_view::view_property_ui::<MapHeatmap>(&ctx, ui, self);
         });
 
         Ok(())
@@ -206,6 +212,12 @@ impl ViewClass for MapView {
             query.view_id,
         );
 
+        let map_heatmap = ViewProperty::from_archetype::<MapHeatmap>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            query.view_id,
+        );
+
         let geo_points_visualizer = system_output.view_systems.get::<GeoPointsVisualizer>()?;
         let geo_line_strings_visualizers = system_output
             .view_systems
@@ -221,11 +233,40 @@ impl ViewClass for MapView {
             self,
             &MapBackground::descriptor_provider(),
         )?;
-        if state.selected_provider != map_provider {
+        let custom_tiles_url = if map_provider == MapProvider::Custom {
+            ctx.app_options().custom_map_tiles_url.clone()
+        } else {
+            String::new()
+        };
+        if state.selected_provider != map_provider
+            || state.selected_custom_tiles_url != custom_tiles_url
+        {
             state.tiles = None;
             state.selected_provider = map_provider;
+            state.selected_custom_tiles_url = custom_tiles_url;
         }
 
+        let heatmap_visible = map_heatmap.component_or_fallback::<Visible>(
+            &view_ctx,
+            self,
+            &MapHeatmap::descriptor_visible(),
+        )?;
+        let heatmap = if heatmap_visible.0.0 {
+            let colormap = map_heatmap.component_or_fallback::<Colormap>(
+                &view_ctx,
+                self,
+                &MapHeatmap::descriptor_colormap(),
+            )?;
+            let opacity = map_heatmap.component_or_fallback::<Opacity>(
+                &view_ctx,
+                self,
+                &MapHeatmap::descriptor_opacity(),
+            )?;
+            Some((colormap, opacity))
+        } else {
+            None
+        };
+
         //
         // Pan/Zoom handling
         //
@@ -305,6 +346,22 @@ impl ViewClass for MapView {
             );
         }
 
+        //
+        // Heatmap, drawn below the objects
+        //
+
+        if let Some((colormap, opacity)) = heatmap {
+            map_overlays::heatmap_overlay(
+                &ui.painter().with_clip_rect(map_rect),
+                map_rect,
+                geo_points_visualizer
+                    .positions()
+                    .map(|position| projector.project(position).to_pos2()),
+                gpu_bridge::colormap_to_re_renderer(colormap),
+                opacity.0.0,
+            );
+        }
+
         //
         // Draw all objects using re_renderer
         //
@@ -562,10 +619,60 @@ fn get_tile_manager(
             options,
             egui_ctx.clone(),
         ),
+        MapProvider::Custom => HttpTiles::with_options(
+            CustomTiles {
+                url_template: ctx.app_options().custom_map_tiles_url.clone(),
+            },
+            options,
+            egui_ctx.clone(),
+        ),
+    }
+}
+
+/// Tiles from a user-provided URL template, e.g. of a self-hosted tile server.
+struct CustomTiles {
+    /// URL with `{z}`, `{x}` and `{y}` placeholders for the zoom level and tile coordinates.
+    url_template: String,
+}
+
+impl walkers::sources::TileSource for CustomTiles {
+    fn tile_url(&self, tile_id: walkers::TileId) -> String {
+        self.url_template
+            .replace("{z}", &tile_id.zoom.to_string())
+            .replace("{x}", &tile_id.x.to_string())
+            .replace("{y}", &tile_id.y.to_string())
+    }
+
+    fn attribution(&self) -> walkers::sources::Attribution {
+        walkers::sources::Attribution {
+            text: "Custom tiles",
+            url: "",
+            logo_light: None,
+            logo_dark: None,
+        }
+    }
+}
+
+impl TypedComponentFallbackProvider<Visible> for MapView {
+    fn fallback_for(&self, _ctx: &re_viewer_context::QueryContext<'_>) -> Visible {
+        // Only the heatmap queries this, which is hidden unless asked for.
+        false.into()
     }
 }
 
-re_viewer_context::impl_component_fallback_provider!(MapView => []);
+impl TypedComponentFallbackProvider<Colormap> for MapView {
+    fn fallback_for(&self, _ctx: &re_viewer_context::QueryContext<'_>) -> Colormap {
+        Colormap::Inferno
+    }
+}
+
+impl TypedComponentFallbackProvider<Opacity> for MapView {
+    fn fallback_for(&self, _ctx: &re_viewer_context::QueryContext<'_>) -> Opacity {
+        0.6.into()
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(MapView => [Visible, Colormap, Opacity]);
 
 // TODO(ab, andreas): this is a partial copy past of re_view_spatial::picking_gpu. Should be
 // turned into a utility function.
@@ -636,3 +743,20 @@ fn picking_gpu(
 fn test_help_view() {
     re_test_context::TestContext::test_help_view(|ctx| MapView.help(ctx));
 }
+
+#[test]
+fn test_custom_tiles_url() {
+    use walkers::sources::TileSource as _;
+
+    let tiles = CustomTiles {
+        url_template: "http://localhost:8080/tiles/{z}/{x}/{y}.png".to_owned(),
+    };
+    assert_eq!(
+        tiles.tile_url(walkers::TileId {
+            x: 3,
+            y: 5,
+            zoom: 4
+        }),
+        "http://localhost:8080/tiles/4/3/5.png"
+    );
+}
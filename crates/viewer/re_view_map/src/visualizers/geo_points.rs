@@ -143,6 +143,13 @@ impl GeoPointsVisualizer {
         )
     }
 
+    /// The positions of all the points in the visualizer.
+    pub fn positions(&self) -> impl Iterator<Item = walkers::Position> + '_ {
+        self.batches
+            .iter()
+            .flat_map(|(_, batch)| batch.positions.iter().copied())
+    }
+
     pub fn queue_draw_data(
         &self,
         render_ctx: &re_renderer::RenderContext,
@@ -0,0 +1,456 @@
+//! Interactive annotation of spatial views, for labeling the data shown in them.
+//!
+//! Annotations are drawn by clicking points in the view while a tool is active, and are logged
+//! into the recording as regular archetypes, at the current time. This way they are shown like
+//! any other data, and exported with the recording when it is saved.
+
+use re_chunk_store::{Chunk, RowId};
+use re_log_types::{EntityPath, TimePoint};
+use re_types::{
+    AsComponents,
+    archetypes::{Boxes2D, Boxes3D, LineStrips2D},
+};
+use re_ui::{ContextExt as _, UiExt as _};
+use re_viewer_context::{SystemCommand, SystemCommandSender as _, ViewerContext};
+
+use crate::view_kind::SpatialViewKind;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnnotationTool {
+    /// Axis-aligned 2D box, logged as [`Boxes2D`].
+    Box2D,
+
+    /// Closed 2D polygon, logged as [`LineStrips2D`].
+    Polygon2D,
+
+    /// Axis-aligned 3D box, logged as [`Boxes3D`].
+    Box3D,
+}
+
+impl AnnotationTool {
+    fn all(spatial_kind: SpatialViewKind) -> &'static [Self] {
+        match spatial_kind {
+            SpatialViewKind::TwoD => &[Self::Box2D, Self::Polygon2D],
+            SpatialViewKind::ThreeD => &[Self::Box3D],
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Box2D | Self::Box3D => "Box",
+            Self::Polygon2D => "Polygon",
+        }
+    }
+
+    fn hint(self) -> &'static str {
+        match self {
+            Self::Box2D => "Click two opposite corners",
+            Self::Polygon2D => "Click the corners, double-click or press Enter to finish",
+            Self::Box3D => "Click the center of the box on a surface",
+        }
+    }
+
+    /// The name of the entities the annotations are logged to, followed by an index.
+    fn entity_name(self) -> &'static str {
+        match self {
+            Self::Box2D | Self::Box3D => "box",
+            Self::Polygon2D => "polygon",
+        }
+    }
+
+    /// How many points complete an annotation, `None` if it is finished explicitly.
+    fn num_points(self) -> Option<usize> {
+        match self {
+            Self::Box2D => Some(2),
+            Self::Polygon2D => None,
+            Self::Box3D => Some(1),
+        }
+    }
+}
+
+/// The annotation in progress in a spatial view.
+#[derive(Clone)]
+pub struct AnnotationState {
+    /// The active tool, `None` if the view isn't annotating anything.
+    pub tool: Option<AnnotationTool>,
+
+    /// The label of the next annotations, may be empty.
+    pub label: String,
+
+    /// The class of the next annotations, see `AnnotationContext`.
+    pub class_id: Option<u16>,
+
+    /// The half size of the next 3D boxes.
+    pub half_size_3d: glam::Vec3,
+
+    /// The picked points, in the space of the view origin.
+    pub points: Vec<glam::Vec3>,
+}
+
+impl Default for AnnotationState {
+    fn default() -> Self {
+        Self {
+            tool: None,
+            label: String::new(),
+            class_id: None,
+            half_size_3d: glam::Vec3::splat(0.5),
+            points: Vec::new(),
+        }
+    }
+}
+
+impl AnnotationState {
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.tool.is_some()
+    }
+
+    /// Adds a picked point, logging the annotation if that completes it.
+    pub fn add_point(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        space_origin: &EntityPath,
+        point: glam::Vec3,
+    ) {
+        let Some(tool) = self.tool else {
+            return;
+        };
+
+        self.points.push(point);
+        if tool.num_points() == Some(self.points.len()) {
+            self.finish(ctx, space_origin);
+        }
+    }
+
+    /// Logs the annotation in progress, if it is complete, and starts the next one.
+    pub fn finish(&mut self, ctx: &ViewerContext<'_>, space_origin: &EntityPath) {
+        let Some(tool) = self.tool else {
+            return;
+        };
+
+        if let Some(archetype) = self.archetype() {
+            let annotations_path =
+                space_origin.join(&EntityPath::from_single_string("annotations"));
+            log_to_recording(
+                ctx,
+                &annotations_path,
+                tool.entity_name(),
+                archetype.as_ref(),
+            );
+        }
+        self.points.clear();
+    }
+
+    /// The annotation in progress as an archetype, `None` if it isn't complete.
+    fn archetype(&self) -> Option<Box<dyn AsComponents>> {
+        let labels = (!self.label.is_empty()).then(|| [self.label.clone()]);
+        let class_ids = self.class_id.map(|class_id| [class_id]);
+
+        match self.tool? {
+            AnnotationTool::Box2D => {
+                let [a, b] = self.points.as_slice() else {
+                    return None;
+                };
+                let (min, max) = (a.min(*b), a.max(*b));
+                let mut boxes = Boxes2D::from_mins_and_sizes(
+                    [[min.x, min.y]],
+                    [[max.x - min.x, max.y - min.y]],
+                );
+                if let Some(labels) = labels {
+                    boxes = boxes.with_labels(labels);
+                }
+                if let Some(class_ids) = class_ids {
+                    boxes = boxes.with_class_ids(class_ids);
+                }
+                Some(Box::new(boxes))
+            }
+
+            AnnotationTool::Polygon2D => {
+                if self.points.len() < 3 {
+                    return None;
+                }
+                let mut strips = LineStrips2D::new([self.strip().iter().map(|p| p.truncate())]);
+                if let Some(labels) = labels {
+                    strips = strips.with_labels(labels);
+                }
+                if let Some(class_ids) = class_ids {
+                    strips = strips.with_class_ids(class_ids);
+                }
+                Some(Box::new(strips))
+            }
+
+            AnnotationTool::Box3D => {
+                let [center] = self.points.as_slice() else {
+                    return None;
+                };
+                let mut boxes = Boxes3D::from_centers_and_half_sizes(
+                    [center.to_array()],
+                    [self.half_size_3d.to_array()],
+                );
+                if let Some(labels) = labels {
+                    boxes = boxes.with_labels(labels);
+                }
+                if let Some(class_ids) = class_ids {
+                    boxes = boxes.with_class_ids(class_ids);
+                }
+                Some(Box::new(boxes))
+            }
+        }
+    }
+
+    /// The outline of the 2D annotation in progress.
+    fn strip(&self) -> Vec<glam::Vec3> {
+        match (self.tool, self.points.as_slice()) {
+            (Some(AnnotationTool::Box2D), &[a, b]) => {
+                vec![
+                    a,
+                    glam::vec3(b.x, a.y, 0.0),
+                    b,
+                    glam::vec3(a.x, b.y, 0.0),
+                    a,
+                ]
+            }
+            (Some(AnnotationTool::Polygon2D), points) if points.len() >= 3 => {
+                let mut strip = points.to_vec();
+                strip.push(points[0]);
+                strip
+            }
+            (_, points) => points.to_vec(),
+        }
+    }
+
+    /// Shows the controls of the annotation tools, for the selection panel.
+    pub fn selection_ui(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        space_origin: &EntityPath,
+        spatial_kind: SpatialViewKind,
+    ) {
+        ui.grid_left_hand_label("Annotate").on_hover_text(format!(
+            "Draw annotations by clicking points in the view. \
+            They are logged under {space_origin}/annotations, at the current time"
+        ));
+        ui.vertical(|ui| {
+            let tool_before = self.tool;
+            egui::ComboBox::from_id_salt("annotation_tool")
+                .selected_text(self.tool.map_or("None", AnnotationTool::label))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.tool, None, "None");
+                    for &tool in AnnotationTool::all(spatial_kind) {
+                        ui.selectable_value(&mut self.tool, Some(tool), tool.label());
+                    }
+                });
+            if self.tool != tool_before {
+                self.points.clear();
+            }
+
+            let Some(tool) = self.tool else {
+                return;
+            };
+            ui.label(tool.hint());
+
+            ui.horizontal(|ui| {
+                ui.label("Label");
+                ui.text_edit_singleline(&mut self.label);
+            });
+
+            ui.horizontal(|ui| {
+                let mut has_class_id = self.class_id.is_some();
+                ui.re_checkbox(&mut has_class_id, "Class id");
+                match (has_class_id, &mut self.class_id) {
+                    (true, Some(class_id)) => {
+                        ui.add(egui::DragValue::new(class_id));
+                    }
+                    (true, class_id @ None) => *class_id = Some(0),
+                    (false, class_id) => *class_id = None,
+                }
+            });
+
+            if tool == AnnotationTool::Box3D {
+                ui.horizontal(|ui| {
+                    ui.label("Half size");
+                    for half_size in self.half_size_3d.as_mut() {
+                        ui.add(
+                            egui::DragValue::new(half_size)
+                                .range(0.0..=f32::INFINITY)
+                                .speed(0.01),
+                        );
+                    }
+                });
+            }
+
+            if tool.num_points().is_none() {
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(self.archetype().is_some(), egui::Button::new("Finish"))
+                        .clicked()
+                    {
+                        self.finish(ctx, space_origin);
+                    }
+                    if ui
+                        .add_enabled(!self.points.is_empty(), egui::Button::new("Undo point"))
+                        .clicked()
+                    {
+                        self.points.pop();
+                    }
+                });
+            }
+        });
+        ui.end_row();
+    }
+
+    /// Handles the clicks and keys for the annotation in progress.
+    ///
+    /// `hovered` is where the next point would be picked, if anywhere.
+    pub fn handle_input(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        ui: &egui::Ui,
+        response: &egui::Response,
+        space_origin: &EntityPath,
+        hovered: Option<glam::Vec3>,
+    ) {
+        if !self.is_active() {
+            return;
+        }
+
+        if response.hovered() {
+            ui.ctx().set_cursor_icon(egui::CursorIcon::Crosshair);
+        }
+
+        // Keys are for text fields while they have focus, e.g. the label.
+        let key_pressed =
+            |key| ui.memory(|mem| mem.focused().is_none()) && ui.input(|i| i.key_pressed(key));
+
+        if response.double_clicked() || key_pressed(egui::Key::Enter) {
+            // The first click of the double click already placed the last point.
+            self.finish(ctx, space_origin);
+        } else if key_pressed(egui::Key::Escape) {
+            self.points.clear();
+        } else if response.clicked()
+            && let Some(position) = hovered
+        {
+            self.add_point(ctx, space_origin, position);
+        }
+    }
+
+    /// Paints the 2D annotation in progress on top of the view.
+    ///
+    /// `ui_from_space` projects points of the view origin space into the ui.
+    /// `hovered` is where the next point would be picked, if anywhere.
+    pub fn paint_2d(
+        &self,
+        ui: &egui::Ui,
+        painter: &egui::Painter,
+        ui_from_space: impl Fn(glam::Vec3) -> egui::Pos2,
+        hovered: Option<glam::Vec3>,
+    ) {
+        if !self.is_active() || self.tool == Some(AnnotationTool::Box3D) {
+            return;
+        }
+
+        let stroke = ui.ctx().selection_stroke();
+
+        // Preview the annotation as if the next point was placed where the pointer is.
+        let mut preview = self.clone();
+        if let Some(hovered) = hovered
+            && !preview.points.is_empty()
+        {
+            preview.points.push(hovered);
+        }
+
+        let strip: Vec<_> = preview.strip().into_iter().map(&ui_from_space).collect();
+        painter.add(egui::Shape::line(strip, stroke));
+
+        for point in &self.points {
+            painter.circle_filled(ui_from_space(*point), 3.0, stroke.color);
+        }
+    }
+}
+
+/// Logs `archetype` into the recording, at the current time of the active timeline.
+///
+/// The entity is a child of `parent` that doesn't exist yet, called `name` followed by an index.
+pub fn log_to_recording(
+    ctx: &ViewerContext<'_>,
+    parent: &EntityPath,
+    name: &str,
+    archetype: &dyn AsComponents,
+) {
+    let recording = ctx.recording();
+    let mut index = 0;
+    let entity_path = loop {
+        let entity_path = parent.join(&EntityPath::from_single_string(format!("{name}_{index}")));
+        if !recording.is_known_entity(&entity_path) {
+            break entity_path;
+        }
+        index += 1;
+    };
+
+    let timepoint = {
+        let time_ctrl = ctx.rec_cfg.time_ctrl.read();
+        time_ctrl
+            .time_int()
+            .map(|time| TimePoint::default().with(*time_ctrl.timeline(), time))
+            .unwrap_or_default()
+    };
+
+    match Chunk::builder(entity_path)
+        .with_archetype(RowId::new(), timepoint, archetype)
+        .build()
+    {
+        Ok(chunk) => {
+            ctx.command_sender()
+                .send_system(SystemCommand::AppendToStore(
+                    recording.store_id().clone(),
+                    vec![chunk],
+                ));
+        }
+        Err(err) => {
+            re_log::error_once!("Failed to create annotation chunk: {err}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::vec3;
+
+    use super::*;
+
+    #[test]
+    fn box_2d_outline() {
+        let state = AnnotationState {
+            tool: Some(AnnotationTool::Box2D),
+            points: vec![vec3(2.0, 1.0, 0.0), vec3(0.0, 3.0, 0.0)],
+            ..Default::default()
+        };
+        assert!(state.archetype().is_some());
+        assert_eq!(
+            vec![
+                vec3(2.0, 1.0, 0.0),
+                vec3(0.0, 1.0, 0.0),
+                vec3(0.0, 3.0, 0.0),
+                vec3(2.0, 3.0, 0.0),
+                vec3(2.0, 1.0, 0.0),
+            ],
+            state.strip()
+        );
+    }
+
+    #[test]
+    fn polygon_needs_three_points() {
+        let mut state = AnnotationState {
+            tool: Some(AnnotationTool::Polygon2D),
+            points: vec![glam::Vec3::ZERO, glam::Vec3::X],
+            ..Default::default()
+        };
+        assert!(state.archetype().is_none());
+        assert_eq!(2, state.strip().len());
+
+        state.points.push(glam::Vec3::Y);
+        assert!(state.archetype().is_some());
+        assert_eq!(4, state.strip().len());
+    }
+}
@@ -1,11 +1,16 @@
+use std::sync::Arc;
+
+use ahash::HashMap;
 use nohash_hasher::IntMap;
 
-use re_chunk_store::LatestAtQuery;
+use re_chunk_store::{ChunkStoreGeneration, LatestAtQuery};
 use re_entity_db::{EntityPath, EntityTree};
-use re_log_types::EntityPathHash;
+use re_log_types::{EntityPathHash, TimeInt, TimelineName};
 use re_types::{ArchetypeName, archetypes, components::ImagePlaneDistance};
 use re_view::DataResultQuery as _;
-use re_viewer_context::{DataResultTree, IdentifiedViewSystem, ViewContext, ViewContextSystem};
+use re_viewer_context::{
+    Cache, DataResultTree, IdentifiedViewSystem, ViewContext, ViewContextSystem, ViewId,
+};
 use vec1::smallvec_v1::SmallVec1;
 
 use crate::{
@@ -69,6 +74,12 @@ impl Default for TransformInfo {
 }
 
 impl TransformInfo {
+    /// The transform from the entity to the reference space, without per instance poses.
+    #[inline]
+    pub fn reference_from_entity(&self) -> glam::Affine3A {
+        self.reference_from_entity
+    }
+
     /// Warns that multiple transforms within the entity are not supported.
     #[inline]
     fn warn_on_per_instance_transform(&self, entity_name: &EntityPath, archetype: ArchetypeName) {
@@ -124,6 +135,9 @@ impl TransformInfo {
 /// The renderer then uses this reference space as its world space,
 /// making world and reference space equivalent for a given view.
 ///
+/// The transforms of the previous frame are kept in the [`TransformTreeCache`], so that only the subtrees
+/// of entities whose transforms changed since are resolved again, see [`Self::update_changed_subtrees`].
+///
 /// TODO(#7025): Right now we also do full tree traversal in here to resolve transforms to the root.
 /// However, for views that share the same query, we can easily make all entities relative to the respective origin in a linear pass over all matrices.
 /// (Note that right now the query IS always the same across all views for a given frame since it's just latest-at controlled by the timeline,
@@ -134,7 +148,9 @@ pub struct TransformTreeContext {
     space_origin: EntityPath,
 
     /// All reachable entities.
-    transform_per_entity: IntMap<EntityPathHash, TransformInfo>,
+    ///
+    /// Shared with the [`TransformTreeCache`], which holds on to it for the next frame.
+    transform_per_entity: Arc<IntMap<EntityPathHash, TransformInfo>>,
 }
 
 impl IdentifiedViewSystem for TransformTreeContext {
@@ -190,9 +206,39 @@ impl ViewContextSystem for TransformTreeContext {
 
         let time_query = ctx.current_query();
 
+        let caches = ctx.viewer_ctx.store_context.caches;
+        let recording_generation = ctx.recording().generation();
+        let blueprint_generation = ctx.blueprint_db().generation();
+
+        // Anything but moving through time may change the transforms of arbitrary entities.
+        let mut previous = caches
+            .entry(|c: &mut TransformTreeCache| c.per_view.remove(&ctx.view_id))
+            .filter(|previous| {
+                previous.space_origin == self.space_origin
+                    && previous.timeline == query.timeline
+                    && previous.recording_generation == recording_generation
+                    && previous.blueprint_generation == blueprint_generation
+            });
+
         TransformCacheStoreSubscriber::access(ctx.recording().store_id(), |cache| {
             let transforms = cache.transforms_for_timeline(query.timeline);
 
+            if let Some(previous) = previous.take() {
+                // Not shared with anyone else anymore, so it can be updated in place.
+                self.transform_per_entity = previous.transform_per_entity;
+                if self.update_changed_subtrees(
+                    ctx,
+                    data_result_tree,
+                    &time_query,
+                    previous.time,
+                    transforms,
+                ) {
+                    return;
+                }
+            }
+
+            self.transform_per_entity = Default::default();
+
             // Child transforms of this space
             {
                 re_tracing::profile_scope!("gather_descendants_transforms");
@@ -217,6 +263,17 @@ impl ViewContextSystem for TransformTreeContext {
                 transforms,
             );
         }); // Note that this can return None if no event has happened for this timeline yet.
+
+        let cached = CachedTransformTree {
+            space_origin: self.space_origin.clone(),
+            timeline: query.timeline,
+            time: time_query.at(),
+            recording_generation,
+            blueprint_generation,
+            transform_per_entity: self.transform_per_entity.clone(),
+            is_used: true,
+        };
+        caches.entry(|c: &mut TransformTreeCache| c.per_view.insert(ctx.view_id, cached));
     }
 
     fn as_any(&self) -> &dyn std::any::Any {
@@ -225,6 +282,96 @@ impl ViewContextSystem for TransformTreeContext {
 }
 
 impl TransformTreeContext {
+    /// Updates the transforms of the previous frame, which were resolved at `previous_time`,
+    /// to the current query time.
+    ///
+    /// Only the subtrees of entities whose transforms changed in between are resolved again.
+    /// Returns `false` if that's not possible because a transform at or above the space origin changed,
+    /// in which case all transforms have to be resolved from scratch.
+    fn update_changed_subtrees(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        data_result_tree: &DataResultTree,
+        query: &LatestAtQuery,
+        previous_time: TimeInt,
+        transforms: &CachedTransformsForTimeline,
+    ) -> bool {
+        re_tracing::profile_function!();
+
+        let (min_time, max_time) = if previous_time <= query.at() {
+            (previous_time, query.at())
+        } else {
+            (query.at(), previous_time)
+        };
+
+        let mut changed_entities = Vec::new();
+        for (entity_path, entity_transforms) in transforms.entities_with_transforms() {
+            let is_at_or_above_origin = self.space_origin.starts_with(entity_path);
+            if entity_transforms.has_changes_in(min_time, max_time) {
+                if is_at_or_above_origin {
+                    return false;
+                }
+                changed_entities.push(entity_path);
+            } else if !is_at_or_above_origin && entity_transforms.latest_at_pinhole(query).is_some()
+            {
+                // The image plane distance of pinholes below the origin may change any frame,
+                // e.g. because its fallback depends on the size of the scene.
+                changed_entities.push(entity_path);
+            }
+        }
+
+        // Resolving a subtree covers all changes below it.
+        changed_entities.sort_by_key(|entity_path| entity_path.len());
+        let mut resolved_subtrees: Vec<&EntityPath> = Vec::new();
+
+        let entity_tree = ctx.recording().tree();
+        for entity_path in changed_entities {
+            if resolved_subtrees
+                .iter()
+                .any(|subtree| entity_path.is_descendant_of(subtree))
+            {
+                continue;
+            }
+            resolved_subtrees.push(entity_path);
+
+            let (Some(parent_path), Some(subtree)) =
+                (entity_path.parent(), entity_tree.subtree(entity_path))
+            else {
+                continue;
+            };
+            let Some(parent_transform) = self.transform_per_entity.get(&parent_path.hash()) else {
+                continue;
+            };
+            let reference_from_parent = parent_transform.reference_from_entity;
+            let twod_in_threed_info = parent_transform.twod_in_threed_info.clone();
+
+            let transform_per_entity = Arc::make_mut(&mut self.transform_per_entity);
+            subtree.visit_children_recursively(|child_path| {
+                transform_per_entity.remove(&child_path.hash());
+            });
+
+            let transform = child_transform_info(
+                ctx,
+                data_result_tree,
+                entity_path,
+                query,
+                reference_from_parent,
+                twod_in_threed_info.as_ref(),
+                transforms,
+            );
+            self.gather_descendants_transforms(
+                ctx,
+                data_result_tree,
+                subtree,
+                query,
+                transform,
+                transforms,
+            );
+        }
+
+        true
+    }
+
     /// Gather transforms for everything _above_ the root.
     fn gather_parent_transforms<'a>(
         &mut self,
@@ -292,7 +439,7 @@ impl TransformTreeContext {
     ) {
         let twod_in_threed_info = transform.twod_in_threed_info.clone();
         let reference_from_parent = transform.reference_from_entity;
-        match self.transform_per_entity.entry(subtree.path.hash()) {
+        match Arc::make_mut(&mut self.transform_per_entity).entry(subtree.path.hash()) {
             std::collections::hash_map::Entry::Occupied(_) => {
                 return;
             }
@@ -302,27 +449,14 @@ impl TransformTreeContext {
         }
 
         for child_tree in subtree.children.values() {
-            let child_path = &child_tree.path;
-
-            let lookup_image_plane =
-                |p: &_| lookup_image_plane_distance(ctx, data_result_tree, p, query);
-
-            let mut encountered_pinhole = twod_in_threed_info
-                .as_ref()
-                .map(|info| info.parent_pinhole.clone());
-
-            let transforms_at_entity = transforms_at(
-                child_path,
+            let new_transform = child_transform_info(
+                ctx,
+                data_result_tree,
+                &child_tree.path,
                 query,
-                lookup_image_plane,
-                &mut encountered_pinhole,
-                transforms_for_timeline,
-            );
-            let new_transform = transform_info_for_downward_propagation(
-                child_path,
                 reference_from_parent,
-                twod_in_threed_info.clone(),
-                &transforms_at_entity,
+                twod_in_threed_info.as_ref(),
+                transforms_for_timeline,
             );
 
             self.gather_descendants_transforms(
@@ -348,6 +482,82 @@ impl TransformTreeContext {
     }
 }
 
+/// Transforms of a view as of the previous frame, see [`TransformTreeCache`].
+struct CachedTransformTree {
+    space_origin: EntityPath,
+    timeline: TimelineName,
+    time: TimeInt,
+    recording_generation: ChunkStoreGeneration,
+    blueprint_generation: ChunkStoreGeneration,
+    transform_per_entity: Arc<IntMap<EntityPathHash, TransformInfo>>,
+
+    /// Whether the view was shown since the last frame began.
+    is_used: bool,
+}
+
+/// Keeps the resolved transforms of every view around for the next frame,
+/// so that [`TransformTreeContext`] doesn't need to resolve the whole tree again when only a few transforms change.
+#[derive(Default)]
+pub struct TransformTreeCache {
+    per_view: HashMap<ViewId, CachedTransformTree>,
+}
+
+impl Cache for TransformTreeCache {
+    fn begin_frame(&mut self) {
+        // Forget about views that are no longer shown.
+        self.per_view
+            .retain(|_, cached| std::mem::take(&mut cached.is_used));
+    }
+
+    fn bytes_used(&self) -> u64 {
+        self.per_view
+            .values()
+            .map(|cached| {
+                (cached.transform_per_entity.capacity()
+                    * (size_of::<EntityPathHash>() + size_of::<TransformInfo>()))
+                    as u64
+            })
+            .sum()
+    }
+
+    fn purge_memory(&mut self) {
+        self.per_view.clear();
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Resolves the transform of a child entity, given the one of its parent.
+fn child_transform_info(
+    ctx: &ViewContext<'_>,
+    data_result_tree: &DataResultTree,
+    child_path: &EntityPath,
+    query: &LatestAtQuery,
+    reference_from_parent: glam::Affine3A,
+    twod_in_threed_info: Option<&TwoDInThreeDTransformInfo>,
+    transforms_for_timeline: &CachedTransformsForTimeline,
+) -> TransformInfo {
+    let lookup_image_plane = |p: &_| lookup_image_plane_distance(ctx, data_result_tree, p, query);
+
+    let mut encountered_pinhole = twod_in_threed_info.map(|info| info.parent_pinhole.clone());
+
+    let transforms_at_entity = transforms_at(
+        child_path,
+        query,
+        lookup_image_plane,
+        &mut encountered_pinhole,
+        transforms_for_timeline,
+    );
+    transform_info_for_downward_propagation(
+        child_path,
+        reference_from_parent,
+        twod_in_threed_info.cloned(),
+        &transforms_at_entity,
+    )
+}
+
 fn lookup_image_plane_distance(
     ctx: &ViewContext<'_>,
     data_result_tree: &DataResultTree,
@@ -521,6 +731,7 @@ fn debug_assert_transform_field_order(reflection: &re_types::reflection::Reflect
         components::Translation3D::name(),
         components::RotationAxisAngle::name(),
         components::RotationQuat::name(),
+        components::RotationEuler::name(),
         components::Scale3D::name(),
         components::TransformMat3x3::name(),
     ];
@@ -0,0 +1,87 @@
+//! Tight bounds for depth clouds.
+//!
+//! Depth clouds are backprojected on the GPU, so the viewer doesn't otherwise know where their
+//! points end up. Bounding the whole frustum up to the farthest depth is far too loose for e.g.
+//! a camera looking at a wall, so the depth images are backprojected once on the CPU instead.
+
+use ahash::{HashMap, HashSet};
+use itertools::Either;
+
+use re_chunk_store::ChunkStoreEvent;
+use re_types::{Component as _, components};
+use re_viewer_context::{Cache, ImageInfo, StoredBlobCacheKey};
+
+/// Identifies the points of a depth cloud: the image, the intrinsics, and the depth scale.
+type CacheKey = (StoredBlobCacheKey, [u32; 9], u32);
+
+/// Caches the bounding boxes of depth clouds, in the camera's RDF space.
+#[derive(Default)]
+pub struct DepthCloudBoundsCache(HashMap<CacheKey, macaw::BoundingBox>);
+
+impl DepthCloudBoundsCache {
+    /// The bounding box of the valid depths of the image, backprojected with the given
+    /// intrinsics, in RDF space.
+    ///
+    /// Is [`macaw::BoundingBox::nothing`] if the image has no valid depths.
+    pub fn entry(
+        &mut self,
+        image: &ImageInfo,
+        image_from_camera: glam::Mat3,
+        depth_meter: f32,
+    ) -> macaw::BoundingBox {
+        let key = (
+            image.buffer_content_hash,
+            image_from_camera.to_cols_array().map(f32::to_bits),
+            depth_meter.to_bits(),
+        );
+
+        *self.0.entry(key).or_insert_with(|| {
+            let points = components::PinholeProjection::from(image_from_camera)
+                .backproject_depth_image(&image.buffer, &image.format, depth_meter);
+            macaw::BoundingBox::from_points(points.into_iter())
+        })
+    }
+}
+
+impl Cache for DepthCloudBoundsCache {
+    fn purge_memory(&mut self) {
+        // Purging the bounds is not worth it - these are very small objects!
+    }
+
+    fn bytes_used(&self) -> u64 {
+        (self.0.len() * std::mem::size_of::<(CacheKey, macaw::BoundingBox)>()) as u64
+    }
+
+    fn on_store_events(&mut self, events: &[&ChunkStoreEvent]) {
+        re_tracing::profile_function!();
+
+        let blobs_removed: HashSet<StoredBlobCacheKey> = events
+            .iter()
+            .flat_map(|event| {
+                if event.kind == re_chunk_store::ChunkStoreDiffKind::Deletion {
+                    Either::Left(
+                        event
+                            .chunk
+                            .component_descriptors()
+                            .filter(|descr| descr.component_type == Some(components::Blob::name()))
+                            .flat_map(|descr| {
+                                event
+                                    .chunk
+                                    .row_ids()
+                                    .map(move |row_id| StoredBlobCacheKey::new(row_id, &descr))
+                            }),
+                    )
+                } else {
+                    Either::Right(std::iter::empty())
+                }
+            })
+            .collect();
+
+        self.0
+            .retain(|(blob, _, _), _| !blobs_removed.contains(blob));
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -2,10 +2,13 @@
 //!
 //! Views that show entities in a 2D or 3D spatial relationship.
 
+mod annotation;
 mod contexts;
+mod depth_cloud_bounds;
 mod eye;
 mod heuristics;
 mod max_image_dimension_subscriber;
+mod measurement;
 mod mesh_cache;
 mod mesh_loader;
 mod pickable_textured_rect;
@@ -13,6 +16,8 @@ mod picking;
 mod picking_ui;
 mod picking_ui_pixel;
 mod pinhole;
+mod point_accumulation;
+mod point_lod;
 mod proc_mesh;
 mod scene_bounding_boxes;
 mod space_camera_3d;
@@ -27,13 +32,15 @@ mod view_3d_properties;
 mod visualizers;
 
 mod transform_cache;
+mod transform_tree_panel;
 
+pub use transform_tree_panel::TransformTreePanel;
 pub use ui::SpatialViewState;
 pub use view_2d::SpatialView2D;
 pub use view_3d::SpatialView3D;
 
 pub(crate) use pickable_textured_rect::{PickableRectSourceData, PickableTexturedRect};
-pub(crate) use pinhole::Pinhole;
+pub(crate) use pinhole::{LensDistortion, Pinhole};
 
 // ---
 
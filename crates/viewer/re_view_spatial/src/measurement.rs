@@ -0,0 +1,357 @@
+//! Interactive measurements of distances, angles and areas in spatial views.
+//!
+//! Measurements are picked by clicking points in the view while a tool is active,
+//! and can be logged back into the recording as line strip annotations.
+
+use re_format::format_f32;
+use re_log_types::EntityPath;
+use re_types::archetypes::{LineStrips2D, LineStrips3D};
+use re_ui::{ContextExt as _, UiExt as _};
+use re_viewer_context::ViewerContext;
+
+use crate::{annotation::log_to_recording, view_kind::SpatialViewKind};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MeasurementTool {
+    /// Distance between two points.
+    Distance,
+
+    /// Angle between the lines from a vertex to two points.
+    Angle,
+
+    /// Area of a planar polygon.
+    Area,
+}
+
+impl MeasurementTool {
+    const ALL: [Self; 3] = [Self::Distance, Self::Angle, Self::Area];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Distance => "Distance",
+            Self::Angle => "Angle",
+            Self::Area => "Area",
+        }
+    }
+
+    fn hint(self) -> &'static str {
+        match self {
+            Self::Distance => "Click two points",
+            Self::Angle => "Click a point, the vertex, and another point",
+            Self::Area => "Click the corners of a polygon",
+        }
+    }
+
+    /// How many points a measurement needs before it has a value.
+    fn min_points(self) -> usize {
+        match self {
+            Self::Distance => 2,
+            Self::Angle | Self::Area => 3,
+        }
+    }
+
+    /// How many points a measurement takes at most, `None` if there is no limit.
+    fn max_points(self) -> Option<usize> {
+        match self {
+            Self::Distance => Some(2),
+            Self::Angle => Some(3),
+            Self::Area => None,
+        }
+    }
+}
+
+/// The measurement in progress in a spatial view.
+#[derive(Clone, Default)]
+pub struct MeasurementState {
+    /// The active tool, `None` if the view isn't measuring anything.
+    pub tool: Option<MeasurementTool>,
+
+    /// The picked points, in the space of the view origin.
+    pub points: Vec<glam::Vec3>,
+}
+
+impl MeasurementState {
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.tool.is_some()
+    }
+
+    /// Adds a picked point, starting a new measurement if the current one is complete.
+    pub fn add_point(&mut self, point: glam::Vec3) {
+        let Some(tool) = self.tool else {
+            return;
+        };
+
+        if tool
+            .max_points()
+            .is_some_and(|max_points| self.points.len() >= max_points)
+        {
+            self.points.clear();
+        }
+        self.points.push(point);
+    }
+
+    /// The measured value, if enough points were picked.
+    ///
+    /// Distances and areas are in scene units, angles in degrees.
+    pub fn value(&self) -> Option<f32> {
+        let tool = self.tool?;
+        if self.points.len() < tool.min_points() {
+            return None;
+        }
+
+        Some(match tool {
+            MeasurementTool::Distance => self.points[0].distance(self.points[1]),
+            MeasurementTool::Angle => angle_degrees(self.points[0], self.points[1], self.points[2]),
+            MeasurementTool::Area => polygon_area(&self.points),
+        })
+    }
+
+    pub fn value_text(&self) -> Option<String> {
+        let value = self.value()?;
+        Some(match self.tool? {
+            MeasurementTool::Distance => format_f32(value),
+            MeasurementTool::Angle => format!("{}°", format_f32(value)),
+            MeasurementTool::Area => format!("{}²", format_f32(value)),
+        })
+    }
+
+    /// The points to draw as a line strip, closed for areas.
+    fn strip(&self) -> Vec<glam::Vec3> {
+        let mut strip = self.points.clone();
+        if self.tool == Some(MeasurementTool::Area)
+            && self.points.len() >= MeasurementTool::Area.min_points()
+        {
+            strip.push(self.points[0]);
+        }
+        strip
+    }
+
+    /// Shows the controls of the measurement tools, for the selection panel.
+    pub fn selection_ui(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        space_origin: &EntityPath,
+        spatial_kind: SpatialViewKind,
+    ) {
+        ui.grid_left_hand_label("Measure")
+            .on_hover_text("Measure distances, angles and areas by clicking points in the view");
+        ui.vertical(|ui| {
+            let tool_before = self.tool;
+            egui::ComboBox::from_id_salt("measurement_tool")
+                .selected_text(self.tool.map_or("None", MeasurementTool::label))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.tool, None, "None");
+                    for tool in MeasurementTool::ALL {
+                        ui.selectable_value(&mut self.tool, Some(tool), tool.label());
+                    }
+                });
+            if self.tool != tool_before {
+                self.points.clear();
+            }
+
+            let Some(tool) = self.tool else {
+                return;
+            };
+
+            match self.value_text() {
+                Some(value_text) => {
+                    ui.strong(format!("{}: {value_text}", tool.label()));
+                }
+                None => {
+                    ui.label(tool.hint());
+                }
+            }
+
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(!self.points.is_empty(), egui::Button::new("Undo point"))
+                    .clicked()
+                {
+                    self.points.pop();
+                }
+                if ui
+                    .add_enabled(!self.points.is_empty(), egui::Button::new("Clear"))
+                    .clicked()
+                {
+                    self.points.clear();
+                }
+            });
+
+            if ui
+                .add_enabled(self.value().is_some(), egui::Button::new("Log to recording"))
+                .on_hover_text(format!(
+                    "Log the measurement as an annotation under {space_origin}/measurements, at the current time"
+                ))
+                .clicked()
+            {
+                self.log_to_recording(ctx, space_origin, spatial_kind);
+            }
+        });
+        ui.end_row();
+    }
+
+    /// Logs the measurement as a labeled line strip, at the current time of the active timeline.
+    fn log_to_recording(
+        &self,
+        ctx: &ViewerContext<'_>,
+        space_origin: &EntityPath,
+        spatial_kind: SpatialViewKind,
+    ) {
+        let (Some(tool), Some(label)) = (self.tool, self.value_text()) else {
+            return;
+        };
+
+        let measurements_path = space_origin.join(&EntityPath::from_single_string("measurements"));
+        let name = tool.label().to_lowercase();
+        let strip = self.strip();
+        match spatial_kind {
+            SpatialViewKind::TwoD => log_to_recording(
+                ctx,
+                &measurements_path,
+                &name,
+                &LineStrips2D::new([strip.iter().map(|p| p.truncate())]).with_labels([label]),
+            ),
+            SpatialViewKind::ThreeD => log_to_recording(
+                ctx,
+                &measurements_path,
+                &name,
+                &LineStrips3D::new([strip]).with_labels([label]),
+            ),
+        }
+    }
+
+    /// Paints the measurement on top of the view.
+    ///
+    /// `ui_from_space` projects points of the view origin space into the ui, `None` if they aren't visible.
+    /// `hovered` is where the next point would be picked, if anywhere.
+    pub fn paint(
+        &self,
+        ui: &egui::Ui,
+        painter: &egui::Painter,
+        ui_from_space: impl Fn(glam::Vec3) -> Option<egui::Pos2>,
+        hovered: Option<glam::Vec3>,
+    ) {
+        let Some(tool) = self.tool else {
+            return;
+        };
+
+        let stroke = ui.ctx().selection_stroke();
+        let strip: Vec<_> = self
+            .strip()
+            .into_iter()
+            .filter_map(&ui_from_space)
+            .collect();
+        painter.add(egui::Shape::line(strip, stroke));
+
+        for point in self.points.iter().filter_map(|&p| ui_from_space(p)) {
+            painter.circle_filled(point, 3.0, stroke.color);
+        }
+
+        // Preview where the next point goes.
+        let is_complete = tool
+            .max_points()
+            .is_some_and(|max_points| self.points.len() >= max_points);
+        if !is_complete
+            && let Some(last) = self.points.last().and_then(|&p| ui_from_space(p))
+            && let Some(hovered) = hovered.and_then(&ui_from_space)
+        {
+            painter.extend(egui::Shape::dashed_line(&[last, hovered], stroke, 4.0, 4.0));
+        }
+
+        let Some(value_text) = self.value_text() else {
+            return;
+        };
+        let anchor = match tool {
+            MeasurementTool::Distance => self
+                .points
+                .first()
+                .zip(self.points.get(1))
+                .map(|(&a, &b)| a.lerp(b, 0.5)),
+            MeasurementTool::Angle => self.points.get(1).copied(),
+            MeasurementTool::Area => {
+                Some(self.points.iter().sum::<glam::Vec3>() / self.points.len() as f32)
+            }
+        };
+        let Some(anchor) = anchor.and_then(&ui_from_space) else {
+            return;
+        };
+
+        let text_color = ui.visuals().strong_text_color();
+        let galley = painter.layout_no_wrap(
+            value_text,
+            egui::TextStyle::Body.resolve(ui.style()),
+            text_color,
+        );
+        let text_rect =
+            egui::Align2::CENTER_BOTTOM.anchor_size(anchor - egui::vec2(0.0, 6.0), galley.size());
+        painter.rect_filled(
+            text_rect.expand2(egui::vec2(4.0, 2.0)),
+            4.0,
+            ui.visuals().widgets.inactive.bg_fill,
+        );
+        painter.galley(text_rect.min, galley, text_color);
+    }
+}
+
+/// The angle at `vertex` between the lines to `a` and `b`, in degrees.
+fn angle_degrees(a: glam::Vec3, vertex: glam::Vec3, b: glam::Vec3) -> f32 {
+    (a - vertex).angle_between(b - vertex).to_degrees()
+}
+
+/// The area of a planar polygon, in any orientation.
+fn polygon_area(points: &[glam::Vec3]) -> f32 {
+    // Newell's method: the length of the summed cross products is twice the area.
+    let normal: glam::Vec3 = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(a, b)| a.cross(*b))
+        .sum();
+    0.5 * normal.length()
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::{Vec3, vec3};
+
+    use super::*;
+
+    #[test]
+    fn measurements() {
+        let mut state = MeasurementState {
+            tool: Some(MeasurementTool::Distance),
+            points: Vec::new(),
+        };
+        state.add_point(Vec3::ZERO);
+        assert_eq!(None, state.value());
+        state.add_point(vec3(3.0, 4.0, 0.0));
+        assert_eq!(Some(5.0), state.value());
+
+        // A complete measurement is replaced by the next one.
+        state.add_point(Vec3::X);
+        assert_eq!(vec![Vec3::X], state.points);
+
+        state.tool = Some(MeasurementTool::Angle);
+        state.points = vec![Vec3::X, Vec3::ZERO, Vec3::Z];
+        assert!((state.value().unwrap_or_default() - 90.0).abs() < 1e-4);
+
+        state.tool = Some(MeasurementTool::Area);
+        state.points = vec![Vec3::ZERO, Vec3::X, Vec3::X + Vec3::Z];
+        state.add_point(vec3(0.5, 0.0, 1.5));
+        state.add_point(Vec3::Z);
+        assert!((state.value().unwrap_or_default() - 1.25).abs() < 1e-4);
+        assert_eq!(6, state.strip().len());
+    }
+
+    #[test]
+    fn area_of_tilted_polygon() {
+        let area = polygon_area(&[
+            vec3(1.0, 1.0, 1.0),
+            vec3(3.0, 1.0, 3.0),
+            vec3(3.0, 3.0, 3.0),
+            vec3(1.0, 3.0, 1.0),
+        ]);
+        assert!((area - 2.0 * 8.0_f32.sqrt()).abs() < 1e-4);
+    }
+}
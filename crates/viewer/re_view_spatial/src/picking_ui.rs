@@ -9,8 +9,8 @@ use re_ui::{
 };
 use re_view::AnnotationSceneContext;
 use re_viewer_context::{
-    Item, ItemCollection, ItemContext, UiLayout, ViewQuery, ViewSystemExecutionError,
-    ViewerContext, VisualizerCollection,
+    HoveredTime, Item, ItemCollection, ItemContext, UiLayout, ViewQuery, ViewSystemExecutionError,
+    ViewerContext, VisualizerCollection, latest_row_time,
 };
 
 use crate::{
@@ -176,8 +176,12 @@ pub fn picking(
         //
         // So we apply the following logic: if the hovered items are a mix of images and non-images,
         // then we only select the non-images on click.
+        //
+        // While measuring or annotating, clicks pick points instead, so the view stays selected.
 
-        if !hovered_non_image_items.is_empty() {
+        if state.measurement.is_active() || state.annotation.is_active() {
+            vec![Item::View(query.view_id)]
+        } else if !hovered_non_image_items.is_empty() {
             hovered_non_image_items
         } else if !hovered_image_items.is_empty() {
             hovered_image_items
@@ -225,6 +229,24 @@ pub fn picking(
         });
     }
 
+    // Show the time of the hovered entity in other views, e.g. as a cursor in plots.
+    if ctx.app_options().linked_time_cursor
+        && let Some(Item::DataResult(_, instance_path)) = hovered_items.first_item()
+    {
+        let latest_at_query = query.latest_at_query();
+        if let Some(time) = latest_row_time(
+            ctx.recording(),
+            &latest_at_query,
+            &instance_path.entity_path,
+        ) {
+            ctx.selection_state().set_hovered_time(HoveredTime {
+                view_id: query.view_id,
+                timeline: latest_at_query.timeline(),
+                time,
+            });
+        }
+    }
+
     ctx.handle_select_hover_drag_interactions(&response, hovered_items, false);
 
     Ok(response)
@@ -9,6 +9,59 @@ use crate::resolution_of_image_at;
 pub struct Pinhole {
     pub image_from_camera: glam::Mat3,
     pub resolution: glam::Vec2,
+
+    /// Lens distortion, if any.
+    pub distortion: Option<LensDistortion>,
+}
+
+/// Lens distortion of a [`Pinhole`] camera.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LensDistortion {
+    pub model: components::DistortionModel,
+
+    /// The coefficients of the model, padded with zeros.
+    pub coefficients: [f32; components::DistortionModel::MAX_COEFFICIENTS],
+}
+
+impl LensDistortion {
+    /// Returns `None` if all coefficients are zero, i.e. if there's no distortion at all.
+    pub fn new(
+        model: components::DistortionModel,
+        coefficients: impl IntoIterator<Item = components::DistortionCoefficient>,
+    ) -> Option<Self> {
+        let mut padded = [0.0; components::DistortionModel::MAX_COEFFICIENTS];
+        for (padded, coefficient) in padded
+            .iter_mut()
+            .zip(coefficients)
+            .take(model.num_coefficients())
+        {
+            *padded = coefficient.0.0;
+        }
+
+        padded
+            .iter()
+            .any(|&coefficient| coefficient != 0.0)
+            .then_some(Self {
+                model,
+                coefficients: padded,
+            })
+    }
+
+    /// Distorts a point on the normalized image plane.
+    #[inline]
+    pub fn distort(&self, point: glam::Vec2) -> glam::Vec2 {
+        self.model.distort(&self.coefficients, point)
+    }
+
+    /// Undistorts a point on the normalized image plane.
+    ///
+    /// Points for which this doesn't converge are returned as is.
+    #[inline]
+    pub fn undistort(&self, point: glam::Vec2) -> glam::Vec2 {
+        self.model
+            .undistort(&self.coefficients, point)
+            .unwrap_or(point)
+    }
 }
 
 impl Pinhole {
@@ -59,20 +112,39 @@ impl Pinhole {
 
     /// Project camera-space coordinates into pixel coordinates,
     /// returning the same z/depth.
+    ///
+    /// Takes lens distortion into account.
     #[inline]
     pub fn project(&self, pixel: glam::Vec3) -> glam::Vec3 {
-        ((pixel.truncate() * self.focal_length_in_pixels()) / pixel.z + self.principal_point())
-            .extend(pixel.z)
+        let mut normalized = pixel.truncate() / pixel.z;
+        if let Some(distortion) = &self.distortion {
+            normalized = distortion.distort(normalized);
+        }
+        (normalized * self.focal_length_in_pixels() + self.principal_point()).extend(pixel.z)
     }
 
     /// Given pixel coordinates and a world-space depth,
     /// return a position in the camera space.
     ///
     /// The returned z is the same as the input z (depth).
+    /// Takes lens distortion into account.
     #[inline]
     pub fn unproject(&self, pixel: glam::Vec3) -> glam::Vec3 {
-        ((pixel.truncate() - self.principal_point()) * pixel.z / self.focal_length_in_pixels())
-            .extend(pixel.z)
+        let mut normalized =
+            (pixel.truncate() - self.principal_point()) / self.focal_length_in_pixels();
+        if let Some(distortion) = &self.distortion {
+            normalized = distortion.undistort(normalized);
+        }
+        (normalized * pixel.z).extend(pixel.z)
+    }
+
+    /// The same camera, but without lens distortion.
+    #[inline]
+    pub fn without_distortion(self) -> Self {
+        Self {
+            distortion: None,
+            ..self
+        }
     }
 }
 
@@ -99,6 +171,8 @@ pub fn query_pinhole_and_view_coordinates_from_store_without_blueprint(
         [
             &archetypes::Pinhole::descriptor_image_from_camera(),
             &archetypes::Pinhole::descriptor_resolution(),
+            &archetypes::Pinhole::descriptor_distortion_model(),
+            &archetypes::Pinhole::descriptor_distortion_coefficients(),
             // Note that `components::ViewCoordinates` is somewhat special, in that for convenience it can
             // be specified in multiple places (i.e. `archetypes`). This used to be fine, but got quite a
             // bit more cumbersome with fully-qualified component descriptors. Because of this, we now have
@@ -126,11 +200,22 @@ pub fn query_pinhole_and_view_coordinates_from_store_without_blueprint(
             query_results.component_mono_quiet(&archetypes::ViewCoordinates::descriptor_xyz())
         })
         .unwrap_or(archetypes::Pinhole::DEFAULT_CAMERA_XYZ);
+    let distortion = LensDistortion::new(
+        query_results
+            .component_mono_quiet(&archetypes::Pinhole::descriptor_distortion_model())
+            .unwrap_or_default(),
+        query_results
+            .component_batch_quiet::<components::DistortionCoefficient>(
+                &archetypes::Pinhole::descriptor_distortion_coefficients(),
+            )
+            .unwrap_or_default(),
+    );
 
     Some((
         Pinhole {
             image_from_camera: pinhole_projection.0.into(),
             resolution: resolution.into(),
+            distortion,
         },
         camera_xyz,
     ))
@@ -0,0 +1,115 @@
+//! Accumulation of points over time, e.g. of sparse lidar scans.
+//!
+//! Points are accumulated over the visible time range of their entity, e.g. the last 5 seconds or
+//! 10 frames. These options control how the accumulated points are shown.
+
+use ahash::HashMap;
+
+use re_chunk_store::LatestAtQuery;
+use re_log_types::{EntityPath, TimeInt, TimelineName};
+use re_ui::UiExt as _;
+
+use crate::transform_cache::TransformCacheStoreSubscriber;
+
+/// How much the oldest accumulated points are darkened, from 0 (black) to 1 (not at all).
+const OLDEST_BRIGHTNESS: f32 = 0.2;
+
+/// Options for points accumulated over a visible time range.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PointAccumulation {
+    /// Darken points the older they are.
+    pub fade_by_age: bool,
+
+    /// Place points with the transforms of their entity at the time they were logged,
+    /// instead of the transforms at the current time.
+    pub transform_at_log_time: bool,
+}
+
+impl PointAccumulation {
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.fade_by_age || self.transform_at_log_time
+    }
+
+    /// Shows the accumulation options, for the selection panel.
+    pub fn selection_ui(&mut self, ui: &mut egui::Ui) {
+        ui.grid_left_hand_label("Accumulation").on_hover_text(
+            "How points accumulated over the visible time range of their entity are shown",
+        );
+        ui.vertical(|ui| {
+            ui.re_checkbox(&mut self.fade_by_age, "Fade by age")
+                .on_hover_text("Darken points the longer ago they were logged");
+            ui.re_checkbox(&mut self.transform_at_log_time, "Transform at log time")
+                .on_hover_text(
+                    "Place points with the transforms of their entity at the time they were logged, \
+                    so that e.g. lidar scans of a moving sensor line up in the current frame",
+                );
+        });
+        ui.end_row();
+    }
+}
+
+/// The brightness of points that are `age` older than the newest accumulated points,
+/// if the oldest ones are `max_age` older.
+pub fn fade_by_age(age: i64, max_age: i64) -> f32 {
+    if max_age <= 0 {
+        return 1.0;
+    }
+
+    let t = age.clamp(0, max_age) as f32 / max_age as f32;
+    egui::lerp(1.0..=OLDEST_BRIGHTNESS, t)
+}
+
+/// Darkens `color` by `brightness`, keeping its alpha.
+pub fn darken(color: egui::Color32, brightness: f32) -> egui::Color32 {
+    let [r, g, b, a] = color.to_array();
+    let darken = |channel: u8| (f32::from(channel) * brightness).round() as u8;
+    egui::Color32::from_rgba_premultiplied(darken(r), darken(g), darken(b), a)
+}
+
+/// For each time in `times`, the transform from `entity_path` at that time to `entity_path` at `now`.
+///
+/// Only tree transforms are taken into account: the entity and its ancestors are assumed
+/// to have moved with their transforms, relative to the root of the entity tree.
+pub fn entity_now_from_entity_at_times(
+    recording: &re_entity_db::EntityDb,
+    timeline: TimelineName,
+    now: TimeInt,
+    entity_path: &EntityPath,
+    times: &[TimeInt],
+) -> HashMap<TimeInt, glam::Affine3A> {
+    re_tracing::profile_function!();
+
+    TransformCacheStoreSubscriber::access(recording.store_id(), |cache| {
+        let transforms = cache.transforms_for_timeline(timeline);
+        let entity_now_from_root = transforms
+            .root_from_entity_tree_transforms(entity_path, &LatestAtQuery::new(timeline, now))
+            .inverse();
+
+        times
+            .iter()
+            .map(|&time| {
+                let root_from_entity_at_time = transforms.root_from_entity_tree_transforms(
+                    entity_path,
+                    &LatestAtQuery::new(timeline, time),
+                );
+                (time, entity_now_from_root * root_from_entity_at_time)
+            })
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fading() {
+        assert_eq!(1.0, fade_by_age(0, 10));
+        assert_eq!(OLDEST_BRIGHTNESS, fade_by_age(10, 10));
+        assert_eq!(OLDEST_BRIGHTNESS, fade_by_age(20, 10));
+        assert!((fade_by_age(5, 10) - 0.6).abs() < 1e-6);
+        assert_eq!(1.0, fade_by_age(5, 0));
+    }
+}
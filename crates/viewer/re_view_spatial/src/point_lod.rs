@@ -0,0 +1,337 @@
+//! Level of detail for large point clouds.
+//!
+//! The points of a large batch are ordered once, coarsest first: level `l` of the order contains
+//! at most one point per cell of a `2^l` grid over the bounding box of the batch.
+//! Drawing all levels whose cells are larger than a point on screen then looks the same as drawing
+//! every point, while only drawing a fraction of them for far away or small clouds.
+
+use std::sync::Arc;
+
+use ahash::{HashMap, HashSet};
+use itertools::Either;
+
+use re_chunk_store::{ChunkStoreEvent, RowId};
+use re_types::archetypes::Points3D;
+use re_viewer_context::Cache;
+
+/// Batches with fewer points than this are always drawn completely.
+pub const MIN_POINTS_FOR_LOD: usize = 100_000;
+
+/// Maximum number of points drawn per batch, however close the camera is.
+pub const MAX_POINTS_PER_BATCH: usize = 4_000_000;
+
+/// Number of levels of the grid hierarchy.
+///
+/// Points that only differ past the last level, i.e. that are closer than `1/2^21` of the batch size,
+/// go to an extra level which is only drawn if everything else is.
+const NUM_GRID_LEVELS: usize = 21;
+
+/// The point order of a batch, see the module documentation.
+pub struct PointLod {
+    /// Indices of the points, coarsest level first.
+    order: Vec<u32>,
+
+    /// Number of points up to and including each level.
+    points_up_to_level: Vec<usize>,
+
+    /// Bounding box of the points, in object space.
+    pub bounding_box: macaw::BoundingBox,
+}
+
+impl PointLod {
+    pub fn new(positions: &[glam::Vec3]) -> Self {
+        re_tracing::profile_function!(positions.len().to_string());
+
+        let bounding_box = macaw::BoundingBox::from_points(positions.iter().copied());
+
+        // Use cubic cells, so that their size on screen doesn't depend on their orientation.
+        let extent = bounding_box.size().max_element();
+        let scale = if extent > 0.0 && extent.is_finite() {
+            ((1 << NUM_GRID_LEVELS) - 1) as f32 / extent
+        } else {
+            0.0
+        };
+
+        // Points in the same cell of any level are next to each other when sorted by Morton code.
+        let mut codes: Vec<(u64, u32)> = positions
+            .iter()
+            .enumerate()
+            .map(|(index, &position)| {
+                let cell = ((position - bounding_box.min) * scale).as_uvec3();
+                (morton_code(cell), index as u32)
+            })
+            .collect();
+        codes.sort_unstable();
+
+        // The first point of each cell gets that cell's level.
+        let levels: Vec<usize> = codes
+            .iter()
+            .enumerate()
+            .map(
+                |(i, &(code, _))| match i.checked_sub(1).map(|i| codes[i].0) {
+                    None => 0,
+                    Some(previous) => first_differing_level(previous, code),
+                },
+            )
+            .collect();
+
+        let mut points_up_to_level = vec![0; NUM_GRID_LEVELS + 2];
+        for &level in &levels {
+            points_up_to_level[level] += 1;
+        }
+        let mut next_in_level = Vec::with_capacity(points_up_to_level.len());
+        let mut total = 0;
+        for count in &mut points_up_to_level {
+            next_in_level.push(total);
+            total += *count;
+            *count = total;
+        }
+
+        let mut order = vec![0; codes.len()];
+        for (&(_, index), &level) in codes.iter().zip(&levels) {
+            order[next_in_level[level]] = index;
+            next_in_level[level] += 1;
+        }
+
+        Self {
+            order,
+            points_up_to_level,
+            bounding_box,
+        }
+    }
+
+    /// Number of points needed for the batch to look complete, if it is `screen_size` ui points large.
+    pub fn num_points_for_screen_size(&self, screen_size: f32) -> usize {
+        // Cells of level `l` are `screen_size / 2^l` large: stop at the first level smaller than a point.
+        let level = screen_size.max(1.0).log2().ceil() as usize;
+        self.points_up_to_level[level.min(self.points_up_to_level.len() - 1)]
+    }
+
+    /// The indices of `num_points` points evenly spread over the batch, in ascending order.
+    pub fn indices(&self, num_points: usize) -> Vec<u32> {
+        re_tracing::profile_function!(num_points.to_string());
+
+        let num_points = num_points.min(self.order.len());
+
+        // Complete levels first, then evenly spaced points of the next level to fill up the rest.
+        let num_complete = self
+            .points_up_to_level
+            .iter()
+            .copied()
+            .take_while(|&count| count <= num_points)
+            .last()
+            .unwrap_or(0);
+        let next_level = &self.order[num_complete..self.points_up_to_level_after(num_complete)];
+        let num_remaining = num_points - num_complete;
+
+        let mut indices: Vec<u32> = self.order[..num_complete].to_vec();
+        if num_remaining > 0 {
+            let step = next_level.len() as f64 / num_remaining as f64;
+            indices.extend((0..num_remaining).map(|i| next_level[(i as f64 * step) as usize]));
+        }
+        indices.sort_unstable();
+        indices
+    }
+
+    /// The number of points up to the end of the level following the one ending at `count`.
+    fn points_up_to_level_after(&self, count: usize) -> usize {
+        self.points_up_to_level
+            .iter()
+            .copied()
+            .find(|&up_to_level| up_to_level > count)
+            .unwrap_or(self.order.len())
+    }
+}
+
+impl re_byte_size::SizeBytes for PointLod {
+    fn heap_size_bytes(&self) -> u64 {
+        let Self {
+            order,
+            points_up_to_level,
+            bounding_box: _,
+        } = self;
+        order.heap_size_bytes() + points_up_to_level.heap_size_bytes()
+    }
+}
+
+/// The level of the first cell that differs between two Morton codes.
+fn first_differing_level(a: u64, b: u64) -> usize {
+    let difference = a ^ b;
+    if difference == 0 {
+        return NUM_GRID_LEVELS + 1;
+    }
+
+    // The codes have 63 bits, the top bit is always zero.
+    let equal_bits = difference.leading_zeros() as usize - 1;
+    equal_bits / 3 + 1
+}
+
+/// Interleaves the lowest 21 bits of each coordinate.
+fn morton_code(cell: glam::UVec3) -> u64 {
+    fn spread_bits(value: u32) -> u64 {
+        let mut x = u64::from(value) & 0x1f_ffff;
+        x = (x | (x << 32)) & 0x001f_0000_0000_ffff;
+        x = (x | (x << 16)) & 0x001f_0000_ff00_00ff;
+        x = (x | (x << 8)) & 0x100f_00f0_0f00_f00f;
+        x = (x | (x << 4)) & 0x10c3_0c30_c30c_30c3;
+        x = (x | (x << 2)) & 0x1249_2492_4924_9249;
+        x
+    }
+
+    spread_bits(cell.x) | (spread_bits(cell.y) << 1) | (spread_bits(cell.z) << 2)
+}
+
+/// The projection of a 3D view in the last frame, used to cull point clouds and pick their level of detail.
+#[derive(Clone, Copy, Debug)]
+pub struct LodProjection {
+    pub ui_from_world: glam::Mat4,
+
+    /// The rectangle of the view, in ui points.
+    pub viewport: egui::Rect,
+}
+
+impl LodProjection {
+    /// How large `bbox` is on screen, in ui points, or `None` if it's outside of the view.
+    pub fn screen_size(&self, bbox: &macaw::BoundingBox) -> Option<f32> {
+        let corners = bbox
+            .corners()
+            .map(|corner| self.ui_from_world * corner.extend(1.0));
+
+        // A box is outside of the view if all of its corners are outside of the same plane of the frustum.
+        let viewport = self.viewport;
+        let outside_of = |is_outside: fn(glam::Vec4, egui::Rect) -> bool| {
+            corners.iter().all(|&corner| is_outside(corner, viewport))
+        };
+        if outside_of(|c, _| c.w <= 0.0)
+            || outside_of(|c, r| c.x < r.left() * c.w)
+            || outside_of(|c, r| c.x > r.right() * c.w)
+            || outside_of(|c, r| c.y < r.top() * c.w)
+            || outside_of(|c, r| c.y > r.bottom() * c.w)
+        {
+            return None;
+        }
+
+        if corners.iter().any(|corner| corner.w <= 0.0) {
+            // Partially behind the camera, so it can't be projected: assume it's as close as can be.
+            return Some(f32::INFINITY);
+        }
+
+        let projected = corners.map(|corner| egui::pos2(corner.x / corner.w, corner.y / corner.w));
+        let rect = egui::Rect::from_points(&projected);
+        Some(rect.width().max(rect.height()))
+    }
+}
+
+/// Caches the [`PointLod`] of large batches of points.
+#[derive(Default)]
+pub struct PointLodCache(HashMap<RowId, Arc<PointLod>>);
+
+impl PointLodCache {
+    pub fn entry(&mut self, row_id: RowId, positions: &[glam::Vec3]) -> Arc<PointLod> {
+        self.0
+            .entry(row_id)
+            .or_insert_with(|| Arc::new(PointLod::new(positions)))
+            .clone()
+    }
+}
+
+impl Cache for PointLodCache {
+    fn purge_memory(&mut self) {
+        self.0.clear();
+    }
+
+    fn bytes_used(&self) -> u64 {
+        use re_byte_size::SizeBytes as _;
+
+        self.0
+            .values()
+            .map(|lod| std::mem::size_of::<RowId>() as u64 + lod.total_size_bytes())
+            .sum()
+    }
+
+    fn on_store_events(&mut self, events: &[&ChunkStoreEvent]) {
+        re_tracing::profile_function!();
+
+        let row_ids_removed: HashSet<RowId> = events
+            .iter()
+            .flat_map(|event| {
+                let is_deletion = || event.kind == re_chunk_store::ChunkStoreDiffKind::Deletion;
+                let contains_positions = || {
+                    event
+                        .chunk
+                        .components()
+                        .contains_component(&Points3D::descriptor_positions())
+                };
+
+                if is_deletion() && contains_positions() {
+                    Either::Left(event.chunk.row_ids())
+                } else {
+                    Either::Right(std::iter::empty())
+                }
+            })
+            .collect();
+
+        self.0.retain(|row_id, _| !row_ids_removed.contains(row_id));
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_are_coarsest_first() {
+        // A 16x16x16 grid of points, with every point logged twice.
+        let positions: Vec<_> = (0..2)
+            .flat_map(|_| {
+                itertools::iproduct!(0..16, 0..16, 0..16)
+                    .map(|(x, y, z)| glam::vec3(x as f32, y as f32, z as f32))
+            })
+            .collect();
+        let lod = PointLod::new(&positions);
+        assert_eq!(positions.len(), lod.order.len());
+
+        // One point per cell of each level, until the cells are smaller than the grid spacing.
+        assert_eq!(&[1_usize, 8, 64, 512, 4096], &lod.points_up_to_level[..5]);
+        assert_eq!(4096, lod.points_up_to_level[NUM_GRID_LEVELS]);
+        assert_eq!(8192, lod.points_up_to_level[NUM_GRID_LEVELS + 1]);
+
+        assert_eq!(1, lod.num_points_for_screen_size(0.5));
+        assert_eq!(64, lod.num_points_for_screen_size(3.0));
+        assert_eq!(8192, lod.num_points_for_screen_size(f32::INFINITY));
+
+        // The points of a level are spread over the whole grid.
+        let indices = lod.indices(64);
+        assert!(indices.is_sorted());
+        let cells: HashSet<_> = indices
+            .iter()
+            .map(|&i| (positions[i as usize] / 4.0).floor().as_ivec3().to_array())
+            .collect();
+        assert_eq!(64, cells.len());
+
+        let indices = lod.indices(100);
+        assert_eq!(100, indices.len());
+        assert_eq!(100, indices.iter().collect::<HashSet<_>>().len());
+    }
+
+    #[test]
+    fn projection_culls_and_sizes() {
+        let viewport = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(100.0, 100.0));
+        let projection = LodProjection {
+            ui_from_world: glam::Mat4::IDENTITY,
+            viewport,
+        };
+
+        let inside =
+            macaw::BoundingBox::from_min_max(glam::vec3(10.0, 20.0, 0.0), glam::Vec3::splat(50.0));
+        assert_eq!(Some(40.0), projection.screen_size(&inside));
+
+        let outside =
+            macaw::BoundingBox::from_min_max(glam::vec3(200.0, 0.0, 0.0), glam::Vec3::splat(300.0));
+        assert_eq!(None, projection.screen_size(&outside));
+    }
+}
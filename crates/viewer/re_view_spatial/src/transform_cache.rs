@@ -32,6 +32,8 @@ use vec1::smallvec_v1::SmallVec1;
 ///   Instance poses that should be applied to the tree transforms (via [`crate::contexts::TransformTreeContext`]) but not propagate.
 /// * [`components::PinholeProjection`] and [`components::ViewCoordinates`]
 ///   Pinhole projections & associated view coordinates used for visualizing cameras in 3D and embedding 2D in 3D
+/// * [`archetypes::CameraRig`]
+///   Poses of the cameras in a rig, which are tree transforms of the rig's child entities rather than of the rig entity itself.
 ///
 /// Most of what this construct does internally is to keep track at which points in time these sets of components
 /// change such that a latest-at query for them may (!) yield any new results.
@@ -241,6 +243,32 @@ impl CachedTransformsForTimeline {
     pub fn entity_transforms(&self, entity_path: &EntityPath) -> Option<&TransformsForEntity> {
         self.per_entity.get(entity_path)
     }
+
+    /// All entities with any kind of transform on this timeline, in no particular order.
+    #[inline]
+    pub fn entities_with_transforms(
+        &self,
+    ) -> impl Iterator<Item = (&EntityPath, &TransformsForEntity)> {
+        self.per_entity.iter()
+    }
+
+    /// The transform from `entity_path` to the root of the entity tree, composed of the tree
+    /// transforms of the entity and all its ancestors.
+    pub fn root_from_entity_tree_transforms(
+        &self,
+        entity_path: &EntityPath,
+        query: &LatestAtQuery,
+    ) -> Affine3A {
+        let mut root_from_entity = Affine3A::IDENTITY;
+        let mut current = Some(entity_path.clone());
+        while let Some(path) = current {
+            if let Some(transforms) = self.entity_transforms(&path) {
+                root_from_entity = transforms.latest_at_tree_transform(query) * root_from_entity;
+            }
+            current = path.parent();
+        }
+        root_from_entity
+    }
 }
 
 impl TransformsForEntity {
@@ -321,6 +349,29 @@ impl TransformsForEntity {
             .extend(times.iter().map(|time| (*time, None)));
     }
 
+    /// Whether any latest-at query for a time in `(min, max]` could yield a different transform
+    /// than a query at `min`.
+    ///
+    /// Can be used to find out whether moving from one time to another changes this entity's transforms.
+    pub fn has_changes_in(&self, min: TimeInt, max: TimeInt) -> bool {
+        use std::ops::Bound;
+
+        if max <= min {
+            return false;
+        }
+        let range = (Bound::Excluded(min), Bound::Included(max));
+
+        self.tree_transforms.range(range).next().is_some()
+            || self
+                .pose_transforms
+                .as_ref()
+                .is_some_and(|poses| poses.range(range).next().is_some())
+            || self
+                .pinhole_projections
+                .as_ref()
+                .is_some_and(|pinholes| pinholes.range(range).next().is_some())
+    }
+
     #[inline]
     pub fn latest_at_tree_transform(&self, query: &LatestAtQuery) -> Affine3A {
         #[cfg(debug_assertions)] // `self.timeline` is only present with `debug_assertions` enabled.
@@ -333,6 +384,23 @@ impl TransformsForEntity {
             .unwrap_or(Affine3A::IDENTITY)
     }
 
+    /// Like [`Self::latest_at_tree_transform`], but also returns when the transform was logged.
+    ///
+    /// Returns `None` if there's no tree transform at or before the query time.
+    #[inline]
+    pub fn latest_at_tree_transform_with_time(
+        &self,
+        query: &LatestAtQuery,
+    ) -> Option<(TimeInt, Affine3A)> {
+        #[cfg(debug_assertions)] // `self.timeline` is only present with `debug_assertions` enabled.
+        debug_assert!(Some(query.timeline()) == self.timeline || self.timeline.is_none());
+
+        self.tree_transforms
+            .range(..query.at().inc())
+            .next_back()
+            .map(|(time, transform)| (*time, *transform))
+    }
+
     #[cfg(test)]
     #[inline]
     pub fn latest_at_instance_poses(
@@ -538,6 +606,7 @@ impl TransformCacheStoreSubscriber {
     fn add_temporal_chunk(
         &mut self,
         event: &re_chunk_store::ChunkStoreEvent,
+        entity_path: &EntityPath,
         aspects: TransformAspect,
     ) {
         re_tracing::profile_function!();
@@ -545,8 +614,6 @@ impl TransformCacheStoreSubscriber {
         let chunk = &event.diff.chunk;
         debug_assert!(!chunk.is_static());
 
-        let entity_path = chunk.entity_path();
-
         for (timeline, time_column) in chunk.timelines() {
             let per_timeline = self.per_timeline.entry(*timeline).or_insert_with(|| {
                 CachedTransformsForTimeline::new(timeline, &self.static_timeline)
@@ -629,14 +696,13 @@ impl TransformCacheStoreSubscriber {
     fn add_static_chunk(
         &mut self,
         event: &re_chunk_store::ChunkStoreEvent,
+        entity_path: &EntityPath,
         aspects: TransformAspect,
     ) {
         re_tracing::profile_function!();
 
         debug_assert!(event.diff.chunk.is_static());
 
-        let entity_path = event.chunk.entity_path();
-
         self.static_timeline
             .invalidated_transforms
             .push(InvalidatedTransforms {
@@ -704,11 +770,14 @@ impl TransformCacheStoreSubscriber {
         }
     }
 
-    fn remove_chunk(&mut self, event: &re_chunk_store::ChunkStoreEvent, aspects: TransformAspect) {
+    fn remove_chunk(
+        &mut self,
+        event: &re_chunk_store::ChunkStoreEvent,
+        entity_path: &EntityPath,
+        aspects: TransformAspect,
+    ) {
         re_tracing::profile_function!();
 
-        let entity_path = event.chunk.entity_path();
-
         // Note that we ignore static timelines for removal.
         for (timeline, time_column) in event.diff.chunk.timelines() {
             let Some(per_timeline) = self.per_timeline.get_mut(timeline) else {
@@ -801,27 +870,118 @@ impl PerStoreChunkSubscriber for TransformCacheStoreSubscriber {
                     aspects |= TransformAspect::Clear;
                 }
             }
-            if aspects.is_empty() {
-                continue;
-            }
 
-            if event.kind == re_chunk_store::ChunkStoreDiffKind::Deletion {
-                self.remove_chunk(event, aspects);
-            } else if event.diff.chunk.is_static() {
-                self.add_static_chunk(event, aspects);
-            } else {
-                self.add_temporal_chunk(event, aspects);
+            // A camera rig changes the tree transforms of its cameras, not of the rig entity itself.
+            let affected_entities = rig_camera_entities(&event.chunk)
+                .into_iter()
+                .map(|camera| (camera, TransformAspect::Tree))
+                .chain((!aspects.is_empty()).then(|| (event.chunk.entity_path().clone(), aspects)));
+
+            for (entity_path, aspects) in affected_entities {
+                if event.kind == re_chunk_store::ChunkStoreDiffKind::Deletion {
+                    self.remove_chunk(event, &entity_path, aspects);
+                } else if event.diff.chunk.is_static() {
+                    self.add_static_chunk(event, &entity_path, aspects);
+                } else {
+                    self.add_temporal_chunk(event, &entity_path, aspects);
+                }
             }
         }
     }
 }
 
-/// Queries all components that are part of pose transforms, returning the transform from child to parent.
+/// Returns the camera entities of all [`archetypes::CameraRig`]s in the given chunk.
+fn rig_camera_entities(chunk: &re_chunk_store::Chunk) -> IntSet<EntityPath> {
+    let descr = archetypes::CameraRig::descriptor_cameras();
+    if !chunk.components().contains_component(&descr) {
+        return Default::default();
+    }
+
+    let rig_path = chunk.entity_path();
+    chunk
+        .iter_slices::<String>(descr)
+        .flat_map(|cameras| {
+            cameras
+                .iter()
+                .map(|camera| rig_path.join(&EntityPath::from(camera.as_str())))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Queries all components that are part of tree transforms, returning the transform from child to parent.
+///
+/// This includes the pose of the entity in an [`archetypes::CameraRig`] at its parent, if any,
+/// which is applied on top of the entity's own [`archetypes::Transform3D`].
+fn query_and_resolve_tree_transform_at_entity(
+    entity_path: &EntityPath,
+    entity_db: &EntityDb,
+    query: &LatestAtQuery,
+) -> Option<Affine3A> {
+    let transform = query_and_resolve_transform3d_at_entity(entity_path, entity_db, query);
+
+    match query_and_resolve_rig_from_camera_at_entity(entity_path, entity_db, query) {
+        Some(rig_from_camera) => Some(rig_from_camera * transform.unwrap_or(Affine3A::IDENTITY)),
+        None => transform,
+    }
+}
+
+/// Queries the [`archetypes::CameraRig`] at the parent of the given entity,
+/// returning the pose of the entity in the rig if it is one of the rig's cameras.
+///
+/// If the pose is invalid, returns a `glam::Affine3A::ZERO`.
+fn query_and_resolve_rig_from_camera_at_entity(
+    entity_path: &EntityPath,
+    entity_db: &EntityDb,
+    query: &LatestAtQuery,
+) -> Option<Affine3A> {
+    let rig_path = entity_path.parent()?;
+    let results = entity_db.latest_at(
+        query,
+        &rig_path,
+        archetypes::CameraRig::all_components().iter(),
+    );
+    if results.components.is_empty() {
+        return None;
+    }
+
+    let cameras = results
+        .component_batch::<components::EntityPath>(&archetypes::CameraRig::descriptor_cameras())?;
+    let camera_index = cameras
+        .iter()
+        .position(|camera| rig_path.join(&EntityPath::from(camera.as_str())) == *entity_path)?;
+
+    let mut rig_from_camera = Affine3A::IDENTITY;
+    if let Some(translation) = results
+        .component_batch::<components::PoseTranslation3D>(
+            &archetypes::CameraRig::descriptor_translations(),
+        )
+        .and_then(|translations| translations.get(camera_index).copied())
+    {
+        rig_from_camera = Affine3A::from(translation);
+    }
+    if let Some(quaternion) = results
+        .component_batch::<components::PoseRotationQuat>(
+            &archetypes::CameraRig::descriptor_quaternions(),
+        )
+        .and_then(|quaternions| quaternions.get(camera_index).copied())
+    {
+        if let Ok(quaternion) = Affine3A::try_from(quaternion) {
+            rig_from_camera *= quaternion;
+        } else {
+            return Some(Affine3A::ZERO);
+        }
+    }
+
+    Some(rig_from_camera)
+}
+
+/// Queries all components of [`archetypes::Transform3D`], returning the transform from child to parent.
 ///
 /// If any of the components yields an invalid transform, returns a `glam::Affine3A::ZERO`.
 /// (this effectively disconnects a subtree from the transform hierarchy!)
 // TODO(#3849): There's no way to discover invalid transforms right now (they can be intentional but often aren't).
-fn query_and_resolve_tree_transform_at_entity(
+fn query_and_resolve_transform3d_at_entity(
     entity_path: &EntityPath,
     entity_db: &EntityDb,
     query: &LatestAtQuery,
@@ -870,6 +1030,25 @@ fn query_and_resolve_tree_transform_at_entity(
             return Some(Affine3A::ZERO);
         }
     }
+    if let Some(rotation_euler) = results
+        .component_mono_with_log_level::<components::RotationEuler>(
+            &archetypes::Transform3D::descriptor_rotation_euler(),
+            mono_log_level,
+        )
+    {
+        let convention = results
+            .component_mono_with_log_level::<components::EulerConvention>(
+                &archetypes::Transform3D::descriptor_euler_convention(),
+                mono_log_level,
+            )
+            .unwrap_or_default();
+        let quaternion = rotation_euler.to_quat(convention);
+        if quaternion.is_finite() {
+            transform *= Affine3A::from_quat(quaternion);
+        } else {
+            return Some(Affine3A::ZERO);
+        }
+    }
     if let Some(scale) = results.component_mono_with_log_level::<components::Scale3D>(
         &archetypes::Transform3D::descriptor_scale(),
         mono_log_level,
@@ -1782,6 +1961,79 @@ mod tests {
                 transforms.latest_at_tree_transform(&LatestAtQuery::new(timeline_name, 123)),
                 glam::Affine3A::IDENTITY
             );
+
+            // Only moving past a logged transform changes anything.
+            assert!(transforms.has_changes_in(TimeInt::new_temporal(0), TimeInt::new_temporal(1)));
+            assert!(!transforms.has_changes_in(TimeInt::new_temporal(1), TimeInt::new_temporal(2)));
+            assert!(transforms.has_changes_in(TimeInt::new_temporal(2), TimeInt::new_temporal(5)));
+            assert!(
+                !transforms.has_changes_in(TimeInt::new_temporal(5), TimeInt::new_temporal(123))
+            );
+        });
+    }
+
+    #[test]
+    fn test_camera_rig_transforms() {
+        let mut entity_db = new_entity_db_with_subscriber_registered();
+
+        // A static stereo rig, with one of the cameras additionally being moved over time.
+        let timeline = Timeline::new_sequence("t");
+        let rig_chunk = Chunk::builder(EntityPath::from("rig"))
+            .with_archetype(
+                RowId::new(),
+                TimePoint::default(),
+                &archetypes::CameraRig::new(["left", "right"])
+                    .with_translations([[-0.1, 0.0, 0.0], [0.1, 0.0, 0.0]])
+                    .with_quaternions([glam::Quat::IDENTITY, glam::Quat::from_rotation_y(0.5)]),
+            )
+            .build()
+            .unwrap();
+        let camera_chunk = Chunk::builder(EntityPath::from("rig/right"))
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 1)],
+                &archetypes::Transform3D::from_translation([0.0, 0.0, 1.0]),
+            )
+            .build()
+            .unwrap();
+        entity_db.add_chunk(&Arc::new(rig_chunk)).unwrap();
+        entity_db.add_chunk(&Arc::new(camera_chunk)).unwrap();
+
+        TransformCacheStoreSubscriber::access_mut(entity_db.store_id(), |cache| {
+            let timeline_name = *timeline.name();
+            cache.apply_all_updates(&entity_db);
+            let transforms_per_timeline = cache.transforms_for_timeline(timeline_name);
+
+            // The rig itself isn't moved.
+            assert!(
+                transforms_per_timeline
+                    .entity_transforms(&EntityPath::from("rig"))
+                    .is_none_or(|transforms| transforms.tree_transforms.is_empty())
+            );
+
+            let left = transforms_per_timeline
+                .entity_transforms(&EntityPath::from("rig/left"))
+                .unwrap();
+            assert_eq!(
+                left.latest_at_tree_transform(&LatestAtQuery::new(timeline_name, 1)),
+                glam::Affine3A::from_translation(glam::vec3(-0.1, 0.0, 0.0))
+            );
+
+            let rig_from_right = glam::Affine3A::from_rotation_translation(
+                glam::Quat::from_rotation_y(0.5),
+                glam::vec3(0.1, 0.0, 0.0),
+            );
+            let right = transforms_per_timeline
+                .entity_transforms(&EntityPath::from("rig/right"))
+                .unwrap();
+            assert_eq!(
+                right.latest_at_tree_transform(&LatestAtQuery::new(timeline_name, 0)),
+                rig_from_right
+            );
+            assert_eq!(
+                right.latest_at_tree_transform(&LatestAtQuery::new(timeline_name, 1)),
+                rig_from_right * glam::Affine3A::from_translation(glam::vec3(0.0, 0.0, 1.0))
+            );
         });
     }
 
@@ -0,0 +1,335 @@
+use egui::{Align2, NumExt as _, Sense, pos2, vec2};
+use glam::{Affine3A, EulerRot};
+
+use re_chunk_store::LatestAtQuery;
+use re_log_types::{EntityPath, TimeInt, TimeType};
+use re_viewer_context::{Item, ViewerContext};
+
+use crate::transform_cache::{CachedTransformsForTimeline, TransformCacheStoreSubscriber};
+
+const ROW_HEIGHT: f32 = 36.0;
+const INDENT: f32 = 20.0;
+const NODE_RADIUS: f32 = 4.0;
+
+/// A frame of the transform hierarchy, i.e. the root or an entity with a transform.
+struct Frame {
+    entity_path: EntityPath,
+
+    /// Index of the parent frame, i.e. the closest ancestor that is a frame.
+    parent: Option<usize>,
+
+    depth: usize,
+
+    /// The latest transform from this frame to its parent frame, and when it was logged.
+    parent_from_frame: Option<(TimeInt, Affine3A)>,
+
+    has_pinhole: bool,
+}
+
+/// Shows the transform hierarchy of the active recording as a graph.
+///
+/// Every edge is labeled with the transform at the current time, and highlighted
+/// if that transform is older than a threshold, e.g. because a publisher stopped sending it.
+#[derive(Clone, Debug)]
+pub struct TransformTreePanel {
+    /// Transforms older than this are stale on timelines measuring time.
+    stale_after_secs: f64,
+
+    /// Transforms older than this are stale on sequence timelines.
+    stale_after_steps: i64,
+}
+
+impl Default for TransformTreePanel {
+    fn default() -> Self {
+        Self {
+            stale_after_secs: 1.0,
+            stale_after_steps: 10,
+        }
+    }
+}
+
+impl TransformTreePanel {
+    pub fn ui(&mut self, ctx: &ViewerContext<'_>, ui: &mut egui::Ui) {
+        re_tracing::profile_function!();
+
+        let recording = ctx.recording();
+        let query = ctx.current_query();
+        let time_type = ctx.rec_cfg.time_ctrl.read().time_type();
+
+        let frames = TransformCacheStoreSubscriber::access_mut(recording.store_id(), |cache| {
+            // Usually done by the spatial views, but there may be none.
+            cache.apply_all_updates(recording);
+            collect_frames(cache.transforms_for_timeline(query.timeline()), &query)
+        })
+        .unwrap_or_default();
+
+        ui.horizontal(|ui| {
+            ui.label("Stale after");
+            match time_type {
+                TimeType::Sequence => ui.add(
+                    egui::DragValue::new(&mut self.stale_after_steps)
+                        .range(0..=i64::MAX)
+                        .suffix(" steps"),
+                ),
+                TimeType::DurationNs | TimeType::TimestampNs => ui.add(
+                    egui::DragValue::new(&mut self.stale_after_secs)
+                        .range(0.0..=f64::MAX)
+                        .speed(0.01)
+                        .suffix(" s"),
+                ),
+            }
+            .on_hover_text("Transforms that weren't updated for longer than this are highlighted");
+        });
+
+        if frames.len() <= 1 {
+            ui.weak("No transforms at the current time");
+            return;
+        }
+
+        egui::ScrollArea::both()
+            .auto_shrink([false; 2])
+            .show(ui, |ui| {
+                self.graph_ui(ctx, ui, &frames, &query, time_type);
+            });
+    }
+
+    fn graph_ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        frames: &[Frame],
+        query: &LatestAtQuery,
+        time_type: TimeType,
+    ) {
+        let font_id = egui::TextStyle::Body.resolve(ui.style());
+        let max_depth = frames
+            .iter()
+            .map(|frame| frame.depth)
+            .max()
+            .unwrap_or_default();
+        let (rect, _) = ui.allocate_exact_size(
+            vec2(
+                (max_depth as f32 * INDENT + 300.0).at_least(ui.available_width()),
+                frames.len() as f32 * ROW_HEIGHT,
+            ),
+            Sense::hover(),
+        );
+        let painter = ui.painter_at(rect);
+
+        let node_pos = |row: usize| {
+            pos2(
+                rect.left() + NODE_RADIUS + frames[row].depth as f32 * INDENT,
+                rect.top() + (row as f32 + 0.3) * ROW_HEIGHT,
+            )
+        };
+
+        for (row, frame) in frames.iter().enumerate() {
+            let pos = node_pos(row);
+
+            let (edge_label, is_stale) = match frame.parent_from_frame {
+                Some((time, transform)) => {
+                    let (age, is_stale) = self.age(time, query, time_type);
+                    (
+                        format!("{}  ·  {age}", format_transform(&transform)),
+                        is_stale,
+                    )
+                }
+                None => (String::new(), false),
+            };
+            let edge_color = if is_stale {
+                ui.visuals().warn_fg_color
+            } else if frame
+                .parent_from_frame
+                .is_some_and(|(time, _)| !time.is_static())
+            {
+                ui.visuals().text_color()
+            } else {
+                ui.visuals().weak_text_color()
+            };
+
+            if let Some(parent) = frame.parent {
+                let parent_pos = node_pos(parent);
+                let stroke = egui::Stroke::new(1.0, edge_color);
+                painter.line_segment(
+                    [
+                        pos2(parent_pos.x, parent_pos.y + NODE_RADIUS),
+                        pos2(parent_pos.x, pos.y),
+                    ],
+                    stroke,
+                );
+                painter.line_segment(
+                    [pos2(parent_pos.x, pos.y), pos2(pos.x - NODE_RADIUS, pos.y)],
+                    stroke,
+                );
+            }
+            painter.circle_filled(pos, NODE_RADIUS, edge_color);
+
+            let name = match frame.parent {
+                Some(parent) => frame
+                    .entity_path
+                    .strip_prefix(&frames[parent].entity_path)
+                    .unwrap_or_else(|| frame.entity_path.clone())
+                    .to_string()
+                    .trim_start_matches('/')
+                    .to_owned(),
+                None => frame.entity_path.to_string(),
+            };
+            let name = if frame.has_pinhole {
+                format!("{name} (pinhole)")
+            } else {
+                name
+            };
+            let name_rect = painter.text(
+                pos2(pos.x + 2.0 * NODE_RADIUS, pos.y),
+                Align2::LEFT_CENTER,
+                name,
+                font_id.clone(),
+                ui.visuals().strong_text_color(),
+            );
+            painter.text(
+                pos2(name_rect.left(), pos.y + 0.45 * ROW_HEIGHT),
+                Align2::LEFT_CENTER,
+                edge_label,
+                font_id.clone(),
+                edge_color,
+            );
+
+            let item = Item::from(frame.entity_path.clone());
+            let response = ui
+                .interact(
+                    name_rect.expand(2.0),
+                    ui.id().with(("transform_tree_frame", row)),
+                    Sense::click(),
+                )
+                .on_hover_ui(|ui| {
+                    ui.label(frame.entity_path.to_string());
+                    if let Some((time, transform)) = frame.parent_from_frame {
+                        ui.label(format!("Parent from frame:\n{transform}"));
+                        if !time.is_static() {
+                            ui.label(format!(
+                                "Logged at {}",
+                                time_type.format(time, ctx.app_options().timestamp_format)
+                            ));
+                        }
+                    }
+                });
+            ctx.handle_select_hover_drag_interactions(&response, item, false);
+        }
+    }
+
+    /// Describes how old a transform logged at `time` is, and whether that's stale.
+    fn age(&self, time: TimeInt, query: &LatestAtQuery, time_type: TimeType) -> (String, bool) {
+        if time.is_static() {
+            return ("static".to_owned(), false);
+        }
+
+        let age = query.at().as_i64().saturating_sub(time.as_i64());
+        match time_type {
+            TimeType::Sequence => (
+                format!("{} steps old", re_format::format_int(age)),
+                age > self.stale_after_steps,
+            ),
+            TimeType::DurationNs | TimeType::TimestampNs => {
+                let age_secs = age as f64 * 1e-9;
+                (
+                    format!("{age_secs:.3} s old"),
+                    age_secs > self.stale_after_secs,
+                )
+            }
+        }
+    }
+}
+
+/// The frames with a transform at the time of `query`, depth-first, starting with the root.
+fn collect_frames(transforms: &CachedTransformsForTimeline, query: &LatestAtQuery) -> Vec<Frame> {
+    let mut entities = transforms
+        .entities_with_transforms()
+        .filter_map(|(entity_path, transforms)| {
+            let parent_from_frame = transforms.latest_at_tree_transform_with_time(query);
+            let has_pinhole = transforms.latest_at_pinhole(query).is_some();
+            (parent_from_frame.is_some() || has_pinhole)
+                .then(|| (entity_path.clone(), parent_from_frame, has_pinhole))
+        })
+        .collect::<Vec<_>>();
+
+    // Parents sort before their children, which makes this a depth-first order.
+    entities.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+
+    let mut frames = vec![Frame {
+        entity_path: EntityPath::root(),
+        parent: None,
+        depth: 0,
+        parent_from_frame: None,
+        has_pinhole: false,
+    }];
+
+    // Indices of the frames on the path from the root to the current one.
+    let mut ancestors = vec![0];
+
+    for (entity_path, parent_from_frame, has_pinhole) in entities {
+        if entity_path.is_root() {
+            frames[0].parent_from_frame = parent_from_frame;
+            frames[0].has_pinhole = has_pinhole;
+            continue;
+        }
+
+        while let Some(&ancestor) = ancestors.last()
+            && ancestor != 0
+            && !entity_path.is_descendant_of(&frames[ancestor].entity_path)
+        {
+            ancestors.pop();
+        }
+
+        let parent = ancestors.last().copied().unwrap_or_default();
+        ancestors.push(frames.len());
+        frames.push(Frame {
+            entity_path,
+            parent: Some(parent),
+            depth: frames[parent].depth + 1,
+            parent_from_frame,
+            has_pinhole,
+        });
+    }
+
+    frames
+}
+
+/// Translation, rotation as roll/pitch/yaw and, if not uniform 1, scale.
+fn format_transform(transform: &Affine3A) -> String {
+    let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+    let (yaw, pitch, roll) = rotation.to_euler(EulerRot::ZYX);
+
+    let mut text = format!(
+        "t [{:.3}, {:.3}, {:.3}]  rpy [{:.1}°, {:.1}°, {:.1}°]",
+        translation.x,
+        translation.y,
+        translation.z,
+        roll.to_degrees(),
+        pitch.to_degrees(),
+        yaw.to_degrees(),
+    );
+    if !scale.abs_diff_eq(glam::Vec3::ONE, 1e-6) {
+        text += &format!("  s [{:.3}, {:.3}, {:.3}]", scale.x, scale.y, scale.z);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transform_formatting() {
+        let transform = Affine3A::from_translation(glam::Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            "t [1.000, 2.000, 3.000]  rpy [0.0°, 0.0°, 0.0°]",
+            format_transform(&transform)
+        );
+
+        let transform = Affine3A::from_scale(glam::Vec3::splat(2.0));
+        assert_eq!(
+            "t [0.000, 0.000, 0.000]  rpy [0.0°, 0.0°, 0.0°]  s [2.000, 2.000, 2.000]",
+            format_transform(&transform)
+        );
+    }
+}
@@ -10,6 +10,8 @@ use re_viewer_context::{HoverHighlight, ImageInfo, SelectionHighlight, ViewHighl
 
 use crate::{
     Pinhole,
+    annotation::AnnotationState,
+    measurement::MeasurementState,
     pickable_textured_rect::PickableRectSourceData,
     picking::{PickableUiRect, PickingResult},
     scene_bounding_boxes::SceneBoundingBoxes,
@@ -61,6 +63,12 @@ pub struct SpatialViewState {
     pub pinhole_at_origin: Option<Pinhole>,
 
     pub visual_bounds_2d: Option<VisualBounds2D>,
+
+    /// The measurement in progress, if any.
+    pub measurement: MeasurementState,
+
+    /// The annotation in progress, if any.
+    pub annotation: AnnotationState,
 }
 
 impl ViewState for SpatialViewState {
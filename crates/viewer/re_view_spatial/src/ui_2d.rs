@@ -96,7 +96,8 @@ fn ui_from_scene(
 
     // Update blueprint if changed
     let updated_bounds: blueprint_components::VisualBounds2D = bounds_rect.into();
-    if response.double_clicked() {
+    // While annotating, double clicks finish polygons instead.
+    if response.double_clicked() && !view_state.annotation.is_active() {
         bounds_property
             .reset_blueprint_component(ctx.viewer_ctx, VisualBounds2D::descriptor_range());
     } else if bounds != updated_bounds {
@@ -232,6 +233,30 @@ impl SpatialView2D {
 
         let mut view_builder = ViewBuilder::new(ctx.render_ctx(), target_config);
 
+        // 2D measurements and annotations are in scene coordinates, regardless of what's under the pointer.
+        let measurement_hover_position = response.hover_pos().map(|pointer_pos_ui| {
+            let pointer_pos_scene = scene_from_ui.transform_pos(pointer_pos_ui);
+            glam::vec3(pointer_pos_scene.x, pointer_pos_scene.y, 0.0)
+        });
+        if state.annotation.is_active() {
+            state.annotation.handle_input(
+                ctx,
+                ui,
+                &response,
+                query.space_origin,
+                measurement_hover_position,
+            );
+        } else if state.measurement.is_active() {
+            if response.hovered() {
+                ui.ctx().set_cursor_icon(egui::CursorIcon::Crosshair);
+            }
+            if response.clicked()
+                && let Some(position) = measurement_hover_position
+            {
+                state.measurement.add_point(position);
+            }
+        }
+
         if let Some(pointer_pos_ui) = response.hover_pos() {
             let picking_context = crate::picking::PickingContext::new(
                 pointer_pos_ui,
@@ -307,6 +332,19 @@ impl SpatialView2D {
         // Add egui-rendered labels on top of everything else:
         painter.extend(label_shapes);
 
+        state.measurement.paint(
+            ui,
+            &painter,
+            |position| Some(ui_from_scene.transform_pos(pos2(position.x, position.y))),
+            measurement_hover_position,
+        );
+        state.annotation.paint_2d(
+            ui,
+            &painter,
+            |position| ui_from_scene.transform_pos(pos2(position.x, position.y)),
+            measurement_hover_position,
+        );
+
         Ok(())
     }
 }
@@ -355,6 +393,7 @@ fn setup_target_config(
                 principal_point.extend(1.0),
             ),
             resolution,
+            distortion: None,
         }
     };
     let pinhole_rect = Rect::from_min_size(
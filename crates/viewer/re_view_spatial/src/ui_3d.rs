@@ -29,6 +29,8 @@ use re_viewport_blueprint::ViewProperty;
 
 use crate::{
     SpatialView3D,
+    point_accumulation::PointAccumulation,
+    point_lod::LodProjection,
     scene_bounding_boxes::SceneBoundingBoxes,
     space_camera_3d::SpaceCamera3D,
     transform_cache::query_view_coordinates_at_closest_ancestor,
@@ -63,6 +65,12 @@ pub struct View3DState {
     /// Used to detect changes in view coordinates, in which case we reset the camera eye.
     pub scene_view_coordinates: Option<ViewCoordinates>,
 
+    /// Projection of the last frame, used to cull point clouds and pick their level of detail.
+    pub lod_projection: Option<LodProjection>,
+
+    /// How points accumulated over a visible time range are shown.
+    pub point_accumulation: PointAccumulation,
+
     // options:
     spin: bool,
     pub show_axes: bool,
@@ -82,6 +90,8 @@ impl Default for View3DState {
             camera_before_tracked_entity: None,
             eye_interpolation: Default::default(),
             scene_view_coordinates: None,
+            lod_projection: None,
+            point_accumulation: Default::default(),
             spin: false,
             show_axes: false,
             show_bbox: false,
@@ -460,6 +470,10 @@ impl SpatialView3D {
             &eye_property,
         );
         let eye = view_eye.to_eye();
+        state.state_3d.lod_projection = Some(LodProjection {
+            ui_from_world: eye.ui_from_world(ui_rect),
+            viewport: ui_rect,
+        });
 
         // Determine view port resolution and position.
         let resolution_in_pixel =
@@ -555,6 +569,29 @@ impl SpatialView3D {
             state.previous_picking_result = None;
         }
 
+        let measurement_hover_position = state
+            .previous_picking_result
+            .as_ref()
+            .and_then(|picking_result| picking_result.space_position());
+        if state.annotation.is_active() {
+            state.annotation.handle_input(
+                ctx,
+                ui,
+                &response,
+                query.space_origin,
+                measurement_hover_position,
+            );
+        } else if state.measurement.is_active() {
+            if response.hovered() {
+                ui.ctx().set_cursor_icon(egui::CursorIcon::Crosshair);
+            }
+            if response.clicked()
+                && let Some(position) = measurement_hover_position
+            {
+                state.measurement.add_point(position);
+            }
+        }
+
         // Track focused entity if any.
         if let Some(focused_item) = ctx.focused_item {
             let focused_entity = match focused_item {
@@ -719,6 +756,18 @@ impl SpatialView3D {
         let painter = ui.painter().with_clip_rect(ui.max_rect());
         painter.extend(label_shapes);
 
+        let ui_from_world = eye.ui_from_world(ui_rect);
+        state.measurement.paint(
+            ui,
+            &painter,
+            |position| {
+                let position = ui_from_world * position.extend(1.0);
+                (position.w > 0.0)
+                    .then(|| egui::pos2(position.x / position.w, position.y / position.w))
+            },
+            measurement_hover_position,
+        );
+
         Ok(())
     }
 
@@ -244,13 +244,21 @@ impl ViewClass for SpatialView2D {
         ctx: &re_viewer_context::ViewerContext<'_>,
         ui: &mut egui::Ui,
         state: &mut dyn ViewState,
-        _space_origin: &EntityPath,
+        space_origin: &EntityPath,
         view_id: ViewId,
     ) -> Result<(), ViewSystemExecutionError> {
         let state = state.downcast_mut::<SpatialViewState>()?;
         // TODO(andreas): list_item'ify the rest
         ui.selection_grid("spatial_settings_ui").show(ui, |ui| {
             state.bounding_box_ui(ui, SpatialViewKind::TwoD);
+
+            state
+                .measurement
+                .selection_ui(ctx, ui, space_origin, SpatialViewKind::TwoD);
+
+            state
+                .annotation
+                .selection_ui(ctx, ui, space_origin, SpatialViewKind::TwoD);
         });
 
         re_ui::list_item::list_item_scope(ui, "spatial_view2d_selection_ui", |ui| {
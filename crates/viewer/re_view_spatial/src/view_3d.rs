@@ -420,6 +420,16 @@ impl ViewClass for SpatialView3D {
             ui.end_row();
 
             state.bounding_box_ui(ui, SpatialViewKind::ThreeD);
+
+            state.state_3d.point_accumulation.selection_ui(ui);
+
+            state
+                .measurement
+                .selection_ui(ctx, ui, space_origin, SpatialViewKind::ThreeD);
+
+            state
+                .annotation
+                .selection_ui(ctx, ui, space_origin, SpatialViewKind::ThreeD);
         });
 
         re_ui::list_item::list_item_scope(ui, "spatial_view3d_selection_ui", |ui| {
@@ -120,19 +120,21 @@ impl CamerasVisualizer {
         });
 
         // Setup a RDF frustum (for non-RDF we apply a transformation matrix later).
+        //
+        // Lens distortion is ignored here, so that the frustum lines up with the image plane,
+        // which is placed in 3D without undistorting it.
+        let pinhole = pinhole_properties.pinhole.without_distortion();
         let corners = [
-            pinhole_properties.pinhole.unproject(vec3(0.0, 0.0, z)),
-            pinhole_properties.pinhole.unproject(vec3(0.0, h, z)),
-            pinhole_properties.pinhole.unproject(vec3(w, h, z)),
-            pinhole_properties.pinhole.unproject(vec3(w, 0.0, z)),
+            pinhole.unproject(vec3(0.0, 0.0, z)),
+            pinhole.unproject(vec3(0.0, h, z)),
+            pinhole.unproject(vec3(w, h, z)),
+            pinhole.unproject(vec3(w, 0.0, z)),
         ];
 
         let up_triangle = [
-            pinhole_properties.pinhole.unproject(vec3(0.4 * w, 0.0, z)),
-            pinhole_properties
-                .pinhole
-                .unproject(vec3(0.5 * w, -0.1 * w, z)),
-            pinhole_properties.pinhole.unproject(vec3(0.6 * w, 0.0, z)),
+            pinhole.unproject(vec3(0.4 * w, 0.0, z)),
+            pinhole.unproject(vec3(0.5 * w, -0.1 * w, z)),
+            pinhole.unproject(vec3(0.6 * w, 0.0, z)),
         ];
 
         let strips = vec![
@@ -266,11 +268,26 @@ impl VisualizerSystem for CamerasVisualizer {
                     &Pinhole::descriptor_image_plane_distance(),
                 )
                 .unwrap_or_else(|| self.fallback_for(&query_ctx));
+            let distortion_model = query_results
+                .get_mono::<components::DistortionModel>(&Pinhole::descriptor_distortion_model())
+                .unwrap_or_default();
+            let distortion_coefficients_descr = Pinhole::descriptor_distortion_coefficients();
+            let distortion_coefficients = (0..components::DistortionModel::MAX_COEFFICIENTS)
+                .map_while(|index| {
+                    query_results.get_instance::<components::DistortionCoefficient>(
+                        index,
+                        &distortion_coefficients_descr,
+                    )
+                });
 
             let component_data = CameraComponentDataWithFallbacks {
                 pinhole: crate::Pinhole {
                     image_from_camera: pinhole_projection.0.into(),
                     resolution: resolution.into(),
+                    distortion: crate::LensDistortion::new(
+                        distortion_model,
+                        distortion_coefficients,
+                    ),
                 },
                 camera_xyz,
                 image_plane_distance: image_plane_distance.into(),
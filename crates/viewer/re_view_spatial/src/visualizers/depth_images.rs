@@ -7,7 +7,8 @@ use re_types::{
     Archetype as _,
     archetypes::DepthImage,
     components::{
-        self, Colormap, DepthMeter, DrawOrder, FillRatio, ImageBuffer, ImageFormat, ValueRange,
+        self, Colormap, DepthMeter, DrawOrder, FillRatio, ImageBuffer, ImageFormat, Range1D,
+        ValueRange,
     },
     image::ImageKind,
 };
@@ -21,6 +22,7 @@ use re_viewer_context::{
 use crate::{
     PickableRectSourceData, PickableTexturedRect, SpatialView3D,
     contexts::{SpatialSceneEntityContext, TwoDInThreeDTransformInfo},
+    depth_cloud_bounds::DepthCloudBoundsCache,
     view_kind::SpatialViewKind,
     visualizers::filter_visualizable_2d_entities,
 };
@@ -49,6 +51,7 @@ struct DepthImageComponentData {
     fill_ratio: Option<FillRatio>,
     colormap: Option<Colormap>,
     value_range: Option<[f64; 2]>,
+    clip_range: Option<[f64; 2]>,
 }
 
 impl DepthImageVisualizer {
@@ -70,6 +73,7 @@ impl DepthImageVisualizer {
                 fill_ratio,
                 colormap,
                 value_range,
+                clip_range,
             } = data;
 
             let depth_meter = depth_meter.unwrap_or_else(|| self.fallback_for(ctx));
@@ -111,6 +115,7 @@ impl DepthImageVisualizer {
                 && let Some(twod_in_threed_info) = &ent_context.transform_info.twod_in_threed_info
             {
                 let fill_ratio = fill_ratio.unwrap_or_default();
+                let clip_range = clip_range.map_or_else(|| self.fallback_for(ctx), Range1D::from);
 
                 // NOTE: we don't pass in `world_from_obj` because this corresponds to the
                 // transform of the projection plane, which is of no use to us here.
@@ -122,14 +127,23 @@ impl DepthImageVisualizer {
                     twod_in_threed_info,
                     depth_meter,
                     fill_ratio,
+                    clip_range,
                     &textured_rect.colormapped_texture,
                 ) {
                     Ok(cloud) => {
-                        self.data.add_bounding_box(
-                            entity_path.hash(),
-                            cloud.world_space_bbox(),
-                            glam::Affine3A::IDENTITY,
-                        );
+                        let bbox_in_rdf =
+                            ctx.store_ctx()
+                                .caches
+                                .entry(|c: &mut DepthCloudBoundsCache| {
+                                    c.entry(&image, cloud.depth_camera_intrinsics, *depth_meter.0)
+                                });
+                        if bbox_in_rdf.is_something() {
+                            self.data.add_bounding_box(
+                                entity_path.hash(),
+                                bbox_in_rdf,
+                                cloud.world_from_rdf,
+                            );
+                        }
                         self.depth_cloud_entities.insert(
                             entity_path.hash(),
                             (image, depth_meter, textured_rect.colormapped_texture),
@@ -166,6 +180,7 @@ impl DepthImageVisualizer {
         twod_in_threed_info: &TwoDInThreeDTransformInfo,
         depth_meter: DepthMeter,
         radius_scale: FillRatio,
+        clip_range: Range1D,
         depth_texture: &ColormappedTexture,
     ) -> anyhow::Result<DepthCloud> {
         re_tracing::profile_function!();
@@ -210,6 +225,7 @@ impl DepthImageVisualizer {
             world_depth_from_texture_depth,
             point_radius_from_world_depth,
             min_max_depth_in_world,
+            clip_depth_in_world: [clip_range.start() as f32, clip_range.end() as f32],
             depth_dimensions: dimensions,
             depth_texture: depth_texture.texture.clone(),
             colormap: match depth_texture.color_mapper {
@@ -279,14 +295,17 @@ impl VisualizerSystem for DepthImageVisualizer {
                 let all_depth_meters = results.iter_as(timeline, DepthImage::descriptor_meter());
                 let all_fill_ratios =
                     results.iter_as(timeline, DepthImage::descriptor_point_fill_ratio());
+                let all_clip_ranges =
+                    results.iter_as(timeline, DepthImage::descriptor_clip_range());
 
-                let mut data = re_query::range_zip_1x5(
+                let mut data = re_query::range_zip_1x6(
                     all_buffers_indexed,
                     all_formats_indexed,
                     all_colormaps.slice::<u8>(),
                     all_value_ranges.slice::<[f64; 2]>(),
                     all_depth_meters.slice::<f32>(),
                     all_fill_ratios.slice::<f32>(),
+                    all_clip_ranges.slice::<[f64; 2]>(),
                 )
                 .filter_map(
                     |(
@@ -297,6 +316,7 @@ impl VisualizerSystem for DepthImageVisualizer {
                         value_range,
                         depth_meter,
                         fill_ratio,
+                        clip_range,
                     )| {
                         let buffer = buffers.first()?;
 
@@ -312,6 +332,7 @@ impl VisualizerSystem for DepthImageVisualizer {
                             fill_ratio: first_copied(fill_ratio).map(Into::into),
                             colormap: first_copied(colormap).and_then(Colormap::from_u8),
                             value_range: first_copied(value_range),
+                            clip_range: first_copied(clip_range),
                         })
                     },
                 );
@@ -405,6 +426,13 @@ impl TypedComponentFallbackProvider<ValueRange> for DepthImageVisualizer {
     }
 }
 
+impl TypedComponentFallbackProvider<Range1D> for DepthImageVisualizer {
+    fn fallback_for(&self, _ctx: &re_viewer_context::QueryContext<'_>) -> Range1D {
+        // Show every point with a positive depth.
+        Range1D::new(0.0, f64::INFINITY)
+    }
+}
+
 impl TypedComponentFallbackProvider<Colormap> for DepthImageVisualizer {
     fn fallback_for(&self, _ctx: &re_viewer_context::QueryContext<'_>) -> Colormap {
         ColormapWithRange::DEFAULT_DEPTH_COLORMAP
@@ -426,7 +454,7 @@ impl TypedComponentFallbackProvider<DepthMeter> for DepthImageVisualizer {
     }
 }
 
-re_viewer_context::impl_component_fallback_provider!(DepthImageVisualizer => [Colormap, ValueRange, DepthMeter, DrawOrder]);
+re_viewer_context::impl_component_fallback_provider!(DepthImageVisualizer => [Colormap, ValueRange, Range1D, DepthMeter, DrawOrder]);
 
 fn first_copied<T: Copy>(slice: Option<&[T]>) -> Option<T> {
     slice.and_then(|element| element.first()).copied()
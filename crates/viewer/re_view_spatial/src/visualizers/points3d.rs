@@ -1,5 +1,7 @@
 use itertools::Itertools as _;
 
+use re_chunk_store::RowId;
+use re_log_types::TimeInt;
 use re_renderer::{LineDrawableBuilder, PickingLayerInstanceId, PointCloudBuilder};
 use re_types::{
     Archetype as _, ArrowString,
@@ -9,12 +11,16 @@ use re_types::{
 use re_view::{process_annotation_and_keypoint_slices, process_color_slice};
 use re_viewer_context::{
     IdentifiedViewSystem, MaybeVisualizableEntities, QueryContext, TypedComponentFallbackProvider,
-    ViewContext, ViewContextCollection, ViewQuery, ViewSystemExecutionError, VisualizableEntities,
-    VisualizableFilterContext, VisualizerQueryInfo, VisualizerSystem, auto_color_for_entity_path,
+    ViewContext, ViewContextCollection, ViewQuery, ViewStateExt as _, ViewSystemExecutionError,
+    VisualizableEntities, VisualizableFilterContext, VisualizerQueryInfo, VisualizerSystem,
+    auto_color_for_entity_path,
 };
 
 use crate::{
     contexts::SpatialSceneEntityContext,
+    point_accumulation::{PointAccumulation, darken, entity_now_from_entity_at_times, fade_by_age},
+    point_lod::{MAX_POINTS_PER_BATCH, MIN_POINTS_FOR_LOD, PointLodCache},
+    ui::SpatialViewState,
     view_kind::SpatialViewKind,
     visualizers::{load_keypoint_connections, process_radius_slice},
 };
@@ -39,6 +45,9 @@ impl Default for Points3DVisualizer {
 }
 
 struct Points3DComponentData<'a> {
+    time: TimeInt,
+    row_id: RowId,
+
     // Point of views
     positions: &'a [Position3D],
 
@@ -53,6 +62,61 @@ struct Points3DComponentData<'a> {
     show_labels: Option<ShowLabels>,
 }
 
+/// The points of a large batch picked by its level of detail, see [`crate::point_lod`].
+struct LodPoints {
+    /// Indices of the picked points in the batch, in ascending order.
+    indices: Vec<u32>,
+
+    positions: Vec<Position3D>,
+    colors: Vec<Color>,
+    radii: Vec<Radius>,
+    labels: Vec<ArrowString>,
+    keypoint_ids: Vec<KeypointId>,
+    class_ids: Vec<ClassId>,
+}
+
+impl LodPoints {
+    fn new(data: &Points3DComponentData<'_>, indices: Vec<u32>) -> Self {
+        re_tracing::profile_function!(indices.len().to_string());
+
+        Self {
+            positions: gather_clamped(data.positions, &indices),
+            colors: gather_clamped(data.colors, &indices),
+            radii: gather_clamped(data.radii, &indices),
+            labels: gather_clamped(&data.labels, &indices),
+            keypoint_ids: gather_clamped(data.keypoint_ids, &indices),
+            class_ids: gather_clamped(data.class_ids, &indices),
+            indices,
+        }
+    }
+
+    fn component_data(&self, data: &Points3DComponentData<'_>) -> Points3DComponentData<'_> {
+        Points3DComponentData {
+            time: data.time,
+            row_id: data.row_id,
+            positions: &self.positions,
+            colors: &self.colors,
+            radii: &self.radii,
+            labels: self.labels.clone(),
+            keypoint_ids: &self.keypoint_ids,
+            class_ids: &self.class_ids,
+            show_labels: data.show_labels,
+        }
+    }
+}
+
+/// The values at `indices`, keeping the clamp-to-edge semantics of component batches.
+fn gather_clamped<T: Clone>(values: &[T], indices: &[u32]) -> Vec<T> {
+    if values.len() <= 1 {
+        return values.to_vec();
+    }
+
+    indices
+        .iter()
+        .map(|&index| values[(index as usize).min(values.len() - 1)].clone())
+        .collect()
+}
+
 // NOTE: Do not put profile scopes in these methods. They are called for all entities and all
 // timestamps within a time range -- it's _a lot_.
 impl Points3DVisualizer {
@@ -67,15 +131,115 @@ impl Points3DVisualizer {
     ) -> Result<(), ViewSystemExecutionError> {
         let entity_path = ctx.target_entity_path;
 
+        let (lod_projection, point_accumulation) = ctx
+            .view_state()
+            .downcast_ref::<SpatialViewState>()
+            .ok()
+            .map_or((None, PointAccumulation::default()), |state| {
+                (
+                    state.state_3d.lod_projection,
+                    state.state_3d.point_accumulation,
+                )
+            });
+
+        let data = data.collect_vec();
+
+        // Points accumulated over a visible time range may be faded by age, and transformed at their own time.
+        let accumulated_times = if point_accumulation.is_active() {
+            data.iter()
+                .map(|data| data.time)
+                .filter(|time| !time.is_static())
+                .unique()
+                .collect_vec()
+        } else {
+            Vec::new()
+        };
+        let newest_time = accumulated_times.iter().max().copied();
+        let max_age = newest_time
+            .zip(accumulated_times.iter().min())
+            .map_or(0, |(newest, oldest)| newest.as_i64() - oldest.as_i64());
+        let entity_now_from_entity_at_time = if point_accumulation.transform_at_log_time {
+            entity_now_from_entity_at_times(
+                ctx.recording(),
+                query.timeline,
+                query.latest_at,
+                entity_path,
+                &accumulated_times,
+            )
+        } else {
+            Default::default()
+        };
+
         for data in data {
-            let num_instances = data.positions.len();
-            if num_instances == 0 {
+            if data.positions.is_empty() {
                 continue;
             }
 
-            let picking_ids = (0..num_instances)
-                .map(|i| PickingLayerInstanceId(i as _))
-                .collect_vec();
+            let mut world_from_obj = ent_context
+                .transform_info
+                .single_entity_transform_required(entity_path, Points3D::name());
+            if let Some(entity_now_from_entity) = entity_now_from_entity_at_time.get(&data.time) {
+                // Poses are applied on top of the entity's transform, so the correction goes in between.
+                let reference_from_entity = ent_context.transform_info.reference_from_entity();
+                world_from_obj = reference_from_entity
+                    * *entity_now_from_entity
+                    * reference_from_entity.inverse()
+                    * world_from_obj;
+            }
+
+            // Large batches are culled, and only drawn in as much detail as they have on screen.
+            // Without a projection (e.g. in 2D views, or in the first frame), they are only capped.
+            let lod = (data.positions.len() >= MIN_POINTS_FOR_LOD).then(|| {
+                let lod = ctx.store_ctx().caches.entry(|c: &mut PointLodCache| {
+                    c.entry(data.row_id, bytemuck::cast_slice(data.positions))
+                });
+                let screen_size = match lod_projection {
+                    Some(projection) => {
+                        projection.screen_size(&lod.bounding_box.transform_affine3(&world_from_obj))
+                    }
+                    None => Some(f32::INFINITY),
+                };
+                (lod, screen_size)
+            });
+
+            let lod_points = match &lod {
+                Some((lod, None)) => {
+                    // Not in view, but still part of the scene.
+                    self.data.add_bounding_box(
+                        entity_path.hash(),
+                        lod.bounding_box,
+                        world_from_obj,
+                    );
+                    continue;
+                }
+                Some((lod, Some(screen_size))) => {
+                    let num_points = lod
+                        .num_points_for_screen_size(*screen_size)
+                        .min(MAX_POINTS_PER_BATCH);
+                    (num_points < data.positions.len())
+                        .then(|| LodPoints::new(&data, lod.indices(num_points)))
+                }
+                None => None,
+            };
+            let lod_indices = lod_points
+                .as_ref()
+                .map(|lod_points| lod_points.indices.as_slice());
+            let data = match &lod_points {
+                Some(lod_points) => lod_points.component_data(&data),
+                None => data,
+            };
+
+            let num_instances = data.positions.len();
+            // Picking ids are the indices in the whole batch, also for the points picked by the level of detail.
+            let picking_ids = match lod_indices {
+                Some(indices) => indices
+                    .iter()
+                    .map(|&i| PickingLayerInstanceId(i as _))
+                    .collect_vec(),
+                None => (0..num_instances)
+                    .map(|i| PickingLayerInstanceId(i as _))
+                    .collect_vec(),
+            };
 
             let (annotation_infos, keypoints) = process_annotation_and_keypoint_slices(
                 query.latest_at,
@@ -92,12 +256,19 @@ impl Points3DVisualizer {
             // TODO(andreas): It would be nice to have this handle this fallback as part of the query.
             let radii =
                 process_radius_slice(entity_path, num_instances, data.radii, Radius::default());
-            let colors =
+            let mut colors =
                 process_color_slice(ctx, self, num_instances, &annotation_infos, data.colors);
-
-            let world_from_obj = ent_context
-                .transform_info
-                .single_entity_transform_required(entity_path, Points3D::name());
+            if point_accumulation.fade_by_age
+                && let Some(newest_time) = newest_time
+                && !data.time.is_static()
+            {
+                let brightness = fade_by_age(newest_time.as_i64() - data.time.as_i64(), max_age);
+                if brightness < 1.0 {
+                    for color in &mut colors {
+                        *color = darken(*color, brightness);
+                    }
+                }
+            }
 
             {
                 let point_batch = point_builder
@@ -112,9 +283,14 @@ impl Points3DVisualizer {
                 // Determine if there's any sub-ranges that need extra highlighting.
                 {
                     for (highlighted_key, instance_mask_ids) in &ent_context.highlight.instances {
-                        let highlighted_point_index = (highlighted_key.get()
-                            < num_instances as u64)
-                            .then_some(highlighted_key.get());
+                        let highlighted_point_index = match lod_indices {
+                            Some(indices) => u32::try_from(highlighted_key.get())
+                                .ok()
+                                .and_then(|index| indices.binary_search(&index).ok())
+                                .map(|index| index as u64),
+                            None => (highlighted_key.get() < num_instances as u64)
+                                .then_some(highlighted_key.get()),
+                        };
                         if let Some(highlighted_point_index) = highlighted_point_index {
                             point_range_builder = point_range_builder
                                 .push_additional_outline_mask_ids_for_range(
@@ -127,7 +303,10 @@ impl Points3DVisualizer {
                 }
             }
 
-            let obj_space_bounding_box = macaw::BoundingBox::from_points(positions.iter().copied());
+            let obj_space_bounding_box = match &lod {
+                Some((lod, _)) => lod.bounding_box,
+                None => macaw::BoundingBox::from_points(positions.iter().copied()),
+            };
             self.data
                 .add_bounding_box(entity_path.hash(), obj_space_bounding_box, world_from_obj);
 
@@ -213,7 +392,7 @@ impl VisualizerSystem for Points3DVisualizer {
                 let num_positions = all_position_chunks
                     .iter()
                     .flat_map(|chunk| chunk.iter_slices::<[f32; 3]>())
-                    .map(|points| points.len())
+                    .map(|points| points.len().min(MAX_POINTS_PER_BATCH))
                     .sum();
 
                 if num_positions == 0 {
@@ -243,7 +422,7 @@ impl VisualizerSystem for Points3DVisualizer {
                 )
                 .map(
                     |(
-                        _index,
+                        (time, row_id),
                         positions,
                         colors,
                         radii,
@@ -253,6 +432,8 @@ impl VisualizerSystem for Points3DVisualizer {
                         show_labels,
                     )| {
                         Points3DComponentData {
+                            time,
+                            row_id,
                             positions: bytemuck::cast_slice(positions),
                             colors: colors.map_or(&[], |colors| bytemuck::cast_slice(colors)),
                             radii: radii.map_or(&[], |radii| bytemuck::cast_slice(radii)),
@@ -3,8 +3,11 @@
 //! A view dedicated to visualizing tensors with arbitrary dimensionality.
 
 mod dimension_mapping;
+mod projection;
+mod slicing_presets;
 mod tensor_dimension_mapper;
 mod tensor_slice_to_gpu;
+mod transfer_function_ui;
 mod view_class;
 mod visualizer_system;
 
@@ -0,0 +1,283 @@
+use ndarray::Axis;
+
+use re_types::{
+    datatypes::TensorData,
+    tensor_data::{TensorCastError, TensorDataType},
+};
+
+use crate::dimension_mapping::TensorSliceSelection;
+
+/// Opacities of the transfer function are for a volume this many samples deep.
+///
+/// Deeper volumes get more transparent samples, so that their overall look doesn't depend on the resolution.
+const REFERENCE_DEPTH: f32 = 64.0;
+
+/// How the tensor is turned into the 2D image shown by the view.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TensorProjection {
+    /// The slice at the selected index of every dimension other than width and height.
+    #[default]
+    Slice,
+
+    /// The maximum along the depth dimension, a.k.a. MIP.
+    MaximumIntensity,
+
+    /// All values along the depth dimension, composited front to back using the transfer function.
+    Volume,
+}
+
+impl TensorProjection {
+    pub const ALL: [Self; 3] = [Self::Slice, Self::MaximumIntensity, Self::Volume];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Slice => "Slice",
+            Self::MaximumIntensity => "Maximum intensity",
+            Self::Volume => "Volume",
+        }
+    }
+}
+
+/// Maps normalized values to opacities for volume rendering.
+///
+/// The opacity is interpolated linearly between the control points.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferFunction {
+    /// `[value, opacity]` pairs sorted by value, both in `0..=1`.
+    ///
+    /// The first and last point are always at value 0 and 1.
+    pub points: Vec<[f32; 2]>,
+}
+
+impl Default for TransferFunction {
+    fn default() -> Self {
+        Self {
+            points: vec![[0.0, 0.0], [0.25, 0.0], [1.0, 0.8]],
+        }
+    }
+}
+
+impl std::hash::Hash for TransferFunction {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for [value, opacity] in &self.points {
+            value.to_bits().hash(state);
+            opacity.to_bits().hash(state);
+        }
+    }
+}
+
+impl TransferFunction {
+    /// Opacity for a value in `0..=1`.
+    pub fn opacity(&self, value: f32) -> f32 {
+        let next = self.points.partition_point(|[v, _]| *v < value);
+        match (next.checked_sub(1), self.points.get(next)) {
+            (Some(prev), Some(&[next_value, next_opacity])) => {
+                let [prev_value, prev_opacity] = self.points[prev];
+                let t = (value - prev_value) / (next_value - prev_value).max(f32::EPSILON);
+                egui::lerp(prev_opacity..=next_opacity, t.clamp(0.0, 1.0))
+            }
+            (None, Some(&[_, opacity])) => opacity,
+            (Some(prev), None) => self.points[prev][1],
+            (None, None) => 0.0,
+        }
+    }
+}
+
+/// The dimension reduced by the projections.
+///
+/// That's the dimension of the first slider, i.e. the one that would otherwise be scrolled through.
+pub fn depth_dimension(slice_selection: &TensorSliceSelection) -> Option<u32> {
+    slice_selection
+        .slider
+        .as_ref()
+        .and_then(|slider| slider.first())
+        .map(|slider| slider.dimension)
+        .or_else(|| slice_selection.indices.first().map(|index| index.dimension))
+}
+
+/// Converts the tensor to `f32`, which is precise enough for projections.
+pub fn tensor_as_f32(tensor: &TensorData) -> Result<ndarray::ArrayD<f32>, TensorCastError> {
+    Ok(match tensor.dtype() {
+        TensorDataType::U8 => ndarray::ArrayViewD::<u8>::try_from(tensor)?.mapv(f32::from),
+        TensorDataType::U16 => ndarray::ArrayViewD::<u16>::try_from(tensor)?.mapv(f32::from),
+        TensorDataType::U32 => ndarray::ArrayViewD::<u32>::try_from(tensor)?.mapv(|x| x as f32),
+        TensorDataType::U64 => ndarray::ArrayViewD::<u64>::try_from(tensor)?.mapv(|x| x as f32),
+        TensorDataType::I8 => ndarray::ArrayViewD::<i8>::try_from(tensor)?.mapv(f32::from),
+        TensorDataType::I16 => ndarray::ArrayViewD::<i16>::try_from(tensor)?.mapv(f32::from),
+        TensorDataType::I32 => ndarray::ArrayViewD::<i32>::try_from(tensor)?.mapv(|x| x as f32),
+        TensorDataType::I64 => ndarray::ArrayViewD::<i64>::try_from(tensor)?.mapv(|x| x as f32),
+        TensorDataType::F16 => ndarray::ArrayViewD::<half::f16>::try_from(tensor)?.mapv(f32::from),
+        TensorDataType::F32 => ndarray::ArrayViewD::<f32>::try_from(tensor)?.to_owned(),
+        TensorDataType::F64 => ndarray::ArrayViewD::<f64>::try_from(tensor)?.mapv(|x| x as f32),
+    })
+}
+
+/// The volume spanned by height, width and depth, in that order, at the selected index of all other dimensions.
+///
+/// Returns `None` if width or height aren't set, e.g. because the tensor has less than three dimensions.
+pub fn selected_volume<'a>(
+    slice_selection: &TensorSliceSelection,
+    depth: u32,
+    tensor: &'a ndarray::ArrayViewD<'_, f32>,
+) -> Option<ndarray::ArrayView3<'a, f32>> {
+    let (Some(width), Some(height)) = (slice_selection.width, slice_selection.height) else {
+        return None;
+    };
+    let others = || {
+        slice_selection
+            .indices
+            .iter()
+            .filter(move |index| index.dimension != depth)
+    };
+
+    let axes = [height.dimension, width.dimension, depth]
+        .into_iter()
+        .chain(others().map(|index| index.dimension))
+        .map(|dimension| dimension as usize)
+        .collect::<Vec<_>>();
+    if axes.len() != tensor.ndim() {
+        return None;
+    }
+
+    let mut volume = tensor.view().permuted_axes(axes);
+    for index in others() {
+        // 0, 1 and 2 are height, width and depth, and this removes Axis(3) for the next index.
+        volume.index_axis_inplace(Axis(3), index.index as usize);
+    }
+    if height.invert {
+        volume.invert_axis(Axis(0));
+    }
+    if width.invert {
+        volume.invert_axis(Axis(1));
+    }
+
+    volume.into_dimensionality::<ndarray::Ix3>().ok()
+}
+
+/// The maximum along the depth axis of a volume.
+pub fn max_intensity_projection(volume: ndarray::ArrayView3<'_, f32>) -> ndarray::Array2<f32> {
+    volume.map_axis(Axis(2), |samples| {
+        samples.iter().copied().fold(f32::NEG_INFINITY, f32::max)
+    })
+}
+
+/// Composites the samples along the depth axis of a volume front to back.
+///
+/// Values are normalized with `value_range`, and colored with the colormap and the opacity of the transfer function.
+/// Returns unmultiplied sRGBA pixels.
+pub fn composite_volume(
+    volume: ndarray::ArrayView3<'_, f32>,
+    value_range: [f32; 2],
+    colormap: re_renderer::Colormap,
+    transfer_function: &TransferFunction,
+) -> ndarray::Array2<[u8; 4]> {
+    re_tracing::profile_function!();
+
+    let [min, max] = value_range;
+    let step_scale = REFERENCE_DEPTH / volume.len_of(Axis(2)).max(1) as f32;
+
+    volume.map_axis(Axis(2), |samples| {
+        let mut color = [0.0_f32; 3];
+        let mut alpha = 0.0_f32;
+
+        for &value in samples {
+            let t = ((value - min) / (max - min)).clamp(0.0, 1.0);
+            if t.is_nan() {
+                continue;
+            }
+
+            let sample_alpha = 1.0 - (1.0 - transfer_function.opacity(t)).powf(step_scale);
+            if sample_alpha <= 0.0 {
+                continue;
+            }
+
+            let [r, g, b, _] = re_renderer::colormap_srgb(colormap, t);
+            let weight = (1.0 - alpha) * sample_alpha;
+            color[0] += weight * r as f32;
+            color[1] += weight * g as f32;
+            color[2] += weight * b as f32;
+            alpha += weight;
+
+            if alpha > 0.99 {
+                break;
+            }
+        }
+
+        if alpha <= 0.0 {
+            return [0; 4];
+        }
+        [
+            (color[0] / alpha).round() as u8,
+            (color[1] / alpha).round() as u8,
+            (color[2] / alpha).round() as u8,
+            (alpha * 255.0).round() as u8,
+        ]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use re_types::{
+        blueprint::components::TensorDimensionIndexSlider,
+        datatypes::{TensorDimensionIndexSelection, TensorDimensionSelection},
+    };
+
+    use super::*;
+
+    #[test]
+    fn transfer_function_interpolates() {
+        let transfer_function = TransferFunction {
+            points: vec![[0.0, 0.0], [0.5, 1.0], [1.0, 0.5]],
+        };
+        assert_eq!(transfer_function.opacity(0.0), 0.0);
+        assert_eq!(transfer_function.opacity(0.25), 0.5);
+        assert_eq!(transfer_function.opacity(0.5), 1.0);
+        assert_eq!(transfer_function.opacity(0.75), 0.75);
+        assert_eq!(transfer_function.opacity(1.0), 0.5);
+    }
+
+    #[test]
+    fn projects_along_depth() {
+        // Shape [depth, height, width].
+        let tensor =
+            ndarray::Array3::from_shape_fn((3, 2, 4), |(d, h, w)| (d * 100 + h * 10 + w) as f32)
+                .into_dyn();
+        let slice_selection = TensorSliceSelection {
+            width: Some(
+                TensorDimensionSelection {
+                    dimension: 2,
+                    invert: false,
+                }
+                .into(),
+            ),
+            height: Some(
+                TensorDimensionSelection {
+                    dimension: 1,
+                    invert: true,
+                }
+                .into(),
+            ),
+            indices: vec![
+                TensorDimensionIndexSelection {
+                    dimension: 0,
+                    index: 1,
+                }
+                .into(),
+            ],
+            slider: Some(vec![TensorDimensionIndexSlider::from(0)]),
+        };
+
+        let depth = depth_dimension(&slice_selection).unwrap();
+        assert_eq!(depth, 0);
+
+        let tensor = tensor.view();
+        let volume = selected_volume(&slice_selection, depth, &tensor).unwrap();
+        assert_eq!(volume.shape(), &[2, 4, 3]);
+        assert_eq!(volume[[0, 3, 1]], 113.0);
+
+        let mip = max_intensity_projection(volume);
+        assert_eq!(mip.shape(), &[2, 4]);
+        assert_eq!(mip[[0, 0]], 210.0);
+        assert_eq!(mip[[1, 3]], 203.0);
+    }
+}
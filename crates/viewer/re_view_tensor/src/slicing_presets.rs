@@ -0,0 +1,148 @@
+use re_types::{
+    blueprint::{archetypes, components::TensorDimensionIndexSlider},
+    components::{TensorHeightDimension, TensorWidthDimension},
+    datatypes::TensorDimensionSelection,
+};
+use re_viewer_context::ViewerContext;
+use re_viewport_blueprint::ViewProperty;
+
+use crate::{TensorDimension, dimension_mapping::TensorSliceSelection};
+
+/// A named dimension mapping, to quickly switch between different planes of a volume.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlicingPreset {
+    pub name: String,
+    pub width: TensorWidthDimension,
+    pub height: TensorHeightDimension,
+    pub slider: Vec<TensorDimensionIndexSlider>,
+}
+
+impl SlicingPreset {
+    /// The current dimension mapping, if it has both width and height.
+    pub fn from_selection(name: String, slice_selection: &TensorSliceSelection) -> Option<Self> {
+        Some(Self {
+            name,
+            width: slice_selection.width?,
+            height: slice_selection.height?,
+            slider: slice_selection.slider.clone().unwrap_or_default(),
+        })
+    }
+
+    /// Writes the dimension mapping to the blueprint.
+    ///
+    /// The selected indices are kept, and made valid for the new mapping when loaded.
+    pub fn apply(&self, ctx: &ViewerContext<'_>, slice_property: &ViewProperty) {
+        slice_property.save_blueprint_component(
+            ctx,
+            &archetypes::TensorSliceSelection::descriptor_width(),
+            &self.width,
+        );
+        slice_property.save_blueprint_component(
+            ctx,
+            &archetypes::TensorSliceSelection::descriptor_height(),
+            &self.height,
+        );
+        slice_property.save_blueprint_component(
+            ctx,
+            &archetypes::TensorSliceSelection::descriptor_slider(),
+            &self.slider,
+        );
+    }
+
+    /// Whether the preset is what `slice_selection` currently shows.
+    pub fn is_selected(&self, slice_selection: &TensorSliceSelection) -> bool {
+        slice_selection.width == Some(self.width)
+            && slice_selection.height == Some(self.height)
+            && slice_selection
+                .slider
+                .as_ref()
+                .and_then(|slider| slider.first())
+                == self.slider.first()
+    }
+}
+
+/// For volumes, one preset per dimension, showing the planes orthogonal to it.
+///
+/// For instance, the axial, coronal and sagittal planes of a `[z, y, x]` scan.
+/// Dimensions of size one are ignored, so that e.g. a channel dimension doesn't get in the way.
+pub fn builtin_presets(shape: &[TensorDimension]) -> Vec<SlicingPreset> {
+    let dimensions = (0..shape.len() as u32)
+        .filter(|dim| shape[*dim as usize].size > 1)
+        .collect::<Vec<_>>();
+    if dimensions.len() < 3 {
+        return Vec::new();
+    }
+
+    dimensions
+        .iter()
+        .map(|&depth| {
+            let mut others = dimensions.iter().copied().filter(|dim| *dim != depth);
+            let height = others.next().unwrap_or_default();
+            let width = others.next().unwrap_or_default();
+
+            let name = shape[depth as usize].name.as_ref().map_or_else(
+                || format!("Along dimension {depth}"),
+                |name| format!("Along {name}"),
+            );
+
+            SlicingPreset {
+                name,
+                width: TensorDimensionSelection {
+                    dimension: width,
+                    invert: false,
+                }
+                .into(),
+                height: TensorDimensionSelection {
+                    dimension: height,
+                    invert: false,
+                }
+                .into(),
+                slider: std::iter::once(depth)
+                    .chain(
+                        dimensions
+                            .iter()
+                            .copied()
+                            .filter(|dim| *dim != depth && *dim != width && *dim != height),
+                    )
+                    .map(Into::into)
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_for_volume() {
+        let shape = [
+            TensorDimension::named(10, "z"),
+            TensorDimension::named(20, "y"),
+            TensorDimension::named(30, "x"),
+            TensorDimension::unnamed(1),
+        ];
+        let presets = builtin_presets(&shape);
+
+        let names = presets
+            .iter()
+            .map(|preset| preset.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["Along z", "Along y", "Along x"]);
+
+        let axes = presets
+            .iter()
+            .map(|preset| {
+                (
+                    preset.height.dimension,
+                    preset.width.dimension,
+                    preset.slider[0].dimension,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(axes, [(1, 2, 0), (0, 2, 1), (0, 1, 2)]);
+
+        assert!(builtin_presets(&shape[..2]).is_empty());
+    }
+}
@@ -13,7 +13,11 @@ use re_viewer_context::{
     gpu_bridge::{self, colormap_to_re_renderer},
 };
 
-use crate::{dimension_mapping::TensorSliceSelection, view_class::selected_tensor_slice};
+use crate::{
+    dimension_mapping::TensorSliceSelection,
+    projection::{self, TensorProjection, TransferFunction},
+    view_class::selected_tensor_slice,
+};
 
 #[derive(thiserror::Error, Debug, PartialEq)]
 pub enum TensorUploadError {
@@ -22,6 +26,9 @@ pub enum TensorUploadError {
 
     #[error("Expected a 2D slice")]
     Not2D,
+
+    #[error("Projections need a tensor with at least three dimensions")]
+    NoDepth,
 }
 
 pub fn colormapped_texture(
@@ -50,6 +57,107 @@ pub fn colormapped_texture(
     })
 }
 
+/// Like [`colormapped_texture`], but reduces the depth dimension as specified by `projection`.
+#[allow(clippy::too_many_arguments)]
+pub fn projected_texture(
+    render_ctx: &re_renderer::RenderContext,
+    tensor_data_row_id: RowId,
+    tensor: &TensorData,
+    slice_selection: &TensorSliceSelection,
+    projection: TensorProjection,
+    colormap: &ColormapWithRange,
+    gamma: GammaCorrection,
+    transfer_function: &TransferFunction,
+) -> Result<ColormappedTexture, TextureManager2DError<TensorUploadError>> {
+    re_tracing::profile_function!();
+
+    match projection {
+        TensorProjection::Slice => colormapped_texture(
+            render_ctx,
+            tensor_data_row_id,
+            tensor,
+            slice_selection,
+            colormap,
+            gamma,
+        ),
+
+        TensorProjection::MaximumIntensity => {
+            let id = egui::util::hash((tensor_data_row_id, slice_selection, projection));
+            let texture = gpu_bridge::try_get_or_create_texture(render_ctx, id, || {
+                let tensor = projection::tensor_as_f32(tensor)?;
+                let tensor = tensor.view();
+                let volume = volume_to_project(slice_selection, &tensor)?;
+                Ok::<_, TensorUploadError>(image_desc(
+                    projection::max_intensity_projection(volume),
+                    wgpu::TextureFormat::R32Float,
+                ))
+            })?;
+
+            Ok(ColormappedTexture {
+                texture,
+                range: colormap.value_range,
+                decode_srgb: false,
+                multiply_rgb_with_alpha: false,
+                gamma: *gamma.0,
+                color_mapper: re_renderer::renderer::ColorMapper::Function(
+                    colormap_to_re_renderer(colormap.colormap),
+                ),
+                shader_decoding: None,
+            })
+        }
+
+        TensorProjection::Volume => {
+            // The colors are baked into the texture, so everything they depend on is part of the key.
+            let id = egui::util::hash((
+                tensor_data_row_id,
+                slice_selection,
+                projection,
+                colormap.colormap,
+                colormap.value_range.map(f32::to_bits),
+                transfer_function,
+            ));
+            let texture = gpu_bridge::try_get_or_create_texture(render_ctx, id, || {
+                let tensor = projection::tensor_as_f32(tensor)?;
+                let tensor = tensor.view();
+                let volume = volume_to_project(slice_selection, &tensor)?;
+                Ok::<_, TensorUploadError>(image_desc(
+                    projection::composite_volume(
+                        volume,
+                        colormap.value_range,
+                        colormap_to_re_renderer(colormap.colormap),
+                        transfer_function,
+                    ),
+                    wgpu::TextureFormat::Rgba8UnormSrgb,
+                ))
+            })?;
+
+            Ok(ColormappedTexture::from_unorm_rgba(texture))
+        }
+    }
+}
+
+fn volume_to_project<'a>(
+    slice_selection: &TensorSliceSelection,
+    tensor: &'a ndarray::ArrayViewD<'_, f32>,
+) -> Result<ndarray::ArrayView3<'a, f32>, TensorUploadError> {
+    projection::depth_dimension(slice_selection)
+        .and_then(|depth| projection::selected_volume(slice_selection, depth, tensor))
+        .ok_or(TensorUploadError::NoDepth)
+}
+
+fn image_desc<T: bytemuck::Pod>(
+    pixels: ndarray::Array2<T>,
+    format: wgpu::TextureFormat,
+) -> ImageDataDesc<'static> {
+    let (height, width) = pixels.dim();
+    ImageDataDesc {
+        label: "tensor_projection".into(),
+        data: bytemuck::pod_collect_to_vec(&pixels.iter().copied().collect::<Vec<_>>()).into(),
+        format: format.into(),
+        width_height: [width as u32, height as u32],
+    }
+}
+
 fn upload_texture_slice_to_gpu(
     render_ctx: &re_renderer::RenderContext,
     tensor_data_row_id: RowId,
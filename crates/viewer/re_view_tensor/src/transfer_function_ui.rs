@@ -0,0 +1,142 @@
+use egui::{NumExt as _, Sense, pos2};
+
+use crate::projection::TransferFunction;
+
+const HEIGHT: f32 = 80.0;
+const POINT_RADIUS: f32 = 4.0;
+
+/// Editor for the opacity curve of a [`TransferFunction`], drawn over the colormap.
+///
+/// Drag points to move them, double click to add a point, and right click a point to remove it.
+/// Returns `true` if the transfer function changed.
+pub fn transfer_function_ui(
+    ui: &mut egui::Ui,
+    transfer_function: &mut TransferFunction,
+    colormap: re_renderer::Colormap,
+) -> bool {
+    let (rect, response) = ui.allocate_exact_size(
+        egui::vec2(ui.available_width().at_least(64.0), HEIGHT),
+        Sense::click_and_drag(),
+    );
+    let painter = ui.painter_at(rect);
+
+    let to_screen = |[value, opacity]: [f32; 2]| {
+        pos2(
+            egui::lerp(rect.x_range(), value),
+            egui::lerp(rect.bottom()..=rect.top(), opacity),
+        )
+    };
+    let from_screen = |pos: egui::Pos2| {
+        [
+            egui::remap_clamp(pos.x, rect.x_range(), 0.0..=1.0),
+            egui::remap_clamp(pos.y, rect.bottom()..=rect.top(), 0.0..=1.0),
+        ]
+    };
+
+    // The colormap, dimmed where the volume is transparent.
+    const NUM_STEPS: usize = 64;
+    for step in 0..NUM_STEPS {
+        let value = (step as f32 + 0.5) / NUM_STEPS as f32;
+        let [r, g, b, _] = re_renderer::colormap_srgb(colormap, value);
+        let alpha = (64.0 + 191.0 * transfer_function.opacity(value)) as u8;
+        painter.rect_filled(
+            egui::Rect::from_x_y_ranges(
+                egui::lerp(rect.x_range(), step as f32 / NUM_STEPS as f32)
+                    ..=egui::lerp(rect.x_range(), (step + 1) as f32 / NUM_STEPS as f32),
+                rect.y_range(),
+            ),
+            0.0,
+            egui::Color32::from_rgba_unmultiplied(r, g, b, alpha),
+        );
+    }
+
+    let hovered_point = response.hover_pos().and_then(|hover_pos| {
+        transfer_function
+            .points
+            .iter()
+            .position(|point| to_screen(*point).distance(hover_pos) < 2.0 * POINT_RADIUS)
+    });
+
+    let dragged_point_id = response.id.with("dragged_point");
+    if response.drag_started() {
+        ui.data_mut(|data| data.insert_temp(dragged_point_id, hovered_point));
+    }
+    let dragged_point = ui
+        .data(|data| data.get_temp::<Option<usize>>(dragged_point_id))
+        .flatten();
+
+    let mut changed = false;
+
+    if let (Some(index), Some(pointer_pos)) = (dragged_point, response.interact_pointer_pos())
+        && response.dragged()
+    {
+        let num_points = transfer_function.points.len();
+        let [mut value, opacity] = from_screen(pointer_pos);
+        if index == 0 {
+            value = 0.0;
+        } else if index + 1 == num_points {
+            value = 1.0;
+        } else {
+            value = value.clamp(
+                transfer_function.points[index - 1][0],
+                transfer_function.points[index + 1][0],
+            );
+        }
+        transfer_function.points[index] = [value, opacity];
+        changed = true;
+    }
+    if response.drag_stopped() {
+        ui.data_mut(|data| data.remove::<Option<usize>>(dragged_point_id));
+    }
+
+    if response.secondary_clicked()
+        && let Some(index) = hovered_point
+        && 0 < index
+        && index + 1 < transfer_function.points.len()
+    {
+        transfer_function.points.remove(index);
+        changed = true;
+    } else if response.double_clicked()
+        && hovered_point.is_none()
+        && let Some(pointer_pos) = response.interact_pointer_pos()
+    {
+        let point = from_screen(pointer_pos);
+        let index = transfer_function
+            .points
+            .partition_point(|[value, _]| *value < point[0])
+            .clamp(1, transfer_function.points.len().saturating_sub(1));
+        transfer_function.points.insert(index, point);
+        changed = true;
+    }
+
+    let stroke = ui.visuals().widgets.active.fg_stroke;
+    painter.add(egui::Shape::line(
+        transfer_function
+            .points
+            .iter()
+            .copied()
+            .map(to_screen)
+            .collect(),
+        stroke,
+    ));
+    for (index, point) in transfer_function.points.iter().enumerate() {
+        let radius = if Some(index) == hovered_point || Some(index) == dragged_point {
+            1.5 * POINT_RADIUS
+        } else {
+            POINT_RADIUS
+        };
+        painter.circle(
+            to_screen(*point),
+            radius,
+            ui.visuals().widgets.active.bg_fill,
+            stroke,
+        );
+    }
+
+    response.on_hover_text(
+        "Opacity by value. Drag points to move them, double click to add a point, \
+        and right click a point to remove it.",
+    );
+
+    changed
+}
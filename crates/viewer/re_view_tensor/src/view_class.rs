@@ -27,7 +27,10 @@ use re_viewport_blueprint::ViewProperty;
 use crate::{
     TensorDimension,
     dimension_mapping::TensorSliceSelection,
+    projection::{TensorProjection, TransferFunction, depth_dimension},
+    slicing_presets::{SlicingPreset, builtin_presets},
     tensor_dimension_mapper::dimension_mapping_ui,
+    transfer_function_ui::transfer_function_ui,
     visualizer_system::{TensorSystem, TensorVisualization},
 };
 
@@ -41,6 +44,18 @@ pub struct ViewTensorState {
     /// Last viewed tensor, copied each frame.
     /// Used for the selection view.
     tensor: Option<TensorVisualization>,
+
+    /// How the depth dimension is reduced to the shown image.
+    projection: TensorProjection,
+
+    /// Opacities used by [`TensorProjection::Volume`].
+    transfer_function: TransferFunction,
+
+    /// Dimension mappings saved by the user, in addition to the built-in ones.
+    presets: Vec<SlicingPreset>,
+
+    /// Name for the next saved preset.
+    new_preset_name: String,
 }
 
 impl ViewState for ViewTensorState {
@@ -72,7 +87,10 @@ impl ViewClass for TensorView {
             .markdown(
                 "An N-dimensional tensor displayed as a 2D slice with a custom colormap.
 
-Set the displayed dimensions in a selection panel.",
+Set the displayed dimensions in a selection panel.
+
+Volumes can also be shown as a maximum intensity projection, or rendered with a transfer function \
+that maps values to opacities.",
             )
     }
 
@@ -143,14 +161,27 @@ Set the displayed dimensions in a selection panel.",
             }
         });
 
-        list_item::list_item_scope(ui, "tensor_selection_ui", |ui| {
+        let colormap = list_item::list_item_scope(ui, "tensor_selection_ui", |ui| {
             let ctx = self.view_context(ctx, view_id, state);
             view_property_ui::<TensorScalarMapping>(&ctx, ui, self);
             view_property_ui::<TensorViewFit>(&ctx, ui, self);
-        });
+
+            ViewProperty::from_archetype::<TensorScalarMapping>(
+                ctx.blueprint_db(),
+                ctx.blueprint_query(),
+                view_id,
+            )
+            .component_or_fallback::<Colormap>(
+                &ctx,
+                self,
+                &TensorScalarMapping::descriptor_colormap(),
+            )
+        })?;
 
         // TODO(#6075): Listitemify
-        if let Some(TensorVisualization { tensor, .. }) = &state.tensor {
+        // Cloned (cheaply) so that the presets and projection can be edited in the state.
+        if let Some(TensorVisualization { tensor, .. }) = state.tensor.clone() {
+            let tensor = &tensor;
             let slice_property = ViewProperty::from_archetype::<
                 re_types::blueprint::archetypes::TensorSliceSelection,
             >(ctx.blueprint_db(), ctx.blueprint_query, view_id);
@@ -189,6 +220,14 @@ Set the displayed dimensions in a selection panel.",
             {
                 slice_property.reset_all_components_to_empty(ctx);
             }
+
+            ui.separator();
+            ui.strong("Slicing presets");
+            slicing_presets_ui(ctx, ui, state, tensor, &slice_selection, &slice_property);
+
+            ui.separator();
+            ui.strong("Projection");
+            projection_ui(ui, state, tensor, &slice_selection, colormap);
         }
 
         Ok(())
@@ -390,13 +429,15 @@ impl TensorView {
             colormap,
             value_range: [data_range.start() as f32, data_range.end() as f32],
         };
-        let colormapped_texture = super::tensor_slice_to_gpu::colormapped_texture(
+        let colormapped_texture = super::tensor_slice_to_gpu::projected_texture(
             ctx.render_ctx(),
             *tensor_row_id,
             tensor,
             slice_selection,
+            state.projection,
             &colormap,
             gamma,
+            &state.transfer_function,
         )?;
         let [width, height] = colormapped_texture.width_height();
 
@@ -636,6 +677,110 @@ fn paint_axis_names(
     }
 }
 
+fn slicing_presets_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    state: &mut ViewTensorState,
+    tensor: &TensorData,
+    slice_selection: &TensorSliceSelection,
+    slice_property: &ViewProperty,
+) {
+    let builtin = builtin_presets(&TensorDimension::from_tensor_data(tensor));
+    if builtin.is_empty() && state.presets.is_empty() {
+        ui.weak("Presets are available for tensors with at least three dimensions");
+    }
+
+    let mut removed_preset = None;
+    ui.horizontal_wrapped(|ui| {
+        for preset in &builtin {
+            if ui
+                .selectable_label(preset.is_selected(slice_selection), &preset.name)
+                .clicked()
+            {
+                preset.apply(ctx, slice_property);
+            }
+        }
+
+        for (index, preset) in state.presets.iter().enumerate() {
+            let response = ui
+                .selectable_label(preset.is_selected(slice_selection), &preset.name)
+                .on_hover_text("Right click to remove");
+            if response.clicked() {
+                preset.apply(ctx, slice_property);
+            }
+            if response.secondary_clicked() {
+                removed_preset = Some(index);
+            }
+        }
+    });
+    if let Some(index) = removed_preset {
+        state.presets.remove(index);
+    }
+
+    ui.horizontal(|ui| {
+        ui.add(
+            egui::TextEdit::singleline(&mut state.new_preset_name)
+                .hint_text("Preset name")
+                .desired_width(120.0),
+        );
+        let preset = (!state.new_preset_name.is_empty())
+            .then(|| SlicingPreset::from_selection(state.new_preset_name.clone(), slice_selection))
+            .flatten();
+        if ui
+            .add_enabled(preset.is_some(), egui::Button::new("Save current"))
+            .on_hover_text("Save the current dimension mapping as a preset")
+            .on_disabled_hover_text("Enter a name, and map both width and height to dimensions")
+            .clicked()
+            && let Some(preset) = preset
+        {
+            state.presets.retain(|other| other.name != preset.name);
+            state.presets.push(preset);
+            state.new_preset_name.clear();
+        }
+    });
+}
+
+fn projection_ui(
+    ui: &mut egui::Ui,
+    state: &mut ViewTensorState,
+    tensor: &TensorData,
+    slice_selection: &TensorSliceSelection,
+    colormap: Colormap,
+) {
+    let Some(depth) = depth_dimension(slice_selection).filter(|_| tensor.shape.len() >= 3) else {
+        ui.weak("Projections are available for tensors with at least three dimensions");
+        return;
+    };
+
+    ui.horizontal(|ui| {
+        egui::ComboBox::from_id_salt("tensor_projection")
+            .selected_text(state.projection.label())
+            .show_ui(ui, |ui| {
+                for projection in TensorProjection::ALL {
+                    ui.selectable_value(&mut state.projection, projection, projection.label());
+                }
+            });
+        if state.projection != TensorProjection::Slice {
+            ui.label(format!(
+                "along {}",
+                dimension_name(&TensorDimension::from_tensor_data(tensor), depth)
+            ))
+            .on_hover_text("The dimension of the first slider is projected");
+        }
+    });
+
+    if state.projection == TensorProjection::Volume {
+        transfer_function_ui(
+            ui,
+            &mut state.transfer_function,
+            gpu_bridge::colormap_to_re_renderer(colormap),
+        );
+        if ui.button("Reset transfer function").clicked() {
+            state.transfer_function = TransferFunction::default();
+        }
+    }
+}
+
 pub fn index_for_dimension_mut(
     indices: &mut [TensorDimensionIndexSelection],
     dimension: u32,
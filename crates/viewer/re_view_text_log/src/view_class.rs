@@ -1,9 +1,12 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
+use itertools::Itertools as _;
+
+use re_chunk_store::LatestAtQuery;
 use re_data_ui::item_ui;
 use re_log_types::{EntityPath, TimelineName};
 use re_types::View as _;
-use re_types::{ViewClassIdentifier, components::TextLogLevel};
+use re_types::{ComponentDescriptor, ViewClassIdentifier, components::TextLogLevel};
 use re_ui::{DesignTokens, Help, UiExt as _};
 use re_viewer_context::{
     IdentifiedViewSystem as _, ViewClass, ViewClassRegistryError, ViewId, ViewQuery,
@@ -14,7 +17,7 @@ use re_viewer_context::{
 use super::visualizer_system::{Entry, TextLogSystem};
 
 // TODO(andreas): This should be a blueprint component.
-#[derive(Clone, PartialEq, Eq, Default)]
+#[derive(Clone, Default)]
 pub struct TextViewState {
     /// Keeps track of the latest time selection made by the user.
     ///
@@ -25,6 +28,47 @@ pub struct TextViewState {
     pub filters: ViewTextFilters,
 
     monospace: bool,
+
+    /// Keep scrolling to the newest entry, instead of the entry at the time cursor.
+    follow: bool,
+
+    /// [`ViewTextFilters::row_body_regex`], compiled.
+    body_regex: Option<(String, Result<regex_lite::Regex, String>)>,
+}
+
+impl TextViewState {
+    /// Compiles the body filter, if it changed.
+    fn update_body_regex(&mut self) {
+        let pattern = &self.filters.row_body_regex;
+        if self
+            .body_regex
+            .as_ref()
+            .is_some_and(|(compiled_pattern, _)| compiled_pattern == pattern)
+        {
+            return;
+        }
+
+        self.body_regex = (!pattern.is_empty()).then(|| {
+            (
+                pattern.clone(),
+                regex_lite::Regex::new(pattern).map_err(|err| err.to_string()),
+            )
+        });
+    }
+
+    fn body_regex(&self) -> Option<&regex_lite::Regex> {
+        match &self.body_regex {
+            Some((_, Ok(regex))) => Some(regex),
+            Some((_, Err(_))) | None => None,
+        }
+    }
+
+    fn body_regex_error(&self) -> Option<&str> {
+        match &self.body_regex {
+            Some((_, Err(err))) => Some(err),
+            Some((_, Ok(_))) | None => None,
+        }
+    }
 }
 
 impl ViewState for TextViewState {
@@ -61,7 +105,11 @@ impl ViewClass for TextView {
             .markdown(
                 "TextLog entries over time.
 
-Filter message types and toggle column visibility in a selection panel.",
+Filter message types and toggle column visibility in a selection panel.
+
+Entries can also be filtered by entity, by a regular expression on their body, \
+and by the values of other components logged on the same entity. \
+Enable \"Follow\" to keep the newest entry in view.",
             )
     }
 
@@ -119,6 +167,9 @@ Filter message types and toggle column visibility in a selection panel.",
             col_entity_path,
             col_log_level,
             row_log_levels,
+            row_entity_path,
+            row_body_regex,
+            row_component_values,
         } = &mut state.filters;
 
         ui.selection_grid("log_config").show(ui, |ui| {
@@ -140,6 +191,31 @@ Filter message types and toggle column visibility in a selection panel.",
             });
             ui.end_row();
 
+            ui.grid_left_hand_label("Entity Filter");
+            ui.add(
+                egui::TextEdit::singleline(row_entity_path).hint_text("Part of the entity path"),
+            );
+            ui.end_row();
+
+            ui.grid_left_hand_label("Body Filter");
+            ui.add(egui::TextEdit::singleline(row_body_regex).hint_text("Regular expression"));
+            ui.end_row();
+
+            ui.grid_left_hand_label("Component Filters");
+            ui.vertical(|ui| {
+                component_filters_ui(ui, row_component_values);
+            })
+            .response
+            .on_hover_text(
+                "Only show entries where a component logged on the same entity, \
+                e.g. one logged with `rr.AnyValues`, contains the given text",
+            );
+            ui.end_row();
+
+            ui.grid_left_hand_label("Follow");
+            ui.re_checkbox(&mut state.follow, "Scroll to the newest entry");
+            ui.end_row();
+
             ui.grid_left_hand_label("Text style");
             ui.vertical(|ui| {
                 ui.re_radio_value(&mut state.monospace, false, "Proportional");
@@ -157,7 +233,7 @@ Filter message types and toggle column visibility in a selection panel.",
         ui: &mut egui::Ui,
         state: &mut dyn ViewState,
 
-        _query: &ViewQuery<'_>,
+        query: &ViewQuery<'_>,
         system_output: re_viewer_context::SystemExecutionOutput,
     ) -> Result<(), ViewSystemExecutionError> {
         re_tracing::profile_function!();
@@ -166,17 +242,28 @@ Filter message types and toggle column visibility in a selection panel.",
         let state = state.downcast_mut::<TextViewState>()?;
         let text = system_output.view_systems.get::<TextLogSystem>()?;
 
+        state.update_body_regex();
+
         // TODO(andreas): Should filter text entries in the part-system instead.
         // this likely requires a way to pass state into a context.
-        let entries = text
-            .entries
-            .iter()
-            .filter(|te| {
-                te.level
-                    .as_ref()
-                    .is_none_or(|lvl| state.filters.is_log_level_visible(lvl))
-            })
-            .collect::<Vec<_>>();
+        let mut entries = {
+            re_tracing::profile_scope!("filter");
+
+            let body_regex = state.body_regex();
+            text.entries
+                .iter()
+                .filter(|te| {
+                    te.level
+                        .as_ref()
+                        .is_none_or(|lvl| state.filters.is_log_level_visible(lvl))
+                        && state.filters.is_entity_path_visible(&te.entity_path)
+                        && body_regex.is_none_or(|regex| regex.is_match(te.body.as_str()))
+                })
+                .collect::<Vec<_>>()
+        };
+        state
+            .filters
+            .retain_component_values(ctx, query.timeline, &mut entries);
 
         egui::Frame {
             inner_margin: tokens.view_padding().into(),
@@ -186,6 +273,8 @@ Filter message types and toggle column visibility in a selection panel.",
             // Update filters if necessary.
             state.filters.update(ctx, &entries);
 
+            toolbar_ui(ui, state, entries.len(), text.entries.len());
+
             let time = ctx
                 .rec_cfg
                 .time_ctrl
@@ -196,11 +285,22 @@ Filter message types and toggle column visibility in a selection panel.",
             // Did the time cursor move since last time?
             // - If it did, autoscroll to the text log to reveal the current time.
             // - Otherwise, let the user scroll around freely!
+            // When following, always reveal the newest entry instead.
             let time_cursor_moved = state.latest_time != time;
-            let scroll_to_row = time_cursor_moved.then(|| {
-                re_tracing::profile_scope!("search scroll time");
-                entries.partition_point(|te| te.time.as_i64() < time)
-            });
+            let scroll_to_row = if state.follow {
+                entries
+                    .len()
+                    .checked_sub(1)
+                    .map(|last_row| (last_row, egui::Align::BOTTOM))
+            } else {
+                time_cursor_moved.then(|| {
+                    re_tracing::profile_scope!("search scroll time");
+                    (
+                        entries.partition_point(|te| te.time.as_i64() < time),
+                        egui::Align::Center,
+                    )
+                })
+            };
 
             state.latest_time = time;
 
@@ -216,9 +316,77 @@ Filter message types and toggle column visibility in a selection panel.",
     }
 }
 
+fn toolbar_ui(
+    ui: &mut egui::Ui,
+    state: &mut TextViewState,
+    num_shown_entries: usize,
+    num_entries: usize,
+) {
+    ui.horizontal(|ui| {
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut state.filters.row_body_regex)
+                .hint_text("Filter body (regular expression)")
+                .desired_width(240.0),
+        );
+        if let Some(err) = state.body_regex_error() {
+            response.on_hover_text(err);
+            ui.error_label("Invalid regular expression");
+        }
+
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            ui.re_checkbox(&mut state.follow, "Follow")
+                .on_hover_text("Keep scrolling to the newest entry");
+
+            if num_shown_entries == num_entries {
+                ui.weak(format!("{} entries", re_format::format_uint(num_entries)));
+            } else {
+                ui.weak(format!(
+                    "{} of {} entries",
+                    re_format::format_uint(num_shown_entries),
+                    re_format::format_uint(num_entries)
+                ));
+            }
+        });
+    });
+}
+
+fn component_filters_ui(ui: &mut egui::Ui, filters: &mut Vec<ComponentValueFilter>) {
+    let mut removed_filter = None;
+    for (index, filter) in filters.iter_mut().enumerate() {
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut filter.component)
+                    .hint_text("Component")
+                    .desired_width(80.0),
+            );
+            ui.label("contains");
+            ui.add(
+                egui::TextEdit::singleline(&mut filter.value)
+                    .hint_text("Value")
+                    .desired_width(80.0),
+            );
+            if ui
+                .small_icon_button(&re_ui::icons::REMOVE, "Remove filter")
+                .clicked()
+            {
+                removed_filter = Some(index);
+            }
+        });
+    }
+    if let Some(index) = removed_filter {
+        filters.remove(index);
+    }
+
+    if ui
+        .small_icon_button(&re_ui::icons::ADD, "Add component filter")
+        .clicked()
+    {
+        filters.push(ComponentValueFilter::default());
+    }
+}
+
 // --- Filters ---
 
-// TODO(cmc): implement "body contains <value>" filter.
 // TODO(cmc): beyond filters, it'd be nice to be able to swap columns at some point.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ViewTextFilters {
@@ -230,6 +398,38 @@ pub struct ViewTextFilters {
 
     // Row filters: which rows should be visible?
     pub row_log_levels: BTreeMap<TextLogLevel, bool>,
+
+    /// Only show entities whose path contains this.
+    pub row_entity_path: String,
+
+    /// Only show entries whose body matches this regular expression.
+    pub row_body_regex: String,
+
+    pub row_component_values: Vec<ComponentValueFilter>,
+}
+
+/// Only show entries where the value of a component logged on the same entity contains some text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComponentValueFilter {
+    /// Name of the component, e.g. `node` for `rr.AnyValues(node=…)`.
+    pub component: String,
+
+    /// Text that the formatted value has to contain.
+    pub value: String,
+}
+
+impl ComponentValueFilter {
+    fn is_active(&self) -> bool {
+        !self.component.is_empty() && !self.value.is_empty()
+    }
+
+    fn matches_descriptor(&self, descr: &ComponentDescriptor) -> bool {
+        let component = descr.component.as_str();
+        component.eq_ignore_ascii_case(&self.component)
+            || component
+                .rsplit_once(':')
+                .is_some_and(|(_, field)| field.eq_ignore_ascii_case(&self.component))
+    }
 }
 
 impl Default for ViewTextFilters {
@@ -239,6 +439,9 @@ impl Default for ViewTextFilters {
             col_log_level: true,
             col_timelines: Default::default(),
             row_log_levels: Default::default(),
+            row_entity_path: String::new(),
+            row_body_regex: String::new(),
+            row_component_values: Vec::new(),
         }
     }
 }
@@ -248,6 +451,52 @@ impl ViewTextFilters {
         self.row_log_levels.get(level).copied().unwrap_or(true)
     }
 
+    pub fn is_entity_path_visible(&self, entity_path: &EntityPath) -> bool {
+        self.row_entity_path.is_empty() || entity_path.to_string().contains(&self.row_entity_path)
+    }
+
+    /// Removes the entries that don't match the component value filters.
+    ///
+    /// Values are the latest ones at the time of each entry on `timeline`,
+    /// which includes values logged in the same call.
+    fn retain_component_values(
+        &self,
+        ctx: &ViewerContext<'_>,
+        timeline: TimelineName,
+        entries: &mut Vec<&Entry>,
+    ) {
+        for filter in self.row_component_values.iter().filter(|f| f.is_active()) {
+            re_tracing::profile_scope!("component filter");
+
+            // The component matching the filter, per entity.
+            let mut descriptors: HashMap<EntityPath, Option<ComponentDescriptor>> =
+                HashMap::default();
+
+            entries.retain(|entry| {
+                let descr = descriptors
+                    .entry(entry.entity_path.clone())
+                    .or_insert_with(|| {
+                        ctx.recording()
+                            .storage_engine()
+                            .store()
+                            .all_components_for_entity(&entry.entity_path)?
+                            .into_iter()
+                            .find(|descr| filter.matches_descriptor(descr))
+                    });
+                let Some(descr) = descr.as_ref() else {
+                    return false;
+                };
+
+                let query = LatestAtQuery::new(timeline, entry.time);
+                ctx.recording()
+                    .latest_at(&query, &entry.entity_path, [descr])
+                    .component_batch_raw(descr)
+                    .and_then(|array| format_array(array.as_ref()))
+                    .is_some_and(|value| value.contains(&filter.value))
+            });
+        }
+    }
+
     // Checks whether new values are available for any of the filters, and updates everything
     // accordingly.
     fn update(&mut self, ctx: &ViewerContext<'_>, entries: &[&Entry]) {
@@ -258,6 +507,9 @@ impl ViewTextFilters {
             col_entity_path: _,
             col_log_level: _,
             row_log_levels,
+            row_entity_path: _,
+            row_body_regex: _,
+            row_component_values: _,
         } = self;
 
         for &timeline in ctx.recording().timelines().keys() {
@@ -270,6 +522,17 @@ impl ViewTextFilters {
     }
 }
 
+/// All values of a component batch, comma-separated.
+fn format_array(array: &dyn arrow::array::Array) -> Option<String> {
+    let formatter =
+        arrow::util::display::ArrayFormatter::try_new(array, &Default::default()).ok()?;
+    Some(
+        (0..array.len())
+            .map(|index| formatter.value(index).to_string())
+            .join(", "),
+    )
+}
+
 // ---
 
 /// `scroll_to_row` indicates how far down we want to scroll in terms of logical rows,
@@ -280,7 +543,7 @@ fn table_ui(
     ui: &mut egui::Ui,
     state: &TextViewState,
     entries: &[&Entry],
-    scroll_to_row: Option<usize>,
+    scroll_to_row: Option<(usize, egui::Align)>,
 ) {
     let tokens = ui.tokens();
     let table_style = re_ui::TableStyle::Dense;
@@ -307,8 +570,8 @@ fn table_ui(
         .max_scroll_height(f32::INFINITY) // Fill up whole height
         .cell_layout(egui::Layout::left_to_right(egui::Align::Center));
 
-    if let Some(scroll_to_row) = scroll_to_row {
-        table_builder = table_builder.scroll_to_row(scroll_to_row, Some(egui::Align::Center));
+    if let Some((scroll_to_row, align)) = scroll_to_row {
+        table_builder = table_builder.scroll_to_row(scroll_to_row, Some(align));
     }
 
     let mut body_clip_rect = None;
@@ -6,10 +6,14 @@
 
 mod aggregation;
 mod line_visualizer_system;
+mod overlays;
 mod point_visualizer_system;
+mod rotation_visualizer_system;
+mod series_lines;
 mod series_query;
 mod util;
 mod view_class;
+mod waveform_visualizer_system;
 
 use re_types::components::{AggregationPolicy, MarkerShape};
 use re_viewer_context::external::re_entity_db::InstancePath;
@@ -87,6 +91,9 @@ pub struct PlotSeries {
     /// Label of the series.
     pub label: String,
 
+    /// Unit of the series' values, if any was logged.
+    pub unit: Option<String>,
+
     pub color: egui::Color32,
 
     /// Radius of markers, or stroke radius for lines.
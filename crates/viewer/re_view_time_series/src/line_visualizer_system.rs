@@ -5,7 +5,7 @@ use re_log_types::{EntityPath, TimeInt};
 use re_types::{
     Archetype as _,
     archetypes::{self},
-    components::{AggregationPolicy, Color, Name, SeriesVisible, StrokeWidth},
+    components::{AggregationPolicy, Color, StrokeWidth},
 };
 use re_view::{
     RangeResultsExt as _, latest_at_with_blueprint_resolved_data,
@@ -13,14 +13,14 @@ use re_view::{
 };
 use re_viewer_context::external::re_entity_db::InstancePath;
 use re_viewer_context::{
-    IdentifiedViewSystem, QueryContext, TypedComponentFallbackProvider, ViewContext, ViewQuery,
+    IdentifiedViewSystem, TypedComponentFallbackProvider as _, ViewContext, ViewQuery,
     ViewStateExt as _, ViewSystemExecutionError, VisualizerQueryInfo, VisualizerSystem,
-    auto_color_for_entity_path,
 };
 
+use crate::series_lines::impl_series_lines_fallbacks;
 use crate::series_query::{
     allocate_plot_points, collect_colors, collect_radius_ui, collect_scalars, collect_series_name,
-    collect_series_visibility, determine_num_series,
+    collect_series_units, collect_series_visibility, determine_num_series,
 };
 use crate::util::{determine_time_per_pixel, determine_time_range, points_to_series};
 use crate::view_class::TimeSeriesViewState;
@@ -38,8 +38,6 @@ impl IdentifiedViewSystem for SeriesLinesSystem {
     }
 }
 
-const DEFAULT_STROKE_WIDTH: f32 = 0.75;
-
 impl VisualizerSystem for SeriesLinesSystem {
     fn visualizer_query_info(&self) -> VisualizerQueryInfo {
         let mut query_info = VisualizerQueryInfo::from_archetype::<archetypes::Scalars>();
@@ -73,46 +71,7 @@ impl VisualizerSystem for SeriesLinesSystem {
     }
 }
 
-impl TypedComponentFallbackProvider<Color> for SeriesLinesSystem {
-    fn fallback_for(&self, ctx: &QueryContext<'_>) -> Color {
-        auto_color_for_entity_path(ctx.target_entity_path)
-    }
-}
-
-impl TypedComponentFallbackProvider<StrokeWidth> for SeriesLinesSystem {
-    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> StrokeWidth {
-        StrokeWidth(DEFAULT_STROKE_WIDTH.into())
-    }
-}
-
-impl TypedComponentFallbackProvider<Name> for SeriesLinesSystem {
-    fn fallback_for(&self, ctx: &QueryContext<'_>) -> Name {
-        let state = ctx.view_state().downcast_ref::<TimeSeriesViewState>();
-
-        state
-            .ok()
-            .and_then(|state| {
-                state
-                    .default_names_for_entities
-                    .get(ctx.target_entity_path)
-                    .map(|name| name.clone().into())
-            })
-            .or_else(|| {
-                ctx.target_entity_path
-                    .last()
-                    .map(|part| part.ui_string().into())
-            })
-            .unwrap_or_default()
-    }
-}
-
-impl TypedComponentFallbackProvider<SeriesVisible> for SeriesLinesSystem {
-    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> SeriesVisible {
-        true.into()
-    }
-}
-
-re_viewer_context::impl_component_fallback_provider!(SeriesLinesSystem => [Color, StrokeWidth, Name, SeriesVisible]);
+impl_series_lines_fallbacks!(SeriesLinesSystem);
 
 impl SeriesLinesSystem {
     fn load_scalars(&mut self, ctx: &ViewContext<'_>, query: &ViewQuery<'_>) {
@@ -241,12 +200,15 @@ impl SeriesLinesSystem {
             //   though: you don't want your plot to change color depending on what the currently
             //   visible time range is! Secondary components have to be bootstrapped.
             let query_shadowed_components = false;
+            let units_descr = archetypes::Scalars::descriptor_units();
             let bootstrapped_results = latest_at_with_blueprint_resolved_data(
                 ctx,
                 None,
                 &LatestAtQuery::new(query.timeline, query.range.min()),
                 data_result,
-                archetypes::SeriesLines::all_components().iter(),
+                archetypes::SeriesLines::all_components()
+                    .iter()
+                    .chain(std::iter::once(&units_descr)),
                 query_shadowed_components,
             );
 
@@ -311,7 +273,11 @@ impl SeriesLinesSystem {
 
                 re_tracing::profile_scope!("discontinuities");
 
-                let cleared_indices = collect_recursive_clears(ctx, &query, entity_path);
+                let cleared_indices = collect_recursive_clears(
+                    ctx,
+                    &data_result.time_alignment().range_query(&query),
+                    entity_path,
+                );
                 let has_discontinuities = !cleared_indices.is_empty();
 
                 for points in &mut points_per_series {
@@ -353,11 +319,14 @@ impl SeriesLinesSystem {
                 num_series,
                 &archetypes::SeriesLines::descriptor_names(),
             );
+            let series_units =
+                collect_series_units(&bootstrapped_results, &results, num_series, &units_descr);
 
             debug_assert_eq!(points_per_series.len(), series_names.len());
-            for (instance, (points, label, visible)) in itertools::izip!(
+            for (instance, (points, label, unit, visible)) in itertools::izip!(
                 points_per_series.into_iter(),
                 series_names.into_iter(),
+                series_units.into_iter(),
                 series_visibility.into_iter()
             )
             .enumerate()
@@ -376,9 +345,11 @@ impl SeriesLinesSystem {
                     time_per_pixel,
                     visible,
                     points,
+                    data_result.time_alignment(),
                     ctx.recording_engine().store(),
                     view_query,
                     label,
+                    unit,
                     aggregator,
                     all_series,
                 );
@@ -0,0 +1,358 @@
+//! Derived overlays of plot series: rolling statistics, min/max envelopes and spectra.
+//!
+//! Overlays are computed from the points of the series, and extended rather than recomputed
+//! when new points are appended to a series as data streams in.
+
+use std::collections::VecDeque;
+
+/// Which overlays are shown in a time series view.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OverlaySettings {
+    /// Show the rolling mean of each series.
+    pub rolling_mean: bool,
+
+    /// Show a band of one standard deviation around the rolling mean.
+    pub rolling_stddev: bool,
+
+    /// Show the rolling minimum and maximum of each series.
+    pub min_max_envelope: bool,
+
+    /// Number of points the rolling statistics are computed over.
+    pub window: usize,
+
+    /// Show the frequency spectra of the series instead of their values over time.
+    pub spectrum: bool,
+}
+
+impl Default for OverlaySettings {
+    fn default() -> Self {
+        Self {
+            rolling_mean: false,
+            rolling_stddev: false,
+            min_max_envelope: false,
+            window: 32,
+            spectrum: false,
+        }
+    }
+}
+
+impl OverlaySettings {
+    pub fn any_rolling(&self) -> bool {
+        self.rolling_mean || self.rolling_stddev || self.min_max_envelope
+    }
+}
+
+/// Rolling statistics of a series, over the `window` points up to and including each point.
+#[derive(Clone, Debug, Default)]
+pub struct RollingStats {
+    window: usize,
+
+    /// The first and last point the statistics were computed from, to detect appended points.
+    first: Option<(i64, f64)>,
+    last: Option<(i64, f64)>,
+
+    /// Prefix sums of the values and of their squares, one more than there are points.
+    sum: Vec<f64>,
+    sum_of_squares: Vec<f64>,
+
+    pub mean: Vec<f64>,
+    pub stddev: Vec<f64>,
+    pub min: Vec<f64>,
+    pub max: Vec<f64>,
+}
+
+impl RollingStats {
+    /// Number of points the statistics were computed for.
+    #[inline]
+    fn len(&self) -> usize {
+        self.mean.len()
+    }
+
+    /// Brings the statistics up to date with `points`.
+    ///
+    /// If `points` only had points appended since the last update, only those are computed.
+    pub fn update(&mut self, points: &[(i64, f64)], window: usize) {
+        re_tracing::profile_function!();
+
+        let window = window.max(1);
+        let len = self.len();
+        let is_appended = self.window == window
+            && len <= points.len()
+            && points.first().copied() == self.first
+            && len
+                .checked_sub(1)
+                .and_then(|last_index| points.get(last_index))
+                .copied()
+                == self.last;
+
+        if !is_appended {
+            *self = Self {
+                window,
+                sum: vec![0.0],
+                sum_of_squares: vec![0.0],
+                ..Default::default()
+            };
+        }
+
+        let start = self.len();
+        if start == points.len() {
+            return;
+        }
+
+        // Monotonic queues of indices, whose values are increasing for the minimum,
+        // and decreasing for the maximum: their fronts are the extrema of the window.
+        let mut min_queue = VecDeque::new();
+        let mut max_queue = VecDeque::new();
+        let (first_in_window, _) = window_range(start, window);
+        for (index, &(_, value)) in points.iter().enumerate().skip(first_in_window) {
+            while min_queue
+                .back()
+                .is_some_and(|&back: &usize| points[back].1 >= value)
+            {
+                min_queue.pop_back();
+            }
+            min_queue.push_back(index);
+
+            while max_queue
+                .back()
+                .is_some_and(|&back: &usize| points[back].1 <= value)
+            {
+                max_queue.pop_back();
+            }
+            max_queue.push_back(index);
+
+            if index < start {
+                continue;
+            }
+
+            let (window_start, window_len) = window_range(index, window);
+            while min_queue.front().is_some_and(|&front| front < window_start) {
+                min_queue.pop_front();
+            }
+            while max_queue.front().is_some_and(|&front| front < window_start) {
+                max_queue.pop_front();
+            }
+
+            self.sum.push(self.sum[index] + value);
+            self.sum_of_squares
+                .push(self.sum_of_squares[index] + value * value);
+
+            let window_len = window_len as f64;
+            let mean = (self.sum[index + 1] - self.sum[window_start]) / window_len;
+            let mean_of_squares =
+                (self.sum_of_squares[index + 1] - self.sum_of_squares[window_start]) / window_len;
+
+            self.mean.push(mean);
+            // Rounding errors may make the variance slightly negative.
+            self.stddev
+                .push((mean_of_squares - mean * mean).max(0.0).sqrt());
+            self.min
+                .push(min_queue.front().map_or(value, |&front| points[front].1));
+            self.max
+                .push(max_queue.front().map_or(value, |&front| points[front].1));
+        }
+
+        self.first = points.first().copied();
+        self.last = points.last().copied();
+    }
+}
+
+/// The overlays of a single series, kept across frames.
+#[derive(Clone, Default)]
+pub struct SeriesOverlays {
+    pub rolling: RollingStats,
+
+    /// The spectrum, and the number of points and last point of the series it was computed from.
+    spectrum: Option<(usize, Option<(i64, f64)>, Option<Vec<[f64; 2]>>)>,
+}
+
+impl SeriesOverlays {
+    /// The spectrum of `points`, only recomputed if they changed since the last call.
+    pub fn spectrum(
+        &mut self,
+        points: &[(i64, f64)],
+        time_units_per_cycle: f64,
+    ) -> Option<&[[f64; 2]]> {
+        let (len, last) = (points.len(), points.last().copied());
+        if !matches!(&self.spectrum, Some((cached_len, cached_last, _)) if *cached_len == len && *cached_last == last)
+        {
+            self.spectrum = Some((len, last, spectrum(points, time_units_per_cycle)));
+        }
+
+        self.spectrum
+            .as_ref()
+            .and_then(|(_, _, spectrum)| spectrum.as_deref())
+    }
+}
+
+/// The first index and length of the window ending at `index`.
+fn window_range(index: usize, window: usize) -> (usize, usize) {
+    let start = (index + 1).saturating_sub(window);
+    (start, index + 1 - start)
+}
+
+/// Maximum number of points the spectrum of a series is computed from.
+const MAX_SPECTRUM_POINTS: usize = 4096;
+
+/// The magnitude spectrum of a series, as `[frequency, magnitude]` pairs.
+///
+/// The spectrum is computed from the last points of the series (the largest power of two, up to
+/// [`MAX_SPECTRUM_POINTS`]), which are assumed to be evenly spaced in time.
+/// Frequencies are in cycles per `time_units_per_cycle`, e.g. `1e9` for Hertz on nanosecond timelines.
+///
+/// Returns `None` if there are too few points, or if they don't span any time.
+pub fn spectrum(points: &[(i64, f64)], time_units_per_cycle: f64) -> Option<Vec<[f64; 2]>> {
+    re_tracing::profile_function!();
+
+    if points.len() < 4 {
+        return None;
+    }
+
+    let len = if points.len() >= MAX_SPECTRUM_POINTS {
+        MAX_SPECTRUM_POINTS
+    } else {
+        // Largest power of two that fits.
+        1 << (usize::BITS - 1 - points.len().leading_zeros())
+    };
+    let points = &points[points.len() - len..];
+
+    let (first_time, last_time) = (points.first()?.0, points.last()?.0);
+    let sample_spacing = (last_time - first_time) as f64 / (len - 1) as f64;
+    if sample_spacing <= 0.0 {
+        return None;
+    }
+    let sample_rate = time_units_per_cycle / sample_spacing;
+
+    // Remove the DC offset, and apply a Hann window to reduce spectral leakage.
+    let mean = points.iter().map(|(_, value)| value).sum::<f64>() / len as f64;
+    let mut re: Vec<f64> = points
+        .iter()
+        .enumerate()
+        .map(|(i, (_, value))| {
+            let hann = 0.5 - 0.5 * (std::f64::consts::TAU * i as f64 / (len - 1) as f64).cos();
+            (value - mean) * hann
+        })
+        .collect();
+    let mut im = vec![0.0; len];
+    fft(&mut re, &mut im);
+
+    // The Hann window halves the amplitude on average, compensate for it.
+    let scale = 4.0 / len as f64;
+    Some(
+        (1..=len / 2)
+            .map(|k| {
+                let frequency = k as f64 * sample_rate / len as f64;
+                [frequency, re[k].hypot(im[k]) * scale]
+            })
+            .collect(),
+    )
+}
+
+/// In-place iterative radix-2 fast Fourier transform.
+///
+/// The length of `re` and `im` must be the same power of two.
+fn fft(re: &mut [f64], im: &mut [f64]) {
+    let len = re.len();
+    debug_assert!(len.is_power_of_two() && im.len() == len);
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..len {
+        let mut bit = len >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let mut half_size = 1;
+    while half_size < len {
+        let angle = -std::f64::consts::PI / half_size as f64;
+        for start in (0..len).step_by(2 * half_size) {
+            for k in 0..half_size {
+                let (sin, cos) = (angle * k as f64).sin_cos();
+                let (a, b) = (start + k, start + k + half_size);
+                let t_re = re[b] * cos - im[b] * sin;
+                let t_im = re[b] * sin + im[b] * cos;
+                re[b] = re[a] - t_re;
+                im[b] = im[a] - t_im;
+                re[a] += t_re;
+                im[a] += t_im;
+            }
+        }
+        half_size *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn assert_close(expected: &[f64], actual: &[f64]) {
+        assert_eq!(expected.len(), actual.len());
+        for (expected, actual) in expected.iter().zip(actual) {
+            assert!((expected - actual).abs() < 1e-9, "{expected} != {actual}");
+        }
+    }
+
+    #[test]
+    fn rolling_stats_are_extended() {
+        let points: Vec<_> = [1.0, 3.0, 2.0, 6.0, 4.0]
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| (i as i64, value))
+            .collect();
+
+        let mut stats = RollingStats::default();
+        stats.update(&points[..3], 2);
+        assert_close(&[1.0, 2.0, 2.5], &stats.mean);
+
+        stats.update(&points, 2);
+        assert_close(&[1.0, 2.0, 2.5, 4.0, 5.0], &stats.mean);
+        assert_close(&[0.0, 1.0, 0.5, 2.0, 1.0], &stats.stddev);
+        assert_close(&[1.0, 1.0, 2.0, 2.0, 4.0], &stats.min);
+        assert_close(&[1.0, 3.0, 3.0, 6.0, 6.0], &stats.max);
+
+        // Changed points are recomputed from scratch.
+        let mut changed = points.clone();
+        changed[0].1 = 5.0;
+        stats.update(&changed, 3);
+        assert_close(&[5.0, 4.0, 10.0 / 3.0, 11.0 / 3.0, 4.0], &stats.mean);
+        assert_close(&[5.0, 3.0, 2.0, 2.0, 2.0], &stats.min);
+        assert_close(&[5.0, 5.0, 5.0, 6.0, 6.0], &stats.max);
+    }
+
+    #[test]
+    fn spectrum_of_sine() {
+        // 64 samples per second of a 8 Hz sine with an amplitude of 2, over 16 seconds.
+        let points: Vec<_> = (0..1024)
+            .map(|i| {
+                let t = i as f64 / 64.0;
+                (
+                    (t * 1e9) as i64,
+                    1.0 + 2.0 * (std::f64::consts::TAU * 8.0 * t).sin(),
+                )
+            })
+            .collect();
+
+        let spectrum = spectrum(&points, 1e9).unwrap();
+        assert_eq!(512, spectrum.len());
+
+        let [frequency, magnitude] = spectrum
+            .iter()
+            .copied()
+            .max_by(|a, b| a[1].total_cmp(&b[1]))
+            .unwrap();
+        assert!((frequency - 8.0).abs() < 0.1, "{frequency}");
+        assert!((magnitude - 2.0).abs() < 0.1, "{magnitude}");
+
+        assert!(super::spectrum(&points[..3], 1e9).is_none());
+    }
+}
@@ -18,7 +18,8 @@ use crate::{
     PlotPoint, PlotPointAttrs, PlotSeries, PlotSeriesKind, ScatterAttrs,
     series_query::{
         all_scalars_indices, allocate_plot_points, collect_colors, collect_radius_ui,
-        collect_scalars, collect_series_name, collect_series_visibility, determine_num_series,
+        collect_scalars, collect_series_name, collect_series_units, collect_series_visibility,
+        determine_num_series,
     },
     util::{determine_time_per_pixel, determine_time_range, points_to_series},
     view_class::TimeSeriesViewState,
@@ -246,12 +247,15 @@ impl SeriesPointsSystem {
             //   though: you don't want your plot to change color depending on what the currently
             //   visible time range is! Secondary components have to be bootstrapped.
             let query_shadowed_components = false;
+            let units_descr = archetypes::Scalars::descriptor_units();
             let bootstrapped_results = latest_at_with_blueprint_resolved_data(
                 ctx,
                 None,
                 &LatestAtQuery::new(query.timeline, query.range.min()),
                 data_result,
-                archetypes::SeriesPoints::all_components().iter(),
+                archetypes::SeriesPoints::all_components()
+                    .iter()
+                    .chain(std::iter::once(&units_descr)),
                 query_shadowed_components,
             );
 
@@ -390,11 +394,14 @@ impl SeriesPointsSystem {
                 num_series,
                 &archetypes::SeriesPoints::descriptor_names(),
             );
+            let series_units =
+                collect_series_units(&bootstrapped_results, &results, num_series, &units_descr);
 
             debug_assert_eq!(points_per_series.len(), series_names.len());
-            for (instance, (points, label, visible)) in itertools::izip!(
+            for (instance, (points, label, unit, visible)) in itertools::izip!(
                 points_per_series.into_iter(),
                 series_names.into_iter(),
+                series_units.into_iter(),
                 series_visibility.into_iter()
             )
             .enumerate()
@@ -413,9 +420,11 @@ impl SeriesPointsSystem {
                     time_per_pixel,
                     visible,
                     points,
+                    data_result.time_alignment(),
                     ctx.recording_engine().store(),
                     view_query,
                     label,
+                    unit,
                     // Aggregation for points is not supported.
                     re_types::components::AggregationPolicy::Off,
                     all_series,
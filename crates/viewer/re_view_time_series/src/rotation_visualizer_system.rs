@@ -0,0 +1,327 @@
+use std::collections::BTreeMap;
+
+use re_chunk_store::{ChunkStoreEvent, RangeQuery, RowId};
+use re_log_types::TimeInt;
+use re_types::{
+    Archetype as _, ComponentDescriptor,
+    archetypes::{self},
+    components::{EulerConvention, RotationAxisAngle, RotationEuler},
+    datatypes,
+    external::glam,
+};
+use re_view::{HybridRangeResults, RangeResultsExt as _};
+use re_viewer_context::{
+    DataBasedVisualizabilityFilter, IdentifiedViewSystem, ViewContext, ViewQuery,
+    ViewSystemExecutionError, VisualizerQueryInfo, VisualizerSystem,
+};
+
+use crate::series_lines::{SeriesLinesResults, impl_series_lines_fallbacks, visible_range_query};
+use crate::series_query::collect_logged_series_names;
+use crate::util::determine_time_per_pixel;
+use crate::{PlotPoint, PlotSeries};
+
+/// Labels of the extracted series, in instance order.
+const SERIES_LABELS: [&str; 3] = ["roll", "pitch", "yaw"];
+
+/// The system for plotting the rotation of [`archetypes::Transform3D`]s.
+///
+/// Rotations logged as axis-angles, quaternions or Euler angles with any [`EulerConvention`] are
+/// all converted to roll, pitch and yaw (i.e. the `Zyx` convention), so that different entities
+/// can be compared. Like in the 3D views, the rotations logged in the same row are composed.
+#[derive(Default, Debug)]
+pub struct RotationSeriesSystem {
+    pub all_series: Vec<PlotSeries>,
+}
+
+impl IdentifiedViewSystem for RotationSeriesSystem {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "RotationSeries".into()
+    }
+}
+
+/// The components a rotation can be logged with, in the order they are composed in.
+fn rotation_descriptors() -> [ComponentDescriptor; 3] {
+    [
+        archetypes::Transform3D::descriptor_rotation_axis_angle(),
+        archetypes::Transform3D::descriptor_quaternion(),
+        archetypes::Transform3D::descriptor_rotation_euler(),
+    ]
+}
+
+/// Any of the rotation components makes an entity visualizable, but neither is required on its own.
+struct RotationVisualizabilityFilter {
+    rotation_descriptors: [ComponentDescriptor; 3],
+}
+
+impl DataBasedVisualizabilityFilter for RotationVisualizabilityFilter {
+    fn update_visualizability(&mut self, event: &ChunkStoreEvent) -> bool {
+        let components = event.diff.chunk.components();
+        self.rotation_descriptors.iter().any(|descr| {
+            components
+                .get(descr)
+                .is_some_and(|list_array| !list_array.values().is_empty())
+        })
+    }
+}
+
+impl VisualizerSystem for RotationSeriesSystem {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        let mut query_info = VisualizerQueryInfo::from_archetype::<archetypes::Transform3D>();
+        query_info
+            .queried
+            .extend(archetypes::SeriesLines::all_components().iter().cloned());
+
+        query_info
+    }
+
+    fn data_based_visualizability_filter(&self) -> Option<Box<dyn DataBasedVisualizabilityFilter>> {
+        Some(Box::new(RotationVisualizabilityFilter {
+            rotation_descriptors: rotation_descriptors(),
+        }))
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        query: &ViewQuery<'_>,
+        _context: &re_viewer_context::ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        self.load_rotations(ctx, query);
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+impl_series_lines_fallbacks!(RotationSeriesSystem);
+
+impl RotationSeriesSystem {
+    fn load_rotations(&mut self, ctx: &ViewContext<'_>, query: &ViewQuery<'_>) {
+        re_tracing::profile_function!();
+
+        let plot_mem =
+            egui_plot::PlotMemory::load(ctx.viewer_ctx.egui_ctx(), crate::plot_id(query.view_id));
+        let time_per_pixel = determine_time_per_pixel(ctx.viewer_ctx, plot_mem.as_ref());
+
+        let mut series = vec![];
+        for data_result in query.iter_visible_data_results(Self::identifier()) {
+            self.load_series(
+                ctx,
+                query,
+                plot_mem.as_ref(),
+                time_per_pixel,
+                data_result,
+                &mut series,
+            );
+        }
+        self.all_series = series;
+    }
+
+    fn load_series(
+        &self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        plot_mem: Option<&egui_plot::PlotMemory>,
+        time_per_pixel: f64,
+        data_result: &re_viewer_context::DataResult,
+        all_series: &mut Vec<PlotSeries>,
+    ) {
+        re_tracing::profile_function!();
+
+        let current_query = ctx.current_query();
+        let query_ctx = ctx.query_context(data_result, &current_query);
+
+        let convention_descr = archetypes::Transform3D::descriptor_euler_convention();
+        let mut queried_components = rotation_descriptors().to_vec();
+        queried_components.push(convention_descr.clone());
+
+        let results = SeriesLinesResults::new(
+            ctx,
+            visible_range_query(ctx, view_query, plot_mem, data_result),
+            data_result,
+            &queried_components,
+        );
+
+        // The convention is not expected to change over time.
+        let convention = results
+            .get_mono::<EulerConvention>(&convention_descr)
+            .unwrap_or_default();
+
+        let rotations = collect_rotations(&results.query, &results.results, convention);
+        if rotations.is_empty() {
+            return;
+        }
+
+        let attrs_per_series = results.plot_point_attrs(self, &query_ctx, SERIES_LABELS.len());
+        let mut points_per_series = vec![Vec::with_capacity(rotations.len()); SERIES_LABELS.len()];
+
+        {
+            re_tracing::profile_scope!("extract angles");
+
+            for (time, rotation) in rotations {
+                for ((attrs, points), value) in attrs_per_series
+                    .iter()
+                    .zip(&mut points_per_series)
+                    .zip(roll_pitch_yaw(rotation))
+                {
+                    points.push(PlotPoint {
+                        time,
+                        value: f64::from(value),
+                        attrs: attrs.clone(),
+                    });
+                }
+            }
+        }
+
+        let logged_names = collect_logged_series_names(
+            &results.bootstrapped_results,
+            &results.results,
+            &archetypes::SeriesLines::descriptor_names(),
+        );
+        let fallback_name = crate::series_lines::fallback_series_name(&query_ctx);
+        let labels = SERIES_LABELS
+            .iter()
+            .enumerate()
+            .map(|(series, label)| {
+                logged_names
+                    .get(series)
+                    .cloned()
+                    .unwrap_or_else(|| format!("{fallback_name}/{label}"))
+            })
+            .collect();
+
+        results.add_series(
+            ctx,
+            view_query,
+            data_result,
+            time_per_pixel,
+            points_per_series,
+            labels,
+            Some("rad".to_owned()),
+            all_series,
+        );
+    }
+}
+
+/// Collects the rotations within the queried range, sorted by time.
+///
+/// Rotations logged in the same row are composed in the same order as in the 3D views, rows with an
+/// invalid rotation (e.g. a quaternion of zero length) are skipped.
+fn collect_rotations(
+    query: &RangeQuery,
+    results: &HybridRangeResults<'_>,
+    convention: EulerConvention,
+) -> Vec<(i64, glam::Quat)> {
+    re_tracing::profile_function!();
+
+    let [axis_angle_descr, quaternion_descr, euler_descr] = rotation_descriptors();
+    let timeline = *query.timeline();
+
+    // `None` marks rows with an invalid rotation.
+    let mut rotations: BTreeMap<(TimeInt, RowId), Option<glam::Quat>> = BTreeMap::new();
+    let mut compose = |index, rotation: Option<glam::Quat>| {
+        let composed = rotations.entry(index).or_insert(Some(glam::Quat::IDENTITY));
+        *composed = composed.zip(rotation).map(|(lhs, rhs)| lhs * rhs);
+    };
+
+    let axis_angles = results.iter_as(timeline, axis_angle_descr);
+    for (index, axis_angle) in axis_angles.component_slow::<RotationAxisAngle>() {
+        if let Some(axis_angle) = axis_angle.first() {
+            compose(index, axis_angle_to_quat(*axis_angle));
+        }
+    }
+
+    let quaternions = results.iter_as(timeline, quaternion_descr);
+    for (index, quaternion) in quaternions.slice::<[f32; 4]>() {
+        if let Some(xyzw) = quaternion.first() {
+            compose(
+                index,
+                glam::Quat::try_from(datatypes::Quaternion::from_xyzw(*xyzw)).ok(),
+            );
+        }
+    }
+
+    let eulers = results.iter_as(timeline, euler_descr);
+    for (index, euler) in eulers.slice::<[f32; 3]>() {
+        if let Some(&[a, b, c]) = euler.first() {
+            let quaternion = RotationEuler::new(a, b, c).to_quat(convention);
+            compose(index, quaternion.is_finite().then_some(quaternion));
+        }
+    }
+
+    rotations
+        .into_iter()
+        .filter_map(|((time, _row_id), rotation)| Some((time.as_i64(), rotation?)))
+        .collect()
+}
+
+/// Converts an axis-angle to a quaternion, unless its axis can't be normalized.
+fn axis_angle_to_quat(axis_angle: RotationAxisAngle) -> Option<glam::Quat> {
+    let datatypes::RotationAxisAngle { axis, angle } = axis_angle.0;
+    glam::Vec3::from(axis)
+        .try_normalize()
+        .map(|axis| glam::Quat::from_axis_angle(axis, angle.radians()))
+}
+
+/// Converts a rotation to `[roll, pitch, yaw]`.
+fn roll_pitch_yaw(rotation: glam::Quat) -> [f32; 3] {
+    let [yaw, pitch, roll] = RotationEuler::from_quat(rotation, EulerConvention::Zyx).0.0;
+    [roll, pitch, yaw]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_roll_pitch_yaw(rotation: glam::Quat, expected: [f32; 3]) {
+        let angles = roll_pitch_yaw(rotation);
+        for (angle, expected) in angles.iter().zip(expected) {
+            assert!(
+                (angle - expected).abs() < 1e-6,
+                "expected {expected:?}, got {angles:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_roll_pitch_yaw() {
+        assert_roll_pitch_yaw(
+            RotationEuler::new(0.3, 0.2, 0.1).to_quat(EulerConvention::Zyx),
+            [0.1, 0.2, 0.3],
+        );
+
+        // A rotation about a single axis doesn't depend on the convention.
+        assert_roll_pitch_yaw(
+            RotationEuler::new(0.0, 0.0, 0.5).to_quat(EulerConvention::Xyz),
+            [0.0, 0.0, 0.5],
+        );
+
+        assert_roll_pitch_yaw(glam::Quat::from_rotation_x(0.25), [0.25, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_axis_angle_to_quat() {
+        let rotation = axis_angle_to_quat(RotationAxisAngle::new(
+            [0.0, 2.0, 0.0],
+            datatypes::Angle::from_radians(0.4),
+        ))
+        .unwrap();
+        assert_roll_pitch_yaw(rotation, [0.0, 0.4, 0.0]);
+
+        assert!(
+            axis_angle_to_quat(RotationAxisAngle::new(
+                [0.0, 0.0, 0.0],
+                datatypes::Angle::from_radians(0.4),
+            ))
+            .is_none()
+        );
+    }
+}
@@ -0,0 +1,309 @@
+//! Shared functionality for visualizers that plot several [`archetypes::SeriesLines`] derived
+//! from a single component, e.g. the channels of a waveform or the angles of a rotation.
+
+use itertools::Itertools as _;
+
+use re_chunk_store::{LatestAtQuery, RangeQuery};
+use re_types::{
+    Archetype as _, Component, ComponentDescriptor, archetypes,
+    components::{AggregationPolicy, Color, Name, StrokeWidth},
+};
+use re_view::{
+    HybridLatestAtResults, HybridRangeResults, RangeResultsExt as _,
+    latest_at_with_blueprint_resolved_data, range_with_blueprint_resolved_data,
+};
+use re_viewer_context::external::re_entity_db::InstancePath;
+use re_viewer_context::{
+    DataResult, QueryContext, TypedComponentFallbackProvider, ViewContext, ViewQuery,
+    ViewStateExt as _, auto_color_egui,
+};
+
+use crate::series_query::collect_series_visibility;
+use crate::util::{determine_time_range, points_to_series};
+use crate::view_class::TimeSeriesViewState;
+use crate::{PlotPoint, PlotPointAttrs, PlotSeries, PlotSeriesKind};
+
+pub const DEFAULT_STROKE_WIDTH: f32 = 0.75;
+
+/// The default name of a series: the one the view picked for the entity, or the last part of
+/// its path.
+pub fn fallback_series_name(ctx: &QueryContext<'_>) -> Name {
+    let state = ctx.view_state().downcast_ref::<TimeSeriesViewState>();
+
+    state
+        .ok()
+        .and_then(|state| {
+            state
+                .default_names_for_entities
+                .get(ctx.target_entity_path)
+                .map(|name| name.clone().into())
+        })
+        .or_else(|| {
+            ctx.target_entity_path
+                .last()
+                .map(|part| part.ui_string().into())
+        })
+        .unwrap_or_default()
+}
+
+/// Implements the fallbacks for the [`archetypes::SeriesLines`] components of a visualizer.
+macro_rules! impl_series_lines_fallbacks {
+    ($visualizer:ty) => {
+        impl re_viewer_context::TypedComponentFallbackProvider<re_types::components::Color>
+            for $visualizer
+        {
+            fn fallback_for(
+                &self,
+                ctx: &re_viewer_context::QueryContext<'_>,
+            ) -> re_types::components::Color {
+                re_viewer_context::auto_color_for_entity_path(ctx.target_entity_path)
+            }
+        }
+
+        impl re_viewer_context::TypedComponentFallbackProvider<re_types::components::StrokeWidth>
+            for $visualizer
+        {
+            fn fallback_for(
+                &self,
+                _ctx: &re_viewer_context::QueryContext<'_>,
+            ) -> re_types::components::StrokeWidth {
+                re_types::components::StrokeWidth(
+                    $crate::series_lines::DEFAULT_STROKE_WIDTH.into(),
+                )
+            }
+        }
+
+        impl re_viewer_context::TypedComponentFallbackProvider<re_types::components::Name>
+            for $visualizer
+        {
+            fn fallback_for(
+                &self,
+                ctx: &re_viewer_context::QueryContext<'_>,
+            ) -> re_types::components::Name {
+                $crate::series_lines::fallback_series_name(ctx)
+            }
+        }
+
+        impl re_viewer_context::TypedComponentFallbackProvider<re_types::components::SeriesVisible>
+            for $visualizer
+        {
+            fn fallback_for(
+                &self,
+                _ctx: &re_viewer_context::QueryContext<'_>,
+            ) -> re_types::components::SeriesVisible {
+                true.into()
+            }
+        }
+
+        re_viewer_context::impl_component_fallback_provider!($visualizer => [
+            re_types::components::Color,
+            re_types::components::StrokeWidth,
+            re_types::components::Name,
+            re_types::components::SeriesVisible
+        ]);
+    };
+}
+
+pub(crate) use impl_series_lines_fallbacks;
+
+/// The range query for the part of the timeline that is visible in the plot.
+pub fn visible_range_query(
+    ctx: &ViewContext<'_>,
+    view_query: &ViewQuery<'_>,
+    plot_mem: Option<&egui_plot::PlotMemory>,
+    data_result: &DataResult,
+) -> RangeQuery {
+    let time_offset = ctx
+        .view_state
+        .downcast_ref::<TimeSeriesViewState>()
+        .map_or(0, |state| state.time_offset);
+    let time_range = determine_time_range(view_query.latest_at, time_offset, data_result, plot_mem);
+
+    RangeQuery::new(view_query.timeline, time_range)
+}
+
+/// The data of an entity that several [`archetypes::SeriesLines`] are derived from, along with
+/// their styling.
+pub struct SeriesLinesResults<'a> {
+    pub query: RangeQuery,
+
+    pub results: HybridRangeResults<'a>,
+
+    /// The results at the start of the visible range.
+    ///
+    /// Styling is not expected to change over time, so it is bootstrapped from there.
+    pub bootstrapped_results: HybridLatestAtResults<'a>,
+}
+
+impl<'a> SeriesLinesResults<'a> {
+    /// Queries the given components and those of [`archetypes::SeriesLines`].
+    pub fn new(
+        ctx: &'a ViewContext<'a>,
+        query: RangeQuery,
+        data_result: &'a DataResult,
+        component_descrs: &[ComponentDescriptor],
+    ) -> Self {
+        re_tracing::profile_function!();
+
+        let series_lines_descrs = archetypes::SeriesLines::all_components();
+        let component_descrs = component_descrs
+            .iter()
+            .chain(series_lines_descrs.iter())
+            .collect_vec();
+
+        let results = range_with_blueprint_resolved_data(
+            ctx,
+            None,
+            &query,
+            data_result,
+            component_descrs.iter().copied(),
+        );
+
+        let query_shadowed_components = false;
+        let bootstrapped_results = latest_at_with_blueprint_resolved_data(
+            ctx,
+            None,
+            &LatestAtQuery::new(query.timeline, query.range.min()),
+            data_result,
+            component_descrs.iter().copied(),
+            query_shadowed_components,
+        );
+
+        Self {
+            query,
+            results,
+            bootstrapped_results,
+        }
+    }
+
+    /// The value of a component that is not expected to change over time.
+    ///
+    /// That is the value at the start of the visible range, or the first one logged within it.
+    pub fn get_mono<C: Component>(&self, component_descr: &ComponentDescriptor) -> Option<C> {
+        self.bootstrapped_results
+            .get_mono::<C>(component_descr)
+            .or_else(|| {
+                self.results
+                    .get_optional_chunks(component_descr.clone())
+                    .iter()
+                    .find(|chunk| !chunk.is_empty())
+                    .and_then(|chunk| chunk.component_mono::<C>(component_descr, 0)?.ok())
+            })
+    }
+
+    /// The attributes of the points of each series, from the logged colors and stroke widths.
+    ///
+    /// Series without a color get one derived from the entity path and their index.
+    pub fn plot_point_attrs<F>(
+        &self,
+        fallback_provider: &F,
+        query_ctx: &QueryContext<'_>,
+        num_series: usize,
+    ) -> Vec<PlotPointAttrs>
+    where
+        F: TypedComponentFallbackProvider<Color> + TypedComponentFallbackProvider<StrokeWidth>,
+    {
+        let entity_path = query_ctx.target_entity_path;
+        let colors_descr = archetypes::SeriesLines::descriptor_colors();
+        let widths_descr = archetypes::SeriesLines::descriptor_widths();
+        let fallback_color =
+            TypedComponentFallbackProvider::<Color>::fallback_for(fallback_provider, query_ctx);
+        let fallback_stroke_width = TypedComponentFallbackProvider::<StrokeWidth>::fallback_for(
+            fallback_provider,
+            query_ctx,
+        );
+
+        (0..num_series)
+            .map(|series| {
+                let color = self
+                    .bootstrapped_results
+                    .get_instance::<Color>(series, &colors_descr)
+                    .map(Into::into)
+                    .unwrap_or_else(|| {
+                        if series == 0 {
+                            fallback_color.into()
+                        } else {
+                            auto_color_egui(
+                                (re_log_types::hash::Hash64::hash((entity_path, series - 1))
+                                    .hash64()
+                                    % u16::MAX as u64) as u16,
+                            )
+                        }
+                    });
+                let stroke_width = self
+                    .bootstrapped_results
+                    .get_instance::<StrokeWidth>(series, &widths_descr)
+                    .unwrap_or(fallback_stroke_width);
+
+                PlotPointAttrs {
+                    color,
+                    radius_ui: 0.5 * *stroke_width.0,
+                    kind: PlotSeriesKind::Continuous,
+                }
+            })
+            .collect()
+    }
+
+    /// Turns the points of each series into [`PlotSeries`], applying the logged aggregation
+    /// policy and visibility.
+    ///
+    /// `points_per_series` and `labels` are expected to have one entry per series.
+    #[expect(clippy::too_many_arguments)]
+    pub fn add_series(
+        &self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        data_result: &DataResult,
+        time_per_pixel: f64,
+        points_per_series: Vec<Vec<PlotPoint>>,
+        labels: Vec<String>,
+        unit: Option<String>,
+        all_series: &mut Vec<PlotSeries>,
+    ) {
+        let num_series = points_per_series.len();
+        let entity_path = &data_result.entity_path;
+
+        let aggregator =
+            self.bootstrapped_results
+                .get_mono::<AggregationPolicy>(
+                    &archetypes::SeriesLines::descriptor_aggregation_policy(),
+                )
+                .unwrap_or_default();
+
+        let series_visibility = collect_series_visibility(
+            &self.query,
+            &self.bootstrapped_results,
+            &self.results,
+            num_series,
+            archetypes::SeriesLines::descriptor_visible_series(),
+        );
+
+        for (series, (points, label, visible)) in itertools::izip!(
+            points_per_series.into_iter(),
+            labels.into_iter(),
+            series_visibility.into_iter()
+        )
+        .enumerate()
+        {
+            let instance_path = if num_series == 1 {
+                InstancePath::entity_all(entity_path.clone())
+            } else {
+                InstancePath::instance(entity_path.clone(), (series as u64).into())
+            };
+
+            points_to_series(
+                instance_path,
+                time_per_pixel,
+                visible,
+                points,
+                data_result.time_alignment(),
+                ctx.recording_engine().store(),
+                view_query,
+                label,
+                unit.clone(),
+                aggregator,
+                all_series,
+            );
+        }
+    }
+}
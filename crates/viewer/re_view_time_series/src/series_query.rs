@@ -238,14 +238,8 @@ pub fn collect_series_name(
 ) -> Vec<String> {
     re_tracing::profile_function!();
 
-    let mut series_names: Vec<String> = bootstrapped_results
-        .get_optional_chunks(name_descriptor.clone())
-        .iter()
-        .chain(results.get_optional_chunks(name_descriptor.clone()).iter())
-        .find(|chunk| !chunk.is_empty())
-        .and_then(|chunk| chunk.iter_slices::<String>(name_descriptor.clone()).next())
-        .map(|slice| slice.into_iter().map(|s| s.to_string()).collect())
-        .unwrap_or_default();
+    let mut series_names =
+        collect_logged_series_names(bootstrapped_results, results, name_descriptor);
 
     if series_names.len() < num_series {
         let fallback_name: String = fallback_provider.fallback_for(query_ctx).to_string();
@@ -261,6 +255,56 @@ pub fn collect_series_name(
     series_names
 }
 
+/// Collects the series names that were logged, without filling in any missing ones.
+pub fn collect_logged_series_names(
+    bootstrapped_results: &re_view::HybridLatestAtResults<'_>,
+    results: &re_view::HybridRangeResults<'_>,
+    name_descriptor: &ComponentDescriptor,
+) -> Vec<String> {
+    bootstrapped_results
+        .get_optional_chunks(name_descriptor.clone())
+        .iter()
+        .chain(results.get_optional_chunks(name_descriptor.clone()).iter())
+        .find(|chunk| !chunk.is_empty())
+        .and_then(|chunk| chunk.iter_slices::<String>(name_descriptor.clone()).next())
+        .map(|slice| slice.into_iter().map(|s| s.to_string()).collect())
+        .unwrap_or_default()
+}
+
+/// Collects the units of the series.
+///
+/// A single logged unit applies to all series.
+pub fn collect_series_units(
+    bootstrapped_results: &re_view::HybridLatestAtResults<'_>,
+    results: &re_view::HybridRangeResults<'_>,
+    num_series: usize,
+    unit_descriptor: &ComponentDescriptor,
+) -> Vec<Option<String>> {
+    re_tracing::profile_function!();
+
+    let units: Vec<Option<String>> = bootstrapped_results
+        .get_optional_chunks(unit_descriptor.clone())
+        .iter()
+        .chain(results.get_optional_chunks(unit_descriptor.clone()).iter())
+        .find(|chunk| !chunk.is_empty())
+        .and_then(|chunk| chunk.iter_slices::<String>(unit_descriptor.clone()).next())
+        .map(|slice| {
+            slice
+                .into_iter()
+                .map(|s| (!s.is_empty()).then(|| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if let [unit] = units.as_slice() {
+        vec![Some(unit.clone()); num_series]
+    } else {
+        (0..num_series)
+            .map(|i| units.get(i).cloned().flatten())
+            .collect()
+    }
+}
+
 /// Collects `radius_ui` for the series into pre-allocated plot points.
 pub fn collect_radius_ui(
     query: &RangeQuery,
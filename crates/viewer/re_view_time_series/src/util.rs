@@ -3,7 +3,9 @@ use re_types::{
     components::AggregationPolicy,
     datatypes::{TimeRange, TimeRangeBoundary},
 };
-use re_viewer_context::{ViewQuery, ViewerContext, external::re_entity_db::InstancePath};
+use re_viewer_context::{
+    TimeAlignment, ViewQuery, ViewerContext, external::re_entity_db::InstancePath,
+};
 
 use crate::{
     PlotPoint, PlotSeries, PlotSeriesKind, ScatterAttrs,
@@ -78,15 +80,20 @@ pub fn determine_time_range(
 // We have a bunch of raw points, and now we need to group them into individual series.
 // A series is a continuous run of points with identical attributes: each time
 // we notice a change in attributes, we need a new series.
+//
+// The points are expected at the times they were logged at, and are moved to the times
+// they are shown at according to `time_alignment`.
 #[expect(clippy::too_many_arguments)]
 pub fn points_to_series(
     instance_path: InstancePath,
     time_per_pixel: f64,
     visible: bool,
-    points: Vec<PlotPoint>,
+    mut points: Vec<PlotPoint>,
+    time_alignment: &TimeAlignment,
     store: &re_chunk_store::ChunkStore,
     query: &ViewQuery<'_>,
     series_label: String,
+    series_unit: Option<String>,
     aggregator: AggregationPolicy,
     all_series: &mut Vec<PlotSeries>,
 ) {
@@ -95,10 +102,18 @@ pub fn points_to_series(
         return;
     }
 
+    if !time_alignment.is_identity() {
+        for point in &mut points {
+            point.time = time_alignment.view_time_from_data_time(point.time);
+        }
+    }
+
     let (aggregation_factor, points) = apply_aggregation(aggregator, time_per_pixel, points, query);
     let min_time = store
         .entity_min_time(&query.timeline, &instance_path.entity_path)
-        .map_or(points.first().map_or(0, |p| p.time), |time| time.as_i64());
+        .map_or(points.first().map_or(0, |p| p.time), |time| {
+            time_alignment.view_time_from_data_time(time.as_i64())
+        });
 
     if points.len() == 1 {
         // Can't draw a single point as a continuous line, so fall back on scatter
@@ -111,6 +126,7 @@ pub fn points_to_series(
             visible,
             id: egui::Id::new(&instance_path),
             label: series_label,
+            unit: series_unit,
             color: points[0].attrs.color,
             radius_ui: points[0].attrs.radius_ui,
             kind,
@@ -124,6 +140,7 @@ pub fn points_to_series(
         add_series_runs(
             visible,
             series_label,
+            series_unit,
             points,
             instance_path,
             aggregator,
@@ -206,6 +223,7 @@ pub fn apply_aggregation(
 fn add_series_runs(
     visible: bool,
     series_label: String,
+    series_unit: Option<String>,
     points: Vec<PlotPoint>,
     instance_path: InstancePath,
     aggregator: AggregationPolicy,
@@ -223,6 +241,7 @@ fn add_series_runs(
         visible,
         id,
         label: series_label.clone(),
+        unit: series_unit.clone(),
         color: attrs.color,
         radius_ui: attrs.radius_ui,
         points: Vec::with_capacity(num_points),
@@ -249,6 +268,7 @@ fn add_series_runs(
                     visible,
                     id,
                     label: series_label.clone(),
+                    unit: series_unit.clone(),
                     color: attrs.color,
                     radius_ui: attrs.radius_ui,
                     kind: attrs.kind,
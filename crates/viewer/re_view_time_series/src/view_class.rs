@@ -1,5 +1,6 @@
 use egui::ahash::{HashMap, HashSet};
-use egui_plot::{ColorConflictHandling, Legend, Line, Plot, PlotPoint, Points};
+use egui_plot::{ColorConflictHandling, Legend, Line, LineStyle, Plot, PlotPoint, Points, Polygon};
+use itertools::Itertools as _;
 use nohash_hasher::IntSet;
 use smallvec::SmallVec;
 
@@ -22,8 +23,8 @@ use re_view::{
     view_property_ui,
 };
 use re_viewer_context::{
-    IdentifiedViewSystem as _, IndicatedEntities, MaybeVisualizableEntities, PerVisualizer,
-    QueryRange, RecommendedView, SmallVisualizerSet, SystemExecutionOutput,
+    HoveredTime, IdentifiedViewSystem as _, IndicatedEntities, MaybeVisualizableEntities,
+    PerVisualizer, QueryRange, RecommendedView, SmallVisualizerSet, SystemExecutionOutput,
     TypedComponentFallbackProvider, ViewClass, ViewClassExt as _, ViewClassRegistryError,
     ViewHighlights, ViewId, ViewQuery, ViewSpawnHeuristics, ViewState, ViewStateExt as _,
     ViewSystemExecutionError, ViewSystemIdentifier, ViewerContext, VisualizableEntities,
@@ -32,8 +33,12 @@ use re_viewer_context::{
 use re_viewport_blueprint::ViewProperty;
 
 use crate::{
-    PlotSeriesKind, line_visualizer_system::SeriesLinesSystem,
+    PlotSeriesKind,
+    line_visualizer_system::SeriesLinesSystem,
+    overlays::{OverlaySettings, SeriesOverlays},
     point_visualizer_system::SeriesPointsSystem,
+    rotation_visualizer_system::RotationSeriesSystem,
+    waveform_visualizer_system::WaveformSystem,
 };
 
 // ---
@@ -67,6 +72,12 @@ pub struct TimeSeriesViewState {
 
     /// Whether to reset the plot bounds next frame.
     reset_bounds_next_frame: bool,
+
+    /// Which derived overlays are shown.
+    overlays: OverlaySettings,
+
+    /// Overlays computed for each plot series, by series id.
+    overlays_per_series: HashMap<egui::Id, SeriesOverlays>,
 }
 
 impl Default for TimeSeriesViewState {
@@ -84,6 +95,8 @@ impl Default for TimeSeriesViewState {
             time_offset: 0,
             default_names_for_entities: Default::default(),
             reset_bounds_next_frame: false,
+            overlays: OverlaySettings::default(),
+            overlays_per_series: Default::default(),
         }
     }
 }
@@ -174,6 +187,8 @@ impl ViewClass for TimeSeriesView {
     ) -> Result<(), ViewClassRegistryError> {
         system_registry.register_visualizer::<SeriesLinesSystem>()?;
         system_registry.register_visualizer::<SeriesPointsSystem>()?;
+        system_registry.register_visualizer::<WaveformSystem>()?;
+        system_registry.register_visualizer::<RotationSeriesSystem>()?;
         Ok(())
     }
 
@@ -212,6 +227,8 @@ impl ViewClass for TimeSeriesView {
             view_property_ui::<PlotLegend>(&ctx, ui, self);
             view_property_ui::<TimeAxis>(&ctx, ui, self);
             view_property_ui::<ScalarAxis>(&ctx, ui, self);
+
+            overlays_ui(ui, &mut state.overlays);
         });
 
         Ok(())
@@ -230,6 +247,7 @@ impl ViewClass for TimeSeriesView {
         for indicated in [
             SeriesLinesSystem::identifier(),
             SeriesPointsSystem::identifier(),
+            WaveformSystem::identifier(),
         ]
         .iter()
         .filter_map(|&system_id| ctx.indicated_entities_per_visualizer.get(&system_id))
@@ -406,12 +424,25 @@ impl ViewClass for TimeSeriesView {
 
         let line_series = system_output.view_systems.get::<SeriesLinesSystem>()?;
         let point_series = system_output.view_systems.get::<SeriesPointsSystem>()?;
+        let waveform_series = system_output.view_systems.get::<WaveformSystem>()?;
+        let rotation_series = system_output.view_systems.get::<RotationSeriesSystem>()?;
 
         let all_plot_series: Vec<_> = std::iter::empty()
             .chain(line_series.all_series.iter())
             .chain(point_series.all_series.iter())
+            .chain(waveform_series.all_series.iter())
+            .chain(rotation_series.all_series.iter())
             .collect();
 
+        state
+            .overlays_per_series
+            .retain(|id, _| all_plot_series.iter().any(|series| series.id == *id));
+
+        if state.overlays.spectrum {
+            spectrum_ui(ctx, ui, state, query, &all_plot_series, timeline.typ());
+            return Ok(());
+        }
+
         // Note that a several plot items can point to the same entity path and in some cases even to the same instance path!
         // (e.g. when plotting both lines & points with the same entity/instance path)
         let plot_item_id_to_instance_path: HashMap<egui::Id, InstancePath> = all_plot_series
@@ -436,6 +467,18 @@ impl ViewClass for TimeSeriesView {
             .map(|line| line.aggregator)
             .unwrap_or_default();
 
+        // The y-axis is only labeled with a unit if all series that have one agree on it.
+        let y_axis_unit = all_plot_series
+            .iter()
+            .filter_map(|series| series.unit.as_deref())
+            .all_equal_value()
+            .ok()
+            .map(ToOwned::to_owned);
+        let units_per_label: HashMap<String, String> = all_plot_series
+            .iter()
+            .filter_map(|series| Some((series.label.clone(), series.unit.clone()?)))
+            .collect();
+
         // …then use that as an offset to avoid nasty precision issues with
         // large times (nanos since epoch does not fit into a f64).
         let time_offset = match timeline.typ() {
@@ -485,6 +528,7 @@ impl ViewClass for TimeSeriesView {
             ])
             .custom_y_axes(vec![
                 egui_plot::AxisHints::new_y()
+                    .label(y_axis_unit.unwrap_or_default())
                     .min_thickness(min_axis_thickness)
                     .formatter(move |mark, _| format_y_axis(mark)),
             ])
@@ -496,6 +540,10 @@ impl ViewClass for TimeSeriesView {
                 );
 
                 let y_value = re_format::format_f64(value.y);
+                let y_value = match units_per_label.get(name) {
+                    Some(unit) => format!("{y_value} {unit}"),
+                    None => y_value,
+                };
 
                 if aggregator == AggregationPolicy::Off || aggregation_factor <= 1.0 {
                     format!("{timeline_name}: {label}\n{name}: {y_value}")
@@ -595,6 +643,16 @@ impl ViewClass for TimeSeriesView {
                 time_offset,
                 &mut state.scalar_range,
             );
+
+            if state.overlays.any_rolling() {
+                add_overlays_to_plot(
+                    plot_ui,
+                    &all_plot_series,
+                    time_offset,
+                    &state.overlays,
+                    &mut state.overlays_per_series,
+                );
+            }
         });
 
         // Interact with the plot items (lines, scatters, etc.)
@@ -679,10 +737,81 @@ impl ViewClass for TimeSeriesView {
             ui.paint_time_cursor(ui.painter(), &response, time_x, response.rect.y_range());
         }
 
+        if ctx.app_options().linked_time_cursor {
+            // Share the time of the hovered sample, so other views can highlight what was logged then.
+            if response.hovered()
+                && let Some(pointer_pos) = response.hover_pos()
+            {
+                let pointer_time =
+                    time_offset + transform.value_from_position(pointer_pos).x.round() as i64;
+                let hovered_series: Vec<_> = all_plot_series
+                    .iter()
+                    .copied()
+                    .filter(|series| Some(series.id) == hovered_plot_item)
+                    .collect();
+                let candidates = if hovered_series.is_empty() {
+                    all_plot_series
+                        .iter()
+                        .copied()
+                        .filter(|series| series.visible)
+                        .collect()
+                } else {
+                    hovered_series
+                };
+
+                if let Some(time) = nearest_sample_time(&candidates, pointer_time) {
+                    ctx.selection_state().set_hovered_time(HoveredTime {
+                        view_id: query.view_id,
+                        timeline: *timeline.name(),
+                        time: TimeInt::new_temporal(time),
+                    });
+                }
+            }
+
+            // Show the time hovered in another view.
+            if let Some(hovered_time) = ctx.selection_state().hovered_time()
+                && hovered_time.view_id != query.view_id
+                && hovered_time.timeline == *timeline.name()
+            {
+                let x = hovered_time.time.as_i64().saturating_sub(time_offset) as f64;
+                if transform.bounds().min()[0] <= x && x <= transform.bounds().max()[0] {
+                    let x = transform.position_from_point(&PlotPoint::new(x, 0.0)).x;
+                    let stroke = egui::Stroke::new(1.0, ui.visuals().strong_text_color());
+                    ui.painter().extend(egui::Shape::dashed_line(
+                        &[
+                            egui::pos2(x, response.rect.top()),
+                            egui::pos2(x, response.rect.bottom()),
+                        ],
+                        stroke,
+                        4.0,
+                        4.0,
+                    ));
+                }
+            }
+        }
+
         Ok(())
     }
 }
 
+/// The time of the sample of `all_series` closest to `time`.
+fn nearest_sample_time(all_series: &[&crate::PlotSeries], time: i64) -> Option<i64> {
+    all_series
+        .iter()
+        .filter_map(|series| {
+            // Points are sorted by time.
+            let index = series.points.partition_point(|&(t, _)| t < time);
+            let before = index.checked_sub(1).and_then(|i| series.points.get(i));
+            let after = series.points.get(index);
+            before
+                .into_iter()
+                .chain(after)
+                .map(|&(t, _)| t)
+                .min_by_key(|t| t.abs_diff(time))
+        })
+        .min_by_key(|t| t.abs_diff(time))
+}
+
 fn set_plot_visibility_from_store(
     egui_ctx: &egui::Context,
     plot_series_from_store: &[&crate::PlotSeries],
@@ -847,6 +976,235 @@ fn add_series_to_plot(
     }
 }
 
+fn overlays_ui(ui: &mut egui::Ui, overlays: &mut OverlaySettings) {
+    ui.list_item()
+        .interactive(false)
+        .show_hierarchical_with_children(
+            ui,
+            ui.make_persistent_id("time_series_overlays"),
+            true,
+            list_item::LabelContent::new("Overlays"),
+            |ui| {
+                ui.list_item_flat_noninteractive(
+                    list_item::PropertyContent::new("Rolling mean")
+                        .value_bool_mut(&mut overlays.rolling_mean),
+                );
+                ui.list_item_flat_noninteractive(
+                    list_item::PropertyContent::new("Standard deviation band")
+                        .value_bool_mut(&mut overlays.rolling_stddev),
+                );
+                ui.list_item_flat_noninteractive(
+                    list_item::PropertyContent::new("Min/max envelope")
+                        .value_bool_mut(&mut overlays.min_max_envelope),
+                );
+                ui.list_item_flat_noninteractive(
+                    list_item::PropertyContent::new("Window").value_fn(|ui, _| {
+                        ui.add(
+                            egui::DragValue::new(&mut overlays.window)
+                                .range(2..=10_000)
+                                .suffix(" points"),
+                        )
+                        .on_hover_text("Number of points the rolling statistics are computed over");
+                    }),
+                );
+                ui.list_item_flat_noninteractive(
+                    list_item::PropertyContent::new("Spectrum")
+                        .value_bool_mut(&mut overlays.spectrum),
+                )
+                .on_hover_text(
+                    "Show the frequency spectrum of the selected series, or of all visible series if none is selected.\n\
+                    The last points of each series are assumed to be evenly spaced in time.",
+                );
+            },
+        );
+}
+
+/// Maximum number of segments of a band, to keep the number of plot items in check.
+const MAX_BAND_SEGMENTS: usize = 1000;
+
+fn add_overlays_to_plot(
+    plot_ui: &mut egui_plot::PlotUi<'_>,
+    all_plot_series: &[&crate::PlotSeries],
+    time_offset: i64,
+    overlays: &OverlaySettings,
+    overlays_per_series: &mut HashMap<egui::Id, SeriesOverlays>,
+) {
+    re_tracing::profile_function!();
+
+    for series in all_plot_series {
+        if !series.visible || series.kind == PlotSeriesKind::Clear {
+            continue;
+        }
+
+        let rolling = &mut overlays_per_series.entry(series.id).or_default().rolling;
+        rolling.update(&series.points, overlays.window);
+
+        let times: Vec<f64> = series
+            .points
+            .iter()
+            .map(|(time, _)| (time - time_offset) as f64)
+            .collect();
+
+        // Overlays have no name, which keeps them out of the legend.
+        if overlays.min_max_envelope {
+            add_band_to_plot(
+                plot_ui,
+                &times,
+                &rolling.min,
+                &rolling.max,
+                series.color.gamma_multiply(0.15),
+            );
+        }
+        if overlays.rolling_stddev {
+            let lower: Vec<f64> = rolling
+                .mean
+                .iter()
+                .zip(&rolling.stddev)
+                .map(|(mean, stddev)| mean - stddev)
+                .collect();
+            let upper: Vec<f64> = rolling
+                .mean
+                .iter()
+                .zip(&rolling.stddev)
+                .map(|(mean, stddev)| mean + stddev)
+                .collect();
+            add_band_to_plot(
+                plot_ui,
+                &times,
+                &lower,
+                &upper,
+                series.color.gamma_multiply(0.3),
+            );
+        }
+        if overlays.rolling_mean {
+            let points: Vec<[f64; 2]> = times
+                .iter()
+                .zip(&rolling.mean)
+                .map(|(&time, &mean)| [time, mean])
+                .collect();
+            plot_ui.line(
+                Line::new("", points)
+                    .color(series.color)
+                    .width(series.radius_ui.max(1.0))
+                    .style(LineStyle::dashed_dense())
+                    .id(series.id.with("rolling_mean")),
+            );
+        }
+    }
+}
+
+/// Fills the area between `lower` and `upper`.
+fn add_band_to_plot(
+    plot_ui: &mut egui_plot::PlotUi<'_>,
+    times: &[f64],
+    lower: &[f64],
+    upper: &[f64],
+    color: egui::Color32,
+) {
+    // Polygons must be convex, so the band is made of one trapezoid per segment.
+    let step = times.len().div_ceil(MAX_BAND_SEGMENTS).max(1);
+    let indices: Vec<usize> = (0..times.len())
+        .step_by(step)
+        .chain(times.len().checked_sub(1))
+        .dedup()
+        .collect();
+
+    for (&a, &b) in indices.iter().tuple_windows() {
+        plot_ui.polygon(
+            Polygon::new(
+                "",
+                vec![
+                    [times[a], lower[a]],
+                    [times[b], lower[b]],
+                    [times[b], upper[b]],
+                    [times[a], upper[a]],
+                ],
+            )
+            .fill_color(color)
+            .stroke(egui::Stroke::NONE),
+        );
+    }
+}
+
+/// Shows the frequency spectra of the selected series, or of all visible series if none is selected.
+fn spectrum_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    state: &mut TimeSeriesViewState,
+    query: &ViewQuery<'_>,
+    all_plot_series: &[&crate::PlotSeries],
+    time_type: TimeType,
+) {
+    re_tracing::profile_function!();
+
+    let is_selected = |series: &crate::PlotSeries| {
+        ctx.selection()
+            .iter_items()
+            .any(|item| item.entity_path() == Some(&series.instance_path.entity_path))
+    };
+    let visible_series = all_plot_series
+        .iter()
+        .filter(|series| series.visible && series.kind != PlotSeriesKind::Clear);
+    let spectrum_series: Vec<_> = if visible_series.clone().any(|series| is_selected(series)) {
+        visible_series
+            .filter(|series| is_selected(series))
+            .collect()
+    } else {
+        visible_series.collect()
+    };
+
+    let (time_units_per_cycle, frequency_unit) = match time_type {
+        TimeType::Sequence => (1.0, "cycles per step"),
+        TimeType::DurationNs | TimeType::TimestampNs => (1e9, "Hz"),
+    };
+
+    let min_axis_thickness = ui.tokens().small_icon_size.y;
+    let plot = Plot::new(("spectrum", query.view_id))
+        .id(crate::plot_id(query.view_id).with("spectrum"))
+        .custom_x_axes(vec![
+            egui_plot::AxisHints::new_x()
+                .label(frequency_unit)
+                .min_thickness(min_axis_thickness),
+        ])
+        .custom_y_axes(vec![
+            egui_plot::AxisHints::new_y()
+                .label("Magnitude")
+                .min_thickness(min_axis_thickness)
+                .formatter(move |mark, _| format_y_axis(mark)),
+        ])
+        .legend(Legend::default().color_conflict_handling(ColorConflictHandling::PickFirst));
+
+    let response = plot
+        .show(ui, |plot_ui| {
+            for series in spectrum_series {
+                let Some(spectrum) = state
+                    .overlays_per_series
+                    .entry(series.id)
+                    .or_default()
+                    .spectrum(&series.points, time_units_per_cycle)
+                else {
+                    continue;
+                };
+
+                plot_ui.line(
+                    Line::new(&series.label, spectrum.to_vec())
+                        .color(series.color)
+                        .width(2.0 * series.radius_ui)
+                        .id(series.id),
+                );
+            }
+        })
+        .response;
+
+    if response.hovered() {
+        ctx.handle_select_hover_drag_interactions(
+            &response,
+            re_viewer_context::Item::View(query.view_id),
+            false,
+        );
+    }
+}
+
 fn format_y_axis(mark: egui_plot::GridMark) -> String {
     // Example: If the step to the next tick is `0.01`, we should use 2 decimals of precision:
     let num_decimals = -mark.step_size.log10().round() as usize;
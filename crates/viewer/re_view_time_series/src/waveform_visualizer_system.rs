@@ -0,0 +1,222 @@
+use re_log_types::TimeType;
+use re_types::{
+    Archetype as _,
+    archetypes::{self},
+    components::{ChannelCount, SampleRate},
+};
+use re_view::RangeResultsExt as _;
+use re_viewer_context::{
+    IdentifiedViewSystem, ViewContext, ViewQuery, ViewSystemExecutionError, VisualizerQueryInfo,
+    VisualizerSystem,
+};
+
+use crate::series_lines::{SeriesLinesResults, impl_series_lines_fallbacks, visible_range_query};
+use crate::series_query::collect_series_name;
+use crate::util::determine_time_per_pixel;
+use crate::{PlotPoint, PlotSeries};
+
+/// The system for rendering [`archetypes::Waveform`] archetypes.
+///
+/// Each logged block of samples is expanded into individual plot points on the fly,
+/// but only for the blocks that intersect the visible time range.
+#[derive(Default, Debug)]
+pub struct WaveformSystem {
+    pub all_series: Vec<PlotSeries>,
+}
+
+impl IdentifiedViewSystem for WaveformSystem {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "Waveform".into()
+    }
+}
+
+impl VisualizerSystem for WaveformSystem {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        let mut query_info = VisualizerQueryInfo::from_archetype::<archetypes::Waveform>();
+        query_info
+            .queried
+            .extend(archetypes::SeriesLines::all_components().iter().cloned());
+
+        query_info
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        query: &ViewQuery<'_>,
+        _context: &re_viewer_context::ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        self.load_waveforms(ctx, query);
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+impl_series_lines_fallbacks!(WaveformSystem);
+
+impl WaveformSystem {
+    fn load_waveforms(&mut self, ctx: &ViewContext<'_>, query: &ViewQuery<'_>) {
+        re_tracing::profile_function!();
+
+        let plot_mem =
+            egui_plot::PlotMemory::load(ctx.viewer_ctx.egui_ctx(), crate::plot_id(query.view_id));
+        let time_per_pixel = determine_time_per_pixel(ctx.viewer_ctx, plot_mem.as_ref());
+
+        let mut series = vec![];
+        for data_result in query.iter_visible_data_results(Self::identifier()) {
+            self.load_series(
+                ctx,
+                query,
+                plot_mem.as_ref(),
+                time_per_pixel,
+                data_result,
+                &mut series,
+            );
+        }
+        self.all_series = series;
+    }
+
+    fn load_series(
+        &self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        plot_mem: Option<&egui_plot::PlotMemory>,
+        time_per_pixel: f64,
+        data_result: &re_viewer_context::DataResult,
+        all_series: &mut Vec<PlotSeries>,
+    ) {
+        re_tracing::profile_function!();
+
+        let current_query = ctx.current_query();
+        let query_ctx = ctx.query_context(data_result, &current_query);
+
+        let entity_path = &data_result.entity_path;
+        let query = visible_range_query(ctx, view_query, plot_mem, data_result)
+            // Blocks that start before the visible range may still reach into it.
+            .include_extended_bounds(true);
+
+        let results = SeriesLinesResults::new(
+            ctx,
+            query,
+            data_result,
+            &archetypes::Waveform::all_components(),
+        );
+        let query = &results.query;
+
+        let Some(all_sample_chunks) = results
+            .results
+            .get_required_chunks(archetypes::Waveform::descriptor_samples())
+        else {
+            return;
+        };
+
+        let Some(sample_rate) =
+            results.get_mono::<SampleRate>(&archetypes::Waveform::descriptor_sample_rate())
+        else {
+            return;
+        };
+        let sample_rate = sample_rate.0.0;
+        if !sample_rate.is_finite() || sample_rate <= 0.0 {
+            re_log::warn_once!(
+                "Ignoring waveform at {entity_path:?} with invalid sample rate {sample_rate}"
+            );
+            return;
+        }
+
+        let num_channels = results
+            .bootstrapped_results
+            .get_mono::<ChannelCount>(&archetypes::Waveform::descriptor_channel_count())
+            .unwrap_or_default()
+            .0
+            .0
+            .max(1) as usize;
+
+        // Duration between two consecutive samples, in units of the timeline.
+        let time_per_sample = match ctx.recording().timeline_type(&view_query.timeline) {
+            TimeType::Sequence => 1.0 / sample_rate,
+            TimeType::DurationNs | TimeType::TimestampNs => 1e9 / sample_rate,
+        };
+
+        let attrs_per_channel = results.plot_point_attrs(self, &query_ctx, num_channels);
+        let mut points_per_channel = vec![Vec::new(); num_channels];
+
+        {
+            re_tracing::profile_scope!("expand blocks");
+
+            let max_time = data_result
+                .time_alignment()
+                .data_time_from_view_time(query.range.max())
+                .as_i64();
+            for chunk in all_sample_chunks.iter() {
+                for ((block_start, _row_id), samples) in itertools::izip!(
+                    chunk.iter_component_indices(query.timeline()),
+                    chunk.iter_slices::<f64>()
+                ) {
+                    for (sample_index, frame) in samples.chunks_exact(num_channels).enumerate() {
+                        let time = block_start.as_i64()
+                            + (sample_index as f64 * time_per_sample).round() as i64;
+
+                        // Everything past the end of the visible range is culled, except for the
+                        // first sample so that lines cross the edge of the view.
+                        let is_past_range = time > max_time;
+
+                        for ((attrs, points), value) in attrs_per_channel
+                            .iter()
+                            .zip(&mut points_per_channel)
+                            .zip(frame)
+                        {
+                            points.push(PlotPoint {
+                                time,
+                                value: *value,
+                                attrs: attrs.clone(),
+                            });
+                        }
+
+                        if is_past_range {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        // Blocks may overlap or arrive out of order.
+        {
+            re_tracing::profile_scope!("sort");
+            for points in &mut points_per_channel {
+                if !points.is_sorted_by_key(|p| p.time) {
+                    points.sort_by_key(|p| p.time);
+                }
+            }
+        }
+
+        let series_names = collect_series_name(
+            self,
+            &query_ctx,
+            &results.bootstrapped_results,
+            &results.results,
+            num_channels,
+            &archetypes::SeriesLines::descriptor_names(),
+        );
+
+        results.add_series(
+            ctx,
+            view_query,
+            data_result,
+            time_per_pixel,
+            points_per_channel,
+            series_names,
+            None,
+            all_series,
+        );
+    }
+}
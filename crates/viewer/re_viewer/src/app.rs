@@ -79,6 +79,9 @@ pub struct App {
     pub(crate) egui_ctx: egui::Context,
     screenshotter: crate::screenshotter::Screenshotter,
 
+    #[cfg(not(target_arch = "wasm32"))]
+    view_exporter: crate::view_exporter::ViewExporter,
+
     #[cfg(target_arch = "wasm32")]
     pub(crate) popstate_listener: Option<crate::history::PopstateListener>,
 
@@ -93,6 +96,12 @@ pub struct App {
     rx_log: ReceiveSet<LogMsg>,
     rx_table: ReceiveSetTable,
 
+    /// Atomic batches that haven't been fully received yet.
+    atomic_batches: parking_lot::Mutex<crate::atomic_batches::AtomicBatches>,
+
+    /// See [`Self::add_ingest_transform`].
+    ingest_transforms: Vec<Arc<dyn re_entity_db::IngestTransform>>,
+
     #[cfg(target_arch = "wasm32")]
     open_files_promise: Option<PendingFilePromise>,
 
@@ -244,12 +253,25 @@ impl App {
             state.app_options.video_decoder_hw_acceleration = video_decoder_hw_acceleration;
         }
 
-        let view_class_registry = crate::default_views::create_view_class_registry()
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            state.app_options.video_decoder_threads = startup_options.video_decoder_threads;
+        }
+
+        #[allow(unused_mut)] // false positive on web
+        let mut view_class_registry = crate::default_views::create_view_class_registry()
             .unwrap_or_else(|err| {
                 re_log::error!("Failed to create view class registry: {err}");
                 Default::default()
             });
 
+        #[cfg(not(target_arch = "wasm32"))]
+        for path in &startup_options.plugins {
+            if let Err(err) = crate::plugin::load_plugin_library(path, &mut view_class_registry) {
+                re_log::error!("Failed to load Viewer plugin {path:?}: {err}");
+            }
+        }
+
         #[allow(unused_mut, clippy::needless_update)] // false positive on web
         let mut screenshotter = crate::screenshotter::Screenshotter::default();
 
@@ -258,6 +280,14 @@ impl App {
             screenshotter.screenshot_to_path_then_quit(&creation_context.egui_ctx, screenshot_path);
         }
 
+        #[cfg(not(target_arch = "wasm32"))]
+        let mut view_exporter = crate::view_exporter::ViewExporter::default();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(options) = startup_options.export_view_then_quit.clone() {
+            view_exporter.export_then_quit(options);
+        }
+
         let (command_sender, command_receiver) = command_channel;
 
         let mut component_ui_registry = re_component_ui::create_component_ui_registry();
@@ -334,6 +364,9 @@ impl App {
             egui_ctx: creation_context.egui_ctx.clone(),
             screenshotter,
 
+            #[cfg(not(target_arch = "wasm32"))]
+            view_exporter,
+
             #[cfg(target_arch = "wasm32")]
             popstate_listener: None,
 
@@ -343,6 +376,8 @@ impl App {
             text_log_rx,
             component_ui_registry,
             rx_log: Default::default(),
+            atomic_batches: Default::default(),
+            ingest_transforms: Vec::new(),
             rx_table: Default::default(),
             #[cfg(target_arch = "wasm32")]
             open_files_promise: Default::default(),
@@ -411,6 +446,11 @@ impl App {
         self.screenshotter.is_screenshotting()
     }
 
+    /// A handle for driving the Viewer from the host application, e.g. to select things.
+    pub fn control(&self) -> crate::ViewerControl {
+        crate::ViewerControl::new(self.command_sender.clone(), self.egui_ctx.clone())
+    }
+
     #[allow(clippy::needless_pass_by_ref_mut)]
     pub fn add_log_receiver(&mut self, rx: re_smart_channel::Receiver<LogMsg>) {
         re_log::debug!("Adding new log receiver: {:?}", rx.source());
@@ -456,6 +496,25 @@ impl App {
         self.view_class_registry.add_class::<T>()
     }
 
+    /// Registers the view classes and visualizers of a compiled-in plugin.
+    ///
+    /// Like with [`Self::view_class_registry`], this should be done before the first frame.
+    pub fn add_plugin(
+        &mut self,
+        plugin: &dyn crate::ViewerPlugin,
+    ) -> Result<(), crate::PluginLoadError> {
+        crate::plugin::register_plugin(plugin, &mut self.view_class_registry)
+    }
+
+    /// Adds a stage that the chunks of all the recordings go through before they hit the store,
+    /// e.g. an [`crate::ImageRedaction`].
+    ///
+    /// This should be done before any data is received, as it only applies to the recordings
+    /// that are created afterwards.
+    pub fn add_ingest_transform(&mut self, transform: Arc<dyn re_entity_db::IngestTransform>) {
+        self.ingest_transforms.push(transform);
+    }
+
     /// Accesses the view class registry which can be used to extend the Viewer.
     ///
     /// **WARNING:** Many parts or the viewer assume that all views & visualizers are registered before the first frame is rendered.
@@ -671,6 +730,15 @@ impl App {
                 blueprint_db.drop_entity_path_recursive(&entity_path);
             }
 
+            SystemCommand::DropTimeRange {
+                store_id,
+                timeline,
+                time_range,
+            } => {
+                let entity_db = store_hub.entity_db_mut(&store_id);
+                entity_db.drop_time_range(&timeline, time_range);
+            }
+
             #[cfg(debug_assertions)]
             SystemCommand::EnableInspectBlueprintTimeline(show) => {
                 self.app_options_mut().inspect_blueprint_timeline = show;
@@ -769,6 +837,51 @@ impl App {
                     re_log::error!("Failed to save file: {err}");
                 }
             }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            SystemCommand::ExportView {
+                store_id,
+                view_id,
+                name,
+                timeline,
+                time_range,
+            } => {
+                let Some(recording) = store_hub.store_bundle().get(&store_id) else {
+                    re_log::debug!(
+                        "SystemCommand::ExportView ignored: unknown store ID '{store_id:?}'"
+                    );
+                    return;
+                };
+
+                let path = {
+                    re_tracing::profile_scope!("file_dialog");
+                    rfd::FileDialog::new()
+                        .set_file_name(format!("{name}.mp4"))
+                        .set_title("Export view")
+                        .add_filter("MP4 video", &["mp4"])
+                        .add_filter("PNG frames", &["png"])
+                        .save_file()
+                };
+                let Some(path) = path else {
+                    return;
+                };
+
+                let fps = self
+                    .state
+                    .recording_config(&store_id)
+                    .and_then(|rec_cfg| rec_cfg.time_ctrl.read().fps())
+                    .unwrap_or(30.0);
+                let ffmpeg_path = self.app_options().video_decoder_settings().ffmpeg_path;
+                match crate::view_exporter::ViewExport::new(
+                    recording, view_id, timeline, time_range, path, fps,
+                ) {
+                    Ok(export) => {
+                        self.view_exporter
+                            .start(export, ffmpeg_path, &self.command_sender);
+                    }
+                    Err(err) => re_log::error!("Failed to export: {err}"),
+                }
+            }
         }
     }
 
@@ -916,10 +1029,12 @@ impl App {
             })
         };
 
-        match data_source
-            .clone()
-            .stream(&self.connection_registry, on_cmd, Some(waker))
-        {
+        match data_source.clone().stream(
+            &self.connection_registry,
+            &self.startup_options.rrd_load_options,
+            on_cmd,
+            Some(waker),
+        ) {
             Ok(re_data_source::StreamSource::LogMessages(rx)) => self.add_log_receiver(rx),
 
             Ok(re_data_source::StreamSource::CatalogUri(uri)) => {
@@ -1222,6 +1337,9 @@ impl App {
                 app_blueprint.toggle_selection_panel(&self.command_sender);
             }
             UICommand::ToggleTimePanel => app_blueprint.toggle_time_panel(&self.command_sender),
+            UICommand::ToggleTransformTreePanel => {
+                self.state.transform_tree_panel_open ^= true;
+            }
 
             UICommand::ToggleChunkStoreBrowser => match self.state.navigation.peek() {
                 DisplayMode::LocalRecordings
@@ -1356,6 +1474,24 @@ impl App {
                 self.run_copy_time_range_link_command(store_context);
             }
 
+            UICommand::DeleteTimeSelection => {
+                self.run_delete_time_selection_command(store_context);
+            }
+
+            UICommand::AddBookmark => {
+                self.run_add_bookmark_command(store_context);
+            }
+            UICommand::ExportClipRrd => {
+                if let Err(err) = export_clip(self, store_context, ClipFormat::Rrd) {
+                    re_log::error!("Failed to export clip: {err}");
+                }
+            }
+            UICommand::ExportClipMcap => {
+                if let Err(err) = export_clip(self, store_context, ClipFormat::Mcap) {
+                    re_log::error!("Failed to export clip: {err}");
+                }
+            }
+
             #[cfg(target_arch = "wasm32")]
             UICommand::RestartWithWebGl => {
                 if crate::web_tools::set_url_parameter_and_refresh("renderer", "webgl").is_err() {
@@ -1459,10 +1595,10 @@ impl App {
                 time_ctrl.set_play_state(times_per_timeline, PlayState::Following);
             }
             TimeControlCommand::StepBack => {
-                time_ctrl.step_time_back(times_per_timeline);
+                time_ctrl.step_time_back(entity_db);
             }
             TimeControlCommand::StepForward => {
-                time_ctrl.step_time_fwd(times_per_timeline);
+                time_ctrl.step_time_fwd(entity_db);
             }
             TimeControlCommand::Restart => {
                 time_ctrl.restart(times_per_timeline);
@@ -1470,6 +1606,48 @@ impl App {
         }
     }
 
+    /// Starts the view export requested on the command line, once all data has been loaded.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn start_pending_view_export(
+        &mut self,
+        store_context: &StoreContext<'_>,
+        blueprint_query: &re_chunk_store::LatestAtQuery,
+    ) {
+        if !self.view_exporter.has_pending_export() {
+            return;
+        }
+
+        // The proxy server never finishes, so it's ignored.
+        let is_loading = self
+            .rx_log
+            .sources()
+            .iter()
+            .any(|source| !matches!(**source, SmartChannelSource::MessageProxy { .. }));
+        if is_loading {
+            return;
+        }
+
+        let viewport = re_viewport_blueprint::ViewportBlueprint::from_db(
+            store_context.blueprint,
+            blueprint_query,
+        );
+        let active_timeline = *self
+            .state
+            .recording_config_mut(store_context.recording)
+            .time_ctrl
+            .get_mut()
+            .timeline();
+        let ffmpeg_path = self.app_options().video_decoder_settings().ffmpeg_path;
+
+        self.view_exporter.start_pending(
+            store_context.recording,
+            &viewport,
+            active_timeline,
+            ffmpeg_path,
+            &self.command_sender,
+        );
+    }
+
     /// Retrieve the link to the current viewer.
     #[cfg(target_arch = "wasm32")]
     fn get_viewer_url(&self) -> Result<String, wasm_bindgen::JsValue> {
@@ -1523,6 +1701,53 @@ impl App {
         Some(())
     }
 
+    fn run_delete_time_selection_command(&mut self, store_context: Option<&StoreContext<'_>>) {
+        let Some(entity_db) = store_context.as_ref().map(|ctx| ctx.recording) else {
+            re_log::warn!("Could not delete time range: No active recording");
+            return;
+        };
+
+        let rec_cfg = self.state.recording_config_mut(entity_db);
+        let time_ctrl = rec_cfg.time_ctrl.get_mut();
+
+        let Some(range) = time_ctrl.loop_selection() else {
+            re_log::warn!(
+                "Could not delete time range: No loop selection set. Use shift to drag a selection on the timeline"
+            );
+            return;
+        };
+
+        // Only drop the times that are fully inside the selection.
+        let time_range = re_log_types::AbsoluteTimeRange::new(range.min.ceil(), range.max.floor());
+        let timeline = *time_ctrl.timeline().name();
+        time_ctrl.remove_loop_selection();
+
+        self.command_sender
+            .send_system(SystemCommand::DropTimeRange {
+                store_id: entity_db.store_id().clone(),
+                timeline,
+                time_range,
+            });
+    }
+
+    fn run_add_bookmark_command(&mut self, store_context: Option<&StoreContext<'_>>) {
+        let Some(entity_db) = store_context.as_ref().map(|ctx| ctx.recording) else {
+            re_log::warn!("Could not add bookmark: No active recording");
+            return;
+        };
+
+        let rec_cfg = self.state.recording_config_mut(entity_db);
+        let time_ctrl = rec_cfg.time_ctrl.get_mut();
+
+        let Some(time) = time_ctrl.time_int() else {
+            re_log::warn!("Could not add bookmark: No current time");
+            return;
+        };
+        let timeline = *time_ctrl.timeline().name();
+
+        rec_cfg.bookmarks.get_mut().add(timeline, time);
+    }
+
     fn run_copy_time_range_link_command(&mut self, store_context: Option<&StoreContext<'_>>) {
         let Some(entity_db) = store_context.as_ref().map(|ctx| ctx.recording) else {
             re_log::warn!("Could not copy time range link: No active recording");
@@ -1708,6 +1933,11 @@ impl App {
                                 &self.async_runtime,
                                 &self.egui_ctx,
                             );
+
+                            crate::custom_component_reflection::register_custom_component_reflection(
+                                &mut self.reflection,
+                                store_context.recording,
+                            );
                         }
 
                         self.state.show(
@@ -1787,25 +2017,43 @@ impl App {
 
         let start = web_time::Instant::now();
 
-        while let Some((channel_source, msg)) = self.rx_log.try_recv() {
-            re_log::trace!("Received a message from {channel_source:?}"); // Used by `test_ui_wakeup` test app!
+        // Messages of atomic batches are held back until the whole batch has been received,
+        // and are then all applied within the same frame.
+        let mut atomic_batches = self.atomic_batches.lock();
+        let mut ready = std::collections::VecDeque::new();
+        atomic_batches.release_stale(&mut ready);
 
-            let msg = match msg.payload {
-                re_smart_channel::SmartMessagePayload::Msg(msg) => msg,
+        loop {
+            let Some((channel_source, msg)) = ready.pop_front() else {
+                let Some((channel_source, msg)) = self.rx_log.try_recv() else {
+                    break;
+                };
 
-                re_smart_channel::SmartMessagePayload::Flush { on_flush_done } => {
-                    on_flush_done();
-                    continue;
-                }
+                re_log::trace!("Received a message from {channel_source:?}"); // Used by `test_ui_wakeup` test app!
 
-                re_smart_channel::SmartMessagePayload::Quit(err) => {
-                    if let Some(err) = err {
-                        re_log::warn!("Data source {} has left unexpectedly: {err}", msg.source);
-                    } else {
-                        re_log::debug!("Data source {} has finished", msg.source);
+                match msg.payload {
+                    re_smart_channel::SmartMessagePayload::Msg(msg) => {
+                        atomic_batches.on_msg(channel_source, msg, &mut ready);
+                    }
+
+                    re_smart_channel::SmartMessagePayload::Flush { on_flush_done } => {
+                        on_flush_done();
+                    }
+
+                    re_smart_channel::SmartMessagePayload::Quit(err) => {
+                        if let Some(err) = err {
+                            re_log::warn!(
+                                "Data source {} has left unexpectedly: {err}",
+                                msg.source
+                            );
+                        } else {
+                            re_log::debug!("Data source {} has finished", msg.source);
+                        }
+                        atomic_batches.on_source_left(&channel_source, &mut ready);
                     }
-                    continue;
                 }
+
+                continue;
             };
 
             let store_id = msg.store_id();
@@ -1829,6 +2077,14 @@ impl App {
                 if entity_db.data_source.is_none() {
                     entity_db.data_source = Some((*channel_source).clone());
                 }
+                if entity_db.is_empty()
+                    && entity_db.ingest_transforms().is_empty()
+                    && entity_db.store_kind() == StoreKind::Recording
+                {
+                    for transform in &self.ingest_transforms {
+                        entity_db.add_ingest_transform(transform.clone());
+                    }
+                }
                 entity_db.is_empty()
             };
 
@@ -1943,7 +2199,8 @@ impl App {
                 }
             }
 
-            if start.elapsed() > web_time::Duration::from_millis(10) {
+            // Never stop halfway through an atomic batch.
+            if ready.is_empty() && start.elapsed() > web_time::Duration::from_millis(10) {
                 egui_ctx.request_repaint(); // make sure we keep receiving messages asap
                 break; // don't block the main thread for too long
             }
@@ -2332,6 +2589,17 @@ impl App {
                 }
             }
         } else {
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(frame) = user_data
+                .data
+                .as_ref()
+                .and_then(|data| data.downcast_ref::<crate::view_exporter::ExportedFrame>())
+            {
+                self.view_exporter
+                    .save_frame(image, frame, &self.command_sender);
+                return;
+            }
+
             #[cfg(not(target_arch = "wasm32"))] // no full-app screenshotting on web
             self.screenshotter.save(&self.egui_ctx, image);
         }
@@ -2493,7 +2761,7 @@ impl eframe::App for App {
         }
 
         #[cfg(not(target_arch = "wasm32"))]
-        if self.screenshotter.update(egui_ctx).quit {
+        if self.screenshotter.update(egui_ctx).quit || self.view_exporter.update(egui_ctx).quit {
             egui_ctx.send_viewport_cmd(egui::ViewportCommand::Close);
             return;
         }
@@ -2567,10 +2835,16 @@ impl eframe::App for App {
 
         self.receive_messages(&mut store_hub, egui_ctx);
 
+        crate::derived_series::sync_derived_series(&mut store_hub);
+
         if self.app_options().blueprint_gc {
             store_hub.gc_blueprints(&self.state.blueprint_undo_state);
         }
 
+        if self.app_options().background_compaction {
+            store_hub.compact_recordings();
+        }
+
         store_hub.purge_empty();
         self.state.cleanup(&store_hub);
 
@@ -2619,6 +2893,11 @@ impl eframe::App for App {
                 },
             );
 
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(store_context) = &store_context {
+                self.start_pending_view_export(store_context, &blueprint_query);
+            }
+
             let app_blueprint = AppBlueprint::new(
                 store_context.as_ref().map(|ctx| ctx.blueprint),
                 &blueprint_query,
@@ -2873,14 +3152,7 @@ fn save_recording(
         .and_then(|info| info.store_version)
         .unwrap_or(re_build_info::CrateVersion::LOCAL);
 
-    let file_name = if let Some(recording_name) = entity_db
-        .recording_info_property::<re_types::components::Name>(
-            &re_types::archetypes::RecordingInfo::descriptor_name(),
-        ) {
-        format!("{}.rrd", santitize_file_name(&recording_name))
-    } else {
-        "data.rrd".to_owned()
-    };
+    let file_name = recording_file_name(entity_db, "rrd");
 
     let title = if loop_selection.is_some() {
         "Save loop selection"
@@ -2897,6 +3169,131 @@ fn save_recording(
     )
 }
 
+/// The file name to suggest when saving (parts of) a recording.
+fn recording_file_name(entity_db: &EntityDb, extension: &str) -> String {
+    if let Some(recording_name) = entity_db.recording_info_property::<re_types::components::Name>(
+        &re_types::archetypes::RecordingInfo::descriptor_name(),
+    ) {
+        format!("{}.{extension}", santitize_file_name(&recording_name))
+    } else {
+        format!("data.{extension}")
+    }
+}
+
+#[derive(Clone, Copy)]
+enum ClipFormat {
+    Rrd,
+    Mcap,
+}
+
+/// Saves the data within the loop selection to a new file.
+///
+/// Only the selected entities and their children are exported, or all entities if none are selected.
+fn export_clip(
+    app: &mut App,
+    store_context: Option<&StoreContext<'_>>,
+    format: ClipFormat,
+) -> anyhow::Result<()> {
+    let Some(entity_db) = store_context.as_ref().map(|view| view.recording) else {
+        anyhow::bail!("No active recording");
+    };
+
+    let Some((timeline, range)) = app.state.loop_selection(store_context) else {
+        anyhow::bail!("No loop selection set. Use shift to drag a selection on the timeline");
+    };
+    let time_range = re_log_types::AbsoluteTimeRange::new(range.min.floor(), range.max.ceil());
+
+    let selected_entities = app
+        .state
+        .selection_state
+        .selected_items()
+        .iter_items()
+        .filter_map(Item::entity_path)
+        .cloned()
+        .collect_vec();
+    let entity_filter = |entity_path: &re_log_types::EntityPath| {
+        selected_entities.is_empty()
+            || selected_entities
+                .iter()
+                .any(|selected| entity_path.starts_with(selected))
+    };
+
+    match format {
+        ClipFormat::Rrd => {
+            let rrd_version = entity_db
+                .store_info()
+                .and_then(|info| info.store_version)
+                .unwrap_or(re_build_info::CrateVersion::LOCAL);
+
+            save_entity_db(
+                app,
+                rrd_version,
+                recording_file_name(entity_db, "rrd"),
+                "Export clip".to_owned(),
+                entity_db.clip_to_messages(&timeline, time_range, entity_filter),
+            )
+        }
+
+        ClipFormat::Mcap => {
+            let chunks = entity_db.clip_chunks(&timeline, time_range, entity_filter);
+
+            let mut mcap = std::io::Cursor::new(Vec::new());
+            re_mcap::export::write_chunks_as_json(&mut mcap, &chunks, &timeline)?;
+
+            save_bytes(
+                app,
+                recording_file_name(entity_db, "mcap"),
+                "Export clip".to_owned(),
+                mcap.into_inner(),
+            )
+        }
+    }
+}
+
+fn save_bytes(
+    #[allow(unused_variables)] app: &mut App, // only used on native
+    file_name: String,
+    title: String,
+    bytes: Vec<u8>,
+) -> anyhow::Result<()> {
+    // Web
+    #[cfg(target_arch = "wasm32")]
+    {
+        wasm_bindgen_futures::spawn_local(async move {
+            let file_handle = rfd::AsyncFileDialog::new()
+                .set_file_name(&file_name)
+                .set_title(&title)
+                .save_file()
+                .await;
+            if let Some(file_handle) = file_handle
+                && let Err(err) = file_handle.write(&bytes).await
+            {
+                re_log::error!("File saving failed: {err}");
+            }
+        });
+    }
+
+    // Native
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let path = {
+            re_tracing::profile_scope!("file_dialog");
+            rfd::FileDialog::new()
+                .set_file_name(file_name)
+                .set_title(title)
+                .save_file()
+        };
+        if let Some(path) = path {
+            app.background_tasks.spawn_file_saver(move || {
+                std::fs::write(&path, bytes)?;
+                Ok(path)
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
 fn save_blueprint(app: &mut App, store_context: Option<&StoreContext<'_>>) -> anyhow::Result<()> {
     let Some(store_context) = store_context else {
         anyhow::bail!("No blueprint to save");
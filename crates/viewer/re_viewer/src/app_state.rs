@@ -23,8 +23,8 @@ use re_viewport_blueprint::ViewportBlueprint;
 use re_viewport_blueprint::ui::add_view_or_container_modal_ui;
 
 use crate::{
-    app_blueprint::AppBlueprint, event::ViewerEventDispatcher, navigation::Navigation, open_url,
-    ui::settings_screen_ui,
+    app_blueprint::AppBlueprint, audio_playback::AudioPlayback, event::ViewerEventDispatcher,
+    navigation::Navigation, open_url, query_prefetcher::QueryPrefetcher, ui::settings_screen_ui,
 };
 
 const WATERMARK: bool = false; // Nice for recording media material
@@ -85,6 +85,20 @@ pub struct AppState {
     /// that last several frames.
     #[serde(skip)]
     pub(crate) focused_item: Option<Item>,
+
+    /// Plays the audio entities of the active recording along with the timeline.
+    #[serde(skip)]
+    audio_playback: AudioPlayback,
+
+    /// Warms up the query caches of the active recording ahead of playback.
+    #[serde(skip)]
+    query_prefetcher: QueryPrefetcher,
+
+    #[serde(skip)]
+    transform_tree_panel: re_view_spatial::TransformTreePanel,
+
+    #[serde(skip)]
+    pub(crate) transform_tree_panel_open: bool,
 }
 
 impl Default for AppState {
@@ -105,6 +119,10 @@ impl Default for AppState {
             view_states: Default::default(),
             selection_state: Default::default(),
             focused_item: Default::default(),
+            audio_playback: Default::default(),
+            query_prefetcher: Default::default(),
+            transform_tree_panel: Default::default(),
+            transform_tree_panel_open: false,
         }
     }
 }
@@ -219,6 +237,10 @@ impl AppState {
                     view_states,
                     selection_state,
                     focused_item,
+                    audio_playback,
+                    query_prefetcher,
+                    transform_tree_panel,
+                    transform_tree_panel_open,
                     ..
                 } = self;
 
@@ -313,6 +335,8 @@ impl AppState {
                         .collect::<_>()
                 };
 
+                sync_linked_playback(recording_configs, recording.store_id());
+
                 let rec_cfg = recording_config_entry(recording_configs, recording);
                 let egui_ctx = ui.ctx().clone();
                 let display_mode = self.navigation.peek();
@@ -357,6 +381,7 @@ impl AppState {
                 // We move the time at the very start of the frame,
                 // so that we always show the latest data when we're in "follow" mode.
                 move_time(&ctx, recording, rx_log, event_dispatcher);
+                audio_playback.update(&ctx, recording);
 
                 // Update the viewport. May spawn new views and handle queued requests (like screenshots).
                 viewport_ui.on_frame_start(&ctx);
@@ -525,6 +550,31 @@ impl AppState {
                     );
                 }
 
+                //
+                // Transform tree panel
+                //
+
+                if *display_mode == DisplayMode::LocalRecordings {
+                    egui::SidePanel::right("transform_tree_panel")
+                        .min_width(120.0)
+                        .default_width(300.0)
+                        .resizable(true)
+                        .frame(egui::Frame {
+                            fill: ui.style().visuals.panel_fill,
+                            ..Default::default()
+                        })
+                        .show_animated_inside(ui, *transform_tree_panel_open, |ui| {
+                            ui.panel_content(|ui| {
+                                let hover = "The frames of the transform hierarchy, \
+                                    with the latest transform to their parent frame";
+                                ui.panel_title_bar("Transform tree", Some(hover));
+                            });
+                            ui.panel_content(|ui| {
+                                transform_tree_panel.ui(&ctx, ui);
+                            });
+                        });
+                }
+
                 //
                 // Left panel (recordings and blueprint)
                 //
@@ -659,6 +709,9 @@ impl AppState {
                 // Process deferred layout operations and apply updates back to blueprint:
                 viewport_ui.save_to_blueprint_store(&ctx);
 
+                // Now that the views are done, get the caches ready for where playback is heading.
+                query_prefetcher.update(&ctx, recording);
+
                 self.redap_servers.modals_ui(&ctx.global_context, ui);
             }
         }
@@ -767,14 +820,18 @@ fn move_time(
     };
 
     let should_diff_time_ctrl = ctx.has_active_recording();
-    let recording_time_ctrl_response = ctx.rec_cfg.time_ctrl.write().update(
-        recording.times_per_timeline(),
-        dt,
-        more_data_is_coming,
-        // The state diffs are used to trigger callbacks if they are configured.
-        // Unless we have a real recording open, we should not actually trigger any callbacks.
-        should_diff_time_ctrl,
-    );
+    let recording_time_ctrl_response = {
+        let mut time_ctrl = ctx.rec_cfg.time_ctrl.write();
+        time_ctrl.set_recording_default_timeline(recording.default_timeline());
+        time_ctrl.update(
+            recording.times_per_timeline(),
+            dt,
+            more_data_is_coming,
+            // The state diffs are used to trigger callbacks if they are configured.
+            // Unless we have a real recording open, we should not actually trigger any callbacks.
+            should_diff_time_ctrl,
+        )
+    };
 
     handle_time_ctrl_event(recording, events, &recording_time_ctrl_response);
 
@@ -825,6 +882,33 @@ fn handle_time_ctrl_event(
     }
 }
 
+/// Moves the recordings whose playback is linked to the active recording along with it.
+fn sync_linked_playback(recording_configs: &HashMap<StoreId, RecordingConfig>, active: &StoreId) {
+    let Some(leader_cfg) = recording_configs.get(active) else {
+        return;
+    };
+    let Some(leader_link) = *leader_cfg.playback_link.read() else {
+        return;
+    };
+    let leader = leader_cfg.time_ctrl.read();
+    let Some(time) = leader.time() else {
+        return;
+    };
+
+    #[expect(clippy::iter_over_hash_type)] // Each recording is updated independently.
+    for (store_id, rec_cfg) in recording_configs {
+        if store_id == active {
+            continue;
+        }
+        if let Some(link) = *rec_cfg.playback_link.read() {
+            rec_cfg
+                .time_ctrl
+                .write()
+                .follow_linked(&leader, link.time_from(&leader_link, time));
+        }
+    }
+}
+
 pub(crate) fn recording_config_entry<'cfgs>(
     configs: &'cfgs mut HashMap<StoreId, RecordingConfig>,
     entity_db: &'_ EntityDb,
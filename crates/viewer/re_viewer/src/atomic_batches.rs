@@ -0,0 +1,134 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use ahash::HashMap;
+use re_log_types::external::re_tuid::Tuid;
+use re_log_types::{AtomicBatchMetadata, LogMsg};
+use re_smart_channel::SmartChannelSource;
+
+type SourcedMsg = (Arc<SmartChannelSource>, LogMsg);
+
+/// Batches with more messages than this are applied as they come once they reach it.
+///
+/// The length of a batch comes from the data, so it cannot be trusted to be reasonable.
+const MAX_BATCH_LEN: usize = 10_000;
+
+/// Batches still incomplete after this long are applied as is, e.g. if their sender crashed.
+const MAX_BATCH_AGE: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Holds back the messages of atomic batches until all of them have been received, so that the
+/// viewer never renders a partially applied batch.
+///
+/// See `re_sdk::AtomicBatch`.
+#[derive(Default)]
+pub struct AtomicBatches {
+    pending: HashMap<Tuid, PendingBatch>,
+}
+
+struct PendingBatch {
+    len: usize,
+    msgs: Vec<SourcedMsg>,
+
+    /// When the first message of the batch was received.
+    started: web_time::Instant,
+}
+
+impl AtomicBatches {
+    /// Appends the messages that are ready to be applied to `ready`, in order.
+    ///
+    /// A message that isn't part of an atomic batch is ready right away. The messages of an
+    /// atomic batch are all ready at once, as soon as the last one is received.
+    pub fn on_msg(
+        &mut self,
+        channel_source: Arc<SmartChannelSource>,
+        msg: LogMsg,
+        ready: &mut VecDeque<SourcedMsg>,
+    ) {
+        let metadata = match &msg {
+            LogMsg::ArrowMsg(_, arrow_msg) => AtomicBatchMetadata::from_arrow_msg(arrow_msg),
+            LogMsg::SetStoreInfo(_) | LogMsg::BlueprintActivationCommand(_) => None,
+        };
+
+        let Some(AtomicBatchMetadata { id, len }) = metadata.filter(|metadata| metadata.len > 1)
+        else {
+            ready.push_back((channel_source, msg));
+            return;
+        };
+
+        let batch = self.pending.entry(id).or_insert_with(|| PendingBatch {
+            len: len.min(MAX_BATCH_LEN),
+            msgs: Vec::new(),
+            started: web_time::Instant::now(),
+        });
+        batch.msgs.push((channel_source, msg));
+
+        if batch.msgs.len() >= batch.len
+            && let Some(batch) = self.pending.remove(&id)
+        {
+            if batch.len < len {
+                re_log::warn_once!(
+                    "Applying an atomic batch of {len} messages in parts of {MAX_BATCH_LEN}"
+                );
+            }
+            ready.extend(batch.msgs);
+        }
+    }
+
+    /// Releases the batches that have been waiting for their remaining messages for too long.
+    pub fn release_stale(&mut self, ready: &mut VecDeque<SourcedMsg>) {
+        if self.pending.is_empty() {
+            return;
+        }
+
+        let ids = self
+            .pending
+            .iter()
+            .filter(|(_, batch)| batch.started.elapsed() > MAX_BATCH_AGE)
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+
+        for id in ids {
+            if let Some(batch) = self.pending.remove(&id) {
+                re_log::warn_once!(
+                    "Applying an atomic batch that is still incomplete after {MAX_BATCH_AGE:?}: {}/{} messages received",
+                    batch.msgs.len(),
+                    batch.len
+                );
+                ready.extend(batch.msgs);
+            }
+        }
+    }
+
+    /// Releases the incomplete batches of a data source that has left, which will never be
+    /// completed.
+    ///
+    /// Partial data is better than no data at all.
+    pub fn on_source_left(
+        &mut self,
+        channel_source: &SmartChannelSource,
+        ready: &mut VecDeque<SourcedMsg>,
+    ) {
+        let ids = self
+            .pending
+            .iter()
+            .filter(|(_, batch)| {
+                batch
+                    .msgs
+                    .iter()
+                    .any(|(source, _)| source.as_ref() == channel_source)
+            })
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+
+        for id in ids {
+            if let Some(batch) = self.pending.remove(&id) {
+                re_log::debug!(
+                    "Applying an incomplete atomic batch: {}/{} messages received",
+                    batch.msgs.len(),
+                    batch.len
+                );
+                ready.extend(batch.msgs);
+            }
+        }
+    }
+}
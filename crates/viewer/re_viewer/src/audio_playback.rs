@@ -0,0 +1,304 @@
+//! Plays audio entities in sync with the timeline.
+//!
+//! Audio entities are entities with blocks of samples logged as [`Waveform`]s, on a temporal timeline.
+//! While the timeline is playing, every block that starts in the time that passed since the last
+//! frame is handed to the audio output, unless its entity is muted, see [`AudioMixer`].
+//! Samples are expected to be in the range `[-1, 1]`.
+
+use itertools::izip;
+
+use re_chunk_store::{LatestAtQuery, RangeQuery};
+use re_entity_db::EntityDb;
+use re_log_types::{AbsoluteTimeRange, EntityPath, StoreId, TimeInt, TimeType, TimelineName};
+use re_types::{
+    archetypes::Waveform,
+    components::{ChannelCount, SampleRate},
+};
+use re_viewer_context::{AudioMixer, PlayState, ViewerContext};
+
+/// If the time moved further than this between two frames, the user jumped rather than played through it.
+const MAX_PLAYED_NS_PER_FRAME: i64 = 1_000_000_000;
+
+/// A block of samples of an audio entity.
+struct AudioBlock {
+    sample_rate: f32,
+    num_channels: usize,
+
+    /// The samples of all channels, interleaved.
+    samples: Vec<f32>,
+
+    /// How far into the block playback starts, in seconds.
+    offset_secs: f64,
+}
+
+impl AudioBlock {
+    fn duration_secs(&self) -> f64 {
+        (self.samples.len() / self.num_channels) as f64 / f64::from(self.sample_rate)
+    }
+}
+
+/// Audio playback of the active recording.
+#[derive(Default)]
+pub struct AudioPlayback {
+    /// The recording, timeline and time up to which blocks were played.
+    played_up_to: Option<(StoreId, TimelineName, TimeInt)>,
+
+    /// Created the first time anything is played.
+    output: Option<AudioOutput>,
+}
+
+impl AudioPlayback {
+    /// Plays the blocks that started since the last frame, or stops playback if the timeline is paused.
+    pub fn update(&mut self, ctx: &ViewerContext<'_>, recording: &EntityDb) {
+        if !AudioOutput::IS_SUPPORTED {
+            return;
+        }
+
+        re_tracing::profile_function!();
+
+        let (timeline, now, speed) = {
+            let time_ctrl = ctx.rec_cfg.time_ctrl.read();
+            let is_playing = time_ctrl.play_state() != PlayState::Paused
+                && time_ctrl.time_type() != TimeType::Sequence;
+            match time_ctrl.time_int() {
+                Some(now) if is_playing => (*time_ctrl.timeline().name(), now, time_ctrl.speed()),
+                _ => {
+                    self.stop();
+                    return;
+                }
+            }
+        };
+
+        let played_up_to = match &self.played_up_to {
+            Some((store_id, played_timeline, played_up_to))
+                if store_id == recording.store_id()
+                    && *played_timeline == timeline
+                    && (0..=MAX_PLAYED_NS_PER_FRAME)
+                        .contains(&(now.as_i64() - played_up_to.as_i64())) =>
+            {
+                Some(*played_up_to)
+            }
+            _ => {
+                // Playback (re)started, possibly in the middle of blocks.
+                self.stop();
+                None
+            }
+        };
+        self.played_up_to = Some((recording.store_id().clone(), timeline, now));
+
+        if played_up_to == Some(now) {
+            return;
+        }
+
+        let audio_mixer = ctx.rec_cfg.audio_mixer.read();
+        let blocks = audio_blocks(recording, timeline, played_up_to, now, &audio_mixer);
+        if blocks.is_empty() {
+            return;
+        }
+
+        let output = self.output.get_or_insert_with(AudioOutput::default);
+        for block in &blocks {
+            output.play(block, speed);
+        }
+    }
+
+    fn stop(&mut self) {
+        self.played_up_to = None;
+        if let Some(output) = &mut self.output {
+            output.stop();
+        }
+    }
+}
+
+/// The blocks of all audible entities that start in `(played_up_to, now]`.
+///
+/// If `played_up_to` is `None`, the blocks that are still playing at `now` are included as well.
+fn audio_blocks(
+    recording: &EntityDb,
+    timeline: TimelineName,
+    played_up_to: Option<TimeInt>,
+    now: TimeInt,
+    audio_mixer: &AudioMixer,
+) -> Vec<AudioBlock> {
+    re_tracing::profile_function!();
+
+    let samples_descr = Waveform::descriptor_samples();
+    let sample_rate_descr = Waveform::descriptor_sample_rate();
+    let channel_count_descr = Waveform::descriptor_channel_count();
+    let latest_at_now = LatestAtQuery::new(timeline, now);
+
+    let mut blocks = Vec::new();
+    for entity_path in recording.entity_paths() {
+        if !audio_mixer.is_audible(entity_path)
+            || !recording
+                .storage_engine()
+                .store()
+                .entity_has_temporal_component_on_timeline(&timeline, entity_path, &samples_descr)
+        {
+            continue;
+        }
+
+        let results = recording.latest_at(
+            &latest_at_now,
+            entity_path,
+            [&sample_rate_descr, &channel_count_descr],
+        );
+        let Some(sample_rate) = results.component_mono::<SampleRate>(&sample_rate_descr) else {
+            continue;
+        };
+        let num_channels = results
+            .component_mono::<ChannelCount>(&channel_count_descr)
+            .map_or(1, |channel_count| channel_count.0.0.max(1) as usize);
+
+        let Some(first_start) = played_up_to
+            .map(|played_up_to| played_up_to.inc())
+            .or_else(|| latest_block_start(recording, &latest_at_now, entity_path))
+        else {
+            continue;
+        };
+
+        let query = RangeQuery::new(timeline, AbsoluteTimeRange::new(first_start, now));
+        let chunks = recording.storage_engine().store().range_relevant_chunks(
+            &query,
+            entity_path,
+            &samples_descr,
+        );
+        for chunk in chunks {
+            for ((start, _row_id), samples) in izip!(
+                chunk.iter_component_indices(&timeline, &samples_descr),
+                chunk.iter_slices::<f64>(samples_descr.clone())
+            ) {
+                if start < first_start || now < start {
+                    continue;
+                }
+
+                let block = AudioBlock {
+                    sample_rate: sample_rate.0.0 as f32,
+                    num_channels,
+                    samples: samples.iter().map(|&sample| sample as f32).collect(),
+                    offset_secs: (now.as_i64() - start.as_i64()) as f64 * 1e-9,
+                };
+                if block.offset_secs < block.duration_secs() {
+                    blocks.push(block);
+                }
+            }
+        }
+    }
+    blocks
+}
+
+/// The start of the latest block of `entity_path` as of `query`.
+fn latest_block_start(
+    recording: &EntityDb,
+    query: &LatestAtQuery,
+    entity_path: &EntityPath,
+) -> Option<TimeInt> {
+    let at = query.at().as_i64();
+    recording
+        .storage_engine()
+        .store()
+        .latest_at_relevant_chunks(query, entity_path, &Waveform::descriptor_samples())
+        .iter()
+        .filter_map(|chunk| {
+            chunk
+                .timelines()
+                .get(&query.timeline())?
+                .times_raw()
+                .iter()
+                .copied()
+                .filter(|&time| time <= at)
+                .max()
+        })
+        .max()
+        .map(TimeInt::new_temporal)
+}
+
+// ----------------------------------------------------------------------------
+
+/// Plays blocks with the Web Audio API.
+#[cfg(target_arch = "wasm32")]
+#[derive(Default)]
+struct AudioOutput {
+    context: Option<web_sys::AudioContext>,
+
+    /// The sources that are playing or scheduled, and when they end, in the time of `context`.
+    sources: Vec<(web_sys::AudioBufferSourceNode, f64)>,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl AudioOutput {
+    const IS_SUPPORTED: bool = true;
+
+    fn play(&mut self, block: &AudioBlock, speed: f32) {
+        if let Err(err) = self.try_play(block, speed) {
+            re_log::warn_once!(
+                "Failed to play audio: {}",
+                crate::web_tools::string_from_js_value(err)
+            );
+        }
+    }
+
+    fn try_play(&mut self, block: &AudioBlock, speed: f32) -> Result<(), wasm_bindgen::JsValue> {
+        let context = match &self.context {
+            Some(context) => context,
+            None => self.context.insert(web_sys::AudioContext::new()?),
+        };
+        if context.state() == web_sys::AudioContextState::Suspended {
+            // Browsers only allow starting audio after a user interaction, like pressing play.
+            let _promise = context.resume()?;
+        }
+
+        let num_frames = block.samples.len() / block.num_channels;
+        let buffer = context.create_buffer(
+            block.num_channels as u32,
+            num_frames as u32,
+            block.sample_rate,
+        )?;
+        for channel in 0..block.num_channels {
+            let channel_samples: Vec<f32> = block
+                .samples
+                .iter()
+                .skip(channel)
+                .step_by(block.num_channels)
+                .copied()
+                .collect();
+            buffer.copy_to_channel(&channel_samples, channel as i32)?;
+        }
+
+        let source = context.create_buffer_source()?;
+        source.set_buffer(Some(&buffer));
+        source.playback_rate().set_value(speed);
+        source.connect_with_audio_node(&context.destination())?;
+
+        let now = context.current_time();
+        source.start_with_when_and_grain_offset(now, block.offset_secs)?;
+
+        let end = now + (block.duration_secs() - block.offset_secs) / f64::from(speed);
+        self.sources.retain(|(_, source_end)| now < *source_end);
+        self.sources.push((source, end));
+
+        Ok(())
+    }
+
+    fn stop(&mut self) {
+        for (source, _) in self.sources.drain(..) {
+            source.stop().ok();
+        }
+    }
+}
+
+/// There is no audio backend for the native viewer yet, so nothing is played.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Default)]
+struct AudioOutput;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl AudioOutput {
+    const IS_SUPPORTED: bool = false;
+
+    #[allow(clippy::unused_self)]
+    fn play(&self, _block: &AudioBlock, _speed: f32) {}
+
+    #[allow(clippy::unused_self)]
+    fn stop(&self) {}
+}
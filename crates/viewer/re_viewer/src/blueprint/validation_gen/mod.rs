@@ -28,11 +28,13 @@ pub use re_types::blueprint::components::LockRangeDuringZoom;
 pub use re_types::blueprint::components::MapProvider;
 pub use re_types::blueprint::components::NearClipPlane;
 pub use re_types::blueprint::components::PanelState;
+pub use re_types::blueprint::components::PlaybackRate;
 pub use re_types::blueprint::components::QueryExpression;
 pub use re_types::blueprint::components::RootContainer;
 pub use re_types::blueprint::components::RowShare;
 pub use re_types::blueprint::components::SelectedColumns;
 pub use re_types::blueprint::components::TensorDimensionIndexSlider;
+pub use re_types::blueprint::components::TimeOffset;
 pub use re_types::blueprint::components::TimelineName;
 pub use re_types::blueprint::components::ViewClass;
 pub use re_types::blueprint::components::ViewFit;
@@ -72,11 +74,13 @@ pub fn is_valid_blueprint(blueprint: &EntityDb) -> bool {
         && validate_component::<MapProvider>(blueprint)
         && validate_component::<NearClipPlane>(blueprint)
         && validate_component::<PanelState>(blueprint)
+        && validate_component::<PlaybackRate>(blueprint)
         && validate_component::<QueryExpression>(blueprint)
         && validate_component::<RootContainer>(blueprint)
         && validate_component::<RowShare>(blueprint)
         && validate_component::<SelectedColumns>(blueprint)
         && validate_component::<TensorDimensionIndexSlider>(blueprint)
+        && validate_component::<TimeOffset>(blueprint)
         && validate_component::<TimelineName>(blueprint)
         && validate_component::<ViewClass>(blueprint)
         && validate_component::<ViewFit>(blueprint)
@@ -0,0 +1,89 @@
+//! Picks up the [`CustomComponentReflection`] that applications register for their own components.
+
+use re_chunk_store::LatestAtQuery;
+use re_entity_db::EntityDb;
+use re_log_types::TimelineName;
+use re_types::{ComponentType, CustomComponentReflection, components::Text};
+use re_types_core::reflection::{ComponentReflection, Reflection};
+
+/// Adds the custom component reflection logged to the recording to the reflection of the Viewer.
+///
+/// Components that already have reflection, in particular the built-in ones, are left alone.
+/// The reflection is only added once the component was logged, since its datatype is needed.
+pub fn register_custom_component_reflection(reflection: &mut Reflection, recording: &EntityDb) {
+    let Some(subtree) = recording
+        .tree()
+        .subtree(&CustomComponentReflection::entity_path_root())
+    else {
+        return;
+    };
+
+    let query = LatestAtQuery::latest(TimelineName::log_tick());
+    for (part, child) in &subtree.children {
+        let component_type = ComponentType::from(part.unescaped_str());
+        if reflection.components.contains_key(&component_type) {
+            continue;
+        }
+
+        let Some(datatype) = recording
+            .storage_engine()
+            .store()
+            .lookup_datatype(&component_type)
+        else {
+            continue; // Not logged yet.
+        };
+
+        let results = recording.latest_at(
+            &query,
+            &child.path,
+            CustomComponentReflection::all_descriptors().iter(),
+        );
+        let Some(custom) = CustomComponentReflection::from_fields(
+            results.component_batch::<Text>(&CustomComponentReflection::descriptor_display_name()),
+            results.component_batch::<Text>(&CustomComponentReflection::descriptor_docstring_md()),
+            results.component_batch::<Text>(&CustomComponentReflection::descriptor_field_names()),
+            results.component_batch::<Text>(
+                &CustomComponentReflection::descriptor_field_docstrings_md(),
+            ),
+        ) else {
+            continue;
+        };
+
+        re_log::debug!("Registered reflection for custom component {component_type}");
+
+        // The reflection of the Viewer is static, but this only happens once per component type.
+        let docstring_md: &'static str = Box::leak(docstring_md(&custom).into_boxed_str());
+        reflection.components.insert(
+            component_type,
+            ComponentReflection {
+                docstring_md,
+                deprecation_summary: None,
+                custom_placeholder: None,
+                datatype,
+                verify_arrow_array: |_| Ok(()),
+            },
+        );
+    }
+}
+
+/// Merges the display name and the field docs into the docstring, which is what the UI shows.
+fn docstring_md(custom: &CustomComponentReflection) -> String {
+    use std::fmt::Write as _;
+
+    let mut docstring_md = String::new();
+    if let Some(display_name) = &custom.display_name {
+        writeln!(docstring_md, "**{display_name}**: {}", custom.docstring_md).ok();
+    } else {
+        docstring_md.push_str(&custom.docstring_md);
+        docstring_md.push('\n');
+    }
+
+    if !custom.fields.is_empty() {
+        docstring_md.push_str("\nFields:\n");
+        for field in &custom.fields {
+            writeln!(docstring_md, "* `{}`: {}", field.name, field.docstring_md).ok();
+        }
+    }
+
+    docstring_md
+}
@@ -15,6 +15,7 @@ fn populate_view_class_registry_with_builtin(
     view_class_registry.add_class::<re_view_bar_chart::BarChartView>()?;
     view_class_registry.add_class::<re_view_dataframe::DataframeView>()?;
     view_class_registry.add_class::<re_view_graph::GraphView>()?;
+    view_class_registry.add_class::<re_view_image_compare::ImageCompareView>()?;
     #[cfg(feature = "map_view")]
     view_class_registry.add_class::<re_view_map::MapView>()?;
     view_class_registry.add_class::<re_view_spatial::SpatialView2D>()?;
@@ -0,0 +1,100 @@
+//! Keeps the derived series of the active recording in sync with the definitions in its blueprint.
+
+use re_chunk_store::{DerivedSeries, Expression, LatestAtQuery};
+use re_entity_db::EntityDb;
+use re_log_types::EntityPath;
+use re_types::{DerivedSeriesDefinition, components::Text};
+use re_viewer_context::{StoreHub, blueprint_timeline};
+
+/// Registers the [`DerivedSeriesDefinition`]s of the active blueprint with the active recording.
+///
+/// Only definitions that changed are (re-)registered, and the series whose definition was removed
+/// from the blueprint are dropped along with their values.
+pub fn sync_derived_series(store_hub: &mut StoreHub) {
+    let Some(definitions) = store_hub.active_blueprint().map(definitions) else {
+        return;
+    };
+    let Some(recording) = store_hub.active_recording_mut() else {
+        return;
+    };
+
+    let registered: Vec<DerivedSeries> = recording
+        .storage_engine()
+        .store()
+        .derived_series()
+        .cloned()
+        .collect();
+    if definitions.is_empty() && registered.is_empty() {
+        return;
+    }
+
+    re_tracing::profile_function!();
+
+    for derived in &definitions {
+        if registered.contains(derived) {
+            continue;
+        }
+
+        if let Err(err) = recording.register_derived_series(derived.clone()) {
+            re_log::warn_once!("Failed to derive series {}: {err}", derived.entity_path);
+        }
+    }
+
+    let removed: Vec<&EntityPath> = registered
+        .iter()
+        .map(|derived| &derived.entity_path)
+        .filter(|entity_path| {
+            !definitions
+                .iter()
+                .any(|derived| &derived.entity_path == *entity_path)
+        })
+        .collect();
+    for entity_path in removed {
+        recording.unregister_derived_series(entity_path);
+    }
+}
+
+/// All the valid definitions in the blueprint.
+fn definitions(blueprint: &EntityDb) -> Vec<DerivedSeries> {
+    let Some(subtree) = blueprint
+        .tree()
+        .subtree(&DerivedSeriesDefinition::entity_path_root())
+    else {
+        return Vec::new();
+    };
+
+    let query = LatestAtQuery::latest(blueprint_timeline());
+    subtree
+        .children
+        .values()
+        .filter_map(|child| {
+            let results = blueprint.latest_at(
+                &query,
+                &child.path,
+                DerivedSeriesDefinition::all_descriptors().iter(),
+            );
+            let definition = DerivedSeriesDefinition::from_fields(
+                results.component_batch::<Text>(&DerivedSeriesDefinition::descriptor_entity_path()),
+                results.component_batch::<Text>(
+                    &DerivedSeriesDefinition::descriptor_source_entity_path(),
+                ),
+                results.component_batch::<Text>(&DerivedSeriesDefinition::descriptor_expression()),
+            )?;
+
+            match Expression::parse(&definition.expression) {
+                Ok(expression) => Some(DerivedSeries {
+                    entity_path: definition.entity_path,
+                    source_entity_path: definition.source_entity_path,
+                    expression,
+                }),
+                Err(err) => {
+                    re_log::warn_once!(
+                        "Invalid expression for derived series {}: {err}",
+                        definition.entity_path
+                    );
+                    None
+                }
+            }
+        })
+        .collect()
+}
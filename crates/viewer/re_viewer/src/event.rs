@@ -223,6 +223,22 @@ impl SelectionChangeItem {
 
 pub type ViewerEventCallback = Rc<dyn Fn(ViewerEvent)>;
 
+/// Creates a callback for [`crate::StartupOptions::on_event`] which forwards all events to the returned receiver.
+///
+/// The callback is called on the UI thread, while the receiver can be handed to any thread,
+/// e.g. the one running the analysis code of the host. See [`crate::ViewerControl`] for the other direction.
+pub fn event_channel() -> (
+    ViewerEventCallback,
+    crossbeam::channel::Receiver<ViewerEvent>,
+) {
+    let (tx, rx) = crossbeam::channel::unbounded();
+    let on_event = Rc::new(move |event| {
+        // The host may not be interested in events anymore.
+        tx.send(event).ok();
+    });
+    (on_event, rx)
+}
+
 #[derive(Clone)]
 pub struct ViewerEventDispatcher {
     f: ViewerEventCallback,
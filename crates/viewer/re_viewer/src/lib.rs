@@ -8,17 +8,28 @@
 mod app;
 mod app_blueprint;
 mod app_state;
+mod atomic_batches;
+mod audio_playback;
 mod background_tasks;
+mod custom_component_reflection;
 mod default_views;
+mod derived_series;
 mod docker_detection;
 pub mod env_vars;
 pub mod event;
 mod navigation;
 mod open_url;
+mod plugin;
+mod query_prefetcher;
+mod redaction;
 mod saving;
 mod screenshotter;
 mod startup_options;
 mod ui;
+mod viewer_control;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod view_exporter;
 
 #[cfg(feature = "analytics")]
 mod viewer_analytics;
@@ -38,7 +49,13 @@ pub(crate) use {app_state::AppState, ui::memory_panel};
 pub use event::{SelectionChangeItem, ViewerEvent, ViewerEventKind};
 
 pub use app::App;
+pub use plugin::{PLUGIN_API_VERSION, PluginLoadError, ViewerPlugin};
+pub use redaction::ImageRedaction;
 pub use startup_options::StartupOptions;
+pub use viewer_control::ViewerControl;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use view_exporter::ViewExportOptions;
 
 pub use re_capabilities::MainThreadToken;
 
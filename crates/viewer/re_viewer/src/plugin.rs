@@ -0,0 +1,150 @@
+//! Extending the Viewer with custom views and visualizers, see [`ViewerPlugin`].
+//!
+//! A plugin registers its view classes and visualizers with the [`ViewClassRegistry`], just like
+//! the built-in views do. Visualizers get full query access to the recording through the
+//! [`re_viewer_context::ViewContext`], and render with `re_renderer`, whose wgpu render context
+//! is available via [`re_viewer_context::ViewerContext::render_ctx`].
+//!
+//! Plugins are either compiled into a custom Viewer and added with [`crate::App::add_plugin`],
+//! or built as a dynamic library that exports its plugin with [`crate::export_viewer_plugin`],
+//! and loaded with `rerun --plugin <path>`.
+
+use re_viewer_context::{ViewClassRegistry, ViewClassRegistryError};
+
+/// A bundle of custom view classes and visualizers.
+///
+/// ```ignore
+/// #[derive(Default)]
+/// struct RadarPlugin;
+///
+/// impl re_viewer::ViewerPlugin for RadarPlugin {
+///     fn name(&self) -> &str {
+///         "radar"
+///     }
+///
+///     fn on_register(&self, registry: &mut ViewClassRegistry) -> Result<(), ViewClassRegistryError> {
+///         // A visualizer for a custom archetype, in one of the built-in views …
+///         registry.add_visualizer::<RadarSpectrumVisualizer>(SpatialView3D::identifier())?;
+///         // … or a whole new view.
+///         registry.add_class::<RadarView>()
+///     }
+/// }
+///
+/// re_viewer::export_viewer_plugin!(RadarPlugin);
+/// ```
+pub trait ViewerPlugin {
+    /// Name of the plugin, used in logs and errors.
+    fn name(&self) -> &str;
+
+    /// Registers the view classes and visualizers of the plugin.
+    ///
+    /// Called once, before the first frame.
+    fn on_register(&self, registry: &mut ViewClassRegistry) -> Result<(), ViewClassRegistryError>;
+}
+
+/// The version of the plugin API, which a plugin library has to match exactly to be loaded.
+///
+/// Plugins are passed across the library boundary as Rust trait objects, which have no stable ABI,
+/// so they have to be built with the same Rerun version and the same Rust compiler as the Viewer.
+pub const PLUGIN_API_VERSION: &str = concat!(
+    env!("CARGO_PKG_VERSION"),
+    " ",
+    env!("RE_BUILD_RUSTC_VERSION")
+);
+
+#[doc(hidden)]
+pub const PLUGIN_ENTRY_POINT: &[u8] = b"rerun_viewer_plugin";
+
+#[doc(hidden)]
+pub const PLUGIN_API_VERSION_SYMBOL: &[u8] = b"rerun_viewer_plugin_api_version";
+
+/// Exports a [`ViewerPlugin`] from a dynamic library (`crate-type = ["cdylib"]`), so that it
+/// can be loaded with `rerun --plugin <path>`.
+///
+/// The plugin type has to implement [`Default`].
+#[macro_export]
+macro_rules! export_viewer_plugin {
+    ($plugin:ty) => {
+        #[unsafe(no_mangle)]
+        pub static rerun_viewer_plugin_api_version: &str = $crate::PLUGIN_API_VERSION;
+
+        #[unsafe(no_mangle)]
+        pub fn rerun_viewer_plugin() -> Box<dyn $crate::ViewerPlugin> {
+            Box::new(<$plugin as Default>::default())
+        }
+    };
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PluginLoadError {
+    #[cfg(not(target_arch = "wasm32"))]
+    #[error("Failed to load the plugin library: {0}")]
+    Library(#[from] libloading::Error),
+
+    #[error(
+        "The plugin was built for {plugin_version:?}, but the Viewer is {:?}. \
+         Rebuild the plugin against this version of Rerun, with the same Rust compiler.",
+        PLUGIN_API_VERSION
+    )]
+    VersionMismatch { plugin_version: String },
+
+    #[error("Failed to register plugin {name:?}: {err}")]
+    Registration {
+        name: String,
+        err: ViewClassRegistryError,
+    },
+}
+
+/// Loads a plugin library that was built with [`crate::export_viewer_plugin`], and registers it.
+///
+/// The library stays loaded for the lifetime of the process, since the registered views and
+/// visualizers point into it.
+#[cfg(not(target_arch = "wasm32"))]
+#[allow(unsafe_code)]
+pub fn load_plugin_library(
+    path: &std::path::Path,
+    registry: &mut ViewClassRegistry,
+) -> Result<(), PluginLoadError> {
+    re_log::debug!("Loading Viewer plugin from {path:?}");
+
+    // SAFETY: running the initializers of an arbitrary library is inherently unsafe;
+    // the user explicitly asked for this library to be loaded.
+    let library = unsafe { libloading::Library::new(path) }?;
+
+    let plugin = {
+        // SAFETY: the symbol is declared by `export_viewer_plugin!` with exactly this type.
+        let plugin_version = unsafe { library.get::<*const &str>(PLUGIN_API_VERSION_SYMBOL) }?;
+        // SAFETY: the symbol points to a valid `&'static str` in the still loaded library.
+        let plugin_version = unsafe { **plugin_version };
+        if plugin_version != PLUGIN_API_VERSION {
+            return Err(PluginLoadError::VersionMismatch {
+                plugin_version: plugin_version.to_owned(),
+            });
+        }
+
+        // SAFETY: the versions match, so the entry point has the signature this Viewer expects.
+        let entry_point =
+            unsafe { library.get::<fn() -> Box<dyn ViewerPlugin>>(PLUGIN_ENTRY_POINT) }?;
+        entry_point()
+    };
+
+    // Code and vtables of the plugin live in the library, so it must never be unloaded.
+    std::mem::forget(library);
+
+    register_plugin(plugin.as_ref(), registry)
+}
+
+pub(crate) fn register_plugin(
+    plugin: &dyn ViewerPlugin,
+    registry: &mut ViewClassRegistry,
+) -> Result<(), PluginLoadError> {
+    plugin
+        .on_register(registry)
+        .map_err(|err| PluginLoadError::Registration {
+            name: plugin.name().to_owned(),
+            err,
+        })?;
+
+    re_log::info!("Registered Viewer plugin {:?}", plugin.name());
+    Ok(())
+}
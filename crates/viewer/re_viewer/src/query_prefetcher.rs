@@ -0,0 +1,172 @@
+//! Warms up the query caches ahead of playback.
+//!
+//! The first latest-at query into a cold region of a big recording can be slow, e.g. because the
+//! relevant chunks have to be sorted first. While the timeline is playing, [`QueryPrefetcher`] runs
+//! the queries of all entities at an upcoming time on the rayon thread pool, so that the views hit
+//! warm caches once playback gets there.
+//!
+//! The UI thread never waits for the warm-up: it only kicks off a task once the previous one is
+//! done. Each task keeps the store locked for at most [`TIME_BUDGET`], so that it can't hold up
+//! ingestion for long either, and leaves the rest of its entities to the next task.
+//!
+//! Video doesn't need any of this: the video players already stay one GOP ahead of the playhead.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use parking_lot::Mutex;
+
+use re_chunk_store::LatestAtQuery;
+use re_entity_db::EntityDb;
+use re_log_types::{EntityPath, StoreId, TimeInt, TimeType, TimelineName};
+use re_query::StorageEngine;
+use re_viewer_context::{PlayState, ViewerContext};
+
+/// How far ahead of the time cursor the caches are warmed up, in seconds of playback.
+const LOOKAHEAD_SECS: f64 = 1.0;
+
+/// At how many times within the lookahead the caches are warmed up.
+const NUM_STEPS: i64 = 8;
+
+/// Playback fps to assume for sequence timelines without one.
+const DEFAULT_FPS: f64 = 30.0;
+
+/// For how long a single warm-up task may keep the store locked, a fraction of a frame.
+const TIME_BUDGET: web_time::Duration = web_time::Duration::from_millis(4);
+
+/// Warms up the query caches of the active recording for the times that playback is about to reach.
+#[derive(Default)]
+pub struct QueryPrefetcher {
+    /// The recording, timeline and time up to which the caches were warmed up.
+    prefetched_up_to: Option<(StoreId, TimelineName, TimeInt)>,
+
+    task: Arc<WarmUpTask>,
+}
+
+/// The state shared with the warm-up tasks running on the rayon thread pool.
+#[derive(Default)]
+struct WarmUpTask {
+    /// Set while a task is running.
+    is_running: AtomicBool,
+
+    /// The entities that the last task ran out of time for.
+    remaining: Mutex<Vec<EntityPath>>,
+}
+
+impl QueryPrefetcher {
+    /// Kicks off warming up the caches at the next upcoming time, if the timeline is playing.
+    ///
+    /// Should be called once the views of the frame are done, to not compete with them.
+    pub fn update(&mut self, ctx: &ViewerContext<'_>, recording: &EntityDb) {
+        // There are no worker threads on the web, so the warm-up would block the UI thread.
+        if cfg!(target_arch = "wasm32") {
+            return;
+        }
+
+        let (timeline, now, lookahead) = {
+            let time_ctrl = ctx.rec_cfg.time_ctrl.read();
+            let secs_to_time = match time_ctrl.time_type() {
+                TimeType::Sequence => time_ctrl.fps().map_or(DEFAULT_FPS, f64::from),
+                TimeType::DurationNs | TimeType::TimestampNs => 1e9,
+            };
+            let lookahead = LOOKAHEAD_SECS * secs_to_time * f64::from(time_ctrl.speed());
+
+            match time_ctrl.time_int() {
+                Some(now) if time_ctrl.play_state() == PlayState::Playing && lookahead >= 1.0 => {
+                    (*time_ctrl.timeline().name(), now, lookahead as i64)
+                }
+                _ => {
+                    self.prefetched_up_to = None;
+                    return;
+                }
+            }
+        };
+
+        if self.task.is_running.load(Ordering::Acquire) {
+            return; // Don't pile up work, the caches are warmed up as fast as the pool allows.
+        }
+        let remaining = std::mem::take(&mut *self.task.remaining.lock());
+
+        let horizon = now.as_i64().saturating_add(lookahead);
+        let prefetched_up_to = match &self.prefetched_up_to {
+            Some((store_id, prefetched_timeline, prefetched_up_to))
+                if store_id == recording.store_id()
+                    && *prefetched_timeline == timeline
+                    && (now.as_i64()..=horizon).contains(&prefetched_up_to.as_i64()) =>
+            {
+                *prefetched_up_to
+            }
+
+            // Playback (re)started, or jumped e.g. because it looped.
+            _ => {
+                self.prefetched_up_to = None;
+                now
+            }
+        };
+
+        let (query, entity_paths) = if self.prefetched_up_to.is_some() && !remaining.is_empty() {
+            // The last task didn't get through all entities.
+            (
+                LatestAtQuery::new(timeline, prefetched_up_to),
+                Some(remaining),
+            )
+        } else {
+            let step = (lookahead / NUM_STEPS).max(1);
+            let next = prefetched_up_to.as_i64().saturating_add(step);
+            if next > horizon {
+                return; // Far enough ahead already.
+            }
+
+            let next = TimeInt::new_temporal(next);
+            self.prefetched_up_to = Some((recording.store_id().clone(), timeline, next));
+            (LatestAtQuery::new(timeline, next), None)
+        };
+
+        // Safety: the task never waits for the store to be unlocked, and only keeps it locked for
+        // a fraction of a frame, see `warm_up_caches`.
+        #[expect(unsafe_code)]
+        let engine = unsafe { recording.storage_engine_raw() }.clone();
+        let task = self.task.clone();
+
+        task.is_running.store(true, Ordering::Release);
+        rayon::spawn(move || {
+            let remaining = warm_up_caches(&engine, &query, entity_paths);
+            *task.remaining.lock() = remaining;
+            task.is_running.store(false, Ordering::Release);
+        });
+    }
+}
+
+/// Runs a latest-at query for all components of the given entities on the query's timeline, or of
+/// all entities if none are given.
+///
+/// Gives up without blocking if the store is locked for writing, and stops once [`TIME_BUDGET`] is
+/// used up. Returns the entities that are left.
+fn warm_up_caches(
+    engine: &StorageEngine,
+    query: &LatestAtQuery,
+    entity_paths: Option<Vec<EntityPath>>,
+) -> Vec<EntityPath> {
+    re_tracing::profile_function!();
+
+    let Some(engine) = engine.try_read() else {
+        return entity_paths.unwrap_or_default();
+    };
+    let store = engine.store();
+
+    let mut entity_paths =
+        entity_paths.unwrap_or_else(|| store.all_entities().into_iter().collect());
+
+    let deadline = web_time::Instant::now() + TIME_BUDGET;
+    while web_time::Instant::now() < deadline {
+        let Some(entity_path) = entity_paths.pop() else {
+            break;
+        };
+        if let Some(components) = store.all_components_on_timeline(&query.timeline(), &entity_path)
+        {
+            _ = engine.cache().latest_at(query, &entity_path, &components);
+        }
+    }
+
+    entity_paths
+}
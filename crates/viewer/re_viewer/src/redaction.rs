@@ -0,0 +1,285 @@
+//! Redacting regions of images as they are ingested, see [`ImageRedaction`].
+
+use arrow::{
+    array::{Array as _, ListArray as ArrowListArray},
+    buffer::OffsetBuffer,
+};
+
+use re_chunk::{Chunk, TimelineName};
+use re_chunk_store::LatestAtQuery;
+use re_entity_db::{EntityDb, IngestTransform};
+use re_log_types::{EntityPath, TimeInt};
+use re_types::{
+    Loggable as _,
+    archetypes::{Boxes2D, Image},
+    components::{HalfSize2D, ImageBuffer, ImageFormat, Position2D},
+    datatypes::ChannelDatatype,
+};
+
+type RegionsFn = dyn Fn(&EntityDb, &EntityPath, &LatestAtQuery) -> Vec<egui::Rect> + Send + Sync;
+
+/// An [`IngestTransform`] that pixelates regions of images, e.g. faces or license plates, before
+/// they hit the store.
+///
+/// The regions, in pixel coordinates, are computed for every image from the data that was
+/// ingested before it, so detections have to be logged before the images they apply to.
+/// Only raw images with 8-bit channels can be redacted, encoded images are left unchanged.
+///
+/// Add it to the Viewer with [`crate::App::add_ingest_transform`].
+pub struct ImageRedaction {
+    images_root: EntityPath,
+    timeline: TimelineName,
+    regions: Box<RegionsFn>,
+}
+
+impl ImageRedaction {
+    /// Redacts the regions returned by `regions` for each image, which is given the recording,
+    /// the entity of the image, and the time of the image on `timeline`.
+    pub fn new(
+        timeline: impl Into<TimelineName>,
+        regions: impl Fn(&EntityDb, &EntityPath, &LatestAtQuery) -> Vec<egui::Rect>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        Self {
+            images_root: EntityPath::root(),
+            timeline: timeline.into(),
+            regions: Box::new(regions),
+        }
+    }
+
+    /// Redacts the boxes logged as [`Boxes2D`] to `detections`, as of the time of each image.
+    ///
+    /// The boxes have to be in the pixel coordinates of the images.
+    pub fn from_boxes2d(timeline: impl Into<TimelineName>, detections: EntityPath) -> Self {
+        Self::new(timeline, move |recording, _image, query| {
+            let results = recording.latest_at(
+                query,
+                &detections,
+                [
+                    Boxes2D::descriptor_centers(),
+                    Boxes2D::descriptor_half_sizes(),
+                ]
+                .iter(),
+            );
+            let centers = results
+                .component_batch::<Position2D>(&Boxes2D::descriptor_centers())
+                .unwrap_or_default();
+            let half_sizes = results
+                .component_batch::<HalfSize2D>(&Boxes2D::descriptor_half_sizes())
+                .unwrap_or_default();
+
+            half_sizes
+                .iter()
+                .enumerate()
+                .map(|(i, half_size)| {
+                    let [cx, cy] = centers.get(i).map_or([0.0; 2], |center| center.0.0);
+                    let [hx, hy] = half_size.0.0;
+                    egui::Rect::from_center_size(egui::pos2(cx, cy), egui::vec2(2.0 * hx, 2.0 * hy))
+                })
+                .collect()
+        })
+    }
+
+    /// Only redacts the images logged under `images_root`, instead of all of them.
+    pub fn with_images_root(mut self, images_root: EntityPath) -> Self {
+        self.images_root = images_root;
+        self
+    }
+
+    fn row_query(&self, chunk: &Chunk, row: usize) -> Option<LatestAtQuery> {
+        if chunk.is_static() {
+            return Some(LatestAtQuery::latest(self.timeline));
+        }
+
+        let time = chunk.timelines().get(&self.timeline)?.times_raw()[row];
+        Some(LatestAtQuery::new(
+            self.timeline,
+            TimeInt::new_temporal(time),
+        ))
+    }
+}
+
+impl IngestTransform for ImageRedaction {
+    fn name(&self) -> &str {
+        "image redaction"
+    }
+
+    fn transform(&self, recording: &EntityDb, chunk: &Chunk) -> Option<Chunk> {
+        if !chunk.entity_path().starts_with(&self.images_root) {
+            return None;
+        }
+
+        let buffer_descr = Image::descriptor_buffer();
+        let list_array = chunk.components().get(&buffer_descr)?;
+
+        re_tracing::profile_function!();
+
+        let mut is_redacted = false;
+        let mut buffers = Vec::with_capacity(chunk.num_rows());
+        for row in 0..chunk.num_rows() {
+            if !list_array.is_valid(row) {
+                continue;
+            }
+
+            // Images are single instance, anything else is left alone.
+            let buffer = chunk
+                .component_mono::<ImageBuffer>(&buffer_descr, row)?
+                .ok()?;
+            let format = chunk
+                .component_mono::<ImageFormat>(&Image::descriptor_format(), row)
+                .and_then(Result::ok);
+
+            let Some(query) = self.row_query(chunk, row) else {
+                re_log::warn_once!(
+                    "Cannot redact {}: it has no {:?} timeline",
+                    chunk.entity_path(),
+                    self.timeline
+                );
+                return None;
+            };
+
+            let regions = (self.regions)(recording, chunk.entity_path(), &query);
+            if regions.is_empty() {
+                buffers.push(buffer);
+            } else if let Some(redacted) = format
+                .as_ref()
+                .and_then(|format| pixelate(format, &buffer, &regions))
+            {
+                is_redacted = true;
+                buffers.push(ImageBuffer::from(redacted));
+            } else {
+                re_log::warn_once!(
+                    "Cannot redact {}: only raw images with 8-bit channels are supported",
+                    chunk.entity_path()
+                );
+                buffers.push(buffer);
+            }
+        }
+
+        if !is_redacted {
+            return None;
+        }
+
+        let values = match ImageBuffer::to_arrow(buffers.iter()) {
+            Ok(values) => values,
+            Err(err) => {
+                re_log::warn_once!("Failed to redact {}: {err}", chunk.entity_path());
+                return None;
+            }
+        };
+        let (field, _offsets, _values, nulls) = list_array.clone().into_parts();
+        let offsets = OffsetBuffer::from_lengths(
+            (0..chunk.num_rows()).map(|row| usize::from(list_array.is_valid(row))),
+        );
+        let list_array = ArrowListArray::try_new(field, offsets, values, nulls).ok()?;
+
+        let mut components = chunk.components().clone();
+        components.insert(buffer_descr, list_array);
+
+        match Chunk::new(
+            chunk.id(),
+            chunk.entity_path().clone(),
+            Some(chunk.is_sorted()),
+            chunk.row_ids_array().clone(),
+            chunk.timelines().clone(),
+            components,
+        ) {
+            Ok(chunk) => Some(chunk),
+            Err(err) => {
+                re_log::warn_once!("Failed to redact {}: {err}", chunk.entity_path());
+                None
+            }
+        }
+    }
+}
+
+/// Pixelates the given regions of an image, returning `None` if its format is not supported.
+fn pixelate(format: &ImageFormat, buffer: &ImageBuffer, regions: &[egui::Rect]) -> Option<Vec<u8>> {
+    let format = &format.0;
+    if format.pixel_format.is_some() || format.datatype() != ChannelDatatype::U8 {
+        return None;
+    }
+
+    let num_channels = format.color_model().num_channels();
+    let [width, height] = [format.width as usize, format.height as usize];
+    let mut pixels = buffer.0.0.to_vec();
+    if pixels.len() != width * height * num_channels {
+        return None;
+    }
+
+    let image_rect =
+        egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(width as f32, height as f32));
+    for region in regions {
+        let region = region.intersect(image_rect);
+        if !region.is_positive() {
+            continue;
+        }
+
+        // Coarse enough to hide a face, whatever the size of the region.
+        let block_size = (region.size().max_elem() / 8.0).ceil().max(4.0) as usize;
+
+        let [min_x, min_y] = [region.min.x.floor() as usize, region.min.y.floor() as usize];
+        let [max_x, max_y] = [region.max.x.ceil() as usize, region.max.y.ceil() as usize];
+        for block_y in (min_y..max_y).step_by(block_size) {
+            for block_x in (min_x..max_x).step_by(block_size) {
+                let ys = block_y..(block_y + block_size).min(max_y);
+                let xs = block_x..(block_x + block_size).min(max_x);
+                let num_pixels = ys.len() * xs.len();
+
+                let mut sums = [0usize; 4];
+                for y in ys.clone() {
+                    for x in xs.clone() {
+                        let offset = (y * width + x) * num_channels;
+                        for (sum, value) in sums.iter_mut().zip(&pixels[offset..][..num_channels]) {
+                            *sum += *value as usize;
+                        }
+                    }
+                }
+
+                for y in ys.clone() {
+                    for x in xs.clone() {
+                        let offset = (y * width + x) * num_channels;
+                        for (value, sum) in pixels[offset..][..num_channels].iter_mut().zip(sums) {
+                            *value = (sum / num_pixels) as u8;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Some(pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pixelate_region() {
+        let format = ImageFormat::from(re_types::datatypes::ImageFormat::from_color_model(
+            [8, 8],
+            re_types::datatypes::ColorModel::L,
+            ChannelDatatype::U8,
+        ));
+        let buffer = ImageBuffer::from((0..64).map(|i| i as u8 * 2).collect::<Vec<u8>>());
+
+        let region = egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(4.0, 4.0));
+        let pixels = pixelate(&format, &buffer, &[region]).unwrap();
+
+        // The top-left 4x4 block is averaged…
+        let average = ((0..4)
+            .flat_map(|y| (0..4).map(move |x| (y * 8 + x) * 2))
+            .sum::<usize>()
+            / 16) as u8;
+        for y in 0..4 {
+            assert_eq!(&pixels[y * 8..y * 8 + 4], &[average; 4]);
+        }
+
+        // …and the rest is left alone.
+        assert_eq!(&pixels[4..8], &buffer.0.0[4..8]);
+        assert_eq!(&pixels[32..], &buffer.0.0[32..]);
+    }
+}
@@ -63,12 +63,16 @@ pub fn encode_to_file(
 ) -> anyhow::Result<()> {
     re_tracing::profile_function!();
     use anyhow::Context as _;
+    use std::io::Write as _;
 
-    let mut file = std::fs::File::create(path)
+    let file = std::fs::File::create(path)
         .with_context(|| format!("Failed to create file at {path:?}"))?;
+    let mut file = std::io::BufWriter::new(file);
 
+    // Messages are serialized and compressed in parallel, but written in order.
     let encoding_options = re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED;
     re_log_encoding::encoder::encode(version, encoding_options, messages, &mut file)
-        .map(|_| ())
-        .context("Message encode")
+        .context("Message encode")?;
+
+    file.flush().context("Failed to write file")
 }
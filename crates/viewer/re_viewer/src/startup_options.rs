@@ -21,6 +21,10 @@ pub struct StartupOptions {
     #[cfg(not(target_arch = "wasm32"))]
     pub screenshot_to_path_then_quit: Option<std::path::PathBuf>,
 
+    /// Render a view across a time range to PNG frames or an MP4 video, and quit.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub export_view_then_quit: Option<crate::ViewExportOptions>,
+
     /// A user has specifically requested the welcome screen be hidden.
     pub hide_welcome_screen: bool,
 
@@ -50,6 +54,19 @@ pub struct StartupOptions {
     /// This also can be changed in the viewer's option menu.
     pub video_decoder_hw_acceleration: Option<re_video::DecodeHardwareAcceleration>,
 
+    /// Limits the number of threads a software video decoder may use per video.
+    ///
+    /// By default the decoders pick a number based on the number of cores.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub video_decoder_threads: Option<std::num::NonZeroUsize>,
+
+    /// Dynamic libraries with [`crate::ViewerPlugin`]s to load on startup.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub plugins: Vec<std::path::PathBuf>,
+
+    /// How `rrd` files opened in the viewer are decoded, e.g. which key decrypts their payloads.
+    pub rrd_load_options: re_data_loader::RrdLoadOptions,
+
     /// External interactions with the Viewer host (JS, custom egui app, notebook, etc.).
     pub on_event: Option<ViewerEventCallback>,
 
@@ -109,6 +126,9 @@ impl Default for StartupOptions {
             #[cfg(not(target_arch = "wasm32"))]
             screenshot_to_path_then_quit: None,
 
+            #[cfg(not(target_arch = "wasm32"))]
+            export_view_then_quit: None,
+
             hide_welcome_screen: false,
 
             #[cfg(not(target_arch = "wasm32"))]
@@ -121,6 +141,14 @@ impl Default for StartupOptions {
             force_wgpu_backend: None,
             video_decoder_hw_acceleration: None,
 
+            #[cfg(not(target_arch = "wasm32"))]
+            video_decoder_threads: None,
+
+            #[cfg(not(target_arch = "wasm32"))]
+            plugins: Vec::new(),
+
+            rrd_load_options: Default::default(),
+
             on_event: None,
 
             #[cfg(target_arch = "wasm32")]
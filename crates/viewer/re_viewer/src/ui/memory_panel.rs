@@ -1,5 +1,6 @@
 use re_chunk_store::{ChunkStoreChunkStats, ChunkStoreConfig, ChunkStoreStats};
 use re_format::{format_bytes, format_uint};
+use re_mcap::ingest_stats::IngestStatistics;
 use re_memory::{MemoryLimit, MemoryUse, util::sec_since_start};
 use re_query::{QueryCacheStats, QueryCachesStats};
 use re_renderer::WgpuResourcePoolStatistics;
@@ -80,6 +81,14 @@ impl MemoryPanel {
             Self::gpu_stats(ui, gpu_resource_stats);
         });
 
+        let ingest_stats = re_mcap::ingest_stats::ingest_statistics();
+        if !ingest_stats.is_empty() {
+            ui.separator();
+            ui.collapsing("MCAP Ingest", |ui| {
+                Self::ingest_stats(ui, &ingest_stats);
+            });
+        }
+
         if let Some(store_stats) = store_stats {
             ui.separator();
 
@@ -127,6 +136,19 @@ impl MemoryPanel {
             }
         }
 
+        for (name, stats) in re_memory::pools::pool_statistics() {
+            ui.label(format!(
+                "{name}: {} in {} pooled buffers",
+                format_bytes(stats.pooled.size as _),
+                format_uint(stats.pooled.count),
+            ))
+            .on_hover_text(format!(
+                "Memory kept for reuse. {} buffers were reused, {} allocated.",
+                format_uint(stats.num_reused),
+                format_uint(stats.num_allocated),
+            ));
+        }
+
         let mut is_tracking_callstacks = re_memory::accounting_allocator::is_tracking_callstacks();
         ui.re_checkbox(&mut is_tracking_callstacks, "Detailed allocation tracking")
             .on_hover_text("This will slow down the program");
@@ -142,6 +164,48 @@ impl MemoryPanel {
         }
     }
 
+    fn ingest_stats(
+        ui: &mut egui::Ui,
+        ingest_stats: &[(re_mcap::ingest_stats::ParserKey, IngestStatistics)],
+    ) {
+        let total = re_mcap::ingest_stats::total_ingest_statistics();
+        ui.label(format!(
+            "{} messages, {} decoded in {:.1}s",
+            format_uint(total.num_messages),
+            format_bytes(total.num_bytes as _),
+            total.decode_time.as_secs_f64(),
+        ));
+
+        egui::Grid::new("ingest stats grid")
+            .num_columns(5)
+            .show(ui, |ui| {
+                ui.label(egui::RichText::new("Schema").underline());
+                ui.label(egui::RichText::new("Messages").underline());
+                ui.label(egui::RichText::new("Messages/s").underline());
+                ui.label(egui::RichText::new("Bytes/s").underline());
+                ui.label(egui::RichText::new("Decode time").underline());
+                ui.end_row();
+
+                for (key, stats) in ingest_stats {
+                    let schema = if key.schema.is_empty() {
+                        "(no schema)"
+                    } else {
+                        key.schema.as_str()
+                    };
+                    ui.label(schema)
+                        .on_hover_text(format!("Decoded by the `{}` layer", key.layer));
+                    ui.label(format_uint(stats.num_messages));
+                    ui.label(format_uint(stats.messages_per_second() as u64));
+                    ui.label(format_bytes(stats.bytes_per_second()));
+                    ui.label(format!("{:.1}s", stats.decode_time.as_secs_f64()));
+                    ui.end_row();
+                }
+            });
+        ui.label(
+            "Rates are per second spent decoding, so the slowest schemas are the bottlenecks.",
+        );
+    }
+
     fn gpu_stats(ui: &mut egui::Ui, gpu_resource_stats: &WgpuResourcePoolStatistics) {
         egui::Grid::new("gpu resource grid")
             .num_columns(2)
@@ -106,6 +106,7 @@ impl App {
 
             UICommand::ToggleMemoryPanel.menu_button_ui(ui, &self.command_sender);
             UICommand::ToggleChunkStoreBrowser.menu_button_ui(ui, &self.command_sender);
+            UICommand::ToggleTransformTreePanel.menu_button_ui(ui, &self.command_sender);
 
             #[cfg(debug_assertions)]
             UICommand::ToggleEguiDebugPanel.menu_button_ui(ui, &self.command_sender);
@@ -430,6 +431,12 @@ fn debug_menu_options_ui(
         ui.re_radio_value(&mut app_options.blueprint_gc, false, "Disabled");
     });
 
+    ui.horizontal(|ui| {
+        ui.label("Background compaction:");
+        ui.re_radio_value(&mut app_options.background_compaction, true, "Enabled");
+        ui.re_radio_value(&mut app_options.background_compaction, false, "Disabled");
+    });
+
     ui.re_checkbox(
         &mut app_options.show_picking_debug_overlay,
         "Picking Debug Overlay",
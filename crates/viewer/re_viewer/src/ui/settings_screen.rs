@@ -80,6 +80,15 @@ fn settings_screen_ui_impl(ui: &mut egui::Ui, app_options: &mut AppOptions, keep
     ui.re_checkbox(&mut app_options.show_metrics, "Show performance metrics")
         .on_hover_text("Show metrics for milliseconds/frame and RAM usage in the top bar");
 
+    ui.re_checkbox(
+        &mut app_options.linked_time_cursor,
+        "Link time cursor across views",
+    )
+    .on_hover_text(
+        "Hovering a sample in a plot highlights what was logged at that time in other views, \
+            and hovering an entity in a spatial view shows a cursor at its time in plots",
+    );
+
     //
     // Timezone
     //
@@ -130,6 +139,25 @@ fn settings_screen_ui_impl(ui: &mut egui::Ui, app_options: &mut AppOptions, keep
         ui.add(egui::TextEdit::singleline(&mut app_options.mapbox_access_token).password(true));
     });
 
+    ui.horizontal(|ui| {
+        // TODO(ab): needed for alignment, we should use egui flex instead
+        ui.set_height(19.0);
+
+        ui.label("Custom tiles URL:").on_hover_ui(|ui| {
+            ui.markdown_ui(
+                "URL template of the tiles used by the `Custom` map view background, \
+                e.g. `http://localhost:8080/tiles/{z}/{x}/{y}.png`.\n\n\
+                `{z}`, `{x}` and `{y}` are replaced by the zoom level and the tile coordinates. \
+                Use this for self-hosted tile servers, e.g. one serving offline MBTiles.",
+            );
+        });
+
+        ui.add(
+            egui::TextEdit::singleline(&mut app_options.custom_map_tiles_url)
+                .hint_text("http://localhost:8080/tiles/{z}/{x}/{y}.png"),
+        );
+    });
+
     //
     // Video
     //
@@ -153,6 +181,8 @@ fn settings_screen_ui_impl(ui: &mut egui::Ui, app_options: &mut AppOptions, keep
 }
 
 fn video_section_ui(ui: &mut Ui, app_options: &mut AppOptions) {
+    use re_video::DecodeHardwareAcceleration;
+
     #[cfg(not(target_arch = "wasm32"))]
     {
         ui.re_checkbox(
@@ -183,37 +213,31 @@ fn video_section_ui(ui: &mut Ui, app_options: &mut AppOptions) {
         ffmpeg_path_status_ui(ui, app_options);
     }
 
-    // This affects only the web target, so we don't need to show it on native.
-    #[cfg(target_arch = "wasm32")]
-    {
-        use re_video::DecodeHardwareAcceleration;
-
-        let hardware_acceleration = &mut app_options.video_decoder_hw_acceleration;
-        ui.horizontal(|ui| {
-            ui.label("Decoder:");
-            egui::ComboBox::from_id_salt("video_decoder_hw_acceleration")
-                .selected_text(hardware_acceleration.to_string())
-                .show_ui(ui, |ui| {
-                    ui.selectable_value(
-                        hardware_acceleration,
-                        DecodeHardwareAcceleration::Auto,
-                        DecodeHardwareAcceleration::Auto.to_string(),
-                    );
-                    ui.selectable_value(
-                        hardware_acceleration,
-                        DecodeHardwareAcceleration::PreferSoftware,
-                        DecodeHardwareAcceleration::PreferSoftware.to_string(),
-                    );
-                    ui.selectable_value(
-                        hardware_acceleration,
-                        DecodeHardwareAcceleration::PreferHardware,
-                        DecodeHardwareAcceleration::PreferHardware.to_string(),
-                    );
-                });
-            // Note that the setting is part of the video's cache key, so, if it changes, the cache
-            // entries outdate automatically.
-        });
-    }
+    let hardware_acceleration = &mut app_options.video_decoder_hw_acceleration;
+    ui.horizontal(|ui| {
+        ui.label("Decoder:");
+        egui::ComboBox::from_id_salt("video_decoder_hw_acceleration")
+            .selected_text(hardware_acceleration.to_string())
+            .show_ui(ui, |ui| {
+                ui.selectable_value(
+                    hardware_acceleration,
+                    DecodeHardwareAcceleration::Auto,
+                    DecodeHardwareAcceleration::Auto.to_string(),
+                );
+                ui.selectable_value(
+                    hardware_acceleration,
+                    DecodeHardwareAcceleration::PreferSoftware,
+                    DecodeHardwareAcceleration::PreferSoftware.to_string(),
+                );
+                ui.selectable_value(
+                    hardware_acceleration,
+                    DecodeHardwareAcceleration::PreferHardware,
+                    DecodeHardwareAcceleration::PreferHardware.to_string(),
+                );
+            });
+        // Note that the setting is part of the video's cache key, so, if it changes, the cache
+        // entries outdate automatically.
+    });
 }
 
 #[cfg(not(target_arch = "wasm32"))]
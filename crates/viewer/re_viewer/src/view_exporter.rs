@@ -0,0 +1,451 @@
+//! Rendering a view across a time range, to PNG frames or an MP4 video.
+//!
+//! The viewer steps through every time with data in the range, and takes a screenshot of the view at each of them.
+//! Videos are encoded from these frames by FFmpeg.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context as _;
+
+use re_entity_db::EntityDb;
+use re_log_types::{AbsoluteTimeRangeF, StoreId, TimeInt, Timeline};
+use re_viewer_context::{
+    CommandSender, SystemCommand, SystemCommandSender as _, ViewId, ViewRectPublisher,
+};
+use re_viewport_blueprint::ViewportBlueprint;
+
+/// How many frames to wait after changing the time before taking a screenshot of the view.
+///
+/// This gives the view time to catch up, e.g. to decode video frames.
+const SETTLE_FRAMES: usize = 5;
+
+/// How many frames to wait for the view of an export requested on the command line to show up.
+///
+/// Views created by heuristics only show up a few frames after the data has been loaded.
+const FIND_VIEW_FRAMES: usize = 60;
+
+/// A view export requested on the command line.
+#[derive(Clone, Debug)]
+pub struct ViewExportOptions {
+    /// Name or id of the view to export.
+    pub view: String,
+
+    /// Where to save the export: an `.mp4` video, or numbered frames for a `.png` path.
+    pub path: PathBuf,
+
+    /// Timeline to step through, the active one if `None`.
+    pub timeline: Option<String>,
+
+    /// Frame rate of videos.
+    pub fps: f32,
+}
+
+/// What a view is exported to, based on the extension of the path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Numbered PNG images, e.g. `frames_00000.png`, `frames_00001.png`, … for `frames.png`.
+    PngFrames,
+
+    /// An H.264 video.
+    Mp4,
+}
+
+impl ExportFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "png" => Some(Self::PngFrames),
+            "mp4" => Some(Self::Mp4),
+            _ => None,
+        }
+    }
+}
+
+/// Screenshot user data of an exported frame.
+#[derive(Clone, Debug)]
+pub struct ExportedFrame {
+    index: usize,
+
+    /// The view, in ui points.
+    ui_rect: egui::Rect,
+    pixels_per_point: f32,
+}
+
+pub struct ViewExport {
+    store_id: StoreId,
+    view_id: ViewId,
+    timeline: Timeline,
+
+    /// The times to render, one frame each.
+    times: Vec<TimeInt>,
+
+    path: PathBuf,
+    format: ExportFormat,
+    fps: f32,
+
+    /// Where the frames are saved, with their file names starting with `frame_prefix`.
+    frames_dir: PathBuf,
+    frame_prefix: String,
+
+    next_frame: usize,
+
+    /// Frames left until the screenshot of the next frame is taken, `None` while waiting for it.
+    countdown: Option<usize>,
+}
+
+impl ViewExport {
+    pub fn new(
+        recording: &EntityDb,
+        view_id: ViewId,
+        timeline: Timeline,
+        time_range: AbsoluteTimeRangeF,
+        path: PathBuf,
+        fps: f32,
+    ) -> anyhow::Result<Self> {
+        let format = ExportFormat::from_path(&path)
+            .with_context(|| format!("Can't export to {path:?}: expected a .png or .mp4 file"))?;
+
+        let (min, max) = (time_range.min.ceil(), time_range.max.floor());
+        let times: Vec<TimeInt> = recording
+            .times_per_timeline()
+            .get(timeline.name())
+            .filter(|_| min <= max)
+            .map(|stats| {
+                stats
+                    .per_time
+                    .range(min..=max)
+                    .map(|(time, _)| *time)
+                    .collect()
+            })
+            .unwrap_or_default();
+        anyhow::ensure!(
+            !times.is_empty(),
+            "Nothing to export: no data on the timeline {:?} in the selected range",
+            timeline.name()
+        );
+
+        let stem = path.file_stem().map_or_else(
+            || "view".to_owned(),
+            |stem| stem.to_string_lossy().into_owned(),
+        );
+        let parent = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        let (frames_dir, frame_prefix) = match format {
+            ExportFormat::PngFrames => (parent, stem),
+            ExportFormat::Mp4 => (parent.join(format!("{stem}_frames")), "frame".to_owned()),
+        };
+
+        Ok(Self {
+            store_id: recording.store_id().clone(),
+            view_id,
+            timeline,
+            times,
+            path,
+            format,
+            fps,
+            frames_dir,
+            frame_prefix,
+            next_frame: 0,
+            countdown: None,
+        })
+    }
+
+    fn frame_path(&self, index: usize) -> PathBuf {
+        self.frames_dir
+            .join(format!("{}_{index:05}.png", self.frame_prefix))
+    }
+
+    fn go_to_next_frame(&mut self, command_sender: &CommandSender) {
+        command_sender.send_system(SystemCommand::SetActiveTime {
+            store_id: self.store_id.clone(),
+            timeline: self.timeline,
+            time: Some(self.times[self.next_frame].into()),
+        });
+        self.countdown = Some(SETTLE_FRAMES);
+    }
+}
+
+/// Helper for exporting a view, one frame at a time.
+#[derive(Default)]
+pub struct ViewExporter {
+    /// An export requested on the command line, started once its view shows up.
+    pending: Option<ViewExportOptions>,
+    find_view_frames_left: usize,
+
+    export: Option<ViewExport>,
+    ffmpeg_path: PathBuf,
+
+    /// If true, quit once the export is done.
+    quit_when_done: bool,
+    quit: bool,
+}
+
+#[must_use]
+pub struct ViewExporterOutput {
+    /// If true, the exporter was told at startup to quit after it's done.
+    pub quit: bool,
+}
+
+impl ViewExporter {
+    /// Exports a view once data has been loaded, and then quits.
+    ///
+    /// Should only be called at startup.
+    pub fn export_then_quit(&mut self, options: ViewExportOptions) {
+        self.pending = Some(options);
+        self.find_view_frames_left = FIND_VIEW_FRAMES;
+        self.quit_when_done = true;
+    }
+
+    pub fn has_pending_export(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    pub fn is_exporting(&self) -> bool {
+        self.export.is_some()
+    }
+
+    /// Starts the export requested on the command line, if its view can be found.
+    ///
+    /// Call once per frame after all data has been loaded.
+    pub fn start_pending(
+        &mut self,
+        recording: &EntityDb,
+        viewport: &ViewportBlueprint,
+        active_timeline: Timeline,
+        ffmpeg_path: Option<PathBuf>,
+        command_sender: &CommandSender,
+    ) {
+        let Some(options) = &self.pending else {
+            return;
+        };
+
+        let view_id = viewport.views.values().find_map(|view| {
+            let uuid = view.id.uuid();
+            (options.view == uuid.to_string()
+                || options.view == uuid.simple().to_string()
+                || options.view == view.display_name_or_default().as_ref())
+            .then_some(view.id)
+        });
+        let Some(view_id) = view_id else {
+            if self.find_view_frames_left == 0 {
+                re_log::error!("Failed to export: there's no view named {:?}", options.view);
+                self.pending = None;
+                self.quit = true;
+            } else {
+                self.find_view_frames_left -= 1;
+            }
+            return;
+        };
+
+        let Some(options) = self.pending.take() else {
+            return;
+        };
+        let timeline = match &options.timeline {
+            Some(name) => recording
+                .times_per_timeline()
+                .timelines()
+                .find(|timeline| timeline.name().as_str() == name)
+                .copied(),
+            None => Some(active_timeline),
+        };
+        let result = timeline
+            .with_context(|| format!("There's no timeline named {:?}", options.timeline))
+            .and_then(|timeline| {
+                let time_range = recording
+                    .time_range_for(timeline.name())
+                    .context("There's no data to export")?;
+                ViewExport::new(
+                    recording,
+                    view_id,
+                    timeline,
+                    time_range.into(),
+                    options.path,
+                    options.fps,
+                )
+            });
+
+        match result {
+            Ok(export) => self.start(export, ffmpeg_path, command_sender),
+            Err(err) => {
+                re_log::error!("Failed to export: {err}");
+                self.quit = true;
+            }
+        }
+    }
+
+    pub fn start(
+        &mut self,
+        mut export: ViewExport,
+        ffmpeg_path: Option<PathBuf>,
+        command_sender: &CommandSender,
+    ) {
+        if self.export.is_some() {
+            re_log::warn!("Another view is still being exported");
+            return;
+        }
+
+        if let Err(err) = std::fs::create_dir_all(&export.frames_dir) {
+            re_log::error!("Failed to create {:?}: {err}", export.frames_dir);
+            return;
+        }
+
+        re_log::info!(
+            "Exporting {} frames to {:?}…",
+            export.times.len(),
+            export.path
+        );
+        export.go_to_next_frame(command_sender);
+        self.export = Some(export);
+        self.ffmpeg_path = ffmpeg_path.unwrap_or_else(|| "ffmpeg".into());
+    }
+
+    /// Call once per frame
+    pub fn update(&mut self, egui_ctx: &egui::Context) -> ViewExporterOutput {
+        if let Some(export) = &mut self.export {
+            match &mut export.countdown {
+                Some(0) => {
+                    export.countdown = None;
+
+                    let view_info = egui_ctx.memory_mut(|mem| {
+                        mem.caches
+                            .cache::<ViewRectPublisher>()
+                            .get(&export.view_id)
+                            .cloned()
+                    });
+                    // Hacky: Shrink so we don't accidentally include the border of the view.
+                    let ui_rect = view_info.map(|info| info.rect.shrink(2.5));
+
+                    if let Some(ui_rect) = ui_rect.filter(|rect| rect.is_positive()) {
+                        egui_ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot(
+                            egui::UserData::new(ExportedFrame {
+                                index: export.next_frame,
+                                ui_rect,
+                                pixels_per_point: egui_ctx.pixels_per_point(),
+                            }),
+                        ));
+                    } else {
+                        re_log::error!("Failed to export: the view isn't shown");
+                        self.export = None;
+                        self.quit = self.quit_when_done;
+                    }
+                }
+                Some(countdown) => *countdown -= 1,
+                None => {}
+            }
+
+            egui_ctx.request_repaint(); // Make sure we keep going
+        }
+
+        ViewExporterOutput { quit: self.quit }
+    }
+
+    /// Saves a screenshot of an exported frame, and moves on to the next frame.
+    pub fn save_frame(
+        &mut self,
+        image: &egui::ColorImage,
+        frame: &ExportedFrame,
+        command_sender: &CommandSender,
+    ) {
+        let Some(export) = &mut self.export else {
+            return;
+        };
+        if frame.index != export.next_frame {
+            return;
+        }
+
+        let image = image.region(&frame.ui_rect, Some(frame.pixels_per_point));
+        let path = export.frame_path(frame.index);
+        if let Err(err) = save_png(&image, &path) {
+            re_log::error!("Failed to export: {err}");
+            self.export = None;
+            self.quit = self.quit_when_done;
+            return;
+        }
+
+        export.next_frame += 1;
+        if export.next_frame < export.times.len() {
+            export.go_to_next_frame(command_sender);
+            return;
+        }
+
+        let Some(export) = self.export.take() else {
+            return;
+        };
+        match export.format {
+            ExportFormat::PngFrames => {
+                re_log::info!(
+                    "Exported {} frames to {:?}",
+                    export.times.len(),
+                    export.frames_dir
+                );
+            }
+            ExportFormat::Mp4 => {
+                let ffmpeg_path = self.ffmpeg_path.clone();
+                let encode = move || {
+                    encode_mp4(&ffmpeg_path, &export.frames_dir, export.fps, &export.path)?;
+                    Ok(export.path)
+                };
+
+                if self.quit_when_done {
+                    // Quitting would kill a background task, so encode right away.
+                    match encode() {
+                        Ok(path) => re_log::info!("Exported video to {path:?}"),
+                        Err(err) => re_log::error!("Failed to export: {err}"),
+                    }
+                } else {
+                    command_sender.send_system(SystemCommand::FileSaver(Box::new(encode)));
+                }
+            }
+        }
+        self.quit = self.quit_when_done;
+    }
+}
+
+fn save_png(image: &egui::ColorImage, path: &Path) -> anyhow::Result<()> {
+    let [w, h] = image.size;
+    let image =
+        image::RgbaImage::from_raw(w as _, h as _, bytemuck::pod_collect_to_vec(&image.pixels))
+            .context("Failed to create image")?;
+    image
+        .save(path)
+        .with_context(|| format!("Failed to save {path:?}"))
+}
+
+/// Encodes the frames in `frames_dir` to an H.264 video, and removes them.
+fn encode_mp4(ffmpeg_path: &Path, frames_dir: &Path, fps: f32, path: &Path) -> anyhow::Result<()> {
+    let status = std::process::Command::new(ffmpeg_path)
+        .args(["-y", "-loglevel", "error"])
+        .args(["-framerate", &fps.to_string()])
+        .arg("-i")
+        .arg(frames_dir.join("frame_%05d.png"))
+        // H.264 with 4:2:0 chroma subsampling needs even dimensions.
+        .args(["-vf", "pad=ceil(iw/2)*2:ceil(ih/2)*2"])
+        .args(["-c:v", "libx264", "-pix_fmt", "yuv420p"])
+        .arg(path)
+        .status()
+        .with_context(|| format!("Failed to run FFmpeg at {ffmpeg_path:?}"))?;
+    anyhow::ensure!(status.success(), "FFmpeg failed: {status}");
+
+    std::fs::remove_dir_all(frames_dir)
+        .with_context(|| format!("Failed to remove {frames_dir:?}"))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_format_from_path() {
+        assert_eq!(
+            ExportFormat::from_path(Path::new("out/frames.PNG")),
+            Some(ExportFormat::PngFrames)
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("result.mp4")),
+            Some(ExportFormat::Mp4)
+        );
+        assert_eq!(ExportFormat::from_path(Path::new("result.gif")), None);
+        assert_eq!(ExportFormat::from_path(Path::new("result")), None);
+    }
+}
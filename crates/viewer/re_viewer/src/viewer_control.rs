@@ -0,0 +1,64 @@
+//! Driving an embedded Viewer from its host, see [`ViewerControl`].
+
+use re_entity_db::InstancePath;
+use re_log_types::{EntityPath, Instance, StoreId, TimeReal, Timeline};
+use re_viewer_context::{CommandSender, Item, SystemCommand, SystemCommandSender as _};
+
+/// Lets the host of an embedded Viewer drive it, e.g. to select what its analysis code points at.
+///
+/// Together with [`crate::event::event_channel`], which goes the other way, this makes for a two-way
+/// bridge between the Viewer and its host.
+///
+/// Get one from [`crate::App::control`]. It is cheap to clone, and can be sent to other threads.
+#[derive(Clone)]
+pub struct ViewerControl {
+    command_sender: CommandSender,
+    egui_ctx: egui::Context,
+}
+
+impl ViewerControl {
+    pub(crate) fn new(command_sender: CommandSender, egui_ctx: egui::Context) -> Self {
+        Self {
+            command_sender,
+            egui_ctx,
+        }
+    }
+
+    /// Selects an entity of the active recording, replacing the current selection.
+    pub fn select_entity(&self, entity_path: impl Into<EntityPath>) {
+        self.select(Item::from(entity_path.into()));
+    }
+
+    /// Selects a single instance of an entity of the active recording, e.g. one of its points or boxes.
+    pub fn select_instance(
+        &self,
+        entity_path: impl Into<EntityPath>,
+        instance: impl Into<Instance>,
+    ) {
+        self.select(Item::from(InstancePath::instance(
+            entity_path.into(),
+            instance.into(),
+        )));
+    }
+
+    /// Selects the given item, replacing the current selection.
+    pub fn select(&self, item: Item) {
+        self.send(SystemCommand::SetSelection(item));
+    }
+
+    /// Moves the time cursor of a recording to the given time on the given timeline, and pauses playback.
+    pub fn set_time(&self, store_id: StoreId, timeline: Timeline, time: impl Into<TimeReal>) {
+        self.send(SystemCommand::SetActiveTime {
+            store_id,
+            timeline,
+            time: Some(time.into()),
+        });
+    }
+
+    fn send(&self, command: SystemCommand) {
+        self.command_sender.send_system(command);
+
+        // Commands are only handled during a frame.
+        self.egui_ctx.request_repaint();
+    }
+}
@@ -13,7 +13,7 @@ use wasm_bindgen::prelude::*;
 use re_log::ResultExt as _;
 use re_log_types::{TableId, TableMsg};
 use re_memory::AccountingAllocator;
-use re_viewer_context::AsyncRuntimeHandle;
+use re_viewer_context::{AsyncRuntimeHandle, SystemCommandSender as _};
 
 use crate::app_state::recording_config_entry;
 use crate::history::install_popstate_listener;
@@ -613,6 +613,132 @@ impl WebHandle {
             .set_play_state(recording.times_per_timeline(), play_state);
         egui_ctx.request_repaint();
     }
+
+    /// Get the paths of all selected entities of the active recording.
+    #[wasm_bindgen]
+    pub fn get_selected_entities(&self) -> Vec<String> {
+        let Some(app) = self.runner.app_mut::<crate::App>() else {
+            return Vec::new();
+        };
+
+        app.state
+            .selection_state
+            .selected_items()
+            .iter_items()
+            .filter_map(|item| item.entity_path())
+            .map(|entity_path| entity_path.to_string())
+            .collect()
+    }
+
+    /// Select the given entities of the active recording, replacing the current selection.
+    ///
+    /// An empty list clears the selection.
+    #[wasm_bindgen]
+    pub fn set_selected_entities(&self, entity_paths: Vec<String>) {
+        let Some(app) = self.runner.app_mut::<crate::App>() else {
+            return;
+        };
+
+        let items = entity_paths.iter().map(|entity_path| {
+            let item = re_viewer_context::Item::from(re_log_types::EntityPath::parse_forgiving(
+                entity_path,
+            ));
+            (item, None)
+        });
+        app.state.selection_state.set_selection(
+            re_viewer_context::ItemCollection::from_items_and_context(items),
+        );
+        app.egui_ctx.request_repaint();
+    }
+
+    /// Show or hide an entity and its children.
+    ///
+    /// This is the same as toggling the visibility of the entity in the blueprint panel, either in
+    /// the view with the given id, or in all views of the active blueprint that include the entity.
+    ///
+    /// This does nothing if the recording or view can't be found.
+    //TODO(#10737): we should refer to logical recordings using store id (recording id is ambibuous)
+    #[wasm_bindgen]
+    pub fn set_entity_visible(
+        &self,
+        recording_id: &str,
+        entity_path: &str,
+        visible: bool,
+        view_id: Option<String>,
+    ) {
+        let Some(mut app) = self.runner.app_mut::<crate::App>() else {
+            return;
+        };
+        let crate::App {
+            store_hub: Some(hub),
+            state,
+            command_sender,
+            egui_ctx,
+            ..
+        } = &mut *app
+        else {
+            return;
+        };
+
+        let view_id = match view_id
+            .map(|view_id| re_types::external::uuid::Uuid::try_parse(&view_id))
+            .transpose()
+        {
+            Ok(view_id) => view_id.map(re_viewer_context::ViewId::from),
+            Err(err) => {
+                re_log::warn!("Invalid view id: {err}");
+                return;
+            }
+        };
+
+        let Some(store_id) = store_id_from_recording_id(hub, recording_id) else {
+            return;
+        };
+        let Some(blueprint) = hub.active_blueprint_for_app(store_id.application_id()) else {
+            return;
+        };
+
+        let entity_path = re_log_types::EntityPath::parse_forgiving(entity_path);
+        let blueprint_query = state.blueprint_query_for_viewer(blueprint);
+        let viewport =
+            re_viewport_blueprint::ViewportBlueprint::from_db(blueprint, &blueprint_query);
+        let timepoint = re_viewer_context::blueprint_timepoint_for_writes(blueprint);
+
+        let chunks = viewport
+            .views
+            .values()
+            .filter(|view| match view_id {
+                Some(view_id) => view.id == view_id,
+                None => view.contents.entity_path_filter().matches(&entity_path),
+            })
+            .filter_map(|view| {
+                re_chunk::Chunk::builder(
+                    re_viewport_blueprint::ViewContents::override_path_for_entity(
+                        view.id,
+                        &entity_path,
+                    ),
+                )
+                .with_archetype(
+                    re_chunk::RowId::new(),
+                    timepoint.clone(),
+                    &re_types::blueprint::archetypes::EntityBehavior::update_fields()
+                        .with_visible(visible),
+                )
+                .build()
+                .ok_or_log_error()
+            })
+            .collect::<Vec<_>>();
+
+        if chunks.is_empty() {
+            return;
+        }
+
+        command_sender.send_system(re_viewer_context::SystemCommand::AppendToStore(
+            blueprint.store_id().clone(),
+            chunks,
+        ));
+        egui_ctx.request_repaint();
+    }
 }
 
 /// Best effort attempt at finding a store id based on the recording id.
@@ -766,6 +892,7 @@ fn create_app(
         force_wgpu_backend: render_backend.clone(),
         video_decoder_hw_acceleration,
         hide_welcome_screen: hide_welcome_screen.unwrap_or(false),
+        rrd_load_options: Default::default(),
 
         on_event: on_viewer_event.clone().map(|on_event| {
             Rc::new(move |event: crate::ViewerEvent| {
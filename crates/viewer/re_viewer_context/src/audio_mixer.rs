@@ -0,0 +1,65 @@
+use std::collections::BTreeSet;
+
+use re_log_types::EntityPath;
+
+/// Which audio entities of a recording are heard when playing the timeline.
+///
+/// Audio entities are entities with blocks of samples logged as `Waveform`s.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct AudioMixer {
+    /// Entities that are never heard.
+    pub muted: BTreeSet<EntityPath>,
+
+    /// If any entity is soloed, only soloed entities are heard.
+    pub soloed: BTreeSet<EntityPath>,
+}
+
+impl AudioMixer {
+    /// Whether `entity_path` is heard, given the mute and solo state of all entities.
+    pub fn is_audible(&self, entity_path: &EntityPath) -> bool {
+        if self.soloed.is_empty() {
+            !self.muted.contains(entity_path)
+        } else {
+            self.soloed.contains(entity_path)
+        }
+    }
+
+    pub fn set_muted(&mut self, entity_path: &EntityPath, muted: bool) {
+        set_contains(&mut self.muted, entity_path, muted);
+    }
+
+    pub fn set_soloed(&mut self, entity_path: &EntityPath, soloed: bool) {
+        set_contains(&mut self.soloed, entity_path, soloed);
+    }
+}
+
+fn set_contains(set: &mut BTreeSet<EntityPath>, entity_path: &EntityPath, contains: bool) {
+    if contains {
+        set.insert(entity_path.clone());
+    } else {
+        set.remove(entity_path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solo_overrides_mute() {
+        let (a, b) = (EntityPath::from("audio/a"), EntityPath::from("audio/b"));
+        let mut mixer = AudioMixer::default();
+        assert!(mixer.is_audible(&a) && mixer.is_audible(&b));
+
+        mixer.set_muted(&a, true);
+        assert!(!mixer.is_audible(&a) && mixer.is_audible(&b));
+
+        mixer.set_soloed(&a, true);
+        assert!(mixer.is_audible(&a) && !mixer.is_audible(&b));
+
+        mixer.set_soloed(&a, false);
+        mixer.set_muted(&a, false);
+        assert_eq!(AudioMixer::default(), mixer);
+    }
+}
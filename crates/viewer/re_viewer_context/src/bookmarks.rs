@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+
+use re_log_types::{TimeInt, TimelineName};
+
+/// A named point in time on a timeline.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct Bookmark {
+    pub name: String,
+    pub time: TimeInt,
+}
+
+/// The bookmarks of a recording, per timeline.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Bookmarks {
+    /// Sorted by time.
+    per_timeline: BTreeMap<TimelineName, Vec<Bookmark>>,
+}
+
+impl Bookmarks {
+    /// The bookmarks on `timeline`, sorted by time.
+    pub fn on_timeline(&self, timeline: &TimelineName) -> &[Bookmark] {
+        self.per_timeline.get(timeline).map_or(&[], Vec::as_slice)
+    }
+
+    /// Adds a bookmark, named after its position on the timeline, and returns its index.
+    pub fn add(&mut self, timeline: TimelineName, time: TimeInt) -> usize {
+        let bookmarks = self.per_timeline.entry(timeline).or_default();
+        let index = bookmarks.partition_point(|bookmark| bookmark.time <= time);
+        bookmarks.insert(
+            index,
+            Bookmark {
+                name: format!("Bookmark {}", bookmarks.len() + 1),
+                time,
+            },
+        );
+        index
+    }
+
+    pub fn rename(&mut self, timeline: &TimelineName, index: usize, name: String) {
+        if let Some(bookmark) = self
+            .per_timeline
+            .get_mut(timeline)
+            .and_then(|bookmarks| bookmarks.get_mut(index))
+        {
+            bookmark.name = name;
+        }
+    }
+
+    pub fn remove(&mut self, timeline: &TimelineName, index: usize) {
+        if let Some(bookmarks) = self.per_timeline.get_mut(timeline)
+            && index < bookmarks.len()
+        {
+            bookmarks.remove(index);
+            if bookmarks.is_empty() {
+                self.per_timeline.remove(timeline);
+            }
+        }
+    }
+
+    /// The first bookmark after `time`, if any.
+    pub fn next(&self, timeline: &TimelineName, time: TimeInt) -> Option<&Bookmark> {
+        let bookmarks = self.on_timeline(timeline);
+        bookmarks.get(bookmarks.partition_point(|bookmark| bookmark.time <= time))
+    }
+
+    /// The last bookmark before `time`, if any.
+    pub fn previous(&self, timeline: &TimelineName, time: TimeInt) -> Option<&Bookmark> {
+        let bookmarks = self.on_timeline(timeline);
+        let index = bookmarks.partition_point(|bookmark| bookmark.time < time);
+        index.checked_sub(1).and_then(|index| bookmarks.get(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_by_time() {
+        let timeline = TimelineName::log_time();
+        let mut bookmarks = Bookmarks::default();
+        assert_eq!(0, bookmarks.add(timeline, TimeInt::new_temporal(20)));
+        assert_eq!(0, bookmarks.add(timeline, TimeInt::new_temporal(10)));
+        assert_eq!(2, bookmarks.add(timeline, TimeInt::new_temporal(30)));
+        bookmarks.rename(&timeline, 1, "Takeoff".to_owned());
+
+        let names = |bookmarks: &Bookmarks| {
+            bookmarks
+                .on_timeline(&timeline)
+                .iter()
+                .map(|bookmark| bookmark.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            ["Bookmark 2", "Takeoff", "Bookmark 3"],
+            names(&bookmarks).as_slice()
+        );
+
+        let time = TimeInt::new_temporal(20);
+        assert_eq!(
+            Some(TimeInt::new_temporal(30)),
+            bookmarks.next(&timeline, time).map(|b| b.time)
+        );
+        assert_eq!(
+            Some(TimeInt::new_temporal(10)),
+            bookmarks.previous(&timeline, time).map(|b| b.time)
+        );
+
+        bookmarks.remove(&timeline, 0);
+        assert_eq!(["Takeoff", "Bookmark 3"], names(&bookmarks).as_slice());
+        assert_eq!(None, bookmarks.previous(&timeline, time));
+    }
+}
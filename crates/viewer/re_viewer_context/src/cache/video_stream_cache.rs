@@ -171,7 +171,18 @@ impl VideoStreamCache {
 
         let entry = match self.0.entry(key) {
             std::collections::hash_map::Entry::Occupied(occupied_entry) => {
-                occupied_entry.into_mut()
+                let entry = occupied_entry.into_mut();
+
+                // E.g. hardware acceleration was toggled in the settings.
+                if entry.video_stream.read().video_renderer.decode_settings() != &decode_settings {
+                    entry
+                        .video_stream
+                        .write()
+                        .video_renderer
+                        .set_decode_settings(decode_settings);
+                }
+
+                entry
             }
             std::collections::hash_map::Entry::Vacant(vacant_entry) => {
                 let (video_data, video_sample_buffers) =
@@ -0,0 +1,67 @@
+use re_chunk_store::{LatestAtQuery, RangeQuery};
+use re_entity_db::{EntityDb, EntityPath};
+use re_log_types::{AbsoluteTimeRange, TimeInt, TimelineName};
+
+use crate::ViewId;
+
+/// A time hovered in a view, e.g. a sample in a plot or a primitive in a spatial view.
+///
+/// Shared with all other views, which show a linked cursor at that time if
+/// [`crate::AppOptions::linked_time_cursor`] is enabled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HoveredTime {
+    /// The view the time is hovered in.
+    pub view_id: ViewId,
+
+    pub timeline: TimelineName,
+
+    pub time: TimeInt,
+}
+
+/// The time of the latest row logged to `entity_path` as of `query`, ignoring static data.
+pub fn latest_row_time(
+    recording: &EntityDb,
+    query: &LatestAtQuery,
+    entity_path: &EntityPath,
+) -> Option<TimeInt> {
+    re_tracing::profile_function!();
+
+    let at = query.at().as_i64();
+    recording
+        .storage_engine()
+        .store()
+        .latest_at_relevant_chunks_for_all_components(query, entity_path, false)
+        .iter()
+        .filter_map(|chunk| {
+            let time_column = chunk.timelines().get(&query.timeline())?;
+            time_column
+                .times_raw()
+                .iter()
+                .copied()
+                .filter(|&time| time <= at)
+                .max()
+        })
+        .max()
+        .map(TimeInt::new_temporal)
+}
+
+/// Whether a row was logged to `entity_path` at exactly `time` on `timeline`.
+pub fn has_row_at(
+    recording: &EntityDb,
+    timeline: TimelineName,
+    time: TimeInt,
+    entity_path: &EntityPath,
+) -> bool {
+    let query = RangeQuery::new(timeline, AbsoluteTimeRange::point(time));
+    recording
+        .storage_engine()
+        .store()
+        .range_relevant_chunks_for_all_components(&query, entity_path, false)
+        .iter()
+        .any(|chunk| {
+            chunk
+                .timelines()
+                .get(&timeline)
+                .is_some_and(|time_column| time_column.times_raw().contains(&time.as_i64()))
+        })
+}
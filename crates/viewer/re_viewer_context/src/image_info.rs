@@ -5,7 +5,7 @@ use re_log_types::hash::Hash64;
 use re_types::{
     ComponentDescriptor,
     components::Colormap,
-    datatypes::{Blob, ChannelDatatype, ColorModel, ImageFormat},
+    datatypes::{Blob, ChannelDatatype, ColorModel, ImageFormat, PixelFormat},
     image::{ImageKind, rgb_from_yuv},
     tensor_data::TensorElement,
 };
@@ -248,18 +248,7 @@ impl ImageInfo {
         let (w, h) = (self.width(), self.height());
 
         if let Some(pixel_format) = self.format.pixel_format {
-            // Convert to RGB.
-            // TODO(emilk): this can probably be optimized.
-            let mut rgb = Vec::with_capacity((w * h * 3) as usize);
-            for y in 0..h {
-                for x in 0..w {
-                    let [r, g, b] = pixel_format.decode_rgb_at(&self.buffer, [w, h], [x, y])?;
-                    rgb.push(r);
-                    rgb.push(g);
-                    rgb.push(b);
-                }
-            }
-            RgbImage::from_vec(w, h, rgb).map(DynamicImage::ImageRgb8)
+            RgbImage::from_vec(w, h, self.decode_rgb(pixel_format)?).map(DynamicImage::ImageRgb8)
         } else if self.format.datatype() == ChannelDatatype::U8 {
             let mut u8 = self.buffer.to_vec();
             match self.color_model() {
@@ -309,6 +298,32 @@ impl ImageInfo {
         }
     }
 
+    /// Converts a chroma-subsampled image to tightly packed RGB, using all cores for big images.
+    fn decode_rgb(&self, pixel_format: PixelFormat) -> Option<Vec<u8>> {
+        re_tracing::profile_function!();
+
+        use rayon::prelude::*;
+
+        /// Rows per parallel task, big enough to amortize the scheduling.
+        const ROWS_PER_BAND: usize = 64;
+
+        let (w, h) = (self.width(), self.height());
+        let row_len = 3 * w as usize;
+        let mut rgb = vec![0; row_len * h as usize];
+        if rgb.is_empty() {
+            return Some(rgb);
+        }
+
+        rgb.par_chunks_mut(row_len * ROWS_PER_BAND)
+            .enumerate()
+            .try_for_each(|(band, rgb)| {
+                let first_row = (band * ROWS_PER_BAND) as u32;
+                pixel_format.decode_rgb_rows(&self.buffer, [w, h], first_row, rgb)
+            })?;
+
+        Some(rgb)
+    }
+
     /// See [`Self::to_dynamic_image`].
     pub fn to_rgba8_image(&self, data_range: RangeInclusive<f32>) -> Option<image::RgbaImage> {
         self.to_dynamic_image(data_range).map(|img| img.to_rgba8())
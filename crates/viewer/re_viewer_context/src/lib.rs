@@ -6,15 +6,19 @@
 
 mod annotations;
 mod async_runtime_handle;
+mod audio_mixer;
 mod blueprint_helpers;
+mod bookmarks;
 mod cache;
 mod collapsed_id;
 mod component_fallbacks;
 mod component_ui_registry;
 mod drag_and_drop;
 mod heuristics;
+mod hovered_time;
 mod image_info;
 mod maybe_mut_ref;
+mod playback_link;
 mod query_context;
 mod query_range;
 mod selection_state;
@@ -23,6 +27,7 @@ mod store_context;
 pub mod store_hub;
 mod tables;
 mod tensor;
+mod time_alignment;
 mod time_control;
 mod typed_entity_collections;
 mod undo;
@@ -41,7 +46,9 @@ pub use re_global_context::*;
 pub use self::{
     annotations::{AnnotationMap, Annotations, ResolvedAnnotationInfo, ResolvedAnnotationInfos},
     async_runtime_handle::{AsyncRuntimeError, AsyncRuntimeHandle, WasmNotSend},
+    audio_mixer::AudioMixer,
     blueprint_helpers::{blueprint_timeline, blueprint_timepoint_for_writes},
+    bookmarks::{Bookmark, Bookmarks},
     cache::{
         Cache, Caches, ImageDecodeCache, ImageStatsCache, SharablePlayableVideoStream,
         TensorStatsCache, VideoAssetCache, VideoStreamCache, VideoStreamProcessingError,
@@ -54,8 +61,10 @@ pub use self::{
     component_ui_registry::{ComponentUiRegistry, ComponentUiTypes, EditTarget, VariantName},
     drag_and_drop::{DragAndDropFeedback, DragAndDropManager, DragAndDropPayload},
     heuristics::suggest_view_for_each_entity,
+    hovered_time::{HoveredTime, has_row_at, latest_row_time},
     image_info::{ColormapWithRange, ImageInfo, StoredBlobCacheKey},
     maybe_mut_ref::MaybeMutRef,
+    playback_link::PlaybackLink,
     query_context::{
         DataQueryResult, DataResultHandle, DataResultNode, DataResultTree, QueryContext,
     },
@@ -69,6 +78,7 @@ pub use self::{
     store_hub::StoreHub,
     tables::{TableStore, TableStores},
     tensor::{ImageStats, TensorStats},
+    time_alignment::TimeAlignment,
     time_control::{Looping, PlayState, TimeControl, TimeControlResponse, TimeView},
     typed_entity_collections::{
         IndicatedEntities, MaybeVisualizableEntities, PerVisualizer, VisualizableEntities,
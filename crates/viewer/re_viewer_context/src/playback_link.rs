@@ -0,0 +1,44 @@
+use re_log_types::TimeReal;
+
+/// Links the playback of a recording to that of the other linked recordings.
+///
+/// Playing, pausing and scrubbing the active recording moves all linked recordings along,
+/// e.g. to compare different runs of the same scenario by switching between them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct PlaybackLink {
+    /// Time of this recording at time zero of the shared playback, in the unit of the timeline.
+    ///
+    /// With all offsets at zero, linked recordings are aligned on absolute time.
+    pub offset: i64,
+}
+
+impl PlaybackLink {
+    /// The time in this recording that corresponds to `time` in the recording linked by `other`.
+    pub fn time_from(&self, other: &Self, time: TimeReal) -> TimeReal {
+        time + TimeReal::from(self.offset.saturating_sub(other.offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offsets_are_relative() {
+        let leader = PlaybackLink { offset: 10 };
+        let follower = PlaybackLink { offset: 25 };
+        assert_eq!(
+            TimeReal::from(115),
+            follower.time_from(&leader, TimeReal::from(100))
+        );
+        assert_eq!(
+            TimeReal::from(100),
+            leader.time_from(&follower, TimeReal::from(115))
+        );
+        assert_eq!(
+            TimeReal::from(100),
+            leader.time_from(&leader, TimeReal::from(100))
+        );
+    }
+}
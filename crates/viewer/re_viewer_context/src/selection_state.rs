@@ -7,7 +7,7 @@ use re_entity_db::EntityPath;
 use re_global_context::{ViewId, resolve_mono_instance_path_item};
 use re_log_types::StoreKind;
 
-use crate::ViewerContext;
+use crate::{HoveredTime, ViewerContext};
 
 use super::Item;
 
@@ -373,6 +373,14 @@ pub struct ApplicationSelectionState {
     /// What objects are hovered? Write to this.
     #[serde(skip)]
     hovered_this_frame: Mutex<ItemCollection>,
+
+    /// What time is hovered? Read from this.
+    #[serde(skip)]
+    hovered_time_previous_frame: Option<HoveredTime>,
+
+    /// What time is hovered? Write to this.
+    #[serde(skip)]
+    hovered_time_this_frame: Mutex<Option<HoveredTime>>,
 }
 
 pub enum SelectionChange<'a> {
@@ -401,6 +409,7 @@ impl ApplicationSelectionState {
 
         // Hovering needs to be refreshed every frame: If it wasn't hovered last frame, it's no longer hovered!
         self.hovered_previous_frame = std::mem::take(self.hovered_this_frame.get_mut());
+        self.hovered_time_previous_frame = self.hovered_time_this_frame.get_mut().take();
 
         // Selection in contrast, is sticky!
         if selection_this_frame != &self.selection_previous_frame {
@@ -444,6 +453,16 @@ impl ApplicationSelectionState {
         *self.hovered_this_frame.lock() = hovered.into();
     }
 
+    /// Returns the currently hovered time, if any.
+    pub fn hovered_time(&self) -> Option<HoveredTime> {
+        self.hovered_time_previous_frame
+    }
+
+    /// Set the hovered time. Will be in [`Self::hovered_time`] on the next frame.
+    pub fn set_hovered_time(&self, hovered_time: HoveredTime) {
+        *self.hovered_time_this_frame.lock() = Some(hovered_time);
+    }
+
     /// Select passed objects unless already selected in which case they get unselected.
     /// If however an object is already selected but now gets passed a *different* item context, it stays selected after all
     /// but with an updated context!
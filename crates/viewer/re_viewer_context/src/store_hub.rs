@@ -6,8 +6,8 @@ use itertools::Itertools as _;
 use nohash_hasher::IntMap;
 
 use re_chunk_store::{
-    ChunkStoreConfig, ChunkStoreGeneration, ChunkStoreStats, GarbageCollectionOptions,
-    GarbageCollectionTarget,
+    ChunkStoreConfig, ChunkStoreGeneration, ChunkStoreStats, CompactionOptions,
+    GarbageCollectionOptions, GarbageCollectionTarget,
 };
 use re_entity_db::{EntityDb, StoreBundle};
 use re_global_context::RecordingOrTable;
@@ -63,6 +63,9 @@ pub struct StoreHub {
 
     /// The [`ChunkStoreGeneration`] from when the [`EntityDb`] was last garbage collected
     blueprint_last_gc: HashMap<StoreId, ChunkStoreGeneration>,
+
+    /// The [`ChunkStoreGeneration`] from when the recording was last compacted
+    recording_last_compaction: HashMap<StoreId, ChunkStoreGeneration>,
 }
 
 /// Load a blueprint from persisted storage, e.g. disk.
@@ -170,6 +173,7 @@ impl StoreHub {
             caches_per_recording: Default::default(),
             blueprint_last_save: Default::default(),
             blueprint_last_gc: Default::default(),
+            recording_last_compaction: Default::default(),
 
             table_stores: TableStores::default(),
         }
@@ -303,6 +307,7 @@ impl StoreHub {
 
     fn remove_store(&mut self, store_id: &StoreId) {
         _ = self.caches_per_recording.remove(store_id);
+        _ = self.recording_last_compaction.remove(store_id);
         let removed_store = self.store_bundle.remove(store_id);
 
         let Some(removed_store) = removed_store else {
@@ -845,6 +850,48 @@ impl StoreHub {
         }
     }
 
+    /// Merges the small chunks of every recording into larger ones, see [`EntityDb::compact`].
+    ///
+    /// Recordings that haven't changed since they were last compacted are skipped.
+    pub fn compact_recordings(&mut self) {
+        re_tracing::profile_function!();
+
+        for entity_db in self.store_bundle.entity_dbs_mut() {
+            if entity_db.store_kind() != StoreKind::Recording {
+                continue;
+            }
+
+            let store_id = entity_db.store_id().clone();
+            if self.recording_last_compaction.get(&store_id) == Some(&entity_db.generation()) {
+                continue; // no change since last compaction
+            }
+
+            // Aim for the same chunk sizes as the insertion-time compaction of that recording.
+            let options = CompactionOptions {
+                time_budget: re_entity_db::DEFAULT_GC_TIME_BUDGET,
+                ..CompactionOptions::from_store_config(entity_db.storage_engine().store().config())
+            };
+
+            match entity_db.compact(&options) {
+                Ok(store_events) if !store_events.is_empty() => {
+                    // We might have run out of time budget: keep going next frame.
+                    re_log::debug!("Compacted recording {store_id}");
+                    if let Some(caches) = self.caches_per_recording.get_mut(&store_id) {
+                        caches.on_store_events(&store_events);
+                    }
+                    continue;
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    re_log::warn_once!("Failed to compact recording {store_id}: {err}");
+                }
+            }
+
+            self.recording_last_compaction
+                .insert(store_id, entity_db.generation());
+        }
+    }
+
     /// See [`crate::Caches::begin_frame`].
     pub fn begin_frame_caches(&mut self) {
         self.caches_per_recording.retain(|store_id, caches| {
@@ -950,6 +997,7 @@ impl StoreHub {
             caches_per_recording,
             blueprint_last_save: _,
             blueprint_last_gc: _,
+            recording_last_compaction: _,
         } = self;
 
         let mut store_stats = BTreeMap::new();
@@ -0,0 +1,119 @@
+use re_chunk_store::{LatestAtQuery, RangeQuery};
+use re_log_types::{AbsoluteTimeRange, TimeInt};
+
+/// Aligns the data of an entity with the timeline, e.g. for sensors recorded with a skewed clock.
+///
+/// Data logged at time `t` is shown at time `t * rate + offset` on the timeline.
+///
+/// Set via the `EntityTimeAlignment` blueprint archetype, and propagated through the entity tree.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimeAlignment {
+    /// Added to the times of the data, in nanoseconds or ticks.
+    pub offset: i64,
+
+    /// How fast the clock of the data runs relative to the timeline.
+    pub rate: f64,
+}
+
+impl Default for TimeAlignment {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl TimeAlignment {
+    pub const IDENTITY: Self = Self {
+        offset: 0,
+        rate: 1.0,
+    };
+
+    #[inline]
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// The time on the timeline of data logged at `data_time`.
+    pub fn view_time_from_data_time(&self, data_time: i64) -> i64 {
+        if self.rate == 1.0 {
+            data_time.saturating_add(self.offset)
+        } else {
+            ((data_time as f64 * self.rate).round() as i64).saturating_add(self.offset)
+        }
+    }
+
+    /// The time data must have been logged at to be shown at `view_time` on the timeline.
+    ///
+    /// Static time and the bounds of the timeline are kept as they are.
+    pub fn data_time_from_view_time(&self, view_time: TimeInt) -> TimeInt {
+        if self.is_identity()
+            || view_time.is_static()
+            || view_time == TimeInt::MIN
+            || view_time == TimeInt::MAX
+        {
+            return view_time;
+        }
+
+        let time = view_time.as_i64().saturating_sub(self.offset);
+        let time = if self.rate == 1.0 || !self.rate.is_normal() {
+            time
+        } else {
+            (time as f64 / self.rate).round() as i64
+        };
+        TimeInt::saturated_temporal_i64(time)
+    }
+
+    /// The range of data shown in `view_range` on the timeline.
+    pub fn data_range(&self, view_range: AbsoluteTimeRange) -> AbsoluteTimeRange {
+        AbsoluteTimeRange::new(
+            self.data_time_from_view_time(view_range.min()),
+            self.data_time_from_view_time(view_range.max()),
+        )
+    }
+
+    /// The query for the data shown at the time of `view_query`.
+    pub fn latest_at_query(&self, view_query: &LatestAtQuery) -> LatestAtQuery {
+        LatestAtQuery::new(
+            view_query.timeline(),
+            self.data_time_from_view_time(view_query.at()),
+        )
+    }
+
+    /// The query for the data shown in the range of `view_query`.
+    pub fn range_query(&self, view_query: &RangeQuery) -> RangeQuery {
+        let mut query = view_query.clone();
+        query.range = self.data_range(view_query.range);
+        query
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let alignment = TimeAlignment {
+            offset: -500,
+            rate: 2.0,
+        };
+        assert_eq!(1_500, alignment.view_time_from_data_time(1_000));
+        assert_eq!(
+            TimeInt::new_temporal(1_000),
+            alignment.data_time_from_view_time(TimeInt::new_temporal(1_500))
+        );
+
+        // Static data and open ranges are never shifted.
+        assert_eq!(
+            TimeInt::STATIC,
+            alignment.data_time_from_view_time(TimeInt::STATIC)
+        );
+        assert_eq!(
+            AbsoluteTimeRange::EVERYTHING,
+            alignment.data_range(AbsoluteTimeRange::EVERYTHING)
+        );
+
+        assert!(TimeAlignment::default().is_identity());
+        assert_eq!(42, TimeAlignment::IDENTITY.view_time_from_data_time(42));
+    }
+}
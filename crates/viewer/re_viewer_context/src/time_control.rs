@@ -1,9 +1,11 @@
-use std::collections::BTreeMap;
+use std::{borrow::Cow, collections::BTreeMap};
 
 use re_chunk::TimelineName;
-use re_entity_db::{TimeCounts, TimesPerTimeline};
+use re_chunk_store::RangeQuery;
+use re_entity_db::{EntityDb, EntityPath, TimeCounts, TimesPerTimeline};
 use re_log_types::{
     AbsoluteTimeRange, AbsoluteTimeRangeF, Duration, TimeInt, TimeReal, TimeType, Timeline,
+    TimelineMapping,
 };
 
 use crate::NeedsRepaint;
@@ -161,6 +163,15 @@ pub struct TimeControl {
 
     looping: Looping,
 
+    /// If set, stepping back and forward goes to the previous and next sample of this entity,
+    /// instead of the previous and next time with any data.
+    #[serde(default)]
+    step_entity: Option<EntityPath>,
+
+    /// The timeline that the recording asks to select by default, see [`Self::set_recording_default_timeline`].
+    #[serde(skip)]
+    recording_default_timeline: Option<TimelineName>,
+
     /// Range with special highlight.
     ///
     /// This is used during UI interactions. E.g. to show visual history range that's highlighted.
@@ -178,6 +189,8 @@ impl Default for TimeControl {
             following: true,
             speed: 1.0,
             looping: Looping::Off,
+            step_entity: None,
+            recording_default_timeline: None,
             highlighted_range: None,
         }
     }
@@ -433,8 +446,18 @@ impl TimeControl {
         self.playing = false;
     }
 
-    pub fn step_time_back(&mut self, times_per_timeline: &TimesPerTimeline) {
-        let Some(timeline_stats) = times_per_timeline.get(self.timeline().name()) else {
+    /// The entity whose samples are stepped through, if any.
+    pub fn step_entity(&self) -> Option<&EntityPath> {
+        self.step_entity.as_ref()
+    }
+
+    /// Step through the samples of the given entity, or through all data if `None`.
+    pub fn set_step_entity(&mut self, entity_path: Option<EntityPath>) {
+        self.step_entity = entity_path;
+    }
+
+    pub fn step_time_back(&mut self, recording: &EntityDb) {
+        let Some(values) = self.step_values(recording) else {
             return;
         };
 
@@ -443,16 +466,16 @@ impl TimeControl {
         if let Some(time) = self.time() {
             #[allow(clippy::collapsible_else_if)]
             let new_time = if let Some(loop_range) = self.active_loop_selection() {
-                step_back_time_looped(time, &timeline_stats.per_time, &loop_range)
+                step_back_time_looped(time, &values, &loop_range)
             } else {
-                step_back_time(time, &timeline_stats.per_time).into()
+                step_back_time(time, &values).into()
             };
             self.set_time(new_time);
         }
     }
 
-    pub fn step_time_fwd(&mut self, times_per_timeline: &TimesPerTimeline) {
-        let Some(stats) = times_per_timeline.get(self.timeline().name()) else {
+    pub fn step_time_fwd(&mut self, recording: &EntityDb) {
+        let Some(values) = self.step_values(recording) else {
             return;
         };
 
@@ -461,14 +484,30 @@ impl TimeControl {
         if let Some(time) = self.time() {
             #[allow(clippy::collapsible_else_if)]
             let new_time = if let Some(loop_range) = self.active_loop_selection() {
-                step_fwd_time_looped(time, &stats.per_time, &loop_range)
+                step_fwd_time_looped(time, &values, &loop_range)
             } else {
-                step_fwd_time(time, &stats.per_time).into()
+                step_fwd_time(time, &values).into()
             };
             self.set_time(new_time);
         }
     }
 
+    /// The times stepped through on the current timeline.
+    ///
+    /// Returns `None` if there's nothing to step to, e.g. because the pinned entity has no data on this timeline.
+    fn step_values<'a>(&self, recording: &'a EntityDb) -> Option<Cow<'a, TimeCounts>> {
+        let timeline = *self.timeline().name();
+        if let Some(entity_path) = &self.step_entity {
+            let values = entity_time_counts(recording, timeline, entity_path);
+            (!values.is_empty()).then_some(Cow::Owned(values))
+        } else {
+            recording
+                .times_per_timeline()
+                .get(&timeline)
+                .map(|stats| Cow::Borrowed(&stats.per_time))
+        }
+    }
+
     pub fn restart(&mut self, times_per_timeline: &TimesPerTimeline) {
         if let Some(stats) = times_per_timeline.get(self.timeline.name())
             && let Some(state) = self.states.get_mut(self.timeline.name())
@@ -563,10 +602,26 @@ impl TimeControl {
         if matches!(self.timeline, ActiveTimeline::Auto(_))
             || !is_timeline_valid(self.timeline(), times_per_timeline)
         {
-            self.timeline = ActiveTimeline::Auto(default_timeline(times_per_timeline.timelines()));
+            let timeline = self
+                .recording_default_timeline
+                .and_then(|name| {
+                    times_per_timeline
+                        .timelines()
+                        .find(|timeline| *timeline.name() == name)
+                        .copied()
+                })
+                .unwrap_or_else(|| default_timeline(times_per_timeline.timelines()));
+            self.timeline = ActiveTimeline::Auto(timeline);
         }
     }
 
+    /// Sets the timeline that the recording asks to select, as long as the user didn't pick one.
+    ///
+    /// See [`re_log_types::DefaultTimeline`].
+    pub fn set_recording_default_timeline(&mut self, timeline: Option<TimelineName>) {
+        self.recording_default_timeline = timeline;
+    }
+
     /// The currently selected timeline
     #[inline]
     pub fn timeline(&self) -> &Timeline {
@@ -582,6 +637,29 @@ impl TimeControl {
         self.timeline = ActiveTimeline::UserEdited(timeline);
     }
 
+    /// Like [`Self::set_timeline`], but keeps the time cursor on the same moment if one of the
+    /// `mappings` relates the current timeline to the new one.
+    pub fn set_timeline_aligned(&mut self, timeline: Timeline, mappings: &[TimelineMapping]) {
+        let current = *self.timeline.name();
+        let aligned_time = self.time_int().and_then(|time| {
+            mappings.iter().find_map(|mapping| {
+                if mapping.source == current && mapping.target == *timeline.name() {
+                    Some(mapping.map(time))
+                } else if mapping.source == *timeline.name() && mapping.target == current {
+                    Some(mapping.inverse().map(time))
+                } else {
+                    None
+                }
+            })
+        });
+
+        self.set_timeline(timeline);
+
+        if let Some(time) = aligned_time {
+            self.set_time(time);
+        }
+    }
+
     /// The current time.
     pub fn time(&self) -> Option<TimeReal> {
         self.states
@@ -668,6 +746,17 @@ impl TimeControl {
         }
     }
 
+    /// Mirrors the timeline, play state and speed of `leader`, with the time cursor at `time`.
+    ///
+    /// Used to move recordings with linked playback along with the active one.
+    pub fn follow_linked(&mut self, leader: &Self, time: TimeReal) {
+        self.timeline = ActiveTimeline::UserEdited(*leader.timeline());
+        self.playing = leader.playing;
+        self.following = false;
+        self.speed = leader.speed;
+        self.set_time(time);
+    }
+
     pub fn set_timeline_and_time(&mut self, timeline: Timeline, time: impl Into<TimeReal>) {
         self.timeline = ActiveTimeline::UserEdited(timeline);
         self.set_time(time);
@@ -755,6 +844,28 @@ fn default_timeline<'a>(timelines: impl IntoIterator<Item = &'a Timeline>) -> Ti
     }
 }
 
+/// The times at which the entity has data on the given timeline, ignoring static data.
+fn entity_time_counts(
+    recording: &EntityDb,
+    timeline: TimelineName,
+    entity_path: &EntityPath,
+) -> TimeCounts {
+    let query = RangeQuery::everything(timeline);
+    let mut values = TimeCounts::default();
+    for chunk in recording
+        .storage_engine()
+        .store()
+        .range_relevant_chunks_for_all_components(&query, entity_path, false)
+    {
+        if let Some(time_column) = chunk.timelines().get(&timeline) {
+            for time in time_column.times() {
+                *values.entry(time).or_default() += 1;
+            }
+        }
+    }
+    values
+}
+
 fn step_fwd_time(time: TimeReal, values: &TimeCounts) -> TimeInt {
     if let Some((next, _)) = values
         .range((
@@ -862,4 +973,73 @@ mod tests {
         );
         assert_eq!(default_timeline([&custom_timeline0]), custom_timeline0);
     }
+
+    #[test]
+    fn test_set_timeline_aligned() {
+        let ros_time = Timeline::new("ros_time", TimeType::TimestampNs);
+        let wall_clock = Timeline::new("wall_clock", TimeType::TimestampNs);
+        let frame_nr = Timeline::new("frame_nr", TimeType::Sequence);
+        let mappings =
+            [TimelineMapping::new(*ros_time.name(), *wall_clock.name()).with_offset(100)];
+
+        let mut time_ctrl = TimeControl::default();
+        time_ctrl.set_timeline(ros_time);
+        time_ctrl.set_time(TimeInt::new_temporal(1_000));
+
+        time_ctrl.set_timeline_aligned(wall_clock, &mappings);
+        assert_eq!(time_ctrl.time_int(), Some(TimeInt::new_temporal(1_100)));
+
+        time_ctrl.set_time(TimeInt::new_temporal(2_100));
+        time_ctrl.set_timeline_aligned(ros_time, &mappings);
+        assert_eq!(time_ctrl.time_int(), Some(TimeInt::new_temporal(2_000)));
+
+        // No mapping: the time of the new timeline is left untouched.
+        time_ctrl.set_timeline_aligned(frame_nr, &mappings);
+        assert_eq!(time_ctrl.time_int(), None);
+    }
+
+    #[test]
+    fn test_step_through_entity() {
+        use std::sync::Arc;
+
+        use re_chunk::{Chunk, RowId, TimePoint};
+        use re_log_types::{StoreId, StoreKind};
+        use re_types::archetypes::Points2D;
+
+        let frame_nr = Timeline::new_sequence("frame_nr");
+        let mut recording = EntityDb::new(StoreId::random(StoreKind::Recording, "test_app"));
+        for (entity_path, frames) in [("camera", [10_i64, 20, 30]), ("lidar", [11, 12, 25])] {
+            let mut builder = Chunk::builder(entity_path.into());
+            for frame in frames {
+                builder = builder.with_archetype(
+                    RowId::new(),
+                    TimePoint::from_iter([(frame_nr, frame)]),
+                    &Points2D::new([(0.0, 0.0)]),
+                );
+            }
+            recording
+                .add_chunk(&Arc::new(builder.build().unwrap()))
+                .unwrap();
+        }
+
+        let mut time_ctrl = TimeControl::default();
+        time_ctrl.set_timeline(frame_nr);
+        time_ctrl.set_time(TimeInt::new_temporal(10));
+
+        time_ctrl.step_time_fwd(&recording);
+        assert_eq!(time_ctrl.time_int(), Some(TimeInt::new_temporal(11)));
+
+        time_ctrl.set_step_entity(Some("camera".into()));
+        time_ctrl.step_time_fwd(&recording);
+        assert_eq!(time_ctrl.time_int(), Some(TimeInt::new_temporal(20)));
+        time_ctrl.step_time_fwd(&recording);
+        assert_eq!(time_ctrl.time_int(), Some(TimeInt::new_temporal(30)));
+        time_ctrl.step_time_back(&recording);
+        assert_eq!(time_ctrl.time_int(), Some(TimeInt::new_temporal(20)));
+
+        // Nothing to step to.
+        time_ctrl.set_step_entity(Some("radar".into()));
+        time_ctrl.step_time_back(&recording);
+        assert_eq!(time_ctrl.time_int(), Some(TimeInt::new_temporal(20)));
+    }
 }
@@ -217,6 +217,38 @@ impl ViewClassRegistry {
         Ok(())
     }
 
+    /// Adds a visualizer to an already registered view class, e.g. to one of the built-in views.
+    ///
+    /// This is how custom archetypes get visualized in the existing views, next to the built-in
+    /// visualizers. Fails if the view class is unknown, or if the visualizer was already added to it.
+    pub fn add_visualizer<T: VisualizerSystem + IdentifiedViewSystem + Default + 'static>(
+        &mut self,
+        view_class: ViewClassIdentifier,
+    ) -> Result<(), ViewClassRegistryError> {
+        let Some(visualizers) = self
+            .view_classes
+            .get(&view_class)
+            .map(|entry| entry.visualizer_system_ids.clone())
+        else {
+            return Err(ViewClassRegistryError::UnknownClassIdentifier(view_class));
+        };
+
+        let mut registrator = ViewSystemRegistrator {
+            registry: self,
+            identifier: view_class,
+            context_systems: Default::default(),
+            visualizers,
+        };
+        registrator.register_visualizer::<T>()?;
+        let visualizers = registrator.visualizers;
+
+        if let Some(entry) = self.view_classes.get_mut(&view_class) {
+            entry.visualizer_system_ids = visualizers;
+        }
+
+        Ok(())
+    }
+
     /// Removes a view class from the registry.
     pub fn remove_class<T: ViewClass + Sized>(&mut self) -> Result<(), ViewClassRegistryError> {
         let identifier = T::identifier();
@@ -14,7 +14,8 @@ use re_types::{
 };
 
 use crate::{
-    DataResultTree, QueryRange, ViewHighlights, ViewId, ViewSystemIdentifier, ViewerContext,
+    DataResultTree, QueryRange, TimeAlignment, ViewHighlights, ViewId, ViewSystemIdentifier,
+    ViewerContext,
 };
 
 /// Path to a specific entity in a specific store used for overrides.
@@ -35,7 +36,7 @@ impl OverridePath {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct PropertyOverrides {
     /// An alternative store and entity path to use for the specified component.
     ///
@@ -63,6 +64,11 @@ pub struct PropertyOverrides {
     ///
     /// This is sourced either from an override or via the `View`'s query range.
     pub query_range: QueryRange,
+
+    /// How the times of the data are mapped onto the timeline.
+    ///
+    /// This is propagated through the entity tree.
+    pub time_alignment: TimeAlignment,
 }
 
 pub type SmallVisualizerSet = SmallVec<[ViewSystemIdentifier; 4]>;
@@ -210,6 +216,12 @@ impl DataResult {
     pub fn query_range(&self) -> &QueryRange {
         &self.property_overrides.query_range
     }
+
+    /// Returns how the times of the data are mapped onto the timeline.
+    #[inline]
+    pub fn time_alignment(&self) -> &TimeAlignment {
+        &self.property_overrides.time_alignment
+    }
 }
 
 pub type PerSystemDataResults<'a> = BTreeMap<ViewSystemIdentifier, Vec<&'a DataResult>>;
@@ -12,10 +12,10 @@ use re_ui::ContextExt as _;
 
 use crate::drag_and_drop::DragAndDropPayload;
 use crate::{
-    AppOptions, ApplicationSelectionState, CommandSender, ComponentUiRegistry, DragAndDropManager,
-    IndicatedEntities, ItemCollection, MaybeVisualizableEntities, PerVisualizer, StoreContext,
-    SystemCommandSender as _, TimeControl, ViewClassRegistry, ViewId,
-    query_context::DataQueryResult,
+    AppOptions, ApplicationSelectionState, AudioMixer, Bookmarks, CommandSender,
+    ComponentUiRegistry, DragAndDropManager, IndicatedEntities, ItemCollection,
+    MaybeVisualizableEntities, PerVisualizer, PlaybackLink, StoreContext, SystemCommandSender as _,
+    TimeControl, ViewClassRegistry, ViewId, query_context::DataQueryResult,
 };
 use crate::{GlobalContext, Item, StorageContext, StoreHub};
 
@@ -359,4 +359,13 @@ impl ViewerContext<'_> {
 pub struct RecordingConfig {
     /// The current time of the time panel, how fast it is moving, etc.
     pub time_ctrl: RwLock<TimeControl>,
+
+    /// Which audio entities are heard when playing the timeline.
+    pub audio_mixer: RwLock<AudioMixer>,
+
+    /// Named points in time, shown on the timeline.
+    pub bookmarks: RwLock<Bookmarks>,
+
+    /// Set if the playback of this recording is linked to other recordings.
+    pub playback_link: RwLock<Option<PlaybackLink>>,
 }
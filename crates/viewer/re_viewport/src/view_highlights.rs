@@ -6,7 +6,7 @@ use re_log_types::EntityPathHash;
 use re_renderer::OutlineMaskPreference;
 use re_viewer_context::{
     HoverHighlight, Item, SelectionHighlight, ViewEntityHighlight, ViewHighlights, ViewId,
-    ViewOutlineMasks,
+    ViewOutlineMasks, has_row_at,
 };
 
 /// Computes which things in a view should received highlighting.
@@ -123,6 +123,44 @@ pub fn highlights_for_view(
         }
     }
 
+    // Hovering a time in another view highlights everything that was logged at that time.
+    if ctx.app_options().linked_time_cursor
+        && let Some(hovered_time) = ctx.selection_state().hovered_time()
+        && hovered_time.view_id != view_id
+    {
+        re_tracing::profile_scope!("linked_time_cursor");
+
+        let mut entity_paths = Vec::new();
+        ctx.lookup_query_result(view_id).tree.visit(&mut |node| {
+            if node.data_result.is_visible() && !node.data_result.visualizers.is_empty() {
+                entity_paths.push(node.data_result.entity_path.clone());
+            }
+            true
+        });
+
+        for entity_path in entity_paths {
+            if !has_row_at(
+                ctx.recording(),
+                hovered_time.timeline,
+                hovered_time.time,
+                &entity_path,
+            ) {
+                continue;
+            }
+
+            let entity_hash = entity_path.hash();
+            let instance = InstancePath::entity_all(entity_path);
+            highlighted_entity_paths
+                .entry(entity_hash)
+                .or_default()
+                .add_hover(&instance, HoverHighlight::Hovered);
+            outlines_masks
+                .entry(entity_hash)
+                .or_default()
+                .add(&instance, next_hover_mask());
+        }
+    }
+
     ViewHighlights {
         highlighted_entity_paths,
         outlines_masks,
@@ -22,7 +22,8 @@ use re_types::{
 use re_viewer_context::{
     DataQueryResult, DataResult, DataResultHandle, DataResultNode, DataResultTree,
     IndicatedEntities, MaybeVisualizableEntities, OverridePath, PerVisualizer, PropertyOverrides,
-    QueryRange, ViewClassRegistry, ViewId, ViewStates, ViewerContext, VisualizableEntities,
+    QueryRange, TimeAlignment, ViewClassRegistry, ViewId, ViewStates, ViewerContext,
+    VisualizableEntities,
 };
 
 use crate::{ViewBlueprint, ViewProperty};
@@ -411,6 +412,7 @@ impl QueryExpressionEvaluator<'_> {
                         interactive: true, // Determined later during `update_overrides_recursive`.
                         override_path: self.override_base_path.join(entity_path),
                         query_range: QueryRange::default(), // Determined later during `update_overrides_recursive`.
+                        time_alignment: TimeAlignment::default(), // Determined later during `update_overrides_recursive`.
                     },
                 },
                 children,
@@ -461,16 +463,18 @@ impl<'a> DataQueryPropertyResolver<'a> {
         default_query_range: &QueryRange,
         parent_visible: bool,
         parent_interactive: bool,
+        parent_time_alignment: TimeAlignment,
     ) {
         let Some(node) = query_result.tree.lookup_node_mut(handle) else {
             return;
         };
         let property_overrides = &mut node.data_result.property_overrides;
 
-        // Set defaults for time-range/visible/interactive.
+        // Set defaults for time-range/visible/interactive/time alignment.
         property_overrides.query_range = default_query_range.clone();
         property_overrides.visible = parent_visible;
         property_overrides.interactive = parent_interactive;
+        property_overrides.time_alignment = parent_time_alignment;
 
         let override_path = &property_overrides.override_path;
 
@@ -557,6 +561,28 @@ impl<'a> DataQueryPropertyResolver<'a> {
                             // We already checked for non-empty above, so this should be safe.
                             property_overrides.interactive = interactive_array.value(0);
                         }
+                        // Time offset override.
+                        else if component_descr
+                            == blueprint_archetypes::EntityTimeAlignment::descriptor_time_offset()
+                        {
+                            if let Ok(time_offsets) =
+                                blueprint_components::TimeOffset::from_arrow(&component_data)
+                                && let Some(time_offset) = time_offsets.first()
+                            {
+                                property_overrides.time_alignment.offset = time_offset.0.0;
+                            }
+                        }
+                        // Playback rate override.
+                        else if component_descr
+                            == blueprint_archetypes::EntityTimeAlignment::descriptor_playback_rate()
+                            && let Ok(playback_rates) =
+                                blueprint_components::PlaybackRate::from_arrow(&component_data)
+                            && let Some(playback_rate) = playback_rates.first()
+                            && playback_rate.0.0.is_normal()
+                            && playback_rate.0.0 > 0.0
+                        {
+                            property_overrides.time_alignment.rate = playback_rate.0.0;
+                        }
 
                         // TODO(andreas): Why not keep the component data while we're here? Could speed up things a lot down the line.
                         component_overrides.insert(
@@ -571,6 +597,7 @@ impl<'a> DataQueryPropertyResolver<'a> {
         let children = node.children.clone(); // Borrow-checker workaround.
         let visible = property_overrides.visible;
         let interactive = property_overrides.interactive;
+        let time_alignment = property_overrides.time_alignment;
 
         for child in children {
             self.update_overrides_recursive(
@@ -582,6 +609,7 @@ impl<'a> DataQueryPropertyResolver<'a> {
                 default_query_range,
                 visible,
                 interactive,
+                time_alignment,
             );
         }
     }
@@ -611,6 +639,7 @@ impl<'a> DataQueryPropertyResolver<'a> {
             );
             let parent_visible = true;
             let parent_interactive = true;
+            let parent_time_alignment = TimeAlignment::default();
 
             self.update_overrides_recursive(
                 blueprint,
@@ -621,6 +650,7 @@ impl<'a> DataQueryPropertyResolver<'a> {
                 &default_query_range,
                 parent_visible,
                 parent_interactive,
+                parent_time_alignment,
             );
         }
     }
@@ -10,16 +10,19 @@
 #include "archetypes/bar_chart.hpp"
 #include "archetypes/boxes2d.hpp"
 #include "archetypes/boxes3d.hpp"
+#include "archetypes/camera_rig.hpp"
 #include "archetypes/capsules3d.hpp"
 #include "archetypes/clear.hpp"
 #include "archetypes/cylinders3d.hpp"
 #include "archetypes/depth_image.hpp"
 #include "archetypes/ellipsoids3d.hpp"
 #include "archetypes/encoded_image.hpp"
+#include "archetypes/entity_relations.hpp"
 #include "archetypes/geo_line_strings.hpp"
 #include "archetypes/geo_points.hpp"
 #include "archetypes/graph_edges.hpp"
 #include "archetypes/graph_nodes.hpp"
+#include "archetypes/histogram.hpp"
 #include "archetypes/image.hpp"
 #include "archetypes/instance_poses3d.hpp"
 #include "archetypes/line_strips2d.hpp"
@@ -40,3 +43,4 @@
 #include "archetypes/video_frame_reference.hpp"
 #include "archetypes/video_stream.hpp"
 #include "archetypes/view_coordinates.hpp"
+#include "archetypes/waveform.hpp"
@@ -0,0 +1,73 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/camera_rig.fbs".
+
+#include "camera_rig.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {
+    CameraRig CameraRig::clear_fields() {
+        auto archetype = CameraRig();
+        archetype.cameras =
+            ComponentBatch::empty<rerun::components::EntityPath>(Descriptor_cameras)
+                .value_or_throw();
+        archetype.translations =
+            ComponentBatch::empty<rerun::components::PoseTranslation3D>(Descriptor_translations)
+                .value_or_throw();
+        archetype.quaternions =
+            ComponentBatch::empty<rerun::components::PoseRotationQuat>(Descriptor_quaternions)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> CameraRig::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(3);
+        if (cameras.has_value()) {
+            columns.push_back(cameras.value().partitioned(lengths_).value_or_throw());
+        }
+        if (translations.has_value()) {
+            columns.push_back(translations.value().partitioned(lengths_).value_or_throw());
+        }
+        if (quaternions.has_value()) {
+            columns.push_back(quaternions.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> CameraRig::columns() {
+        if (cameras.has_value()) {
+            return columns(std::vector<uint32_t>(cameras.value().length(), 1));
+        }
+        if (translations.has_value()) {
+            return columns(std::vector<uint32_t>(translations.value().length(), 1));
+        }
+        if (quaternions.has_value()) {
+            return columns(std::vector<uint32_t>(quaternions.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<archetypes::CameraRig>::as_batches(
+        const archetypes::CameraRig& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(3);
+
+        if (archetype.cameras.has_value()) {
+            cells.push_back(archetype.cameras.value());
+        }
+        if (archetype.translations.has_value()) {
+            cells.push_back(archetype.translations.value());
+        }
+        if (archetype.quaternions.has_value()) {
+            cells.push_back(archetype.quaternions.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,147 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/camera_rig.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/entity_path.hpp"
+#include "../components/pose_rotation_quat.hpp"
+#include "../components/pose_translation3d.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: Describes a rig of several cameras with fixed extrinsics relative to the rig entity.
+    ///
+    /// This makes it possible to express the calibration of a stereo pair or a multi-camera robot once,
+    /// at the rig entity, instead of logging a `archetypes::Transform3D` for each camera.
+    /// Each camera is a direct child of the rig entity and is placed relative to it by its pose in the rig.
+    ///
+    /// If a camera entity also has a `archetypes::Transform3D`, it is applied on top of its pose in the rig.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct CameraRig {
+        /// The camera entities of the rig, relative to the rig entity.
+        ///
+        /// Each camera must be a direct child of the rig entity, e.g. `left` and `right` for a stereo pair.
+        std::optional<ComponentBatch> cameras;
+
+        /// Translation of each camera relative to the rig entity.
+        ///
+        /// If there are fewer translations than cameras, the missing cameras aren't translated.
+        std::optional<ComponentBatch> translations;
+
+        /// Rotation of each camera relative to the rig entity, as a quaternion.
+        ///
+        /// If there are fewer quaternions than cameras, the missing cameras aren't rotated.
+        std::optional<ComponentBatch> quaternions;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.CameraRig";
+
+        /// `ComponentDescriptor` for the `cameras` field.
+        static constexpr auto Descriptor_cameras = ComponentDescriptor(
+            ArchetypeName, "CameraRig:cameras",
+            Loggable<rerun::components::EntityPath>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `translations` field.
+        static constexpr auto Descriptor_translations = ComponentDescriptor(
+            ArchetypeName, "CameraRig:translations",
+            Loggable<rerun::components::PoseTranslation3D>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `quaternions` field.
+        static constexpr auto Descriptor_quaternions = ComponentDescriptor(
+            ArchetypeName, "CameraRig:quaternions",
+            Loggable<rerun::components::PoseRotationQuat>::ComponentType
+        );
+
+      public:
+        CameraRig() = default;
+        CameraRig(CameraRig&& other) = default;
+        CameraRig(const CameraRig& other) = default;
+        CameraRig& operator=(const CameraRig& other) = default;
+        CameraRig& operator=(CameraRig&& other) = default;
+
+        explicit CameraRig(Collection<rerun::components::EntityPath> _cameras)
+            : cameras(ComponentBatch::from_loggable(std::move(_cameras), Descriptor_cameras)
+                          .value_or_throw()) {}
+
+        /// Update only some specific fields of a `CameraRig`.
+        static CameraRig update_fields() {
+            return CameraRig();
+        }
+
+        /// Clear all the fields of a `CameraRig`.
+        static CameraRig clear_fields();
+
+        /// The camera entities of the rig, relative to the rig entity.
+        ///
+        /// Each camera must be a direct child of the rig entity, e.g. `left` and `right` for a stereo pair.
+        CameraRig with_cameras(const Collection<rerun::components::EntityPath>& _cameras) && {
+            cameras = ComponentBatch::from_loggable(_cameras, Descriptor_cameras).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Translation of each camera relative to the rig entity.
+        ///
+        /// If there are fewer translations than cameras, the missing cameras aren't translated.
+        CameraRig with_translations(
+            const Collection<rerun::components::PoseTranslation3D>& _translations
+        ) && {
+            translations = ComponentBatch::from_loggable(_translations, Descriptor_translations)
+                               .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Rotation of each camera relative to the rig entity, as a quaternion.
+        ///
+        /// If there are fewer quaternions than cameras, the missing cameras aren't rotated.
+        CameraRig with_quaternions(
+            const Collection<rerun::components::PoseRotationQuat>& _quaternions
+        ) && {
+            quaternions = ComponentBatch::from_loggable(_quaternions, Descriptor_quaternions)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::CameraRig> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const archetypes::CameraRig& archetype
+        );
+    };
+} // namespace rerun
@@ -19,6 +19,9 @@ namespace rerun::archetypes {
         archetype.depth_range =
             ComponentBatch::empty<rerun::components::ValueRange>(Descriptor_depth_range)
                 .value_or_throw();
+        archetype.clip_range =
+            ComponentBatch::empty<rerun::components::Range1D>(Descriptor_clip_range)
+                .value_or_throw();
         archetype.point_fill_ratio =
             ComponentBatch::empty<rerun::components::FillRatio>(Descriptor_point_fill_ratio)
                 .value_or_throw();
@@ -30,7 +33,7 @@ namespace rerun::archetypes {
 
     Collection<ComponentColumn> DepthImage::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(7);
+        columns.reserve(8);
         if (buffer.has_value()) {
             columns.push_back(buffer.value().partitioned(lengths_).value_or_throw());
         }
@@ -46,6 +49,9 @@ namespace rerun::archetypes {
         if (depth_range.has_value()) {
             columns.push_back(depth_range.value().partitioned(lengths_).value_or_throw());
         }
+        if (clip_range.has_value()) {
+            columns.push_back(clip_range.value().partitioned(lengths_).value_or_throw());
+        }
         if (point_fill_ratio.has_value()) {
             columns.push_back(point_fill_ratio.value().partitioned(lengths_).value_or_throw());
         }
@@ -71,6 +77,9 @@ namespace rerun::archetypes {
         if (depth_range.has_value()) {
             return columns(std::vector<uint32_t>(depth_range.value().length(), 1));
         }
+        if (clip_range.has_value()) {
+            return columns(std::vector<uint32_t>(clip_range.value().length(), 1));
+        }
         if (point_fill_ratio.has_value()) {
             return columns(std::vector<uint32_t>(point_fill_ratio.value().length(), 1));
         }
@@ -88,7 +97,7 @@ namespace rerun {
     ) {
         using namespace archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(7);
+        cells.reserve(8);
 
         if (archetype.buffer.has_value()) {
             cells.push_back(archetype.buffer.value());
@@ -105,6 +114,9 @@ namespace rerun {
         if (archetype.depth_range.has_value()) {
             cells.push_back(archetype.depth_range.value());
         }
+        if (archetype.clip_range.has_value()) {
+            cells.push_back(archetype.clip_range.value());
+        }
         if (archetype.point_fill_ratio.has_value()) {
             cells.push_back(archetype.point_fill_ratio.value());
         }
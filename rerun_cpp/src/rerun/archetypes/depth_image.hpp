@@ -12,6 +12,7 @@
 #include "../components/fill_ratio.hpp"
 #include "../components/image_buffer.hpp"
 #include "../components/image_format.hpp"
+#include "../components/range1d.hpp"
 #include "../components/value_range.hpp"
 #include "../image_utils.hpp"
 #include "../result.hpp"
@@ -107,6 +108,14 @@ namespace rerun::archetypes {
         /// the Viewer will guess that the data likely came from an 8bit image, thus assuming a range of 0-255.
         std::optional<ComponentBatch> depth_range;
 
+        /// Depth values outside of this range, in meters, are left out of the point cloud generated from this image.
+        ///
+        /// Use this to hide invalid measurements close to the sensor or far away from it, e.g. `[0.3, 10.0]`.
+        /// If not specified, all points with a positive depth are shown.
+        ///
+        /// This has no effect on 2D views.
+        std::optional<ComponentBatch> clip_range;
+
         /// Scale the radii of the points in the point cloud generated from this image.
         ///
         /// A fill ratio of 1.0 (the default) means that each point is as big as to touch the center of its neighbor
@@ -151,6 +160,11 @@ namespace rerun::archetypes {
             ArchetypeName, "DepthImage:depth_range",
             Loggable<rerun::components::ValueRange>::ComponentType
         );
+        /// `ComponentDescriptor` for the `clip_range` field.
+        static constexpr auto Descriptor_clip_range = ComponentDescriptor(
+            ArchetypeName, "DepthImage:clip_range",
+            Loggable<rerun::components::Range1D>::ComponentType
+        );
         /// `ComponentDescriptor` for the `point_fill_ratio` field.
         static constexpr auto Descriptor_point_fill_ratio = ComponentDescriptor(
             ArchetypeName, "DepthImage:point_fill_ratio",
@@ -337,6 +351,29 @@ namespace rerun::archetypes {
             return std::move(*this);
         }
 
+        /// Depth values outside of this range, in meters, are left out of the point cloud generated from this image.
+        ///
+        /// Use this to hide invalid measurements close to the sensor or far away from it, e.g. `[0.3, 10.0]`.
+        /// If not specified, all points with a positive depth are shown.
+        ///
+        /// This has no effect on 2D views.
+        DepthImage with_clip_range(const rerun::components::Range1D& _clip_range) && {
+            clip_range = ComponentBatch::from_loggable(_clip_range, Descriptor_clip_range)
+                             .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `clip_range` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_clip_range` should
+        /// be used when logging a single row's worth of data.
+        DepthImage with_many_clip_range(const Collection<rerun::components::Range1D>& _clip_range
+        ) && {
+            clip_range = ComponentBatch::from_loggable(_clip_range, Descriptor_clip_range)
+                             .value_or_throw();
+            return std::move(*this);
+        }
+
         /// Scale the radii of the points in the point cloud generated from this image.
         ///
         /// A fill ratio of 1.0 (the default) means that each point is as big as to touch the center of its neighbor
@@ -0,0 +1,60 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/entity_relations.fbs".
+
+#include "entity_relations.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {
+    EntityRelations EntityRelations::clear_fields() {
+        auto archetype = EntityRelations();
+        archetype.targets =
+            ComponentBatch::empty<rerun::components::EntityPath>(Descriptor_targets)
+                .value_or_throw();
+        archetype.kinds =
+            ComponentBatch::empty<rerun::components::Text>(Descriptor_kinds).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> EntityRelations::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(2);
+        if (targets.has_value()) {
+            columns.push_back(targets.value().partitioned(lengths_).value_or_throw());
+        }
+        if (kinds.has_value()) {
+            columns.push_back(kinds.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> EntityRelations::columns() {
+        if (targets.has_value()) {
+            return columns(std::vector<uint32_t>(targets.value().length(), 1));
+        }
+        if (kinds.has_value()) {
+            return columns(std::vector<uint32_t>(kinds.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<archetypes::EntityRelations>::as_batches(
+        const archetypes::EntityRelations& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(2);
+
+        if (archetype.targets.has_value()) {
+            cells.push_back(archetype.targets.value());
+        }
+        if (archetype.kinds.has_value()) {
+            cells.push_back(archetype.kinds.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,119 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/entity_relations.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/entity_path.hpp"
+#include "../components/text.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: Relations between an entity and other entities, beyond the entity hierarchy.
+    ///
+    /// This makes it possible to express that a detection came from a given camera, or that a track
+    /// is the same object as another one across frames.
+    /// Relations are logged on the source entity, and each target is the full path of a related entity.
+    /// Like any other data, relations can change over time.
+    ///
+    /// The viewer lists the related entities of the selected entity, in both directions, so that one can
+    /// navigate between them.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct EntityRelations {
+        /// The related entities.
+        std::optional<ComponentBatch> targets;
+
+        /// The kind of each relation, e.g. `source` or `same_track_as`.
+        ///
+        /// If there are fewer kinds than targets, the remaining relations have no kind.
+        std::optional<ComponentBatch> kinds;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.EntityRelations";
+
+        /// `ComponentDescriptor` for the `targets` field.
+        static constexpr auto Descriptor_targets = ComponentDescriptor(
+            ArchetypeName, "EntityRelations:targets",
+            Loggable<rerun::components::EntityPath>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `kinds` field.
+        static constexpr auto Descriptor_kinds = ComponentDescriptor(
+            ArchetypeName, "EntityRelations:kinds", Loggable<rerun::components::Text>::ComponentType
+        );
+
+      public:
+        EntityRelations() = default;
+        EntityRelations(EntityRelations&& other) = default;
+        EntityRelations(const EntityRelations& other) = default;
+        EntityRelations& operator=(const EntityRelations& other) = default;
+        EntityRelations& operator=(EntityRelations&& other) = default;
+
+        explicit EntityRelations(Collection<rerun::components::EntityPath> _targets)
+            : targets(ComponentBatch::from_loggable(std::move(_targets), Descriptor_targets)
+                          .value_or_throw()) {}
+
+        /// Update only some specific fields of a `EntityRelations`.
+        static EntityRelations update_fields() {
+            return EntityRelations();
+        }
+
+        /// Clear all the fields of a `EntityRelations`.
+        static EntityRelations clear_fields();
+
+        /// The related entities.
+        EntityRelations with_targets(const Collection<rerun::components::EntityPath>& _targets) && {
+            targets = ComponentBatch::from_loggable(_targets, Descriptor_targets).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The kind of each relation, e.g. `source` or `same_track_as`.
+        ///
+        /// If there are fewer kinds than targets, the remaining relations have no kind.
+        EntityRelations with_kinds(const Collection<rerun::components::Text>& _kinds) && {
+            kinds = ComponentBatch::from_loggable(_kinds, Descriptor_kinds).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::EntityRelations> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const archetypes::EntityRelations& archetype
+        );
+    };
+} // namespace rerun
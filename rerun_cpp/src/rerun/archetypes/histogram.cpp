@@ -0,0 +1,70 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/histogram.fbs".
+
+#include "histogram.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {
+    Histogram Histogram::clear_fields() {
+        auto archetype = Histogram();
+        archetype.counts =
+            ComponentBatch::empty<rerun::components::Scalar>(Descriptor_counts).value_or_throw();
+        archetype.bin_edges =
+            ComponentBatch::empty<rerun::components::Scalar>(Descriptor_bin_edges).value_or_throw();
+        archetype.color =
+            ComponentBatch::empty<rerun::components::Color>(Descriptor_color).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> Histogram::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(3);
+        if (counts.has_value()) {
+            columns.push_back(counts.value().partitioned(lengths_).value_or_throw());
+        }
+        if (bin_edges.has_value()) {
+            columns.push_back(bin_edges.value().partitioned(lengths_).value_or_throw());
+        }
+        if (color.has_value()) {
+            columns.push_back(color.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> Histogram::columns() {
+        if (counts.has_value()) {
+            return columns(std::vector<uint32_t>(counts.value().length(), 1));
+        }
+        if (bin_edges.has_value()) {
+            return columns(std::vector<uint32_t>(bin_edges.value().length(), 1));
+        }
+        if (color.has_value()) {
+            return columns(std::vector<uint32_t>(color.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<archetypes::Histogram>::as_batches(
+        const archetypes::Histogram& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(3);
+
+        if (archetype.counts.has_value()) {
+            cells.push_back(archetype.counts.value());
+        }
+        if (archetype.bin_edges.has_value()) {
+            cells.push_back(archetype.bin_edges.value());
+        }
+        if (archetype.color.has_value()) {
+            cells.push_back(archetype.color.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
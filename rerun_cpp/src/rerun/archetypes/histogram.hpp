@@ -0,0 +1,140 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/histogram.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/color.hpp"
+#include "../components/scalar.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: A histogram, i.e. counts over a set of contiguous bins.
+    ///
+    /// Each logged histogram replaces the previous one, making it possible to step through
+    /// per-frame distributions (e.g. latencies, pixel intensities) on the timeline.
+    ///
+    /// If no bin edges are provided, bin `i` spans the range `[i, i + 1)`.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct Histogram {
+        /// The count (or weight) of each bin.
+        std::optional<ComponentBatch> counts;
+
+        /// The edges of the bins, in ascending order.
+        ///
+        /// Must contain exactly one more value than `counts`: bin `i` spans `[bin_edges[i], bin_edges[i + 1])`.
+        /// Defaults to unit-width bins starting at zero.
+        std::optional<ComponentBatch> bin_edges;
+
+        /// The color of the bars.
+        std::optional<ComponentBatch> color;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.Histogram";
+
+        /// `ComponentDescriptor` for the `counts` field.
+        static constexpr auto Descriptor_counts = ComponentDescriptor(
+            ArchetypeName, "Histogram:counts", Loggable<rerun::components::Scalar>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `bin_edges` field.
+        static constexpr auto Descriptor_bin_edges = ComponentDescriptor(
+            ArchetypeName, "Histogram:bin_edges", Loggable<rerun::components::Scalar>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `color` field.
+        static constexpr auto Descriptor_color = ComponentDescriptor(
+            ArchetypeName, "Histogram:color", Loggable<rerun::components::Color>::ComponentType
+        );
+
+      public:
+        Histogram() = default;
+        Histogram(Histogram&& other) = default;
+        Histogram(const Histogram& other) = default;
+        Histogram& operator=(const Histogram& other) = default;
+        Histogram& operator=(Histogram&& other) = default;
+
+        explicit Histogram(Collection<rerun::components::Scalar> _counts)
+            : counts(ComponentBatch::from_loggable(std::move(_counts), Descriptor_counts)
+                         .value_or_throw()) {}
+
+        /// Update only some specific fields of a `Histogram`.
+        static Histogram update_fields() {
+            return Histogram();
+        }
+
+        /// Clear all the fields of a `Histogram`.
+        static Histogram clear_fields();
+
+        /// The count (or weight) of each bin.
+        Histogram with_counts(const Collection<rerun::components::Scalar>& _counts) && {
+            counts = ComponentBatch::from_loggable(_counts, Descriptor_counts).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The edges of the bins, in ascending order.
+        ///
+        /// Must contain exactly one more value than `counts`: bin `i` spans `[bin_edges[i], bin_edges[i + 1])`.
+        /// Defaults to unit-width bins starting at zero.
+        Histogram with_bin_edges(const Collection<rerun::components::Scalar>& _bin_edges) && {
+            bin_edges = ComponentBatch::from_loggable(_bin_edges, Descriptor_bin_edges)
+                            .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The color of the bars.
+        Histogram with_color(const rerun::components::Color& _color) && {
+            color = ComponentBatch::from_loggable(_color, Descriptor_color).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `color` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_color` should
+        /// be used when logging a single row's worth of data.
+        Histogram with_many_color(const Collection<rerun::components::Color>& _color) && {
+            color = ComponentBatch::from_loggable(_color, Descriptor_color).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::Histogram> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const archetypes::Histogram& archetype
+        );
+    };
+} // namespace rerun
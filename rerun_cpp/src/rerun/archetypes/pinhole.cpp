@@ -23,12 +23,20 @@ namespace rerun::archetypes {
                 Descriptor_image_plane_distance
             )
                 .value_or_throw();
+        archetype.distortion_model =
+            ComponentBatch::empty<rerun::components::DistortionModel>(Descriptor_distortion_model)
+                .value_or_throw();
+        archetype.distortion_coefficients =
+            ComponentBatch::empty<rerun::components::DistortionCoefficient>(
+                Descriptor_distortion_coefficients
+            )
+                .value_or_throw();
         return archetype;
     }
 
     Collection<ComponentColumn> Pinhole::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(4);
+        columns.reserve(6);
         if (image_from_camera.has_value()) {
             columns.push_back(image_from_camera.value().partitioned(lengths_).value_or_throw());
         }
@@ -41,6 +49,14 @@ namespace rerun::archetypes {
         if (image_plane_distance.has_value()) {
             columns.push_back(image_plane_distance.value().partitioned(lengths_).value_or_throw());
         }
+        if (distortion_model.has_value()) {
+            columns.push_back(distortion_model.value().partitioned(lengths_).value_or_throw());
+        }
+        if (distortion_coefficients.has_value()) {
+            columns.push_back(
+                distortion_coefficients.value().partitioned(lengths_).value_or_throw()
+            );
+        }
         return columns;
     }
 
@@ -57,6 +73,12 @@ namespace rerun::archetypes {
         if (image_plane_distance.has_value()) {
             return columns(std::vector<uint32_t>(image_plane_distance.value().length(), 1));
         }
+        if (distortion_model.has_value()) {
+            return columns(std::vector<uint32_t>(distortion_model.value().length(), 1));
+        }
+        if (distortion_coefficients.has_value()) {
+            return columns(std::vector<uint32_t>(distortion_coefficients.value().length(), 1));
+        }
         return Collection<ComponentColumn>();
     }
 } // namespace rerun::archetypes
@@ -68,7 +90,7 @@ namespace rerun {
     ) {
         using namespace archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(4);
+        cells.reserve(6);
 
         if (archetype.image_from_camera.has_value()) {
             cells.push_back(archetype.image_from_camera.value());
@@ -82,6 +104,12 @@ namespace rerun {
         if (archetype.image_plane_distance.has_value()) {
             cells.push_back(archetype.image_plane_distance.value());
         }
+        if (archetype.distortion_model.has_value()) {
+            cells.push_back(archetype.distortion_model.value());
+        }
+        if (archetype.distortion_coefficients.has_value()) {
+            cells.push_back(archetype.distortion_coefficients.value());
+        }
 
         return rerun::take_ownership(std::move(cells));
     }
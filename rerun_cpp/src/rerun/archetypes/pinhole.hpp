@@ -6,6 +6,8 @@
 #include "../collection.hpp"
 #include "../component_batch.hpp"
 #include "../component_column.hpp"
+#include "../components/distortion_coefficient.hpp"
+#include "../components/distortion_model.hpp"
 #include "../components/image_plane_distance.hpp"
 #include "../components/pinhole_projection.hpp"
 #include "../components/resolution.hpp"
@@ -126,6 +128,17 @@ namespace rerun::archetypes {
         /// This is only used for visualization purposes, and does not affect the projection itself.
         std::optional<ComponentBatch> image_plane_distance;
 
+        /// The lens distortion model that the `distortion_coefficients` are given for.
+        ///
+        /// Defaults to the Brown-Conrady (`plumb_bob`) model used by OpenCV.
+        std::optional<ComponentBatch> distortion_model;
+
+        /// Lens distortion coefficients, in the order defined by the `distortion_model`.
+        ///
+        /// Points are distorted on the normalized image plane, i.e. after dividing by depth and before applying
+        /// `image_from_camera`. If no coefficients are logged, the camera is an ideal pinhole camera.
+        std::optional<ComponentBatch> distortion_coefficients;
+
       public:
         /// The name of the archetype as used in `ComponentDescriptor`s.
         static constexpr const char ArchetypeName[] = "rerun.archetypes.Pinhole";
@@ -150,6 +163,16 @@ namespace rerun::archetypes {
             ArchetypeName, "Pinhole:image_plane_distance",
             Loggable<rerun::components::ImagePlaneDistance>::ComponentType
         );
+        /// `ComponentDescriptor` for the `distortion_model` field.
+        static constexpr auto Descriptor_distortion_model = ComponentDescriptor(
+            ArchetypeName, "Pinhole:distortion_model",
+            Loggable<rerun::components::DistortionModel>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `distortion_coefficients` field.
+        static constexpr auto Descriptor_distortion_coefficients = ComponentDescriptor(
+            ArchetypeName, "Pinhole:distortion_coefficients",
+            Loggable<rerun::components::DistortionCoefficient>::ComponentType
+        );
 
       public: // START of extensions from pinhole_ext.cpp:
         /// Creates a pinhole from the camera focal length and resolution, both specified in pixels.
@@ -347,6 +370,50 @@ namespace rerun::archetypes {
             return std::move(*this);
         }
 
+        /// The lens distortion model that the `distortion_coefficients` are given for.
+        ///
+        /// Defaults to the Brown-Conrady (`plumb_bob`) model used by OpenCV.
+        Pinhole with_distortion_model(
+            const rerun::components::DistortionModel& _distortion_model
+        ) && {
+            distortion_model = ComponentBatch::from_loggable(
+                               _distortion_model,
+                               Descriptor_distortion_model
+            )
+                               .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `distortion_model` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_distortion_model` should
+        /// be used when logging a single row's worth of data.
+        Pinhole with_many_distortion_model(
+            const Collection<rerun::components::DistortionModel>& _distortion_model
+        ) && {
+            distortion_model = ComponentBatch::from_loggable(
+                               _distortion_model,
+                               Descriptor_distortion_model
+            )
+                               .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Lens distortion coefficients, in the order defined by the `distortion_model`.
+        ///
+        /// Points are distorted on the normalized image plane, i.e. after dividing by depth and before applying
+        /// `image_from_camera`. If no coefficients are logged, the camera is an ideal pinhole camera.
+        Pinhole with_distortion_coefficients(
+            const Collection<rerun::components::DistortionCoefficient>& _distortion_coefficients
+        ) && {
+            distortion_coefficients = ComponentBatch::from_loggable(
+                                      _distortion_coefficients,
+                                      Descriptor_distortion_coefficients
+            )
+                                      .value_or_throw();
+            return std::move(*this);
+        }
+
         /// Partitions the component data into multiple sub-batches.
         ///
         /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
@@ -7,6 +7,7 @@
 #include "../component_batch.hpp"
 #include "../component_column.hpp"
 #include "../components/scalar.hpp"
+#include "../components/unit.hpp"
 #include "../result.hpp"
 
 #include <cstdint>
@@ -81,6 +82,13 @@ namespace rerun::archetypes {
         /// The scalar values to log.
         std::optional<ComponentBatch> scalars;
 
+        /// The unit of each scalar value, e.g. `m/s` or `°C`.
+        ///
+        /// Shown on the plot axes and in tooltips.
+        /// If a single unit is logged, it applies to all scalars.
+        /// Expected to be unchanging over time.
+        std::optional<ComponentBatch> units;
+
       public:
         /// The name of the archetype as used in `ComponentDescriptor`s.
         static constexpr const char ArchetypeName[] = "rerun.archetypes.Scalars";
@@ -89,6 +97,10 @@ namespace rerun::archetypes {
         static constexpr auto Descriptor_scalars = ComponentDescriptor(
             ArchetypeName, "Scalars:scalars", Loggable<rerun::components::Scalar>::ComponentType
         );
+        /// `ComponentDescriptor` for the `units` field.
+        static constexpr auto Descriptor_units = ComponentDescriptor(
+            ArchetypeName, "Scalars:units", Loggable<rerun::components::Unit>::ComponentType
+        );
 
       public:
         Scalars() = default;
@@ -115,6 +127,16 @@ namespace rerun::archetypes {
             return std::move(*this);
         }
 
+        /// The unit of each scalar value, e.g. `m/s` or `°C`.
+        ///
+        /// Shown on the plot axes and in tooltips.
+        /// If a single unit is logged, it applies to all scalars.
+        /// Expected to be unchanging over time.
+        Scalars with_units(const Collection<rerun::components::Unit>& _units) && {
+            units = ComponentBatch::from_loggable(_units, Descriptor_units).value_or_throw();
+            return std::move(*this);
+        }
+
         /// Partitions the component data into multiple sub-batches.
         ///
         /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
@@ -18,6 +18,12 @@ namespace rerun::archetypes {
         archetype.quaternion =
             ComponentBatch::empty<rerun::components::RotationQuat>(Descriptor_quaternion)
                 .value_or_throw();
+        archetype.rotation_euler =
+            ComponentBatch::empty<rerun::components::RotationEuler>(Descriptor_rotation_euler)
+                .value_or_throw();
+        archetype.euler_convention =
+            ComponentBatch::empty<rerun::components::EulerConvention>(Descriptor_euler_convention)
+                .value_or_throw();
         archetype.scale =
             ComponentBatch::empty<rerun::components::Scale3D>(Descriptor_scale).value_or_throw();
         archetype.mat3x3 =
@@ -34,7 +40,7 @@ namespace rerun::archetypes {
 
     Collection<ComponentColumn> Transform3D::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(7);
+        columns.reserve(9);
         if (translation.has_value()) {
             columns.push_back(translation.value().partitioned(lengths_).value_or_throw());
         }
@@ -44,6 +50,12 @@ namespace rerun::archetypes {
         if (quaternion.has_value()) {
             columns.push_back(quaternion.value().partitioned(lengths_).value_or_throw());
         }
+        if (rotation_euler.has_value()) {
+            columns.push_back(rotation_euler.value().partitioned(lengths_).value_or_throw());
+        }
+        if (euler_convention.has_value()) {
+            columns.push_back(euler_convention.value().partitioned(lengths_).value_or_throw());
+        }
         if (scale.has_value()) {
             columns.push_back(scale.value().partitioned(lengths_).value_or_throw());
         }
@@ -69,6 +81,12 @@ namespace rerun::archetypes {
         if (quaternion.has_value()) {
             return columns(std::vector<uint32_t>(quaternion.value().length(), 1));
         }
+        if (rotation_euler.has_value()) {
+            return columns(std::vector<uint32_t>(rotation_euler.value().length(), 1));
+        }
+        if (euler_convention.has_value()) {
+            return columns(std::vector<uint32_t>(euler_convention.value().length(), 1));
+        }
         if (scale.has_value()) {
             return columns(std::vector<uint32_t>(scale.value().length(), 1));
         }
@@ -92,7 +110,7 @@ namespace rerun {
     ) {
         using namespace archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(7);
+        cells.reserve(9);
 
         if (archetype.translation.has_value()) {
             cells.push_back(archetype.translation.value());
@@ -103,6 +121,12 @@ namespace rerun {
         if (archetype.quaternion.has_value()) {
             cells.push_back(archetype.quaternion.value());
         }
+        if (archetype.rotation_euler.has_value()) {
+            cells.push_back(archetype.rotation_euler.value());
+        }
+        if (archetype.euler_convention.has_value()) {
+            cells.push_back(archetype.euler_convention.value());
+        }
         if (archetype.scale.has_value()) {
             cells.push_back(archetype.scale.value());
         }
@@ -8,7 +8,9 @@
 #include "../component_batch.hpp"
 #include "../component_column.hpp"
 #include "../components/axis_length.hpp"
+#include "../components/euler_convention.hpp"
 #include "../components/rotation_axis_angle.hpp"
+#include "../components/rotation_euler.hpp"
 #include "../components/rotation_quat.hpp"
 #include "../components/scale3d.hpp"
 #include "../components/transform_mat3x3.hpp"
@@ -309,6 +311,16 @@ namespace rerun::archetypes {
         /// Rotation via quaternion.
         std::optional<ComponentBatch> quaternion;
 
+        /// Rotation via Euler angles, in radians.
+        ///
+        /// The axes the angles are applied about are given by `components::EulerConvention`.
+        std::optional<ComponentBatch> rotation_euler;
+
+        /// The axis order used to interpret `components::RotationEuler`.
+        ///
+        /// Defaults to `Zyx`, i.e. yaw, pitch, roll.
+        std::optional<ComponentBatch> euler_convention;
+
         /// Scaling factor.
         std::optional<ComponentBatch> scale;
 
@@ -343,6 +355,16 @@ namespace rerun::archetypes {
             ArchetypeName, "Transform3D:quaternion",
             Loggable<rerun::components::RotationQuat>::ComponentType
         );
+        /// `ComponentDescriptor` for the `rotation_euler` field.
+        static constexpr auto Descriptor_rotation_euler = ComponentDescriptor(
+            ArchetypeName, "Transform3D:rotation_euler",
+            Loggable<rerun::components::RotationEuler>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `euler_convention` field.
+        static constexpr auto Descriptor_euler_convention = ComponentDescriptor(
+            ArchetypeName, "Transform3D:euler_convention",
+            Loggable<rerun::components::EulerConvention>::ComponentType
+        );
         /// `ComponentDescriptor` for the `scale` field.
         static constexpr auto Descriptor_scale = ComponentDescriptor(
             ArchetypeName, "Transform3D:scale", Loggable<rerun::components::Scale3D>::ComponentType
@@ -812,6 +834,51 @@ namespace rerun::archetypes {
             return std::move(*this);
         }
 
+        /// Rotation via Euler angles, in radians.
+        ///
+        /// The axes the angles are applied about are given by `components::EulerConvention`.
+        Transform3D with_rotation_euler(const rerun::components::RotationEuler& _rotation_euler
+        ) && {
+            rotation_euler = ComponentBatch::from_loggable(_rotation_euler, Descriptor_rotation_euler)
+                                 .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `rotation_euler` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_rotation_euler` should
+        /// be used when logging a single row's worth of data.
+        Transform3D with_many_rotation_euler(
+            const Collection<rerun::components::RotationEuler>& _rotation_euler
+        ) && {
+            rotation_euler = ComponentBatch::from_loggable(_rotation_euler, Descriptor_rotation_euler)
+                                 .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The axis order used to interpret `components::RotationEuler`.
+        ///
+        /// Defaults to `Zyx`, i.e. yaw, pitch, roll.
+        Transform3D with_euler_convention(
+            const rerun::components::EulerConvention& _euler_convention
+        ) && {
+            euler_convention = ComponentBatch::from_loggable(_euler_convention, Descriptor_euler_convention)
+                                   .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `euler_convention` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_euler_convention` should
+        /// be used when logging a single row's worth of data.
+        Transform3D with_many_euler_convention(
+            const Collection<rerun::components::EulerConvention>& _euler_convention
+        ) && {
+            euler_convention = ComponentBatch::from_loggable(_euler_convention, Descriptor_euler_convention)
+                                   .value_or_throw();
+            return std::move(*this);
+        }
+
         /// Scaling factor.
         Transform3D with_scale(const rerun::components::Scale3D& _scale) && {
             scale = ComponentBatch::from_loggable(_scale, Descriptor_scale).value_or_throw();
@@ -0,0 +1,72 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/waveform.fbs".
+
+#include "waveform.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {
+    Waveform Waveform::clear_fields() {
+        auto archetype = Waveform();
+        archetype.samples =
+            ComponentBatch::empty<rerun::components::Scalar>(Descriptor_samples).value_or_throw();
+        archetype.sample_rate =
+            ComponentBatch::empty<rerun::components::SampleRate>(Descriptor_sample_rate)
+                .value_or_throw();
+        archetype.channel_count =
+            ComponentBatch::empty<rerun::components::ChannelCount>(Descriptor_channel_count)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> Waveform::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(3);
+        if (samples.has_value()) {
+            columns.push_back(samples.value().partitioned(lengths_).value_or_throw());
+        }
+        if (sample_rate.has_value()) {
+            columns.push_back(sample_rate.value().partitioned(lengths_).value_or_throw());
+        }
+        if (channel_count.has_value()) {
+            columns.push_back(channel_count.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> Waveform::columns() {
+        if (samples.has_value()) {
+            return columns(std::vector<uint32_t>(samples.value().length(), 1));
+        }
+        if (sample_rate.has_value()) {
+            return columns(std::vector<uint32_t>(sample_rate.value().length(), 1));
+        }
+        if (channel_count.has_value()) {
+            return columns(std::vector<uint32_t>(channel_count.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<archetypes::Waveform>::as_batches(
+        const archetypes::Waveform& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(3);
+
+        if (archetype.samples.has_value()) {
+            cells.push_back(archetype.samples.value());
+        }
+        if (archetype.sample_rate.has_value()) {
+            cells.push_back(archetype.sample_rate.value());
+        }
+        if (archetype.channel_count.has_value()) {
+            cells.push_back(archetype.channel_count.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,176 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/waveform.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/channel_count.hpp"
+#include "../components/sample_rate.hpp"
+#include "../components/scalar.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: A block of evenly spaced samples for one or more channels, e.g. for high-rate IMU, audio or EMG data.
+    ///
+    /// Instead of logging one `archetypes::Scalars` row per sample, a whole block of samples is logged at once:
+    /// the current timeline value is the time of the first sample in the block, and all following samples
+    /// are spaced according to the sample rate.
+    /// This drastically reduces the number of rows for kHz-rate signals.
+    ///
+    /// For multi-channel data, samples are interleaved, i.e. the first sample of every channel comes first,
+    /// then the second sample of every channel, etc.
+    /// The number of channels is expected to be the same over time.
+    ///
+    /// Like for `archetypes::Scalars`, the style of each channel can be configured by logging `archetypes::SeriesLines`
+    /// to the same entity path.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct Waveform {
+        /// The interleaved samples of all channels in this block.
+        std::optional<ComponentBatch> samples;
+
+        /// The rate at which the samples were acquired.
+        ///
+        /// In Hz for temporal timelines, and per tick for sequence timelines.
+        /// Expected to be unchanging over time and can therefore be logged statically.
+        std::optional<ComponentBatch> sample_rate;
+
+        /// The number of channels the samples are interleaved across.
+        ///
+        /// Defaults to a single channel.
+        /// Expected to be unchanging over time and can therefore be logged statically.
+        std::optional<ComponentBatch> channel_count;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.Waveform";
+
+        /// `ComponentDescriptor` for the `samples` field.
+        static constexpr auto Descriptor_samples = ComponentDescriptor(
+            ArchetypeName, "Waveform:samples", Loggable<rerun::components::Scalar>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `sample_rate` field.
+        static constexpr auto Descriptor_sample_rate = ComponentDescriptor(
+            ArchetypeName, "Waveform:sample_rate",
+            Loggable<rerun::components::SampleRate>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `channel_count` field.
+        static constexpr auto Descriptor_channel_count = ComponentDescriptor(
+            ArchetypeName, "Waveform:channel_count",
+            Loggable<rerun::components::ChannelCount>::ComponentType
+        );
+
+      public:
+        Waveform() = default;
+        Waveform(Waveform&& other) = default;
+        Waveform(const Waveform& other) = default;
+        Waveform& operator=(const Waveform& other) = default;
+        Waveform& operator=(Waveform&& other) = default;
+
+        explicit Waveform(
+            Collection<rerun::components::Scalar> _samples,
+            rerun::components::SampleRate _sample_rate
+        )
+            : samples(ComponentBatch::from_loggable(std::move(_samples), Descriptor_samples)
+                          .value_or_throw()),
+              sample_rate(
+                  ComponentBatch::from_loggable(std::move(_sample_rate), Descriptor_sample_rate)
+                      .value_or_throw()
+              ) {}
+
+        /// Update only some specific fields of a `Waveform`.
+        static Waveform update_fields() {
+            return Waveform();
+        }
+
+        /// Clear all the fields of a `Waveform`.
+        static Waveform clear_fields();
+
+        /// The interleaved samples of all channels in this block.
+        Waveform with_samples(const Collection<rerun::components::Scalar>& _samples) && {
+            samples = ComponentBatch::from_loggable(_samples, Descriptor_samples).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The rate at which the samples were acquired.
+        ///
+        /// In Hz for temporal timelines, and per tick for sequence timelines.
+        /// Expected to be unchanging over time and can therefore be logged statically.
+        Waveform with_sample_rate(const rerun::components::SampleRate& _sample_rate) && {
+            sample_rate = ComponentBatch::from_loggable(_sample_rate, Descriptor_sample_rate)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `sample_rate` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_sample_rate` should
+        /// be used when logging a single row's worth of data.
+        Waveform with_many_sample_rate(const Collection<rerun::components::SampleRate>& _sample_rate
+        ) && {
+            sample_rate = ComponentBatch::from_loggable(_sample_rate, Descriptor_sample_rate)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The number of channels the samples are interleaved across.
+        ///
+        /// Defaults to a single channel.
+        /// Expected to be unchanging over time and can therefore be logged statically.
+        Waveform with_channel_count(const rerun::components::ChannelCount& _channel_count) && {
+            channel_count = ComponentBatch::from_loggable(_channel_count, Descriptor_channel_count)
+                                .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `channel_count` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_channel_count` should
+        /// be used when logging a single row's worth of data.
+        Waveform with_many_channel_count(
+            const Collection<rerun::components::ChannelCount>& _channel_count
+        ) && {
+            channel_count = ComponentBatch::from_loggable(_channel_count, Descriptor_channel_count)
+                                .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::Waveform> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(const archetypes::Waveform& archetype);
+    };
+} // namespace rerun
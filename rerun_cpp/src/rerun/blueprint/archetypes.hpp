@@ -6,6 +6,7 @@
 #include "blueprint/archetypes/container_blueprint.hpp"
 #include "blueprint/archetypes/dataframe_query.hpp"
 #include "blueprint/archetypes/entity_behavior.hpp"
+#include "blueprint/archetypes/entity_time_alignment.hpp"
 #include "blueprint/archetypes/eye_controls3d.hpp"
 #include "blueprint/archetypes/force_center.hpp"
 #include "blueprint/archetypes/force_collision_radius.hpp"
@@ -14,6 +15,7 @@
 #include "blueprint/archetypes/force_position.hpp"
 #include "blueprint/archetypes/line_grid3d.hpp"
 #include "blueprint/archetypes/map_background.hpp"
+#include "blueprint/archetypes/map_heatmap.hpp"
 #include "blueprint/archetypes/map_zoom.hpp"
 #include "blueprint/archetypes/near_clip_plane.hpp"
 #include "blueprint/archetypes/panel_blueprint.hpp"
@@ -0,0 +1,64 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/entity_time_alignment.fbs".
+
+#include "entity_time_alignment.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    EntityTimeAlignment EntityTimeAlignment::clear_fields() {
+        auto archetype = EntityTimeAlignment();
+        archetype.time_offset =
+            ComponentBatch::empty<rerun::blueprint::components::TimeOffset>(Descriptor_time_offset)
+                .value_or_throw();
+        archetype.playback_rate = ComponentBatch::empty<rerun::blueprint::components::PlaybackRate>(
+                                      Descriptor_playback_rate
+        )
+                                      .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> EntityTimeAlignment::columns(const Collection<uint32_t>& lengths_
+    ) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(2);
+        if (time_offset.has_value()) {
+            columns.push_back(time_offset.value().partitioned(lengths_).value_or_throw());
+        }
+        if (playback_rate.has_value()) {
+            columns.push_back(playback_rate.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> EntityTimeAlignment::columns() {
+        if (time_offset.has_value()) {
+            return columns(std::vector<uint32_t>(time_offset.value().length(), 1));
+        }
+        if (playback_rate.has_value()) {
+            return columns(std::vector<uint32_t>(playback_rate.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::EntityTimeAlignment>::as_batches(
+            const blueprint::archetypes::EntityTimeAlignment& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(2);
+
+        if (archetype.time_offset.has_value()) {
+            cells.push_back(archetype.time_offset.value());
+        }
+        if (archetype.playback_rate.has_value()) {
+            cells.push_back(archetype.playback_rate.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,135 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/entity_time_alignment.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/playback_rate.hpp"
+#include "../../blueprint/components/time_offset.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Aligns the data of an entity with the timeline, e.g. for sensors that were recorded with a skewed clock.
+    ///
+    /// An entity's data logged at time `t` is shown at time `t * playback_rate + time_offset` on the timeline.
+    ///
+    /// TODO(#6541): Fields of this archetype currently only have an effect when logged in the blueprint store.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct EntityTimeAlignment {
+        /// A constant offset added to the times of the entity's data.
+        ///
+        /// This property is propagated down the entity hierarchy until another child entity
+        /// sets `time_offset` to a different value at which point propagation continues with that value instead.
+        ///
+        /// Defaults to parent's `time_offset` value or 0 if there is no parent.
+        std::optional<ComponentBatch> time_offset;
+
+        /// How fast the clock of the entity's data runs relative to the timeline.
+        ///
+        /// This property is propagated down the entity hierarchy until another child entity
+        /// sets `playback_rate` to a different value at which point propagation continues with that value instead.
+        ///
+        /// Defaults to parent's `playback_rate` value or 1 if there is no parent.
+        std::optional<ComponentBatch> playback_rate;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] =
+            "rerun.blueprint.archetypes.EntityTimeAlignment";
+
+        /// `ComponentDescriptor` for the `time_offset` field.
+        static constexpr auto Descriptor_time_offset = ComponentDescriptor(
+            ArchetypeName, "EntityTimeAlignment:time_offset",
+            Loggable<rerun::blueprint::components::TimeOffset>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `playback_rate` field.
+        static constexpr auto Descriptor_playback_rate = ComponentDescriptor(
+            ArchetypeName, "EntityTimeAlignment:playback_rate",
+            Loggable<rerun::blueprint::components::PlaybackRate>::ComponentType
+        );
+
+      public:
+        EntityTimeAlignment() = default;
+        EntityTimeAlignment(EntityTimeAlignment&& other) = default;
+        EntityTimeAlignment(const EntityTimeAlignment& other) = default;
+        EntityTimeAlignment& operator=(const EntityTimeAlignment& other) = default;
+        EntityTimeAlignment& operator=(EntityTimeAlignment&& other) = default;
+
+        /// Update only some specific fields of a `EntityTimeAlignment`.
+        static EntityTimeAlignment update_fields() {
+            return EntityTimeAlignment();
+        }
+
+        /// Clear all the fields of a `EntityTimeAlignment`.
+        static EntityTimeAlignment clear_fields();
+
+        /// A constant offset added to the times of the entity's data.
+        ///
+        /// This property is propagated down the entity hierarchy until another child entity
+        /// sets `time_offset` to a different value at which point propagation continues with that value instead.
+        ///
+        /// Defaults to parent's `time_offset` value or 0 if there is no parent.
+        EntityTimeAlignment with_time_offset(
+            const rerun::blueprint::components::TimeOffset& _time_offset
+        ) && {
+            time_offset = ComponentBatch::from_loggable(_time_offset, Descriptor_time_offset)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// How fast the clock of the entity's data runs relative to the timeline.
+        ///
+        /// This property is propagated down the entity hierarchy until another child entity
+        /// sets `playback_rate` to a different value at which point propagation continues with that value instead.
+        ///
+        /// Defaults to parent's `playback_rate` value or 1 if there is no parent.
+        EntityTimeAlignment with_playback_rate(
+            const rerun::blueprint::components::PlaybackRate& _playback_rate
+        ) && {
+            playback_rate = ComponentBatch::from_loggable(_playback_rate, Descriptor_playback_rate)
+                                .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::EntityTimeAlignment> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::EntityTimeAlignment& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,70 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/map_heatmap.fbs".
+
+#include "map_heatmap.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    MapHeatmap MapHeatmap::clear_fields() {
+        auto archetype = MapHeatmap();
+        archetype.visible =
+            ComponentBatch::empty<rerun::components::Visible>(Descriptor_visible).value_or_throw();
+        archetype.colormap = ComponentBatch::empty<rerun::components::Colormap>(Descriptor_colormap)
+                                 .value_or_throw();
+        archetype.opacity =
+            ComponentBatch::empty<rerun::components::Opacity>(Descriptor_opacity).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> MapHeatmap::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(3);
+        if (visible.has_value()) {
+            columns.push_back(visible.value().partitioned(lengths_).value_or_throw());
+        }
+        if (colormap.has_value()) {
+            columns.push_back(colormap.value().partitioned(lengths_).value_or_throw());
+        }
+        if (opacity.has_value()) {
+            columns.push_back(opacity.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> MapHeatmap::columns() {
+        if (visible.has_value()) {
+            return columns(std::vector<uint32_t>(visible.value().length(), 1));
+        }
+        if (colormap.has_value()) {
+            return columns(std::vector<uint32_t>(colormap.value().length(), 1));
+        }
+        if (opacity.has_value()) {
+            return columns(std::vector<uint32_t>(opacity.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<blueprint::archetypes::MapHeatmap>::as_batches(
+        const blueprint::archetypes::MapHeatmap& archetype
+    ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(3);
+
+        if (archetype.visible.has_value()) {
+            cells.push_back(archetype.visible.value());
+        }
+        if (archetype.colormap.has_value()) {
+            cells.push_back(archetype.colormap.value());
+        }
+        if (archetype.opacity.has_value()) {
+            cells.push_back(archetype.opacity.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
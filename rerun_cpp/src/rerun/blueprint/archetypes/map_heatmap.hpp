@@ -0,0 +1,135 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/map_heatmap.fbs".
+
+#pragma once
+
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/colormap.hpp"
+#include "../../components/opacity.hpp"
+#include "../../components/visible.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configuration of the density heatmap layer of the map view.
+    ///
+    /// The heatmap shows where geo points are concentrated,
+    /// which is easier to read than the individual points when there are many of them.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct MapHeatmap {
+        /// Whether the heatmap is visible.
+        ///
+        /// Defaults to false.
+        std::optional<ComponentBatch> visible;
+
+        /// Colormap used to map the density of points to colors.
+        ///
+        /// Defaults to inferno.
+        std::optional<ComponentBatch> colormap;
+
+        /// Opacity of the heatmap on top of the background map.
+        ///
+        /// Defaults to 0.6.
+        std::optional<ComponentBatch> opacity;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.MapHeatmap";
+
+        /// `ComponentDescriptor` for the `visible` field.
+        static constexpr auto Descriptor_visible = ComponentDescriptor(
+            ArchetypeName, "MapHeatmap:visible",
+            Loggable<rerun::components::Visible>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `colormap` field.
+        static constexpr auto Descriptor_colormap = ComponentDescriptor(
+            ArchetypeName, "MapHeatmap:colormap",
+            Loggable<rerun::components::Colormap>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `opacity` field.
+        static constexpr auto Descriptor_opacity = ComponentDescriptor(
+            ArchetypeName, "MapHeatmap:opacity",
+            Loggable<rerun::components::Opacity>::ComponentType
+        );
+
+      public:
+        MapHeatmap() = default;
+        MapHeatmap(MapHeatmap&& other) = default;
+        MapHeatmap(const MapHeatmap& other) = default;
+        MapHeatmap& operator=(const MapHeatmap& other) = default;
+        MapHeatmap& operator=(MapHeatmap&& other) = default;
+
+        /// Update only some specific fields of a `MapHeatmap`.
+        static MapHeatmap update_fields() {
+            return MapHeatmap();
+        }
+
+        /// Clear all the fields of a `MapHeatmap`.
+        static MapHeatmap clear_fields();
+
+        /// Whether the heatmap is visible.
+        ///
+        /// Defaults to false.
+        MapHeatmap with_visible(const rerun::components::Visible& _visible) && {
+            visible = ComponentBatch::from_loggable(_visible, Descriptor_visible).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Colormap used to map the density of points to colors.
+        ///
+        /// Defaults to inferno.
+        MapHeatmap with_colormap(const rerun::components::Colormap& _colormap) && {
+            colormap =
+                ComponentBatch::from_loggable(_colormap, Descriptor_colormap).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Opacity of the heatmap on top of the background map.
+        ///
+        /// Defaults to 0.6.
+        MapHeatmap with_opacity(const rerun::components::Opacity& _opacity) && {
+            opacity = ComponentBatch::from_loggable(_opacity, Descriptor_opacity).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::MapHeatmap> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::MapHeatmap& archetype
+        );
+    };
+} // namespace rerun
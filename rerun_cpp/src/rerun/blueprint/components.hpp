@@ -26,11 +26,13 @@
 #include "blueprint/components/map_provider.hpp"
 #include "blueprint/components/near_clip_plane.hpp"
 #include "blueprint/components/panel_state.hpp"
+#include "blueprint/components/playback_rate.hpp"
 #include "blueprint/components/query_expression.hpp"
 #include "blueprint/components/root_container.hpp"
 #include "blueprint/components/row_share.hpp"
 #include "blueprint/components/selected_columns.hpp"
 #include "blueprint/components/tensor_dimension_index_slider.hpp"
+#include "blueprint/components/time_offset.hpp"
 #include "blueprint/components/timeline_name.hpp"
 #include "blueprint/components/view_class.hpp"
 #include "blueprint/components/view_fit.hpp"
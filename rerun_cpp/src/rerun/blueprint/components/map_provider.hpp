@@ -34,6 +34,11 @@ namespace rerun::blueprint::components {
 
         /// Mapbox Satellite is a satellite map designed by Mapbox.
         MapboxSatellite = 4,
+
+        /// Tiles from the URL template configured in the viewer settings.
+        ///
+        /// Use this for self-hosted tile servers, e.g. one serving offline MBTiles.
+        Custom = 5,
     };
 } // namespace rerun::blueprint::components
 
@@ -0,0 +1,78 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/playback_rate.fbs".
+
+#pragma once
+
+#include "../../datatypes/float64.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::blueprint::components {
+    /// **Component**: How fast the clock of an entity's data runs relative to the timeline.
+    ///
+    /// A rate of 1 means both clocks run at the same speed.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct PlaybackRate {
+        rerun::datatypes::Float64 rate;
+
+      public:
+        PlaybackRate() = default;
+
+        PlaybackRate(rerun::datatypes::Float64 rate_) : rate(rate_) {}
+
+        PlaybackRate& operator=(rerun::datatypes::Float64 rate_) {
+            rate = rate_;
+            return *this;
+        }
+
+        PlaybackRate(double value_) : rate(value_) {}
+
+        PlaybackRate& operator=(double value_) {
+            rate = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying Float64 datatype
+        operator rerun::datatypes::Float64() const {
+            return rate;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Float64) == sizeof(blueprint::components::PlaybackRate));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::PlaybackRate> {
+        static constexpr std::string_view ComponentType = "rerun.blueprint.components.PlaybackRate";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Float64>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::PlaybackRate` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::PlaybackRate* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Float64>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Float64>::to_arrow(
+                    &instances->rate,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
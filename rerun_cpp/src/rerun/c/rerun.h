@@ -394,6 +394,7 @@ enum {
     // Utility errors.
     _RR_ERROR_CODE_CATEGORY_UTILITIES = 0x00010000,
     RR_ERROR_CODE_VIDEO_LOAD_ERROR,
+    RR_ERROR_CODE_DATA_LOADER_ERROR,
 
     // Generic errors.
     RR_ERROR_CODE_UNKNOWN,
@@ -646,6 +647,39 @@ extern void rr_recording_stream_send_columns(
     rr_error* error
 );
 
+// ----------------------------------------------------------------------------
+// Data loaders
+
+/// Called by the data loaders for every chunk they load.
+///
+/// The chunk is passed as an arrow struct array, with its schema.
+/// Both are released after the callback returns, unless the callback moves them out,
+/// e.g. by importing them into arrow.
+typedef void (*rr_data_loader_chunk_callback)(
+    void* user_data, rr_string entity_path, struct ArrowArray* chunk, struct ArrowSchema* schema
+);
+
+/// Loads a file with the same data loaders that the Viewer and `log_file_from_path` use.
+///
+/// Blocks until the file was fully loaded, calling `on_chunk` on the calling thread for every chunk.
+///
+/// \param entity_path_prefix
+/// Optional prefix for the entity paths of all loaded chunks, may be null.
+extern void rr_data_loader_load_file(
+    rr_string filepath, rr_string entity_path_prefix, rr_data_loader_chunk_callback on_chunk,
+    void* user_data, rr_error* error
+);
+
+/// Loads the contents of a file with the same data loaders that the Viewer and
+/// `log_file_from_contents` use.
+///
+/// The file path is only used to pick a data loader and to name the entities.
+/// See `rr_data_loader_load_file` for details.
+extern void rr_data_loader_load_file_from_contents(
+    rr_string filepath, rr_bytes contents, rr_string entity_path_prefix,
+    rr_data_loader_chunk_callback on_chunk, void* user_data, rr_error* error
+);
+
 // ----------------------------------------------------------------------------
 // Other utilities
 
@@ -7,13 +7,17 @@
 #include "components/annotation_context.hpp"
 #include "components/axis_length.hpp"
 #include "components/blob.hpp"
+#include "components/channel_count.hpp"
 #include "components/class_id.hpp"
 #include "components/clear_is_recursive.hpp"
 #include "components/color.hpp"
 #include "components/colormap.hpp"
 #include "components/depth_meter.hpp"
+#include "components/distortion_coefficient.hpp"
+#include "components/distortion_model.hpp"
 #include "components/draw_order.hpp"
 #include "components/entity_path.hpp"
+#include "components/euler_convention.hpp"
 #include "components/fill_mode.hpp"
 #include "components/fill_ratio.hpp"
 #include "components/gamma_correction.hpp"
@@ -52,7 +56,9 @@
 #include "components/range1d.hpp"
 #include "components/resolution.hpp"
 #include "components/rotation_axis_angle.hpp"
+#include "components/rotation_euler.hpp"
 #include "components/rotation_quat.hpp"
+#include "components/sample_rate.hpp"
 #include "components/scalar.hpp"
 #include "components/scale3d.hpp"
 #include "components/series_visible.hpp"
@@ -70,6 +76,7 @@
 #include "components/transform_relation.hpp"
 #include "components/translation3d.hpp"
 #include "components/triangle_indices.hpp"
+#include "components/unit.hpp"
 #include "components/value_range.hpp"
 #include "components/vector2d.hpp"
 #include "components/vector3d.hpp"
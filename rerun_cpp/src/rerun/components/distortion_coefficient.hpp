@@ -0,0 +1,76 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/distortion_coefficient.fbs".
+
+#pragma once
+
+#include "../datatypes/float32.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::components {
+    /// **Component**: A single lens distortion coefficient of a camera.
+    ///
+    /// The meaning of each coefficient depends on its index and the `components::DistortionModel`.
+    ///
+    struct DistortionCoefficient {
+        rerun::datatypes::Float32 coefficient;
+
+      public:
+        DistortionCoefficient() = default;
+
+        DistortionCoefficient(rerun::datatypes::Float32 coefficient_) : coefficient(coefficient_) {}
+
+        DistortionCoefficient& operator=(rerun::datatypes::Float32 coefficient_) {
+            coefficient = coefficient_;
+            return *this;
+        }
+
+        DistortionCoefficient(float value_) : coefficient(value_) {}
+
+        DistortionCoefficient& operator=(float value_) {
+            coefficient = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying Float32 datatype
+        operator rerun::datatypes::Float32() const {
+            return coefficient;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Float32) == sizeof(components::DistortionCoefficient));
+
+    /// \private
+    template <>
+    struct Loggable<components::DistortionCoefficient> {
+        static constexpr std::string_view ComponentType = "rerun.components.DistortionCoefficient";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Float32>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::DistortionCoefficient` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::DistortionCoefficient* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Float32>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Float32>::to_arrow(
+                    &instances->coefficient,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
@@ -0,0 +1,67 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/distortion_model.fbs".
+
+#pragma once
+
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class UInt8Type;
+    using UInt8Builder = NumericBuilder<UInt8Type>;
+} // namespace arrow
+
+namespace rerun::components {
+    /// **Component**: The lens distortion model of a camera, which defines how `components::DistortionCoefficient`s are interpreted.
+    ///
+    /// All models operate on normalized image coordinates, i.e. camera space coordinates divided by their depth.
+    ///
+    enum class DistortionModel : uint8_t {
+
+        /// The Brown-Conrady model used by OpenCV, also known as `plumb_bob` in ROS.
+        ///
+        /// The coefficients are `[k1, k2, p1, p2, k3, k4, k5, k6]`, where `k` are radial and `p` tangential coefficients.
+        /// Any trailing coefficients may be omitted, in which case they are zero.
+        /// Specifying `k4`, `k5` and `k6` yields the `rational_polynomial` model of OpenCV.
+        PlumbBob = 1,
+
+        /// The equidistant fisheye model used by OpenCV's `fisheye` module, also known as `equidistant` in ROS.
+        ///
+        /// The coefficients are `[k1, k2, k3, k4]`.
+        /// Any trailing coefficients may be omitted, in which case they are zero.
+        Fisheye = 2,
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<components::DistortionModel> {
+        static constexpr std::string_view ComponentType = "rerun.components.DistortionModel";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::components::DistortionModel` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::DistortionModel* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::UInt8Builder* builder, const components::DistortionModel* elements,
+            size_t num_elements
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,75 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/euler_convention.fbs".
+
+#pragma once
+
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class UInt8Type;
+    using UInt8Builder = NumericBuilder<UInt8Type>;
+} // namespace arrow
+
+namespace rerun::components {
+    /// **Component**: The order of the axes that the angles of a `components::RotationEuler` are applied about.
+    ///
+    /// All conventions describe intrinsic rotations, i.e. each rotation is about an axis of the frame
+    /// resulting from the previous rotations.
+    ///
+    enum class EulerConvention : uint8_t {
+
+        /// Rotate about X, then about the new Y, then about the new Z.
+        Xyz = 1,
+
+        /// Rotate about X, then about the new Z, then about the new Y.
+        Xzy = 2,
+
+        /// Rotate about Y, then about the new X, then about the new Z.
+        Yxz = 3,
+
+        /// Rotate about Y, then about the new Z, then about the new X.
+        Yzx = 4,
+
+        /// Rotate about Z, then about the new X, then about the new Y.
+        Zxy = 5,
+
+        /// Rotate about Z, then about the new Y, then about the new X.
+        ///
+        /// This is the common yaw, pitch, roll convention used in aerospace and robotics.
+        Zyx = 6,
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<components::EulerConvention> {
+        static constexpr std::string_view ComponentType = "rerun.components.EulerConvention";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::components::EulerConvention` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::EulerConvention* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::UInt8Builder* builder, const components::EulerConvention* elements,
+            size_t num_elements
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,78 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/rotation_euler.fbs".
+
+#pragma once
+
+#include "../datatypes/vec3d.hpp"
+#include "../result.hpp"
+
+#include <array>
+#include <cstdint>
+#include <memory>
+
+namespace rerun::components {
+    /// **Component**: A 3D rotation expressed as three Euler angles, in radians.
+    ///
+    /// The angles are intrinsic rotations about the axes given by `components::EulerConvention`, in that order.
+    /// E.g. for the default `Zyx` convention, the angles are yaw (about Z), pitch (about the new Y) and roll (about the new X).
+    ///
+    struct RotationEuler {
+        rerun::datatypes::Vec3D angles;
+
+      public:
+        RotationEuler() = default;
+
+        RotationEuler(rerun::datatypes::Vec3D angles_) : angles(angles_) {}
+
+        RotationEuler& operator=(rerun::datatypes::Vec3D angles_) {
+            angles = angles_;
+            return *this;
+        }
+
+        RotationEuler(std::array<float, 3> xyz_) : angles(xyz_) {}
+
+        RotationEuler& operator=(std::array<float, 3> xyz_) {
+            angles = xyz_;
+            return *this;
+        }
+
+        /// Cast to the underlying Vec3D datatype
+        operator rerun::datatypes::Vec3D() const {
+            return angles;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Vec3D) == sizeof(components::RotationEuler));
+
+    /// \private
+    template <>
+    struct Loggable<components::RotationEuler> {
+        static constexpr std::string_view ComponentType = "rerun.components.RotationEuler";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Vec3D>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::RotationEuler` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::RotationEuler* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Vec3D>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Vec3D>::to_arrow(
+                    &instances->angles,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
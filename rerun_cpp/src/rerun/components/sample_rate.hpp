@@ -0,0 +1,75 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/sample_rate.fbs".
+
+#pragma once
+
+#include "../datatypes/float64.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::components {
+    /// **Component**: The rate at which evenly spaced samples were acquired, in samples per second (Hz).
+    ///
+    /// On sequence timelines, this is interpreted as samples per timeline tick instead.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct SampleRate {
+        rerun::datatypes::Float64 hz;
+
+      public:
+        SampleRate() = default;
+
+        SampleRate(rerun::datatypes::Float64 hz_) : hz(hz_) {}
+
+        SampleRate& operator=(rerun::datatypes::Float64 hz_) {
+            hz = hz_;
+            return *this;
+        }
+
+        SampleRate(double value_) : hz(value_) {}
+
+        SampleRate& operator=(double value_) {
+            hz = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying Float64 datatype
+        operator rerun::datatypes::Float64() const {
+            return hz;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Float64) == sizeof(components::SampleRate));
+
+    /// \private
+    template <>
+    struct Loggable<components::SampleRate> {
+        static constexpr std::string_view ComponentType = "rerun.components.SampleRate";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Float64>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::SampleRate` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::SampleRate* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Float64>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Float64>::to_arrow(&instances->hz, num_instances);
+            }
+        }
+    };
+} // namespace rerun
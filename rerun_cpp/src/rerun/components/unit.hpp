@@ -0,0 +1,84 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/unit.fbs".
+
+#pragma once
+
+#include "../datatypes/utf8.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+#include <string>
+#include <utility>
+
+namespace rerun::components {
+    /// **Component**: The unit of a quantity, e.g. `m/s` or `°C`.
+    ///
+    /// This is used purely for display purposes, no conversions between units are performed.
+    struct Unit {
+        rerun::datatypes::Utf8 value;
+
+      public: // START of extensions from unit_ext.cpp:
+        /// Construct `Unit` from a null-terminated UTF8 string.
+        Unit(const char* str) : value(str) {}
+
+        const char* c_str() const {
+            return value.c_str();
+        }
+
+        // END of extensions from unit_ext.cpp, start of generated code:
+
+      public:
+        Unit() = default;
+
+        Unit(rerun::datatypes::Utf8 value_) : value(std::move(value_)) {}
+
+        Unit& operator=(rerun::datatypes::Utf8 value_) {
+            value = std::move(value_);
+            return *this;
+        }
+
+        Unit(std::string value_) : value(std::move(value_)) {}
+
+        Unit& operator=(std::string value_) {
+            value = std::move(value_);
+            return *this;
+        }
+
+        /// Cast to the underlying Utf8 datatype
+        operator rerun::datatypes::Utf8() const {
+            return value;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Utf8) == sizeof(components::Unit));
+
+    /// \private
+    template <>
+    struct Loggable<components::Unit> {
+        static constexpr std::string_view ComponentType = "rerun.components.Unit";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Utf8>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::Unit` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::Unit* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Utf8>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Utf8>::to_arrow(&instances->value, num_instances);
+            }
+        }
+    };
+} // namespace rerun
@@ -64,6 +64,7 @@ namespace rerun {
         // Utility errors.
         _CategoryUtilities = 0x0001'0000,
         VideoLoadError,
+        DataLoaderError,
 
         // Errors relating to file IO.
         _CategoryFileIO = 0x0010'0000,
@@ -876,6 +876,61 @@ export class WebViewer {
     return this.#handle.get_timeline_time_range(recording_id, timeline);
   }
 
+  /**
+   * Get the paths of all selected entities of the active recording.
+   */
+  get_selected_entities(): string[] {
+    if (!this.#handle) {
+      throw new Error(
+        `attempted to get selected entities in a stopped web viewer`,
+      );
+    }
+
+    return this.#handle.get_selected_entities();
+  }
+
+  /**
+   * Select entities of the active recording, replacing the current selection.
+   *
+   * This is the same as clicking on the entities in the Viewer's blueprint panel.
+   * An empty list clears the selection.
+   *
+   * Use `viewer.on("selection_change", …)` to react to the user changing the selection.
+   */
+  set_selected_entities(entity_paths: string[]) {
+    if (!this.#handle) {
+      throw new Error(
+        `attempted to set selected entities in a stopped web viewer`,
+      );
+    }
+
+    this.#handle.set_selected_entities(entity_paths);
+  }
+
+  /**
+   * Show or hide an entity and its children.
+   *
+   * This is the same as clicking the eye icon next to the entity in the Viewer's blueprint panel.
+   * If `view_id` is given, this only affects that view, otherwise all views that include the entity.
+   * View ids are part of `selection_change` events.
+   *
+   * This does nothing if the recording or view can't be found.
+   */
+  set_entity_visible(
+    recording_id: string,
+    entity_path: string,
+    visible: boolean,
+    view_id?: string,
+  ) {
+    if (!this.#handle) {
+      throw new Error(
+        `attempted to ${visible ? "show" : "hide"} ${entity_path} in a stopped web viewer`,
+      );
+    }
+
+    this.#handle.set_entity_visible(recording_id, entity_path, visible, view_id);
+  }
+
   /**
    * Toggle fullscreen mode.
    *
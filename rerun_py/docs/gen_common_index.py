@@ -163,6 +163,8 @@ SECTION_TABLE: Final[list[Section]] = [
         class_list=[
             "archetypes.Clear",
             "blueprint.archetypes.EntityBehavior",
+            "blueprint.archetypes.EntityTimeAlignment",
+            "archetypes.EntityRelations",
             "archetypes.RecordingInfo",
         ],
         gen_page=False,
@@ -199,9 +201,11 @@ SECTION_TABLE: Final[list[Section]] = [
         title="Plotting",
         class_list=[
             "archetypes.BarChart",
+            "archetypes.Histogram",
             "archetypes.Scalars",
             "archetypes.SeriesLines",
             "archetypes.SeriesPoints",
+            "archetypes.Waveform",
         ],
         gen_page=False,
     ),
@@ -256,6 +260,7 @@ SECTION_TABLE: Final[list[Section]] = [
             "archetypes.Pinhole",
             "archetypes.Transform3D",
             "archetypes.InstancePoses3D",
+            "archetypes.CameraRig",
             "archetypes.ViewCoordinates",
             "components.Scale3D",
             "datatypes.Quaternion",
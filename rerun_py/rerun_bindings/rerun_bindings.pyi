@@ -364,6 +364,57 @@ class RecordingView:
 
         """
 
+    def filter_where(self, column: AnyComponentColumn, op: str, value: Any) -> RecordingView:
+        """
+        Filter the view to only include rows where the given component column satisfies a comparison.
+
+        A row matches if at least one of the component's instances satisfies the comparison.
+        The predicate is evaluated while iterating over the data, so filtered-out rows are never
+        materialized. Calling this multiple times only keeps the rows that match all predicates.
+
+        Parameters
+        ----------
+        column : AnyComponentColumn
+            The component column to filter by.
+        op : str
+            The comparison operator, one of `==`, `!=`, `<`, `<=`, `>`, `>=`.
+        value : Any
+            The value to compare against. It is converted to a pyarrow scalar, and then cast to
+            the datatype of the component.
+
+        Returns
+        -------
+        RecordingView
+            A new view containing only the data where the specified component column satisfies the
+            comparison.
+
+            The original view will not be modified.
+
+        """
+
+    def join_on_entity(self, entity_path: str) -> RecordingView:
+        """
+        Only include rows where the given entity has data, and align all other entities on them.
+
+        This is akin to an as-of join: the columns of all the other entities yield their most
+        recent value at or before each row, regardless of [`.fill_latest_at()`][rerun.dataframe.RecordingView.fill_latest_at].
+        This makes it possible to e.g. compare data logged at different rates without having to
+        merge it client-side.
+
+        Parameters
+        ----------
+        entity_path : str
+            The entity whose rows drive the view.
+
+        Returns
+        -------
+        RecordingView
+            A new view containing one row per index value at which the given entity has data.
+
+            The original view will not be modified.
+
+        """
+
     def using_index_values(self, values: IndexValuesLike) -> RecordingView:
         """
         Replace the index in the view with the provided values.
@@ -403,6 +454,33 @@ class RecordingView:
 
         """
 
+    def aggregate(self, bucket_size: int, op: str) -> RecordingView:
+        """
+        Reduce the rows of the view into fixed-size buckets of index values.
+
+        Buckets are aligned on multiples of `bucket_size`, and each non-empty bucket yields a
+        single row whose index value is the start of the bucket. Numeric component columns are
+        reduced instance-wise using `op`, all other columns yield their most recent value.
+
+        This bounds the number of rows returned for very large datasets, e.g. when plotting
+        long scalar series.
+
+        Parameters
+        ----------
+        bucket_size : int
+            The size of each bucket, in index units (e.g. nanoseconds or sequence numbers).
+        op : str
+            The aggregation operator, one of `min`, `max`, `mean`, `last`.
+
+        Returns
+        -------
+        RecordingView
+            A new view with one row per bucket.
+
+            The original view will not be modified.
+
+        """
+
     def select(self, *args: AnyColumn, columns: Optional[Sequence[AnyColumn]] = None) -> pa.RecordBatchReader:
         """
         Select the columns from the view.
@@ -1357,6 +1435,80 @@ class DatasetEntry(Entry):
 
         """
 
+    def update_partition_tags(
+        self,
+        partition_id: str,
+        *,
+        add: dict[str, str | None] | None = None,
+        remove: list[str] | None = None,
+    ) -> dict[str, str | None]:
+        """
+        Add tags to, and remove tags from, a partition.
+
+        Tags are either plain labels (with a `None` value), or properties with a value, e.g.
+        `{"robot_id": "r2d2", "mission": "survey-42", "reviewed": None}`. They show up in the
+        partition table, and can be searched for with [`DatasetEntry.find_partitions`][].
+
+        Parameters
+        ----------
+        partition_id: str
+            The ID of the partition to tag.
+
+        add: dict[str, str | None] | None
+            The tags to add. Adding a tag the partition already has replaces its value.
+
+        remove: list[str] | None
+            The names of the tags to remove.
+
+        Returns
+        -------
+        dict[str, str | None]
+            All the tags of the partition, once updated.
+
+        """
+
+    def find_partitions(
+        self,
+        *,
+        tags: dict[str, str | None] | None = None,
+        timeline: str | None = None,
+        start: datetime | int | None = None,
+        end: datetime | int | None = None,
+    ) -> list[str]:
+        """
+        Return the IDs of the partitions matching all the given criteria.
+
+        Parameters
+        ----------
+        tags: dict[str, str | None] | None
+            The tags the partitions must have. A `None` value matches any value of the tag.
+
+        timeline: str | None
+            The timeline on which the partitions must have data between `start` and `end`.
+
+        start: int | datetime | None
+            The start of the time range, inclusive.
+            Integer for ticks, or datetime/nanoseconds for timestamps.
+
+        end: int | datetime | None
+            The end of the time range, inclusive.
+            Integer for ticks, or datetime/nanoseconds for timestamps.
+
+        Examples
+        --------
+        # The runs of a robot during a mission
+        >>> dataset.find_partitions(tags={"robot_id": "r2d2", "mission": None})
+
+        # The runs recorded in the last day
+        >>> dataset.find_partitions(timeline="log_time", start=datetime.now() - timedelta(days=1))
+
+        Returns
+        -------
+        list[str]
+            The IDs of the matching partitions.
+
+        """
+
     def register(self, recording_uri: str, *, recording_layer: str = "base", timeout_secs: int = 60) -> str:
         """
         Register a RRD URI to the dataset and wait for completion.
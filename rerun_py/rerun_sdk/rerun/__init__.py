@@ -73,16 +73,19 @@ from .archetypes import (
     BarChart as BarChart,
     Boxes2D as Boxes2D,
     Boxes3D as Boxes3D,
+    CameraRig as CameraRig,
     Capsules3D as Capsules3D,
     Clear as Clear,
     Cylinders3D as Cylinders3D,
     DepthImage as DepthImage,
     Ellipsoids3D as Ellipsoids3D,
     EncodedImage as EncodedImage,
+    EntityRelations as EntityRelations,
     GeoLineStrings as GeoLineStrings,
     GeoPoints as GeoPoints,
     GraphEdges as GraphEdges,
     GraphNodes as GraphNodes,
+    Histogram as Histogram,
     Image as Image,
     InstancePoses3D as InstancePoses3D,
     LineStrips2D as LineStrips2D,
@@ -102,12 +105,15 @@ from .archetypes import (
     VideoFrameReference as VideoFrameReference,
     VideoStream as VideoStream,
     ViewCoordinates as ViewCoordinates,
+    Waveform as Waveform,
 )
 from .archetypes.boxes2d_ext import (
     Box2DFormat as Box2DFormat,
 )
 from .components import (
     AlbedoFactor as AlbedoFactor,
+    DistortionModel as DistortionModel,
+    EulerConvention as EulerConvention,
     GraphEdge as GraphEdge,
     GraphType as GraphType,
     MediaType as MediaType,
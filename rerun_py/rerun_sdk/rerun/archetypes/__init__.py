@@ -10,16 +10,19 @@ from .asset_video import AssetVideo
 from .bar_chart import BarChart
 from .boxes2d import Boxes2D
 from .boxes3d import Boxes3D
+from .camera_rig import CameraRig
 from .capsules3d import Capsules3D
 from .clear import Clear
 from .cylinders3d import Cylinders3D
 from .depth_image import DepthImage
 from .ellipsoids3d import Ellipsoids3D
 from .encoded_image import EncodedImage
+from .entity_relations import EntityRelations
 from .geo_line_strings import GeoLineStrings
 from .geo_points import GeoPoints
 from .graph_edges import GraphEdges
 from .graph_nodes import GraphNodes
+from .histogram import Histogram
 from .image import Image
 from .instance_poses3d import InstancePoses3D
 from .line_strips2d import LineStrips2D
@@ -40,6 +43,7 @@ from .transform3d import Transform3D
 from .video_frame_reference import VideoFrameReference
 from .video_stream import VideoStream
 from .view_coordinates import ViewCoordinates
+from .waveform import Waveform
 
 __all__ = [
     "AnnotationContext",
@@ -50,16 +54,19 @@ __all__ = [
     "BarChart",
     "Boxes2D",
     "Boxes3D",
+    "CameraRig",
     "Capsules3D",
     "Clear",
     "Cylinders3D",
     "DepthImage",
     "Ellipsoids3D",
     "EncodedImage",
+    "EntityRelations",
     "GeoLineStrings",
     "GeoPoints",
     "GraphEdges",
     "GraphNodes",
+    "Histogram",
     "Image",
     "InstancePoses3D",
     "LineStrips2D",
@@ -80,4 +87,5 @@ __all__ = [
     "VideoFrameReference",
     "VideoStream",
     "ViewCoordinates",
+    "Waveform",
 ]
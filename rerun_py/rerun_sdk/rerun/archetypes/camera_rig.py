@@ -0,0 +1,252 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/camera_rig.fbs".
+
+# You can extend this class by creating a "CameraRigExt" class in "camera_rig_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+import numpy as np
+import pyarrow as pa
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["CameraRig"]
+
+
+@define(str=False, repr=False, init=False)
+class CameraRig(Archetype):
+    """
+    **Archetype**: Describes a rig of several cameras with fixed extrinsics relative to the rig entity.
+
+    This makes it possible to express the calibration of a stereo pair or a multi-camera robot once,
+    at the rig entity, instead of logging a [`archetypes.Transform3D`][rerun.archetypes.Transform3D] for each camera.
+    Each camera is a direct child of the rig entity and is placed relative to it by its pose in the rig.
+
+    If a camera entity also has a [`archetypes.Transform3D`][rerun.archetypes.Transform3D], it is applied on top of its pose in the rig.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        cameras: datatypes.EntityPathArrayLike,
+        *,
+        translations: datatypes.Vec3DArrayLike | None = None,
+        quaternions: datatypes.QuaternionArrayLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the CameraRig archetype.
+
+        Parameters
+        ----------
+        cameras:
+            The camera entities of the rig, relative to the rig entity.
+
+            Each camera must be a direct child of the rig entity, e.g. `left` and `right` for a stereo pair.
+        translations:
+            Translation of each camera relative to the rig entity.
+
+            If there are fewer translations than cameras, the missing cameras aren't translated.
+        quaternions:
+            Rotation of each camera relative to the rig entity, as a quaternion.
+
+            If there are fewer quaternions than cameras, the missing cameras aren't rotated.
+
+        """
+
+        # You can define your own __init__ function as a member of CameraRigExt in camera_rig_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(cameras=cameras, translations=translations, quaternions=quaternions)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            cameras=None,
+            translations=None,
+            quaternions=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> CameraRig:
+        """Produce an empty CameraRig, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        cameras: datatypes.EntityPathArrayLike | None = None,
+        translations: datatypes.Vec3DArrayLike | None = None,
+        quaternions: datatypes.QuaternionArrayLike | None = None,
+    ) -> CameraRig:
+        """
+        Update only some specific fields of a `CameraRig`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        cameras:
+            The camera entities of the rig, relative to the rig entity.
+
+            Each camera must be a direct child of the rig entity, e.g. `left` and `right` for a stereo pair.
+        translations:
+            Translation of each camera relative to the rig entity.
+
+            If there are fewer translations than cameras, the missing cameras aren't translated.
+        quaternions:
+            Rotation of each camera relative to the rig entity, as a quaternion.
+
+            If there are fewer quaternions than cameras, the missing cameras aren't rotated.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "cameras": cameras,
+                "translations": translations,
+                "quaternions": quaternions,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> CameraRig:
+        """Clear all the fields of a `CameraRig`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        cameras: datatypes.EntityPathArrayLike | None = None,
+        translations: datatypes.Vec3DArrayLike | None = None,
+        quaternions: datatypes.QuaternionArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        cameras:
+            The camera entities of the rig, relative to the rig entity.
+
+            Each camera must be a direct child of the rig entity, e.g. `left` and `right` for a stereo pair.
+        translations:
+            Translation of each camera relative to the rig entity.
+
+            If there are fewer translations than cameras, the missing cameras aren't translated.
+        quaternions:
+            Rotation of each camera relative to the rig entity, as a quaternion.
+
+            If there are fewer quaternions than cameras, the missing cameras aren't rotated.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                cameras=cameras,
+                translations=translations,
+                quaternions=quaternions,
+            )
+
+        batches = inst.as_component_batches()
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        kwargs = {
+            "CameraRig:cameras": cameras,
+            "CameraRig:translations": translations,
+            "CameraRig:quaternions": quaternions,
+        }
+        columns = []
+
+        for batch in batches:
+            arrow_array = batch.as_arrow_array()
+
+            # For primitive arrays and fixed size list arrays, we infer partition size from the input shape.
+            if pa.types.is_primitive(arrow_array.type) or pa.types.is_fixed_size_list(arrow_array.type):
+                param = kwargs[batch.component_descriptor().component]  # type: ignore[index]
+                shape = np.shape(param)  # type: ignore[arg-type]
+                elem_flat_len = int(np.prod(shape[1:])) if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                if pa.types.is_fixed_size_list(arrow_array.type) and arrow_array.type.list_size == elem_flat_len:
+                    # If the product of the last dimensions of the shape are equal to the size of the fixed size list array,
+                    # we have `num_rows` single element batches (each element is a fixed sized list).
+                    # (This should have been already validated by conversion to the arrow_array)
+                    batch_length = 1
+                else:
+                    batch_length = shape[1] if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                num_rows = shape[0] if len(shape) >= 1 else 1  # type: ignore[redundant-expr,misc]
+                sizes = batch_length * np.ones(num_rows)
+            else:
+                # For non-primitive types, default to partitioning each element separately.
+                sizes = np.ones(len(arrow_array))
+
+            columns.append(batch.partition(sizes))
+
+        return ComponentColumnList(columns)
+
+    cameras: components.EntityPathBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.EntityPathBatch._converter,  # type: ignore[misc]
+    )
+    # The camera entities of the rig, relative to the rig entity.
+    #
+    # Each camera must be a direct child of the rig entity, e.g. `left` and `right` for a stereo pair.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    translations: components.PoseTranslation3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.PoseTranslation3DBatch._converter,  # type: ignore[misc]
+    )
+    # Translation of each camera relative to the rig entity.
+    #
+    # If there are fewer translations than cameras, the missing cameras aren't translated.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    quaternions: components.PoseRotationQuatBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.PoseRotationQuatBatch._converter,  # type: ignore[misc]
+    )
+    # Rotation of each camera relative to the rig entity, as a quaternion.
+    #
+    # If there are fewer quaternions than cameras, the missing cameras aren't rotated.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
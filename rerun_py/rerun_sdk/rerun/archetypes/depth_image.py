@@ -75,6 +75,7 @@ class DepthImage(DepthImageExt, Archetype):
             meter=None,
             colormap=None,
             depth_range=None,
+            clip_range=None,
             point_fill_ratio=None,
             draw_order=None,
         )
@@ -96,6 +97,7 @@ class DepthImage(DepthImageExt, Archetype):
         meter: datatypes.Float32Like | None = None,
         colormap: components.ColormapLike | None = None,
         depth_range: datatypes.Range1DLike | None = None,
+        clip_range: datatypes.Range1DLike | None = None,
         point_fill_ratio: datatypes.Float32Like | None = None,
         draw_order: datatypes.Float32Like | None = None,
     ) -> DepthImage:
@@ -134,6 +136,13 @@ class DepthImage(DepthImageExt, Archetype):
             in the contents of the depth image.
             E.g. if all values are positive, some bigger than 1.0 and all smaller than 255.0,
             the Viewer will guess that the data likely came from an 8bit image, thus assuming a range of 0-255.
+        clip_range:
+            Depth values outside of this range, in meters, are left out of the point cloud generated from this image.
+
+            Use this to hide invalid measurements close to the sensor or far away from it, e.g. `[0.3, 10.0]`.
+            If not specified, all points with a positive depth are shown.
+
+            This has no effect on 2D views.
         point_fill_ratio:
             Scale the radii of the points in the point cloud generated from this image.
 
@@ -158,6 +167,7 @@ class DepthImage(DepthImageExt, Archetype):
                 "meter": meter,
                 "colormap": colormap,
                 "depth_range": depth_range,
+                "clip_range": clip_range,
                 "point_fill_ratio": point_fill_ratio,
                 "draw_order": draw_order,
             }
@@ -185,6 +195,7 @@ class DepthImage(DepthImageExt, Archetype):
         meter: datatypes.Float32ArrayLike | None = None,
         colormap: components.ColormapArrayLike | None = None,
         depth_range: datatypes.Range1DArrayLike | None = None,
+        clip_range: datatypes.Range1DArrayLike | None = None,
         point_fill_ratio: datatypes.Float32ArrayLike | None = None,
         draw_order: datatypes.Float32ArrayLike | None = None,
     ) -> ComponentColumnList:
@@ -226,6 +237,13 @@ class DepthImage(DepthImageExt, Archetype):
             in the contents of the depth image.
             E.g. if all values are positive, some bigger than 1.0 and all smaller than 255.0,
             the Viewer will guess that the data likely came from an 8bit image, thus assuming a range of 0-255.
+        clip_range:
+            Depth values outside of this range, in meters, are left out of the point cloud generated from this image.
+
+            Use this to hide invalid measurements close to the sensor or far away from it, e.g. `[0.3, 10.0]`.
+            If not specified, all points with a positive depth are shown.
+
+            This has no effect on 2D views.
         point_fill_ratio:
             Scale the radii of the points in the point cloud generated from this image.
 
@@ -250,6 +268,7 @@ class DepthImage(DepthImageExt, Archetype):
                 meter=meter,
                 colormap=colormap,
                 depth_range=depth_range,
+                clip_range=clip_range,
                 point_fill_ratio=point_fill_ratio,
                 draw_order=draw_order,
             )
@@ -264,6 +283,7 @@ class DepthImage(DepthImageExt, Archetype):
             "DepthImage:meter": meter,
             "DepthImage:colormap": colormap,
             "DepthImage:depth_range": depth_range,
+            "DepthImage:clip_range": clip_range,
             "DepthImage:point_fill_ratio": point_fill_ratio,
             "DepthImage:draw_order": draw_order,
         }
@@ -359,6 +379,20 @@ class DepthImage(DepthImageExt, Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    clip_range: components.Range1DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Range1DBatch._converter,  # type: ignore[misc]
+    )
+    # Depth values outside of this range, in meters, are left out of the point cloud generated from this image.
+    #
+    # Use this to hide invalid measurements close to the sensor or far away from it, e.g. `[0.3, 10.0]`.
+    # If not specified, all points with a positive depth are shown.
+    #
+    # This has no effect on 2D views.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     point_fill_ratio: components.FillRatioBatch | None = field(
         metadata={"component": True},
         default=None,
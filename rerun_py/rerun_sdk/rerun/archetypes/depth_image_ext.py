@@ -49,6 +49,7 @@ class DepthImageExt:
         meter: Float32Like | None = None,
         colormap: ColormapLike | None = None,
         depth_range: Range1DLike | None = None,
+        clip_range: Range1DLike | None = None,
         point_fill_ratio: Float32Like | None = None,
         draw_order: Float32Like | None = None,
     ) -> None:
@@ -85,6 +86,13 @@ class DepthImageExt:
             in the contents of the depth image.
             E.g. if all values are positive, some bigger than 1.0 and all smaller than 255.0,
             the Viewer will guess that the data likely came from an 8bit image, thus assuming a range of 0-255.
+        clip_range:
+            Depth values outside of this range, in meters, are left out of the point cloud generated from this image.
+
+            Use this to hide invalid measurements close to the sensor or far away from it, e.g. `[0.3, 10.0]`.
+            If not specified, all points with a positive depth are shown.
+
+            This has no effect on 2D views.
         point_fill_ratio:
             Scale the radii of the points in the point cloud generated from this image.
 
@@ -128,6 +136,7 @@ class DepthImageExt:
             meter=meter,
             colormap=colormap,
             depth_range=depth_range,
+            clip_range=clip_range,
             point_fill_ratio=point_fill_ratio,
             draw_order=draw_order,
         )
@@ -0,0 +1,216 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/entity_relations.fbs".
+
+# You can extend this class by creating a "EntityRelationsExt" class in "entity_relations_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+import numpy as np
+import pyarrow as pa
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["EntityRelations"]
+
+
+@define(str=False, repr=False, init=False)
+class EntityRelations(Archetype):
+    """
+    **Archetype**: Relations between an entity and other entities, beyond the entity hierarchy.
+
+    This makes it possible to express that a detection came from a given camera, or that a track
+    is the same object as another one across frames.
+    Relations are logged on the source entity, and each target is the full path of a related entity.
+    Like any other data, relations can change over time.
+
+    The viewer lists the related entities of the selected entity, in both directions, so that one can
+    navigate between them.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        targets: datatypes.EntityPathArrayLike,
+        *,
+        kinds: datatypes.Utf8ArrayLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the EntityRelations archetype.
+
+        Parameters
+        ----------
+        targets:
+            The related entities.
+        kinds:
+            The kind of each relation, e.g. `source` or `same_track_as`.
+
+            If there are fewer kinds than targets, the remaining relations have no kind.
+
+        """
+
+        # You can define your own __init__ function as a member of EntityRelationsExt in entity_relations_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(targets=targets, kinds=kinds)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            targets=None,
+            kinds=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> EntityRelations:
+        """Produce an empty EntityRelations, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        targets: datatypes.EntityPathArrayLike | None = None,
+        kinds: datatypes.Utf8ArrayLike | None = None,
+    ) -> EntityRelations:
+        """
+        Update only some specific fields of a `EntityRelations`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        targets:
+            The related entities.
+        kinds:
+            The kind of each relation, e.g. `source` or `same_track_as`.
+
+            If there are fewer kinds than targets, the remaining relations have no kind.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "targets": targets,
+                "kinds": kinds,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> EntityRelations:
+        """Clear all the fields of a `EntityRelations`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        targets: datatypes.EntityPathArrayLike | None = None,
+        kinds: datatypes.Utf8ArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        targets:
+            The related entities.
+        kinds:
+            The kind of each relation, e.g. `source` or `same_track_as`.
+
+            If there are fewer kinds than targets, the remaining relations have no kind.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                targets=targets,
+                kinds=kinds,
+            )
+
+        batches = inst.as_component_batches()
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        kwargs = {
+            "EntityRelations:targets": targets,
+            "EntityRelations:kinds": kinds,
+        }
+        columns = []
+
+        for batch in batches:
+            arrow_array = batch.as_arrow_array()
+
+            # For primitive arrays and fixed size list arrays, we infer partition size from the input shape.
+            if pa.types.is_primitive(arrow_array.type) or pa.types.is_fixed_size_list(arrow_array.type):
+                param = kwargs[batch.component_descriptor().component]  # type: ignore[index]
+                shape = np.shape(param)  # type: ignore[arg-type]
+                elem_flat_len = int(np.prod(shape[1:])) if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                if pa.types.is_fixed_size_list(arrow_array.type) and arrow_array.type.list_size == elem_flat_len:
+                    # If the product of the last dimensions of the shape are equal to the size of the fixed size list array,
+                    # we have `num_rows` single element batches (each element is a fixed sized list).
+                    # (This should have been already validated by conversion to the arrow_array)
+                    batch_length = 1
+                else:
+                    batch_length = shape[1] if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                num_rows = shape[0] if len(shape) >= 1 else 1  # type: ignore[redundant-expr,misc]
+                sizes = batch_length * np.ones(num_rows)
+            else:
+                # For non-primitive types, default to partitioning each element separately.
+                sizes = np.ones(len(arrow_array))
+
+            columns.append(batch.partition(sizes))
+
+        return ComponentColumnList(columns)
+
+    targets: components.EntityPathBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.EntityPathBatch._converter,  # type: ignore[misc]
+    )
+    # The related entities.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    kinds: components.TextBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextBatch._converter,  # type: ignore[misc]
+    )
+    # The kind of each relation, e.g. `source` or `same_track_as`.
+    #
+    # If there are fewer kinds than targets, the remaining relations have no kind.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,235 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/histogram.fbs".
+
+# You can extend this class by creating a "HistogramExt" class in "histogram_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+import numpy as np
+import pyarrow as pa
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["Histogram"]
+
+
+@define(str=False, repr=False, init=False)
+class Histogram(Archetype):
+    """
+    **Archetype**: A histogram, i.e. counts over a set of contiguous bins.
+
+    Each logged histogram replaces the previous one, making it possible to step through
+    per-frame distributions (e.g. latencies, pixel intensities) on the timeline.
+
+    If no bin edges are provided, bin `i` spans the range `[i, i + 1)`.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        counts: datatypes.Float64ArrayLike,
+        *,
+        bin_edges: datatypes.Float64ArrayLike | None = None,
+        color: datatypes.Rgba32Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the Histogram archetype.
+
+        Parameters
+        ----------
+        counts:
+            The count (or weight) of each bin.
+        bin_edges:
+            The edges of the bins, in ascending order.
+
+            Must contain exactly one more value than `counts`: bin `i` spans `[bin_edges[i], bin_edges[i + 1])`.
+            Defaults to unit-width bins starting at zero.
+        color:
+            The color of the bars.
+
+        """
+
+        # You can define your own __init__ function as a member of HistogramExt in histogram_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(counts=counts, bin_edges=bin_edges, color=color)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            counts=None,
+            bin_edges=None,
+            color=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> Histogram:
+        """Produce an empty Histogram, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        counts: datatypes.Float64ArrayLike | None = None,
+        bin_edges: datatypes.Float64ArrayLike | None = None,
+        color: datatypes.Rgba32Like | None = None,
+    ) -> Histogram:
+        """
+        Update only some specific fields of a `Histogram`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        counts:
+            The count (or weight) of each bin.
+        bin_edges:
+            The edges of the bins, in ascending order.
+
+            Must contain exactly one more value than `counts`: bin `i` spans `[bin_edges[i], bin_edges[i + 1])`.
+            Defaults to unit-width bins starting at zero.
+        color:
+            The color of the bars.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "counts": counts,
+                "bin_edges": bin_edges,
+                "color": color,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> Histogram:
+        """Clear all the fields of a `Histogram`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        counts: datatypes.Float64ArrayLike | None = None,
+        bin_edges: datatypes.Float64ArrayLike | None = None,
+        color: datatypes.Rgba32ArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        counts:
+            The count (or weight) of each bin.
+        bin_edges:
+            The edges of the bins, in ascending order.
+
+            Must contain exactly one more value than `counts`: bin `i` spans `[bin_edges[i], bin_edges[i + 1])`.
+            Defaults to unit-width bins starting at zero.
+        color:
+            The color of the bars.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                counts=counts,
+                bin_edges=bin_edges,
+                color=color,
+            )
+
+        batches = inst.as_component_batches()
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        kwargs = {"Histogram:counts": counts, "Histogram:bin_edges": bin_edges, "Histogram:color": color}
+        columns = []
+
+        for batch in batches:
+            arrow_array = batch.as_arrow_array()
+
+            # For primitive arrays and fixed size list arrays, we infer partition size from the input shape.
+            if pa.types.is_primitive(arrow_array.type) or pa.types.is_fixed_size_list(arrow_array.type):
+                param = kwargs[batch.component_descriptor().component]  # type: ignore[index]
+                shape = np.shape(param)  # type: ignore[arg-type]
+                elem_flat_len = int(np.prod(shape[1:])) if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                if pa.types.is_fixed_size_list(arrow_array.type) and arrow_array.type.list_size == elem_flat_len:
+                    # If the product of the last dimensions of the shape are equal to the size of the fixed size list array,
+                    # we have `num_rows` single element batches (each element is a fixed sized list).
+                    # (This should have been already validated by conversion to the arrow_array)
+                    batch_length = 1
+                else:
+                    batch_length = shape[1] if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                num_rows = shape[0] if len(shape) >= 1 else 1  # type: ignore[redundant-expr,misc]
+                sizes = batch_length * np.ones(num_rows)
+            else:
+                # For non-primitive types, default to partitioning each element separately.
+                sizes = np.ones(len(arrow_array))
+
+            columns.append(batch.partition(sizes))
+
+        return ComponentColumnList(columns)
+
+    counts: components.ScalarBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ScalarBatch._converter,  # type: ignore[misc]
+    )
+    # The count (or weight) of each bin.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    bin_edges: components.ScalarBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ScalarBatch._converter,  # type: ignore[misc]
+    )
+    # The edges of the bins, in ascending order.
+    #
+    # Must contain exactly one more value than `counts`: bin `i` spans `[bin_edges[i], bin_edges[i + 1])`.
+    # Defaults to unit-width bins starting at zero.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    color: components.ColorBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColorBatch._converter,  # type: ignore[misc]
+    )
+    # The color of the bars.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -85,6 +85,8 @@ class Pinhole(PinholeExt, Archetype):
             resolution=None,
             camera_xyz=None,
             image_plane_distance=None,
+            distortion_model=None,
+            distortion_coefficients=None,
         )
 
     @classmethod
@@ -103,6 +105,8 @@ class Pinhole(PinholeExt, Archetype):
         resolution: datatypes.Vec2DLike | None = None,
         camera_xyz: datatypes.ViewCoordinatesLike | None = None,
         image_plane_distance: datatypes.Float32Like | None = None,
+        distortion_model: components.DistortionModelLike | None = None,
+        distortion_coefficients: datatypes.Float32ArrayLike | None = None,
     ) -> Pinhole:
         """
         Update only some specific fields of a `Pinhole`.
@@ -154,6 +158,15 @@ class Pinhole(PinholeExt, Archetype):
             The distance from the camera origin to the image plane when the projection is shown in a 3D viewer.
 
             This is only used for visualization purposes, and does not affect the projection itself.
+        distortion_model:
+            The lens distortion model that the `distortion_coefficients` are given for.
+
+            Defaults to the Brown-Conrady (`plumb_bob`) model used by OpenCV.
+        distortion_coefficients:
+            Lens distortion coefficients, in the order defined by the `distortion_model`.
+
+            Points are distorted on the normalized image plane, i.e. after dividing by depth and before applying
+            `image_from_camera`. If no coefficients are logged, the camera is an ideal pinhole camera.
 
         """
 
@@ -164,6 +177,8 @@ class Pinhole(PinholeExt, Archetype):
                 "resolution": resolution,
                 "camera_xyz": camera_xyz,
                 "image_plane_distance": image_plane_distance,
+                "distortion_model": distortion_model,
+                "distortion_coefficients": distortion_coefficients,
             }
 
             if clear_unset:
@@ -188,6 +203,8 @@ class Pinhole(PinholeExt, Archetype):
         resolution: datatypes.Vec2DArrayLike | None = None,
         camera_xyz: datatypes.ViewCoordinatesArrayLike | None = None,
         image_plane_distance: datatypes.Float32ArrayLike | None = None,
+        distortion_model: components.DistortionModelArrayLike | None = None,
+        distortion_coefficients: datatypes.Float32ArrayLike | None = None,
     ) -> ComponentColumnList:
         """
         Construct a new column-oriented component bundle.
@@ -242,6 +259,15 @@ class Pinhole(PinholeExt, Archetype):
             The distance from the camera origin to the image plane when the projection is shown in a 3D viewer.
 
             This is only used for visualization purposes, and does not affect the projection itself.
+        distortion_model:
+            The lens distortion model that the `distortion_coefficients` are given for.
+
+            Defaults to the Brown-Conrady (`plumb_bob`) model used by OpenCV.
+        distortion_coefficients:
+            Lens distortion coefficients, in the order defined by the `distortion_model`.
+
+            Points are distorted on the normalized image plane, i.e. after dividing by depth and before applying
+            `image_from_camera`. If no coefficients are logged, the camera is an ideal pinhole camera.
 
         """
 
@@ -252,6 +278,8 @@ class Pinhole(PinholeExt, Archetype):
                 resolution=resolution,
                 camera_xyz=camera_xyz,
                 image_plane_distance=image_plane_distance,
+                distortion_model=distortion_model,
+                distortion_coefficients=distortion_coefficients,
             )
 
         batches = inst.as_component_batches()
@@ -263,6 +291,8 @@ class Pinhole(PinholeExt, Archetype):
             "Pinhole:resolution": resolution,
             "Pinhole:camera_xyz": camera_xyz,
             "Pinhole:image_plane_distance": image_plane_distance,
+            "Pinhole:distortion_model": distortion_model,
+            "Pinhole:distortion_coefficients": distortion_coefficients,
         }
         columns = []
 
@@ -364,5 +394,28 @@ class Pinhole(PinholeExt, Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    distortion_model: components.DistortionModelBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.DistortionModelBatch._converter,  # type: ignore[misc]
+    )
+    # The lens distortion model that the `distortion_coefficients` are given for.
+    #
+    # Defaults to the Brown-Conrady (`plumb_bob`) model used by OpenCV.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    distortion_coefficients: components.DistortionCoefficientBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.DistortionCoefficientBatch._converter,  # type: ignore[misc]
+    )
+    # Lens distortion coefficients, in the order defined by the `distortion_model`.
+    #
+    # Points are distorted on the normalized image plane, i.e. after dividing by depth and before applying
+    # `image_from_camera`. If no coefficients are logged, the camera is an ideal pinhole camera.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     __str__ = Archetype.__str__
     __repr__ = Archetype.__repr__  # type: ignore[assignment]
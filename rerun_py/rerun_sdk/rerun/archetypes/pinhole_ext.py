@@ -9,6 +9,9 @@ from ..error_utils import _send_warning_or_raise, catch_and_log_exceptions
 if TYPE_CHECKING:
     import numpy.typing as npt
 
+    from ..components import DistortionModelLike
+    from ..datatypes import Float32ArrayLike
+
 
 class PinholeExt:
     """Extension for [Pinhole][rerun.archetypes.Pinhole]."""
@@ -26,6 +29,8 @@ class PinholeExt:
         fov_y: float | None = None,
         aspect_ratio: float | None = None,
         image_plane_distance: float | None = None,
+        distortion_model: DistortionModelLike | None = None,
+        distortion_coefficients: Float32ArrayLike | None = None,
     ) -> None:
         """
         Create a new instance of the Pinhole archetype.
@@ -88,6 +93,12 @@ class PinholeExt:
         image_plane_distance:
             The distance from the camera origin to the image plane when the projection is shown in a 3D viewer.
             This is only used for visualization purposes, and does not affect the projection itself.
+        distortion_model:
+            The lens distortion model that the `distortion_coefficients` are given for.
+            Defaults to the Brown-Conrady (`plumb_bob`) model used by OpenCV.
+        distortion_coefficients:
+            Lens distortion coefficients, in the order defined by the `distortion_model`,
+            e.g. `[k1, k2, p1, p2, k3]` as given by OpenCV's `calibrateCamera`.
 
         """
 
@@ -156,6 +167,8 @@ class PinholeExt:
                 resolution=resolution,
                 camera_xyz=camera_xyz,
                 image_plane_distance=image_plane_distance,
+                distortion_model=distortion_model,
+                distortion_coefficients=distortion_coefficients,
             )
             return
 
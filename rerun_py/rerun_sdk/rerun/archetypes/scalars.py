@@ -91,7 +91,12 @@ class Scalars(Archetype):
 
     """
 
-    def __init__(self: Any, scalars: datatypes.Float64ArrayLike) -> None:
+    def __init__(
+        self: Any,
+        scalars: datatypes.Float64ArrayLike,
+        *,
+        units: datatypes.Utf8ArrayLike | None = None,
+    ) -> None:
         """
         Create a new instance of the Scalars archetype.
 
@@ -99,12 +104,18 @@ class Scalars(Archetype):
         ----------
         scalars:
             The scalar values to log.
+        units:
+            The unit of each scalar value, e.g. `m/s` or `°C`.
+
+            Shown on the plot axes and in tooltips.
+            If a single unit is logged, it applies to all scalars.
+            Expected to be unchanging over time.
 
         """
 
         # You can define your own __init__ function as a member of ScalarsExt in scalars_ext.py
         with catch_and_log_exceptions(context=self.__class__.__name__):
-            self.__attrs_init__(scalars=scalars)
+            self.__attrs_init__(scalars=scalars, units=units)
             return
         self.__attrs_clear__()
 
@@ -112,6 +123,7 @@ class Scalars(Archetype):
         """Convenience method for calling `__attrs_init__` with all `None`s."""
         self.__attrs_init__(
             scalars=None,
+            units=None,
         )
 
     @classmethod
@@ -127,6 +139,7 @@ class Scalars(Archetype):
         *,
         clear_unset: bool = False,
         scalars: datatypes.Float64ArrayLike | None = None,
+        units: datatypes.Utf8ArrayLike | None = None,
     ) -> Scalars:
         """
         Update only some specific fields of a `Scalars`.
@@ -137,6 +150,12 @@ class Scalars(Archetype):
             If true, all unspecified fields will be explicitly cleared.
         scalars:
             The scalar values to log.
+        units:
+            The unit of each scalar value, e.g. `m/s` or `°C`.
+
+            Shown on the plot axes and in tooltips.
+            If a single unit is logged, it applies to all scalars.
+            Expected to be unchanging over time.
 
         """
 
@@ -144,6 +163,7 @@ class Scalars(Archetype):
         with catch_and_log_exceptions(context=cls.__name__):
             kwargs = {
                 "scalars": scalars,
+                "units": units,
             }
 
             if clear_unset:
@@ -165,6 +185,7 @@ class Scalars(Archetype):
         cls,
         *,
         scalars: datatypes.Float64ArrayLike | None = None,
+        units: datatypes.Utf8ArrayLike | None = None,
     ) -> ComponentColumnList:
         """
         Construct a new column-oriented component bundle.
@@ -178,6 +199,12 @@ class Scalars(Archetype):
         ----------
         scalars:
             The scalar values to log.
+        units:
+            The unit of each scalar value, e.g. `m/s` or `°C`.
+
+            Shown on the plot axes and in tooltips.
+            If a single unit is logged, it applies to all scalars.
+            Expected to be unchanging over time.
 
         """
 
@@ -185,13 +212,14 @@ class Scalars(Archetype):
         with catch_and_log_exceptions(context=cls.__name__):
             inst.__attrs_init__(
                 scalars=scalars,
+                units=units,
             )
 
         batches = inst.as_component_batches()
         if len(batches) == 0:
             return ComponentColumnList([])
 
-        kwargs = {"Scalars:scalars": scalars}
+        kwargs = {"Scalars:scalars": scalars, "Scalars:units": units}
         columns = []
 
         for batch in batches:
@@ -230,5 +258,18 @@ class Scalars(Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    units: components.UnitBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.UnitBatch._converter,  # type: ignore[misc]
+    )
+    # The unit of each scalar value, e.g. `m/s` or `°C`.
+    #
+    # Shown on the plot axes and in tooltips.
+    # If a single unit is logged, it applies to all scalars.
+    # Expected to be unchanging over time.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     __str__ = Archetype.__str__
     __repr__ = Archetype.__repr__  # type: ignore[assignment]
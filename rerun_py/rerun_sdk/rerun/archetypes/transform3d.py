@@ -294,6 +294,8 @@ class Transform3D(Transform3DExt, Archetype):
             translation=None,
             rotation_axis_angle=None,
             quaternion=None,
+            rotation_euler=None,
+            euler_convention=None,
             scale=None,
             mat3x3=None,
             relation=None,
@@ -315,6 +317,8 @@ class Transform3D(Transform3DExt, Archetype):
         translation: datatypes.Vec3DLike | None = None,
         rotation_axis_angle: datatypes.RotationAxisAngleLike | None = None,
         quaternion: datatypes.QuaternionLike | None = None,
+        rotation_euler: datatypes.Vec3DLike | None = None,
+        euler_convention: components.EulerConventionLike | None = None,
         scale: datatypes.Vec3DLike | None = None,
         mat3x3: datatypes.Mat3x3Like | None = None,
         relation: components.TransformRelationLike | None = None,
@@ -333,6 +337,14 @@ class Transform3D(Transform3DExt, Archetype):
             Rotation via axis + angle.
         quaternion:
             Rotation via quaternion.
+        rotation_euler:
+            Rotation via Euler angles, in radians.
+
+            The axes the angles are applied about are given by [`components.EulerConvention`][rerun.components.EulerConvention].
+        euler_convention:
+            The axis order used to interpret [`components.RotationEuler`][rerun.components.RotationEuler].
+
+            Defaults to `Zyx`, i.e. yaw, pitch, roll.
         scale:
             Scaling factor.
         mat3x3:
@@ -353,6 +365,8 @@ class Transform3D(Transform3DExt, Archetype):
                 "translation": translation,
                 "rotation_axis_angle": rotation_axis_angle,
                 "quaternion": quaternion,
+                "rotation_euler": rotation_euler,
+                "euler_convention": euler_convention,
                 "scale": scale,
                 "mat3x3": mat3x3,
                 "relation": relation,
@@ -380,6 +394,8 @@ class Transform3D(Transform3DExt, Archetype):
         translation: datatypes.Vec3DArrayLike | None = None,
         rotation_axis_angle: datatypes.RotationAxisAngleArrayLike | None = None,
         quaternion: datatypes.QuaternionArrayLike | None = None,
+        rotation_euler: datatypes.Vec3DArrayLike | None = None,
+        euler_convention: components.EulerConventionArrayLike | None = None,
         scale: datatypes.Vec3DArrayLike | None = None,
         mat3x3: datatypes.Mat3x3ArrayLike | None = None,
         relation: components.TransformRelationArrayLike | None = None,
@@ -401,6 +417,14 @@ class Transform3D(Transform3DExt, Archetype):
             Rotation via axis + angle.
         quaternion:
             Rotation via quaternion.
+        rotation_euler:
+            Rotation via Euler angles, in radians.
+
+            The axes the angles are applied about are given by [`components.EulerConvention`][rerun.components.EulerConvention].
+        euler_convention:
+            The axis order used to interpret [`components.RotationEuler`][rerun.components.RotationEuler].
+
+            Defaults to `Zyx`, i.e. yaw, pitch, roll.
         scale:
             Scaling factor.
         mat3x3:
@@ -421,6 +445,8 @@ class Transform3D(Transform3DExt, Archetype):
                 translation=translation,
                 rotation_axis_angle=rotation_axis_angle,
                 quaternion=quaternion,
+                rotation_euler=rotation_euler,
+                euler_convention=euler_convention,
                 scale=scale,
                 mat3x3=mat3x3,
                 relation=relation,
@@ -435,6 +461,8 @@ class Transform3D(Transform3DExt, Archetype):
             "Transform3D:translation": translation,
             "Transform3D:rotation_axis_angle": rotation_axis_angle,
             "Transform3D:quaternion": quaternion,
+            "Transform3D:rotation_euler": rotation_euler,
+            "Transform3D:euler_convention": euler_convention,
             "Transform3D:scale": scale,
             "Transform3D:mat3x3": mat3x3,
             "Transform3D:relation": relation,
@@ -496,6 +524,28 @@ class Transform3D(Transform3DExt, Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    rotation_euler: components.RotationEulerBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.RotationEulerBatch._converter,  # type: ignore[misc]
+    )
+    # Rotation via Euler angles, in radians.
+    #
+    # The axes the angles are applied about are given by [`components.EulerConvention`][rerun.components.EulerConvention].
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    euler_convention: components.EulerConventionBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.EulerConventionBatch._converter,  # type: ignore[misc]
+    )
+    # The axis order used to interpret [`components.RotationEuler`][rerun.components.RotationEuler].
+    #
+    # Defaults to `Zyx`, i.e. yaw, pitch, roll.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     scale: components.Scale3DBatch | None = field(
         metadata={"component": True},
         default=None,
@@ -2,7 +2,7 @@ from __future__ import annotations
 
 from typing import Any
 
-from rerun.components import Scale3D, TransformRelation, TransformRelationLike
+from rerun.components import EulerConventionLike, Scale3D, TransformRelation, TransformRelationLike
 from rerun.datatypes import (
     Float32Like,
     Mat3x3Like,
@@ -27,6 +27,8 @@ class Transform3DExt:
         rotation: QuaternionLike | RotationAxisAngleLike | None = None,
         rotation_axis_angle: RotationAxisAngleLike | None = None,
         quaternion: QuaternionLike | None = None,
+        rotation_euler: Vec3DLike | None = None,
+        euler_convention: EulerConventionLike | None = None,
         scale: Vec3DLike | Float32Like | None = None,
         mat3x3: Mat3x3Like | None = None,
         from_parent: bool | None = None,
@@ -53,6 +55,14 @@ class Transform3DExt:
             Quaternion representing rotation.
 
             Mutually exclusive with `rotation` parameter.
+        rotation_euler:
+            Euler angles representing rotation, in radians.
+
+            The axes the angles are applied about are given by `euler_convention`.
+        euler_convention:
+            The axis order used to interpret `rotation_euler`.
+
+            Defaults to `EulerConvention.Zyx`, i.e. yaw, pitch, roll.
         scale:
             3D scale.
         mat3x3:
@@ -133,6 +143,8 @@ class Transform3DExt:
                     translation=translation if translation is not None else [],
                     rotation_axis_angle=rotation_axis_angle if rotation_axis_angle is not None else [],
                     quaternion=quaternion if quaternion is not None else [],
+                    rotation_euler=rotation_euler if rotation_euler is not None else [],
+                    euler_convention=euler_convention if euler_convention is not None else [],
                     scale=scale if scale is not None else [],
                     mat3x3=mat3x3 if mat3x3 is not None else [],
                     relation=relation if relation is not None else [],
@@ -143,6 +155,8 @@ class Transform3DExt:
                     translation=translation,
                     rotation_axis_angle=rotation_axis_angle,
                     quaternion=quaternion,
+                    rotation_euler=rotation_euler,
+                    euler_convention=euler_convention,
                     scale=scale,
                     mat3x3=mat3x3,
                     relation=relation,
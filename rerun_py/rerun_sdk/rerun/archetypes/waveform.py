@@ -0,0 +1,258 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/waveform.fbs".
+
+# You can extend this class by creating a "WaveformExt" class in "waveform_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+import numpy as np
+import pyarrow as pa
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["Waveform"]
+
+
+@define(str=False, repr=False, init=False)
+class Waveform(Archetype):
+    """
+    **Archetype**: A block of evenly spaced samples for one or more channels, e.g. for high-rate IMU, audio or EMG data.
+
+    Instead of logging one [`archetypes.Scalars`][rerun.archetypes.Scalars] row per sample, a whole block of samples is logged at once:
+    the current timeline value is the time of the first sample in the block, and all following samples
+    are spaced according to the sample rate.
+    This drastically reduces the number of rows for kHz-rate signals.
+
+    For multi-channel data, samples are interleaved, i.e. the first sample of every channel comes first,
+    then the second sample of every channel, etc.
+    The number of channels is expected to be the same over time.
+
+    Like for [`archetypes.Scalars`][rerun.archetypes.Scalars], the style of each channel can be configured by logging [`archetypes.SeriesLines`][rerun.archetypes.SeriesLines]
+    to the same entity path.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        samples: datatypes.Float64ArrayLike,
+        sample_rate: datatypes.Float64Like,
+        *,
+        channel_count: datatypes.UInt32Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the Waveform archetype.
+
+        Parameters
+        ----------
+        samples:
+            The interleaved samples of all channels in this block.
+        sample_rate:
+            The rate at which the samples were acquired.
+
+            In Hz for temporal timelines, and per tick for sequence timelines.
+            Expected to be unchanging over time and can therefore be logged statically.
+        channel_count:
+            The number of channels the samples are interleaved across.
+
+            Defaults to a single channel.
+            Expected to be unchanging over time and can therefore be logged statically.
+
+        """
+
+        # You can define your own __init__ function as a member of WaveformExt in waveform_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(samples=samples, sample_rate=sample_rate, channel_count=channel_count)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            samples=None,
+            sample_rate=None,
+            channel_count=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> Waveform:
+        """Produce an empty Waveform, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        samples: datatypes.Float64ArrayLike | None = None,
+        sample_rate: datatypes.Float64Like | None = None,
+        channel_count: datatypes.UInt32Like | None = None,
+    ) -> Waveform:
+        """
+        Update only some specific fields of a `Waveform`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        samples:
+            The interleaved samples of all channels in this block.
+        sample_rate:
+            The rate at which the samples were acquired.
+
+            In Hz for temporal timelines, and per tick for sequence timelines.
+            Expected to be unchanging over time and can therefore be logged statically.
+        channel_count:
+            The number of channels the samples are interleaved across.
+
+            Defaults to a single channel.
+            Expected to be unchanging over time and can therefore be logged statically.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "samples": samples,
+                "sample_rate": sample_rate,
+                "channel_count": channel_count,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> Waveform:
+        """Clear all the fields of a `Waveform`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        samples: datatypes.Float64ArrayLike | None = None,
+        sample_rate: datatypes.Float64ArrayLike | None = None,
+        channel_count: datatypes.UInt32ArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        samples:
+            The interleaved samples of all channels in this block.
+        sample_rate:
+            The rate at which the samples were acquired.
+
+            In Hz for temporal timelines, and per tick for sequence timelines.
+            Expected to be unchanging over time and can therefore be logged statically.
+        channel_count:
+            The number of channels the samples are interleaved across.
+
+            Defaults to a single channel.
+            Expected to be unchanging over time and can therefore be logged statically.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                samples=samples,
+                sample_rate=sample_rate,
+                channel_count=channel_count,
+            )
+
+        batches = inst.as_component_batches()
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        kwargs = {
+            "Waveform:samples": samples,
+            "Waveform:sample_rate": sample_rate,
+            "Waveform:channel_count": channel_count,
+        }
+        columns = []
+
+        for batch in batches:
+            arrow_array = batch.as_arrow_array()
+
+            # For primitive arrays and fixed size list arrays, we infer partition size from the input shape.
+            if pa.types.is_primitive(arrow_array.type) or pa.types.is_fixed_size_list(arrow_array.type):
+                param = kwargs[batch.component_descriptor().component]  # type: ignore[index]
+                shape = np.shape(param)  # type: ignore[arg-type]
+                elem_flat_len = int(np.prod(shape[1:])) if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                if pa.types.is_fixed_size_list(arrow_array.type) and arrow_array.type.list_size == elem_flat_len:
+                    # If the product of the last dimensions of the shape are equal to the size of the fixed size list array,
+                    # we have `num_rows` single element batches (each element is a fixed sized list).
+                    # (This should have been already validated by conversion to the arrow_array)
+                    batch_length = 1
+                else:
+                    batch_length = shape[1] if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                num_rows = shape[0] if len(shape) >= 1 else 1  # type: ignore[redundant-expr,misc]
+                sizes = batch_length * np.ones(num_rows)
+            else:
+                # For non-primitive types, default to partitioning each element separately.
+                sizes = np.ones(len(arrow_array))
+
+            columns.append(batch.partition(sizes))
+
+        return ComponentColumnList(columns)
+
+    samples: components.ScalarBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ScalarBatch._converter,  # type: ignore[misc]
+    )
+    # The interleaved samples of all channels in this block.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    sample_rate: components.SampleRateBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.SampleRateBatch._converter,  # type: ignore[misc]
+    )
+    # The rate at which the samples were acquired.
+    #
+    # In Hz for temporal timelines, and per tick for sequence timelines.
+    # Expected to be unchanging over time and can therefore be logged statically.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    channel_count: components.ChannelCountBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ChannelCountBatch._converter,  # type: ignore[misc]
+    )
+    # The number of channels the samples are interleaved across.
+    #
+    # Defaults to a single channel.
+    # Expected to be unchanging over time and can therefore be logged statically.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
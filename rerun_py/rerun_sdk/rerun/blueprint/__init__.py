@@ -31,6 +31,7 @@ from .api import (
 from .archetypes import (
     Background as Background,
     EntityBehavior as EntityBehavior,
+    EntityTimeAlignment as EntityTimeAlignment,
     EyeControls3D as EyeControls3D,
     LineGrid3D as LineGrid3D,
     PlotLegend as PlotLegend,
@@ -6,6 +6,7 @@ from .background import Background
 from .container_blueprint import ContainerBlueprint
 from .dataframe_query import DataframeQuery
 from .entity_behavior import EntityBehavior
+from .entity_time_alignment import EntityTimeAlignment
 from .eye_controls3d import EyeControls3D
 from .force_center import ForceCenter
 from .force_collision_radius import ForceCollisionRadius
@@ -14,6 +15,7 @@ from .force_many_body import ForceManyBody
 from .force_position import ForcePosition
 from .line_grid3d import LineGrid3D
 from .map_background import MapBackground
+from .map_heatmap import MapHeatmap
 from .map_zoom import MapZoom
 from .near_clip_plane import NearClipPlane
 from .panel_blueprint import PanelBlueprint
@@ -35,6 +37,7 @@ __all__ = [
     "ContainerBlueprint",
     "DataframeQuery",
     "EntityBehavior",
+    "EntityTimeAlignment",
     "EyeControls3D",
     "ForceCenter",
     "ForceCollisionRadius",
@@ -43,6 +46,7 @@ __all__ = [
     "ForcePosition",
     "LineGrid3D",
     "MapBackground",
+    "MapHeatmap",
     "MapZoom",
     "NearClipPlane",
     "PanelBlueprint",
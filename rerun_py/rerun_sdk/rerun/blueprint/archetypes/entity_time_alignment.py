@@ -0,0 +1,166 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/entity_time_alignment.fbs".
+
+# You can extend this class by creating a "EntityTimeAlignmentExt" class in "entity_time_alignment_ext.py".
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any
+
+from attrs import define, field
+
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+if TYPE_CHECKING:
+    from ... import datatypes
+
+__all__ = ["EntityTimeAlignment"]
+
+
+@define(str=False, repr=False, init=False)
+class EntityTimeAlignment(Archetype):
+    """
+    **Archetype**: Aligns the data of an entity with the timeline, e.g. for sensors that were recorded with a skewed clock.
+
+    An entity's data logged at time `t` is shown at time `t * playback_rate + time_offset` on the timeline.
+
+    TODO(#6541): Fields of this archetype currently only have an effect when logged in the blueprint store.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        time_offset: datatypes.TimeIntLike | None = None,
+        playback_rate: datatypes.Float64Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the EntityTimeAlignment archetype.
+
+        Parameters
+        ----------
+        time_offset:
+            A constant offset added to the times of the entity's data.
+
+            This property is propagated down the entity hierarchy until another child entity
+            sets `time_offset` to a different value at which point propagation continues with that value instead.
+
+            Defaults to parent's `time_offset` value or 0 if there is no parent.
+        playback_rate:
+            How fast the clock of the entity's data runs relative to the timeline.
+
+            This property is propagated down the entity hierarchy until another child entity
+            sets `playback_rate` to a different value at which point propagation continues with that value instead.
+
+            Defaults to parent's `playback_rate` value or 1 if there is no parent.
+
+        """
+
+        # You can define your own __init__ function as a member of EntityTimeAlignmentExt in entity_time_alignment_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(time_offset=time_offset, playback_rate=playback_rate)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            time_offset=None,
+            playback_rate=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> EntityTimeAlignment:
+        """Produce an empty EntityTimeAlignment, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        time_offset: datatypes.TimeIntLike | None = None,
+        playback_rate: datatypes.Float64Like | None = None,
+    ) -> EntityTimeAlignment:
+        """
+        Update only some specific fields of a `EntityTimeAlignment`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        time_offset:
+            A constant offset added to the times of the entity's data.
+
+            This property is propagated down the entity hierarchy until another child entity
+            sets `time_offset` to a different value at which point propagation continues with that value instead.
+
+            Defaults to parent's `time_offset` value or 0 if there is no parent.
+        playback_rate:
+            How fast the clock of the entity's data runs relative to the timeline.
+
+            This property is propagated down the entity hierarchy until another child entity
+            sets `playback_rate` to a different value at which point propagation continues with that value instead.
+
+            Defaults to parent's `playback_rate` value or 1 if there is no parent.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "time_offset": time_offset,
+                "playback_rate": playback_rate,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> EntityTimeAlignment:
+        """Clear all the fields of a `EntityTimeAlignment`."""
+        return cls.from_fields(clear_unset=True)
+
+    time_offset: blueprint_components.TimeOffsetBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TimeOffsetBatch._converter,  # type: ignore[misc]
+    )
+    # A constant offset added to the times of the entity's data.
+    #
+    # This property is propagated down the entity hierarchy until another child entity
+    # sets `time_offset` to a different value at which point propagation continues with that value instead.
+    #
+    # Defaults to parent's `time_offset` value or 0 if there is no parent.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    playback_rate: blueprint_components.PlaybackRateBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.PlaybackRateBatch._converter,  # type: ignore[misc]
+    )
+    # How fast the clock of the entity's data runs relative to the timeline.
+    #
+    # This property is propagated down the entity hierarchy until another child entity
+    # sets `playback_rate` to a different value at which point propagation continues with that value instead.
+    #
+    # Defaults to parent's `playback_rate` value or 1 if there is no parent.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
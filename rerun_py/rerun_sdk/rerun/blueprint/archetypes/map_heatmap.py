@@ -0,0 +1,167 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/map_heatmap.fbs".
+
+# You can extend this class by creating a "MapHeatmapExt" class in "map_heatmap_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["MapHeatmap"]
+
+
+@define(str=False, repr=False, init=False)
+class MapHeatmap(Archetype):
+    """
+    **Archetype**: Configuration of the density heatmap layer of the map view.
+
+    The heatmap shows where geo points are concentrated,
+    which is easier to read than the individual points when there are many of them.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        visible: datatypes.BoolLike | None = None,
+        colormap: components.ColormapLike | None = None,
+        opacity: datatypes.Float32Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the MapHeatmap archetype.
+
+        Parameters
+        ----------
+        visible:
+            Whether the heatmap is visible.
+
+            Defaults to false.
+        colormap:
+            Colormap used to map the density of points to colors.
+
+            Defaults to inferno.
+        opacity:
+            Opacity of the heatmap on top of the background map.
+
+            Defaults to 0.6.
+
+        """
+
+        # You can define your own __init__ function as a member of MapHeatmapExt in map_heatmap_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(visible=visible, colormap=colormap, opacity=opacity)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            visible=None,
+            colormap=None,
+            opacity=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> MapHeatmap:
+        """Produce an empty MapHeatmap, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        visible: datatypes.BoolLike | None = None,
+        colormap: components.ColormapLike | None = None,
+        opacity: datatypes.Float32Like | None = None,
+    ) -> MapHeatmap:
+        """
+        Update only some specific fields of a `MapHeatmap`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        visible:
+            Whether the heatmap is visible.
+
+            Defaults to false.
+        colormap:
+            Colormap used to map the density of points to colors.
+
+            Defaults to inferno.
+        opacity:
+            Opacity of the heatmap on top of the background map.
+
+            Defaults to 0.6.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "visible": visible,
+                "colormap": colormap,
+                "opacity": opacity,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> MapHeatmap:
+        """Clear all the fields of a `MapHeatmap`."""
+        return cls.from_fields(clear_unset=True)
+
+    visible: components.VisibleBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.VisibleBatch._converter,  # type: ignore[misc]
+    )
+    # Whether the heatmap is visible.
+    #
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    colormap: components.ColormapBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColormapBatch._converter,  # type: ignore[misc]
+    )
+    # Colormap used to map the density of points to colors.
+    #
+    # Defaults to inferno.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    opacity: components.OpacityBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.OpacityBatch._converter,  # type: ignore[misc]
+    )
+    # Opacity of the heatmap on top of the background map.
+    #
+    # Defaults to 0.6.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -26,11 +26,13 @@ from .lock_range_during_zoom import LockRangeDuringZoom, LockRangeDuringZoomBatc
 from .map_provider import MapProvider, MapProviderArrayLike, MapProviderBatch, MapProviderLike
 from .near_clip_plane import NearClipPlane, NearClipPlaneBatch
 from .panel_state import PanelState, PanelStateArrayLike, PanelStateBatch, PanelStateLike
+from .playback_rate import PlaybackRate, PlaybackRateBatch
 from .query_expression import QueryExpression, QueryExpressionBatch
 from .root_container import RootContainer, RootContainerBatch
 from .row_share import RowShare, RowShareBatch
 from .selected_columns import SelectedColumns, SelectedColumnsBatch
 from .tensor_dimension_index_slider import TensorDimensionIndexSlider, TensorDimensionIndexSliderBatch
+from .time_offset import TimeOffset, TimeOffsetBatch
 from .timeline_name import TimelineName, TimelineNameBatch
 from .view_class import ViewClass, ViewClassBatch
 from .view_fit import ViewFit, ViewFitArrayLike, ViewFitBatch, ViewFitLike
@@ -105,6 +107,8 @@ __all__ = [
     "PanelStateArrayLike",
     "PanelStateBatch",
     "PanelStateLike",
+    "PlaybackRate",
+    "PlaybackRateBatch",
     "QueryExpression",
     "QueryExpressionBatch",
     "RootContainer",
@@ -115,6 +119,8 @@ __all__ = [
     "SelectedColumnsBatch",
     "TensorDimensionIndexSlider",
     "TensorDimensionIndexSliderBatch",
+    "TimeOffset",
+    "TimeOffsetBatch",
     "TimelineName",
     "TimelineNameBatch",
     "ViewClass",
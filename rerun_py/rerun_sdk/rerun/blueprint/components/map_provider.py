@@ -36,6 +36,13 @@ class MapProvider(Enum):
     MapboxSatellite = 4
     """Mapbox Satellite is a satellite map designed by Mapbox."""
 
+    Custom = 5
+    """
+    Tiles from the URL template configured in the viewer settings.
+
+    Use this for self-hosted tile servers, e.g. one serving offline MBTiles.
+    """
+
     @classmethod
     def auto(cls, val: str | int | MapProvider) -> MapProvider:
         """Best-effort converter, including a case-insensitive string matcher."""
@@ -60,10 +67,12 @@ class MapProvider(Enum):
 MapProviderLike = Union[
     MapProvider,
     Literal[
+        "Custom",
         "MapboxDark",
         "MapboxSatellite",
         "MapboxStreets",
         "OpenStreetMap",
+        "custom",
         "mapboxdark",
         "mapboxsatellite",
         "mapboxstreets",
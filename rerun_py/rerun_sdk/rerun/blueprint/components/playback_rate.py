@@ -0,0 +1,37 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/playback_rate.fbs".
+
+# You can extend this class by creating a "PlaybackRateExt" class in "playback_rate_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["PlaybackRate", "PlaybackRateBatch"]
+
+
+class PlaybackRate(datatypes.Float64, ComponentMixin):
+    """
+    **Component**: How fast the clock of an entity's data runs relative to the timeline.
+
+    A rate of 1 means both clocks run at the same speed.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of PlaybackRateExt in playback_rate_ext.py
+
+    # Note: there are no fields here because PlaybackRate delegates to datatypes.Float64
+
+
+class PlaybackRateBatch(datatypes.Float64Batch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.blueprint.components.PlaybackRate"
+
+
+# This is patched in late to avoid circular dependencies.
+PlaybackRate._BATCH_TYPE = PlaybackRateBatch  # type: ignore[assignment]
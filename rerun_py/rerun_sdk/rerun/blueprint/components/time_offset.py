@@ -0,0 +1,37 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/time_offset.fbs".
+
+# You can extend this class by creating a "TimeOffsetExt" class in "time_offset_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["TimeOffset", "TimeOffsetBatch"]
+
+
+class TimeOffset(datatypes.TimeInt, ComponentMixin):
+    """
+    **Component**: A constant offset added to the times of an entity's data, to align it with the timeline.
+
+    In nanoseconds for temporal timelines, and in ticks for sequence timelines.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of TimeOffsetExt in time_offset_ext.py
+
+    # Note: there are no fields here because TimeOffset delegates to datatypes.TimeInt
+
+
+class TimeOffsetBatch(datatypes.TimeIntBatch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.blueprint.components.TimeOffset"
+
+
+# This is patched in late to avoid circular dependencies.
+TimeOffset._BATCH_TYPE = TimeOffsetBatch  # type: ignore[assignment]
@@ -79,6 +79,7 @@ class MapView(View):
         | None = None,
         zoom: blueprint_archetypes.MapZoom | datatypes.Float64Like | None = None,
         background: blueprint_archetypes.MapBackground | blueprint_components.MapProviderLike | None = None,
+        heatmap: blueprint_archetypes.MapHeatmap | None = None,
     ) -> None:
         """
         Construct a blueprint for a new MapView view.
@@ -119,6 +120,8 @@ class MapView(View):
             Configures the zoom level of the map view.
         background:
             Configuration for the background map of the map view.
+        heatmap:
+            Configuration for the density heatmap layer over the geo points.
 
         """
 
@@ -133,6 +136,9 @@ class MapView(View):
                 background = blueprint_archetypes.MapBackground(background)
             properties["MapBackground"] = background
 
+        if heatmap is not None:
+            properties["MapHeatmap"] = heatmap
+
         super().__init__(
             class_identifier="Map",
             origin=origin,
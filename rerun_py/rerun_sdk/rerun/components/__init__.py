@@ -17,13 +17,17 @@ from .annotation_context import (
 )
 from .axis_length import AxisLength, AxisLengthBatch
 from .blob import Blob, BlobBatch
+from .channel_count import ChannelCount, ChannelCountBatch
 from .class_id import ClassId, ClassIdBatch
 from .clear_is_recursive import ClearIsRecursive, ClearIsRecursiveBatch
 from .color import Color, ColorBatch
 from .colormap import Colormap, ColormapArrayLike, ColormapBatch, ColormapLike
 from .depth_meter import DepthMeter, DepthMeterBatch
+from .distortion_coefficient import DistortionCoefficient, DistortionCoefficientBatch
+from .distortion_model import DistortionModel, DistortionModelArrayLike, DistortionModelBatch, DistortionModelLike
 from .draw_order import DrawOrder, DrawOrderBatch
 from .entity_path import EntityPath, EntityPathBatch
+from .euler_convention import EulerConvention, EulerConventionArrayLike, EulerConventionBatch, EulerConventionLike
 from .fill_mode import FillMode, FillModeArrayLike, FillModeBatch, FillModeLike
 from .fill_ratio import FillRatio, FillRatioBatch
 from .gamma_correction import GammaCorrection, GammaCorrectionBatch
@@ -67,7 +71,9 @@ from .radius import Radius, RadiusBatch
 from .range1d import Range1D, Range1DBatch
 from .resolution import Resolution, ResolutionBatch
 from .rotation_axis_angle import RotationAxisAngle, RotationAxisAngleBatch
+from .rotation_euler import RotationEuler, RotationEulerBatch
 from .rotation_quat import RotationQuat, RotationQuatBatch
+from .sample_rate import SampleRate, SampleRateBatch
 from .scalar import Scalar, ScalarBatch
 from .scale3d import Scale3D, Scale3DBatch
 from .series_visible import SeriesVisible, SeriesVisibleBatch
@@ -90,6 +96,7 @@ from .transform_relation import (
 )
 from .translation3d import Translation3D, Translation3DBatch
 from .triangle_indices import TriangleIndices, TriangleIndicesBatch
+from .unit import Unit, UnitBatch
 from .value_range import ValueRange, ValueRangeBatch
 from .vector2d import Vector2D, Vector2DBatch
 from .vector3d import Vector3D, Vector3DBatch
@@ -114,6 +121,8 @@ __all__ = [
     "AxisLengthBatch",
     "Blob",
     "BlobBatch",
+    "ChannelCount",
+    "ChannelCountBatch",
     "ClassId",
     "ClassIdBatch",
     "ClearIsRecursive",
@@ -126,10 +135,20 @@ __all__ = [
     "ColormapLike",
     "DepthMeter",
     "DepthMeterBatch",
+    "DistortionCoefficient",
+    "DistortionCoefficientBatch",
+    "DistortionModel",
+    "DistortionModelArrayLike",
+    "DistortionModelBatch",
+    "DistortionModelLike",
     "DrawOrder",
     "DrawOrderBatch",
     "EntityPath",
     "EntityPathBatch",
+    "EulerConvention",
+    "EulerConventionArrayLike",
+    "EulerConventionBatch",
+    "EulerConventionLike",
     "FillMode",
     "FillModeArrayLike",
     "FillModeBatch",
@@ -220,8 +239,12 @@ __all__ = [
     "ResolutionBatch",
     "RotationAxisAngle",
     "RotationAxisAngleBatch",
+    "RotationEuler",
+    "RotationEulerBatch",
     "RotationQuat",
     "RotationQuatBatch",
+    "SampleRate",
+    "SampleRateBatch",
     "Scalar",
     "ScalarBatch",
     "Scale3D",
@@ -258,6 +281,8 @@ __all__ = [
     "Translation3DBatch",
     "TriangleIndices",
     "TriangleIndicesBatch",
+    "Unit",
+    "UnitBatch",
     "ValueRange",
     "ValueRangeBatch",
     "Vector2D",
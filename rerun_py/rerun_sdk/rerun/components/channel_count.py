@@ -0,0 +1,35 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/channel_count.fbs".
+
+# You can extend this class by creating a "ChannelCountExt" class in "channel_count_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["ChannelCount", "ChannelCountBatch"]
+
+
+class ChannelCount(datatypes.UInt32, ComponentMixin):
+    """
+    **Component**: The number of channels that samples are interleaved across.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of ChannelCountExt in channel_count_ext.py
+
+    # Note: there are no fields here because ChannelCount delegates to datatypes.UInt32
+
+
+class ChannelCountBatch(datatypes.UInt32Batch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.components.ChannelCount"
+
+
+# This is patched in late to avoid circular dependencies.
+ChannelCount._BATCH_TYPE = ChannelCountBatch  # type: ignore[assignment]
@@ -0,0 +1,35 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/distortion_coefficient.fbs".
+
+# You can extend this class by creating a "DistortionCoefficientExt" class in "distortion_coefficient_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["DistortionCoefficient", "DistortionCoefficientBatch"]
+
+
+class DistortionCoefficient(datatypes.Float32, ComponentMixin):
+    """
+    **Component**: A single lens distortion coefficient of a camera.
+
+    The meaning of each coefficient depends on its index and the [`components.DistortionModel`][rerun.components.DistortionModel].
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of DistortionCoefficientExt in distortion_coefficient_ext.py
+
+    # Note: there are no fields here because DistortionCoefficient delegates to datatypes.Float32
+
+
+class DistortionCoefficientBatch(datatypes.Float32Batch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.components.DistortionCoefficient"
+
+
+# This is patched in late to avoid circular dependencies.
+DistortionCoefficient._BATCH_TYPE = DistortionCoefficientBatch  # type: ignore[assignment]
@@ -0,0 +1,84 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/distortion_model.fbs".
+
+# You can extend this class by creating a "DistortionModelExt" class in "distortion_model_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal, Union
+
+import pyarrow as pa
+
+from .._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+)
+
+__all__ = ["DistortionModel", "DistortionModelArrayLike", "DistortionModelBatch", "DistortionModelLike"]
+
+
+from enum import Enum
+
+
+class DistortionModel(Enum):
+    """
+    **Component**: The lens distortion model of a camera, which defines how [`components.DistortionCoefficient`][rerun.components.DistortionCoefficient]s are interpreted.
+
+    All models operate on normalized image coordinates, i.e. camera space coordinates divided by their depth.
+    """
+
+    PlumbBob = 1
+    """
+    The Brown-Conrady model used by OpenCV, also known as `plumb_bob` in ROS.
+
+    The coefficients are `[k1, k2, p1, p2, k3, k4, k5, k6]`, where `k` are radial and `p` tangential coefficients.
+    Any trailing coefficients may be omitted, in which case they are zero.
+    Specifying `k4`, `k5` and `k6` yields the `rational_polynomial` model of OpenCV.
+    """
+
+    Fisheye = 2
+    """
+    The equidistant fisheye model used by OpenCV's `fisheye` module, also known as `equidistant` in ROS.
+
+    The coefficients are `[k1, k2, k3, k4]`.
+    Any trailing coefficients may be omitted, in which case they are zero.
+    """
+
+    @classmethod
+    def auto(cls, val: str | int | DistortionModel) -> DistortionModel:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, DistortionModel):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+DistortionModelLike = Union[DistortionModel, Literal["Fisheye", "PlumbBob", "fisheye", "plumbbob"], int]
+DistortionModelArrayLike = Union[DistortionModelLike, Sequence[DistortionModelLike]]
+
+
+class DistortionModelBatch(BaseBatch[DistortionModelArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_TYPE: str = "rerun.components.DistortionModel"
+
+    @staticmethod
+    def _native_to_pa_array(data: DistortionModelArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (DistortionModel, int, str)):
+            data = [data]
+
+        pa_data = [DistortionModel.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
@@ -0,0 +1,92 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/euler_convention.fbs".
+
+# You can extend this class by creating a "EulerConventionExt" class in "euler_convention_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal, Union
+
+import pyarrow as pa
+
+from .._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+)
+
+__all__ = ["EulerConvention", "EulerConventionArrayLike", "EulerConventionBatch", "EulerConventionLike"]
+
+
+from enum import Enum
+
+
+class EulerConvention(Enum):
+    """
+    **Component**: The order of the axes that the angles of a [`components.RotationEuler`][rerun.components.RotationEuler] are applied about.
+
+    All conventions describe intrinsic rotations, i.e. each rotation is about an axis of the frame
+    resulting from the previous rotations.
+    """
+
+    Xyz = 1
+    """Rotate about X, then about the new Y, then about the new Z."""
+
+    Xzy = 2
+    """Rotate about X, then about the new Z, then about the new Y."""
+
+    Yxz = 3
+    """Rotate about Y, then about the new X, then about the new Z."""
+
+    Yzx = 4
+    """Rotate about Y, then about the new Z, then about the new X."""
+
+    Zxy = 5
+    """Rotate about Z, then about the new X, then about the new Y."""
+
+    Zyx = 6
+    """
+    Rotate about Z, then about the new Y, then about the new X.
+
+    This is the common yaw, pitch, roll convention used in aerospace and robotics.
+    """
+
+    @classmethod
+    def auto(cls, val: str | int | EulerConvention) -> EulerConvention:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, EulerConvention):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+EulerConventionLike = Union[
+    EulerConvention, Literal["Xyz", "Xzy", "Yxz", "Yzx", "Zxy", "Zyx", "xyz", "xzy", "yxz", "yzx", "zxy", "zyx"], int
+]
+EulerConventionArrayLike = Union[EulerConventionLike, Sequence[EulerConventionLike]]
+
+
+class EulerConventionBatch(BaseBatch[EulerConventionArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_TYPE: str = "rerun.components.EulerConvention"
+
+    @staticmethod
+    def _native_to_pa_array(data: EulerConventionArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (EulerConvention, int, str)):
+            data = [data]
+
+        pa_data = [EulerConvention.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
@@ -0,0 +1,36 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/rotation_euler.fbs".
+
+# You can extend this class by creating a "RotationEulerExt" class in "rotation_euler_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["RotationEuler", "RotationEulerBatch"]
+
+
+class RotationEuler(datatypes.Vec3D, ComponentMixin):
+    """
+    **Component**: A 3D rotation expressed as three Euler angles, in radians.
+
+    The angles are intrinsic rotations about the axes given by [`components.EulerConvention`][rerun.components.EulerConvention], in that order.
+    E.g. for the default `Zyx` convention, the angles are yaw (about Z), pitch (about the new Y) and roll (about the new X).
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of RotationEulerExt in rotation_euler_ext.py
+
+    # Note: there are no fields here because RotationEuler delegates to datatypes.Vec3D
+
+
+class RotationEulerBatch(datatypes.Vec3DBatch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.components.RotationEuler"
+
+
+# This is patched in late to avoid circular dependencies.
+RotationEuler._BATCH_TYPE = RotationEulerBatch  # type: ignore[assignment]
@@ -0,0 +1,37 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/sample_rate.fbs".
+
+# You can extend this class by creating a "SampleRateExt" class in "sample_rate_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["SampleRate", "SampleRateBatch"]
+
+
+class SampleRate(datatypes.Float64, ComponentMixin):
+    """
+    **Component**: The rate at which evenly spaced samples were acquired, in samples per second (Hz).
+
+    On sequence timelines, this is interpreted as samples per timeline tick instead.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of SampleRateExt in sample_rate_ext.py
+
+    # Note: there are no fields here because SampleRate delegates to datatypes.Float64
+
+
+class SampleRateBatch(datatypes.Float64Batch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.components.SampleRate"
+
+
+# This is patched in late to avoid circular dependencies.
+SampleRate._BATCH_TYPE = SampleRateBatch  # type: ignore[assignment]
@@ -21,7 +21,7 @@ use re_protos::{
     },
     common::v1alpha1::{
         TaskId,
-        ext::{IfDuplicateBehavior, ScanParameters},
+        ext::{IfDuplicateBehavior, PartitionId, ScanParameters},
     },
     frontend::v1alpha1::{GetChunksRequest, GetDatasetSchemaRequest, QueryDatasetRequest},
     manifest_registry::v1alpha1::ext::{DataSource, RegisterWithDatasetTaskDescriptor},
@@ -322,6 +322,61 @@ impl ConnectionHandle {
         )
     }
 
+    #[tracing::instrument(level = "info", skip_all)]
+    pub fn update_partition_tags(
+        &self,
+        py: Python<'_>,
+        dataset_id: EntryId,
+        partition_id: String,
+        add_tags: BTreeMap<String, Option<String>>,
+        remove_tags: Vec<String>,
+    ) -> PyResult<BTreeMap<String, Option<String>>> {
+        wait_for_future(
+            py,
+            async {
+                self.client()
+                    .await?
+                    .update_partition_tags(
+                        dataset_id,
+                        PartitionId::new(partition_id),
+                        add_tags.into_iter().collect(),
+                        remove_tags,
+                    )
+                    .await
+                    .map(|tags| tags.into_iter().collect())
+                    .map_err(to_py_err)
+            }
+            .in_current_span(),
+        )
+    }
+
+    #[tracing::instrument(level = "info", skip_all)]
+    pub fn find_partitions(
+        &self,
+        py: Python<'_>,
+        dataset_id: EntryId,
+        tags: BTreeMap<String, Option<String>>,
+        time_range: Option<(re_log_types::TimelineName, re_log_types::AbsoluteTimeRange)>,
+    ) -> PyResult<Vec<String>> {
+        wait_for_future(
+            py,
+            async {
+                self.client()
+                    .await?
+                    .find_partitions(dataset_id, tags.into_iter().collect(), time_range)
+                    .await
+                    .map(|partition_ids| {
+                        partition_ids
+                            .into_iter()
+                            .map(|partition_id| partition_id.id)
+                            .collect()
+                    })
+                    .map_err(to_py_err)
+            }
+            .in_current_span(),
+        )
+    }
+
     // TODO(ab): migrate this to the `ConnectionClient` API.
     #[tracing::instrument(level = "info", skip_all)]
     pub fn query_tasks(&self, py: Python<'_>, task_ids: &[TaskId]) -> PyResult<RecordBatch> {
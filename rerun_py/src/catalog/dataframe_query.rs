@@ -77,7 +77,11 @@ impl PyDataframeQueryView {
                 filtered_index_values: None,
                 using_index_values: None,
                 filtered_is_not_null: None,
+                filtered_component_values: None,
+                filtered_component_predicates: Vec::new(),
+                join_on_entity: None,
                 sparse_fill_strategy: SparseFillStrategy::None,
+                aggregation: None,
                 selection: None,
             },
             partition_ids: vec![],
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use arrow::array::{RecordBatch, StringArray};
@@ -247,6 +248,118 @@ impl PyDatasetEntry {
         .to_string())
     }
 
+    /// Add tags to, and remove tags from, a partition.
+    ///
+    /// Tags are either plain labels (with a `None` value), or properties with a value, e.g.
+    /// `{"robot_id": "r2d2", "mission": "survey-42", "reviewed": None}`. They show up in the
+    /// partition table, and can be searched for with [`DatasetEntry.find_partitions`][].
+    ///
+    /// Parameters
+    /// ----------
+    /// partition_id: str
+    ///     The ID of the partition to tag.
+    ///
+    /// add: dict[str, str | None] | None
+    ///     The tags to add. Adding a tag the partition already has replaces its value.
+    ///
+    /// remove: list[str] | None
+    ///     The names of the tags to remove.
+    ///
+    /// Returns
+    /// -------
+    /// dict[str, str | None]
+    ///     All the tags of the partition, once updated.
+    #[pyo3(signature = (partition_id, *, add = None, remove = None))]
+    #[instrument(skip_all, err)]
+    fn update_partition_tags(
+        self_: PyRef<'_, Self>,
+        py: Python<'_>,
+        partition_id: String,
+        add: Option<BTreeMap<String, Option<String>>>,
+        remove: Option<Vec<String>>,
+    ) -> PyResult<BTreeMap<String, Option<String>>> {
+        let super_ = self_.as_super();
+        let connection = super_.client.borrow(py).connection().clone();
+        let dataset_id = super_.details.id;
+
+        connection.update_partition_tags(
+            py,
+            dataset_id,
+            partition_id,
+            add.unwrap_or_default(),
+            remove.unwrap_or_default(),
+        )
+    }
+
+    /// Return the IDs of the partitions matching all the given criteria.
+    ///
+    /// Parameters
+    /// ----------
+    /// tags: dict[str, str | None] | None
+    ///     The tags the partitions must have. A `None` value matches any value of the tag.
+    ///
+    /// timeline: str | None
+    ///     The timeline on which the partitions must have data between `start` and `end`.
+    ///
+    /// start: int | datetime | None
+    ///     The start of the time range, inclusive.
+    ///     Integer for ticks, or datetime/nanoseconds for timestamps.
+    ///
+    /// end: int | datetime | None
+    ///     The end of the time range, inclusive.
+    ///     Integer for ticks, or datetime/nanoseconds for timestamps.
+    ///
+    /// Examples
+    /// --------
+    /// # The runs of a robot during a mission
+    /// >>> dataset.find_partitions(tags={"robot_id": "r2d2", "mission": None})
+    ///
+    /// # The runs recorded in the last day
+    /// >>> dataset.find_partitions(timeline="log_time", start=datetime.now() - timedelta(days=1))
+    ///
+    /// Returns
+    /// -------
+    /// list[str]
+    ///     The IDs of the matching partitions.
+    #[pyo3(signature = (*, tags = None, timeline = None, start = None, end = None))]
+    #[instrument(skip_all, err)]
+    fn find_partitions(
+        self_: PyRef<'_, Self>,
+        py: Python<'_>,
+        tags: Option<BTreeMap<String, Option<String>>>,
+        timeline: Option<&str>,
+        start: Option<Bound<'_, PyAny>>,
+        end: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<Vec<String>> {
+        let super_ = self_.as_super();
+        let connection = super_.client.borrow(py).connection().clone();
+        let dataset_id = super_.details.id;
+
+        if timeline.is_none() && (start.is_some() || end.is_some()) {
+            return Err(PyValueError::new_err(
+                "If `start` or `end` is specified, `timeline` must also be specified.",
+            ));
+        }
+
+        let start = start
+            .as_ref()
+            .map(|s| py_object_to_i64(py, s))
+            .transpose()?;
+        let end = end.as_ref().map(|e| py_object_to_i64(py, e)).transpose()?;
+
+        let time_range = timeline.map(|name| {
+            (
+                re_log_types::TimelineName::new(name),
+                re_log_types::AbsoluteTimeRange::new(
+                    re_log_types::NonMinI64::saturating_from_i64(start.unwrap_or(i64::MIN)),
+                    re_log_types::NonMinI64::saturating_from_i64(end.unwrap_or(i64::MAX)),
+                ),
+            )
+        });
+
+        connection.find_partitions(py, dataset_id, tags.unwrap_or_default(), time_range)
+    }
+
     /// Register a RRD URI to the dataset and wait for completion.
     ///
     /// This method registers a single recording to the dataset and blocks until the registration is
@@ -1,14 +1,20 @@
 #![expect(deprecated)] // False positive due to macro
 
-use arrow::array::{RecordBatchIterator, RecordBatchReader};
-use arrow::pyarrow::PyArrowType;
+use std::num::NonZeroU64;
+use std::str::FromStr as _;
+
+use arrow::array::{ArrayData, RecordBatchIterator, RecordBatchReader, make_array};
+use arrow::pyarrow::{FromPyArrow as _, PyArrowType};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::{PyAnyMethods as _, PyTupleMethods as _};
 use pyo3::types::PyTuple;
-use pyo3::{Bound, PyRef, PyResult, Python, pyclass, pymethods};
+use pyo3::{Bound, PyAny, PyRef, PyResult, Python, pyclass, pymethods};
 
-use re_chunk_store::{QueryExpression, SparseFillStrategy};
-use re_log_types::AbsoluteTimeRange;
+use re_chunk_store::{
+    AggregationOperator, ComparisonOperator, ComponentPredicate, QueryExpression,
+    SparseFillStrategy, TemporalAggregation,
+};
+use re_log_types::{AbsoluteTimeRange, EntityPath};
 use re_sorbet::{ColumnDescriptor, ColumnSelector};
 
 use super::{
@@ -492,6 +498,85 @@ impl PyRecordingView {
         })
     }
 
+    #[allow(rustdoc::private_doc_tests)]
+    /// Filter the view to only include rows where the given component column satisfies a comparison.
+    ///
+    /// A row matches if at least one of the component's instances satisfies the comparison.
+    /// The predicate is evaluated while iterating over the data, so filtered-out rows are never
+    /// materialized. Calling this multiple times only keeps the rows that match all predicates.
+    ///
+    /// Parameters
+    /// ----------
+    /// column : AnyComponentColumn
+    ///     The component column to filter by.
+    /// op : str
+    ///     The comparison operator, one of `==`, `!=`, `<`, `<=`, `>`, `>=`.
+    /// value : Any
+    ///     The value to compare against. It is converted to a pyarrow scalar, and then cast to
+    ///     the datatype of the component.
+    ///
+    /// Returns
+    /// -------
+    /// RecordingView
+    ///     A new view containing only the data where the specified component column satisfies the
+    ///     comparison.
+    ///
+    ///     The original view will not be modified.
+    fn filter_where(
+        &self,
+        py: Python<'_>,
+        column: AnyComponentColumn,
+        op: &str,
+        value: Bound<'_, PyAny>,
+    ) -> PyResult<Self> {
+        let column = column.into_selector()?;
+        let op = ComparisonOperator::from_str(op).map_err(PyValueError::new_err)?;
+
+        let value = py
+            .import("pyarrow")?
+            .getattr("array")?
+            .call1((vec![value],))?;
+        let value = make_array(ArrayData::from_pyarrow_bound(&value)?);
+
+        let mut query_expression = self.query_expression.clone();
+        query_expression
+            .filtered_component_predicates
+            .push(ComponentPredicate { column, op, value });
+
+        Ok(Self {
+            recording: self.recording.clone(),
+            query_expression,
+        })
+    }
+
+    /// Only include rows where the given entity has data, and align all other entities on them.
+    ///
+    /// This is akin to an as-of join: the columns of all the other entities yield their most
+    /// recent value at or before each row, regardless of [`.fill_latest_at()`][rerun.dataframe.RecordingView.fill_latest_at].
+    /// This makes it possible to e.g. compare data logged at different rates without having to
+    /// merge it client-side.
+    ///
+    /// Parameters
+    /// ----------
+    /// entity_path : str
+    ///     The entity whose rows drive the view.
+    ///
+    /// Returns
+    /// -------
+    /// RecordingView
+    ///     A new view containing one row per index value at which the given entity has data.
+    ///
+    ///     The original view will not be modified.
+    fn join_on_entity(&self, entity_path: &str) -> Self {
+        let mut query_expression = self.query_expression.clone();
+        query_expression.join_on_entity = Some(EntityPath::parse_forgiving(entity_path));
+
+        Self {
+            recording: self.recording.clone(),
+            query_expression,
+        }
+    }
+
     #[allow(rustdoc::private_doc_tests)]
     /// Replace the index in the view with the provided values.
     ///
@@ -544,4 +629,41 @@ impl PyRecordingView {
             query_expression,
         }
     }
+
+    #[allow(rustdoc::private_doc_tests)]
+    /// Reduce the rows of the view into fixed-size buckets of index values.
+    ///
+    /// Buckets are aligned on multiples of `bucket_size`, and each non-empty bucket yields a
+    /// single row whose index value is the start of the bucket. Numeric component columns are
+    /// reduced instance-wise using `op`, all other columns yield their most recent value.
+    ///
+    /// This bounds the number of rows returned for very large datasets, e.g. when plotting
+    /// long scalar series.
+    ///
+    /// Parameters
+    /// ----------
+    /// bucket_size : int
+    ///     The size of each bucket, in index units (e.g. nanoseconds or sequence numbers).
+    /// op : str
+    ///     The aggregation operator, one of `min`, `max`, `mean`, `last`.
+    ///
+    /// Returns
+    /// -------
+    /// RecordingView
+    ///     A new view with one row per bucket.
+    ///
+    ///     The original view will not be modified.
+    fn aggregate(&self, bucket_size: u64, op: &str) -> PyResult<Self> {
+        let bucket_size = NonZeroU64::new(bucket_size)
+            .ok_or_else(|| PyValueError::new_err("`bucket_size` must be greater than zero"))?;
+        let op = AggregationOperator::from_str(op).map_err(PyValueError::new_err)?;
+
+        let mut query_expression = self.query_expression.clone();
+        query_expression.aggregation = Some(TemporalAggregation { bucket_size, op });
+
+        Ok(Self {
+            recording: self.recording.clone(),
+            query_expression,
+        })
+    }
 }
@@ -3,9 +3,11 @@
 use arrow::array::RecordBatch;
 use pyo3::{Bound, PyResult, prelude::*};
 
-use re_grpc_client::message_proxy::write_table::viewer_client;
+use re_grpc_client::message_proxy::{
+    token_from_env,
+    write_table::{ViewerClient, viewer_client},
+};
 use re_log_encoding::codec::wire::encoder::Encode as _;
-use re_protos::sdk_comms::v1alpha1::message_proxy_service_client::MessageProxyServiceClient;
 
 use crate::{catalog::to_py_err, utils::wait_for_future};
 
@@ -56,12 +58,13 @@ impl PyViewerClient {
 // TODO(grtlr): In the future, we probably want to merge this with the other APIs.
 #[derive(Clone)]
 pub struct ViewerConnectionHandle {
-    client: MessageProxyServiceClient<tonic::transport::Channel>,
+    client: ViewerClient,
 }
 
 impl ViewerConnectionHandle {
     pub fn new(py: Python<'_>, origin: re_uri::Origin) -> PyResult<Self> {
-        let client = wait_for_future(py, viewer_client(origin.clone())).map_err(to_py_err)?;
+        let client = wait_for_future(py, viewer_client(origin.clone(), token_from_env()))
+            .map_err(to_py_err)?;
 
         Ok(Self { client })
     }